@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use ansi_to_tui::Error;
 use ansi_to_tui::IntoText;
 use ratatui::text::Line;
@@ -8,16 +10,101 @@ use ratatui::text::Text;
 // transcript views (e.g., `nl` separates line numbers from content with a tab).
 // Replacing tabs with spaces avoids odd visual artifacts without changing
 // semantics for our use cases.
-fn expand_tabs(s: &str) -> std::borrow::Cow<'_, str> {
+fn expand_tabs(s: &str) -> Cow<'_, str> {
     if s.contains('\t') {
         // Keep it simple: replace each tab with 4 spaces.
         // We do not try to align to tab stops since most usages (like `nl`)
         // look acceptable with a fixed substitution and this avoids stateful math
         // across spans.
-        std::borrow::Cow::Owned(s.replace('\t', "    "))
+        Cow::Owned(s.replace('\t', "    "))
     } else {
-        std::borrow::Cow::Borrowed(s)
+        Cow::Borrowed(s)
+    }
+}
+
+/// Collapses carriage-return rewrites the way a terminal shows them once a
+/// line is finished. Progress bars redraw themselves with `\r`, so for each
+/// `\n`-separated line only the last `\r` segment with visible text is kept;
+/// a trailing `\r` (CRLF line endings) is simply dropped.
+pub fn collapse_carriage_returns(s: &str) -> Cow<'_, str> {
+    if !s.contains('\r') {
+        return Cow::Borrowed(s);
     }
+    let collapsed = s
+        .split('\n')
+        .map(|line| {
+            line.rsplit('\r')
+                .find(|segment| !strip_ansi_escapes(segment).is_empty())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Cow::Owned(collapsed)
+}
+
+/// Removes every escape sequence and stray control character, leaving the
+/// text a reader would see. Used when output leaves the terminal, e.g. in
+/// exported transcripts.
+pub fn strip_ansi_escapes(s: &str) -> Cow<'_, str> {
+    filter_escapes(s, false)
+}
+
+/// Drops escape sequences that cannot be mapped onto a ratatui style (cursor
+/// movement, erase-line, OSC titles and hyperlinks, ...). SGR color sequences
+/// are kept when `keep_sgr` is set.
+fn filter_escapes(s: &str, keep_sgr: bool) -> Cow<'_, str> {
+    if !s
+        .chars()
+        .any(|ch| ch.is_control() && !matches!(ch, '\t' | '\n' | '\r'))
+    {
+        return Cow::Borrowed(s);
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, ch)) = chars.next() {
+        if ch != '\u{1b}' {
+            if !ch.is_control() || matches!(ch, '\t' | '\n' | '\r') {
+                out.push(ch);
+            }
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters and intermediates, then a final byte in `@..=~`.
+            Some((_, '[')) => {
+                let mut end = None;
+                for (idx, c) in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        end = Some((idx, c));
+                        break;
+                    }
+                }
+                if let Some((idx, 'm')) = end
+                    && keep_sgr
+                {
+                    out.push_str(&s[start..=idx]);
+                }
+            }
+            // OSC: terminated by BEL or ST (`ESC \`).
+            Some((_, ']')) => {
+                while let Some((_, c)) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+                    if c == '\u{1b}' {
+                        chars.next_if(|(_, c)| *c == '\\');
+                        break;
+                    }
+                }
+            }
+            // Character set designation takes one more byte.
+            Some((_, '(' | ')' | '*' | '+')) => {
+                chars.next();
+            }
+            Some(_) | None => {}
+        }
+    }
+    Cow::Owned(out)
 }
 
 /// This function should be used when the contents of `s` are expected to match
@@ -26,6 +113,7 @@ fn expand_tabs(s: &str) -> std::borrow::Cow<'_, str> {
 pub fn ansi_escape_line(s: &str) -> Line<'static> {
     // Normalize tabs to spaces to avoid odd gutter collisions in transcript mode.
     let s = expand_tabs(s);
+    let s = collapse_carriage_returns(&s);
     let text = ansi_escape(&s);
     match text.lines.as_slice() {
         [] => "".into(),
@@ -38,9 +126,10 @@ pub fn ansi_escape_line(s: &str) -> Line<'static> {
 }
 
 pub fn ansi_escape(s: &str) -> Text<'static> {
+    let s = filter_escapes(s, true);
     // to_text() claims to be faster, but introduces complex lifetime issues
     // such that it's not worth it.
-    match s.into_text() {
+    match s.as_ref().into_text() {
         Ok(text) => text,
         Err(err) => match err {
             Error::NomError(message) => {
@@ -56,3 +145,33 @@ pub fn ansi_escape(s: &str) -> Text<'static> {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use ratatui::style::Color;
+
+    #[test]
+    fn progress_rewrites_collapse_to_final_state() {
+        assert_eq!(
+            collapse_carriage_returns("fetch\n 10%\r 55%\r100%\r\ndone\r\n"),
+            "fetch\n100%\ndone\n"
+        );
+    }
+
+    #[test]
+    fn strip_removes_colors_cursor_moves_and_hyperlinks() {
+        let raw = "\u{1b}[1;31merror\u{1b}[0m\u{1b}[K: \u{1b}]8;;https://x.test\u{7}link\u{1b}]8;;\u{1b}\\ \u{7}ok";
+        assert_eq!(strip_ansi_escapes(raw), "error: link ok");
+    }
+
+    #[test]
+    fn escape_line_keeps_colors_and_drops_other_sequences() {
+        let line = ansi_escape_line("\u{1b}[2K\r 40%\r\u{1b}[32mok\u{1b}[0m");
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "ok");
+        let ok = line.spans.iter().find(|s| s.content == "ok");
+        assert_eq!(ok.and_then(|s| s.style.fg), Some(Color::Green));
+    }
+}
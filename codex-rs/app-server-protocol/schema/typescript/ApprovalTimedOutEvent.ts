@@ -0,0 +1,27 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ApprovalTimeoutAction } from "./ApprovalTimeoutAction";
+import type { ReviewDecision } from "./ReviewDecision";
+
+export type ApprovalTimedOutEvent = { 
+/**
+ * Identifier of the exec or patch call whose approval timed out.
+ */
+call_id: string, 
+/**
+ * Turn ID that the approval request belongs to.
+ */
+turn_id: string, 
+/**
+ * How long the request waited before the timeout fired.
+ */
+timeout_seconds: bigint, 
+/**
+ * The configured action that produced `decision`.
+ */
+action: ApprovalTimeoutAction, 
+/**
+ * Decision recorded on the user's behalf.
+ */
+decision: ReviewDecision, };
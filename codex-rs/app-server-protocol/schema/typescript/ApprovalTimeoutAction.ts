@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What happens to an approval request nobody answers within
+ * `approval_timeout_seconds`.
+ */
+export type ApprovalTimeoutAction = "deny" | "allow-if-policy-safe" | "abort-turn";
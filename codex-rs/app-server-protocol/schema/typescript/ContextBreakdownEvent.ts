@@ -0,0 +1,17 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ContextComponent } from "./ContextComponent";
+
+/**
+ * Response payload for `Op::InspectContext` and `Op::SetContextExclusions`.
+ */
+export type ContextBreakdownEvent = { 
+/**
+ * One entry per component, in the order they appear in the request.
+ */
+components: Array<ContextComponent>, 
+/**
+ * Estimated tokens for the whole request, excluded components left out.
+ */
+total_tokens: bigint, model_context_window: bigint | null, };
@@ -0,0 +1,18 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ContextComponentKind } from "./ContextComponentKind";
+
+export type ContextComponent = { kind: ContextComponentKind, 
+/**
+ * Estimated tokens, using the same bytes-per-token heuristic as compaction.
+ */
+tokens: bigint, 
+/**
+ * Whether `Op::SetContextExclusions` accepts this component.
+ */
+droppable: boolean, 
+/**
+ * Whether the next turn will leave this component out.
+ */
+excluded: boolean, };
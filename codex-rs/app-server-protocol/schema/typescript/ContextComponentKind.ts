@@ -0,0 +1,8 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Parts of a model request that `/context` reports on.
+ */
+export type ContextComponentKind = "system_prompt" | "developer_instructions" | "project_docs" | "skills" | "environment_context" | "history" | "tool_schemas";
@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlannedToolCall } from "./PlannedToolCall";
+
+export type DryRunPlanEvent = { 
+/**
+ * Turn ID that this plan belongs to.
+ */
+turn_id: string, 
+/**
+ * Calls in the order the model made them.
+ */
+calls: Array<PlannedToolCall>, };
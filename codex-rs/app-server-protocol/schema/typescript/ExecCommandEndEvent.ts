@@ -1,6 +1,7 @@
 // GENERATED CODE! DO NOT MODIFY BY HAND!
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExecCommandProvenance } from "./ExecCommandProvenance";
 import type { ExecCommandSource } from "./ExecCommandSource";
 import type { ParsedCommand } from "./ParsedCommand";
 
@@ -53,7 +54,15 @@ exit_code: number,
  * The duration of the command execution.
  */
 duration: string, 
+/**
+ * Whether the command was killed for exceeding its timeout.
+ */
+timed_out: boolean, 
 /**
  * Formatted output from the command, as seen by the model.
  */
-formatted_output: string, };
+formatted_output: string, 
+/**
+ * Why the command ran and what cleared it to run.
+ */
+provenance?: ExecCommandProvenance, };
@@ -0,0 +1,8 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Why a command ran, independent of how it was executed.
+ */
+export type ExecCommandOrigin = "model" | "user_shell" | "sdd_git" | "hook";
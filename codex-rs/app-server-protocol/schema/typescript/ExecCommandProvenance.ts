@@ -0,0 +1,21 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExecCommandOrigin } from "./ExecCommandOrigin";
+import type { ReviewDecision } from "./ReviewDecision";
+
+/**
+ * Why a command ran and what cleared it to run, for audits of what the
+ * agent actually did.
+ */
+export type ExecCommandProvenance = { origin: ExecCommandOrigin, 
+/**
+ * Most specific execpolicy rule that matched the command, e.g.
+ * `prefix_rule(pattern=["git", "push"], decision="prompt")`.
+ */
+policy_rule?: string, 
+/**
+ * The user's answer when the command needed approval; `None` when it
+ * ran without asking.
+ */
+approval?: ReviewDecision, };
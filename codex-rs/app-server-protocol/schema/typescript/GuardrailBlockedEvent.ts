@@ -0,0 +1,27 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GuardrailReason } from "./GuardrailReason";
+
+export type GuardrailBlockedEvent = { 
+/**
+ * Identifier of the tool call that was refused.
+ */
+call_id: string, 
+/**
+ * Turn ID that the call belongs to.
+ */
+turn_id: string, 
+/**
+ * Paths the call would have modified that are protected.
+ */
+paths: Array<string>, 
+/**
+ * The `protected_paths` patterns those paths matched. Empty when the
+ * call tripped `edit_limits` instead.
+ */
+patterns: Array<string>, 
+/**
+ * Which guardrail refused the call.
+ */
+reason: GuardrailReason, };
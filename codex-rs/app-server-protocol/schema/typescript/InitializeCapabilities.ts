@@ -14,4 +14,10 @@ experimentalApi: boolean,
  * Exact notification method names that should be suppressed for this
  * connection (for example `codex/event/session_configured`).
  */
-optOutNotificationMethods?: Array<string> | null, };
+optOutNotificationMethods?: Array<string> | null, 
+/**
+ * Opt into approval requests that carry file change hunks and the
+ * command's argument vector, and into approving some hunks of a file
+ * change with `acceptHunks`. Without it, approvals stay accept/decline.
+ */
+richApprovals: boolean, };
@@ -0,0 +1,18 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FileChange } from "./FileChange";
+
+export type PatchProposalEvent = { 
+/**
+ * Identifier of the `apply_patch` call that was turned into a proposal.
+ */
+call_id: string, 
+/**
+ * Turn ID that this proposal belongs to.
+ */
+turn_id: string, 
+/**
+ * The changes the patch would have made.
+ */
+changes: { [key in string]?: FileChange }, };
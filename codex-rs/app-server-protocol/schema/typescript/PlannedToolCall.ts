@@ -0,0 +1,17 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One tool call held back by a dry run.
+ */
+export type PlannedToolCall = { call_id: string, tool_name: string, 
+/**
+ * What the call would do: the command line for shell tools, the raw
+ * arguments for anything else.
+ */
+summary: string, 
+/**
+ * Files an `apply_patch` call would add, update, or delete.
+ */
+files: Array<string>, };
@@ -6,4 +6,9 @@ import type { ReviewCodeLocation } from "./ReviewCodeLocation";
 /**
  * A single review finding describing an observed issue or recommendation.
  */
-export type ReviewFinding = { title: string, body: string, confidence_score: number, priority: number, code_location: ReviewCodeLocation, };
+export type ReviewFinding = { title: string, body: string, confidence_score: number, priority: number, code_location: ReviewCodeLocation, 
+/**
+ * Focus area from the repository's review rubric, when the finding
+ * belongs to one.
+ */
+category?: string, };
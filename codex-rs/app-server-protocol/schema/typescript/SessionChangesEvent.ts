@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SessionFileChange } from "./SessionFileChange";
+
+export type SessionChangesEvent = { 
+/**
+ * Files whose contents differ from what they were before this session first patched them, sorted by path.
+ */
+files: Array<SessionFileChange>, 
+/**
+ * Cumulative unified diff covering every entry in `files`.
+ */
+unified_diff: string, };
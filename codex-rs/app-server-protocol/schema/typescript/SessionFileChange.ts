@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SessionFileChange = { 
+/**
+ * Current location of the file; differs from the original after a move.
+ */
+path: string, added_lines: number, removed_lines: number, };
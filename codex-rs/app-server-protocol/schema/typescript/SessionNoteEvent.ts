@@ -0,0 +1,12 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A note saved with `Op::AddNote`.
+ */
+export type SessionNoteEvent = { text: string, 
+/**
+ * Whether the note was also added to the conversation for the model.
+ */
+in_context: boolean, };
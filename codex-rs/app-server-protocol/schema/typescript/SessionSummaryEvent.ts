@@ -0,0 +1,12 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Response payload for `Op::SummarizeSession`.
+ */
+export type SessionSummaryEvent = { 
+/**
+ * What the session accomplished, one item per bullet. Empty when no summary could be generated.
+ */
+bullets: Array<string>, };
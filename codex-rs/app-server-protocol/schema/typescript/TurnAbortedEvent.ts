@@ -3,4 +3,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { TurnAbortReason } from "./TurnAbortReason";
 
-export type TurnAbortedEvent = { reason: TurnAbortReason, };
+export type TurnAbortedEvent = { reason: TurnAbortReason, 
+/**
+ * Approximate output tokens the model had streamed for the request the
+ * abort cut off. `None` when no model request was in flight.
+ */
+cut_off_output_tokens?: bigint, };
@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebCitation } from "./WebCitation";
+
+export type TurnSourcesEvent = { 
+/**
+ * Cited pages in the order they were first seen, one entry per URL.
+ */
+citations: Array<WebCitation>, };
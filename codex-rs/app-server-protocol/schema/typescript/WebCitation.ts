@@ -0,0 +1,12 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A source the model was given, shown under the web search history entry.
+ */
+export type WebCitation = { url: string, title?: string, 
+/**
+ * Search snippet or the start of the fetched page.
+ */
+snippet?: string, };
@@ -1,6 +1,7 @@
 // GENERATED CODE! DO NOT MODIFY BY HAND!
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebCitation } from "./WebCitation";
 import type { WebSearchAction } from "./WebSearchAction";
 
-export type WebSearchEndEvent = { call_id: string, query: string, action: WebSearchAction, };
+export type WebSearchEndEvent = { call_id: string, query: string, action: WebSearchAction, citations: Array<WebCitation>, };
@@ -1,6 +1,11 @@
 // GENERATED CODE! DO NOT MODIFY BY HAND!
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebCitation } from "./WebCitation";
 import type { WebSearchAction } from "./WebSearchAction";
 
-export type WebSearchItem = { id: string, query: string, action: WebSearchAction, };
+export type WebSearchItem = { id: string, query: string, action: WebSearchAction, 
+/**
+ * Pages returned by a local search backend or read by `fetch_url`.
+ */
+citations: Array<WebCitation>, };
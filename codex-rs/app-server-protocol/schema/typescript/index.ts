@@ -17,6 +17,8 @@ export type { AgentStatus } from "./AgentStatus";
 export type { ApplyPatchApprovalParams } from "./ApplyPatchApprovalParams";
 export type { ApplyPatchApprovalRequestEvent } from "./ApplyPatchApprovalRequestEvent";
 export type { ApplyPatchApprovalResponse } from "./ApplyPatchApprovalResponse";
+export type { ApprovalTimedOutEvent } from "./ApprovalTimedOutEvent";
+export type { ApprovalTimeoutAction } from "./ApprovalTimeoutAction";
 export type { ArchiveConversationParams } from "./ArchiveConversationParams";
 export type { ArchiveConversationResponse } from "./ArchiveConversationResponse";
 export type { AskForApproval } from "./AskForApproval";
@@ -44,13 +46,17 @@ export type { CollabWaitingEndEvent } from "./CollabWaitingEndEvent";
 export type { CollaborationMode } from "./CollaborationMode";
 export type { CollaborationModeMask } from "./CollaborationModeMask";
 export type { ContentItem } from "./ContentItem";
+export type { ContextBreakdownEvent } from "./ContextBreakdownEvent";
 export type { ContextCompactedEvent } from "./ContextCompactedEvent";
 export type { ContextCompactionItem } from "./ContextCompactionItem";
+export type { ContextComponent } from "./ContextComponent";
+export type { ContextComponentKind } from "./ContextComponentKind";
 export type { ConversationGitInfo } from "./ConversationGitInfo";
 export type { ConversationSummary } from "./ConversationSummary";
 export type { CreditsSnapshot } from "./CreditsSnapshot";
 export type { CustomPrompt } from "./CustomPrompt";
 export type { DeprecationNoticeEvent } from "./DeprecationNoticeEvent";
+export type { DryRunPlanEvent } from "./DryRunPlanEvent";
 export type { DynamicToolCallRequest } from "./DynamicToolCallRequest";
 export type { ElicitationRequestEvent } from "./ElicitationRequestEvent";
 export type { ErrorEvent } from "./ErrorEvent";
@@ -60,7 +66,9 @@ export type { ExecCommandApprovalParams } from "./ExecCommandApprovalParams";
 export type { ExecCommandApprovalResponse } from "./ExecCommandApprovalResponse";
 export type { ExecCommandBeginEvent } from "./ExecCommandBeginEvent";
 export type { ExecCommandEndEvent } from "./ExecCommandEndEvent";
+export type { ExecCommandOrigin } from "./ExecCommandOrigin";
 export type { ExecCommandOutputDeltaEvent } from "./ExecCommandOutputDeltaEvent";
+export type { ExecCommandProvenance } from "./ExecCommandProvenance";
 export type { ExecCommandSource } from "./ExecCommandSource";
 export type { ExecOneOffCommandParams } from "./ExecOneOffCommandParams";
 export type { ExecOneOffCommandResponse } from "./ExecOneOffCommandResponse";
@@ -88,6 +96,8 @@ export type { GhostCommit } from "./GhostCommit";
 export type { GitDiffToRemoteParams } from "./GitDiffToRemoteParams";
 export type { GitDiffToRemoteResponse } from "./GitDiffToRemoteResponse";
 export type { GitSha } from "./GitSha";
+export type { GuardrailBlockedEvent } from "./GuardrailBlockedEvent";
+export type { GuardrailReason } from "./GuardrailReason";
 export type { HistoryEntry } from "./HistoryEntry";
 export type { InitializeCapabilities } from "./InitializeCapabilities";
 export type { InitializeParams } from "./InitializeParams";
@@ -128,11 +138,13 @@ export type { NewConversationResponse } from "./NewConversationResponse";
 export type { ParsedCommand } from "./ParsedCommand";
 export type { PatchApplyBeginEvent } from "./PatchApplyBeginEvent";
 export type { PatchApplyEndEvent } from "./PatchApplyEndEvent";
+export type { PatchProposalEvent } from "./PatchProposalEvent";
 export type { Personality } from "./Personality";
 export type { PlanDeltaEvent } from "./PlanDeltaEvent";
 export type { PlanItem } from "./PlanItem";
 export type { PlanItemArg } from "./PlanItemArg";
 export type { PlanType } from "./PlanType";
+export type { PlannedToolCall } from "./PlannedToolCall";
 export type { Profile } from "./Profile";
 export type { RateLimitSnapshot } from "./RateLimitSnapshot";
 export type { RateLimitWindow } from "./RateLimitWindow";
@@ -173,10 +185,14 @@ export type { SendUserTurnParams } from "./SendUserTurnParams";
 export type { SendUserTurnResponse } from "./SendUserTurnResponse";
 export type { ServerNotification } from "./ServerNotification";
 export type { ServerRequest } from "./ServerRequest";
+export type { SessionChangesEvent } from "./SessionChangesEvent";
 export type { SessionConfiguredEvent } from "./SessionConfiguredEvent";
 export type { SessionConfiguredNotification } from "./SessionConfiguredNotification";
+export type { SessionFileChange } from "./SessionFileChange";
 export type { SessionNetworkProxyRuntime } from "./SessionNetworkProxyRuntime";
+export type { SessionNoteEvent } from "./SessionNoteEvent";
 export type { SessionSource } from "./SessionSource";
+export type { SessionSummaryEvent } from "./SessionSummaryEvent";
 export type { SetDefaultModelParams } from "./SetDefaultModelParams";
 export type { SetDefaultModelResponse } from "./SetDefaultModelResponse";
 export type { Settings } from "./Settings";
@@ -205,6 +221,7 @@ export type { TurnAbortedEvent } from "./TurnAbortedEvent";
 export type { TurnCompleteEvent } from "./TurnCompleteEvent";
 export type { TurnDiffEvent } from "./TurnDiffEvent";
 export type { TurnItem } from "./TurnItem";
+export type { TurnSourcesEvent } from "./TurnSourcesEvent";
 export type { TurnStartedEvent } from "./TurnStartedEvent";
 export type { UndoCompletedEvent } from "./UndoCompletedEvent";
 export type { UndoStartedEvent } from "./UndoStartedEvent";
@@ -217,6 +234,7 @@ export type { UserSavedConfig } from "./UserSavedConfig";
 export type { Verbosity } from "./Verbosity";
 export type { ViewImageToolCallEvent } from "./ViewImageToolCallEvent";
 export type { WarningEvent } from "./WarningEvent";
+export type { WebCitation } from "./WebCitation";
 export type { WebSearchAction } from "./WebSearchAction";
 export type { WebSearchBeginEvent } from "./WebSearchBeginEvent";
 export type { WebSearchEndEvent } from "./WebSearchEndEvent";
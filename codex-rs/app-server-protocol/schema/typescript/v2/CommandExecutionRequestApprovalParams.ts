@@ -24,4 +24,8 @@ commandActions?: Array<CommandAction> | null,
 /**
  * Optional proposed execpolicy amendment to allow similar commands without prompting.
  */
-proposedExecpolicyAmendment?: ExecPolicyAmendment | null, };
+proposedExecpolicyAmendment?: ExecPolicyAmendment | null, 
+/**
+ * The command as an argument vector. Sent only with the `richApprovals` capability.
+ */
+argv?: Array<string> | null, };
@@ -2,4 +2,4 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type FileChangeApprovalDecision = "accept" | "acceptForSession" | "decline" | "cancel";
+export type FileChangeApprovalDecision = "accept" | "acceptForSession" | { "acceptHunks": { hunk_ids: Array<string>, } } | "decline" | "cancel";
@@ -0,0 +1,13 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One independently approvable piece of a file change: a whole added or deleted file, or one
+ * `@@` section of an updated file's diff.
+ */
+export type FileChangeHunk = { 
+/**
+ * Stable id of the hunk, `<path>#<n>`.
+ */
+id: string, path: string, diff: string, };
@@ -1,6 +1,7 @@
 // GENERATED CODE! DO NOT MODIFY BY HAND!
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FileChangeHunk } from "./FileChangeHunk";
 
 export type FileChangeRequestApprovalParams = { threadId: string, turnId: string, itemId: string, 
 /**
@@ -11,4 +12,9 @@ reason?: string | null,
  * [UNSTABLE] When set, the agent is asking the user to allow writes under this root
  * for the remainder of the session (unclear if this is honored today).
  */
-grantRoot?: string | null, };
+grantRoot?: string | null, 
+/**
+ * The changes split into hunks that can be approved one by one with `acceptHunks`.
+ * Sent only with the `richApprovals` capability.
+ */
+hunks?: Array<FileChangeHunk> | null, };
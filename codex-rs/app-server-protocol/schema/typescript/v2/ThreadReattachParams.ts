@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ThreadReattachParams = { threadId: string, 
+/**
+ * The last turn the client saw before it disconnected. Turns after it are
+ * returned in `missedTurns`; when omitted or unknown, every turn is.
+ */
+lastTurnId?: string | null, };
@@ -0,0 +1,17 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Thread } from "./Thread";
+import type { Turn } from "./Turn";
+
+export type ThreadReattachResponse = { thread: Thread, 
+/**
+ * Turns recorded in the rollout after `lastTurnId`, oldest first. The
+ * last one may still be in progress.
+ */
+missedTurns: Array<Turn>, 
+/**
+ * Whether the thread was still loaded in this server. When false it was
+ * resumed from its rollout.
+ */
+wasLoaded: boolean, };
@@ -235,6 +235,10 @@ client_request_definitions! {
         params: v2::ThreadReadParams,
         response: v2::ThreadReadResponse,
     },
+    ThreadReattach => "thread/reattach" {
+        params: v2::ThreadReattachParams,
+        response: v2::ThreadReattachResponse,
+    },
     SkillsList => "skills/list" {
         params: v2::SkillsListParams,
         response: v2::SkillsListResponse,
@@ -822,6 +826,7 @@ mod tests {
                         "codex/event/session_configured".to_string(),
                         "item/agentMessage/delta".to_string(),
                     ]),
+                    rich_approvals: true,
                 }),
             },
         };
@@ -841,7 +846,8 @@ mod tests {
                         "optOutNotificationMethods": [
                             "codex/event/session_configured",
                             "item/agentMessage/delta"
-                        ]
+                        ],
+                        "richApprovals": true
                     }
                 }
             }),
@@ -866,7 +872,8 @@ mod tests {
                     "optOutNotificationMethods": [
                         "codex/event/session_configured",
                         "item/agentMessage/delta"
-                    ]
+                    ],
+                    "richApprovals": true
                 }
             }
         }))?;
@@ -887,6 +894,7 @@ mod tests {
                             "codex/event/session_configured".to_string(),
                             "item/agentMessage/delta".to_string(),
                         ]),
+                        rich_approvals: true,
                     }),
                 },
             }
@@ -56,6 +56,11 @@ pub struct InitializeCapabilities {
     /// connection (for example `codex/event/session_configured`).
     #[ts(optional = nullable)]
     pub opt_out_notification_methods: Option<Vec<String>>,
+    /// Opt into approval requests that carry file change hunks and the
+    /// command's argument vector, and into approving some hunks of a file
+    /// change with `acceptHunks`. Without it, approvals stay accept/decline.
+    #[serde(default)]
+    pub rich_approvals: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
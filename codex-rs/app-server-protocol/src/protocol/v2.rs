@@ -632,6 +632,9 @@ pub enum FileChangeApprovalDecision {
     Accept,
     /// User approved the file changes and future changes to the same files should run without prompting.
     AcceptForSession,
+    /// User approved only the listed hunks of the file changes; the rest are not applied.
+    /// Requires the `richApprovals` capability.
+    AcceptHunks { hunk_ids: Vec<String> },
     /// User denied the file changes. The agent will continue the turn.
     Decline,
     /// User denied the file changes. The turn will also be immediately interrupted.
@@ -1696,6 +1699,30 @@ pub struct ThreadReadResponse {
     pub thread: Thread,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadReattachParams {
+    pub thread_id: String,
+    /// The last turn the client saw before it disconnected. Turns after it are
+    /// returned in `missedTurns`; when omitted or unknown, every turn is.
+    #[ts(optional = nullable)]
+    pub last_turn_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadReattachResponse {
+    pub thread: Thread,
+    /// Turns recorded in the rollout after `lastTurnId`, oldest first. The
+    /// last one may still be in progress.
+    pub missed_turns: Vec<Turn>,
+    /// Whether the thread was still loaded in this server. When false it was
+    /// resumed from its rollout.
+    pub was_loaded: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -2172,6 +2199,9 @@ pub enum ReviewTarget {
     /// Review the working tree: staged, unstaged, and untracked files.
     UncommittedChanges,
 
+    /// Review only the changes staged in the index.
+    StagedChanges,
+
     /// Review changes between the current branch and the given base branch.
     #[serde(rename_all = "camelCase")]
     #[ts(rename_all = "camelCase")]
@@ -2186,6 +2216,12 @@ pub enum ReviewTarget {
         title: Option<String>,
     },
 
+    /// Review the current contents of the files matching the given globs,
+    /// relative to the working directory.
+    #[serde(rename_all = "camelCase")]
+    #[ts(rename_all = "camelCase")]
+    Files { patterns: Vec<String> },
+
     /// Arbitrary instructions, equivalent to the old free-form prompt.
     #[serde(rename_all = "camelCase")]
     #[ts(rename_all = "camelCase")]
@@ -2967,6 +3003,10 @@ pub struct CommandExecutionRequestApprovalParams {
     /// Optional proposed execpolicy amendment to allow similar commands without prompting.
     #[ts(optional = nullable)]
     pub proposed_execpolicy_amendment: Option<ExecPolicyAmendment>,
+    /// The command as an argument vector. Sent only with the `richApprovals` capability.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional = nullable)]
+    pub argv: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
@@ -2990,6 +3030,23 @@ pub struct FileChangeRequestApprovalParams {
     /// for the remainder of the session (unclear if this is honored today).
     #[ts(optional = nullable)]
     pub grant_root: Option<PathBuf>,
+    /// The changes split into hunks that can be approved one by one with `acceptHunks`.
+    /// Sent only with the `richApprovals` capability.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional = nullable)]
+    pub hunks: Option<Vec<FileChangeHunk>>,
+}
+
+/// One independently approvable piece of a file change: a whole added or deleted file, or one
+/// `@@` section of an updated file's diff.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct FileChangeHunk {
+    /// Stable id of the hunk, `<path>#<n>`.
+    pub id: String,
+    pub path: String,
+    pub diff: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
@@ -3347,6 +3404,7 @@ mod tests {
                 query: Some("docs".to_string()),
                 queries: None,
             },
+            citations: Vec::new(),
         });
 
         assert_eq!(
@@ -512,6 +512,7 @@ impl CodexClient {
                 capabilities: Some(InitializeCapabilities {
                     experimental_api: true,
                     opt_out_notification_methods: None,
+                    rich_approvals: false,
                 }),
             },
         };
@@ -940,6 +941,7 @@ impl CodexClient {
             cwd,
             command_actions,
             proposed_execpolicy_amendment,
+            argv: _,
         } = params;
 
         println!(
@@ -982,6 +984,7 @@ impl CodexClient {
             item_id,
             reason,
             grant_root,
+            hunks: _,
         } = params;
 
         println!(
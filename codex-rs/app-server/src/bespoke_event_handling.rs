@@ -31,6 +31,7 @@ use codex_app_server_protocol::ExecCommandApprovalParams;
 use codex_app_server_protocol::ExecCommandApprovalResponse;
 use codex_app_server_protocol::ExecPolicyAmendment as V2ExecPolicyAmendment;
 use codex_app_server_protocol::FileChangeApprovalDecision;
+use codex_app_server_protocol::FileChangeHunk;
 use codex_app_server_protocol::FileChangeOutputDeltaNotification;
 use codex_app_server_protocol::FileChangeRequestApprovalParams;
 use codex_app_server_protocol::FileChangeRequestApprovalResponse;
@@ -72,6 +73,7 @@ use codex_app_server_protocol::TurnStatus;
 use codex_app_server_protocol::build_turns_from_event_msgs;
 use codex_core::CodexThread;
 use codex_core::parse_command::shlex_join;
+use codex_core::patch_hunks::split_hunks;
 use codex_core::protocol::ApplyPatchApprovalRequestEvent;
 use codex_core::protocol::CodexErrorInfo as CoreCodexErrorInfo;
 use codex_core::protocol::Event;
@@ -181,12 +183,16 @@ pub(crate) async fn apply_bespoke_event_handling(
                         .await;
                 }
 
+                let hunks = outgoing
+                    .rich_approvals()
+                    .then(|| convert_patch_hunks(&changes));
                 let params = FileChangeRequestApprovalParams {
                     thread_id: conversation_id.to_string(),
                     turn_id: turn_id.clone(),
                     item_id: item_id.clone(),
                     reason,
                     grant_root,
+                    hunks,
                 };
                 let rx = outgoing
                     .send_request(ServerRequestPayload::FileChangeRequestApproval(params))
@@ -253,6 +259,7 @@ pub(crate) async fn apply_bespoke_event_handling(
                     cwd: Some(cwd.clone()),
                     command_actions: Some(command_actions.clone()),
                     proposed_execpolicy_amendment: proposed_execpolicy_amendment_v2,
+                    argv: outgoing.rich_approvals().then(|| command.clone()),
                 };
                 let rx = outgoing
                     .send_request(ServerRequestPayload::CommandExecutionRequestApproval(
@@ -1630,12 +1637,26 @@ fn format_file_change_diff(change: &CoreFileChange) -> String {
     }
 }
 
+fn convert_patch_hunks(changes: &HashMap<PathBuf, CoreFileChange>) -> Vec<FileChangeHunk> {
+    split_hunks(changes)
+        .into_iter()
+        .map(|hunk| FileChangeHunk {
+            id: hunk.id,
+            path: hunk.path.to_string_lossy().into_owned(),
+            diff: hunk.diff,
+        })
+        .collect()
+}
+
 fn map_file_change_approval_decision(
     decision: FileChangeApprovalDecision,
 ) -> (ReviewDecision, Option<PatchApplyStatus>) {
     match decision {
         FileChangeApprovalDecision::Accept => (ReviewDecision::Approved, None),
         FileChangeApprovalDecision::AcceptForSession => (ReviewDecision::ApprovedForSession, None),
+        FileChangeApprovalDecision::AcceptHunks { hunk_ids } => {
+            (ReviewDecision::ApprovedHunks { hunk_ids }, None)
+        }
         FileChangeApprovalDecision::Decline => {
             (ReviewDecision::Denied, Some(PatchApplyStatus::Declined))
         }
@@ -1933,6 +1954,17 @@ mod tests {
         assert_eq!(completion_status, None);
     }
 
+    #[test]
+    fn file_change_accept_hunks_maps_to_approved_hunks() {
+        let hunk_ids = vec!["/repo/src/lib.rs#2".to_string()];
+        let (decision, completion_status) =
+            map_file_change_approval_decision(FileChangeApprovalDecision::AcceptHunks {
+                hunk_ids: hunk_ids.clone(),
+            });
+        assert_eq!(decision, ReviewDecision::ApprovedHunks { hunk_ids });
+        assert_eq!(completion_status, None);
+    }
+
     #[test]
     fn collab_resume_begin_maps_to_item_started_resume_agent() {
         let event = CollabResumeBeginEvent {
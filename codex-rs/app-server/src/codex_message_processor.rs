@@ -123,6 +123,8 @@ use codex_app_server_protocol::ThreadLoadedListParams;
 use codex_app_server_protocol::ThreadLoadedListResponse;
 use codex_app_server_protocol::ThreadReadParams;
 use codex_app_server_protocol::ThreadReadResponse;
+use codex_app_server_protocol::ThreadReattachParams;
+use codex_app_server_protocol::ThreadReattachResponse;
 use codex_app_server_protocol::ThreadResumeParams;
 use codex_app_server_protocol::ThreadResumeResponse;
 use codex_app_server_protocol::ThreadRollbackParams;
@@ -273,6 +275,8 @@ const THREAD_LIST_MAX_LIMIT: usize = 100;
 // Duration before a ChatGPT login attempt is abandoned.
 const LOGIN_CHATGPT_TIMEOUT: Duration = Duration::from_secs(10 * 60);
 const APP_LIST_LOAD_TIMEOUT: Duration = Duration::from_secs(90);
+// How often a detached server checks whether its turns have finished.
+const DETACHED_POLL_INTERVAL: Duration = Duration::from_millis(500);
 struct ActiveLogin {
     shutdown_handle: ShutdownHandle,
     login_id: Uuid,
@@ -442,6 +446,7 @@ impl CodexMessageProcessor {
 
         let cleaned_target = match target {
             ApiReviewTarget::UncommittedChanges => ApiReviewTarget::UncommittedChanges,
+            ApiReviewTarget::StagedChanges => ApiReviewTarget::StagedChanges,
             ApiReviewTarget::BaseBranch { branch } => {
                 let branch = branch.trim().to_string();
                 if branch.is_empty() {
@@ -459,6 +464,17 @@ impl CodexMessageProcessor {
                     .filter(|t| !t.is_empty());
                 ApiReviewTarget::Commit { sha, title }
             }
+            ApiReviewTarget::Files { patterns } => {
+                let patterns: Vec<String> = patterns
+                    .into_iter()
+                    .map(|pattern| pattern.trim().to_string())
+                    .filter(|pattern| !pattern.is_empty())
+                    .collect();
+                if patterns.is_empty() {
+                    return Err(invalid_request("patterns must not be empty".to_string()));
+                }
+                ApiReviewTarget::Files { patterns }
+            }
             ApiReviewTarget::Custom { instructions } => {
                 let trimmed = instructions.trim().to_string();
                 if trimmed.is_empty() {
@@ -474,8 +490,10 @@ impl CodexMessageProcessor {
 
         let core_target = match cleaned_target {
             ApiReviewTarget::UncommittedChanges => CoreReviewTarget::UncommittedChanges,
+            ApiReviewTarget::StagedChanges => CoreReviewTarget::StagedChanges,
             ApiReviewTarget::BaseBranch { branch } => CoreReviewTarget::BaseBranch { branch },
             ApiReviewTarget::Commit { sha, title } => CoreReviewTarget::Commit { sha, title },
+            ApiReviewTarget::Files { patterns } => CoreReviewTarget::Files { patterns },
             ApiReviewTarget::Custom { instructions } => CoreReviewTarget::Custom { instructions },
         };
 
@@ -531,6 +549,9 @@ impl CodexMessageProcessor {
             ClientRequest::ThreadRead { request_id, params } => {
                 self.thread_read(request_id, params).await;
             }
+            ClientRequest::ThreadReattach { request_id, params } => {
+                self.thread_reattach(request_id, params).await;
+            }
             ClientRequest::SkillsList { request_id, params } => {
                 self.skills_list(request_id, params).await;
             }
@@ -1601,6 +1622,7 @@ impl CodexMessageProcessor {
         let outgoing = self.outgoing.clone();
         let req_id = request_id;
         let sandbox_cwd = self.config.cwd.clone();
+        let protected_paths = self.config.protected_paths.clone();
         let started_network_proxy_for_task = started_network_proxy;
         let use_linux_sandbox_bwrap = self.config.features.enabled(Feature::UseLinuxSandboxBwrap);
 
@@ -1610,6 +1632,7 @@ impl CodexMessageProcessor {
                 exec_params,
                 &effective_policy,
                 sandbox_cwd.as_path(),
+                &protected_paths,
                 &codex_linux_sandbox_exe,
                 use_linux_sandbox_bwrap,
                 None,
@@ -2479,6 +2502,185 @@ impl CodexMessageProcessor {
         self.outgoing.send_response(request_id, response).await;
     }
 
+    async fn thread_reattach(&mut self, request_id: RequestId, params: ThreadReattachParams) {
+        let ThreadReattachParams {
+            thread_id,
+            last_turn_id,
+        } = params;
+
+        let thread_uuid = match ThreadId::from_string(&thread_id) {
+            Ok(id) => id,
+            Err(err) => {
+                self.send_invalid_request_error(request_id, format!("invalid thread id: {err}"))
+                    .await;
+                return;
+            }
+        };
+
+        let (thread, rollout_path, was_loaded) =
+            if let Ok(loaded) = self.thread_manager.get_thread(thread_uuid).await {
+                self.try_attach_thread_listener(thread_uuid).await;
+                let config_snapshot = loaded.config_snapshot().await;
+                let rollout_path = loaded.rollout_path();
+                let thread =
+                    build_thread_from_snapshot(thread_uuid, &config_snapshot, rollout_path.clone());
+                (thread, rollout_path, true)
+            } else {
+                match self.resume_detached_thread(thread_uuid).await {
+                    Ok((thread, rollout_path)) => (thread, Some(rollout_path), false),
+                    Err(error) => {
+                        self.outgoing.send_error(request_id, error).await;
+                        return;
+                    }
+                }
+            };
+
+        // Ephemeral threads and threads without a first user message have no
+        // rollout yet, so there is nothing to replay.
+        let turns = match rollout_path.as_ref() {
+            Some(path) => match read_event_msgs_from_rollout(path).await {
+                Ok(events) => build_turns_from_event_msgs(&events),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+                Err(err) => {
+                    self.send_internal_error(
+                        request_id,
+                        format!(
+                            "failed to load rollout `{}` for thread {thread_uuid}: {err}",
+                            path.display()
+                        ),
+                    )
+                    .await;
+                    return;
+                }
+            },
+            None => Vec::new(),
+        };
+
+        let response = ThreadReattachResponse {
+            thread,
+            missed_turns: turns_after(turns, last_turn_id.as_deref()),
+            was_loaded,
+        };
+        self.outgoing.send_response(request_id, response).await;
+    }
+
+    /// Load a thread that is no longer running in this server from its
+    /// rollout, with the config it was started in, and subscribe to it.
+    async fn resume_detached_thread(
+        &mut self,
+        thread_id: ThreadId,
+    ) -> Result<(Thread, PathBuf), JSONRPCErrorError> {
+        let invalid_request = |message: String| JSONRPCErrorError {
+            code: INVALID_REQUEST_ERROR_CODE,
+            message,
+            data: None,
+        };
+        let internal_error = |message: String| JSONRPCErrorError {
+            code: INTERNAL_ERROR_CODE,
+            message,
+            data: None,
+        };
+
+        let path = match find_thread_path_by_id_str(&self.config.codex_home, &thread_id.to_string())
+            .await
+        {
+            Ok(Some(path)) => path,
+            Ok(None) => {
+                return Err(invalid_request(format!(
+                    "no rollout found for thread id {thread_id}"
+                )));
+            }
+            Err(err) => {
+                return Err(invalid_request(format!(
+                    "failed to locate thread id {thread_id}: {err}"
+                )));
+            }
+        };
+        let history = RolloutRecorder::get_rollout_history(&path)
+            .await
+            .map_err(|err| {
+                invalid_request(format!(
+                    "failed to load rollout `{}`: {err}",
+                    path.display()
+                ))
+            })?;
+
+        let history_cwd = history.session_cwd();
+        let typesafe_overrides =
+            self.build_thread_config_overrides(None, None, None, None, None, None, None, None);
+        let cloud_requirements = self.current_cloud_requirements();
+        let config = derive_config_for_cwd(
+            &self.cli_overrides,
+            None,
+            typesafe_overrides,
+            history_cwd,
+            &cloud_requirements,
+        )
+        .await
+        .map_err(|err| invalid_request(format!("error deriving config: {err}")))?;
+        let fallback_model_provider = config.model_provider_id.clone();
+
+        let NewThread {
+            thread_id,
+            session_configured,
+            ..
+        } = self
+            .thread_manager
+            .resume_thread_with_history(config, history, self.auth_manager.clone())
+            .await
+            .map_err(|err| internal_error(format!("error resuming thread: {err}")))?;
+        let Some(rollout_path) = session_configured.rollout_path else {
+            return Err(internal_error(format!(
+                "rollout path missing for thread {thread_id}"
+            )));
+        };
+        if let Err(err) = self
+            .attach_conversation_listener(thread_id, false, ApiVersion::V2)
+            .await
+        {
+            tracing::warn!(
+                "failed to attach listener for thread {}: {}",
+                thread_id,
+                err.message
+            );
+        }
+
+        let summary = read_summary_from_rollout(&rollout_path, &fallback_model_provider)
+            .await
+            .map_err(|err| {
+                internal_error(format!(
+                    "failed to load rollout `{}` for thread {thread_id}: {err}",
+                    rollout_path.display()
+                ))
+            })?;
+        Ok((summary_to_thread(summary), rollout_path))
+    }
+
+    /// After the client disconnects, keep serving loaded threads until none
+    /// has a turn running or `grace` elapses, so a client that reconnects can
+    /// pick up the finished turns with `thread/reattach`.
+    pub(crate) async fn wait_for_running_turns(&self, grace: Duration) {
+        let deadline = tokio::time::Instant::now() + grace;
+        loop {
+            let mut running = 0;
+            for thread_id in self.thread_manager.list_thread_ids().await {
+                if let Ok(thread) = self.thread_manager.get_thread(thread_id).await
+                    && matches!(thread.agent_status().await, AgentStatus::Running)
+                {
+                    running += 1;
+                }
+            }
+            if running == 0 {
+                return;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                warn!("detach grace period elapsed with {running} turn(s) still running");
+                return;
+            }
+            tokio::time::sleep(DETACHED_POLL_INTERVAL).await;
+        }
+    }
+
     pub(crate) fn thread_created_receiver(&self) -> broadcast::Receiver<ThreadId> {
         self.thread_manager.subscribe_thread_created()
     }
@@ -3236,7 +3438,8 @@ impl CodexMessageProcessor {
         let data = FEATURES
             .iter()
             .map(|spec| {
-                let (stage, display_name, description, announcement) = match spec.stage {
+                let spec_stage = config.features.stage(spec.id);
+                let (stage, display_name, description, announcement) = match spec_stage {
                     Stage::Experimental {
                         name,
                         menu_description,
@@ -4705,6 +4908,9 @@ impl CodexMessageProcessor {
                     spec_parallel_priority: None,
 
                     spec_sdd_planning: None,
+                    explore_mode: None,
+                    persona: None,
+                    scope: None,
                 })
                 .await;
         }
@@ -5881,6 +6087,18 @@ async fn read_updated_at(path: &Path, created_at: Option<&str>) -> Option<String
     updated_at.or_else(|| created_at.map(str::to_string))
 }
 
+/// The turns after `last_turn_id`, or all of them when it is absent or no
+/// longer in the history.
+fn turns_after(turns: Vec<Turn>, last_turn_id: Option<&str>) -> Vec<Turn> {
+    let Some(last_turn_id) = last_turn_id else {
+        return turns;
+    };
+    match turns.iter().position(|turn| turn.id == last_turn_id) {
+        Some(index) => turns.into_iter().skip(index + 1).collect(),
+        None => turns,
+    }
+}
+
 fn build_thread_from_snapshot(
     thread_id: ThreadId,
     config_snapshot: &ThreadConfigSnapshot,
@@ -5970,6 +6188,29 @@ mod tests {
         validate_dynamic_tools(&tools, &HashSet::new()).expect("valid schema");
     }
 
+    #[test]
+    fn turns_after_skips_turns_the_client_already_saw() {
+        let turn = |id: &str| Turn {
+            id: id.to_string(),
+            items: Vec::new(),
+            status: TurnStatus::Completed,
+            error: None,
+        };
+        let turns = vec![turn("a"), turn("b"), turn("c")];
+        let ids = |turns: Vec<Turn>| turns.into_iter().map(|turn| turn.id).collect::<Vec<_>>();
+
+        assert_eq!(ids(turns_after(turns.clone(), Some("a"))), vec!["b", "c"]);
+        assert_eq!(
+            ids(turns_after(turns.clone(), Some("c"))),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            ids(turns_after(turns.clone(), Some("gone"))),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(ids(turns_after(turns, None)), vec!["a", "b", "c"]);
+    }
+
     #[test]
     fn extract_conversation_summary_prefers_plain_user_messages() -> Result<()> {
         let conversation_id = ThreadId::from_string("3f941c35-29b3-493b-b0a4-e25800d9aeb0")?;
@@ -11,6 +11,7 @@ use codex_core::config_loader::LoaderOverrides;
 use std::io::ErrorKind;
 use std::io::Result as IoResult;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use crate::message_processor::MessageProcessor;
 use crate::message_processor::MessageProcessorArgs;
@@ -172,6 +173,7 @@ pub async fn run_main(
     cli_config_overrides: CliConfigOverrides,
     loader_overrides: LoaderOverrides,
     default_analytics_enabled: bool,
+    detach_grace: Duration,
 ) -> IoResult<()> {
     // Set up channels.
     let (incoming_tx, mut incoming_rx) = mpsc::channel::<JSONRPCMessage>(CHANNEL_CAPACITY);
@@ -376,6 +378,10 @@ pub async fn run_main(
                 }
             }
 
+            // Stdin closed: the client is gone. Turns still running get a
+            // chance to finish so a reconnecting client can reattach to them.
+            processor.client_disconnected(detach_grace).await;
+
             info!("processor task exited (channel closed)");
         }
     });
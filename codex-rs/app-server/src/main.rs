@@ -3,6 +3,7 @@ use codex_arg0::arg0_dispatch_or_else;
 use codex_common::CliConfigOverrides;
 use codex_core::config_loader::LoaderOverrides;
 use std::path::PathBuf;
+use std::time::Duration;
 
 // Debug-only test hook: lets integration tests point the server at a temporary
 // managed config file without writing to /etc.
@@ -21,6 +22,7 @@ fn main() -> anyhow::Result<()> {
             CliConfigOverrides::default(),
             loader_overrides,
             false,
+            Duration::ZERO,
         )
         .await?;
         Ok(())
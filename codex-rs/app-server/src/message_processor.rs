@@ -228,21 +228,23 @@ impl MessageProcessor {
                     self.outgoing.send_error(request_id, error).await;
                     return;
                 } else {
-                    let (experimental_api_enabled, opt_out_notification_methods) =
+                    let (experimental_api_enabled, opt_out_notification_methods, rich_approvals) =
                         match params.capabilities {
                             Some(capabilities) => (
                                 capabilities.experimental_api,
                                 capabilities
                                     .opt_out_notification_methods
                                     .unwrap_or_default(),
+                                capabilities.rich_approvals,
                             ),
-                            None => (false, Vec::new()),
+                            None => (false, Vec::new(), false),
                         };
                     self.experimental_api_enabled
                         .store(experimental_api_enabled, Ordering::Relaxed);
                     self.outgoing
                         .set_opted_out_notification_methods(opt_out_notification_methods)
                         .await;
+                    self.outgoing.set_rich_approvals(rich_approvals);
                     let ClientInfo {
                         name,
                         title: _title,
@@ -359,6 +361,19 @@ impl MessageProcessor {
             .await;
     }
 
+    /// Called once the client has disconnected. With a non-zero `grace`,
+    /// outstanding client requests fail and running turns are given up to
+    /// `grace` to finish so their results land in the rollout.
+    pub(crate) async fn client_disconnected(&self, grace: Duration) {
+        if grace.is_zero() {
+            return;
+        }
+        self.outgoing.detach().await;
+        self.codex_message_processor
+            .wait_for_running_turns(grace)
+            .await;
+    }
+
     /// Handle a standalone JSON-RPC response originating from the peer.
     pub(crate) async fn process_response(&mut self, response: JSONRPCResponse) {
         tracing::info!("<- response: {:?}", response);
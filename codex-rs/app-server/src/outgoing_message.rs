@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
 
@@ -26,6 +27,11 @@ pub(crate) struct OutgoingMessageSender {
     sender: mpsc::Sender<OutgoingMessage>,
     request_id_to_callback: Mutex<HashMap<RequestId, oneshot::Sender<Result>>>,
     opted_out_notification_methods: Mutex<HashSet<String>>,
+    /// Whether the client declared the `richApprovals` capability.
+    rich_approvals: AtomicBool,
+    /// Set once the client has disconnected; requests fail immediately
+    /// instead of waiting for a response that will never come.
+    detached: AtomicBool,
 }
 
 impl OutgoingMessageSender {
@@ -35,9 +41,26 @@ impl OutgoingMessageSender {
             sender,
             request_id_to_callback: Mutex::new(HashMap::new()),
             opted_out_notification_methods: Mutex::new(HashSet::new()),
+            rich_approvals: AtomicBool::new(false),
+            detached: AtomicBool::new(false),
         }
     }
 
+    /// Mark the client as gone and fail every pending request, so turns
+    /// waiting on an approval see it declined and can finish.
+    pub(crate) async fn detach(&self) {
+        self.detached.store(true, Ordering::Relaxed);
+        self.request_id_to_callback.lock().await.clear();
+    }
+
+    pub(crate) fn set_rich_approvals(&self, enabled: bool) {
+        self.rich_approvals.store(enabled, Ordering::Relaxed);
+    }
+
+    pub(crate) fn rich_approvals(&self) -> bool {
+        self.rich_approvals.load(Ordering::Relaxed)
+    }
+
     pub(crate) async fn set_opted_out_notification_methods(&self, methods: Vec<String>) {
         let mut opted_out = self.opted_out_notification_methods.lock().await;
         opted_out.clear();
@@ -64,6 +87,10 @@ impl OutgoingMessageSender {
         let id = RequestId::Integer(self.next_request_id.fetch_add(1, Ordering::Relaxed));
         let outgoing_message_id = id.clone();
         let (tx_approve, rx_approve) = oneshot::channel();
+        if self.detached.load(Ordering::Relaxed) {
+            // Dropping the sender resolves `rx_approve` with an error.
+            return (id, rx_approve);
+        }
         {
             let mut request_id_to_callback = self.request_id_to_callback.lock().await;
             request_id_to_callback.insert(id, tx_approve);
@@ -218,6 +245,8 @@ mod tests {
     use codex_app_server_protocol::AccountRateLimitsUpdatedNotification;
     use codex_app_server_protocol::AccountUpdatedNotification;
     use codex_app_server_protocol::AuthMode;
+    use codex_app_server_protocol::ChatgptAuthTokensRefreshParams;
+    use codex_app_server_protocol::ChatgptAuthTokensRefreshReason;
     use codex_app_server_protocol::ConfigWarningNotification;
     use codex_app_server_protocol::LoginChatGptCompleteNotification;
     use codex_app_server_protocol::RateLimitSnapshot;
@@ -360,4 +389,25 @@ mod tests {
             "ensure the notification serializes correctly"
         );
     }
+
+    #[tokio::test]
+    async fn detach_fails_pending_and_new_requests() {
+        let (tx, mut rx) = mpsc::channel(4);
+        let outgoing = OutgoingMessageSender::new(tx);
+        let refresh = || {
+            ServerRequestPayload::ChatgptAuthTokensRefresh(ChatgptAuthTokensRefreshParams {
+                reason: ChatgptAuthTokensRefreshReason::Unauthorized,
+                previous_account_id: None,
+            })
+        };
+
+        let pending = outgoing.send_request(refresh()).await;
+        assert!(rx.recv().await.is_some());
+        outgoing.detach().await;
+        let after = outgoing.send_request(refresh()).await;
+
+        assert!(pending.await.is_err());
+        assert!(after.await.is_err());
+        assert!(rx.try_recv().is_err());
+    }
 }
@@ -57,6 +57,7 @@ use codex_app_server_protocol::ThreadForkParams;
 use codex_app_server_protocol::ThreadListParams;
 use codex_app_server_protocol::ThreadLoadedListParams;
 use codex_app_server_protocol::ThreadReadParams;
+use codex_app_server_protocol::ThreadReattachParams;
 use codex_app_server_protocol::ThreadResumeParams;
 use codex_app_server_protocol::ThreadRollbackParams;
 use codex_app_server_protocol::ThreadStartParams;
@@ -175,6 +176,7 @@ impl McpProcess {
             Some(InitializeCapabilities {
                 experimental_api: true,
                 opt_out_notification_methods: None,
+                rich_approvals: false,
             }),
         )
         .await
@@ -470,6 +472,15 @@ impl McpProcess {
         self.send_request("thread/read", params).await
     }
 
+    /// Send a `thread/reattach` JSON-RPC request.
+    pub async fn send_thread_reattach_request(
+        &mut self,
+        params: ThreadReattachParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("thread/reattach", params).await
+    }
+
     /// Send a `model/list` JSON-RPC request.
     pub async fn send_list_models_request(
         &mut self,
@@ -31,6 +31,7 @@ async fn mock_experimental_method_requires_experimental_api_capability() -> Resu
             Some(InitializeCapabilities {
                 experimental_api: false,
                 opt_out_notification_methods: None,
+                rich_approvals: false,
             }),
         )
         .await?;
@@ -63,6 +64,7 @@ async fn thread_start_mock_field_requires_experimental_api_capability() -> Resul
             Some(InitializeCapabilities {
                 experimental_api: false,
                 opt_out_notification_methods: None,
+                rich_approvals: false,
             }),
         )
         .await?;
@@ -100,6 +102,7 @@ async fn thread_start_without_dynamic_tools_allows_without_experimental_api_capa
             Some(InitializeCapabilities {
                 experimental_api: false,
                 opt_out_notification_methods: None,
+                rich_approvals: false,
             }),
         )
         .await?;
@@ -20,6 +20,7 @@ mod thread_fork;
 mod thread_list;
 mod thread_loaded_list;
 mod thread_read;
+mod thread_reattach;
 mod thread_resume;
 mod thread_rollback;
 mod thread_start;
@@ -0,0 +1,136 @@
+use anyhow::Result;
+use app_test_support::McpProcess;
+use app_test_support::create_fake_rollout;
+use app_test_support::create_mock_responses_server_repeating_assistant;
+use app_test_support::to_response;
+use codex_app_server_protocol::JSONRPCError;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::RequestId;
+use codex_app_server_protocol::ThreadReattachParams;
+use codex_app_server_protocol::ThreadReattachResponse;
+use codex_app_server_protocol::TurnStatus;
+use pretty_assertions::assert_eq;
+use std::path::Path;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+const DEFAULT_READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+#[tokio::test]
+async fn thread_reattach_replays_turns_after_the_last_seen_one() -> Result<()> {
+    let server = create_mock_responses_server_repeating_assistant("Done").await;
+    let codex_home = TempDir::new()?;
+    create_config_toml(codex_home.path(), &server.uri())?;
+
+    let conversation_id = create_fake_rollout(
+        codex_home.path(),
+        "2025-01-05T12-00-00",
+        "2025-01-05T12:00:00Z",
+        "Saved user message",
+        Some("mock_provider"),
+        None,
+    )?;
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    // The thread is not loaded in this server, so it is resumed from its
+    // rollout and every recorded turn is missed.
+    let reattach_id = mcp
+        .send_thread_reattach_request(ThreadReattachParams {
+            thread_id: conversation_id.clone(),
+            last_turn_id: None,
+        })
+        .await?;
+    let reattach_resp: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(reattach_id)),
+    )
+    .await??;
+    let ThreadReattachResponse {
+        thread,
+        missed_turns,
+        was_loaded,
+    } = to_response::<ThreadReattachResponse>(reattach_resp)?;
+    assert_eq!(thread.id, conversation_id);
+    assert!(!was_loaded);
+    assert_eq!(missed_turns.len(), 1);
+    assert_eq!(missed_turns[0].status, TurnStatus::Completed);
+
+    // Reattaching again finds the loaded thread and nothing new to replay.
+    let reattach_id = mcp
+        .send_thread_reattach_request(ThreadReattachParams {
+            thread_id: conversation_id.clone(),
+            last_turn_id: Some(missed_turns[0].id.clone()),
+        })
+        .await?;
+    let reattach_resp: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(reattach_id)),
+    )
+    .await??;
+    let ThreadReattachResponse {
+        missed_turns,
+        was_loaded,
+        ..
+    } = to_response::<ThreadReattachResponse>(reattach_resp)?;
+    assert!(was_loaded);
+    assert_eq!(missed_turns, Vec::new());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn thread_reattach_rejects_unknown_thread() -> Result<()> {
+    let server = create_mock_responses_server_repeating_assistant("Done").await;
+    let codex_home = TempDir::new()?;
+    create_config_toml(codex_home.path(), &server.uri())?;
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    let reattach_id = mcp
+        .send_thread_reattach_request(ThreadReattachParams {
+            thread_id: "67e55044-10b1-426f-9247-bb680e5fe0c8".to_string(),
+            last_turn_id: None,
+        })
+        .await?;
+    let reattach_err: JSONRPCError = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_error_message(RequestId::Integer(reattach_id)),
+    )
+    .await??;
+    assert!(
+        reattach_err
+            .error
+            .message
+            .contains("no rollout found for thread id"),
+        "unexpected reattach error: {}",
+        reattach_err.error.message
+    );
+
+    Ok(())
+}
+
+fn create_config_toml(codex_home: &Path, server_uri: &str) -> std::io::Result<()> {
+    let config_toml = codex_home.join("config.toml");
+    std::fs::write(
+        config_toml,
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+
+model_provider = "mock_provider"
+
+[model_providers.mock_provider]
+name = "Mock provider for test"
+base_url = "{server_uri}/v1"
+wire_api = "responses"
+request_max_retries = 0
+stream_max_retries = 0
+"#
+        ),
+    )
+}
@@ -824,6 +824,41 @@ mod tests {
         assert_eq!(String::from_utf8(stderr).unwrap(), "");
     }
 
+    /// CJK file and directory names must survive parsing, the move, and the
+    /// success summary unchanged.
+    #[test]
+    fn test_update_file_hunk_with_cjk_paths() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("文档").join("说明.md");
+        let dest = dir.path().join("归档").join("説明書.md");
+        fs::create_dir_all(src.parent().unwrap()).unwrap();
+        fs::write(&src, "标题\n旧内容\n").unwrap();
+        let patch = wrap_patch(&format!(
+            r#"*** Update File: {}
+*** Move to: {}
+@@
+ 标题
+-旧内容
++新内容"#,
+            src.display(),
+            dest.display()
+        ));
+
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        apply_patch(&patch, &mut stdout, &mut stderr).unwrap();
+
+        let stdout_str = String::from_utf8(stdout).unwrap();
+        let expected_out = format!(
+            "Success. Updated the following files:\nM {}\n",
+            dest.display()
+        );
+        assert_eq!(stdout_str, expected_out);
+        assert_eq!(String::from_utf8(stderr).unwrap(), "");
+        assert!(!src.exists());
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "标题\n新内容\n");
+    }
+
     #[test]
     fn test_unified_diff() {
         // Start with a file containing four lines.
@@ -0,0 +1,521 @@
+//! `codex commit`: drafts a Conventional Commits message for the staged
+//! changes, lets the user accept, edit, or regenerate it, and commits.
+//! With `provenance.commit_trailers` set, the commit gets `Codex-Model` and
+//! `Codex-Session` trailers naming the drafting model and thread.
+//!
+//! The draft comes from a short-lived, read-only agent thread that is not
+//! recorded. A repository can describe its commit style in
+//! `.codex/commit.toml`:
+//!
+//! ```toml
+//! types = ["feat", "fix", "docs", "chore"]
+//! scopes = ["core", "tui", "cli"]
+//! require_scope = true
+//! max_subject_length = 60
+//! body = "always"
+//! instructions = "Mention the issue number from the branch name."
+//! ```
+
+use std::io::IsTerminal;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_common::CliConfigOverrides;
+use codex_core::Agent;
+use codex_core::AgentThread;
+use codex_core::TurnOutcome;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use codex_core::git_info::get_git_repo_root;
+use codex_core::protocol::AskForApproval;
+use codex_core::protocol::ReviewDecision;
+use codex_core::provenance::commit_trailers;
+use codex_protocol::config_types::SandboxMode;
+use serde::Deserialize;
+
+/// Style file name under the repository's `.codex` directory.
+const COMMIT_STYLE_FILE: &str = "commit.toml";
+const DEFAULT_TYPES: &[&str] = &[
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
+const DEFAULT_MAX_SUBJECT_LENGTH: usize = 72;
+/// Longer staged diffs are cut; the `--stat` summary always covers every file.
+const MAX_DIFF_CHARS: usize = 60_000;
+
+const COMMIT_INSTRUCTIONS: &str = "You write git commit messages in the Conventional Commits \
+format. You are given the staged diff of a repository and the project's commit rules. Reply with \
+the commit message only: no code fences, no quotes, no commentary. Do not run commands or edit \
+files.";
+
+/// Draft a commit message for the staged changes and commit it.
+#[derive(Debug, clap::Parser)]
+pub struct CommitCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    /// Model used to draft the message; defaults to the configured model.
+    #[arg(long = "model", short = 'm')]
+    pub model: Option<String>,
+
+    /// Commit the first draft without asking.
+    #[arg(long = "yes", short = 'y', default_value_t = false)]
+    pub yes: bool,
+
+    /// Print the draft and exit without committing.
+    #[arg(long = "print", default_value_t = false, conflicts_with = "yes")]
+    pub print: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum BodyPolicy {
+    /// A body only when the subject alone does not explain the change.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CommitStyleToml {
+    /// Allowed commit types; defaults to the Conventional Commits set.
+    #[serde(default)]
+    types: Option<Vec<String>>,
+    /// Allowed scopes; any scope is accepted when empty.
+    #[serde(default)]
+    scopes: Vec<String>,
+    #[serde(default)]
+    require_scope: bool,
+    #[serde(default)]
+    max_subject_length: Option<usize>,
+    #[serde(default)]
+    body: BodyPolicy,
+    /// Extra guidance for the message, in the project's words.
+    #[serde(default)]
+    instructions: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CommitStyle {
+    types: Vec<String>,
+    scopes: Vec<String>,
+    require_scope: bool,
+    max_subject_length: usize,
+    body: BodyPolicy,
+    instructions: Option<String>,
+}
+
+impl From<CommitStyleToml> for CommitStyle {
+    fn from(toml: CommitStyleToml) -> Self {
+        Self {
+            types: toml
+                .types
+                .unwrap_or_else(|| DEFAULT_TYPES.iter().map(ToString::to_string).collect()),
+            scopes: toml.scopes,
+            require_scope: toml.require_scope,
+            max_subject_length: toml
+                .max_subject_length
+                .unwrap_or(DEFAULT_MAX_SUBJECT_LENGTH),
+            body: toml.body,
+            instructions: toml.instructions,
+        }
+    }
+}
+
+impl CommitStyle {
+    /// Loads `.codex/commit.toml` from the repository root, falling back to
+    /// the defaults when the file does not exist.
+    fn load(root: &Path) -> Result<Self> {
+        let path = root.join(".codex").join(COMMIT_STYLE_FILE);
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(CommitStyleToml::default().into());
+            }
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()));
+            }
+        };
+        let style: CommitStyleToml = toml::from_str(&contents)
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        Ok(style.into())
+    }
+
+    /// The rules as a bullet list for the prompt.
+    fn rules(&self) -> String {
+        let mut rules = vec![
+            "- Subject line: `type(scope): summary`, or `type: summary` without a scope; \
+             add `!` after the type or scope for breaking changes."
+                .to_string(),
+            format!("- Allowed types: {}.", self.types.join(", ")),
+        ];
+        if !self.scopes.is_empty() {
+            rules.push(format!("- Allowed scopes: {}.", self.scopes.join(", ")));
+        }
+        if self.require_scope {
+            rules.push("- A scope is required.".to_string());
+        }
+        rules.push(format!(
+            "- Keep the subject within {} characters, in the imperative mood, without a trailing period.",
+            self.max_subject_length
+        ));
+        rules.push(
+            match self.body {
+                BodyPolicy::Auto => {
+                    "- Add a body after a blank line only when the change needs explaining; wrap it at 72 columns."
+                }
+                BodyPolicy::Always => {
+                    "- Always add a body after a blank line explaining what changed and why; wrap it at 72 columns."
+                }
+                BodyPolicy::Never => "- Write the subject line only, without a body.",
+            }
+            .to_string(),
+        );
+        if let Some(instructions) = self.instructions.as_deref().map(str::trim) {
+            rules.push(format!("- {instructions}"));
+        }
+        rules.join("\n")
+    }
+
+    /// Ways `message` breaks the rules, for the user to see before accepting.
+    fn check(&self, message: &str) -> Vec<String> {
+        let mut problems = Vec::new();
+        let subject = message.lines().next().unwrap_or_default();
+        let Some((head, summary)) = subject.split_once(": ") else {
+            return vec!["the subject is not `type(scope): summary`".to_string()];
+        };
+        let head = head.strip_suffix('!').unwrap_or(head);
+        let (kind, scope) = match head.split_once('(') {
+            Some((kind, rest)) => (kind, rest.strip_suffix(')')),
+            None => (head, None),
+        };
+        if !self.types.iter().any(|allowed| allowed == kind) {
+            problems.push(format!("`{kind}` is not an allowed type"));
+        }
+        match scope {
+            None if self.require_scope => problems.push("the scope is missing".to_string()),
+            Some(scope) if !self.scopes.is_empty() && !self.scopes.iter().any(|s| s == scope) => {
+                problems.push(format!("`{scope}` is not an allowed scope"));
+            }
+            _ => {}
+        }
+        if summary.trim().is_empty() {
+            problems.push("the summary is empty".to_string());
+        }
+        let length = subject.chars().count();
+        if length > self.max_subject_length {
+            problems.push(format!(
+                "the subject is {length} characters, over the limit of {}",
+                self.max_subject_length
+            ));
+        }
+        let has_body = message.lines().skip(1).any(|line| !line.trim().is_empty());
+        match self.body {
+            BodyPolicy::Always if !has_body => problems.push("the body is missing".to_string()),
+            BodyPolicy::Never if has_body => {
+                problems.push("the message has a body, but the style asks for none".to_string());
+            }
+            _ => {}
+        }
+        problems
+    }
+}
+
+impl CommitCli {
+    pub async fn run(self) -> Result<()> {
+        let cwd = std::env::current_dir()?;
+        let root = get_git_repo_root(&cwd).context("not inside a git repository")?;
+        let diff = git_output(&root, &["diff", "--cached", "--no-color", "--no-ext-diff"])?;
+        if diff.trim().is_empty() {
+            bail!("Nothing is staged; stage changes with `git add` first.");
+        }
+        let stat = git_output(&root, &["diff", "--cached", "--stat", "--no-color"])?;
+        let style = CommitStyle::load(&root)?;
+        let interactive = !self.yes && !self.print;
+        if interactive && !std::io::stdin().is_terminal() {
+            bail!(
+                "stdin is not a terminal; pass --yes to commit the draft or --print to print it."
+            );
+        }
+
+        let overrides = self
+            .config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides_and_harness_overrides(
+            overrides,
+            ConfigOverrides {
+                model: self.model,
+                cwd: Some(root.clone()),
+                approval_policy: Some(AskForApproval::Never),
+                sandbox_mode: Some(SandboxMode::ReadOnly),
+                base_instructions: Some(COMMIT_INSTRUCTIONS.to_string()),
+                ephemeral: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .context("failed to load configuration")?;
+        let add_trailers = config.provenance.commit_trailers;
+        let agent = Agent::from_config(config);
+        let thread = agent.start_thread().await?;
+        let trailers = if add_trailers {
+            commit_trailers(&thread.session().model, &thread.id().to_string())
+        } else {
+            Vec::new()
+        };
+
+        eprintln!("Drafting a commit message…");
+        let mut message = draft(&thread, &draft_prompt(&style, &stat, &diff)).await?;
+        loop {
+            let problems = style.check(&message);
+            if !interactive {
+                for problem in &problems {
+                    eprintln!("warning: {problem}");
+                }
+                if self.print {
+                    println!("{message}");
+                    thread.shutdown().await?;
+                    return Ok(());
+                }
+                break;
+            }
+            eprintln!("\n{message}\n");
+            for problem in &problems {
+                eprintln!("warning: {problem}");
+            }
+            match ask("[a]ccept, [e]dit, [r]egenerate, [q]uit? ")?.as_str() {
+                "a" | "accept" | "" => break,
+                "e" | "edit" => {
+                    let edited = edit_in_editor(&message)?;
+                    if edited.is_empty() {
+                        eprintln!("The edited message is empty; keeping the previous one.");
+                    } else {
+                        message = edited;
+                    }
+                }
+                "r" | "regenerate" => {
+                    let feedback = ask("What should change? (Enter to just try again) ")?;
+                    eprintln!("Drafting a new message…");
+                    message = draft(&thread, &regenerate_prompt(&feedback, &problems)).await?;
+                }
+                "q" | "quit" => {
+                    eprintln!("Nothing was committed.");
+                    thread.shutdown().await?;
+                    return Ok(());
+                }
+                other => eprintln!("Unknown choice `{other}`."),
+            }
+        }
+        thread.shutdown().await?;
+        commit(&root, &message, &trailers)
+    }
+}
+
+fn draft_prompt(style: &CommitStyle, stat: &str, diff: &str) -> String {
+    let mut diff_text: String = diff.chars().take(MAX_DIFF_CHARS).collect();
+    if diff_text.len() < diff.len() {
+        diff_text.push_str("\n[diff truncated]");
+    }
+    format!(
+        "Write a commit message for these staged changes.\n\nRules:\n{}\n\nFiles:\n{}\n\nDiff:\n```diff\n{diff_text}\n```",
+        style.rules(),
+        stat.trim_end()
+    )
+}
+
+fn regenerate_prompt(feedback: &str, problems: &[String]) -> String {
+    let mut prompt =
+        "Write a different commit message for the same changes, following the same rules."
+            .to_string();
+    if !problems.is_empty() {
+        prompt.push_str(&format!(
+            "\nThe previous one broke the rules: {}.",
+            problems.join("; ")
+        ));
+    }
+    let feedback = feedback.trim();
+    if !feedback.is_empty() {
+        prompt.push_str(&format!("\nRequested changes: {feedback}"));
+    }
+    prompt
+}
+
+async fn draft(thread: &AgentThread, prompt: &str) -> Result<String> {
+    // The thread runs read-only and never asks, but deny anything that gets through.
+    let outcome = thread
+        .run_turn(prompt, |_request| ReviewDecision::Denied)
+        .await?;
+    let message = match outcome {
+        TurnOutcome::Completed {
+            last_agent_message: Some(message),
+        } => clean_message(&message),
+        TurnOutcome::Completed { .. } => String::new(),
+        TurnOutcome::Aborted { reason } => bail!("drafting was interrupted: {reason:?}"),
+        _ => String::new(),
+    };
+    if message.is_empty() {
+        bail!("the model did not return a commit message");
+    }
+    Ok(message)
+}
+
+/// The reply without surrounding whitespace or a code fence.
+fn clean_message(reply: &str) -> String {
+    let reply = reply.trim();
+    let unfenced = reply
+        .strip_prefix("```")
+        .and_then(|rest| rest.strip_suffix("```"))
+        .map(|inner| inner.split_once('\n').map_or(inner, |(_, body)| body));
+    unfenced.unwrap_or(reply).trim().to_string()
+}
+
+fn ask(question: &str) -> Result<String> {
+    eprint!("{question}");
+    std::io::stderr().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(answer.trim().to_ascii_lowercase())
+}
+
+/// Opens `message` in `$VISUAL`/`$EDITOR` (default `vi`) and returns the
+/// edited text without `#` comment lines.
+fn edit_in_editor(message: &str) -> Result<String> {
+    let mut file = tempfile::Builder::new()
+        .prefix("COMMIT_EDITMSG-")
+        .tempfile()?;
+    writeln!(
+        file,
+        "{message}\n\n# Edit the commit message. Lines starting with '#' are ignored."
+    )?;
+    file.flush()?;
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let mut argv = shlex::split(&editor)
+        .filter(|argv| !argv.is_empty())
+        .with_context(|| format!("cannot parse editor command `{editor}`"))?;
+    let program = argv.remove(0);
+    let status = Command::new(&program)
+        .args(argv)
+        .arg(file.path())
+        .status()
+        .with_context(|| format!("failed to run {program}"))?;
+    if !status.success() {
+        bail!("{program} exited with {status}");
+    }
+    let edited = std::fs::read_to_string(file.path())?;
+    Ok(edited
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string())
+}
+
+/// Commits with `message`, letting git place `trailers` at its end.
+fn commit(root: &Path, message: &str, trailers: &[String]) -> Result<()> {
+    let mut file = tempfile::NamedTempFile::new()?;
+    writeln!(file, "{message}")?;
+    file.flush()?;
+    let mut command = Command::new("git");
+    command.arg("commit").arg("--file").arg(file.path());
+    for trailer in trailers {
+        command.arg("--trailer").arg(trailer);
+    }
+    let status = command
+        .current_dir(root)
+        .status()
+        .context("failed to run git commit")?;
+    if !status.success() {
+        bail!("git commit exited with {status}");
+    }
+    Ok(())
+}
+
+fn git_output(root: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .with_context(|| format!("failed to run git {}", args.join(" ")))?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn style(toml: &str) -> CommitStyle {
+        toml::from_str::<CommitStyleToml>(toml)
+            .expect("valid commit style")
+            .into()
+    }
+
+    #[test]
+    fn default_style_accepts_conventional_subjects() {
+        let style = style("");
+        assert_eq!(
+            style.check("feat(cli): add codex commit"),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            style.check("fix!: drop the legacy flag\n\nIt was unused."),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            style.check("Add codex commit"),
+            vec!["the subject is not `type(scope): summary`".to_string()]
+        );
+    }
+
+    #[test]
+    fn repo_style_limits_types_scopes_and_body() {
+        let style = style(
+            r#"
+types = ["feat", "fix"]
+scopes = ["core", "tui"]
+require_scope = true
+max_subject_length = 30
+body = "always"
+"#,
+        );
+        assert_eq!(
+            style.check("docs(cli): explain codex commit in the README"),
+            vec![
+                "`docs` is not an allowed type".to_string(),
+                "`cli` is not an allowed scope".to_string(),
+                "the subject is 45 characters, over the limit of 30".to_string(),
+                "the body is missing".to_string(),
+            ]
+        );
+        assert_eq!(
+            style.check("fix: handle empty diffs\n\nBody."),
+            vec!["the scope is missing".to_string()]
+        );
+        assert!(style.rules().contains("- Allowed scopes: core, tui."));
+    }
+
+    #[test]
+    fn code_fences_are_removed_from_replies() {
+        assert_eq!(
+            clean_message("```text\nfeat: add x\n\nBody\n```\n"),
+            "feat: add x\n\nBody"
+        );
+        assert_eq!(clean_message("  fix: y  "), "fix: y");
+    }
+}
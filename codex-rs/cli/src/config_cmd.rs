@@ -0,0 +1,398 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use codex_app_server_protocol::ConfigLayerSource;
+use codex_common::CliConfigOverrides;
+use codex_core::config::CONFIG_TOML_FILE;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use codex_core::config_loader::ConfigLayerStack;
+use codex_core::config_loader::ConfigLayerStackOrdering;
+use toml::Value as TomlValue;
+
+/// Environment variables that change resolved settings without appearing in
+/// any config layer, with the setting each one feeds.
+const CONFIG_ENV_VARS: [(&str, &str); 4] = [
+    ("CODEX_HOME", "location of the user config.toml"),
+    ("OPENAI_BASE_URL", "model_providers.openai.base_url"),
+    ("CODEX_OSS_BASE_URL", "model_providers.oss.base_url"),
+    ("CODEX_OSS_PORT", "model_providers.oss.base_url port"),
+];
+
+/// Subcommands:
+/// - `show` — print the effective configuration, optionally with the layer each value came from
+#[derive(Debug, clap::Parser)]
+pub struct ConfigCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: ConfigSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ConfigSubcommand {
+    /// Print the configuration that applies in the current directory.
+    Show(ShowArgs),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ShowArgs {
+    /// Print one `key = value` line per setting, annotated with the file, profile, or flag that set it.
+    #[arg(long, visible_alias = "origins")]
+    pub origin: bool,
+
+    /// Resolve with this profile active instead of the configured `profile`.
+    #[arg(long, short = 'p', value_name = "PROFILE")]
+    pub profile: Option<String>,
+
+    /// Only print settings whose resolved value differs under PROFILE.
+    #[arg(long, value_name = "PROFILE", conflicts_with = "origin")]
+    pub diff: Option<String>,
+
+    /// Resolve project `.codex/config.toml` files for this directory instead of the current one.
+    #[arg(long = "cd", short = 'C', value_name = "DIR")]
+    pub cwd: Option<PathBuf>,
+}
+
+impl ConfigCli {
+    pub async fn run(self) -> Result<()> {
+        let ConfigCli {
+            config_overrides,
+            subcommand,
+        } = self;
+
+        match subcommand {
+            ConfigSubcommand::Show(args) => run_show(&config_overrides, args).await?,
+        }
+
+        Ok(())
+    }
+}
+
+async fn run_show(config_overrides: &CliConfigOverrides, show_args: ShowArgs) -> Result<()> {
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let ShowArgs {
+        origin,
+        profile,
+        diff,
+        cwd,
+    } = show_args;
+    let config = Config::load_with_cli_overrides_and_harness_overrides(
+        overrides,
+        ConfigOverrides {
+            cwd,
+            config_profile: profile,
+            ..Default::default()
+        },
+    )
+    .await
+    .context("failed to load configuration")?;
+    let stack = &config.config_layer_stack;
+    let active_profile = config.active_profile.as_deref();
+
+    if let Some(other) = diff.as_deref() {
+        let current = resolve_settings(stack, active_profile)?;
+        let compared = resolve_settings(stack, Some(other))?;
+        let lines = diff_lines(&current, &compared);
+        if lines.is_empty() {
+            println!(
+                "profile `{other}` resolves to the same settings as {}",
+                describe_profile(active_profile)
+            );
+        }
+        for line in lines {
+            println!("{line}");
+        }
+    } else if origin {
+        for (path, setting) in resolve_settings(stack, active_profile)? {
+            println!(
+                "{} = {}  # {}",
+                dotted_key(&path),
+                setting.value,
+                setting.origin
+            );
+        }
+        for (name, feeds) in CONFIG_ENV_VARS {
+            if let Ok(value) = std::env::var(name) {
+                println!("# env {name}={value} ({feeds})");
+            }
+        }
+    } else {
+        print!("{}", toml::to_string_pretty(&stack.effective_config())?);
+    }
+
+    for layer in stack.get_layers(ConfigLayerStackOrdering::LowestPrecedenceFirst, true) {
+        if let Some(reason) = &layer.disabled_reason {
+            eprintln!(
+                "note: ignoring {}: {reason}",
+                describe_layer_source(&layer.name)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ResolvedSetting {
+    value: TomlValue,
+    origin: String,
+}
+
+/// Flattens the effective config into one entry per leaf value, then lays the
+/// selected profile's values over the top-level keys they replace. Arrays are
+/// kept whole since layers replace them wholesale.
+fn resolve_settings(
+    stack: &ConfigLayerStack,
+    profile: Option<&str>,
+) -> Result<BTreeMap<Vec<String>, ResolvedSetting>> {
+    let effective = stack.effective_config();
+    let mut settings = BTreeMap::new();
+    let mut path = Vec::new();
+    collect_settings(
+        stack,
+        &effective,
+        &[],
+        &mut path,
+        &mut settings,
+        &|layer: String| layer,
+    );
+
+    if let Some(profile) = profile {
+        let profile_table = effective
+            .get("profiles")
+            .and_then(|profiles| profiles.get(profile))
+            .with_context(|| format!("config profile `{profile}` not found"))?;
+        let prefix = ["profiles".to_string(), profile.to_string()];
+        collect_settings(
+            stack,
+            profile_table,
+            &prefix,
+            &mut path,
+            &mut settings,
+            &|layer: String| format!("profile {profile} ({layer})"),
+        );
+    }
+
+    Ok(settings)
+}
+
+fn collect_settings(
+    stack: &ConfigLayerStack,
+    value: &TomlValue,
+    origin_prefix: &[String],
+    path: &mut Vec<String>,
+    settings: &mut BTreeMap<Vec<String>, ResolvedSetting>,
+    label: &dyn Fn(String) -> String,
+) {
+    match value {
+        TomlValue::Table(table) if !table.is_empty() => {
+            for (key, value) in table {
+                path.push(key.clone());
+                collect_settings(stack, value, origin_prefix, path, settings, label);
+                path.pop();
+            }
+        }
+        _ => {
+            let origin_path: Vec<&str> = origin_prefix
+                .iter()
+                .chain(path.iter())
+                .map(String::as_str)
+                .collect();
+            let origin = stack.origin_of(&origin_path).map_or_else(
+                || "unknown".to_string(),
+                |layer| describe_layer_source(&layer.name),
+            );
+            settings.insert(
+                path.clone(),
+                ResolvedSetting {
+                    value: value.clone(),
+                    origin: label(origin),
+                },
+            );
+        }
+    }
+}
+
+fn diff_lines(
+    current: &BTreeMap<Vec<String>, ResolvedSetting>,
+    compared: &BTreeMap<Vec<String>, ResolvedSetting>,
+) -> Vec<String> {
+    let mut keys: Vec<&Vec<String>> = current.keys().chain(compared.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    let render = |setting: Option<&ResolvedSetting>| {
+        setting.map_or_else(
+            || "<unset>".to_string(),
+            |setting| setting.value.to_string(),
+        )
+    };
+    keys.into_iter()
+        .filter_map(|key| {
+            let before = current.get(key);
+            let after = compared.get(key);
+            if before.map(|setting| &setting.value) == after.map(|setting| &setting.value) {
+                return None;
+            }
+            let origin = after
+                .or(before)
+                .map_or("", |setting| setting.origin.as_str());
+            Some(format!(
+                "{}: {} -> {}  # {origin}",
+                dotted_key(key),
+                render(before),
+                render(after)
+            ))
+        })
+        .collect()
+}
+
+fn describe_profile(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("profile `{profile}`"),
+        None => "the default settings".to_string(),
+    }
+}
+
+fn dotted_key(path: &[String]) -> String {
+    path.iter()
+        .map(|segment| {
+            let bare = !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-');
+            if bare {
+                segment.clone()
+            } else {
+                TomlValue::String(segment.clone()).to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn describe_layer_source(source: &ConfigLayerSource) -> String {
+    match source {
+        ConfigLayerSource::Mdm { domain, key } => format!("managed preferences {domain}:{key}"),
+        ConfigLayerSource::System { file } => format!("system {}", file.display()),
+        ConfigLayerSource::User { file } => format!("user {}", file.display()),
+        ConfigLayerSource::Project { dot_codex_folder } => format!(
+            "project {}",
+            dot_codex_folder.as_path().join(CONFIG_TOML_FILE).display()
+        ),
+        ConfigLayerSource::SessionFlags => "-c/--enable/--disable flag".to_string(),
+        ConfigLayerSource::LegacyManagedConfigTomlFromFile { file } => {
+            format!("managed {}", file.display())
+        }
+        ConfigLayerSource::LegacyManagedConfigTomlFromMdm => "managed preferences".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::config_loader::ConfigLayerEntry;
+    use codex_core::config_loader::ConfigRequirements;
+    use codex_core::config_loader::ConfigRequirementsToml;
+    use codex_utils_absolute_path::AbsolutePathBuf;
+    use pretty_assertions::assert_eq;
+
+    fn key(dotted: &str) -> Vec<String> {
+        dotted.split('.').map(ToString::to_string).collect()
+    }
+
+    fn test_stack() -> (ConfigLayerStack, String, String) {
+        let root = std::env::temp_dir();
+        let user_file =
+            AbsolutePathBuf::from_absolute_path(root.join("home").join(CONFIG_TOML_FILE))
+                .expect("absolute");
+        let dot_codex = AbsolutePathBuf::from_absolute_path(root.join("repo").join(".codex"))
+            .expect("absolute");
+        let user = ConfigLayerEntry::new(
+            ConfigLayerSource::User {
+                file: user_file.clone(),
+            },
+            toml::from_str(
+                "model = \"gpt-5\"\n[tui]\nanimations = false\n[profiles.fast]\nmodel = \"gpt-5-mini\"\n",
+            )
+            .expect("toml"),
+        );
+        let project = ConfigLayerEntry::new(
+            ConfigLayerSource::Project {
+                dot_codex_folder: dot_codex.clone(),
+            },
+            toml::from_str("model = \"o3\"\n").expect("toml"),
+        );
+        let stack = ConfigLayerStack::new(
+            vec![user, project],
+            ConfigRequirements::default(),
+            ConfigRequirementsToml::default(),
+        )
+        .expect("stack");
+        (
+            stack,
+            format!("user {}", user_file.display()),
+            format!(
+                "project {}",
+                dot_codex.as_path().join(CONFIG_TOML_FILE).display()
+            ),
+        )
+    }
+
+    #[test]
+    fn settings_name_the_winning_layer() {
+        let (stack, user, project) = test_stack();
+
+        let settings = resolve_settings(&stack, None).expect("resolve");
+
+        assert_eq!(
+            settings.get(&key("model")),
+            Some(&ResolvedSetting {
+                value: TomlValue::String("o3".to_string()),
+                origin: project,
+            })
+        );
+        assert_eq!(
+            settings.get(&key("tui.animations")).map(|s| &s.origin),
+            Some(&user)
+        );
+    }
+
+    #[test]
+    fn profile_overrides_top_level_and_diffs() {
+        let (stack, user, _) = test_stack();
+
+        let current = resolve_settings(&stack, None).expect("resolve");
+        let fast = resolve_settings(&stack, Some("fast")).expect("resolve");
+
+        assert_eq!(
+            fast.get(&key("model")).map(|s| s.origin.clone()),
+            Some(format!("profile fast ({user})"))
+        );
+        assert_eq!(
+            diff_lines(&current, &fast),
+            vec![format!(
+                "model: \"o3\" -> \"gpt-5-mini\"  # profile fast ({user})"
+            )]
+        );
+        assert!(resolve_settings(&stack, Some("missing")).is_err());
+    }
+
+    #[test]
+    fn dotted_key_quotes_non_bare_segments() {
+        assert_eq!(
+            dotted_key(&[
+                "projects".to_string(),
+                "/srv/app".to_string(),
+                "trust_level".to_string()
+            ]),
+            "projects.\"/srv/app\".trust_level"
+        );
+    }
+}
@@ -6,15 +6,21 @@ mod seatbelt;
 use std::path::PathBuf;
 
 use codex_common::CliConfigOverrides;
+use codex_common::summarize_sandbox_policy;
 use codex_core::config::Config;
 use codex_core::config::ConfigOverrides;
 use codex_core::exec_env::create_env;
 use codex_core::landlock::spawn_command_under_linux_sandbox;
+use codex_core::sandbox_explain::ExplainedOutcome;
+use codex_core::sandbox_explain::explain_command;
 #[cfg(target_os = "macos")]
 use codex_core::seatbelt::spawn_command_under_seatbelt;
 use codex_core::spawn::StdioPolicy;
+use codex_execpolicy::Decision;
+use codex_execpolicy::RuleMatch;
 use codex_protocol::config_types::SandboxMode;
 
+use crate::ExplainCommand;
 use crate::LandlockCommand;
 use crate::SeatbeltCommand;
 use crate::WindowsCommand;
@@ -288,6 +294,128 @@ async fn run_command_under_sandbox(
     handle_exit_status(status);
 }
 
+/// Prints what the sandbox and execpolicy would do with a command, for every
+/// platform backend, without running it.
+pub async fn run_sandbox_explain(
+    command: ExplainCommand,
+    codex_linux_sandbox_exe: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let ExplainCommand {
+        full_auto,
+        config_overrides,
+        command,
+    } = command;
+    let config = Config::load_with_cli_overrides_and_harness_overrides(
+        config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?,
+        ConfigOverrides {
+            sandbox_mode: full_auto.then(|| create_sandbox_mode(full_auto)),
+            codex_linux_sandbox_exe,
+            ..Default::default()
+        },
+    )
+    .await?;
+    let explanation = explain_command(&config, &command).await?;
+
+    println!("command: {}", shlex_join(&command));
+    println!(
+        "sandbox: {} (approval: {})",
+        summarize_sandbox_policy(&explanation.sandbox_policy),
+        explanation.approval_policy
+    );
+    if explanation.sandbox_policy.has_full_disk_write_access() {
+        println!("writable: everywhere");
+    } else if explanation.writable_roots.is_empty() {
+        println!("writable: nothing");
+    } else {
+        println!("writable:");
+        for root in &explanation.writable_roots {
+            println!("  {}", root.root.display());
+            for subpath in &root.read_only_subpaths {
+                println!("    read-only: {}", subpath.display());
+            }
+        }
+    }
+    println!(
+        "network: {}",
+        if explanation.network_access {
+            "allowed"
+        } else {
+            "blocked"
+        }
+    );
+
+    println!("execpolicy:");
+    for rule in &explanation.matched_rules {
+        match rule {
+            RuleMatch::PrefixRuleMatch {
+                matched_prefix,
+                decision,
+                justification,
+            } => {
+                let justification = justification
+                    .as_deref()
+                    .map(|justification| format!(" ({justification})"))
+                    .unwrap_or_default();
+                println!(
+                    "  rule `{}` -> {}{justification}",
+                    shlex_join(matched_prefix),
+                    decision_label(*decision)
+                );
+            }
+            RuleMatch::HeuristicsRuleMatch { command, decision } => {
+                println!(
+                    "  no rule for `{}`, built-in heuristics -> {}",
+                    shlex_join(command),
+                    decision_label(*decision)
+                );
+            }
+        }
+    }
+
+    let outcome = match &explanation.outcome {
+        ExplainedOutcome::Sandboxed => "runs in the sandbox without asking".to_string(),
+        ExplainedOutcome::BypassesSandbox => {
+            "runs outside the sandbox without asking (allowed by a rule)".to_string()
+        }
+        ExplainedOutcome::NeedsApproval {
+            reason: Some(reason),
+        } => {
+            format!("asks for approval: {reason}")
+        }
+        ExplainedOutcome::NeedsApproval { reason: None } => "asks for approval".to_string(),
+        ExplainedOutcome::Forbidden { reason } => format!("refused: {reason}"),
+    };
+    println!("outcome: {outcome}");
+
+    for backend in &explanation.backends {
+        println!("{} ({}):", backend.platform, backend.backend);
+        for note in &backend.notes {
+            println!("  {note}");
+        }
+    }
+
+    println!("env:");
+    for (name, value) in &explanation.env {
+        println!("  {name}={value}");
+    }
+
+    Ok(())
+}
+
+fn decision_label(decision: Decision) -> &'static str {
+    match decision {
+        Decision::Allow => "allow",
+        Decision::Prompt => "prompt",
+        Decision::Forbidden => "forbidden",
+    }
+}
+
+fn shlex_join(args: &[String]) -> String {
+    shlex::try_join(args.iter().map(String::as_str)).unwrap_or_else(|_| args.join(" "))
+}
+
 pub fn create_sandbox_mode(full_auto: bool) -> SandboxMode {
     if full_auto {
         SandboxMode::WorkspaceWrite
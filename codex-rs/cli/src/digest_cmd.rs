@@ -0,0 +1,406 @@
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::Context;
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::Local;
+use chrono::NaiveDate;
+use chrono::Utc;
+use codex_common::CliConfigOverrides;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use codex_core::find_thread_names_by_ids;
+use codex_core::load_session_patches;
+use codex_core::load_session_preview;
+
+use crate::sessions_cmd::list_sessions;
+
+/// Files listed per session before the rest are folded into "and N more".
+const MAX_FILES_PER_SESSION: usize = 8;
+
+/// Prints a short "what Codex did" digest of the sessions updated in a time
+/// range, built from their exit summaries and apply_patch diff stats.
+#[derive(Debug, clap::Parser)]
+pub struct DigestCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    /// Start of the range: `today`, `yesterday`, a date (`2025-01-31`), or an age (`12h`, `3d`, `1w`).
+    #[arg(long, value_name = "WHEN", default_value = "yesterday")]
+    pub since: String,
+
+    /// End of the range, in the same forms as `--since`; a date includes that whole day. Defaults to now.
+    #[arg(long, value_name = "WHEN")]
+    pub until: Option<String>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = DigestFormat::Markdown)]
+    pub format: DigestFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DigestFormat {
+    Markdown,
+    /// Slack `mrkdwn`, ready to paste into a channel.
+    Slack,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DigestSession {
+    title: String,
+    /// Last component of the session's working directory.
+    project: Option<String>,
+    /// Exit summary bullets, or the first prompt when there is no summary.
+    bullets: Vec<String>,
+    files: Vec<FileStat>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileStat {
+    path: String,
+    added: usize,
+    removed: usize,
+}
+
+impl DigestCli {
+    pub async fn run(self) -> Result<()> {
+        let overrides = self
+            .config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides_and_harness_overrides(
+            overrides,
+            ConfigOverrides::default(),
+        )
+        .await
+        .context("failed to load configuration")?;
+
+        let now = Utc::now();
+        let since = parse_when(&self.since, now, false).with_context(|| {
+            format!(
+                "invalid --since `{}`; use today, yesterday, YYYY-MM-DD, or 12h/3d/1w",
+                self.since
+            )
+        })?;
+        let until = match self.until.as_deref() {
+            Some(value) => parse_when(value, now, true).with_context(|| {
+                format!("invalid --until `{value}`; use today, yesterday, YYYY-MM-DD, or 12h/3d/1w")
+            })?,
+            None => now,
+        };
+
+        let sessions = collect_sessions(&config, since, until).await?;
+        let range = format!(
+            "{} – {}",
+            since.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+            until.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        );
+        let digest = match self.format {
+            DigestFormat::Markdown => render_markdown(&range, &sessions),
+            DigestFormat::Slack => render_slack(&range, &sessions),
+        };
+        print!("{digest}");
+        Ok(())
+    }
+}
+
+/// Sessions updated in `since..until`, oldest first.
+async fn collect_sessions(
+    config: &Config,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Result<Vec<DigestSession>> {
+    let threads: Vec<_> = list_sessions(config, false, None)
+        .await?
+        .into_iter()
+        .filter(|thread| {
+            thread
+                .updated_at
+                .as_deref()
+                .or(thread.created_at.as_deref())
+                .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+                .is_some_and(|ts| ts >= since && ts < until)
+        })
+        .collect();
+    let ids: HashSet<_> = threads
+        .iter()
+        .filter_map(|thread| thread.thread_id)
+        .collect();
+    let names = find_thread_names_by_ids(&config.codex_home, &ids)
+        .await
+        .unwrap_or_default();
+
+    let mut sessions = Vec::new();
+    for thread in threads.iter().rev() {
+        let Some(first_prompt) = thread.first_user_message.as_deref() else {
+            continue;
+        };
+        let first_prompt = first_prompt.lines().next().unwrap_or_default().trim();
+        let preview = load_session_preview(&thread.path)
+            .await
+            .with_context(|| format!("failed to read {}", thread.path.display()))?;
+        let patches = load_session_patches(&thread.path)
+            .await
+            .with_context(|| format!("failed to read {}", thread.path.display()))?;
+        let title = thread
+            .thread_id
+            .and_then(|id| names.get(&id).cloned())
+            .unwrap_or_else(|| first_prompt.to_string());
+        let bullets = if preview.summary.is_empty() {
+            vec![format!("Asked: {first_prompt}")]
+        } else {
+            preview.summary
+        };
+        let files = patches
+            .files
+            .iter()
+            .map(|(path, log)| file_stat(path, log))
+            .collect();
+        sessions.push(DigestSession {
+            title,
+            project: thread
+                .cwd
+                .as_deref()
+                .and_then(Path::file_name)
+                .map(|name| name.to_string_lossy().into_owned()),
+            bullets,
+            files,
+        });
+    }
+    Ok(sessions)
+}
+
+fn file_stat(path: &Path, log: &str) -> FileStat {
+    let mut stat = FileStat {
+        path: path.display().to_string(),
+        added: 0,
+        removed: 0,
+    };
+    for line in log.lines() {
+        if line.starts_with('+') {
+            stat.added += 1;
+        } else if line.starts_with('-') {
+            stat.removed += 1;
+        }
+    }
+    stat
+}
+
+/// `today` or `yesterday` (start of the local day), a local date, or an age
+/// like `3d` counted back from `now`. With `end_of_day`, named days and dates
+/// mean the start of the following day so the whole day is included.
+fn parse_when(value: &str, now: DateTime<Utc>, end_of_day: bool) -> Option<DateTime<Utc>> {
+    let today = now.with_timezone(&Local).date_naive();
+    let date = match value {
+        "today" => Some(today),
+        "yesterday" => today.pred_opt(),
+        _ => NaiveDate::parse_from_str(value, "%Y-%m-%d").ok(),
+    };
+    if let Some(date) = date {
+        let date = if end_of_day { date.succ_opt()? } else { date };
+        return date
+            .and_hms_opt(0, 0, 0)?
+            .and_local_timezone(Local)
+            .earliest()
+            .map(|ts| ts.with_timezone(&Utc));
+    }
+    let unit = value.chars().last()?;
+    let amount: i64 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    let age = match unit {
+        'h' => chrono::Duration::try_hours(amount)?,
+        'd' => chrono::Duration::try_days(amount)?,
+        'w' => chrono::Duration::try_weeks(amount)?,
+        _ => return None,
+    };
+    now.checked_sub_signed(age)
+}
+
+fn totals(sessions: &[DigestSession]) -> String {
+    let files: usize = sessions.iter().map(|session| session.files.len()).sum();
+    let added: usize = sessions
+        .iter()
+        .flat_map(|session| &session.files)
+        .map(|file| file.added)
+        .sum();
+    let removed: usize = sessions
+        .iter()
+        .flat_map(|session| &session.files)
+        .map(|file| file.removed)
+        .sum();
+    format!(
+        "{} session(s) · {files} file(s) changed · +{added} −{removed}",
+        sessions.len()
+    )
+}
+
+fn heading(session: &DigestSession) -> String {
+    match &session.project {
+        Some(project) => format!("{} ({project})", session.title),
+        None => session.title.clone(),
+    }
+}
+
+/// `` `path` (+a −r) `` for each file, folding the tail into "and N more".
+fn file_list(files: &[FileStat]) -> String {
+    let mut list: Vec<String> = files
+        .iter()
+        .take(MAX_FILES_PER_SESSION)
+        .map(|file| format!("`{}` (+{} −{})", file.path, file.added, file.removed))
+        .collect();
+    if files.len() > MAX_FILES_PER_SESSION {
+        list.push(format!("and {} more", files.len() - MAX_FILES_PER_SESSION));
+    }
+    list.join(", ")
+}
+
+fn render_markdown(range: &str, sessions: &[DigestSession]) -> String {
+    let mut out = format!("# What Codex did ({range})\n\n");
+    if sessions.is_empty() {
+        out.push_str("No sessions in this range.\n");
+        return out;
+    }
+    let _ = writeln!(out, "{}", totals(sessions));
+    for session in sessions {
+        let _ = writeln!(out, "\n## {}\n", heading(session));
+        for bullet in &session.bullets {
+            let _ = writeln!(out, "- {bullet}");
+        }
+        if !session.files.is_empty() {
+            let _ = writeln!(out, "\nFiles: {}", file_list(&session.files));
+        }
+    }
+    out
+}
+
+fn render_slack(range: &str, sessions: &[DigestSession]) -> String {
+    let mut out = format!("*What Codex did* ({range})\n");
+    if sessions.is_empty() {
+        out.push_str("No sessions in this range.\n");
+        return out;
+    }
+    let _ = writeln!(out, "_{}_", totals(sessions));
+    for session in sessions {
+        let _ = writeln!(out, "\n*{}*", heading(session));
+        for bullet in &session.bullets {
+            let _ = writeln!(out, "• {bullet}");
+        }
+        if !session.files.is_empty() {
+            let _ = writeln!(out, "_Files:_ {}", file_list(&session.files));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+
+    fn sessions() -> Vec<DigestSession> {
+        vec![
+            DigestSession {
+                title: "Fix the flaky login test".to_string(),
+                project: Some("webapp".to_string()),
+                bullets: vec![
+                    "Found a race in the session cookie setup".to_string(),
+                    "Added a retry-free wait in `login.spec.ts`".to_string(),
+                ],
+                files: vec![FileStat {
+                    path: "tests/login.spec.ts".to_string(),
+                    added: 4,
+                    removed: 2,
+                }],
+            },
+            DigestSession {
+                title: "explain the build".to_string(),
+                project: None,
+                bullets: vec!["Asked: explain the build".to_string()],
+                files: Vec::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn file_stats_count_added_and_removed_lines() {
+        let log = "*** Add File\n+a\n+b\n@@ fn main\n-old\n+new\n";
+        assert_eq!(
+            file_stat(Path::new("src/main.rs"), log),
+            FileStat {
+                path: "src/main.rs".to_string(),
+                added: 3,
+                removed: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_named_days_dates_and_ages() {
+        let now = Local
+            .with_ymd_and_hms(2025, 3, 12, 15, 30, 0)
+            .unwrap()
+            .with_timezone(&Utc);
+        let local_midnight = |day| {
+            Local
+                .with_ymd_and_hms(2025, 3, day, 0, 0, 0)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        assert_eq!(
+            parse_when("yesterday", now, false),
+            Some(local_midnight(11))
+        );
+        assert_eq!(parse_when("yesterday", now, true), Some(local_midnight(12)));
+        assert_eq!(parse_when("today", now, false), Some(local_midnight(12)));
+        assert_eq!(
+            parse_when("2025-03-10", now, true),
+            Some(local_midnight(11))
+        );
+        assert_eq!(
+            parse_when("12h", now, false),
+            Some(now - chrono::Duration::hours(12))
+        );
+        assert_eq!(parse_when("last week", now, false), None);
+    }
+
+    #[test]
+    fn renders_markdown_and_slack() {
+        let range = "2025-03-11 00:00 – 2025-03-12 09:00";
+        assert_eq!(
+            render_markdown(range, &sessions()),
+            "# What Codex did (2025-03-11 00:00 – 2025-03-12 09:00)\n\
+             \n\
+             2 session(s) · 1 file(s) changed · +4 −2\n\
+             \n\
+             ## Fix the flaky login test (webapp)\n\
+             \n\
+             - Found a race in the session cookie setup\n\
+             - Added a retry-free wait in `login.spec.ts`\n\
+             \n\
+             Files: `tests/login.spec.ts` (+4 −2)\n\
+             \n\
+             ## explain the build\n\
+             \n\
+             - Asked: explain the build\n"
+        );
+        assert_eq!(
+            render_slack(range, &sessions()),
+            "*What Codex did* (2025-03-11 00:00 – 2025-03-12 09:00)\n\
+             _2 session(s) · 1 file(s) changed · +4 −2_\n\
+             \n\
+             *Fix the flaky login test (webapp)*\n\
+             • Found a race in the session cookie setup\n\
+             • Added a retry-free wait in `login.spec.ts`\n\
+             _Files:_ `tests/login.spec.ts` (+4 −2)\n\
+             \n\
+             *explain the build*\n\
+             • Asked: explain the build\n"
+        );
+        assert_eq!(
+            render_markdown(range, &[]),
+            "# What Codex did (2025-03-11 00:00 – 2025-03-12 09:00)\n\nNo sessions in this range.\n"
+        );
+    }
+}
@@ -0,0 +1,597 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use codex_ansi_escape::collapse_carriage_returns;
+use codex_ansi_escape::strip_ansi_escapes;
+use codex_common::CliConfigOverrides;
+use codex_core::RolloutRecorder;
+use codex_core::ThreadSortKey;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use codex_core::config::types::TranscriptDensity;
+use codex_core::find_thread_path_by_id_str;
+use codex_core::find_thread_path_by_name_str;
+use codex_protocol::ThreadId;
+use codex_protocol::items::WebCitation;
+use codex_protocol::models::FunctionCallOutputPayload;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::SessionMetaLine;
+
+const BUNDLE_TRANSCRIPT_FILENAME: &str = "index.html";
+const BUNDLE_ROLLOUT_FILENAME: &str = "session.jsonl";
+
+const STYLE: &str = "body{font-family:-apple-system,BlinkMacSystemFont,\"Segoe UI\",sans-serif;max-width:960px;margin:2rem auto;padding:0 1rem;color:#1f2328;background:#fff}
+header{border-bottom:1px solid #d0d7de;margin-bottom:1.5rem}
+header dl{display:grid;grid-template-columns:max-content 1fr;gap:.25rem 1rem;font-size:.9rem}
+header dt{color:#59636e}
+.message{margin:1rem 0;padding:.75rem 1rem;border-radius:6px}
+.message .role{font-weight:600;font-size:.8rem;text-transform:uppercase;color:#59636e}
+.user{background:#ddf4ff}
+.assistant{background:#f6f8fa}
+.session-note{background:#fff8c5}
+.reasoning{background:#fbefff;font-style:italic}
+.note{color:#59636e;font-style:italic}
+pre{white-space:pre-wrap;word-break:break-word;font-size:.85rem;margin:.5rem 0}
+details{margin:.5rem 0;border:1px solid #d0d7de;border-radius:6px;padding:.25rem .75rem}
+summary{cursor:pointer;font-family:ui-monospace,SFMono-Regular,Menlo,monospace;font-size:.85rem}
+.diff .add{background:#dafbe1;display:block}
+.diff .del{background:#ffebe9;display:block}
+.diff .hunk{color:#8250df;display:block}";
+
+/// Writes a recorded session as a self-contained HTML transcript.
+#[derive(Debug, clap::Parser)]
+pub struct ExportCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    /// Session id (UUID) or thread name to export.
+    #[arg(value_name = "SESSION_ID", required_unless_present = "last")]
+    pub session_id: Option<String>,
+
+    /// Export the most recent session recorded in the current directory.
+    #[arg(long, conflicts_with = "session_id")]
+    pub last: bool,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = ExportFormat::Html)]
+    pub format: ExportFormat,
+
+    /// Write a folder holding the transcript and the raw session log instead of a single file.
+    #[arg(long)]
+    pub bundle: bool,
+
+    /// How much detail to include: compact, normal, or verbose. Defaults to
+    /// `tui.density`.
+    #[arg(long, value_name = "DENSITY")]
+    pub density: Option<TranscriptDensity>,
+
+    /// Destination file, or folder with --bundle. Defaults to `codex-session-<id>` in the current directory.
+    #[arg(long, short = 'o', value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Html,
+}
+
+impl ExportCli {
+    pub async fn run(self) -> Result<()> {
+        let overrides = self
+            .config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides_and_harness_overrides(
+            overrides,
+            ConfigOverrides::default(),
+        )
+        .await
+        .context("failed to load configuration")?;
+
+        let rollout_path = match self.session_id.as_deref() {
+            Some(id) => find_session(&config, id).await?,
+            None => RolloutRecorder::find_latest_thread_path(
+                &config,
+                1,
+                None,
+                ThreadSortKey::UpdatedAt,
+                &[],
+                None,
+                &config.model_provider_id,
+                Some(config.cwd.as_path()),
+            )
+            .await?
+            .context("no recorded session found for the current directory")?,
+        };
+
+        let history = RolloutRecorder::get_rollout_history(&rollout_path)
+            .await
+            .with_context(|| format!("failed to read {}", rollout_path.display()))?;
+        let items = history.get_rollout_items();
+        let html = match self.format {
+            ExportFormat::Html => render_html(&items, self.density.unwrap_or(config.tui_density)),
+        };
+
+        let stem = format!("codex-session-{}", session_id_of(&items, &rollout_path));
+        let written = if self.bundle {
+            let dir = self.output.unwrap_or_else(|| PathBuf::from(stem));
+            std::fs::create_dir_all(&dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+            std::fs::write(dir.join(BUNDLE_TRANSCRIPT_FILENAME), html)?;
+            std::fs::copy(&rollout_path, dir.join(BUNDLE_ROLLOUT_FILENAME))?;
+            dir
+        } else {
+            let file = self
+                .output
+                .unwrap_or_else(|| PathBuf::from(format!("{stem}.html")));
+            std::fs::write(&file, html)
+                .with_context(|| format!("failed to write {}", file.display()))?;
+            file
+        };
+        println!("Exported session to {}", written.display());
+        Ok(())
+    }
+}
+
+/// Resolves a session id or thread name to its rollout file.
+pub(crate) async fn find_session(config: &Config, id: &str) -> Result<PathBuf> {
+    let path = if ThreadId::from_string(id).is_ok() {
+        find_thread_path_by_id_str(&config.codex_home, id).await?
+    } else {
+        find_thread_path_by_name_str(&config.codex_home, id).await?
+    };
+    path.with_context(|| format!("no recorded session matches `{id}`"))
+}
+
+fn session_id_of(items: &[RolloutItem], rollout_path: &Path) -> String {
+    items
+        .iter()
+        .find_map(|item| match item {
+            RolloutItem::SessionMeta(meta) => Some(meta.meta.id.to_string()),
+            _ => None,
+        })
+        .unwrap_or_else(|| {
+            rollout_path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        })
+}
+
+/// Renders the transcript: user and assistant messages, session notes and
+/// each turn's web sources in order, with each tool call folded into a
+/// `<details>` block next to its output.
+/// `apply_patch` inputs are shown as colored diffs.
+///
+/// `density` mirrors the TUI: compact reduces each tool call to one line with
+/// its output size, verbose adds the reasoning summaries and unfolds every
+/// tool call.
+fn render_html(items: &[RolloutItem], density: TranscriptDensity) -> String {
+    let mut outputs: HashMap<&str, String> = HashMap::new();
+    for item in items {
+        match item {
+            RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput { call_id, output }) => {
+                outputs.insert(call_id, plain_output(&function_output_text(output)));
+            }
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCallOutput { call_id, output }) => {
+                outputs.insert(call_id, plain_output(output));
+            }
+            _ => {}
+        }
+    }
+
+    let meta = items.iter().find_map(|item| match item {
+        RolloutItem::SessionMeta(meta) => Some(meta),
+        _ => None,
+    });
+    let title = meta.map_or_else(
+        || "Codex session".to_string(),
+        |meta| format!("Codex session {}", meta.meta.id),
+    );
+
+    let mut body = String::new();
+    if let Some(meta) = meta {
+        render_header(&mut body, meta);
+    }
+    for item in items {
+        match item {
+            RolloutItem::EventMsg(EventMsg::UserMessage(event)) => {
+                render_message(&mut body, "user", "You", &event.message);
+            }
+            RolloutItem::EventMsg(EventMsg::AgentMessage(event)) => {
+                render_message(&mut body, "assistant", "Codex", &event.message);
+            }
+            RolloutItem::EventMsg(EventMsg::SessionNote(event)) => {
+                render_message(&mut body, "session-note", "Note", &event.text);
+            }
+            RolloutItem::EventMsg(EventMsg::AgentReasoning(event))
+                if density == TranscriptDensity::Verbose =>
+            {
+                render_message(&mut body, "reasoning", "Reasoning", &event.text);
+            }
+            RolloutItem::EventMsg(EventMsg::TurnSources(event)) => {
+                render_sources(&mut body, &event.citations);
+            }
+            RolloutItem::EventMsg(EventMsg::ContextCompacted(_)) => {
+                body.push_str("<p class=\"note\">Context compacted.</p>\n");
+            }
+            RolloutItem::EventMsg(EventMsg::TurnAborted(_)) => {
+                body.push_str("<p class=\"note\">Turn interrupted.</p>\n");
+            }
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                name,
+                arguments,
+                call_id,
+                ..
+            }) => {
+                render_tool_call(
+                    &mut body,
+                    density,
+                    name,
+                    &pretty_arguments(arguments),
+                    outputs.get(call_id.as_str()),
+                );
+            }
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                name,
+                input,
+                call_id,
+                ..
+            }) if name == "apply_patch" => {
+                let open = if density == TranscriptDensity::Compact {
+                    ""
+                } else {
+                    " open"
+                };
+                let _ = writeln!(
+                    body,
+                    "<details{open}><summary>{}</summary>\n<pre class=\"diff\">{}</pre>",
+                    escape(name),
+                    render_diff(input)
+                );
+                if let Some(output) = outputs.get(call_id.as_str()) {
+                    let _ = writeln!(body, "<pre>{}</pre>", escape(output));
+                }
+                body.push_str("</details>\n");
+            }
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                name,
+                input,
+                call_id,
+                ..
+            }) => {
+                render_tool_call(
+                    &mut body,
+                    density,
+                    name,
+                    input,
+                    outputs.get(call_id.as_str()),
+                );
+            }
+            RolloutItem::ResponseItem(ResponseItem::LocalShellCall {
+                call_id,
+                action: LocalShellAction::Exec(exec),
+                ..
+            }) => {
+                let output = call_id.as_deref().and_then(|call_id| outputs.get(call_id));
+                render_tool_call(&mut body, density, "shell", &exec.command.join(" "), output);
+            }
+            _ => {}
+        }
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        escape(&title)
+    )
+}
+
+fn render_header(body: &mut String, meta: &SessionMetaLine) {
+    let _ = writeln!(
+        body,
+        "<header>\n<h1>Codex session</h1>\n<dl>\n<dt>Session</dt><dd>{}</dd>\n<dt>Started</dt><dd>{}</dd>\n<dt>Directory</dt><dd>{}</dd>",
+        escape(&meta.meta.id.to_string()),
+        escape(&meta.meta.timestamp),
+        escape(&meta.meta.cwd.display().to_string())
+    );
+    if let Some(branch) = meta.git.as_ref().and_then(|git| git.branch.as_deref()) {
+        let _ = writeln!(body, "<dt>Branch</dt><dd>{}</dd>", escape(branch));
+    }
+    body.push_str("</dl>\n</header>\n");
+}
+
+fn render_message(body: &mut String, class: &str, role: &str, text: &str) {
+    let _ = writeln!(
+        body,
+        "<section class=\"message {class}\"><div class=\"role\">{role}</div><pre>{}</pre></section>",
+        escape(text)
+    );
+}
+
+fn render_tool_call(
+    body: &mut String,
+    density: TranscriptDensity,
+    name: &str,
+    input: &str,
+    output: Option<&String>,
+) {
+    let open = match density {
+        TranscriptDensity::Compact => {
+            let count = output.map_or(0, |output| output.lines().count());
+            let _ = writeln!(
+                body,
+                "<p class=\"note\">{} · {count} lines of output</p>",
+                escape(name)
+            );
+            return;
+        }
+        TranscriptDensity::Normal => "",
+        TranscriptDensity::Verbose => " open",
+    };
+    let _ = writeln!(
+        body,
+        "<details{open}><summary>{}</summary>\n<pre>{}</pre>",
+        escape(name),
+        escape(input)
+    );
+    if let Some(output) = output {
+        let _ = writeln!(body, "<pre>{}</pre>", escape(output));
+    }
+    body.push_str("</details>\n");
+}
+
+fn render_sources(body: &mut String, citations: &[WebCitation]) {
+    let _ = writeln!(
+        body,
+        "<details class=\"sources\"><summary>Sources ({})</summary>\n<ol>",
+        citations.len()
+    );
+    for citation in citations {
+        let url = escape(&citation.url);
+        let title = citation.title.as_deref().map(escape);
+        if is_web_url(&citation.url) {
+            let label = title.unwrap_or_else(|| url.clone());
+            let _ = writeln!(body, "<li><a href=\"{url}\">{label}</a></li>");
+        } else {
+            let label = title.map_or_else(|| url.clone(), |title| format!("{title} ({url})"));
+            let _ = writeln!(body, "<li>{label}</li>");
+        }
+    }
+    body.push_str("</ol>\n</details>\n");
+}
+
+/// Only web links become anchors: a `javascript:` citation would run when the
+/// reader clicks it.
+fn is_web_url(url: &str) -> bool {
+    url.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+    })
+}
+
+/// Tool output as a reader saw it in the terminal: progress-bar rewrites
+/// collapsed and escape sequences removed, so colors don't leak into the HTML
+/// as raw `ESC[` noise.
+fn plain_output(text: &str) -> String {
+    strip_ansi_escapes(&collapse_carriage_returns(text)).into_owned()
+}
+
+fn render_diff(patch: &str) -> String {
+    patch
+        .lines()
+        .map(|line| {
+            let class = if line.starts_with("@@") || line.starts_with("*** ") {
+                Some("hunk")
+            } else if line.starts_with('+') {
+                Some("add")
+            } else if line.starts_with('-') {
+                Some("del")
+            } else {
+                None
+            };
+            match class {
+                Some(class) => format!("<span class=\"{class}\">{}</span>", escape(line)),
+                None => format!("{}\n", escape(line)),
+            }
+        })
+        .collect()
+}
+
+fn pretty_arguments(arguments: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(arguments)
+        .and_then(|value| serde_json::to_string_pretty(&value))
+        .unwrap_or_else(|_| arguments.to_string())
+}
+
+fn function_output_text(output: &FunctionCallOutputPayload) -> String {
+    output.text_content().map_or_else(
+        || {
+            output
+                .content_items()
+                .map(|items| format!("[{} content items]", items.len()))
+                .unwrap_or_default()
+        },
+        ToString::to_string,
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::AgentMessageEvent;
+    use codex_protocol::protocol::AgentReasoningEvent;
+    use codex_protocol::protocol::SessionNoteEvent;
+    use codex_protocol::protocol::TurnSourcesEvent;
+    use codex_protocol::protocol::UserMessageEvent;
+    use pretty_assertions::assert_eq;
+
+    fn user_message(message: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+            message: message.to_string(),
+            images: None,
+            local_images: Vec::new(),
+            text_elements: Vec::new(),
+        }))
+    }
+
+    #[test]
+    fn renders_messages_and_folds_tool_calls() {
+        let items = vec![
+            user_message("fix <main>"),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: r#"{"command":["ls"]}"#.to_string(),
+                call_id: "call-1".to_string(),
+            }),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput {
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload::from_text("Cargo.toml".to_string()),
+            }),
+            RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+                message: "done".to_string(),
+            })),
+        ];
+
+        let html = render_html(&items, TranscriptDensity::Normal);
+
+        assert!(html.contains("<pre>fix &lt;main&gt;</pre>"));
+        assert!(html.contains("<details><summary>shell</summary>"));
+        assert!(html.contains("<pre>Cargo.toml</pre>"));
+        let user = html.find("fix &lt;main&gt;").expect("user message");
+        let agent = html.find("<pre>done</pre>").expect("agent message");
+        assert!(user < agent);
+    }
+
+    #[test]
+    fn density_controls_tool_calls_and_reasoning() {
+        let items = vec![
+            RolloutItem::EventMsg(EventMsg::AgentReasoning(AgentReasoningEvent {
+                text: "check the manifest".to_string(),
+            })),
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "call-1".to_string(),
+                name: "exec".to_string(),
+                input: "ls".to_string(),
+            }),
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCallOutput {
+                call_id: "call-1".to_string(),
+                output: "Cargo.toml\nsrc\n".to_string(),
+            }),
+        ];
+
+        let compact = render_html(&items, TranscriptDensity::Compact);
+        assert!(compact.contains("<p class=\"note\">exec · 2 lines of output</p>"));
+        assert!(!compact.contains("<pre>Cargo.toml"));
+        assert!(!compact.contains("check the manifest"));
+
+        let verbose = render_html(&items, TranscriptDensity::Verbose);
+        assert!(verbose.contains("<details open><summary>exec</summary>"));
+        assert!(verbose.contains("<pre>check the manifest</pre>"));
+    }
+
+    #[test]
+    fn tool_output_is_exported_without_escapes() {
+        let items = vec![
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "call-1".to_string(),
+                name: "exec".to_string(),
+                input: "cargo build".to_string(),
+            }),
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCallOutput {
+                call_id: "call-1".to_string(),
+                output: "\u{1b}[32mCompiling\u{1b}[0m a\n 10%\r100%\n".to_string(),
+            }),
+        ];
+
+        let html = render_html(&items, TranscriptDensity::Normal);
+
+        assert!(html.contains("<pre>Compiling a\n100%\n</pre>"), "{html}");
+    }
+
+    #[test]
+    fn session_notes_are_exported() {
+        let items = vec![
+            user_message("deploy"),
+            RolloutItem::EventMsg(EventMsg::SessionNote(SessionNoteEvent {
+                text: "staging only & no migrations".to_string(),
+                in_context: false,
+            })),
+        ];
+
+        let html = render_html(&items, TranscriptDensity::Normal);
+
+        assert!(html.contains(
+            "<section class=\"message session-note\"><div class=\"role\">Note</div><pre>staging only &amp; no migrations</pre></section>"
+        ));
+    }
+
+    #[test]
+    fn turn_sources_are_exported_as_links() {
+        let items = vec![RolloutItem::EventMsg(EventMsg::TurnSources(
+            TurnSourcesEvent {
+                citations: vec![
+                    WebCitation {
+                        url: "https://tokio.rs/?a=1&b=2".to_string(),
+                        title: Some("Tokio <runtime>".to_string()),
+                        snippet: None,
+                    },
+                    WebCitation {
+                        url: "https://docs.rs".to_string(),
+                        title: None,
+                        snippet: None,
+                    },
+                    WebCitation {
+                        url: "javascript:alert(1)".to_string(),
+                        title: Some("Click me".to_string()),
+                        snippet: None,
+                    },
+                    WebCitation {
+                        url: " JavaScript:alert(2)".to_string(),
+                        title: None,
+                        snippet: None,
+                    },
+                ],
+            },
+        ))];
+
+        let html = render_html(&items, TranscriptDensity::Normal);
+
+        assert!(html.contains(
+            "<details class=\"sources\"><summary>Sources (4)</summary>\n<ol>\n<li><a href=\"https://tokio.rs/?a=1&amp;b=2\">Tokio &lt;runtime&gt;</a></li>\n<li><a href=\"https://docs.rs\">https://docs.rs</a></li>\n<li>Click me (javascript:alert(1))</li>\n<li> JavaScript:alert(2)</li>\n</ol>\n</details>\n"
+        ), "{html}");
+    }
+
+    #[test]
+    fn patch_lines_are_classified() {
+        assert_eq!(
+            render_diff("*** Update File: a.rs\n@@\n-old\n+new\n ctx"),
+            "<span class=\"hunk\">*** Update File: a.rs</span><span class=\"hunk\">@@</span><span class=\"del\">-old</span><span class=\"add\">+new</span> ctx\n"
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_exec::ReviewPriority;
+
+/// First line after the shebang of every hook Codex writes, so `install-hooks`
+/// and `uninstall-hooks` only ever replace or remove their own files.
+const MANAGED_MARKER: &str = "# codex-managed-hook";
+/// Suffix for a hook that `install-hooks --force` moved out of the way.
+const BACKUP_SUFFIX: &str = "pre-codex";
+
+const PREPARE_COMMIT_MSG_TEMPLATE: &str = include_str!("git_hooks/prepare-commit-msg");
+const PRE_PUSH_TEMPLATE: &str = include_str!("git_hooks/pre-push");
+
+/// Subcommands:
+/// - `install-hooks` — draft commit messages and review pushes with Codex
+/// - `uninstall-hooks` — remove the hooks Codex installed
+#[derive(Debug, clap::Parser)]
+pub struct GitCli {
+    #[command(subcommand)]
+    pub subcommand: GitSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum GitSubcommand {
+    /// Install a prepare-commit-msg hook that drafts the commit message from
+    /// the staged diff, and optionally a pre-push review hook.
+    InstallHooks(InstallHooksArgs),
+    /// Remove the hooks installed by `install-hooks` and restore any hooks
+    /// they replaced.
+    UninstallHooks,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct InstallHooksArgs {
+    /// Also install a pre-push hook that runs `codex review` on the commits
+    /// being pushed and stops the push when it finds serious issues.
+    #[arg(long = "pre-push", default_value_t = false)]
+    pub pre_push: bool,
+
+    /// Least urgent finding priority that stops a push (`p0` is the most
+    /// urgent). `CODEX_PRE_PUSH_FAIL_ON` overrides it per push.
+    #[arg(
+        long = "fail-on",
+        value_enum,
+        value_name = "PRIORITY",
+        default_value = "p1",
+        ignore_case = true,
+        requires = "pre_push"
+    )]
+    pub fail_on: ReviewPriority,
+
+    /// Model used to draft commit messages; defaults to the configured model.
+    #[arg(long = "model", short = 'm')]
+    pub model: Option<String>,
+
+    /// Replace existing hooks that Codex did not install. They are kept
+    /// next to the new ones with a `.pre-codex` suffix.
+    #[arg(long = "force", default_value_t = false)]
+    pub force: bool,
+}
+
+impl GitCli {
+    pub fn run(self) -> Result<()> {
+        let hooks_dir = hooks_dir()?;
+        match self.subcommand {
+            GitSubcommand::InstallHooks(args) => run_install(&hooks_dir, &args),
+            GitSubcommand::UninstallHooks => run_uninstall(&hooks_dir),
+        }
+    }
+}
+
+/// The repository's hooks directory, honoring `core.hooksPath`.
+fn hooks_dir() -> Result<PathBuf> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--path-format=absolute", "--git-path", "hooks"])
+        .output()
+        .context("failed to run git")?;
+    if !output.status.success() {
+        bail!("not inside a git repository");
+    }
+    Ok(PathBuf::from(
+        String::from_utf8_lossy(&output.stdout).trim().to_string(),
+    ))
+}
+
+fn run_install(hooks_dir: &Path, args: &InstallHooksArgs) -> Result<()> {
+    let mut hooks = vec![(
+        "prepare-commit-msg",
+        prepare_commit_msg_hook(args.model.as_deref()),
+    )];
+    if args.pre_push {
+        hooks.push(("pre-push", pre_push_hook(args.fail_on)));
+    }
+    // Check every hook before writing any, so a conflict leaves nothing half done.
+    for (name, _) in &hooks {
+        let path = hooks_dir.join(name);
+        if path.exists() && !is_managed(&path) && !args.force {
+            bail!(
+                "{} already exists and was not installed by Codex; rerun with --force to replace it (it is kept as {name}.{BACKUP_SUFFIX})",
+                path.display()
+            );
+        }
+    }
+    std::fs::create_dir_all(hooks_dir)
+        .with_context(|| format!("failed to create {}", hooks_dir.display()))?;
+    for (name, script) in hooks {
+        let path = hooks_dir.join(name);
+        if path.exists() && !is_managed(&path) {
+            let backup = hooks_dir.join(format!("{name}.{BACKUP_SUFFIX}"));
+            std::fs::rename(&path, &backup)
+                .with_context(|| format!("failed to move {} aside", path.display()))?;
+            println!("Moved the existing {name} hook to {}", backup.display());
+        }
+        write_executable(&path, &script)?;
+        println!("Installed {}", path.display());
+    }
+    Ok(())
+}
+
+fn run_uninstall(hooks_dir: &Path) -> Result<()> {
+    let mut removed = 0;
+    for name in ["prepare-commit-msg", "pre-push"] {
+        let path = hooks_dir.join(name);
+        if !path.exists() || !is_managed(&path) {
+            continue;
+        }
+        std::fs::remove_file(&path)
+            .with_context(|| format!("failed to remove {}", path.display()))?;
+        removed += 1;
+        println!("Removed {}", path.display());
+        let backup = hooks_dir.join(format!("{name}.{BACKUP_SUFFIX}"));
+        if backup.exists() {
+            std::fs::rename(&backup, &path)
+                .with_context(|| format!("failed to restore {}", backup.display()))?;
+            println!("Restored the previous {name} hook");
+        }
+    }
+    if removed == 0 {
+        println!(
+            "No Codex git hooks are installed in {}",
+            hooks_dir.display()
+        );
+    }
+    Ok(())
+}
+
+fn is_managed(path: &Path) -> bool {
+    std::fs::read_to_string(path).is_ok_and(|script| {
+        script
+            .lines()
+            .nth(1)
+            .is_some_and(|line| line.starts_with(MANAGED_MARKER))
+    })
+}
+
+fn prepare_commit_msg_hook(model: Option<&str>) -> String {
+    let model_flag = model
+        .map(|model| {
+            let quoted = shlex::try_quote(model)
+                .map(std::borrow::Cow::into_owned)
+                .unwrap_or_else(|_| model.to_string());
+            format!("--model {quoted} ")
+        })
+        .unwrap_or_default();
+    PREPARE_COMMIT_MSG_TEMPLATE.replace("{{MODEL_FLAG}}", &model_flag)
+}
+
+fn pre_push_hook(fail_on: ReviewPriority) -> String {
+    PRE_PUSH_TEMPLATE.replace("{{FAIL_ON}}", &format!("p{}", fail_on.level()))
+}
+
+fn write_executable(path: &Path, script: &str) -> Result<()> {
+    std::fs::write(path, script).with_context(|| format!("failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+            .with_context(|| format!("failed to make {} executable", path.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn templates_are_filled_in() {
+        let hook = prepare_commit_msg_hook(Some("gpt-5.1-codex-mini"));
+        assert!(
+            hook.contains("-c 'model_reasoning_effort=\"low\"' --model gpt-5.1-codex-mini \\\n")
+        );
+        assert!(!prepare_commit_msg_hook(None).contains("{{"));
+
+        let hook = pre_push_hook(ReviewPriority::P2);
+        assert!(hook.contains("fail_on=\"${CODEX_PRE_PUSH_FAIL_ON:-p2}\""));
+        assert!(!hook.contains("{{"));
+    }
+
+    #[test]
+    fn install_keeps_foreign_hooks_unless_forced() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let foreign = dir.path().join("prepare-commit-msg");
+        std::fs::write(&foreign, "#!/bin/sh\necho mine\n").expect("write hook");
+        let mut args = InstallHooksArgs {
+            pre_push: true,
+            fail_on: ReviewPriority::P1,
+            model: None,
+            force: false,
+        };
+
+        assert!(run_install(dir.path(), &args).is_err());
+        assert!(!dir.path().join("pre-push").exists());
+
+        args.force = true;
+        run_install(dir.path(), &args).expect("forced install");
+        assert!(is_managed(&foreign));
+        assert!(is_managed(&dir.path().join("pre-push")));
+
+        run_uninstall(dir.path()).expect("uninstall");
+        assert_eq!(
+            std::fs::read_to_string(&foreign).expect("restored hook"),
+            "#!/bin/sh\necho mine\n"
+        );
+        assert!(!dir.path().join("pre-push").exists());
+    }
+}
@@ -50,3 +50,17 @@ pub struct WindowsCommand {
     #[arg(trailing_var_arg = true)]
     pub command: Vec<String>,
 }
+
+#[derive(Debug, Parser)]
+pub struct ExplainCommand {
+    /// Explain under the `--full-auto` sandbox instead of the configured one
+    #[arg(long = "full-auto", default_value_t = false)]
+    pub full_auto: bool,
+
+    #[clap(skip)]
+    pub config_overrides: CliConfigOverrides,
+
+    /// Full command args to analyze. Nothing is executed.
+    #[arg(trailing_var_arg = true, required = true)]
+    pub command: Vec<String>,
+}
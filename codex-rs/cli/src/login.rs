@@ -20,9 +20,14 @@ const API_KEY_LOGIN_DISABLED_MESSAGE: &str =
     "API key login is disabled. Use ChatGPT login instead.";
 const LOGIN_SUCCESS_MESSAGE: &str = "Successfully logged in";
 
-fn print_login_server_start(actual_port: u16, auth_url: &str) {
+fn print_login_server_start(actual_port: u16, auth_url: &str, open_browser: bool) {
+    let hint = if open_browser {
+        "If your browser did not open, navigate to this URL to authenticate:"
+    } else {
+        "Open this URL in a browser to authenticate:"
+    };
     eprintln!(
-        "Starting local login server on http://localhost:{actual_port}.\nIf your browser did not open, navigate to this URL to authenticate:\n\n{auth_url}"
+        "Starting local login server on http://localhost:{actual_port}.\n{hint}\n\n{auth_url}"
     );
 }
 
@@ -30,21 +35,27 @@ pub async fn login_with_chatgpt(
     codex_home: PathBuf,
     forced_chatgpt_workspace_id: Option<String>,
     cli_auth_credentials_store_mode: AuthCredentialsStoreMode,
+    open_browser: bool,
 ) -> std::io::Result<()> {
-    let opts = ServerOptions::new(
+    let mut opts = ServerOptions::new(
         codex_home,
         CLIENT_ID.to_string(),
         forced_chatgpt_workspace_id,
         cli_auth_credentials_store_mode,
     );
+    opts.open_browser = open_browser;
     let server = run_login_server(opts)?;
 
-    print_login_server_start(server.actual_port, &server.auth_url);
+    print_login_server_start(server.actual_port, &server.auth_url, open_browser);
 
     server.block_until_done().await
 }
 
-pub async fn run_login_with_chatgpt(cli_config_overrides: CliConfigOverrides) -> ! {
+/// `open_browser` is false for `codex login --no-browser`.
+pub async fn run_login_with_chatgpt(
+    cli_config_overrides: CliConfigOverrides,
+    open_browser: bool,
+) -> ! {
     let config = load_config_or_exit(cli_config_overrides).await;
 
     if matches!(config.forced_login_method, Some(ForcedLoginMethod::Api)) {
@@ -58,6 +69,7 @@ pub async fn run_login_with_chatgpt(cli_config_overrides: CliConfigOverrides) ->
         config.codex_home,
         forced_chatgpt_workspace_id,
         config.cli_auth_credentials_store_mode,
+        open_browser,
     )
     .await
     {
@@ -196,7 +208,7 @@ pub async fn run_login_with_device_code_fallback_to_browser(
                 eprintln!("Device code login is not enabled; falling back to browser login.");
                 match run_login_server(opts) {
                     Ok(server) => {
-                        print_login_server_start(server.actual_port, &server.auth_url);
+                        print_login_server_start(server.actual_port, &server.auth_url, false);
                         match server.block_until_done().await {
                             Ok(()) => {
                                 eprintln!("{LOGIN_SUCCESS_MESSAGE}");
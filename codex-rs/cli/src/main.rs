@@ -6,6 +6,7 @@ use clap_complete::generate;
 use codex_arg0::arg0_dispatch_or_else;
 use codex_chatgpt::apply_command::ApplyCommand;
 use codex_chatgpt::apply_command::run_apply_command;
+use codex_cli::ExplainCommand;
 use codex_cli::LandlockCommand;
 use codex_cli::SeatbeltCommand;
 use codex_cli::WindowsCommand;
@@ -22,6 +23,7 @@ use codex_exec::Command as ExecCommand;
 use codex_exec::ReviewArgs;
 use codex_execpolicy::ExecPolicyCheckCommand;
 use codex_responses_api_proxy::Args as ResponsesApiProxyArgs;
+use codex_serve::ServeCli;
 use codex_tui::AppExitInfo;
 use codex_tui::Cli as TuiCli;
 use codex_tui::ExitReason;
@@ -29,31 +31,62 @@ use codex_tui::update_action::UpdateAction;
 use codex_tui2 as tui2;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use owo_colors::OwoColorize;
-use std::io::IsTerminal;
 use std::path::PathBuf;
 use supports_color::Stream;
 
 #[cfg(target_os = "macos")]
 mod app_cmd;
+mod commit_cmd;
+mod config_cmd;
 #[cfg(target_os = "macos")]
 mod desktop_app;
+mod digest_cmd;
+mod export_cmd;
+mod git_cmd;
 mod mcp_cmd;
+mod profiles_cmd;
+mod schedule_cmd;
+mod sessions_cmd;
+mod shell_integration;
+mod skills_cmd;
+mod status_cmd;
+mod trust_cmd;
 #[cfg(not(windows))]
 mod wsl_paths;
 
+use crate::commit_cmd::CommitCli;
+use crate::config_cmd::ConfigCli;
+use crate::digest_cmd::DigestCli;
+use crate::export_cmd::ExportCli;
+use crate::git_cmd::GitCli;
 use crate::mcp_cmd::McpCli;
-
+use crate::profiles_cmd::ProfilesCli;
+use crate::schedule_cmd::ScheduleCli;
+use crate::sessions_cmd::SessionsCli;
+use crate::shell_integration::LastCommand;
+use crate::shell_integration::LastCommandAction;
+use crate::shell_integration::ShellInitCommand;
+use crate::skills_cmd::SkillsCli;
+use crate::status_cmd::StatusCli;
+use crate::trust_cmd::TrustCli;
+
+use codex_core::base_instructions::preview_base_instructions;
 use codex_core::config::Config;
 use codex_core::config::ConfigOverrides;
 use codex_core::config::edit::ConfigEditsBuilder;
 use codex_core::config::find_codex_home;
 use codex_core::config::load_config_as_toml_with_cli_overrides;
+use codex_core::config::types::TuiFrontend;
 use codex_core::features::Feature;
+use codex_core::features::FeatureOrigin;
 use codex_core::features::FeatureOverrides;
 use codex_core::features::Features;
 use codex_core::features::Stage;
+use codex_core::features::feature_origin;
 use codex_core::features::is_known_feature_key;
 use codex_core::terminal::TerminalName;
+use codex_core::terminal::no_color_requested;
+use codex_protocol::ThreadId;
 
 /// Codex 命令行工具
 ///
@@ -77,6 +110,9 @@ struct MultitoolCli {
     #[clap(flatten)]
     pub feature_toggles: FeatureToggles,
 
+    #[clap(flatten)]
+    pub tui_frontend_flags: TuiFrontendFlags,
+
     #[clap(flatten)]
     interactive: TuiCli,
 
@@ -105,6 +141,12 @@ enum Subcommand {
     /// 【实验特性】运行 Codex MCP 服务器（stdio 传输）。
     McpServer,
 
+    /// 创建与管理技能（Skills）。
+    Skills(SkillsCli),
+
+    /// 查看当前目录下生效的配置及每项的来源。
+    Config(ConfigCli),
+
     /// 【实验特性】运行应用服务器或相关工具。
     AppServer(AppServerCommand),
 
@@ -115,6 +157,16 @@ enum Subcommand {
     /// 生成 shell 补全脚本。
     Completion(CompletionCommand),
 
+    /// 输出 bash/zsh/fish 钩子脚本，记录上一条命令及其退出码，供 `codex fix` / `codex explain` 使用。
+    /// 例如在 ~/.zshrc 中加入 `eval "$(codex shell-init zsh)"`。
+    ShellInit(ShellInitCommand),
+
+    /// 诊断上一条失败的命令，给出修正后的命令并经审批运行（需启用 `codex shell-init` 钩子）。
+    Fix(LastCommandCli),
+
+    /// 解释上一条命令失败的原因及修复方法，不修改文件（需启用 `codex shell-init` 钩子）。
+    Explain(LastCommandCli),
+
     /// 在 Codex 提供的沙箱中运行命令。
     Sandbox(SandboxArgs),
 
@@ -135,6 +187,36 @@ enum Subcommand {
     /// 分叉此前的交互式会话（默认显示列表，使用 --last 选择最近一次）。
     Fork(ForkCommand),
 
+    /// 将已记录的会话导出为独立的 HTML 文件，便于附在 PR 中或分享给他人。
+    Export(ExportCli),
+
+    /// 管理已记录的会话：导出/导入会话包、查看分叉、对比改动、导出操作审计日志，列出、归档和恢复会话，以及跨设备同步。
+    Sessions(SessionsCli),
+
+    /// 汇总一段时间内（默认自昨天起）各会话的摘要与改动统计，生成 Markdown 或 Slack 格式的工作简报。
+    Digest(DigestCli),
+
+    /// 查看或撤销目录的信任设置（trusted / read-only / untrusted）。
+    Trust(TrustCli),
+
+    /// 管理配置 profile（`[profiles.*]`）：列出、创建、复制、删除及设置默认 profile。
+    Profiles(ProfilesCli),
+
+    /// 显示正在运行的交互式会话的状态（模型、运行/空闲、已用 token、分支）；`--porcelain` 便于 tmux 状态栏与 shell 提示符读取。
+    Status(StatusCli),
+
+    /// 安装或移除 Codex 的 git 钩子：根据暂存的改动起草提交信息，并可在推送前运行代码审查。
+    Git(GitCli),
+
+    /// 根据暂存的改动起草 Conventional Commits 风格的提交信息，可接受、编辑或重新生成后直接提交，无需进入 TUI。
+    Commit(CommitCli),
+
+    /// 按 cron 计划定时以非交互模式运行提示词（配合 systemd/launchd 每分钟执行 `codex schedule run`）。
+    Schedule(ScheduleCli),
+
+    /// 【实验特性】启动本地 HTTP API（REST + SSE），供脚本发起 exec 运行并查询会话。
+    Serve(ServeCli),
+
     /// 【实验特性】浏览 Codex Cloud 任务并在本地应用更改。
     #[clap(name = "cloud", alias = "cloud-tasks")]
     Cloud(CloudTasksCli),
@@ -143,7 +225,7 @@ enum Subcommand {
     #[clap(hide = true)]
     ResponsesApiProxy(ResponsesApiProxyArgs),
 
-    /// 内部用途：将 stdio 转发至 Unix 域套接字。
+    /// 内部用途：将 stdio 转发至 Unix 域套接字、TCP 端口或 Windows 命名管道。
     #[clap(hide = true, name = "stdio-to-uds")]
     StdioToUds(StdioToUdsCommand),
 
@@ -151,6 +233,20 @@ enum Subcommand {
     Features(FeaturesCli),
 }
 
+#[derive(Debug, Parser)]
+struct LastCommandCli {
+    /// 由 `codex shell-init` 钩子传入的上一条命令。
+    #[arg(long = "last-command", hide = true, value_name = "COMMAND")]
+    last_command: Option<String>,
+
+    /// 由 `codex shell-init` 钩子传入的上一条命令的退出码。
+    #[arg(long = "last-status", hide = true, value_name = "STATUS")]
+    last_status: Option<String>,
+
+    #[clap(flatten)]
+    config_overrides: TuiCli,
+}
+
 #[derive(Debug, Parser)]
 struct CompletionCommand {
     /// 指定要生成补全脚本的 shell。
@@ -168,6 +264,38 @@ struct DebugCommand {
 enum DebugSubcommand {
     /// Tooling: helps debug the app server.
     AppServer(DebugAppServerCommand),
+
+    /// Tooling: checks translation catalogs and `$CODEX_HOME/locales` overrides.
+    I18n(DebugI18nCommand),
+
+    /// Print the base instructions a new session would send, after layering.
+    Prompt(DebugPromptCommand),
+}
+
+#[derive(Debug, Parser)]
+struct DebugPromptCommand {
+    /// Profile from config.toml whose `additional_instructions` to include.
+    #[arg(long = "profile", short = 'p')]
+    config_profile: Option<String>,
+
+    /// Show each layer under a header naming where it came from.
+    #[arg(long, default_value_t = false)]
+    layers: bool,
+
+    #[clap(skip)]
+    config_overrides: CliConfigOverrides,
+}
+
+#[derive(Debug, Parser)]
+struct DebugI18nCommand {
+    #[command(subcommand)]
+    subcommand: DebugI18nSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum DebugI18nSubcommand {
+    /// Report unknown keys and placeholder mismatches against the English catalog.
+    Lint,
 }
 
 #[derive(Debug, Parser)]
@@ -203,6 +331,10 @@ struct ResumeCommand {
     #[arg(long = "all", default_value_t = false)]
     all: bool,
 
+    /// 即使会话正被另一个进程使用也继续恢复；原进程将停止写入该会话。
+    #[arg(long = "force-takeover", default_value_t = false)]
+    force_takeover: bool,
+
     #[clap(flatten)]
     config_overrides: TuiCli,
 }
@@ -244,6 +376,9 @@ enum SandboxCommand {
 
     /// 在受限令牌下运行命令（仅限 Windows）。
     Windows(WindowsCommand),
+
+    /// 不执行命令，说明当前沙箱与 execpolicy 会如何处理它（各平台后端）。
+    Explain(ExplainCommand),
 }
 
 #[derive(Debug, Parser)]
@@ -281,6 +416,10 @@ struct LoginCommand {
     #[arg(long = "device-auth")]
     use_device_code: bool,
 
+    /// 不自动打开浏览器，只打印登录链接（适用于无图形界面的服务器）。
+    #[arg(long = "no-browser")]
+    no_browser: bool,
+
     /// 【实验特性】自定义 OAuth issuer 基础 URL（高级）。
     /// 覆写 OAuth issuer 基础 URL（高级）。
     #[arg(long = "experimental_issuer", value_name = "URL", hide = true)]
@@ -329,6 +468,18 @@ struct AppServerCommand {
     /// See https://developers.openai.com/codex/config-advanced/#metrics for more details.
     #[arg(long = "analytics-default-enabled")]
     analytics_default_enabled: bool,
+
+    /// Seconds to keep running turns alive after the client disconnects.
+    ///
+    /// With the default of 0 the server exits as soon as stdin closes. Otherwise
+    /// pending approvals are declined and running turns get up to this long to
+    /// finish, so a restarted client can pick them up with `thread/reattach`.
+    #[arg(
+        long = "detach-grace-seconds",
+        value_name = "SECONDS",
+        default_value_t = 0
+    )]
+    detach_grace_seconds: u64,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -368,9 +519,17 @@ struct GenerateJsonSchemaCommand {
 
 #[derive(Debug, Parser)]
 struct StdioToUdsCommand {
-    /// 要连接的 Unix 域套接字路径。
-    #[arg(value_name = "SOCKET_PATH")]
-    socket_path: PathBuf,
+    /// 连接目标：Unix 域套接字路径、`tcp://HOST:PORT` 或 `\\.\pipe\NAME`。
+    #[arg(value_name = "TARGET")]
+    target: codex_stdio_to_uds::Target,
+
+    /// 对端在 stdin 关闭前断开时自动重连，并重发尚未送达的数据。
+    #[arg(long)]
+    reconnect: bool,
+
+    /// 连接（及每次重连）最多等待的秒数，超时即退出。
+    #[arg(long, value_name = "SECONDS")]
+    timeout: Option<u64>,
 }
 
 fn format_exit_messages(exit_info: AppExitInfo, color_enabled: bool) -> Vec<String> {
@@ -378,6 +537,7 @@ fn format_exit_messages(exit_info: AppExitInfo, color_enabled: bool) -> Vec<Stri
         token_usage,
         thread_id: conversation_id,
         thread_name,
+        exit_summary,
         ..
     } = exit_info;
 
@@ -390,6 +550,11 @@ fn format_exit_messages(exit_info: AppExitInfo, color_enabled: bool) -> Vec<Stri
         codex_core::protocol::FinalOutput::from(token_usage)
     )];
 
+    if !exit_summary.is_empty() {
+        lines.push("本次会话摘要：".to_string());
+        lines.extend(exit_summary.iter().map(|bullet| format!("  • {bullet}")));
+    }
+
     if let Some(resume_cmd) =
         codex_core::util::resume_command(thread_name.as_deref(), conversation_id)
     {
@@ -411,11 +576,11 @@ fn handle_app_exit(exit_info: AppExitInfo) -> anyhow::Result<()> {
             eprintln!("ERROR: {message}");
             std::process::exit(1);
         }
-        ExitReason::UserRequested => { /* normal exit */ }
+        ExitReason::UserRequested | ExitReason::SwitchFrontend => { /* normal exit */ }
     }
 
     let update_action = exit_info.update_action;
-    let color_enabled = supports_color::on(Stream::Stdout).is_some();
+    let color_enabled = !no_color_requested() && supports_color::on(Stream::Stdout).is_some();
     for line in format_exit_messages(exit_info, color_enabled) {
         println!("{line}");
     }
@@ -453,6 +618,53 @@ fn run_debug_app_server_command(cmd: DebugAppServerCommand) -> anyhow::Result<()
     }
 }
 
+async fn run_debug_prompt_command(cmd: DebugPromptCommand) -> anyhow::Result<()> {
+    let DebugPromptCommand {
+        config_profile,
+        layers,
+        config_overrides,
+    } = cmd;
+    let config = Config::load_with_cli_overrides_and_harness_overrides(
+        config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?,
+        ConfigOverrides {
+            config_profile,
+            ..Default::default()
+        },
+    )
+    .await?;
+    let preview = preview_base_instructions(&config).await;
+
+    if !layers {
+        println!("{}", preview.text);
+        return Ok(());
+    }
+    println!("# model: {}", preview.model);
+    println!("\n# 1. {}\n\n{}", preview.base_source, preview.base);
+    for (index, layer) in preview.layers.iter().enumerate() {
+        println!("\n# {}. {}\n\n{}", index + 2, layer.source, layer.text);
+    }
+    Ok(())
+}
+
+fn run_debug_i18n_command(cmd: DebugI18nCommand) -> anyhow::Result<()> {
+    match cmd.subcommand {
+        DebugI18nSubcommand::Lint => {
+            let codex_home = find_codex_home()?;
+            let issues = codex_core::i18n::lint_locales(&codex_home);
+            if issues.is_empty() {
+                println!("No locale issues found.");
+                return Ok(());
+            }
+            for issue in &issues {
+                println!("{issue}");
+            }
+            anyhow::bail!("found {} locale issue(s)", issues.len());
+        }
+    }
+}
+
 #[derive(Debug, Default, Parser, Clone)]
 struct FeatureToggles {
     /// 启用指定功能（可重复）。等价于 `-c features.<name>=true`。
@@ -487,6 +699,29 @@ impl FeatureToggles {
     }
 }
 
+#[derive(Debug, Default, Parser, Clone)]
+struct TuiFrontendFlags {
+    /// 本次使用 TUI1（经典界面）启动，忽略 `tui_frontend` 配置与 tui2 功能开关。
+    #[arg(long = "tui1", global = true, conflicts_with = "tui2")]
+    tui1: bool,
+
+    /// 本次使用 TUI2 启动，忽略 `tui_frontend` 配置与 tui2 功能开关。
+    #[arg(long = "tui2", global = true)]
+    tui2: bool,
+}
+
+impl TuiFrontendFlags {
+    fn selected(&self) -> Option<TuiFrontend> {
+        if self.tui1 {
+            Some(TuiFrontend::Tui1)
+        } else if self.tui2 {
+            Some(TuiFrontend::Tui2)
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Debug, Parser)]
 struct FeaturesCli {
     #[command(subcommand)]
@@ -495,8 +730,10 @@ struct FeaturesCli {
 
 #[derive(Debug, Parser)]
 enum FeaturesSubcommand {
-    /// 列出所有功能及其阶段与当前状态。
+    /// 列出所有功能及其阶段、当前状态与取值来源。
     List,
+    /// 显示功能的说明、依赖与风险。
+    Describe(FeatureSetArgs),
     /// Enable a feature in config.toml.
     Enable(FeatureSetArgs),
     /// Disable a feature in config.toml.
@@ -520,6 +757,18 @@ fn stage_str(stage: codex_core::features::Stage) -> &'static str {
     }
 }
 
+fn origin_str(origin: FeatureOrigin) -> &'static str {
+    match origin {
+        FeatureOrigin::Default => "默认",
+        FeatureOrigin::User => "用户配置",
+        FeatureOrigin::Profile => "配置档",
+        FeatureOrigin::Project => "项目配置",
+        FeatureOrigin::Session => "命令行",
+        FeatureOrigin::Managed => "托管配置",
+        FeatureOrigin::Locked => "features.lock",
+    }
+}
+
 fn main() -> anyhow::Result<()> {
     arg0_dispatch_or_else(|codex_linux_sandbox_exe| async move {
         cli_main(codex_linux_sandbox_exe).await?;
@@ -531,9 +780,11 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
     let MultitoolCli {
         config_overrides: mut root_config_overrides,
         feature_toggles,
+        tui_frontend_flags,
         mut interactive,
         subcommand,
     } = MultitoolCli::parse();
+    let frontend_flag = tui_frontend_flags.selected();
 
     // Fold --enable/--disable into config overrides so they flow to all subcommands.
     let toggle_overrides = feature_toggles.to_overrides()?;
@@ -545,7 +796,8 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 &mut interactive.config_overrides,
                 root_config_overrides.clone(),
             );
-            let exit_info = run_interactive_tui(interactive, codex_linux_sandbox_exe).await?;
+            let exit_info =
+                run_interactive_tui(interactive, frontend_flag, codex_linux_sandbox_exe).await?;
             handle_app_exit(exit_info)?;
         }
         Some(Subcommand::Exec(mut exec_cli)) => {
@@ -572,6 +824,86 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
             prepend_config_flags(&mut mcp_cli.config_overrides, root_config_overrides.clone());
             mcp_cli.run().await?;
         }
+        Some(Subcommand::Skills(mut skills_cli)) => {
+            prepend_config_flags(
+                &mut skills_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            skills_cli.run(codex_linux_sandbox_exe).await?;
+        }
+        Some(Subcommand::Config(mut config_cli)) => {
+            prepend_config_flags(
+                &mut config_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            config_cli.run().await?;
+        }
+        Some(Subcommand::Export(mut export_cli)) => {
+            prepend_config_flags(
+                &mut export_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            export_cli.run().await?;
+        }
+        Some(Subcommand::Sessions(mut sessions_cli)) => {
+            prepend_config_flags(
+                &mut sessions_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            sessions_cli.run().await?;
+        }
+        Some(Subcommand::Digest(mut digest_cli)) => {
+            prepend_config_flags(
+                &mut digest_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            digest_cli.run().await?;
+        }
+        Some(Subcommand::Trust(mut trust_cli)) => {
+            prepend_config_flags(
+                &mut trust_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            trust_cli.run().await?;
+        }
+        Some(Subcommand::Profiles(mut profiles_cli)) => {
+            prepend_config_flags(
+                &mut profiles_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            profiles_cli.run().await?;
+        }
+        Some(Subcommand::Status(mut status_cli)) => {
+            prepend_config_flags(
+                &mut status_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            status_cli.run().await?;
+        }
+        Some(Subcommand::Git(git_cli)) => {
+            git_cli.run()?;
+        }
+        Some(Subcommand::Commit(mut commit_cli)) => {
+            prepend_config_flags(
+                &mut commit_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            commit_cli.run().await?;
+        }
+        Some(Subcommand::Schedule(mut schedule_cli)) => {
+            prepend_config_flags(
+                &mut schedule_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            schedule_cli.run().await?;
+        }
+        Some(Subcommand::Serve(mut serve_cli)) => {
+            prepend_config_flags(
+                &mut serve_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            codex_serve::run_main(serve_cli, std::env::current_exe()?).await?;
+        }
         Some(Subcommand::AppServer(app_server_cli)) => match app_server_cli.subcommand {
             None => {
                 codex_app_server::run_main(
@@ -579,6 +911,7 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                     root_config_overrides,
                     codex_core::config_loader::LoaderOverrides::default(),
                     app_server_cli.analytics_default_enabled,
+                    std::time::Duration::from_secs(app_server_cli.detach_grace_seconds),
                 )
                 .await?;
             }
@@ -608,6 +941,7 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
             session_id,
             last,
             all,
+            force_takeover,
             config_overrides,
         })) => {
             interactive = finalize_resume_interactive(
@@ -616,9 +950,11 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 session_id,
                 last,
                 all,
+                force_takeover,
                 config_overrides,
             );
-            let exit_info = run_interactive_tui(interactive, codex_linux_sandbox_exe).await?;
+            let exit_info =
+                run_interactive_tui(interactive, frontend_flag, codex_linux_sandbox_exe).await?;
             handle_app_exit(exit_info)?;
         }
         Some(Subcommand::Fork(ForkCommand {
@@ -635,7 +971,8 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 all,
                 config_overrides,
             );
-            let exit_info = run_interactive_tui(interactive, codex_linux_sandbox_exe).await?;
+            let exit_info =
+                run_interactive_tui(interactive, frontend_flag, codex_linux_sandbox_exe).await?;
             handle_app_exit(exit_info)?;
         }
         Some(Subcommand::Login(mut login_cli)) => {
@@ -664,7 +1001,8 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                         let api_key = read_api_key_from_stdin();
                         run_login_with_api_key(login_cli.config_overrides, api_key).await;
                     } else {
-                        run_login_with_chatgpt(login_cli.config_overrides).await;
+                        run_login_with_chatgpt(login_cli.config_overrides, !login_cli.no_browser)
+                            .await;
                     }
                 }
             }
@@ -679,6 +1017,31 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
         Some(Subcommand::Completion(completion_cli)) => {
             print_completion(completion_cli);
         }
+        Some(Subcommand::ShellInit(shell_init_cli)) => {
+            shell_init_cli.run();
+        }
+        Some(Subcommand::Fix(last_command_cli)) => {
+            interactive = finalize_last_command_interactive(
+                interactive,
+                root_config_overrides.clone(),
+                LastCommandAction::Fix,
+                last_command_cli,
+            )?;
+            let exit_info =
+                run_interactive_tui(interactive, frontend_flag, codex_linux_sandbox_exe).await?;
+            handle_app_exit(exit_info)?;
+        }
+        Some(Subcommand::Explain(last_command_cli)) => {
+            interactive = finalize_last_command_interactive(
+                interactive,
+                root_config_overrides.clone(),
+                LastCommandAction::Explain,
+                last_command_cli,
+            )?;
+            let exit_info =
+                run_interactive_tui(interactive, frontend_flag, codex_linux_sandbox_exe).await?;
+            handle_app_exit(exit_info)?;
+        }
         Some(Subcommand::Cloud(mut cloud_cli)) => {
             prepend_config_flags(
                 &mut cloud_cli.config_overrides,
@@ -720,11 +1083,26 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 )
                 .await?;
             }
+            SandboxCommand::Explain(mut explain_cli) => {
+                prepend_config_flags(
+                    &mut explain_cli.config_overrides,
+                    root_config_overrides.clone(),
+                );
+                codex_cli::debug_sandbox::run_sandbox_explain(explain_cli, codex_linux_sandbox_exe)
+                    .await?;
+            }
         },
         Some(Subcommand::Debug(DebugCommand { subcommand })) => match subcommand {
             DebugSubcommand::AppServer(cmd) => {
                 run_debug_app_server_command(cmd)?;
             }
+            DebugSubcommand::I18n(cmd) => {
+                run_debug_i18n_command(cmd)?;
+            }
+            DebugSubcommand::Prompt(mut cmd) => {
+                prepend_config_flags(&mut cmd.config_overrides, root_config_overrides.clone());
+                run_debug_prompt_command(cmd).await?;
+            }
         },
         Some(Subcommand::Execpolicy(ExecpolicyCommand { sub })) => match sub {
             ExecpolicySubcommand::Check(cmd) => run_execpolicycheck(cmd)?,
@@ -741,54 +1119,39 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 .await??;
         }
         Some(Subcommand::StdioToUds(cmd)) => {
-            let socket_path = cmd.socket_path;
-            tokio::task::spawn_blocking(move || codex_stdio_to_uds::run(socket_path.as_path()))
+            let options = codex_stdio_to_uds::RelayOptions {
+                reconnect: cmd.reconnect,
+                timeout: cmd.timeout.map(std::time::Duration::from_secs),
+            };
+            tokio::task::spawn_blocking(move || codex_stdio_to_uds::run(&cmd.target, &options))
                 .await??;
         }
         Some(Subcommand::Features(FeaturesCli { sub })) => match sub {
             FeaturesSubcommand::List => {
-                // Respect root-level `-c` overrides plus top-level flags like `--profile`.
-                let mut cli_kv_overrides = root_config_overrides
-                    .parse_overrides()
-                    .map_err(anyhow::Error::msg)?;
-
-                // Honor `--search` via the canonical web_search mode.
-                if interactive.web_search {
-                    cli_kv_overrides.push((
-                        "web_search".to_string(),
-                        toml::Value::String("live".to_string()),
-                    ));
-                }
-
-                // Thread through relevant top-level flags (at minimum, `--profile`).
-                let overrides = ConfigOverrides {
-                    config_profile: interactive.config_profile.clone(),
-                    ..Default::default()
-                };
-
-                let config = Config::load_with_cli_overrides_and_harness_overrides(
-                    cli_kv_overrides,
-                    overrides,
-                )
-                .await?;
+                let config = load_features_config(&root_config_overrides, &interactive).await?;
                 let mut rows = Vec::with_capacity(codex_core::features::FEATURES.len());
                 let mut name_width = 0;
                 let mut stage_width = 0;
                 for def in codex_core::features::FEATURES.iter() {
                     let name = def.key;
-                    let stage = stage_str(def.stage);
+                    let stage = stage_str(config.features.stage(def.id));
                     let enabled = config.features.enabled(def.id);
+                    let origin = origin_str(feature_origin(&config, def.id));
                     name_width = name_width.max(name.len());
                     stage_width = stage_width.max(stage.len());
-                    rows.push((name, stage, enabled));
+                    rows.push((name, stage, enabled, origin));
                 }
 
-                for (name, stage, enabled) in rows {
-                    println!("{name:<name_width$}  {stage:<stage_width$}  {enabled}");
+                for (name, stage, enabled, origin) in rows {
+                    println!("{name:<name_width$}  {stage:<stage_width$}  {enabled:<5}  {origin}");
                 }
             }
+            FeaturesSubcommand::Describe(FeatureSetArgs { feature }) => {
+                let config = load_features_config(&root_config_overrides, &interactive).await?;
+                print!("{}", describe_feature(&config, &feature)?);
+            }
             FeaturesSubcommand::Enable(FeatureSetArgs { feature }) => {
-                enable_feature_in_config(&interactive, &feature).await?;
+                enable_feature_in_config(&interactive, &root_config_overrides, &feature).await?;
             }
             FeaturesSubcommand::Disable(FeatureSetArgs { feature }) => {
                 disable_feature_in_config(&interactive, &feature).await?;
@@ -799,7 +1162,81 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
     Ok(())
 }
 
-async fn enable_feature_in_config(interactive: &TuiCli, feature: &str) -> anyhow::Result<()> {
+/// Loads the effective config the way a session would see it: root-level `-c`
+/// overrides plus top-level flags like `--profile`.
+async fn load_features_config(
+    root_config_overrides: &CliConfigOverrides,
+    interactive: &TuiCli,
+) -> anyhow::Result<Config> {
+    let mut cli_kv_overrides = root_config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+
+    // Honor `--search` via the canonical web_search mode.
+    if interactive.web_search {
+        cli_kv_overrides.push((
+            "web_search".to_string(),
+            toml::Value::String("live".to_string()),
+        ));
+    }
+
+    // Thread through relevant top-level flags (at minimum, `--profile`).
+    let overrides = ConfigOverrides {
+        config_profile: interactive.config_profile.clone(),
+        ..Default::default()
+    };
+
+    Ok(Config::load_with_cli_overrides_and_harness_overrides(cli_kv_overrides, overrides).await?)
+}
+
+fn describe_feature(config: &Config, key: &str) -> anyhow::Result<String> {
+    let Some(spec) = codex_core::features::FEATURES
+        .iter()
+        .find(|spec| spec.key == key)
+    else {
+        anyhow::bail!("未知功能开关：{key}");
+    };
+    let on_off = |enabled: bool| if enabled { "开启" } else { "关闭" };
+
+    let mut out = format!("{}\n  {}\n\n", spec.key, spec.description);
+    out.push_str(&format!(
+        "  阶段：{}\n",
+        stage_str(config.features.stage(spec.id))
+    ));
+    out.push_str(&format!("  默认：{}\n", on_off(spec.default_enabled)));
+    out.push_str(&format!(
+        "  当前：{}（来源：{}）\n",
+        on_off(config.features.enabled(spec.id)),
+        origin_str(feature_origin(config, spec.id))
+    ));
+    let dependencies = spec
+        .dependencies
+        .iter()
+        .map(|dependency| {
+            format!(
+                "{}（{}）",
+                dependency.key(),
+                on_off(config.features.enabled(*dependency))
+            )
+        })
+        .collect::<Vec<_>>();
+    out.push_str(&format!(
+        "  依赖：{}\n",
+        if dependencies.is_empty() {
+            "无".to_string()
+        } else {
+            dependencies.join("、")
+        }
+    ));
+    out.push_str(&format!("  风险：{}\n", spec.risk.unwrap_or("无")));
+    Ok(out)
+}
+
+async fn enable_feature_in_config(
+    interactive: &TuiCli,
+    root_config_overrides: &CliConfigOverrides,
+    feature: &str,
+) -> anyhow::Result<()> {
     FeatureToggles::validate_feature(feature)?;
     let codex_home = find_codex_home()?;
     ConfigEditsBuilder::new(&codex_home)
@@ -809,6 +1246,7 @@ async fn enable_feature_in_config(interactive: &TuiCli, feature: &str) -> anyhow
         .await?;
     println!("Enabled feature `{feature}` in config.toml.");
     maybe_print_under_development_feature_warning(&codex_home, interactive, feature);
+    maybe_print_missing_dependency_warning(root_config_overrides, interactive, feature).await;
     Ok(())
 }
 
@@ -850,6 +1288,40 @@ fn maybe_print_under_development_feature_warning(
     );
 }
 
+/// Warns when a dependency of the newly enabled feature is still off, since the
+/// feature does nothing until it is turned on too.
+async fn maybe_print_missing_dependency_warning(
+    root_config_overrides: &CliConfigOverrides,
+    interactive: &TuiCli,
+    feature: &str,
+) {
+    let Some(spec) = codex_core::features::FEATURES
+        .iter()
+        .find(|spec| spec.key == feature)
+    else {
+        return;
+    };
+    if spec.dependencies.is_empty() {
+        return;
+    }
+    // The feature is already saved; a config that fails to load here will
+    // report its own error the next time Codex starts.
+    let Ok(config) = load_features_config(root_config_overrides, interactive).await else {
+        return;
+    };
+    let missing = config.features.missing_dependencies(spec.id);
+    if missing.is_empty() {
+        return;
+    }
+
+    let missing = missing
+        .iter()
+        .map(|dependency| format!("`{}`", dependency.key()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    eprintln!("Feature `{feature}` has no effect until these features are enabled too: {missing}.");
+}
+
 /// Prepend root-level overrides so they have lower precedence than
 /// CLI-specific ones specified after the subcommand (if any).
 fn prepend_config_flags(
@@ -863,6 +1335,7 @@ fn prepend_config_flags(
 
 async fn run_interactive_tui(
     mut interactive: TuiCli,
+    frontend_flag: Option<TuiFrontend>,
     codex_linux_sandbox_exe: Option<PathBuf>,
 ) -> std::io::Result<AppExitInfo> {
     if let Some(prompt) = interactive.prompt.take() {
@@ -870,53 +1343,68 @@ async fn run_interactive_tui(
         interactive.prompt = Some(prompt.replace("\r\n", "\n").replace('\r', "\n"));
     }
 
-    let terminal_info = codex_core::terminal::terminal_info();
-    if terminal_info.name == TerminalName::Dumb {
-        if !(std::io::stdin().is_terminal() && std::io::stderr().is_terminal()) {
-            return Ok(AppExitInfo::fatal(
-                "TERM is set to \"dumb\". Refusing to start the interactive TUI because no terminal is available for a confirmation prompt (stdin/stderr is not a TTY). Run in a supported terminal or unset TERM.",
-            ));
-        }
-
-        eprintln!(
-            "WARNING: TERM is set to \"dumb\". Codex's interactive TUI may not work in this terminal."
-        );
-        if !confirm("Continue anyway? [y/N]: ")? {
-            return Ok(AppExitInfo::fatal(
-                "Refusing to start the interactive TUI because TERM is set to \"dumb\". Run in a supported terminal or unset TERM.",
-            ));
-        }
+    // The full-screen TUI cannot draw on a dumb terminal; the plain frontend
+    // prints lines instead and works with piped stdin too.
+    if codex_core::terminal::terminal_info().name == TerminalName::Dumb {
+        interactive.plain = true;
     }
-
-    match select_tui_frontend(is_tui2_enabled(&interactive).await?) {
-        TuiFrontend::Tui2 => {
-            let result = tui2::run_main(interactive.into(), codex_linux_sandbox_exe).await?;
-            Ok(result.into())
-        }
-        TuiFrontend::Tui => codex_tui::run_main(interactive, codex_linux_sandbox_exe).await,
+    if interactive.plain {
+        return codex_tui::run_main(interactive, codex_linux_sandbox_exe).await;
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum TuiFrontend {
-    Tui,
-    Tui2,
+    let mut frontend = select_tui_frontend(
+        frontend_flag,
+        load_tui_frontend_settings(&interactive).await?,
+    );
+    loop {
+        // `/ui switch` exits one frontend and asks for the same session to be
+        // resumed in the other.
+        let exit_info = match frontend {
+            TuiFrontend::Tui2 => {
+                let result =
+                    tui2::run_main(interactive.clone().into(), codex_linux_sandbox_exe.clone())
+                        .await?;
+                result.into()
+            }
+            TuiFrontend::Tui1 => {
+                codex_tui::run_main(interactive.clone(), codex_linux_sandbox_exe.clone()).await?
+            }
+        };
+        let (ExitReason::SwitchFrontend, Some(thread_id)) =
+            (&exit_info.exit_reason, exit_info.thread_id)
+        else {
+            return Ok(exit_info);
+        };
+        interactive = switch_frontend_interactive(interactive, thread_id);
+        frontend = frontend.other();
+    }
 }
 
-fn select_tui_frontend(tui2_enabled: bool) -> TuiFrontend {
-    if tui2_enabled {
+/// Precedence: `--tui1`/`--tui2`, then `tui_frontend` from the active profile
+/// or the top level of config.toml, then the `tui2` feature flag.
+fn select_tui_frontend(flag: Option<TuiFrontend>, settings: TuiFrontendSettings) -> TuiFrontend {
+    if let Some(frontend) = flag.or(settings.configured) {
+        return frontend;
+    }
+    if settings.tui2_enabled {
         TuiFrontend::Tui2
     } else {
-        TuiFrontend::Tui
+        TuiFrontend::Tui1
     }
 }
 
-/// Returns `Ok(true)` when the resolved configuration enables the `tui2` feature flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TuiFrontendSettings {
+    configured: Option<TuiFrontend>,
+    tui2_enabled: bool,
+}
+
+/// Reads the frontend choice from the resolved configuration.
 ///
 /// This performs a lightweight config load (honoring the same precedence as the lower-level TUI
 /// bootstrap: `$CODEX_HOME`, config.toml, profile, cwd, and CLI `-c` overrides) solely to decide
 /// which TUI frontend to launch. The full configuration is still loaded later by the selected TUI.
-async fn is_tui2_enabled(cli: &TuiCli) -> std::io::Result<bool> {
+async fn load_tui_frontend_settings(cli: &TuiCli) -> std::io::Result<TuiFrontendSettings> {
     let raw_overrides = cli.config_overrides.raw_overrides.clone();
     let overrides_cli = codex_common::CliConfigOverrides { raw_overrides };
     let cli_kv_overrides = overrides_cli
@@ -934,16 +1422,27 @@ async fn is_tui2_enabled(cli: &TuiCli) -> std::io::Result<bool> {
     let config_profile = config_toml.get_config_profile(cli.config_profile.clone())?;
     let features =
         Features::from_config(&config_toml, &config_profile, FeatureOverrides::default());
-    Ok(features.enabled(Feature::Tui2))
+    Ok(TuiFrontendSettings {
+        configured: config_profile.tui_frontend.or(config_toml.tui_frontend),
+        tui2_enabled: features.enabled(Feature::Tui2),
+    })
 }
 
-fn confirm(prompt: &str) -> std::io::Result<bool> {
-    eprintln!("{prompt}");
-
-    let mut input = String::new();
-    std::io::stdin().read_line(&mut input)?;
-    let answer = input.trim();
-    Ok(answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
+/// Resume `thread_id` with the same flags, without replaying the initial
+/// prompt or reopening a picker.
+fn switch_frontend_interactive(mut interactive: TuiCli, thread_id: ThreadId) -> TuiCli {
+    interactive.prompt = None;
+    interactive.images.clear();
+    interactive.resume_picker = false;
+    interactive.resume_last = false;
+    interactive.resume_session_id = Some(thread_id.to_string());
+    interactive.resume_show_all = false;
+    interactive.resume_force_takeover = false;
+    interactive.fork_picker = false;
+    interactive.fork_last = false;
+    interactive.fork_session_id = None;
+    interactive.fork_show_all = false;
+    interactive
 }
 
 /// Build the final `TuiCli` for a `codex resume` invocation.
@@ -953,6 +1452,7 @@ fn finalize_resume_interactive(
     session_id: Option<String>,
     last: bool,
     show_all: bool,
+    force_takeover: bool,
     resume_cli: TuiCli,
 ) -> TuiCli {
     // Start with the parsed interactive CLI so resume shares the same
@@ -962,6 +1462,7 @@ fn finalize_resume_interactive(
     interactive.resume_last = last;
     interactive.resume_session_id = resume_session_id;
     interactive.resume_show_all = show_all;
+    interactive.resume_force_takeover = force_takeover;
 
     // Merge resume-scoped flags and overrides with highest precedence.
     merge_interactive_cli_flags(&mut interactive, resume_cli);
@@ -998,6 +1499,29 @@ fn finalize_fork_interactive(
     interactive
 }
 
+/// Build the final `TuiCli` for `codex fix` / `codex explain`: the first
+/// prompt describes the command recorded by the shell hook, and a prompt
+/// given on the command line is appended as extra context.
+fn finalize_last_command_interactive(
+    mut interactive: TuiCli,
+    root_config_overrides: CliConfigOverrides,
+    action: LastCommandAction,
+    last_command_cli: LastCommandCli,
+) -> anyhow::Result<TuiCli> {
+    let LastCommandCli {
+        last_command,
+        last_status,
+        config_overrides: mut subcommand_cli,
+    } = last_command_cli;
+    let last = LastCommand::from_args(last_command, last_status)?;
+    let note = subcommand_cli.prompt.take();
+    let prompt = shell_integration::last_command_prompt(action, &last, note.as_deref())?;
+    merge_interactive_cli_flags(&mut interactive, subcommand_cli);
+    interactive.prompt = Some(prompt);
+    prepend_config_flags(&mut interactive.config_overrides, root_config_overrides);
+    Ok(interactive)
+}
+
 /// Merge flags provided to `codex resume`/`codex fork` so they take precedence over any
 /// root-level flags. Only overrides fields explicitly set on the subcommand-scoped
 /// CLI. Also appends `-c key=value` overrides with highest precedence.
@@ -1023,12 +1547,21 @@ fn merge_interactive_cli_flags(interactive: &mut TuiCli, subcommand_cli: TuiCli)
     if subcommand_cli.dangerously_bypass_approvals_and_sandbox {
         interactive.dangerously_bypass_approvals_and_sandbox = true;
     }
+    if subcommand_cli.read_only {
+        interactive.read_only = true;
+    }
+    if subcommand_cli.offline {
+        interactive.offline = true;
+    }
     if let Some(cwd) = subcommand_cli.cwd {
         interactive.cwd = Some(cwd);
     }
     if subcommand_cli.web_search {
         interactive.web_search = true;
     }
+    if subcommand_cli.accessible {
+        interactive.accessible = true;
+    }
     if !subcommand_cli.images.is_empty() {
         interactive.images = subcommand_cli.images;
     }
@@ -1073,6 +1606,7 @@ mod tests {
             session_id,
             last,
             all,
+            force_takeover,
             config_overrides: resume_cli,
         }) = subcommand.expect("resume present")
         else {
@@ -1085,6 +1619,7 @@ mod tests {
             session_id,
             last,
             all,
+            force_takeover,
             resume_cli,
         )
     }
@@ -1150,18 +1685,77 @@ mod tests {
                 .map(Result::unwrap),
             thread_name: thread_name.map(str::to_string),
             update_action: None,
+            exit_summary: Vec::new(),
             exit_reason: ExitReason::UserRequested,
         }
     }
 
+    fn frontend_settings(
+        configured: Option<TuiFrontend>,
+        tui2_enabled: bool,
+    ) -> TuiFrontendSettings {
+        TuiFrontendSettings {
+            configured,
+            tui2_enabled,
+        }
+    }
+
     #[test]
     fn select_tui_frontend_uses_tui2_when_enabled() {
-        assert_eq!(select_tui_frontend(true), TuiFrontend::Tui2);
+        assert_eq!(
+            select_tui_frontend(None, frontend_settings(None, true)),
+            TuiFrontend::Tui2
+        );
     }
 
     #[test]
     fn select_tui_frontend_uses_tui_when_disabled() {
-        assert_eq!(select_tui_frontend(false), TuiFrontend::Tui);
+        assert_eq!(
+            select_tui_frontend(None, frontend_settings(None, false)),
+            TuiFrontend::Tui1
+        );
+    }
+
+    #[test]
+    fn select_tui_frontend_prefers_flag_then_config_over_feature() {
+        assert_eq!(
+            select_tui_frontend(None, frontend_settings(Some(TuiFrontend::Tui1), true)),
+            TuiFrontend::Tui1
+        );
+        assert_eq!(
+            select_tui_frontend(
+                Some(TuiFrontend::Tui2),
+                frontend_settings(Some(TuiFrontend::Tui1), false)
+            ),
+            TuiFrontend::Tui2
+        );
+    }
+
+    #[test]
+    fn tui_frontend_flags_parse_and_conflict() {
+        let cli = MultitoolCli::try_parse_from(["codex", "--tui1"]).expect("parse should succeed");
+        assert_eq!(cli.tui_frontend_flags.selected(), Some(TuiFrontend::Tui1));
+
+        let cli = MultitoolCli::try_parse_from(["codex", "resume", "--tui2"])
+            .expect("parse should succeed");
+        assert_eq!(cli.tui_frontend_flags.selected(), Some(TuiFrontend::Tui2));
+
+        assert!(MultitoolCli::try_parse_from(["codex", "--tui1", "--tui2"]).is_err());
+    }
+
+    #[test]
+    fn switch_frontend_resumes_the_thread_without_replaying_the_prompt() {
+        let interactive = MultitoolCli::try_parse_from(["codex", "-m", "gpt-5.1", "fix the bug"])
+            .expect("parse should succeed")
+            .interactive;
+        let thread_id = ThreadId::new();
+
+        let interactive = switch_frontend_interactive(interactive, thread_id);
+
+        assert_eq!(interactive.prompt, None);
+        assert_eq!(interactive.model.as_deref(), Some("gpt-5.1"));
+        assert_eq!(interactive.resume_session_id, Some(thread_id.to_string()));
+        assert!(!interactive.resume_picker);
     }
 
     #[test]
@@ -1171,6 +1765,7 @@ mod tests {
             thread_id: None,
             thread_name: None,
             update_action: None,
+            exit_summary: Vec::new(),
             exit_reason: ExitReason::UserRequested,
         };
         let lines = format_exit_messages(exit_info, false);
@@ -1215,6 +1810,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_exit_messages_lists_the_exit_summary() {
+        let mut exit_info = sample_exit_info(Some("123e4567-e89b-12d3-a456-426614174000"), None);
+        exit_info.exit_summary = vec![
+            "Fixed the login redirect".to_string(),
+            "Tests for the callback are still missing".to_string(),
+        ];
+        let lines = format_exit_messages(exit_info, false);
+        assert_eq!(
+            lines,
+            vec![
+                "Token usage: total=2 input=0 output=2".to_string(),
+                "本次会话摘要：".to_string(),
+                "  • Fixed the login redirect".to_string(),
+                "  • Tests for the callback are still missing".to_string(),
+                "若要继续此会话，请运行 codex resume 123e4567-e89b-12d3-a456-426614174000"
+                    .to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn resume_model_flag_applies_when_no_root_flags() {
         let interactive =
@@ -1260,6 +1876,17 @@ mod tests {
         assert!(interactive.resume_show_all);
     }
 
+    #[test]
+    fn resume_force_takeover_flag_is_forwarded() {
+        let interactive =
+            finalize_resume_from_args(["codex", "resume", "--force-takeover", "--last"].as_ref());
+        assert!(interactive.resume_last);
+        assert!(interactive.resume_force_takeover);
+
+        let interactive = finalize_resume_from_args(["codex", "resume", "--last"].as_ref());
+        assert!(!interactive.resume_force_takeover);
+    }
+
     #[test]
     fn resume_merges_option_flags_and_full_auto() {
         let interactive = finalize_resume_from_args(
@@ -1380,6 +2007,16 @@ mod tests {
         assert!(app_server.analytics_default_enabled);
     }
 
+    #[test]
+    fn app_server_detach_grace_defaults_to_zero() {
+        let app_server = app_server_from_args(["codex", "app-server"].as_ref());
+        assert_eq!(app_server.detach_grace_seconds, 0);
+
+        let app_server =
+            app_server_from_args(["codex", "app-server", "--detach-grace-seconds", "120"].as_ref());
+        assert_eq!(app_server.detach_grace_seconds, 120);
+    }
+
     #[test]
     fn features_enable_parses_feature_name() {
         let cli = MultitoolCli::try_parse_from(["codex", "features", "enable", "unified_exec"])
@@ -0,0 +1,420 @@
+use std::path::Path;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_common::ApprovalModeCliArg;
+use codex_common::CliConfigOverrides;
+use codex_common::SandboxModeCliArg;
+use codex_core::config::CONFIG_TOML_FILE;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use codex_core::config::edit::ConfigEditsBuilder;
+use codex_core::config::find_codex_home;
+use codex_protocol::openai_models::ReasoningEffort;
+use toml::Value as TomlValue;
+
+/// Subcommands:
+/// - `list` — show the profiles defined in the user config
+/// - `create` — add a profile, optionally with its model and policies
+/// - `copy` — duplicate a profile under a new name
+/// - `delete` — remove a profile and every reference to it
+/// - `set-default` — choose the profile used when none is selected
+#[derive(Debug, clap::Parser)]
+pub struct ProfilesCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: ProfilesSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ProfilesSubcommand {
+    /// List profiles; `*` marks the default.
+    List,
+    /// Create an empty profile, or one with the given settings.
+    Create(CreateArgs),
+    /// Copy every setting of a profile to a new profile.
+    Copy(CopyArgs),
+    /// Delete a profile; clears `profile` and per-project selections that use it.
+    Delete(DeleteArgs),
+    /// Set the profile used when neither `-p` nor a project selection applies.
+    SetDefault(SetDefaultArgs),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CreateArgs {
+    #[arg(value_name = "NAME")]
+    pub name: String,
+
+    /// Model the profile uses.
+    #[arg(long, short = 'm')]
+    pub model: Option<String>,
+
+    /// Reasoning effort (none, minimal, low, medium, high, xhigh).
+    #[arg(long, value_parser = parse_reasoning_effort)]
+    pub reasoning_effort: Option<ReasoningEffort>,
+
+    /// Approval policy the profile uses.
+    #[arg(long, value_enum)]
+    pub approval_policy: Option<ApprovalModeCliArg>,
+
+    /// Sandbox mode the profile uses.
+    #[arg(long, short = 's', value_enum)]
+    pub sandbox: Option<SandboxModeCliArg>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CopyArgs {
+    #[arg(value_name = "FROM")]
+    pub from: String,
+
+    #[arg(value_name = "TO")]
+    pub to: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct DeleteArgs {
+    #[arg(value_name = "NAME")]
+    pub name: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct SetDefaultArgs {
+    #[arg(value_name = "NAME", required_unless_present = "clear")]
+    pub name: Option<String>,
+
+    /// Remove the default so the top-level settings apply.
+    #[arg(long, conflicts_with = "name")]
+    pub clear: bool,
+}
+
+impl ProfilesCli {
+    pub async fn run(self) -> Result<()> {
+        let ProfilesCli {
+            config_overrides,
+            subcommand,
+        } = self;
+        let overrides = config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let codex_home = find_codex_home().context("failed to locate CODEX_HOME")?;
+        let user_config = read_user_config(&codex_home)?;
+        let profiles = ProfileTables::from_user_config(&user_config);
+
+        match subcommand {
+            ProfilesSubcommand::List => {
+                print!("{}", profiles.render_list());
+                Ok(())
+            }
+            ProfilesSubcommand::Create(args) => {
+                validate_new_name(&profiles, &args.name)?;
+                let mut builder = ConfigEditsBuilder::new(&codex_home)
+                    .with_profile(Some(&args.name))
+                    .create_profile(&args.name);
+                if args.model.is_some() || args.reasoning_effort.is_some() {
+                    builder = builder.set_model(args.model.as_deref(), args.reasoning_effort);
+                }
+                if let Some(policy) = args.approval_policy {
+                    builder = builder.set_approval_policy(policy.into());
+                }
+                if let Some(sandbox) = args.sandbox {
+                    builder = builder.set_sandbox_mode(sandbox.into());
+                }
+                apply_checked(&codex_home, builder, &overrides, Some(&args.name)).await?;
+                println!("Created profile `{}`.", args.name);
+                Ok(())
+            }
+            ProfilesSubcommand::Copy(args) => {
+                profiles.require(&args.from)?;
+                validate_new_name(&profiles, &args.to)?;
+                let builder =
+                    ConfigEditsBuilder::new(&codex_home).copy_profile(&args.from, &args.to);
+                apply_checked(&codex_home, builder, &overrides, Some(&args.to)).await?;
+                println!("Copied profile `{}` to `{}`.", args.from, args.to);
+                Ok(())
+            }
+            ProfilesSubcommand::Delete(args) => {
+                profiles.require(&args.name)?;
+                let mut builder = ConfigEditsBuilder::new(&codex_home).delete_profile(&args.name);
+                let is_default = profiles.default.as_deref() == Some(args.name.as_str());
+                if is_default {
+                    builder = builder.set_default_profile(None);
+                }
+                let projects = projects_using_profile(&user_config, &args.name);
+                for project in &projects {
+                    builder = builder.set_project_profile(project, None);
+                }
+                apply_checked(&codex_home, builder, &overrides, None).await?;
+                println!("Deleted profile `{}`.", args.name);
+                if is_default {
+                    println!("It was the default profile; the top-level settings now apply.");
+                }
+                for project in projects {
+                    println!("Cleared the profile selected for {project}.");
+                }
+                Ok(())
+            }
+            ProfilesSubcommand::SetDefault(args) => {
+                let name = if args.clear { None } else { args.name };
+                if let Some(name) = name.as_deref() {
+                    profiles.require(name)?;
+                }
+                let builder =
+                    ConfigEditsBuilder::new(&codex_home).set_default_profile(name.as_deref());
+                apply_checked(&codex_home, builder, &overrides, None).await?;
+                match name {
+                    Some(name) => println!("Default profile set to `{name}`."),
+                    None => println!("Cleared the default profile."),
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn parse_reasoning_effort(raw: &str) -> Result<ReasoningEffort, String> {
+    TomlValue::String(raw.to_ascii_lowercase())
+        .try_into()
+        .map_err(|_| format!("unknown reasoning effort `{raw}`"))
+}
+
+/// Profiles defined in the user `config.toml`, the only file these commands
+/// edit.
+#[derive(Debug, Default, PartialEq)]
+struct ProfileTables {
+    default: Option<String>,
+    /// `(name, table)` sorted by name.
+    profiles: Vec<(String, toml::Table)>,
+}
+
+impl ProfileTables {
+    fn from_user_config(user_config: &TomlValue) -> Self {
+        let default = user_config
+            .get("profile")
+            .and_then(TomlValue::as_str)
+            .map(ToString::to_string);
+        let mut profiles: Vec<(String, toml::Table)> = user_config
+            .get("profiles")
+            .and_then(TomlValue::as_table)
+            .map(|profiles| {
+                profiles
+                    .iter()
+                    .filter_map(|(name, table)| Some((name.clone(), table.as_table()?.clone())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        profiles.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Self { default, profiles }
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.profiles.iter().any(|(existing, _)| existing == name)
+    }
+
+    fn require(&self, name: &str) -> Result<()> {
+        if self.contains(name) {
+            return Ok(());
+        }
+        let known: Vec<&str> = self
+            .profiles
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        if known.is_empty() {
+            bail!("profile `{name}` does not exist; no profiles are defined");
+        }
+        bail!(
+            "profile `{name}` does not exist; defined profiles: {}",
+            known.join(", ")
+        );
+    }
+
+    fn render_list(&self) -> String {
+        if self.profiles.is_empty() {
+            return "No profiles defined. Create one with `codex profiles create <NAME>`.\n"
+                .to_string();
+        }
+        let width = self
+            .profiles
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or_default();
+        let mut out = String::new();
+        for (name, table) in &self.profiles {
+            let marker = if self.default.as_deref() == Some(name.as_str()) {
+                '*'
+            } else {
+                ' '
+            };
+            let summary = summarize_profile(table);
+            out.push_str(format!("{marker} {name:<width$}  {summary}").trim_end());
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// One-line summary of a profile: scalar settings as `key=value`, nested
+/// tables as `[key]`.
+fn summarize_profile(table: &toml::Table) -> String {
+    table
+        .iter()
+        .map(|(key, value)| match value {
+            TomlValue::String(value) => format!("{key}={value}"),
+            TomlValue::Table(_) => format!("[{key}]"),
+            other => format!("{key}={other}"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn validate_new_name(profiles: &ProfileTables, name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+    {
+        bail!("invalid profile name `{name}`; use letters, digits, `-`, and `_`");
+    }
+    if profiles.contains(name) {
+        bail!("profile `{name}` already exists");
+    }
+    Ok(())
+}
+
+/// Project paths whose `[projects."<path>"] profile` selects `name`.
+fn projects_using_profile(user_config: &TomlValue, name: &str) -> Vec<String> {
+    let Some(projects) = user_config.get("projects").and_then(TomlValue::as_table) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<String> = projects
+        .iter()
+        .filter(|(_, project)| project.get("profile").and_then(TomlValue::as_str) == Some(name))
+        .map(|(path, _)| path.clone())
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn read_user_config(codex_home: &Path) -> Result<TomlValue> {
+    let path = codex_home.join(CONFIG_TOML_FILE);
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => {
+            toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            Ok(TomlValue::Table(toml::Table::new()))
+        }
+        Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// Apply `builder`, then load the configuration (with `profile` active, when
+/// given) and restore the previous file if the edit made it unloadable.
+///
+/// A configuration that already failed to load is not held against the edit,
+/// so these commands can be used to repair it.
+async fn apply_checked(
+    codex_home: &Path,
+    builder: ConfigEditsBuilder,
+    overrides: &[(String, TomlValue)],
+    profile: Option<&str>,
+) -> Result<()> {
+    let path = codex_home.join(CONFIG_TOML_FILE);
+    let previous = match std::fs::read_to_string(&path) {
+        Ok(contents) => Some(contents),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => {
+            return Err(err).with_context(|| format!("failed to read {}", path.display()));
+        }
+    };
+    let loaded_before = load_config(overrides, None).await.is_ok();
+
+    builder
+        .apply()
+        .await
+        .with_context(|| format!("failed to update {}", path.display()))?;
+
+    let Err(err) = load_config(overrides, profile).await else {
+        return Ok(());
+    };
+    if !loaded_before {
+        return Ok(());
+    }
+    match previous {
+        Some(contents) => std::fs::write(&path, contents),
+        None => std::fs::remove_file(&path),
+    }
+    .with_context(|| format!("failed to restore {}", path.display()))?;
+    Err(err).context("the edit would leave config.toml unloadable, so it was not saved")
+}
+
+async fn load_config(
+    overrides: &[(String, TomlValue)],
+    profile: Option<&str>,
+) -> std::io::Result<Config> {
+    Config::load_with_cli_overrides_and_harness_overrides(
+        overrides.to_vec(),
+        ConfigOverrides {
+            config_profile: profile.map(ToString::to_string),
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn user_config() -> TomlValue {
+        toml::from_str(
+            r#"
+profile = "fast"
+
+[profiles.review]
+model = "o3"
+approval_policy = "never"
+
+[profiles.fast]
+model = "gpt-5-mini"
+
+[profiles.fast.features]
+web_search_request = true
+
+[projects."/src/api"]
+trust_level = "trusted"
+profile = "review"
+"#,
+        )
+        .expect("toml")
+    }
+
+    #[test]
+    fn lists_profiles_sorted_with_default_marked() {
+        let profiles = ProfileTables::from_user_config(&user_config());
+        assert_eq!(
+            profiles.render_list(),
+            "* fast    model=gpt-5-mini, [features]\n  review  approval_policy=never, model=o3\n"
+        );
+    }
+
+    #[test]
+    fn validates_new_names_and_finds_project_references() {
+        let config = user_config();
+        let profiles = ProfileTables::from_user_config(&config);
+
+        assert!(validate_new_name(&profiles, "work-2").is_ok());
+        assert!(validate_new_name(&profiles, "fast").is_err());
+        assert!(validate_new_name(&profiles, "a.b").is_err());
+        assert!(profiles.require("missing").is_err());
+        assert_eq!(
+            projects_using_profile(&config, "review"),
+            vec!["/src/api".to_string()]
+        );
+    }
+}
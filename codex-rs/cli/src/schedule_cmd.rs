@@ -0,0 +1,416 @@
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use chrono::Utc;
+use codex_common::CliConfigOverrides;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use codex_core::schedules::ScheduleNotify;
+use codex_core::schedules::ScheduleStore;
+use codex_core::schedules::ScheduledRun;
+use codex_core::schedules::ScheduledTask;
+use codex_core::schedules::ScheduledTaskReport;
+use codex_core::schedules::deliver_report;
+
+/// Subcommands:
+/// - `add` — schedule a prompt to run headlessly
+/// - `list` — show scheduled tasks and when they run next
+/// - `remove` — delete a scheduled task
+/// - `run` — run the tasks that are due; invoke it every minute
+#[derive(Debug, clap::Parser)]
+pub struct ScheduleCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: ScheduleSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ScheduleSubcommand {
+    /// Schedule a prompt to run with `codex exec` on a cron schedule.
+    Add(AddArgs),
+    /// List scheduled tasks.
+    List,
+    /// Remove a scheduled task.
+    Remove(RemoveArgs),
+    /// Run every task that is due. Meant to be started each minute by systemd, launchd, or cron.
+    Run(RunArgs),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AddArgs {
+    /// Cron expression in local time: minute hour day-of-month month day-of-week.
+    #[arg(value_name = "CRON")]
+    pub cron: String,
+
+    /// Prompt to run.
+    #[arg(long, value_name = "PROMPT")]
+    pub prompt: String,
+
+    /// Directory to run the task in. Defaults to the current directory.
+    #[arg(long = "cd", short = 'C', value_name = "DIR")]
+    pub cwd: Option<PathBuf>,
+
+    /// Where to report each run's result.
+    #[arg(long, value_enum, default_value_t = NotifyArg::None)]
+    pub notify: NotifyArg,
+
+    /// URL that receives the result as a JSON POST; required with `--notify webhook`.
+    #[arg(long, value_name = "URL")]
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct RemoveArgs {
+    /// Id shown by `codex schedule list`.
+    #[arg(value_name = "ID")]
+    pub id: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct RunArgs {
+    /// Run this task now, whether or not it is due.
+    #[arg(long, value_name = "ID")]
+    pub task: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum NotifyArg {
+    /// Only record the run as a session.
+    None,
+    /// Pass the result to the `notify` program from config.toml.
+    Command,
+    /// POST the result as JSON to --webhook-url.
+    Webhook,
+}
+
+impl From<NotifyArg> for ScheduleNotify {
+    fn from(value: NotifyArg) -> Self {
+        match value {
+            NotifyArg::None => ScheduleNotify::None,
+            NotifyArg::Command => ScheduleNotify::Command,
+            NotifyArg::Webhook => ScheduleNotify::Webhook,
+        }
+    }
+}
+
+/// What one `codex exec` run produced.
+struct ExecOutcome {
+    success: bool,
+    thread_id: Option<String>,
+    last_message: Option<String>,
+}
+
+impl ScheduleCli {
+    pub async fn run(self) -> Result<()> {
+        let ScheduleCli {
+            config_overrides,
+            subcommand,
+        } = self;
+        let overrides = config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides_and_harness_overrides(
+            overrides,
+            ConfigOverrides::default(),
+        )
+        .await
+        .context("failed to load configuration")?;
+
+        match subcommand {
+            ScheduleSubcommand::Add(args) => run_add(&config, args),
+            ScheduleSubcommand::List => run_list(&config),
+            ScheduleSubcommand::Remove(args) => run_remove(&config, args),
+            ScheduleSubcommand::Run(args) => run_due(&config, &config_overrides, args).await,
+        }
+    }
+}
+
+fn run_add(config: &Config, args: AddArgs) -> Result<()> {
+    let cwd = match args.cwd {
+        Some(cwd) => cwd,
+        None => std::env::current_dir()?,
+    };
+    let cwd = cwd
+        .canonicalize()
+        .with_context(|| format!("failed to resolve {}", cwd.display()))?;
+    let mut store = ScheduleStore::load(&config.codex_home)?;
+    let task = store
+        .add(
+            args.cron,
+            args.prompt,
+            cwd,
+            args.notify.into(),
+            args.webhook_url,
+        )?
+        .clone();
+    store.save()?;
+
+    println!("Scheduled task {} ({})", task.id, task.cron);
+    if let Some(next) = task.next_run()? {
+        println!("Next run: {}", next.format("%Y-%m-%d %H:%M"));
+    }
+    println!("Run `codex schedule run` every minute to execute due tasks.");
+    Ok(())
+}
+
+fn run_list(config: &Config) -> Result<()> {
+    let store = ScheduleStore::load(&config.codex_home)?;
+    if store.tasks.is_empty() {
+        println!("No scheduled tasks. Add one with `codex schedule add`.");
+        return Ok(());
+    }
+
+    let rows = store
+        .tasks
+        .iter()
+        .map(|task| {
+            let next = match task.next_run() {
+                Ok(Some(next)) => next.format("%Y-%m-%d %H:%M").to_string(),
+                Ok(None) => "never".to_string(),
+                Err(_) => "invalid".to_string(),
+            };
+            let last = match &task.last_run {
+                Some(ScheduledRun {
+                    success: Some(true),
+                    ..
+                }) => "ok",
+                Some(ScheduledRun {
+                    success: Some(false),
+                    ..
+                }) => "failed",
+                Some(ScheduledRun { success: None, .. }) => "running",
+                None => "-",
+            };
+            [
+                task.id.clone(),
+                task.cron.clone(),
+                next,
+                last.to_string(),
+                notify_label(task.notify).to_string(),
+                task.prompt.clone(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let header = ["ID", "SCHEDULE", "NEXT RUN", "LAST RUN", "NOTIFY", "PROMPT"];
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let render = |cells: [&str; 6]| {
+        let (prompt, columns) = cells.split_last().expect("six columns");
+        let mut line = String::new();
+        for (cell, width) in columns.iter().zip(widths) {
+            line.push_str(&format!("{cell:<width$}  "));
+        }
+        line.push_str(prompt);
+        line
+    };
+    println!("{}", render(header));
+    for row in &rows {
+        println!("{}", render(row.each_ref().map(String::as_str)));
+    }
+    Ok(())
+}
+
+fn notify_label(notify: ScheduleNotify) -> &'static str {
+    match notify {
+        ScheduleNotify::None => "none",
+        ScheduleNotify::Command => "command",
+        ScheduleNotify::Webhook => "webhook",
+    }
+}
+
+fn run_remove(config: &Config, args: RemoveArgs) -> Result<()> {
+    let mut store = ScheduleStore::load(&config.codex_home)?;
+    if store.remove(&args.id).is_none() {
+        bail!("no scheduled task with id {}", args.id);
+    }
+    store.save()?;
+    println!("Removed scheduled task {}", args.id);
+    Ok(())
+}
+
+async fn run_due(
+    config: &Config,
+    config_overrides: &CliConfigOverrides,
+    args: RunArgs,
+) -> Result<()> {
+    let mut store = ScheduleStore::load(&config.codex_home)?;
+    let now = Utc::now();
+    let due: Vec<String> = match args.task {
+        Some(id) => {
+            if store.get(&id).is_none() {
+                bail!("no scheduled task with id {id}");
+            }
+            vec![id]
+        }
+        None => store
+            .tasks
+            .iter()
+            .filter(|task| match task.is_due(now) {
+                Ok(due) => due,
+                Err(err) => {
+                    eprintln!("Skipping task {}: {err:#}", task.id);
+                    false
+                }
+            })
+            .map(|task| task.id.clone())
+            .collect(),
+    };
+
+    let mut failures = 0;
+    for id in due {
+        // Record the start before running so an overlapping invocation does
+        // not start the same occurrence again.
+        let Some(task) = store.get_mut(&id) else {
+            continue;
+        };
+        task.last_run = Some(ScheduledRun {
+            started_at: Utc::now(),
+            success: None,
+            thread_id: None,
+        });
+        let task = task.clone();
+        store.save()?;
+
+        eprintln!(
+            "Running scheduled task {} in {}",
+            task.id,
+            task.cwd.display()
+        );
+        let outcome = match run_exec(&task, config_overrides).await {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                eprintln!("Task {} could not start: {err:#}", task.id);
+                ExecOutcome {
+                    success: false,
+                    thread_id: None,
+                    last_message: None,
+                }
+            }
+        };
+        if !outcome.success {
+            failures += 1;
+        }
+
+        // Reload so edits made while the task ran (e.g. `codex schedule
+        // remove`) are kept.
+        store = ScheduleStore::load(&config.codex_home)?;
+        let Some(run) = store
+            .get_mut(&task.id)
+            .and_then(|task| task.last_run.as_mut())
+        else {
+            continue;
+        };
+        run.success = Some(outcome.success);
+        run.thread_id = outcome.thread_id.clone();
+        let started_at = run.started_at;
+        store.save()?;
+
+        match &outcome.thread_id {
+            Some(thread_id) => println!(
+                "Task {} {}; session {thread_id}",
+                task.id,
+                if outcome.success {
+                    "completed"
+                } else {
+                    "failed"
+                }
+            ),
+            None => println!("Task {} failed before a session started", task.id),
+        }
+
+        let report = ScheduledTaskReport {
+            task_id: task.id.clone(),
+            cron: task.cron.clone(),
+            prompt: task.prompt.clone(),
+            cwd: task.cwd.display().to_string(),
+            started_at,
+            success: outcome.success,
+            thread_id: outcome.thread_id,
+            last_assistant_message: outcome.last_message,
+        };
+        if let Err(err) = deliver_report(&task, &report, config.notify.as_deref()).await {
+            eprintln!("Failed to report task {}: {err:#}", task.id);
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} scheduled task(s) failed");
+    }
+    Ok(())
+}
+
+/// Runs the task's prompt with `codex exec --json`, which records the run as
+/// a session like any other exec invocation.
+async fn run_exec(
+    task: &ScheduledTask,
+    config_overrides: &CliConfigOverrides,
+) -> Result<ExecOutcome> {
+    let last_message_file = tempfile::NamedTempFile::new()?;
+    let mut command = tokio::process::Command::new(std::env::current_exe()?);
+    command.arg("exec");
+    for raw in &config_overrides.raw_overrides {
+        command.arg("-c").arg(raw);
+    }
+    let output = command
+        .arg("--json")
+        .arg("--skip-git-repo-check")
+        .arg("--cd")
+        .arg(&task.cwd)
+        .arg("--output-last-message")
+        .arg(last_message_file.path())
+        .arg("--")
+        .arg(&task.prompt)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .await
+        .context("failed to start codex exec")?;
+
+    let thread_id = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(thread_started_id);
+    let last_message = std::fs::read_to_string(last_message_file.path())
+        .ok()
+        .map(|message| message.trim().to_string())
+        .filter(|message| !message.is_empty());
+    Ok(ExecOutcome {
+        success: output.status.success(),
+        thread_id,
+        last_message,
+    })
+}
+
+/// Extracts the session id from exec's `thread.started` JSON event.
+fn thread_started_id(line: &str) -> Option<String> {
+    let event: serde_json::Value = serde_json::from_str(line).ok()?;
+    if event.get("type")?.as_str()? != "thread.started" {
+        return None;
+    }
+    Some(event.get("thread_id")?.as_str()?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn thread_id_comes_from_thread_started_event() {
+        assert_eq!(
+            thread_started_id(r#"{"type":"thread.started","thread_id":"abc"}"#),
+            Some("abc".to_string())
+        );
+        assert_eq!(thread_started_id(r#"{"type":"turn.started"}"#), None);
+        assert_eq!(thread_started_id("not json"), None);
+    }
+}
@@ -0,0 +1,607 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use codex_common::CliConfigOverrides;
+use codex_core::AuditEntry;
+use codex_core::BranchNode;
+use codex_core::BranchTurn;
+use codex_core::Cursor;
+use codex_core::ImportConflict;
+use codex_core::PatchComparison;
+use codex_core::RolloutRecorder;
+use codex_core::SESSION_BUNDLE_EXTENSION;
+use codex_core::SessionBundleImportOptions;
+use codex_core::ThreadItem;
+use codex_core::ThreadSortKey;
+use codex_core::archive_session;
+use codex_core::compare_session_patches;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use codex_core::export_session_bundle;
+use codex_core::find_archived_thread_path_by_id_str;
+use codex_core::find_branch_tree;
+use codex_core::find_thread_names_by_ids;
+use codex_core::import_session_bundle;
+use codex_core::load_audit_log;
+use codex_core::load_branch_turns;
+use codex_core::load_session_patches;
+use codex_core::read_session_meta_line;
+use codex_core::restore_session;
+use codex_core::session_sync::sync_sessions;
+use codex_core::shared_turn_count;
+use codex_core::side_by_side_patch_diff;
+use codex_core::unified_patch_diff;
+
+use crate::export_cmd::find_session;
+
+/// Subcommands:
+/// - `export` — pack a recorded session into a portable bundle
+/// - `import` — restore a bundle so the session can be resumed here
+/// - `tree` — show the fork graph a session belongs to
+/// - `diff` — compare the file changes two sessions made
+/// - `audit` — print the actions a session took as JSON lines
+/// - `list` — list recorded sessions, or archived ones with `--archived`
+/// - `archive` / `restore` — hide sessions from the resume pickers and bring
+///   them back
+/// - `sync` — exchange sessions with other machines through the `[sync]` remote
+#[derive(Debug, clap::Parser)]
+pub struct SessionsCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: SessionsSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum SessionsSubcommand {
+    /// Pack a session's rollout, referenced images, and metadata into one file.
+    Export(ExportArgs),
+    /// Restore a session bundle so `codex resume` can continue it on this machine.
+    Import(ImportArgs),
+    /// Show the forks of a session as a tree, optionally diffing two branches.
+    Tree(TreeArgs),
+    /// Compare the file changes two sessions made with apply_patch.
+    Diff(DiffArgs),
+    /// Print every file write, command, network host, and approval decision of a
+    /// session as chronological JSON lines.
+    Audit(AuditArgs),
+    /// List recorded sessions, most recently updated first.
+    List(ListArgs),
+    /// Archive sessions so they no longer appear in `codex resume`.
+    Archive(ArchiveArgs),
+    /// Move archived sessions back so they can be resumed.
+    Restore(RestoreArgs),
+    /// Push and pull sessions through the encrypted `[sync]` remote.
+    Sync,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ExportArgs {
+    /// Session id (UUID) or thread name to export.
+    #[arg(value_name = "SESSION_ID")]
+    pub session_id: String,
+
+    /// Bundle file to write. Defaults to `<id>.codexsession` in the current directory.
+    #[arg(long, value_name = "FILE")]
+    pub bundle: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ImportArgs {
+    /// Bundle written by `codex sessions export`.
+    #[arg(value_name = "FILE")]
+    pub bundle: PathBuf,
+
+    /// What to do when a session with the same id already exists here.
+    #[arg(long, value_enum, default_value_t = ConflictArg::Fail)]
+    pub on_conflict: ConflictArg,
+
+    /// Record this directory as the session's working directory instead of the original one.
+    #[arg(long = "cd", short = 'C', value_name = "DIR")]
+    pub cwd: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct TreeArgs {
+    /// Session id (UUID) or thread name of any branch in the tree.
+    #[arg(value_name = "SESSION_ID")]
+    pub session_id: String,
+
+    /// Show where this session and another branch diverge.
+    #[arg(long, value_name = "OTHER_ID")]
+    pub diff: Option<String>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct DiffArgs {
+    /// Session id (UUID) or thread name of the first session (A).
+    #[arg(value_name = "SESSION_A")]
+    pub session_a: String,
+
+    /// Session id (UUID) or thread name of the second session (B).
+    #[arg(value_name = "SESSION_B")]
+    pub session_b: String,
+
+    /// Show differing changes in two columns instead of a unified diff.
+    #[arg(long)]
+    pub side_by_side: bool,
+
+    /// Column width for `--side-by-side`.
+    #[arg(
+        long,
+        value_name = "COLUMNS",
+        default_value_t = 60,
+        requires = "side_by_side"
+    )]
+    pub width: usize,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AuditArgs {
+    /// Session id (UUID) or thread name to audit.
+    #[arg(value_name = "SESSION_ID")]
+    pub session_id: String,
+
+    /// Write the log to this file instead of stdout.
+    #[arg(long, short = 'o', value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ListArgs {
+    /// List archived sessions instead of active ones.
+    #[arg(long)]
+    pub archived: bool,
+
+    /// Maximum number of sessions to print.
+    #[arg(long, short = 'n', value_name = "COUNT", default_value_t = 20)]
+    pub limit: usize,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ArchiveArgs {
+    /// Session ids (UUID) or thread names to archive.
+    #[arg(value_name = "SESSION_ID", required = true)]
+    pub session_ids: Vec<String>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct RestoreArgs {
+    /// Session ids (UUID) of archived sessions to restore.
+    #[arg(
+        value_name = "SESSION_ID",
+        required_unless_present = "all",
+        conflicts_with = "all"
+    )]
+    pub session_ids: Vec<String>,
+
+    /// Restore every archived session.
+    #[arg(long)]
+    pub all: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ConflictArg {
+    /// Stop without importing.
+    Fail,
+    /// Import under a newly generated session id.
+    NewId,
+    /// Overwrite the existing session.
+    Replace,
+}
+
+impl From<ConflictArg> for ImportConflict {
+    fn from(value: ConflictArg) -> Self {
+        match value {
+            ConflictArg::Fail => ImportConflict::Fail,
+            ConflictArg::NewId => ImportConflict::NewId,
+            ConflictArg::Replace => ImportConflict::Replace,
+        }
+    }
+}
+
+impl SessionsCli {
+    pub async fn run(self) -> Result<()> {
+        let SessionsCli {
+            config_overrides,
+            subcommand,
+        } = self;
+        let overrides = config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides_and_harness_overrides(
+            overrides,
+            ConfigOverrides::default(),
+        )
+        .await
+        .context("failed to load configuration")?;
+
+        match subcommand {
+            SessionsSubcommand::Export(args) => run_export(&config, args).await,
+            SessionsSubcommand::Import(args) => run_import(&config, args).await,
+            SessionsSubcommand::Tree(args) => run_tree(&config, args).await,
+            SessionsSubcommand::Diff(args) => run_diff(&config, args).await,
+            SessionsSubcommand::Audit(args) => run_audit(&config, args).await,
+            SessionsSubcommand::List(args) => run_list(&config, args).await,
+            SessionsSubcommand::Archive(args) => run_archive(&config, args).await,
+            SessionsSubcommand::Restore(args) => run_restore(&config, args).await,
+            SessionsSubcommand::Sync => run_sync(&config).await,
+        }
+    }
+}
+
+async fn run_export(config: &Config, args: ExportArgs) -> Result<()> {
+    let rollout_path = find_session(config, &args.session_id).await?;
+    let bundle = args.bundle.unwrap_or_else(|| {
+        PathBuf::from(format!("{}.{SESSION_BUNDLE_EXTENSION}", args.session_id))
+    });
+    let exported = export_session_bundle(&config.codex_home, &rollout_path, &bundle)
+        .await
+        .with_context(|| format!("failed to write {}", bundle.display()))?;
+
+    println!(
+        "Exported session {} with {} image(s) to {}",
+        exported.thread_id,
+        exported.images,
+        bundle.display()
+    );
+    for missing in exported.missing_images {
+        eprintln!("note: skipped missing image {}", missing.display());
+    }
+    Ok(())
+}
+
+async fn run_import(config: &Config, args: ImportArgs) -> Result<()> {
+    let cwd = args.cwd.map(|cwd| cwd.canonicalize().unwrap_or(cwd));
+    let options = SessionBundleImportOptions {
+        on_conflict: args.on_conflict.into(),
+        cwd,
+    };
+    let imported = import_session_bundle(&config.codex_home, &args.bundle, &options)
+        .await
+        .with_context(|| format!("failed to import {}", args.bundle.display()))?;
+
+    if let Some(original) = imported.remapped_from {
+        println!(
+            "Session {original} already exists; imported as {}",
+            imported.thread_id
+        );
+    } else {
+        println!("Imported session {}", imported.thread_id);
+    }
+    println!("Continue it with `codex resume {}`", imported.thread_id);
+    Ok(())
+}
+
+async fn run_tree(config: &Config, args: TreeArgs) -> Result<()> {
+    let rollout_path = find_session(config, &args.session_id).await?;
+    let thread_id = read_session_meta_line(&rollout_path)
+        .await
+        .with_context(|| format!("failed to read {}", rollout_path.display()))?
+        .meta
+        .id;
+    let tree = find_branch_tree(&config.codex_home, thread_id)
+        .await
+        .context("failed to scan recorded sessions")?
+        .with_context(|| format!("no recorded session matches `{}`", args.session_id))?;
+
+    for (prefix, node) in tree.tree_rows() {
+        let marker = if node.thread_id == thread_id {
+            " *"
+        } else {
+            ""
+        };
+        println!("{prefix}{}{marker}", describe_branch(node));
+    }
+
+    if let Some(other) = args.diff {
+        let other_path = find_session(config, &other).await?;
+        let ours = load_branch_turns(&rollout_path)
+            .await
+            .with_context(|| format!("failed to read {}", rollout_path.display()))?;
+        let theirs = load_branch_turns(&other_path)
+            .await
+            .with_context(|| format!("failed to read {}", other_path.display()))?;
+        let shared = shared_turn_count(&ours, &theirs);
+        println!();
+        println!("The branches share {shared} turn(s).");
+        print_branch_tail(&args.session_id, &ours[shared..]);
+        print_branch_tail(&other, &theirs[shared..]);
+    } else {
+        println!();
+        println!("Continue a branch with `codex resume <id>`.");
+    }
+    Ok(())
+}
+
+fn describe_branch(node: &BranchNode) -> String {
+    let mut line = format!("{}  {}", node.thread_id, node.timestamp);
+    if let Some(turn) = node.fork_turn {
+        line.push_str(&format!("  forked after turn {turn}"));
+    }
+    if let Some(name) = &node.thread_name {
+        line.push_str(&format!("  {name}"));
+    }
+    line
+}
+
+fn print_branch_tail(label: &str, turns: &[BranchTurn]) {
+    println!();
+    if turns.is_empty() {
+        println!("{label}: no further turns");
+        return;
+    }
+    println!("{label}:");
+    for turn in turns {
+        println!("  > {}", first_line(&turn.prompt));
+        if let Some(reply) = &turn.reply {
+            println!("    {}", first_line(reply));
+        }
+    }
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or_default()
+}
+
+async fn run_diff(config: &Config, args: DiffArgs) -> Result<()> {
+    let path_a = find_session(config, &args.session_a).await?;
+    let path_b = find_session(config, &args.session_b).await?;
+    let patches_a = load_session_patches(&path_a)
+        .await
+        .with_context(|| format!("failed to read {}", path_a.display()))?;
+    let patches_b = load_session_patches(&path_b)
+        .await
+        .with_context(|| format!("failed to read {}", path_b.display()))?;
+
+    let files = compare_session_patches(&patches_a, &patches_b);
+    if files.is_empty() {
+        println!("Neither session changed files with apply_patch.");
+        return Ok(());
+    }
+
+    println!("A: {}", args.session_a);
+    println!("B: {}", args.session_b);
+    for file in files {
+        let path = file.path.display();
+        println!();
+        match file.comparison {
+            PatchComparison::Same => println!("== {path}: same changes in A and B"),
+            PatchComparison::OnlyA(changes) => {
+                println!("== {path}: changed only in A");
+                print_changes(&changes, "", &args);
+            }
+            PatchComparison::OnlyB(changes) => {
+                println!("== {path}: changed only in B");
+                print_changes("", &changes, &args);
+            }
+            PatchComparison::Different { a, b } => {
+                println!("== {path}: changed differently");
+                print_changes(&a, &b, &args);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_changes(a: &str, b: &str, args: &DiffArgs) {
+    if args.side_by_side {
+        for row in side_by_side_patch_diff(a, b, args.width) {
+            println!("{row}");
+        }
+    } else if a.is_empty() || b.is_empty() {
+        print!("{a}{b}");
+    } else {
+        print!("{}", unified_patch_diff(a, b, "A", "B"));
+    }
+}
+
+async fn run_audit(config: &Config, args: AuditArgs) -> Result<()> {
+    let rollout_path = find_session(config, &args.session_id).await?;
+    let entries = load_audit_log(&rollout_path)
+        .await
+        .with_context(|| format!("failed to read {}", rollout_path.display()))?;
+    let log = audit_jsonl(&entries)?;
+    match args.output {
+        Some(output) => {
+            std::fs::write(&output, log)
+                .with_context(|| format!("failed to write {}", output.display()))?;
+            eprintln!(
+                "Wrote {} audit entries to {}",
+                entries.len(),
+                output.display()
+            );
+        }
+        None => print!("{log}"),
+    }
+    Ok(())
+}
+
+fn audit_jsonl(entries: &[AuditEntry]) -> Result<String> {
+    let mut log = String::new();
+    for entry in entries {
+        log.push_str(&serde_json::to_string(entry)?);
+        log.push('\n');
+    }
+    Ok(log)
+}
+
+const LIST_PAGE_SIZE: usize = 50;
+
+async fn run_list(config: &Config, args: ListArgs) -> Result<()> {
+    let threads = list_sessions(config, args.archived, Some(args.limit)).await?;
+    if threads.is_empty() {
+        if args.archived {
+            println!("No archived sessions.");
+        } else {
+            println!("No recorded sessions.");
+        }
+        return Ok(());
+    }
+
+    let ids: HashSet<_> = threads
+        .iter()
+        .filter_map(|thread| thread.thread_id)
+        .collect();
+    let names = find_thread_names_by_ids(&config.codex_home, &ids)
+        .await
+        .unwrap_or_default();
+    for thread in &threads {
+        let id = thread
+            .thread_id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        let updated_at = thread
+            .updated_at
+            .as_deref()
+            .or(thread.created_at.as_deref())
+            .unwrap_or("-");
+        let title = thread
+            .thread_id
+            .and_then(|id| names.get(&id).cloned())
+            .or_else(|| {
+                thread
+                    .first_user_message
+                    .as_deref()
+                    .map(|message| first_line(message).to_string())
+            })
+            .unwrap_or_default();
+        println!("{id}  {updated_at}  {title}");
+    }
+    Ok(())
+}
+
+/// Recorded sessions from every source and provider, most recently updated
+/// first, stopping after `limit` when given.
+pub(crate) async fn list_sessions(
+    config: &Config,
+    archived: bool,
+    limit: Option<usize>,
+) -> Result<Vec<ThreadItem>> {
+    let mut threads = Vec::new();
+    let mut cursor: Option<Cursor> = None;
+    loop {
+        let page = if archived {
+            RolloutRecorder::list_archived_threads(
+                config,
+                LIST_PAGE_SIZE,
+                cursor.as_ref(),
+                ThreadSortKey::UpdatedAt,
+                &[],
+                None,
+                &config.model_provider_id,
+            )
+            .await
+        } else {
+            RolloutRecorder::list_threads(
+                config,
+                LIST_PAGE_SIZE,
+                cursor.as_ref(),
+                ThreadSortKey::UpdatedAt,
+                &[],
+                None,
+                &config.model_provider_id,
+            )
+            .await
+        }
+        .context("failed to list recorded sessions")?;
+        threads.extend(page.items);
+        if let Some(limit) = limit
+            && threads.len() >= limit
+        {
+            threads.truncate(limit);
+            break;
+        }
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    Ok(threads)
+}
+
+async fn run_archive(config: &Config, args: ArchiveArgs) -> Result<()> {
+    for id in &args.session_ids {
+        let rollout_path = find_session(config, id).await?;
+        archive_session(config, &rollout_path)
+            .await
+            .with_context(|| format!("failed to archive `{id}`"))?;
+        println!("Archived {id}");
+    }
+    println!();
+    println!("Restore with `codex sessions restore <id>`.");
+    Ok(())
+}
+
+async fn run_restore(config: &Config, args: RestoreArgs) -> Result<()> {
+    let targets: Vec<(String, PathBuf)> = if args.all {
+        list_sessions(config, true, None)
+            .await?
+            .into_iter()
+            .map(|thread| {
+                let label = thread
+                    .thread_id
+                    .map(|id| id.to_string())
+                    .unwrap_or_else(|| thread.path.display().to_string());
+                (label, thread.path)
+            })
+            .collect()
+    } else {
+        let mut targets = Vec::new();
+        for id in args.session_ids {
+            let path = find_archived_thread_path_by_id_str(&config.codex_home, &id)
+                .await?
+                .with_context(|| format!("no archived session matches `{id}`"))?;
+            targets.push((id, path));
+        }
+        targets
+    };
+    if targets.is_empty() {
+        println!("No archived sessions.");
+        return Ok(());
+    }
+
+    for (label, path) in targets {
+        restore_session(config, &path)
+            .await
+            .with_context(|| format!("failed to restore `{label}`"))?;
+        println!("Restored {label}");
+    }
+    Ok(())
+}
+
+async fn run_sync(config: &Config) -> Result<()> {
+    let summary = sync_sessions(config)
+        .await
+        .context("failed to sync sessions")?;
+    println!(
+        "Pushed {} and pulled {} session(s); {} already up to date.",
+        summary.pushed, summary.pulled, summary.unchanged
+    );
+    println!("Session index: {} entries.", summary.index_entries);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn restore_takes_ids_or_all() {
+        assert!(SessionsCli::try_parse_from(["sessions", "restore"]).is_err());
+        assert!(SessionsCli::try_parse_from(["sessions", "restore", "--all", "abc"]).is_err());
+
+        let cli = SessionsCli::try_parse_from(["sessions", "restore", "abc", "def"])
+            .expect("parse restore ids");
+        let SessionsSubcommand::Restore(args) = cli.subcommand else {
+            panic!("expected restore subcommand");
+        };
+        assert_eq!(args.session_ids, vec!["abc".to_string(), "def".to_string()]);
+        assert!(!args.all);
+    }
+}
@@ -0,0 +1,161 @@
+//! Shell hooks behind `codex fix` and `codex explain`.
+//!
+//! `codex shell-init <shell>` prints a hook that keeps the last command line
+//! and its exit status in unexported shell variables (skipping `codex fix` and
+//! `codex explain` themselves, so they can be repeated), plus a `codex` shell
+//! function that passes them to `codex fix` / `codex explain` as the hidden
+//! `--last-command` / `--last-status` flags. Command lines can hold secrets,
+//! so they never reach the environment of other programs. `codex fix` and
+//! `codex explain` start an interactive session whose first prompt asks the
+//! model to diagnose the command; any command it runs goes through the usual
+//! approval flow.
+
+use anyhow::Result;
+use anyhow::bail;
+
+const BASH_HOOK: &str = include_str!("shell_integration/codex.bash");
+const ZSH_HOOK: &str = include_str!("shell_integration/codex.zsh");
+const FISH_HOOK: &str = include_str!("shell_integration/codex.fish");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum HookShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+#[derive(Debug, clap::Parser)]
+pub(crate) struct ShellInitCommand {
+    /// 要生成钩子脚本的 shell。
+    #[clap(value_enum)]
+    pub(crate) shell: HookShell,
+}
+
+impl ShellInitCommand {
+    pub(crate) fn run(&self) {
+        print!("{}", hook_script(self.shell));
+    }
+}
+
+fn hook_script(shell: HookShell) -> &'static str {
+    match shell {
+        HookShell::Bash => BASH_HOOK,
+        HookShell::Zsh => ZSH_HOOK,
+        HookShell::Fish => FISH_HOOK,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LastCommandAction {
+    Fix,
+    Explain,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LastCommand {
+    pub(crate) command: String,
+    pub(crate) status: i32,
+}
+
+impl LastCommand {
+    /// The command the `codex shell-init` hook passed on the command line.
+    pub(crate) fn from_args(command: Option<String>, status: Option<String>) -> Result<Self> {
+        let command = command.map(|command| command.trim().to_string());
+        let (Some(command), Some(status)) = (command.filter(|c| !c.is_empty()), status) else {
+            bail!(
+                "No command recorded. Enable the shell hook first, e.g. add \
+                 `eval \"$(codex shell-init zsh)\"` to ~/.zshrc (see `codex shell-init --help`)."
+            );
+        };
+        let Ok(status) = status.trim().parse() else {
+            bail!("--last-status is not an exit status: {status:?}");
+        };
+        Ok(Self { command, status })
+    }
+}
+
+/// First prompt of a `codex fix` / `codex explain` session. `note` is extra
+/// context passed on the command line.
+pub(crate) fn last_command_prompt(
+    action: LastCommandAction,
+    last: &LastCommand,
+    note: Option<&str>,
+) -> Result<String> {
+    if action == LastCommandAction::Fix && last.status == 0 {
+        bail!(
+            "The last command succeeded, so there is nothing to fix: {}",
+            last.command
+        );
+    }
+    let outcome = if last.status == 0 {
+        "succeeded".to_string()
+    } else {
+        format!("failed with exit status {}", last.status)
+    };
+    let ask = match action {
+        LastCommandAction::Fix => {
+            "Diagnose why it failed. Re-run it or inspect files if you need its output. \
+             Then propose a corrected command, run it to confirm it works, and explain \
+             the change in a sentence or two. Don't change anything unrelated."
+        }
+        LastCommandAction::Explain => {
+            "Explain what went wrong and what would fix it. Re-run it or inspect files if \
+             you need its output, but don't change any files or run commands with side effects."
+        }
+    };
+    let mut prompt = format!(
+        "My last shell command {outcome}:\n\n```sh\n{}\n```\n\n{ask}",
+        last.command
+    );
+    if let Some(note) = note.map(str::trim).filter(|note| !note.is_empty()) {
+        prompt.push_str(&format!("\n\nMore context: {note}"));
+    }
+    Ok(prompt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn reads_command_and_status_from_hook_args() {
+        assert_eq!(
+            LastCommand::from_args(Some("cargo test -p foo\n".into()), Some("101".into())).unwrap(),
+            LastCommand {
+                command: "cargo test -p foo".to_string(),
+                status: 101,
+            }
+        );
+        assert!(LastCommand::from_args(None, Some("1".into())).is_err());
+        assert!(LastCommand::from_args(Some(String::new()), Some(String::new())).is_err());
+        assert!(LastCommand::from_args(Some("make".into()), Some("x".into())).is_err());
+    }
+
+    #[test]
+    fn fix_prompt_names_the_command_and_refuses_successes() {
+        let failed = LastCommand {
+            command: "npm run build".to_string(),
+            status: 2,
+        };
+        assert_eq!(
+            last_command_prompt(LastCommandAction::Fix, &failed, Some("worked on main")).unwrap(),
+            "My last shell command failed with exit status 2:\n\n```sh\nnpm run build\n```\n\n\
+             Diagnose why it failed. Re-run it or inspect files if you need its output. \
+             Then propose a corrected command, run it to confirm it works, and explain \
+             the change in a sentence or two. Don't change anything unrelated.\n\n\
+             More context: worked on main"
+        );
+
+        let succeeded = LastCommand {
+            command: "ls".to_string(),
+            status: 0,
+        };
+        assert!(last_command_prompt(LastCommandAction::Fix, &succeeded, None).is_err());
+        assert!(
+            last_command_prompt(LastCommandAction::Explain, &succeeded, None)
+                .unwrap()
+                .starts_with("My last shell command succeeded:")
+        );
+    }
+}
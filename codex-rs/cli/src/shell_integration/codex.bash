@@ -0,0 +1,41 @@
+# Codex shell integration for bash: records the last command and its exit
+# status for `codex fix` and `codex explain`.
+# Enable with: eval "$(codex shell-init bash)"
+
+__codex_record_last_command() {
+    local last_status=$?
+    local last_command
+    last_command=$(HISTTIMEFORMAT= builtin history 1)
+    last_command="${last_command#"${last_command%%[![:space:]]*}"}"
+    last_command="${last_command#*[[:space:]]}"
+    last_command="${last_command#"${last_command%%[![:space:]]*}"}"
+    case "$last_command" in
+        "" | "codex fix"* | "codex explain"*) ;;
+        *)
+            __codex_last_command="$last_command"
+            __codex_last_status="$last_status"
+            ;;
+    esac
+    return $last_status
+}
+
+# The recorded command is handed to `codex fix` / `codex explain` as
+# arguments; it is never exported to other programs.
+codex() {
+    case "$1" in
+        fix | explain)
+            local action="$1"
+            shift
+            command codex "$action" \
+                --last-command="${__codex_last_command-}" \
+                --last-status="${__codex_last_status-}" \
+                "$@"
+            ;;
+        *) command codex "$@" ;;
+    esac
+}
+
+case ";${PROMPT_COMMAND:-};" in
+    *";__codex_record_last_command;"*) ;;
+    *) PROMPT_COMMAND="__codex_record_last_command${PROMPT_COMMAND:+;$PROMPT_COMMAND}" ;;
+esac
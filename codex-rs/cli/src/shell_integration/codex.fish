@@ -0,0 +1,27 @@
+# Codex shell integration for fish: records the last command and its exit
+# status for `codex fix` and `codex explain`.
+# Enable with: codex shell-init fish | source
+
+function __codex_record_last_command --on-event fish_postexec
+    set -l last_status $status
+    switch "$argv[1]"
+        case '' 'codex fix*' 'codex explain*'
+            return
+    end
+    set -g __codex_last_command $argv[1]
+    set -g __codex_last_status $last_status
+end
+
+# The recorded command is handed to `codex fix` / `codex explain` as
+# arguments; it is never exported to other programs.
+function codex
+    switch "$argv[1]"
+        case fix explain
+            command codex $argv[1] \
+                "--last-command=$__codex_last_command" \
+                "--last-status=$__codex_last_status" \
+                $argv[2..-1]
+        case '*'
+            command codex $argv
+    end
+end
@@ -0,0 +1,467 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use clap::Parser;
+use codex_common::CliConfigOverrides;
+use codex_common::SandboxModeCliArg;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use codex_core::config::find_codex_home;
+use codex_core::skills::SkillScaffold;
+use codex_core::skills::SkillsManager;
+use codex_core::skills::harness::SkillIssueSeverity;
+use codex_core::skills::harness::render_skill_test_turn;
+use codex_core::skills::harness::skill_test_prompt;
+use codex_core::skills::harness::validate_skill;
+use codex_core::skills::install::InstallOptions;
+use codex_core::skills::install::LockedSkill;
+use codex_core::skills::install::SkillSource;
+use codex_core::skills::install::SkillUpdateStatus;
+use codex_core::skills::install::check_skill_updates;
+use codex_core::skills::install::install_skill;
+use codex_core::skills::install::update_skills;
+use codex_core::skills::model::SkillToolDependency;
+use codex_core::skills::scaffold_skill;
+use codex_exec::Cli as ExecCli;
+use codex_protocol::protocol::SkillScope;
+
+/// Inexpensive model used by `skills test` unless `--model` is given.
+const DEFAULT_TEST_MODEL: &str = "gpt-5.1-codex-mini";
+
+/// Subcommands:
+/// - `new`     — scaffold a skill directory and validate it
+/// - `install` — install a skill from a git URL or registry index into `$CODEX_HOME/skills`
+/// - `update`  — check for and apply updates to installed skills
+/// - `test`    — validate a skill and run it once against a model (or render it with `--dry-run`)
+#[derive(Debug, clap::Parser)]
+pub struct SkillsCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: SkillsSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum SkillsSubcommand {
+    /// Scaffold a new skill (SKILL.md plus optional agents/openai.yaml).
+    New(NewArgs),
+    /// Install a skill from a git repository or registry index.
+    Install(InstallArgs),
+    /// Check for or apply updates to skills installed with `install`.
+    Update(UpdateArgs),
+    /// Validate a skill's metadata and run it once in a read-only, ephemeral session.
+    Test(TestArgs),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct NewArgs {
+    /// Skill name (lowercase letters, digits, and hyphens). Also used as the directory name.
+    pub name: String,
+
+    /// Description used by the model to decide when to use the skill.
+    #[arg(long, short = 'd')]
+    pub description: Option<String>,
+
+    /// Short description shown in the `$` skill picker.
+    #[arg(long = "short-description", value_name = "TEXT")]
+    pub short_description: Option<String>,
+
+    /// Prompt inserted into the composer when the skill is selected.
+    #[arg(long = "default-prompt", value_name = "TEXT")]
+    pub default_prompt: Option<String>,
+
+    /// Declare a tool dependency, e.g. `mcp:github` or `env_var:GITHUB_TOKEN` (repeatable).
+    #[arg(long = "tool", value_name = "TYPE:VALUE", value_parser = parse_tool_dependency)]
+    pub tools: Vec<SkillToolDependency>,
+
+    /// Create the skill in `.codex/skills` under the current directory instead of `$CODEX_HOME/skills`.
+    #[arg(long, conflicts_with = "dir")]
+    pub repo: bool,
+
+    /// Create the skill under this skills root instead of `$CODEX_HOME/skills`.
+    #[arg(long, value_name = "DIR")]
+    pub dir: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct InstallArgs {
+    /// Git URL (or local repository path), or `name[@version]` when using --registry.
+    pub source: String,
+
+    /// Branch, tag, or commit to check out. Pins the skill so `update` leaves it alone.
+    #[arg(long = "ref", value_name = "REF")]
+    pub reference: Option<String>,
+
+    /// Directory inside the repository that contains SKILL.md.
+    #[arg(long, value_name = "PATH")]
+    pub path: Option<PathBuf>,
+
+    /// Registry index (URL or file path) used to resolve `name[@version]` sources.
+    #[arg(long, value_name = "URL|PATH")]
+    pub registry: Option<String>,
+
+    /// Install under this directory name instead of the skill's declared name.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Expected integrity hash (`sha256:<hex>`) of the skill files.
+    #[arg(long, value_name = "HASH")]
+    pub integrity: Option<String>,
+
+    /// Replace an existing skill directory, discarding local modifications.
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct UpdateArgs {
+    /// Only update this skill.
+    pub name: Option<String>,
+
+    /// Report available updates without installing them.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Overwrite skills with local modifications.
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct TestArgs {
+    /// Name of the skill to test.
+    pub name: String,
+
+    /// Text sent after the `$name` mention. Defaults to the skill's `interface.default_prompt`.
+    #[arg(long, short = 'i', value_name = "TEXT")]
+    pub input: Option<String>,
+
+    /// Model used for the run.
+    #[arg(long, short = 'm', default_value = DEFAULT_TEST_MODEL)]
+    pub model: String,
+
+    /// Validate and print the rendered skill turn without calling a model.
+    #[arg(long = "dry-run")]
+    pub dry_run: bool,
+
+    /// Directory used to discover repository skills and as the session's working root.
+    #[arg(long = "cd", short = 'C', value_name = "DIR")]
+    pub cwd: Option<PathBuf>,
+}
+
+impl SkillsCli {
+    pub async fn run(self, codex_linux_sandbox_exe: Option<PathBuf>) -> Result<()> {
+        let SkillsCli {
+            config_overrides,
+            subcommand,
+        } = self;
+
+        match subcommand {
+            SkillsSubcommand::New(args) => {
+                run_new(&config_overrides, args)?;
+            }
+            SkillsSubcommand::Install(args) => {
+                run_install(&config_overrides, args).await?;
+            }
+            SkillsSubcommand::Update(args) => {
+                run_update(&config_overrides, args).await?;
+            }
+            SkillsSubcommand::Test(args) => {
+                run_test(config_overrides, args, codex_linux_sandbox_exe).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn run_new(config_overrides: &CliConfigOverrides, new_args: NewArgs) -> Result<()> {
+    config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+
+    let NewArgs {
+        name,
+        description,
+        short_description,
+        default_prompt,
+        tools,
+        repo,
+        dir,
+    } = new_args;
+
+    let (skills_root, scope) = if let Some(dir) = dir {
+        (dir, SkillScope::User)
+    } else if repo {
+        let cwd = std::env::current_dir().context("failed to resolve current directory")?;
+        (cwd.join(".codex").join("skills"), SkillScope::Repo)
+    } else {
+        let codex_home = find_codex_home().context("failed to resolve CODEX_HOME")?;
+        (codex_home.join("skills"), SkillScope::User)
+    };
+
+    let description = description.unwrap_or_else(|| {
+        format!("Describe what the {name} skill does and when Codex should use it.")
+    });
+    let scaffold = SkillScaffold {
+        name,
+        description,
+        short_description,
+        default_prompt,
+        tools,
+    };
+
+    let skill = scaffold_skill(&skills_root, scope, &scaffold)?;
+    let skill_dir = skill
+        .path
+        .parent()
+        .map(std::path::Path::to_path_buf)
+        .unwrap_or_else(|| skill.path.clone());
+
+    println!("Created skill '{}' at {}.", skill.name, skill_dir.display());
+    if let Some(dependencies) = &skill.dependencies {
+        for tool in &dependencies.tools {
+            println!("  depends on {}: {}", tool.r#type, tool.value);
+        }
+    }
+    println!(
+        "Edit {} to add instructions. Running sessions pick up the skill automatically; mention it with ${}.",
+        skill.path.display(),
+        skill.name
+    );
+
+    Ok(())
+}
+
+async fn run_install(
+    config_overrides: &CliConfigOverrides,
+    install_args: InstallArgs,
+) -> Result<()> {
+    config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+
+    let InstallArgs {
+        source,
+        reference,
+        path,
+        registry,
+        name,
+        integrity,
+        force,
+    } = install_args;
+
+    let source = SkillSource::parse(&source, reference, path, registry)?;
+    let codex_home = find_codex_home().context("failed to resolve CODEX_HOME")?;
+    let options = InstallOptions {
+        name,
+        integrity,
+        force,
+    };
+    let installed = install_skill(&codex_home, &source, &options).await?;
+
+    let verb = if installed.replaced {
+        "Reinstalled"
+    } else {
+        "Installed"
+    };
+    println!(
+        "{verb} skill '{}' at {} ({}).",
+        installed.name,
+        installed.path.display(),
+        describe_revision(&installed.locked)
+    );
+    println!("  integrity: {}", installed.locked.integrity);
+    Ok(())
+}
+
+async fn run_update(config_overrides: &CliConfigOverrides, update_args: UpdateArgs) -> Result<()> {
+    config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+
+    let UpdateArgs { name, check, force } = update_args;
+    let codex_home = find_codex_home().context("failed to resolve CODEX_HOME")?;
+
+    if check {
+        let statuses = check_skill_updates(&codex_home, name.as_deref()).await?;
+        if statuses.is_empty() {
+            println!("No skills installed with `codex skills install`.");
+        }
+        for (name, status) in statuses {
+            let status = match status {
+                SkillUpdateStatus::UpToDate => "up to date".to_string(),
+                SkillUpdateStatus::Pinned => "pinned".to_string(),
+                SkillUpdateStatus::Available { current, latest } => {
+                    format!(
+                        "update available ({} -> {})",
+                        short_rev(&current),
+                        short_rev(&latest)
+                    )
+                }
+                SkillUpdateStatus::LocallyModified => {
+                    "locally modified (use --force to overwrite)".to_string()
+                }
+                SkillUpdateStatus::Missing => "missing (will be reinstalled)".to_string(),
+                SkillUpdateStatus::Error(err) => format!("error: {err}"),
+            };
+            println!("{name}: {status}");
+        }
+        return Ok(());
+    }
+
+    let updated = update_skills(&codex_home, name.as_deref(), force).await?;
+    if updated.is_empty() {
+        println!("All installed skills are up to date.");
+    }
+    for installed in updated {
+        println!(
+            "Updated skill '{}' ({}).",
+            installed.name,
+            describe_revision(&installed.locked)
+        );
+    }
+    Ok(())
+}
+
+async fn run_test(
+    config_overrides: CliConfigOverrides,
+    test_args: TestArgs,
+    codex_linux_sandbox_exe: Option<PathBuf>,
+) -> Result<()> {
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+
+    let TestArgs {
+        name,
+        input,
+        model,
+        dry_run,
+        cwd,
+    } = test_args;
+
+    let config = Config::load_with_cli_overrides_and_harness_overrides(
+        overrides,
+        ConfigOverrides {
+            cwd: cwd.clone(),
+            ..Default::default()
+        },
+    )
+    .await
+    .context("failed to load configuration")?;
+    let outcome = SkillsManager::new(config.codex_home.clone()).skills_for_config(&config);
+    let Some(skill) = outcome.skills.iter().find(|skill| skill.name == name) else {
+        anyhow::bail!("no skill named '{name}' found for {}", config.cwd.display());
+    };
+
+    println!("Testing skill '{}' ({}).", skill.name, skill.path.display());
+    let issues = validate_skill(skill);
+    let mut error_count = 0;
+    for issue in &issues {
+        let label = match issue.severity {
+            SkillIssueSeverity::Error => {
+                error_count += 1;
+                "error"
+            }
+            SkillIssueSeverity::Warning => "warning",
+        };
+        println!("  {label}: {}", issue.message);
+    }
+    if error_count > 0 {
+        anyhow::bail!("skill '{name}' failed validation with {error_count} error(s)");
+    }
+    if issues.is_empty() {
+        println!("  metadata OK");
+    }
+
+    let input = input
+        .or_else(|| {
+            skill
+                .interface
+                .as_ref()
+                .and_then(|interface| interface.default_prompt.clone())
+        })
+        .context("pass --input or declare interface.default_prompt in agents/openai.yaml")?;
+
+    if dry_run {
+        println!();
+        println!("{}", render_skill_test_turn(skill, &input)?);
+        return Ok(());
+    }
+
+    if !outcome.is_skill_enabled(skill) {
+        let reason = if outcome.untrusted_paths.contains(&skill.path) {
+            "it has not been trusted yet (see [skills].trusted)"
+        } else {
+            "it is disabled in [[skills.config]]"
+        };
+        anyhow::bail!(
+            "skill '{name}' would not load because {reason}; use --dry-run to render it anyway"
+        );
+    }
+
+    let mut exec_cli = ExecCli::try_parse_from(["codex", "exec"])?;
+    exec_cli.prompt = Some(skill_test_prompt(skill, &input));
+    exec_cli.model = Some(model);
+    exec_cli.sandbox_mode = Some(SandboxModeCliArg::ReadOnly);
+    exec_cli.ephemeral = true;
+    exec_cli.skip_git_repo_check = true;
+    exec_cli.cwd = cwd;
+    exec_cli.config_overrides = config_overrides;
+    codex_exec::run_main(exec_cli, codex_linux_sandbox_exe).await
+}
+
+fn describe_revision(locked: &LockedSkill) -> String {
+    let commit = short_rev(&locked.commit);
+    match &locked.version {
+        Some(version) => format!("version {version}, commit {commit}"),
+        None => format!("commit {commit}"),
+    }
+}
+
+fn short_rev(rev: &str) -> &str {
+    rev.get(..12).unwrap_or(rev)
+}
+
+fn parse_tool_dependency(raw: &str) -> Result<SkillToolDependency, String> {
+    let (kind, value) = raw
+        .split_once(':')
+        .map(|(kind, value)| (kind.trim(), value.trim()))
+        .filter(|(kind, value)| !kind.is_empty() && !value.is_empty())
+        .ok_or_else(|| "tool dependencies must be in TYPE:VALUE form".to_string())?;
+
+    Ok(SkillToolDependency {
+        r#type: kind.to_string(),
+        value: value.to_string(),
+        description: None,
+        transport: None,
+        command: None,
+        url: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_tool_dependency_splits_type_and_value() {
+        assert_eq!(
+            parse_tool_dependency("env_var:GITHUB_TOKEN"),
+            Ok(SkillToolDependency {
+                r#type: "env_var".to_string(),
+                value: "GITHUB_TOKEN".to_string(),
+                description: None,
+                transport: None,
+                command: None,
+                url: None,
+            })
+        );
+        assert!(parse_tool_dependency("github").is_err());
+        assert!(parse_tool_dependency("mcp:").is_err());
+    }
+}
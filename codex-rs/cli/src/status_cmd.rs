@@ -0,0 +1,131 @@
+use anyhow::Context;
+use anyhow::Result;
+use codex_common::CliConfigOverrides;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use codex_core::session_status::SessionStatus;
+use codex_core::session_status::read_statuses;
+use codex_core::session_status::remove_status;
+
+/// Shows what running interactive sessions are doing. Sessions only publish
+/// their state when `tui.status_file = true`.
+#[derive(Debug, clap::Parser)]
+pub struct StatusCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    /// Print one tab-separated line per session (activity, model, tokens,
+    /// branch, cwd, thread id) for tmux status bars and shell prompts.
+    #[arg(long)]
+    pub porcelain: bool,
+
+    /// Only show sessions whose working directory is the current directory.
+    #[arg(long)]
+    pub here: bool,
+}
+
+impl StatusCli {
+    pub async fn run(self) -> Result<()> {
+        let overrides = self
+            .config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides_and_harness_overrides(
+            overrides,
+            ConfigOverrides::default(),
+        )
+        .await
+        .context("failed to load configuration")?;
+
+        let mut statuses =
+            read_statuses(&config.codex_home).context("failed to read session status files")?;
+        // A crashed session never gets to remove its file; clean up after it.
+        statuses.retain(|status| {
+            let alive = process_alive(status.pid);
+            if !alive {
+                let _ = remove_status(&config.codex_home, status.pid);
+            }
+            alive
+        });
+        if self.here {
+            let cwd = std::env::current_dir().context("failed to read current directory")?;
+            statuses.retain(|status| status.cwd == cwd);
+        }
+
+        if self.porcelain {
+            for status in &statuses {
+                println!("{}", status.porcelain_line());
+            }
+            return Ok(());
+        }
+
+        if statuses.is_empty() {
+            println!("No running sessions. Set `tui.status_file = true` to publish session state.");
+            return Ok(());
+        }
+        for status in &statuses {
+            println!("{}", describe(status));
+        }
+        Ok(())
+    }
+}
+
+fn describe(status: &SessionStatus) -> String {
+    let branch = status
+        .branch
+        .as_deref()
+        .map(|branch| format!(" ({branch})"))
+        .unwrap_or_default();
+    format!(
+        "{pid}  {activity:<20}  {model}  {tokens} tokens  {cwd}{branch}",
+        pid = status.pid,
+        activity = status.activity.as_str(),
+        model = status.model,
+        tokens = status.tokens_used,
+        cwd = status.cwd.display(),
+    )
+}
+
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    // Signal 0 only checks that the pid exists; EPERM still means it does.
+    let result = unsafe { libc::kill(pid as libc::pid_t, 0) };
+    result == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+fn process_alive(_pid: u32) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::session_status::SessionActivity;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    #[test]
+    fn describe_includes_branch_when_known() {
+        let mut status = SessionStatus {
+            pid: 42,
+            thread_id: None,
+            cwd: PathBuf::from("/repo"),
+            model: "gpt-5.1-codex".to_string(),
+            activity: SessionActivity::Idle,
+            tokens_used: 900,
+            branch: Some("main".to_string()),
+            updated_at: 0,
+        };
+        assert_eq!(
+            describe(&status),
+            "42  idle                  gpt-5.1-codex  900 tokens  /repo (main)"
+        );
+
+        status.branch = None;
+        assert_eq!(
+            describe(&status),
+            "42  idle                  gpt-5.1-codex  900 tokens  /repo"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use codex_common::CliConfigOverrides;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use codex_core::config::edit::ConfigEditsBuilder;
+use codex_core::git_info::resolve_root_git_project_for_trust;
+use codex_utils_absolute_path::AbsolutePathBuf;
+
+/// Subcommands:
+/// - `list` — show directories with a recorded trust decision
+/// - `revoke` — forget the trust decision for a directory
+#[derive(Debug, clap::Parser)]
+pub struct TrustCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: TrustSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum TrustSubcommand {
+    /// List directories and their trust level (trusted, read-only, untrusted).
+    List,
+    /// Forget the trust decision so Codex asks again next time.
+    Revoke(RevokeArgs),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct RevokeArgs {
+    /// Directory to revoke. Defaults to the current directory; inside a git
+    /// repository the repository root is used.
+    #[arg(value_name = "PATH")]
+    pub path: Option<PathBuf>,
+}
+
+impl TrustCli {
+    pub async fn run(self) -> Result<()> {
+        let TrustCli {
+            config_overrides,
+            subcommand,
+        } = self;
+        let overrides = config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides_and_harness_overrides(
+            overrides,
+            ConfigOverrides::default(),
+        )
+        .await
+        .context("failed to load configuration")?;
+
+        match subcommand {
+            TrustSubcommand::List => run_list(&config),
+            TrustSubcommand::Revoke(args) => run_revoke(&config, args).await,
+        }
+    }
+}
+
+/// `(path, trust_level)` pairs recorded under `[projects]` in the user config.
+fn trusted_projects(config: &Config) -> Vec<(String, String)> {
+    let Some(projects) = config
+        .config_layer_stack
+        .get_user_layer()
+        .and_then(|layer| layer.config.get("projects"))
+        .and_then(toml::Value::as_table)
+    else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<(String, String)> = projects
+        .iter()
+        .filter_map(|(path, entry)| {
+            let level = entry.get("trust_level")?.as_str()?;
+            Some((path.clone(), level.to_string()))
+        })
+        .collect();
+    entries.sort();
+    entries
+}
+
+fn run_list(config: &Config) -> Result<()> {
+    let entries = trusted_projects(config);
+    if entries.is_empty() {
+        println!("No trust decisions recorded.");
+        return Ok(());
+    }
+
+    let width = entries
+        .iter()
+        .map(|(_, level)| level.len())
+        .max()
+        .unwrap_or_default();
+    for (path, level) in entries {
+        println!("{level:<width$}  {path}");
+    }
+    Ok(())
+}
+
+async fn run_revoke(config: &Config, args: RevokeArgs) -> Result<()> {
+    let path = match args.path {
+        Some(path) => AbsolutePathBuf::resolve_path_against_base(&path, &config.cwd)
+            .with_context(|| format!("failed to resolve {}", path.display()))?
+            .into_path_buf(),
+        None => config.cwd.clone(),
+    };
+    let target = resolve_root_git_project_for_trust(&path).unwrap_or(path);
+    let key = target.to_string_lossy().to_string();
+
+    if !trusted_projects(config)
+        .iter()
+        .any(|(path, _)| *path == key)
+    {
+        println!("No trust decision recorded for {key}.");
+        return Ok(());
+    }
+
+    ConfigEditsBuilder::new(&config.codex_home)
+        .clear_project_trust_level(&target)
+        .apply()
+        .await
+        .with_context(|| format!("failed to update {}", config.codex_home.display()))?;
+
+    println!("Revoked trust for {key}. Codex will ask again next time it runs there.");
+    Ok(())
+}
@@ -56,3 +56,75 @@ async fn features_enable_under_development_feature_prints_warning() -> Result<()
 
     Ok(())
 }
+
+#[tokio::test]
+async fn features_list_shows_where_each_value_comes_from() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        "[features]\nunified_exec = true\n",
+    )?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    let output = cmd
+        .args(["--enable", "sqlite", "features", "list"])
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    let origin_of = |key: &str| {
+        stdout
+            .lines()
+            .find(|line| line.split_whitespace().next() == Some(key))
+            .and_then(|line| line.split_whitespace().last())
+            .map(str::to_string)
+    };
+
+    assert_eq!(origin_of("unified_exec").as_deref(), Some("用户配置"));
+    assert_eq!(origin_of("sqlite").as_deref(), Some("命令行"));
+    assert_eq!(origin_of("shell_tool").as_deref(), Some("默认"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn features_describe_prints_dependencies_and_risk() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        "[features]\nshell_tool = false\n",
+    )?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    cmd.args(["features", "describe", "unified_exec"])
+        .assert()
+        .success()
+        .stdout(contains("依赖：shell_tool（关闭）"))
+        .stdout(contains("风险：无"));
+
+    let mut cmd = codex_command(codex_home.path())?;
+    cmd.args(["features", "describe", "tui3"])
+        .assert()
+        .failure()
+        .stderr(contains("未知功能开关：tui3"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn features_enable_warns_when_a_dependency_is_disabled() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        "[features]\nshell_tool = false\n",
+    )?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    cmd.args(["features", "enable", "unified_exec"])
+        .assert()
+        .success()
+        .stderr(contains(
+            "Feature `unified_exec` has no effect until these features are enabled too: `shell_tool`.",
+        ));
+
+    Ok(())
+}
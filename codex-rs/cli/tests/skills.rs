@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use anyhow::Result;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+#[tokio::test]
+async fn skills_new_scaffolds_skill_under_codex_home() -> Result<()> {
+    let codex_home = TempDir::new()?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    cmd.args([
+        "skills",
+        "new",
+        "release-notes",
+        "--description",
+        "Draft release notes from merged PRs",
+        "--tool",
+        "env_var:GITHUB_TOKEN",
+    ])
+    .assert()
+    .success()
+    .stdout(contains("Created skill 'release-notes'"))
+    .stdout(contains("depends on env_var: GITHUB_TOKEN"));
+
+    let skill_dir = codex_home.path().join("skills").join("release-notes");
+    let skill_md = std::fs::read_to_string(skill_dir.join("SKILL.md"))?;
+    assert!(skill_md.contains("name: release-notes"));
+    assert!(skill_md.contains("description: Draft release notes from merged PRs"));
+
+    let metadata = std::fs::read_to_string(skill_dir.join("agents").join("openai.yaml"))?;
+    assert!(metadata.contains("type: env_var"));
+    assert!(metadata.contains("value: GITHUB_TOKEN"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn skills_new_rejects_invalid_name() -> Result<()> {
+    let codex_home = TempDir::new()?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    cmd.args(["skills", "new", "Not Valid"])
+        .assert()
+        .failure()
+        .stderr(contains("invalid skill name"));
+
+    assert!(!codex_home.path().join("skills").join("Not Valid").exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn skills_test_dry_run_renders_skill_turn() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let cwd = TempDir::new()?;
+
+    codex_command(codex_home.path())?
+        .args([
+            "skills",
+            "new",
+            "release-notes",
+            "--default-prompt",
+            "for v1.2.0",
+        ])
+        .assert()
+        .success();
+
+    let mut cmd = codex_command(codex_home.path())?;
+    cmd.current_dir(cwd.path())
+        .args(["skills", "test", "release-notes", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(contains("metadata OK"))
+        .stdout(contains("<name>release-notes</name>"))
+        .stdout(contains("$release-notes for v1.2.0"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn skills_test_reports_ignored_interface_fields() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let cwd = TempDir::new()?;
+
+    codex_command(codex_home.path())?
+        .args(["skills", "new", "triage"])
+        .assert()
+        .success();
+    let metadata_dir = codex_home
+        .path()
+        .join("skills")
+        .join("triage")
+        .join("agents");
+    std::fs::create_dir_all(&metadata_dir)?;
+    std::fs::write(
+        metadata_dir.join("openai.yaml"),
+        "interface:\n  brand_color: blue\n",
+    )?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    cmd.current_dir(cwd.path())
+        .args(["skills", "test", "triage", "--input", "now", "--dry-run"])
+        .assert()
+        .failure()
+        .stdout(contains("error: interface.brand_color was ignored"))
+        .stderr(contains("failed validation"));
+
+    Ok(())
+}
@@ -47,6 +47,10 @@ pub struct ExecCommand {
     /// Git branch to run in Codex Cloud (defaults to current branch).
     #[arg(long = "branch", value_name = "BRANCH")]
     pub branch: Option<String>,
+
+    /// Wait for the task to finish, print its status, and post it to the `[notifications]` webhooks.
+    #[arg(long = "wait", default_value_t = false)]
+    pub wait: bool,
 }
 
 fn parse_attempts(input: &str) -> Result<usize, String> {
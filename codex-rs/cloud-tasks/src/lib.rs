@@ -161,6 +161,7 @@ async fn run_exec_command(args: crate::cli::ExecCommand) -> anyhow::Result<()> {
         environment,
         branch,
         attempts,
+        wait,
     } = args;
     let ctx = init_backend("codex_cloud_tasks_exec").await?;
     let prompt = resolve_query_input(query)?;
@@ -177,9 +178,62 @@ async fn run_exec_command(args: crate::cli::ExecCommand) -> anyhow::Result<()> {
     .await?;
     let url = util::task_url(&ctx.base_url, &created.id.0);
     println!("{url}");
+    if wait {
+        wait_for_task(&ctx, created.id).await?;
+    }
+    Ok(())
+}
+
+const WAIT_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Poll until the task leaves `Pending`, print its status, and post it to the
+/// configured chat webhooks. Exits with status 1 when the task failed.
+async fn wait_for_task(
+    ctx: &BackendContext,
+    task_id: codex_cloud_tasks_client::TaskId,
+) -> anyhow::Result<()> {
+    let summary = loop {
+        tokio::time::sleep(WAIT_POLL_INTERVAL).await;
+        let summary = codex_cloud_tasks_client::CloudBackend::get_task_summary(
+            &*ctx.backend,
+            task_id.clone(),
+        )
+        .await?;
+        if !matches!(summary.status, TaskStatus::Pending) {
+            break summary;
+        }
+    };
+    let colorize = supports_color::on(SupportStream::Stdout).is_some();
+    for line in format_task_status_lines(&summary, Utc::now(), colorize) {
+        println!("{line}");
+    }
+    if let Some(notifier) = util::load_chat_notifier().await {
+        notifier
+            .send(util::cloud_task_notification(&ctx.base_url, &summary))
+            .await;
+    }
+    if matches!(summary.status, TaskStatus::Error) {
+        std::process::exit(1);
+    }
     Ok(())
 }
 
+/// Tasks that were pending in `before` and have finished in `after`.
+fn newly_finished_tasks<'a>(
+    before: &[codex_cloud_tasks_client::TaskSummary],
+    after: &'a [codex_cloud_tasks_client::TaskSummary],
+) -> Vec<&'a codex_cloud_tasks_client::TaskSummary> {
+    after
+        .iter()
+        .filter(|task| matches!(task.status, TaskStatus::Ready | TaskStatus::Error))
+        .filter(|task| {
+            before
+                .iter()
+                .any(|old| old.id == task.id && matches!(old.status, TaskStatus::Pending))
+        })
+        .collect()
+}
+
 async fn resolve_environment_id(ctx: &BackendContext, requested: &str) -> anyhow::Result<String> {
     let trimmed = requested.trim();
     if trimmed.is_empty() {
@@ -754,8 +808,9 @@ pub async fn run_main(cli: Cli, _codex_linux_sandbox_exe: Option<PathBuf>) -> an
         .try_init();
 
     info!("Launching Cloud Tasks list UI");
-    let BackendContext { backend, .. } = init_backend("codex_cloud_tasks_tui").await?;
+    let BackendContext { backend, base_url } = init_backend("codex_cloud_tasks_tui").await?;
     let backend = backend;
+    let chat_notifier = util::load_chat_notifier().await;
 
     // Terminal setup
     use crossterm::ExecutableCommand;
@@ -966,6 +1021,11 @@ pub async fn run_main(cli: Cli, _codex_linux_sandbox_exe: Option<PathBuf>) -> an
                                         env.clone().unwrap_or_else(|| "<all>".to_string()),
                                         tasks.len()
                                     ));
+                                    if let Some(notifier) = chat_notifier.as_ref() {
+                                        for task in newly_finished_tasks(&app.tasks, &tasks) {
+                                            notifier.notify(util::cloud_task_notification(&base_url, task));
+                                        }
+                                    }
                                     app.tasks = tasks;
                                     if app.selected >= app.tasks.len() { app.selected = app.tasks.len().saturating_sub(1); }
                                     app.status = "Loaded tasks".to_string();
@@ -2213,6 +2273,39 @@ mod tests {
         assert_eq!(git_ref, "main");
     }
 
+    #[test]
+    fn only_tasks_that_left_pending_count_as_finished() {
+        let task = |id: &str, status: TaskStatus| TaskSummary {
+            id: TaskId(id.to_string()),
+            title: id.to_string(),
+            status,
+            updated_at: Utc::now(),
+            environment_id: None,
+            environment_label: None,
+            summary: DiffSummary::default(),
+            is_review: false,
+            attempt_total: None,
+        };
+        let before = vec![
+            task("done", TaskStatus::Pending),
+            task("failed", TaskStatus::Pending),
+            task("running", TaskStatus::Pending),
+            task("old", TaskStatus::Ready),
+        ];
+        let after = vec![
+            task("new", TaskStatus::Ready),
+            task("done", TaskStatus::Ready),
+            task("failed", TaskStatus::Error),
+            task("running", TaskStatus::Pending),
+            task("old", TaskStatus::Ready),
+        ];
+        let finished: Vec<&str> = newly_finished_tasks(&before, &after)
+            .into_iter()
+            .map(|task| task.id.0.as_str())
+            .collect();
+        assert_eq!(finished, vec!["done", "failed"]);
+    }
+
     #[test]
     fn format_task_status_lines_with_diff_and_label() {
         let now = Utc::now();
@@ -3,7 +3,12 @@ use chrono::DateTime;
 use chrono::Local;
 use chrono::Utc;
 use reqwest::header::HeaderMap;
+use std::sync::Arc;
 
+use codex_cloud_tasks_client::TaskStatus;
+use codex_cloud_tasks_client::TaskSummary;
+use codex_core::chat_notifications::ChatNotification;
+use codex_core::chat_notifications::ChatNotifier;
 use codex_core::config::Config;
 use codex_login::AuthManager;
 
@@ -69,6 +74,28 @@ pub async fn load_auth_manager() -> Option<AuthManager> {
     ))
 }
 
+/// Notifier for the `[notifications]` webhooks, when any are configured.
+pub async fn load_chat_notifier() -> Option<Arc<ChatNotifier>> {
+    let config = Config::load_with_cli_overrides(Vec::new()).await.ok()?;
+    ChatNotifier::shared(&config)
+}
+
+/// Message announcing that `task` finished.
+pub fn cloud_task_notification(base_url: &str, task: &TaskSummary) -> ChatNotification {
+    let status = match task.status {
+        TaskStatus::Pending => "pending",
+        TaskStatus::Ready => "ready",
+        TaskStatus::Applied => "applied",
+        TaskStatus::Error => "error",
+    };
+    ChatNotification::CloudTaskFinished {
+        task_id: task.id.0.clone(),
+        title: task.title.clone(),
+        status: status.to_string(),
+        url: task_url(base_url, &task.id.0),
+    }
+}
+
 /// Build headers for ChatGPT-backed requests: `User-Agent`, optional `Authorization`,
 /// and optional `ChatGPT-Account-Id`.
 pub async fn build_chatgpt_headers() -> HeaderMap {
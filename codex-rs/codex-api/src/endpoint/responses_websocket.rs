@@ -358,9 +358,16 @@ async fn run_websocket_response_stream(
 
     loop {
         let poll_start = Instant::now();
-        let response = tokio::time::timeout(idle_timeout, ws_stream.next())
-            .await
-            .map_err(|_| ApiError::Stream("idle timeout waiting for websocket".into()));
+        let response = tokio::select! {
+            // The turn was interrupted. The connection cannot carry another
+            // request until this response ends, so drop it; the server stops
+            // generating and the next request reconnects.
+            _ = tx_event.closed() => {
+                return Err(ApiError::Stream("response stream dropped by the consumer".into()));
+            }
+            response = tokio::time::timeout(idle_timeout, ws_stream.next()) => response,
+        }
+        .map_err(|_| ApiError::Stream("idle timeout waiting for websocket".into()));
         if let Some(t) = telemetry.as_ref() {
             t.on_ws_event(&response, poll_start.elapsed());
         }
@@ -326,7 +326,12 @@ pub async fn process_sse(
 
     loop {
         let start = Instant::now();
-        let response = timeout(idle_timeout, stream.next()).await;
+        let response = tokio::select! {
+            // The consumer dropped the stream (the turn was interrupted): stop
+            // reading so the connection closes and the model stops generating.
+            _ = tx_event.closed() => return,
+            response = timeout(idle_timeout, stream.next()) => response,
+        };
         if let Some(t) = telemetry.as_ref() {
             t.on_sse_poll(&response, start.elapsed());
         }
@@ -0,0 +1,47 @@
+//! Minimal application embedding the Codex agent loop.
+//!
+//! ```text
+//! cargo run -p codex-core --example embed_agent -- "What does this repository do?"
+//! ```
+//!
+//! Uses `config.toml` from `CODEX_HOME` with a read-only sandbox, prints the
+//! agent's messages and commands as they happen, and denies every approval
+//! request.
+
+use codex_core::Agent;
+use codex_core::AgentOptions;
+use codex_core::ApprovalRequest;
+use codex_core::protocol::EventMsg;
+use codex_core::protocol::ReviewDecision;
+use codex_protocol::config_types::SandboxMode;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let prompt = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "Summarize this repository in two sentences.".to_string());
+
+    let agent = Agent::load(AgentOptions::new().sandbox_mode(SandboxMode::ReadOnly)).await?;
+    let thread = agent.start_thread().await?;
+    println!("thread {} using {}", thread.id(), thread.session().model);
+
+    thread.send_message(prompt).await?;
+    loop {
+        let event = thread.next_event().await?;
+        if let Some(request) = ApprovalRequest::from_event(&event.msg) {
+            println!("denying approval request: {request:?}");
+            thread.approve(&request, ReviewDecision::Denied).await?;
+            continue;
+        }
+        match event.msg {
+            EventMsg::AgentMessage(message) => println!("codex: {}", message.message),
+            EventMsg::ExecCommandBegin(begin) => println!("$ {}", begin.command.join(" ")),
+            EventMsg::Error(error) => eprintln!("error: {}", error.message),
+            EventMsg::TurnComplete(_) | EventMsg::TurnAborted(_) => break,
+            _ => {}
+        }
+    }
+
+    thread.shutdown().await?;
+    Ok(())
+}
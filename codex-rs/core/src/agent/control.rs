@@ -447,6 +447,7 @@ mod tests {
     async fn on_event_updates_status_from_turn_aborted() {
         let status = agent_status_from_event(&EventMsg::TurnAborted(TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }));
 
         let expected = AgentStatus::Errored("Interrupted".to_string());
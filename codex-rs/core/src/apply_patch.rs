@@ -1,5 +1,11 @@
 use crate::codex::TurnContext;
+use crate::config::types::EditLimitAction;
+use crate::edit_limits;
+use crate::edit_limits::EditLimitHit;
 use crate::function_tool::FunctionCallError;
+use crate::protected_paths::ProtectedPathMatch;
+use crate::protected_paths::patch_paths;
+use crate::protocol::AskForApproval;
 use crate::protocol::FileChange;
 use crate::safety::SafetyCheck;
 use crate::safety::assess_patch_safety;
@@ -24,6 +30,18 @@ pub(crate) enum InternalApplyPatchInvocation {
     /// but [`ApplyPatchExec::auto_approved`] is used to determine the sandbox
     /// used with the `exec()`.
     DelegateToExec(ApplyPatchExec),
+
+    /// The session is in explore mode, so the patch is shown to the user as a
+    /// proposal and nothing is written.
+    Proposal(ApplyPatchAction),
+
+    /// The patch touches paths matching `protected_paths`, so it is refused
+    /// whatever the approval policy.
+    Blocked(Vec<ProtectedPathMatch>),
+
+    /// The patch rewrites files guarded by `edit_limits` and the user cannot
+    /// be asked to confirm it.
+    EditLimited(Vec<EditLimitHit>),
 }
 
 #[derive(Debug)]
@@ -37,6 +55,35 @@ pub(crate) async fn apply_patch(
     turn_context: &TurnContext,
     action: ApplyPatchAction,
 ) -> InternalApplyPatchInvocation {
+    if turn_context.config.explore_mode {
+        return InternalApplyPatchInvocation::Proposal(action);
+    }
+
+    let blocked = turn_context.config.protected_paths.check(
+        patch_paths(&action).iter().map(PathBuf::as_path),
+        &turn_context.cwd,
+    );
+    if !blocked.is_empty() {
+        return InternalApplyPatchInvocation::Blocked(blocked);
+    }
+
+    let limited = edit_limits::check_patch(&turn_context.config.edit_limits, &action);
+    if !limited.is_empty() {
+        if turn_context.config.edit_limits.on_exceed == EditLimitAction::Ask
+            && turn_context.approval_policy != AskForApproval::Never
+        {
+            return InternalApplyPatchInvocation::DelegateToExec(ApplyPatchExec {
+                action,
+                auto_approved: false,
+                exec_approval_requirement: ExecApprovalRequirement::NeedsApproval {
+                    reason: Some(edit_limits::approval_reason(&limited)),
+                    proposed_execpolicy_amendment: None,
+                },
+            });
+        }
+        return InternalApplyPatchInvocation::EditLimited(limited);
+    }
+
     match assess_patch_safety(
         &action,
         turn_context.approval_policy,
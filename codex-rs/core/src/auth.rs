@@ -1,6 +1,7 @@
 mod storage;
 
 use async_trait::async_trait;
+use chrono::DateTime;
 use chrono::Utc;
 use reqwest::StatusCode;
 use serde::Deserialize;
@@ -137,6 +138,24 @@ pub trait ExternalAuthRefresher: Send + Sync {
     ) -> std::io::Result<ExternalAuthTokens>;
 }
 
+/// How the stored ChatGPT tokens are doing, for status displays and
+/// re-login prompts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TokenRefreshHealth {
+    /// Tokens were issued or refreshed recently.
+    Healthy { last_refresh: DateTime<Utc> },
+    /// Tokens are due for a refresh; the next request will attempt one.
+    RefreshDue { last_refresh: DateTime<Utc> },
+    /// The auth server rejected the refresh token. Only signing in again helps.
+    Failed(RefreshTokenFailedError),
+}
+
+impl TokenRefreshHealth {
+    pub fn needs_login(&self) -> bool {
+        matches!(self, Self::Failed(_))
+    }
+}
+
 impl RefreshTokenError {
     pub fn failed_reason(&self) -> Option<RefreshTokenFailedReason> {
         match self {
@@ -303,6 +322,11 @@ impl CodexAuth {
 
     /// Consider this private to integration tests.
     pub fn create_dummy_chatgpt_auth_for_testing() -> Self {
+        Self::create_dummy_chatgpt_auth_refreshed_at_for_testing(Utc::now())
+    }
+
+    /// Consider this private to integration tests.
+    pub fn create_dummy_chatgpt_auth_refreshed_at_for_testing(last_refresh: DateTime<Utc>) -> Self {
         let auth_dot_json = AuthDotJson {
             auth_mode: Some(ApiAuthMode::Chatgpt),
             openai_api_key: None,
@@ -312,7 +336,7 @@ impl CodexAuth {
                 refresh_token: "test".to_string(),
                 account_id: Some("account_id".to_string()),
             }),
-            last_refresh: Some(Utc::now()),
+            last_refresh: Some(last_refresh),
         };
 
         let client = crate::default_client::create_client();
@@ -950,6 +974,9 @@ pub struct AuthManager {
     enable_codex_api_key_env: bool,
     auth_credentials_store_mode: AuthCredentialsStoreMode,
     forced_chatgpt_workspace_id: RwLock<Option<String>>,
+    /// Last permanent refresh failure and when it happened. Tokens saved
+    /// after that time (a refresh or a new login) supersede it.
+    refresh_failure: RwLock<Option<(RefreshTokenFailedError, DateTime<Utc>)>>,
 }
 
 impl AuthManager {
@@ -978,6 +1005,7 @@ impl AuthManager {
             enable_codex_api_key_env,
             auth_credentials_store_mode,
             forced_chatgpt_workspace_id: RwLock::new(None),
+            refresh_failure: RwLock::new(None),
         }
     }
 
@@ -995,6 +1023,7 @@ impl AuthManager {
             enable_codex_api_key_env: false,
             auth_credentials_store_mode: AuthCredentialsStoreMode::File,
             forced_chatgpt_workspace_id: RwLock::new(None),
+            refresh_failure: RwLock::new(None),
         })
     }
 
@@ -1011,9 +1040,19 @@ impl AuthManager {
             enable_codex_api_key_env: false,
             auth_credentials_store_mode: AuthCredentialsStoreMode::File,
             forced_chatgpt_workspace_id: RwLock::new(None),
+            refresh_failure: RwLock::new(None),
         })
     }
 
+    #[cfg(any(test, feature = "test-support"))]
+    /// Record a permanent refresh failure as if the auth server had just
+    /// rejected the refresh token, for testing only.
+    pub fn record_refresh_failure_for_testing(&self, error: RefreshTokenFailedError) {
+        if let Ok(mut guard) = self.refresh_failure.write() {
+            *guard = Some((error, Utc::now()));
+        }
+    }
+
     /// Current cached auth (clone) without attempting a refresh.
     pub fn auth_cached(&self) -> Option<CodexAuth> {
         self.inner.read().ok().and_then(|c| c.auth.clone())
@@ -1193,6 +1232,31 @@ impl AuthManager {
         self.auth_cached().as_ref().map(CodexAuth::auth_mode)
     }
 
+    /// Health of the managed ChatGPT tokens. `None` when signed out, using an
+    /// API key, or using externally managed tokens.
+    pub fn token_refresh_health(&self) -> Option<TokenRefreshHealth> {
+        let CodexAuth::Chatgpt(chatgpt_auth) = self.auth_cached()? else {
+            return None;
+        };
+        let last_refresh = chatgpt_auth.current_auth_json()?.last_refresh;
+        let failure = self
+            .refresh_failure
+            .read()
+            .ok()
+            .and_then(|guard| guard.clone());
+        if let Some((error, failed_at)) = failure
+            && last_refresh.is_none_or(|last_refresh| last_refresh <= failed_at)
+        {
+            return Some(TokenRefreshHealth::Failed(error));
+        }
+        let last_refresh = last_refresh?;
+        if last_refresh < Utc::now() - chrono::Duration::days(TOKEN_REFRESH_INTERVAL) {
+            Some(TokenRefreshHealth::RefreshDue { last_refresh })
+        } else {
+            Some(TokenRefreshHealth::Healthy { last_refresh })
+        }
+    }
+
     async fn refresh_if_stale(&self, auth: &CodexAuth) -> Result<bool, RefreshTokenError> {
         let chatgpt_auth = match auth {
             CodexAuth::Chatgpt(chatgpt_auth) => chatgpt_auth,
@@ -1277,7 +1341,17 @@ impl AuthManager {
         auth: &ChatgptAuth,
         refresh_token: String,
     ) -> Result<(), RefreshTokenError> {
-        let refresh_response = try_refresh_token(refresh_token, auth.client()).await?;
+        let refresh_response = match try_refresh_token(refresh_token, auth.client()).await {
+            Ok(response) => response,
+            Err(err) => {
+                if let RefreshTokenError::Permanent(failed) = &err
+                    && let Ok(mut guard) = self.refresh_failure.write()
+                {
+                    *guard = Some((failed.clone(), Utc::now()));
+                }
+                return Err(err);
+            }
+        };
 
         update_tokens(
             auth.storage(),
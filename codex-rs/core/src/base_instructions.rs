@@ -0,0 +1,110 @@
+//! Assembly of the base instructions (the system prompt) sent with every
+//! request, and the preview behind `codex debug prompt`.
+//!
+//! Merge order, first to last:
+//! 1. `base_instructions` override, `model_instructions_file` (profile before
+//!    global), or the built-in instructions for the selected model.
+//! 2. Global `additional_instructions_file`, then `additional_instructions`.
+//! 3. Active profile's `additional_instructions_file`, then
+//!    `additional_instructions`.
+//!
+//! Layers are separated by a blank line. A resumed session keeps the text it
+//! was started with, so edits only apply to new sessions.
+
+use crate::AuthManager;
+use crate::config::Config;
+use crate::config::types::InstructionsLayer;
+use crate::models_manager::manager::ModelsManager;
+use crate::models_manager::manager::RefreshStrategy;
+
+/// Appends `layers` to `base` in order.
+pub fn assemble_base_instructions(base: String, layers: &[InstructionsLayer]) -> String {
+    layers.iter().fold(base, |mut text, layer| {
+        if !text.is_empty() {
+            text.push_str("\n\n");
+        }
+        text.push_str(&layer.text);
+        text
+    })
+}
+
+/// The base instructions a new session would start with.
+#[derive(Debug, Clone)]
+pub struct BaseInstructionsPreview {
+    pub model: String,
+    /// Where the first layer came from.
+    pub base_source: String,
+    pub base: String,
+    pub layers: Vec<InstructionsLayer>,
+    /// `base` followed by every layer, exactly as sent to the model.
+    pub text: String,
+}
+
+/// Resolves the model and assembles its base instructions without starting a
+/// session. Model metadata comes from the local cache only.
+pub async fn preview_base_instructions(config: &Config) -> BaseInstructionsPreview {
+    let auth_manager = AuthManager::shared(
+        config.codex_home.clone(),
+        false,
+        config.cli_auth_credentials_store_mode,
+    );
+    let models_manager = ModelsManager::new(config.codex_home.clone(), auth_manager);
+    let model = models_manager
+        .get_default_model(&config.model, config, RefreshStrategy::Offline)
+        .await;
+    let model_info = models_manager.get_model_info(&model, config).await;
+
+    let (base_source, base) = match &config.base_instructions {
+        Some(base) => (
+            "base_instructions override (model_instructions_file)".to_string(),
+            base.clone(),
+        ),
+        None => (
+            format!("built-in instructions for `{model}`"),
+            model_info.get_model_instructions(config.personality),
+        ),
+    };
+    let text = assemble_base_instructions(base.clone(), &config.additional_instructions);
+
+    BaseInstructionsPreview {
+        model,
+        base_source,
+        base,
+        layers: config.additional_instructions.clone(),
+        text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn layer(source: &str, text: &str) -> InstructionsLayer {
+        InstructionsLayer {
+            source: source.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn layers_follow_the_base_in_order() {
+        let assembled = assemble_base_instructions(
+            "You are Codex.".to_string(),
+            &[
+                layer("additional_instructions", "Prefer tabs."),
+                layer("profile `work` additional_instructions", "Cite tickets."),
+            ],
+        );
+
+        assert_eq!(assembled, "You are Codex.\n\nPrefer tabs.\n\nCite tickets.");
+    }
+
+    #[test]
+    fn no_layers_leaves_the_base_untouched() {
+        assert_eq!(
+            assemble_base_instructions("You are Codex.".to_string(), &[]),
+            "You are Codex."
+        );
+    }
+}
@@ -0,0 +1,368 @@
+//! Slack and Discord messages for long-running work.
+//!
+//! Enabled by `[notifications.slack]` / `[notifications.discord]`. Finished
+//! turns, pending approvals, and finished Codex Cloud tasks are posted to the
+//! configured webhooks as short messages carrying the session id (or the task
+//! link) and the files involved. Messages of the same kind about the same
+//! session or task are rate limited: within `min_interval_secs` of the last
+//! one they are dropped and counted, and the next message mentions the count.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_protocol::ThreadId;
+use futures::future::join_all;
+use serde_json::json;
+use tracing::warn;
+
+use crate::config::Config;
+use crate::config::types::ChatNotificationEvent;
+use crate::config::types::ChatNotificationsConfig;
+use crate::config::types::ChatPlatform;
+use crate::default_client::create_client;
+
+const POST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Longest excerpt of the agent's last message included in a message.
+const MAX_EXCERPT_CHARS: usize = 280;
+/// Files listed before the rest are folded into "and N more".
+const MAX_LISTED_FILES: usize = 5;
+
+static SHARED_NOTIFIER: OnceLock<Arc<ChatNotifier>> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatNotification {
+    TurnComplete {
+        thread_id: ThreadId,
+        cwd: PathBuf,
+        last_agent_message: Option<String>,
+        /// Files `apply_patch` changed during the turn.
+        changed_files: Vec<PathBuf>,
+    },
+    ApprovalNeeded {
+        thread_id: ThreadId,
+        cwd: PathBuf,
+        /// The command line, or the files a patch would change.
+        request: String,
+        reason: Option<String>,
+    },
+    CloudTaskFinished {
+        task_id: String,
+        title: String,
+        /// Final status, e.g. `ready` or `error`.
+        status: String,
+        url: String,
+    },
+}
+
+impl ChatNotification {
+    fn event(&self) -> ChatNotificationEvent {
+        match self {
+            Self::TurnComplete { .. } => ChatNotificationEvent::TurnComplete,
+            Self::ApprovalNeeded { .. } => ChatNotificationEvent::ApprovalNeeded,
+            Self::CloudTaskFinished { .. } => ChatNotificationEvent::CloudTaskFinished,
+        }
+    }
+
+    /// Session or task the rate limit is tracked for.
+    fn subject(&self) -> String {
+        match self {
+            Self::TurnComplete { thread_id, .. } | Self::ApprovalNeeded { thread_id, .. } => {
+                thread_id.to_string()
+            }
+            Self::CloudTaskFinished { task_id, .. } => task_id.clone(),
+        }
+    }
+}
+
+struct RateState {
+    last_sent: Instant,
+    suppressed: usize,
+}
+
+/// Posts [`ChatNotification`]s to the configured webhooks. One notifier is
+/// shared by every session in the process so the rate limit holds across
+/// them.
+pub struct ChatNotifier {
+    config: ChatNotificationsConfig,
+    rate: Mutex<HashMap<(ChatNotificationEvent, String), RateState>>,
+}
+
+impl ChatNotifier {
+    /// The process-wide notifier, or `None` when no webhook is configured.
+    pub fn shared(config: &Config) -> Option<Arc<Self>> {
+        let chat_notifications = config.chat_notifications.clone()?;
+        Some(
+            SHARED_NOTIFIER
+                .get_or_init(|| Arc::new(Self::new(chat_notifications)))
+                .clone(),
+        )
+    }
+
+    fn new(config: ChatNotificationsConfig) -> Self {
+        Self {
+            config,
+            rate: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Post `notification` in the background; see [`ChatNotifier::send`].
+    pub fn notify(self: &Arc<Self>, notification: ChatNotification) {
+        let notifier = Arc::clone(self);
+        tokio::spawn(async move { notifier.send(notification).await });
+    }
+
+    /// Post `notification` to every webhook that wants it, unless the rate
+    /// limit drops it. Delivery failures are logged, not returned.
+    pub async fn send(&self, notification: ChatNotification) {
+        let event = notification.event();
+        let targets: Vec<_> = self
+            .config
+            .webhooks
+            .iter()
+            .filter(|webhook| webhook.events.contains(&event))
+            .collect();
+        if targets.is_empty() {
+            return;
+        }
+        let Some(suppressed) = self.admit(&notification, Instant::now()) else {
+            return;
+        };
+        join_all(targets.into_iter().map(|webhook| {
+            let text = render(&notification, suppressed, webhook.platform);
+            let body = match webhook.platform {
+                ChatPlatform::Slack => json!({ "text": text }),
+                ChatPlatform::Discord => json!({ "content": text }),
+            };
+            async move {
+                let result = create_client()
+                    .post(&webhook.url)
+                    .timeout(POST_TIMEOUT)
+                    .json(&body)
+                    .send()
+                    .await;
+                match result {
+                    Ok(response) if !response.status().is_success() => {
+                        warn!("chat webhook returned {}", response.status());
+                    }
+                    Ok(_) => {}
+                    Err(err) => warn!("failed to post chat notification: {err}"),
+                }
+            }
+        }))
+        .await;
+    }
+
+    /// Number of messages dropped since the last one about the same subject,
+    /// or `None` when this one falls inside the rate limit window.
+    fn admit(&self, notification: &ChatNotification, now: Instant) -> Option<usize> {
+        let mut rate = self
+            .rate
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let key = (notification.event(), notification.subject());
+        match rate.get_mut(&key) {
+            Some(state) if now.duration_since(state.last_sent) < self.config.min_interval => {
+                state.suppressed += 1;
+                None
+            }
+            Some(state) => {
+                let suppressed = state.suppressed;
+                state.last_sent = now;
+                state.suppressed = 0;
+                Some(suppressed)
+            }
+            None => {
+                rate.insert(
+                    key,
+                    RateState {
+                        last_sent: now,
+                        suppressed: 0,
+                    },
+                );
+                Some(0)
+            }
+        }
+    }
+}
+
+/// Message text in the platform's markup. Slack and Discord agree on
+/// backticks and `>` quotes but differ in bold and link syntax.
+fn render(notification: &ChatNotification, suppressed: usize, platform: ChatPlatform) -> String {
+    let bold = |text: &str| match platform {
+        ChatPlatform::Slack => format!("*{text}*"),
+        ChatPlatform::Discord => format!("**{text}**"),
+    };
+    let mut lines = Vec::new();
+    match notification {
+        ChatNotification::TurnComplete {
+            thread_id,
+            cwd,
+            last_agent_message,
+            changed_files,
+        } => {
+            lines.push(format!(
+                "{} in `{}` · session `{thread_id}`",
+                bold("Codex finished a turn"),
+                cwd.display()
+            ));
+            if let Some(message) = last_agent_message
+                .as_deref()
+                .map(str::trim)
+                .filter(|message| !message.is_empty())
+            {
+                lines.push(format!("> {}", excerpt(message)));
+            }
+            if !changed_files.is_empty() {
+                lines.push(format!("Changed: {}", file_list(changed_files)));
+            }
+            lines.push(format!("Continue with `codex resume {thread_id}`"));
+        }
+        ChatNotification::ApprovalNeeded {
+            thread_id,
+            cwd,
+            request,
+            reason,
+        } => {
+            lines.push(format!(
+                "{} in `{}` · session `{thread_id}`",
+                bold("Codex is waiting for approval"),
+                cwd.display()
+            ));
+            lines.push(format!("`{}`", excerpt(request)));
+            if let Some(reason) = reason {
+                lines.push(format!("> {}", excerpt(reason)));
+            }
+        }
+        ChatNotification::CloudTaskFinished {
+            title, status, url, ..
+        } => {
+            let link = match platform {
+                ChatPlatform::Slack => format!("<{url}|{title}>"),
+                ChatPlatform::Discord => format!("[{title}]({url})"),
+            };
+            lines.push(format!(
+                "{}: {link} ({status})",
+                bold("Codex Cloud task finished")
+            ));
+        }
+    }
+    if suppressed > 0 {
+        lines.push(format!("_{suppressed} similar message(s) skipped_"));
+    }
+    lines.join("\n")
+}
+
+/// First line of `text`, cut to [`MAX_EXCERPT_CHARS`].
+fn excerpt(text: &str) -> String {
+    let line = text.lines().next().unwrap_or_default();
+    if line.chars().count() <= MAX_EXCERPT_CHARS && !text.contains('\n') {
+        return line.to_string();
+    }
+    let mut cut: String = line.chars().take(MAX_EXCERPT_CHARS).collect();
+    cut.push('…');
+    cut
+}
+
+fn file_list(files: &[PathBuf]) -> String {
+    let mut list: Vec<String> = files
+        .iter()
+        .take(MAX_LISTED_FILES)
+        .map(|path| format!("`{}`", path.display()))
+        .collect();
+    if files.len() > MAX_LISTED_FILES {
+        list.push(format!("and {} more", files.len() - MAX_LISTED_FILES));
+    }
+    list.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::ChatWebhook;
+    use pretty_assertions::assert_eq;
+
+    fn notifier() -> ChatNotifier {
+        ChatNotifier::new(ChatNotificationsConfig {
+            webhooks: vec![ChatWebhook {
+                platform: ChatPlatform::Slack,
+                url: "https://hooks.slack.com/services/T/B/X".to_string(),
+                events: vec![ChatNotificationEvent::ApprovalNeeded],
+            }],
+            min_interval: Duration::from_secs(60),
+        })
+    }
+
+    fn approval(thread_id: ThreadId) -> ChatNotification {
+        ChatNotification::ApprovalNeeded {
+            thread_id,
+            cwd: PathBuf::from("/work/app"),
+            request: "cargo publish".to_string(),
+            reason: None,
+        }
+    }
+
+    #[test]
+    fn rate_limit_counts_skipped_messages_per_session() {
+        let notifier = notifier();
+        let session = ThreadId::new();
+        let start = Instant::now();
+        assert_eq!(notifier.admit(&approval(session), start), Some(0));
+        assert_eq!(
+            notifier.admit(&approval(session), start + Duration::from_secs(10)),
+            None
+        );
+        assert_eq!(
+            notifier.admit(&approval(ThreadId::new()), start + Duration::from_secs(10)),
+            Some(0)
+        );
+        assert_eq!(
+            notifier.admit(&approval(session), start + Duration::from_secs(20)),
+            None
+        );
+        assert_eq!(
+            notifier.admit(&approval(session), start + Duration::from_secs(61)),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn renders_turn_and_cloud_messages_per_platform() {
+        let thread_id = ThreadId::new();
+        let turn = ChatNotification::TurnComplete {
+            thread_id,
+            cwd: PathBuf::from("/work/app"),
+            last_agent_message: Some("Fixed the login test.\nDetails follow.".to_string()),
+            changed_files: vec![PathBuf::from("/work/app/tests/login.rs")],
+        };
+        assert_eq!(
+            render(&turn, 3, ChatPlatform::Slack),
+            format!(
+                "*Codex finished a turn* in `/work/app` · session `{thread_id}`\n\
+                 > Fixed the login test.…\n\
+                 Changed: `/work/app/tests/login.rs`\n\
+                 Continue with `codex resume {thread_id}`\n\
+                 _3 similar message(s) skipped_"
+            )
+        );
+
+        let task = ChatNotification::CloudTaskFinished {
+            task_id: "task_e_1".to_string(),
+            title: "Bump deps".to_string(),
+            status: "ready".to_string(),
+            url: "https://chatgpt.com/codex/tasks/task_e_1".to_string(),
+        };
+        assert_eq!(
+            render(&task, 0, ChatPlatform::Slack),
+            "*Codex Cloud task finished*: <https://chatgpt.com/codex/tasks/task_e_1|Bump deps> (ready)"
+        );
+        assert_eq!(
+            render(&task, 0, ChatPlatform::Discord),
+            "**Codex Cloud task finished**: [Bump deps](https://chatgpt.com/codex/tasks/task_e_1) (ready)"
+        );
+    }
+}
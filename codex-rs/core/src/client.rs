@@ -998,7 +998,17 @@ where
     tokio::spawn(async move {
         let mut logged_error = false;
         let mut api_stream = api_stream;
-        while let Some(event) = api_stream.next().await {
+        loop {
+            // Drop the API stream as soon as the turn stops listening so an
+            // interrupt closes the connection instead of waiting for the next
+            // event to fail to send.
+            let event = tokio::select! {
+                _ = tx_event.closed() => return,
+                event = api_stream.next() => event,
+            };
+            let Some(event) = event else {
+                return;
+            };
             match event {
                 Ok(ResponseEvent::Completed {
                     response_id,
@@ -30,14 +30,14 @@ pub const REVIEW_EXIT_INTERRUPTED_TMPL_ZH: &str =
 pub fn review_exit_success_template(language: Language) -> &'static str {
     match language {
         Language::ZhCn => REVIEW_EXIT_SUCCESS_TMPL_ZH,
-        Language::En => REVIEW_EXIT_SUCCESS_TMPL_EN,
+        _ => REVIEW_EXIT_SUCCESS_TMPL_EN,
     }
 }
 
 pub fn review_exit_interrupted_template(language: Language) -> &'static str {
     match language {
         Language::ZhCn => REVIEW_EXIT_INTERRUPTED_TMPL_ZH,
-        Language::En => REVIEW_EXIT_INTERRUPTED_TMPL_EN,
+        _ => REVIEW_EXIT_INTERRUPTED_TMPL_EN,
     }
 }
 
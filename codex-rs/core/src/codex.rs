@@ -16,11 +16,17 @@ use crate::agent::agent_status_from_event;
 use crate::analytics_client::AnalyticsEventsClient;
 use crate::analytics_client::build_track_events_context;
 use crate::apps::render_apps_section;
+use crate::base_instructions::assemble_base_instructions;
+use crate::chat_notifications::ChatNotification;
+use crate::chat_notifications::ChatNotifier;
 use crate::compact;
 use crate::compact::run_inline_auto_compact_task;
 use crate::compact::should_use_remote_compact_task;
 use crate::compact_remote::run_inline_remote_auto_compact_task;
 use crate::connectors;
+use crate::context_inspector;
+use crate::danger_audit;
+use crate::dry_run;
 use crate::exec_policy::ExecPolicyManager;
 use crate::features::FEATURES;
 use crate::features::Feature;
@@ -29,6 +35,7 @@ use crate::features::maybe_push_unstable_features_warning;
 use crate::models_manager::manager::ModelsManager;
 use crate::parse_command::parse_command;
 use crate::parse_turn_item;
+use crate::personas;
 use crate::rollout::session_index;
 use crate::sandbox_tags::sandbox_tag;
 use crate::stream_events_utils::HandleOutputCtx;
@@ -36,7 +43,9 @@ use crate::stream_events_utils::handle_non_tool_response_item;
 use crate::stream_events_utils::handle_output_item_done;
 use crate::stream_events_utils::last_assistant_message_from_item;
 use crate::terminal;
+use crate::thread_title;
 use crate::truncate::TruncationPolicy;
+use crate::truncate::approx_tokens_from_byte_count;
 use crate::turn_metadata::build_turn_metadata_header;
 use crate::turn_metadata::resolve_turn_metadata_header_with_timeout;
 use crate::util::error_or_panic;
@@ -49,6 +58,7 @@ use codex_hooks::Hooks;
 use codex_network_proxy::NetworkProxy;
 use codex_protocol::ThreadId;
 use codex_protocol::approvals::ExecPolicyAmendment;
+use codex_protocol::config_types::ApprovalTimeoutAction;
 use codex_protocol::config_types::ModeKind;
 use codex_protocol::config_types::Settings;
 use codex_protocol::config_types::WebSearchMode;
@@ -57,10 +67,12 @@ use codex_protocol::dynamic_tools::DynamicToolSpec;
 use codex_protocol::items::PlanItem;
 use codex_protocol::items::TurnItem;
 use codex_protocol::items::UserMessageItem;
+use codex_protocol::items::WebCitation;
 use codex_protocol::mcp::CallToolResult;
 use codex_protocol::models::BaseInstructions;
 use codex_protocol::models::format_allow_prefixes;
 use codex_protocol::openai_models::ModelInfo;
+use codex_protocol::protocol::ContextComponentKind;
 use codex_protocol::protocol::FileChange;
 use codex_protocol::protocol::HasLegacyEvent;
 use codex_protocol::protocol::ItemCompletedEvent;
@@ -122,6 +134,7 @@ use crate::config::types::McpServerConfig;
 use crate::config::types::ShellEnvironmentPolicy;
 use crate::context_manager::ContextManager;
 use crate::context_manager::TotalTokenUsageBreakdown;
+use crate::context_manager::is_user_turn_boundary;
 use crate::environment_context::EnvironmentContext;
 use crate::error::CodexErr;
 use crate::error::Result as CodexResult;
@@ -140,7 +153,9 @@ use crate::file_watcher::FileWatcher;
 use crate::file_watcher::FileWatcherEvent;
 use crate::git_info::get_git_repo_root;
 use crate::i18n::tr;
+use crate::i18n::tr_args;
 use crate::instructions::UserInstructions;
+use crate::is_safe_command::is_known_safe_command;
 use crate::mcp::CODEX_APPS_MCP_SERVER_NAME;
 use crate::mcp::auth::compute_auth_statuses;
 use crate::mcp::effective_mcp_servers;
@@ -154,6 +169,7 @@ use crate::mentions::build_connector_slug_counts;
 use crate::mentions::build_skill_name_counts;
 use crate::mentions::collect_explicit_app_ids;
 use crate::mentions::collect_tool_mentions_from_messages;
+use crate::project_doc;
 use crate::project_doc::get_user_instructions;
 use crate::proposed_plan_parser::ProposedPlanParser;
 use crate::proposed_plan_parser::ProposedPlanSegment;
@@ -161,16 +177,22 @@ use crate::proposed_plan_parser::extract_proposed_plan_text;
 use crate::protocol::AgentMessageContentDeltaEvent;
 use crate::protocol::AgentReasoningSectionBreakEvent;
 use crate::protocol::ApplyPatchApprovalRequestEvent;
+use crate::protocol::ApprovalTimedOutEvent;
 use crate::protocol::AskForApproval;
 use crate::protocol::BackgroundEventEvent;
 use crate::protocol::DeprecationNoticeEvent;
+use crate::protocol::DryRunPlanEvent;
 use crate::protocol::ErrorEvent;
 use crate::protocol::Event;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecApprovalRequestEvent;
+use crate::protocol::ExecCommandOrigin;
+use crate::protocol::ExecCommandProvenance;
+use crate::protocol::ExecProvenanceItem;
 use crate::protocol::McpServerRefreshConfig;
 use crate::protocol::Op;
 use crate::protocol::PlanDeltaEvent;
+use crate::protocol::PlannedToolCall;
 use crate::protocol::RateLimitSnapshot;
 use crate::protocol::ReasoningContentDeltaEvent;
 use crate::protocol::ReasoningRawContentDeltaEvent;
@@ -191,10 +213,12 @@ use crate::protocol::TokenUsage;
 use crate::protocol::TokenUsageInfo;
 use crate::protocol::TurnDiffEvent;
 use crate::protocol::WarningEvent;
+use crate::provenance;
 use crate::rollout::RolloutRecorder;
 use crate::rollout::RolloutRecorderParams;
 use crate::rollout::map_session_init_error;
 use crate::rollout::metadata;
+use crate::rollout::truncation::user_message_positions_in_rollout;
 use crate::shell;
 use crate::shell_snapshot::ShellSnapshot;
 use crate::skills::SkillError;
@@ -221,12 +245,15 @@ use crate::tasks::SessionTaskContext;
 use crate::tools::ToolRouter;
 use crate::tools::context::SharedTurnDiffTracker;
 use crate::tools::parallel::ToolCallRuntime;
+use crate::tools::router::ToolCall;
 use crate::tools::sandboxing::ApprovalStore;
 use crate::tools::spec::ToolsConfig;
 use crate::tools::spec::ToolsConfigParams;
 use crate::turn_diff_tracker::TurnDiffTracker;
 use crate::unified_exec::UnifiedExecProcessManager;
+use crate::usage_reporting::UsageReporter;
 use crate::util::backoff;
+use crate::web_cache::WebCache;
 use crate::windows_sandbox::WindowsSandboxLevelExt;
 use codex_async_utils::OrCancelExt;
 use codex_otel::OtelManager;
@@ -314,30 +341,33 @@ impl Codex {
             .map_err(|err| CodexErr::Fatal(format!("failed to load rules: {err}")))?;
 
         let config = Arc::new(config);
-        let _ = models_manager
-            .list_models(
-                &config,
-                crate::models_manager::manager::RefreshStrategy::OnlineIfUncached,
-            )
-            .await;
+        let refresh_strategy = if config.offline {
+            crate::models_manager::manager::RefreshStrategy::Offline
+        } else {
+            crate::models_manager::manager::RefreshStrategy::OnlineIfUncached
+        };
+        let _ = models_manager.list_models(&config, refresh_strategy).await;
         let model = models_manager
-            .get_default_model(
-                &config.model,
-                &config,
-                crate::models_manager::manager::RefreshStrategy::OnlineIfUncached,
-            )
+            .get_default_model(&config.model, &config, refresh_strategy)
             .await;
 
         // Resolve base instructions for the session. Priority order:
         // 1. config.base_instructions override
         // 2. conversation history => session_meta.base_instructions
         // 3. base_instructions for current model
+        // `additional_instructions` layers are appended to 1 and 3; history
+        // already holds the assembled text.
         let model_info = models_manager.get_model_info(model.as_str(), &config).await;
-        let base_instructions = config
-            .base_instructions
-            .clone()
-            .or_else(|| conversation_history.get_base_instructions().map(|s| s.text))
-            .unwrap_or_else(|| model_info.get_model_instructions(config.personality));
+        let base_instructions = match (
+            config.base_instructions.clone(),
+            conversation_history.get_base_instructions(),
+        ) {
+            (None, Some(persisted)) => persisted.text,
+            (base, _) => assemble_base_instructions(
+                base.unwrap_or_else(|| model_info.get_model_instructions(config.personality)),
+                &config.additional_instructions,
+            ),
+        };
 
         // Respect thread-start tools. When missing (resumed/forked threads), read from the db
         // first, then fall back to rollout-file tools.
@@ -386,6 +416,9 @@ impl Codex {
             user_instructions,
             spec_parallel_priority: config.spec.parallel_priority,
             spec_sdd_planning: config.spec.sdd_planning,
+            explore_mode: config.explore_mode,
+            persona: config.persona.clone(),
+            scoped_from: None,
             personality: config.personality,
             base_instructions,
             compact_prompt: config.compact_prompt.clone(),
@@ -562,10 +595,7 @@ pub(crate) struct TurnContext {
 }
 impl TurnContext {
     pub(crate) fn model_context_window(&self) -> Option<i64> {
-        let effective_context_window_percent = self.model_info.effective_context_window_percent;
-        self.model_info.context_window.map(|context_window| {
-            context_window.saturating_mul(effective_context_window_percent) / 100
-        })
+        self.model_info.effective_context_window()
     }
 
     pub(crate) fn resolve_path(&self, path: Option<String>) -> PathBuf {
@@ -636,6 +666,17 @@ pub(crate) struct SessionConfiguration {
     /// Whether to inject the built-in "SDD Planning" spec guidance.
     spec_sdd_planning: bool,
 
+    /// Read-only explore mode: tool calls run under a read-only sandbox and
+    /// `apply_patch` only proposes changes.
+    explore_mode: bool,
+
+    /// Active persona; its instructions are sent with every request.
+    persona: Option<String>,
+
+    /// Working directory the session had before `/scope` narrowed it to a
+    /// monorepo package; `cwd` is the package while this is set.
+    scoped_from: Option<PathBuf>,
+
     /// Personality preference for the model.
     personality: Option<Personality>,
 
@@ -676,12 +717,22 @@ impl SessionConfiguration {
         &self.codex_home
     }
 
+    /// The sandbox tool calls run under: read-only while exploring, otherwise
+    /// the configured policy.
+    fn effective_sandbox_policy(&self) -> SandboxPolicy {
+        if self.explore_mode {
+            SandboxPolicy::new_read_only_policy()
+        } else {
+            self.sandbox_policy.get().clone()
+        }
+    }
+
     fn thread_config_snapshot(&self) -> ThreadConfigSnapshot {
         ThreadConfigSnapshot {
             model: self.collaboration_mode.model().to_string(),
             model_provider_id: self.original_config_do_not_use.model_provider_id.clone(),
             approval_policy: self.approval_policy.value(),
-            sandbox_policy: self.sandbox_policy.get().clone(),
+            sandbox_policy: self.effective_sandbox_policy(),
             cwd: self.cwd.clone(),
             reasoning_effort: self.collaboration_mode.reasoning_effort(),
             personality: self.personality,
@@ -710,7 +761,12 @@ impl SessionConfiguration {
             next_configuration.windows_sandbox_level = windows_sandbox_level;
         }
         if let Some(cwd) = updates.cwd.clone() {
-            next_configuration.cwd = cwd;
+            // While scoped, the package stays the working directory and `cwd`
+            // replaces the directory to return to when widening.
+            match next_configuration.scoped_from.as_mut() {
+                Some(scoped_from) => *scoped_from = cwd,
+                None => next_configuration.cwd = cwd,
+            }
         }
         if let Some(spec_parallel_priority) = updates.spec_parallel_priority {
             next_configuration.spec_parallel_priority = spec_parallel_priority;
@@ -718,6 +774,28 @@ impl SessionConfiguration {
         if let Some(spec_sdd_planning) = updates.spec_sdd_planning {
             next_configuration.spec_sdd_planning = spec_sdd_planning;
         }
+        if let Some(explore_mode) = updates.explore_mode {
+            next_configuration.explore_mode = explore_mode;
+        }
+        if let Some(persona) = updates.persona.clone() {
+            next_configuration.persona = persona;
+        }
+        if let Some(scope) = updates.scope.clone() {
+            // Scoping moves the working directory into the package, so tool
+            // calls and the sandbox's writable root follow it. Widening
+            // returns to the directory the session had before.
+            let unscoped_cwd = next_configuration
+                .scoped_from
+                .take()
+                .unwrap_or_else(|| next_configuration.cwd.clone());
+            match scope {
+                Some(package_dir) => {
+                    next_configuration.cwd = package_dir;
+                    next_configuration.scoped_from = Some(unscoped_cwd);
+                }
+                None => next_configuration.cwd = unscoped_cwd,
+            }
+        }
         Ok(next_configuration)
     }
 }
@@ -734,6 +812,9 @@ pub(crate) struct SessionSettingsUpdate {
     pub(crate) personality: Option<Personality>,
     pub(crate) spec_parallel_priority: Option<bool>,
     pub(crate) spec_sdd_planning: Option<bool>,
+    pub(crate) explore_mode: Option<bool>,
+    pub(crate) persona: Option<Option<String>>,
+    pub(crate) scope: Option<Option<PathBuf>>,
 }
 
 impl Session {
@@ -775,6 +856,12 @@ impl Session {
         per_turn_config.personality = session_configuration.personality;
         per_turn_config.spec.parallel_priority = session_configuration.spec_parallel_priority;
         per_turn_config.spec.sdd_planning = session_configuration.spec_sdd_planning;
+        per_turn_config.explore_mode = session_configuration.explore_mode;
+        per_turn_config.persona = session_configuration.persona.clone();
+        per_turn_config.scope = session_configuration
+            .scoped_from
+            .is_some()
+            .then(|| session_configuration.cwd.clone());
         let resolved_web_search_mode = resolve_web_search_mode_for_turn(
             &per_turn_config.web_search_mode,
             session_configuration.sandbox_policy.get(),
@@ -800,6 +887,22 @@ impl Session {
         state.session_configuration.codex_home().clone()
     }
 
+    /// Pick up `*.rules` edits made while the session is running.
+    async fn reload_exec_policy(&self) {
+        let config = {
+            let state = self.state.lock().await;
+            Arc::clone(&state.session_configuration.original_config_do_not_use)
+        };
+        if let Err(err) = self
+            .services
+            .exec_policy
+            .reload(&config.config_layer_stack)
+            .await
+        {
+            warn!("failed to reload rules: {err}");
+        }
+    }
+
     fn start_file_watcher_listener(self: &Arc<Self>) {
         let mut rx = self.services.file_watcher.subscribe();
         let weak_sess = Arc::downgrade(self);
@@ -816,6 +919,12 @@ impl Session {
                         };
                         sess.send_event_raw(event).await;
                     }
+                    Ok(FileWatcherEvent::ConfigChanged { .. }) => {
+                        let Some(sess) = weak_sess.upgrade() else {
+                            break;
+                        };
+                        sess.reload_exec_policy().await;
+                    }
                     Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
                     Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
                 }
@@ -850,7 +959,13 @@ impl Session {
             model_info: &model_info,
             features: &per_turn_config.features,
             web_search_mode: Some(per_turn_config.web_search_mode.value()),
-        });
+        })
+        .with_web_search_provider(
+            per_turn_config
+                .web_search_provider
+                .as_ref()
+                .map(|provider| provider.kind),
+        );
 
         let cwd = session_configuration.cwd.clone();
         TurnContext {
@@ -870,7 +985,7 @@ impl Session {
             collaboration_mode: session_configuration.collaboration_mode.clone(),
             personality: session_configuration.personality,
             approval_policy: session_configuration.approval_policy.value(),
-            sandbox_policy: session_configuration.sandbox_policy.get().clone(),
+            sandbox_policy: session_configuration.effective_sandbox_policy(),
             network,
             windows_sandbox_level: session_configuration.windows_sandbox_level,
             shell_environment_policy: per_turn_config.shell_environment_policy.clone(),
@@ -914,6 +1029,12 @@ impl Session {
         }
 
         let forked_from_id = initial_history.forked_from_id();
+        let fork_turn = match &initial_history {
+            InitialHistory::Forked(items) => {
+                u32::try_from(user_message_positions_in_rollout(items).len()).ok()
+            }
+            InitialHistory::New | InitialHistory::Resumed(_) => None,
+        };
 
         let (conversation_id, rollout_params) = match &initial_history {
             InitialHistory::New | InitialHistory::Forked(_) => {
@@ -923,6 +1044,7 @@ impl Session {
                     RolloutRecorderParams::new(
                         conversation_id,
                         forked_from_id,
+                        fork_turn,
                         session_source,
                         BaseInstructions {
                             text: session_configuration.base_instructions.clone(),
@@ -1068,7 +1190,7 @@ impl Session {
             config.active_profile.clone(),
         );
 
-        let mut default_shell = shell::default_user_shell();
+        let mut default_shell = shell::configured_user_shell(config.shell.program.as_deref());
         // Create the mutable state for the Session.
         let shell_snapshot_tx = if config.features.enabled(Feature::ShellSnapshot) {
             ShellSnapshot::start_snapshotting(
@@ -1133,6 +1255,10 @@ impl Session {
             agent_control,
             network_proxy,
             state_db: state_db_ctx.clone(),
+            session_diff_tracker: Arc::new(Mutex::new(TurnDiffTracker::new())),
+            usage_reporter: UsageReporter::for_session(&config),
+            chat_notifier: ChatNotifier::shared(&config),
+            web_cache: Mutex::new(WebCache::default()),
             model_client: ModelClient::new(
                 Some(Arc::clone(&auth_manager)),
                 conversation_id,
@@ -1189,7 +1315,7 @@ impl Session {
                 model: session_configuration.collaboration_mode.model().to_string(),
                 model_provider_id: config.model_provider_id.clone(),
                 approval_policy: session_configuration.approval_policy.value(),
-                sandbox_policy: session_configuration.sandbox_policy.get().clone(),
+                sandbox_policy: session_configuration.effective_sandbox_policy(),
                 cwd: session_configuration.cwd.clone(),
                 reasoning_effort: session_configuration.collaboration_mode.reasoning_effort(),
                 history_log_id,
@@ -1210,7 +1336,7 @@ impl Session {
         // Construct sandbox_state before initialize() so it can be sent to each
         // MCP server immediately after it becomes ready (avoiding blocking).
         let sandbox_state = SandboxState {
-            sandbox_policy: session_configuration.sandbox_policy.get().clone(),
+            sandbox_policy: session_configuration.effective_sandbox_policy(),
             codex_linux_sandbox_exe: config.codex_linux_sandbox_exe.clone(),
             sandbox_cwd: session_configuration.cwd.clone(),
             use_linux_sandbox_bwrap: config.features.enabled(Feature::UseLinuxSandboxBwrap),
@@ -1536,7 +1662,8 @@ impl Session {
                 Ok(next) => {
                     let previous_cwd = state.session_configuration.cwd.clone();
                     let sandbox_policy_changed =
-                        state.session_configuration.sandbox_policy != next.sandbox_policy;
+                        state.session_configuration.effective_sandbox_policy()
+                            != next.effective_sandbox_policy();
                     let codex_home = next.codex_home.clone();
                     state.session_configuration = next.clone();
                     (next, sandbox_policy_changed, previous_cwd, codex_home)
@@ -1583,7 +1710,7 @@ impl Session {
 
         if sandbox_policy_changed {
             let sandbox_state = SandboxState {
-                sandbox_policy: per_turn_config.sandbox_policy.get().clone(),
+                sandbox_policy: session_configuration.effective_sandbox_policy(),
                 codex_linux_sandbox_exe: per_turn_config.codex_linux_sandbox_exe.clone(),
                 sandbox_cwd: per_turn_config.cwd.clone(),
                 use_linux_sandbox_bwrap: per_turn_config
@@ -1642,6 +1769,45 @@ impl Session {
         state.take_startup_regular_task()
     }
 
+    pub(crate) async fn set_context_exclusions(&self, excluded: Vec<ContextComponentKind>) {
+        let mut state = self.state.lock().await;
+        state.set_context_exclusions(excluded);
+    }
+
+    pub(crate) async fn context_exclusions(&self) -> Vec<ContextComponentKind> {
+        let state = self.state.lock().await;
+        state.context_exclusions()
+    }
+
+    async fn take_context_exclusions(&self) -> Vec<ContextComponentKind> {
+        let mut state = self.state.lock().await;
+        state.take_context_exclusions()
+    }
+
+    pub(crate) async fn set_dry_run_next_turn(&self, enabled: bool) {
+        let mut state = self.state.lock().await;
+        state.set_dry_run_next_turn(enabled);
+    }
+
+    async fn take_dry_run_next_turn(&self) -> bool {
+        let mut state = self.state.lock().await;
+        state.take_dry_run_next_turn()
+    }
+
+    /// Note that an interrupt dropped a model response after `streamed_bytes`
+    /// of output had arrived.
+    async fn record_cut_off_stream(&self, streamed_bytes: usize) {
+        let tokens =
+            i64::try_from(approx_tokens_from_byte_count(streamed_bytes)).unwrap_or(i64::MAX);
+        let mut state = self.state.lock().await;
+        state.set_cut_off_stream_tokens(tokens);
+    }
+
+    pub(crate) async fn take_cut_off_stream_tokens(&self) -> Option<i64> {
+        let mut state = self.state.lock().await;
+        state.take_cut_off_stream_tokens()
+    }
+
     async fn get_config(&self) -> std::sync::Arc<Config> {
         let state = self.state.lock().await;
         state
@@ -1817,6 +1983,28 @@ impl Session {
 
     /// Persist the event to rollout and send it to clients.
     pub(crate) async fn send_event(&self, turn_context: &TurnContext, msg: EventMsg) {
+        if let EventMsg::ExecCommandEnd(end) = &msg
+            && danger_audit::is_danger_mode(
+                turn_context.approval_policy,
+                &turn_context.sandbox_policy,
+            )
+        {
+            danger_audit::record_command(&turn_context.config.log_dir, self.conversation_id, end)
+                .await;
+        }
+        if let EventMsg::ExecCommandEnd(end) = &msg
+            && let Some(provenance) = &end.provenance
+        {
+            self.persist_rollout_items(&[RolloutItem::ExecProvenance(ExecProvenanceItem {
+                call_id: Some(end.call_id.clone()),
+                turn_id: end.turn_id.clone(),
+                command: end.command.clone(),
+                cwd: end.cwd.clone(),
+                exit_code: Some(end.exit_code),
+                provenance: provenance.clone(),
+            })])
+            .await;
+        }
         let legacy_source = msg.clone();
         let event = Event {
             id: turn_context.sub_id.clone(),
@@ -1996,7 +2184,17 @@ impl Session {
             warn!("Overwriting existing pending approval for call_id: {approval_id}");
         }
 
+        if let Some(notifier) = self.services.chat_notifier.as_ref() {
+            notifier.notify(ChatNotification::ApprovalNeeded {
+                thread_id: self.conversation_id,
+                cwd: cwd.clone(),
+                request: shlex::try_join(command.iter().map(String::as_str))
+                    .unwrap_or_else(|_| command.join(" ")),
+                reason: reason.clone(),
+            });
+        }
         let parsed_cmd = parse_command(&command);
+        let policy_safe = is_known_safe_command(&command);
         let event = EventMsg::ExecApprovalRequest(ExecApprovalRequestEvent {
             call_id,
             turn_id: turn_context.sub_id.clone(),
@@ -2007,7 +2205,8 @@ impl Session {
             parsed_cmd,
         });
         self.send_event(turn_context, event).await;
-        rx_approve.await.unwrap_or_default()
+        self.await_approval_decision(turn_context, &approval_id, rx_approve, policy_safe)
+            .await
     }
 
     pub async fn request_patch_approval(
@@ -2017,7 +2216,7 @@ impl Session {
         changes: HashMap<PathBuf, FileChange>,
         reason: Option<String>,
         grant_root: Option<PathBuf>,
-    ) -> oneshot::Receiver<ReviewDecision> {
+    ) -> ReviewDecision {
         // Add the tx_approve callback to the map before sending the request.
         let (tx_approve, rx_approve) = oneshot::channel();
         let approval_id = call_id.clone();
@@ -2035,6 +2234,20 @@ impl Session {
             warn!("Overwriting existing pending approval for call_id: {approval_id}");
         }
 
+        if let Some(notifier) = self.services.chat_notifier.as_ref() {
+            let mut paths: Vec<String> = changes
+                .keys()
+                .map(|path| path.display().to_string())
+                .collect();
+            paths.sort();
+            notifier.notify(ChatNotification::ApprovalNeeded {
+                thread_id: self.conversation_id,
+                cwd: turn_context.cwd.clone(),
+                request: format!("apply_patch {}", paths.join(" ")),
+                reason: reason.clone(),
+            });
+        }
+        let policy_safe = grant_root.is_none() && patch_is_policy_safe(turn_context, &changes);
         let event = EventMsg::ApplyPatchApprovalRequest(ApplyPatchApprovalRequestEvent {
             call_id,
             turn_id: turn_context.sub_id.clone(),
@@ -2043,7 +2256,70 @@ impl Session {
             grant_root,
         });
         self.send_event(turn_context, event).await;
-        rx_approve
+        self.await_approval_decision(turn_context, &approval_id, rx_approve, policy_safe)
+            .await
+    }
+
+    /// Waits for the answer to a pending approval. When
+    /// `approval_timeout_seconds` is configured and elapses first, the
+    /// configured action decides instead and the outcome is recorded as an
+    /// `ApprovalTimedOut` event.
+    async fn await_approval_decision(
+        &self,
+        turn_context: &TurnContext,
+        approval_id: &str,
+        mut rx_approve: oneshot::Receiver<ReviewDecision>,
+        policy_safe: bool,
+    ) -> ReviewDecision {
+        let Some(timeout) = turn_context.config.approval_timeout else {
+            return rx_approve.await.unwrap_or_default();
+        };
+        if let Ok(decision) = tokio::time::timeout(timeout, &mut rx_approve).await {
+            return decision.unwrap_or_default();
+        }
+
+        let pending = {
+            let mut active = self.active_turn.lock().await;
+            match active.as_mut() {
+                Some(at) => {
+                    let mut ts = at.turn_state.lock().await;
+                    ts.remove_pending_approval(approval_id)
+                }
+                None => None,
+            }
+        };
+        if pending.is_none() {
+            // An answer (or an interrupt) claimed the request just as the
+            // timeout fired; honor it.
+            return rx_approve.await.unwrap_or_default();
+        }
+
+        let action = turn_context.config.approval_timeout_action;
+        let decision = match action {
+            ApprovalTimeoutAction::Deny => ReviewDecision::Denied,
+            ApprovalTimeoutAction::AllowIfPolicySafe if policy_safe => ReviewDecision::Approved,
+            ApprovalTimeoutAction::AllowIfPolicySafe => ReviewDecision::Denied,
+            ApprovalTimeoutAction::AbortTurn => ReviewDecision::Abort,
+        };
+        let event = EventMsg::ApprovalTimedOut(ApprovalTimedOutEvent {
+            call_id: approval_id.to_string(),
+            turn_id: turn_context.sub_id.clone(),
+            timeout_seconds: timeout.as_secs(),
+            action,
+            decision: decision.clone(),
+        });
+        self.send_event(turn_context, event).await;
+
+        if action == ApprovalTimeoutAction::AbortTurn
+            && let Err(err) = self
+                .services
+                .agent_control
+                .interrupt_agent(self.conversation_id)
+                .await
+        {
+            warn!("failed to interrupt turn after approval timeout: {err}");
+        }
+        decision
     }
 
     pub async fn request_user_input(
@@ -2103,6 +2379,58 @@ impl Session {
         }
     }
 
+    /// Sends the held calls as a [`EventMsg::DryRunPlan`] and waits for the
+    /// ids the user approved. `None` means the turn ended first.
+    async fn request_dry_run_decision(
+        &self,
+        turn_context: &TurnContext,
+        calls: Vec<PlannedToolCall>,
+    ) -> Option<Vec<String>> {
+        let sub_id = turn_context.sub_id.clone();
+        let (tx_response, rx_response) = oneshot::channel();
+        let prev_entry = {
+            let mut active = self.active_turn.lock().await;
+            match active.as_mut() {
+                Some(at) => {
+                    let mut ts = at.turn_state.lock().await;
+                    ts.insert_pending_dry_run(sub_id.clone(), tx_response)
+                }
+                None => None,
+            }
+        };
+        if prev_entry.is_some() {
+            warn!("Overwriting existing pending dry run for sub_id: {sub_id}");
+        }
+
+        let event = EventMsg::DryRunPlan(DryRunPlanEvent {
+            turn_id: sub_id,
+            calls,
+        });
+        self.send_event(turn_context, event).await;
+        rx_response.await.ok()
+    }
+
+    pub async fn notify_dry_run_decision(&self, sub_id: &str, approved_call_ids: Vec<String>) {
+        let entry = {
+            let mut active = self.active_turn.lock().await;
+            match active.as_mut() {
+                Some(at) => {
+                    let mut ts = at.turn_state.lock().await;
+                    ts.remove_pending_dry_run(sub_id)
+                }
+                None => None,
+            }
+        };
+        match entry {
+            Some(tx_response) => {
+                tx_response.send(approved_call_ids).ok();
+            }
+            None => {
+                warn!("No pending dry run found for sub_id: {sub_id}");
+            }
+        }
+    }
+
     pub async fn notify_dynamic_tool_response(&self, call_id: &str, response: DynamicToolResponse) {
         let entry = {
             let mut active = self.active_turn.lock().await;
@@ -2314,6 +2642,15 @@ impl Session {
         if let Some(developer_instructions) = turn_context.developer_instructions.as_deref() {
             items.push(DeveloperInstructions::new(developer_instructions.to_string()).into());
         }
+        if turn_context.config.provenance.commit_trailers {
+            items.push(
+                DeveloperInstructions::new(provenance::commit_trailer_instructions(
+                    &turn_context.model_info.slug,
+                    &self.conversation_id.to_string(),
+                ))
+                .into(),
+            );
+        }
         if turn_context.tools_config.search_tool {
             items.push(
                 DeveloperInstructions::new(SEARCH_TOOL_DEVELOPER_INSTRUCTIONS.to_string()).into(),
@@ -2337,7 +2674,8 @@ impl Session {
         {
             let model_info = turn_context.model_info.clone();
             let has_baked_personality = model_info.supports_personality()
-                && base_instructions == model_info.get_model_instructions(Some(personality));
+                && base_instructions
+                    .starts_with(&model_info.get_model_instructions(Some(personality)));
             if !has_baked_personality
                 && let Some(personality_message) =
                     Self::personality_message_for(&model_info, personality)
@@ -2383,6 +2721,44 @@ impl Session {
         state.clone_history()
     }
 
+    /// Name the thread after `input` when the turn that just completed was
+    /// its first one and it has no name yet (`auto_title`).
+    pub(crate) async fn maybe_auto_title(
+        self: &Arc<Self>,
+        turn_context: &TurnContext,
+        input: &[UserInput],
+    ) {
+        if !turn_context.config.auto_title {
+            return;
+        }
+        if self
+            .state
+            .lock()
+            .await
+            .session_configuration
+            .thread_name
+            .is_some()
+        {
+            return;
+        }
+        if self.services.rollout.lock().await.is_none() {
+            return;
+        }
+        let user_turns = self
+            .clone_history()
+            .await
+            .raw_items()
+            .iter()
+            .filter(|item| is_user_turn_boundary(item))
+            .count();
+        if user_turns != 1 {
+            return;
+        }
+        if let Some(title) = thread_title::title_from_input(input) {
+            handlers::set_thread_name(self, turn_context.sub_id.clone(), title).await;
+        }
+    }
+
     pub(crate) async fn update_token_usage_info(
         &self,
         turn_context: &TurnContext,
@@ -2631,6 +3007,19 @@ impl Session {
         }
     }
 
+    /// Adds citations to the running turn's source list, which is sent as
+    /// [`EventMsg::TurnSources`] when the turn finishes.
+    pub(crate) async fn record_turn_sources(&self, citations: &[WebCitation]) {
+        if citations.is_empty() {
+            return;
+        }
+        let mut active = self.active_turn.lock().await;
+        if let Some(at) = active.as_mut() {
+            let mut ts = at.turn_state.lock().await;
+            ts.record_sources(citations);
+        }
+    }
+
     pub async fn get_pending_input(&self) -> Vec<ResponseInputItem> {
         let mut active = self.active_turn.lock().await;
         match active.as_mut() {
@@ -2870,6 +3259,9 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                 personality,
                 spec_parallel_priority,
                 spec_sdd_planning,
+                explore_mode,
+                persona,
+                scope,
             } => {
                 let collaboration_mode = if let Some(collab_mode) = collaboration_mode {
                     collab_mode
@@ -2894,6 +3286,9 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                         personality,
                         spec_parallel_priority,
                         spec_sdd_planning,
+                        explore_mode,
+                        persona,
+                        scope,
                         ..Default::default()
                     },
                 )
@@ -2957,6 +3352,33 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
             Op::Undo => {
                 handlers::undo(&sess, sub.id.clone()).await;
             }
+            Op::ListSessionChanges => {
+                handlers::list_session_changes(&sess, sub.id.clone()).await;
+            }
+            Op::DiscardSessionChange { path } => {
+                handlers::discard_session_change(&sess, sub.id.clone(), path).await;
+            }
+            Op::AddNote { text } => {
+                handlers::add_note(&sess, &config, sub.id.clone(), text).await;
+            }
+            Op::SummarizeSession => {
+                handlers::summarize_session(&sess, sub.id.clone()).await;
+            }
+            Op::InspectContext => {
+                handlers::inspect_context(&sess, sub.id.clone()).await;
+            }
+            Op::SetContextExclusions { excluded } => {
+                handlers::set_context_exclusions(&sess, sub.id.clone(), excluded).await;
+            }
+            Op::SetDryRun { enabled } => {
+                handlers::set_dry_run(&sess, enabled).await;
+            }
+            Op::DryRunDecision {
+                id,
+                approved_call_ids,
+            } => {
+                handlers::dry_run_decision(&sess, id, approved_call_ids).await;
+            }
             Op::Compact => {
                 handlers::compact(&sess, sub.id.clone()).await;
             }
@@ -3000,20 +3422,53 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
 }
 
 /// Operation handlers
+/// Whether the patch only touches paths the sandbox policy already lets the
+/// agent write, i.e. it would not have needed approval for its location.
+fn patch_is_policy_safe(
+    turn_context: &TurnContext,
+    changes: &HashMap<PathBuf, FileChange>,
+) -> bool {
+    let policy = &turn_context.sandbox_policy;
+    if policy.has_full_disk_write_access() {
+        return true;
+    }
+    let writable_roots = policy.get_writable_roots_with_cwd(&turn_context.cwd);
+    let is_writable = |path: &Path| {
+        let path = turn_context.cwd.join(path);
+        writable_roots
+            .iter()
+            .any(|root| root.is_path_writable(&path))
+    };
+    changes.iter().all(|(path, change)| {
+        let destination_ok = match change {
+            FileChange::Update {
+                move_path: Some(dest),
+                ..
+            } => is_writable(dest),
+            _ => true,
+        };
+        is_writable(path) && destination_ok
+    })
+}
+
 mod handlers {
     use crate::codex::Session;
     use crate::codex::SessionSettingsUpdate;
     use crate::codex::SteerInputError;
     use crate::codex::TurnContext;
 
+    use crate::codex::built_tools;
     use crate::codex::spawn_review_thread;
     use crate::config::Config;
+    use crate::context_inspector;
 
     use crate::mcp::auth::compute_auth_statuses;
     use crate::mcp::collect_mcp_snapshot_from_manager;
     use crate::mcp::effective_mcp_servers;
     use crate::review_prompts::resolve_review_request;
     use crate::rollout::session_index;
+    use crate::session_note::session_note_input_item;
+    use crate::session_summary;
     use crate::tasks::CompactTask;
     use crate::tasks::SddGitTask;
     use crate::tasks::UndoTask;
@@ -3021,7 +3476,9 @@ mod handlers {
     use crate::tasks::UserShellCommandTask;
     use crate::tasks::execute_user_shell_command;
     use codex_protocol::custom_prompts::CustomPrompt;
+    use codex_protocol::models::ResponseItem;
     use codex_protocol::protocol::CodexErrorInfo;
+    use codex_protocol::protocol::ContextComponentKind;
     use codex_protocol::protocol::ErrorEvent;
     use codex_protocol::protocol::Event;
     use codex_protocol::protocol::EventMsg;
@@ -3035,6 +3492,9 @@ mod handlers {
     use codex_protocol::protocol::ReviewDecision;
     use codex_protocol::protocol::ReviewRequest;
     use codex_protocol::protocol::SddGitAction;
+    use codex_protocol::protocol::SessionChangesEvent;
+    use codex_protocol::protocol::SessionNoteEvent;
+    use codex_protocol::protocol::SessionSummaryEvent;
     use codex_protocol::protocol::SkillsListEntry;
     use codex_protocol::protocol::ThreadNameUpdatedEvent;
     use codex_protocol::protocol::ThreadRolledBackEvent;
@@ -3051,8 +3511,10 @@ mod handlers {
     use codex_protocol::user_input::UserInput;
     use codex_rmcp_client::ElicitationAction;
     use codex_rmcp_client::ElicitationResponse;
+    use std::collections::HashSet;
     use std::path::PathBuf;
     use std::sync::Arc;
+    use tokio_util::sync::CancellationToken;
     use tracing::info;
     use tracing::warn;
 
@@ -3123,6 +3585,9 @@ mod handlers {
                         personality,
                         spec_parallel_priority: None,
                         spec_sdd_planning: None,
+                        explore_mode: None,
+                        persona: None,
+                        scope: None,
                     },
                 )
             }
@@ -3397,6 +3862,137 @@ mod handlers {
         sess.send_event_raw(event).await;
     }
 
+    pub async fn list_session_changes(sess: &Session, sub_id: String) {
+        let (files, unified_diff) = {
+            let mut tracker = sess.services.session_diff_tracker.lock().await;
+            (tracker.file_changes(), tracker.get_unified_diff())
+        };
+        let unified_diff = match unified_diff {
+            Ok(diff) => diff.unwrap_or_default(),
+            Err(err) => {
+                warn!("failed to compute session diff: {err:#}");
+                String::new()
+            }
+        };
+        let event = Event {
+            id: sub_id,
+            msg: EventMsg::SessionChanges(SessionChangesEvent {
+                files,
+                unified_diff,
+            }),
+        };
+        sess.send_event_raw(event).await;
+    }
+
+    pub async fn discard_session_change(sess: &Session, sub_id: String, path: PathBuf) {
+        let result = sess
+            .services
+            .session_diff_tracker
+            .lock()
+            .await
+            .revert_path(&path);
+        if let Err(err) = result {
+            sess.send_event_raw(Event {
+                id: sub_id.clone(),
+                msg: EventMsg::Error(ErrorEvent {
+                    message: format!("failed to discard changes to {}: {err:#}", path.display()),
+                    codex_error_info: Some(CodexErrorInfo::Other),
+                }),
+            })
+            .await;
+        }
+        list_session_changes(sess, sub_id).await;
+    }
+
+    pub async fn inspect_context(sess: &Session, sub_id: String) {
+        let turn_context = sess.new_default_turn().await;
+        let history = sess.clone_history().await;
+        let base_instructions = sess.get_base_instructions().await;
+        let tools = match built_tools(
+            sess,
+            turn_context.as_ref(),
+            history.raw_items(),
+            &HashSet::new(),
+            None,
+            &CancellationToken::new(),
+        )
+        .await
+        {
+            Ok(router) => router.specs(),
+            Err(err) => {
+                warn!("failed to build tools for context breakdown: {err:#}");
+                Vec::new()
+            }
+        };
+        let excluded = sess.context_exclusions().await;
+        let event = Event {
+            id: sub_id,
+            msg: EventMsg::ContextBreakdown(context_inspector::breakdown(
+                &base_instructions.text,
+                history.raw_items(),
+                &tools,
+                &excluded,
+                turn_context.model_context_window(),
+            )),
+        };
+        sess.send_event_raw(event).await;
+    }
+
+    pub async fn set_context_exclusions(
+        sess: &Session,
+        sub_id: String,
+        excluded: Vec<ContextComponentKind>,
+    ) {
+        sess.set_context_exclusions(excluded).await;
+        inspect_context(sess, sub_id).await;
+    }
+
+    pub async fn set_dry_run(sess: &Session, enabled: bool) {
+        sess.set_dry_run_next_turn(enabled).await;
+    }
+
+    pub async fn dry_run_decision(sess: &Arc<Session>, id: String, approved_call_ids: Vec<String>) {
+        sess.notify_dry_run_decision(&id, approved_call_ids).await;
+    }
+
+    /// Record a `/note`. With `notes_in_context`, the note also reaches the
+    /// model: mid-turn as pending input, otherwise straight into history.
+    pub async fn add_note(sess: &Arc<Session>, config: &Arc<Config>, sub_id: String, text: String) {
+        let text = text.trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        let in_context = config.notes_in_context;
+        if in_context {
+            let item = session_note_input_item(&text);
+            if let Err(items) = sess.inject_response_items(vec![item]).await {
+                let turn_context = sess.new_default_turn_with_sub_id(sub_id.clone()).await;
+                let items: Vec<ResponseItem> = items.into_iter().map(ResponseItem::from).collect();
+                sess.record_conversation_items(&turn_context, &items).await;
+            }
+        }
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::SessionNote(SessionNoteEvent { text, in_context }),
+        })
+        .await;
+    }
+
+    /// Runs in the background so an `Op::Shutdown` sent while the model is
+    /// still writing the summary is not held up by it.
+    pub async fn summarize_session(sess: &Arc<Session>, sub_id: String) {
+        let sess = Arc::clone(sess);
+        tokio::spawn(async move {
+            let turn_context = sess.new_default_turn_with_sub_id(sub_id.clone()).await;
+            let bullets = session_summary::summarize_session(&sess, &turn_context).await;
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::SessionSummary(SessionSummaryEvent { bullets }),
+            })
+            .await;
+        });
+    }
+
     pub async fn list_skills(
         sess: &Session,
         sub_id: String,
@@ -3649,6 +4245,18 @@ mod handlers {
             i64::try_from(turn_count).unwrap_or(0),
             &[],
         );
+        if let Some(usage_reporter) = sess.services.usage_reporter.as_ref() {
+            let (snapshot, usage) = {
+                let state = sess.state.lock().await;
+                (
+                    state.session_configuration.thread_config_snapshot(),
+                    state.token_info().map(|info| info.total_token_usage),
+                )
+            };
+            usage_reporter
+                .finish(sess.conversation_id, &snapshot, turn_count, usage.as_ref())
+                .await;
+        }
 
         // Gracefully flush and shutdown rollout recorder on session end so tests
         // that inspect the rollout file do not race with the background writer.
@@ -3815,7 +4423,8 @@ async fn spawn_review_thread(
         text_elements: Vec::new(),
     }];
     let tc = Arc::new(review_turn_context);
-    sess.spawn_task(tc.clone(), input, ReviewTask::new()).await;
+    sess.spawn_task(tc.clone(), input, ReviewTask::new(resolved.rubric))
+        .await;
 
     // Announce entering review mode so UIs can switch modes.
     let review_request = ReviewRequest {
@@ -3990,6 +4599,13 @@ pub(crate) async fn run_turn(
             .await;
     }
 
+    // Exclusions chosen in `/context` apply to this turn only. Everything
+    // recorded from here on belongs to the turn and is kept as history.
+    let context_exclusions = sess.take_context_exclusions().await;
+    // A dry run gates every sampling request of this turn, not just the first.
+    let dry_run = sess.take_dry_run_next_turn().await;
+    let turn_history_start = sess.clone_history().await.raw_items().len();
+
     let initial_input_for_turn: ResponseInputItem = ResponseInputItem::from(input.clone());
     let response_item: ResponseItem = initial_input_for_turn.clone().into();
     sess.record_user_prompt_and_emit_turn_item(turn_context.as_ref(), &input, response_item)
@@ -4046,9 +4662,15 @@ pub(crate) async fn run_turn(
 
         // Construct the input that we will send to the model.
         let sampling_request_input: Vec<ResponseItem> = {
-            sess.clone_history()
-                .await
-                .for_prompt(&turn_context.model_info.input_modalities)
+            let mut history = sess.clone_history().await;
+            if !context_exclusions.is_empty() {
+                history.replace(context_inspector::drop_excluded_items(
+                    history.raw_items().to_vec(),
+                    turn_history_start,
+                    &context_exclusions,
+                ));
+            }
+            history.for_prompt(&turn_context.model_info.input_modalities)
         };
 
         let sampling_request_input_messages = sampling_request_input
@@ -4068,6 +4690,8 @@ pub(crate) async fn run_turn(
             sampling_request_input,
             &explicitly_enabled_connectors,
             skills_outcome.as_ref(),
+            &context_exclusions,
+            dry_run,
             cancellation_token.child_token(),
         )
         .await
@@ -4103,6 +4727,21 @@ pub(crate) async fn run_turn(
 
                 if !needs_follow_up {
                     last_agent_message = sampling_request_last_agent_message;
+                    if let Some(notifier) = sess.services.chat_notifier.as_ref() {
+                        let changed_files = turn_diff_tracker
+                            .lock()
+                            .await
+                            .file_changes()
+                            .into_iter()
+                            .map(|change| change.path)
+                            .collect();
+                        notifier.notify(ChatNotification::TurnComplete {
+                            thread_id: sess.conversation_id,
+                            cwd: turn_context.cwd.clone(),
+                            last_agent_message: last_agent_message.clone(),
+                            changed_files,
+                        });
+                    }
                     sess.hooks()
                         .dispatch(HookPayload {
                             session_id: sess.conversation_id,
@@ -4118,6 +4757,21 @@ pub(crate) async fn run_turn(
                             },
                         })
                         .await;
+                    if let Some(notify) = turn_context.config.notify.as_ref()
+                        && !notify.is_empty()
+                    {
+                        sess.persist_rollout_items(&[RolloutItem::ExecProvenance(
+                            ExecProvenanceItem {
+                                call_id: None,
+                                turn_id: turn_context.sub_id.clone(),
+                                command: notify.clone(),
+                                cwd: turn_context.cwd.clone(),
+                                exit_code: None,
+                                provenance: ExecCommandProvenance::new(ExecCommandOrigin::Hook),
+                            },
+                        )])
+                        .await;
+                    }
                     break;
                 }
                 continue;
@@ -4275,6 +4929,8 @@ async fn run_sampling_request(
     input: Vec<ResponseItem>,
     explicitly_enabled_connectors: &HashSet<String>,
     skills_outcome: Option<&SkillLoadOutcome>,
+    context_exclusions: &[ContextComponentKind],
+    dry_run: bool,
     cancellation_token: CancellationToken,
 ) -> CodexResult<SamplingRequestResult> {
     let mut input = input;
@@ -4289,6 +4945,15 @@ async fn run_sampling_request(
         if let Some(item) = spec_sdd_planning_instruction_item(turn_context.as_ref()) {
             input.push(item);
         }
+        if let Some(item) = explore_mode_instruction_item(turn_context.as_ref()) {
+            input.push(item);
+        }
+        if let Some(item) = persona_instruction_item(turn_context.as_ref()) {
+            input.push(item);
+        }
+        if let Some(item) = scope_instruction_item(turn_context.as_ref()) {
+            input.push(item);
+        }
     }
     let router = built_tools(
         sess.as_ref(),
@@ -4304,9 +4969,15 @@ async fn run_sampling_request(
 
     let base_instructions = sess.get_base_instructions().await;
 
+    let tools = if context_exclusions.contains(&ContextComponentKind::ToolSchemas) {
+        Vec::new()
+    } else {
+        router.specs()
+    };
+
     let prompt = Prompt {
         input,
-        tools: router.specs(),
+        tools,
         parallel_tool_calls: model_supports_parallel,
         base_instructions,
         personality: turn_context.personality,
@@ -4323,6 +4994,7 @@ async fn run_sampling_request(
             turn_metadata_header,
             Arc::clone(&turn_diff_tracker),
             &prompt,
+            dry_run,
             cancellation_token.child_token(),
         )
         .await
@@ -4417,6 +5089,52 @@ fn spec_sdd_planning_instruction_item(turn_context: &TurnContext) -> Option<Resp
     Some(UserInstructions { directory, text }.into())
 }
 
+fn explore_mode_instruction_item(turn_context: &TurnContext) -> Option<ResponseItem> {
+    if !turn_context.config.explore_mode {
+        return None;
+    }
+
+    let text = tr(turn_context.config.language, "prompt.explore_mode").to_string();
+    let directory = turn_context.cwd.to_string_lossy().into_owned();
+    Some(UserInstructions { directory, text }.into())
+}
+
+fn persona_instruction_item(turn_context: &TurnContext) -> Option<ResponseItem> {
+    let name = turn_context.config.persona.as_deref()?;
+    let Some(persona) = personas::resolve_persona(&turn_context.config, name) else {
+        warn!("unknown persona `{name}`; sending the request without persona instructions");
+        return None;
+    };
+
+    let directory = turn_context.cwd.to_string_lossy().into_owned();
+    Some(
+        UserInstructions {
+            directory,
+            text: persona.instructions,
+        }
+        .into(),
+    )
+}
+
+fn scope_instruction_item(turn_context: &TurnContext) -> Option<ResponseItem> {
+    let package_dir = turn_context.config.scope.as_deref()?;
+    let directory = package_dir.to_string_lossy().into_owned();
+    let mut text = tr_args(
+        turn_context.config.language,
+        "prompt.scope",
+        &[("path", &directory)],
+    );
+    match project_doc::read_scoped_project_docs(&turn_context.config, package_dir) {
+        Ok(Some(docs)) => {
+            text.push_str("\n\n");
+            text.push_str(&docs);
+        }
+        Ok(None) => {}
+        Err(err) => warn!("failed to read project docs for scope {directory}: {err}"),
+    }
+    Some(UserInstructions { directory, text }.into())
+}
+
 async fn built_tools(
     sess: &Session,
     turn_context: &TurnContext,
@@ -4896,6 +5614,7 @@ async fn try_run_sampling_request(
     turn_metadata_header: Option<&str>,
     turn_diff_tracker: SharedTurnDiffTracker,
     prompt: &Prompt,
+    dry_run: bool,
     cancellation_token: CancellationToken,
 ) -> CodexResult<SamplingRequestResult> {
     let collaboration_mode = sess.current_collaboration_mode().await;
@@ -4924,7 +5643,7 @@ async fn try_run_sampling_request(
     );
 
     sess.persist_rollout_items(&[rollout_item]).await;
-    let mut stream = client_session
+    let mut stream = match client_session
         .stream(
             prompt,
             &turn_context.model_info,
@@ -4935,7 +5654,14 @@ async fn try_run_sampling_request(
         )
         .instrument(trace_span!("stream_request"))
         .or_cancel(&cancellation_token)
-        .await??;
+        .await
+    {
+        Ok(stream) => stream?,
+        Err(codex_async_utils::CancelErr::Cancelled) => {
+            sess.record_cut_off_stream(0).await;
+            return Err(CodexErr::TurnAborted);
+        }
+    };
 
     let tool_runtime = ToolCallRuntime::new(
         Arc::clone(&router),
@@ -4945,6 +5671,7 @@ async fn try_run_sampling_request(
     );
     let mut in_flight: FuturesOrdered<BoxFuture<'static, CodexResult<ResponseInputItem>>> =
         FuturesOrdered::new();
+    let mut held_calls: Vec<ToolCall> = Vec::new();
     let mut needs_follow_up = false;
     let mut last_agent_message: Option<String> = None;
     let mut saw_assistant_commentary_message = false;
@@ -4952,6 +5679,8 @@ async fn try_run_sampling_request(
     let mut saw_tool_call = false;
     let mut active_item: Option<TurnItem> = None;
     let mut should_emit_turn_diff = false;
+    // Output received so far, reported if an interrupt cuts the response off.
+    let mut streamed_bytes = 0usize;
     let plan_mode = turn_context.collaboration_mode.mode == ModeKind::Plan;
     let mut plan_mode_state = plan_mode.then(|| PlanModeStreamState::new(&turn_context.sub_id));
     let receiving_span = trace_span!("receiving_stream");
@@ -4971,7 +5700,12 @@ async fn try_run_sampling_request(
             .await
         {
             Ok(event) => event,
-            Err(codex_async_utils::CancelErr::Cancelled) => break Err(CodexErr::TurnAborted),
+            Err(codex_async_utils::CancelErr::Cancelled) => {
+                // Dropping `stream` below closes the connection; the tool
+                // calls still in flight see the same cancellation.
+                sess.record_cut_off_stream(streamed_bytes).await;
+                break Err(CodexErr::TurnAborted);
+            }
         };
 
         let event = match event {
@@ -4991,6 +5725,11 @@ async fn try_run_sampling_request(
         match event {
             ResponseEvent::Created => {}
             ResponseEvent::OutputItemDone(item) => {
+                if let ResponseItem::FunctionCall { arguments, .. } = &item {
+                    streamed_bytes += arguments.len();
+                } else if let ResponseItem::CustomToolCall { input, .. } = &item {
+                    streamed_bytes += input.len();
+                }
                 if let ResponseItem::Message { role, phase, .. } = &item
                     && role == "assistant"
                 {
@@ -5034,17 +5773,21 @@ async fn try_run_sampling_request(
                     turn_context: turn_context.clone(),
                     tool_runtime: tool_runtime.clone(),
                     cancellation_token: cancellation_token.child_token(),
+                    dry_run,
                 };
 
                 let output_result = handle_output_item_done(&mut ctx, item, previously_active_item)
                     .instrument(handle_responses)
                     .await?;
-                if output_result.tool_future.is_some() {
+                if output_result.tool_future.is_some() || output_result.held_call.is_some() {
                     saw_tool_call = true;
                 }
                 if let Some(tool_future) = output_result.tool_future {
                     in_flight.push_back(tool_future);
                 }
+                if let Some(call) = output_result.held_call {
+                    held_calls.push(call);
+                }
                 if let Some(agent_message) = output_result.last_agent_message {
                     last_agent_message = Some(agent_message);
                 }
@@ -5108,6 +5851,7 @@ async fn try_run_sampling_request(
                 });
             }
             ResponseEvent::OutputTextDelta(delta) => {
+                streamed_bytes += delta.len();
                 // In review child threads, suppress assistant text deltas; the
                 // UI will show a selection popup from the final ReviewOutput.
                 if let Some(active) = active_item.as_ref() {
@@ -5138,6 +5882,7 @@ async fn try_run_sampling_request(
                 delta,
                 summary_index,
             } => {
+                streamed_bytes += delta.len();
                 if let Some(active) = active_item.as_ref() {
                     let event = ReasoningContentDeltaEvent {
                         thread_id: sess.conversation_id.to_string(),
@@ -5168,6 +5913,7 @@ async fn try_run_sampling_request(
                 delta,
                 content_index,
             } => {
+                streamed_bytes += delta.len();
                 if let Some(active) = active_item.as_ref() {
                     let event = ReasoningRawContentDeltaEvent {
                         thread_id: sess.conversation_id.to_string(),
@@ -5185,6 +5931,35 @@ async fn try_run_sampling_request(
         }
     };
 
+    if !held_calls.is_empty() {
+        let approved = if outcome.is_ok() {
+            let planned = held_calls.iter().map(dry_run::planned_call).collect();
+            sess.request_dry_run_decision(&turn_context, planned)
+                .or_cancel(&cancellation_token)
+                .await
+                .ok()
+                .flatten()
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        for call in held_calls {
+            if approved.contains(&call.call_id) {
+                in_flight.push_back(Box::pin(
+                    tool_runtime
+                        .clone()
+                        .handle_tool_call(call, cancellation_token.child_token()),
+                ));
+            } else {
+                sess.record_conversation_items(
+                    &turn_context,
+                    &[dry_run::declined_response(&call).into()],
+                )
+                .await;
+            }
+        }
+    }
+
     drain_in_flight(&mut in_flight, sess.clone(), turn_context.clone()).await?;
 
     if should_emit_turn_diff {
@@ -5886,6 +6661,42 @@ mod tests {
         assert_eq!(initial_context, history.raw_items());
     }
 
+    #[tokio::test]
+    async fn unanswered_approval_falls_back_to_timeout_action() {
+        let (session, mut turn_context) = make_session_and_context().await;
+        let mut config = (*turn_context.config).clone();
+        config.approval_timeout = Some(Duration::from_millis(20));
+        config.approval_timeout_action = ApprovalTimeoutAction::AllowIfPolicySafe;
+        turn_context.config = Arc::new(config);
+        *session.active_turn.lock().await = Some(crate::state::ActiveTurn::default());
+
+        let command =
+            |args: &[&str]| -> Vec<String> { args.iter().map(ToString::to_string).collect() };
+        let safe = session
+            .request_command_approval(
+                &turn_context,
+                "call-safe".to_string(),
+                command(&["ls"]),
+                turn_context.cwd.clone(),
+                None,
+                None,
+            )
+            .await;
+        let risky = session
+            .request_command_approval(
+                &turn_context,
+                "call-risky".to_string(),
+                command(&["rm", "-rf", "build"]),
+                turn_context.cwd.clone(),
+                None,
+                None,
+            )
+            .await;
+
+        assert_eq!(safe, ReviewDecision::Approved);
+        assert_eq!(risky, ReviewDecision::Denied);
+    }
+
     #[tokio::test]
     async fn set_rate_limits_retains_previous_credits() {
         let codex_home = tempfile::tempdir().expect("create temp dir");
@@ -5910,6 +6721,9 @@ mod tests {
             user_instructions: config.user_instructions.clone(),
             spec_parallel_priority: config.spec.parallel_priority,
             spec_sdd_planning: config.spec.sdd_planning,
+            explore_mode: config.explore_mode,
+            persona: config.persona.clone(),
+            scoped_from: None,
             personality: config.personality,
             base_instructions: config
                 .base_instructions
@@ -5995,6 +6809,9 @@ mod tests {
             user_instructions: config.user_instructions.clone(),
             spec_parallel_priority: config.spec.parallel_priority,
             spec_sdd_planning: config.spec.sdd_planning,
+            explore_mode: config.explore_mode,
+            persona: config.persona.clone(),
+            scoped_from: None,
             personality: config.personality,
             base_instructions: config
                 .base_instructions
@@ -6261,6 +7078,9 @@ mod tests {
             user_instructions: config.user_instructions.clone(),
             spec_parallel_priority: config.spec.parallel_priority,
             spec_sdd_planning: config.spec.sdd_planning,
+            explore_mode: config.explore_mode,
+            persona: config.persona.clone(),
+            scoped_from: None,
             personality: config.personality,
             base_instructions: config
                 .base_instructions
@@ -6314,6 +7134,9 @@ mod tests {
             user_instructions: config.user_instructions.clone(),
             spec_parallel_priority: config.spec.parallel_priority,
             spec_sdd_planning: config.spec.sdd_planning,
+            explore_mode: config.explore_mode,
+            persona: config.persona.clone(),
+            scoped_from: None,
             personality: config.personality,
             base_instructions: config
                 .base_instructions
@@ -6370,6 +7193,10 @@ mod tests {
             agent_control,
             network_proxy: None,
             state_db: None,
+            session_diff_tracker: Arc::new(Mutex::new(TurnDiffTracker::new())),
+            usage_reporter: None,
+            chat_notifier: None,
+            web_cache: Mutex::new(WebCache::default()),
             model_client: ModelClient::new(
                 Some(auth_manager.clone()),
                 conversation_id,
@@ -6452,6 +7279,9 @@ mod tests {
             user_instructions: config.user_instructions.clone(),
             spec_parallel_priority: config.spec.parallel_priority,
             spec_sdd_planning: config.spec.sdd_planning,
+            explore_mode: config.explore_mode,
+            persona: config.persona.clone(),
+            scoped_from: None,
             personality: config.personality,
             base_instructions: config
                 .base_instructions
@@ -6508,6 +7338,10 @@ mod tests {
             agent_control,
             network_proxy: None,
             state_db: None,
+            session_diff_tracker: Arc::new(Mutex::new(TurnDiffTracker::new())),
+            usage_reporter: None,
+            chat_notifier: None,
+            web_cache: Mutex::new(WebCache::default()),
             model_client: ModelClient::new(
                 Some(Arc::clone(&auth_manager)),
                 conversation_id,
@@ -6854,7 +7688,7 @@ mod tests {
             text: "start review".to_string(),
             text_elements: Vec::new(),
         }];
-        sess.spawn_task(Arc::clone(&tc), input, ReviewTask::new())
+        sess.spawn_task(Arc::clone(&tc), input, ReviewTask::new(None))
             .await;
 
         sess.abort_all_tasks(TurnAbortReason::Interrupted).await;
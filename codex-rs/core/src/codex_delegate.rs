@@ -360,16 +360,10 @@ async fn handle_patch_approval(
         ..
     } = event;
     let approval_id = call_id.clone();
-    let decision_rx = parent_session
-        .request_patch_approval(parent_ctx, call_id, changes, reason, grant_root)
-        .await;
-    let decision = await_approval_with_cancel(
-        async move { decision_rx.await.unwrap_or_default() },
-        parent_session,
-        &approval_id,
-        cancel_token,
-    )
-    .await;
+    let approval_fut =
+        parent_session.request_patch_approval(parent_ctx, call_id, changes, reason, grant_root);
+    let decision =
+        await_approval_with_cancel(approval_fut, parent_session, &approval_id, cancel_token).await;
     let _ = codex
         .submit(Op::PatchApproval {
             id: approval_id,
@@ -483,6 +477,7 @@ mod tests {
                 id: "full".to_string(),
                 msg: EventMsg::TurnAborted(TurnAbortedEvent {
                     reason: TurnAbortReason::Interrupted,
+                    cut_off_output_tokens: None,
                 }),
             })
             .await
@@ -2,6 +2,7 @@ use crate::config::CONFIG_TOML_FILE;
 use crate::config::types::McpServerConfig;
 use crate::config::types::Notice;
 use crate::config::types::SubagentPreset;
+use crate::config::types::TranscriptDensity;
 use crate::path_utils::resolve_symlink_write_paths;
 use crate::path_utils::write_atomically;
 use crate::protocol::AskForApproval;
@@ -12,15 +13,25 @@ use codex_protocol::config_types::SandboxMode;
 use codex_protocol::config_types::TrustLevel;
 use codex_protocol::openai_models::ReasoningEffort;
 use std::collections::BTreeMap;
+use std::fs::File;
 use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
 use tokio::task;
 use toml_edit::ArrayOfTables;
 use toml_edit::DocumentMut;
 use toml_edit::Item as TomlItem;
 use toml_edit::Table as TomlTable;
+use toml_edit::Value as TomlValue;
 use toml_edit::value;
 
+/// How long to wait for another Codex process to finish editing config.toml.
+const LOCK_RETRIES: usize = 50;
+const LOCK_RETRY_SLEEP: Duration = Duration::from_millis(100);
+
+/// How often to redo edits when config.toml keeps changing underneath them.
+const MAX_CONFLICT_RETRIES: usize = 3;
+
 /// Discrete config mutations supported by the persistence engine.
 #[derive(Clone, Debug)]
 pub enum ConfigEdit {
@@ -51,6 +62,8 @@ pub enum ConfigEdit {
     ReplaceMcpServers(BTreeMap<String, McpServerConfig>),
     /// Set or clear a skill config entry under `[[skills.config]]`.
     SetSkillConfig { path: PathBuf, enabled: bool },
+    /// Add or remove a repository skill path under `[skills].trusted`.
+    SetSkillTrusted { path: PathBuf, trusted: bool },
     /// Set trust_level under `[projects."<path>"]`,
     /// migrating inline tables to explicit tables.
     SetProjectTrustLevel { path: PathBuf, level: TrustLevel },
@@ -61,6 +74,8 @@ pub enum ConfigEdit {
     },
     /// Remove the value stored at the exact dotted path.
     ClearPath { segments: Vec<String> },
+    /// Copy the value stored at `from` to `to`, replacing anything there.
+    CopyPath { from: Vec<String>, to: Vec<String> },
 }
 
 pub fn status_line_items_edit(items: &[String]) -> ConfigEdit {
@@ -352,8 +367,12 @@ impl ConfigDocument {
             ConfigEdit::SetSkillConfig { path, enabled } => {
                 Ok(self.set_skill_config(path.as_path(), *enabled))
             }
+            ConfigEdit::SetSkillTrusted { path, trusted } => {
+                Ok(self.set_skill_trusted(path.as_path(), *trusted))
+            }
             ConfigEdit::SetPath { segments, value } => Ok(self.insert(segments, value.clone())),
             ConfigEdit::ClearPath { segments } => Ok(self.clear_owned(segments)),
+            ConfigEdit::CopyPath { from, to } => Ok(self.copy(from, to)),
             ConfigEdit::SetProjectTrustLevel { path, level } => {
                 // Delegate to the existing, tested logic in config.rs to
                 // ensure tables are explicit and migration is preserved.
@@ -548,6 +567,47 @@ impl ConfigDocument {
         mutated
     }
 
+    fn set_skill_trusted(&mut self, path: &Path, trusted: bool) -> bool {
+        let normalized_path = normalize_skill_config_path(path);
+        let mut entries: Vec<String> = self
+            .doc
+            .get("skills")
+            .and_then(|skills| skills.get("trusted"))
+            .and_then(TomlItem::as_array)
+            .map(|array| {
+                array
+                    .iter()
+                    .filter_map(|entry| entry.as_str())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let existing_index = entries.iter().position(|entry| {
+            normalize_skill_config_path(Path::new(entry.as_str())) == normalized_path
+        });
+
+        match (trusted, existing_index) {
+            (true, Some(_)) | (false, None) => return false,
+            (true, None) => entries.push(normalized_path),
+            (false, Some(index)) => {
+                entries.remove(index);
+            }
+        }
+
+        if entries.is_empty() {
+            return self.clear(Scope::Global, &["skills", "trusted"]);
+        }
+        let mut array = toml_edit::Array::new();
+        for entry in entries {
+            array.push(entry);
+        }
+        self.write_value(
+            Scope::Global,
+            &["skills", "trusted"],
+            TomlItem::Value(array.into()),
+        )
+    }
+
     fn scoped_segments(&self, scope: Scope, segments: &[&str]) -> Vec<String> {
         let resolved: Vec<String> = segments
             .iter()
@@ -579,12 +639,36 @@ impl ConfigDocument {
 
         let mut value = value;
         if let Some(existing) = parent.get(last) {
+            // Leave the file untouched when the value is already set.
+            if let (Some(existing), Some(value)) = (existing.as_value(), value.as_value())
+                && same_scalar(existing, value)
+            {
+                return false;
+            }
             Self::preserve_decor(existing, &mut value);
         }
         parent[last] = value;
         true
     }
 
+    fn copy(&mut self, from: &[String], to: &[String]) -> bool {
+        let Some((last, parents)) = from.split_last() else {
+            return false;
+        };
+        let Some(mut item) = self
+            .descend(parents, TraversalMode::Existing)
+            .and_then(|parent| parent.get(last))
+            .cloned()
+        else {
+            return false;
+        };
+        // Comments above the source table describe the source, not the copy.
+        if let TomlItem::Table(table) = &mut item {
+            table.decor_mut().clear();
+        }
+        self.insert(to, item)
+    }
+
     fn remove(&mut self, segments: &[String]) -> bool {
         let Some((last, parents)) = segments.split_last() else {
             return false;
@@ -655,6 +739,16 @@ impl ConfigDocument {
     }
 }
 
+fn same_scalar(existing: &TomlValue, value: &TomlValue) -> bool {
+    match (existing, value) {
+        (TomlValue::String(a), TomlValue::String(b)) => a.value() == b.value(),
+        (TomlValue::Integer(a), TomlValue::Integer(b)) => a.value() == b.value(),
+        (TomlValue::Float(a), TomlValue::Float(b)) => a.value() == b.value(),
+        (TomlValue::Boolean(a), TomlValue::Boolean(b)) => a.value() == b.value(),
+        _ => false,
+    }
+}
+
 fn normalize_skill_config_path(path: &Path) -> String {
     dunce::canonicalize(path)
         .unwrap_or_else(|_| path.to_path_buf())
@@ -663,6 +757,12 @@ fn normalize_skill_config_path(path: &Path) -> String {
 }
 
 /// Persist edits using a blocking strategy.
+///
+/// Edits are applied to the file as it is on disk at write time, never to a
+/// stale copy, so concurrent Codex processes do not drop each other's
+/// changes: they serialize on a lock file next to config.toml, and writers
+/// that ignore the lock (such as an editor) are caught by re-reading the file
+/// right before replacing it.
 pub fn apply_blocking(
     codex_home: &Path,
     profile: Option<&str>,
@@ -674,15 +774,39 @@ pub fn apply_blocking(
 
     let config_path = codex_home.join(CONFIG_TOML_FILE);
     let write_paths = resolve_symlink_write_paths(&config_path)?;
-    let serialized = match write_paths.read_path {
-        Some(path) => match std::fs::read_to_string(&path) {
-            Ok(contents) => contents,
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
-            Err(err) => return Err(err.into()),
-        },
-        None => String::new(),
-    };
+    let _lock = lock_config(&write_paths.write_path)?;
+
+    for _ in 0..MAX_CONFLICT_RETRIES {
+        let serialized = read_config(write_paths.read_path.as_deref())?;
+        let Some(updated) = apply_edits(&serialized, profile, edits)? else {
+            return Ok(());
+        };
+        if read_config(write_paths.read_path.as_deref())? != serialized {
+            continue;
+        }
 
+        write_atomically(&write_paths.write_path, &updated).with_context(|| {
+            format!(
+                "failed to persist config.toml at {}",
+                write_paths.write_path.display()
+            )
+        })?;
+        return Ok(());
+    }
+
+    anyhow::bail!(
+        "config.toml at {} kept changing while Codex was updating it; try again",
+        write_paths.write_path.display()
+    )
+}
+
+/// Applies `edits` to `serialized` and returns the new contents, or `None`
+/// when nothing changed.
+fn apply_edits(
+    serialized: &str,
+    profile: Option<&str>,
+    edits: &[ConfigEdit],
+) -> anyhow::Result<Option<String>> {
     let doc = if serialized.is_empty() {
         DocumentMut::new()
     } else {
@@ -702,18 +826,58 @@ pub fn apply_blocking(
         mutated |= document.apply(edit)?;
     }
 
-    if !mutated {
-        return Ok(());
+    Ok(mutated.then(|| document.doc.to_string()))
+}
+
+fn read_config(read_path: Option<&Path>) -> anyhow::Result<String> {
+    let Some(path) = read_path else {
+        return Ok(String::new());
+    };
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(contents),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(err) => Err(err.into()),
     }
+}
 
-    write_atomically(&write_paths.write_path, &document.doc.to_string()).with_context(|| {
-        format!(
-            "failed to persist config.toml at {}",
-            write_paths.write_path.display()
-        )
-    })?;
+/// Takes the advisory lock that serializes config.toml edits across Codex
+/// processes. The lock lives in a sibling file because config.toml itself is
+/// replaced on every write.
+///
+/// Waiting sleeps the calling thread, which is why [`apply`] and
+/// [`ConfigEditsBuilder::apply`] run the whole edit on a blocking task.
+fn lock_config(write_path: &Path) -> anyhow::Result<File> {
+    lock_config_with_wait(write_path, || std::thread::sleep(LOCK_RETRY_SLEEP))
+}
 
-    Ok(())
+/// [`lock_config`] with `wait` called between attempts.
+fn lock_config_with_wait(write_path: &Path, mut wait: impl FnMut()) -> anyhow::Result<File> {
+    let mut lock_path = write_path.as_os_str().to_owned();
+    lock_path.push(".lock");
+    let lock_path = PathBuf::from(lock_path);
+    if let Some(parent) = lock_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let lock_file = File::options()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&lock_path)
+        .with_context(|| format!("failed to open {}", lock_path.display()))?;
+
+    for _ in 0..LOCK_RETRIES {
+        match lock_file.try_lock() {
+            Ok(()) => return Ok(lock_file),
+            Err(std::fs::TryLockError::WouldBlock) => wait(),
+            Err(std::fs::TryLockError::Error(err)) => return Err(err.into()),
+        }
+    }
+
+    anyhow::bail!(
+        "config.toml at {} is being edited by another Codex process; try again",
+        write_path.display()
+    )
 }
 
 /// Persist edits asynchronously by offloading the blocking writer.
@@ -778,6 +942,14 @@ impl ConfigEditsBuilder {
         self
     }
 
+    pub fn set_tui_density(mut self, density: TranscriptDensity) -> Self {
+        self.edits.push(ConfigEdit::SetPath {
+            segments: vec!["tui".to_string(), "density".to_string()],
+            value: value(density.as_str()),
+        });
+        self
+    }
+
     pub fn set_spec_parallel_priority(mut self, enabled: bool) -> Self {
         self.edits.push(ConfigEdit::SetPath {
             segments: vec!["spec".to_string(), "parallel_priority".to_string()],
@@ -901,6 +1073,99 @@ impl ConfigEditsBuilder {
         self
     }
 
+    /// Remove the trust decision under `[projects."<path>"]`, so Codex asks again.
+    pub fn clear_project_trust_level<P: Into<PathBuf>>(mut self, project_path: P) -> Self {
+        self.edits.push(ConfigEdit::ClearPath {
+            segments: vec![
+                "projects".to_string(),
+                project_path.into().to_string_lossy().to_string(),
+                "trust_level".to_string(),
+            ],
+        });
+        self
+    }
+
+    /// Set or clear the persona under `[projects."<path>"]`.
+    pub fn set_project_persona<P: Into<PathBuf>>(
+        mut self,
+        project_path: P,
+        persona: Option<&str>,
+    ) -> Self {
+        let segments = vec![
+            "projects".to_string(),
+            project_path.into().to_string_lossy().to_string(),
+            "persona".to_string(),
+        ];
+        match persona {
+            Some(persona) => self.edits.push(ConfigEdit::SetPath {
+                segments,
+                value: value(persona),
+            }),
+            None => self.edits.push(ConfigEdit::ClearPath { segments }),
+        }
+        self
+    }
+
+    /// Set or clear the profile under `[projects."<path>"]`.
+    pub fn set_project_profile<P: Into<PathBuf>>(
+        mut self,
+        project_path: P,
+        profile: Option<&str>,
+    ) -> Self {
+        let segments = vec![
+            "projects".to_string(),
+            project_path.into().to_string_lossy().to_string(),
+            "profile".to_string(),
+        ];
+        match profile {
+            Some(profile) => self.edits.push(ConfigEdit::SetPath {
+                segments,
+                value: value(profile),
+            }),
+            None => self.edits.push(ConfigEdit::ClearPath { segments }),
+        }
+        self
+    }
+
+    /// Create an empty `[profiles.<name>]` table.
+    pub fn create_profile(mut self, name: &str) -> Self {
+        self.edits.push(ConfigEdit::SetPath {
+            segments: vec!["profiles".to_string(), name.to_string()],
+            value: TomlItem::Table(TomlTable::new()),
+        });
+        self
+    }
+
+    /// Copy `[profiles.<from>]` to `[profiles.<to>]`.
+    pub fn copy_profile(mut self, from: &str, to: &str) -> Self {
+        self.edits.push(ConfigEdit::CopyPath {
+            from: vec!["profiles".to_string(), from.to_string()],
+            to: vec!["profiles".to_string(), to.to_string()],
+        });
+        self
+    }
+
+    /// Remove `[profiles.<name>]`.
+    pub fn delete_profile(mut self, name: &str) -> Self {
+        self.edits.push(ConfigEdit::ClearPath {
+            segments: vec!["profiles".to_string(), name.to_string()],
+        });
+        self
+    }
+
+    /// Set or clear the top-level `profile` used when none is selected.
+    pub fn set_default_profile(mut self, name: Option<&str>) -> Self {
+        let segments = vec!["profile".to_string()];
+        match name {
+            Some(name) => self.edits.push(ConfigEdit::SetPath {
+                segments,
+                value: value(name),
+            }),
+            None => self.edits.push(ConfigEdit::ClearPath { segments }),
+        }
+        self
+    }
+
     /// Enable or disable a feature flag by key under the `[features]` table.
     pub fn set_feature_enabled(mut self, key: &str, enabled: bool) -> Self {
         self.edits.push(ConfigEdit::SetPath {
@@ -1020,6 +1285,105 @@ model_reasoning_effort = "high"
         assert_eq!(contents, "enabled = true\n");
     }
 
+    #[test]
+    fn blocking_copy_and_delete_profiles() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+        std::fs::write(
+            codex_home.join(CONFIG_TOML_FILE),
+            r#"# Fast iteration.
+[profiles.fast]
+model = "gpt-5-mini"
+"#,
+        )
+        .expect("seed config");
+
+        ConfigEditsBuilder::new(codex_home)
+            .copy_profile("fast", "review")
+            .set_default_profile(Some("review"))
+            .apply_blocking()
+            .expect("copy");
+        ConfigEditsBuilder::new(codex_home)
+            .delete_profile("fast")
+            .create_profile("empty")
+            .apply_blocking()
+            .expect("delete");
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let parsed: TomlValue = toml::from_str(&contents).expect("parse config");
+        let expected: TomlValue = toml::from_str(
+            r#"
+profile = "review"
+
+[profiles.review]
+model = "gpt-5-mini"
+
+[profiles.empty]
+"#,
+        )
+        .expect("parse expected");
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn blocking_set_project_persona_keeps_trust_level() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+        std::fs::write(
+            codex_home.join(CONFIG_TOML_FILE),
+            "[projects.\"/workspace/repo\"]\ntrust_level = \"trusted\"\n",
+        )
+        .expect("seed config");
+
+        ConfigEditsBuilder::new(codex_home)
+            .set_project_persona("/workspace/repo", Some("reviewer"))
+            .apply_blocking()
+            .expect("persist");
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        assert_eq!(
+            contents,
+            "[projects.\"/workspace/repo\"]\ntrust_level = \"trusted\"\npersona = \"reviewer\"\n"
+        );
+
+        ConfigEditsBuilder::new(codex_home)
+            .set_project_persona("/workspace/repo", None)
+            .apply_blocking()
+            .expect("persist");
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        assert_eq!(
+            contents,
+            "[projects.\"/workspace/repo\"]\ntrust_level = \"trusted\"\n"
+        );
+    }
+
+    #[test]
+    fn blocking_clear_project_trust_level_keeps_persona() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+        std::fs::write(
+            codex_home.join(CONFIG_TOML_FILE),
+            "[projects.\"/workspace/repo\"]\ntrust_level = \"read-only\"\npersona = \"reviewer\"\n",
+        )
+        .expect("seed config");
+
+        ConfigEditsBuilder::new(codex_home)
+            .clear_project_trust_level("/workspace/repo")
+            .apply_blocking()
+            .expect("persist");
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        assert_eq!(
+            contents,
+            "[projects.\"/workspace/repo\"]\npersona = \"reviewer\"\n"
+        );
+    }
+
     #[test]
     fn blocking_set_language_top_level() {
         let tmp = tempdir().expect("tmpdir");
@@ -1035,6 +1399,26 @@ model_reasoning_effort = "high"
         assert_eq!(contents, "language = \"zh-cn\"\n");
     }
 
+    #[test]
+    fn blocking_set_tui_density_nested() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+
+        ConfigEditsBuilder::new(codex_home)
+            .set_tui_density(TranscriptDensity::Compact)
+            .apply_blocking()
+            .expect("persist");
+
+        let raw = std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let value: TomlValue = toml::from_str(&raw).expect("parse config");
+        let density = value
+            .get("tui")
+            .and_then(TomlValue::as_table)
+            .and_then(|table| table.get("density"))
+            .and_then(TomlValue::as_str);
+        assert_eq!(density, Some("compact"));
+    }
+
     #[test]
     fn blocking_set_spec_parallel_priority_nested() {
         let tmp = tempdir().expect("tmpdir");
@@ -1129,6 +1513,52 @@ enabled = false
         assert_eq!(contents, expected);
     }
 
+    #[test]
+    fn set_skill_trusted_adds_and_removes_entries() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+
+        ConfigEditsBuilder::new(codex_home)
+            .with_edits([
+                ConfigEdit::SetSkillTrusted {
+                    path: PathBuf::from("/repo/.codex/skills/a/SKILL.md"),
+                    trusted: true,
+                },
+                ConfigEdit::SetSkillTrusted {
+                    path: PathBuf::from("/repo/.codex/skills/b/SKILL.md"),
+                    trusted: true,
+                },
+                ConfigEdit::SetSkillTrusted {
+                    path: PathBuf::from("/repo/.codex/skills/a/SKILL.md"),
+                    trusted: true,
+                },
+            ])
+            .apply_blocking()
+            .expect("persist");
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let expected = r#"[skills]
+trusted = ["/repo/.codex/skills/a/SKILL.md", "/repo/.codex/skills/b/SKILL.md"]
+"#;
+        assert_eq!(contents, expected);
+
+        ConfigEditsBuilder::new(codex_home)
+            .with_edits([ConfigEdit::SetSkillTrusted {
+                path: PathBuf::from("/repo/.codex/skills/a/SKILL.md"),
+                trusted: false,
+            }])
+            .apply_blocking()
+            .expect("persist");
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let expected = r#"[skills]
+trusted = ["/repo/.codex/skills/b/SKILL.md"]
+"#;
+        assert_eq!(contents, expected);
+    }
+
     #[test]
     fn set_skill_config_removes_entry_when_enabled() {
         let tmp = tempdir().expect("tmpdir");
@@ -1903,6 +2333,114 @@ foo = { command = "cmd" , enabled = false }
         assert_eq!(notifications, Some(false));
     }
 
+    #[test]
+    fn blocking_feature_toggle_and_model_preserve_comments_and_order() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+        std::fs::write(
+            codex_home.join(CONFIG_TOML_FILE),
+            r#"# Pinned by the team.
+model = "gpt-5"   # keep in sync with CI
+
+[features]
+# Experimental; revisit next quarter.
+web_search_request = false
+unified_exec = true
+"#,
+        )
+        .expect("seed");
+
+        ConfigEditsBuilder::new(codex_home)
+            .set_model(Some("gpt-5.1-codex"), None)
+            .set_feature_enabled("web_search_request", true)
+            .set_feature_enabled("shell_snapshot", true)
+            .apply_blocking()
+            .expect("persist");
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let expected = r#"# Pinned by the team.
+model = "gpt-5.1-codex"   # keep in sync with CI
+
+[features]
+# Experimental; revisit next quarter.
+web_search_request = true
+unified_exec = true
+shell_snapshot = true
+"#;
+        assert_eq!(contents, expected);
+    }
+
+    #[test]
+    fn blocking_noop_edit_leaves_file_untouched() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+        let original = "model = 'gpt-5'\n\n[features]\nshell_snapshot=true\n";
+        std::fs::write(codex_home.join(CONFIG_TOML_FILE), original).expect("seed");
+
+        ConfigEditsBuilder::new(codex_home)
+            .set_model(Some("gpt-5"), None)
+            .set_feature_enabled("shell_snapshot", true)
+            .apply_blocking()
+            .expect("persist");
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        assert_eq!(contents, original);
+    }
+
+    #[test]
+    fn concurrent_appliers_keep_every_edit() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path().to_path_buf();
+
+        let writers: Vec<_> = (0..8)
+            .map(|idx| {
+                let codex_home = codex_home.clone();
+                std::thread::spawn(move || {
+                    ConfigEditsBuilder::new(&codex_home)
+                        .set_feature_enabled(&format!("feature_{idx}"), true)
+                        .apply_blocking()
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().expect("writer thread").expect("persist");
+        }
+
+        let raw = std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let config: TomlValue = toml::from_str(&raw).expect("parse config");
+        let features = config
+            .get("features")
+            .and_then(toml::Value::as_table)
+            .expect("features table");
+        assert_eq!(features.len(), 8);
+    }
+
+    #[test]
+    fn config_lock_waits_for_other_holder() {
+        let tmp = tempdir().expect("tmpdir");
+        let config_path = tmp.path().join(CONFIG_TOML_FILE);
+        let holder = lock_config(&config_path).expect("first lock");
+
+        let (waiting_tx, waiting_rx) = std::sync::mpsc::channel();
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let waiter = std::thread::spawn({
+            let config_path = config_path.clone();
+            move || {
+                lock_config_with_wait(&config_path, || {
+                    let _ = waiting_tx.send(());
+                    let _ = release_rx.recv();
+                })
+            }
+        });
+
+        waiting_rx.recv().expect("waiter found the lock held");
+        drop(holder);
+        release_tx.send(()).expect("release waiter");
+        waiter.join().expect("waiter thread").expect("lock after release");
+    }
+
     #[tokio::test]
     async fn async_builder_set_model_persists() {
         let tmp = tempdir().expect("tmpdir");
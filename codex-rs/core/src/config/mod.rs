@@ -2,8 +2,13 @@ use crate::auth::AuthCredentialsStoreMode;
 use crate::config::edit::ConfigEdit;
 use crate::config::edit::ConfigEditsBuilder;
 use crate::config::types::AppsConfigToml;
+use crate::config::types::ChatNotificationsToml;
 use crate::config::types::DEFAULT_OTEL_ENVIRONMENT;
+use crate::config::types::EditLimits;
+use crate::config::types::EditLimitsToml;
+use crate::config::types::FollowInPane;
 use crate::config::types::History;
+use crate::config::types::InstructionsLayer;
 use crate::config::types::McpServerConfig;
 use crate::config::types::McpServerDisabledReason;
 use crate::config::types::McpServerTransportConfig;
@@ -13,14 +18,25 @@ use crate::config::types::Notifications;
 use crate::config::types::OtelConfig;
 use crate::config::types::OtelConfigToml;
 use crate::config::types::OtelExporterKind;
+use crate::config::types::PersonaToml;
+use crate::config::types::Provenance;
+use crate::config::types::ProvenanceToml;
+use crate::config::types::ReportingConfigToml;
+use crate::config::types::RolloutFsync;
 use crate::config::types::SandboxWorkspaceWrite;
+use crate::config::types::SessionSyncToml;
+use crate::config::types::ShellConfig;
 use crate::config::types::ShellEnvironmentPolicy;
 use crate::config::types::ShellEnvironmentPolicyToml;
+use crate::config::types::ShellToml;
 use crate::config::types::SkillsConfig;
 use crate::config::types::SpecConfig;
 use crate::config::types::SubagentPresetsConfig;
+use crate::config::types::TranscriptDensity;
 use crate::config::types::Tui;
+use crate::config::types::TuiFrontend;
 use crate::config::types::UriBasedFileOpener;
+use crate::config::types::WebSearchProviderToml;
 use crate::config_loader::CloudRequirementsLoader;
 use crate::config_loader::ConfigLayerStack;
 use crate::config_loader::ConfigRequirements;
@@ -31,6 +47,7 @@ use crate::config_loader::McpServerRequirement;
 use crate::config_loader::ResidencyRequirement;
 use crate::config_loader::Sourced;
 use crate::config_loader::load_config_layers_state;
+use crate::exec_env::load_shell_secrets;
 use crate::features::Feature;
 use crate::features::FeatureOverrides;
 use crate::features::Features;
@@ -44,12 +61,15 @@ use crate::model_provider_info::OLLAMA_OSS_PROVIDER_ID;
 use crate::model_provider_info::built_in_model_providers;
 use crate::project_doc::DEFAULT_PROJECT_DOC_FILENAME;
 use crate::project_doc::LOCAL_PROJECT_DOC_FILENAME;
+use crate::protected_paths::ProtectedPaths;
 use crate::protocol::AskForApproval;
 use crate::protocol::SandboxPolicy;
 use crate::windows_sandbox::WindowsSandboxLevelExt;
 use codex_app_server_protocol::Tools;
 use codex_app_server_protocol::UserSavedConfig;
+use codex_keyring_store::DefaultKeyringStore;
 use codex_protocol::config_types::AltScreenMode;
+use codex_protocol::config_types::ApprovalTimeoutAction;
 use codex_protocol::config_types::ForcedLoginMethod;
 use codex_protocol::config_types::Language;
 use codex_protocol::config_types::ModeKind;
@@ -73,6 +93,7 @@ use std::collections::HashMap;
 use std::io::ErrorKind;
 use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
 #[cfg(test)]
 use tempfile::tempdir;
 
@@ -108,6 +129,7 @@ pub const DEFAULT_COLLAB_WAIT_TIMEOUT_MS: i64 = 30_000;
 pub const DEFAULT_COLLAB_AUTO_CLOSE_ON_PARENT_SHUTDOWN: bool = true;
 pub const DEFAULT_COLLAB_ALLOW_SUBAGENT_PERMISSION_ESCALATION: bool = false;
 pub(crate) const DEFAULT_AGENT_MAX_THREADS: Option<usize> = Some(30);
+pub const DEFAULT_TUI_EXEC_OUTPUT_LINES: usize = 5;
 
 pub const CONFIG_TOML_FILE: &str = "config.toml";
 
@@ -156,8 +178,24 @@ pub struct Config {
     /// Approval policy for executing commands.
     pub approval_policy: Constrained<AskForApproval>,
 
+    /// How long an approval request may go unanswered before
+    /// `approval_timeout_action` decides it. `None` waits indefinitely.
+    pub approval_timeout: Option<Duration>,
+
+    /// Decision applied when `approval_timeout` elapses.
+    pub approval_timeout_action: ApprovalTimeoutAction,
+
     pub sandbox_policy: Constrained<SandboxPolicy>,
 
+    /// Paths the agent may never modify, whatever the approval policy.
+    pub protected_paths: ProtectedPaths,
+
+    /// Size and binary-file limits on whole-file rewrites.
+    pub edit_limits: EditLimits,
+
+    /// Trailers and file headers marking work done through Codex.
+    pub provenance: Provenance,
+
     /// enforce_residency means web traffic cannot be routed outside of a
     /// particular geography. HTTP clients should direct their requests
     /// using backend-specific headers or URLs to enforce this.
@@ -200,6 +238,19 @@ pub struct Config {
     /// Defaults to `false`.
     pub show_raw_agent_reasoning: bool,
 
+    /// When `true`, an unnamed thread is named after its first prompt once the
+    /// first turn completes. Defaults to `true`.
+    pub auto_title: bool,
+
+    /// When `true`, notes saved with `/note` are also added to the
+    /// conversation so the model sees them. Defaults to `false`.
+    pub notes_in_context: bool,
+
+    /// When `true`, quitting the TUI after at least one turn asks the model
+    /// for a short summary of the session and records it in the rollout.
+    /// Defaults to `true`.
+    pub exit_summary: bool,
+
     /// User-provided instructions from AGENTS.md.
     pub user_instructions: Option<String>,
 
@@ -218,6 +269,19 @@ pub struct Config {
     /// Developer instructions override injected as a separate message.
     pub developer_instructions: Option<String>,
 
+    /// Layers appended to the base instructions, in merge order: global file,
+    /// global inline text, profile file, profile inline text.
+    pub additional_instructions: Vec<InstructionsLayer>,
+
+    /// Personas defined under `[personas]`.
+    pub personas: BTreeMap<String, PersonaToml>,
+
+    /// Remote for `codex sessions sync`, from `[sync]`.
+    pub sync: Option<SessionSyncToml>,
+
+    /// Active persona, restored from `[projects."<path>"] persona`.
+    pub persona: Option<String>,
+
     /// Compact prompt override.
     pub compact_prompt: Option<String>,
 
@@ -250,9 +314,14 @@ pub struct Config {
     /// Notification method for terminal notifications (osc9 or bel).
     pub tui_notification_method: NotificationMethod,
 
-    /// Enable ASCII animations and shimmer effects in the TUI.
+    /// Enable ASCII animations and shimmer effects in the TUI. Forced off by
+    /// `tui.reduced_motion` and `tui.accessible`.
     pub animations: bool,
 
+    /// Screen-reader friendly TUI mode (`tui.accessible` or `--a11y`).
+    /// Implies `animations = false`.
+    pub tui_accessible: bool,
+
     /// Show startup tooltips in the TUI welcome screen.
     pub show_tooltips: bool,
 
@@ -270,6 +339,26 @@ pub struct Config {
     /// Ordered list of status line item identifiers for the TUI.
     pub tui_status_line: Option<Vec<String>>,
 
+    /// Number of command output lines the TUI keeps inline in an exec cell.
+    pub tui_exec_output_lines: usize,
+
+    /// Persist the reasoning effort picked with `ctrl+r` to `config.toml`.
+    pub tui_persist_reasoning_toggle: bool,
+
+    /// Command template for opening `path:line` references from the
+    /// transcript overlay.
+    pub tui_open_file_command: Option<String>,
+
+    /// Where `ctrl+o` and `/follow` open a live command follow inside tmux or
+    /// Zellij; `None` keeps the overlay for `ctrl+o`.
+    pub tui_follow_in_pane: Option<FollowInPane>,
+
+    /// Publish session state to `$CODEX_HOME/status/<pid>.json`.
+    pub tui_status_file: bool,
+
+    /// How much detail transcript cells show; see [`Tui::density`].
+    pub tui_density: TranscriptDensity,
+
     /// The directory that should be treated as the current working directory
     /// for the session. All relative paths inside the business-logic layer are
     /// resolved against this path.
@@ -310,6 +399,13 @@ pub struct Config {
     /// Token budget applied when storing tool/function outputs in the context manager.
     pub tool_output_token_limit: Option<usize>,
 
+    /// Timeout applied to shell tool calls that do not set `timeout_ms`.
+    /// `None` uses [`crate::exec::DEFAULT_EXEC_COMMAND_TIMEOUT_MS`].
+    pub exec_timeout_ms: Option<u64>,
+
+    /// Shell program, login default, and rc files for the model's commands.
+    pub shell: ShellConfig,
+
     /// Maximum number of agent threads that can be open concurrently.
     pub agent_max_threads: Option<usize>,
 
@@ -323,9 +419,32 @@ pub struct Config {
     /// Settings that govern if and what will be written to `~/.codex/history.jsonl`.
     pub history: History,
 
+    /// When session rollout writes are synced to disk.
+    pub rollout_fsync: RolloutFsync,
+
     /// When true, session is not persisted on disk. Default to `false`
     pub ephemeral: bool,
 
+    /// Read-only explore mode (`--read-only` / `/mode explore`): tool calls
+    /// run under a read-only sandbox, `apply_patch` calls are shown as
+    /// proposals instead of being applied, and the model is told the session
+    /// is analysis-only.
+    pub explore_mode: bool,
+
+    /// `--offline`: skip the network calls made at startup that a session can
+    /// do without (models refresh, usage and plan lookup, update check, app
+    /// connectors) and use the cached copies instead.
+    pub offline: bool,
+
+    /// `--force-takeover`: resume a session even while another process is
+    /// recording it; that process stops writing to the rollout.
+    pub force_takeover: bool,
+
+    /// Monorepo package the session is narrowed to with `/scope`. Tool calls
+    /// run in it, only it is writable, and its project docs are sent with each
+    /// request. Set at runtime only.
+    pub scope: Option<PathBuf>,
+
     /// Optional URI-based file opener. If set, citations to files in the model
     /// output will be hyperlinked using the specified URI scheme.
     pub file_opener: UriBasedFileOpener,
@@ -412,6 +531,18 @@ pub struct Config {
     /// Defaults to `true`.
     pub feedback_enabled: bool,
 
+    /// Where to send anonymized usage records; `None` unless `[reporting]`
+    /// sets an endpoint.
+    pub reporting: Option<crate::config::types::ReportingConfig>,
+
+    /// Slack/Discord webhooks for turn, approval, and cloud task events;
+    /// `None` unless `[notifications]` configures one.
+    pub chat_notifications: Option<crate::config::types::ChatNotificationsConfig>,
+
+    /// Search backend for `web_search` plus the local `fetch_url` tool;
+    /// `None` unless `[web_search_provider]` is set.
+    pub web_search_provider: Option<crate::config::types::WebSearchProviderConfig>,
+
     /// OTEL configuration (exporter type, endpoint, headers, etc.).
     pub otel: crate::config::types::OtelConfig,
 }
@@ -764,7 +895,7 @@ pub(crate) fn set_project_trust_level_inner(
     //
     // [projects]
     // [projects."/path/to/project"]
-    // trust_level = "trusted" or "untrusted"
+    // trust_level = "trusted", "read-only" or "untrusted"
     //
     // rather than inline tables like:
     //
@@ -894,6 +1025,23 @@ pub struct ConfigToml {
     /// Default approval policy for executing commands.
     pub approval_policy: Option<AskForApproval>,
 
+    /// Seconds to wait for an answer to an approval request before applying
+    /// `approval_timeout_action`. Unset (or 0) waits indefinitely.
+    pub approval_timeout_seconds: Option<u64>,
+
+    /// Decision applied when an approval request times out. Defaults to `deny`.
+    pub approval_timeout_action: Option<ApprovalTimeoutAction>,
+
+    /// Glob patterns, relative to the working directory, for paths the agent
+    /// may never modify, e.g. `[".env", "secrets/**"]`.
+    pub protected_paths: Option<Vec<String>>,
+
+    /// Guards against rewriting large or binary files.
+    pub edit_limits: Option<EditLimitsToml>,
+
+    /// Commit trailers and file headers recording that Codex produced a change.
+    pub provenance: Option<ProvenanceToml>,
+
     #[serde(default)]
     pub shell_environment_policy: ShellEnvironmentPolicyToml,
 
@@ -929,6 +1077,25 @@ pub struct ConfigToml {
     #[serde(default)]
     pub developer_instructions: Option<String>,
 
+    /// Instructions appended to the model's base instructions (or to the
+    /// `model_instructions_file` override), after `additional_instructions_file`.
+    #[serde(default)]
+    pub additional_instructions: Option<String>,
+
+    /// File whose contents are appended to the base instructions before
+    /// `additional_instructions`.
+    #[serde(default)]
+    pub additional_instructions_file: Option<AbsolutePathBuf>,
+
+    /// Personas selectable with `/persona`, keyed by name. A persona named
+    /// after a built-in preset replaces it.
+    #[serde(default)]
+    pub personas: BTreeMap<String, PersonaToml>,
+
+    /// Remote that `codex sessions sync` pushes rollouts to and pulls them from.
+    #[serde(default)]
+    pub sync: Option<SessionSyncToml>,
+
     /// Optional path to a file containing model instructions that will override
     /// the built-in instructions for the selected model. Users are STRONGLY
     /// DISCOURAGED from using this field, as deviating from the instructions
@@ -984,6 +1151,14 @@ pub struct ConfigToml {
     /// Token budget applied when storing tool/function outputs in the context manager.
     pub tool_output_token_limit: Option<usize>,
 
+    /// Default timeout (in milliseconds) for shell commands run by the model
+    /// when the tool call does not set `timeout_ms`. The command's process
+    /// group is killed when it expires.
+    pub exec_timeout_ms: Option<u64>,
+
+    /// Shell used for the model's commands; see [`ShellToml`].
+    pub shell: Option<ShellToml>,
+
     /// Profile to use from the `profiles` map.
     pub profile: Option<String>,
 
@@ -995,6 +1170,10 @@ pub struct ConfigToml {
     #[serde(default)]
     pub history: Option<History>,
 
+    /// When session rollout writes are synced to disk: `never`, `turn`
+    /// (default), or `always`.
+    pub rollout_fsync: Option<RolloutFsync>,
+
     /// Directory where Codex writes log files, for example `codex-tui.log`.
     /// Defaults to `$CODEX_HOME/log`.
     pub log_dir: Option<AbsolutePathBuf>,
@@ -1017,6 +1196,18 @@ pub struct ConfigToml {
     /// Defaults to `false`.
     pub show_raw_agent_reasoning: Option<bool>,
 
+    /// When `true` (the default), name an unnamed thread after its first
+    /// prompt once the first turn completes.
+    pub auto_title: Option<bool>,
+
+    /// When `true`, notes saved with `/note` are also added to the
+    /// conversation so the model sees them. Defaults to `false`.
+    pub notes_in_context: Option<bool>,
+
+    /// When `true` (the default), quitting the TUI after at least one turn
+    /// records a short model-written summary of the session.
+    pub exit_summary: Option<bool>,
+
     pub model_reasoning_effort: Option<ReasoningEffort>,
     pub model_reasoning_summary: Option<ReasoningSummary>,
     /// Optional verbosity control for GPT-5 models (Responses API `text.verbosity`).
@@ -1028,6 +1219,10 @@ pub struct ConfigToml {
     /// Optionally specify a personality for the model
     pub personality: Option<Personality>,
 
+    /// Interactive frontend to launch (`tui1` or `tui2`). When unset, the
+    /// `tui2` feature flag decides.
+    pub tui_frontend: Option<TuiFrontend>,
+
     /// Base URL for requests to ChatGPT (as opposed to the OpenAI API).
     pub chatgpt_base_url: Option<String>,
 
@@ -1089,6 +1284,15 @@ pub struct ConfigToml {
     /// Defaults to `true`.
     pub feedback: Option<crate::config::types::FeedbackConfigToml>,
 
+    /// Periodic upload of anonymized usage records for headless deployments.
+    pub reporting: Option<crate::config::types::ReportingConfigToml>,
+
+    /// Slack and Discord webhooks that receive turn, approval, and cloud task messages.
+    pub notifications: Option<crate::config::types::ChatNotificationsToml>,
+
+    /// Search backend (hosted, Brave, Bing, SearxNG) and local page fetching.
+    pub web_search_provider: Option<crate::config::types::WebSearchProviderToml>,
+
     /// Settings for app-specific controls.
     #[serde(default)]
     pub apps: Option<AppsConfigToml>,
@@ -1143,6 +1347,11 @@ impl From<ConfigToml> for UserSavedConfig {
 #[schemars(deny_unknown_fields)]
 pub struct ProjectConfig {
     pub trust_level: Option<TrustLevel>,
+    /// Persona selected with `/persona` while working in this project.
+    pub persona: Option<String>,
+    /// Profile selected with `/profile` while working in this project. Takes
+    /// precedence over the top-level `profile`.
+    pub profile: Option<String>,
 }
 
 impl ProjectConfig {
@@ -1150,6 +1359,10 @@ impl ProjectConfig {
         matches!(self.trust_level, Some(TrustLevel::Trusted))
     }
 
+    pub fn is_read_only(&self) -> bool {
+        matches!(self.trust_level, Some(TrustLevel::ReadOnly))
+    }
+
     pub fn is_untrusted(&self) -> bool {
         matches!(self.trust_level, Some(TrustLevel::Untrusted))
     }
@@ -1221,10 +1434,13 @@ impl ConfigToml {
             .or(profile_sandbox_mode)
             .or(self.sandbox_mode)
             .or_else(|| {
-                // if no sandbox_mode is set, but user has marked directory as trusted or untrusted, use WorkspaceWrite
+                // if no sandbox_mode is set, but user has marked directory as trusted or untrusted, use WorkspaceWrite;
+                // a read-only trust decision keeps the read-only sandbox
                 self.get_active_project(resolved_cwd).and_then(|p| {
                     if p.is_trusted() || p.is_untrusted() {
                         Some(SandboxMode::WorkspaceWrite)
+                    } else if p.is_read_only() {
+                        Some(SandboxMode::ReadOnly)
                     } else {
                         None
                     }
@@ -1344,6 +1560,9 @@ pub struct ConfigOverrides {
     pub show_raw_agent_reasoning: Option<bool>,
     pub tools_web_search_request: Option<bool>,
     pub ephemeral: Option<bool>,
+    pub explore_mode: Option<bool>,
+    pub offline: Option<bool>,
+    pub force_takeover: Option<bool>,
     /// Additional directories that should be treated as writable roots for this session.
     pub additional_writable_roots: Vec<PathBuf>,
 }
@@ -1470,11 +1689,51 @@ impl Config {
             show_raw_agent_reasoning,
             tools_web_search_request: override_tools_web_search_request,
             ephemeral,
+            explore_mode,
+            offline,
+            force_takeover,
             additional_writable_roots,
         } = overrides;
 
+        let resolved_cwd = {
+            use std::env;
+
+            match cwd {
+                None => {
+                    tracing::info!("cwd not set, using current dir");
+                    env::current_dir()?
+                }
+                Some(p) if p.is_absolute() => p,
+                Some(p) => {
+                    // Resolve relative path against the current working directory.
+                    tracing::info!("cwd is relative, resolving against current dir");
+                    let mut current = env::current_dir()?;
+                    current.push(p);
+                    current
+                }
+            }
+        };
+        let active_project = cfg
+            .get_active_project(&resolved_cwd)
+            .unwrap_or(ProjectConfig {
+                trust_level: None,
+                persona: None,
+                profile: None,
+            });
+        // A profile saved for the project may have been removed from the
+        // file since; fall back instead of refusing to start.
+        let project_profile = active_project.profile.as_ref().filter(|name| {
+            let known = cfg.profiles.contains_key(*name);
+            if !known {
+                startup_warnings.push(format!(
+                    "Profile `{name}` selected for this project is not defined; ignoring it."
+                ));
+            }
+            known
+        });
         let active_profile_name = config_profile_key
             .as_ref()
+            .or(project_profile)
             .or(cfg.profile.as_ref())
             .cloned();
         let config_profile = match active_profile_name.as_ref() {
@@ -1490,38 +1749,23 @@ impl Config {
                 .clone(),
             None => ConfigProfile::default(),
         };
+        let additional_instructions = Self::load_additional_instructions(
+            &cfg,
+            &config_profile,
+            active_profile_name.as_deref(),
+        )?;
 
         let feature_overrides = FeatureOverrides {
             include_apply_patch_tool: include_apply_patch_tool_override,
             web_search_request: override_tools_web_search_request,
         };
 
-        let features = Features::from_config(&cfg, &config_profile, feature_overrides);
-        let resolved_cwd = {
-            use std::env;
-
-            match cwd {
-                None => {
-                    tracing::info!("cwd not set, using current dir");
-                    env::current_dir()?
-                }
-                Some(p) if p.is_absolute() => p,
-                Some(p) => {
-                    // Resolve relative path against the current working directory.
-                    tracing::info!("cwd is relative, resolving against current dir");
-                    let mut current = env::current_dir()?;
-                    current.push(p);
-                    current
-                }
-            }
-        };
+        let mut features = Features::from_config(&cfg, &config_profile, feature_overrides);
+        features.apply_lock(config_layer_stack.feature_lock());
         let additional_writable_roots: Vec<AbsolutePathBuf> = additional_writable_roots
             .into_iter()
             .map(|path| AbsolutePathBuf::resolve_path_against_base(path, &resolved_cwd))
             .collect::<Result<Vec<_>, _>>()?;
-        let active_project = cfg
-            .get_active_project(&resolved_cwd)
-            .unwrap_or(ProjectConfig { trust_level: None });
         let sandbox_mode_was_explicit = sandbox_mode.is_some()
             || config_profile.sandbox_mode.is_some()
             || cfg.sandbox_mode.is_some();
@@ -1551,7 +1795,7 @@ impl Config {
             .or(config_profile.approval_policy)
             .or(cfg.approval_policy)
             .unwrap_or_else(|| {
-                if active_project.is_trusted() {
+                if active_project.is_trusted() || active_project.is_read_only() {
                     AskForApproval::OnRequest
                 } else if active_project.is_untrusted() {
                     AskForApproval::UnlessTrusted
@@ -1614,9 +1858,14 @@ impl Config {
             })?
             .clone();
 
-        let shell_environment_policy = cfg.shell_environment_policy.into();
+        let mut shell_environment_policy: ShellEnvironmentPolicy =
+            cfg.shell_environment_policy.into();
+        if !shell_environment_policy.secrets.is_empty() {
+            load_shell_secrets(&mut shell_environment_policy, &DefaultKeyringStore);
+        }
 
         let history = cfg.history.unwrap_or_default();
+        let protected_paths = ProtectedPaths::new(cfg.protected_paths.clone().unwrap_or_default())?;
 
         let agent_max_threads = cfg
             .agents
@@ -1783,7 +2032,15 @@ impl Config {
             cwd: resolved_cwd,
             startup_warnings,
             approval_policy: constrained_approval_policy.value,
+            approval_timeout: cfg
+                .approval_timeout_seconds
+                .filter(|seconds| *seconds > 0)
+                .map(Duration::from_secs),
+            approval_timeout_action: cfg.approval_timeout_action.unwrap_or_default(),
             sandbox_policy: constrained_sandbox_policy.value,
+            protected_paths,
+            edit_limits: cfg.edit_limits.clone().unwrap_or_default().into(),
+            provenance: cfg.provenance.clone().unwrap_or_default().into(),
             enforce_residency: enforce_residency.value,
             max_active_subagents_per_thread,
             max_spawn_depth,
@@ -1802,6 +2059,10 @@ impl Config {
             base_instructions,
             personality,
             developer_instructions,
+            additional_instructions,
+            personas: cfg.personas,
+            sync: cfg.sync,
+            persona: active_project.persona.clone(),
             compact_prompt,
             // The config.toml omits "_mode" because it's a config file. However, "_mode"
             // is important in code to differentiate the mode from the store implementation.
@@ -1827,12 +2088,19 @@ impl Config {
                 })
                 .collect(),
             tool_output_token_limit: cfg.tool_output_token_limit,
+            exec_timeout_ms: cfg.exec_timeout_ms.filter(|timeout_ms| *timeout_ms > 0),
+            shell: cfg.shell.unwrap_or_default().into(),
             agent_max_threads,
             codex_home,
             log_dir,
             config_layer_stack,
             history,
+            rollout_fsync: cfg.rollout_fsync.unwrap_or_default(),
             ephemeral: ephemeral.unwrap_or_default(),
+            explore_mode: explore_mode.unwrap_or_default(),
+            offline: offline.unwrap_or_default(),
+            force_takeover: force_takeover.unwrap_or_default(),
+            scope: None,
             file_opener: cfg.file_opener.unwrap_or(UriBasedFileOpener::VsCode),
             codex_linux_sandbox_exe,
 
@@ -1841,6 +2109,9 @@ impl Config {
                 .show_raw_agent_reasoning
                 .or(show_raw_agent_reasoning)
                 .unwrap_or(false),
+            auto_title: cfg.auto_title.unwrap_or(true),
+            notes_in_context: cfg.notes_in_context.unwrap_or(false),
+            exit_summary: cfg.exit_summary.unwrap_or(true),
             model_reasoning_effort: config_profile
                 .model_reasoning_effort
                 .or(cfg.model_reasoning_effort),
@@ -1880,6 +2151,18 @@ impl Config {
                 .as_ref()
                 .and_then(|feedback| feedback.enabled)
                 .unwrap_or(true),
+            reporting: cfg
+                .reporting
+                .as_ref()
+                .and_then(ReportingConfigToml::resolve),
+            chat_notifications: cfg
+                .notifications
+                .as_ref()
+                .and_then(ChatNotificationsToml::resolve),
+            web_search_provider: cfg
+                .web_search_provider
+                .as_ref()
+                .map(WebSearchProviderToml::resolve),
             tui_notifications: cfg
                 .tui
                 .as_ref()
@@ -1890,7 +2173,12 @@ impl Config {
                 .as_ref()
                 .map(|t| t.notification_method)
                 .unwrap_or_default(),
-            animations: cfg.tui.as_ref().map(|t| t.animations).unwrap_or(true),
+            animations: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.animations && !t.accessible && !t.reduced_motion)
+                .unwrap_or(true),
+            tui_accessible: cfg.tui.as_ref().is_some_and(|t| t.accessible),
             show_tooltips: cfg.tui.as_ref().map(|t| t.show_tooltips).unwrap_or(true),
             experimental_mode: cfg.tui.as_ref().and_then(|t| t.experimental_mode),
             tui_alternate_screen: cfg
@@ -1899,6 +2187,19 @@ impl Config {
                 .map(|t| t.alternate_screen)
                 .unwrap_or_default(),
             tui_status_line: cfg.tui.as_ref().and_then(|t| t.status_line.clone()),
+            tui_exec_output_lines: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.exec_output_lines)
+                .unwrap_or(DEFAULT_TUI_EXEC_OUTPUT_LINES),
+            tui_persist_reasoning_toggle: cfg
+                .tui
+                .as_ref()
+                .is_some_and(|t| t.persist_reasoning_toggle),
+            tui_open_file_command: cfg.tui.as_ref().and_then(|t| t.open_file_command.clone()),
+            tui_follow_in_pane: cfg.tui.as_ref().and_then(|t| t.follow_in_pane),
+            tui_status_file: cfg.tui.as_ref().is_some_and(|t| t.status_file),
+            tui_density: cfg.tui.as_ref().and_then(|t| t.density).unwrap_or_default(),
             otel: {
                 let t: OtelConfigToml = cfg.otel.unwrap_or_default();
                 let log_user_prompt = t.log_user_prompt.unwrap_or(false);
@@ -1938,6 +2239,46 @@ impl Config {
     /// If `path` is `Some`, attempts to read the file at the given path and
     /// returns its contents as a trimmed `String`. If the file is empty, or
     /// is `Some` but cannot be read, returns an `Err`.
+    fn load_additional_instructions(
+        cfg: &ConfigToml,
+        profile: &ConfigProfile,
+        profile_name: Option<&str>,
+    ) -> std::io::Result<Vec<InstructionsLayer>> {
+        let profile_scope = profile_name.map(|name| format!("profile `{name}` "));
+        let scopes = [
+            (
+                String::new(),
+                cfg.additional_instructions_file.as_ref(),
+                cfg.additional_instructions.as_deref(),
+            ),
+            (
+                profile_scope.unwrap_or_default(),
+                profile.additional_instructions_file.as_ref(),
+                profile.additional_instructions.as_deref(),
+            ),
+        ];
+
+        let mut layers = Vec::new();
+        for (scope, file, inline) in scopes {
+            if let Some(path) = file
+                && let Some(text) =
+                    Self::try_read_non_empty_file(Some(path), "additional instructions file")?
+            {
+                layers.push(InstructionsLayer {
+                    source: format!("{scope}additional_instructions_file ({})", path.display()),
+                    text,
+                });
+            }
+            if let Some(text) = inline.map(str::trim).filter(|text| !text.is_empty()) {
+                layers.push(InstructionsLayer {
+                    source: format!("{scope}additional_instructions"),
+                    text: text.to_string(),
+                });
+            }
+        }
+        Ok(layers)
+    }
+
     fn try_read_non_empty_file(
         path: Option<&AbsolutePathBuf>,
         context: &str,
@@ -1981,6 +2322,17 @@ impl Config {
             self.features.disable(Feature::WindowsSandboxElevated);
         }
     }
+
+    /// Names of the `[profiles.<name>]` tables across all config layers, sorted.
+    pub fn profile_names(&self) -> Vec<String> {
+        let mut names: Vec<String> =
+            match self.config_layer_stack.effective_config().get("profiles") {
+                Some(TomlValue::Table(profiles)) => profiles.keys().cloned().collect(),
+                _ => Vec::new(),
+            };
+        names.sort();
+        names
+    }
 }
 
 pub(crate) fn uses_deprecated_instructions_file(config_layer_stack: &ConfigLayerStack) -> bool {
@@ -2134,14 +2486,44 @@ persistence = "none"
                 notifications: Notifications::Enabled(true),
                 notification_method: NotificationMethod::Auto,
                 animations: true,
+                accessible: false,
+                reduced_motion: false,
                 show_tooltips: true,
                 experimental_mode: None,
                 alternate_screen: AltScreenMode::Auto,
                 status_line: None,
+                exec_output_lines: None,
+                persist_reasoning_toggle: false,
+                open_file_command: None,
+                follow_in_pane: None,
+                status_file: false,
+                density: None,
             }
         );
     }
 
+    #[test]
+    fn tui_reduced_motion_disables_animations() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cfg = toml::from_str::<ConfigToml>(
+            r#"
+[tui]
+reduced_motion = true
+"#,
+        )
+        .expect("TOML deserialization should succeed");
+
+        let config = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )?;
+
+        assert!(!config.animations);
+        assert!(!config.tui_accessible);
+        Ok(())
+    }
+
     #[test]
     fn test_sandbox_config_parsing() {
         let sandbox_full_access = r#"
@@ -2719,6 +3101,59 @@ profile = "project"
         Ok(())
     }
 
+    #[tokio::test]
+    async fn profile_selected_for_project_overrides_top_level_profile() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let workspace = TempDir::new()?;
+        let workspace_key = workspace.path().to_string_lossy().replace('\\', "\\\\");
+        let write_config = |project_profile: &str| {
+            std::fs::write(
+                codex_home.path().join(CONFIG_TOML_FILE),
+                format!(
+                    r#"
+profile = "global"
+
+[profiles.global]
+model = "gpt-global"
+
+[profiles.work]
+model = "gpt-work"
+
+[projects."{workspace_key}"]
+profile = "{project_profile}"
+"#,
+                ),
+            )
+        };
+        let load = || {
+            ConfigBuilder::default()
+                .codex_home(codex_home.path().to_path_buf())
+                .harness_overrides(ConfigOverrides {
+                    cwd: Some(workspace.path().to_path_buf()),
+                    ..Default::default()
+                })
+                .build()
+        };
+
+        write_config("work")?;
+        let config = load().await?;
+        assert_eq!(config.active_profile.as_deref(), Some("work"));
+        assert_eq!(config.model.as_deref(), Some("gpt-work"));
+
+        write_config("removed")?;
+        let config = load().await?;
+        assert_eq!(config.active_profile.as_deref(), Some("global"));
+        assert_eq!(config.model.as_deref(), Some("gpt-global"));
+        assert!(
+            config
+                .startup_warnings
+                .iter()
+                .any(|warning| warning.contains("`removed`"))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn profile_sandbox_mode_overrides_base() -> std::io::Result<()> {
         let codex_home = TempDir::new()?;
@@ -2752,6 +3187,54 @@ profile = "project"
         Ok(())
     }
 
+    #[test]
+    fn additional_instructions_layer_global_before_profile() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let global_file = codex_home.path().join("global.md");
+        std::fs::write(&global_file, "Global file.\n")?;
+        let mut profiles = HashMap::new();
+        profiles.insert(
+            "work".to_string(),
+            ConfigProfile {
+                additional_instructions: Some("Profile inline.".to_string()),
+                ..Default::default()
+            },
+        );
+        let cfg = ConfigToml {
+            profiles,
+            profile: Some("work".to_string()),
+            additional_instructions: Some("  Global inline.  ".to_string()),
+            additional_instructions_file: Some(AbsolutePathBuf::try_from(global_file.clone())?),
+            ..Default::default()
+        };
+
+        let config = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )?;
+
+        assert_eq!(
+            config.additional_instructions,
+            vec![
+                InstructionsLayer {
+                    source: format!("additional_instructions_file ({})", global_file.display()),
+                    text: "Global file.".to_string(),
+                },
+                InstructionsLayer {
+                    source: "additional_instructions".to_string(),
+                    text: "Global inline.".to_string(),
+                },
+                InstructionsLayer {
+                    source: "profile `work` additional_instructions".to_string(),
+                    text: "Profile inline.".to_string(),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn cli_override_takes_precedence_over_profile_sandbox_mode() -> std::io::Result<()> {
         let codex_home = TempDir::new()?;
@@ -4106,7 +4589,12 @@ model_verbosity = "high"
                 model_provider_id: "openai".to_string(),
                 model_provider: fixture.openai_provider.clone(),
                 approval_policy: Constrained::allow_any(AskForApproval::Never),
+                approval_timeout: None,
+                approval_timeout_action: ApprovalTimeoutAction::Deny,
                 sandbox_policy: Constrained::allow_any(SandboxPolicy::new_read_only_policy()),
+                protected_paths: ProtectedPaths::default(),
+                edit_limits: EditLimits::default(),
+                provenance: Provenance::default(),
                 enforce_residency: Constrained::allow_any(None),
                 max_active_subagents_per_thread: DEFAULT_COLLAB_MAX_ACTIVE_SUBAGENTS_PER_THREAD,
                 max_spawn_depth: DEFAULT_COLLAB_MAX_SPAWN_DEPTH,
@@ -4131,18 +4619,28 @@ model_verbosity = "high"
                 project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
                 project_doc_fallback_filenames: Vec::new(),
                 tool_output_token_limit: None,
+                exec_timeout_ms: None,
+                shell: ShellConfig::default(),
                 agent_max_threads: DEFAULT_AGENT_MAX_THREADS,
                 codex_home: fixture.codex_home(),
                 log_dir: fixture.codex_home().join("log"),
                 config_layer_stack: Default::default(),
                 startup_warnings: Vec::new(),
                 history: History::default(),
+                rollout_fsync: RolloutFsync::default(),
                 ephemeral: false,
+                explore_mode: false,
+                offline: false,
+                force_takeover: false,
+                scope: None,
                 file_opener: UriBasedFileOpener::VsCode,
                 codex_linux_sandbox_exe: None,
                 language: Language::En,
                 hide_agent_reasoning: false,
                 show_raw_agent_reasoning: false,
+                auto_title: true,
+                notes_in_context: false,
+                exit_summary: true,
                 model_reasoning_effort: Some(ReasoningEffort::High),
                 model_reasoning_summary: ReasoningSummary::Detailed,
                 model_supports_reasoning_summaries: None,
@@ -4151,6 +4649,10 @@ model_verbosity = "high"
                 chatgpt_base_url: "https://chatgpt.com/backend-api/".to_string(),
                 base_instructions: None,
                 developer_instructions: None,
+                additional_instructions: Vec::new(),
+                personas: BTreeMap::new(),
+                sync: None,
+                persona: None,
                 compact_prompt: None,
                 forced_chatgpt_workspace_id: None,
                 forced_login_method: None,
@@ -4161,7 +4663,11 @@ model_verbosity = "high"
                 features: Features::with_defaults(),
                 suppress_unstable_features_warning: false,
                 active_profile: Some("o3".to_string()),
-                active_project: ProjectConfig { trust_level: None },
+                active_project: ProjectConfig {
+                    trust_level: None,
+                    persona: None,
+                    profile: None,
+                },
                 windows_wsl_setup_acknowledged: false,
                 notices: Default::default(),
                 check_for_update_on_startup: true,
@@ -4169,12 +4675,22 @@ model_verbosity = "high"
                 tui_notifications: Default::default(),
                 tui_notification_method: Default::default(),
                 animations: true,
+                tui_accessible: false,
                 show_tooltips: true,
                 experimental_mode: None,
                 analytics_enabled: Some(true),
                 feedback_enabled: true,
+                reporting: None,
+                chat_notifications: None,
+                web_search_provider: None,
                 tui_alternate_screen: AltScreenMode::Auto,
                 tui_status_line: None,
+                tui_exec_output_lines: DEFAULT_TUI_EXEC_OUTPUT_LINES,
+                tui_persist_reasoning_toggle: false,
+                tui_open_file_command: None,
+                tui_follow_in_pane: None,
+                tui_status_file: false,
+                tui_density: TranscriptDensity::Normal,
                 otel: OtelConfig::default(),
             },
             o3_profile_config
@@ -4221,7 +4737,12 @@ model_verbosity = "high"
             model_provider_id: "openai-custom".to_string(),
             model_provider: fixture.openai_custom_provider.clone(),
             approval_policy: Constrained::allow_any(AskForApproval::UnlessTrusted),
+            approval_timeout: None,
+            approval_timeout_action: ApprovalTimeoutAction::Deny,
             sandbox_policy: Constrained::allow_any(SandboxPolicy::new_read_only_policy()),
+            protected_paths: ProtectedPaths::default(),
+            edit_limits: EditLimits::default(),
+            provenance: Provenance::default(),
             enforce_residency: Constrained::allow_any(None),
             max_active_subagents_per_thread: DEFAULT_COLLAB_MAX_ACTIVE_SUBAGENTS_PER_THREAD,
             max_spawn_depth: DEFAULT_COLLAB_MAX_SPAWN_DEPTH,
@@ -4246,18 +4767,28 @@ model_verbosity = "high"
             project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
             project_doc_fallback_filenames: Vec::new(),
             tool_output_token_limit: None,
+            exec_timeout_ms: None,
+            shell: ShellConfig::default(),
             agent_max_threads: DEFAULT_AGENT_MAX_THREADS,
             codex_home: fixture.codex_home(),
             log_dir: fixture.codex_home().join("log"),
             config_layer_stack: Default::default(),
             startup_warnings: Vec::new(),
             history: History::default(),
+            rollout_fsync: RolloutFsync::default(),
             ephemeral: false,
+            explore_mode: false,
+            offline: false,
+            force_takeover: false,
+            scope: None,
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
             language: Language::En,
             hide_agent_reasoning: false,
             show_raw_agent_reasoning: false,
+            auto_title: true,
+            notes_in_context: false,
+            exit_summary: true,
             model_reasoning_effort: None,
             model_reasoning_summary: ReasoningSummary::default(),
             model_supports_reasoning_summaries: None,
@@ -4266,6 +4797,10 @@ model_verbosity = "high"
             chatgpt_base_url: "https://chatgpt.com/backend-api/".to_string(),
             base_instructions: None,
             developer_instructions: None,
+            additional_instructions: Vec::new(),
+            personas: BTreeMap::new(),
+            sync: None,
+            persona: None,
             compact_prompt: None,
             forced_chatgpt_workspace_id: None,
             forced_login_method: None,
@@ -4276,7 +4811,11 @@ model_verbosity = "high"
             features: Features::with_defaults(),
             suppress_unstable_features_warning: false,
             active_profile: Some("gpt3".to_string()),
-            active_project: ProjectConfig { trust_level: None },
+            active_project: ProjectConfig {
+                trust_level: None,
+                persona: None,
+                profile: None,
+            },
             windows_wsl_setup_acknowledged: false,
             notices: Default::default(),
             check_for_update_on_startup: true,
@@ -4284,12 +4823,22 @@ model_verbosity = "high"
             tui_notifications: Default::default(),
             tui_notification_method: Default::default(),
             animations: true,
+            tui_accessible: false,
             show_tooltips: true,
             experimental_mode: None,
             analytics_enabled: Some(true),
             feedback_enabled: true,
+            reporting: None,
+            chat_notifications: None,
+            web_search_provider: None,
             tui_alternate_screen: AltScreenMode::Auto,
             tui_status_line: None,
+            tui_exec_output_lines: DEFAULT_TUI_EXEC_OUTPUT_LINES,
+            tui_persist_reasoning_toggle: false,
+            tui_open_file_command: None,
+            tui_follow_in_pane: None,
+            tui_status_file: false,
+            tui_density: TranscriptDensity::Normal,
             otel: OtelConfig::default(),
         };
 
@@ -4334,7 +4883,12 @@ model_verbosity = "high"
             model_provider_id: "openai".to_string(),
             model_provider: fixture.openai_provider.clone(),
             approval_policy: Constrained::allow_any(AskForApproval::OnFailure),
+            approval_timeout: None,
+            approval_timeout_action: ApprovalTimeoutAction::Deny,
             sandbox_policy: Constrained::allow_any(SandboxPolicy::new_read_only_policy()),
+            protected_paths: ProtectedPaths::default(),
+            edit_limits: EditLimits::default(),
+            provenance: Provenance::default(),
             enforce_residency: Constrained::allow_any(None),
             max_active_subagents_per_thread: DEFAULT_COLLAB_MAX_ACTIVE_SUBAGENTS_PER_THREAD,
             max_spawn_depth: DEFAULT_COLLAB_MAX_SPAWN_DEPTH,
@@ -4359,18 +4913,28 @@ model_verbosity = "high"
             project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
             project_doc_fallback_filenames: Vec::new(),
             tool_output_token_limit: None,
+            exec_timeout_ms: None,
+            shell: ShellConfig::default(),
             agent_max_threads: DEFAULT_AGENT_MAX_THREADS,
             codex_home: fixture.codex_home(),
             log_dir: fixture.codex_home().join("log"),
             config_layer_stack: Default::default(),
             startup_warnings: Vec::new(),
             history: History::default(),
+            rollout_fsync: RolloutFsync::default(),
             ephemeral: false,
+            explore_mode: false,
+            offline: false,
+            force_takeover: false,
+            scope: None,
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
             language: Language::En,
             hide_agent_reasoning: false,
             show_raw_agent_reasoning: false,
+            auto_title: true,
+            notes_in_context: false,
+            exit_summary: true,
             model_reasoning_effort: None,
             model_reasoning_summary: ReasoningSummary::default(),
             model_supports_reasoning_summaries: None,
@@ -4379,6 +4943,10 @@ model_verbosity = "high"
             chatgpt_base_url: "https://chatgpt.com/backend-api/".to_string(),
             base_instructions: None,
             developer_instructions: None,
+            additional_instructions: Vec::new(),
+            personas: BTreeMap::new(),
+            sync: None,
+            persona: None,
             compact_prompt: None,
             forced_chatgpt_workspace_id: None,
             forced_login_method: None,
@@ -4389,7 +4957,11 @@ model_verbosity = "high"
             features: Features::with_defaults(),
             suppress_unstable_features_warning: false,
             active_profile: Some("zdr".to_string()),
-            active_project: ProjectConfig { trust_level: None },
+            active_project: ProjectConfig {
+                trust_level: None,
+                persona: None,
+                profile: None,
+            },
             windows_wsl_setup_acknowledged: false,
             notices: Default::default(),
             check_for_update_on_startup: true,
@@ -4397,12 +4969,22 @@ model_verbosity = "high"
             tui_notifications: Default::default(),
             tui_notification_method: Default::default(),
             animations: true,
+            tui_accessible: false,
             show_tooltips: true,
             experimental_mode: None,
             analytics_enabled: Some(false),
             feedback_enabled: true,
+            reporting: None,
+            chat_notifications: None,
+            web_search_provider: None,
             tui_alternate_screen: AltScreenMode::Auto,
             tui_status_line: None,
+            tui_exec_output_lines: DEFAULT_TUI_EXEC_OUTPUT_LINES,
+            tui_persist_reasoning_toggle: false,
+            tui_open_file_command: None,
+            tui_follow_in_pane: None,
+            tui_status_file: false,
+            tui_density: TranscriptDensity::Normal,
             otel: OtelConfig::default(),
         };
 
@@ -4433,7 +5015,12 @@ model_verbosity = "high"
             model_provider_id: "openai".to_string(),
             model_provider: fixture.openai_provider.clone(),
             approval_policy: Constrained::allow_any(AskForApproval::OnFailure),
+            approval_timeout: None,
+            approval_timeout_action: ApprovalTimeoutAction::Deny,
             sandbox_policy: Constrained::allow_any(SandboxPolicy::new_read_only_policy()),
+            protected_paths: ProtectedPaths::default(),
+            edit_limits: EditLimits::default(),
+            provenance: Provenance::default(),
             enforce_residency: Constrained::allow_any(None),
             max_active_subagents_per_thread: DEFAULT_COLLAB_MAX_ACTIVE_SUBAGENTS_PER_THREAD,
             max_spawn_depth: DEFAULT_COLLAB_MAX_SPAWN_DEPTH,
@@ -4458,18 +5045,28 @@ model_verbosity = "high"
             project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
             project_doc_fallback_filenames: Vec::new(),
             tool_output_token_limit: None,
+            exec_timeout_ms: None,
+            shell: ShellConfig::default(),
             agent_max_threads: DEFAULT_AGENT_MAX_THREADS,
             codex_home: fixture.codex_home(),
             log_dir: fixture.codex_home().join("log"),
             config_layer_stack: Default::default(),
             startup_warnings: Vec::new(),
             history: History::default(),
+            rollout_fsync: RolloutFsync::default(),
             ephemeral: false,
+            explore_mode: false,
+            offline: false,
+            force_takeover: false,
+            scope: None,
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
             language: Language::En,
             hide_agent_reasoning: false,
             show_raw_agent_reasoning: false,
+            auto_title: true,
+            notes_in_context: false,
+            exit_summary: true,
             model_reasoning_effort: Some(ReasoningEffort::High),
             model_reasoning_summary: ReasoningSummary::Detailed,
             model_supports_reasoning_summaries: None,
@@ -4478,6 +5075,10 @@ model_verbosity = "high"
             chatgpt_base_url: "https://chatgpt.com/backend-api/".to_string(),
             base_instructions: None,
             developer_instructions: None,
+            additional_instructions: Vec::new(),
+            personas: BTreeMap::new(),
+            sync: None,
+            persona: None,
             compact_prompt: None,
             forced_chatgpt_workspace_id: None,
             forced_login_method: None,
@@ -4488,7 +5089,11 @@ model_verbosity = "high"
             features: Features::with_defaults(),
             suppress_unstable_features_warning: false,
             active_profile: Some("gpt5".to_string()),
-            active_project: ProjectConfig { trust_level: None },
+            active_project: ProjectConfig {
+                trust_level: None,
+                persona: None,
+                profile: None,
+            },
             windows_wsl_setup_acknowledged: false,
             notices: Default::default(),
             check_for_update_on_startup: true,
@@ -4496,12 +5101,22 @@ model_verbosity = "high"
             tui_notifications: Default::default(),
             tui_notification_method: Default::default(),
             animations: true,
+            tui_accessible: false,
             show_tooltips: true,
             experimental_mode: None,
             analytics_enabled: Some(true),
             feedback_enabled: true,
+            reporting: None,
+            chat_notifications: None,
+            web_search_provider: None,
             tui_alternate_screen: AltScreenMode::Auto,
             tui_status_line: None,
+            tui_exec_output_lines: DEFAULT_TUI_EXEC_OUTPUT_LINES,
+            tui_persist_reasoning_toggle: false,
+            tui_open_file_command: None,
+            tui_follow_in_pane: None,
+            tui_status_file: false,
+            tui_density: TranscriptDensity::Normal,
             otel: OtelConfig::default(),
         };
 
@@ -4817,6 +5432,8 @@ trust_level = "untrusted"
                 project_key,
                 ProjectConfig {
                     trust_level: Some(TrustLevel::Trusted),
+                    persona: None,
+                    profile: None,
                 },
             )])),
             ..Default::default()
@@ -4857,6 +5474,8 @@ trust_level = "untrusted"
                 project_key,
                 ProjectConfig {
                     trust_level: Some(TrustLevel::Trusted),
+                    persona: None,
+                    profile: None,
                 },
             )])),
             ..Default::default()
@@ -5022,6 +5641,8 @@ mcp_oauth_callback_port = 5678
                     test_path.to_string_lossy().to_string(),
                     ProjectConfig {
                         trust_level: Some(TrustLevel::Untrusted),
+                        persona: None,
+                        profile: None,
                     },
                 )])),
                 ..Default::default()
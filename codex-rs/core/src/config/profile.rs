@@ -4,6 +4,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::config::types::Personality;
+use crate::config::types::TuiFrontend;
 use crate::protocol::AskForApproval;
 use codex_protocol::config_types::ReasoningSummary;
 use codex_protocol::config_types::SandboxMode;
@@ -29,6 +30,12 @@ pub struct ConfigProfile {
     pub chatgpt_base_url: Option<String>,
     /// Optional path to a file containing model instructions.
     pub model_instructions_file: Option<AbsolutePathBuf>,
+    /// Instructions appended to the base instructions after the global
+    /// `additional_instructions`.
+    pub additional_instructions: Option<String>,
+    /// File appended to the base instructions after the global layers and
+    /// before this profile's `additional_instructions`.
+    pub additional_instructions_file: Option<AbsolutePathBuf>,
     /// Deprecated: ignored. Use `model_instructions_file`.
     #[schemars(skip)]
     pub experimental_instructions_file: Option<AbsolutePathBuf>,
@@ -46,6 +53,9 @@ pub struct ConfigProfile {
     #[schemars(schema_with = "crate::config::schema::features_schema")]
     pub features: Option<crate::features::FeaturesToml>,
     pub oss_provider: Option<String>,
+    /// Frontend to launch for this profile; overrides the top-level
+    /// `tui_frontend`.
+    pub tui_frontend: Option<TuiFrontend>,
 }
 
 impl From<ConfigProfile> for codex_app_server_protocol::Profile {
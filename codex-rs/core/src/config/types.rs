@@ -302,6 +302,25 @@ impl UriBasedFileOpener {
     }
 }
 
+/// One `additional_instructions` layer appended to the base instructions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionsLayer {
+    /// Where the text came from, e.g. ``profile `work` additional_instructions``.
+    pub source: String,
+    pub text: String,
+}
+
+/// A persona defined under `[personas.<name>]`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct PersonaToml {
+    /// Shown next to the name in the `/persona` picker.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Sent to the model with every request while the persona is active.
+    pub instructions: String,
+}
+
 /// Settings that govern if and what will be written to `~/.codex/history.jsonl`.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
 #[schemars(deny_unknown_fields)]
@@ -324,6 +343,46 @@ pub enum HistoryPersistence {
     None,
 }
 
+/// When rollout writes are flushed to stable storage with `fsync`.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RolloutFsync {
+    /// Leave flushing to the OS; a power loss can drop recent events.
+    Never,
+    /// Sync once per turn, when the session flushes its rollout.
+    #[default]
+    Turn,
+    /// Sync after every event; the safest and slowest option.
+    Always,
+}
+
+/// Settings for `codex sessions sync` under `[sync]`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct SessionSyncToml {
+    /// Where synced sessions are stored: `s3://bucket/prefix`, an
+    /// `https://` WebDAV collection, or `git+<url>` for a Git repository.
+    pub remote: String,
+
+    /// Environment variable holding the passphrase that protects the remote
+    /// encryption key. Defaults to `CODEX_SYNC_PASSPHRASE`.
+    pub passphrase_env: Option<String>,
+
+    /// S3 region used to sign requests. Defaults to `us-east-1`.
+    pub s3_region: Option<String>,
+
+    /// S3-compatible endpoint such as a MinIO server. Buckets are addressed
+    /// path-style when set.
+    pub s3_endpoint: Option<String>,
+
+    /// User name for WebDAV basic authentication.
+    pub webdav_username: Option<String>,
+
+    /// Environment variable holding the WebDAV password. Defaults to
+    /// `CODEX_SYNC_WEBDAV_PASSWORD`.
+    pub webdav_password_env: Option<String>,
+}
+
 // ===== Analytics configuration =====
 
 /// Analytics settings loaded from config.toml. Fields are optional so we can apply defaults.
@@ -341,6 +400,233 @@ pub struct FeedbackConfigToml {
     pub enabled: Option<bool>,
 }
 
+// ===== Usage reporting configuration =====
+
+pub const DEFAULT_REPORTING_FLUSH_INTERVAL_SECS: u64 = 300;
+pub const DEFAULT_REPORTING_MAX_BATCH_SIZE: usize = 100;
+
+/// Organization usage reporting from the `[reporting]` table. Independent of
+/// `[analytics]`: records go only to the configured endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ReportingConfigToml {
+    /// URL that receives batches of anonymized usage records. Reporting is off when unset.
+    pub endpoint: Option<String>,
+    /// Seconds between uploads. Defaults to 300.
+    pub flush_interval_secs: Option<u64>,
+    /// Maximum number of records per upload. Defaults to 100.
+    pub max_batch_size: Option<usize>,
+    /// Environment variable holding a bearer token sent with each upload.
+    pub bearer_token_env_var: Option<String>,
+    /// Free-form label copied into every record, e.g. a team or runner pool.
+    pub deployment: Option<String>,
+}
+
+/// Resolved `[reporting]` settings; only present when an endpoint is configured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportingConfig {
+    pub endpoint: String,
+    pub flush_interval: Duration,
+    pub max_batch_size: usize,
+    pub bearer_token_env_var: Option<String>,
+    pub deployment: Option<String>,
+}
+
+impl ReportingConfigToml {
+    pub fn resolve(&self) -> Option<ReportingConfig> {
+        let endpoint = self.endpoint.clone()?;
+        Some(ReportingConfig {
+            endpoint,
+            flush_interval: Duration::from_secs(
+                self.flush_interval_secs
+                    .unwrap_or(DEFAULT_REPORTING_FLUSH_INTERVAL_SECS)
+                    .max(1),
+            ),
+            max_batch_size: self
+                .max_batch_size
+                .unwrap_or(DEFAULT_REPORTING_MAX_BATCH_SIZE)
+                .max(1),
+            bearer_token_env_var: self.bearer_token_env_var.clone(),
+            deployment: self.deployment.clone(),
+        })
+    }
+}
+
+// ===== Chat notifications =====
+
+pub const DEFAULT_CHAT_NOTIFICATION_MIN_INTERVAL_SECS: u64 = 60;
+
+/// Slack and Discord webhooks from the `[notifications]` table.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ChatNotificationsToml {
+    /// Slack incoming webhook, from `[notifications.slack]`.
+    pub slack: Option<ChatWebhookToml>,
+    /// Discord channel webhook, from `[notifications.discord]`.
+    pub discord: Option<ChatWebhookToml>,
+    /// Minimum seconds between two messages of the same kind about the same
+    /// session or cloud task; messages in between are counted and mentioned
+    /// in the next one. Defaults to 60.
+    pub min_interval_secs: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ChatWebhookToml {
+    /// Webhook URL that messages are POSTed to.
+    pub webhook_url: String,
+    /// Events to post. Defaults to all of them.
+    pub events: Option<Vec<ChatNotificationEvent>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatNotificationEvent {
+    /// A turn finished and Codex is waiting for the next message.
+    TurnComplete,
+    /// A command or patch is waiting for approval.
+    ApprovalNeeded,
+    /// A Codex Cloud task finished or failed.
+    CloudTaskFinished,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatPlatform {
+    Slack,
+    Discord,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatWebhook {
+    pub platform: ChatPlatform,
+    pub url: String,
+    pub events: Vec<ChatNotificationEvent>,
+}
+
+/// Resolved `[notifications]` settings; only present when a webhook is configured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatNotificationsConfig {
+    pub webhooks: Vec<ChatWebhook>,
+    pub min_interval: Duration,
+}
+
+impl ChatNotificationsToml {
+    pub fn resolve(&self) -> Option<ChatNotificationsConfig> {
+        let all_events = [
+            ChatNotificationEvent::TurnComplete,
+            ChatNotificationEvent::ApprovalNeeded,
+            ChatNotificationEvent::CloudTaskFinished,
+        ];
+        let webhooks: Vec<ChatWebhook> = [
+            (ChatPlatform::Slack, self.slack.as_ref()),
+            (ChatPlatform::Discord, self.discord.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(platform, webhook)| {
+            let webhook = webhook?;
+            Some(ChatWebhook {
+                platform,
+                url: webhook.webhook_url.clone(),
+                events: webhook
+                    .events
+                    .clone()
+                    .unwrap_or_else(|| all_events.to_vec()),
+            })
+        })
+        .collect();
+        if webhooks.is_empty() {
+            return None;
+        }
+        Some(ChatNotificationsConfig {
+            webhooks,
+            min_interval: Duration::from_secs(
+                self.min_interval_secs
+                    .unwrap_or(DEFAULT_CHAT_NOTIFICATION_MIN_INTERVAL_SECS),
+            ),
+        })
+    }
+}
+
+// ===== Web search provider =====
+
+pub const DEFAULT_WEB_SEARCH_MAX_RESULTS: usize = 5;
+
+/// `[web_search_provider]` table: where `web_search` queries go and whether
+/// the model gets the local `fetch_url` tool.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct WebSearchProviderToml {
+    /// Search backend. `hosted` keeps the model provider's built-in search.
+    pub kind: WebSearchProviderKind,
+    /// Environment variable holding the API key. Defaults to
+    /// `BRAVE_SEARCH_API_KEY` / `BING_SEARCH_API_KEY`; optional for SearxNG.
+    pub api_key_env: Option<String>,
+    /// API endpoint. Required for `searxng`; overrides the public endpoint
+    /// for the other providers.
+    pub base_url: Option<String>,
+    /// Results returned per query. Defaults to 5.
+    pub max_results: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebSearchProviderKind {
+    Hosted,
+    Brave,
+    Bing,
+    #[serde(rename = "searxng")]
+    SearxNg,
+}
+
+impl WebSearchProviderKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            WebSearchProviderKind::Hosted => "hosted",
+            WebSearchProviderKind::Brave => "Brave",
+            WebSearchProviderKind::Bing => "Bing",
+            WebSearchProviderKind::SearxNg => "SearxNG",
+        }
+    }
+}
+
+/// Resolved `[web_search_provider]` settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebSearchProviderConfig {
+    pub kind: WebSearchProviderKind,
+    pub api_key_env: Option<String>,
+    pub base_url: Option<String>,
+    pub max_results: usize,
+}
+
+impl WebSearchProviderToml {
+    pub fn resolve(&self) -> WebSearchProviderConfig {
+        let api_key_env = self
+            .api_key_env
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .or_else(|| match self.kind {
+                WebSearchProviderKind::Brave => Some("BRAVE_SEARCH_API_KEY".to_string()),
+                WebSearchProviderKind::Bing => Some("BING_SEARCH_API_KEY".to_string()),
+                WebSearchProviderKind::Hosted | WebSearchProviderKind::SearxNg => None,
+            });
+        WebSearchProviderConfig {
+            kind: self.kind,
+            api_key_env,
+            base_url: self
+                .base_url
+                .as_deref()
+                .map(|url| url.trim().trim_end_matches('/').to_string())
+                .filter(|url| !url.is_empty()),
+            max_results: self
+                .max_results
+                .filter(|max| *max > 0)
+                .unwrap_or(DEFAULT_WEB_SEARCH_MAX_RESULTS),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AppDisabledReason {
@@ -496,6 +782,70 @@ pub enum ScrollInputMode {
     Trackpad,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FollowInPane {
+    Split,
+    Window,
+}
+
+/// How much detail transcript cells show, set with `/density`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscriptDensity {
+    /// Tool output collapses to a one-line summary and reasoning stays in the
+    /// transcript overlay.
+    Compact,
+    #[default]
+    Normal,
+    /// Full tool output and reasoning, including the summary headers.
+    Verbose,
+}
+
+impl TranscriptDensity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TranscriptDensity::Compact => "compact",
+            TranscriptDensity::Normal => "normal",
+            TranscriptDensity::Verbose => "verbose",
+        }
+    }
+}
+
+impl std::str::FromStr for TranscriptDensity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "compact" => Ok(TranscriptDensity::Compact),
+            "normal" => Ok(TranscriptDensity::Normal),
+            "verbose" => Ok(TranscriptDensity::Verbose),
+            other => Err(format!(
+                "unknown density `{other}` (expected compact, normal, or verbose)"
+            )),
+        }
+    }
+}
+
+/// Which interactive frontend `codex` launches. When unset, the `tui2`
+/// feature flag decides.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TuiFrontend {
+    Tui1,
+    Tui2,
+}
+
+impl TuiFrontend {
+    /// The frontend `/ui switch` relaunches into.
+    pub fn other(self) -> Self {
+        match self {
+            TuiFrontend::Tui1 => TuiFrontend::Tui2,
+            TuiFrontend::Tui2 => TuiFrontend::Tui1,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum NotificationMethod {
@@ -533,6 +883,17 @@ pub struct Tui {
     #[serde(default = "default_true")]
     pub animations: bool,
 
+    /// Screen-reader friendly mode: turns off animations, keeps the TUI inline,
+    /// and announces status changes, approvals, and menus as appended lines
+    /// instead of redrawing them in place. Defaults to `false`.
+    #[serde(default)]
+    pub accessible: bool,
+
+    /// Reduced-motion mode: keeps colors but replaces spinners, shimmer, and
+    /// the welcome animation with static glyphs. Defaults to `false`.
+    #[serde(default)]
+    pub reduced_motion: bool,
+
     /// Show startup tooltips in the TUI welcome screen.
     /// Defaults to `true`.
     #[serde(default = "default_true")]
@@ -559,6 +920,43 @@ pub struct Tui {
     /// When set, the TUI renders the selected items as the status line.
     #[serde(default)]
     pub status_line: Option<Vec<String>>,
+
+    /// Number of output lines a running or finished command keeps inline in
+    /// the transcript. The full output stays available in the follow-output
+    /// overlay (`ctrl+o`). Defaults to `5`.
+    #[serde(default)]
+    pub exec_output_lines: Option<usize>,
+
+    /// Persist the reasoning effort chosen with `ctrl+r` as the default for
+    /// future sessions. When `false` (the default), `ctrl+r` only affects the
+    /// current session.
+    #[serde(default)]
+    pub persist_reasoning_toggle: bool,
+
+    /// Command used to open `path:line` references from the transcript
+    /// overlay, with `{file}` and `{line}` placeholders, for example
+    /// `code -g {file}:{line}`. Defaults to `$VISUAL`/`$EDITOR` with
+    /// `+{line} {file}`.
+    #[serde(default)]
+    pub open_file_command: Option<String>,
+
+    /// When Codex runs inside tmux or Zellij, `ctrl+o` follows the running
+    /// command in a new pane (`split`) or window (`window`) instead of the
+    /// full-screen overlay. Unset (the default) keeps the overlay.
+    #[serde(default)]
+    pub follow_in_pane: Option<FollowInPane>,
+
+    /// Keep `$CODEX_HOME/status/<pid>.json` updated with this session's model,
+    /// activity, token usage, and git branch for `codex status --porcelain`,
+    /// tmux status bars, and shell prompts. Defaults to `false`.
+    #[serde(default)]
+    pub status_file: bool,
+
+    /// How much detail transcript cells show: `compact`, `normal` (the
+    /// default), or `verbose`. `/density` changes and saves it; `codex export`
+    /// uses it unless `--density` is given.
+    #[serde(default)]
+    pub density: Option<TranscriptDensity>,
 }
 
 const fn default_true() -> bool {
@@ -672,6 +1070,11 @@ pub struct SkillConfig {
 pub struct SkillsConfig {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub config: Vec<SkillConfig>,
+
+    /// Repository skills (`SKILL.md` paths) the user has confirmed they trust.
+    /// Repo-scoped skills not listed here stay disabled until trusted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trusted: Vec<AbsolutePathBuf>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
@@ -730,9 +1133,41 @@ pub struct ShellEnvironmentPolicyToml {
     /// List of regular expressions.
     pub include_only: Option<Vec<String>>,
 
+    /// Variables whose values are read from the OS keychain, keyed by the
+    /// variable name.
+    pub secrets: Option<BTreeMap<String, ShellEnvironmentSecretToml>>,
+
     pub experimental_use_profile: Option<bool>,
 }
 
+/// OS keychain entry that supplies a `[shell_environment_policy.secrets]`
+/// variable: a macOS Keychain generic password, a Secret Service item, or a
+/// Windows Credential Manager entry.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ShellEnvironmentSecretToml {
+    pub service: String,
+    pub account: String,
+}
+
+/// A keychain-backed variable together with the value read at startup. The
+/// value is `None` when the entry is missing or the keychain is locked, and is
+/// never printed by `Debug`.
+#[derive(Clone, PartialEq)]
+pub struct ShellEnvironmentSecret {
+    pub source: ShellEnvironmentSecretToml,
+    pub value: Option<String>,
+}
+
+impl fmt::Debug for ShellEnvironmentSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShellEnvironmentSecret")
+            .field("source", &self.source)
+            .field("value", &self.value.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
 pub type EnvironmentVariablePattern = WildMatchPattern<'*', '?'>;
 
 /// Deriving the `env` based on this policy works as follows:
@@ -742,6 +1177,8 @@ pub type EnvironmentVariablePattern = WildMatchPattern<'*', '?'>;
 /// 3. If `exclude` is not empty, filter the map using the provided patterns.
 /// 4. Insert any entries from `r#set` into the map.
 /// 5. If non-empty, filter the map using the `include_only` patterns.
+/// 6. Insert every `secrets` entry whose keychain value could be read; these
+///    bypass both the default excludes and `include_only`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ShellEnvironmentPolicy {
     /// Starting point when building the environment.
@@ -760,6 +1197,10 @@ pub struct ShellEnvironmentPolicy {
     /// Environment variable names to retain in the environment.
     pub include_only: Vec<EnvironmentVariablePattern>,
 
+    /// Variables read from the OS keychain; see
+    /// [`crate::exec_env::load_shell_secrets`].
+    pub secrets: BTreeMap<String, ShellEnvironmentSecret>,
+
     /// If true, the shell profile will be used to run the command.
     pub use_profile: bool,
 }
@@ -782,6 +1223,20 @@ impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
             .into_iter()
             .map(|s| EnvironmentVariablePattern::new_case_insensitive(&s))
             .collect();
+        let secrets = toml
+            .secrets
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, source)| {
+                (
+                    name,
+                    ShellEnvironmentSecret {
+                        source,
+                        value: None,
+                    },
+                )
+            })
+            .collect();
         let use_profile = toml.experimental_use_profile.unwrap_or(false);
 
         Self {
@@ -790,6 +1245,7 @@ impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
             exclude,
             r#set,
             include_only,
+            secrets,
             use_profile,
         }
     }
@@ -803,11 +1259,157 @@ impl Default for ShellEnvironmentPolicy {
             exclude: Vec::new(),
             r#set: HashMap::new(),
             include_only: Vec::new(),
+            secrets: BTreeMap::new(),
             use_profile: false,
         }
     }
 }
 
+/// `[shell]` table: which shell runs the model's commands and how it starts.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ShellToml {
+    /// Shell to use: `zsh`, `bash`, `sh`, `pwsh`, `powershell`, `cmd`, or a
+    /// path to one of them. Defaults to the user's login shell.
+    pub program: Option<String>,
+
+    /// Whether commands run in a login shell when the model does not say.
+    /// Defaults to true.
+    pub login: Option<bool>,
+
+    /// Files sourced before every command, e.g. `~/.zshrc` for shells whose
+    /// `PATH` setup (nvm, pyenv, ...) lives outside the login profile.
+    pub rc_files: Option<Vec<AbsolutePathBuf>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShellConfig {
+    /// Shell name or path from `shell.program`; `None` uses the login shell.
+    pub program: Option<String>,
+
+    /// Default for the `login` argument of shell tool calls.
+    pub login: bool,
+
+    /// Files sourced, in order, before each command.
+    pub rc_files: Vec<PathBuf>,
+}
+
+impl From<ShellToml> for ShellConfig {
+    fn from(toml: ShellToml) -> Self {
+        Self {
+            program: toml
+                .program
+                .map(|program| program.trim().to_string())
+                .filter(|program| !program.is_empty()),
+            login: toml.login.unwrap_or(true),
+            rc_files: toml
+                .rc_files
+                .unwrap_or_default()
+                .into_iter()
+                .map(AbsolutePathBuf::into_path_buf)
+                .collect(),
+        }
+    }
+}
+
+impl Default for ShellConfig {
+    fn default() -> Self {
+        Self {
+            program: None,
+            login: true,
+            rc_files: Vec::new(),
+        }
+    }
+}
+
+/// `[edit_limits]` table: files too large or too opaque for the model to
+/// rewrite by hand.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct EditLimitsToml {
+    /// Files larger than this many bytes may only receive targeted edits;
+    /// replacing, overwriting, or deleting them is guarded. Unset disables
+    /// the size check.
+    pub max_file_bytes: Option<u64>,
+
+    /// Guard every change to a binary file. Defaults to false.
+    pub binary_files: Option<bool>,
+
+    /// What happens to a guarded change. Defaults to `refuse`.
+    pub on_exceed: Option<EditLimitAction>,
+}
+
+/// What happens when a change trips `[edit_limits]`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum EditLimitAction {
+    /// Refuse the change and ask the model for a targeted edit.
+    #[default]
+    Refuse,
+    /// Ask the user to confirm the change. Refuses when the approval policy
+    /// never asks, and for tools without an approval prompt.
+    Ask,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EditLimits {
+    /// `None` when the size check is off.
+    pub max_file_bytes: Option<u64>,
+
+    pub binary_files: bool,
+
+    pub on_exceed: EditLimitAction,
+}
+
+impl EditLimits {
+    pub fn is_enabled(&self) -> bool {
+        self.max_file_bytes.is_some() || self.binary_files
+    }
+}
+
+impl From<EditLimitsToml> for EditLimits {
+    fn from(toml: EditLimitsToml) -> Self {
+        Self {
+            max_file_bytes: toml.max_file_bytes.filter(|bytes| *bytes > 0),
+            binary_files: toml.binary_files.unwrap_or(false),
+            on_exceed: toml.on_exceed.unwrap_or_default(),
+        }
+    }
+}
+
+/// `[provenance]` table: marks commits and files produced through Codex.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ProvenanceToml {
+    /// Add `Codex-Model` and `Codex-Session` trailers to commits made with
+    /// `codex commit` or by the agent. Defaults to false.
+    pub commit_trailers: Option<bool>,
+
+    /// Header written as a comment at the top of files the agent creates.
+    /// `{model}` and `{session_id}` are replaced. Unset writes no header.
+    pub file_header: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Provenance {
+    pub commit_trailers: bool,
+
+    /// Header template; `None` when files are not annotated.
+    pub file_header: Option<String>,
+}
+
+impl From<ProvenanceToml> for Provenance {
+    fn from(toml: ProvenanceToml) -> Self {
+        Self {
+            commit_trailers: toml.commit_trailers.unwrap_or(false),
+            file_header: toml
+                .file_header
+                .map(|header| header.trim().to_string())
+                .filter(|header| !header.is_empty()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,6 +3,8 @@ use super::diagnostics::config_error_from_toml;
 use super::diagnostics::io_error_from_config_error;
 #[cfg(target_os = "macos")]
 use super::macos::load_managed_admin_config_layer;
+use crate::features::FEATURE_LOCK_FILE;
+use crate::features::FeatureLock;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use std::io;
 use std::path::Path;
@@ -25,6 +27,8 @@ pub(super) struct LoadedConfigLayers {
     pub managed_config: Option<MangedConfigFromFile>,
     /// If present, data read from managed preferences (macOS only).
     pub managed_config_from_mdm: Option<TomlValue>,
+    /// `features.lock` from the managed config's folder; empty when absent.
+    pub feature_lock: FeatureLock,
 }
 
 pub(super) async fn load_config_layers_internal(
@@ -55,6 +59,11 @@ pub(super) async fn load_config_layers_internal(
             file: managed_config_path.clone(),
         });
 
+    let feature_lock = match managed_config_path.as_path().parent() {
+        Some(folder) => load_feature_lock(&folder.join(FEATURE_LOCK_FILE)).await?,
+        None => FeatureLock::default(),
+    };
+
     #[cfg(target_os = "macos")]
     let managed_preferences =
         load_managed_admin_config_layer(managed_preferences_base64.as_deref()).await?;
@@ -65,6 +74,19 @@ pub(super) async fn load_config_layers_internal(
     Ok(LoadedConfigLayers {
         managed_config,
         managed_config_from_mdm: managed_preferences,
+        feature_lock,
+    })
+}
+
+async fn load_feature_lock(path: &Path) -> io::Result<FeatureLock> {
+    let Some(value) = read_config_from_path(path, false).await? else {
+        return Ok(FeatureLock::default());
+    };
+    FeatureLock::from_toml(value).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid {}: {err}", path.display()),
+        )
     })
 }
 
@@ -259,6 +259,7 @@ pub async fn load_config_layers_state(
     let LoadedConfigLayers {
         managed_config,
         managed_config_from_mdm,
+        feature_lock,
     } = loaded_config_layers;
     if let Some(config) = managed_config {
         let managed_parent = config.file.as_path().parent().ok_or_else(|| {
@@ -284,11 +285,12 @@ pub async fn load_config_layers_state(
         ));
     }
 
-    ConfigLayerStack::new(
+    Ok(ConfigLayerStack::new(
         layers,
         config_requirements_toml.clone().try_into()?,
         config_requirements_toml.into_toml(),
-    )
+    )?
+    .with_feature_lock(feature_lock))
 }
 
 /// Attempts to load a config.toml file from `config_toml`.
@@ -482,6 +484,7 @@ async fn load_requirements_from_legacy_scheme(
     let LoadedConfigLayers {
         managed_config,
         managed_config_from_mdm,
+        ..
     } = loaded_config_layers;
 
     for (source, config) in managed_config_from_mdm
@@ -575,6 +578,16 @@ impl ProjectTrustDecision {
     fn is_trusted(&self) -> bool {
         matches!(self.trust_level, Some(TrustLevel::Trusted))
     }
+
+    /// Whether the user explicitly withheld trust, as opposed to not having
+    /// decided yet. Such projects contribute neither config nor rules, even
+    /// when they have no `config.toml`.
+    fn is_restricted(&self) -> bool {
+        matches!(
+            self.trust_level,
+            Some(TrustLevel::ReadOnly | TrustLevel::Untrusted)
+        )
+    }
 }
 
 impl ProjectTrustContext {
@@ -624,6 +637,9 @@ impl ProjectTrustContext {
             Some(TrustLevel::Untrusted) => Some(format!(
                 "{trust_key} is marked as untrusted in {user_config_file}. To load config.toml, mark it trusted."
             )),
+            Some(TrustLevel::ReadOnly) => Some(format!(
+                "{trust_key} is trusted read-only in {user_config_file}. To load config.toml and rules, mark it trusted."
+            )),
             _ => Some(format!(
                 "To load config.toml, add {trust_key} as a trusted project in {user_config_file}."
             )),
@@ -642,7 +658,10 @@ fn project_layer_entry(
         dot_codex_folder: dot_codex_folder.clone(),
     };
 
-    if config_toml_exists && let Some(reason) = trust_context.disabled_reason_for_dir(layer_dir) {
+    let restricted = trust_context.decision_for_dir(layer_dir).is_restricted();
+    if (config_toml_exists || restricted)
+        && let Some(reason) = trust_context.disabled_reason_for_dir(layer_dir)
+    {
         ConfigLayerEntry::new_disabled(source, config, reason)
     } else {
         ConfigLayerEntry::new(source, config)
@@ -1,5 +1,7 @@
+use crate::config::CONFIG_TOML_FILE;
 use crate::config_loader::ConfigRequirements;
 use crate::config_loader::ConfigRequirementsToml;
+use crate::features::FeatureLock;
 
 use super::fingerprint::record_origins;
 use super::fingerprint::version_for_toml;
@@ -113,6 +115,10 @@ pub struct ConfigLayerStack {
     /// sources. This preserves the original allow-lists so they can be
     /// surfaced via APIs.
     requirements_toml: ConfigRequirementsToml,
+
+    /// Feature stages and values pinned by an administrator's
+    /// `features.lock`, applied after every layer.
+    feature_lock: FeatureLock,
 }
 
 impl ConfigLayerStack {
@@ -127,9 +133,19 @@ impl ConfigLayerStack {
             user_layer_index,
             requirements,
             requirements_toml,
+            feature_lock: FeatureLock::default(),
         })
     }
 
+    pub fn with_feature_lock(mut self, feature_lock: FeatureLock) -> Self {
+        self.feature_lock = feature_lock;
+        self
+    }
+
+    pub fn feature_lock(&self) -> &FeatureLock {
+        &self.feature_lock
+    }
+
     /// Returns the user config layer, if any.
     pub fn get_user_layer(&self) -> Option<&ConfigLayerEntry> {
         self.user_layer_index
@@ -165,6 +181,7 @@ impl ConfigLayerStack {
                     user_layer_index: self.user_layer_index,
                     requirements: self.requirements.clone(),
                     requirements_toml: self.requirements_toml.clone(),
+                    feature_lock: self.feature_lock.clone(),
                 }
             }
             None => {
@@ -186,6 +203,7 @@ impl ConfigLayerStack {
                     user_layer_index: Some(user_layer_index),
                     requirements: self.requirements.clone(),
                     requirements_toml: self.requirements_toml.clone(),
+                    feature_lock: self.feature_lock.clone(),
                 }
             }
         }
@@ -210,6 +228,36 @@ impl ConfigLayerStack {
         origins
     }
 
+    /// Returns the enabled layer that supplies the effective value at `path`
+    /// (table keys, outermost first): the highest-precedence layer setting it.
+    pub fn origin_of(&self, path: &[&str]) -> Option<&ConfigLayerEntry> {
+        self.layers
+            .iter()
+            .rev()
+            .filter(|layer| !layer.is_disabled())
+            .find(|layer| {
+                path.iter()
+                    .try_fold(&layer.config, |value, key| value.get(key))
+                    .is_some()
+            })
+    }
+
+    /// Project `.codex/config.toml` files that were found but not applied
+    /// because the project has not been trusted yet.
+    pub fn untrusted_project_config_files(&self) -> Vec<AbsolutePathBuf> {
+        self.layers
+            .iter()
+            .filter(|layer| layer.is_disabled())
+            .filter_map(|layer| match &layer.name {
+                ConfigLayerSource::Project { dot_codex_folder } => {
+                    dot_codex_folder.join(CONFIG_TOML_FILE).ok()
+                }
+                _ => None,
+            })
+            .filter(|path| path.as_path().is_file())
+            .collect()
+    }
+
     /// Returns the highest-precedence to lowest-precedence layers, so
     /// `ConfigLayerSource::SessionFlags` would be first, if present.
     pub fn layers_high_to_low(&self) -> Vec<&ConfigLayerEntry> {
@@ -47,6 +47,8 @@ async fn make_config_for_test(
                 project_path.to_string_lossy().to_string(),
                 ProjectConfig {
                     trust_level: Some(trust_level),
+                    persona: None,
+                    profile: None,
                 },
             )])),
             project_root_markers,
@@ -871,6 +873,44 @@ async fn project_layer_is_added_when_dot_codex_exists_without_config_toml() -> s
     Ok(())
 }
 
+#[tokio::test]
+async fn read_only_project_layer_is_disabled_without_config_toml() -> std::io::Result<()> {
+    let tmp = tempdir()?;
+    let project_root = tmp.path().join("project");
+    tokio::fs::create_dir_all(project_root.join(".codex").join("rules")).await?;
+    tokio::fs::write(project_root.join(".git"), "gitdir: here").await?;
+
+    let codex_home = tmp.path().join("home");
+    tokio::fs::create_dir_all(&codex_home).await?;
+    make_config_for_test(&codex_home, &project_root, TrustLevel::ReadOnly, None).await?;
+    let cwd = AbsolutePathBuf::from_absolute_path(&project_root)?;
+    let layers = load_config_layers_state(
+        &codex_home,
+        Some(cwd),
+        &[] as &[(String, TomlValue)],
+        LoaderOverrides::default(),
+        CloudRequirementsLoader::default(),
+    )
+    .await?;
+
+    let project_layers: Vec<_> = layers
+        .get_layers(
+            super::ConfigLayerStackOrdering::HighestPrecedenceFirst,
+            true,
+        )
+        .into_iter()
+        .filter(|layer| matches!(layer.name, super::ConfigLayerSource::Project { .. }))
+        .collect();
+    assert_eq!(project_layers.len(), 1);
+    assert!(
+        project_layers[0].disabled_reason.is_some(),
+        "expected read-only project layer to be disabled so its rules are skipped"
+    );
+    assert_eq!(layers.untrusted_project_config_files(), Vec::new());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn codex_home_is_not_loaded_as_project_layer_from_home_dir() -> std::io::Result<()> {
     let tmp = tempdir()?;
@@ -1066,6 +1106,16 @@ async fn project_layers_disabled_when_untrusted_or_unknown() -> std::io::Result<
         layers_unknown.effective_config().get("foo"),
         Some(&TomlValue::String("user".to_string()))
     );
+    assert_eq!(
+        layers_unknown.untrusted_project_config_files(),
+        vec![AbsolutePathBuf::from_absolute_path(
+            nested.join(".codex").join(CONFIG_TOML_FILE)
+        )?]
+    );
+    assert!(matches!(
+        layers_unknown.origin_of(&["foo"]).map(|layer| &layer.name),
+        Some(super::ConfigLayerSource::User { .. })
+    ));
 
     Ok(())
 }
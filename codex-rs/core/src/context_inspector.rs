@@ -0,0 +1,234 @@
+//! Per-component token estimates for `/context`, and the filtering that
+//! applies the user's exclusions to the next model request.
+//!
+//! Estimates use the same bytes-per-token heuristic as auto-compaction, so
+//! they track relative weight well but will not match the server's count.
+
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::ContextBreakdownEvent;
+use codex_protocol::protocol::ContextComponent;
+use codex_protocol::protocol::ContextComponentKind;
+
+use crate::client_common::tools::ToolSpec;
+use crate::context_manager::estimate_response_item_model_visible_bytes;
+use crate::instructions::SkillInstructions;
+use crate::instructions::UserInstructions;
+use crate::session_prefix::ENVIRONMENT_CONTEXT_OPEN_TAG;
+use crate::truncate::approx_token_count;
+use crate::truncate::approx_tokens_from_byte_count_i64;
+
+/// Order in which components are reported, matching their position in a request.
+const COMPONENT_ORDER: [ContextComponentKind; 7] = [
+    ContextComponentKind::SystemPrompt,
+    ContextComponentKind::ToolSchemas,
+    ContextComponentKind::DeveloperInstructions,
+    ContextComponentKind::ProjectDocs,
+    ContextComponentKind::EnvironmentContext,
+    ContextComponentKind::Skills,
+    ContextComponentKind::History,
+];
+
+/// Classifies a history item by the part of the request it belongs to.
+pub(crate) fn classify_item(item: &ResponseItem) -> ContextComponentKind {
+    let ResponseItem::Message { role, content, .. } = item else {
+        return ContextComponentKind::History;
+    };
+    match role.as_str() {
+        "developer" => ContextComponentKind::DeveloperInstructions,
+        "user" if UserInstructions::is_user_instructions(content) => {
+            ContextComponentKind::ProjectDocs
+        }
+        "user" if SkillInstructions::is_skill_instructions(content) => ContextComponentKind::Skills,
+        "user" if is_environment_context(content) => ContextComponentKind::EnvironmentContext,
+        _ => ContextComponentKind::History,
+    }
+}
+
+fn is_environment_context(content: &[ContentItem]) -> bool {
+    matches!(
+        content,
+        [ContentItem::InputText { text }]
+            if text
+                .trim_start()
+                .to_ascii_lowercase()
+                .starts_with(ENVIRONMENT_CONTEXT_OPEN_TAG)
+    )
+}
+
+/// Estimates each component of a request built from `base_instructions`,
+/// `history`, and `tools`. Excluded components are still measured but left
+/// out of the total.
+pub(crate) fn breakdown(
+    base_instructions: &str,
+    history: &[ResponseItem],
+    tools: &[ToolSpec],
+    excluded: &[ContextComponentKind],
+    model_context_window: Option<i64>,
+) -> ContextBreakdownEvent {
+    let mut history_bytes = [0i64; COMPONENT_ORDER.len()];
+    for item in history {
+        let kind = classify_item(item);
+        if let Some(slot) = COMPONENT_ORDER
+            .iter()
+            .position(|candidate| *candidate == kind)
+        {
+            history_bytes[slot] = history_bytes[slot]
+                .saturating_add(estimate_response_item_model_visible_bytes(item));
+        }
+    }
+    let tool_bytes = tools
+        .iter()
+        .map(|tool| {
+            serde_json::to_string(tool)
+                .map(|json| i64::try_from(json.len()).unwrap_or(i64::MAX))
+                .unwrap_or_default()
+        })
+        .fold(0i64, i64::saturating_add);
+
+    let components: Vec<ContextComponent> = COMPONENT_ORDER
+        .iter()
+        .zip(history_bytes)
+        .map(|(kind, bytes)| {
+            let tokens = match kind {
+                ContextComponentKind::SystemPrompt => {
+                    i64::try_from(approx_token_count(base_instructions)).unwrap_or(i64::MAX)
+                }
+                ContextComponentKind::ToolSchemas => approx_tokens_from_byte_count_i64(tool_bytes),
+                _ => approx_tokens_from_byte_count_i64(bytes),
+            };
+            ContextComponent {
+                kind: *kind,
+                tokens,
+                droppable: kind.is_droppable(),
+                excluded: excluded.contains(kind),
+            }
+        })
+        .collect();
+    let total_tokens = components
+        .iter()
+        .filter(|component| !component.excluded)
+        .map(|component| component.tokens)
+        .fold(0i64, i64::saturating_add);
+
+    ContextBreakdownEvent {
+        components,
+        total_tokens,
+        model_context_window,
+    }
+}
+
+/// Drops excluded components from `history`. Items at or after `turn_start`
+/// belong to the turn being sent and are never dropped as
+/// [`ContextComponentKind::History`].
+pub(crate) fn drop_excluded_items(
+    history: Vec<ResponseItem>,
+    turn_start: usize,
+    excluded: &[ContextComponentKind],
+) -> Vec<ResponseItem> {
+    history
+        .into_iter()
+        .enumerate()
+        .filter(|(index, item)| match classify_item(item) {
+            ContextComponentKind::History => {
+                *index >= turn_start || !excluded.contains(&ContextComponentKind::History)
+            }
+            kind => !excluded.contains(&kind),
+        })
+        .map(|(_, item)| item)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn message(role: &str, text: &str) -> ResponseItem {
+        ResponseItem::Message {
+            id: None,
+            role: role.to_string(),
+            content: vec![ContentItem::InputText {
+                text: text.to_string(),
+            }],
+            end_turn: None,
+            phase: None,
+        }
+    }
+
+    fn sample_history() -> Vec<ResponseItem> {
+        vec![
+            message(
+                "developer",
+                "<permissions instructions>sandboxed</permissions instructions>",
+            ),
+            message(
+                "user",
+                "# AGENTS.md instructions for /repo\n\n<INSTRUCTIONS>\nuse tabs\n</INSTRUCTIONS>",
+            ),
+            message(
+                "user",
+                "<environment_context>\n  <cwd>/repo</cwd>\n</environment_context>",
+            ),
+            message("user", "first question"),
+            message("assistant", "first answer"),
+            message("user", "second question"),
+        ]
+    }
+
+    #[test]
+    fn classifies_session_prefix_items() {
+        let kinds: Vec<ContextComponentKind> = sample_history().iter().map(classify_item).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ContextComponentKind::DeveloperInstructions,
+                ContextComponentKind::ProjectDocs,
+                ContextComponentKind::EnvironmentContext,
+                ContextComponentKind::History,
+                ContextComponentKind::History,
+                ContextComponentKind::History,
+            ]
+        );
+    }
+
+    #[test]
+    fn excluded_components_leave_the_total() {
+        let history = sample_history();
+        let full = breakdown("base instructions", &history, &[], &[], Some(1_000));
+        let without_docs = breakdown(
+            "base instructions",
+            &history,
+            &[],
+            &[ContextComponentKind::ProjectDocs],
+            Some(1_000),
+        );
+
+        let docs = without_docs
+            .components
+            .iter()
+            .find(|component| component.kind == ContextComponentKind::ProjectDocs)
+            .expect("project docs component");
+        assert!(docs.excluded);
+        assert!(docs.tokens > 0);
+        assert_eq!(without_docs.total_tokens, full.total_tokens - docs.tokens);
+    }
+
+    #[test]
+    fn dropping_history_keeps_the_current_turn() {
+        let history = sample_history();
+        let kept = drop_excluded_items(
+            history.clone(),
+            5,
+            &[
+                ContextComponentKind::History,
+                ContextComponentKind::EnvironmentContext,
+            ],
+        );
+
+        assert_eq!(
+            kept,
+            vec![history[0].clone(), history[1].clone(), history[5].clone()]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! Audit trail for sessions running with approvals and sandboxing disabled.
+//!
+//! When a turn runs with `danger-full-access` and `approval_policy = never`
+//! (what `--dangerously-bypass-approvals-and-sandbox` selects), every command
+//! that finishes executing is appended to `<log_dir>/danger_audit.jsonl` as
+//! one JSON object per line:
+//!
+//! ````text
+//! {"ts":"2026-01-01T00:00:00Z","thread_id":"<uuid>","turn_id":"…","cwd":"/repo","command":["bash","-lc","ls"],"exit_code":0,"duration_ms":12,"timed_out":false}
+//! ````
+//!
+//! The file is kept separate from the rollout so it survives session
+//! deletion and can be handed to whoever reviews what ran unattended.
+
+use std::io::Result;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::ExecCommandEndEvent;
+use codex_protocol::protocol::SandboxPolicy;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+
+use crate::config::Config;
+
+/// Filename of the audit log inside the configured log directory.
+const AUDIT_FILENAME: &str = "danger_audit.jsonl";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DangerAuditRecord {
+    pub ts: String,
+    pub thread_id: String,
+    pub turn_id: String,
+    pub cwd: PathBuf,
+    pub command: Vec<String>,
+    pub exit_code: i32,
+    pub duration_ms: u64,
+    pub timed_out: bool,
+}
+
+impl DangerAuditRecord {
+    fn from_end_event(thread_id: ThreadId, ev: &ExecCommandEndEvent) -> Self {
+        Self {
+            ts: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            thread_id: thread_id.to_string(),
+            turn_id: ev.turn_id.clone(),
+            cwd: ev.cwd.clone(),
+            command: ev.command.clone(),
+            exit_code: ev.exit_code,
+            duration_ms: u64::try_from(ev.duration.as_millis()).unwrap_or(u64::MAX),
+            timed_out: ev.timed_out,
+        }
+    }
+}
+
+/// Whether the given policies amount to running without any guard rails.
+pub fn is_danger_mode(approval_policy: AskForApproval, sandbox_policy: &SandboxPolicy) -> bool {
+    matches!(sandbox_policy, SandboxPolicy::DangerFullAccess)
+        && approval_policy == AskForApproval::Never
+}
+
+/// Location of the audit log for this configuration.
+pub fn audit_log_path(config: &Config) -> PathBuf {
+    audit_log_path_in(&config.log_dir)
+}
+
+fn audit_log_path_in(log_dir: &Path) -> PathBuf {
+    log_dir.join(AUDIT_FILENAME)
+}
+
+/// Append one record for a finished command. Failures are logged and
+/// swallowed: auditing must never abort the turn that produced the event.
+pub(crate) async fn record_command(log_dir: &Path, thread_id: ThreadId, ev: &ExecCommandEndEvent) {
+    let record = DangerAuditRecord::from_end_event(thread_id, ev);
+    if let Err(err) = append_record(log_dir, &record).await {
+        tracing::warn!("failed to write danger-mode audit record: {err}");
+    }
+}
+
+async fn append_record(log_dir: &Path, record: &DangerAuditRecord) -> Result<()> {
+    tokio::fs::create_dir_all(log_dir).await?;
+    let mut line = serde_json::to_string(record)?;
+    line.push('\n');
+
+    let mut options = tokio::fs::OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(audit_log_path_in(log_dir)).await?;
+    // A single write keeps concurrent sessions from interleaving lines.
+    file.write_all(line.as_bytes()).await?;
+    file.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::ExecCommandSource;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn end_event(command: &[&str], exit_code: i32) -> ExecCommandEndEvent {
+        ExecCommandEndEvent {
+            call_id: "call-1".to_string(),
+            process_id: None,
+            turn_id: "turn-1".to_string(),
+            command: command.iter().map(ToString::to_string).collect(),
+            cwd: PathBuf::from("/repo"),
+            parsed_cmd: Vec::new(),
+            source: ExecCommandSource::Agent,
+            interaction_input: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            aggregated_output: String::new(),
+            exit_code,
+            duration: Duration::from_millis(42),
+            formatted_output: String::new(),
+            timed_out: false,
+            provenance: None,
+        }
+    }
+
+    #[test]
+    fn only_full_access_without_approvals_is_danger_mode() {
+        assert!(is_danger_mode(
+            AskForApproval::Never,
+            &SandboxPolicy::DangerFullAccess
+        ));
+        assert!(!is_danger_mode(
+            AskForApproval::OnRequest,
+            &SandboxPolicy::DangerFullAccess
+        ));
+        assert!(!is_danger_mode(
+            AskForApproval::Never,
+            &SandboxPolicy::new_read_only_policy()
+        ));
+    }
+
+    #[tokio::test]
+    async fn appends_one_line_per_command() {
+        let dir = TempDir::new().expect("tempdir");
+        let log_dir = dir.path().join("log");
+        let thread_id = ThreadId::new();
+
+        record_command(&log_dir, thread_id, &end_event(&["ls"], 0)).await;
+        record_command(&log_dir, thread_id, &end_event(&["rm", "-rf", "build"], 1)).await;
+
+        let contents = std::fs::read_to_string(audit_log_path_in(&log_dir)).expect("read log");
+        let records: Vec<DangerAuditRecord> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("valid json"))
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].command, vec!["rm", "-rf", "build"]);
+        assert_eq!(records[1].exit_code, 1);
+        assert_eq!(records[1].duration_ms, 42);
+        assert_eq!(records[0].thread_id, thread_id.to_string());
+    }
+}
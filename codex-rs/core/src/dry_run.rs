@@ -0,0 +1,177 @@
+//! Dry-run turns (`/dry-run`): the model's tool calls are shown to the user
+//! as a plan, and only the calls they approve run. The rest are answered with
+//! a "declined" output so the model can carry on without them.
+
+use std::path::PathBuf;
+
+use codex_apply_patch::Hunk;
+use codex_apply_patch::parse_patch;
+use codex_protocol::models::FunctionCallOutputBody;
+use codex_protocol::models::FunctionCallOutputPayload;
+use codex_protocol::models::ResponseInputItem;
+use codex_protocol::protocol::PlannedToolCall;
+use serde_json::Value;
+
+use crate::parse_command::shlex_join;
+use crate::tools::context::ToolPayload;
+use crate::tools::router::ToolCall;
+
+/// Tools that only talk to the user; they run even during a dry run.
+const UNGATED_TOOLS: [&str; 2] = ["update_plan", "request_user_input"];
+
+const DECLINED_MESSAGE: &str = "The user reviewed this call in a dry run and chose not to run it.";
+
+/// Whether a dry run holds this call back for the user's decision.
+pub(crate) fn is_gated(call: &ToolCall) -> bool {
+    !UNGATED_TOOLS.contains(&call.tool_name.as_str())
+}
+
+pub(crate) fn planned_call(call: &ToolCall) -> PlannedToolCall {
+    let (summary, files) = match &call.payload {
+        ToolPayload::LocalShell { params } => (shlex_join(&params.command), Vec::new()),
+        ToolPayload::Custom { input } if call.tool_name == "apply_patch" => describe_patch(input),
+        ToolPayload::Custom { input } => (input.clone(), Vec::new()),
+        ToolPayload::Function { arguments } => describe_arguments(arguments),
+        ToolPayload::Mcp { raw_arguments, .. } => (raw_arguments.clone(), Vec::new()),
+    };
+    PlannedToolCall {
+        call_id: call.call_id.clone(),
+        tool_name: call.tool_name.clone(),
+        summary,
+        files,
+    }
+}
+
+/// Output recorded for a call the user did not approve.
+pub(crate) fn declined_response(call: &ToolCall) -> ResponseInputItem {
+    match &call.payload {
+        ToolPayload::Custom { .. } => ResponseInputItem::CustomToolCallOutput {
+            call_id: call.call_id.clone(),
+            output: DECLINED_MESSAGE.to_string(),
+        },
+        ToolPayload::Mcp { .. } => ResponseInputItem::McpToolCallOutput {
+            call_id: call.call_id.clone(),
+            result: Err(DECLINED_MESSAGE.to_string()),
+        },
+        _ => ResponseInputItem::FunctionCallOutput {
+            call_id: call.call_id.clone(),
+            output: FunctionCallOutputPayload {
+                body: FunctionCallOutputBody::Text(DECLINED_MESSAGE.to_string()),
+                ..Default::default()
+            },
+        },
+    }
+}
+
+/// Shell tools carry their command as `command` (argv or script) or `cmd`;
+/// the freeform `apply_patch` variant carries the patch as `input`.
+fn describe_arguments(arguments: &str) -> (String, Vec<PathBuf>) {
+    let Ok(Value::Object(args)) = serde_json::from_str::<Value>(arguments) else {
+        return (arguments.to_string(), Vec::new());
+    };
+    match (args.get("command"), args.get("cmd"), args.get("input")) {
+        (Some(Value::Array(argv)), _, _) => {
+            let argv: Vec<String> = argv
+                .iter()
+                .filter_map(|arg| arg.as_str().map(str::to_string))
+                .collect();
+            (shlex_join(&argv), Vec::new())
+        }
+        (Some(Value::String(command)), _, _) | (None, Some(Value::String(command)), _) => {
+            (command.clone(), Vec::new())
+        }
+        (None, None, Some(Value::String(input))) if input.contains("*** Begin Patch") => {
+            describe_patch(input)
+        }
+        _ => (arguments.to_string(), Vec::new()),
+    }
+}
+
+fn describe_patch(patch: &str) -> (String, Vec<PathBuf>) {
+    let Ok(parsed) = parse_patch(patch) else {
+        return (patch.to_string(), Vec::new());
+    };
+    let mut files = Vec::new();
+    let mut changes = Vec::new();
+    for hunk in parsed.hunks {
+        let (verb, path) = match hunk {
+            Hunk::AddFile { path, .. } => ("add", path),
+            Hunk::DeleteFile { path } => ("delete", path),
+            Hunk::UpdateFile {
+                move_path: Some(dest),
+                ..
+            } => ("move to", dest),
+            Hunk::UpdateFile { path, .. } => ("update", path),
+        };
+        changes.push(format!("{verb} {}", path.display()));
+        files.push(path);
+    }
+    (changes.join(", "), files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn call(tool_name: &str, payload: ToolPayload) -> ToolCall {
+        ToolCall {
+            tool_name: tool_name.to_string(),
+            call_id: "call-1".to_string(),
+            payload,
+        }
+    }
+
+    #[test]
+    fn shell_calls_are_summarized_as_command_lines() {
+        let argv = call(
+            "shell",
+            ToolPayload::Function {
+                arguments: r#"{"command":["rm","-rf","build dir"],"workdir":"/repo"}"#.to_string(),
+            },
+        );
+        assert_eq!(planned_call(&argv).summary, "rm -rf 'build dir'");
+
+        let script = call(
+            "exec_command",
+            ToolPayload::Function {
+                arguments: r#"{"cmd":"cargo test -p codex-core"}"#.to_string(),
+            },
+        );
+        assert_eq!(planned_call(&script).summary, "cargo test -p codex-core");
+    }
+
+    #[test]
+    fn patches_list_the_files_they_touch() {
+        let patch = "*** Begin Patch\n*** Add File: notes.md\n+hi\n*** Update File: src/lib.rs\n@@\n-a\n+b\n*** Delete File: old.rs\n*** End Patch";
+        let planned = planned_call(&call(
+            "apply_patch",
+            ToolPayload::Custom {
+                input: patch.to_string(),
+            },
+        ));
+
+        assert_eq!(
+            planned,
+            PlannedToolCall {
+                call_id: "call-1".to_string(),
+                tool_name: "apply_patch".to_string(),
+                summary: "add notes.md, update src/lib.rs, delete old.rs".to_string(),
+                files: vec![
+                    PathBuf::from("notes.md"),
+                    PathBuf::from("src/lib.rs"),
+                    PathBuf::from("old.rs"),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn plan_updates_are_not_gated() {
+        let arguments = ToolPayload::Function {
+            arguments: "{}".to_string(),
+        };
+        assert!(!is_gated(&call("update_plan", arguments.clone())));
+        assert!(is_gated(&call("read_file", arguments)));
+    }
+}
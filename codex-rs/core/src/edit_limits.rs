@@ -0,0 +1,291 @@
+//! Guards against rewriting large or binary files, from `[edit_limits]` in
+//! `config.toml`.
+//!
+//! ```toml
+//! [edit_limits]
+//! max_file_bytes = 262144
+//! binary_files = true
+//! on_exceed = "ask" # default "refuse"
+//! ```
+//!
+//! A change trips the size limit when it would replace most of an existing
+//! file over `max_file_bytes`: adding a file on top of it, deleting it,
+//! overwriting it with the write tool, or a patch whose hunks remove more
+//! than half of its bytes. Small hunks in a large file still go through, which
+//! is what the refusal asks the model to do instead. With `binary_files`, any
+//! change to an existing binary file trips the guard.
+
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_apply_patch::ApplyPatchAction;
+use codex_apply_patch::ApplyPatchFileChange;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::GuardrailBlockedEvent;
+use codex_protocol::protocol::GuardrailReason;
+
+use crate::codex::Session;
+use crate::codex::TurnContext;
+use crate::config::types::EditLimits;
+use crate::function_tool::FunctionCallError;
+
+/// Bytes inspected when deciding whether a file is binary, the same window
+/// git uses.
+const BINARY_SNIFF_BYTES: u64 = 8000;
+
+/// A file a change may not rewrite, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditLimitHit {
+    pub path: PathBuf,
+    pub kind: EditLimitKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditLimitKind {
+    /// The file is over `max_file_bytes` and the change replaces most of it.
+    Large {
+        bytes: u64,
+        limit: u64,
+    },
+    Binary,
+}
+
+impl EditLimitHit {
+    fn describe(&self) -> String {
+        let path = self.path.display();
+        match self.kind {
+            EditLimitKind::Large { bytes, limit } => format!(
+                "{path}: {bytes} bytes, over the {limit}-byte limit for rewriting a whole file"
+            ),
+            EditLimitKind::Binary => format!("{path}: binary file"),
+        }
+    }
+}
+
+/// Every file `action` would rewrite past the limits, in patch order.
+pub(crate) fn check_patch(limits: &EditLimits, action: &ApplyPatchAction) -> Vec<EditLimitHit> {
+    if !limits.is_enabled() {
+        return Vec::new();
+    }
+    let mut hits = Vec::new();
+    for (path, change) in action.changes() {
+        let hit = match change {
+            ApplyPatchFileChange::Add { .. } | ApplyPatchFileChange::Delete { .. } => {
+                check_file(limits, path, |_| true)
+            }
+            ApplyPatchFileChange::Update {
+                unified_diff,
+                move_path,
+                ..
+            } => {
+                let removed = removed_bytes(unified_diff);
+                // A move replaces whatever sits at the destination.
+                check_file(limits, path, |bytes| removed.saturating_mul(2) > bytes).or_else(|| {
+                    move_path
+                        .as_deref()
+                        .and_then(|dest| check_file(limits, dest, |_| true))
+                })
+            }
+        };
+        hits.extend(hit);
+    }
+    hits
+}
+
+/// Whether overwriting `path` with entirely new content trips the limits.
+pub(crate) fn check_overwrite(limits: &EditLimits, path: &Path) -> Option<EditLimitHit> {
+    if !limits.is_enabled() {
+        return None;
+    }
+    check_file(limits, path, |_| true)
+}
+
+/// Refuse a tool call that would overwrite `path` when it trips the limits.
+pub(crate) async fn guard_overwrite(
+    session: &Session,
+    turn: &TurnContext,
+    call_id: &str,
+    path: &Path,
+) -> Result<(), FunctionCallError> {
+    match check_overwrite(&turn.config.edit_limits, path) {
+        Some(hit) => Err(block(session, turn, call_id, vec![hit]).await),
+        None => Ok(()),
+    }
+}
+
+/// Report a refused call to the client and build the error returned to the
+/// model.
+pub(crate) async fn block(
+    session: &Session,
+    turn: &TurnContext,
+    call_id: &str,
+    hits: Vec<EditLimitHit>,
+) -> FunctionCallError {
+    let message = blocked_message(&hits);
+    session
+        .send_event(
+            turn,
+            EventMsg::GuardrailBlocked(GuardrailBlockedEvent {
+                call_id: call_id.to_string(),
+                turn_id: turn.sub_id.clone(),
+                paths: hits.into_iter().map(|hit| hit.path).collect(),
+                patterns: Vec::new(),
+                reason: GuardrailReason::EditLimits,
+            }),
+        )
+        .await;
+    FunctionCallError::RespondToModel(message)
+}
+
+/// Reason shown in the approval prompt when `on_exceed = "ask"`.
+pub(crate) fn approval_reason(hits: &[EditLimitHit]) -> String {
+    format!(
+        "This change rewrites files guarded by edit_limits: {}",
+        hits.iter()
+            .map(EditLimitHit::describe)
+            .collect::<Vec<_>>()
+            .join("; ")
+    )
+}
+
+fn blocked_message(hits: &[EditLimitHit]) -> String {
+    let mut message =
+        "blocked by guardrail: `edit_limits` refused this change. Nothing was written.\n"
+            .to_string();
+    for hit in hits {
+        message.push_str(&format!("- {}\n", hit.describe()));
+    }
+    message.push_str(
+        "Make a targeted edit instead: change only the lines that need to change with small \
+apply_patch hunks, and regenerate generated files such as lockfiles or build output with the \
+tool that produces them. Ask the user if the whole file really has to change.",
+    );
+    message
+}
+
+fn check_file(
+    limits: &EditLimits,
+    path: &Path,
+    rewrites: impl FnOnce(u64) -> bool,
+) -> Option<EditLimitHit> {
+    let metadata = std::fs::metadata(path).ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+    if limits.binary_files && is_binary(path) {
+        return Some(EditLimitHit {
+            path: path.to_path_buf(),
+            kind: EditLimitKind::Binary,
+        });
+    }
+    let limit = limits.max_file_bytes?;
+    let bytes = metadata.len();
+    (bytes > limit && rewrites(bytes)).then(|| EditLimitHit {
+        path: path.to_path_buf(),
+        kind: EditLimitKind::Large { bytes, limit },
+    })
+}
+
+/// A file is binary when its first few kilobytes contain a NUL byte.
+fn is_binary(path: &Path) -> bool {
+    let Ok(file) = std::fs::File::open(path) else {
+        return false;
+    };
+    let mut head = Vec::new();
+    if file
+        .take(BINARY_SNIFF_BYTES)
+        .read_to_end(&mut head)
+        .is_err()
+    {
+        return false;
+    }
+    head.contains(&0)
+}
+
+/// Bytes of the original file a unified diff removes.
+fn removed_bytes(unified_diff: &str) -> u64 {
+    unified_diff
+        .lines()
+        .filter_map(|line| line.strip_prefix('-'))
+        .map(|line| line.len() as u64 + 1)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::EditLimitAction;
+    use codex_apply_patch::MaybeApplyPatchVerified;
+    use codex_apply_patch::maybe_parse_apply_patch_verified;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    fn limits() -> EditLimits {
+        EditLimits {
+            max_file_bytes: Some(100),
+            binary_files: true,
+            on_exceed: EditLimitAction::Refuse,
+        }
+    }
+
+    fn parse(patch: &str, cwd: &Path) -> ApplyPatchAction {
+        let command = vec!["apply_patch".to_string(), patch.to_string()];
+        match maybe_parse_apply_patch_verified(&command, cwd) {
+            MaybeApplyPatchVerified::Body(action) => action,
+            other => panic!("expected a patch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn large_files_take_targeted_hunks_but_not_rewrites() {
+        let dir = tempdir().expect("tempdir");
+        let lock = (0..40).map(|i| format!("line {i}\n")).collect::<String>();
+        std::fs::write(dir.path().join("Cargo.lock"), &lock).expect("write");
+        std::fs::write(dir.path().join("small.txt"), "a\nb\n").expect("write");
+        let bytes = lock.len() as u64;
+
+        let targeted = parse(
+            "*** Begin Patch\n*** Update File: Cargo.lock\n@@\n-line 3\n+line three\n*** End Patch",
+            dir.path(),
+        );
+        assert_eq!(check_patch(&limits(), &targeted), Vec::new());
+
+        let rewrite = parse(
+            "*** Begin Patch\n*** Delete File: Cargo.lock\n*** Update File: small.txt\n@@\n-a\n-b\n+c\n*** End Patch",
+            dir.path(),
+        );
+        assert_eq!(
+            check_patch(&limits(), &rewrite),
+            vec![EditLimitHit {
+                path: dir.path().join("Cargo.lock"),
+                kind: EditLimitKind::Large { bytes, limit: 100 },
+            }]
+        );
+        assert_eq!(check_patch(&EditLimits::default(), &rewrite), Vec::new());
+    }
+
+    #[test]
+    fn binary_files_are_guarded_only_when_enabled() {
+        let dir = tempdir().expect("tempdir");
+        let logo = dir.path().join("logo.png");
+        std::fs::write(&logo, b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR").expect("write");
+
+        assert_eq!(
+            check_overwrite(&limits(), &logo),
+            Some(EditLimitHit {
+                path: logo.clone(),
+                kind: EditLimitKind::Binary,
+            })
+        );
+        let size_only = EditLimits {
+            binary_files: false,
+            ..limits()
+        };
+        assert_eq!(check_overwrite(&size_only, &logo), None);
+        assert_eq!(
+            check_overwrite(&limits(), &dir.path().join("missing.bin")),
+            None
+        );
+    }
+}
@@ -0,0 +1,378 @@
+//! Stable entry point for embedding the agent loop in other Rust applications.
+//!
+//! [`Agent`] owns authentication and thread management; each [`AgentThread`]
+//! is one conversation. Submit input, then read [`Event`]s until the turn
+//! completes, answering any [`ApprovalRequest`] along the way:
+//!
+//! ```no_run
+//! # async fn demo() -> anyhow::Result<()> {
+//! use codex_core::Agent;
+//! use codex_core::AgentOptions;
+//! use codex_core::protocol::ReviewDecision;
+//!
+//! let agent = Agent::load(AgentOptions::new()).await?;
+//! let thread = agent.start_thread().await?;
+//! let outcome = thread
+//!     .run_turn("Summarize README.md", |_request| ReviewDecision::Denied)
+//!     .await?;
+//! println!("{outcome:?}");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Threads are recorded like `codex exec` sessions and can be resumed by id.
+//! This module follows semver: items here only change in a breaking way with
+//! a major version bump. It never takes the CLI's `Config`, which changes
+//! with every release; [`AgentOptions`] covers what embedders set instead.
+//! The protocol types it hands out (`Event`, `Op`, `UserInput`) are the same
+//! ones the CLI uses and evolve additively.
+//!
+//! Sandboxed commands on Linux re-execute the host binary as
+//! `codex-linux-sandbox`; embedders that want sandboxing should dispatch
+//! through `codex_arg0::arg0_dispatch_or_else` in `main`, like the CLI does.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use codex_protocol::ThreadId;
+use codex_protocol::config_types::SandboxMode;
+use codex_protocol::protocol::ApplyPatchApprovalRequestEvent;
+use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::Event;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::ExecApprovalRequestEvent;
+use codex_protocol::protocol::Op;
+use codex_protocol::protocol::ReviewDecision;
+use codex_protocol::protocol::SessionConfiguredEvent;
+use codex_protocol::protocol::SessionSource;
+use codex_protocol::protocol::TurnAbortReason;
+use codex_protocol::user_input::UserInput;
+
+use crate::AuthManager;
+use crate::CodexThread;
+use crate::NewThread;
+use crate::ThreadManager;
+use crate::config::Config;
+use crate::config::ConfigOverrides;
+use crate::error::Result as CodexResult;
+
+/// Embeddable Codex agent: creates and resumes threads.
+pub struct Agent {
+    config: Config,
+    auth_manager: Arc<AuthManager>,
+    threads: ThreadManager,
+}
+
+/// Settings for an [`Agent`], applied on top of `config.toml` the way the
+/// CLI's flags are. Unset options keep the configured values.
+#[derive(Debug, Clone, Default)]
+pub struct AgentOptions {
+    overrides: ConfigOverrides,
+    config_overrides: Vec<(String, toml::Value)>,
+}
+
+impl AgentOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.overrides.model = Some(model.into());
+        self
+    }
+
+    /// Directory the agent works in; defaults to the process's.
+    pub fn cwd(mut self, cwd: impl Into<PathBuf>) -> Self {
+        self.overrides.cwd = Some(cwd.into());
+        self
+    }
+
+    pub fn approval_policy(mut self, policy: AskForApproval) -> Self {
+        self.overrides.approval_policy = Some(policy);
+        self
+    }
+
+    pub fn sandbox_mode(mut self, mode: SandboxMode) -> Self {
+        self.overrides.sandbox_mode = Some(mode);
+        self
+    }
+
+    /// Replaces the built-in system instructions.
+    pub fn base_instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.overrides.base_instructions = Some(instructions.into());
+        self
+    }
+
+    /// Skips recording threads, so they cannot be resumed later.
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.overrides.ephemeral = Some(ephemeral);
+        self
+    }
+
+    /// Sets any `config.toml` key by its dotted path, like `-c key=value`.
+    pub fn config_override(mut self, key: impl Into<String>, value: toml::Value) -> Self {
+        self.config_overrides.push((key.into(), value));
+        self
+    }
+}
+
+impl Agent {
+    /// Loads `config.toml` from `CODEX_HOME` the way the CLI does, with
+    /// `options` applied on top.
+    pub async fn load(options: AgentOptions) -> std::io::Result<Self> {
+        let config = Config::load_with_cli_overrides_and_harness_overrides(
+            options.config_overrides,
+            options.overrides,
+        )
+        .await?;
+        Ok(Self::from_config(config))
+    }
+
+    /// Uses an already loaded `config` for every thread the agent starts.
+    /// For callers inside this workspace; `Config` is not covered by the
+    /// semver promise.
+    #[doc(hidden)]
+    pub fn from_config(config: Config) -> Self {
+        let auth_manager = AuthManager::shared(
+            config.codex_home.clone(),
+            true,
+            config.cli_auth_credentials_store_mode,
+        );
+        let threads = ThreadManager::new(
+            config.codex_home.clone(),
+            auth_manager.clone(),
+            SessionSource::Exec,
+        );
+        Self {
+            config,
+            auth_manager,
+            threads,
+        }
+    }
+
+    /// Starts a new, empty thread.
+    pub async fn start_thread(&self) -> CodexResult<AgentThread> {
+        Ok(self.threads.start_thread(self.config.clone()).await?.into())
+    }
+
+    /// Continues a recorded thread from its rollout file.
+    pub async fn resume_thread(&self, rollout_path: PathBuf) -> CodexResult<AgentThread> {
+        Ok(self
+            .threads
+            .resume_thread_from_rollout(
+                self.config.clone(),
+                rollout_path,
+                self.auth_manager.clone(),
+            )
+            .await?
+            .into())
+    }
+}
+
+/// One conversation with the agent.
+pub struct AgentThread {
+    id: ThreadId,
+    thread: Arc<CodexThread>,
+    session: SessionConfiguredEvent,
+}
+
+impl From<NewThread> for AgentThread {
+    fn from(new_thread: NewThread) -> Self {
+        Self {
+            id: new_thread.thread_id,
+            thread: new_thread.thread,
+            session: new_thread.session_configured,
+        }
+    }
+}
+
+impl AgentThread {
+    pub fn id(&self) -> ThreadId {
+        self.id
+    }
+
+    /// Model, cwd, and policies the thread was configured with.
+    pub fn session(&self) -> &SessionConfiguredEvent {
+        &self.session
+    }
+
+    /// Where the thread is recorded, if persistence is enabled.
+    pub fn rollout_path(&self) -> Option<PathBuf> {
+        self.thread.rollout_path()
+    }
+
+    /// Starts a turn with a text message. Returns the submission id.
+    pub async fn send_message(&self, text: impl Into<String>) -> CodexResult<String> {
+        self.submit(vec![UserInput::Text {
+            text: text.into(),
+            text_elements: Vec::new(),
+        }])
+        .await
+    }
+
+    /// Starts a turn with arbitrary input items. Returns the submission id.
+    pub async fn submit(&self, items: Vec<UserInput>) -> CodexResult<String> {
+        self.thread
+            .submit(Op::UserInput {
+                items,
+                final_output_json_schema: None,
+            })
+            .await
+    }
+
+    /// Sends any protocol operation, for needs the helpers do not cover.
+    pub async fn submit_op(&self, op: Op) -> CodexResult<String> {
+        self.thread.submit(op).await
+    }
+
+    /// Waits for the next event. Every event is delivered to exactly one
+    /// caller, so read events from a single task.
+    pub async fn next_event(&self) -> CodexResult<Event> {
+        self.thread.next_event().await
+    }
+
+    /// Answers an approval request raised by [`ApprovalRequest::from_event`].
+    pub async fn approve(
+        &self,
+        request: &ApprovalRequest,
+        decision: ReviewDecision,
+    ) -> CodexResult<()> {
+        let op = match request {
+            ApprovalRequest::Exec(event) => Op::ExecApproval {
+                id: event.call_id.clone(),
+                turn_id: Some(event.turn_id.clone()),
+                decision,
+            },
+            ApprovalRequest::Patch(event) => Op::PatchApproval {
+                id: event.call_id.clone(),
+                decision,
+            },
+        };
+        self.thread.submit(op).await.map(drop)
+    }
+
+    /// Aborts the running turn, if any.
+    pub async fn interrupt(&self) -> CodexResult<()> {
+        self.thread.submit(Op::Interrupt).await.map(drop)
+    }
+
+    /// Ends the thread; no further input is accepted.
+    pub async fn shutdown(&self) -> CodexResult<()> {
+        self.thread.submit(Op::Shutdown).await.map(drop)
+    }
+
+    /// Sends `text` and drives the turn to its end, asking `approver` about
+    /// each approval request. Other events are discarded; use
+    /// [`next_event`](Self::next_event) to observe them.
+    pub async fn run_turn<F>(
+        &self,
+        text: impl Into<String>,
+        mut approver: F,
+    ) -> CodexResult<TurnOutcome>
+    where
+        F: FnMut(&ApprovalRequest) -> ReviewDecision,
+    {
+        self.send_message(text).await?;
+        loop {
+            let event = self.next_event().await?;
+            if let Some(request) = ApprovalRequest::from_event(&event.msg) {
+                let decision = approver(&request);
+                self.approve(&request, decision).await?;
+                continue;
+            }
+            match event.msg {
+                EventMsg::TurnComplete(event) => {
+                    return Ok(TurnOutcome::Completed {
+                        last_agent_message: event.last_agent_message,
+                    });
+                }
+                EventMsg::TurnAborted(event) => {
+                    return Ok(TurnOutcome::Aborted {
+                        reason: event.reason,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Something the agent needs permission for before it continues.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ApprovalRequest {
+    /// Run a command outside the sandbox or policy.
+    Exec(ExecApprovalRequestEvent),
+    /// Apply file changes.
+    Patch(ApplyPatchApprovalRequestEvent),
+}
+
+impl ApprovalRequest {
+    pub fn from_event(msg: &EventMsg) -> Option<Self> {
+        match msg {
+            EventMsg::ExecApprovalRequest(event) => Some(Self::Exec(event.clone())),
+            EventMsg::ApplyPatchApprovalRequest(event) => Some(Self::Patch(event.clone())),
+            _ => None,
+        }
+    }
+}
+
+/// How a turn driven by [`AgentThread::run_turn`] ended.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TurnOutcome {
+    Completed { last_agent_message: Option<String> },
+    Aborted { reason: TurnAbortReason },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::AgentMessageEvent;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn approval_requests_are_recognized() {
+        let exec = EventMsg::ExecApprovalRequest(ExecApprovalRequestEvent {
+            call_id: "call-1".to_string(),
+            turn_id: "turn-1".to_string(),
+            command: vec!["rm".to_string(), "-rf".to_string(), "build".to_string()],
+            cwd: PathBuf::from("/repo"),
+            reason: None,
+            proposed_execpolicy_amendment: None,
+            parsed_cmd: Vec::new(),
+        });
+        let message = EventMsg::AgentMessage(AgentMessageEvent {
+            message: "done".to_string(),
+        });
+
+        let Some(ApprovalRequest::Exec(event)) = ApprovalRequest::from_event(&exec) else {
+            panic!("expected an exec approval request");
+        };
+        assert_eq!(event.call_id, "call-1");
+        assert!(ApprovalRequest::from_event(&message).is_none());
+    }
+
+    #[test]
+    fn options_map_onto_cli_overrides() {
+        let options = AgentOptions::new()
+            .model("gpt-5")
+            .cwd("/repo")
+            .sandbox_mode(SandboxMode::ReadOnly)
+            .ephemeral(true)
+            .config_override("model_reasoning_effort", toml::Value::from("high"));
+
+        assert_eq!(options.overrides.model.as_deref(), Some("gpt-5"));
+        assert_eq!(options.overrides.cwd, Some(PathBuf::from("/repo")));
+        assert_eq!(options.overrides.sandbox_mode, Some(SandboxMode::ReadOnly));
+        assert_eq!(options.overrides.ephemeral, Some(true));
+        assert_eq!(options.overrides.approval_policy, None);
+        assert_eq!(
+            options.config_overrides,
+            vec![(
+                "model_reasoning_effort".to_string(),
+                toml::Value::from("high")
+            )]
+        );
+    }
+}
@@ -20,6 +20,7 @@ use uuid::Uuid;
 
 use crate::instructions::SkillInstructions;
 use crate::instructions::UserInstructions;
+use crate::session_note::is_session_note_text;
 use crate::session_prefix::is_session_prefix;
 use crate::user_shell_command::is_user_shell_command_text;
 use crate::web_search::web_search_action_detail;
@@ -44,7 +45,10 @@ fn parse_user_message(message: &[ContentItem]) -> Option<UserMessageItem> {
                 {
                     continue;
                 }
-                if is_session_prefix(text) || is_user_shell_command_text(text) {
+                if is_session_prefix(text)
+                    || is_user_shell_command_text(text)
+                    || is_session_note_text(text)
+                {
                     return None;
                 }
                 content.push(UserInput::Text {
@@ -147,6 +151,7 @@ pub fn parse_turn_item(item: &ResponseItem) -> Option<TurnItem> {
                 id: id.clone().unwrap_or_default(),
                 query,
                 action,
+                citations: Vec::new(),
             }))
         }
         _ => None,
@@ -461,6 +466,7 @@ mod tests {
                         query: Some("weather".to_string()),
                         queries: None,
                     },
+                    citations: Vec::new(),
                 }
             ),
             other => panic!("expected TurnItem::WebSearch, got {other:?}"),
@@ -488,6 +494,7 @@ mod tests {
                     action: WebSearchAction::OpenPage {
                         url: Some("https://example.com".to_string()),
                     },
+                    citations: Vec::new(),
                 }
             ),
             other => panic!("expected TurnItem::WebSearch, got {other:?}"),
@@ -517,6 +524,7 @@ mod tests {
                         url: Some("https://example.com".to_string()),
                         pattern: Some("needle".to_string()),
                     },
+                    citations: Vec::new(),
                 }
             ),
             other => panic!("expected TurnItem::WebSearch, got {other:?}"),
@@ -539,6 +547,7 @@ mod tests {
                     id: "ws_partial".to_string(),
                     query: String::new(),
                     action: WebSearchAction::Other,
+                    citations: Vec::new(),
                 }
             ),
             other => panic!("expected TurnItem::WebSearch, got {other:?}"),
@@ -20,6 +20,8 @@ use crate::error::CodexErr;
 use crate::error::Result;
 use crate::error::SandboxErr;
 use crate::get_platform_sandbox;
+use crate::protected_paths::ProtectedPaths;
+use crate::protected_paths::blocked_command_message;
 use crate::protocol::Event;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecCommandOutputDeltaEvent;
@@ -153,6 +155,7 @@ pub async fn process_exec_tool_call(
     params: ExecParams,
     sandbox_policy: &SandboxPolicy,
     sandbox_cwd: &Path,
+    protected_paths: &ProtectedPaths,
     codex_linux_sandbox_exe: &Option<PathBuf>,
     use_linux_sandbox_bwrap: bool,
     stdout_stream: Option<StdoutStream>,
@@ -192,6 +195,20 @@ pub async fn process_exec_tool_call(
     if let Some(network) = network.as_ref() {
         network.apply_to_env(&mut env);
     }
+    // Nothing keeps an unsandboxed command away from protected paths, so
+    // refuse one that names them.
+    let sandbox_protected_paths = if sandbox_type == SandboxType::None {
+        let matches = protected_paths.check_command(&command, &cwd, sandbox_cwd);
+        if !matches.is_empty() {
+            return Err(CodexErr::Io(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                blocked_command_message(&matches),
+            )));
+        }
+        Vec::new()
+    } else {
+        protected_paths.sandbox_paths(sandbox_cwd)
+    };
     let (program, args) = command.split_first().ok_or_else(|| {
         CodexErr::Io(io::Error::new(
             io::ErrorKind::InvalidInput,
@@ -218,9 +235,11 @@ pub async fn process_exec_tool_call(
             enforce_managed_network,
             network: network.as_ref(),
             sandbox_policy_cwd: sandbox_cwd,
+            protected_paths: &sandbox_protected_paths,
             codex_linux_sandbox_exe: codex_linux_sandbox_exe.as_ref(),
             use_linux_sandbox_bwrap,
             windows_sandbox_level,
+            windows_conpty: false,
         })
         .map_err(CodexErr::from)?;
 
@@ -241,6 +260,7 @@ pub(crate) async fn execute_exec_env(
         expiration,
         sandbox,
         windows_sandbox_level,
+        windows_conpty,
         sandbox_permissions,
         justification,
         arg0,
@@ -259,7 +279,14 @@ pub(crate) async fn execute_exec_env(
     };
 
     let start = Instant::now();
-    let raw_output_result = exec(params, sandbox, sandbox_policy, stdout_stream).await;
+    let raw_output_result = exec(
+        params,
+        sandbox,
+        sandbox_policy,
+        windows_conpty,
+        stdout_stream,
+    )
+    .await;
     let duration = start.elapsed();
     finalize_exec_result(raw_output_result, sandbox, duration)
 }
@@ -687,6 +714,7 @@ async fn exec(
     params: ExecParams,
     sandbox: SandboxType,
     sandbox_policy: &SandboxPolicy,
+    windows_conpty: bool,
     stdout_stream: Option<StdoutStream>,
 ) -> Result<RawExecToolCallOutput> {
     #[cfg(target_os = "windows")]
@@ -698,6 +726,10 @@ async fn exec(
     {
         return exec_windows_sandbox(params, sandbox_policy).await;
     }
+    #[cfg(target_os = "windows")]
+    if windows_conpty && sandbox == SandboxType::None && codex_utils_pty::conpty_supported() {
+        return exec_conpty(params, stdout_stream).await;
+    }
     let ExecParams {
         command,
         cwd,
@@ -730,6 +762,154 @@ async fn exec(
     consume_truncated_output(child, expiration, stdout_stream).await
 }
 
+/// Runs the command attached to a pseudo console so console programs keep
+/// their colors and write UTF-8 regardless of the active code page. ConPTY
+/// merges stdout and stderr, so everything is reported as stdout.
+#[cfg(target_os = "windows")]
+async fn exec_conpty(
+    params: ExecParams,
+    stdout_stream: Option<StdoutStream>,
+) -> Result<RawExecToolCallOutput> {
+    use tokio::sync::broadcast::error::RecvError;
+
+    // ConPTY keeps the output pipe open until the pseudo console is closed,
+    // so after the child exits we read until the output goes quiet.
+    const CONPTY_DRAIN_IDLE: Duration = Duration::from_millis(100);
+    const CONPTY_DRAIN_MAX: Duration = Duration::from_secs(2);
+
+    let ExecParams {
+        command,
+        cwd,
+        mut env,
+        network,
+        arg0,
+        expiration,
+        ..
+    } = params;
+    let (program, args) = command.split_first().ok_or_else(|| {
+        CodexErr::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "command args are empty",
+        ))
+    })?;
+    if let Some(network) = network.as_ref() {
+        network.apply_to_env(&mut env);
+    }
+    let codex_utils_pty::SpawnedProcess {
+        session,
+        mut output_rx,
+        mut exit_rx,
+    } = codex_utils_pty::spawn_pty_process(program, args, &cwd, &env, &arg0)
+        .await
+        .map_err(|err| CodexErr::Io(io::Error::other(err.to_string())))?;
+
+    // The broadcast channel drops its oldest chunks once a reader falls
+    // behind, and recording a chunk waits on the event stream, so move chunks
+    // into an unbounded channel as soon as they arrive. `Err` carries the
+    // number of chunks lost anyway.
+    let (chunk_tx, mut chunk_rx) = tokio::sync::mpsc::unbounded_channel();
+    let forwarder = tokio::spawn(async move {
+        loop {
+            let forwarded = match output_rx.recv().await {
+                Ok(chunk) => chunk_tx.send(Ok(chunk)),
+                Err(RecvError::Lagged(skipped)) => chunk_tx.send(Err(skipped)),
+                Err(RecvError::Closed) => break,
+            };
+            if forwarded.is_err() {
+                break;
+            }
+        }
+    });
+
+    fn record_lost_chunks(text: &mut Vec<u8>, skipped: u64) {
+        tracing::warn!("conpty output reader fell behind; dropped {skipped} chunks");
+        let marker = format!("\n[... {skipped} output chunks dropped ...]\n");
+        append_capped(text, marker.as_bytes(), EXEC_OUTPUT_MAX_BYTES);
+    }
+
+    async fn record_chunk(
+        text: &mut Vec<u8>,
+        chunk: Vec<u8>,
+        stream: Option<&StdoutStream>,
+        emitted_deltas: &mut usize,
+    ) {
+        append_capped(text, &chunk, EXEC_OUTPUT_MAX_BYTES);
+        if let Some(stream) = stream
+            && *emitted_deltas < MAX_EXEC_OUTPUT_DELTAS_PER_CALL
+        {
+            send_output_delta(stream, chunk, false).await;
+            *emitted_deltas += 1;
+        }
+    }
+
+    let mut text = Vec::with_capacity(AGGREGATE_BUFFER_INITIAL_CAPACITY);
+    let mut emitted_deltas: usize = 0;
+    let expiration = expiration.wait();
+    tokio::pin!(expiration);
+    // The session owns the sender, so the channel only closes if the reader
+    // task went away; keep waiting for the exit code in that case.
+    let mut output_open = true;
+    let (exit_status, timed_out) = loop {
+        tokio::select! {
+            chunk = chunk_rx.recv(), if output_open => match chunk {
+                Some(Ok(chunk)) => {
+                    record_chunk(&mut text, chunk, stdout_stream.as_ref(), &mut emitted_deltas)
+                        .await;
+                }
+                Some(Err(skipped)) => record_lost_chunks(&mut text, skipped),
+                None => output_open = false,
+            },
+            code = &mut exit_rx => {
+                break (synthetic_exit_status(code.unwrap_or(-1)), false);
+            }
+            _ = &mut expiration => {
+                session.terminate();
+                break (synthetic_exit_status(EXIT_CODE_SIGNAL_BASE + TIMEOUT_CODE), true);
+            }
+            _ = tokio::signal::ctrl_c() => {
+                session.terminate();
+                break (synthetic_exit_status(EXIT_CODE_SIGNAL_BASE + SIGKILL_CODE), false);
+            }
+        }
+    };
+
+    let drain_deadline = Instant::now() + CONPTY_DRAIN_MAX;
+    while output_open && Instant::now() < drain_deadline {
+        match tokio::time::timeout(CONPTY_DRAIN_IDLE, chunk_rx.recv()).await {
+            Ok(Some(Ok(chunk))) => {
+                record_chunk(
+                    &mut text,
+                    chunk,
+                    stdout_stream.as_ref(),
+                    &mut emitted_deltas,
+                )
+                .await;
+            }
+            Ok(Some(Err(skipped))) => record_lost_chunks(&mut text, skipped),
+            Ok(None) | Err(_) => break,
+        }
+    }
+    session.terminate();
+    forwarder.abort();
+
+    let stdout = StreamOutput {
+        text,
+        truncated_after_lines: None,
+    };
+    let stderr = StreamOutput {
+        text: Vec::new(),
+        truncated_after_lines: None,
+    };
+    let aggregated_output = aggregate_output(&stdout, &stderr);
+    Ok(RawExecToolCallOutput {
+        exit_status,
+        stdout,
+        stderr,
+        aggregated_output,
+        timed_out,
+    })
+}
+
 /// Consumes the output of a child process, truncating it so it is suitable for
 /// use as the output of a `shell` tool call. Also enforces specified timeout.
 async fn consume_truncated_output(
@@ -855,22 +1035,7 @@ async fn read_capped<R: AsyncRead + Unpin + Send + 'static>(
         if let Some(stream) = &stream
             && emitted_deltas < MAX_EXEC_OUTPUT_DELTAS_PER_CALL
         {
-            let chunk = tmp[..n].to_vec();
-            let msg = EventMsg::ExecCommandOutputDelta(ExecCommandOutputDeltaEvent {
-                call_id: stream.call_id.clone(),
-                stream: if is_stderr {
-                    ExecOutputStream::Stderr
-                } else {
-                    ExecOutputStream::Stdout
-                },
-                chunk,
-            });
-            let event = Event {
-                id: stream.sub_id.clone(),
-                msg,
-            };
-            #[allow(clippy::let_unit_value)]
-            let _ = stream.tx_event.send(event).await;
+            send_output_delta(stream, tmp[..n].to_vec(), is_stderr).await;
             emitted_deltas += 1;
         }
 
@@ -884,6 +1049,24 @@ async fn read_capped<R: AsyncRead + Unpin + Send + 'static>(
     })
 }
 
+async fn send_output_delta(stream: &StdoutStream, chunk: Vec<u8>, is_stderr: bool) {
+    let msg = EventMsg::ExecCommandOutputDelta(ExecCommandOutputDeltaEvent {
+        call_id: stream.call_id.clone(),
+        stream: if is_stderr {
+            ExecOutputStream::Stderr
+        } else {
+            ExecOutputStream::Stdout
+        },
+        chunk,
+    });
+    let event = Event {
+        id: stream.sub_id.clone(),
+        msg,
+    };
+    #[allow(clippy::let_unit_value)]
+    let _ = stream.tx_event.send(event).await;
+}
+
 #[cfg(unix)]
 fn synthetic_exit_status(code: i32) -> ExitStatus {
     use std::os::unix::process::ExitStatusExt;
@@ -1094,7 +1277,14 @@ mod tests {
             arg0: None,
         };
 
-        let output = exec(params, SandboxType::None, &SandboxPolicy::ReadOnly, None).await?;
+        let output = exec(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::ReadOnly,
+            false,
+            None,
+        )
+        .await?;
         assert!(output.timed_out);
 
         let stdout = output.stdout.from_utf8_lossy().text;
@@ -1148,6 +1338,7 @@ mod tests {
             params,
             &SandboxPolicy::DangerFullAccess,
             cwd.as_path(),
+            &ProtectedPaths::default(),
             &None,
             false,
             None,
@@ -1,6 +1,8 @@
 use crate::config::types::EnvironmentVariablePattern;
 use crate::config::types::ShellEnvironmentPolicy;
 use crate::config::types::ShellEnvironmentPolicyInherit;
+use crate::config::types::ShellEnvironmentSecretToml;
+use codex_keyring_store::KeyringStore;
 use codex_protocol::ThreadId;
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -24,6 +26,30 @@ pub fn create_env(
     populate_env(std::env::vars(), policy, thread_id)
 }
 
+/// Reads every `[shell_environment_policy.secrets]` entry from `store`.
+///
+/// Missing entries and keychain errors are logged and leave the value unset,
+/// so a locked keychain never blocks startup; the variable is simply absent
+/// from spawned commands.
+pub fn load_shell_secrets(policy: &mut ShellEnvironmentPolicy, store: &dyn KeyringStore) {
+    for (name, secret) in &mut policy.secrets {
+        let ShellEnvironmentSecretToml { service, account } = &secret.source;
+        secret.value = match store.load(service, account) {
+            Ok(Some(value)) => Some(value),
+            Ok(None) => {
+                tracing::warn!(
+                    "no keychain entry for {name} (service={service}, account={account})"
+                );
+                None
+            }
+            Err(err) => {
+                tracing::warn!("failed to read keychain entry for {name}: {err}");
+                None
+            }
+        };
+    }
+}
+
 fn populate_env<I>(
     vars: I,
     policy: &ShellEnvironmentPolicy,
@@ -85,7 +111,15 @@ where
         env_map.retain(|k, _| matches_any(k, &policy.include_only));
     }
 
-    // Step 6 – Populate the thread ID environment variable when provided.
+    // Step 6 – Inject keychain secrets. They were requested explicitly, so
+    // neither the default excludes nor `include_only` remove them.
+    for (key, secret) in &policy.secrets {
+        if let Some(value) = &secret.value {
+            env_map.insert(key.clone(), value.clone());
+        }
+    }
+
+    // Step 7 – Populate the thread ID environment variable when provided.
     if let Some(thread_id) = thread_id {
         env_map.insert(CODEX_THREAD_ID_ENV_VAR.to_string(), thread_id.to_string());
     }
@@ -97,7 +131,10 @@ where
 mod tests {
     use super::*;
     use crate::config::types::ShellEnvironmentPolicyInherit;
+    use crate::config::types::ShellEnvironmentPolicyToml;
+    use codex_keyring_store::tests::MockKeyringStore;
     use maplit::hashmap;
+    use std::collections::BTreeMap;
 
     fn make_vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
         pairs
@@ -289,6 +326,47 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn secrets_bypass_excludes_and_include_only() {
+        let vars = make_vars(&[("PATH", "/usr/bin"), ("GITHUB_TOKEN", "from-env")]);
+        let store = MockKeyringStore::default();
+        store
+            .save("gh", "me", "from-keychain")
+            .expect("save secret");
+        let mut policy: ShellEnvironmentPolicy = ShellEnvironmentPolicyToml {
+            ignore_default_excludes: Some(false),
+            include_only: Some(vec!["PATH".to_string()]),
+            secrets: Some(BTreeMap::from([
+                (
+                    "GITHUB_TOKEN".to_string(),
+                    ShellEnvironmentSecretToml {
+                        service: "gh".to_string(),
+                        account: "me".to_string(),
+                    },
+                ),
+                (
+                    "NPM_TOKEN".to_string(),
+                    ShellEnvironmentSecretToml {
+                        service: "npm".to_string(),
+                        account: "missing".to_string(),
+                    },
+                ),
+            ])),
+            ..Default::default()
+        }
+        .into();
+        load_shell_secrets(&mut policy, &store);
+
+        let result = populate_env(vars, &policy, None);
+
+        let expected: HashMap<String, String> = hashmap! {
+            "PATH".to_string() => "/usr/bin".to_string(),
+            "GITHUB_TOKEN".to_string() => "from-keychain".to_string(),
+        };
+        assert_eq!(result, expected);
+        assert!(!format!("{policy:?}").contains("from-keychain"));
+    }
+
     #[test]
     fn test_inherit_none() {
         let vars = make_vars(&[("PATH", "/usr/bin"), ("HOME", "/home")]);
@@ -32,8 +32,8 @@ use shlex::try_join as shlex_try_join;
 
 const PROMPT_CONFLICT_REASON: &str =
     "approval required by policy, but AskForApproval is set to Never";
-const RULES_DIR_NAME: &str = "rules";
-const RULE_EXTENSION: &str = "rules";
+pub(crate) const RULES_DIR_NAME: &str = "rules";
+pub(crate) const RULE_EXTENSION: &str = "rules";
 const DEFAULT_POLICY_FILE: &str = "default.rules";
 
 fn is_policy_match(rule_match: &RuleMatch) -> bool {
@@ -110,6 +110,17 @@ impl ExecPolicyManager {
         self.policy.load_full()
     }
 
+    /// Re-read the `*.rules` files of every layer in `config_stack`, keeping
+    /// the current policy when they no longer parse.
+    pub(crate) async fn reload(
+        &self,
+        config_stack: &ConfigLayerStack,
+    ) -> Result<(), ExecPolicyError> {
+        let policy = load_exec_policy(config_stack).await?;
+        self.policy.store(Arc::new(policy));
+        Ok(())
+    }
+
     pub(crate) async fn create_exec_approval_requirement_for_command(
         &self,
         req: ExecApprovalRequest<'_>,
@@ -178,6 +189,29 @@ impl ExecPolicyManager {
         }
     }
 
+    /// The execpolicy rule that decided `command`, written the way it appears
+    /// in a rules file. `None` when no rule matched and the built-in
+    /// heuristics decided instead.
+    pub(crate) fn matched_rule_for_command(&self, command: &[String]) -> Option<String> {
+        let (commands, _) = commands_for_exec_policy(command);
+        let evaluation = self
+            .current()
+            .check_multiple(commands.iter(), &|_: &[String]| Decision::Allow);
+        evaluation
+            .matched_rules
+            .iter()
+            .filter_map(|rule_match| match rule_match {
+                RuleMatch::PrefixRuleMatch {
+                    matched_prefix,
+                    decision,
+                    ..
+                } => Some((*decision, matched_prefix)),
+                RuleMatch::HeuristicsRuleMatch { .. } => None,
+            })
+            .max_by_key(|(decision, matched_prefix)| (*decision, matched_prefix.len()))
+            .map(|(decision, matched_prefix)| render_prefix_rule(matched_prefix, decision))
+    }
+
     pub(crate) async fn append_amendment_and_update(
         &self,
         codex_home: &Path,
@@ -344,7 +378,7 @@ fn default_policy_path(codex_home: &Path) -> PathBuf {
     codex_home.join(RULES_DIR_NAME).join(DEFAULT_POLICY_FILE)
 }
 
-fn commands_for_exec_policy(command: &[String]) -> (Vec<Vec<String>>, bool) {
+pub(crate) fn commands_for_exec_policy(command: &[String]) -> (Vec<Vec<String>>, bool) {
     if let Some(commands) = parse_shell_lc_plain_commands(command)
         && !commands.is_empty()
     {
@@ -458,6 +492,20 @@ fn derive_prompt_reason(command_args: &[String], evaluation: &Evaluation) -> Opt
     }
 }
 
+fn render_prefix_rule(prefix: &[String], decision: Decision) -> String {
+    let pattern = prefix
+        .iter()
+        .map(|token| serde_json::Value::from(token.as_str()).to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let decision = match decision {
+        Decision::Allow => "allow",
+        Decision::Prompt => "prompt",
+        Decision::Forbidden => "forbidden",
+    };
+    format!(r#"prefix_rule(pattern=[{pattern}], decision="{decision}")"#)
+}
+
 fn render_shlex_command(args: &[String]) -> String {
     shlex_try_join(args.iter().map(String::as_str)).unwrap_or_else(|_| args.join(" "))
 }
@@ -816,6 +864,30 @@ prefix_rule(pattern=["rm"], decision="forbidden")
         );
     }
 
+    #[test]
+    fn matched_rule_reports_the_deciding_rule() {
+        let policy_src = r#"
+prefix_rule(pattern=["git"], decision="allow")
+prefix_rule(pattern=["git", "push"], decision="prompt")
+"#;
+        let mut parser = PolicyParser::new();
+        parser
+            .parse("test.rules", policy_src)
+            .expect("parse policy");
+        let manager = ExecPolicyManager::new(Arc::new(parser.build()));
+
+        let script = vec![
+            "bash".to_string(),
+            "-lc".to_string(),
+            "git status && git push origin main".to_string(),
+        ];
+        assert_eq!(
+            manager.matched_rule_for_command(&script),
+            Some(r#"prefix_rule(pattern=["git", "push"], decision="prompt")"#.to_string())
+        );
+        assert_eq!(manager.matched_rule_for_command(&["ls".to_string()]), None);
+    }
+
     #[test]
     fn commands_for_exec_policy_falls_back_for_empty_shell_script() {
         let command = vec!["bash".to_string(), "-lc".to_string(), "".to_string()];
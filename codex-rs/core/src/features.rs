@@ -12,6 +12,7 @@ use crate::config::profile::ConfigProfile;
 use crate::protocol::Event;
 use crate::protocol::EventMsg;
 use crate::protocol::WarningEvent;
+use codex_app_server_protocol::ConfigLayerSource;
 use codex_otel::OtelManager;
 use schemars::JsonSchema;
 use serde::Deserialize;
@@ -21,8 +22,13 @@ use std::collections::BTreeSet;
 use toml::Value as TomlValue;
 
 mod legacy;
+mod lock;
 pub(crate) use legacy::LegacyFeatureToggles;
 pub(crate) use legacy::legacy_feature_keys;
+pub use lock::FEATURE_LOCK_FILE;
+pub use lock::FeatureLock;
+pub use lock::FeatureLockEntry;
+pub use lock::LockedStage;
 
 /// High-level lifecycle stage for a feature.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -89,6 +95,8 @@ pub enum Feature {
     WebSearchCached,
     /// Allow the model to search MCP tools via BM25 before exposing them.
     SearchTool,
+    /// Expose the dependency_audit tool (lockfile listings + OSV advisories).
+    DependencyAudit,
     /// Use the bubblewrap-based Linux sandbox pipeline.
     UseLinuxSandboxBwrap,
     /// Allow the model to request approval and propose exec rules.
@@ -97,6 +105,8 @@ pub enum Feature {
     WindowsSandbox,
     /// Use the elevated Windows sandbox pipeline (setup + runner).
     WindowsSandboxElevated,
+    /// Run unsandboxed shell commands through ConPTY on Windows.
+    WindowsConptyExec,
     /// Refresh remote models and emit AppReady once the list is available.
     RemoteModels,
     /// Experimental shell snapshotting.
@@ -105,6 +115,8 @@ pub enum Feature {
     Tui2,
     /// Enable runtime metrics snapshots via a manual reader.
     RuntimeMetrics,
+    /// Overlay frame times and event rates on the TUI.
+    RenderStatsHud,
     /// Persist rollout metadata to a local SQLite database.
     Sqlite,
     /// Enable startup memory extraction and file-backed memory consolidation.
@@ -148,6 +160,18 @@ impl Feature {
         self.info().default_enabled
     }
 
+    pub fn description(self) -> &'static str {
+        self.info().description
+    }
+
+    pub fn dependencies(self) -> &'static [Feature] {
+        self.info().dependencies
+    }
+
+    pub fn risk(self) -> Option<&'static str> {
+        self.info().risk
+    }
+
     fn info(self) -> &'static FeatureSpec {
         FEATURES
             .iter()
@@ -169,6 +193,39 @@ pub struct LegacyFeatureUsage {
 pub struct Features {
     enabled: BTreeSet<Feature>,
     legacy_usages: BTreeSet<LegacyFeatureUsage>,
+    /// Stages pinned by `features.lock`.
+    stage_overrides: BTreeMap<Feature, Stage>,
+    /// Features whose value `features.lock` pins.
+    locked: BTreeSet<Feature>,
+}
+
+/// Where the effective value of a feature toggle comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureOrigin {
+    Default,
+    User,
+    Profile,
+    Project,
+    /// `-c features.<key>=...` or `--enable`/`--disable` for this run.
+    Session,
+    /// A managed config layer (system config, `managed_config.toml`, MDM).
+    Managed,
+    /// Pinned by `features.lock`.
+    Locked,
+}
+
+impl FeatureOrigin {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FeatureOrigin::Default => "default",
+            FeatureOrigin::User => "user",
+            FeatureOrigin::Profile => "profile",
+            FeatureOrigin::Project => "project",
+            FeatureOrigin::Session => "session",
+            FeatureOrigin::Managed => "managed",
+            FeatureOrigin::Locked => "locked",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -200,6 +257,8 @@ impl Features {
         Self {
             enabled: set,
             legacy_usages: BTreeSet::new(),
+            stage_overrides: BTreeMap::new(),
+            locked: BTreeSet::new(),
         }
     }
 
@@ -217,6 +276,37 @@ impl Features {
         self
     }
 
+    /// The feature's stage, as pinned by `features.lock` or built in.
+    pub fn stage(&self, f: Feature) -> Stage {
+        self.stage_overrides
+            .get(&f)
+            .copied()
+            .unwrap_or_else(|| f.stage())
+    }
+
+    /// Whether `features.lock` pins the feature's value, so toggling it has no
+    /// lasting effect.
+    pub fn is_locked(&self, f: Feature) -> bool {
+        self.locked.contains(&f)
+    }
+
+    /// Applies `features.lock` on top of every config layer.
+    pub fn apply_lock(&mut self, lock: &FeatureLock) {
+        for (feature, entry) in lock.entries() {
+            if let Some(stage) = entry.stage {
+                self.stage_overrides.insert(feature, stage.resolve(feature));
+            }
+            if let Some(enabled) = entry.enabled {
+                if enabled {
+                    self.enable(feature);
+                } else {
+                    self.disable(feature);
+                }
+                self.locked.insert(feature);
+            }
+        }
+    }
+
     pub fn record_legacy_usage_force(&mut self, alias: &str, feature: Feature) {
         let (summary, details) = legacy_usage_notice(alias, feature);
         self.legacy_usages.insert(LegacyFeatureUsage {
@@ -329,6 +419,15 @@ impl Features {
     pub fn enabled_features(&self) -> Vec<Feature> {
         self.enabled.iter().copied().collect()
     }
+
+    /// Dependencies of `f` that are currently disabled.
+    pub fn missing_dependencies(&self, f: Feature) -> Vec<Feature> {
+        f.dependencies()
+            .iter()
+            .copied()
+            .filter(|dependency| !self.enabled(*dependency))
+            .collect()
+    }
 }
 
 fn legacy_usage_notice(alias: &str, feature: Feature) -> (String, Option<String>) {
@@ -377,6 +476,38 @@ fn feature_for_key(key: &str) -> Option<Feature> {
     legacy::feature_for_key(key)
 }
 
+/// Reports which layer decided `feature`'s effective value. A profile's
+/// `[features]` table wins over top-level ones, and `features.lock` over both.
+pub fn feature_origin(config: &Config, feature: Feature) -> FeatureOrigin {
+    if config.features.is_locked(feature) {
+        return FeatureOrigin::Locked;
+    }
+    let key = feature.key();
+    let layers = &config.config_layer_stack;
+    if let Some(profile) = config.active_profile.as_deref()
+        && layers
+            .origin_of(&["profiles", profile, "features", key])
+            .is_some()
+    {
+        return FeatureOrigin::Profile;
+    }
+    match layers
+        .origin_of(&["features", key])
+        .map(|layer| &layer.name)
+    {
+        None => FeatureOrigin::Default,
+        Some(ConfigLayerSource::User { .. }) => FeatureOrigin::User,
+        Some(ConfigLayerSource::Project { .. }) => FeatureOrigin::Project,
+        Some(ConfigLayerSource::SessionFlags) => FeatureOrigin::Session,
+        Some(
+            ConfigLayerSource::Mdm { .. }
+            | ConfigLayerSource::System { .. }
+            | ConfigLayerSource::LegacyManagedConfigTomlFromFile { .. }
+            | ConfigLayerSource::LegacyManagedConfigTomlFromMdm,
+        ) => FeatureOrigin::Managed,
+    }
+}
+
 /// Returns `true` if the provided string matches a known feature toggle key.
 pub fn is_known_feature_key(key: &str) -> bool {
     feature_for_key(key).is_some()
@@ -396,6 +527,12 @@ pub struct FeatureSpec {
     pub key: &'static str,
     pub stage: Stage,
     pub default_enabled: bool,
+    /// One-line summary shown by `codex features describe`.
+    pub description: &'static str,
+    /// Features that must also be enabled for this one to have any effect.
+    pub dependencies: &'static [Feature],
+    /// What enabling the feature can cost or expose, if anything.
+    pub risk: Option<&'static str>,
 }
 
 pub const FEATURES: &[FeatureSpec] = &[
@@ -405,48 +542,81 @@ pub const FEATURES: &[FeatureSpec] = &[
         key: "undo",
         stage: Stage::Stable,
         default_enabled: false,
+        description: "Snapshot the worktree in a ghost commit each turn so `/undo` can restore it.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::ShellTool,
         key: "shell_tool",
         stage: Stage::Stable,
         default_enabled: true,
+        description: "Expose the default shell tool so the model can run commands.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::UnifiedExec,
         key: "unified_exec",
         stage: Stage::Stable,
         default_enabled: !cfg!(windows),
+        description: "Run commands through the single PTY-backed exec tool instead of one-shot shell calls.",
+        dependencies: &[Feature::ShellTool],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::ShellSnapshot,
         key: "shell_snapshot",
         stage: Stage::Stable,
         default_enabled: true,
+        description: "Capture the login shell environment once and reuse it for every command.",
+        dependencies: &[Feature::ShellTool],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::Tui2,
         key: "tui2",
         stage: Stage::Stable,
         default_enabled: true,
+        description: "Route interactive sessions through the TUI2 frontend.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::WebSearchRequest,
         key: "web_search_request",
         stage: Stage::Deprecated,
         default_enabled: false,
+        description: "Let the model request live web searches. Superseded by `web_search = \"live\"`.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::WebSearchCached,
         key: "web_search_cached",
         stage: Stage::Deprecated,
         default_enabled: false,
+        description: "Let the model request cached web searches. Superseded by `web_search = \"cached\"`.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::SearchTool,
         key: "search_tool",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Let the model search MCP tools with BM25 before they are exposed.",
+        dependencies: &[],
+        risk: None,
+    },
+    FeatureSpec {
+        id: Feature::DependencyAudit,
+        key: "dependency_audit",
+        stage: Stage::UnderDevelopment,
+        default_enabled: false,
+        description: "Expose the dependency_audit tool for lockfile listings and OSV advisories.",
+        dependencies: &[],
+        risk: Some("Sends package names and versions to the OSV API."),
     },
     // Experimental program. Rendered in the `/experimental` menu for users.
     FeatureSpec {
@@ -454,60 +624,108 @@ pub const FEATURES: &[FeatureSpec] = &[
         key: "runtime_metrics",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Collect runtime metrics snapshots through a manual reader.",
+        dependencies: &[],
+        risk: None,
+    },
+    FeatureSpec {
+        id: Feature::RenderStatsHud,
+        key: "render_stats_hud",
+        stage: Stage::UnderDevelopment,
+        default_enabled: false,
+        description: "Show frame times and event rates in a corner of the TUI.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::Sqlite,
         key: "sqlite",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Persist rollout metadata to a local SQLite database.",
+        dependencies: &[],
+        risk: Some("Writes an extra database under CODEX_HOME that older versions ignore."),
     },
     FeatureSpec {
         id: Feature::MemoryTool,
         key: "memory_tool",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Extract memories at startup and consolidate them into files.",
+        dependencies: &[],
+        risk: Some("Stores conversation-derived notes on disk under CODEX_HOME."),
     },
     FeatureSpec {
         id: Feature::ChildAgentsMd,
         key: "child_agents_md",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Append extra AGENTS.md guidance to the user instructions.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::ApplyPatchFreeform,
         key: "apply_patch_freeform",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Offer the freeform apply_patch tool to the model.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::UseLinuxSandboxBwrap,
         key: "use_linux_sandbox_bwrap",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Sandbox commands on Linux with the bubblewrap-based pipeline.",
+        dependencies: &[],
+        risk: Some("Needs a working `bwrap`; commands fail to start when it is missing."),
     },
     FeatureSpec {
         id: Feature::RequestRule,
         key: "request_rule",
         stage: Stage::Stable,
         default_enabled: true,
+        description: "Let the model ask for approval and propose exec rules.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::WindowsSandbox,
         key: "experimental_windows_sandbox",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Sandbox commands on Windows with a restricted token.",
+        dependencies: &[],
+        risk: Some("Some tools misbehave under a restricted token."),
     },
     FeatureSpec {
         id: Feature::WindowsSandboxElevated,
         key: "elevated_windows_sandbox",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Sandbox commands on Windows with the elevated setup and runner.",
+        dependencies: &[],
+        risk: Some("Runs a one-time setup that needs administrator rights."),
+    },
+    FeatureSpec {
+        id: Feature::WindowsConptyExec,
+        key: "windows_conpty_exec",
+        stage: Stage::UnderDevelopment,
+        default_enabled: false,
+        description: "Capture Windows shell command output through ConPTY to keep colors.",
+        dependencies: &[],
+        risk: Some("stdout and stderr are merged into one stream."),
     },
     FeatureSpec {
         id: Feature::RemoteModels,
         key: "remote_models",
         stage: Stage::Stable,
         default_enabled: true,
+        description: "Refresh the model list from the server at startup.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::PowershellUtf8,
@@ -520,12 +738,18 @@ pub const FEATURES: &[FeatureSpec] = &[
         stage: Stage::UnderDevelopment,
         #[cfg(not(windows))]
         default_enabled: false,
+        description: "Force UTF-8 output in PowerShell.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::EnableRequestCompression,
         key: "enable_request_compression",
         stage: Stage::Stable,
         default_enabled: true,
+        description: "Compress streaming request bodies with zstd when talking to codex-backend.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::Collab,
@@ -536,6 +760,9 @@ pub const FEATURES: &[FeatureSpec] = &[
             announcement: "NEW: Sub-agents can now be spawned by Codex. Enable in /experimental and restart Codex!",
         },
         default_enabled: false,
+        description: "Let the model spawn sub-agents that work in parallel.",
+        dependencies: &[],
+        risk: Some("Each sub-agent runs its own turns, so usage grows with the number of agents."),
     },
     FeatureSpec {
         id: Feature::Apps,
@@ -546,48 +773,72 @@ pub const FEATURES: &[FeatureSpec] = &[
             announcement: "NEW: Use ChatGPT Apps (Connectors) in Codex via $ mentions. Enable in /experimental and restart Codex!",
         },
         default_enabled: false,
+        description: "Use connected ChatGPT Apps through `$` mentions.",
+        dependencies: &[],
+        risk: Some("Gives the model access to data in the connected apps."),
     },
     FeatureSpec {
         id: Feature::SkillMcpDependencyInstall,
         key: "skill_mcp_dependency_install",
         stage: Stage::Stable,
         default_enabled: true,
+        description: "Offer to install MCP servers that a skill depends on.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::SkillEnvVarDependencyPrompt,
         key: "skill_env_var_dependency_prompt",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Prompt for environment variables that a skill needs.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::Steer,
         key: "steer",
         stage: Stage::Stable,
         default_enabled: true,
+        description: "Submit on Enter during a running turn instead of queuing the message.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::CollaborationModes,
         key: "collaboration_modes",
         stage: Stage::Stable,
         default_enabled: true,
+        description: "Enable collaboration modes such as Plan and Default.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::Personality,
         key: "personality",
         stage: Stage::Stable,
         default_enabled: true,
+        description: "Enable personality selection in the TUI.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::ResponsesWebsockets,
         key: "responses_websockets",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Use the Responses API WebSocket transport for OpenAI by default.",
+        dependencies: &[],
+        risk: None,
     },
     FeatureSpec {
         id: Feature::ResponsesWebsocketsV2,
         key: "responses_websockets_v2",
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        description: "Use v2 of the Responses API WebSocket transport.",
+        dependencies: &[],
+        risk: None,
     },
 ];
 
@@ -617,7 +868,7 @@ pub fn maybe_push_unstable_features_warning(
             if !config.features.enabled(spec.id) {
                 continue;
             }
-            if matches!(spec.stage, Stage::UnderDevelopment) {
+            if matches!(config.features.stage(spec.id), Stage::UnderDevelopment) {
                 under_development_feature_keys.push(spec.key.to_string());
             }
         }
@@ -648,6 +899,64 @@ mod tests {
 
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn feature_lock_pins_stage_and_value() {
+        let lock = FeatureLock::from_toml(
+            toml::from_str(
+                "[tui2]\nstage = \"stable\"\nenabled = true\n\n[shell_tool]\nenabled = false\n",
+            )
+            .expect("toml"),
+        )
+        .expect("lock");
+        let mut features = Features::with_defaults();
+        features.apply_lock(&lock);
+
+        assert_eq!(features.enabled(Feature::Tui2), true);
+        assert_eq!(features.stage(Feature::Tui2), Stage::Stable);
+        assert_eq!(features.is_locked(Feature::Tui2), true);
+        assert_eq!(features.enabled(Feature::ShellTool), false);
+        assert_eq!(
+            features.stage(Feature::ShellTool),
+            Feature::ShellTool.stage()
+        );
+        assert_eq!(features.is_locked(Feature::GhostCommit), false);
+    }
+
+    #[test]
+    fn missing_dependencies_lists_disabled_prerequisites() {
+        let mut features = Features::with_defaults();
+        features.enable(Feature::UnifiedExec);
+        assert_eq!(
+            features.missing_dependencies(Feature::UnifiedExec),
+            Vec::<Feature>::new()
+        );
+
+        features.disable(Feature::ShellTool);
+        assert_eq!(
+            features.missing_dependencies(Feature::UnifiedExec),
+            vec![Feature::ShellTool]
+        );
+    }
+
+    #[test]
+    fn every_feature_is_described_and_dependencies_do_not_loop_back() {
+        for spec in FEATURES {
+            assert!(
+                !spec.description.is_empty(),
+                "feature `{}` needs a description",
+                spec.key
+            );
+            for dependency in spec.dependencies {
+                assert!(
+                    !dependency.dependencies().contains(&spec.id) && *dependency != spec.id,
+                    "feature `{}` has a circular dependency on `{}`",
+                    spec.key,
+                    dependency.key()
+                );
+            }
+        }
+    }
+
     #[test]
     fn under_development_features_are_disabled_by_default() {
         for spec in FEATURES {
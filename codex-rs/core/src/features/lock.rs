@@ -0,0 +1,124 @@
+//! `features.lock`: feature stages and values pinned by an administrator.
+//!
+//! The file is distributed next to the managed config (on Unix,
+//! `/etc/codex/features.lock`) and holds one table per feature key:
+//!
+//! ```toml
+//! [tui2]
+//! stage = "stable"
+//! enabled = true
+//! ```
+//!
+//! `stage` changes how Codex presents the feature: unstable-feature warnings,
+//! the `/experimental` menu, and `codex features list`. `enabled`, when set,
+//! wins over every config layer, profile, and `--enable`/`--disable` flag.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use toml::Value as TomlValue;
+
+use super::FEATURES;
+use super::Feature;
+use super::Stage;
+
+pub const FEATURE_LOCK_FILE: &str = "features.lock";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockedStage {
+    UnderDevelopment,
+    Experimental,
+    Stable,
+    Deprecated,
+    Removed,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureLockEntry {
+    #[serde(default)]
+    pub stage: Option<LockedStage>,
+    #[serde(default)]
+    pub enabled: Option<bool>,
+}
+
+/// Parsed `features.lock`, keyed by feature.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeatureLock {
+    entries: BTreeMap<Feature, FeatureLockEntry>,
+}
+
+impl FeatureLock {
+    /// Parses the file contents. Keys must be canonical feature keys so a
+    /// typo in an org-wide file fails loudly instead of pinning nothing.
+    pub fn from_toml(value: TomlValue) -> Result<Self, String> {
+        let table: BTreeMap<String, FeatureLockEntry> = value
+            .try_into()
+            .map_err(|err: toml::de::Error| err.to_string())?;
+        let mut entries = BTreeMap::new();
+        for (key, entry) in table {
+            let Some(spec) = FEATURES.iter().find(|spec| spec.key == key) else {
+                return Err(format!("unknown feature `{key}`"));
+            };
+            entries.insert(spec.id, entry);
+        }
+        Ok(Self { entries })
+    }
+
+    pub(super) fn entries(&self) -> impl Iterator<Item = (Feature, &FeatureLockEntry)> + '_ {
+        self.entries
+            .iter()
+            .map(|(feature, entry)| (*feature, entry))
+    }
+}
+
+impl LockedStage {
+    /// The [`Stage`] to report for `feature`. A feature pinned to
+    /// experimental keeps its own menu text when it has some.
+    pub(super) fn resolve(self, feature: Feature) -> Stage {
+        match self {
+            LockedStage::UnderDevelopment => Stage::UnderDevelopment,
+            LockedStage::Experimental => match feature.stage() {
+                stage @ Stage::Experimental { .. } => stage,
+                _ => Stage::Experimental {
+                    name: feature.key(),
+                    menu_description: "",
+                    announcement: "",
+                },
+            },
+            LockedStage::Stable => Stage::Stable,
+            LockedStage::Deprecated => Stage::Deprecated,
+            LockedStage::Removed => Stage::Removed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_entries_and_rejects_unknown_keys() {
+        let lock = FeatureLock::from_toml(
+            toml::from_str("[tui2]\nstage = \"stable\"\nenabled = true\n").expect("toml"),
+        )
+        .expect("lock");
+        assert_eq!(
+            lock.entries().collect::<Vec<_>>(),
+            vec![(
+                Feature::Tui2,
+                &FeatureLockEntry {
+                    stage: Some(LockedStage::Stable),
+                    enabled: Some(true),
+                }
+            )]
+        );
+
+        assert_eq!(
+            FeatureLock::from_toml(toml::from_str("[tui3]\nenabled = true\n").expect("toml")),
+            Err("unknown feature `tui3`".to_string())
+        );
+    }
+}
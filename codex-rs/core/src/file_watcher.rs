@@ -1,5 +1,5 @@
-//! Watches skill roots for changes and broadcasts coarse-grained
-//! `FileWatcherEvent`s that higher-level components react to on the next turn.
+//! Watches skill roots and config layer files for changes and broadcasts
+//! coarse-grained `FileWatcherEvent`s that higher-level components react to.
 
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -22,16 +22,31 @@ use tokio::time::Instant;
 use tokio::time::sleep_until;
 use tracing::warn;
 
+use crate::config::CONFIG_TOML_FILE;
 use crate::config::Config;
+use crate::config_loader::ConfigLayerStackOrdering;
+use crate::exec_policy::RULE_EXTENSION;
+use crate::exec_policy::RULES_DIR_NAME;
 use crate::skills::loader::skill_roots_from_layer_stack_with_agents;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FileWatcherEvent {
-    SkillsChanged { paths: Vec<PathBuf> },
+    SkillsChanged {
+        paths: Vec<PathBuf>,
+    },
+    /// A layer's `config.toml` or a file in its `rules/` folder changed.
+    ConfigChanged {
+        paths: Vec<PathBuf>,
+    },
 }
 
+#[derive(Default)]
 struct WatchState {
     skills_roots: HashSet<PathBuf>,
+    /// `config.toml` of every config layer with a folder on disk.
+    config_files: HashSet<PathBuf>,
+    /// `rules/` folder of every config layer.
+    rules_dirs: HashSet<PathBuf>,
 }
 
 struct FileWatcherInner {
@@ -103,9 +118,7 @@ impl FileWatcher {
             watched_paths: HashMap::new(),
         };
         let (tx, _) = broadcast::channel(128);
-        let state = Arc::new(RwLock::new(WatchState {
-            skills_roots: HashSet::new(),
-        }));
+        let state = Arc::new(RwLock::new(WatchState::default()));
         let file_watcher = Self {
             inner: Some(Mutex::new(inner)),
             state: Arc::clone(&state),
@@ -119,9 +132,7 @@ impl FileWatcher {
         let (tx, _) = broadcast::channel(1);
         Self {
             inner: None,
-            state: Arc::new(RwLock::new(WatchState {
-                skills_roots: HashSet::new(),
-            })),
+            state: Arc::new(RwLock::new(WatchState::default())),
             tx,
         }
     }
@@ -136,6 +147,14 @@ impl FileWatcher {
         for root in roots {
             self.register_skills_root(root.path);
         }
+        for layer in config
+            .config_layer_stack
+            .get_layers(ConfigLayerStackOrdering::LowestPrecedenceFirst, false)
+        {
+            if let Some(config_folder) = layer.config_folder() {
+                self.register_config_folder(config_folder.into_path_buf());
+            }
+        }
     }
 
     // Bridge `notify`'s callback-based events into the Tokio runtime and
@@ -150,10 +169,15 @@ impl FileWatcher {
             handle.spawn(async move {
                 let now = Instant::now();
                 let mut skills = ThrottledPaths::new(now);
+                let mut config = ThrottledPaths::new(now);
 
                 loop {
                     let now = Instant::now();
-                    let next_deadline = skills.next_deadline(now);
+                    let next_deadline = match (skills.next_deadline(now), config.next_deadline(now))
+                    {
+                        (Some(a), Some(b)) => Some(a.min(b)),
+                        (a, b) => a.or(b),
+                    };
                     let timer_deadline = next_deadline
                         .unwrap_or_else(|| now + Duration::from_secs(60 * 60 * 24 * 365));
                     let timer = sleep_until(timer_deadline);
@@ -164,12 +188,17 @@ impl FileWatcher {
                             match res {
                                 Some(Ok(event)) => {
                                     let skills_paths = classify_event(&event, &state);
+                                    let config_paths = classify_config_event(&event, &state);
                                     let now = Instant::now();
                                     skills.add(skills_paths);
+                                    config.add(config_paths);
 
                                     if let Some(paths) = skills.take_ready(now) {
                                         let _ = tx.send(FileWatcherEvent::SkillsChanged { paths });
                                     }
+                                    if let Some(paths) = config.take_ready(now) {
+                                        let _ = tx.send(FileWatcherEvent::ConfigChanged { paths });
+                                    }
                                 }
                                 Some(Err(err)) => {
                                     warn!("file watcher error: {err}");
@@ -181,6 +210,9 @@ impl FileWatcher {
                                     if let Some(paths) = skills.take_pending(now) {
                                         let _ = tx.send(FileWatcherEvent::SkillsChanged { paths });
                                     }
+                                    if let Some(paths) = config.take_pending(now) {
+                                        let _ = tx.send(FileWatcherEvent::ConfigChanged { paths });
+                                    }
                                     break;
                                 }
                             }
@@ -190,6 +222,9 @@ impl FileWatcher {
                             if let Some(paths) = skills.take_ready(now) {
                                 let _ = tx.send(FileWatcherEvent::SkillsChanged { paths });
                             }
+                            if let Some(paths) = config.take_ready(now) {
+                                let _ = tx.send(FileWatcherEvent::ConfigChanged { paths });
+                            }
                         }
                     }
                 }
@@ -210,6 +245,22 @@ impl FileWatcher {
         self.watch_path(root, RecursiveMode::Recursive);
     }
 
+    // Editors usually save by renaming a temp file over the original, which
+    // drops a watch on the file itself, so watch the containing folders.
+    fn register_config_folder(&self, folder: PathBuf) {
+        let rules_dir = folder.join(RULES_DIR_NAME);
+        {
+            let mut state = match self.state.write() {
+                Ok(state) => state,
+                Err(err) => err.into_inner(),
+            };
+            state.config_files.insert(folder.join(CONFIG_TOML_FILE));
+            state.rules_dirs.insert(rules_dir.clone());
+        }
+        self.watch_path(folder, RecursiveMode::NonRecursive);
+        self.watch_path(rules_dir, RecursiveMode::NonRecursive);
+    }
+
     fn watch_path(&self, path: PathBuf, mode: RecursiveMode) {
         let Some(inner) = &self.inner else {
             return;
@@ -238,11 +289,15 @@ impl FileWatcher {
     }
 }
 
-fn classify_event(event: &Event, state: &RwLock<WatchState>) -> Vec<PathBuf> {
-    if !matches!(
+fn is_mutating(event: &Event) -> bool {
+    matches!(
         event.kind,
         EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
-    ) {
+    )
+}
+
+fn classify_event(event: &Event, state: &RwLock<WatchState>) -> Vec<PathBuf> {
+    if !is_mutating(event) {
         return Vec::new();
     }
 
@@ -268,6 +323,29 @@ fn is_skills_path(path: &Path, roots: &HashSet<PathBuf>) -> bool {
     roots.iter().any(|root| path.starts_with(root))
 }
 
+fn classify_config_event(event: &Event, state: &RwLock<WatchState>) -> Vec<PathBuf> {
+    if !is_mutating(event) {
+        return Vec::new();
+    }
+
+    let state = match state.read() {
+        Ok(state) => state,
+        Err(err) => err.into_inner(),
+    };
+    event
+        .paths
+        .iter()
+        .filter(|path| {
+            state.config_files.contains(*path)
+                || (path.extension().is_some_and(|ext| ext == RULE_EXTENSION)
+                    && path
+                        .parent()
+                        .is_some_and(|parent| state.rules_dirs.contains(parent)))
+        })
+        .cloned()
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -332,6 +410,7 @@ mod tests {
         let root = path("/tmp/skills");
         let state = RwLock::new(WatchState {
             skills_roots: HashSet::from([root.clone()]),
+            ..Default::default()
         });
         let event = notify_event(
             EventKind::Create(CreateKind::Any),
@@ -351,6 +430,7 @@ mod tests {
         let root_b = path("/tmp/workspace/.codex/skills");
         let state = RwLock::new(WatchState {
             skills_roots: HashSet::from([root_a.clone(), root_b.clone()]),
+            ..Default::default()
         });
         let event = notify_event(
             EventKind::Modify(ModifyKind::Any),
@@ -373,6 +453,7 @@ mod tests {
         let root = path("/tmp/skills");
         let state = RwLock::new(WatchState {
             skills_roots: HashSet::from([root.clone()]),
+            ..Default::default()
         });
         let path = root.join("demo/SKILL.md");
 
@@ -389,6 +470,35 @@ mod tests {
         assert_eq!(classify_event(&other_event, &state), Vec::<PathBuf>::new());
     }
 
+    #[test]
+    fn classify_config_event_keeps_config_files_and_rules() {
+        let folder = path("/tmp/home/.codex");
+        let state = RwLock::new(WatchState {
+            config_files: HashSet::from([folder.join("config.toml")]),
+            rules_dirs: HashSet::from([folder.join("rules")]),
+            ..Default::default()
+        });
+        let event = notify_event(
+            EventKind::Modify(ModifyKind::Any),
+            vec![
+                folder.join("config.toml"),
+                folder.join("config.toml.swp"),
+                folder.join("history.jsonl"),
+                folder.join("rules/default.rules"),
+                folder.join("rules/notes.txt"),
+            ],
+        );
+
+        assert_eq!(
+            classify_config_event(&event, &state),
+            vec![
+                folder.join("config.toml"),
+                folder.join("rules/default.rules")
+            ]
+        );
+        assert_eq!(classify_event(&event, &state), Vec::<PathBuf>::new());
+    }
+
     #[test]
     fn register_skills_root_dedupes_state_entries() {
         let watcher = FileWatcher::noop();
@@ -0,0 +1,423 @@
+//! Turns an HTML page into readable plain text for the `fetch_url` tool.
+//!
+//! This is deliberately a small tag scanner rather than a DOM parser: it drops
+//! scripts, styles, and page chrome (navigation, footers, forms), keeps block
+//! structure as line breaks, renders headings and list items in a
+//! Markdown-like form, and prefers the `<article>`/`<main>` content when the
+//! page has one.
+
+/// Text extracted from an HTML document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ReadablePage {
+    pub title: Option<String>,
+    pub text: String,
+}
+
+/// Elements whose content never contributes readable text.
+const SKIPPED_ELEMENTS: &[&str] = &[
+    "script", "style", "noscript", "template", "svg", "iframe", "nav", "footer", "aside", "form",
+    "button", "select", "textarea", "head",
+];
+
+/// Elements that start a new line.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "p",
+    "div",
+    "section",
+    "article",
+    "main",
+    "header",
+    "br",
+    "ul",
+    "ol",
+    "table",
+    "tr",
+    "blockquote",
+    "pre",
+    "hr",
+    "dl",
+    "dt",
+    "dd",
+    "figure",
+    "figcaption",
+    "details",
+    "summary",
+];
+
+/// Below this many characters the `<article>`/`<main>` content is assumed to
+/// be a stub and the whole body is used instead.
+const MIN_MAIN_CONTENT_CHARS: usize = 200;
+
+pub(crate) fn extract_readable_text(html: &str) -> ReadablePage {
+    let mut state = ExtractState::default();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        state.push_text(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .map_or("", |end| &comment[end + "-->".len()..]);
+            continue;
+        }
+        let Some(end) = tag_end(rest) else {
+            // Unterminated tag: treat the remainder as text.
+            state.push_text(rest);
+            rest = "";
+            break;
+        };
+        state.handle_tag(&rest[1..end]);
+        rest = &rest[end + 1..];
+    }
+    state.push_text(rest);
+
+    let main = normalize_whitespace(&state.main.text);
+    let text = if main.chars().count() >= MIN_MAIN_CONTENT_CHARS {
+        main
+    } else {
+        normalize_whitespace(&state.all.text)
+    };
+    let title = Some(collapse_spaces(&decode_entities(&state.title)))
+        .filter(|title| !title.is_empty())
+        .or_else(|| {
+            Some(collapse_spaces(&decode_entities(&state.first_heading)))
+                .filter(|heading| !heading.is_empty())
+        });
+    ReadablePage { title, text }
+}
+
+/// Extracted text plus the line break requested by the last block boundary,
+/// which is only written once more text follows.
+#[derive(Default)]
+struct TextBuffer {
+    text: String,
+    pending_newlines: usize,
+}
+
+impl TextBuffer {
+    fn push_break(&mut self, newlines: usize) {
+        self.pending_newlines = self.pending_newlines.max(newlines);
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if self.pending_newlines > 0 {
+            if text.trim().is_empty() {
+                return;
+            }
+            if !self.text.is_empty() {
+                self.text.push_str(&"\n".repeat(self.pending_newlines));
+            }
+            self.pending_newlines = 0;
+        }
+        self.text.push_str(text);
+    }
+}
+
+#[derive(Default)]
+struct ExtractState {
+    /// Text from the whole document.
+    all: TextBuffer,
+    /// Text from inside `<article>`/`<main>`.
+    main: TextBuffer,
+    title: String,
+    first_heading: String,
+    skip_depth: usize,
+    main_depth: usize,
+    pre_depth: usize,
+    in_title: bool,
+    in_first_heading: bool,
+    seen_heading: bool,
+}
+
+impl ExtractState {
+    fn push_text(&mut self, raw: &str) {
+        if raw.is_empty() {
+            return;
+        }
+        if self.in_title {
+            self.title.push_str(raw);
+            return;
+        }
+        if self.skip_depth > 0 {
+            return;
+        }
+        if self.in_first_heading {
+            self.first_heading.push_str(raw);
+        }
+        let decoded = decode_entities(raw);
+        let text = if self.pre_depth > 0 {
+            // Keep preformatted text verbatim; mark its spaces so whitespace
+            // normalization leaves them alone.
+            decoded.replace(' ', "\u{a0}")
+        } else {
+            decoded.replace('\n', " ")
+        };
+        self.push_str(&text);
+    }
+
+    fn push_str(&mut self, text: &str) {
+        self.all.push_text(text);
+        if self.main_depth > 0 {
+            self.main.push_text(text);
+        }
+    }
+
+    fn push_break(&mut self, newlines: usize) {
+        self.all.push_break(newlines);
+        if self.main_depth > 0 {
+            self.main.push_break(newlines);
+        }
+    }
+
+    fn handle_tag(&mut self, tag: &str) {
+        let closing = tag.starts_with('/');
+        let name: String = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(|ch| ch.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        if name.is_empty() {
+            // `<!DOCTYPE ...>`, `<?xml ...?>`, or a stray `<`.
+            return;
+        }
+        let self_closing = tag.ends_with('/') || matches!(name.as_str(), "br" | "hr" | "img");
+
+        if name == "title" {
+            self.in_title = !closing && !self_closing;
+            return;
+        }
+        if SKIPPED_ELEMENTS.contains(&name.as_str()) {
+            if self_closing {
+                return;
+            }
+            if closing {
+                self.skip_depth = self.skip_depth.saturating_sub(1);
+            } else {
+                self.skip_depth += 1;
+            }
+            return;
+        }
+        if self.skip_depth > 0 {
+            return;
+        }
+
+        if matches!(name.as_str(), "article" | "main") && !self_closing {
+            if closing {
+                self.main_depth = self.main_depth.saturating_sub(1);
+            } else {
+                self.main_depth += 1;
+            }
+        }
+        if name == "pre" && !self_closing {
+            if closing {
+                self.pre_depth = self.pre_depth.saturating_sub(1);
+            } else {
+                self.pre_depth += 1;
+            }
+        }
+
+        if let Some(level) = heading_level(&name) {
+            self.push_break(2);
+            if closing {
+                self.in_first_heading = false;
+            } else {
+                if level == 1 && !self.seen_heading {
+                    self.seen_heading = true;
+                    self.in_first_heading = true;
+                }
+                self.push_str(&format!("{} ", "#".repeat(level)));
+            }
+            return;
+        }
+        match name.as_str() {
+            "li" if !closing => {
+                self.push_break(1);
+                self.push_str("- ");
+            }
+            "td" | "th" if !closing => self.push_str(" "),
+            "p" | "blockquote" | "pre" | "table" => self.push_break(2),
+            "li" => self.push_break(1),
+            _ if BLOCK_ELEMENTS.contains(&name.as_str()) => self.push_break(1),
+            _ => {}
+        }
+    }
+}
+
+/// Index of the `>` closing the tag that starts at `html[0] == '<'`, skipping
+/// over quoted attribute values.
+fn tag_end(html: &str) -> Option<usize> {
+    let mut quote = None;
+    for (idx, ch) in html.char_indices().skip(1) {
+        match (quote, ch) {
+            (Some(open), ch) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '>') => return Some(idx),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn heading_level(name: &str) -> Option<usize> {
+    match name {
+        "h1" => Some(1),
+        "h2" => Some(2),
+        "h3" => Some(3),
+        "h4" => Some(4),
+        "h5" => Some(5),
+        "h6" => Some(6),
+        _ => None,
+    }
+}
+
+/// Collapses runs of spaces within lines, trims lines, and keeps at most one
+/// blank line between paragraphs.
+fn normalize_whitespace(text: &str) -> String {
+    let mut out = String::new();
+    let mut blank = false;
+    for line in text.split('\n') {
+        let line = collapse_spaces(line);
+        if line.is_empty() || line == "-" {
+            blank = true;
+            continue;
+        }
+        if !out.is_empty() {
+            out.push_str(if blank { "\n\n" } else { "\n" });
+        }
+        out.push_str(&line.replace('\u{a0}', " "));
+        blank = false;
+    }
+    out
+}
+
+fn collapse_spaces(line: &str) -> String {
+    line.split([' ', '\t', '\r'])
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end]).map(|ch| (ch, end)));
+        match decoded {
+            Some((ch, end)) => {
+                out.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(number) = entity.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    let ch = match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "middot" => '·',
+        "bull" => '•',
+        _ => return None,
+    };
+    Some(ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn extracts_title_and_structure_without_page_chrome() {
+        let html = r#"<!DOCTYPE html>
+<html>
+<head>
+  <title>Release notes &amp; changes</title>
+  <style>body { color: red; }</style>
+  <script>console.log("<p>not text</p>");</script>
+</head>
+<body>
+  <nav><a href="/">Home</a> <a href="/docs">Docs</a></nav>
+  <h1>Version 2.0</h1>
+  <p>This release   adds <b>streaming</b> and
+     fixes&nbsp;bugs.</p>
+  <!-- <p>hidden</p> -->
+  <ul>
+    <li>Faster startup</li>
+    <li>Smaller binary &lt;5&nbsp;MB&gt;</li>
+  </ul>
+  <pre>fn main() {
+    run();
+}</pre>
+  <footer>Copyright &copy; Example</footer>
+</body>
+</html>"#;
+        assert_eq!(
+            extract_readable_text(html),
+            ReadablePage {
+                title: Some("Release notes & changes".to_string()),
+                text: "# Version 2.0\n\nThis release adds streaming and fixes bugs.\n\n- Faster startup\n- Smaller binary <5 MB>\n\nfn main() {\n    run();\n}".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn prefers_article_content_when_substantial() {
+        let paragraph = "Readable article text. ".repeat(12);
+        let html = format!(
+            "<body><div class=\"sidebar\">Related links</div><article><h2>Intro</h2><p>{paragraph}</p></article></body>"
+        );
+        let page = extract_readable_text(&html);
+        assert_eq!(page.title, None);
+        assert_eq!(page.text, format!("## Intro\n\n{}", paragraph.trim_end()));
+    }
+
+    #[test]
+    fn falls_back_to_first_heading_for_title_and_keeps_unknown_entities() {
+        let html = "<h1 class='x>y'>Guide</h1><p>a &unknown; b &#x41;&#66;</p><p>unterminated <b";
+        assert_eq!(
+            extract_readable_text(html),
+            ReadablePage {
+                title: Some("Guide".to_string()),
+                text: "# Guide\n\na &unknown; b AB\n\nunterminated <b".to_string(),
+            }
+        );
+    }
+}
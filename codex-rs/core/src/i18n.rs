@@ -1,13 +1,25 @@
 use codex_protocol::config_types::Language;
 use once_cell::sync::Lazy;
-#[cfg(test)]
 use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::OnceLock;
 use toml::Value;
 
+/// Directory under `CODEX_HOME` holding `<lang>.toml` translation overrides.
+pub const LOCALES_DIR: &str = "locales";
+
 static EN_CATALOG: Lazy<Catalog> = Lazy::new(|| Catalog::from_str(include_str!("../i18n/en.toml")));
 static ZH_CATALOG: Lazy<Catalog> =
     Lazy::new(|| Catalog::from_str(include_str!("../i18n/zh-cn.toml")));
+static JA_CATALOG: Lazy<Catalog> = Lazy::new(|| Catalog::from_str(include_str!("../i18n/ja.toml")));
+static KO_CATALOG: Lazy<Catalog> = Lazy::new(|| Catalog::from_str(include_str!("../i18n/ko.toml")));
+static DE_CATALOG: Lazy<Catalog> = Lazy::new(|| Catalog::from_str(include_str!("../i18n/de.toml")));
+static FR_CATALOG: Lazy<Catalog> = Lazy::new(|| Catalog::from_str(include_str!("../i18n/fr.toml")));
+static ES_CATALOG: Lazy<Catalog> = Lazy::new(|| Catalog::from_str(include_str!("../i18n/es.toml")));
+static OVERRIDE_CATALOGS: OnceLock<HashMap<Language, Catalog>> = OnceLock::new();
 
 pub fn tr(language: Language, key: &'static str) -> &'static str {
     lookup(language, key).unwrap_or(key)
@@ -19,10 +31,11 @@ pub fn tr_args(language: Language, key: &'static str, args: &[(&str, &str)]) ->
 }
 
 pub fn tr_list(language: Language, key: &'static str) -> &'static [String] {
-    if let Some(list) = lookup_list(language, key) {
-        return list;
-    }
-    if let Some(list) = lookup_list(Language::En, key) {
+    if let Some(list) = fallback_chain(language).iter().find_map(|language| {
+        override_catalog(*language)
+            .and_then(|catalog| catalog.lists.get(key))
+            .or_else(|| catalog(*language).lists.get(key))
+    }) {
         return list;
     }
     static EMPTY: Lazy<Vec<String>> = Lazy::new(Vec::new);
@@ -33,29 +46,58 @@ pub fn language_name(ui_language: Language, target: Language) -> &'static str {
     match target {
         Language::En => tr(ui_language, "language.name.en"),
         Language::ZhCn => tr(ui_language, "language.name.zh_cn"),
+        Language::Ja => tr(ui_language, "language.name.ja"),
+        Language::Ko => tr(ui_language, "language.name.ko"),
+        Language::De => tr(ui_language, "language.name.de"),
+        Language::Fr => tr(ui_language, "language.name.fr"),
+        Language::Es => tr(ui_language, "language.name.es"),
+    }
+}
+
+/// Catalogs consulted, in order, when resolving a key for `language`.
+///
+/// English is the complete reference catalog and always comes last, so a
+/// partially translated locale degrades to English one key at a time.
+pub fn fallback_chain(language: Language) -> &'static [Language] {
+    match language {
+        Language::En => &[Language::En],
+        Language::ZhCn => &[Language::ZhCn, Language::En],
+        Language::Ja => &[Language::Ja, Language::En],
+        Language::Ko => &[Language::Ko, Language::En],
+        Language::De => &[Language::De, Language::En],
+        Language::Fr => &[Language::Fr, Language::En],
+        Language::Es => &[Language::Es, Language::En],
     }
 }
 
 fn lookup(language: Language, key: &'static str) -> Option<&'static str> {
-    catalog(language)
-        .strings
-        .get(key)
+    fallback_chain(language)
+        .iter()
+        .find_map(|language| {
+            override_catalog(*language)
+                .and_then(|catalog| catalog.strings.get(key))
+                .or_else(|| catalog(*language).strings.get(key))
+        })
         .map(String::as_str)
-        .or_else(|| catalog(Language::En).strings.get(key).map(String::as_str))
 }
 
-fn lookup_list(language: Language, key: &'static str) -> Option<&'static [String]> {
-    catalog(language).lists.get(key).map(Vec::as_slice)
+fn override_catalog(language: Language) -> Option<&'static Catalog> {
+    OVERRIDE_CATALOGS.get()?.get(&language)
 }
 
 fn catalog(language: Language) -> &'static Catalog {
     match language {
         Language::En => &EN_CATALOG,
         Language::ZhCn => &ZH_CATALOG,
+        Language::Ja => &JA_CATALOG,
+        Language::Ko => &KO_CATALOG,
+        Language::De => &DE_CATALOG,
+        Language::Fr => &FR_CATALOG,
+        Language::Es => &ES_CATALOG,
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 struct Catalog {
     strings: HashMap<String, String>,
     lists: HashMap<String, Vec<String>>,
@@ -63,14 +105,14 @@ struct Catalog {
 
 impl Catalog {
     fn from_str(raw: &str) -> Self {
-        let value: Value =
-            toml::from_str(raw).unwrap_or_else(|err| panic!("i18n TOML must parse: {err}"));
-        let mut catalog = Catalog {
-            strings: HashMap::new(),
-            lists: HashMap::new(),
-        };
-        flatten(None, &value, &mut catalog);
-        catalog
+        Self::parse(raw).unwrap_or_else(|err| panic!("i18n TOML must parse: {err}"))
+    }
+
+    fn parse(raw: &str) -> Result<Self, String> {
+        let value: Value = toml::from_str(raw).map_err(|err| err.to_string())?;
+        let mut catalog = Catalog::default();
+        flatten(None, &value, &mut catalog)?;
+        Ok(catalog)
     }
 
     #[cfg(test)]
@@ -83,7 +125,7 @@ impl Catalog {
     }
 }
 
-fn flatten(prefix: Option<&str>, value: &Value, catalog: &mut Catalog) {
+fn flatten(prefix: Option<&str>, value: &Value, catalog: &mut Catalog) -> Result<(), String> {
     match value {
         Value::Table(table) => {
             for (key, entry) in table {
@@ -91,46 +133,239 @@ fn flatten(prefix: Option<&str>, value: &Value, catalog: &mut Catalog) {
                     Some(prefix) => format!("{prefix}.{key}"),
                     None => key.clone(),
                 };
-                flatten(Some(&next), entry, catalog);
+                flatten(Some(&next), entry, catalog)?;
             }
+            Ok(())
         }
         Value::String(text) => {
-            if let Some(prefix) = prefix {
-                insert_string(prefix, text, catalog);
-            } else {
-                panic!("i18n values must be keyed");
-            }
+            let prefix = prefix.ok_or("i18n values must be keyed")?;
+            insert_string(prefix, text, catalog)
         }
         Value::Array(values) => {
-            if let Some(prefix) = prefix {
-                let mut items = Vec::with_capacity(values.len());
-                for entry in values {
-                    match entry {
-                        Value::String(text) => items.push(text.clone()),
-                        _ => panic!("i18n list values must be strings"),
-                    }
+            let prefix = prefix.ok_or("i18n lists must be keyed")?;
+            let mut items = Vec::with_capacity(values.len());
+            for entry in values {
+                match entry {
+                    Value::String(text) => items.push(text.clone()),
+                    _ => return Err(format!("{prefix}: i18n list values must be strings")),
                 }
-                insert_list(prefix, items, catalog);
-            } else {
-                panic!("i18n lists must be keyed");
             }
+            insert_list(prefix, items, catalog)
         }
-        _ => panic!("unsupported i18n value type"),
+        _ => Err(format!(
+            "{}: unsupported i18n value type",
+            prefix.unwrap_or_default()
+        )),
     }
 }
 
-fn insert_string(key: &str, value: &str, catalog: &mut Catalog) {
+fn insert_string(key: &str, value: &str, catalog: &mut Catalog) -> Result<(), String> {
     let previous = catalog.strings.insert(key.to_string(), value.to_string());
     if previous.is_some() {
-        panic!("duplicate i18n key: {key}");
+        return Err(format!("duplicate i18n key: {key}"));
     }
+    Ok(())
 }
 
-fn insert_list(key: &str, value: Vec<String>, catalog: &mut Catalog) {
+fn insert_list(key: &str, value: Vec<String>, catalog: &mut Catalog) -> Result<(), String> {
     let previous = catalog.lists.insert(key.to_string(), value);
     if previous.is_some() {
-        panic!("duplicate i18n list key: {key}");
+        return Err(format!("duplicate i18n list key: {key}"));
     }
+    Ok(())
+}
+
+/// A problem found in a locale catalog or override file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocaleIssue {
+    /// Override file the issue was found in; `None` for built-in catalogs.
+    pub path: Option<PathBuf>,
+    pub language: Option<Language>,
+    pub key: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for LocaleIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.path, self.language) {
+            (Some(path), _) => write!(f, "{}", path.display())?,
+            (None, Some(language)) => write!(f, "built-in {language} catalog")?,
+            (None, None) => write!(f, "locale catalog")?,
+        }
+        if let Some(key) = &self.key {
+            write!(f, " [{key}]")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Reads `<codex_home>/locales/<lang>.toml` override files and activates them
+/// for the rest of the process. Invalid entries are skipped and reported.
+///
+/// Only the first call takes effect; overrides are meant to be installed once
+/// at startup, before any UI is drawn.
+pub fn install_locale_overrides(codex_home: &Path) -> Vec<LocaleIssue> {
+    let (catalogs, issues) = load_locale_overrides(codex_home);
+    let _ = OVERRIDE_CATALOGS.set(catalogs);
+    issues
+}
+
+/// Checks built-in catalogs and `<codex_home>/locales/*.toml` overrides for
+/// unknown keys, type mismatches, and placeholders that differ from English.
+pub fn lint_locales(codex_home: &Path) -> Vec<LocaleIssue> {
+    let mut issues = Vec::new();
+    for language in Language::ALL {
+        if language == Language::En {
+            continue;
+        }
+        issues.extend(
+            validate_catalog(catalog(language), language, None)
+                .into_iter()
+                .map(|(_, issue)| issue),
+        );
+    }
+    issues.extend(load_locale_overrides(codex_home).1);
+    issues
+}
+
+fn load_locale_overrides(codex_home: &Path) -> (HashMap<Language, Catalog>, Vec<LocaleIssue>) {
+    let dir = codex_home.join(LOCALES_DIR);
+    let mut catalogs = HashMap::new();
+    let mut issues = Vec::new();
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return (catalogs, issues);
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let issue = |message: String| LocaleIssue {
+            path: Some(path.clone()),
+            language: None,
+            key: None,
+            message,
+        };
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        let Some(language) = Language::ALL
+            .into_iter()
+            .find(|language| language.to_string() == stem)
+        else {
+            let known = Language::ALL
+                .map(|language| language.to_string())
+                .join(", ");
+            issues.push(issue(format!(
+                "unknown locale `{stem}`; expected one of: {known}"
+            )));
+            continue;
+        };
+        let raw = match std::fs::read_to_string(&path) {
+            Ok(raw) => raw,
+            Err(err) => {
+                issues.push(issue(format!("failed to read: {err}")));
+                continue;
+            }
+        };
+        let mut overrides = match Catalog::parse(&raw) {
+            Ok(overrides) => overrides,
+            Err(err) => {
+                issues.push(issue(format!("failed to parse: {err}")));
+                continue;
+            }
+        };
+        for (key, issue) in validate_catalog(&overrides, language, Some(&path)) {
+            overrides.strings.remove(&key);
+            overrides.lists.remove(&key);
+            issues.push(issue);
+        }
+        catalogs.insert(language, overrides);
+    }
+
+    (catalogs, issues)
+}
+
+/// Returns the keys of `candidate` that cannot be used, paired with why.
+fn validate_catalog(
+    candidate: &Catalog,
+    language: Language,
+    path: Option<&Path>,
+) -> Vec<(String, LocaleIssue)> {
+    let issue = |key: &str, message: String| {
+        (
+            key.to_string(),
+            LocaleIssue {
+                path: path.map(Path::to_path_buf),
+                language: Some(language),
+                key: Some(key.to_string()),
+                message,
+            },
+        )
+    };
+    let mut issues = Vec::new();
+    let mut keys: Vec<&String> = candidate.strings.keys().collect();
+    keys.sort();
+    for key in keys {
+        let value = &candidate.strings[key];
+        match EN_CATALOG.strings.get(key) {
+            Some(reference) => {
+                // Translations may drop placeholders (e.g. English plural
+                // suffixes) but must not invent ones the caller never passes.
+                let expected = placeholders(reference);
+                let unknown: BTreeSet<&str> =
+                    placeholders(value).difference(&expected).copied().collect();
+                if !unknown.is_empty() {
+                    issues.push(issue(
+                        key,
+                        format!(
+                            "placeholders {} are not in English (available: {})",
+                            format_placeholders(&unknown),
+                            format_placeholders(&expected)
+                        ),
+                    ));
+                }
+            }
+            None if EN_CATALOG.lists.contains_key(key) => {
+                issues.push(issue(key, "expected a list of strings".to_string()));
+            }
+            None => issues.push(issue(key, "unknown key".to_string())),
+        }
+    }
+    let mut list_keys: Vec<&String> = candidate.lists.keys().collect();
+    list_keys.sort();
+    for key in list_keys {
+        if EN_CATALOG.strings.contains_key(key) {
+            issues.push(issue(key, "expected a string".to_string()));
+        } else if !EN_CATALOG.lists.contains_key(key) {
+            issues.push(issue(key, "unknown key".to_string()));
+        }
+    }
+    issues
+}
+
+fn placeholders(template: &str) -> BTreeSet<&str> {
+    template
+        .split("${")
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+fn format_placeholders(names: &BTreeSet<&str>) -> String {
+    if names.is_empty() {
+        return "(none)".to_string();
+    }
+    names
+        .iter()
+        .map(|name| format!("${{{name}}}"))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 fn interpolate(template: &str, args: &[(&str, &str)]) -> String {
@@ -171,6 +406,93 @@ mod tests {
         assert_eq!(en_keys, zh_keys);
     }
 
+    #[test]
+    fn partial_catalogs_only_translate_known_keys() {
+        let en_keys = EN_CATALOG.keys();
+        for language in Language::ALL {
+            let stray: Vec<String> = catalog(language)
+                .keys()
+                .difference(&en_keys)
+                .cloned()
+                .collect();
+            assert!(
+                stray.is_empty(),
+                "{language}: keys missing from en: {stray:?}"
+            );
+            for (key, value) in &catalog(language).strings {
+                assert!(
+                    placeholders(value).is_subset(&placeholders(&EN_CATALOG.strings[key])),
+                    "{language}: unknown placeholders in {key}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn missing_keys_fall_back_to_english() {
+        assert_eq!(tr(Language::Ja, "language.name.ja"), "日本語");
+        assert_eq!(
+            tr(Language::Ja, "chatwidget.language_popup.english_desc"),
+            tr(Language::En, "chatwidget.language_popup.english_desc")
+        );
+        assert_eq!(
+            tr_list(Language::De, "chatwidget.example_prompts"),
+            tr_list(Language::En, "chatwidget.example_prompts")
+        );
+    }
+
+    #[test]
+    fn overrides_are_validated_against_english() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let dir = codex_home.path().join(LOCALES_DIR);
+        std::fs::create_dir_all(&dir).expect("mkdir");
+        std::fs::write(
+            dir.join("ja.toml"),
+            r#"
+[language.name]
+ja = "にほんご"
+
+[app.skills]
+invalid = "${count} 件のスキルを読み込めませんでした"
+
+[not_a_section]
+key = "x"
+"#,
+        )
+        .expect("write ja.toml");
+        std::fs::write(dir.join("xx.toml"), "").expect("write xx.toml");
+
+        let (catalogs, issues) = load_locale_overrides(codex_home.path());
+
+        let ja = &catalogs[&Language::Ja];
+        assert_eq!(
+            ja.strings.get("language.name.ja").map(String::as_str),
+            Some("にほんご")
+        );
+        assert!(!ja.strings.contains_key("app.skills.invalid"));
+        assert!(!ja.strings.contains_key("not_a_section.key"));
+        let messages: Vec<String> = issues
+            .iter()
+            .map(|issue| format!("{:?}: {}", issue.key, issue.message))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Some(\"app.skills.invalid\"): placeholders ${count} are not in English (available: ${error_count})"
+                    .to_string(),
+                "Some(\"not_a_section.key\"): unknown key".to_string(),
+                "None: unknown locale `xx`; expected one of: en, zh-cn, ja, ko, de, fr, es"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn built_in_catalogs_lint_clean() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        assert_eq!(lint_locales(codex_home.path()), Vec::new());
+    }
+
     #[test]
     fn interpolate_replaces_placeholders() {
         let rendered = interpolate("Hello ${name}", &[("name", "World")]);
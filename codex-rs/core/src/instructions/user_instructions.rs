@@ -60,6 +60,13 @@ impl SkillInstructions {
             false
         }
     }
+
+    pub fn render(&self) -> String {
+        format!(
+            "<skill>\n<name>{}</name>\n<path>{}</path>\n{}\n</skill>",
+            self.name, self.path, self.contents
+        )
+    }
 }
 
 impl From<SkillInstructions> for ResponseItem {
@@ -67,12 +74,7 @@ impl From<SkillInstructions> for ResponseItem {
         ResponseItem::Message {
             id: None,
             role: "user".to_string(),
-            content: vec![ContentItem::InputText {
-                text: format!(
-                    "<skill>\n<name>{}</name>\n<path>{}</path>\n{}\n</skill>",
-                    si.name, si.path, si.contents
-                ),
-            }],
+            content: vec![ContentItem::InputText { text: si.render() }],
             end_turn: None,
             phase: None,
         }
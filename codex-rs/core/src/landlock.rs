@@ -3,6 +3,7 @@ use crate::spawn::SpawnChildRequest;
 use crate::spawn::StdioPolicy;
 use crate::spawn::spawn_child_async;
 use codex_network_proxy::NetworkProxy;
+use codex_utils_absolute_path::AbsolutePathBuf;
 use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
@@ -35,6 +36,7 @@ where
         command,
         sandbox_policy,
         sandbox_policy_cwd,
+        &[],
         use_bwrap_sandbox,
         allow_network_for_proxy(false),
     );
@@ -67,6 +69,7 @@ pub(crate) fn create_linux_sandbox_command_args(
     command: Vec<String>,
     sandbox_policy: &SandboxPolicy,
     sandbox_policy_cwd: &Path,
+    protected_paths: &[AbsolutePathBuf],
     use_bwrap_sandbox: bool,
     allow_network_for_proxy: bool,
 ) -> Vec<String> {
@@ -92,6 +95,10 @@ pub(crate) fn create_linux_sandbox_command_args(
     if allow_network_for_proxy {
         linux_cmd.push("--allow-network-for-proxy".to_string());
     }
+    for path in protected_paths {
+        linux_cmd.push("--protected-path".to_string());
+        linux_cmd.push(path.to_string_lossy().to_string());
+    }
 
     // Separator so that command arguments starting with `-` are not parsed as
     // options of the helper itself.
@@ -115,13 +122,14 @@ mod tests {
         let policy = SandboxPolicy::ReadOnly;
 
         let with_bwrap =
-            create_linux_sandbox_command_args(command.clone(), &policy, cwd, true, false);
+            create_linux_sandbox_command_args(command.clone(), &policy, cwd, &[], true, false);
         assert_eq!(
             with_bwrap.contains(&"--use-bwrap-sandbox".to_string()),
             true
         );
 
-        let without_bwrap = create_linux_sandbox_command_args(command, &policy, cwd, false, false);
+        let without_bwrap =
+            create_linux_sandbox_command_args(command, &policy, cwd, &[], false, false);
         assert_eq!(
             without_bwrap.contains(&"--use-bwrap-sandbox".to_string()),
             false
@@ -134,13 +142,30 @@ mod tests {
         let cwd = Path::new("/tmp");
         let policy = SandboxPolicy::ReadOnly;
 
-        let args = create_linux_sandbox_command_args(command, &policy, cwd, true, true);
+        let args = create_linux_sandbox_command_args(command, &policy, cwd, &[], true, true);
         assert_eq!(
             args.contains(&"--allow-network-for-proxy".to_string()),
             true
         );
     }
 
+    #[test]
+    fn protected_paths_are_passed_before_the_command() {
+        let command = vec!["/bin/true".to_string()];
+        let cwd = Path::new("/tmp");
+        let policy = SandboxPolicy::new_workspace_write_policy();
+        let protected =
+            vec![AbsolutePathBuf::from_absolute_path("/tmp/.env").expect("absolute path")];
+
+        let args =
+            create_linux_sandbox_command_args(command, &policy, cwd, &protected, true, false);
+        let separator = args.iter().position(|arg| arg == "--").expect("separator");
+        assert_eq!(
+            args[separator - 2..separator].to_vec(),
+            vec!["--protected-path".to_string(), "/tmp/.env".to_string()]
+        );
+    }
+
     #[test]
     fn proxy_network_requires_managed_requirements() {
         assert_eq!(allow_network_for_proxy(false), false);
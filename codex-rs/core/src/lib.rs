@@ -10,6 +10,7 @@ pub mod api_bridge;
 mod apply_patch;
 mod apps;
 pub mod auth;
+pub mod base_instructions;
 mod client;
 mod client_common;
 pub mod codex;
@@ -24,8 +25,18 @@ mod command_canonicalization;
 pub mod config;
 pub mod config_loader;
 pub mod connectors;
+mod context_inspector;
 mod context_manager;
 pub mod custom_prompts;
+pub mod danger_audit;
+mod dry_run;
+mod edit_limits;
+mod embedded_agent;
+pub use embedded_agent::Agent;
+pub use embedded_agent::AgentOptions;
+pub use embedded_agent::AgentThread;
+pub use embedded_agent::ApprovalRequest;
+pub use embedded_agent::TurnOutcome;
 pub mod env;
 mod environment_context;
 pub mod error;
@@ -36,9 +47,11 @@ pub mod features;
 mod file_watcher;
 mod flags;
 pub mod git_info;
+mod html_text;
 pub mod i18n;
 pub mod instructions;
 pub mod landlock;
+pub mod locale_format;
 pub mod mcp;
 mod mcp_connection_manager;
 pub mod models_manager;
@@ -51,12 +64,19 @@ mod memories;
 mod mentions;
 mod message_history;
 mod model_provider_info;
+pub mod patch_hunks;
 pub mod path_utils;
 pub mod personality_migration;
+pub mod personas;
 mod proposed_plan_parser;
+pub mod protected_paths;
+pub mod provenance;
+pub mod sandbox_explain;
 mod sandbox_tags;
 pub mod sandboxing;
 mod session_prefix;
+pub mod session_status;
+pub mod session_sync;
 mod shell_detect;
 mod stream_events_utils;
 mod tagged_block_parser;
@@ -64,6 +84,7 @@ mod text_encoding;
 pub mod token_data;
 mod truncate;
 mod unified_exec;
+pub mod usage_cache;
 pub mod windows_sandbox;
 pub use client::X_RESPONSESAPI_INCLUDE_TIMING_METRICS_HEADER;
 pub use model_provider_info::DEFAULT_LMSTUDIO_PORT;
@@ -75,10 +96,18 @@ pub use model_provider_info::WireApi;
 pub use model_provider_info::built_in_model_providers;
 pub use model_provider_info::create_oss_provider_with_base_url;
 mod event_mapping;
+pub mod review_annotations;
 pub mod review_format;
 pub mod review_prompts;
+pub mod review_rubric;
+mod session_note;
+mod session_summary;
 mod thread_manager;
+mod thread_title;
+mod web_cache;
 pub mod web_search;
+mod web_search_provider;
+pub mod workspace_packages;
 pub use codex_protocol::protocol::InitialHistory;
 pub use thread_manager::NewThread;
 pub use thread_manager::ThreadManager;
@@ -91,10 +120,12 @@ pub type CodexConversation = CodexThread;
 // Re-export common auth types for workspace consumers
 pub use auth::AuthManager;
 pub use auth::CodexAuth;
+pub mod chat_notifications;
 pub mod default_client;
 pub mod project_doc;
 mod rollout;
 pub(crate) mod safety;
+pub mod schedules;
 pub mod seatbelt;
 pub mod shell;
 pub mod shell_snapshot;
@@ -111,6 +142,23 @@ pub use rollout::RolloutRecorder;
 pub use rollout::RolloutRecorderParams;
 pub use rollout::SESSIONS_SUBDIR;
 pub use rollout::SessionMeta;
+pub use rollout::archive::archive_session;
+pub use rollout::archive::delete_session;
+pub use rollout::archive::restore_session;
+pub use rollout::audit_log::AuditEntry;
+pub use rollout::audit_log::load_audit_log;
+pub use rollout::branches::BranchNode;
+pub use rollout::branches::BranchTurn;
+pub use rollout::branches::find_branch_tree;
+pub use rollout::branches::load_branch_turns;
+pub use rollout::branches::shared_turn_count;
+pub use rollout::bundle::ImportConflict;
+pub use rollout::bundle::SESSION_BUNDLE_EXTENSION;
+pub use rollout::bundle::SessionBundleExport;
+pub use rollout::bundle::SessionBundleImport;
+pub use rollout::bundle::SessionBundleImportOptions;
+pub use rollout::bundle::export_session_bundle;
+pub use rollout::bundle::import_session_bundle;
 pub use rollout::find_archived_thread_path_by_id_str;
 #[deprecated(note = "use find_thread_path_by_id_str")]
 pub use rollout::find_conversation_path_by_id_str;
@@ -124,11 +172,25 @@ pub use rollout::list::ThreadsPage;
 pub use rollout::list::parse_cursor;
 pub use rollout::list::read_head_for_summary;
 pub use rollout::list::read_session_meta_line;
+pub use rollout::patch_history::FilePatchComparison;
+pub use rollout::patch_history::PatchComparison;
+pub use rollout::patch_history::SessionPatches;
+pub use rollout::patch_history::compare_session_patches;
+pub use rollout::patch_history::load_session_patches;
+pub use rollout::patch_history::side_by_side_patch_diff;
+pub use rollout::patch_history::unified_patch_diff;
+pub use rollout::preview::SessionPreview;
+pub use rollout::preview::load_session_preview;
+pub use rollout::recovery::RolloutRecovery;
+pub use rollout::recovery::rollout_lost_events;
 pub use rollout::rollout_date_parts;
 pub use rollout::session_index::find_thread_names_by_ids;
+pub use rollout::session_lock::SessionActiveError;
+pub use rollout::session_lock::SessionLockHolder;
 mod function_tool;
 mod state;
 mod tasks;
+mod usage_reporting;
 mod user_shell_command;
 pub mod util;
 pub use codex_shell_command::bash;
@@ -145,6 +207,7 @@ pub use exec_policy::load_exec_policy;
 pub use file_watcher::FileWatcherEvent;
 pub use safety::get_platform_sandbox;
 pub use tools::spec::parse_tool_input_schema;
+pub use truncate::approx_token_count;
 pub use turn_metadata::build_turn_metadata_header;
 #[cfg(any(test, feature = "test-support"))]
 pub use unified_exec::set_deterministic_process_ids_for_tests;
@@ -0,0 +1,110 @@
+//! Language-aware formatting for durations, dates, and numbers shown in the UI.
+//!
+//! Templates and month names live in the `format.*` catalog keys so each
+//! language controls word order; digit grouping comes from
+//! [`codex_protocol::num_format`], which follows the same [`Language`].
+
+use chrono::Datelike;
+use chrono::NaiveDate;
+use codex_protocol::config_types::Language;
+use codex_protocol::num_format::format_with_separators_for;
+
+use crate::i18n::tr;
+use crate::i18n::tr_args;
+use crate::i18n::tr_list;
+
+const MINUTES_PER_HOUR: i64 = 60;
+const MINUTES_PER_DAY: i64 = 24 * MINUTES_PER_HOUR;
+const MINUTES_PER_WEEK: i64 = 7 * MINUTES_PER_DAY;
+const MINUTES_PER_MONTH: i64 = 30 * MINUTES_PER_DAY;
+const ROUNDING_BIAS_MINUTES: i64 = 3;
+
+/// Labels a rate-limit window length, e.g. "5h" or "weekly" in English.
+pub fn format_limit_window(language: Language, window_minutes: i64) -> String {
+    let window_minutes = window_minutes.max(0);
+
+    if window_minutes <= MINUTES_PER_DAY.saturating_add(ROUNDING_BIAS_MINUTES) {
+        let adjusted = window_minutes.saturating_add(ROUNDING_BIAS_MINUTES);
+        let hours = std::cmp::max(1, adjusted / MINUTES_PER_HOUR);
+        tr_args(
+            language,
+            "format.limit_window.hours",
+            &[("count", &hours.to_string())],
+        )
+    } else if window_minutes <= MINUTES_PER_WEEK.saturating_add(ROUNDING_BIAS_MINUTES) {
+        tr(language, "format.limit_window.weekly").to_string()
+    } else if window_minutes <= MINUTES_PER_MONTH.saturating_add(ROUNDING_BIAS_MINUTES) {
+        tr(language, "format.limit_window.monthly").to_string()
+    } else {
+        tr(language, "format.limit_window.annual").to_string()
+    }
+}
+
+/// Formats a count with the language's digit grouping ("12,345" / "12.345").
+pub fn format_count(language: Language, value: i64) -> String {
+    format_with_separators_for(value, language)
+}
+
+/// Short day-and-month form, e.g. "4 Mar" or "3月4日".
+pub fn format_month_day(language: Language, date: NaiveDate) -> String {
+    format_with_date(language, "format.date.month_day", date)
+}
+
+/// Full date including the year, e.g. "Mar 4, 2026" or "2026年3月4日".
+pub fn format_date(language: Language, date: NaiveDate) -> String {
+    format_with_date(language, "format.date.full", date)
+}
+
+/// Combines an already formatted time and date, e.g. "14:05 on 4 Mar".
+pub fn format_time_on_date(language: Language, time: &str, date: &str) -> String {
+    tr_args(
+        language,
+        "format.date.time_on_date",
+        &[("time", time), ("date", date)],
+    )
+}
+
+fn format_with_date(language: Language, key: &'static str, date: NaiveDate) -> String {
+    let month_name = tr_list(language, "format.date.months")
+        .get(date.month0() as usize)
+        .cloned()
+        .unwrap_or_else(|| date.month().to_string());
+    tr_args(
+        language,
+        key,
+        &[
+            ("day", &date.day().to_string()),
+            ("month_name", &month_name),
+            ("year", &date.year().to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn limit_windows_follow_language() {
+        assert_eq!(format_limit_window(Language::En, 300), "5h");
+        assert_eq!(format_limit_window(Language::En, 7 * 24 * 60), "weekly");
+        assert_eq!(format_limit_window(Language::ZhCn, 300), "5 小时");
+        assert_eq!(format_limit_window(Language::De, 30 * 24 * 60), "monatlich");
+        assert_eq!(format_limit_window(Language::Ja, 400 * 24 * 60), "年間");
+    }
+
+    #[test]
+    fn dates_follow_language_word_order() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 4).expect("valid date");
+        assert_eq!(format_month_day(Language::En, date), "4 Mar");
+        assert_eq!(format_date(Language::En, date), "Mar 4, 2026");
+        assert_eq!(format_date(Language::ZhCn, date), "2026年3月4日");
+        assert_eq!(format_date(Language::Ko, date), "2026년 3월 4일");
+        assert_eq!(format_date(Language::De, date), "4. März 2026");
+        assert_eq!(
+            format_time_on_date(Language::Fr, "14:05", &format_month_day(Language::Fr, date)),
+            "14:05 le 4 mars"
+        );
+    }
+}
@@ -126,7 +126,8 @@ pub(super) fn filter_rollout_response_items(
             | RolloutItem::TurnContext(_)
             | RolloutItem::EventMsg(_)
             | RolloutItem::ResponseItem(_)
-            | RolloutItem::Compacted(_) => {}
+            | RolloutItem::Compacted(_)
+            | RolloutItem::ExecProvenance(_) => {}
         }
 
         if let Some(limit) = filter.max_items
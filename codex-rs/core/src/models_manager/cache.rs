@@ -28,6 +28,16 @@ impl ModelsCacheManager {
 
     /// Attempt to load a fresh cache entry. Returns `None` if the cache doesn't exist or is stale.
     pub(crate) async fn load_fresh(&self, expected_version: &str) -> Option<ModelsCache> {
+        let cache = self.load_stale(expected_version).await?;
+        if !cache.is_fresh(self.cache_ttl) {
+            return None;
+        }
+        Some(cache)
+    }
+
+    /// Load the cache entry written by this client version regardless of its
+    /// age, for when the models endpoint cannot be reached.
+    pub(crate) async fn load_stale(&self, expected_version: &str) -> Option<ModelsCache> {
         let cache = match self.load().await {
             Ok(cache) => cache?,
             Err(err) => {
@@ -38,9 +48,6 @@ impl ModelsCacheManager {
         if cache.client_version.as_deref() != Some(expected_version) {
             return None;
         }
-        if !cache.is_fresh(self.cache_ttl) {
-            return None;
-        }
         Some(cache)
     }
 
@@ -15,6 +15,7 @@ use crate::models_manager::model_presets::builtin_model_presets;
 use codex_api::ModelsClient;
 use codex_api::ReqwestTransport;
 use codex_protocol::config_types::CollaborationModeMask;
+use codex_protocol::openai_models::ModelCapabilities;
 use codex_protocol::openai_models::ModelInfo;
 use codex_protocol::openai_models::ModelPreset;
 use codex_protocol::openai_models::ModelsResponse;
@@ -26,6 +27,7 @@ use tokio::sync::RwLock;
 use tokio::sync::TryLockError;
 use tokio::time::timeout;
 use tracing::error;
+use tracing::warn;
 
 const MODEL_CACHE_FILE: &str = "models_cache.json";
 const DEFAULT_MODEL_CACHE_TTL: Duration = Duration::from_secs(300);
@@ -147,26 +149,31 @@ impl ModelsManager {
         model_info::with_config_overrides(model, config)
     }
 
+    /// Capability table entry for `model`: vision, parallel tool calls, reasoning
+    /// efforts, and context window.
+    pub async fn get_model_capabilities(&self, model: &str, config: &Config) -> ModelCapabilities {
+        self.get_model_info(model, config).await.capabilities()
+    }
+
+    /// Attempt to look up capabilities without blocking, using the current cached state.
+    ///
+    /// Returns an error if the internal lock cannot be acquired.
+    pub fn try_get_model_capabilities(
+        &self,
+        model: &str,
+        config: &Config,
+    ) -> Result<ModelCapabilities, TryLockError> {
+        let remote = longest_prefix_match(self.try_get_remote_models(config)?, model);
+        let info = remote.unwrap_or_else(|| model_info::model_info_from_slug(model));
+        Ok(model_info::with_config_overrides(info, config).capabilities())
+    }
+
     async fn find_remote_model_by_longest_prefix(
         &self,
         model: &str,
         config: &Config,
     ) -> Option<ModelInfo> {
-        let mut best: Option<ModelInfo> = None;
-        for candidate in self.get_remote_models(config).await {
-            if !model.starts_with(&candidate.slug) {
-                continue;
-            }
-            let is_better_match = if let Some(current) = best.as_ref() {
-                candidate.slug.len() > current.slug.len()
-            } else {
-                true
-            };
-            if is_better_match {
-                best = Some(candidate);
-            }
-        }
-        best
+        longest_prefix_match(self.get_remote_models(config).await, model)
     }
 
     /// Refresh models if the provided ETag differs from the cached ETag.
@@ -202,31 +209,44 @@ impl ModelsManager {
                 refresh_strategy,
                 RefreshStrategy::Offline | RefreshStrategy::OnlineIfUncached
             ) {
-                self.try_load_cache().await;
+                self.try_load_cache(false).await;
             }
             return Ok(());
         }
 
         match refresh_strategy {
             RefreshStrategy::Offline => {
-                // Only try to load from cache, never fetch
-                self.try_load_cache().await;
+                // Only try to load from cache, never fetch; an expired entry
+                // is still better than the bundled catalog.
+                if !self.try_load_cache(false).await {
+                    self.try_load_cache(true).await;
+                }
                 Ok(())
             }
             RefreshStrategy::OnlineIfUncached => {
                 // Try cache first, fall back to online if unavailable
-                if self.try_load_cache().await {
+                if self.try_load_cache(false).await {
                     return Ok(());
                 }
-                self.fetch_and_update_models().await
+                self.fetch_or_load_stale_cache().await
             }
             RefreshStrategy::Online => {
                 // Always fetch from network
-                self.fetch_and_update_models().await
+                self.fetch_or_load_stale_cache().await
             }
         }
     }
 
+    /// Fetch the models list, serving the expired cache entry when the
+    /// endpoint cannot be reached. The fetch error is still returned.
+    async fn fetch_or_load_stale_cache(&self) -> CoreResult<()> {
+        let result = self.fetch_and_update_models().await;
+        if result.is_err() && self.try_load_cache(true).await {
+            warn!("models endpoint unreachable; using the cached models list");
+        }
+        result
+    }
+
     async fn fetch_and_update_models(&self) -> CoreResult<()> {
         let _timer =
             codex_otel::start_global_timer("codex.remote_models.fetch_update.duration_ms", &[]);
@@ -281,13 +301,18 @@ impl ModelsManager {
     }
 
     /// Attempt to satisfy the refresh from the cache when it matches the provider and TTL.
-    async fn try_load_cache(&self) -> bool {
+    /// With `allow_stale`, an entry past its TTL is accepted too.
+    async fn try_load_cache(&self, allow_stale: bool) -> bool {
         let _timer =
             codex_otel::start_global_timer("codex.remote_models.load_cache.duration_ms", &[]);
         let client_version = crate::models_manager::client_version_to_whole();
-        let cache = match self.cache_manager.load_fresh(&client_version).await {
-            Some(cache) => cache,
-            None => return false,
+        let cache = if allow_stale {
+            self.cache_manager.load_stale(&client_version).await
+        } else {
+            self.cache_manager.load_fresh(&client_version).await
+        };
+        let Some(cache) = cache else {
+            return false;
         };
         let models = cache.models.clone();
         *self.etag.write().await = cache.etag.clone();
@@ -377,6 +402,25 @@ impl ModelsManager {
     }
 }
 
+/// Pick the candidate whose slug is the longest prefix of `model`.
+fn longest_prefix_match(candidates: Vec<ModelInfo>, model: &str) -> Option<ModelInfo> {
+    let mut best: Option<ModelInfo> = None;
+    for candidate in candidates {
+        if !model.starts_with(&candidate.slug) {
+            continue;
+        }
+        let is_better_match = if let Some(current) = best.as_ref() {
+            candidate.slug.len() > current.slug.len()
+        } else {
+            true
+        };
+        if is_better_match {
+            best = Some(candidate);
+        }
+    }
+    best
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,7 +430,9 @@ mod tests {
     use crate::features::Feature;
     use crate::model_provider_info::WireApi;
     use chrono::Utc;
+    use codex_protocol::openai_models::InputModality;
     use codex_protocol::openai_models::ModelsResponse;
+    use codex_protocol::openai_models::ReasoningEffort;
     use core_test_support::responses::mount_models_once;
     use pretty_assertions::assert_eq;
     use serde_json::json;
@@ -624,6 +670,61 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn refresh_available_models_serves_stale_cache_when_unreachable() {
+        let server = MockServer::start().await;
+        let cached_models = vec![remote_model("cached", "Cached", 1)];
+        mount_models_once(
+            &server,
+            ModelsResponse {
+                models: cached_models.clone(),
+            },
+        )
+        .await;
+
+        let codex_home = tempdir().expect("temp dir");
+        let mut config = ConfigBuilder::default()
+            .codex_home(codex_home.path().to_path_buf())
+            .build()
+            .await
+            .expect("load default test config");
+        config.features.enable(Feature::RemoteModels);
+        let auth_manager =
+            AuthManager::from_auth_for_testing(CodexAuth::create_dummy_chatgpt_auth_for_testing());
+        let manager = ModelsManager::with_provider(
+            codex_home.path().to_path_buf(),
+            auth_manager.clone(),
+            provider_for(server.uri()),
+        );
+        manager
+            .refresh_available_models(&config, RefreshStrategy::OnlineIfUncached)
+            .await
+            .expect("initial refresh succeeds");
+        manager
+            .cache_manager
+            .manipulate_cache_for_test(|fetched_at| {
+                *fetched_at = Utc::now() - chrono::Duration::days(3);
+            })
+            .await
+            .expect("cache manipulation succeeds");
+
+        // A new process with the endpoint down still sees the cached list.
+        server.reset().await;
+        let restarted = ModelsManager::with_provider(
+            codex_home.path().to_path_buf(),
+            auth_manager,
+            provider_for(server.uri()),
+        );
+        assert!(
+            restarted
+                .refresh_available_models(&config, RefreshStrategy::OnlineIfUncached)
+                .await
+                .is_err(),
+            "the fetch itself should still fail"
+        );
+        assert_models_contain(&restarted.get_remote_models(&config).await, &cached_models);
+    }
+
     #[tokio::test]
     async fn refresh_available_models_refetches_when_version_mismatch() {
         let server = MockServer::start().await;
@@ -827,6 +928,48 @@ mod tests {
         assert_eq!(available, vec![expected_hidden, expected_visible]);
     }
 
+    #[tokio::test]
+    async fn model_capabilities_use_longest_remote_prefix() {
+        let codex_home = tempdir().expect("temp dir");
+        let mut config = ConfigBuilder::default()
+            .codex_home(codex_home.path().to_path_buf())
+            .build()
+            .await
+            .expect("load default test config");
+        config.features.enable(Feature::RemoteModels);
+        let auth_manager =
+            AuthManager::from_auth_for_testing(CodexAuth::from_api_key("Test API Key"));
+        let provider = provider_for("http://example.test".to_string());
+        let manager =
+            ModelsManager::with_provider(codex_home.path().to_path_buf(), auth_manager, provider);
+        let mut text_only = remote_model("caps-test-mini", "Caps Mini", 1);
+        text_only.input_modalities = vec![InputModality::Text];
+        *manager.remote_models.write().await =
+            vec![remote_model("caps-test", "Caps", 0), text_only];
+
+        let capabilities = manager
+            .get_model_capabilities("caps-test-mini-2025", &config)
+            .await;
+
+        assert_eq!(
+            manager
+                .try_get_model_capabilities("caps-test-mini-2025", &config)
+                .expect("lock available"),
+            capabilities
+        );
+        assert!(!capabilities.vision);
+        assert_eq!(
+            capabilities.reasoning_efforts,
+            vec![ReasoningEffort::Low, ReasoningEffort::Medium]
+        );
+        assert!(
+            manager
+                .get_model_capabilities("caps-test-large", &config)
+                .await
+                .vision
+        );
+    }
+
     #[test]
     fn bundled_models_json_roundtrips() {
         let file_contents = include_str!("../../models.json");
@@ -0,0 +1,356 @@
+//! Hunks of a proposed patch, for clients that approve only part of it.
+//!
+//! Each added or deleted file is one hunk; an updated file has one hunk per
+//! `@@` section of its unified diff. Hunk ids are `<path>#<n>`, counting from 1
+//! within each file, and are derived from the `changes` of the approval
+//! request alone, so a client and the core agree on them without exchanging
+//! anything else.
+//!
+//! A partial approval is turned back into an `apply_patch` patch holding only
+//! the approved hunks, each with a few more lines of context than the diff
+//! carries so it lands where the hunk was. A file move is kept only when every
+//! hunk of the file is approved. Before the patch is used, it is checked to
+//! produce exactly the content the approved hunks describe; when it does not,
+//! or the file changed since the request, the call is refused rather than
+//! applied somewhere else.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_apply_patch::ApplyPatchAction;
+use codex_apply_patch::ApplyPatchFileChange;
+use codex_apply_patch::MaybeApplyPatchVerified;
+use codex_apply_patch::maybe_parse_apply_patch_verified;
+use codex_protocol::protocol::FileChange;
+
+/// Leading context lines added to each chunk of a partial patch.
+const CHUNK_CONTEXT: usize = 3;
+
+/// One independently approvable piece of a patch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchHunk {
+    pub id: String,
+    pub path: PathBuf,
+    /// Unified diff lines of the hunk; updates start with their `@@` header.
+    pub diff: String,
+}
+
+/// The hunks of `changes`, ordered by path and then by position in the file.
+pub fn split_hunks(changes: &HashMap<PathBuf, FileChange>) -> Vec<PatchHunk> {
+    let mut hunks = Vec::new();
+    for (path, change) in sorted(changes) {
+        let diffs = match change {
+            FileChange::Add { content } => vec![prefixed_lines('+', content)],
+            FileChange::Delete { content } => vec![prefixed_lines('-', content)],
+            FileChange::Update { unified_diff, .. } => update_hunks(unified_diff),
+        };
+        hunks.extend(
+            diffs
+                .into_iter()
+                .enumerate()
+                .map(|(index, diff)| PatchHunk {
+                    id: format!("{}#{}", path.display(), index + 1),
+                    path: path.clone(),
+                    diff,
+                }),
+        );
+    }
+    hunks
+}
+
+/// The `apply_patch` patch limited to the `approved` hunks of `action`, or
+/// `None` when no hunk was approved.
+pub(crate) fn partial_patch(
+    action: &ApplyPatchAction,
+    changes: &HashMap<PathBuf, FileChange>,
+    approved: &[String],
+) -> Result<Option<String>, String> {
+    let hunks = split_hunks(changes);
+    if let Some(unknown) = approved
+        .iter()
+        .find(|id| !hunks.iter().any(|hunk| &hunk.id == *id))
+    {
+        return Err(format!("unknown hunk id `{unknown}`"));
+    }
+    if hunks.iter().all(|hunk| approved.contains(&hunk.id)) {
+        return Ok(Some(action.patch.clone()));
+    }
+
+    let mut body = String::new();
+    let mut expected_updates = Vec::new();
+    for (path, change) in sorted(changes) {
+        let file_hunks: Vec<&PatchHunk> = hunks.iter().filter(|hunk| &hunk.path == path).collect();
+        let selected: Vec<&str> = file_hunks
+            .iter()
+            .filter(|hunk| approved.contains(&hunk.id))
+            .map(|hunk| hunk.diff.as_str())
+            .collect();
+        if selected.is_empty() {
+            continue;
+        }
+        match change {
+            FileChange::Add { content } => {
+                body.push_str(&format!("*** Add File: {}\n", path.display()));
+                body.push_str(&prefixed_lines('+', content));
+            }
+            FileChange::Delete { .. } => {
+                body.push_str(&format!("*** Delete File: {}\n", path.display()));
+            }
+            FileChange::Update { move_path, .. } => {
+                body.push_str(&format!("*** Update File: {}\n", path.display()));
+                if selected.len() == file_hunks.len()
+                    && let Some(dest) = move_path
+                {
+                    body.push_str(&format!("*** Move to: {}\n", dest.display()));
+                }
+                let original = std::fs::read_to_string(path)
+                    .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+                let update = apply_hunks(&original, &selected).ok_or_else(|| {
+                    format!(
+                        "the approved hunks of {} do not match the file",
+                        path.display()
+                    )
+                })?;
+                body.push_str(&update.chunks);
+                expected_updates.push((path.clone(), update.content));
+            }
+        }
+    }
+    if body.is_empty() {
+        return Ok(None);
+    }
+
+    let patch = format!("*** Begin Patch\n{body}*** End Patch\n");
+    verify(&patch, &action.cwd, &expected_updates)?;
+    Ok(Some(patch))
+}
+
+/// Check that `patch` parses and rewrites each updated file to the expected
+/// content.
+fn verify(patch: &str, cwd: &Path, expected_updates: &[(PathBuf, String)]) -> Result<(), String> {
+    let argv = vec!["apply_patch".to_string(), patch.to_string()];
+    let action = match maybe_parse_apply_patch_verified(&argv, cwd) {
+        MaybeApplyPatchVerified::Body(action) => action,
+        MaybeApplyPatchVerified::CorrectnessError(err) => {
+            return Err(format!("the approved hunks do not apply: {err}"));
+        }
+        other => return Err(format!("the approved hunks do not form a patch: {other:?}")),
+    };
+    for (path, expected) in expected_updates {
+        let applies = matches!(
+            action.changes().get(path),
+            Some(ApplyPatchFileChange::Update { new_content, .. }) if new_content == expected
+        );
+        if !applies {
+            return Err(format!(
+                "the approved hunks of {} cannot be applied on their own",
+                path.display()
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn sorted(changes: &HashMap<PathBuf, FileChange>) -> Vec<(&PathBuf, &FileChange)> {
+    let mut entries: Vec<_> = changes.iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    entries
+}
+
+fn prefixed_lines(prefix: char, content: &str) -> String {
+    content
+        .lines()
+        .map(|line| format!("{prefix}{line}\n"))
+        .collect()
+}
+
+/// The `@@` sections of a unified diff, skipping any file header.
+fn update_hunks(unified_diff: &str) -> Vec<String> {
+    let mut hunks: Vec<String> = Vec::new();
+    for line in unified_diff.lines() {
+        if line.starts_with("@@") {
+            hunks.push(String::new());
+        }
+        if let Some(hunk) = hunks.last_mut() {
+            hunk.push_str(line);
+            hunk.push('\n');
+        }
+    }
+    hunks
+}
+
+/// Selected hunks of one file as `apply_patch` chunks, and the content they
+/// produce.
+#[derive(Debug, PartialEq, Eq)]
+struct PartialUpdate {
+    chunks: String,
+    content: String,
+}
+
+/// Apply unified diff hunks to `original` by their line numbers, checking
+/// every context and removed line. Returns `None` on any mismatch.
+///
+/// Each chunk gets up to `CHUNK_CONTEXT` extra lines of leading context from
+/// the original file, since the unified diff carries only one.
+fn apply_hunks(original: &str, hunks: &[&str]) -> Option<PartialUpdate> {
+    let lines: Vec<&str> = original.lines().collect();
+    let mut out: Vec<&str> = Vec::new();
+    let mut chunks = String::new();
+    let mut next = 0;
+    for hunk in hunks {
+        let mut hunk_lines = hunk.lines();
+        let (start, len) = old_range(hunk_lines.next()?)?;
+        // A hunk that removes nothing names the line it inserts after.
+        let start = if len == 0 {
+            start
+        } else {
+            start.checked_sub(1)?
+        };
+        if start < next || start > lines.len() {
+            return None;
+        }
+        chunks.push_str("@@\n");
+        for line in &lines[start.saturating_sub(CHUNK_CONTEXT).max(next)..start] {
+            chunks.push_str(&format!(" {line}\n"));
+        }
+        out.extend(&lines[next..start]);
+        next = start;
+        for line in hunk_lines {
+            if let Some(context) = line.strip_prefix(' ') {
+                if lines.get(next) != Some(&context) {
+                    return None;
+                }
+                out.push(context);
+                next += 1;
+            } else if let Some(removed) = line.strip_prefix('-') {
+                if lines.get(next) != Some(&removed) {
+                    return None;
+                }
+                next += 1;
+            } else if let Some(added) = line.strip_prefix('+') {
+                out.push(added);
+            } else {
+                // `\ No newline at end of file` has no `apply_patch` counterpart.
+                continue;
+            }
+            chunks.push_str(line);
+            chunks.push('\n');
+        }
+    }
+    out.extend(&lines[next..]);
+    let content = if out.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", out.join("\n"))
+    };
+    Some(PartialUpdate { chunks, content })
+}
+
+/// `(start, len)` of the original side of an `@@ -start,len +.. @@` header.
+fn old_range(header: &str) -> Option<(usize, usize)> {
+    let range = header.strip_prefix("@@ -")?.split_once(' ')?.0;
+    match range.split_once(',') {
+        Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    fn parse(patch: &str, cwd: &Path) -> ApplyPatchAction {
+        let argv = vec!["apply_patch".to_string(), patch.to_string()];
+        match maybe_parse_apply_patch_verified(&argv, cwd) {
+            MaybeApplyPatchVerified::Body(action) => action,
+            other => panic!("expected a patch, got {other:?}"),
+        }
+    }
+
+    fn changes(action: &ApplyPatchAction) -> HashMap<PathBuf, FileChange> {
+        action
+            .changes()
+            .iter()
+            .map(|(path, change)| {
+                let change = match change {
+                    ApplyPatchFileChange::Add { content } => FileChange::Add {
+                        content: content.clone(),
+                    },
+                    ApplyPatchFileChange::Delete { content } => FileChange::Delete {
+                        content: content.clone(),
+                    },
+                    ApplyPatchFileChange::Update {
+                        unified_diff,
+                        move_path,
+                        ..
+                    } => FileChange::Update {
+                        unified_diff: unified_diff.clone(),
+                        move_path: move_path.clone(),
+                    },
+                };
+                (path.clone(), change)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn partial_approval_applies_only_the_chosen_hunks() {
+        let dir = tempdir().expect("tempdir");
+        let lib = dir.path().join("lib.rs");
+        std::fs::write(&lib, "a\nb\nc\nd\ne\nf\ng\n").expect("write");
+        let action = parse(
+            "*** Begin Patch\n*** Update File: lib.rs\n@@\n-a\n+A\n@@\n f\n-g\n+G\n*** Add File: new.txt\n+hi\n*** End Patch",
+            dir.path(),
+        );
+        let changes = changes(&action);
+
+        let hunks = split_hunks(&changes);
+        let lib_id = |n: usize| format!("{}#{n}", lib.display());
+        assert_eq!(
+            hunks.iter().map(|hunk| hunk.id.clone()).collect::<Vec<_>>(),
+            vec![
+                lib_id(1),
+                lib_id(2),
+                format!("{}#1", dir.path().join("new.txt").display())
+            ]
+        );
+        assert_eq!(hunks[1].diff, "@@ -6,2 +6,2 @@\n f\n-g\n+G\n");
+
+        let patch = partial_patch(&action, &changes, &[lib_id(2)])
+            .expect("valid")
+            .expect("a hunk was approved");
+        assert_eq!(
+            patch,
+            format!(
+                "*** Begin Patch\n*** Update File: {}\n@@\n c\n d\n e\n f\n-g\n+G\n*** End Patch\n",
+                lib.display()
+            )
+        );
+        assert_eq!(partial_patch(&action, &changes, &[]), Ok(None));
+        assert!(partial_patch(&action, &changes, &["nope#1".to_string()]).is_err());
+    }
+
+    #[test]
+    fn hunks_that_no_longer_match_the_file_are_refused() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("a.txt");
+        std::fs::write(&path, "x\n1\n2\n3\n4\nx\n").expect("write");
+        let action = parse(
+            "*** Begin Patch\n*** Update File: a.txt\n@@\n-x\n+X\n@@\n 4\n x\n+y\n*** End Patch",
+            dir.path(),
+        );
+        let changes = changes(&action);
+        let second = split_hunks(&changes)[1].id.clone();
+        assert!(
+            partial_patch(&action, &changes, std::slice::from_ref(&second))
+                .expect("valid")
+                .is_some()
+        );
+
+        std::fs::write(&path, "x\n1\n2\n3\n4\nz\n").expect("write");
+        assert!(partial_patch(&action, &changes, &[second]).is_err());
+    }
+}
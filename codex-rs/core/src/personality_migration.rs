@@ -167,6 +167,7 @@ mod tests {
             meta: SessionMeta {
                 id: thread_id,
                 forked_from_id: None,
+                fork_turn: None,
                 timestamp: TEST_TIMESTAMP.to_string(),
                 cwd: std::path::PathBuf::from("."),
                 originator: "test_originator".to_string(),
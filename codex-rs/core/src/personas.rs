@@ -0,0 +1,149 @@
+//! Personas: named instruction sets that shape how the model responds,
+//! selected with `/persona` and remembered per project.
+//!
+//! Built-in presets ship with Codex. `[personas.<name>]` in config adds new
+//! personas or replaces a built-in one with the same name.
+
+use crate::config::Config;
+use crate::config::types::PersonaToml;
+use crate::i18n::tr;
+
+struct BuiltinPersona {
+    name: &'static str,
+    description_key: &'static str,
+    instructions_key: &'static str,
+}
+
+const BUILTIN_PERSONAS: [BuiltinPersona; 4] = [
+    BuiltinPersona {
+        name: "terse",
+        description_key: "persona.terse",
+        instructions_key: "prompt.persona.terse",
+    },
+    BuiltinPersona {
+        name: "explanatory",
+        description_key: "persona.explanatory",
+        instructions_key: "prompt.persona.explanatory",
+    },
+    BuiltinPersona {
+        name: "pair-programmer",
+        description_key: "persona.pair_programmer",
+        instructions_key: "prompt.persona.pair_programmer",
+    },
+    BuiltinPersona {
+        name: "reviewer",
+        description_key: "persona.reviewer",
+        instructions_key: "prompt.persona.reviewer",
+    },
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Persona {
+    pub name: String,
+    pub description: Option<String>,
+    pub instructions: String,
+    /// False for personas defined in config, including ones that replace a
+    /// built-in preset.
+    pub builtin: bool,
+}
+
+/// Built-in presets in their fixed order, followed by configured personas
+/// sorted by name.
+pub fn available_personas(config: &Config) -> Vec<Persona> {
+    let mut personas: Vec<Persona> = BUILTIN_PERSONAS
+        .iter()
+        .map(|builtin| {
+            config
+                .personas
+                .get(builtin.name)
+                .map(|configured| configured_persona(builtin.name, configured))
+                .unwrap_or_else(|| Persona {
+                    name: builtin.name.to_string(),
+                    description: Some(tr(config.language, builtin.description_key).to_string()),
+                    instructions: tr(config.language, builtin.instructions_key).to_string(),
+                    builtin: true,
+                })
+        })
+        .collect();
+    personas.extend(
+        config
+            .personas
+            .iter()
+            .filter(|(name, _)| !is_builtin(name))
+            .map(|(name, configured)| configured_persona(name, configured)),
+    );
+    personas
+}
+
+/// Looks up a persona by name, preferring one defined in config.
+pub fn resolve_persona(config: &Config, name: &str) -> Option<Persona> {
+    available_personas(config)
+        .into_iter()
+        .find(|persona| persona.name == name)
+}
+
+fn is_builtin(name: &str) -> bool {
+    BUILTIN_PERSONAS.iter().any(|builtin| builtin.name == name)
+}
+
+fn configured_persona(name: &str, configured: &PersonaToml) -> Persona {
+    Persona {
+        name: name.to_string(),
+        description: configured.description.clone(),
+        instructions: configured.instructions.trim().to_string(),
+        builtin: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigBuilder;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn configured_personas_follow_builtins_and_can_replace_them() {
+        let codex_home = TempDir::new().expect("tempdir");
+        let mut config = ConfigBuilder::default()
+            .codex_home(codex_home.path().to_path_buf())
+            .build()
+            .await
+            .expect("load config");
+        config.personas.insert(
+            "reviewer".to_string(),
+            PersonaToml {
+                description: None,
+                instructions: "  Review like our staff engineers.  ".to_string(),
+            },
+        );
+        config.personas.insert(
+            "archivist".to_string(),
+            PersonaToml {
+                description: Some("Cites history".to_string()),
+                instructions: "Cite the commit that introduced each line.".to_string(),
+            },
+        );
+
+        let names: Vec<String> = available_personas(&config)
+            .into_iter()
+            .map(|persona| persona.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "terse",
+                "explanatory",
+                "pair-programmer",
+                "reviewer",
+                "archivist"
+            ]
+        );
+
+        let reviewer = resolve_persona(&config, "reviewer").expect("reviewer persona");
+        assert_eq!(reviewer.instructions, "Review like our staff engineers.");
+        assert!(!reviewer.builtin);
+        assert!(resolve_persona(&config, "terse").is_some_and(|persona| persona.builtin));
+        assert_eq!(resolve_persona(&config, "missing"), None);
+    }
+}
@@ -144,14 +144,68 @@ pub async fn read_project_docs(config: &Config) -> std::io::Result<Option<String
     }
 }
 
+/// Project docs that apply inside `package_dir` but were not loaded for the
+/// session's own working directory, for sessions narrowed to a monorepo
+/// package with `/scope`. Shares the `project_doc_max_bytes` budget rules
+/// with [`read_project_docs`].
+pub(crate) fn read_scoped_project_docs(
+    config: &Config,
+    package_dir: &std::path::Path,
+) -> std::io::Result<Option<String>> {
+    if config.project_doc_max_bytes == 0 {
+        return Ok(None);
+    }
+    let loaded = discover_project_doc_paths(config)?;
+    let mut scoped_config = config.clone();
+    scoped_config.cwd = package_dir.to_path_buf();
+
+    let mut remaining = config.project_doc_max_bytes;
+    let mut parts: Vec<String> = Vec::new();
+    for path in discover_project_doc_paths(&scoped_config)? {
+        if remaining == 0 {
+            break;
+        }
+        if loaded.contains(&path) {
+            continue;
+        }
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        let mut end = text.len().min(remaining);
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end < text.len() {
+            tracing::warn!(
+                "Project doc `{}` exceeds remaining budget ({} bytes) - truncating.",
+                path.display(),
+                remaining,
+            );
+        }
+        let text = &text[..end];
+        if !text.trim().is_empty() {
+            parts.push(text.to_string());
+            remaining -= end;
+        }
+    }
+
+    Ok((!parts.is_empty()).then(|| parts.join("\n\n")))
+}
+
 /// Discover the list of AGENTS.md files using the same search rules as
 /// `read_project_docs`, but return the file paths instead of concatenated
 /// contents. The list is ordered from repository root to the current working
 /// directory (inclusive). If both `AGENTS.md` and `.codex/AGENTS.md` exist in
 /// the current working directory, only the most recently modified file is
-/// included. Symlinks are allowed. When `project_doc_max_bytes` is zero,
-/// returns an empty list.
+/// included. Symlinks are allowed. When `project_doc_max_bytes` is zero, or
+/// the project is marked untrusted, returns an empty list.
 pub fn discover_project_doc_paths(config: &Config) -> std::io::Result<Vec<PathBuf>> {
+    if config.active_project.is_untrusted() {
+        return Ok(Vec::new());
+    }
+
     let mut dir = config.cwd.clone();
     if let Ok(canon) = normalize_path(&dir) {
         dir = canon;
@@ -417,6 +471,19 @@ mod tests {
         );
     }
 
+    /// Untrusted projects contribute no AGENTS.md instructions.
+    #[tokio::test]
+    async fn untrusted_project_skips_docs() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        fs::write(tmp.path().join("AGENTS.md"), "repo doc").unwrap();
+
+        let mut cfg = make_config(&tmp, 4096, Some("user instructions")).await;
+        cfg.active_project.trust_level = Some(codex_protocol::config_types::TrustLevel::Untrusted);
+
+        let res = get_user_instructions(&cfg, None).await;
+        assert_eq!(res, Some("user instructions".to_string()));
+    }
+
     /// When both system instructions *and* a project doc are present the two
     /// should be concatenated with the separator.
     #[tokio::test]
@@ -479,6 +546,27 @@ mod tests {
         assert_eq!(res, "root doc\n\ncrate doc");
     }
 
+    #[tokio::test]
+    async fn scoped_docs_skip_docs_already_loaded() {
+        let repo = tempfile::tempdir().expect("tempdir");
+        std::fs::write(
+            repo.path().join(".git"),
+            "gitdir: /path/to/actual/git/dir\n",
+        )
+        .unwrap();
+        fs::write(repo.path().join("AGENTS.md"), "root doc").unwrap();
+        let package = repo.path().join("packages/web");
+        std::fs::create_dir_all(&package).unwrap();
+        fs::write(package.join("AGENTS.md"), "web doc").unwrap();
+
+        let cfg = make_config(&repo, 4096, None).await;
+        assert_eq!(
+            read_scoped_project_docs(&cfg, &package).unwrap(),
+            Some("web doc".to_string())
+        );
+        assert_eq!(read_scoped_project_docs(&cfg, repo.path()).unwrap(), None);
+    }
+
     #[tokio::test]
     async fn prefers_newer_doc_between_cwd_and_codex() {
         let tmp = tempfile::tempdir().expect("tempdir");
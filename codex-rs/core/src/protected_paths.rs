@@ -0,0 +1,478 @@
+//! Paths the agent may never modify, from `protected_paths` in `config.toml`.
+//!
+//! ```toml
+//! protected_paths = [".env", "secrets/**", ".git/**"]
+//! ```
+//!
+//! Patterns are globs resolved against the turn's working directory; absolute
+//! and `~/` patterns are used as written. A pattern that matches a directory
+//! protects everything under it. File-editing tools check every path they
+//! would write before doing anything and refuse the whole call when one is
+//! protected, whatever the approval policy. Sandboxed commands see every
+//! existing protected path as read-only; commands that would run outside the
+//! sandbox are refused when they name a protected path.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_apply_patch::ApplyPatchAction;
+use codex_apply_patch::ApplyPatchFileChange;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::GuardrailBlockedEvent;
+use codex_protocol::protocol::GuardrailReason;
+use codex_utils_absolute_path::AbsolutePathBuf;
+use glob::MatchOptions;
+use glob::Pattern;
+
+use crate::bash::extract_bash_command;
+use crate::codex::Session;
+use crate::codex::TurnContext;
+use crate::function_tool::FunctionCallError;
+
+const GLOB_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Upper bound on the paths handed to the sandbox, so a broad wildcard cannot
+/// blow up the sandbox command line.
+const MAX_SANDBOX_PATHS: usize = 256;
+
+/// The `protected_paths` patterns, validated when the config is loaded.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProtectedPaths {
+    patterns: Vec<String>,
+}
+
+/// A path a tool call would modify and the pattern that protects it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtectedPathMatch {
+    pub path: PathBuf,
+    pub pattern: String,
+}
+
+impl ProtectedPaths {
+    pub fn new(patterns: Vec<String>) -> std::io::Result<Self> {
+        for pattern in &patterns {
+            if let Err(err) = Pattern::new(pattern) {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("invalid protected_paths pattern `{pattern}`: {err}"),
+                ));
+            }
+        }
+        Ok(Self { patterns })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// The first pattern that protects `path` or one of its parent
+    /// directories, with relative paths and patterns resolved against `cwd`.
+    /// A pattern ending in `/**` also protects the directory itself.
+    pub fn matching_pattern(&self, path: &Path, cwd: &Path) -> Option<&str> {
+        let path = AbsolutePathBuf::resolve_path_against_base(path, cwd).ok()?;
+        self.patterns
+            .iter()
+            .find(|pattern| {
+                [pattern.as_str(), strip_recursive_suffix(pattern)]
+                    .into_iter()
+                    .filter_map(|pattern| resolve_pattern(pattern, cwd))
+                    .any(|pattern| {
+                        path.as_path()
+                            .ancestors()
+                            .any(|candidate| pattern.matches_path_with(candidate, GLOB_OPTIONS))
+                    })
+            })
+            .map(String::as_str)
+    }
+
+    /// Each of `paths` that is protected, in order.
+    pub fn check<'a>(
+        &self,
+        paths: impl IntoIterator<Item = &'a Path>,
+        cwd: &Path,
+    ) -> Vec<ProtectedPathMatch> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                self.matching_pattern(path, cwd)
+                    .map(|pattern| ProtectedPathMatch {
+                        path: path.to_path_buf(),
+                        pattern: pattern.to_string(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Each path `command` names that is protected, for commands that run
+    /// outside the sandbox. Best effort: the arguments, or the script of a
+    /// `bash -lc` style invocation, are split into words and around shell
+    /// operators, and each piece is resolved against `command_cwd`.
+    pub fn check_command(
+        &self,
+        command: &[String],
+        command_cwd: &Path,
+        cwd: &Path,
+    ) -> Vec<ProtectedPathMatch> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        command_words(command)
+            .into_iter()
+            .filter_map(|word| {
+                let path = AbsolutePathBuf::resolve_path_against_base(&word, command_cwd).ok()?;
+                self.matching_pattern(path.as_path(), cwd)
+                    .map(|pattern| ProtectedPathMatch {
+                        path: PathBuf::from(word),
+                        pattern: pattern.to_string(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Existing files and directories the patterns match, for the sandbox to
+    /// keep read-only. Patterns ending in `/**` contribute the directory
+    /// itself; wildcard patterns are expanded against the file system.
+    pub fn sandbox_paths(&self, cwd: &Path) -> Vec<AbsolutePathBuf> {
+        let mut paths: Vec<AbsolutePathBuf> = Vec::new();
+        for pattern in &self.patterns {
+            let literal = strip_recursive_suffix(pattern);
+            if literal.is_empty() {
+                continue;
+            }
+            let Ok(resolved) = AbsolutePathBuf::resolve_path_against_base(literal, cwd) else {
+                continue;
+            };
+            let matches: Vec<PathBuf> = if literal.contains(['*', '?', '[']) {
+                match glob::glob_with(&resolved.to_string_lossy(), GLOB_OPTIONS) {
+                    Ok(entries) => entries.flatten().collect(),
+                    Err(_) => continue,
+                }
+            } else if resolved.as_path().exists() {
+                vec![resolved.into_path_buf()]
+            } else {
+                continue;
+            };
+            for path in matches {
+                let Ok(path) = AbsolutePathBuf::from_absolute_path(path) else {
+                    continue;
+                };
+                if paths.len() == MAX_SANDBOX_PATHS {
+                    return paths;
+                }
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+        paths
+    }
+}
+
+fn strip_recursive_suffix(pattern: &str) -> &str {
+    let mut literal = pattern;
+    while let Some(parent) = literal.strip_suffix("/**") {
+        literal = parent;
+    }
+    literal
+}
+
+/// The words of `command`, further split around redirections and other shell
+/// operators so `echo x >.env` yields `.env`.
+fn command_words(command: &[String]) -> Vec<String> {
+    let args = match extract_bash_command(command) {
+        Some((_shell, script)) => shlex::split(script)
+            .unwrap_or_else(|| script.split_whitespace().map(str::to_string).collect()),
+        None => command.to_vec(),
+    };
+    let mut words: Vec<String> = Vec::new();
+    for arg in &args {
+        for piece in arg.split(['>', '<', ';', '|', '&', '(', ')', '=']) {
+            let piece = piece.trim();
+            if !piece.is_empty() && !words.iter().any(|word| word == piece) {
+                words.push(piece.to_string());
+            }
+        }
+    }
+    words
+}
+
+fn resolve_pattern(pattern: &str, cwd: &Path) -> Option<Pattern> {
+    let resolved = AbsolutePathBuf::resolve_path_against_base(pattern, cwd).ok()?;
+    Pattern::new(&resolved.to_string_lossy()).ok()
+}
+
+/// Every path a patch writes: added, updated, and deleted files, plus the
+/// destination of each move.
+pub(crate) fn patch_paths(action: &ApplyPatchAction) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    for (path, change) in action.changes() {
+        paths.push(path.clone());
+        if let ApplyPatchFileChange::Update {
+            move_path: Some(dest),
+            ..
+        } = change
+        {
+            paths.push(dest.clone());
+        }
+    }
+    paths
+}
+
+/// Refuse a tool call that would write `path` when it is protected.
+pub(crate) async fn guard_path(
+    session: &Session,
+    turn: &TurnContext,
+    call_id: &str,
+    path: &Path,
+) -> Result<(), FunctionCallError> {
+    let matches = turn.config.protected_paths.check([path], &turn.cwd);
+    if matches.is_empty() {
+        return Ok(());
+    }
+    Err(block(session, turn, call_id, matches).await)
+}
+
+/// Report a refused call to the client and build the error returned to the
+/// model.
+pub(crate) async fn block(
+    session: &Session,
+    turn: &TurnContext,
+    call_id: &str,
+    matches: Vec<ProtectedPathMatch>,
+) -> FunctionCallError {
+    let message = blocked_message(&matches);
+    FunctionCallError::RespondToModel(report(session, turn, call_id, matches, message).await)
+}
+
+/// Report a refused unsandboxed command to the client and return the message
+/// for the model.
+pub(crate) async fn block_command(
+    session: &Session,
+    turn: &TurnContext,
+    call_id: &str,
+    matches: Vec<ProtectedPathMatch>,
+) -> String {
+    let message = blocked_command_message(&matches);
+    report(session, turn, call_id, matches, message).await
+}
+
+async fn report(
+    session: &Session,
+    turn: &TurnContext,
+    call_id: &str,
+    matches: Vec<ProtectedPathMatch>,
+    message: String,
+) -> String {
+    let mut patterns: Vec<String> = Vec::new();
+    for matched in &matches {
+        if !patterns.contains(&matched.pattern) {
+            patterns.push(matched.pattern.clone());
+        }
+    }
+    session
+        .send_event(
+            turn,
+            EventMsg::GuardrailBlocked(GuardrailBlockedEvent {
+                call_id: call_id.to_string(),
+                turn_id: turn.sub_id.clone(),
+                paths: matches.into_iter().map(|matched| matched.path).collect(),
+                patterns,
+                reason: GuardrailReason::ProtectedPaths,
+            }),
+        )
+        .await;
+    message
+}
+
+fn blocked_message(matches: &[ProtectedPathMatch]) -> String {
+    let list = match_list(matches);
+    format!(
+        "blocked by guardrail: `protected_paths` forbids modifying {list}. Nothing was written. \
+Do not try to change these paths another way; ask the user to make the change if it is needed."
+    )
+}
+
+/// The message for a command refused because it would run outside the
+/// sandbox and names a protected path.
+pub(crate) fn blocked_command_message(matches: &[ProtectedPathMatch]) -> String {
+    let list = match_list(matches);
+    format!(
+        "blocked by guardrail: this command would run outside the sandbox and names {list}, \
+which `protected_paths` forbids modifying. Nothing was run. Do not try to change these paths \
+another way; ask the user to make the change if it is needed."
+    )
+}
+
+fn match_list(matches: &[ProtectedPathMatch]) -> String {
+    matches
+        .iter()
+        .map(|matched| format!("{} (matches `{}`)", matched.path.display(), matched.pattern))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    fn protected() -> ProtectedPaths {
+        ProtectedPaths::new(vec![
+            ".env".to_string(),
+            "secrets/**".to_string(),
+            "**/*.pem".to_string(),
+        ])
+        .expect("valid patterns")
+    }
+
+    #[test]
+    fn matches_paths_and_their_parents_relative_to_cwd() {
+        let cwd = Path::new("/repo");
+        let protected = protected();
+
+        assert_eq!(
+            protected.check(
+                [
+                    Path::new(".env"),
+                    Path::new("/repo/secrets/prod/key.txt"),
+                    Path::new("src/../certs/server.pem"),
+                    Path::new("src/main.rs"),
+                    Path::new("config/.env"),
+                    Path::new("/elsewhere/.env"),
+                ],
+                cwd,
+            ),
+            vec![
+                ProtectedPathMatch {
+                    path: PathBuf::from(".env"),
+                    pattern: ".env".to_string(),
+                },
+                ProtectedPathMatch {
+                    path: PathBuf::from("/repo/secrets/prod/key.txt"),
+                    pattern: "secrets/**".to_string(),
+                },
+                ProtectedPathMatch {
+                    path: PathBuf::from("src/../certs/server.pem"),
+                    pattern: "**/*.pem".to_string(),
+                },
+            ]
+        );
+
+        let whole_dir = ProtectedPaths::new(vec!["secrets".to_string()]).expect("valid");
+        assert_eq!(
+            whole_dir.matching_pattern(Path::new("secrets/a/b"), cwd),
+            Some("secrets")
+        );
+    }
+
+    #[test]
+    fn sandbox_paths_keep_existing_literal_patterns() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::create_dir(dir.path().join("secrets")).expect("mkdir");
+        std::fs::write(dir.path().join(".env"), "TOKEN=1").expect("write");
+        let protected = ProtectedPaths::new(vec![
+            ".env".to_string(),
+            "secrets/**".to_string(),
+            "missing.txt".to_string(),
+            "**/*.pem".to_string(),
+        ])
+        .expect("valid patterns");
+
+        let paths: Vec<PathBuf> = protected
+            .sandbox_paths(dir.path())
+            .into_iter()
+            .map(AbsolutePathBuf::into_path_buf)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![dir.path().join(".env"), dir.path().join("secrets")]
+        );
+    }
+
+    #[test]
+    fn sandbox_paths_expand_wildcard_patterns() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::create_dir_all(dir.path().join("certs/old")).expect("mkdir");
+        std::fs::write(dir.path().join("certs/server.pem"), "").expect("write");
+        std::fs::write(dir.path().join("certs/old/ca.pem"), "").expect("write");
+        std::fs::write(dir.path().join("certs/README.md"), "").expect("write");
+
+        let mut paths: Vec<PathBuf> = protected()
+            .sandbox_paths(dir.path())
+            .into_iter()
+            .map(AbsolutePathBuf::into_path_buf)
+            .collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                dir.path().join("certs/old/ca.pem"),
+                dir.path().join("certs/server.pem"),
+            ]
+        );
+    }
+
+    #[test]
+    fn commands_naming_protected_paths_are_reported() {
+        let cwd = Path::new("/repo");
+        let protected = protected();
+        let bash = |script: &str| vec!["bash".to_string(), "-lc".to_string(), script.to_string()];
+
+        assert_eq!(
+            protected.check_command(&bash("echo TOKEN=2 >.env && rm -rf secrets"), cwd, cwd),
+            vec![
+                ProtectedPathMatch {
+                    path: PathBuf::from(".env"),
+                    pattern: ".env".to_string(),
+                },
+                ProtectedPathMatch {
+                    path: PathBuf::from("secrets"),
+                    pattern: "secrets/**".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            protected.check_command(
+                &[
+                    "cp".to_string(),
+                    "new.pem".to_string(),
+                    "../.env".to_string()
+                ],
+                Path::new("/repo/certs"),
+                cwd,
+            ),
+            vec![
+                ProtectedPathMatch {
+                    path: PathBuf::from("new.pem"),
+                    pattern: "**/*.pem".to_string(),
+                },
+                ProtectedPathMatch {
+                    path: PathBuf::from("../.env"),
+                    pattern: ".env".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            protected.check_command(&bash("cargo test -p secrets-cli"), cwd, cwd),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn invalid_pattern_is_rejected() {
+        let err = ProtectedPaths::new(vec!["secrets/[".to_string()]).expect_err("invalid");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,214 @@
+//! Provenance annotations for work done through Codex, from `[provenance]` in
+//! `config.toml`.
+//!
+//! ```toml
+//! [provenance]
+//! commit_trailers = true
+//! file_header = "Generated with Codex ({model}, session {session_id})."
+//! ```
+//!
+//! With `commit_trailers`, `codex commit` adds `Codex-Model` and
+//! `Codex-Session` trailers to the commits it makes, and the agent is asked to
+//! add the same trailers to commits it makes during a session. `file_header`
+//! is written as a comment at the top of each file the agent creates with
+//! `apply_patch` or the write tool. Files whose type has no known line or
+//! block comment syntax, such as JSON, are left alone.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_apply_patch::ApplyPatchAction;
+use codex_apply_patch::ApplyPatchFileChange;
+
+use crate::codex::Session;
+use crate::codex::TurnContext;
+use crate::config::types::Provenance;
+
+pub const MODEL_TRAILER: &str = "Codex-Model";
+pub const SESSION_TRAILER: &str = "Codex-Session";
+
+/// `Key: value` trailers recording the model and session behind a commit.
+pub fn commit_trailers(model: &str, session_id: &str) -> Vec<String> {
+    vec![
+        format!("{MODEL_TRAILER}: {model}"),
+        format!("{SESSION_TRAILER}: {session_id}"),
+    ]
+}
+
+/// Developer instructions asking the agent to add the trailers itself.
+pub(crate) fn commit_trailer_instructions(model: &str, session_id: &str) -> String {
+    let flags = commit_trailers(model, session_id)
+        .iter()
+        .map(|trailer| format!("--trailer \"{trailer}\""))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "This project records which commits were made with Codex. Whenever you run `git commit`, \
+pass {flags} so the message ends with these trailers. Do not add them to commits you did not make."
+    )
+}
+
+/// How comments are written in a file, by extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CommentStyle {
+    Line(&'static str),
+    Block(&'static str, &'static str),
+}
+
+fn comment_style(path: &Path) -> Option<CommentStyle> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    let style = match extension.as_str() {
+        "rs" | "c" | "h" | "cc" | "cpp" | "hpp" | "cs" | "go" | "java" | "js" | "jsx" | "mjs"
+        | "cjs" | "ts" | "tsx" | "kt" | "kts" | "swift" | "scala" | "dart" | "php" | "zig"
+        | "proto" | "scss" => CommentStyle::Line("//"),
+        "py" | "sh" | "bash" | "zsh" | "fish" | "rb" | "pl" | "r" | "toml" | "yaml" | "yml"
+        | "ps1" | "nix" | "cmake" | "tf" | "ex" | "exs" => CommentStyle::Line("#"),
+        "sql" | "lua" | "hs" => CommentStyle::Line("--"),
+        "html" | "htm" | "xml" | "md" | "vue" | "svelte" => CommentStyle::Block("<!--", "-->"),
+        "css" => CommentStyle::Block("/*", "*/"),
+        _ => return None,
+    };
+    Some(style)
+}
+
+/// The header for `path`, commented for its file type, or `None` when the
+/// type has no known comment syntax.
+pub fn render_file_header(
+    template: &str,
+    model: &str,
+    session_id: &str,
+    path: &Path,
+) -> Option<String> {
+    let text = template
+        .replace("{model}", model)
+        .replace("{session_id}", session_id);
+    let header = match comment_style(path)? {
+        CommentStyle::Line(prefix) => text
+            .lines()
+            .map(|line| format!("{prefix} {line}").trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        CommentStyle::Block(open, close) => format!("{open} {} {close}", text.trim()),
+    };
+    Some(header)
+}
+
+/// `content` with `header` on top, after a shebang or XML declaration.
+/// Returns `content` unchanged when it already carries the header.
+pub fn with_file_header(content: &str, header: &str) -> String {
+    if content.contains(header) {
+        return content.to_string();
+    }
+    let keeps_first_line = content.starts_with("#!") || content.starts_with("<?xml");
+    match content.split_once('\n') {
+        Some((first, rest)) if keeps_first_line => format!("{first}\n{header}\n{rest}"),
+        _ if keeps_first_line => format!("{content}\n{header}\n"),
+        _ if content.is_empty() => format!("{header}\n"),
+        _ => format!("{header}\n\n{content}"),
+    }
+}
+
+/// Files `action` adds that do not exist yet, to be annotated once the patch
+/// is applied.
+pub(crate) fn new_files(provenance: &Provenance, action: &ApplyPatchAction) -> Vec<PathBuf> {
+    if provenance.file_header.is_none() {
+        return Vec::new();
+    }
+    action
+        .changes()
+        .iter()
+        .filter(|(path, change)| {
+            matches!(change, ApplyPatchFileChange::Add { .. }) && !path.exists()
+        })
+        .map(|(path, _)| path.clone())
+        .collect()
+}
+
+/// The header for a file the agent creates at `path` in this turn, if
+/// `file_header` is set and the file type takes comments.
+pub(crate) fn file_header_for(
+    session: &Session,
+    turn: &TurnContext,
+    path: &Path,
+) -> Option<String> {
+    let template = turn.config.provenance.file_header.as_deref()?;
+    render_file_header(
+        template,
+        &turn.model_info.slug,
+        &session.conversation_id.to_string(),
+        path,
+    )
+}
+
+/// Write the header into each of `paths`. Failures are logged and skipped:
+/// the files themselves were written successfully.
+pub(crate) async fn annotate_new_files(session: &Session, turn: &TurnContext, paths: &[PathBuf]) {
+    for path in paths {
+        let Some(header) = file_header_for(session, turn, path) else {
+            continue;
+        };
+        let result = match tokio::fs::read_to_string(path).await {
+            Ok(content) => tokio::fs::write(path, with_file_header(&content, &header)).await,
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            tracing::warn!(
+                "failed to add provenance header to {}: {err}",
+                path.display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn header_uses_the_comment_syntax_of_the_file() {
+        let template = "Generated with Codex ({model}).\nSession {session_id}.";
+        let render = |path: &str| render_file_header(template, "gpt-5", "abc", Path::new(path));
+
+        assert_eq!(
+            render("src/lib.rs").as_deref(),
+            Some("// Generated with Codex (gpt-5).\n// Session abc.")
+        );
+        assert_eq!(
+            render("build.py").as_deref(),
+            Some("# Generated with Codex (gpt-5).\n# Session abc.")
+        );
+        assert_eq!(
+            render("index.html").as_deref(),
+            Some("<!-- Generated with Codex (gpt-5).\nSession abc. -->")
+        );
+        assert_eq!(render("package.json"), None);
+        assert_eq!(render("Makefile"), None);
+    }
+
+    #[test]
+    fn header_goes_after_a_shebang_and_only_once() {
+        let header = "# Generated with Codex.";
+        assert_eq!(
+            with_file_header("#!/bin/sh\necho hi\n", header),
+            "#!/bin/sh\n# Generated with Codex.\necho hi\n"
+        );
+        assert_eq!(
+            with_file_header("print(1)\n", header),
+            "# Generated with Codex.\n\nprint(1)\n"
+        );
+        let annotated = with_file_header("print(1)\n", header);
+        assert_eq!(with_file_header(&annotated, header), annotated);
+    }
+
+    #[test]
+    fn trailers_name_the_model_and_session() {
+        assert_eq!(
+            commit_trailers("gpt-5", "abc"),
+            vec![
+                "Codex-Model: gpt-5".to_string(),
+                "Codex-Session: abc".to_string()
+            ]
+        );
+    }
+}
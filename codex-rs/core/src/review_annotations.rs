@@ -0,0 +1,260 @@
+//! Materializes review findings as `TODO`/`FIXME` comments at the reported
+//! lines, either written into the reviewed files or collected into a patch.
+//!
+//! Findings in files whose comment syntax is unknown, that cannot be read, or
+//! whose line range falls outside the file are skipped.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::protocol::ReviewFinding;
+use similar::TextDiff;
+
+/// File name `codex review --annotate patch` writes to.
+pub const REVIEW_PATCH_FILE: &str = "review.patch";
+
+/// Findings at this priority or more severe (P0, P1) become `FIXME`; the rest
+/// are `TODO`.
+const FIXME_PRIORITY: i32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedFile {
+    pub path: PathBuf,
+    pub original: String,
+    pub annotated: String,
+    /// Number of comments inserted into this file.
+    pub comments: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewAnnotations {
+    /// Files with at least one comment, sorted by path.
+    pub files: Vec<AnnotatedFile>,
+    /// Findings that could not be placed.
+    pub skipped: usize,
+}
+
+impl ReviewAnnotations {
+    pub fn comments(&self) -> usize {
+        self.files.iter().map(|file| file.comments).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Writes the annotated contents over the reviewed files.
+    pub fn apply(&self) -> std::io::Result<()> {
+        for file in &self.files {
+            std::fs::write(&file.path, &file.annotated)?;
+        }
+        Ok(())
+    }
+
+    /// Renders the annotations as a unified diff that `git apply` accepts from
+    /// `root`. Paths outside `root` are kept absolute.
+    pub fn to_patch(&self, root: &Path) -> String {
+        let mut patch = String::new();
+        for file in &self.files {
+            let relative = file.path.strip_prefix(root).unwrap_or(&file.path);
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            let old_header = format!("a/{relative}");
+            let new_header = format!("b/{relative}");
+            patch.push_str(&format!("diff --git {old_header} {new_header}\n"));
+            patch.push_str(
+                &TextDiff::from_lines(&file.original, &file.annotated)
+                    .unified_diff()
+                    .context_radius(3)
+                    .header(&old_header, &new_header)
+                    .to_string(),
+            );
+        }
+        patch
+    }
+}
+
+/// Builds the annotated contents of every file the findings point at.
+pub fn annotate_findings(findings: &[ReviewFinding]) -> ReviewAnnotations {
+    let mut by_path: BTreeMap<&Path, Vec<&ReviewFinding>> = BTreeMap::new();
+    for finding in findings {
+        by_path
+            .entry(finding.code_location.absolute_file_path.as_path())
+            .or_default()
+            .push(finding);
+    }
+
+    let mut annotations = ReviewAnnotations::default();
+    for (path, findings) in by_path {
+        let Some(syntax) = comment_syntax(path) else {
+            annotations.skipped += findings.len();
+            continue;
+        };
+        let Ok(original) = std::fs::read_to_string(path) else {
+            annotations.skipped += findings.len();
+            continue;
+        };
+        let line_ending = if original.contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        };
+        let mut lines: Vec<String> = original.split_inclusive('\n').map(str::to_string).collect();
+
+        // Insert bottom-up so earlier line numbers stay valid; for findings on
+        // the same line, insert the last one first so they keep their order.
+        let mut ordered: Vec<(usize, &ReviewFinding)> = findings.into_iter().enumerate().collect();
+        ordered.sort_by(|(a_index, a), (b_index, b)| {
+            b.code_location
+                .line_range
+                .start
+                .cmp(&a.code_location.line_range.start)
+                .then(b_index.cmp(a_index))
+        });
+
+        let mut comments = 0;
+        for (_, finding) in ordered {
+            let start = finding.code_location.line_range.start as usize;
+            let Some(target) = start.checked_sub(1).and_then(|index| lines.get(index)) else {
+                annotations.skipped += 1;
+                continue;
+            };
+            let indent: String = target
+                .chars()
+                .take_while(|ch| *ch == ' ' || *ch == '\t')
+                .collect();
+            let comment = format!(
+                "{indent}{}{line_ending}",
+                syntax.render(&comment_text(finding))
+            );
+            lines.insert(start - 1, comment);
+            comments += 1;
+        }
+
+        if comments > 0 {
+            annotations.files.push(AnnotatedFile {
+                path: path.to_path_buf(),
+                annotated: lines.concat(),
+                original,
+                comments,
+            });
+        }
+    }
+    annotations
+}
+
+fn comment_text(finding: &ReviewFinding) -> String {
+    let marker = if finding.priority <= FIXME_PRIORITY {
+        "FIXME"
+    } else {
+        "TODO"
+    };
+    let title = finding
+        .title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    match finding.category.as_deref() {
+        Some(category) => format!("{marker}(review/{category}): {title}"),
+        None => format!("{marker}(review): {title}"),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CommentSyntax {
+    open: &'static str,
+    close: &'static str,
+}
+
+impl CommentSyntax {
+    const fn new(open: &'static str, close: &'static str) -> Self {
+        Self { open, close }
+    }
+
+    fn render(self, text: &str) -> String {
+        format!("{}{text}{}", self.open, self.close)
+    }
+}
+
+fn comment_syntax(path: &Path) -> Option<CommentSyntax> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    let syntax = match extension.as_str() {
+        "rs" | "c" | "h" | "cc" | "cpp" | "hpp" | "cs" | "go" | "java" | "js" | "jsx" | "mjs"
+        | "cjs" | "ts" | "tsx" | "kt" | "kts" | "swift" | "scala" | "dart" | "php" | "proto" => {
+            CommentSyntax::new("// ", "")
+        }
+        "py" | "sh" | "bash" | "zsh" | "rb" | "pl" | "toml" | "yaml" | "yml" | "r" | "ex"
+        | "exs" | "nix" => CommentSyntax::new("# ", ""),
+        "sql" | "lua" | "hs" => CommentSyntax::new("-- ", ""),
+        "css" | "scss" | "less" => CommentSyntax::new("/* ", " */"),
+        "html" | "htm" | "xml" | "md" | "vue" | "svelte" => CommentSyntax::new("<!-- ", " -->"),
+        _ => return None,
+    };
+    Some(syntax)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::ReviewCodeLocation;
+    use codex_protocol::protocol::ReviewLineRange;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn finding(title: &str, path: &Path, start: u32, priority: i32) -> ReviewFinding {
+        ReviewFinding {
+            title: title.to_string(),
+            body: String::new(),
+            confidence_score: 0.5,
+            priority,
+            code_location: ReviewCodeLocation {
+                absolute_file_path: path.to_path_buf(),
+                line_range: ReviewLineRange { start, end: start },
+            },
+            category: None,
+        }
+    }
+
+    #[test]
+    fn comments_are_inserted_above_reported_lines() {
+        let dir = TempDir::new().expect("tempdir");
+        let source = dir.path().join("lib.rs");
+        std::fs::write(&source, "fn main() {\n    let x = 1;\n    run(x);\n}\n").expect("write");
+        let unknown = dir.path().join("data.bin");
+        std::fs::write(&unknown, "binary").expect("write");
+
+        let annotations = annotate_findings(&[
+            finding("Handle the error", &source, 3, 1),
+            finding("Name this   better", &source, 2, 2),
+            finding("Out of range", &source, 40, 2),
+            finding("Unknown syntax", &unknown, 1, 0),
+        ]);
+
+        assert_eq!(annotations.comments(), 2);
+        assert_eq!(annotations.skipped, 2);
+        assert_eq!(
+            annotations.files[0].annotated,
+            "fn main() {\n    // TODO(review): Name this better\n    let x = 1;\n    // FIXME(review): Handle the error\n    run(x);\n}\n"
+        );
+    }
+
+    #[test]
+    fn patch_uses_paths_relative_to_root() {
+        let dir = TempDir::new().expect("tempdir");
+        std::fs::create_dir(dir.path().join("src")).expect("mkdir");
+        let source = dir.path().join("src").join("main.py");
+        std::fs::write(&source, "import os\nprint(os.name)\n").expect("write");
+
+        let annotations = annotate_findings(&[finding("Avoid os.name", &source, 2, 2)]);
+        let patch = annotations.to_patch(dir.path());
+
+        assert!(patch.starts_with(
+            "diff --git a/src/main.py b/src/main.py\n--- a/src/main.py\n+++ b/src/main.py\n"
+        ));
+        assert!(patch.contains("+# TODO(review): Avoid os.name\n"));
+        assert_eq!(
+            std::fs::read_to_string(&source).expect("read"),
+            "import os\nprint(os.name)\n"
+        );
+    }
+}
@@ -20,6 +20,8 @@ const REVIEW_FALLBACK_MESSAGE: &str = "Reviewer failed to output a response.";
 ///   default to selected.
 /// - When `selection` is `None`, the marker is omitted and a simple bullet is
 ///   rendered ("- Title — path:start-end").
+/// - Findings tagged with a rubric category are prefixed with it
+///   ("- [security] Title — path:start-end").
 pub fn format_review_findings_block(
     findings: &[ReviewFinding],
     selection: Option<&[bool]>,
@@ -37,7 +39,10 @@ pub fn format_review_findings_block(
     for (idx, item) in findings.iter().enumerate() {
         lines.push(String::new());
 
-        let title = &item.title;
+        let title = match item.category.as_deref() {
+            Some(category) => format!("[{category}] {}", item.title),
+            None => item.title.clone(),
+        };
         let location = format_location(item);
 
         if let Some(flags) = selection {
@@ -2,16 +2,21 @@ use codex_git::merge_base_with_head;
 use codex_protocol::config_types::Language;
 use codex_protocol::protocol::ReviewRequest;
 use codex_protocol::protocol::ReviewTarget;
+use std::collections::BTreeSet;
 use std::path::Path;
 
 use crate::i18n::tr;
 use crate::i18n::tr_args;
+use crate::review_rubric::ReviewRubric;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ResolvedReviewRequest {
     pub target: ReviewTarget,
     pub prompt: String,
     pub user_facing_hint: String,
+    /// Repository rubric from `.codex/review.toml`, already merged into
+    /// `prompt`; applied again to the reviewer's findings.
+    pub rubric: Option<ReviewRubric>,
 }
 
 pub fn resolve_review_request(
@@ -20,7 +25,18 @@ pub fn resolve_review_request(
     language: Language,
 ) -> anyhow::Result<ResolvedReviewRequest> {
     let target = request.target;
-    let prompt = review_prompt(&target, cwd, language)?;
+    let mut prompt = review_prompt(&target, cwd, language)?;
+    let rubric = ReviewRubric::load(cwd).map_err(|err| {
+        anyhow::anyhow!(tr_args(
+            language,
+            "review.error.invalid_rubric",
+            &[("error", &format!("{err:#}"))],
+        ))
+    })?;
+    if let Some(rubric) = &rubric {
+        prompt.push_str("\n\n");
+        prompt.push_str(&rubric.prompt_section(language));
+    }
     let user_facing_hint = request
         .user_facing_hint
         .unwrap_or_else(|| user_facing_hint(&target, language));
@@ -29,6 +45,7 @@ pub fn resolve_review_request(
         target,
         prompt,
         user_facing_hint,
+        rubric,
     })
 }
 
@@ -41,6 +58,7 @@ pub fn review_prompt(
         ReviewTarget::UncommittedChanges => {
             Ok(tr(language, "review.prompt.uncommitted").to_string())
         }
+        ReviewTarget::StagedChanges => Ok(tr(language, "review.prompt.staged").to_string()),
         ReviewTarget::BaseBranch { branch } => {
             if let Some(commit) = merge_base_with_head(cwd, branch)? {
                 Ok(tr_args(
@@ -71,6 +89,19 @@ pub fn review_prompt(
                 ))
             }
         }
+        ReviewTarget::Files { patterns } => {
+            let files = matching_files(cwd, patterns, language)?;
+            let list = files
+                .iter()
+                .map(|file| format!("- {file}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            Ok(tr_args(
+                language,
+                "review.prompt.files",
+                &[("files", &list)],
+            ))
+        }
         ReviewTarget::Custom { instructions } => {
             let prompt = instructions.trim();
             if prompt.is_empty() {
@@ -84,6 +115,7 @@ pub fn review_prompt(
 pub fn user_facing_hint(target: &ReviewTarget, language: Language) -> String {
     match target {
         ReviewTarget::UncommittedChanges => tr(language, "review.hint.uncommitted").to_string(),
+        ReviewTarget::StagedChanges => tr(language, "review.hint.staged").to_string(),
         ReviewTarget::BaseBranch { branch } => tr_args(
             language,
             "review.hint.base_branch",
@@ -101,10 +133,50 @@ pub fn user_facing_hint(target: &ReviewTarget, language: Language) -> String {
                 tr_args(language, "review.hint.commit", &[("sha", &short_sha)])
             }
         }
+        ReviewTarget::Files { patterns } => tr_args(
+            language,
+            "review.hint.files",
+            &[("patterns", &patterns.join(" "))],
+        ),
         ReviewTarget::Custom { instructions } => instructions.trim().to_string(),
     }
 }
 
+/// Files under `cwd` matching any of `patterns`, as sorted paths relative to
+/// `cwd`. Fails when a pattern is invalid or nothing matches.
+fn matching_files(
+    cwd: &Path,
+    patterns: &[String],
+    language: Language,
+) -> anyhow::Result<Vec<String>> {
+    let mut files = BTreeSet::new();
+    for pattern in patterns.iter().map(|pattern| pattern.trim()) {
+        if pattern.is_empty() {
+            continue;
+        }
+        let absolute = cwd.join(pattern);
+        let entries = glob::glob(&absolute.to_string_lossy()).map_err(|err| {
+            anyhow::anyhow!(tr_args(
+                language,
+                "review.error.invalid_file_pattern",
+                &[("pattern", pattern), ("error", &err.to_string())],
+            ))
+        })?;
+        for path in entries.flatten().filter(|path| path.is_file()) {
+            let relative = path.strip_prefix(cwd).unwrap_or(&path);
+            files.insert(relative.display().to_string());
+        }
+    }
+    if files.is_empty() {
+        anyhow::bail!(tr_args(
+            language,
+            "review.error.no_matching_files",
+            &[("patterns", &patterns.join(" "))],
+        ));
+    }
+    Ok(files.into_iter().collect())
+}
+
 impl From<ResolvedReviewRequest> for ReviewRequest {
     fn from(resolved: ResolvedReviewRequest) -> Self {
         ReviewRequest {
@@ -113,3 +185,44 @@ impl From<ResolvedReviewRequest> for ReviewRequest {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[test]
+    fn files_target_lists_matching_files() {
+        let cwd = TempDir::new().expect("tempdir");
+        std::fs::create_dir_all(cwd.path().join("src/nested")).expect("create dirs");
+        std::fs::write(cwd.path().join("src/lib.rs"), "").expect("write lib.rs");
+        std::fs::write(cwd.path().join("src/nested/mod.rs"), "").expect("write mod.rs");
+        std::fs::write(cwd.path().join("README.md"), "").expect("write README.md");
+
+        let files = matching_files(
+            cwd.path(),
+            &["src/**/*.rs".to_string(), "README.md".to_string()],
+            Language::En,
+        )
+        .expect("matching files");
+
+        assert_eq!(files, vec!["README.md", "src/lib.rs", "src/nested/mod.rs"]);
+    }
+
+    #[test]
+    fn files_target_without_matches_is_an_error() {
+        let cwd = TempDir::new().expect("tempdir");
+
+        let err = review_prompt(
+            &ReviewTarget::Files {
+                patterns: vec!["*.rs".to_string()],
+            },
+            cwd.path(),
+            Language::En,
+        )
+        .expect_err("no files match");
+
+        assert!(err.to_string().contains("*.rs"));
+    }
+}
@@ -0,0 +1,334 @@
+//! Repository review rubric loaded from `.codex/review.toml`.
+//!
+//! The rubric is merged into the review prompt and applied to the reviewer's
+//! structured output: findings in skipped files or below the severity
+//! threshold are dropped, and each finding keeps the focus area the reviewer
+//! tagged it with.
+//!
+//! ```toml
+//! instructions = "Flag public API changes that lack a CHANGELOG entry."
+//! severity_threshold = "P2"
+//! skip = ["vendor/**", "**/*.snap"]
+//!
+//! [[focus]]
+//! category = "security"
+//! description = "Injection, authn/authz, secrets in code"
+//! severity_threshold = "P1"
+//! ```
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use codex_protocol::config_types::Language;
+use codex_protocol::protocol::ReviewFinding;
+use codex_protocol::protocol::ReviewOutputEvent;
+use glob::MatchOptions;
+use glob::Pattern;
+use serde::Deserialize;
+
+use crate::git_info::get_git_repo_root;
+use crate::i18n::tr;
+use crate::i18n::tr_args;
+
+/// Rubric file name under the repository's `.codex` directory.
+pub const REVIEW_RUBRIC_FILE: &str = "review.toml";
+
+const GLOB_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Severity levels used by the review prompt, most severe first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+pub enum ReviewSeverity {
+    P0,
+    P1,
+    P2,
+    P3,
+}
+
+impl ReviewSeverity {
+    /// The numeric `priority` the reviewer reports for this severity.
+    pub fn priority(self) -> i32 {
+        match self {
+            ReviewSeverity::P0 => 0,
+            ReviewSeverity::P1 => 1,
+            ReviewSeverity::P2 => 2,
+            ReviewSeverity::P3 => 3,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ReviewSeverity::P0 => "P0",
+            ReviewSeverity::P1 => "P1",
+            ReviewSeverity::P2 => "P2",
+            ReviewSeverity::P3 => "P3",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReviewRubricToml {
+    /// Extra guidance appended to the review prompt.
+    #[serde(default)]
+    pub instructions: Option<String>,
+    /// Least severe finding to report; less severe findings are dropped.
+    #[serde(default)]
+    pub severity_threshold: Option<ReviewSeverity>,
+    /// Globs, relative to the repository root, whose files are not reviewed.
+    #[serde(default)]
+    pub skip: Vec<String>,
+    /// Focus areas; findings are tagged with one of their categories.
+    #[serde(default)]
+    pub focus: Vec<ReviewFocusArea>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReviewFocusArea {
+    pub category: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Overrides the top-level `severity_threshold` for this category.
+    #[serde(default)]
+    pub severity_threshold: Option<ReviewSeverity>,
+}
+
+/// A parsed rubric together with the repository root its globs resolve against.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewRubric {
+    pub root: PathBuf,
+    pub config: ReviewRubricToml,
+    skip: Vec<Pattern>,
+}
+
+impl ReviewRubric {
+    pub fn new(root: PathBuf, config: ReviewRubricToml) -> anyhow::Result<Self> {
+        let skip = config
+            .skip
+            .iter()
+            .map(|glob| Pattern::new(glob).with_context(|| format!("invalid skip glob `{glob}`")))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(Self { root, config, skip })
+    }
+
+    /// Loads `.codex/review.toml` from the repository containing `cwd`, or
+    /// from `cwd` itself outside a repository. Returns `None` when the file
+    /// does not exist.
+    pub fn load(cwd: &Path) -> anyhow::Result<Option<Self>> {
+        let root = get_git_repo_root(cwd).unwrap_or_else(|| cwd.to_path_buf());
+        let path = root.join(".codex").join(REVIEW_RUBRIC_FILE);
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()));
+            }
+        };
+        let config: ReviewRubricToml = toml::from_str(&contents)
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        Self::new(root, config)
+            .with_context(|| format!("failed to load {}", path.display()))
+            .map(Some)
+    }
+
+    /// Text appended to the review prompt.
+    pub fn prompt_section(&self, language: Language) -> String {
+        let mut lines = vec![tr(language, "review.rubric.header").to_string()];
+
+        if !self.config.focus.is_empty() {
+            lines.push(String::new());
+            lines.push(tr(language, "review.rubric.focus_header").to_string());
+            for area in &self.config.focus {
+                let mut line = format!("- {}", area.category);
+                if let Some(description) = area.description.as_deref() {
+                    line.push_str(": ");
+                    line.push_str(description.trim());
+                }
+                if let Some(threshold) = area.severity_threshold {
+                    line.push_str(&tr_args(
+                        language,
+                        "review.rubric.focus_threshold",
+                        &[("severity", threshold.label())],
+                    ));
+                }
+                lines.push(line);
+            }
+            let categories = self
+                .config
+                .focus
+                .iter()
+                .map(|area| area.category.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            lines.push(tr_args(
+                language,
+                "review.rubric.category_field",
+                &[("categories", &categories)],
+            ));
+        }
+
+        if let Some(threshold) = self.config.severity_threshold {
+            lines.push(String::new());
+            lines.push(tr_args(
+                language,
+                "review.rubric.threshold",
+                &[("severity", threshold.label())],
+            ));
+        }
+
+        if !self.config.skip.is_empty() {
+            lines.push(String::new());
+            lines.push(tr_args(
+                language,
+                "review.rubric.skip",
+                &[("globs", &self.config.skip.join(", "))],
+            ));
+        }
+
+        if let Some(instructions) = self
+            .config
+            .instructions
+            .as_deref()
+            .map(str::trim)
+            .filter(|instructions| !instructions.is_empty())
+        {
+            lines.push(String::new());
+            lines.push(tr(language, "review.rubric.instructions_header").to_string());
+            lines.push(instructions.to_string());
+        }
+
+        lines.join("\n")
+    }
+
+    /// Drops findings the rubric excludes and normalizes each remaining
+    /// finding's category to the spelling used in the rubric. Categories that
+    /// match no focus area are cleared.
+    pub fn apply(&self, output: &mut ReviewOutputEvent) {
+        output.findings.retain_mut(|finding| {
+            finding.category = finding
+                .category
+                .as_deref()
+                .and_then(|category| self.focus_area(category))
+                .map(|area| area.category.clone());
+            !self.is_skipped(finding) && !self.is_below_threshold(finding)
+        });
+    }
+
+    fn focus_area(&self, category: &str) -> Option<&ReviewFocusArea> {
+        self.config
+            .focus
+            .iter()
+            .find(|area| area.category.eq_ignore_ascii_case(category.trim()))
+    }
+
+    fn is_skipped(&self, finding: &ReviewFinding) -> bool {
+        let path = &finding.code_location.absolute_file_path;
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        self.skip
+            .iter()
+            .any(|pattern| pattern.matches_path_with(relative, GLOB_OPTIONS))
+    }
+
+    fn is_below_threshold(&self, finding: &ReviewFinding) -> bool {
+        let threshold = finding
+            .category
+            .as_deref()
+            .and_then(|category| self.focus_area(category))
+            .and_then(|area| area.severity_threshold)
+            .or(self.config.severity_threshold);
+        threshold.is_some_and(|threshold| finding.priority > threshold.priority())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::ReviewCodeLocation;
+    use codex_protocol::protocol::ReviewLineRange;
+    use pretty_assertions::assert_eq;
+
+    fn finding(title: &str, path: &str, priority: i32, category: Option<&str>) -> ReviewFinding {
+        ReviewFinding {
+            title: title.to_string(),
+            body: String::new(),
+            confidence_score: 0.5,
+            priority,
+            code_location: ReviewCodeLocation {
+                absolute_file_path: PathBuf::from(path),
+                line_range: ReviewLineRange { start: 1, end: 1 },
+            },
+            category: category.map(str::to_string),
+        }
+    }
+
+    fn rubric() -> ReviewRubric {
+        let config: ReviewRubricToml = toml::from_str(
+            r#"
+severity_threshold = "P2"
+skip = ["vendor/**", "**/*.snap"]
+
+[[focus]]
+category = "security"
+severity_threshold = "P3"
+
+[[focus]]
+category = "style"
+severity_threshold = "P1"
+"#,
+        )
+        .expect("parse rubric");
+        ReviewRubric::new(PathBuf::from("/repo"), config).expect("compile rubric")
+    }
+
+    #[test]
+    fn apply_filters_and_normalizes_findings() {
+        let mut output = ReviewOutputEvent {
+            findings: vec![
+                finding("vendored", "/repo/vendor/lib/a.rs", 0, None),
+                finding("snapshot", "/repo/src/snapshots/x.snap", 0, None),
+                finding("minor security", "/repo/src/auth.rs", 3, Some("Security")),
+                finding("minor style", "/repo/src/lib.rs", 2, Some("style")),
+                finding("minor untagged", "/repo/src/lib.rs", 3, None),
+                finding("unknown area", "/repo/src/lib.rs", 1, Some("docs")),
+            ],
+            ..Default::default()
+        };
+
+        rubric().apply(&mut output);
+
+        let kept: Vec<(&str, Option<&str>)> = output
+            .findings
+            .iter()
+            .map(|finding| (finding.title.as_str(), finding.category.as_deref()))
+            .collect();
+        assert_eq!(
+            kept,
+            vec![("minor security", Some("security")), ("unknown area", None)]
+        );
+    }
+
+    #[test]
+    fn prompt_section_lists_rubric_parts() {
+        let section = rubric().prompt_section(Language::En);
+
+        assert!(section.contains("- security"));
+        assert!(section.contains("security, style"));
+        assert!(section.contains("vendor/**, **/*.snap"));
+        assert!(section.contains("P2"));
+    }
+
+    #[test]
+    fn invalid_skip_glob_is_rejected() {
+        let config = ReviewRubricToml {
+            skip: vec!["src/[".to_string()],
+            ..Default::default()
+        };
+
+        assert!(ReviewRubric::new(PathBuf::from("/repo"), config).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+//! Archiving and deleting recorded sessions that are not running.
+//!
+//! Archived rollouts move to `archived_sessions/` under their original file
+//! name, which hides them from the resume pickers and `resume --last` until
+//! they are restored; deleted rollouts are removed from disk. Each operation
+//! keeps the state database in step when it is enabled.
+
+use std::ffi::OsString;
+use std::fs::FileTimes;
+use std::fs::OpenOptions;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use chrono::Utc;
+use codex_protocol::ThreadId;
+use tracing::warn;
+
+use super::ARCHIVED_SESSIONS_SUBDIR;
+use super::SESSIONS_SUBDIR;
+use super::list::read_session_meta_line;
+use super::list::rollout_date_parts;
+use super::recovery::move_recovery_note;
+use super::recovery::remove_recovery_note;
+use crate::config::Config;
+use crate::state_db;
+
+/// Move the rollout at `path` into `archived_sessions/` and return its new
+/// location.
+pub async fn archive_session(config: &Config, path: &Path) -> io::Result<PathBuf> {
+    let rollout = SessionRollout::resolve(config, SESSIONS_SUBDIR, path).await?;
+    let archive_dir = config.codex_home.join(ARCHIVED_SESSIONS_SUBDIR);
+    tokio::fs::create_dir_all(&archive_dir).await?;
+    let archived_path = archive_dir.join(&rollout.file_name);
+    tokio::fs::rename(&rollout.path, &archived_path).await?;
+    if let Err(err) = move_recovery_note(&rollout.path, &archived_path).await {
+        warn!(
+            "failed to move the recovery note of {}: {err}",
+            rollout.path.display()
+        );
+    }
+    if let Some(thread_id) = rollout.thread_id
+        && let Some(ctx) = state_db::get_state_db(config, None).await
+    {
+        let _ = ctx
+            .mark_archived(thread_id, archived_path.as_path(), Utc::now())
+            .await;
+    }
+    Ok(archived_path)
+}
+
+/// Remove the rollout at `path` from disk.
+pub async fn delete_session(config: &Config, path: &Path) -> io::Result<()> {
+    let rollout = SessionRollout::resolve(config, SESSIONS_SUBDIR, path).await?;
+    tokio::fs::remove_file(&rollout.path).await?;
+    if let Err(err) = remove_recovery_note(&rollout.path).await {
+        warn!(
+            "failed to remove the recovery note of {}: {err}",
+            rollout.path.display()
+        );
+    }
+    if let Some(thread_id) = rollout.thread_id
+        && let Some(ctx) = state_db::get_state_db(config, None).await
+    {
+        let _ = ctx.delete_thread(thread_id).await;
+    }
+    Ok(())
+}
+
+/// Move an archived rollout back to its dated directory under `sessions/`
+/// and return its new location. The file's modification time is bumped so
+/// the restored session sorts as recently updated.
+pub async fn restore_session(config: &Config, path: &Path) -> io::Result<PathBuf> {
+    let rollout = SessionRollout::resolve(config, ARCHIVED_SESSIONS_SUBDIR, path).await?;
+    let Some((year, month, day)) = rollout_date_parts(&rollout.file_name) else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "rollout path `{}` missing filename timestamp",
+                path.display()
+            ),
+        ));
+    };
+    let dest_dir = config
+        .codex_home
+        .join(SESSIONS_SUBDIR)
+        .join(year)
+        .join(month)
+        .join(day);
+    tokio::fs::create_dir_all(&dest_dir).await?;
+    let restored_path = dest_dir.join(&rollout.file_name);
+    tokio::fs::rename(&rollout.path, &restored_path).await?;
+    if let Err(err) = move_recovery_note(&rollout.path, &restored_path).await {
+        warn!(
+            "failed to move the recovery note of {}: {err}",
+            rollout.path.display()
+        );
+    }
+    tokio::task::spawn_blocking({
+        let restored_path = restored_path.clone();
+        move || -> io::Result<()> {
+            let times = FileTimes::new().set_modified(SystemTime::now());
+            OpenOptions::new()
+                .append(true)
+                .open(&restored_path)?
+                .set_times(times)
+        }
+    })
+    .await
+    .map_err(io::Error::other)??;
+    if let Some(thread_id) = rollout.thread_id
+        && let Some(ctx) = state_db::get_state_db(config, None).await
+    {
+        let _ = ctx
+            .mark_unarchived(thread_id, restored_path.as_path())
+            .await;
+    }
+    Ok(restored_path)
+}
+
+struct SessionRollout {
+    path: PathBuf,
+    file_name: OsString,
+    thread_id: Option<ThreadId>,
+}
+
+impl SessionRollout {
+    /// Resolve `path`, which must lie under `subdir` of the Codex home.
+    async fn resolve(config: &Config, subdir: &str, path: &Path) -> io::Result<Self> {
+        let root = tokio::fs::canonicalize(config.codex_home.join(subdir)).await?;
+        let canonical = tokio::fs::canonicalize(path).await?;
+        if !canonical.starts_with(&root) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("rollout path `{}` must be in {subdir}", path.display()),
+            ));
+        }
+        let Some(file_name) = canonical.file_name().map(OsString::from) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("rollout path `{}` missing file name", path.display()),
+            ));
+        };
+        let thread_id = read_session_meta_line(&canonical)
+            .await
+            .ok()
+            .map(|line| line.meta.id);
+        Ok(Self {
+            path: canonical,
+            file_name,
+            thread_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigBuilder;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn archives_restores_and_deletes_session_rollouts() {
+        let home = TempDir::new().expect("create temp dir");
+        let config = ConfigBuilder::default()
+            .codex_home(home.path().to_path_buf())
+            .build()
+            .await
+            .expect("load default test config");
+        let day_dir = home.path().join(SESSIONS_SUBDIR).join("2025/01/02");
+        std::fs::create_dir_all(&day_dir).expect("create sessions dir");
+        let first = day_dir.join("rollout-2025-01-02T10-00-00-first.jsonl");
+        let second = day_dir.join("rollout-2025-01-02T11-00-00-second.jsonl");
+        let outside = home.path().join("rollout-outside.jsonl");
+        for path in [&first, &second, &outside] {
+            std::fs::write(path, "").expect("write rollout");
+        }
+
+        let archived = archive_session(&config, &first).await.expect("archive");
+        assert_eq!(archived.file_name(), first.file_name());
+        assert_eq!(
+            archived.parent().and_then(Path::file_name),
+            Some(std::ffi::OsStr::new(ARCHIVED_SESSIONS_SUBDIR))
+        );
+        assert!(archived.exists());
+        assert!(!first.exists());
+        restore_session(&config, &first)
+            .await
+            .expect_err("not archived");
+
+        let restored = restore_session(&config, &archived).await.expect("restore");
+        assert!(restored.ends_with("2025/01/02/rollout-2025-01-02T10-00-00-first.jsonl"));
+        assert!(first.exists());
+        assert!(!archived.exists());
+
+        delete_session(&config, &second).await.expect("delete");
+        assert!(!second.exists());
+
+        let err = delete_session(&config, &outside)
+            .await
+            .expect_err("outside sessions dir");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(outside.exists());
+    }
+}
@@ -0,0 +1,467 @@
+//! Chronological action log of a recorded thread for `codex sessions audit`.
+//!
+//! The log lists what the session did to the machine it ran on: files written
+//! by `apply_patch`, commands executed with their exit codes, network hosts
+//! reached by web searches and fetched pages, and the approval decisions that
+//! let commands run. Everything is rebuilt from the rollout, so it covers only
+//! what the rollout keeps; edits made by shell commands show up as the
+//! commands that made them.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_apply_patch::Hunk;
+use codex_apply_patch::parse_patch;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::models::WebSearchAction;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::ExecCommandOrigin;
+use codex_protocol::protocol::ExecProvenanceItem;
+use codex_protocol::protocol::ReviewDecision;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use serde::Serialize;
+
+use super::patch_history::APPLY_PATCH_SUCCESS;
+use super::patch_history::APPLY_PATCH_TOOL;
+
+/// One line of the audit log.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditEntry {
+    /// Timestamp of the rollout line the action was recorded on.
+    pub timestamp: String,
+    #[serde(flatten)]
+    pub action: AuditAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AuditAction {
+    /// A file added, updated, moved, or deleted by a successful `apply_patch`.
+    FileWrite {
+        call_id: String,
+        change: FileChangeKind,
+        path: PathBuf,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        move_to: Option<PathBuf>,
+    },
+    /// A command that ran. `exit_code` is `None` when it was not observed.
+    Command {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        call_id: Option<String>,
+        turn_id: String,
+        origin: ExecCommandOrigin,
+        command: Vec<String>,
+        cwd: PathBuf,
+        exit_code: Option<i32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        policy_rule: Option<String>,
+    },
+    /// The first time the session reached a host.
+    Network {
+        host: String,
+        url: String,
+        source: NetworkSource,
+    },
+    /// A decision on a command that asked for approval.
+    Approval {
+        call_id: String,
+        decision: ReviewDecision,
+        decided_by: ApprovalDecider,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChangeKind {
+    Add,
+    Update,
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkSource {
+    /// The model provider's hosted web search opened the page.
+    HostedWebSearch,
+    /// A local web search or `fetch_url` call cited the page.
+    Fetch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalDecider {
+    User,
+    /// `approval_timeout` answered because nobody responded in time.
+    Timeout,
+}
+
+/// Build the audit log of the rollout at `path`, in recorded order.
+pub async fn load_audit_log(path: &Path) -> io::Result<Vec<AuditEntry>> {
+    let text = tokio::fs::read_to_string(path).await?;
+    let lines: Vec<RolloutLine> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    Ok(audit_entries(&lines))
+}
+
+pub(super) fn audit_entries(lines: &[RolloutLine]) -> Vec<AuditEntry> {
+    let mut log = AuditLog::default();
+    for line in lines {
+        log.record(&line.timestamp, &line.item);
+    }
+    log.entries
+}
+
+#[derive(Default)]
+struct AuditLog {
+    entries: Vec<AuditEntry>,
+    cwd: Option<PathBuf>,
+    /// `apply_patch` calls waiting for their output, with the cwd they ran in.
+    pending_patches: HashMap<String, (String, Option<PathBuf>)>,
+    hosts: HashSet<String>,
+    timed_out: HashSet<String>,
+}
+
+impl AuditLog {
+    fn record(&mut self, timestamp: &str, item: &RolloutItem) {
+        match item {
+            RolloutItem::SessionMeta(meta) if self.cwd.is_none() => {
+                self.cwd = Some(meta.meta.cwd.clone());
+            }
+            RolloutItem::TurnContext(context) => self.cwd = Some(context.cwd.clone()),
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                name,
+                call_id,
+                input,
+                ..
+            }) if name == APPLY_PATCH_TOOL => {
+                self.pending_patches
+                    .insert(call_id.clone(), (input.clone(), self.cwd.clone()));
+            }
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                name,
+                call_id,
+                arguments,
+                ..
+            }) if name == APPLY_PATCH_TOOL => {
+                if let Some(input) = serde_json::from_str::<serde_json::Value>(arguments)
+                    .ok()
+                    .and_then(|args| args.get("input")?.as_str().map(str::to_string))
+                {
+                    self.pending_patches
+                        .insert(call_id.clone(), (input, self.cwd.clone()));
+                }
+            }
+            RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput { call_id, output }) => {
+                let ok = output
+                    .text_content()
+                    .is_some_and(|text| text.contains(APPLY_PATCH_SUCCESS));
+                self.finish_patch(timestamp, call_id, ok);
+            }
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCallOutput { call_id, output }) => {
+                self.finish_patch(timestamp, call_id, output.contains(APPLY_PATCH_SUCCESS));
+            }
+            RolloutItem::ResponseItem(ResponseItem::WebSearchCall {
+                action:
+                    Some(
+                        WebSearchAction::OpenPage { url: Some(url) }
+                        | WebSearchAction::FindInPage { url: Some(url), .. },
+                    ),
+                ..
+            }) => self.contact(timestamp, url, NetworkSource::HostedWebSearch),
+            RolloutItem::EventMsg(EventMsg::TurnSources(sources)) => {
+                for citation in &sources.citations {
+                    self.contact(timestamp, &citation.url, NetworkSource::Fetch);
+                }
+            }
+            RolloutItem::EventMsg(EventMsg::ApprovalTimedOut(event)) => {
+                self.timed_out.insert(event.call_id.clone());
+                self.push(
+                    timestamp,
+                    AuditAction::Approval {
+                        call_id: event.call_id.clone(),
+                        decision: event.decision.clone(),
+                        decided_by: ApprovalDecider::Timeout,
+                    },
+                );
+            }
+            RolloutItem::ExecProvenance(item) => self.exec(timestamp, item),
+            _ => {}
+        }
+    }
+
+    fn finish_patch(&mut self, timestamp: &str, call_id: &str, succeeded: bool) {
+        let Some((input, cwd)) = self.pending_patches.remove(call_id) else {
+            return;
+        };
+        if !succeeded {
+            return;
+        }
+        let Ok(args) = parse_patch(&input) else {
+            return;
+        };
+        let resolve = |path: PathBuf| match &cwd {
+            Some(cwd) if path.is_relative() => cwd.join(path),
+            _ => path,
+        };
+        for hunk in args.hunks {
+            let (change, path, move_to) = match hunk {
+                Hunk::AddFile { path, .. } => (FileChangeKind::Add, path, None),
+                Hunk::DeleteFile { path } => (FileChangeKind::Delete, path, None),
+                Hunk::UpdateFile {
+                    path, move_path, ..
+                } => (FileChangeKind::Update, path, move_path),
+            };
+            self.push(
+                timestamp,
+                AuditAction::FileWrite {
+                    call_id: call_id.to_string(),
+                    change,
+                    path: resolve(path),
+                    move_to: move_to.map(resolve),
+                },
+            );
+        }
+    }
+
+    fn contact(&mut self, timestamp: &str, url: &str, source: NetworkSource) {
+        let Some(host) = url::Url::parse(url)
+            .ok()
+            .and_then(|parsed| parsed.host_str().map(str::to_string))
+        else {
+            return;
+        };
+        if self.hosts.insert(host.clone()) {
+            self.push(
+                timestamp,
+                AuditAction::Network {
+                    host,
+                    url: url.to_string(),
+                    source,
+                },
+            );
+        }
+    }
+
+    fn exec(&mut self, timestamp: &str, item: &ExecProvenanceItem) {
+        let approval = item.provenance.approval.as_ref();
+        if let (Some(call_id), Some(decision)) = (&item.call_id, approval)
+            && !self.timed_out.contains(call_id)
+        {
+            self.push(
+                timestamp,
+                AuditAction::Approval {
+                    call_id: call_id.clone(),
+                    decision: decision.clone(),
+                    decided_by: ApprovalDecider::User,
+                },
+            );
+        }
+        // A rejected command never started; its approval entry is the record.
+        if matches!(
+            approval,
+            Some(ReviewDecision::Denied | ReviewDecision::Abort)
+        ) {
+            return;
+        }
+        self.push(
+            timestamp,
+            AuditAction::Command {
+                call_id: item.call_id.clone(),
+                turn_id: item.turn_id.clone(),
+                origin: item.provenance.origin,
+                command: item.command.clone(),
+                cwd: item.cwd.clone(),
+                exit_code: item.exit_code,
+                policy_rule: item.provenance.policy_rule.clone(),
+            },
+        );
+    }
+
+    fn push(&mut self, timestamp: &str, action: AuditAction) {
+        self.entries.push(AuditEntry {
+            timestamp: timestamp.to_string(),
+            action,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::config_types::ApprovalTimeoutAction;
+    use codex_protocol::items::WebCitation;
+    use codex_protocol::protocol::ApprovalTimedOutEvent;
+    use codex_protocol::protocol::ExecCommandProvenance;
+    use codex_protocol::protocol::SessionMeta;
+    use codex_protocol::protocol::SessionMetaLine;
+    use codex_protocol::protocol::TurnSourcesEvent;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn line(timestamp: &str, item: RolloutItem) -> RolloutLine {
+        RolloutLine {
+            timestamp: timestamp.to_string(),
+            item,
+        }
+    }
+
+    fn exec(call_id: &str, command: &str, approval: Option<ReviewDecision>) -> RolloutItem {
+        RolloutItem::ExecProvenance(ExecProvenanceItem {
+            call_id: Some(call_id.to_string()),
+            turn_id: "turn-1".to_string(),
+            command: vec!["bash".to_string(), "-lc".to_string(), command.to_string()],
+            cwd: PathBuf::from("/repo"),
+            exit_code: Some(0),
+            provenance: ExecCommandProvenance {
+                approval,
+                ..ExecCommandProvenance::new(ExecCommandOrigin::Model)
+            },
+        })
+    }
+
+    #[test]
+    fn logs_writes_commands_hosts_and_approvals_in_order() {
+        let lines = vec![
+            line(
+                "t0",
+                RolloutItem::SessionMeta(SessionMetaLine {
+                    meta: SessionMeta {
+                        cwd: PathBuf::from("/repo"),
+                        ..SessionMeta::default()
+                    },
+                    git: None,
+                }),
+            ),
+            line(
+                "t1",
+                RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                    id: None,
+                    status: None,
+                    call_id: "patch".to_string(),
+                    name: APPLY_PATCH_TOOL.to_string(),
+                    input: "*** Begin Patch\n*** Add File: notes.md\n+hi\n*** End Patch"
+                        .to_string(),
+                }),
+            ),
+            line(
+                "t2",
+                RolloutItem::ResponseItem(ResponseItem::CustomToolCallOutput {
+                    call_id: "patch".to_string(),
+                    output: "Success. Updated the following files:\nA notes.md\n".to_string(),
+                }),
+            ),
+            line(
+                "t3",
+                exec("push", "git push", Some(ReviewDecision::Approved)),
+            ),
+            line("t4", exec("rm", "rm -rf /", Some(ReviewDecision::Denied))),
+            line(
+                "t5",
+                RolloutItem::EventMsg(EventMsg::TurnSources(TurnSourcesEvent {
+                    citations: vec![
+                        WebCitation {
+                            url: "https://docs.rs/serde".to_string(),
+                            title: None,
+                            snippet: None,
+                        },
+                        WebCitation {
+                            url: "https://docs.rs/tokio".to_string(),
+                            title: None,
+                            snippet: None,
+                        },
+                    ],
+                })),
+            ),
+        ];
+
+        let entries: Vec<serde_json::Value> = audit_entries(&lines)
+            .iter()
+            .map(|entry| serde_json::to_value(entry).expect("serialize entry"))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                json!({
+                    "timestamp": "t2",
+                    "kind": "file_write",
+                    "call_id": "patch",
+                    "change": "add",
+                    "path": "/repo/notes.md",
+                }),
+                json!({
+                    "timestamp": "t3",
+                    "kind": "approval",
+                    "call_id": "push",
+                    "decision": "approved",
+                    "decided_by": "user",
+                }),
+                json!({
+                    "timestamp": "t3",
+                    "kind": "command",
+                    "call_id": "push",
+                    "turn_id": "turn-1",
+                    "origin": "model",
+                    "command": ["bash", "-lc", "git push"],
+                    "cwd": "/repo",
+                    "exit_code": 0,
+                }),
+                json!({
+                    "timestamp": "t4",
+                    "kind": "approval",
+                    "call_id": "rm",
+                    "decision": "denied",
+                    "decided_by": "user",
+                }),
+                json!({
+                    "timestamp": "t5",
+                    "kind": "network",
+                    "host": "docs.rs",
+                    "url": "https://docs.rs/serde",
+                    "source": "fetch",
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn timed_out_approvals_are_attributed_to_the_timeout() {
+        let lines = vec![
+            line(
+                "t1",
+                RolloutItem::EventMsg(EventMsg::ApprovalTimedOut(ApprovalTimedOutEvent {
+                    call_id: "call-1".to_string(),
+                    turn_id: "turn-1".to_string(),
+                    timeout_seconds: 60,
+                    action: ApprovalTimeoutAction::AllowIfPolicySafe,
+                    decision: ReviewDecision::Approved,
+                })),
+            ),
+            line("t2", exec("call-1", "ls", Some(ReviewDecision::Approved))),
+        ];
+
+        let kinds: Vec<(String, Option<ApprovalDecider>)> = audit_entries(&lines)
+            .into_iter()
+            .map(|entry| match entry.action {
+                AuditAction::Approval { decided_by, .. } => {
+                    ("approval".to_string(), Some(decided_by))
+                }
+                AuditAction::Command { .. } => ("command".to_string(), None),
+                other => panic!("unexpected entry {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("approval".to_string(), Some(ApprovalDecider::Timeout)),
+                ("command".to_string(), None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! Fork graph for recorded threads.
+//!
+//! A forked rollout records the thread it was forked from and how many of the
+//! parent's user turns it inherited. Scanning the session metadata under
+//! `sessions/` is enough to rebuild the tree a thread belongs to, and the
+//! recorded user/agent messages are enough to tell where two branches part.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::SessionMeta;
+
+use super::RolloutRecorder;
+use super::SESSIONS_SUBDIR;
+use super::list::collect_rollout_paths;
+use super::list::read_session_meta_line;
+use super::session_index::find_thread_names_by_ids;
+
+/// One thread in a fork tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchNode {
+    pub thread_id: ThreadId,
+    pub path: PathBuf,
+    pub thread_name: Option<String>,
+    pub timestamp: String,
+    /// Parent user turns inherited at the fork. `None` for the root and for
+    /// forks recorded before the fork point was tracked.
+    pub fork_turn: Option<u32>,
+    pub children: Vec<BranchNode>,
+}
+
+impl BranchNode {
+    /// Depth-first rows with the box-drawing prefix that places each node
+    /// under its parent (`├─ `, `│  └─ `, ...). The root has an empty prefix.
+    pub fn tree_rows(&self) -> Vec<(String, &BranchNode)> {
+        let mut rows = vec![(String::new(), self)];
+        push_child_rows(self, "", &mut rows);
+        rows
+    }
+
+    pub fn find(&self, thread_id: ThreadId) -> Option<&BranchNode> {
+        if self.thread_id == thread_id {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(thread_id))
+    }
+}
+
+fn push_child_rows<'a>(
+    node: &'a BranchNode,
+    indent: &str,
+    rows: &mut Vec<(String, &'a BranchNode)>,
+) {
+    for (idx, child) in node.children.iter().enumerate() {
+        let last = idx + 1 == node.children.len();
+        let (branch, continuation) = if last {
+            ("└─ ", "   ")
+        } else {
+            ("├─ ", "│  ")
+        };
+        rows.push((format!("{indent}{branch}"), child));
+        push_child_rows(child, &format!("{indent}{continuation}"), rows);
+    }
+}
+
+/// Build the fork tree containing `thread_id` from the rollouts under
+/// `codex_home`. Returns `None` when no rollout for `thread_id` exists.
+pub async fn find_branch_tree(
+    codex_home: &Path,
+    thread_id: ThreadId,
+) -> io::Result<Option<BranchNode>> {
+    let root = codex_home.join(SESSIONS_SUBDIR);
+    if !root.exists() {
+        return Ok(None);
+    }
+
+    let mut sessions = Vec::new();
+    for path in collect_rollout_paths(&root).await? {
+        // Rollouts without readable metadata cannot be placed in the tree.
+        if let Ok(line) = read_session_meta_line(&path).await {
+            sessions.push((line.meta, path));
+        }
+    }
+
+    let Some(mut tree) = build_branch_tree(sessions, thread_id) else {
+        return Ok(None);
+    };
+    let ids: HashSet<ThreadId> = tree
+        .tree_rows()
+        .into_iter()
+        .map(|(_, node)| node.thread_id)
+        .collect();
+    let names = find_thread_names_by_ids(codex_home, &ids).await?;
+    apply_thread_names(&mut tree, &names);
+    Ok(Some(tree))
+}
+
+fn build_branch_tree(
+    sessions: Vec<(SessionMeta, PathBuf)>,
+    thread_id: ThreadId,
+) -> Option<BranchNode> {
+    let mut by_id: HashMap<ThreadId, (SessionMeta, PathBuf)> = HashMap::new();
+    for (meta, path) in sessions {
+        by_id.entry(meta.id).or_insert((meta, path));
+    }
+    if !by_id.contains_key(&thread_id) {
+        return None;
+    }
+
+    // Walk up to the oldest ancestor that is still on disk.
+    let mut root_id = thread_id;
+    let mut seen = HashSet::from([root_id]);
+    while let Some(parent) = by_id
+        .get(&root_id)
+        .and_then(|(meta, _)| meta.forked_from_id)
+        .filter(|parent| by_id.contains_key(parent))
+    {
+        if !seen.insert(parent) {
+            break;
+        }
+        root_id = parent;
+    }
+
+    let mut children: HashMap<ThreadId, Vec<ThreadId>> = HashMap::new();
+    for (id, (meta, _)) in &by_id {
+        if let Some(parent) = meta.forked_from_id {
+            children.entry(parent).or_default().push(*id);
+        }
+    }
+    for ids in children.values_mut() {
+        ids.sort_by(|a, b| by_id[a].0.timestamp.cmp(&by_id[b].0.timestamp));
+    }
+
+    let mut visited = HashSet::new();
+    Some(build_node(root_id, &by_id, &children, &mut visited))
+}
+
+fn build_node(
+    id: ThreadId,
+    by_id: &HashMap<ThreadId, (SessionMeta, PathBuf)>,
+    children: &HashMap<ThreadId, Vec<ThreadId>>,
+    visited: &mut HashSet<ThreadId>,
+) -> BranchNode {
+    visited.insert(id);
+    let (meta, path) = &by_id[&id];
+    let mut child_nodes = Vec::new();
+    for child in children.get(&id).into_iter().flatten() {
+        if !visited.contains(child) {
+            child_nodes.push(build_node(*child, by_id, children, visited));
+        }
+    }
+    BranchNode {
+        thread_id: id,
+        path: path.clone(),
+        thread_name: None,
+        timestamp: meta.timestamp.clone(),
+        fork_turn: meta.fork_turn,
+        children: child_nodes,
+    }
+}
+
+fn apply_thread_names(node: &mut BranchNode, names: &HashMap<ThreadId, String>) {
+    node.thread_name = names.get(&node.thread_id).cloned();
+    for child in &mut node.children {
+        apply_thread_names(child, names);
+    }
+}
+
+/// One user turn of a branch: the prompt and the last agent message it got.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchTurn {
+    pub prompt: String,
+    pub reply: Option<String>,
+}
+
+/// The effective user turns recorded in the rollout at `path`, with rolled
+/// back turns removed.
+pub async fn load_branch_turns(path: &Path) -> io::Result<Vec<BranchTurn>> {
+    let history = RolloutRecorder::get_rollout_history(path).await?;
+    Ok(branch_turns(&history.get_rollout_items()))
+}
+
+pub(super) fn branch_turns(items: &[RolloutItem]) -> Vec<BranchTurn> {
+    let mut turns: Vec<BranchTurn> = Vec::new();
+    for item in items {
+        let RolloutItem::EventMsg(event) = item else {
+            continue;
+        };
+        match event {
+            EventMsg::UserMessage(event) => turns.push(BranchTurn {
+                prompt: event.message.clone(),
+                reply: None,
+            }),
+            EventMsg::AgentMessage(event) => {
+                if let Some(turn) = turns.last_mut() {
+                    turn.reply = Some(event.message.clone());
+                }
+            }
+            EventMsg::ThreadRolledBack(event) => {
+                let num_turns = usize::try_from(event.num_turns).unwrap_or(usize::MAX);
+                turns.truncate(turns.len().saturating_sub(num_turns));
+            }
+            _ => {}
+        }
+    }
+    turns
+}
+
+/// Number of leading turns two branches have in common.
+pub fn shared_turn_count(a: &[BranchTurn], b: &[BranchTurn]) -> usize {
+    a.iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::AgentMessageEvent;
+    use codex_protocol::protocol::ThreadRolledBackEvent;
+    use codex_protocol::protocol::UserMessageEvent;
+    use pretty_assertions::assert_eq;
+
+    fn meta(
+        id: ThreadId,
+        parent: Option<ThreadId>,
+        fork_turn: Option<u32>,
+        ts: &str,
+    ) -> (SessionMeta, PathBuf) {
+        (
+            SessionMeta {
+                id,
+                forked_from_id: parent,
+                fork_turn,
+                timestamp: ts.to_string(),
+                ..SessionMeta::default()
+            },
+            PathBuf::from(format!("{id}.jsonl")),
+        )
+    }
+
+    #[test]
+    fn builds_tree_from_any_member() {
+        let root = ThreadId::new();
+        let first = ThreadId::new();
+        let second = ThreadId::new();
+        let nested = ThreadId::new();
+        let unrelated = ThreadId::new();
+        let sessions = vec![
+            meta(nested, Some(first), Some(3), "2025-01-04"),
+            meta(second, Some(root), Some(1), "2025-01-03"),
+            meta(first, Some(root), Some(2), "2025-01-02"),
+            meta(root, None, None, "2025-01-01"),
+            meta(unrelated, None, None, "2025-01-05"),
+        ];
+
+        let tree = build_branch_tree(sessions, nested).expect("tree");
+        let rows: Vec<(String, ThreadId, Option<u32>)> = tree
+            .tree_rows()
+            .into_iter()
+            .map(|(prefix, node)| (prefix, node.thread_id, node.fork_turn))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (String::new(), root, None),
+                ("├─ ".to_string(), first, Some(2)),
+                ("│  └─ ".to_string(), nested, Some(3)),
+                ("└─ ".to_string(), second, Some(1)),
+            ]
+        );
+        assert_eq!(build_branch_tree(Vec::new(), root), None);
+    }
+
+    #[test]
+    fn branch_turns_apply_rollbacks_and_find_divergence() {
+        let user = |text: &str| {
+            RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                message: text.to_string(),
+                images: None,
+                local_images: Vec::new(),
+                text_elements: Vec::new(),
+            }))
+        };
+        let agent = |text: &str| {
+            RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+                message: text.to_string(),
+            }))
+        };
+        let parent = branch_turns(&[user("u1"), agent("a1"), user("u2"), agent("a2")]);
+        let fork = branch_turns(&[
+            user("u1"),
+            agent("a1"),
+            user("u2"),
+            agent("a2"),
+            RolloutItem::EventMsg(EventMsg::ThreadRolledBack(ThreadRolledBackEvent {
+                num_turns: 1,
+            })),
+            user("u2b"),
+            agent("a2b"),
+        ]);
+
+        assert_eq!(
+            fork,
+            vec![
+                BranchTurn {
+                    prompt: "u1".to_string(),
+                    reply: Some("a1".to_string()),
+                },
+                BranchTurn {
+                    prompt: "u2b".to_string(),
+                    reply: Some("a2b".to_string()),
+                },
+            ]
+        );
+        assert_eq!(shared_turn_count(&parent, &fork), 1);
+    }
+}
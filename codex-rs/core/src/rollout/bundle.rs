@@ -0,0 +1,560 @@
+//! Portable session bundles for moving a thread between machines.
+//!
+//! A bundle is a zip archive holding the rollout file, the local images its
+//! user messages reference, and a small manifest. Importing writes the
+//! rollout back under `sessions/` so `codex resume` finds it like any other
+//! thread, optionally under a fresh thread id.
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use zip::ZipArchive;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use super::SESSIONS_SUBDIR;
+use super::list::find_thread_path_by_id_str;
+use super::list::rollout_date_parts;
+use super::recovery::LENGTH_KEY;
+use super::recovery::frame_record;
+use super::session_index::append_thread_name;
+use super::session_index::find_thread_name_by_id;
+
+/// File extension `codex sessions export` uses for bundles.
+pub const SESSION_BUNDLE_EXTENSION: &str = "codexsession";
+
+const BUNDLE_FORMAT_VERSION: u32 = 1;
+const MANIFEST_ENTRY: &str = "manifest.json";
+const ROLLOUT_ENTRY: &str = "rollout.jsonl";
+const IMAGES_ENTRY_DIR: &str = "images";
+const IMPORTED_IMAGES_SUBDIR: &str = "imported_images";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BundleManifest {
+    format_version: u32,
+    thread_id: ThreadId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    thread_name: Option<String>,
+    rollout_file_name: String,
+    #[serde(default)]
+    images: Vec<BundledImage>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BundledImage {
+    original_path: PathBuf,
+    entry: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionBundleExport {
+    pub thread_id: ThreadId,
+    pub images: usize,
+    /// Referenced images that no longer exist on disk and were left out.
+    pub missing_images: Vec<PathBuf>,
+}
+
+/// What to do when the bundled thread id already exists locally.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportConflict {
+    /// Refuse to import.
+    #[default]
+    Fail,
+    /// Import under a freshly generated thread id.
+    NewId,
+    /// Overwrite the existing rollout file.
+    Replace,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SessionBundleImportOptions {
+    pub on_conflict: ImportConflict,
+    /// Rewrites the recorded working directory, for when the repository
+    /// lives somewhere else on this machine.
+    pub cwd: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionBundleImport {
+    pub thread_id: ThreadId,
+    /// Set when the thread was imported under a new id.
+    pub remapped_from: Option<ThreadId>,
+    pub rollout_path: PathBuf,
+}
+
+/// Writes the thread recorded at `rollout_path` to a bundle at `out`.
+pub async fn export_session_bundle(
+    codex_home: &Path,
+    rollout_path: &Path,
+    out: &Path,
+) -> io::Result<SessionBundleExport> {
+    let contents = fs::read_to_string(rollout_path)?;
+    let thread_id = session_meta_id(&contents)?;
+    let thread_name = find_thread_name_by_id(codex_home, &thread_id).await?;
+    let rollout_file_name = rollout_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .ok_or_else(|| io::Error::other("rollout path has no file name"))?;
+
+    let mut images = Vec::new();
+    let mut missing_images = Vec::new();
+    let mut writer = ZipWriter::new(fs::File::create(out)?);
+    let options = SimpleFileOptions::default();
+    for path in referenced_images(&contents) {
+        if images
+            .iter()
+            .any(|image: &BundledImage| image.original_path == path)
+        {
+            continue;
+        }
+        let Ok(bytes) = fs::read(&path) else {
+            missing_images.push(path);
+            continue;
+        };
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let entry = format!("{IMAGES_ENTRY_DIR}/{}-{file_name}", images.len());
+        writer
+            .start_file(entry.as_str(), options)
+            .map_err(io::Error::other)?;
+        writer.write_all(&bytes)?;
+        images.push(BundledImage {
+            original_path: path,
+            entry,
+        });
+    }
+
+    let manifest = BundleManifest {
+        format_version: BUNDLE_FORMAT_VERSION,
+        thread_id,
+        thread_name,
+        rollout_file_name,
+        images,
+    };
+    writer
+        .start_file(ROLLOUT_ENTRY, options)
+        .map_err(io::Error::other)?;
+    writer.write_all(contents.as_bytes())?;
+    writer
+        .start_file(MANIFEST_ENTRY, options)
+        .map_err(io::Error::other)?;
+    writer.write_all(&serde_json::to_vec_pretty(&manifest).map_err(io::Error::other)?)?;
+    writer.finish().map_err(io::Error::other)?;
+
+    Ok(SessionBundleExport {
+        thread_id,
+        images: manifest.images.len(),
+        missing_images,
+    })
+}
+
+/// Restores a bundle written by [`export_session_bundle`] into `codex_home`.
+pub async fn import_session_bundle(
+    codex_home: &Path,
+    bundle: &Path,
+    options: &SessionBundleImportOptions,
+) -> io::Result<SessionBundleImport> {
+    let mut archive = ZipArchive::new(fs::File::open(bundle)?).map_err(io::Error::other)?;
+    let manifest: BundleManifest = serde_json::from_str(&read_entry(&mut archive, MANIFEST_ENTRY)?)
+        .map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid manifest: {err}"),
+            )
+        })?;
+    if manifest.format_version > BUNDLE_FORMAT_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "bundle format {} is newer than this Codex supports ({BUNDLE_FORMAT_VERSION})",
+                manifest.format_version
+            ),
+        ));
+    }
+    validate_rollout_file_name(&manifest.rollout_file_name)?;
+    let contents = read_entry(&mut archive, ROLLOUT_ENTRY)?;
+    let original_id = manifest.thread_id;
+    let rollout_id = session_meta_id(&contents)?;
+    if rollout_id != original_id {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("bundle manifest names thread {original_id} but its rollout is {rollout_id}"),
+        ));
+    }
+
+    let existing = find_thread_path_by_id_str(codex_home, &original_id.to_string()).await?;
+    let (thread_id, target) = match (existing, options.on_conflict) {
+        (None, _) => (original_id, None),
+        (Some(path), ImportConflict::Replace) => (original_id, Some(path)),
+        (Some(_), ImportConflict::NewId) => (ThreadId::new(), None),
+        (Some(path), ImportConflict::Fail) => {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "thread {original_id} already exists at {}; import with a new id or replace it",
+                    path.display()
+                ),
+            ));
+        }
+    };
+
+    let images_dir = codex_home
+        .join(IMPORTED_IMAGES_SUBDIR)
+        .join(thread_id.to_string());
+    let mut image_paths = HashMap::new();
+    for image in &manifest.images {
+        let Some(file_name) = Path::new(&image.entry).file_name() else {
+            continue;
+        };
+        let mut bytes = Vec::new();
+        archive
+            .by_name(&image.entry)
+            .map_err(io::Error::other)?
+            .read_to_end(&mut bytes)?;
+        fs::create_dir_all(&images_dir)?;
+        let restored = images_dir.join(file_name);
+        fs::write(&restored, bytes)?;
+        image_paths.insert(image.original_path.clone(), restored);
+    }
+
+    let rewritten = rewrite_rollout(
+        &contents,
+        (thread_id != original_id).then_some(thread_id),
+        options.cwd.as_deref(),
+        &image_paths,
+    )?;
+    let rollout_path = match target {
+        Some(path) => path,
+        None => {
+            let file_name = manifest
+                .rollout_file_name
+                .replace(&original_id.to_string(), &thread_id.to_string());
+            let (year, month, day) =
+                rollout_date_parts(OsStr::new(&file_name)).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("unexpected rollout file name {file_name}"),
+                    )
+                })?;
+            let dir = codex_home
+                .join(SESSIONS_SUBDIR)
+                .join(year)
+                .join(month)
+                .join(day);
+            fs::create_dir_all(&dir)?;
+            dir.join(file_name)
+        }
+    };
+    fs::write(&rollout_path, rewritten)?;
+    if let Some(name) = manifest.thread_name.as_deref() {
+        append_thread_name(codex_home, thread_id, name).await?;
+    }
+
+    Ok(SessionBundleImport {
+        thread_id,
+        remapped_from: (thread_id != original_id).then_some(original_id),
+        rollout_path,
+    })
+}
+
+/// Only plain `rollout-*.jsonl` file names are accepted, so a crafted
+/// manifest cannot write outside the sessions directory.
+fn validate_rollout_file_name(name: &str) -> io::Result<()> {
+    let plain = Path::new(name).file_name() == Some(OsStr::new(name))
+        && !name.contains(['/', '\\'])
+        && !name.contains("..");
+    if plain && name.ends_with(".jsonl") && rollout_date_parts(OsStr::new(name)).is_some() {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unexpected rollout file name {name}"),
+        ))
+    }
+}
+
+fn read_entry(archive: &mut ZipArchive<fs::File>, name: &str) -> io::Result<String> {
+    let mut contents = String::new();
+    archive
+        .by_name(name)
+        .map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("not a session bundle ({name}: {err})"),
+            )
+        })?
+        .read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+fn session_meta_id(contents: &str) -> io::Result<ThreadId> {
+    contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<JsonValue>(line).ok())
+        .find(|line| line["type"] == "session_meta")
+        .and_then(|line| line["payload"]["id"].as_str().map(str::to_string))
+        .and_then(|id| ThreadId::from_string(&id).ok())
+        .ok_or_else(|| io::Error::other("rollout file has no session metadata"))
+}
+
+fn is_user_message(line: &JsonValue) -> bool {
+    line["type"] == "event_msg" && line["payload"]["type"] == "user_message"
+}
+
+fn referenced_images(contents: &str) -> Vec<PathBuf> {
+    contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<JsonValue>(line).ok())
+        .filter(is_user_message)
+        .flat_map(|line| {
+            line["payload"]["local_images"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(JsonValue::as_str)
+                .map(PathBuf::from)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Applies the id, working directory, and image path remapping line by line;
+/// lines that are not rollout items are kept as they are.
+fn rewrite_rollout(
+    contents: &str,
+    new_id: Option<ThreadId>,
+    cwd: Option<&Path>,
+    image_paths: &HashMap<PathBuf, PathBuf>,
+) -> io::Result<String> {
+    let mut out = String::with_capacity(contents.len());
+    for line in contents.lines() {
+        let mut value = match serde_json::from_str::<JsonValue>(line) {
+            Ok(value) if value.get("payload").is_some_and(JsonValue::is_object) => value,
+            _ => {
+                out.push_str(line);
+                out.push('\n');
+                continue;
+            }
+        };
+        // The record is re-framed below since its length changes.
+        if let Some(record) = value.as_object_mut() {
+            record.remove(LENGTH_KEY);
+        }
+        let kind = value["type"].as_str().unwrap_or_default().to_string();
+        let payload = &mut value["payload"];
+        if kind == "session_meta"
+            && let Some(new_id) = new_id
+        {
+            payload["id"] = JsonValue::String(new_id.to_string());
+        }
+        if let Some(cwd) = cwd
+            && (kind == "session_meta" || kind == "turn_context")
+        {
+            payload["cwd"] = JsonValue::String(cwd.to_string_lossy().into_owned());
+        }
+        if let Some(images) = payload
+            .get_mut("local_images")
+            .and_then(JsonValue::as_array_mut)
+        {
+            for image in images {
+                if let Some(restored) = image
+                    .as_str()
+                    .and_then(|path| image_paths.get(Path::new(path)))
+                {
+                    *image = JsonValue::String(restored.to_string_lossy().into_owned());
+                }
+            }
+        }
+        out.push_str(&frame_record(
+            &serde_json::to_string(&value).map_err(io::Error::other)?,
+        ));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rollout::recovery::recover_rollout;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    const THREAD_ID: &str = "0199a213-81c0-7800-8aa1-bbab2a035a53";
+
+    fn write_rollout(codex_home: &Path, image: &Path) -> PathBuf {
+        let dir = codex_home.join(SESSIONS_SUBDIR).join("2025/10/07");
+        fs::create_dir_all(&dir).expect("mkdir");
+        let path = dir.join(format!("rollout-2025-10-07T10-00-00-{THREAD_ID}.jsonl"));
+        let lines = [
+            serde_json::json!({
+                "timestamp": "2025-10-07T10:00:00.000Z",
+                "type": "session_meta",
+                "payload": {
+                    "id": THREAD_ID,
+                    "timestamp": "2025-10-07T10:00:00.000Z",
+                    "cwd": "/laptop/repo",
+                    "originator": "codex_cli_rs",
+                    "cli_version": "0.0.0",
+                    "model_provider": "openai"
+                }
+            }),
+            serde_json::json!({
+                "timestamp": "2025-10-07T10:00:01.000Z",
+                "type": "event_msg",
+                "payload": {
+                    "type": "user_message",
+                    "message": "look at this",
+                    "local_images": [image]
+                }
+            }),
+        ];
+        let contents: String = lines
+            .iter()
+            .map(|line| frame_record(&line.to_string()))
+            .collect();
+        fs::write(&path, contents).expect("write rollout");
+        path
+    }
+
+    fn write_bundle(path: &Path, manifest: &JsonValue, rollout: &str) {
+        let mut writer = ZipWriter::new(fs::File::create(path).expect("create bundle"));
+        let options = SimpleFileOptions::default();
+        writer.start_file(ROLLOUT_ENTRY, options).expect("rollout");
+        writer.write_all(rollout.as_bytes()).expect("write rollout");
+        writer
+            .start_file(MANIFEST_ENTRY, options)
+            .expect("manifest");
+        writer
+            .write_all(manifest.to_string().as_bytes())
+            .expect("write manifest");
+        writer.finish().expect("finish bundle");
+    }
+
+    #[tokio::test]
+    async fn rejects_crafted_manifests() {
+        let source = TempDir::new().expect("tempdir");
+        let target = TempDir::new().expect("tempdir");
+        let rollout = write_rollout(source.path(), &source.path().join("missing.png"));
+        let contents = fs::read_to_string(rollout).expect("read rollout");
+        let bundle = source.path().join("evil.codexsession");
+        let options = SessionBundleImportOptions::default();
+
+        for file_name in [
+            format!("rollout-2025-10-07T10-00-00-{THREAD_ID}.jsonl/../../../../evil.jsonl"),
+            format!("../rollout-2025-10-07T10-00-00-{THREAD_ID}.jsonl"),
+            "rollout-../../../../../../evil.jsonl".to_string(),
+            format!("rollout-2025-10-07T10-00-00-{THREAD_ID}.sh"),
+        ] {
+            let manifest = serde_json::json!({
+                "format_version": BUNDLE_FORMAT_VERSION,
+                "thread_id": THREAD_ID,
+                "rollout_file_name": file_name,
+            });
+            write_bundle(&bundle, &manifest, &contents);
+            let err = import_session_bundle(target.path(), &bundle, &options)
+                .await
+                .expect_err("crafted file name");
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData, "{file_name}");
+        }
+
+        let other_id = ThreadId::new();
+        let manifest = serde_json::json!({
+            "format_version": BUNDLE_FORMAT_VERSION,
+            "thread_id": other_id,
+            "rollout_file_name": format!("rollout-2025-10-07T10-00-00-{other_id}.jsonl"),
+        });
+        write_bundle(&bundle, &manifest, &contents);
+        let err = import_session_bundle(target.path(), &bundle, &options)
+            .await
+            .expect_err("mismatched thread id");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(
+            fs::read_dir(target.path())
+                .expect("read target")
+                .next()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn round_trips_rollout_and_images_with_remapping() {
+        let laptop = TempDir::new().expect("tempdir");
+        let workstation = TempDir::new().expect("tempdir");
+        let image = laptop.path().join("shot.png");
+        fs::write(&image, b"png").expect("write image");
+        let rollout = write_rollout(laptop.path(), &image);
+        let bundle = laptop.path().join("out.codexsession");
+
+        let exported = export_session_bundle(laptop.path(), &rollout, &bundle)
+            .await
+            .expect("export");
+        assert_eq!(exported.images, 1);
+
+        let options = SessionBundleImportOptions {
+            on_conflict: ImportConflict::Fail,
+            cwd: Some(PathBuf::from("/workstation/repo")),
+        };
+        let imported = import_session_bundle(workstation.path(), &bundle, &options)
+            .await
+            .expect("import");
+        assert_eq!(imported.thread_id.to_string(), THREAD_ID);
+        assert_eq!(imported.remapped_from, None);
+
+        let contents = fs::read_to_string(&imported.rollout_path).expect("read import");
+        assert!(contents.contains("\"cwd\":\"/workstation/repo\""));
+        // Rewritten records are re-framed with their new length.
+        assert_eq!(
+            recover_rollout(&imported.rollout_path)
+                .await
+                .expect("check framing"),
+            None
+        );
+        let restored = referenced_images(&contents);
+        assert_eq!(restored.len(), 1);
+        assert!(restored[0].starts_with(workstation.path().join(IMPORTED_IMAGES_SUBDIR)));
+        assert_eq!(fs::read(&restored[0]).expect("read image"), b"png");
+
+        let conflict = import_session_bundle(workstation.path(), &bundle, &options)
+            .await
+            .expect_err("duplicate import");
+        assert_eq!(conflict.kind(), io::ErrorKind::AlreadyExists);
+
+        let remapped = import_session_bundle(
+            workstation.path(),
+            &bundle,
+            &SessionBundleImportOptions {
+                on_conflict: ImportConflict::NewId,
+                cwd: None,
+            },
+        )
+        .await
+        .expect("import with new id");
+        assert_eq!(
+            remapped.remapped_from.map(|id| id.to_string()),
+            Some(THREAD_ID.to_string())
+        );
+        let contents = fs::read_to_string(&remapped.rollout_path).expect("read import");
+        assert_eq!(
+            session_meta_id(&contents).expect("session meta"),
+            remapped.thread_id
+        );
+        assert!(
+            remapped
+                .rollout_path
+                .to_string_lossy()
+                .ends_with(&format!("{}.jsonl", remapped.thread_id))
+        );
+    }
+}
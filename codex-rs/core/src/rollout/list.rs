@@ -962,6 +962,30 @@ async fn walk_rollout_files(
     Ok(())
 }
 
+/// Paths of every rollout file under a `sessions/`-style root, newest first.
+pub(crate) async fn collect_rollout_paths(root: &Path) -> io::Result<Vec<PathBuf>> {
+    struct PathCollector(Vec<PathBuf>);
+
+    #[async_trait]
+    impl RolloutFileVisitor for PathCollector {
+        async fn visit(
+            &mut self,
+            _ts: OffsetDateTime,
+            _id: Uuid,
+            path: PathBuf,
+            _scanned: usize,
+        ) -> ControlFlow<()> {
+            self.0.push(path);
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut collector = PathCollector(Vec::new());
+    let mut scanned_files = 0usize;
+    walk_rollout_files(root, &mut scanned_files, &mut collector).await?;
+    Ok(collector.0)
+}
+
 struct ProviderMatcher<'a> {
     filters: &'a [String],
     matches_default_provider: bool,
@@ -1046,7 +1070,7 @@ async fn read_head_summary(path: &Path, head_limit: usize) -> io::Result<HeadTai
             RolloutItem::TurnContext(_) => {
                 // Not included in `head`; skip.
             }
-            RolloutItem::Compacted(_) => {
+            RolloutItem::Compacted(_) | RolloutItem::ExecProvenance(_) => {
                 // Not included in `head`; skip.
             }
             RolloutItem::EventMsg(ev) => {
@@ -1102,7 +1126,8 @@ pub async fn read_head_for_summary(path: &Path) -> io::Result<Vec<serde_json::Va
                 }
                 RolloutItem::Compacted(_)
                 | RolloutItem::TurnContext(_)
-                | RolloutItem::EventMsg(_) => {}
+                | RolloutItem::EventMsg(_)
+                | RolloutItem::ExecProvenance(_) => {}
             }
         }
     }
@@ -1250,8 +1275,13 @@ pub async fn find_archived_thread_path_by_id_str(
 pub fn rollout_date_parts(file_name: &OsStr) -> Option<(String, String, String)> {
     let name = file_name.to_string_lossy();
     let date = name.strip_prefix("rollout-")?.get(..10)?;
-    let year = date.get(..4)?.to_string();
-    let month = date.get(5..7)?.to_string();
-    let day = date.get(8..10)?.to_string();
+    let digits = |part: &str| {
+        part.bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| part.to_string())
+    };
+    let year = digits(date.get(..4)?)?;
+    let month = digits(date.get(5..7)?)?;
+    let day = digits(date.get(8..10)?)?;
     Some((year, month, day))
 }
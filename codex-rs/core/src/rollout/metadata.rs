@@ -68,7 +68,8 @@ pub(crate) fn builder_from_items(
         RolloutItem::ResponseItem(_)
         | RolloutItem::Compacted(_)
         | RolloutItem::TurnContext(_)
-        | RolloutItem::EventMsg(_) => None,
+        | RolloutItem::EventMsg(_)
+        | RolloutItem::ExecProvenance(_) => None,
     }) && let Some(builder) = builder_from_session_meta(session_meta, rollout_path)
     {
         return Some(builder);
@@ -507,6 +508,7 @@ mod tests {
         let session_meta = SessionMeta {
             id,
             forked_from_id: None,
+            fork_turn: None,
             timestamp: "2026-01-27T12:34:56Z".to_string(),
             cwd: dir.path().to_path_buf(),
             originator: "cli".to_string(),
@@ -655,6 +657,7 @@ mod tests {
         let session_meta = SessionMeta {
             id,
             forked_from_id: None,
+            fork_turn: None,
             timestamp: event_ts.to_string(),
             cwd: codex_home.to_path_buf(),
             originator: "cli".to_string(),
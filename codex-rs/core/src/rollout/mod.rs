@@ -7,12 +7,20 @@ pub const ARCHIVED_SESSIONS_SUBDIR: &str = "archived_sessions";
 pub const INTERACTIVE_SESSION_SOURCES: &[SessionSource] =
     &[SessionSource::Cli, SessionSource::VSCode];
 
+pub mod archive;
+pub mod audit_log;
+pub mod branches;
+pub mod bundle;
 pub(crate) mod error;
 pub mod list;
 pub(crate) mod metadata;
+pub mod patch_history;
 pub(crate) mod policy;
+pub mod preview;
 pub mod recorder;
+pub mod recovery;
 pub(crate) mod session_index;
+pub(crate) mod session_lock;
 pub(crate) mod truncation;
 
 pub use codex_protocol::protocol::SessionMeta;
@@ -0,0 +1,348 @@
+//! File changes a recorded session made through `apply_patch`.
+//!
+//! Rollouts keep every `apply_patch` call and its output, so the changes a
+//! thread produced can be recovered without the workspace it ran in. Each
+//! successful patch is folded into a per-file change log; comparing the logs
+//! of two threads (for example both sides of a `/compare` run) shows where
+//! their approaches differ. Edits made by shell commands are not recorded
+//! this way and are not included.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_apply_patch::Hunk;
+use codex_apply_patch::parse_patch;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::RolloutItem;
+use similar::ChangeTag;
+use similar::TextDiff;
+
+use super::RolloutRecorder;
+
+pub(super) const APPLY_PATCH_TOOL: &str = "apply_patch";
+pub(super) const APPLY_PATCH_SUCCESS: &str = "Success. Updated the following files";
+
+/// Per-file change log of a thread, keyed by path relative to its cwd when
+/// the file lives under it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionPatches {
+    pub files: BTreeMap<PathBuf, String>,
+}
+
+/// How one file's changes compare between two threads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchComparison {
+    OnlyA(String),
+    OnlyB(String),
+    Same,
+    Different { a: String, b: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePatchComparison {
+    pub path: PathBuf,
+    pub comparison: PatchComparison,
+}
+
+/// Collect the successful `apply_patch` calls recorded at `path`.
+pub async fn load_session_patches(path: &Path) -> io::Result<SessionPatches> {
+    let history = RolloutRecorder::get_rollout_history(path).await?;
+    Ok(session_patches(&history.get_rollout_items()))
+}
+
+pub(super) fn session_patches(items: &[RolloutItem]) -> SessionPatches {
+    let mut cwd: Option<PathBuf> = None;
+    let mut succeeded: HashMap<&str, bool> = HashMap::new();
+    for item in items {
+        match item {
+            RolloutItem::SessionMeta(meta) if cwd.is_none() => {
+                cwd = Some(meta.meta.cwd.clone());
+            }
+            RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput { call_id, output }) => {
+                let ok = output
+                    .text_content()
+                    .is_some_and(|text| text.contains(APPLY_PATCH_SUCCESS));
+                succeeded.insert(call_id.as_str(), ok);
+            }
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCallOutput { call_id, output }) => {
+                succeeded.insert(call_id.as_str(), output.contains(APPLY_PATCH_SUCCESS));
+            }
+            _ => {}
+        }
+    }
+
+    let mut patches = SessionPatches::default();
+    for item in items {
+        let (call_id, input) = match item {
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                name,
+                call_id,
+                input,
+                ..
+            }) if name == APPLY_PATCH_TOOL => (call_id, input.clone()),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                name,
+                call_id,
+                arguments,
+                ..
+            }) if name == APPLY_PATCH_TOOL => {
+                let Some(input) = serde_json::from_str::<serde_json::Value>(arguments)
+                    .ok()
+                    .and_then(|args| args.get("input")?.as_str().map(str::to_string))
+                else {
+                    continue;
+                };
+                (call_id, input)
+            }
+            _ => continue,
+        };
+        if succeeded.get(call_id.as_str()) != Some(&true) {
+            continue;
+        }
+        let Ok(args) = parse_patch(&input) else {
+            continue;
+        };
+        for hunk in args.hunks {
+            let (path, log) = describe_hunk(hunk);
+            let path = relative_to(path, cwd.as_deref());
+            patches.files.entry(path).or_default().push_str(&log);
+        }
+    }
+    patches
+}
+
+fn describe_hunk(hunk: Hunk) -> (PathBuf, String) {
+    match hunk {
+        Hunk::AddFile { path, contents } => {
+            let mut log = "*** Add File\n".to_string();
+            for line in contents.lines() {
+                log.push_str(&format!("+{line}\n"));
+            }
+            (path, log)
+        }
+        Hunk::DeleteFile { path } => (path, "*** Delete File\n".to_string()),
+        Hunk::UpdateFile {
+            path,
+            move_path,
+            chunks,
+        } => {
+            let mut log = String::new();
+            if let Some(move_path) = move_path {
+                log.push_str(&format!("*** Move to: {}\n", move_path.display()));
+            }
+            for chunk in chunks {
+                match chunk.change_context {
+                    Some(context) => log.push_str(&format!("@@ {context}\n")),
+                    None => log.push_str("@@\n"),
+                }
+                for line in chunk.old_lines {
+                    log.push_str(&format!("-{line}\n"));
+                }
+                for line in chunk.new_lines {
+                    log.push_str(&format!("+{line}\n"));
+                }
+            }
+            (path, log)
+        }
+    }
+}
+
+fn relative_to(path: PathBuf, cwd: Option<&Path>) -> PathBuf {
+    match cwd.and_then(|cwd| path.strip_prefix(cwd).ok()) {
+        Some(relative) => relative.to_path_buf(),
+        None => path,
+    }
+}
+
+/// Compare the change logs of two threads file by file, in path order.
+pub fn compare_session_patches(a: &SessionPatches, b: &SessionPatches) -> Vec<FilePatchComparison> {
+    let paths: BTreeSet<&PathBuf> = a.files.keys().chain(b.files.keys()).collect();
+    paths
+        .into_iter()
+        .map(|path| {
+            let comparison = match (a.files.get(path), b.files.get(path)) {
+                (Some(a), Some(b)) if a == b => PatchComparison::Same,
+                (Some(a), Some(b)) => PatchComparison::Different {
+                    a: a.clone(),
+                    b: b.clone(),
+                },
+                (Some(a), None) => PatchComparison::OnlyA(a.clone()),
+                (None, Some(b)) => PatchComparison::OnlyB(b.clone()),
+                (None, None) => PatchComparison::Same,
+            };
+            FilePatchComparison {
+                path: path.clone(),
+                comparison,
+            }
+        })
+        .collect()
+}
+
+/// Unified diff between two change logs of the same file.
+pub fn unified_patch_diff(a: &str, b: &str, label_a: &str, label_b: &str) -> String {
+    TextDiff::from_lines(a, b)
+        .unified_diff()
+        .context_radius(3)
+        .header(label_a, label_b)
+        .to_string()
+}
+
+/// Two-column rendering of the difference between two change logs, each
+/// column `width` characters wide. Changed rows are marked with `<`, `>` or
+/// `|` between the columns.
+pub fn side_by_side_patch_diff(a: &str, b: &str, width: usize) -> Vec<String> {
+    let diff = TextDiff::from_lines(a, b);
+    let mut rows = Vec::new();
+    let mut deleted: Vec<String> = Vec::new();
+    let mut inserted: Vec<String> = Vec::new();
+    let flush = |deleted: &mut Vec<String>, inserted: &mut Vec<String>, rows: &mut Vec<String>| {
+        let len = deleted.len().max(inserted.len());
+        for idx in 0..len {
+            let left = deleted.get(idx).map(String::as_str).unwrap_or_default();
+            let right = inserted.get(idx).map(String::as_str).unwrap_or_default();
+            let marker = match (deleted.get(idx), inserted.get(idx)) {
+                (Some(_), Some(_)) => '|',
+                (Some(_), None) => '<',
+                _ => '>',
+            };
+            rows.push(side_by_side_row(left, marker, right, width));
+        }
+        deleted.clear();
+        inserted.clear();
+    };
+    for change in diff.iter_all_changes() {
+        let line = change.value().trim_end_matches('\n').to_string();
+        match change.tag() {
+            ChangeTag::Delete => deleted.push(line),
+            ChangeTag::Insert => inserted.push(line),
+            ChangeTag::Equal => {
+                flush(&mut deleted, &mut inserted, &mut rows);
+                rows.push(side_by_side_row(&line, ' ', &line, width));
+            }
+        }
+    }
+    flush(&mut deleted, &mut inserted, &mut rows);
+    rows
+}
+
+fn side_by_side_row(left: &str, marker: char, right: &str, width: usize) -> String {
+    let left: String = left.chars().take(width).collect();
+    let right: String = right.chars().take(width).collect();
+    format!("{left:<width$} {marker} {right}")
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::SessionMeta;
+    use codex_protocol::protocol::SessionMetaLine;
+    use pretty_assertions::assert_eq;
+
+    fn patch_call(call_id: &str, input: &str) -> RolloutItem {
+        RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+            id: None,
+            status: None,
+            call_id: call_id.to_string(),
+            name: APPLY_PATCH_TOOL.to_string(),
+            input: input.to_string(),
+        })
+    }
+
+    fn patch_output(call_id: &str, output: &str) -> RolloutItem {
+        RolloutItem::ResponseItem(ResponseItem::CustomToolCallOutput {
+            call_id: call_id.to_string(),
+            output: output.to_string(),
+        })
+    }
+
+    #[test]
+    fn collects_only_successful_patches_relative_to_cwd() {
+        let items = vec![
+            RolloutItem::SessionMeta(SessionMetaLine {
+                meta: SessionMeta {
+                    cwd: PathBuf::from("/repo"),
+                    ..SessionMeta::default()
+                },
+                git: None,
+            }),
+            patch_call(
+                "ok",
+                "*** Begin Patch\n*** Update File: /repo/src/lib.rs\n@@ fn main\n-old\n+new\n*** End Patch",
+            ),
+            patch_output(
+                "ok",
+                "Success. Updated the following files:\nM src/lib.rs\n",
+            ),
+            patch_call(
+                "failed",
+                "*** Begin Patch\n*** Add File: notes.txt\n+draft\n*** End Patch",
+            ),
+            patch_output("failed", "apply_patch verification failed"),
+        ];
+
+        assert_eq!(
+            session_patches(&items).files,
+            BTreeMap::from([(
+                PathBuf::from("src/lib.rs"),
+                "@@ fn main\n-old\n+new\n".to_string(),
+            )])
+        );
+    }
+
+    #[test]
+    fn compares_change_logs_file_by_file() {
+        let a = SessionPatches {
+            files: BTreeMap::from([
+                (PathBuf::from("a.rs"), "+one\n".to_string()),
+                (PathBuf::from("same.rs"), "+x\n".to_string()),
+                (PathBuf::from("shared.rs"), "-old\n+left\n".to_string()),
+            ]),
+        };
+        let b = SessionPatches {
+            files: BTreeMap::from([
+                (PathBuf::from("b.rs"), "+two\n".to_string()),
+                (PathBuf::from("same.rs"), "+x\n".to_string()),
+                (PathBuf::from("shared.rs"), "-old\n+right\n".to_string()),
+            ]),
+        };
+
+        let comparisons: Vec<(PathBuf, PatchComparison)> = compare_session_patches(&a, &b)
+            .into_iter()
+            .map(|file| (file.path, file.comparison))
+            .collect();
+        assert_eq!(
+            comparisons,
+            vec![
+                (
+                    PathBuf::from("a.rs"),
+                    PatchComparison::OnlyA("+one\n".to_string())
+                ),
+                (
+                    PathBuf::from("b.rs"),
+                    PatchComparison::OnlyB("+two\n".to_string())
+                ),
+                (PathBuf::from("same.rs"), PatchComparison::Same),
+                (
+                    PathBuf::from("shared.rs"),
+                    PatchComparison::Different {
+                        a: "-old\n+left\n".to_string(),
+                        b: "-old\n+right\n".to_string(),
+                    }
+                ),
+            ]
+        );
+        assert_eq!(
+            side_by_side_patch_diff("-old\n+left\n", "-old\n+right\n", 8),
+            vec![
+                "-old       -old".to_string(),
+                "+left    | +right".to_string()
+            ]
+        );
+    }
+}
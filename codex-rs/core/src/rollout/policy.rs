@@ -12,6 +12,8 @@ pub(crate) fn is_persisted_response_item(item: &RolloutItem) -> bool {
         RolloutItem::Compacted(_) | RolloutItem::TurnContext(_) | RolloutItem::SessionMeta(_) => {
             true
         }
+        // Provenance records are the audit trail of what ran and why.
+        RolloutItem::ExecProvenance(_) => true,
     }
 }
 
@@ -47,6 +49,11 @@ pub(crate) fn should_persist_event_msg(ev: &EventMsg) -> bool {
         | EventMsg::ExitedReviewMode(_)
         | EventMsg::ThreadRolledBack(_)
         | EventMsg::UndoCompleted(_)
+        | EventMsg::ApprovalTimedOut(_)
+        | EventMsg::GuardrailBlocked(_)
+        | EventMsg::SessionNote(_)
+        | EventMsg::SessionSummary(_)
+        | EventMsg::TurnSources(_)
         | EventMsg::TurnAborted(_) => true,
         EventMsg::ItemCompleted(event) => {
             // Plan items are derived from streaming tags and are not part of the
@@ -82,7 +89,11 @@ pub(crate) fn should_persist_event_msg(ev: &EventMsg) -> bool {
         | EventMsg::StreamError(_)
         | EventMsg::PatchApplyBegin(_)
         | EventMsg::PatchApplyEnd(_)
+        | EventMsg::PatchProposal(_)
+        | EventMsg::DryRunPlan(_)
         | EventMsg::TurnDiff(_)
+        | EventMsg::SessionChanges(_)
+        | EventMsg::ContextBreakdown(_)
         | EventMsg::GetHistoryEntryResponse(_)
         | EventMsg::UndoStarted(_)
         | EventMsg::McpListToolsResponse(_)
@@ -0,0 +1,138 @@
+//! Details of a recorded session shown in the resume picker's preview pane.
+//!
+//! The picker lists sessions from their head summary only. When a session is
+//! selected, or when a filter needs more than the head, the whole rollout is
+//! read once to find the conversation's first prompt, its latest agent reply,
+//! the model it last ran on, whether it changed any files, the notes the
+//! user saved with it and the summary written when the user last quit it.
+
+use std::io;
+use std::path::Path;
+
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+
+use super::RolloutRecorder;
+use super::branches::branch_turns;
+use super::patch_history::session_patches;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionPreview {
+    pub first_prompt: Option<String>,
+    pub last_agent_message: Option<String>,
+    /// Model of the most recent turn.
+    pub model: Option<String>,
+    /// Whether a successful `apply_patch` call is recorded.
+    pub has_diff: bool,
+    /// Notes saved with `/note`, oldest first.
+    pub notes: Vec<String>,
+    /// Bullets of the latest exit summary.
+    pub summary: Vec<String>,
+}
+
+pub async fn load_session_preview(path: &Path) -> io::Result<SessionPreview> {
+    let history = RolloutRecorder::get_rollout_history(path).await?;
+    Ok(session_preview(&history.get_rollout_items()))
+}
+
+fn session_preview(items: &[RolloutItem]) -> SessionPreview {
+    let turns = branch_turns(items);
+    let model = items.iter().rev().find_map(|item| match item {
+        RolloutItem::TurnContext(context) => Some(context.model.clone()),
+        _ => None,
+    });
+    let notes = items
+        .iter()
+        .filter_map(|item| match item {
+            RolloutItem::EventMsg(EventMsg::SessionNote(note)) => Some(note.text.clone()),
+            _ => None,
+        })
+        .collect();
+    let summary = items
+        .iter()
+        .rev()
+        .find_map(|item| match item {
+            RolloutItem::EventMsg(EventMsg::SessionSummary(summary))
+                if !summary.bullets.is_empty() =>
+            {
+                Some(summary.bullets.clone())
+            }
+            _ => None,
+        })
+        .unwrap_or_default();
+    SessionPreview {
+        first_prompt: turns.first().map(|turn| turn.prompt.clone()),
+        last_agent_message: turns.iter().rev().find_map(|turn| turn.reply.clone()),
+        model,
+        has_diff: !session_patches(items).files.is_empty(),
+        notes,
+        summary,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::models::ResponseItem;
+    use codex_protocol::protocol::AgentMessageEvent;
+    use codex_protocol::protocol::SessionNoteEvent;
+    use codex_protocol::protocol::SessionSummaryEvent;
+    use codex_protocol::protocol::UserMessageEvent;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn preview_reads_prompt_reply_and_changes() {
+        let user = |text: &str| {
+            RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                message: text.to_string(),
+                images: None,
+                local_images: Vec::new(),
+                text_elements: Vec::new(),
+            }))
+        };
+        let agent = |text: &str| {
+            RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+                message: text.to_string(),
+            }))
+        };
+        let items = vec![
+            user("add a README"),
+            agent("Added it."),
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "call".to_string(),
+                name: "apply_patch".to_string(),
+                input: "*** Begin Patch\n*** Add File: README.md\n+hi\n*** End Patch".to_string(),
+            }),
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCallOutput {
+                call_id: "call".to_string(),
+                output: "Success. Updated the following files:\nA README.md\n".to_string(),
+            }),
+            RolloutItem::EventMsg(EventMsg::SessionNote(SessionNoteEvent {
+                text: "README wording still needs review".to_string(),
+                in_context: false,
+            })),
+            user("thanks"),
+            RolloutItem::EventMsg(EventMsg::SessionSummary(SessionSummaryEvent {
+                bullets: vec!["Added README.md".to_string()],
+            })),
+            RolloutItem::EventMsg(EventMsg::SessionSummary(SessionSummaryEvent {
+                bullets: Vec::new(),
+            })),
+        ];
+
+        assert_eq!(
+            session_preview(&items),
+            SessionPreview {
+                first_prompt: Some("add a README".to_string()),
+                last_agent_message: Some("Added it.".to_string()),
+                model: None,
+                has_diff: true,
+                notes: vec!["README wording still needs review".to_string()],
+                summary: vec!["Added README.md".to_string()],
+            }
+        );
+        assert_eq!(session_preview(&[]), SessionPreview::default());
+    }
+}
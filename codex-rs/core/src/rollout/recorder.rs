@@ -34,7 +34,12 @@ use super::list::get_threads;
 use super::list::get_threads_in_root;
 use super::metadata;
 use super::policy::is_persisted_response_item;
+use super::recovery::frame_record;
+use super::recovery::recover_rollout;
+use super::session_lock::SessionLock;
+use super::session_lock::TAKEOVER_POLL_INTERVAL;
 use crate::config::Config;
+use crate::config::types::RolloutFsync;
 use crate::default_client::originator;
 use crate::git_info::collect_git_info;
 use crate::path_utils;
@@ -71,6 +76,7 @@ pub enum RolloutRecorderParams {
     Create {
         conversation_id: ThreadId,
         forked_from_id: Option<ThreadId>,
+        fork_turn: Option<u32>,
         source: SessionSource,
         base_instructions: BaseInstructions,
         dynamic_tools: Vec<DynamicToolSpec>,
@@ -98,6 +104,7 @@ impl RolloutRecorderParams {
     pub fn new(
         conversation_id: ThreadId,
         forked_from_id: Option<ThreadId>,
+        fork_turn: Option<u32>,
         source: SessionSource,
         base_instructions: BaseInstructions,
         dynamic_tools: Vec<DynamicToolSpec>,
@@ -105,6 +112,7 @@ impl RolloutRecorderParams {
         Self::Create {
             conversation_id,
             forked_from_id,
+            fork_turn,
             source,
             base_instructions,
             dynamic_tools,
@@ -290,17 +298,22 @@ impl RolloutRecorder {
     /// For newly created sessions, this precomputes path/metadata and defers
     /// file creation/open until an explicit `persist()` call.
     ///
-    /// For resumed sessions, this immediately opens the existing rollout file.
+    /// For resumed sessions, this immediately locks and opens the existing
+    /// rollout file, first truncating any records torn by a crash so new
+    /// events are not appended onto garbage. Fails with
+    /// [`super::session_lock::SessionActiveError`] when another process is
+    /// writing the session, unless `config.force_takeover` is set.
     pub async fn new(
         config: &Config,
         params: RolloutRecorderParams,
         state_db_ctx: Option<StateDbHandle>,
         state_builder: Option<ThreadMetadataBuilder>,
     ) -> std::io::Result<Self> {
-        let (file, deferred_log_file_info, rollout_path, meta) = match params {
+        let (file, session_lock, deferred_log_file_info, rollout_path, meta) = match params {
             RolloutRecorderParams::Create {
                 conversation_id,
                 forked_from_id,
+                fork_turn,
                 source,
                 base_instructions,
                 dynamic_tools,
@@ -321,6 +334,7 @@ impl RolloutRecorder {
                 let session_meta = SessionMeta {
                     id: session_id,
                     forked_from_id,
+                    fork_turn,
                     timestamp,
                     cwd: config.cwd.clone(),
                     originator: originator().value,
@@ -335,19 +349,24 @@ impl RolloutRecorder {
                     },
                 };
 
-                (None, Some(log_file_info), path, Some(session_meta))
+                (None, None, Some(log_file_info), path, Some(session_meta))
+            }
+            RolloutRecorderParams::Resume { path } => {
+                let session_lock = SessionLock::acquire(&path, config.force_takeover).await?;
+                recover_rollout(&path).await?;
+                (
+                    Some(
+                        tokio::fs::OpenOptions::new()
+                            .append(true)
+                            .open(&path)
+                            .await?,
+                    ),
+                    Some(session_lock),
+                    None,
+                    path,
+                    None,
+                )
             }
-            RolloutRecorderParams::Resume { path } => (
-                Some(
-                    tokio::fs::OpenOptions::new()
-                        .append(true)
-                        .open(&path)
-                        .await?,
-                ),
-                None,
-                path,
-                None,
-            ),
         };
 
         // Clone the cwd for the spawned task to collect git info asynchronously
@@ -363,6 +382,7 @@ impl RolloutRecorder {
         // driver instead of blocking the runtime.
         tokio::task::spawn(rollout_writer(
             file,
+            session_lock,
             deferred_log_file_info,
             rx,
             meta,
@@ -371,6 +391,7 @@ impl RolloutRecorder {
             state_db_ctx.clone(),
             state_builder,
             config.model_provider_id.clone(),
+            config.rollout_fsync,
         ));
 
         Ok(Self {
@@ -435,7 +456,10 @@ impl RolloutRecorder {
         path: &Path,
     ) -> std::io::Result<(Vec<RolloutItem>, Option<ThreadId>, usize)> {
         trace!("Resuming rollout from {path:?}");
-        let text = tokio::fs::read_to_string(path).await?;
+        // A crash can tear a multi-byte character in the last record; decode
+        // lossily so the damage stays confined to that record.
+        let bytes = tokio::fs::read(path).await?;
+        let text = String::from_utf8_lossy(&bytes);
         if text.trim().is_empty() {
             return Err(IoError::other("empty session file"));
         }
@@ -479,6 +503,9 @@ impl RolloutRecorder {
                     RolloutItem::EventMsg(_ev) => {
                         items.push(RolloutItem::EventMsg(_ev));
                     }
+                    RolloutItem::ExecProvenance(item) => {
+                        items.push(RolloutItem::ExecProvenance(item));
+                    }
                 },
                 Err(e) => {
                     trace!("failed to parse rollout line: {e}");
@@ -589,6 +616,7 @@ fn open_log_file(path: &Path) -> std::io::Result<File> {
 #[allow(clippy::too_many_arguments)]
 async fn rollout_writer(
     file: Option<tokio::fs::File>,
+    mut session_lock: Option<SessionLock>,
     mut deferred_log_file_info: Option<LogFileInfo>,
     mut rx: mpsc::Receiver<RolloutCmd>,
     mut meta: Option<SessionMeta>,
@@ -597,9 +625,13 @@ async fn rollout_writer(
     state_db_ctx: Option<StateDbHandle>,
     mut state_builder: Option<ThreadMetadataBuilder>,
     default_provider: String,
+    fsync: RolloutFsync,
 ) -> std::io::Result<()> {
-    let mut writer = file.map(|file| JsonlWriter { file });
+    let mut writer = file.map(|file| JsonlWriter { file, fsync });
     let mut buffered_items = Vec::<RolloutItem>::new();
+    // Set once another process forces a takeover; from then on this process
+    // must not touch the rollout.
+    let mut taken_over = false;
     if let Some(builder) = state_builder.as_mut() {
         builder.rollout_path = rollout_path.clone();
     }
@@ -621,8 +653,30 @@ async fn rollout_writer(
         .await?;
     }
 
+    // Checks for takeovers even while idle so a forced takeover does not
+    // wait for this session's next event.
+    let mut takeover_check = tokio::time::interval(TAKEOVER_POLL_INTERVAL);
+
     // Process rollout commands
-    while let Some(cmd) = rx.recv().await {
+    loop {
+        let cmd = tokio::select! {
+            cmd = rx.recv() => match cmd {
+                Some(cmd) => cmd,
+                None => break,
+            },
+            _ = takeover_check.tick(), if session_lock.is_some() => {
+                if session_lock.as_ref().is_some_and(SessionLock::taken_over) {
+                    warn!(
+                        "session {} was taken over by another process; no longer recording it",
+                        rollout_path.display()
+                    );
+                    taken_over = true;
+                    writer = None;
+                    session_lock = None;
+                }
+                continue;
+            }
+        };
         match cmd {
             RolloutCmd::AddItems(items) => {
                 let mut persisted_items = Vec::new();
@@ -631,7 +685,7 @@ async fn rollout_writer(
                         persisted_items.push(item);
                     }
                 }
-                if persisted_items.is_empty() {
+                if persisted_items.is_empty() || taken_over {
                     continue;
                 }
 
@@ -651,7 +705,7 @@ async fn rollout_writer(
                 .await?;
             }
             RolloutCmd::Persist { ack } => {
-                if writer.is_none() {
+                if writer.is_none() && !taken_over {
                     let result = async {
                         let Some(log_file_info) = deferred_log_file_info.take() else {
                             return Err(IoError::other(
@@ -659,8 +713,17 @@ async fn rollout_writer(
                             ));
                         };
                         let file = open_log_file(log_file_info.path.as_path())?;
+                        session_lock =
+                            match SessionLock::acquire(log_file_info.path.as_path(), false).await {
+                                Ok(lock) => Some(lock),
+                                Err(err) => {
+                                    warn!("failed to lock new rollout: {err}");
+                                    None
+                                }
+                            };
                         writer = Some(JsonlWriter {
                             file: tokio::fs::File::from_std(file),
+                            fsync,
                         });
 
                         if let Some(session_meta) = meta.take() {
@@ -703,7 +766,7 @@ async fn rollout_writer(
             RolloutCmd::Flush { ack } => {
                 // Deferred fresh threads may not have an initialized file yet.
                 if let Some(writer) = writer.as_mut()
-                    && let Err(e) = writer.file.flush().await
+                    && let Err(e) = writer.commit().await
                 {
                     let _ = ack.send(());
                     return Err(e);
@@ -711,6 +774,12 @@ async fn rollout_writer(
                 let _ = ack.send(());
             }
             RolloutCmd::Shutdown { ack } => {
+                if let Some(writer) = writer.as_mut()
+                    && let Err(e) = writer.commit().await
+                {
+                    warn!("failed to sync rollout on shutdown: {e}");
+                }
+                session_lock = None;
                 let _ = ack.send(());
             }
         }
@@ -783,6 +852,7 @@ async fn write_and_reconcile_items(
 
 struct JsonlWriter {
     file: tokio::fs::File,
+    fsync: RolloutFsync,
 }
 
 #[derive(serde::Serialize)]
@@ -807,11 +877,27 @@ impl JsonlWriter {
         };
         self.write_line(&line).await
     }
+
+    /// Appends one length-prefixed record. The record and its terminating
+    /// newline go out in a single write on an append-mode handle, so a crash
+    /// can only tear the last record, never interleave two.
     async fn write_line(&mut self, item: &impl serde::Serialize) -> std::io::Result<()> {
-        let mut json = serde_json::to_string(item)?;
-        json.push('\n');
-        self.file.write_all(json.as_bytes()).await?;
+        let record = frame_record(&serde_json::to_string(item)?);
+        self.file.write_all(record.as_bytes()).await?;
+        self.file.flush().await?;
+        if self.fsync == RolloutFsync::Always {
+            self.file.sync_data().await?;
+        }
+        Ok(())
+    }
+
+    /// Flushes written records and, unless syncing is disabled, makes them
+    /// durable.
+    async fn commit(&mut self) -> std::io::Result<()> {
         self.file.flush().await?;
+        if self.fsync != RolloutFsync::Never {
+            self.file.sync_data().await?;
+        }
         Ok(())
     }
 }
@@ -913,6 +999,7 @@ mod tests {
             RolloutRecorderParams::new(
                 thread_id,
                 None,
+                None,
                 SessionSource::Exec,
                 BaseInstructions::default(),
                 Vec::new(),
@@ -0,0 +1,356 @@
+//! Salvaging rollouts damaged by a crash or power loss.
+//!
+//! Each rollout record is appended with one write as a single line that
+//! opens with its own length: `{"len":N,` followed by the N remaining bytes
+//! of the JSON object (see [`frame_record`]). The prefix is an ordinary JSON
+//! key, so every reader, including older versions, still sees one JSON object
+//! per line and ignores it. A crash can still leave a torn final record, and
+//! power loss can leave a tail of NUL bytes. Appending after either glues new
+//! events onto garbage, so before a rollout is reopened for writing,
+//! [`recover_rollout`] truncates it back to the last intact record and leaves
+//! a `<rollout>.recovery` note next to it for the resume picker. Records
+//! written before framing was introduced are intact when they parse as JSON.
+
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::warn;
+
+const RECOVERY_NOTE_EXTENSION: &str = "recovery";
+
+/// How much of a rollout's end [`rollout_lost_events`] reads.
+const TAIL_WINDOW: u64 = 64 * 1024;
+
+/// Opens every framed record; the length of the rest of the record follows.
+const LENGTH_PREFIX: &str = "{\"len\":";
+
+/// The key [`frame_record`] adds; rewriters drop it since it only describes
+/// the record as written.
+pub(crate) const LENGTH_KEY: &str = "len";
+
+/// What a recovery pass dropped from a rollout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RolloutRecovery {
+    /// Records after the last intact one that could not be salvaged.
+    pub lost_events: usize,
+}
+
+/// Truncates the rollout at `path` after its last intact record. Returns
+/// `None` when the file was already intact; damaged files that hold no intact
+/// record at all are left untouched.
+pub(crate) async fn recover_rollout(path: &Path) -> io::Result<Option<RolloutRecovery>> {
+    let bytes = tokio::fs::read(path).await?;
+    let intact_len = intact_prefix_len(&bytes);
+    if intact_len == bytes.len() {
+        return Ok(None);
+    }
+    if intact_len == 0 {
+        warn!(
+            "rollout {} has no intact records; leaving it untouched",
+            path.display()
+        );
+        return Ok(None);
+    }
+
+    let lost_events = count_records(&bytes[intact_len..]).max(1);
+    let file = tokio::fs::OpenOptions::new().write(true).open(path).await?;
+    file.set_len(intact_len as u64).await?;
+    file.sync_all().await?;
+
+    // Repeated recoveries of the same session add up.
+    let previous = read_recovery_note(path).map_or(0, |note| note.lost_events);
+    let recovery = RolloutRecovery {
+        lost_events: previous + lost_events,
+    };
+    let note = serde_json::to_vec(&recovery)?;
+    tokio::fs::write(recovery_note_path(path), note).await?;
+    warn!(
+        "recovered rollout {}: dropped {lost_events} damaged record(s) at the end",
+        path.display()
+    );
+    Ok(Some(recovery))
+}
+
+/// Reads the note left by [`recover_rollout`] for the rollout at `path`.
+pub fn read_recovery_note(path: &Path) -> Option<RolloutRecovery> {
+    let bytes = std::fs::read(recovery_note_path(path)).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+/// Events the rollout at `path` has lost, for the resume picker: those
+/// dropped by earlier recoveries plus a torn tail the next resume will drop.
+/// Only the last [`TAIL_WINDOW`] bytes are inspected so listing stays cheap.
+pub fn rollout_lost_events(path: &Path) -> Option<RolloutRecovery> {
+    let recovered = read_recovery_note(path).map_or(0, |note| note.lost_events);
+    let pending = torn_tail_records(path).unwrap_or_else(|err| {
+        warn!("failed to inspect rollout {}: {err}", path.display());
+        0
+    });
+    let lost_events = recovered + pending;
+    (lost_events > 0).then_some(RolloutRecovery { lost_events })
+}
+
+/// Damaged records [`recover_rollout`] would drop from the end of the
+/// rollout at `path`.
+fn torn_tail_records(path: &Path) -> io::Result<usize> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    let start = len.saturating_sub(TAIL_WINDOW);
+    file.seek(SeekFrom::Start(start))?;
+    let mut tail = Vec::new();
+    file.read_to_end(&mut tail)?;
+    if start > 0 {
+        // Skip the record cut by the window; it ends before anything torn.
+        let Some(newline) = tail.iter().position(|b| *b == b'\n') else {
+            return Ok(0);
+        };
+        tail.drain(..=newline);
+    }
+    let intact_len = intact_prefix_len(&tail);
+    if intact_len == tail.len() || (intact_len == 0 && start == 0) {
+        return Ok(0);
+    }
+    Ok(count_records(&tail[intact_len..]).max(1))
+}
+
+/// Moves the recovery note along with a rollout that moved from `from` to
+/// `to`. Missing notes are not an error.
+pub(crate) async fn move_recovery_note(from: &Path, to: &Path) -> io::Result<()> {
+    match tokio::fs::rename(recovery_note_path(from), recovery_note_path(to)).await {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Removes the recovery note of a deleted rollout. Missing notes are not an
+/// error.
+pub(crate) async fn remove_recovery_note(path: &Path) -> io::Result<()> {
+    match tokio::fs::remove_file(recovery_note_path(path)).await {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Frames the serialized JSON object `json` as one rollout line, newline
+/// included.
+pub(crate) fn frame_record(json: &str) -> String {
+    let rest = json.strip_prefix('{').unwrap_or(json);
+    format!("{LENGTH_PREFIX}{},{rest}\n", rest.len())
+}
+
+fn recovery_note_path(path: &Path) -> PathBuf {
+    let mut note = path.as_os_str().to_owned();
+    note.push(".");
+    note.push(RECOVERY_NOTE_EXTENSION);
+    PathBuf::from(note)
+}
+
+/// Length of the prefix of `bytes` that ends with the last intact
+/// newline-terminated record. Damaged records before it are kept; loading
+/// already skips them.
+fn intact_prefix_len(bytes: &[u8]) -> usize {
+    let mut intact_len = 0;
+    let mut start = 0;
+    while let Some(newline) = bytes[start..].iter().position(|b| *b == b'\n') {
+        let end = start + newline + 1;
+        let record = &bytes[start..end - 1];
+        if is_blank(record) || is_intact(record) {
+            intact_len = end;
+        }
+        start = end;
+    }
+    intact_len
+}
+
+/// Framed records are intact when exactly their announced length follows the
+/// prefix and none of it is power-loss padding; unframed ones when they parse.
+fn is_intact(record: &[u8]) -> bool {
+    let Some(framed) = record.strip_prefix(LENGTH_PREFIX.as_bytes()) else {
+        return serde_json::from_slice::<serde_json::Value>(record).is_ok();
+    };
+    let digits = framed.iter().take_while(|b| b.is_ascii_digit()).count();
+    let Some(rest) = framed[digits..].strip_prefix(b",") else {
+        return false;
+    };
+    std::str::from_utf8(&framed[..digits])
+        .ok()
+        .and_then(|len| len.parse::<usize>().ok())
+        .is_some_and(|len| len == rest.len() && rest.ends_with(b"}") && !rest.contains(&0))
+}
+
+fn count_records(bytes: &[u8]) -> usize {
+    bytes
+        .split(|b| *b == b'\n')
+        .filter(|record| !is_blank(record))
+        .count()
+}
+
+/// Whitespace and the NUL padding power loss can leave behind.
+fn is_blank(record: &[u8]) -> bool {
+    record.iter().all(|b| b.is_ascii_whitespace() || *b == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    const INTACT: &str = "{\"type\":\"session_meta\"}\n{\"type\":\"event_msg\"}\n";
+
+    #[tokio::test]
+    async fn intact_rollout_is_left_alone() -> io::Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("rollout.jsonl");
+        tokio::fs::write(&path, INTACT).await?;
+
+        assert_eq!(recover_rollout(&path).await?, None);
+        assert_eq!(tokio::fs::read_to_string(&path).await?, INTACT);
+        assert_eq!(read_recovery_note(&path), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn torn_tail_is_truncated_and_noted() -> io::Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("rollout.jsonl");
+        tokio::fs::write(&path, format!("{INTACT}{{\"type\":\"resp")).await?;
+
+        let expected = RolloutRecovery { lost_events: 1 };
+        assert_eq!(recover_rollout(&path).await?, Some(expected));
+        assert_eq!(tokio::fs::read_to_string(&path).await?, INTACT);
+        assert_eq!(read_recovery_note(&path), Some(expected));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nul_padding_and_garbage_records_count_as_lost() -> io::Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("rollout.jsonl");
+        let mut damaged = INTACT.as_bytes().to_vec();
+        damaged.extend_from_slice(b"{\"type\":\"x\"\n{\"ty\n");
+        damaged.extend_from_slice(&[0; 64]);
+        tokio::fs::write(&path, &damaged).await?;
+
+        assert_eq!(
+            recover_rollout(&path).await?,
+            Some(RolloutRecovery { lost_events: 2 })
+        );
+        assert_eq!(tokio::fs::read_to_string(&path).await?, INTACT);
+        Ok(())
+    }
+
+    #[test]
+    fn framed_records_stay_json_and_are_checked_by_length() {
+        let record = frame_record(r#"{"timestamp":"t","type":"event_msg"}"#);
+        assert_eq!(
+            record,
+            "{\"len\":35,\"timestamp\":\"t\",\"type\":\"event_msg\"}\n"
+        );
+        let value: serde_json::Value = serde_json::from_str(&record).expect("json");
+        assert_eq!(value["type"], "event_msg");
+
+        let record = record.trim_end().as_bytes();
+        assert!(is_intact(record));
+        assert!(!is_intact(&record[..record.len() - 1]));
+        let mut padded = record.to_vec();
+        padded[20] = 0;
+        assert!(!is_intact(&padded));
+        assert!(!is_intact(b"{\"len\":3,\"a\":1}"));
+    }
+
+    #[tokio::test]
+    async fn torn_framed_record_is_truncated() -> io::Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("rollout.jsonl");
+        let intact = format!("{INTACT}{}", frame_record(r#"{"type":"event_msg"}"#));
+        let torn = frame_record(r#"{"type":"response_item","payload":{}}"#);
+        tokio::fs::write(&path, format!("{intact}{}", &torn[..torn.len() - 5])).await?;
+
+        assert_eq!(
+            recover_rollout(&path).await?,
+            Some(RolloutRecovery { lost_events: 1 })
+        );
+        assert_eq!(tokio::fs::read_to_string(&path).await?, intact);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn torn_tail_is_reported_before_and_after_recovery() -> io::Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("rollout.jsonl");
+        tokio::fs::write(&path, INTACT).await?;
+        assert_eq!(rollout_lost_events(&path), None);
+
+        tokio::fs::write(&path, format!("{INTACT}{{\"b\"\n{{\"c\"")).await?;
+        let expected = Some(RolloutRecovery { lost_events: 2 });
+        assert_eq!(rollout_lost_events(&path), expected);
+        recover_rollout(&path).await?;
+        assert_eq!(rollout_lost_events(&path), expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn torn_tail_of_a_long_rollout_is_found_in_the_window() -> io::Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("rollout.jsonl");
+        let record = frame_record(&format!(r#"{{"text":"{}"}}"#, "x".repeat(1000)));
+        let mut contents = record.repeat(100);
+        tokio::fs::write(&path, &contents).await?;
+        assert_eq!(rollout_lost_events(&path), None);
+
+        contents.push_str(&record[..500]);
+        tokio::fs::write(&path, &contents).await?;
+        assert_eq!(
+            rollout_lost_events(&path),
+            Some(RolloutRecovery { lost_events: 1 })
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn damaged_record_before_intact_ones_is_kept() -> io::Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("rollout.jsonl");
+        let contents = format!("{{\"type\":\"session_meta\"}}\nnot json\n{INTACT}");
+        tokio::fs::write(&path, &contents).await?;
+
+        assert_eq!(recover_rollout(&path).await?, None);
+        assert_eq!(tokio::fs::read_to_string(&path).await?, contents);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn repeated_recoveries_accumulate_and_follow_moves() -> io::Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("rollout.jsonl");
+        tokio::fs::write(&path, format!("{INTACT}{{\"a\"")).await?;
+        recover_rollout(&path).await?;
+        tokio::fs::write(&path, format!("{INTACT}{{\"b\"\n{{\"c\"")).await?;
+        recover_rollout(&path).await?;
+        assert_eq!(
+            read_recovery_note(&path),
+            Some(RolloutRecovery { lost_events: 3 })
+        );
+
+        let moved = dir.path().join("moved.jsonl");
+        tokio::fs::rename(&path, &moved).await?;
+        move_recovery_note(&path, &moved).await?;
+        assert_eq!(read_recovery_note(&path), None);
+        assert_eq!(
+            read_recovery_note(&moved),
+            Some(RolloutRecovery { lost_events: 3 })
+        );
+
+        remove_recovery_note(&moved).await?;
+        assert_eq!(read_recovery_note(&moved), None);
+        remove_recovery_note(&moved).await?;
+        Ok(())
+    }
+}
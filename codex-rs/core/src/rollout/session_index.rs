@@ -142,7 +142,7 @@ pub async fn find_thread_path_by_name_str(
     super::list::find_thread_path_by_id_str(codex_home, &thread_id.to_string()).await
 }
 
-fn session_index_path(codex_home: &Path) -> PathBuf {
+pub(crate) fn session_index_path(codex_home: &Path) -> PathBuf {
     codex_home.join(SESSION_INDEX_FILE)
 }
 
@@ -0,0 +1,373 @@
+//! Advisory locks that keep two processes from writing the same rollout.
+//!
+//! The process writing a rollout holds an OS lock on `<rollout>.lock` and
+//! records its pid and start time in it. The OS drops the lock when the
+//! process exits, so a lock file left behind by a crash never blocks a
+//! resume. Lock files are never deleted, not even when their rollout is
+//! archived or removed: unlinking a file another process is about to lock
+//! would let both think they own it.
+//!
+//! A forced takeover rewrites the holder record; the current holder notices
+//! within [`TAKEOVER_POLL_INTERVAL`], stops writing and releases the OS lock,
+//! which the new process then acquires.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::Weak;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::time::Instant;
+use tracing::warn;
+
+const LOCK_EXTENSION: &str = "lock";
+
+/// How often a holder checks whether its session was taken over.
+pub(crate) const TAKEOVER_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long a forced takeover waits for the holder to let go.
+const TAKEOVER_TIMEOUT: Duration = Duration::from_secs(10);
+
+const TAKEOVER_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// Locks held by this process, keyed by lock file path. Rollouts reopened
+/// in-process share the lock already held instead of contending with it.
+static HELD_LOCKS: LazyLock<Mutex<HashMap<PathBuf, Weak<HeldLock>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Returned (wrapped in an [`io::Error`] of kind
+/// [`io::ErrorKind::ResourceBusy`]) when another process is writing the
+/// session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionActiveError {
+    /// Who holds the lock; unknown when the lock file could not be read.
+    pub holder: Option<SessionLockHolder>,
+}
+
+impl fmt::Display for SessionActiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.holder {
+            Some(holder) => write!(
+                f,
+                "session is active in another process (pid {}, started at {})",
+                holder.pid,
+                holder.started_at.to_rfc3339_opts(SecondsFormat::Secs, true)
+            )?,
+            None => write!(f, "session is active in another process")?,
+        }
+        write!(f, "; pass --force-takeover to take it over")
+    }
+}
+
+impl std::error::Error for SessionActiveError {}
+
+/// The process recorded in a session lock file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionLockHolder {
+    pub pid: u32,
+    pub started_at: DateTime<Utc>,
+}
+
+impl SessionLockHolder {
+    fn current() -> Self {
+        Self {
+            pid: std::process::id(),
+            started_at: Utc::now(),
+        }
+    }
+}
+
+/// An acquired session lock. Clones made for rollouts reopened in-process
+/// share it; dropping the last one releases the OS lock.
+#[derive(Debug, Clone)]
+pub(crate) struct SessionLock {
+    held: Arc<HeldLock>,
+}
+
+#[derive(Debug)]
+struct HeldLock {
+    path: PathBuf,
+    file: Mutex<File>,
+    holder: SessionLockHolder,
+}
+
+impl Drop for HeldLock {
+    fn drop(&mut self) {
+        let mut held = HELD_LOCKS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if held
+            .get(&self.path)
+            .is_some_and(|lock| lock.strong_count() == 0)
+        {
+            held.remove(&self.path);
+        }
+    }
+}
+
+impl SessionLock {
+    /// Locks the session whose rollout lives at `rollout_path`.
+    ///
+    /// When this process already writes the session (a thread reopened
+    /// in-process), the lock it holds is shared. With `force_takeover`, a
+    /// live holder in another process is asked to let go and the OS lock is
+    /// acquired once it has; the takeover fails if it does not let go within
+    /// [`TAKEOVER_TIMEOUT`].
+    pub(crate) async fn acquire(rollout_path: &Path, force_takeover: bool) -> io::Result<Self> {
+        let path = lock_path(rollout_path);
+        if let Some(lock) = held_lock(&path) {
+            return Ok(lock);
+        }
+        let mut file = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)?;
+        let holder = SessionLockHolder::current();
+        if !try_lock(&file)? {
+            // Raced with another acquire in this process.
+            if let Some(lock) = held_lock(&path) {
+                return Ok(lock);
+            }
+            let current = read_holder(&mut file);
+            if !force_takeover {
+                return Err(io::Error::new(
+                    io::ErrorKind::ResourceBusy,
+                    SessionActiveError { holder: current },
+                ));
+            }
+            warn!(
+                "taking over session {} from {current:?}",
+                rollout_path.display()
+            );
+            write_holder(&mut file, &holder)?;
+            if let Err(err) = wait_for_release(&file).await {
+                // Leave the holder in place rather than stopping it without
+                // anyone taking over.
+                if let Some(current) = current
+                    && read_holder(&mut file).as_ref() == Some(&holder)
+                {
+                    write_holder(&mut file, &current)?;
+                }
+                return Err(err);
+            }
+        }
+        write_holder(&mut file, &holder)?;
+        Ok(register(path, file, holder))
+    }
+
+    /// Whether another process has since forced a takeover of the session.
+    /// Unreadable lock files count as still ours.
+    pub(crate) fn taken_over(&self) -> bool {
+        let mut file = self
+            .held
+            .file
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        read_holder(&mut file).is_some_and(|current| current != self.held.holder)
+    }
+}
+
+fn lock_path(rollout_path: &Path) -> PathBuf {
+    let path = std::path::absolute(rollout_path).unwrap_or_else(|_| rollout_path.to_path_buf());
+    let mut path = path.into_os_string();
+    path.push(".");
+    path.push(LOCK_EXTENSION);
+    PathBuf::from(path)
+}
+
+fn held_lock(path: &Path) -> Option<SessionLock> {
+    let held = HELD_LOCKS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let held = held.get(path)?.upgrade()?;
+    Some(SessionLock { held })
+}
+
+fn register(path: PathBuf, file: File, holder: SessionLockHolder) -> SessionLock {
+    let held = Arc::new(HeldLock {
+        path: path.clone(),
+        file: Mutex::new(file),
+        holder,
+    });
+    HELD_LOCKS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(path, Arc::downgrade(&held));
+    SessionLock { held }
+}
+
+/// Returns `false` when another open lock file holds the lock.
+fn try_lock(file: &File) -> io::Result<bool> {
+    match file.try_lock() {
+        Ok(()) => Ok(true),
+        Err(std::fs::TryLockError::WouldBlock) => Ok(false),
+        Err(std::fs::TryLockError::Error(err)) => Err(err),
+    }
+}
+
+async fn wait_for_release(file: &File) -> io::Result<()> {
+    let deadline = Instant::now() + TAKEOVER_TIMEOUT;
+    while !try_lock(file)? {
+        if Instant::now() >= deadline {
+            return Err(io::Error::new(
+                io::ErrorKind::ResourceBusy,
+                format!(
+                    "the process writing the session did not release it within {}s",
+                    TAKEOVER_TIMEOUT.as_secs()
+                ),
+            ));
+        }
+        tokio::time::sleep(TAKEOVER_RETRY_DELAY).await;
+    }
+    Ok(())
+}
+
+fn read_holder(file: &mut File) -> Option<SessionLockHolder> {
+    let mut contents = String::new();
+    file.seek(SeekFrom::Start(0)).ok()?;
+    file.read_to_string(&mut contents).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+fn write_holder(file: &mut File, holder: &SessionLockHolder) -> io::Result<()> {
+    let contents = serde_json::to_vec(holder)?;
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(&contents)?;
+    file.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn rollout_path(dir: &TempDir) -> PathBuf {
+        dir.path().join("rollout-2025-01-01T00-00-00-abc.jsonl")
+    }
+
+    /// Stands in for another process: a different pid holding the OS lock.
+    fn lock_as_other_process(path: &Path) -> File {
+        let mut file = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(lock_path(path))
+            .expect("open lock file");
+        file.try_lock().expect("lock");
+        let holder = SessionLockHolder {
+            pid: std::process::id().wrapping_add(1),
+            started_at: DateTime::parse_from_rfc3339("2025-01-01T08:30:00Z")
+                .expect("timestamp")
+                .with_timezone(&Utc),
+        };
+        write_holder(&mut file, &holder).expect("write holder");
+        file
+    }
+
+    #[tokio::test]
+    async fn lock_is_exclusive_until_dropped() {
+        let dir = TempDir::new().expect("tempdir");
+        let path = rollout_path(&dir);
+
+        let lock = SessionLock::acquire(&path, false).await.expect("acquire");
+        let mut other = File::options()
+            .read(true)
+            .write(true)
+            .open(lock_path(&path))
+            .expect("open lock file");
+        assert!(!try_lock(&other).expect("try lock"));
+        drop(lock);
+        assert!(try_lock(&other).expect("try lock"));
+        other.unlock().expect("unlock");
+
+        SessionLock::acquire(&path, false).await.expect("reacquire");
+        assert_eq!(
+            read_holder(&mut other).map(|holder| holder.pid),
+            Some(std::process::id())
+        );
+    }
+
+    #[tokio::test]
+    async fn reopening_in_process_shares_the_lock() {
+        let dir = TempDir::new().expect("tempdir");
+        let path = rollout_path(&dir);
+
+        let first = SessionLock::acquire(&path, false).await.expect("acquire");
+        let second = SessionLock::acquire(&path, false).await.expect("share");
+        assert!(Arc::ptr_eq(&first.held, &second.held));
+        drop(first);
+        assert!(held_lock(&lock_path(&path)).is_some());
+        drop(second);
+        assert!(held_lock(&lock_path(&path)).is_none());
+    }
+
+    #[tokio::test]
+    async fn active_session_reports_holder() {
+        let dir = TempDir::new().expect("tempdir");
+        let path = rollout_path(&dir);
+        let _other = lock_as_other_process(&path);
+
+        let err = SessionLock::acquire(&path, false)
+            .await
+            .expect_err("session is active");
+        assert_eq!(err.kind(), io::ErrorKind::ResourceBusy);
+        let pid = std::process::id().wrapping_add(1);
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "session is active in another process (pid {pid}, started at 2025-01-01T08:30:00Z); pass --force-takeover to take it over"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn forced_takeover_acquires_the_lock_once_the_holder_lets_go() {
+        let dir = TempDir::new().expect("tempdir");
+        let path = rollout_path(&dir);
+        let mut other = lock_as_other_process(&path);
+        let original = read_holder(&mut other).expect("holder");
+        // The holder stops writing once it sees it was taken over.
+        let holder = tokio::spawn(async move {
+            while read_holder(&mut other).as_ref() == Some(&original) {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        });
+
+        let lock = SessionLock::acquire(&path, true).await.expect("take over");
+        holder.await.expect("holder task");
+        assert!(!lock.taken_over());
+
+        let mut file = File::options()
+            .read(true)
+            .write(true)
+            .open(lock_path(&path))
+            .expect("open lock file");
+        assert!(!try_lock(&file).expect("try lock"));
+        let next_holder = SessionLockHolder {
+            pid: std::process::id().wrapping_add(2),
+            started_at: Utc::now(),
+        };
+        write_holder(&mut file, &next_holder).expect("write holder");
+        assert!(lock.taken_over());
+    }
+}
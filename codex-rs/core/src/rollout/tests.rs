@@ -272,6 +272,10 @@ fn rollout_date_parts_extracts_directory_components() {
         parts,
         Some(("2025".to_string(), "03".to_string(), "01".to_string()))
     );
+    assert_eq!(
+        rollout_date_parts(OsStr::new("rollout-../../../x-y.jsonl")),
+        None
+    );
 }
 
 async fn assert_state_db_rollout_path(
@@ -1081,6 +1085,7 @@ async fn test_updated_at_uses_file_mtime() -> Result<()> {
             meta: SessionMeta {
                 id: conversation_id,
                 forked_from_id: None,
+                fork_turn: None,
                 timestamp: ts.to_string(),
                 cwd: ".".into(),
                 originator: "test_originator".into(),
@@ -0,0 +1,362 @@
+//! Dry-run analysis for `codex sandbox explain`.
+//!
+//! Nothing is executed. The command goes through the same execpolicy
+//! evaluation the shell tool uses, and the configured [`SandboxPolicy`] is
+//! described in terms of what each platform backend would actually enforce.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use codex_execpolicy::Policy;
+use codex_execpolicy::RuleMatch;
+use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::SandboxPolicy;
+use codex_protocol::protocol::WritableRoot;
+
+use crate::config::Config;
+use crate::exec_env::create_env;
+use crate::exec_policy::ExecApprovalRequest;
+use crate::exec_policy::ExecPolicyError;
+use crate::exec_policy::ExecPolicyManager;
+use crate::exec_policy::commands_for_exec_policy;
+use crate::exec_policy::load_exec_policy;
+use crate::exec_policy::render_decision_for_unmatched_command;
+use crate::features::Feature;
+use crate::sandboxing::SandboxPermissions;
+use crate::tools::sandboxing::ExecApprovalRequirement;
+
+/// Syscalls the Linux seccomp filter answers with `EPERM` when network access
+/// is disabled. Keep in sync with `codex-linux-sandbox`'s network filter.
+const SECCOMP_DENIED_SYSCALLS: [&str; 17] = [
+    "connect",
+    "accept",
+    "accept4",
+    "bind",
+    "listen",
+    "getpeername",
+    "getsockname",
+    "shutdown",
+    "sendto",
+    "sendmmsg",
+    "recvmmsg",
+    "getsockopt",
+    "setsockopt",
+    "ptrace",
+    "io_uring_setup",
+    "io_uring_enter",
+    "io_uring_register",
+];
+
+/// What would happen to a command before it reaches a sandbox.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExplainedOutcome {
+    /// Runs without a prompt inside the configured sandbox.
+    Sandboxed,
+    /// An execpolicy `allow` rule matched, so the command runs unsandboxed.
+    BypassesSandbox,
+    /// The user is asked first; `reason` is set when a policy rule asked.
+    NeedsApproval { reason: Option<String> },
+    /// Rejected without running.
+    Forbidden { reason: String },
+}
+
+/// Restrictions one platform backend would apply to the command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendExplanation {
+    pub platform: &'static str,
+    pub backend: &'static str,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SandboxExplanation {
+    pub sandbox_policy: SandboxPolicy,
+    pub approval_policy: AskForApproval,
+    pub writable_roots: Vec<WritableRoot>,
+    pub network_access: bool,
+    /// Rules that matched each parsed command, including heuristic fallbacks.
+    pub matched_rules: Vec<RuleMatch>,
+    pub outcome: ExplainedOutcome,
+    pub backends: Vec<BackendExplanation>,
+    /// Environment the command would see. Keychain secrets are replaced by a
+    /// placeholder naming their keychain entry.
+    pub env: BTreeMap<String, String>,
+}
+
+/// Loads the execpolicy rules for `config` and explains `command` against them.
+pub async fn explain_command(
+    config: &Config,
+    command: &[String],
+) -> Result<SandboxExplanation, ExecPolicyError> {
+    let policy = load_exec_policy(&config.config_layer_stack).await?;
+    Ok(explain_command_with_policy(config, policy, command).await)
+}
+
+async fn explain_command_with_policy(
+    config: &Config,
+    policy: Policy,
+    command: &[String],
+) -> SandboxExplanation {
+    let sandbox_policy = config.sandbox_policy.get().clone();
+    let approval_policy = *config.approval_policy.get();
+
+    let (commands, _) = commands_for_exec_policy(command);
+    let fallback = |cmd: &[String]| {
+        render_decision_for_unmatched_command(
+            approval_policy,
+            &sandbox_policy,
+            cmd,
+            SandboxPermissions::UseDefault,
+        )
+    };
+    let matched_rules = policy
+        .check_multiple(commands.iter(), &fallback)
+        .matched_rules;
+
+    let requirement = ExecPolicyManager::new(Arc::new(policy))
+        .create_exec_approval_requirement_for_command(ExecApprovalRequest {
+            command,
+            approval_policy,
+            sandbox_policy: &sandbox_policy,
+            sandbox_permissions: SandboxPermissions::UseDefault,
+            prefix_rule: None,
+        })
+        .await;
+    let outcome = match requirement {
+        ExecApprovalRequirement::Skip {
+            bypass_sandbox: true,
+            ..
+        } => ExplainedOutcome::BypassesSandbox,
+        ExecApprovalRequirement::Skip { .. } => ExplainedOutcome::Sandboxed,
+        ExecApprovalRequirement::NeedsApproval { reason, .. } => {
+            ExplainedOutcome::NeedsApproval { reason }
+        }
+        ExecApprovalRequirement::Forbidden { reason } => ExplainedOutcome::Forbidden { reason },
+    };
+
+    let mut env: BTreeMap<String, String> = create_env(&config.shell_environment_policy, None)
+        .into_iter()
+        .collect();
+    for (name, secret) in &config.shell_environment_policy.secrets {
+        if let Some(value) = env.get_mut(name) {
+            *value = format!(
+                "<keychain {}/{}>",
+                secret.source.service, secret.source.account
+            );
+        }
+    }
+
+    SandboxExplanation {
+        writable_roots: sandbox_policy.get_writable_roots_with_cwd(&config.cwd),
+        network_access: sandbox_policy.has_full_network_access(),
+        backends: describe_backends(
+            &sandbox_policy,
+            &config.cwd,
+            config.network.is_some(),
+            config.features.enabled(Feature::UseLinuxSandboxBwrap),
+        ),
+        sandbox_policy,
+        approval_policy,
+        matched_rules,
+        outcome,
+        env,
+    }
+}
+
+fn describe_backends(
+    sandbox_policy: &SandboxPolicy,
+    cwd: &Path,
+    managed_network: bool,
+    use_bwrap: bool,
+) -> Vec<BackendExplanation> {
+    let roots: Vec<String> = sandbox_policy
+        .get_writable_roots_with_cwd(cwd)
+        .iter()
+        .map(|root| root.root.display().to_string())
+        .collect();
+    let network_blocked = !sandbox_policy.has_full_network_access();
+    let writes = if sandbox_policy.has_full_disk_write_access() {
+        None
+    } else if roots.is_empty() {
+        Some("writes denied everywhere".to_string())
+    } else {
+        Some(format!("writes allowed only under {}", roots.join(", ")))
+    };
+
+    let mut macos = Vec::new();
+    let mut linux = Vec::new();
+    let mut windows = Vec::new();
+    match writes {
+        None => {
+            let note = if matches!(sandbox_policy, SandboxPolicy::ExternalSandbox { .. }) {
+                "not sandboxed by Codex: the external sandbox decides".to_string()
+            } else {
+                "not sandboxed: the command runs with your user's permissions".to_string()
+            };
+            macos.push(note.clone());
+            linux.push(note.clone());
+            windows.push(note);
+        }
+        Some(writes) => {
+            macos.push(format!(
+                "{writes}; .git and .codex under a writable root stay read-only"
+            ));
+            macos.push(if network_blocked {
+                "outbound network denied by the profile".to_string()
+            } else {
+                "network allowed".to_string()
+            });
+
+            if use_bwrap {
+                linux.push(format!(
+                    "bubblewrap: {writes}; .git and .codex under a writable root stay read-only"
+                ));
+            } else {
+                linux.push(format!(
+                    "landlock: {writes}; read-only subpaths are not enforced"
+                ));
+            }
+            // A managed network proxy keeps the filter on even when the policy
+            // allows network access.
+            linux.push(if network_blocked || managed_network {
+                format!(
+                    "seccomp returns EPERM for {}, and socket/socketpair other than AF_UNIX",
+                    SECCOMP_DENIED_SYSCALLS.join(", ")
+                )
+            } else {
+                "network allowed".to_string()
+            });
+
+            windows.push(format!("restricted token with write ACLs: {writes}"));
+            windows.push(if network_blocked {
+                "network blocked through proxy environment variables (and the firewall in elevated mode)"
+                    .to_string()
+            } else {
+                "network allowed".to_string()
+            });
+            if matches!(sandbox_policy, SandboxPolicy::ReadOnly) {
+                windows.push(
+                    "read-only is not enforced by a real sandbox here, so non-trivial commands prompt"
+                        .to_string(),
+                );
+            }
+        }
+    }
+
+    vec![
+        BackendExplanation {
+            platform: "macos",
+            backend: "seatbelt",
+            notes: macos,
+        },
+        BackendExplanation {
+            platform: "linux",
+            backend: if use_bwrap {
+                "bubblewrap+seccomp"
+            } else {
+                "landlock+seccomp"
+            },
+            notes: linux,
+        },
+        BackendExplanation {
+            platform: "windows",
+            backend: "restricted token",
+            notes: windows,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_config;
+    use codex_execpolicy::Decision;
+    use codex_execpolicy::PolicyParser;
+    use pretty_assertions::assert_eq;
+
+    fn policy(source: &str) -> Policy {
+        let mut parser = PolicyParser::new();
+        parser.parse("test.rules", source).expect("parse rules");
+        parser.build()
+    }
+
+    fn command(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[tokio::test]
+    async fn reports_matched_rule_and_approval() {
+        let mut config = test_config();
+        config
+            .approval_policy
+            .set(AskForApproval::OnRequest)
+            .expect("approval policy");
+        config
+            .sandbox_policy
+            .set(SandboxPolicy::new_workspace_write_policy())
+            .expect("sandbox policy");
+        let rules = policy(
+            r#"prefix_rule(pattern = ["git", "push"], decision = "prompt", justification = "pushes leave the machine")"#,
+        );
+
+        let explanation =
+            explain_command_with_policy(&config, rules, &command(&["git", "push", "origin"])).await;
+
+        assert_eq!(
+            explanation.outcome,
+            ExplainedOutcome::NeedsApproval {
+                reason: Some(
+                    "`git push origin` requires approval: pushes leave the machine".to_string()
+                ),
+            }
+        );
+        assert_eq!(
+            explanation
+                .matched_rules
+                .iter()
+                .map(RuleMatch::decision)
+                .collect::<Vec<_>>(),
+            vec![Decision::Prompt]
+        );
+        assert!(!explanation.network_access);
+        assert_eq!(
+            explanation
+                .writable_roots
+                .first()
+                .map(|root| root.root.as_path()),
+            Some(config.cwd.as_path())
+        );
+    }
+
+    #[tokio::test]
+    async fn allow_rule_bypasses_sandbox() {
+        let config = test_config();
+        let rules = policy(r#"prefix_rule(pattern = ["make"], decision = "allow")"#);
+
+        let explanation = explain_command_with_policy(&config, rules, &command(&["make"])).await;
+
+        assert_eq!(explanation.outcome, ExplainedOutcome::BypassesSandbox);
+    }
+
+    #[test]
+    fn seccomp_listed_only_when_network_is_blocked() {
+        let cwd = std::env::temp_dir();
+        let linux_notes = |policy: &SandboxPolicy| {
+            describe_backends(policy, &cwd, false, false)
+                .into_iter()
+                .find(|backend| backend.platform == "linux")
+                .map(|backend| backend.notes)
+                .unwrap_or_default()
+        };
+
+        let blocked = linux_notes(&SandboxPolicy::new_workspace_write_policy());
+        assert!(blocked.iter().any(|note| note.starts_with("seccomp")));
+
+        let open = linux_notes(&SandboxPolicy::DangerFullAccess);
+        assert_eq!(
+            open,
+            vec!["not sandboxed: the command runs with your user's permissions".to_string()]
+        );
+    }
+}
@@ -25,6 +25,7 @@ use crate::tools::sandboxing::SandboxablePreference;
 use codex_network_proxy::NetworkProxy;
 use codex_protocol::config_types::WindowsSandboxLevel;
 pub use codex_protocol::models::SandboxPermissions;
+use codex_utils_absolute_path::AbsolutePathBuf;
 use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
@@ -49,6 +50,8 @@ pub struct ExecRequest {
     pub expiration: ExecExpiration,
     pub sandbox: SandboxType,
     pub windows_sandbox_level: WindowsSandboxLevel,
+    /// Capture output through ConPTY on Windows when running unsandboxed.
+    pub windows_conpty: bool,
     pub sandbox_permissions: SandboxPermissions,
     pub justification: Option<String>,
     pub arg0: Option<String>,
@@ -66,9 +69,13 @@ pub(crate) struct SandboxTransformRequest<'a> {
     // to make shared ownership explicit across runtime/sandbox plumbing.
     pub network: Option<&'a NetworkProxy>,
     pub sandbox_policy_cwd: &'a Path,
+    /// Existing paths from `protected_paths` to keep read-only under the
+    /// writable roots.
+    pub protected_paths: &'a [AbsolutePathBuf],
     pub codex_linux_sandbox_exe: Option<&'a PathBuf>,
     pub use_linux_sandbox_bwrap: bool,
     pub windows_sandbox_level: WindowsSandboxLevel,
+    pub windows_conpty: bool,
 }
 
 pub enum SandboxPreference {
@@ -141,9 +148,11 @@ impl SandboxManager {
             enforce_managed_network,
             network,
             sandbox_policy_cwd,
+            protected_paths,
             codex_linux_sandbox_exe,
             use_linux_sandbox_bwrap,
             windows_sandbox_level,
+            windows_conpty,
         } = request;
         let mut env = spec.env;
         if !policy.has_full_network_access() {
@@ -167,6 +176,7 @@ impl SandboxManager {
                     command.clone(),
                     policy,
                     sandbox_policy_cwd,
+                    protected_paths,
                     enforce_managed_network,
                     network,
                 );
@@ -185,6 +195,7 @@ impl SandboxManager {
                     command.clone(),
                     policy,
                     sandbox_policy_cwd,
+                    protected_paths,
                     use_linux_sandbox_bwrap,
                     allow_proxy_network,
                 );
@@ -217,6 +228,7 @@ impl SandboxManager {
             expiration: spec.expiration,
             sandbox,
             windows_sandbox_level,
+            windows_conpty,
             sandbox_permissions: spec.sandbox_permissions,
             justification: spec.justification,
             arg0: arg0_override,
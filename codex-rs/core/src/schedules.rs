@@ -0,0 +1,513 @@
+//! Scheduled headless tasks managed by `codex schedule`.
+//!
+//! Tasks live in `$CODEX_HOME/schedules.toml`. Each one pairs a five-field
+//! cron expression (minute, hour, day of month, month, day of week, evaluated
+//! in local time) with an exec prompt. `codex schedule run` is meant to be
+//! invoked every minute by systemd, launchd, or cron: it runs every task whose
+//! next occurrence since its previous run has passed, once, even if several
+//! occurrences were missed while the machine was off.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::Context;
+use anyhow::bail;
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::Local;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::TimeDelta;
+use chrono::TimeZone;
+use chrono::Timelike;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::default_client::create_client;
+use crate::path_utils::write_atomically;
+
+/// File under `CODEX_HOME` that stores scheduled tasks.
+pub const SCHEDULES_FILE: &str = "schedules.toml";
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How far ahead to look for the next occurrence. Four years plus a day
+/// covers expressions that only match on February 29.
+const SEARCH_DAYS: i64 = 4 * 365 + 1;
+
+/// A parsed five-field cron expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    // Standard cron semantics: when both day fields are restricted, a day
+    // matches if either one does.
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> anyhow::Result<Self> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields.as_slice() else {
+            bail!(
+                "cron expression `{expression}` must have 5 fields (minute hour day-of-month month day-of-week)"
+            );
+        };
+        let mut days_of_week = parse_field(day_of_week, 0, 7, "day-of-week")?;
+        // Both 0 and 7 mean Sunday.
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59, "minute")?,
+            hours: parse_field(hour, 0, 23, "hour")?,
+            days_of_month: parse_field(day_of_month, 1, 31, "day-of-month")?,
+            months: parse_field(month, 1, 12, "month")?,
+            days_of_week,
+            day_of_month_restricted: !day_of_month.starts_with('*'),
+            day_of_week_restricted: !day_of_week.starts_with('*'),
+        })
+    }
+
+    /// The first matching minute strictly after `after`, in the same time
+    /// zone. Local times skipped by a DST change never match.
+    pub fn next_after<Tz: TimeZone>(&self, after: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let tz = after.timezone();
+        let start = after
+            .naive_local()
+            .with_second(0)?
+            .with_nanosecond(0)?
+            .checked_add_signed(TimeDelta::minutes(1))?;
+        let limit = start.checked_add_signed(TimeDelta::days(SEARCH_DAYS))?;
+        let mut candidate = start;
+        while candidate < limit {
+            if !self.date_matches(candidate.date()) {
+                candidate = start_of_next_day(candidate)?;
+                continue;
+            }
+            if !has(self.hours, candidate.hour()) {
+                candidate = candidate
+                    .with_minute(0)?
+                    .checked_add_signed(TimeDelta::hours(1))?;
+                continue;
+            }
+            if has(self.minutes, candidate.minute())
+                && let Some(time) = tz.from_local_datetime(&candidate).earliest()
+            {
+                return Some(time);
+            }
+            candidate = candidate.checked_add_signed(TimeDelta::minutes(1))?;
+        }
+        None
+    }
+
+    fn date_matches(&self, date: NaiveDate) -> bool {
+        if !has(self.months, date.month()) {
+            return false;
+        }
+        let day_of_month = has(self.days_of_month, date.day());
+        let day_of_week = has(self.days_of_week, date.weekday().num_days_from_sunday());
+        if self.day_of_month_restricted && self.day_of_week_restricted {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        }
+    }
+}
+
+fn has(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn start_of_next_day(time: NaiveDateTime) -> Option<NaiveDateTime> {
+    time.date().succ_opt()?.and_hms_opt(0, 0, 0)
+}
+
+/// Parses one field (`*`, `5`, `1-5`, `*/15`, `0-30/10`, or a comma-separated
+/// list of those) into a bit mask of the allowed values.
+fn parse_field(field: &str, min: u32, max: u32, name: &str) -> anyhow::Result<u64> {
+    let mut mask = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .with_context(|| format!("invalid step `{step}` in {name} field `{field}`"))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(start, min, max, name)?,
+                parse_value(end, min, max, name)?,
+            )
+        } else {
+            let start = parse_value(range, min, max, name)?;
+            // `5/10` means every 10th value starting at 5.
+            let end = if part.contains('/') { max } else { start };
+            (start, end)
+        };
+        if start > end {
+            bail!("invalid range `{range}` in {name} field `{field}`");
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_value(value: &str, min: u32, max: u32, name: &str) -> anyhow::Result<u32> {
+    value
+        .parse()
+        .ok()
+        .filter(|value| (min..=max).contains(value))
+        .with_context(|| format!("{name} value `{value}` must be a number from {min} to {max}"))
+}
+
+/// Where the result of a scheduled run is reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduleNotify {
+    /// Only recorded as a session.
+    #[default]
+    None,
+    /// Passed to the `notify` program from `config.toml`.
+    Command,
+    /// POSTed as JSON to the task's `webhook_url`.
+    Webhook,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledTask {
+    pub id: String,
+    /// Five-field cron expression, evaluated in local time.
+    pub cron: String,
+    pub prompt: String,
+    /// Working directory the task runs in.
+    pub cwd: PathBuf,
+    #[serde(default)]
+    pub notify: ScheduleNotify,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_run: Option<ScheduledRun>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledRun {
+    pub started_at: DateTime<Utc>,
+    /// Unset until the run finishes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// Session the run was recorded as; resume it with `codex resume`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<String>,
+}
+
+impl ScheduledTask {
+    pub fn schedule(&self) -> anyhow::Result<CronSchedule> {
+        CronSchedule::parse(&self.cron)
+            .with_context(|| format!("scheduled task {} has an invalid schedule", self.id))
+    }
+
+    /// The next occurrence after the previous run, or after creation for a
+    /// task that has never run. It may already be in the past.
+    pub fn next_run(&self) -> anyhow::Result<Option<DateTime<Local>>> {
+        let since = self
+            .last_run
+            .as_ref()
+            .map_or(self.created_at, |run| run.started_at);
+        Ok(self.schedule()?.next_after(&since.with_timezone(&Local)))
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> anyhow::Result<bool> {
+        Ok(self.next_run()?.is_some_and(|next| next <= now))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct SchedulesToml {
+    #[serde(default)]
+    tasks: Vec<ScheduledTask>,
+}
+
+/// The tasks in `$CODEX_HOME/schedules.toml`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleStore {
+    path: PathBuf,
+    pub tasks: Vec<ScheduledTask>,
+}
+
+impl ScheduleStore {
+    /// Loads the store, treating a missing file as empty.
+    pub fn load(codex_home: &Path) -> anyhow::Result<Self> {
+        let path = codex_home.join(SCHEDULES_FILE);
+        let tasks = match std::fs::read_to_string(&path) {
+            Ok(contents) => {
+                toml::from_str::<SchedulesToml>(&contents)
+                    .with_context(|| format!("failed to parse {}", path.display()))?
+                    .tasks
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()));
+            }
+        };
+        Ok(Self { path, tasks })
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let contents = toml::to_string_pretty(&SchedulesToml {
+            tasks: self.tasks.clone(),
+        })?;
+        write_atomically(&self.path, &contents)
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Validates and appends a task with a fresh id.
+    pub fn add(
+        &mut self,
+        cron: String,
+        prompt: String,
+        cwd: PathBuf,
+        notify: ScheduleNotify,
+        webhook_url: Option<String>,
+    ) -> anyhow::Result<&ScheduledTask> {
+        CronSchedule::parse(&cron)?;
+        if prompt.trim().is_empty() {
+            bail!("prompt must not be empty");
+        }
+        match (notify, webhook_url.as_deref()) {
+            (ScheduleNotify::Webhook, None) => bail!("`--notify webhook` requires --webhook-url"),
+            (ScheduleNotify::Webhook, Some(url)) if !url.starts_with("http") => {
+                bail!("webhook url `{url}` must start with http:// or https://")
+            }
+            (ScheduleNotify::None | ScheduleNotify::Command, Some(_)) => {
+                bail!("--webhook-url is only used with `--notify webhook`")
+            }
+            _ => {}
+        }
+        let id = loop {
+            let id = uuid::Uuid::new_v4().simple().to_string()[..8].to_string();
+            if self.get(&id).is_none() {
+                break id;
+            }
+        };
+        self.tasks.push(ScheduledTask {
+            id,
+            cron,
+            prompt,
+            cwd,
+            notify,
+            webhook_url,
+            created_at: Utc::now(),
+            last_run: None,
+        });
+        Ok(&self.tasks[self.tasks.len() - 1])
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ScheduledTask> {
+        self.tasks.iter().find(|task| task.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut ScheduledTask> {
+        self.tasks.iter_mut().find(|task| task.id == id)
+    }
+
+    /// Removes a task, returning it if it existed.
+    pub fn remove(&mut self, id: &str) -> Option<ScheduledTask> {
+        let index = self.tasks.iter().position(|task| task.id == id)?;
+        Some(self.tasks.remove(index))
+    }
+}
+
+/// Result of a scheduled run, as delivered to the notify program or webhook.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "type",
+    rename = "scheduled-task-complete",
+    rename_all = "kebab-case"
+)]
+pub struct ScheduledTaskReport {
+    pub task_id: String,
+    pub cron: String,
+    pub prompt: String,
+    pub cwd: String,
+    pub started_at: DateTime<Utc>,
+    pub success: bool,
+    pub thread_id: Option<String>,
+    pub last_assistant_message: Option<String>,
+}
+
+/// Reports a finished run according to the task's `notify` setting.
+/// `notify_command` is the `notify` program from `config.toml`; like the
+/// turn-complete notification, the JSON payload is appended as its last
+/// argument.
+pub async fn deliver_report(
+    task: &ScheduledTask,
+    report: &ScheduledTaskReport,
+    notify_command: Option<&[String]>,
+) -> anyhow::Result<()> {
+    match task.notify {
+        ScheduleNotify::None => Ok(()),
+        ScheduleNotify::Command => {
+            let Some((program, args)) = notify_command.and_then(|argv| argv.split_first()) else {
+                bail!("`notify` is not set in config.toml");
+            };
+            std::process::Command::new(program)
+                .args(args)
+                .arg(serde_json::to_string(report)?)
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+                .with_context(|| format!("failed to run notify program `{program}`"))?;
+            Ok(())
+        }
+        ScheduleNotify::Webhook => {
+            let Some(url) = task.webhook_url.as_deref() else {
+                bail!("scheduled task {} has no webhook_url", task.id);
+            };
+            let response = create_client()
+                .post(url)
+                .timeout(WEBHOOK_TIMEOUT)
+                .header("Content-Type", "application/json")
+                .json(report)
+                .send()
+                .await
+                .with_context(|| format!("failed to POST to {url}"))?;
+            if !response.status().is_success() {
+                bail!("webhook {url} returned {}", response.status());
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .expect("timestamp")
+            .with_timezone(&Utc)
+    }
+
+    fn next(expression: &str, after: &str) -> Option<DateTime<Utc>> {
+        CronSchedule::parse(expression)
+            .expect("parse")
+            .next_after(&utc(after))
+    }
+
+    #[test]
+    fn next_after_follows_cron_fields() {
+        // 2026-01-05 is a Monday.
+        assert_eq!(
+            next("0 9 * * 1", "2026-01-05T09:00:00Z"),
+            Some(utc("2026-01-12T09:00:00Z"))
+        );
+        assert_eq!(
+            next("*/15 * * * *", "2026-01-05T09:07:30Z"),
+            Some(utc("2026-01-05T09:15:00Z"))
+        );
+        assert_eq!(
+            next("30 8-17/4 * * *", "2026-01-05T13:00:00Z"),
+            Some(utc("2026-01-05T16:30:00Z"))
+        );
+        // Day-of-month and day-of-week are ORed when both are restricted.
+        assert_eq!(
+            next("0 0 15 * 7", "2026-01-05T00:00:00Z"),
+            Some(utc("2026-01-11T00:00:00Z"))
+        );
+        assert_eq!(
+            next("0 0 29 2 *", "2026-03-01T00:00:00Z"),
+            Some(utc("2028-02-29T00:00:00Z"))
+        );
+        assert_eq!(next("0 0 31 4 *", "2026-01-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_expressions() {
+        for expression in [
+            "0 9 * *",
+            "60 * * * *",
+            "* * 0 * *",
+            "*/0 * * * *",
+            "5-1 * * * *",
+        ] {
+            assert!(
+                CronSchedule::parse(expression).is_err(),
+                "{expression} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn tasks_become_due_once_per_occurrence() {
+        let dir = TempDir::new().expect("tempdir");
+        let mut store = ScheduleStore::load(dir.path()).expect("load");
+        let id = store
+            .add(
+                "0 * * * *".to_string(),
+                "summarize".to_string(),
+                dir.path().to_path_buf(),
+                ScheduleNotify::None,
+                None,
+            )
+            .expect("add")
+            .id
+            .clone();
+        let task = store.get_mut(&id).expect("task");
+        task.created_at = utc("2026-01-05T09:10:00Z");
+        assert!(!task.is_due(utc("2026-01-05T09:10:30Z")).expect("due"));
+        assert!(task.is_due(utc("2026-01-05T12:30:00Z")).expect("due"));
+
+        task.last_run = Some(ScheduledRun {
+            started_at: utc("2026-01-05T12:30:00Z"),
+            success: Some(true),
+            thread_id: None,
+        });
+        assert!(!task.is_due(utc("2026-01-05T12:30:30Z")).expect("due"));
+
+        store.save().expect("save");
+        assert_eq!(ScheduleStore::load(dir.path()).expect("reload"), store);
+    }
+
+    #[test]
+    fn webhook_notification_requires_url() {
+        let mut store = ScheduleStore::load(Path::new("/nonexistent")).expect("load");
+
+        assert!(
+            store
+                .add(
+                    "0 9 * * 1".to_string(),
+                    "summarize".to_string(),
+                    PathBuf::from("/repo"),
+                    ScheduleNotify::Webhook,
+                    None,
+                )
+                .is_err()
+        );
+    }
+}
@@ -5,6 +5,7 @@ use codex_network_proxy::NetworkProxy;
 use codex_network_proxy::PROXY_URL_ENV_KEYS;
 use codex_network_proxy::has_proxy_url_env_vars;
 use codex_network_proxy::proxy_url_env_value;
+use codex_utils_absolute_path::AbsolutePathBuf;
 use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::ffi::CStr;
@@ -37,8 +38,14 @@ pub async fn spawn_command_under_seatbelt(
     network: Option<&NetworkProxy>,
     mut env: HashMap<String, String>,
 ) -> std::io::Result<Child> {
-    let args =
-        create_seatbelt_command_args(command, sandbox_policy, sandbox_policy_cwd, false, network);
+    let args = create_seatbelt_command_args(
+        command,
+        sandbox_policy,
+        sandbox_policy_cwd,
+        &[],
+        false,
+        network,
+    );
     let arg0 = None;
     env.insert(CODEX_SANDBOX_ENV_VAR.to_string(), "seatbelt".to_string());
     spawn_child_async(SpawnChildRequest {
@@ -177,6 +184,7 @@ pub(crate) fn create_seatbelt_command_args(
     command: Vec<String>,
     sandbox_policy: &SandboxPolicy,
     sandbox_policy_cwd: &Path,
+    protected_paths: &[AbsolutePathBuf],
     enforce_managed_network: bool,
     network: Option<&NetworkProxy>,
 ) -> Vec<String> {
@@ -188,7 +196,8 @@ pub(crate) fn create_seatbelt_command_args(
                 Vec::new(),
             )
         } else {
-            let writable_roots = sandbox_policy.get_writable_roots_with_cwd(sandbox_policy_cwd);
+            let writable_roots = sandbox_policy
+                .get_writable_roots_with_protected_paths(sandbox_policy_cwd, protected_paths);
 
             let mut writable_folder_policies: Vec<String> = Vec::new();
             let mut file_write_params = Vec::new();
@@ -499,7 +508,8 @@ mod tests {
         .iter()
         .map(std::string::ToString::to_string)
         .collect();
-        let args = create_seatbelt_command_args(shell_command.clone(), &policy, &cwd, false, None);
+        let args =
+            create_seatbelt_command_args(shell_command.clone(), &policy, &cwd, &[], false, None);
 
         // Build the expected policy text using a raw string for readability.
         // Note that the policy includes:
@@ -588,7 +598,7 @@ mod tests {
         .map(std::string::ToString::to_string)
         .collect();
         let write_hooks_file_args =
-            create_seatbelt_command_args(shell_command_git, &policy, &cwd, false, None);
+            create_seatbelt_command_args(shell_command_git, &policy, &cwd, &[], false, None);
         let output = Command::new(MACOS_PATH_TO_SEATBELT_EXECUTABLE)
             .args(&write_hooks_file_args)
             .current_dir(&cwd)
@@ -619,7 +629,7 @@ mod tests {
         .map(std::string::ToString::to_string)
         .collect();
         let write_allowed_file_args =
-            create_seatbelt_command_args(shell_command_allowed, &policy, &cwd, false, None);
+            create_seatbelt_command_args(shell_command_allowed, &policy, &cwd, &[], false, None);
         let output = Command::new(MACOS_PATH_TO_SEATBELT_EXECUTABLE)
             .args(&write_allowed_file_args)
             .current_dir(&cwd)
@@ -679,7 +689,7 @@ mod tests {
         .iter()
         .map(std::string::ToString::to_string)
         .collect();
-        let args = create_seatbelt_command_args(shell_command, &policy, &cwd, false, None);
+        let args = create_seatbelt_command_args(shell_command, &policy, &cwd, &[], false, None);
 
         let output = Command::new(MACOS_PATH_TO_SEATBELT_EXECUTABLE)
             .args(&args)
@@ -710,7 +720,7 @@ mod tests {
         .map(std::string::ToString::to_string)
         .collect();
         let gitdir_args =
-            create_seatbelt_command_args(shell_command_gitdir, &policy, &cwd, false, None);
+            create_seatbelt_command_args(shell_command_gitdir, &policy, &cwd, &[], false, None);
         let output = Command::new(MACOS_PATH_TO_SEATBELT_EXECUTABLE)
             .args(&gitdir_args)
             .current_dir(&cwd)
@@ -770,6 +780,7 @@ mod tests {
             shell_command.clone(),
             &policy,
             vulnerable_root.as_path(),
+            &[],
             false,
             None,
         );
@@ -0,0 +1,50 @@
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseInputItem;
+
+pub const SESSION_NOTE_OPEN: &str = "<session_note>";
+pub const SESSION_NOTE_CLOSE: &str = "</session_note>";
+
+pub fn is_session_note_text(text: &str) -> bool {
+    let trimmed = text.trim_start();
+    let lowered = trimmed.to_ascii_lowercase();
+    lowered.starts_with(SESSION_NOTE_OPEN)
+}
+
+/// Conversation input that shows a `/note` to the model. The tags keep it out
+/// of the user messages replayed to clients.
+pub fn session_note_input_item(text: &str) -> ResponseInputItem {
+    ResponseInputItem::Message {
+        role: "user".to_string(),
+        content: vec![ContentItem::InputText {
+            text: format!(
+                "{SESSION_NOTE_OPEN}\nNote the user saved for this session; keep it in mind, no reply is needed.\n{}\n{SESSION_NOTE_CLOSE}",
+                text.trim()
+            ),
+        }],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn note_input_is_tagged() {
+        let ResponseInputItem::Message { role, content } =
+            session_note_input_item(" staging is down \n")
+        else {
+            panic!("expected a message");
+        };
+        assert_eq!(role, "user");
+        let [ContentItem::InputText { text }] = content.as_slice() else {
+            panic!("expected one text item");
+        };
+        assert_eq!(
+            text,
+            "<session_note>\nNote the user saved for this session; keep it in mind, no reply is needed.\nstaging is down\n</session_note>"
+        );
+        assert!(is_session_note_text(text));
+        assert!(!is_session_note_text("a <session_note> in passing"));
+    }
+}
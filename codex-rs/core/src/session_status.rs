@@ -0,0 +1,147 @@
+//! Machine-readable state of running interactive sessions.
+//!
+//! With `tui.status_file = true`, each TUI process keeps
+//! `$CODEX_HOME/status/<pid>.json` up to date with its model, activity, token
+//! usage, and git branch, and removes it on exit. `codex status --porcelain`
+//! reads these files so tmux status bars and shell prompts can show what Codex
+//! is doing without talking to the process.
+
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::path_utils::write_atomically;
+
+pub const STATUS_SUBDIR: &str = "status";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionActivity {
+    Idle,
+    Running,
+    WaitingForApproval,
+}
+
+impl SessionActivity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SessionActivity::Idle => "idle",
+            SessionActivity::Running => "running",
+            SessionActivity::WaitingForApproval => "waiting_for_approval",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionStatus {
+    pub pid: u32,
+    pub thread_id: Option<String>,
+    pub cwd: PathBuf,
+    pub model: String,
+    pub activity: SessionActivity,
+    pub tokens_used: i64,
+    pub branch: Option<String>,
+    /// Unix timestamp (seconds) of the last change.
+    pub updated_at: i64,
+}
+
+impl SessionStatus {
+    /// One tab-separated line: activity, model, tokens, branch, cwd, thread
+    /// id. Missing values are written as `-` so the column count is fixed.
+    pub fn porcelain_line(&self) -> String {
+        [
+            self.activity.as_str().to_string(),
+            self.model.clone(),
+            self.tokens_used.to_string(),
+            self.branch.clone().unwrap_or_else(|| "-".to_string()),
+            self.cwd.display().to_string(),
+            self.thread_id.clone().unwrap_or_else(|| "-".to_string()),
+        ]
+        .join("\t")
+    }
+}
+
+pub fn status_path(codex_home: &Path, pid: u32) -> PathBuf {
+    codex_home.join(STATUS_SUBDIR).join(format!("{pid}.json"))
+}
+
+pub fn write_status(codex_home: &Path, status: &SessionStatus) -> io::Result<()> {
+    let contents = serde_json::to_string_pretty(status).map_err(io::Error::other)?;
+    write_atomically(&status_path(codex_home, status.pid), &contents)
+}
+
+pub fn remove_status(codex_home: &Path, pid: u32) -> io::Result<()> {
+    match std::fs::remove_file(status_path(codex_home, pid)) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Every readable status file, most recently updated first. Files that fail
+/// to parse (e.g. written by a newer Codex) are skipped.
+pub fn read_statuses(codex_home: &Path) -> io::Result<Vec<SessionStatus>> {
+    let entries = match std::fs::read_dir(codex_home.join(STATUS_SUBDIR)) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut statuses: Vec<SessionStatus> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| std::fs::read_to_string(entry.path()).ok())
+        .filter_map(|contents| serde_json::from_str(&contents).ok())
+        .collect();
+    statuses.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    Ok(statuses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn status(pid: u32, updated_at: i64) -> SessionStatus {
+        SessionStatus {
+            pid,
+            thread_id: None,
+            cwd: PathBuf::from("/repo"),
+            model: "gpt-5.1-codex".to_string(),
+            activity: SessionActivity::Running,
+            tokens_used: 1200,
+            branch: Some("main".to_string()),
+            updated_at,
+        }
+    }
+
+    #[test]
+    fn porcelain_line_has_fixed_columns() {
+        assert_eq!(
+            status(7, 0).porcelain_line(),
+            "running\tgpt-5.1-codex\t1200\tmain\t/repo\t-"
+        );
+    }
+
+    #[test]
+    fn statuses_round_trip_newest_first() {
+        let home = TempDir::new().expect("tempdir");
+        write_status(home.path(), &status(1, 10)).expect("write");
+        write_status(home.path(), &status(2, 20)).expect("write");
+        std::fs::write(home.path().join(STATUS_SUBDIR).join("3.json"), "{").expect("write");
+
+        assert_eq!(
+            read_statuses(home.path()).expect("read"),
+            vec![status(2, 20), status(1, 10)]
+        );
+
+        remove_status(home.path(), 2).expect("remove");
+        remove_status(home.path(), 2).expect("remove again");
+        assert_eq!(
+            read_statuses(home.path()).expect("read"),
+            vec![status(1, 10)]
+        );
+    }
+}
@@ -0,0 +1,264 @@
+//! Summary of a session written when the user quits (`exit_summary`).
+//!
+//! Clients ask for it with `Op::SummarizeSession` before shutting down. The
+//! conversation is condensed into a plain-text transcript, the model turns it
+//! into a few bullets, and the bullets are recorded in the rollout so the
+//! resume picker and the exit message can show what the session did.
+
+use std::time::Duration;
+
+use codex_protocol::items::AgentMessageContent;
+use codex_protocol::items::TurnItem;
+use codex_protocol::models::BaseInstructions;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ResponseItem;
+use futures::StreamExt;
+use tracing::warn;
+
+use crate::client_common::Prompt;
+use crate::client_common::ResponseEvent;
+use crate::codex::Session;
+use crate::codex::TurnContext;
+use crate::event_mapping::parse_turn_item;
+
+const SESSION_SUMMARY_PROMPT: &str = include_str!("../templates/session_summary/prompt.md");
+/// Only the end of longer transcripts is sent to the model.
+const MAX_TRANSCRIPT_CHARS: usize = 24_000;
+/// Tool arguments are cut to this many characters in the transcript.
+const MAX_TOOL_INPUT_CHARS: usize = 200;
+const MAX_BULLETS: usize = 5;
+/// Quitting should not hang on a slow model; give up after this long.
+const SUMMARY_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Ask the model to summarize the session so far. Returns no bullets when the
+/// session has no user turns or the request fails.
+pub(crate) async fn summarize_session(sess: &Session, turn_context: &TurnContext) -> Vec<String> {
+    let history = sess.clone_history().await;
+    let Some(transcript) = session_transcript(history.raw_items()) else {
+        return Vec::new();
+    };
+    let prompt = Prompt {
+        input: vec![ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText { text: transcript }],
+            end_turn: None,
+            phase: None,
+        }],
+        base_instructions: BaseInstructions {
+            text: SESSION_SUMMARY_PROMPT.to_string(),
+        },
+        ..Default::default()
+    };
+    match tokio::time::timeout(SUMMARY_TIMEOUT, request_text(sess, turn_context, &prompt)).await {
+        Ok(Some(text)) => parse_bullets(&text),
+        Ok(None) => Vec::new(),
+        Err(_) => {
+            warn!("session summary timed out");
+            Vec::new()
+        }
+    }
+}
+
+async fn request_text(
+    sess: &Session,
+    turn_context: &TurnContext,
+    prompt: &Prompt,
+) -> Option<String> {
+    let mut client_session = sess.services.model_client.new_session();
+    let mut stream = match client_session
+        .stream(
+            prompt,
+            &turn_context.model_info,
+            &turn_context.otel_manager,
+            turn_context.reasoning_effort,
+            turn_context.reasoning_summary,
+            None,
+        )
+        .await
+    {
+        Ok(stream) => stream,
+        Err(err) => {
+            warn!("session summary request failed: {err}");
+            return None;
+        }
+    };
+    let mut text = String::new();
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(ResponseEvent::OutputTextDelta(delta)) => text.push_str(&delta),
+            Ok(ResponseEvent::OutputItemDone(ResponseItem::Message { content, .. }))
+                if text.is_empty() =>
+            {
+                if let Some(done) = crate::compact::content_items_to_text(&content) {
+                    text.push_str(&done);
+                }
+            }
+            Ok(ResponseEvent::Completed { .. }) => return Some(text),
+            Ok(_) => {}
+            Err(err) => {
+                warn!("session summary stream failed: {err}");
+                return None;
+            }
+        }
+    }
+    None
+}
+
+/// Plain-text transcript of `items`, or `None` when the user never said
+/// anything.
+fn session_transcript(items: &[ResponseItem]) -> Option<String> {
+    let mut entries = Vec::new();
+    let mut saw_user = false;
+    for item in items {
+        let entry = match item {
+            ResponseItem::FunctionCall {
+                name, arguments, ..
+            } => format!("Tool {name}: {}", clip(arguments, MAX_TOOL_INPUT_CHARS)),
+            ResponseItem::CustomToolCall { name, input, .. } if name == "apply_patch" => {
+                let files: Vec<&str> = input
+                    .lines()
+                    .filter(|line| line.starts_with("*** ") && line.contains(" File: "))
+                    .map(|line| line.trim_start_matches("*** "))
+                    .collect();
+                format!("Patch: {}", files.join("; "))
+            }
+            ResponseItem::CustomToolCall { name, input, .. } => {
+                format!("Tool {name}: {}", clip(input, MAX_TOOL_INPUT_CHARS))
+            }
+            ResponseItem::LocalShellCall {
+                action: LocalShellAction::Exec(exec),
+                ..
+            } => format!(
+                "Tool shell: {}",
+                clip(&exec.command.join(" "), MAX_TOOL_INPUT_CHARS)
+            ),
+            _ => match parse_turn_item(item) {
+                Some(TurnItem::UserMessage(message)) => {
+                    saw_user = true;
+                    format!("User: {}", message.message())
+                }
+                Some(TurnItem::AgentMessage(message)) => {
+                    let text: String = message
+                        .content
+                        .iter()
+                        .map(|AgentMessageContent::Text { text }| text.as_str())
+                        .collect();
+                    format!("Codex: {text}")
+                }
+                _ => continue,
+            },
+        };
+        entries.push(entry);
+    }
+    if !saw_user {
+        return None;
+    }
+    let transcript = entries.join("\n\n");
+    let skip = transcript
+        .chars()
+        .count()
+        .saturating_sub(MAX_TRANSCRIPT_CHARS);
+    if skip == 0 {
+        return Some(transcript);
+    }
+    Some(format!(
+        "[earlier part of the session omitted]\n{}",
+        transcript.chars().skip(skip).collect::<String>()
+    ))
+}
+
+fn clip(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut clipped: String = text.chars().take(max_chars).collect();
+    clipped.push('…');
+    clipped
+}
+
+/// Bullet lines of the model's reply without their markers, at most
+/// [`MAX_BULLETS`].
+fn parse_bullets(text: &str) -> Vec<String> {
+    text.lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let rest = line
+                .strip_prefix("- ")
+                .or_else(|| line.strip_prefix("* "))
+                .or_else(|| line.strip_prefix("• "))
+                .or_else(|| {
+                    let (number, rest) = line.split_once(". ")?;
+                    (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+                        .then_some(rest)
+                })?;
+            let rest = rest.trim();
+            (!rest.is_empty()).then(|| rest.to_string())
+        })
+        .take(MAX_BULLETS)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn message(role: &str, text: &str) -> ResponseItem {
+        let content = if role == "user" {
+            ContentItem::InputText {
+                text: text.to_string(),
+            }
+        } else {
+            ContentItem::OutputText {
+                text: text.to_string(),
+            }
+        };
+        ResponseItem::Message {
+            id: None,
+            role: role.to_string(),
+            content: vec![content],
+            end_turn: None,
+            phase: None,
+        }
+    }
+
+    #[test]
+    fn transcript_lists_messages_and_tools() {
+        let items = vec![
+            message("user", "add a README"),
+            ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "call".to_string(),
+                name: "apply_patch".to_string(),
+                input: "*** Begin Patch\n*** Add File: README.md\n+hi\n*** End Patch".to_string(),
+            },
+            message("assistant", "Added README.md."),
+        ];
+        assert_eq!(
+            session_transcript(&items),
+            Some(
+                "User: add a README\n\nPatch: Add File: README.md\n\nCodex: Added README.md."
+                    .to_string()
+            )
+        );
+        assert_eq!(session_transcript(&[message("assistant", "hello")]), None);
+    }
+
+    #[test]
+    fn bullets_are_parsed_and_capped() {
+        let reply = "Summary:\n- Added README.md\n* Fixed `cargo test`\n3. Renamed the crate\n\n• a\n- b\n- c";
+        assert_eq!(
+            parse_bullets(reply),
+            vec![
+                "Added README.md".to_string(),
+                "Fixed `cargo test`".to_string(),
+                "Renamed the crate".to_string(),
+                "a".to_string(),
+                "b".to_string(),
+            ]
+        );
+    }
+}
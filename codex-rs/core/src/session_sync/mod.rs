@@ -0,0 +1,517 @@
+//! Cross-device session sync (`codex sessions sync`).
+//!
+//! Rollouts and the session index are mirrored to a user-provided S3 bucket,
+//! WebDAV collection or Git repository configured under `[sync]`. Nothing
+//! leaves the machine in plain text: a random age identity is stored on the
+//! remote encrypted with the user's passphrase, and every rollout, the index
+//! and the manifest are encrypted to it. Object names are hashes of the
+//! rollout file names.
+//!
+//! The encrypted manifest records each synced rollout's modification time
+//! and content hash. A sync pushes rollouts the remote lacks, pulls the ones
+//! this machine lacks, and resolves a session changed on both sides by
+//! keeping whichever copy was written last. Thread names from
+//! `session_index.jsonl` are merged line by line, so renames made on either
+//! machine survive. Archiving stays local to each machine.
+
+mod remote;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::fs::FileTimes;
+use std::fs::OpenOptions;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::UNIX_EPOCH;
+
+use age::scrypt::Identity as ScryptIdentity;
+use age::scrypt::Recipient as ScryptRecipient;
+use age::secrecy::ExposeSecret;
+use age::secrecy::SecretString;
+use age::x25519;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use self::remote::SyncRemote;
+use self::remote::hex;
+use crate::config::Config;
+use crate::rollout::ARCHIVED_SESSIONS_SUBDIR;
+use crate::rollout::SESSIONS_SUBDIR;
+use crate::rollout::list::collect_rollout_paths;
+use crate::rollout::rollout_date_parts;
+use crate::rollout::session_index::SessionIndexEntry;
+use crate::rollout::session_index::session_index_path;
+use crate::state_db;
+
+const DEFAULT_PASSPHRASE_ENV: &str = "CODEX_SYNC_PASSPHRASE";
+const KEY_OBJECT: &str = "key.age";
+const MANIFEST_OBJECT: &str = "manifest.age";
+const SESSION_INDEX_OBJECT: &str = "session_index.age";
+
+/// What a sync run changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionSyncSummary {
+    /// Rollouts uploaded because the remote lacked them or held an older copy.
+    pub pushed: usize,
+    /// Rollouts downloaded because this machine lacked them or held an older copy.
+    pub pulled: usize,
+    /// Rollouts already identical on both sides.
+    pub unchanged: usize,
+    /// Session index entries after merging both sides.
+    pub index_entries: usize,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SyncManifest {
+    #[serde(default)]
+    sessions: BTreeMap<String, SyncedRollout>,
+}
+
+/// Version of one rollout, keyed by its file name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct SyncedRollout {
+    modified_ms: i64,
+    sha256: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SyncAction {
+    Push,
+    Pull,
+}
+
+struct LocalRollout {
+    path: PathBuf,
+    archived: bool,
+    version: SyncedRollout,
+}
+
+/// Push and pull sessions through the remote configured under `[sync]`.
+pub async fn sync_sessions(config: &Config) -> io::Result<SessionSyncSummary> {
+    let Some(settings) = config.sync.as_ref() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "no sync remote configured; add a [sync] table with `remote` to config.toml",
+        ));
+    };
+    let passphrase_env = settings
+        .passphrase_env
+        .as_deref()
+        .unwrap_or(DEFAULT_PASSPHRASE_ENV);
+    let passphrase = std::env::var(passphrase_env)
+        .ok()
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("set {passphrase_env} to the sync passphrase"),
+            )
+        })?;
+
+    let remote = SyncRemote::from_settings(settings, &config.codex_home)?;
+    remote.prepare().await?;
+    let identity = load_or_create_identity(&remote, SecretString::from(passphrase)).await?;
+    let recipient = identity.to_public();
+
+    let mut manifest: SyncManifest = match remote.get(MANIFEST_OBJECT).await? {
+        Some(ciphertext) => serde_json::from_slice(&decrypt(&identity, &ciphertext)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+        None => SyncManifest::default(),
+    };
+    let local = scan_local_rollouts(&config.codex_home).await?;
+    let local_versions: BTreeMap<String, SyncedRollout> = local
+        .iter()
+        .map(|(name, rollout)| (name.clone(), rollout.version.clone()))
+        .collect();
+    let plan = plan_sync(&local_versions, &manifest.sessions);
+
+    let mut summary = SessionSyncSummary {
+        unchanged: count_unchanged(&local_versions, &manifest.sessions),
+        ..SessionSyncSummary::default()
+    };
+    let state_db = state_db::get_state_db(config, None).await;
+    for (name, action) in plan {
+        match action {
+            SyncAction::Push => {
+                let Some(rollout) = local.get(&name) else {
+                    continue;
+                };
+                let contents = tokio::fs::read(&rollout.path).await?;
+                remote
+                    .put(&object_key(&name), encrypt(&recipient, &contents)?)
+                    .await?;
+                manifest.sessions.insert(name, rollout.version.clone());
+                summary.pushed += 1;
+            }
+            SyncAction::Pull => {
+                let Some(version) = manifest.sessions.get(&name) else {
+                    continue;
+                };
+                let Some(ciphertext) = remote.get(&object_key(&name)).await? else {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("sync remote is missing the object for {name}"),
+                    ));
+                };
+                let contents = decrypt(&identity, &ciphertext)?;
+                if hex(&Sha256::digest(&contents)) != version.sha256 {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("synced copy of {name} does not match the manifest"),
+                    ));
+                }
+                let existing = local.get(&name);
+                let path = match existing {
+                    Some(rollout) => rollout.path.clone(),
+                    None => pulled_rollout_path(&config.codex_home, &name)?,
+                };
+                write_rollout(&path, &contents, version.modified_ms).await?;
+                state_db::reconcile_rollout(
+                    state_db.as_deref(),
+                    &path,
+                    &config.model_provider_id,
+                    None,
+                    &[],
+                    Some(existing.is_some_and(|rollout| rollout.archived)),
+                )
+                .await;
+                summary.pulled += 1;
+            }
+        }
+    }
+
+    summary.index_entries =
+        sync_session_index(&remote, &identity, &recipient, &config.codex_home).await?;
+    if summary.pushed > 0 {
+        let manifest_json = serde_json::to_vec(&manifest).map_err(io::Error::other)?;
+        remote
+            .put(MANIFEST_OBJECT, encrypt(&recipient, &manifest_json)?)
+            .await?;
+    }
+    remote.finish().await?;
+    Ok(summary)
+}
+
+fn count_unchanged(
+    local: &BTreeMap<String, SyncedRollout>,
+    remote: &BTreeMap<String, SyncedRollout>,
+) -> usize {
+    local
+        .iter()
+        .filter(|(name, version)| {
+            remote
+                .get(*name)
+                .is_some_and(|synced| synced.sha256 == version.sha256)
+        })
+        .count()
+}
+
+/// Decide which rollouts to upload and which to download. A rollout that
+/// differs on both sides goes to whichever side wrote it last; the local
+/// copy wins ties.
+fn plan_sync(
+    local: &BTreeMap<String, SyncedRollout>,
+    remote: &BTreeMap<String, SyncedRollout>,
+) -> Vec<(String, SyncAction)> {
+    let names: BTreeSet<&String> = local.keys().chain(remote.keys()).collect();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let action = match (local.get(name), remote.get(name)) {
+                (Some(local), Some(remote)) if local.sha256 == remote.sha256 => return None,
+                (Some(local), Some(remote)) if remote.modified_ms > local.modified_ms => {
+                    SyncAction::Pull
+                }
+                (Some(_), _) => SyncAction::Push,
+                (None, Some(_)) => SyncAction::Pull,
+                (None, None) => return None,
+            };
+            Some((name.clone(), action))
+        })
+        .collect()
+}
+
+/// Merge this machine's `session_index.jsonl` with the remote copy and
+/// write the union back to both sides. Returns the number of entries.
+async fn sync_session_index(
+    remote: &SyncRemote,
+    identity: &x25519::Identity,
+    recipient: &x25519::Recipient,
+    codex_home: &Path,
+) -> io::Result<usize> {
+    let path = session_index_path(codex_home);
+    let local = match tokio::fs::read_to_string(&path).await {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+    let remote_index = match remote.get(SESSION_INDEX_OBJECT).await? {
+        Some(ciphertext) => String::from_utf8(decrypt(identity, &ciphertext)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+        None => String::new(),
+    };
+    let merged = merge_session_index(&local, &remote_index);
+    if merged != local {
+        let tmp = path.with_extension("jsonl.sync");
+        tokio::fs::write(&tmp, &merged).await?;
+        tokio::fs::rename(&tmp, &path).await?;
+    }
+    if merged != remote_index {
+        remote
+            .put(SESSION_INDEX_OBJECT, encrypt(recipient, merged.as_bytes())?)
+            .await?;
+    }
+    Ok(merged.lines().count())
+}
+
+/// Union of two session indexes ordered by `updated_at`, so the newest
+/// name for each thread stays last.
+fn merge_session_index(local: &str, remote: &str) -> String {
+    let mut seen = HashSet::new();
+    let mut entries: Vec<(String, &str)> = Vec::new();
+    for line in local.lines().chain(remote.lines()) {
+        let line = line.trim();
+        if line.is_empty() || !seen.insert(line) {
+            continue;
+        }
+        let Ok(entry) = serde_json::from_str::<SessionIndexEntry>(line) else {
+            continue;
+        };
+        entries.push((entry.updated_at, line));
+    }
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries
+        .into_iter()
+        .map(|(_, line)| format!("{line}\n"))
+        .collect()
+}
+
+async fn load_or_create_identity(
+    remote: &SyncRemote,
+    passphrase: SecretString,
+) -> io::Result<x25519::Identity> {
+    if let Some(ciphertext) = remote.get(KEY_OBJECT).await? {
+        let key = age::decrypt(&ScryptIdentity::new(passphrase), &ciphertext).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("failed to unlock the sync key; check the passphrase: {err}"),
+            )
+        })?;
+        let key = String::from_utf8(key)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        return key.trim().parse::<x25519::Identity>().map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid sync key: {err}"),
+            )
+        });
+    }
+    let identity = x25519::Identity::generate();
+    let key = identity.to_string();
+    let ciphertext = age::encrypt(
+        &ScryptRecipient::new(passphrase),
+        key.expose_secret().as_bytes(),
+    )
+    .map_err(io::Error::other)?;
+    remote.put(KEY_OBJECT, ciphertext).await?;
+    Ok(identity)
+}
+
+fn encrypt(recipient: &x25519::Recipient, plaintext: &[u8]) -> io::Result<Vec<u8>> {
+    age::encrypt(recipient, plaintext).map_err(io::Error::other)
+}
+
+fn decrypt(identity: &x25519::Identity, ciphertext: &[u8]) -> io::Result<Vec<u8>> {
+    age::decrypt(identity, ciphertext)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+fn object_key(file_name: &str) -> String {
+    format!("objects/{}.age", hex(&Sha256::digest(file_name.as_bytes())))
+}
+
+/// Rollouts under `sessions/` and `archived_sessions/`, keyed by file name.
+async fn scan_local_rollouts(codex_home: &Path) -> io::Result<BTreeMap<String, LocalRollout>> {
+    let mut paths: Vec<(PathBuf, bool)> = Vec::new();
+    let sessions = codex_home.join(SESSIONS_SUBDIR);
+    if tokio::fs::try_exists(&sessions).await? {
+        paths.extend(
+            collect_rollout_paths(&sessions)
+                .await?
+                .into_iter()
+                .map(|path| (path, false)),
+        );
+    }
+    match tokio::fs::read_dir(codex_home.join(ARCHIVED_SESSIONS_SUBDIR)).await {
+        Ok(mut dir) => {
+            while let Some(entry) = dir.next_entry().await? {
+                let path = entry.path();
+                let is_rollout = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with("rollout-") && name.ends_with(".jsonl"));
+                if is_rollout {
+                    paths.push((path, true));
+                }
+            }
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+
+    let mut rollouts = BTreeMap::new();
+    for (path, archived) in paths {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let name = name.to_string();
+        let contents = tokio::fs::read(&path).await?;
+        let modified_ms = tokio::fs::metadata(&path)
+            .await?
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as i64)
+            .unwrap_or_default();
+        let version = SyncedRollout {
+            modified_ms,
+            sha256: hex(&Sha256::digest(&contents)),
+        };
+        rollouts.insert(
+            name,
+            LocalRollout {
+                path,
+                archived,
+                version,
+            },
+        );
+    }
+    Ok(rollouts)
+}
+
+/// Where a rollout this machine has never seen is written: its dated
+/// directory under `sessions/`.
+fn pulled_rollout_path(codex_home: &Path, name: &str) -> io::Result<PathBuf> {
+    let Some((year, month, day)) = rollout_date_parts(std::ffi::OsStr::new(name)) else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("synced rollout `{name}` missing filename timestamp"),
+        ));
+    };
+    Ok(codex_home
+        .join(SESSIONS_SUBDIR)
+        .join(year)
+        .join(month)
+        .join(day)
+        .join(name))
+}
+
+/// Replace the rollout at `path` and give it the modification time recorded
+/// on the remote, so the next sync sees both sides as the same version.
+async fn write_rollout(path: &Path, contents: &[u8], modified_ms: i64) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let tmp = path.with_extension("jsonl.sync");
+    tokio::fs::write(&tmp, contents).await?;
+    tokio::fs::rename(&tmp, path).await?;
+    let modified = UNIX_EPOCH + Duration::from_millis(u64::try_from(modified_ms).unwrap_or(0));
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || -> io::Result<()> {
+        let times = FileTimes::new().set_modified(modified);
+        OpenOptions::new()
+            .append(true)
+            .open(&path)?
+            .set_times(times)
+    })
+    .await
+    .map_err(io::Error::other)?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn version(modified_ms: i64, sha256: &str) -> SyncedRollout {
+        SyncedRollout {
+            modified_ms,
+            sha256: sha256.to_string(),
+        }
+    }
+
+    #[test]
+    fn plan_keeps_the_last_written_copy() {
+        let local = BTreeMap::from([
+            ("local-only".to_string(), version(1, "a")),
+            ("same".to_string(), version(5, "s")),
+            ("newer-here".to_string(), version(20, "h")),
+            ("newer-there".to_string(), version(10, "x")),
+        ]);
+        let remote = BTreeMap::from([
+            ("remote-only".to_string(), version(1, "b")),
+            ("same".to_string(), version(9, "s")),
+            ("newer-here".to_string(), version(10, "r")),
+            ("newer-there".to_string(), version(20, "y")),
+        ]);
+
+        assert_eq!(
+            plan_sync(&local, &remote),
+            vec![
+                ("local-only".to_string(), SyncAction::Push),
+                ("newer-here".to_string(), SyncAction::Push),
+                ("newer-there".to_string(), SyncAction::Pull),
+                ("remote-only".to_string(), SyncAction::Pull),
+            ]
+        );
+        assert_eq!(count_unchanged(&local, &remote), 1);
+    }
+
+    #[test]
+    fn session_index_merge_orders_by_update_time() {
+        let id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let entry = |name: &str, at: &str| {
+            format!(r#"{{"id":"{id}","thread_name":"{name}","updated_at":"{at}"}}"#)
+        };
+        let local = format!(
+            "{}\n{}\n",
+            entry("first", "2025-01-01T00:00:00Z"),
+            entry("renamed here", "2025-01-03T00:00:00Z")
+        );
+        let remote = format!(
+            "{}\n{}\nnot json\n",
+            entry("first", "2025-01-01T00:00:00Z"),
+            entry("renamed there", "2025-01-02T00:00:00Z")
+        );
+
+        assert_eq!(
+            merge_session_index(&local, &remote),
+            format!(
+                "{}\n{}\n{}\n",
+                entry("first", "2025-01-01T00:00:00Z"),
+                entry("renamed there", "2025-01-02T00:00:00Z"),
+                entry("renamed here", "2025-01-03T00:00:00Z")
+            )
+        );
+    }
+
+    #[test]
+    fn objects_round_trip_through_the_sync_key() {
+        let identity = x25519::Identity::generate();
+        let ciphertext = encrypt(&identity.to_public(), b"rollout").expect("encrypt");
+        assert_ne!(ciphertext, b"rollout".to_vec());
+        assert_eq!(
+            decrypt(&identity, &ciphertext).expect("decrypt"),
+            b"rollout"
+        );
+        assert!(decrypt(&x25519::Identity::generate(), &ciphertext).is_err());
+        assert_eq!(
+            object_key("rollout-a.jsonl").len(),
+            "objects/.age".len() + 64
+        );
+    }
+}
@@ -0,0 +1,512 @@
+//! Storage backends for session sync.
+//!
+//! Every backend stores opaque, already-encrypted objects under short
+//! relative keys such as `manifest.age` or `objects/<hash>.age`. S3 and
+//! WebDAV are addressed directly over HTTP; a Git remote is cloned into
+//! `$CODEX_HOME/sync/git`, edited in place, then committed and pushed.
+
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use reqwest::Method;
+use reqwest::StatusCode;
+use sha2::Digest;
+use sha2::Sha256;
+use tokio::process::Command;
+use url::Url;
+
+use crate::config::types::SessionSyncToml;
+use crate::default_client::build_reqwest_client;
+
+const DEFAULT_S3_REGION: &str = "us-east-1";
+const DEFAULT_WEBDAV_PASSWORD_ENV: &str = "CODEX_SYNC_WEBDAV_PASSWORD";
+const GIT_CHECKOUT_SUBDIR: &str = "sync/git";
+const GIT_COMMIT_MESSAGE: &str = "Sync Codex sessions";
+
+pub(crate) enum SyncRemote {
+    S3(S3Remote),
+    WebDav(WebDavRemote),
+    Git(GitRemote),
+}
+
+impl SyncRemote {
+    pub(crate) fn from_settings(settings: &SessionSyncToml, codex_home: &Path) -> io::Result<Self> {
+        let remote = settings.remote.trim();
+        if let Some(url) = remote.strip_prefix("git+") {
+            return Ok(Self::Git(GitRemote {
+                url: url.to_string(),
+                dir: codex_home.join(GIT_CHECKOUT_SUBDIR),
+            }));
+        }
+        if let Some(location) = remote.strip_prefix("s3://") {
+            let (bucket, prefix) = location.split_once('/').unwrap_or((location, ""));
+            if bucket.is_empty() {
+                return Err(invalid_remote(remote));
+            }
+            let region = settings
+                .s3_region
+                .clone()
+                .unwrap_or_else(|| DEFAULT_S3_REGION.to_string());
+            let base = match settings.s3_endpoint.as_deref() {
+                Some(endpoint) => format!("{}/{bucket}/", endpoint.trim_end_matches('/')),
+                None => format!("https://{bucket}.s3.{region}.amazonaws.com/"),
+            };
+            let base = with_prefix(&base, prefix).ok_or_else(|| invalid_remote(remote))?;
+            return Ok(Self::S3(S3Remote {
+                client: build_reqwest_client(),
+                base,
+                region,
+            }));
+        }
+        if remote.starts_with("https://") || remote.starts_with("http://") {
+            let base = with_prefix(remote, "").ok_or_else(|| invalid_remote(remote))?;
+            return Ok(Self::WebDav(WebDavRemote {
+                client: build_reqwest_client(),
+                base,
+                username: settings.webdav_username.clone(),
+                password_env: settings
+                    .webdav_password_env
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_WEBDAV_PASSWORD_ENV.to_string()),
+            }));
+        }
+        Err(invalid_remote(remote))
+    }
+
+    /// Bring the local view of the remote up to date before reading it.
+    pub(crate) async fn prepare(&self) -> io::Result<()> {
+        match self {
+            Self::S3(_) | Self::WebDav(_) => Ok(()),
+            Self::Git(git) => git.prepare().await,
+        }
+    }
+
+    pub(crate) async fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        match self {
+            Self::S3(s3) => s3.get(key).await,
+            Self::WebDav(webdav) => webdav.get(key).await,
+            Self::Git(git) => git.get(key).await,
+        }
+    }
+
+    pub(crate) async fn put(&self, key: &str, body: Vec<u8>) -> io::Result<()> {
+        match self {
+            Self::S3(s3) => s3.put(key, body).await,
+            Self::WebDav(webdav) => webdav.put(key, body).await,
+            Self::Git(git) => git.put(key, body).await,
+        }
+    }
+
+    /// Publish everything written with [`SyncRemote::put`].
+    pub(crate) async fn finish(&self) -> io::Result<()> {
+        match self {
+            Self::S3(_) | Self::WebDav(_) => Ok(()),
+            Self::Git(git) => git.finish().await,
+        }
+    }
+}
+
+fn invalid_remote(remote: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!(
+            "unsupported sync remote `{remote}`; use s3://bucket/prefix, an https:// WebDAV URL, or git+<url>"
+        ),
+    )
+}
+
+/// `base` joined with `prefix`, always ending in `/` so keys resolve below it.
+fn with_prefix(base: &str, prefix: &str) -> Option<Url> {
+    let mut url = base.trim_end_matches('/').to_string();
+    let prefix = prefix.trim_matches('/');
+    if !prefix.is_empty() {
+        url.push('/');
+        url.push_str(prefix);
+    }
+    url.push('/');
+    Url::parse(&url).ok()
+}
+
+fn status_error(method: &Method, url: &Url, status: StatusCode) -> io::Error {
+    io::Error::other(format!("{method} {url} failed: {status}"))
+}
+
+pub(crate) struct S3Remote {
+    client: reqwest::Client,
+    base: Url,
+    region: String,
+}
+
+struct S3Credentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+}
+
+impl S3Credentials {
+    fn from_env() -> io::Result<Self> {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        match (var("AWS_ACCESS_KEY_ID"), var("AWS_SECRET_ACCESS_KEY")) {
+            (Some(access_key_id), Some(secret_access_key)) => Ok(Self {
+                access_key_id,
+                secret_access_key,
+                session_token: var("AWS_SESSION_TOKEN"),
+            }),
+            _ => Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "set AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY to sync with S3",
+            )),
+        }
+    }
+}
+
+/// The parts of a request covered by an AWS Signature Version 4.
+struct SigV4Request<'a> {
+    method: &'a str,
+    path: &'a str,
+    /// Lowercase header names with their values, sorted by name.
+    headers: &'a [(&'a str, String)],
+    payload_hash: &'a str,
+    amz_date: &'a str,
+    region: &'a str,
+}
+
+impl SigV4Request<'_> {
+    fn authorization(&self, credentials: &S3Credentials) -> String {
+        let date = &self.amz_date[..8];
+        let scope = format!("{date}/{}/s3/aws4_request", self.region);
+        let signed_headers = self
+            .headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_headers: String = self
+            .headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{}\n", value.trim()))
+            .collect();
+        let canonical_request = format!(
+            "{}\n{}\n\n{canonical_headers}\n{signed_headers}\n{}",
+            self.method, self.path, self.payload_hash
+        );
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{scope}\n{}",
+            self.amz_date,
+            hex(&Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let secret = format!("AWS4{}", credentials.secret_access_key);
+        let key = hmac_sha256(secret.as_bytes(), date.as_bytes());
+        let key = hmac_sha256(&key, self.region.as_bytes());
+        let key = hmac_sha256(&key, b"s3");
+        let key = hmac_sha256(&key, b"aws4_request");
+        let signature = hex(&hmac_sha256(&key, string_to_sign.as_bytes()));
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            credentials.access_key_id
+        )
+    }
+}
+
+impl S3Remote {
+    async fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        let (url, response) = self.send(Method::GET, key, Vec::new()).await?;
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => Ok(Some(
+                response.bytes().await.map_err(io::Error::other)?.to_vec(),
+            )),
+            status => Err(status_error(&Method::GET, &url, status)),
+        }
+    }
+
+    async fn put(&self, key: &str, body: Vec<u8>) -> io::Result<()> {
+        let (url, response) = self.send(Method::PUT, key, body).await?;
+        match response.status() {
+            status if status.is_success() => Ok(()),
+            status => Err(status_error(&Method::PUT, &url, status)),
+        }
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        body: Vec<u8>,
+    ) -> io::Result<(Url, reqwest::Response)> {
+        let credentials = S3Credentials::from_env()?;
+        let url = self.base.join(key).map_err(io::Error::other)?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(invalid_remote(url.as_str())),
+        };
+        let payload_hash = hex(&Sha256::digest(&body));
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let mut headers = vec![
+            ("host", host),
+            ("x-amz-content-sha256", payload_hash.clone()),
+            ("x-amz-date", amz_date.clone()),
+        ];
+        if let Some(token) = &credentials.session_token {
+            headers.push(("x-amz-security-token", token.clone()));
+        }
+        let authorization = SigV4Request {
+            method: method.as_str(),
+            path: url.path(),
+            headers: &headers,
+            payload_hash: &payload_hash,
+            amz_date: &amz_date,
+            region: &self.region,
+        }
+        .authorization(&credentials);
+
+        let mut request = self
+            .client
+            .request(method, url.clone())
+            .header("authorization", authorization);
+        for (name, value) in headers.iter().filter(|(name, _)| *name != "host") {
+            request = request.header(*name, value);
+        }
+        let response = request.body(body).send().await.map_err(io::Error::other)?;
+        Ok((url, response))
+    }
+}
+
+pub(crate) struct WebDavRemote {
+    client: reqwest::Client,
+    base: Url,
+    username: Option<String>,
+    password_env: String,
+}
+
+impl WebDavRemote {
+    async fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        let url = self.base.join(key).map_err(io::Error::other)?;
+        let response = self.send(Method::GET, &url, None).await?;
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => Ok(Some(
+                response.bytes().await.map_err(io::Error::other)?.to_vec(),
+            )),
+            status => Err(status_error(&Method::GET, &url, status)),
+        }
+    }
+
+    async fn put(&self, key: &str, body: Vec<u8>) -> io::Result<()> {
+        let url = self.base.join(key).map_err(io::Error::other)?;
+        let mut status = self
+            .send(Method::PUT, &url, Some(body.clone()))
+            .await?
+            .status();
+        // Servers answer 409 Conflict when a parent collection is missing.
+        if status == StatusCode::CONFLICT {
+            self.create_collections(key).await?;
+            status = self.send(Method::PUT, &url, Some(body)).await?.status();
+        }
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(status_error(&Method::PUT, &url, status))
+        }
+    }
+
+    async fn create_collections(&self, key: &str) -> io::Result<()> {
+        let mkcol = Method::from_bytes(b"MKCOL").map_err(io::Error::other)?;
+        let mut collection = String::new();
+        let Some((parents, _)) = key.rsplit_once('/') else {
+            return Ok(());
+        };
+        for segment in parents.split('/') {
+            collection.push_str(segment);
+            collection.push('/');
+            let url = self.base.join(&collection).map_err(io::Error::other)?;
+            let status = self.send(mkcol.clone(), &url, None).await?.status();
+            // 405 Method Not Allowed means the collection already exists.
+            if !status.is_success() && status != StatusCode::METHOD_NOT_ALLOWED {
+                return Err(status_error(&mkcol, &url, status));
+            }
+        }
+        Ok(())
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        url: &Url,
+        body: Option<Vec<u8>>,
+    ) -> io::Result<reqwest::Response> {
+        let mut request = self.client.request(method, url.clone());
+        if let Some(username) = &self.username {
+            let password = std::env::var(&self.password_env).ok();
+            request = request.basic_auth(username, password);
+        }
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+        request.send().await.map_err(io::Error::other)
+    }
+}
+
+pub(crate) struct GitRemote {
+    url: String,
+    dir: PathBuf,
+}
+
+impl GitRemote {
+    async fn prepare(&self) -> io::Result<()> {
+        if !self.dir.join(".git").exists() {
+            if let Some(parent) = self.dir.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            let dir = self.dir.to_string_lossy();
+            run_git(None, &["clone", "--quiet", &self.url, &dir]).await?;
+            return Ok(());
+        }
+        // A freshly created remote has no branch to pull yet.
+        let heads = run_git(Some(&self.dir), &["ls-remote", "--heads", "origin"]).await?;
+        if !heads.trim().is_empty() {
+            run_git(Some(&self.dir), &["pull", "--ff-only", "--quiet"]).await?;
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        match tokio::fs::read(self.dir.join(key)).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn put(&self, key: &str, body: Vec<u8>) -> io::Result<()> {
+        let path = self.dir.join(key);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(path, body).await
+    }
+
+    async fn finish(&self) -> io::Result<()> {
+        run_git(Some(&self.dir), &["add", "--all"]).await?;
+        let changes = run_git(Some(&self.dir), &["status", "--porcelain"]).await?;
+        if changes.trim().is_empty() {
+            return Ok(());
+        }
+        run_git(
+            Some(&self.dir),
+            &["commit", "--quiet", "--message", GIT_COMMIT_MESSAGE],
+        )
+        .await?;
+        run_git(Some(&self.dir), &["push", "--quiet", "origin", "HEAD"]).await?;
+        Ok(())
+    }
+}
+
+async fn run_git(dir: Option<&Path>, args: &[&str]) -> io::Result<String> {
+    let mut command = Command::new("git");
+    command.args(args);
+    if let Some(dir) = dir {
+        command.current_dir(dir);
+    }
+    let output = command.output().await?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let mut inner = Sha256::new();
+    inner.update(block.map(|byte| byte ^ 0x36));
+    inner.update(data);
+    let mut outer = Sha256::new();
+    outer.update(block.map(|byte| byte ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn settings(remote: &str) -> SessionSyncToml {
+        SessionSyncToml {
+            remote: remote.to_string(),
+            ..SessionSyncToml::default()
+        }
+    }
+
+    #[test]
+    fn parses_each_remote_kind() {
+        let home = Path::new("/home/me/.codex");
+
+        let SyncRemote::S3(s3) =
+            SyncRemote::from_settings(&settings("s3://bucket/codex/sessions/"), home).unwrap()
+        else {
+            panic!("expected an S3 remote");
+        };
+        assert_eq!(
+            s3.base.as_str(),
+            "https://bucket.s3.us-east-1.amazonaws.com/codex/sessions/"
+        );
+        assert_eq!(
+            s3.base.join("objects/ab.age").unwrap().path(),
+            "/codex/sessions/objects/ab.age"
+        );
+
+        let minio = SessionSyncToml {
+            s3_endpoint: Some("http://localhost:9000/".to_string()),
+            ..settings("s3://bucket")
+        };
+        let SyncRemote::S3(s3) = SyncRemote::from_settings(&minio, home).unwrap() else {
+            panic!("expected an S3 remote");
+        };
+        assert_eq!(s3.base.as_str(), "http://localhost:9000/bucket/");
+
+        let SyncRemote::WebDav(webdav) =
+            SyncRemote::from_settings(&settings("https://dav.example.com/codex"), home).unwrap()
+        else {
+            panic!("expected a WebDAV remote");
+        };
+        assert_eq!(webdav.base.as_str(), "https://dav.example.com/codex/");
+
+        let SyncRemote::Git(git) =
+            SyncRemote::from_settings(&settings("git+ssh://git@example.com/me/s.git"), home)
+                .unwrap()
+        else {
+            panic!("expected a Git remote");
+        };
+        assert_eq!(git.url, "ssh://git@example.com/me/s.git");
+        assert_eq!(git.dir, home.join("sync/git"));
+
+        assert!(SyncRemote::from_settings(&settings("ftp://example.com"), home).is_err());
+    }
+
+    #[test]
+    fn hmac_matches_rfc_4231() {
+        assert_eq!(
+            hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
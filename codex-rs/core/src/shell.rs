@@ -249,6 +249,22 @@ pub fn default_user_shell() -> Shell {
     default_user_shell_from_path(get_user_shell_path())
 }
 
+/// Resolves the `shell.program` setting (a shell name or path). Falls back to
+/// [`default_user_shell`] when it is unset, not a supported shell, or not
+/// installed.
+pub fn configured_user_shell(program: Option<&str>) -> Shell {
+    let Some(program) = program else {
+        return default_user_shell();
+    };
+    let path = which::which(program).unwrap_or_else(|_| PathBuf::from(program));
+    detect_shell_type(&path)
+        .and_then(|shell_type| get_shell(shell_type, Some(&path)))
+        .unwrap_or_else(|| {
+            tracing::warn!("configured shell `{program}` is unavailable; using the default shell");
+            default_user_shell()
+        })
+}
+
 fn default_user_shell_from_path(user_shell_path: Option<PathBuf>) -> Shell {
     if cfg!(windows) {
         get_shell(ShellType::PowerShell, None).unwrap_or(ultimate_fallback_shell())
@@ -390,6 +406,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn configured_shell_resolves_names_and_paths() {
+        let sh_shell = configured_user_shell(Some("sh"));
+        assert_eq!(sh_shell.shell_type, ShellType::Sh);
+
+        let bash_path = get_shell(ShellType::Bash, None).unwrap().shell_path;
+        let bash_shell = configured_user_shell(Some(&bash_path.to_string_lossy()));
+        assert_eq!(bash_shell.shell_type, ShellType::Bash);
+        assert_eq!(bash_shell.shell_path, bash_path);
+    }
+
+    #[test]
+    fn configured_shell_falls_back_to_default_when_unavailable() {
+        assert_eq!(
+            configured_user_shell(Some("/nonexistent/fish")),
+            default_user_shell()
+        );
+        assert_eq!(configured_user_shell(None), default_user_shell());
+    }
+
     #[test]
     fn can_run_on_shell_test() {
         let cmd = "echo \"Works\"";
@@ -0,0 +1,358 @@
+//! Offline checks and prompt rendering for `codex skills test`.
+//!
+//! The loader is deliberately lenient: invalid interface fields or dependency
+//! entries are dropped with a log line so a typo never hides a skill. Authors
+//! iterating on a skill want the opposite, so [`validate_skill`] re-reads the
+//! raw `agents/openai.yaml` and reports everything the loader discarded.
+
+use std::fs;
+use std::path::Path;
+
+use serde_yaml::Value as YamlValue;
+
+use crate::instructions::SkillInstructions;
+use crate::skills::model::SkillMetadata;
+
+const SKILLS_METADATA_DIR: &str = "agents";
+const SKILLS_METADATA_FILENAME: &str = "openai.yaml";
+const INTERFACE_FIELDS: [&str; 6] = [
+    "display_name",
+    "short_description",
+    "icon_small",
+    "icon_large",
+    "brand_color",
+    "default_prompt",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillIssueSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillIssue {
+    pub severity: SkillIssueSeverity,
+    pub message: String,
+}
+
+impl SkillIssue {
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: SkillIssueSeverity::Error,
+            message: message.into(),
+        }
+    }
+
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: SkillIssueSeverity::Warning,
+            message: message.into(),
+        }
+    }
+}
+
+/// Checks a loaded skill against its on-disk declaration.
+///
+/// Errors cover metadata the loader ignored (bad icon paths, colors, or
+/// incomplete dependency entries) and declared icons that do not exist.
+/// Warnings cover things that only matter at run time, such as unset
+/// `env_var` dependencies.
+pub fn validate_skill(skill: &SkillMetadata) -> Vec<SkillIssue> {
+    let mut issues = Vec::new();
+    let Some(skill_dir) = skill.path.parent() else {
+        return issues;
+    };
+
+    match fs::read_to_string(&skill.path) {
+        Ok(contents) if skill_body(&contents).trim().is_empty() => {
+            issues.push(SkillIssue::warning(
+                "SKILL.md has no instructions after the frontmatter",
+            ));
+        }
+        Ok(_) => {}
+        Err(err) => issues.push(SkillIssue::error(format!("failed to read SKILL.md: {err}"))),
+    }
+
+    if let Some(raw) = read_raw_metadata(skill_dir, &mut issues) {
+        validate_interface(skill, raw.get("interface"), &mut issues);
+        validate_dependency_count(skill, raw.get("dependencies"), &mut issues);
+    }
+
+    if let Some(interface) = &skill.interface {
+        for (field, icon) in [
+            ("icon_small", &interface.icon_small),
+            ("icon_large", &interface.icon_large),
+        ] {
+            if let Some(icon) = icon
+                && !icon.is_file()
+            {
+                issues.push(SkillIssue::error(format!(
+                    "interface.{field} points to a missing file: {}",
+                    icon.display()
+                )));
+            }
+        }
+    }
+
+    for tool in skill
+        .dependencies
+        .iter()
+        .flat_map(|dependencies| dependencies.tools.iter())
+    {
+        if tool.r#type == "env_var" && std::env::var_os(&tool.value).is_none() {
+            issues.push(SkillIssue::warning(format!(
+                "env_var dependency {} is not set in this environment",
+                tool.value
+            )));
+        }
+    }
+
+    issues
+}
+
+/// The user message `codex skills test` sends for `input`.
+pub fn skill_test_prompt(skill: &SkillMetadata, input: &str) -> String {
+    format!("${} {input}", skill.name)
+}
+
+/// Renders the items a turn mentioning `skill` would send, without calling a
+/// model: the injected skill block followed by the user message.
+pub fn render_skill_test_turn(skill: &SkillMetadata, input: &str) -> std::io::Result<String> {
+    let contents = fs::read_to_string(&skill.path)?;
+    let instructions = SkillInstructions {
+        name: skill.name.clone(),
+        path: skill.path.to_string_lossy().into_owned(),
+        contents,
+    };
+    Ok(format!(
+        "{}\n\n{}",
+        instructions.render(),
+        skill_test_prompt(skill, input)
+    ))
+}
+
+fn skill_body(contents: &str) -> &str {
+    let Some(rest) = contents.trim_start().strip_prefix("---") else {
+        return contents;
+    };
+    match rest.find("\n---") {
+        Some(end) => {
+            let after = &rest[end + "\n---".len()..];
+            after.split_once('\n').map_or("", |(_, body)| body)
+        }
+        None => "",
+    }
+}
+
+fn read_raw_metadata(skill_dir: &Path, issues: &mut Vec<SkillIssue>) -> Option<YamlValue> {
+    let path = skill_dir
+        .join(SKILLS_METADATA_DIR)
+        .join(SKILLS_METADATA_FILENAME);
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(err) => {
+            issues.push(SkillIssue::error(format!(
+                "failed to read {}: {err}",
+                path.display()
+            )));
+            return None;
+        }
+    };
+    match serde_yaml::from_str(&contents) {
+        Ok(raw) => Some(raw),
+        Err(err) => {
+            issues.push(SkillIssue::error(format!(
+                "{} is not valid YAML; the loader ignores it: {err}",
+                path.display()
+            )));
+            None
+        }
+    }
+}
+
+fn validate_interface(
+    skill: &SkillMetadata,
+    raw: Option<&YamlValue>,
+    issues: &mut Vec<SkillIssue>,
+) {
+    let Some(YamlValue::Mapping(raw)) = raw else {
+        return;
+    };
+
+    for key in raw.keys() {
+        let key = key.as_str().unwrap_or_default();
+        if !INTERFACE_FIELDS.contains(&key) {
+            issues.push(SkillIssue::warning(format!(
+                "interface.{key} is not a recognized field"
+            )));
+        }
+    }
+
+    let interface = skill.interface.as_ref();
+    for field in INTERFACE_FIELDS {
+        let declared = raw
+            .get(field)
+            .is_some_and(|value| !matches!(value, YamlValue::Null));
+        if !declared {
+            continue;
+        }
+        let resolved = interface.is_some_and(|interface| match field {
+            "display_name" => interface.display_name.is_some(),
+            "short_description" => interface.short_description.is_some(),
+            "icon_small" => interface.icon_small.is_some(),
+            "icon_large" => interface.icon_large.is_some(),
+            "brand_color" => interface.brand_color.is_some(),
+            "default_prompt" => interface.default_prompt.is_some(),
+            _ => true,
+        });
+        if !resolved {
+            issues.push(SkillIssue::error(format!(
+                "interface.{field} was ignored by the loader ({})",
+                interface_field_rule(field)
+            )));
+        }
+    }
+}
+
+fn interface_field_rule(field: &str) -> &'static str {
+    match field {
+        "icon_small" | "icon_large" => "icons must be relative paths under assets/",
+        "brand_color" => "expected #RRGGBB",
+        "display_name" => "must be a non-empty string of at most 64 characters",
+        _ => "must be a non-empty string of at most 1024 characters",
+    }
+}
+
+fn validate_dependency_count(
+    skill: &SkillMetadata,
+    raw: Option<&YamlValue>,
+    issues: &mut Vec<SkillIssue>,
+) {
+    let declared = raw
+        .and_then(|dependencies| dependencies.get("tools"))
+        .and_then(YamlValue::as_sequence)
+        .map_or(0, Vec::len);
+    let resolved = skill
+        .dependencies
+        .as_ref()
+        .map_or(0, |dependencies| dependencies.tools.len());
+    if declared > resolved {
+        issues.push(SkillIssue::error(format!(
+            "{} of {declared} dependencies.tools entries were ignored (type and value are required)",
+            declared - resolved
+        )));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skills::loader::parse_skill_file;
+    use codex_protocol::protocol::SkillScope;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn write_skill(root: &TempDir, body: &str, metadata: Option<&str>) -> SkillMetadata {
+        let skill_dir = root.path().join("demo");
+        fs::create_dir_all(skill_dir.join(SKILLS_METADATA_DIR)).expect("mkdir");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            format!("---\nname: demo\ndescription: demo skill\n---\n{body}"),
+        )
+        .expect("write SKILL.md");
+        if let Some(metadata) = metadata {
+            fs::write(
+                skill_dir
+                    .join(SKILLS_METADATA_DIR)
+                    .join(SKILLS_METADATA_FILENAME),
+                metadata,
+            )
+            .expect("write openai.yaml");
+        }
+        parse_skill_file(&skill_dir.join("SKILL.md"), SkillScope::User).expect("parse")
+    }
+
+    fn messages(issues: &[SkillIssue], severity: SkillIssueSeverity) -> Vec<String> {
+        issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .map(|issue| issue.message.clone())
+            .collect()
+    }
+
+    #[test]
+    fn valid_skill_has_no_issues() {
+        let root = TempDir::new().expect("tempdir");
+        let skill = write_skill(
+            &root,
+            "\n# Demo\n\nDo the thing.\n",
+            Some("interface:\n  brand_color: \"#112233\"\n  default_prompt: run demo\n"),
+        );
+
+        assert_eq!(validate_skill(&skill), Vec::new());
+    }
+
+    #[test]
+    fn reports_interface_fields_dropped_by_loader() {
+        let root = TempDir::new().expect("tempdir");
+        let skill = write_skill(
+            &root,
+            "\nDo the thing.\n",
+            Some(
+                "interface:\n  brand_color: red\n  icon_small: ../icon.png\n  tagline: hi\ndependencies:\n  tools:\n    - type: mcp\n",
+            ),
+        );
+
+        let issues = validate_skill(&skill);
+        assert_eq!(
+            messages(&issues, SkillIssueSeverity::Error),
+            vec![
+                "interface.icon_small was ignored by the loader (icons must be relative paths under assets/)"
+                    .to_string(),
+                "interface.brand_color was ignored by the loader (expected #RRGGBB)".to_string(),
+                "1 of 1 dependencies.tools entries were ignored (type and value are required)"
+                    .to_string(),
+            ]
+        );
+        assert_eq!(
+            messages(&issues, SkillIssueSeverity::Warning),
+            vec!["interface.tagline is not a recognized field".to_string()]
+        );
+    }
+
+    #[test]
+    fn reports_missing_icon_and_empty_body() {
+        let root = TempDir::new().expect("tempdir");
+        let skill = write_skill(
+            &root,
+            "\n",
+            Some("interface:\n  icon_small: assets/icon.png\n"),
+        );
+
+        let issues = validate_skill(&skill);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            issues[0],
+            SkillIssue::warning("SKILL.md has no instructions after the frontmatter")
+        );
+        assert!(
+            issues[1]
+                .message
+                .starts_with("interface.icon_small points to a missing file")
+        );
+    }
+
+    #[test]
+    fn renders_skill_block_and_prompt() {
+        let root = TempDir::new().expect("tempdir");
+        let skill = write_skill(&root, "\nDo the thing.\n", None);
+
+        let rendered = render_skill_test_turn(&skill, "on main").expect("render");
+
+        assert!(rendered.starts_with("<skill>\n<name>demo</name>\n"));
+        assert!(rendered.contains("Do the thing."));
+        assert!(rendered.ends_with("</skill>\n\n$demo on main"));
+    }
+}
@@ -0,0 +1,888 @@
+//! Installs and updates skills from git repositories or a registry index.
+//!
+//! Installed skills are copied into `CODEX_HOME/skills/<name>` and recorded in
+//! `CODEX_HOME/skills-lock.toml` together with the resolved commit and an
+//! integrity hash of the installed files. Because `CODEX_HOME/skills` is a
+//! watched skill root, running sessions receive `SkillsUpdateAvailable` as soon
+//! as an install or update lands on disk.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_protocol::protocol::SkillScope;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+use tokio::process::Command;
+
+use crate::default_client::build_reqwest_client;
+use crate::skills::loader::parse_skill_file;
+use crate::skills::scaffold::validate_skill_name;
+
+pub const SKILLS_LOCK_FILENAME: &str = "skills-lock.toml";
+const SKILLS_DIR_NAME: &str = "skills";
+const SKILLS_FILENAME: &str = "SKILL.md";
+const REGISTRY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where a skill should be installed from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillSource {
+    /// A git repository. `reference` pins a branch, tag, or commit; `subdir`
+    /// selects the skill directory inside the repository.
+    Git {
+        url: String,
+        reference: Option<String>,
+        subdir: Option<PathBuf>,
+    },
+    /// A skill listed in a registry index, optionally pinned to `version`.
+    Registry {
+        index: String,
+        name: String,
+        version: Option<String>,
+    },
+}
+
+impl SkillSource {
+    /// Interprets `raw` as a git source when it looks like a repository URL or
+    /// local path, and as a `name[@version]` registry entry otherwise.
+    pub fn parse(
+        raw: &str,
+        reference: Option<String>,
+        subdir: Option<PathBuf>,
+        registry: Option<String>,
+    ) -> Result<Self> {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            bail!("skill source must not be empty");
+        }
+
+        let looks_like_git = raw.contains("://")
+            || raw.starts_with("git@")
+            || raw.ends_with(".git")
+            || Path::new(raw).is_dir();
+        if looks_like_git {
+            return Ok(SkillSource::Git {
+                url: raw.to_string(),
+                reference,
+                subdir,
+            });
+        }
+
+        let Some(index) = registry else {
+            bail!(
+                "`{raw}` is not a git URL; pass --registry <URL|PATH> to install from a registry index"
+            );
+        };
+        if reference.is_some() || subdir.is_some() {
+            bail!("--ref and --path only apply to git sources");
+        }
+        let (name, version) = match raw.split_once('@') {
+            Some((name, version)) => (name.to_string(), Some(version.to_string())),
+            None => (raw.to_string(), None),
+        };
+        Ok(SkillSource::Registry {
+            index,
+            name,
+            version,
+        })
+    }
+}
+
+/// Lock file tracking skills installed with [`install_skill`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillsLock {
+    #[serde(default)]
+    pub skills: BTreeMap<String, LockedSkill>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedSkill {
+    /// Git URL the skill was cloned from.
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    #[serde(default, rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Pinned skills are never moved by `codex skills update`.
+    #[serde(default)]
+    pub pinned: bool,
+    pub commit: String,
+    pub integrity: String,
+}
+
+impl SkillsLock {
+    pub fn load(codex_home: &Path) -> Result<Self> {
+        let path = codex_home.join(SKILLS_LOCK_FILENAME);
+        match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("failed to parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, codex_home: &Path) -> Result<()> {
+        let path = codex_home.join(SKILLS_LOCK_FILENAME);
+        let contents = toml::to_string_pretty(self).context("failed to serialize skills lock")?;
+        fs::write(&path, contents).with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledSkill {
+    pub name: String,
+    pub path: PathBuf,
+    pub locked: LockedSkill,
+    /// True when an existing install was replaced.
+    pub replaced: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstallOptions {
+    /// Override the directory name (defaults to the skill's frontmatter name).
+    pub name: Option<String>,
+    /// Expected integrity hash (`sha256:<hex>`), checked before installing.
+    pub integrity: Option<String>,
+    /// Replace an existing directory that was not installed by `codex skills install`
+    /// or that has local modifications.
+    pub force: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillUpdateStatus {
+    UpToDate,
+    Pinned,
+    Available { current: String, latest: String },
+    LocallyModified,
+    Missing,
+    Error(String),
+}
+
+#[derive(Debug, Deserialize)]
+struct RegistryIndex {
+    skills: Vec<RegistryEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RegistryEntry {
+    name: String,
+    /// Newest first.
+    versions: Vec<RegistryVersion>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RegistryVersion {
+    version: String,
+    git: String,
+    #[serde(default, rename = "ref")]
+    reference: Option<String>,
+    #[serde(default)]
+    path: Option<PathBuf>,
+    #[serde(default)]
+    integrity: Option<String>,
+}
+
+struct ResolvedSource {
+    url: String,
+    reference: Option<String>,
+    subdir: Option<PathBuf>,
+    registry: Option<String>,
+    version: Option<String>,
+    integrity: Option<String>,
+    pinned: bool,
+}
+
+pub async fn install_skill(
+    codex_home: &Path,
+    source: &SkillSource,
+    options: &InstallOptions,
+) -> Result<InstalledSkill> {
+    let resolved = resolve_source(source).await?;
+    install_resolved(codex_home, resolved, options).await
+}
+
+/// Reports whether each locked skill (or only `name`) has a newer revision.
+pub async fn check_skill_updates(
+    codex_home: &Path,
+    name: Option<&str>,
+) -> Result<Vec<(String, SkillUpdateStatus)>> {
+    let lock = SkillsLock::load(codex_home)?;
+    let mut statuses = Vec::new();
+    for (skill_name, locked) in locked_entries(&lock, name)? {
+        let status = update_status(codex_home, &skill_name, &locked).await;
+        statuses.push((skill_name, status));
+    }
+    Ok(statuses)
+}
+
+/// Reinstalls every locked skill (or only `name`) that has an update available.
+pub async fn update_skills(
+    codex_home: &Path,
+    name: Option<&str>,
+    force: bool,
+) -> Result<Vec<InstalledSkill>> {
+    let lock = SkillsLock::load(codex_home)?;
+    let mut updated = Vec::new();
+    for (skill_name, locked) in locked_entries(&lock, name)? {
+        match update_status(codex_home, &skill_name, &locked).await {
+            SkillUpdateStatus::Available { .. } | SkillUpdateStatus::Missing => {}
+            SkillUpdateStatus::LocallyModified if force => {}
+            SkillUpdateStatus::LocallyModified => {
+                bail!(
+                    "skill '{skill_name}' has local modifications; rerun with --force to overwrite them"
+                );
+            }
+            SkillUpdateStatus::UpToDate | SkillUpdateStatus::Pinned => continue,
+            SkillUpdateStatus::Error(err) => bail!("failed to check '{skill_name}': {err}"),
+        }
+
+        let resolved = match &locked.registry {
+            Some(index) => {
+                resolve_source(&SkillSource::Registry {
+                    index: index.clone(),
+                    name: skill_name.clone(),
+                    version: None,
+                })
+                .await?
+            }
+            None => ResolvedSource {
+                url: locked.source.clone(),
+                reference: None,
+                subdir: locked.path.clone(),
+                registry: None,
+                version: None,
+                integrity: None,
+                pinned: false,
+            },
+        };
+        let options = InstallOptions {
+            name: Some(skill_name),
+            integrity: None,
+            force: true,
+        };
+        updated.push(install_resolved(codex_home, resolved, &options).await?);
+    }
+    Ok(updated)
+}
+
+fn locked_entries(lock: &SkillsLock, name: Option<&str>) -> Result<Vec<(String, LockedSkill)>> {
+    match name {
+        Some(name) => {
+            let Some(locked) = lock.skills.get(name) else {
+                bail!("skill '{name}' was not installed with `codex skills install`");
+            };
+            Ok(vec![(name.to_string(), locked.clone())])
+        }
+        None => Ok(lock
+            .skills
+            .iter()
+            .map(|(name, locked)| (name.clone(), locked.clone()))
+            .collect()),
+    }
+}
+
+async fn update_status(codex_home: &Path, name: &str, locked: &LockedSkill) -> SkillUpdateStatus {
+    let skill_dir = codex_home.join(SKILLS_DIR_NAME).join(name);
+    if !skill_dir.is_dir() {
+        return SkillUpdateStatus::Missing;
+    }
+    match integrity_of_dir(&skill_dir) {
+        Ok(integrity) if integrity != locked.integrity => {
+            return SkillUpdateStatus::LocallyModified;
+        }
+        Ok(_) => {}
+        Err(err) => return SkillUpdateStatus::Error(err.to_string()),
+    }
+    if locked.pinned {
+        return SkillUpdateStatus::Pinned;
+    }
+
+    let latest = match &locked.registry {
+        Some(index) => fetch_registry_index(index)
+            .await
+            .and_then(|index| latest_registry_version(&index, name).map(|version| version.version)),
+        None => remote_head(&locked.source).await,
+    };
+    let current = match &locked.registry {
+        Some(_) => locked.version.clone().unwrap_or_default(),
+        None => locked.commit.clone(),
+    };
+    match latest {
+        Ok(latest) if latest == current => SkillUpdateStatus::UpToDate,
+        Ok(latest) => SkillUpdateStatus::Available { current, latest },
+        Err(err) => SkillUpdateStatus::Error(err.to_string()),
+    }
+}
+
+async fn resolve_source(source: &SkillSource) -> Result<ResolvedSource> {
+    match source {
+        SkillSource::Git {
+            url,
+            reference,
+            subdir,
+        } => Ok(ResolvedSource {
+            url: url.clone(),
+            reference: reference.clone(),
+            subdir: subdir.clone(),
+            registry: None,
+            version: None,
+            integrity: None,
+            pinned: reference.is_some(),
+        }),
+        SkillSource::Registry {
+            index,
+            name,
+            version,
+        } => {
+            let registry = fetch_registry_index(index).await?;
+            let entry = match version {
+                Some(version) => registry
+                    .skills
+                    .iter()
+                    .find(|entry| entry.name == *name)
+                    .and_then(|entry| entry.versions.iter().find(|v| v.version == *version))
+                    .cloned()
+                    .with_context(|| format!("{name}@{version} not found in registry {index}"))?,
+                None => latest_registry_version(&registry, name)?,
+            };
+            Ok(ResolvedSource {
+                url: entry.git,
+                reference: entry.reference,
+                subdir: entry.path,
+                registry: Some(index.clone()),
+                version: Some(entry.version),
+                integrity: entry.integrity,
+                pinned: version.is_some(),
+            })
+        }
+    }
+}
+
+fn latest_registry_version(index: &RegistryIndex, name: &str) -> Result<RegistryVersion> {
+    index
+        .skills
+        .iter()
+        .find(|entry| entry.name == name)
+        .and_then(|entry| entry.versions.first())
+        .cloned()
+        .with_context(|| format!("skill '{name}' not found in registry"))
+}
+
+async fn fetch_registry_index(index: &str) -> Result<RegistryIndex> {
+    let body = if index.starts_with("http://") || index.starts_with("https://") {
+        let response = build_reqwest_client()
+            .get(index)
+            .timeout(REGISTRY_TIMEOUT)
+            .send()
+            .await
+            .with_context(|| format!("failed to fetch registry index {index}"))?;
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            bail!("registry index request failed with status {status} from {index}");
+        }
+        body
+    } else {
+        fs::read_to_string(index).with_context(|| format!("failed to read registry {index}"))?
+    };
+    serde_json::from_str(&body).with_context(|| format!("failed to parse registry index {index}"))
+}
+
+async fn install_resolved(
+    codex_home: &Path,
+    resolved: ResolvedSource,
+    options: &InstallOptions,
+) -> Result<InstalledSkill> {
+    reject_option_like("git URL", &resolved.url)?;
+    if let Some(reference) = &resolved.reference {
+        reject_option_like("git ref", reference)?;
+    }
+    if let Some(subdir) = &resolved.subdir {
+        validate_subdir(subdir)?;
+    }
+
+    let checkout = tempfile::tempdir().context("failed to create temporary checkout dir")?;
+    run_git(
+        None,
+        &[
+            "clone",
+            "--quiet",
+            "--",
+            &resolved.url,
+            &path_arg(checkout.path()),
+        ],
+    )
+    .await?;
+    if let Some(reference) = &resolved.reference {
+        // The trailing `--` makes git read `reference` as a revision, never a path.
+        run_git(
+            Some(checkout.path()),
+            &["checkout", "--quiet", reference, "--"],
+        )
+        .await?;
+    }
+    let commit = run_git(Some(checkout.path()), &["rev-parse", "HEAD"]).await?;
+
+    let skill_src = match &resolved.subdir {
+        Some(subdir) => {
+            let skill_src = checkout.path().join(subdir);
+            // Symlinks committed to the repository can still lead out of it.
+            let root = checkout
+                .path()
+                .canonicalize()
+                .context("failed to resolve checkout dir")?;
+            if let Ok(target) = skill_src.canonicalize()
+                && !target.starts_with(&root)
+            {
+                bail!(
+                    "skill path {} resolves outside of {}",
+                    subdir.display(),
+                    resolved.url
+                );
+            }
+            skill_src
+        }
+        None => checkout.path().to_path_buf(),
+    };
+    let skill_md = skill_src.join(SKILLS_FILENAME);
+    if !skill_md.is_file() {
+        bail!(
+            "no {SKILLS_FILENAME} found in {}{}",
+            resolved.url,
+            resolved
+                .subdir
+                .as_ref()
+                .map(|subdir| format!(" at {}", subdir.display()))
+                .unwrap_or_default()
+        );
+    }
+    let metadata = parse_skill_file(&skill_md, SkillScope::User)
+        .map_err(|err| anyhow::anyhow!("invalid skill: {err}"))?;
+
+    let name = options.name.clone().unwrap_or(metadata.name);
+    validate_skill_name(&name)?;
+
+    let integrity = integrity_of_dir(&skill_src)?;
+    let expected = options.integrity.as_ref().or(resolved.integrity.as_ref());
+    if let Some(expected) = expected
+        && *expected != integrity
+    {
+        bail!("integrity mismatch for '{name}': expected {expected}, got {integrity}");
+    }
+
+    let skills_root = codex_home.join(SKILLS_DIR_NAME);
+    let dest = skills_root.join(&name);
+    let mut lock = SkillsLock::load(codex_home)?;
+    let replaced = dest.exists();
+    if replaced && !options.force {
+        match lock.skills.get(&name) {
+            Some(previous) if integrity_of_dir(&dest)? == previous.integrity => {}
+            Some(_) => bail!(
+                "skill '{name}' has local modifications; rerun with --force to overwrite them"
+            ),
+            None => bail!(
+                "{} already exists and was not installed by `codex skills install`; rerun with --force to replace it",
+                dest.display()
+            ),
+        }
+    }
+
+    // Stage next to the destination (dot-prefixed dirs are ignored by the loader) and
+    // swap in place so the watcher never observes a half-copied skill.
+    let staging = skills_root.join(format!(".{name}.installing"));
+    if staging.exists() {
+        fs::remove_dir_all(&staging)
+            .with_context(|| format!("failed to remove {}", staging.display()))?;
+    }
+    copy_dir(&skill_src, &staging)?;
+    if replaced {
+        fs::remove_dir_all(&dest)
+            .with_context(|| format!("failed to remove {}", dest.display()))?;
+    }
+    fs::rename(&staging, &dest)
+        .with_context(|| format!("failed to move skill into {}", dest.display()))?;
+
+    let locked = LockedSkill {
+        source: resolved.url,
+        path: resolved.subdir,
+        reference: resolved.reference,
+        registry: resolved.registry,
+        version: resolved.version,
+        pinned: resolved.pinned,
+        commit,
+        integrity,
+    };
+    lock.skills.insert(name.clone(), locked.clone());
+    lock.save(codex_home)?;
+
+    Ok(InstalledSkill {
+        name,
+        path: dest,
+        locked,
+        replaced,
+    })
+}
+
+async fn remote_head(url: &str) -> Result<String> {
+    reject_option_like("git URL", url)?;
+    let output = run_git(None, &["ls-remote", "--", url, "HEAD"]).await?;
+    output
+        .split_whitespace()
+        .next()
+        .map(str::to_string)
+        .with_context(|| format!("no HEAD reported by {url}"))
+}
+
+async fn run_git(cwd: Option<&Path>, args: &[&str]) -> Result<String> {
+    let mut command = Command::new("git");
+    if let Some(cwd) = cwd {
+        command.current_dir(cwd);
+    }
+    let output = command
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .await
+        .context("failed to run git")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git {} failed: {}", args.join(" "), stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// URLs and refs come from registry indexes and lock files; one starting with
+/// `-` would reach git as an option such as `--upload-pack`.
+fn reject_option_like(what: &str, value: &str) -> Result<()> {
+    if value.starts_with('-') {
+        bail!("{what} `{value}` must not start with '-'");
+    }
+    Ok(())
+}
+
+/// Skill paths must stay inside the checkout: only plain relative components.
+fn validate_subdir(subdir: &Path) -> Result<()> {
+    if !subdir
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        bail!(
+            "skill path {} must be relative to the repository root",
+            subdir.display()
+        );
+    }
+    Ok(())
+}
+
+fn path_arg(path: &Path) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+/// Hashes every file under `dir` (excluding `.git`) by relative path and contents.
+pub fn integrity_of_dir(dir: &Path) -> Result<String> {
+    let mut files = Vec::new();
+    collect_files(dir, Path::new(""), &mut files)?;
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for relative in files {
+        let contents = fs::read(dir.join(&relative))
+            .with_context(|| format!("failed to read {}", relative.display()))?;
+        let relative = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        hasher.update(relative.as_bytes());
+        hasher.update([0]);
+        hasher.update((contents.len() as u64).to_le_bytes());
+        hasher.update(contents);
+    }
+    let hex = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    Ok(format!("sha256:{hex}"))
+}
+
+fn collect_files(root: &Path, relative: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let dir = root.join(relative);
+    for entry in fs::read_dir(&dir).with_context(|| format!("failed to read {}", dir.display()))? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        if file_name == ".git" {
+            continue;
+        }
+        let child = relative.join(&file_name);
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_files(root, &child, files)?;
+        } else if file_type.is_file() {
+            files.push(child);
+        }
+    }
+    Ok(())
+}
+
+fn copy_dir(src: &Path, dest: &Path) -> Result<()> {
+    fs::create_dir_all(dest).with_context(|| format!("failed to create {}", dest.display()))?;
+    for entry in fs::read_dir(src).with_context(|| format!("failed to read {}", src.display()))? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        if file_name == ".git" {
+            continue;
+        }
+        let file_type = entry.file_type()?;
+        let target = dest.join(&file_name);
+        if file_type.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if file_type.is_file() {
+            fs::copy(entry.path(), &target)
+                .with_context(|| format!("failed to copy {}", entry.path().display()))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .current_dir(dir)
+            .args(args)
+            .env("GIT_CONFIG_GLOBAL", "/dev/null")
+            .env("GIT_CONFIG_NOSYSTEM", "1")
+            .status()
+            .expect("run git");
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn skill_repo(description: &str) -> TempDir {
+        let repo = TempDir::new().expect("tempdir");
+        let skill_dir = repo.path().join("skills").join("demo");
+        fs::create_dir_all(&skill_dir).expect("mkdir");
+        fs::write(
+            skill_dir.join(SKILLS_FILENAME),
+            format!("---\nname: demo\ndescription: {description}\n---\n\nBody\n"),
+        )
+        .expect("write skill");
+        git(repo.path(), &["init", "--quiet"]);
+        git(repo.path(), &["add", "."]);
+        git(
+            repo.path(),
+            &[
+                "-c",
+                "user.name=test",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "--quiet",
+                "-m",
+                "init",
+            ],
+        );
+        repo
+    }
+
+    fn git_source(repo: &TempDir) -> SkillSource {
+        SkillSource::Git {
+            url: repo.path().to_string_lossy().into_owned(),
+            reference: None,
+            subdir: Some(PathBuf::from("skills/demo")),
+        }
+    }
+
+    fn default_options() -> InstallOptions {
+        InstallOptions {
+            name: None,
+            integrity: None,
+            force: false,
+        }
+    }
+
+    #[test]
+    fn parse_distinguishes_git_and_registry_sources() {
+        assert_eq!(
+            SkillSource::parse("https://example.com/skills.git", None, None, None)
+                .expect("git source"),
+            SkillSource::Git {
+                url: "https://example.com/skills.git".to_string(),
+                reference: None,
+                subdir: None,
+            }
+        );
+        assert_eq!(
+            SkillSource::parse("pdf@1.2.0", None, None, Some("index.json".to_string()))
+                .expect("registry source"),
+            SkillSource::Registry {
+                index: "index.json".to_string(),
+                name: "pdf".to_string(),
+                version: Some("1.2.0".to_string()),
+            }
+        );
+        assert!(SkillSource::parse("pdf", None, None, None).is_err());
+    }
+
+    #[tokio::test]
+    async fn installs_skill_from_git_and_records_lock() {
+        let repo = skill_repo("Demo skill");
+        let codex_home = TempDir::new().expect("tempdir");
+
+        let installed = install_skill(codex_home.path(), &git_source(&repo), &default_options())
+            .await
+            .expect("install");
+
+        assert_eq!(installed.name, "demo");
+        assert!(!installed.replaced);
+        assert!(installed.path.join(SKILLS_FILENAME).is_file());
+        let lock = SkillsLock::load(codex_home.path()).expect("lock");
+        assert_eq!(lock.skills.get("demo"), Some(&installed.locked));
+        assert_eq!(
+            integrity_of_dir(&installed.path).expect("integrity"),
+            installed.locked.integrity
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_integrity_mismatch() {
+        let repo = skill_repo("Demo skill");
+        let codex_home = TempDir::new().expect("tempdir");
+        let options = InstallOptions {
+            integrity: Some("sha256:00".to_string()),
+            ..default_options()
+        };
+
+        let err = install_skill(codex_home.path(), &git_source(&repo), &options)
+            .await
+            .expect_err("mismatch");
+
+        assert!(err.to_string().contains("integrity mismatch"));
+        assert!(
+            !codex_home
+                .path()
+                .join(SKILLS_DIR_NAME)
+                .join("demo")
+                .exists()
+        );
+    }
+
+    fn resolved_git(url: &str, reference: Option<&str>, subdir: Option<&str>) -> ResolvedSource {
+        ResolvedSource {
+            url: url.to_string(),
+            reference: reference.map(str::to_string),
+            subdir: subdir.map(PathBuf::from),
+            registry: None,
+            version: None,
+            integrity: None,
+            pinned: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_option_like_arguments_and_escaping_paths() {
+        let repo = skill_repo("Demo skill");
+        let outside = TempDir::new().expect("tempdir");
+        fs::write(
+            outside.path().join(SKILLS_FILENAME),
+            "---\nname: outside\ndescription: Not in the repo\n---\n",
+        )
+        .expect("write outside skill");
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(outside.path(), repo.path().join("escape"))
+                .expect("symlink");
+            git(repo.path(), &["add", "escape"]);
+            git(
+                repo.path(),
+                &[
+                    "-c",
+                    "user.name=test",
+                    "-c",
+                    "user.email=test@example.com",
+                    "commit",
+                    "--quiet",
+                    "-m",
+                    "escape",
+                ],
+            );
+        }
+        let codex_home = TempDir::new().expect("tempdir");
+        let url = repo.path().to_string_lossy().into_owned();
+        let mut cases = vec![
+            (
+                resolved_git("--upload-pack=touch pwned", None, None),
+                "must not start with '-'",
+            ),
+            (
+                resolved_git(&url, Some("--orphan=x"), Some("skills/demo")),
+                "must not start with '-'",
+            ),
+            (
+                resolved_git(&url, None, Some("../outside")),
+                "must be relative",
+            ),
+            (resolved_git(&url, None, Some("/etc")), "must be relative"),
+        ];
+        if cfg!(unix) {
+            cases.push((resolved_git(&url, None, Some("escape")), "resolves outside"));
+        }
+
+        for (resolved, expected) in cases {
+            let err = install_resolved(codex_home.path(), resolved, &default_options())
+                .await
+                .expect_err("crafted source");
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+        assert!(!codex_home.path().join(SKILLS_DIR_NAME).exists());
+    }
+
+    #[tokio::test]
+    async fn update_detects_new_commits_and_local_modifications() {
+        let repo = skill_repo("Demo skill");
+        let codex_home = TempDir::new().expect("tempdir");
+        let installed = install_skill(codex_home.path(), &git_source(&repo), &default_options())
+            .await
+            .expect("install");
+
+        let statuses = check_skill_updates(codex_home.path(), None)
+            .await
+            .expect("check");
+        assert_eq!(
+            statuses,
+            vec![("demo".to_string(), SkillUpdateStatus::UpToDate)]
+        );
+
+        fs::write(installed.path.join("notes.md"), "local").expect("modify");
+        let statuses = check_skill_updates(codex_home.path(), Some("demo"))
+            .await
+            .expect("check");
+        assert_eq!(
+            statuses,
+            vec![("demo".to_string(), SkillUpdateStatus::LocallyModified)]
+        );
+        assert!(update_skills(codex_home.path(), None, false).await.is_err());
+
+        let updated = update_skills(codex_home.path(), None, true)
+            .await
+            .expect("forced update");
+        assert_eq!(updated.len(), 1);
+        assert!(!installed.path.join("notes.md").exists());
+    }
+}
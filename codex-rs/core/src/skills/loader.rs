@@ -105,7 +105,7 @@ const MAX_SCAN_DEPTH: usize = 6;
 const MAX_SKILLS_DIRS_PER_ROOT: usize = 2000;
 
 #[derive(Debug)]
-enum SkillParseError {
+pub(crate) enum SkillParseError {
     Read(std::io::Error),
     MissingFrontmatter,
     InvalidYaml(serde_yaml::Error),
@@ -236,7 +236,13 @@ fn skill_roots_from_layer_stack_inner(
 }
 
 fn skill_roots(config: &Config) -> Vec<SkillRoot> {
-    skill_roots_from_layer_stack_with_agents(&config.config_layer_stack, &config.cwd)
+    let mut roots =
+        skill_roots_from_layer_stack_with_agents(&config.config_layer_stack, &config.cwd);
+    // Untrusted projects contribute no skills of their own.
+    if config.active_project.is_untrusted() {
+        roots.retain(|root| root.scope != SkillScope::Repo);
+    }
+    roots
 }
 
 #[cfg(test)]
@@ -474,7 +480,10 @@ fn discover_skills_under_root(root: &Path, scope: SkillScope, outcome: &mut Skil
     }
 }
 
-fn parse_skill_file(path: &Path, scope: SkillScope) -> Result<SkillMetadata, SkillParseError> {
+pub(crate) fn parse_skill_file(
+    path: &Path,
+    scope: SkillScope,
+) -> Result<SkillMetadata, SkillParseError> {
     let contents = fs::read_to_string(path).map_err(SkillParseError::Read)?;
 
     let frontmatter = extract_frontmatter(&contents).ok_or(SkillParseError::MissingFrontmatter)?;
@@ -835,6 +844,8 @@ mod tests {
                     trust_root.to_string_lossy().to_string(),
                     ProjectConfig {
                         trust_level: Some(TrustLevel::Trusted),
+                        persona: None,
+                        profile: None,
                     },
                 )])),
                 ..Default::default()
@@ -50,6 +50,7 @@ impl SkillsManager {
             skill_roots_from_layer_stack_with_agents(&config.config_layer_stack, &config.cwd);
         let mut outcome = load_skills_from_roots(roots);
         outcome.disabled_paths = disabled_paths_from_stack(&config.config_layer_stack);
+        apply_skill_trust(&mut outcome, &config.config_layer_stack);
         let mut cache = match self.cache_by_cwd.write() {
             Ok(cache) => cache,
             Err(err) => err.into_inner(),
@@ -125,6 +126,7 @@ impl SkillsManager {
         );
         let mut outcome = load_skills_from_roots(roots);
         outcome.disabled_paths = disabled_paths_from_stack(&config_layer_stack);
+        apply_skill_trust(&mut outcome, &config_layer_stack);
         let mut cache = match self.cache_by_cwd.write() {
             Ok(cache) => cache,
             Err(err) => err.into_inner(),
@@ -151,25 +153,29 @@ impl SkillsManager {
     }
 }
 
+fn skills_config_from_stack(
+    config_layer_stack: &crate::config_loader::ConfigLayerStack,
+) -> Option<SkillsConfig> {
+    // Skills config is user-layer only for now; higher-precedence layers are ignored.
+    let user_layer = config_layer_stack.get_user_layer()?;
+    let skills_value = user_layer.config.get("skills")?;
+    match skills_value.clone().try_into() {
+        Ok(skills) => Some(skills),
+        Err(err) => {
+            warn!("invalid skills config: {err}");
+            None
+        }
+    }
+}
+
 fn disabled_paths_from_stack(
     config_layer_stack: &crate::config_loader::ConfigLayerStack,
 ) -> HashSet<PathBuf> {
     let mut disabled = HashSet::new();
     let mut configs = HashMap::new();
-    // Skills config is user-layer only for now; higher-precedence layers are ignored.
-    let Some(user_layer) = config_layer_stack.get_user_layer() else {
+    let Some(skills) = skills_config_from_stack(config_layer_stack) else {
         return disabled;
     };
-    let Some(skills_value) = user_layer.config.get("skills") else {
-        return disabled;
-    };
-    let skills: SkillsConfig = match skills_value.clone().try_into() {
-        Ok(skills) => skills,
-        Err(err) => {
-            warn!("invalid skills config: {err}");
-            return disabled;
-        }
-    };
 
     for entry in skills.config {
         let path = normalize_override_path(entry.path.as_path());
@@ -185,6 +191,40 @@ fn disabled_paths_from_stack(
     disabled
 }
 
+/// Returns the repository skill paths listed under `[skills].trusted` in the
+/// user config.
+pub fn trusted_skill_paths(
+    config_layer_stack: &crate::config_loader::ConfigLayerStack,
+) -> HashSet<PathBuf> {
+    skills_config_from_stack(config_layer_stack)
+        .map(|skills| {
+            skills
+                .trusted
+                .iter()
+                .map(|path| normalize_override_path(path.as_path()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Repo-scoped skills run instructions checked into the workspace, so they stay
+/// disabled until the user trusts them once.
+fn apply_skill_trust(
+    outcome: &mut SkillLoadOutcome,
+    config_layer_stack: &crate::config_loader::ConfigLayerStack,
+) {
+    let trusted = trusted_skill_paths(config_layer_stack);
+    outcome.untrusted_paths = outcome
+        .skills
+        .iter()
+        .filter(|skill| skill.scope == SkillScope::Repo && !trusted.contains(&skill.path))
+        .map(|skill| skill.path.clone())
+        .collect();
+    outcome
+        .disabled_paths
+        .extend(outcome.untrusted_paths.iter().cloned());
+}
+
 fn normalize_override_path(path: &Path) -> PathBuf {
     dunce::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
 }
@@ -376,6 +416,50 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn repo_skills_stay_disabled_until_trusted() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let repo = tempfile::tempdir().expect("tempdir");
+        fs::create_dir_all(repo.path().join(".git")).unwrap();
+        let skill_dir = repo.path().join(".agents").join("skills").join("deploy");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: deploy\ndescription: deploy the app\n---\n\n# Body\n",
+        )
+        .unwrap();
+
+        let skills_manager = SkillsManager::new(codex_home.path().to_path_buf());
+        let outcome = skills_manager.skills_for_cwd(repo.path(), true).await;
+        let skill = outcome
+            .skills
+            .iter()
+            .find(|skill| skill.name == "deploy")
+            .expect("repo skill loaded");
+        assert_eq!(skill.scope, SkillScope::Repo);
+        assert!(outcome.untrusted_paths.contains(&skill.path));
+        assert!(!outcome.is_skill_enabled(skill));
+
+        let skill_path = skill.path.clone();
+        fs::write(
+            codex_home.path().join(crate::config::CONFIG_TOML_FILE),
+            format!(
+                "[skills]\ntrusted = [{:?}]\n",
+                skill_path.display().to_string()
+            ),
+        )
+        .unwrap();
+
+        let outcome = skills_manager.skills_for_cwd(repo.path(), true).await;
+        let skill = outcome
+            .skills
+            .iter()
+            .find(|skill| skill.name == "deploy")
+            .expect("repo skill loaded");
+        assert!(outcome.untrusted_paths.is_empty());
+        assert!(outcome.is_skill_enabled(skill));
+    }
+
     #[test]
     fn normalize_extra_user_roots_is_stable_for_equivalent_inputs() {
         let a = PathBuf::from("/tmp/a");
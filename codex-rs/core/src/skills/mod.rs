@@ -1,10 +1,13 @@
 mod env_var_dependencies;
+pub mod harness;
 pub mod injection;
+pub mod install;
 pub mod loader;
 pub mod manager;
 pub mod model;
 pub mod remote;
 pub mod render;
+pub mod scaffold;
 pub mod system;
 
 pub(crate) use env_var_dependencies::collect_env_var_dependencies;
@@ -14,8 +17,12 @@ pub(crate) use injection::build_skill_injections;
 pub(crate) use injection::collect_explicit_skill_mentions;
 pub use loader::load_skills;
 pub use manager::SkillsManager;
+pub use manager::trusted_skill_paths;
 pub use model::SkillError;
 pub use model::SkillLoadOutcome;
 pub use model::SkillMetadata;
 pub use model::SkillPolicy;
 pub use render::render_skills_section;
+pub use scaffold::SkillScaffold;
+pub use scaffold::SkillScaffoldError;
+pub use scaffold::scaffold_skill;
@@ -65,6 +65,9 @@ pub struct SkillLoadOutcome {
     pub skills: Vec<SkillMetadata>,
     pub errors: Vec<SkillError>,
     pub disabled_paths: HashSet<PathBuf>,
+    /// Repo-scoped skills awaiting a trust confirmation. These are also
+    /// present in `disabled_paths`.
+    pub untrusted_paths: HashSet<PathBuf>,
 }
 
 impl SkillLoadOutcome {
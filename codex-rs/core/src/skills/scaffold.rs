@@ -0,0 +1,357 @@
+//! Generates new skill directories on disk.
+//!
+//! A scaffolded skill is a `SKILL.md` with YAML frontmatter plus an optional
+//! `agents/openai.yaml` carrying interface and tool dependency metadata. The
+//! generated files are parsed with the regular loader before returning so a
+//! successful scaffold is guaranteed to show up in the skill list.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::protocol::SkillScope;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::skills::loader::parse_skill_file;
+use crate::skills::model::SkillMetadata;
+use crate::skills::model::SkillToolDependency;
+
+const SKILLS_FILENAME: &str = "SKILL.md";
+const SKILLS_METADATA_DIR: &str = "agents";
+const SKILLS_METADATA_FILENAME: &str = "openai.yaml";
+const MAX_NAME_LEN: usize = 64;
+
+/// Inputs for [`scaffold_skill`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillScaffold {
+    pub name: String,
+    pub description: String,
+    pub short_description: Option<String>,
+    pub default_prompt: Option<String>,
+    pub tools: Vec<SkillToolDependency>,
+}
+
+#[derive(Debug, Error)]
+pub enum SkillScaffoldError {
+    #[error("invalid skill name `{name}`: {reason}")]
+    InvalidName { name: String, reason: &'static str },
+
+    #[error("skill directory already exists: {}", path.display())]
+    AlreadyExists { path: PathBuf },
+
+    #[error("failed to serialize skill metadata: {0}")]
+    Serialize(#[from] serde_yaml::Error),
+
+    #[error("io error while {action}: {source}")]
+    Io {
+        action: &'static str,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("generated skill failed validation: {message}")]
+    Invalid { message: String },
+}
+
+impl SkillScaffoldError {
+    fn io(action: &'static str, source: std::io::Error) -> Self {
+        Self::Io { action, source }
+    }
+}
+
+#[derive(Serialize)]
+struct Frontmatter<'a> {
+    name: &'a str,
+    description: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<FrontmatterMetadata<'a>>,
+}
+
+#[derive(Serialize)]
+struct FrontmatterMetadata<'a> {
+    #[serde(rename = "short-description")]
+    short_description: &'a str,
+}
+
+#[derive(Serialize)]
+struct MetadataFile<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interface: Option<MetadataInterface<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dependencies: Option<MetadataDependencies<'a>>,
+}
+
+#[derive(Serialize)]
+struct MetadataInterface<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_prompt: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct MetadataDependencies<'a> {
+    tools: Vec<MetadataTool<'a>>,
+}
+
+#[derive(Serialize)]
+struct MetadataTool<'a> {
+    #[serde(rename = "type")]
+    kind: &'a str,
+    value: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transport: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    command: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<&'a str>,
+}
+
+/// Validates that `name` is usable as both a skill name and a directory name.
+///
+/// Skill names are lowercase ASCII letters, digits, and single hyphens so they
+/// can be typed after `$` in the composer without quoting.
+pub fn validate_skill_name(name: &str) -> Result<(), SkillScaffoldError> {
+    let invalid = |reason| SkillScaffoldError::InvalidName {
+        name: name.to_string(),
+        reason,
+    };
+    if name.is_empty() {
+        return Err(invalid("name must not be empty"));
+    }
+    if name.len() > MAX_NAME_LEN {
+        return Err(invalid("name must be at most 64 characters"));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err(invalid(
+            "only lowercase letters, digits, and hyphens are allowed",
+        ));
+    }
+    if name.starts_with('-') || name.ends_with('-') || name.contains("--") {
+        return Err(invalid(
+            "hyphens must separate words (no leading, trailing, or repeated hyphens)",
+        ));
+    }
+    Ok(())
+}
+
+/// Creates `<skills_root>/<name>/` with a `SKILL.md` and, when interface or
+/// dependency metadata is provided, `agents/openai.yaml`.
+///
+/// The generated skill is parsed with the same loader used at runtime. If it
+/// does not load, the directory is removed and the parse error is returned.
+pub fn scaffold_skill(
+    skills_root: &Path,
+    scope: SkillScope,
+    scaffold: &SkillScaffold,
+) -> Result<SkillMetadata, SkillScaffoldError> {
+    validate_skill_name(&scaffold.name)?;
+
+    let skill_dir = skills_root.join(&scaffold.name);
+    if skill_dir.exists() {
+        return Err(SkillScaffoldError::AlreadyExists { path: skill_dir });
+    }
+
+    let skill_md = render_skill_md(scaffold)?;
+    let metadata = render_metadata_file(scaffold)?;
+
+    fs::create_dir_all(&skill_dir)
+        .map_err(|source| SkillScaffoldError::io("create skill dir", source))?;
+
+    let result = write_skill_files(&skill_dir, &skill_md, metadata.as_deref()).and_then(|()| {
+        parse_skill_file(&skill_dir.join(SKILLS_FILENAME), scope).map_err(|err| {
+            SkillScaffoldError::Invalid {
+                message: err.to_string(),
+            }
+        })
+    });
+
+    if result.is_err()
+        && let Err(err) = fs::remove_dir_all(&skill_dir)
+    {
+        tracing::warn!(
+            "failed to clean up skill dir {}: {err}",
+            skill_dir.display()
+        );
+    }
+    result
+}
+
+fn write_skill_files(
+    skill_dir: &Path,
+    skill_md: &str,
+    metadata: Option<&str>,
+) -> Result<(), SkillScaffoldError> {
+    fs::write(skill_dir.join(SKILLS_FILENAME), skill_md)
+        .map_err(|source| SkillScaffoldError::io("write SKILL.md", source))?;
+
+    if let Some(metadata) = metadata {
+        let metadata_dir = skill_dir.join(SKILLS_METADATA_DIR);
+        fs::create_dir_all(&metadata_dir)
+            .map_err(|source| SkillScaffoldError::io("create agents dir", source))?;
+        fs::write(metadata_dir.join(SKILLS_METADATA_FILENAME), metadata)
+            .map_err(|source| SkillScaffoldError::io("write openai.yaml", source))?;
+    }
+
+    Ok(())
+}
+
+fn render_skill_md(scaffold: &SkillScaffold) -> Result<String, SkillScaffoldError> {
+    let frontmatter = serde_yaml::to_string(&Frontmatter {
+        name: &scaffold.name,
+        description: &scaffold.description,
+        metadata: scaffold
+            .short_description
+            .as_deref()
+            .map(|short_description| FrontmatterMetadata { short_description }),
+    })?;
+    let name = &scaffold.name;
+    let description = &scaffold.description;
+
+    Ok(format!(
+        "---\n{frontmatter}---\n\n# {name}\n\n{description}\n\n## Instructions\n\n- Describe when this skill should be used.\n- List the steps Codex should follow.\n"
+    ))
+}
+
+fn render_metadata_file(scaffold: &SkillScaffold) -> Result<Option<String>, SkillScaffoldError> {
+    let interface = (scaffold.short_description.is_some() || scaffold.default_prompt.is_some())
+        .then(|| MetadataInterface {
+            short_description: scaffold.short_description.as_deref(),
+            default_prompt: scaffold.default_prompt.as_deref(),
+        });
+    let dependencies = (!scaffold.tools.is_empty()).then(|| MetadataDependencies {
+        tools: scaffold
+            .tools
+            .iter()
+            .map(|tool| MetadataTool {
+                kind: &tool.r#type,
+                value: &tool.value,
+                description: tool.description.as_deref(),
+                transport: tool.transport.as_deref(),
+                command: tool.command.as_deref(),
+                url: tool.url.as_deref(),
+            })
+            .collect(),
+    });
+
+    if interface.is_none() && dependencies.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(serde_yaml::to_string(&MetadataFile {
+        interface,
+        dependencies,
+    })?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skills::model::SkillDependencies;
+    use crate::skills::model::SkillInterface;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn scaffold(name: &str) -> SkillScaffold {
+        SkillScaffold {
+            name: name.to_string(),
+            description: "Summarize release notes: for a tag".to_string(),
+            short_description: None,
+            default_prompt: None,
+            tools: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn scaffolds_minimal_skill_that_loads() {
+        let root = TempDir::new().expect("tempdir");
+
+        let skill = scaffold_skill(root.path(), SkillScope::User, &scaffold("release-notes"))
+            .expect("scaffold");
+
+        assert_eq!(skill.name, "release-notes");
+        assert_eq!(skill.description, "Summarize release notes: for a tag");
+        assert_eq!(skill.interface, None);
+        assert_eq!(skill.dependencies, None);
+        assert!(
+            !root
+                .path()
+                .join("release-notes")
+                .join(SKILLS_METADATA_DIR)
+                .exists()
+        );
+    }
+
+    #[test]
+    fn scaffolds_interface_and_tool_dependencies() {
+        let root = TempDir::new().expect("tempdir");
+        let tool = SkillToolDependency {
+            r#type: "mcp".to_string(),
+            value: "github".to_string(),
+            description: Some("GitHub MCP server".to_string()),
+            transport: Some("streamable_http".to_string()),
+            command: None,
+            url: Some("https://example.com/mcp".to_string()),
+        };
+        let mut input = scaffold("triage");
+        input.short_description = Some("Triage issues".to_string());
+        input.default_prompt = Some("Triage the newest issues".to_string());
+        input.tools = vec![tool.clone()];
+
+        let skill = scaffold_skill(root.path(), SkillScope::User, &input).expect("scaffold");
+
+        assert_eq!(skill.short_description, Some("Triage issues".to_string()));
+        assert_eq!(
+            skill.interface,
+            Some(SkillInterface {
+                display_name: None,
+                short_description: Some("Triage issues".to_string()),
+                icon_small: None,
+                icon_large: None,
+                brand_color: None,
+                default_prompt: Some("Triage the newest issues".to_string()),
+            })
+        );
+        assert_eq!(
+            skill.dependencies,
+            Some(SkillDependencies { tools: vec![tool] })
+        );
+    }
+
+    #[test]
+    fn refuses_to_overwrite_existing_skill() {
+        let root = TempDir::new().expect("tempdir");
+        fs::create_dir_all(root.path().join("taken")).expect("mkdir");
+
+        let err = scaffold_skill(root.path(), SkillScope::User, &scaffold("taken"))
+            .expect_err("existing dir");
+
+        assert!(matches!(err, SkillScaffoldError::AlreadyExists { .. }));
+    }
+
+    #[test]
+    fn removes_directory_when_generated_skill_is_invalid() {
+        let root = TempDir::new().expect("tempdir");
+        let mut input = scaffold("too-long");
+        input.description = "x".repeat(2048);
+
+        let err = scaffold_skill(root.path(), SkillScope::User, &input).expect_err("invalid");
+
+        assert!(matches!(err, SkillScaffoldError::Invalid { .. }));
+        assert!(!root.path().join("too-long").exists());
+    }
+
+    #[test]
+    fn rejects_names_that_cannot_be_mentioned() {
+        for name in ["", "Upper", "has space", "-lead", "trail-", "dou--ble"] {
+            assert!(validate_skill_name(name).is_err(), "{name:?} should fail");
+        }
+        validate_skill_name("pdf-tools-2").expect("valid name");
+    }
+}
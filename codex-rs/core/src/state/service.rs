@@ -4,6 +4,7 @@ use crate::AuthManager;
 use crate::RolloutRecorder;
 use crate::agent::AgentControl;
 use crate::analytics_client::AnalyticsEventsClient;
+use crate::chat_notifications::ChatNotifier;
 use crate::client::ModelClient;
 use crate::config::StartedNetworkProxy;
 use crate::exec_policy::ExecPolicyManager;
@@ -12,8 +13,11 @@ use crate::mcp_connection_manager::McpConnectionManager;
 use crate::models_manager::manager::ModelsManager;
 use crate::skills::SkillsManager;
 use crate::state_db::StateDbHandle;
+use crate::tools::context::SharedTurnDiffTracker;
 use crate::tools::sandboxing::ApprovalStore;
 use crate::unified_exec::UnifiedExecProcessManager;
+use crate::usage_reporting::SessionUsageReporter;
+use crate::web_cache::WebCache;
 use codex_hooks::Hooks;
 use codex_otel::OtelManager;
 use tokio::sync::Mutex;
@@ -41,6 +45,16 @@ pub(crate) struct SessionServices {
     pub(crate) agent_control: AgentControl,
     pub(crate) network_proxy: Option<StartedNetworkProxy>,
     pub(crate) state_db: Option<StateDbHandle>,
+    /// Baselines for every file `apply_patch` touched this session, used by
+    /// the session changes view and per-file discard.
+    pub(crate) session_diff_tracker: SharedTurnDiffTracker,
+    /// Set when `[reporting]` is configured; receives the session's usage at shutdown.
+    pub(crate) usage_reporter: Option<SessionUsageReporter>,
+    /// Set when `[notifications]` configures a Slack or Discord webhook.
+    pub(crate) chat_notifier: Option<Arc<ChatNotifier>>,
+    /// Results of the local `web_search` and `fetch_url` tools, reused when
+    /// the model repeats a query or re-reads a page.
+    pub(crate) web_cache: Mutex<WebCache>,
     /// Session-scoped model client shared across turns.
     pub(crate) model_client: ModelClient,
 }
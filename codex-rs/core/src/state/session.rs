@@ -6,6 +6,7 @@ use std::collections::HashSet;
 
 use crate::codex::SessionConfiguration;
 use crate::context_manager::ContextManager;
+use crate::protocol::ContextComponentKind;
 use crate::protocol::RateLimitSnapshot;
 use crate::protocol::TokenUsage;
 use crate::protocol::TokenUsageInfo;
@@ -30,6 +31,13 @@ pub(crate) struct SessionState {
     /// Startup regular task pre-created during session initialization.
     pub(crate) startup_regular_task: Option<RegularTask>,
     pub(crate) active_mcp_tool_selection: Option<Vec<String>>,
+    /// Components `/context` asked to leave out of the next regular turn.
+    pub(crate) context_exclusions: Vec<ContextComponentKind>,
+    /// Whether `/dry-run` armed the next regular turn.
+    pub(crate) dry_run_next_turn: bool,
+    /// Approximate output tokens of the model response an interrupt cut off,
+    /// until the abort event reports them.
+    pub(crate) cut_off_stream_tokens: Option<i64>,
 }
 
 impl SessionState {
@@ -47,6 +55,9 @@ impl SessionState {
             pending_resume_previous_model: None,
             startup_regular_task: None,
             active_mcp_tool_selection: None,
+            context_exclusions: Vec::new(),
+            dry_run_next_turn: false,
+            cut_off_stream_tokens: None,
         }
     }
 
@@ -168,6 +179,39 @@ impl SessionState {
     pub(crate) fn clear_mcp_tool_selection(&mut self) {
         self.active_mcp_tool_selection = None;
     }
+
+    pub(crate) fn set_context_exclusions(&mut self, excluded: Vec<ContextComponentKind>) {
+        self.context_exclusions.clear();
+        for kind in excluded {
+            if kind.is_droppable() && !self.context_exclusions.contains(&kind) {
+                self.context_exclusions.push(kind);
+            }
+        }
+    }
+
+    pub(crate) fn context_exclusions(&self) -> Vec<ContextComponentKind> {
+        self.context_exclusions.clone()
+    }
+
+    pub(crate) fn take_context_exclusions(&mut self) -> Vec<ContextComponentKind> {
+        std::mem::take(&mut self.context_exclusions)
+    }
+
+    pub(crate) fn set_dry_run_next_turn(&mut self, enabled: bool) {
+        self.dry_run_next_turn = enabled;
+    }
+
+    pub(crate) fn take_dry_run_next_turn(&mut self) -> bool {
+        std::mem::take(&mut self.dry_run_next_turn)
+    }
+
+    pub(crate) fn set_cut_off_stream_tokens(&mut self, tokens: i64) {
+        self.cut_off_stream_tokens = Some(tokens);
+    }
+
+    pub(crate) fn take_cut_off_stream_tokens(&mut self) -> Option<i64> {
+        self.cut_off_stream_tokens.take()
+    }
 }
 
 // Sometimes new snapshots don't include credits or plan information.
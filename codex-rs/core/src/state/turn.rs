@@ -9,6 +9,7 @@ use tokio_util::sync::CancellationToken;
 use tokio_util::task::AbortOnDropHandle;
 
 use codex_protocol::dynamic_tools::DynamicToolResponse;
+use codex_protocol::items::WebCitation;
 use codex_protocol::models::ResponseInputItem;
 use codex_protocol::request_user_input::RequestUserInputResponse;
 use tokio::sync::oneshot;
@@ -72,7 +73,11 @@ pub(crate) struct TurnState {
     pending_approvals: HashMap<String, oneshot::Sender<ReviewDecision>>,
     pending_user_input: HashMap<String, oneshot::Sender<RequestUserInputResponse>>,
     pending_dynamic_tools: HashMap<String, oneshot::Sender<DynamicToolResponse>>,
+    /// Dry-run plans waiting for the user, answered with the approved call ids.
+    pending_dry_run: HashMap<String, oneshot::Sender<Vec<String>>>,
     pending_input: Vec<ResponseInputItem>,
+    /// Pages cited by web search and `fetch_url` this turn, one per URL.
+    sources: Vec<WebCitation>,
 }
 
 impl TurnState {
@@ -95,6 +100,7 @@ impl TurnState {
         self.pending_approvals.clear();
         self.pending_user_input.clear();
         self.pending_dynamic_tools.clear();
+        self.pending_dry_run.clear();
         self.pending_input.clear();
     }
 
@@ -128,6 +134,21 @@ impl TurnState {
         self.pending_dynamic_tools.remove(key)
     }
 
+    pub(crate) fn insert_pending_dry_run(
+        &mut self,
+        key: String,
+        tx: oneshot::Sender<Vec<String>>,
+    ) -> Option<oneshot::Sender<Vec<String>>> {
+        self.pending_dry_run.insert(key, tx)
+    }
+
+    pub(crate) fn remove_pending_dry_run(
+        &mut self,
+        key: &str,
+    ) -> Option<oneshot::Sender<Vec<String>>> {
+        self.pending_dry_run.remove(key)
+    }
+
     pub(crate) fn push_pending_input(&mut self, input: ResponseInputItem) {
         self.pending_input.push(input);
     }
@@ -145,6 +166,32 @@ impl TurnState {
     pub(crate) fn has_pending_input(&self) -> bool {
         !self.pending_input.is_empty()
     }
+
+    /// Adds citations to the turn's source list. A URL already listed keeps
+    /// its position; a later citation only fills in a missing title or snippet.
+    pub(crate) fn record_sources(&mut self, citations: &[WebCitation]) {
+        for citation in citations {
+            match self
+                .sources
+                .iter_mut()
+                .find(|source| source.url == citation.url)
+            {
+                Some(source) => {
+                    if source.title.is_none() {
+                        source.title.clone_from(&citation.title);
+                    }
+                    if source.snippet.is_none() {
+                        source.snippet.clone_from(&citation.snippet);
+                    }
+                }
+                None => self.sources.push(citation.clone()),
+            }
+        }
+    }
+
+    pub(crate) fn take_sources(&mut self) -> Vec<WebCitation> {
+        std::mem::take(&mut self.sources)
+    }
 }
 
 impl ActiveTurn {
@@ -154,3 +201,36 @@ impl ActiveTurn {
         ts.clear_pending();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn citation(url: &str, title: Option<&str>) -> WebCitation {
+        WebCitation {
+            url: url.to_string(),
+            title: title.map(str::to_string),
+            snippet: None,
+        }
+    }
+
+    #[test]
+    fn record_sources_keeps_one_entry_per_url() {
+        let mut state = TurnState::default();
+        state.record_sources(&[
+            citation("https://tokio.rs", None),
+            citation("https://docs.rs", Some("Docs.rs")),
+        ]);
+        state.record_sources(&[citation("https://tokio.rs", Some("Tokio"))]);
+
+        assert_eq!(
+            state.take_sources(),
+            vec![
+                citation("https://tokio.rs", Some("Tokio")),
+                citation("https://docs.rs", Some("Docs.rs")),
+            ]
+        );
+        assert_eq!(state.take_sources(), Vec::new());
+    }
+}
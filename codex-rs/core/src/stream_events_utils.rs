@@ -7,12 +7,14 @@ use tokio_util::sync::CancellationToken;
 
 use crate::codex::Session;
 use crate::codex::TurnContext;
+use crate::dry_run;
 use crate::error::CodexErr;
 use crate::error::Result;
 use crate::function_tool::FunctionCallError;
 use crate::parse_turn_item;
 use crate::proposed_plan_parser::strip_proposed_plan_blocks;
 use crate::tools::parallel::ToolCallRuntime;
+use crate::tools::router::ToolCall;
 use crate::tools::router::ToolRouter;
 use codex_protocol::models::FunctionCallOutputBody;
 use codex_protocol::models::FunctionCallOutputPayload;
@@ -33,6 +35,8 @@ pub(crate) struct OutputItemResult {
     pub last_agent_message: Option<String>,
     pub needs_follow_up: bool,
     pub tool_future: Option<InFlightFuture<'static>>,
+    /// A call the dry run is holding until the user decides on it.
+    pub held_call: Option<ToolCall>,
 }
 
 pub(crate) struct HandleOutputCtx {
@@ -40,6 +44,8 @@ pub(crate) struct HandleOutputCtx {
     pub turn_context: Arc<TurnContext>,
     pub tool_runtime: ToolCallRuntime,
     pub cancellation_token: CancellationToken,
+    /// Hold tool calls back instead of running them (`/dry-run`).
+    pub dry_run: bool,
 }
 
 #[instrument(level = "trace", skip_all)]
@@ -66,6 +72,12 @@ pub(crate) async fn handle_output_item_done(
                 .record_conversation_items(&ctx.turn_context, std::slice::from_ref(&item))
                 .await;
 
+            output.needs_follow_up = true;
+            if ctx.dry_run && dry_run::is_gated(&call) {
+                output.held_call = Some(call);
+                return Ok(output);
+            }
+
             let cancellation_token = ctx.cancellation_token.child_token();
             let tool_future: InFlightFuture<'static> = Box::pin(
                 ctx.tool_runtime
@@ -73,7 +85,6 @@ pub(crate) async fn handle_output_item_done(
                     .handle_tool_call(call, cancellation_token),
             );
 
-            output.needs_follow_up = true;
             output.tool_future = Some(tool_future);
         }
         // No tool call: convert messages/reasoning into turn items and mark them as complete.
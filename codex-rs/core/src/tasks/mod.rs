@@ -27,6 +27,7 @@ use crate::protocol::EventMsg;
 use crate::protocol::TurnAbortReason;
 use crate::protocol::TurnAbortedEvent;
 use crate::protocol::TurnCompleteEvent;
+use crate::protocol::TurnSourcesEvent;
 use crate::session_prefix::TURN_ABORTED_OPEN_TAG;
 use crate::state::ActiveTurn;
 use crate::state::RunningTask;
@@ -196,12 +197,14 @@ impl Session {
     ) {
         let mut active = self.active_turn.lock().await;
         let mut pending_input = Vec::<ResponseInputItem>::new();
+        let mut sources = Vec::new();
         let mut should_clear_active_turn = false;
         if let Some(at) = active.as_mut()
             && at.remove_task(&turn_context.sub_id)
         {
             let mut ts = at.turn_state.lock().await;
             pending_input = ts.take_pending_input();
+            sources = ts.take_sources();
             should_clear_active_turn = true;
         }
         if should_clear_active_turn {
@@ -216,6 +219,10 @@ impl Session {
             self.record_conversation_items(turn_context.as_ref(), &pending_response_items)
                 .await;
         }
+        if !sources.is_empty() {
+            let event = EventMsg::TurnSources(TurnSourcesEvent { citations: sources });
+            self.send_event(turn_context.as_ref(), event).await;
+        }
         let event = EventMsg::TurnComplete(TurnCompleteEvent { last_agent_message });
         self.send_event(turn_context.as_ref(), event).await;
     }
@@ -292,7 +299,11 @@ impl Session {
             self.flush_rollout().await;
         }
 
-        let event = EventMsg::TurnAborted(TurnAbortedEvent { reason });
+        let cut_off_output_tokens = self.take_cut_off_stream_tokens().await;
+        let event = EventMsg::TurnAborted(TurnAbortedEvent {
+            reason,
+            cut_off_output_tokens,
+        });
         self.send_event(task.turn_context.as_ref(), event).await;
     }
 }
@@ -100,14 +100,18 @@ impl SessionTask for RegularTask {
             .otel_manager
             .apply_traceparent_parent(&run_turn_span);
         let prewarmed_client_session = self.take_prewarmed_session().await;
-        run_turn(
-            sess,
-            ctx,
-            input,
+        let last_agent_message = run_turn(
+            Arc::clone(&sess),
+            Arc::clone(&ctx),
+            input.clone(),
             prewarmed_client_session,
-            cancellation_token,
+            cancellation_token.clone(),
         )
         .instrument(run_turn_span)
-        .await
+        .await;
+        if !cancellation_token.is_cancelled() {
+            sess.maybe_auto_title(&ctx, &input).await;
+        }
+        last_agent_message
     }
 }
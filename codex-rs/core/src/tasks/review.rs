@@ -21,18 +21,23 @@ use crate::config::Constrained;
 use crate::i18n::tr;
 use crate::review_format::format_review_findings_block;
 use crate::review_format::render_review_output_text;
+use crate::review_rubric::ReviewRubric;
 use crate::state::TaskKind;
 use codex_protocol::user_input::UserInput;
 
 use super::SessionTask;
 use super::SessionTaskContext;
 
-#[derive(Clone, Copy)]
-pub(crate) struct ReviewTask;
+#[derive(Clone)]
+pub(crate) struct ReviewTask {
+    rubric: Option<Arc<ReviewRubric>>,
+}
 
 impl ReviewTask {
-    pub(crate) fn new() -> Self {
-        Self
+    pub(crate) fn new(rubric: Option<ReviewRubric>) -> Self {
+        Self {
+            rubric: rubric.map(Arc::new),
+        }
     }
 }
 
@@ -66,7 +71,13 @@ impl SessionTask for ReviewTask {
         {
             Some(receiver) => process_review_events(session.clone(), ctx.clone(), receiver).await,
             None => None,
-        };
+        }
+        .map(|mut output| {
+            if let Some(rubric) = self.rubric.as_deref() {
+                rubric.apply(&mut output);
+            }
+            output
+        });
         if !cancellation_token.is_cancelled() {
             exit_review_mode(session.clone_session(), output.clone(), ctx.clone()).await;
         }
@@ -24,6 +24,8 @@ use crate::protocol::ErrorEvent;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecCommandBeginEvent;
 use crate::protocol::ExecCommandEndEvent;
+use crate::protocol::ExecCommandOrigin;
+use crate::protocol::ExecCommandProvenance;
 use crate::protocol::ExecCommandSource;
 use crate::protocol::SandboxPolicy;
 use crate::protocol::SddGitAction;
@@ -369,6 +371,7 @@ async fn run_git_logged(
         expiration: SDD_GIT_TIMEOUT_MS.into(),
         sandbox: SandboxType::None,
         windows_sandbox_level: turn_context.windows_sandbox_level,
+        windows_conpty: false,
         sandbox_permissions: crate::sandboxing::SandboxPermissions::UseDefault,
         justification: None,
         arg0: None,
@@ -398,7 +401,9 @@ async fn run_git_logged(
                         aggregated_output: aborted_message.clone(),
                         exit_code: -1,
                         duration: Duration::ZERO,
+                        timed_out: false,
                         formatted_output: aborted_message,
+                        provenance: Some(ExecCommandProvenance::new(ExecCommandOrigin::SddGit)),
                     }),
                 )
                 .await;
@@ -422,10 +427,12 @@ async fn run_git_logged(
                         aggregated_output: output.aggregated_output.text.clone(),
                         exit_code: output.exit_code,
                         duration: output.duration,
+                        timed_out: output.timed_out,
                         formatted_output: format_exec_output_str(
                             &output,
                             turn_context.truncation_policy,
                         ),
+                        provenance: Some(ExecCommandProvenance::new(ExecCommandOrigin::SddGit)),
                     }),
                 )
                 .await;
@@ -472,7 +479,9 @@ async fn run_git_logged(
                         aggregated_output: exec_output.aggregated_output.text.clone(),
                         exit_code: exec_output.exit_code,
                         duration: exec_output.duration,
+                        timed_out: exec_output.timed_out,
                         formatted_output: exec_output.aggregated_output.text.clone(),
+                        provenance: Some(ExecCommandProvenance::new(ExecCommandOrigin::SddGit)),
                     }),
                 )
                 .await;
@@ -16,10 +16,13 @@ use crate::exec::StdoutStream;
 use crate::exec::StreamOutput;
 use crate::exec::execute_exec_env;
 use crate::exec_env::create_env;
+use crate::features::Feature;
 use crate::parse_command::parse_command;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecCommandBeginEvent;
 use crate::protocol::ExecCommandEndEvent;
+use crate::protocol::ExecCommandOrigin;
+use crate::protocol::ExecCommandProvenance;
 use crate::protocol::ExecCommandSource;
 use crate::protocol::SandboxPolicy;
 use crate::protocol::TurnStartedEvent;
@@ -28,6 +31,7 @@ use crate::sandboxing::SandboxPermissions;
 use crate::state::TaskKind;
 use crate::tools::format_exec_output_str;
 use crate::tools::runtimes::maybe_wrap_shell_lc_with_snapshot;
+use crate::tools::runtimes::source_rc_files;
 use crate::user_shell_command::user_shell_command_record_item;
 
 use super::SessionTask;
@@ -109,12 +113,12 @@ pub(crate) async fn execute_user_shell_command(
 
     // Execute the user's script under their default shell when known; this
     // allows commands that use shell features (pipes, &&, redirects, etc.).
-    // We do not source rc files or otherwise reformat the script.
-    let use_login_shell = true;
+    // Only the rc files listed in `shell.rc_files` are sourced.
+    let use_login_shell = turn_context.config.shell.login;
     let session_shell = session.user_shell();
     let display_command = session_shell.derive_exec_args(&command, use_login_shell);
     let exec_command = maybe_wrap_shell_lc_with_snapshot(
-        &display_command,
+        &source_rc_files(&display_command, &turn_context.config.shell.rc_files),
         session_shell.as_ref(),
         turn_context.cwd.as_path(),
     );
@@ -153,6 +157,7 @@ pub(crate) async fn execute_user_shell_command(
         expiration: USER_SHELL_TIMEOUT_MS.into(),
         sandbox: SandboxType::None,
         windows_sandbox_level: turn_context.windows_sandbox_level,
+        windows_conpty: turn_context.features.enabled(Feature::WindowsConptyExec),
         sandbox_permissions: SandboxPermissions::UseDefault,
         justification: None,
         arg0: None,
@@ -205,7 +210,9 @@ pub(crate) async fn execute_user_shell_command(
                         aggregated_output: aborted_message.clone(),
                         exit_code: -1,
                         duration: Duration::ZERO,
+                        timed_out: false,
                         formatted_output: aborted_message,
+                        provenance: Some(ExecCommandProvenance::new(ExecCommandOrigin::UserShell)),
                     }),
                 )
                 .await;
@@ -228,10 +235,12 @@ pub(crate) async fn execute_user_shell_command(
                         aggregated_output: output.aggregated_output.text.clone(),
                         exit_code: output.exit_code,
                         duration: output.duration,
+                        timed_out: output.timed_out,
                         formatted_output: format_exec_output_str(
                             &output,
                             turn_context.truncation_policy,
                         ),
+                        provenance: Some(ExecCommandProvenance::new(ExecCommandOrigin::UserShell)),
                     }),
                 )
                 .await;
@@ -267,10 +276,12 @@ pub(crate) async fn execute_user_shell_command(
                         aggregated_output: exec_output.aggregated_output.text.clone(),
                         exit_code: exec_output.exit_code,
                         duration: exec_output.duration,
+                        timed_out: exec_output.timed_out,
                         formatted_output: format_exec_output_str(
                             &exec_output,
                             turn_context.truncation_policy,
                         ),
+                        provenance: Some(ExecCommandProvenance::new(ExecCommandOrigin::UserShell)),
                     }),
                 )
                 .await;
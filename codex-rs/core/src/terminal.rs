@@ -67,6 +67,29 @@ pub enum Multiplexer {
     Zellij {},
 }
 
+/// Rendering capabilities inferred from the terminal and locale.
+///
+/// The TUI uses these to pick RGB colors and to swap box-drawing characters,
+/// bullets, and emoji for ASCII on terminals that would misalign them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TerminalCapabilities {
+    /// 24-bit RGB colors render faithfully.
+    pub truecolor: bool,
+    /// Box-drawing characters, bullets, and arrows render as single cells.
+    pub unicode: bool,
+    /// East Asian ambiguous-width characters (`─`, `•`, `…`) take two columns.
+    pub ambiguous_wide: bool,
+    /// Emoji render as double-width glyphs.
+    pub emoji: bool,
+}
+
+impl TerminalCapabilities {
+    /// Whether non-ASCII symbols should be replaced before drawing.
+    pub fn ascii_glyphs(&self) -> bool {
+        !self.unicode || self.ambiguous_wide
+    }
+}
+
 /// tmux client terminal identification captured via `tmux display-message`.
 ///
 /// `termtype` corresponds to `#{client_termtype}` and typically reflects the
@@ -183,6 +206,8 @@ impl TerminalInfo {
 }
 
 static TERMINAL_INFO: OnceLock<TerminalInfo> = OnceLock::new();
+static NO_COLOR: OnceLock<bool> = OnceLock::new();
+static TERMINAL_CAPABILITIES: OnceLock<TerminalCapabilities> = OnceLock::new();
 
 /// Environment variable access used by terminal detection.
 ///
@@ -242,6 +267,26 @@ pub fn terminal_info() -> TerminalInfo {
         .clone()
 }
 
+/// Returns the rendering capabilities of the current terminal.
+pub fn terminal_capabilities() -> TerminalCapabilities {
+    *TERMINAL_CAPABILITIES.get_or_init(|| {
+        detect_capabilities_from_env(&ProcessEnvironment, &terminal_info(), cfg!(windows))
+    })
+}
+
+/// Returns whether the user opted out of colored output via `NO_COLOR`.
+///
+/// Follows <https://no-color.org>: any non-empty value disables color. The TUI
+/// renderer, exit messages, and `codex exec` all consult this so output is
+/// either colored everywhere or nowhere.
+pub fn no_color_requested() -> bool {
+    *NO_COLOR.get_or_init(|| detect_no_color(&ProcessEnvironment))
+}
+
+fn detect_no_color(env: &dyn Environment) -> bool {
+    env.var("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
 /// Detects structured terminal metadata from an injectable environment.
 ///
 /// Detection order favors explicit identifiers before falling back to capability strings:
@@ -328,6 +373,71 @@ fn detect_terminal_info_from_env(env: &dyn Environment) -> TerminalInfo {
     TerminalInfo::unknown(multiplexer)
 }
 
+/// Infers rendering capabilities from the detected terminal and the environment.
+///
+/// - `COLORTERM=truecolor|24bit` always enables RGB; otherwise terminals known to
+///   support it do, except inside a multiplexer whose configuration we cannot see.
+/// - The Linux virtual console (`TERM=linux`), VT-style `TERM` values, `TERM=dumb`,
+///   and non-UTF-8 locales get ASCII glyphs and no emoji.
+/// - `VTE_CJK_WIDTH=wide` marks ambiguous-width characters as double-width.
+/// - The legacy Windows console (no Windows Terminal, no `TERM`) keeps Unicode
+///   but drops emoji, which it draws at the wrong width.
+fn detect_capabilities_from_env(
+    env: &dyn Environment,
+    info: &TerminalInfo,
+    is_windows: bool,
+) -> TerminalCapabilities {
+    let term = env.var_non_empty("TERM").unwrap_or_default();
+    let limited_console =
+        info.name == TerminalName::Dumb || term == "linux" || term.starts_with("vt");
+    let legacy_windows_console = is_windows
+        && info.name == TerminalName::Unknown
+        && term.is_empty()
+        && !env.has("WT_SESSION");
+
+    let truecolor = match env.var_non_empty("COLORTERM").as_deref() {
+        Some("truecolor" | "24bit") => true,
+        _ if limited_console || info.multiplexer.is_some() => false,
+        _ => matches!(
+            info.name,
+            TerminalName::Ghostty
+                | TerminalName::Iterm2
+                | TerminalName::WarpTerminal
+                | TerminalName::VsCode
+                | TerminalName::WezTerm
+                | TerminalName::Kitty
+                | TerminalName::Alacritty
+                | TerminalName::Konsole
+                | TerminalName::GnomeTerminal
+                | TerminalName::WindowsTerminal
+        ),
+    };
+    let unicode = !limited_console && (is_windows || locale_is_utf8(env));
+    let ambiguous_wide = env
+        .var_non_empty("VTE_CJK_WIDTH")
+        .is_some_and(|value| matches!(value.trim(), "1" | "wide"));
+
+    TerminalCapabilities {
+        truecolor,
+        unicode,
+        ambiguous_wide,
+        emoji: unicode && !legacy_windows_console,
+    }
+}
+
+/// The first of `LC_ALL`, `LC_CTYPE`, and `LANG` that is set decides, as in
+/// POSIX. With none set we assume UTF-8, which is what nearly every desktop
+/// terminal uses.
+fn locale_is_utf8(env: &dyn Environment) -> bool {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .find_map(|name| env.var_non_empty(name))
+        .is_none_or(|locale| {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
+
 fn detect_multiplexer(env: &dyn Environment) -> Option<Multiplexer> {
     if env.has_non_empty("TMUX") || env.has_non_empty("TMUX_PANE") {
         return Some(Multiplexer::Tmux {
@@ -518,6 +628,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn no_color_requires_non_empty_value() {
+        assert!(!detect_no_color(&FakeEnvironment::new()));
+        assert!(!detect_no_color(
+            &FakeEnvironment::new().with_var("NO_COLOR", "")
+        ));
+        assert!(detect_no_color(
+            &FakeEnvironment::new().with_var("NO_COLOR", "1")
+        ));
+    }
+
     #[test]
     fn detects_term_program() {
         let env = FakeEnvironment::new()
@@ -1124,6 +1245,72 @@ mod tests {
         );
     }
 
+    fn capabilities(env: &FakeEnvironment, is_windows: bool) -> TerminalCapabilities {
+        detect_capabilities_from_env(env, &detect_terminal_info_from_env(env), is_windows)
+    }
+
+    #[test]
+    fn detects_truecolor_from_colorterm_or_known_terminals() {
+        let env = FakeEnvironment::new()
+            .with_var("TERM", "xterm-256color")
+            .with_var("COLORTERM", "truecolor");
+        assert!(capabilities(&env, false).truecolor);
+
+        let env = FakeEnvironment::new().with_var("TERM_PROGRAM", "WezTerm");
+        assert!(capabilities(&env, false).truecolor);
+
+        let env = FakeEnvironment::new().with_var("TERM_PROGRAM", "Apple_Terminal");
+        assert!(!capabilities(&env, false).truecolor);
+
+        let env = FakeEnvironment::new()
+            .with_var("KITTY_WINDOW_ID", "1")
+            .with_var("TMUX", "/tmp/tmux-1000/default,1,0");
+        assert!(!capabilities(&env, false).truecolor);
+    }
+
+    #[test]
+    fn limited_consoles_and_locales_fall_back_to_ascii() {
+        let env = FakeEnvironment::new()
+            .with_var("TERM", "xterm-256color")
+            .with_var("LANG", "en_US.UTF-8");
+        assert_eq!(
+            capabilities(&env, false),
+            TerminalCapabilities {
+                truecolor: false,
+                unicode: true,
+                ambiguous_wide: false,
+                emoji: true,
+            }
+        );
+
+        let env = FakeEnvironment::new()
+            .with_var("TERM", "linux")
+            .with_var("LANG", "en_US.UTF-8");
+        assert!(capabilities(&env, false).ascii_glyphs());
+
+        let env = FakeEnvironment::new()
+            .with_var("TERM", "xterm")
+            .with_var("LC_ALL", "C")
+            .with_var("LANG", "en_US.UTF-8");
+        assert!(capabilities(&env, false).ascii_glyphs());
+
+        let env = FakeEnvironment::new()
+            .with_var("VTE_VERSION", "7600")
+            .with_var("VTE_CJK_WIDTH", "wide");
+        let caps = capabilities(&env, false);
+        assert!(caps.unicode && caps.ambiguous_wide && caps.ascii_glyphs());
+    }
+
+    #[test]
+    fn legacy_windows_console_drops_emoji_only() {
+        let caps = capabilities(&FakeEnvironment::new(), true);
+        assert!(caps.unicode && !caps.emoji);
+
+        let env = FakeEnvironment::new().with_var("WT_SESSION", "1");
+        let caps = capabilities(&env, true);
+        assert!(caps.unicode && caps.emoji && caps.truecolor);
+    }
+
     #[test]
     fn detects_term_fallbacks() {
         let env = FakeEnvironment::new().with_var("TERM", "xterm-256color");
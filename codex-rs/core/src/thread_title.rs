@@ -0,0 +1,96 @@
+//! Automatic thread titles (`auto_title`).
+//!
+//! When the first turn of an unnamed thread completes, the thread is named
+//! after its opening prompt so the resume/fork pickers and the exit resume
+//! hint show something recognizable instead of a UUID. The title is derived
+//! locally from the prompt text; no extra model request is made.
+
+use codex_protocol::user_input::UserInput;
+
+const MAX_TITLE_CHARS: usize = 60;
+const TRAILING_PUNCTUATION: &[char] = &[
+    '.', ',', ':', ';', '!', '?', '。', '，', '：', '；', '！', '？',
+];
+
+/// Title for a thread whose first prompt was `input`, or `None` when the
+/// prompt has no text (for example an image on its own).
+pub(crate) fn title_from_input(input: &[UserInput]) -> Option<String> {
+    let text = input
+        .iter()
+        .filter_map(|item| match item {
+            UserInput::Text { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    title_from_prompt(&text)
+}
+
+/// First non-empty line of `prompt` without markdown markers, cut at a word
+/// boundary to at most [`MAX_TITLE_CHARS`] characters.
+fn title_from_prompt(prompt: &str) -> Option<String> {
+    let line = prompt
+        .lines()
+        .map(|line| line.trim_start_matches(['#', '>', '-', '*']).trim())
+        .find(|line| !line.is_empty())?;
+
+    let mut title = String::new();
+    let mut title_chars = 0;
+    for word in line.split_whitespace() {
+        let word_chars = word.chars().count();
+        let separator = usize::from(title_chars > 0);
+        if title_chars + separator + word_chars > MAX_TITLE_CHARS {
+            if title.is_empty() {
+                title = word.chars().take(MAX_TITLE_CHARS).collect();
+            }
+            title.push('…');
+            return Some(title);
+        }
+        if separator == 1 {
+            title.push(' ');
+        }
+        title.push_str(word);
+        title_chars += separator + word_chars;
+    }
+
+    let title = title.trim_end_matches(TRAILING_PUNCTUATION);
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn title_uses_first_line_without_markdown() {
+        assert_eq!(
+            title_from_prompt("\n## Fix the flaky login test.\n\nIt fails on CI."),
+            Some("Fix the flaky login test".to_string())
+        );
+        assert_eq!(title_from_prompt("   \n"), None);
+    }
+
+    #[test]
+    fn long_titles_are_cut_at_a_word_boundary() {
+        let prompt = "Refactor the configuration loader so that profile overrides are applied before project layers";
+        assert_eq!(
+            title_from_prompt(prompt),
+            Some("Refactor the configuration loader so that profile overrides…".to_string())
+        );
+
+        let cjk = "修".repeat(80);
+        assert_eq!(
+            title_from_prompt(&cjk),
+            Some(format!("{}…", "修".repeat(MAX_TITLE_CHARS)))
+        );
+    }
+
+    #[test]
+    fn image_only_input_has_no_title() {
+        let input = vec![UserInput::Image {
+            image_url: "data:image/png;base64,AAAA".to_string(),
+        }];
+        assert_eq!(title_from_input(&input), None);
+    }
+}
@@ -8,6 +8,7 @@ use crate::parse_command::parse_command;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecCommandBeginEvent;
 use crate::protocol::ExecCommandEndEvent;
+use crate::protocol::ExecCommandProvenance;
 use crate::protocol::ExecCommandSource;
 use crate::protocol::FileChange;
 use crate::protocol::PatchApplyBeginEvent;
@@ -20,9 +21,14 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
 use std::time::Duration;
+use tracing::warn;
 
 use super::format_exec_output_str;
 
+/// Directory under `CODEX_HOME` holding the complete output of commands whose
+/// output was summarized for the model, one subdirectory per thread.
+const TOOL_OUTPUT_DIR: &str = "tool_output";
+
 #[derive(Clone, Copy)]
 pub(crate) struct ToolEventCtx<'a> {
     pub session: &'a Session,
@@ -91,6 +97,7 @@ pub(crate) enum ToolEmitter {
         source: ExecCommandSource,
         parsed_cmd: Vec<ParsedCommand>,
         freeform: bool,
+        provenance: Option<ExecCommandProvenance>,
     },
     ApplyPatch {
         changes: HashMap<PathBuf, FileChange>,
@@ -102,6 +109,7 @@ pub(crate) enum ToolEmitter {
         source: ExecCommandSource,
         parsed_cmd: Vec<ParsedCommand>,
         process_id: Option<String>,
+        provenance: Option<ExecCommandProvenance>,
     },
 }
 
@@ -119,6 +127,7 @@ impl ToolEmitter {
             source,
             parsed_cmd,
             freeform,
+            provenance: None,
         }
     }
 
@@ -142,7 +151,20 @@ impl ToolEmitter {
             source,
             parsed_cmd,
             process_id,
+            provenance: None,
+        }
+    }
+
+    /// Attaches provenance to the end event; it is only known once approval
+    /// has run, after the begin event went out.
+    pub fn with_provenance(mut self, new_provenance: ExecCommandProvenance) -> Self {
+        match &mut self {
+            Self::Shell { provenance, .. } | Self::UnifiedExec { provenance, .. } => {
+                *provenance = Some(new_provenance);
+            }
+            Self::ApplyPatch { .. } => {}
         }
+        self
     }
 
     pub async fn emit(&self, ctx: ToolEventCtx<'_>, stage: ToolEventStage) {
@@ -153,13 +175,22 @@ impl ToolEmitter {
                     cwd,
                     source,
                     parsed_cmd,
+                    provenance,
                     ..
                 },
                 stage,
             ) => {
                 emit_exec_stage(
                     ctx,
-                    ExecCommandInput::new(command, cwd.as_path(), parsed_cmd, *source, None, None),
+                    ExecCommandInput::new(
+                        command,
+                        cwd.as_path(),
+                        parsed_cmd,
+                        *source,
+                        None,
+                        None,
+                        provenance.as_ref(),
+                    ),
                     stage,
                 )
                 .await;
@@ -176,6 +207,12 @@ impl ToolEmitter {
                     let mut guard = tracker.lock().await;
                     guard.on_patch_begin(changes);
                 }
+                ctx.session
+                    .services
+                    .session_diff_tracker
+                    .lock()
+                    .await
+                    .on_patch_begin(changes);
                 ctx.session
                     .send_event(
                         ctx.turn,
@@ -231,6 +268,7 @@ impl ToolEmitter {
                     source,
                     parsed_cmd,
                     process_id,
+                    provenance,
                 },
                 stage,
             ) => {
@@ -243,6 +281,7 @@ impl ToolEmitter {
                         *source,
                         None,
                         process_id.as_deref(),
+                        provenance.as_ref(),
                     ),
                     stage,
                 )
@@ -255,16 +294,24 @@ impl ToolEmitter {
         self.emit(ctx, ToolEventStage::Begin).await;
     }
 
-    fn format_exec_output_for_model(
+    async fn format_exec_output_for_model(
         &self,
         output: &ExecToolCallOutput,
         ctx: ToolEventCtx<'_>,
     ) -> String {
+        let full_output = retain_full_output(output, ctx).await;
+        let full_output = full_output.as_deref();
         match self {
-            Self::Shell { freeform: true, .. } => {
-                super::format_exec_output_for_model_freeform(output, ctx.turn.truncation_policy)
-            }
-            _ => super::format_exec_output_for_model_structured(output, ctx.turn.truncation_policy),
+            Self::Shell { freeform: true, .. } => super::format_exec_output_for_model_freeform(
+                output,
+                ctx.turn.truncation_policy,
+                full_output,
+            ),
+            _ => super::format_exec_output_for_model_structured(
+                output,
+                ctx.turn.truncation_policy,
+                full_output,
+            ),
         }
     }
 
@@ -275,7 +322,7 @@ impl ToolEmitter {
     ) -> Result<String, FunctionCallError> {
         let (event, result) = match out {
             Ok(output) => {
-                let content = self.format_exec_output_for_model(&output, ctx);
+                let content = self.format_exec_output_for_model(&output, ctx).await;
                 let exit_code = output.exit_code;
                 let event = ToolEventStage::Success(output);
                 let result = if exit_code == 0 {
@@ -287,7 +334,7 @@ impl ToolEmitter {
             }
             Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Timeout { output })))
             | Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Denied { output }))) => {
-                let response = self.format_exec_output_for_model(&output, ctx);
+                let response = self.format_exec_output_for_model(&output, ctx).await;
                 let event = ToolEventStage::Failure(ToolEventFailure::Output(*output));
                 let result = Err(FunctionCallError::RespondToModel(response));
                 (event, result)
@@ -321,6 +368,46 @@ impl ToolEmitter {
     }
 }
 
+/// Saves the complete output of a command whose output the model only sees
+/// summarized, so the user (or the model, with `grep`) can still read all of
+/// it. Returns the file's path, or `None` when nothing needed saving or the
+/// write failed.
+async fn retain_full_output(output: &ExecToolCallOutput, ctx: ToolEventCtx<'_>) -> Option<PathBuf> {
+    if !super::exceeds_model_budget(output, ctx.turn.truncation_policy) {
+        return None;
+    }
+    let dir = ctx
+        .turn
+        .config
+        .codex_home
+        .join(TOOL_OUTPUT_DIR)
+        .join(ctx.session.conversation_id.to_string());
+    let file_name: String = ctx
+        .call_id
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let path = dir.join(format!("{file_name}.log"));
+    let result = async {
+        tokio::fs::create_dir_all(&dir).await?;
+        tokio::fs::write(&path, &output.aggregated_output.text).await
+    }
+    .await;
+    match result {
+        Ok(()) => Some(path),
+        Err(err) => {
+            warn!("failed to save full output to {}: {err}", path.display());
+            None
+        }
+    }
+}
+
 struct ExecCommandInput<'a> {
     command: &'a [String],
     cwd: &'a Path,
@@ -328,6 +415,7 @@ struct ExecCommandInput<'a> {
     source: ExecCommandSource,
     interaction_input: Option<&'a str>,
     process_id: Option<&'a str>,
+    provenance: Option<&'a ExecCommandProvenance>,
 }
 
 impl<'a> ExecCommandInput<'a> {
@@ -338,6 +426,7 @@ impl<'a> ExecCommandInput<'a> {
         source: ExecCommandSource,
         interaction_input: Option<&'a str>,
         process_id: Option<&'a str>,
+        provenance: Option<&'a ExecCommandProvenance>,
     ) -> Self {
         Self {
             command,
@@ -346,6 +435,7 @@ impl<'a> ExecCommandInput<'a> {
             source,
             interaction_input,
             process_id,
+            provenance,
         }
     }
 }
@@ -356,6 +446,7 @@ struct ExecCommandResult {
     aggregated_output: String,
     exit_code: i32,
     duration: Duration,
+    timed_out: bool,
     formatted_output: String,
 }
 
@@ -385,6 +476,7 @@ async fn emit_exec_stage(
                 aggregated_output: output.aggregated_output.text.clone(),
                 exit_code: output.exit_code,
                 duration: output.duration,
+                timed_out: output.timed_out,
                 formatted_output: format_exec_output_str(&output, ctx.turn.truncation_policy),
             };
             emit_exec_end(ctx, exec_input, exec_result).await;
@@ -397,6 +489,7 @@ async fn emit_exec_stage(
                 aggregated_output: text.clone(),
                 exit_code: -1,
                 duration: Duration::ZERO,
+                timed_out: false,
                 formatted_output: text,
             };
             emit_exec_end(ctx, exec_input, exec_result).await;
@@ -426,7 +519,9 @@ async fn emit_exec_end(
                 aggregated_output: exec_result.aggregated_output,
                 exit_code: exec_result.exit_code,
                 duration: exec_result.duration,
+                timed_out: exec_result.timed_out,
                 formatted_output: exec_result.formatted_output,
+                provenance: exec_input.provenance.cloned(),
             }),
         )
         .await;
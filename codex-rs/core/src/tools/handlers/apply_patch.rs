@@ -11,7 +11,12 @@ use crate::client_common::tools::ResponsesApiTool;
 use crate::client_common::tools::ToolSpec;
 use crate::codex::Session;
 use crate::codex::TurnContext;
+use crate::edit_limits;
 use crate::function_tool::FunctionCallError;
+use crate::protected_paths;
+use crate::protocol::EventMsg;
+use crate::protocol::PatchProposalEvent;
+use crate::provenance;
 use crate::tools::context::SharedTurnDiffTracker;
 use crate::tools::context::ToolInvocation;
 use crate::tools::context::ToolOutput;
@@ -35,6 +40,8 @@ use codex_utils_absolute_path::AbsolutePathBuf;
 pub struct ApplyPatchHandler;
 
 const APPLY_PATCH_LARK_GRAMMAR: &str = include_str!("tool_apply_patch.lark");
+const EXPLORE_MODE_PROPOSAL_OUTPUT: &str = "Explore mode is on, so this patch was not applied. \
+It was shown to the user as a proposal; do not retry it or write the change another way.";
 
 fn file_paths_for_action(action: &ApplyPatchAction) -> Vec<AbsolutePathBuf> {
     let mut keys = Vec::new();
@@ -60,6 +67,29 @@ fn to_abs_path(cwd: &Path, path: &Path) -> Option<AbsolutePathBuf> {
     AbsolutePathBuf::resolve_path_against_base(path, cwd).ok()
 }
 
+/// Shows an explore-mode patch to the user without touching the workspace.
+async fn propose_patch(
+    session: &Session,
+    turn: &TurnContext,
+    call_id: &str,
+    action: &ApplyPatchAction,
+) -> ToolOutput {
+    session
+        .send_event(
+            turn,
+            EventMsg::PatchProposal(PatchProposalEvent {
+                call_id: call_id.to_string(),
+                turn_id: turn.sub_id.clone(),
+                changes: convert_apply_patch_to_protocol(action),
+            }),
+        )
+        .await;
+    ToolOutput::Function {
+        body: FunctionCallOutputBody::Text(EXPLORE_MODE_PROPOSAL_OUTPUT.to_string()),
+        success: Some(true),
+    }
+}
+
 #[async_trait]
 impl ToolHandler for ApplyPatchHandler {
     fn kind(&self) -> ToolKind {
@@ -114,9 +144,27 @@ impl ToolHandler for ApplyPatchHandler {
                             success: Some(true),
                         })
                     }
+                    InternalApplyPatchInvocation::Proposal(action) => {
+                        Ok(propose_patch(session.as_ref(), turn.as_ref(), &call_id, &action).await)
+                    }
+                    InternalApplyPatchInvocation::Blocked(matches) => Err(protected_paths::block(
+                        session.as_ref(),
+                        turn.as_ref(),
+                        &call_id,
+                        matches,
+                    )
+                    .await),
+                    InternalApplyPatchInvocation::EditLimited(hits) => {
+                        Err(
+                            edit_limits::block(session.as_ref(), turn.as_ref(), &call_id, hits)
+                                .await,
+                        )
+                    }
                     InternalApplyPatchInvocation::DelegateToExec(apply) => {
                         let changes = convert_apply_patch_to_protocol(&apply.action);
                         let file_paths = file_paths_for_action(&apply.action);
+                        let new_files =
+                            provenance::new_files(&turn.config.provenance, &apply.action);
                         let emitter =
                             ToolEmitter::apply_patch(changes.clone(), apply.auto_approved);
                         let event_ctx = ToolEventCtx::new(
@@ -147,6 +195,14 @@ impl ToolHandler for ApplyPatchHandler {
                         let out = orchestrator
                             .run(&mut runtime, &req, &tool_ctx, &turn, turn.approval_policy)
                             .await;
+                        if out.is_ok() {
+                            provenance::annotate_new_files(
+                                session.as_ref(),
+                                turn.as_ref(),
+                                &new_files,
+                            )
+                            .await;
+                        }
                         let event_ctx = ToolEventCtx::new(
                             session.as_ref(),
                             turn.as_ref(),
@@ -208,9 +264,19 @@ pub(crate) async fn intercept_apply_patch(
                         success: Some(true),
                     }))
                 }
+                InternalApplyPatchInvocation::Proposal(action) => {
+                    Ok(Some(propose_patch(session, turn, call_id, &action).await))
+                }
+                InternalApplyPatchInvocation::Blocked(matches) => {
+                    Err(protected_paths::block(session, turn, call_id, matches).await)
+                }
+                InternalApplyPatchInvocation::EditLimited(hits) => {
+                    Err(edit_limits::block(session, turn, call_id, hits).await)
+                }
                 InternalApplyPatchInvocation::DelegateToExec(apply) => {
                     let changes = convert_apply_patch_to_protocol(&apply.action);
                     let approval_keys = file_paths_for_action(&apply.action);
+                    let new_files = provenance::new_files(&turn.config.provenance, &apply.action);
                     let emitter = ToolEmitter::apply_patch(changes.clone(), apply.auto_approved);
                     let event_ctx =
                         ToolEventCtx::new(session, turn, call_id, tracker.as_ref().copied());
@@ -236,6 +302,9 @@ pub(crate) async fn intercept_apply_patch(
                     let out = orchestrator
                         .run(&mut runtime, &req, &tool_ctx, turn, turn.approval_policy)
                         .await;
+                    if out.is_ok() {
+                        provenance::annotate_new_files(session, turn, &new_files).await;
+                    }
                     let event_ctx =
                         ToolEventCtx::new(session, turn, call_id, tracker.as_ref().copied());
                     let content = emitter.finish(event_ctx, out).await?;
@@ -6,6 +6,7 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use crate::function_tool::FunctionCallError;
+use crate::protected_paths;
 use crate::tools::context::ToolInvocation;
 use crate::tools::context::ToolOutput;
 use crate::tools::context::ToolPayload;
@@ -40,7 +41,13 @@ impl ToolHandler for ClaudeEditHandler {
     }
 
     async fn handle(&self, invocation: ToolInvocation) -> Result<ToolOutput, FunctionCallError> {
-        let ToolInvocation { payload, .. } = invocation;
+        let ToolInvocation {
+            session,
+            turn,
+            call_id,
+            payload,
+            ..
+        } = invocation;
 
         let arguments = match payload {
             ToolPayload::Function { arguments } => arguments,
@@ -54,6 +61,7 @@ impl ToolHandler for ClaudeEditHandler {
 
         let args: ClaudeEditArgs = parse_arguments(&arguments)?;
         let path = validate_absolute_path(&args.file_path, "file_path")?;
+        protected_paths::guard_path(session.as_ref(), turn.as_ref(), &call_id, &path).await?;
         let edit_result =
             edit_file(&path, &args.old_string, &args.new_string, args.replace_all).await?;
 
@@ -8,6 +8,7 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use crate::function_tool::FunctionCallError;
+use crate::protected_paths;
 use crate::tools::context::ToolInvocation;
 use crate::tools::context::ToolOutput;
 use crate::tools::context::ToolPayload;
@@ -57,7 +58,13 @@ impl ToolHandler for ClaudeNotebookEditHandler {
     }
 
     async fn handle(&self, invocation: ToolInvocation) -> Result<ToolOutput, FunctionCallError> {
-        let ToolInvocation { payload, .. } = invocation;
+        let ToolInvocation {
+            session,
+            turn,
+            call_id,
+            payload,
+            ..
+        } = invocation;
 
         let arguments = match payload {
             ToolPayload::Function { arguments } => arguments,
@@ -70,6 +77,8 @@ impl ToolHandler for ClaudeNotebookEditHandler {
 
         let args: ClaudeNotebookEditArgs = parse_arguments(&arguments)?;
         let notebook_path = validate_absolute_path(&args.notebook_path, "notebook_path")?;
+        protected_paths::guard_path(session.as_ref(), turn.as_ref(), &call_id, &notebook_path)
+            .await?;
         let mut notebook = read_notebook_json(&notebook_path).await?;
         let edit_result = apply_notebook_edit(&mut notebook, &args)?;
         write_notebook_json(&notebook_path, &notebook).await?;
@@ -5,7 +5,10 @@ use serde_json::json;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::edit_limits;
 use crate::function_tool::FunctionCallError;
+use crate::protected_paths;
+use crate::provenance;
 use crate::tools::context::ToolInvocation;
 use crate::tools::context::ToolOutput;
 use crate::tools::context::ToolPayload;
@@ -32,7 +35,13 @@ impl ToolHandler for ClaudeWriteHandler {
     }
 
     async fn handle(&self, invocation: ToolInvocation) -> Result<ToolOutput, FunctionCallError> {
-        let ToolInvocation { payload, .. } = invocation;
+        let ToolInvocation {
+            session,
+            turn,
+            call_id,
+            payload,
+            ..
+        } = invocation;
 
         let arguments = match payload {
             ToolPayload::Function { arguments } => arguments,
@@ -46,12 +55,18 @@ impl ToolHandler for ClaudeWriteHandler {
 
         let args: ClaudeWriteArgs = parse_arguments(&arguments)?;
         let path = validate_absolute_path(&args.file_path, "file_path")?;
-        write_content(&path, &args.content).await?;
+        protected_paths::guard_path(session.as_ref(), turn.as_ref(), &call_id, &path).await?;
+        edit_limits::guard_overwrite(session.as_ref(), turn.as_ref(), &call_id, &path).await?;
+        let content = match provenance::file_header_for(session.as_ref(), turn.as_ref(), &path) {
+            Some(header) if !path.exists() => provenance::with_file_header(&args.content, &header),
+            _ => args.content,
+        };
+        write_content(&path, &content).await?;
 
         let output = json!({
             "ok": true,
             "file_path": path.to_string_lossy(),
-            "bytes_written": args.content.len(),
+            "bytes_written": content.len(),
         });
 
         Ok(ToolOutput::Function {
@@ -0,0 +1,706 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use codex_protocol::models::FunctionCallOutputBody;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use serde_json::json;
+
+use crate::default_client::create_client;
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolOutput;
+use crate::tools::context::ToolPayload;
+use crate::tools::handlers::parse_arguments;
+use crate::tools::registry::ToolHandler;
+use crate::tools::registry::ToolKind;
+
+pub struct DependencyAuditHandler;
+
+pub(crate) const DEFAULT_PACKAGE_LIMIT: usize = 200;
+const OSV_QUERY_BATCH_URL: &str = "https://api.osv.dev/v1/querybatch";
+const OSV_VULN_URL: &str = "https://api.osv.dev/v1/vulns";
+/// OSV rejects batches larger than this.
+const OSV_BATCH_SIZE: usize = 1000;
+/// Cap on advisories fetched in full; the rest are reported by id only.
+const MAX_ADVISORY_DETAILS: usize = 50;
+const OSV_TIMEOUT: Duration = Duration::from_secs(30);
+
+const LOCKFILE_NAMES: &[&str] = &["Cargo.lock", "package-lock.json", "poetry.lock"];
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_limit() -> usize {
+    DEFAULT_PACKAGE_LIMIT
+}
+
+#[derive(Deserialize)]
+struct DependencyAuditArgs {
+    #[serde(default)]
+    path: Option<String>,
+    #[serde(default = "default_true")]
+    check_vulnerabilities: bool,
+    #[serde(default = "default_limit")]
+    limit: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+enum Ecosystem {
+    #[serde(rename = "crates.io")]
+    CratesIo,
+    #[serde(rename = "npm")]
+    Npm,
+    #[serde(rename = "PyPI")]
+    PyPi,
+}
+
+impl Ecosystem {
+    fn osv_name(self) -> &'static str {
+        match self {
+            Ecosystem::CratesIo => "crates.io",
+            Ecosystem::Npm => "npm",
+            Ecosystem::PyPi => "PyPI",
+        }
+    }
+
+    fn for_lockfile(path: &Path) -> Option<Self> {
+        match path.file_name()?.to_str()? {
+            "Cargo.lock" => Some(Ecosystem::CratesIo),
+            "package-lock.json" => Some(Ecosystem::Npm),
+            "poetry.lock" => Some(Ecosystem::PyPi),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+struct Dependency {
+    name: String,
+    version: String,
+    /// Set for packages that do not come from the ecosystem's public registry
+    /// (git, path, workspace members); these are not checked against OSV.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+}
+
+impl Dependency {
+    fn is_registry_package(&self) -> bool {
+        self.source.is_none()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Vulnerability {
+    id: String,
+    ecosystem: Ecosystem,
+    package: String,
+    version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixed_in: Vec<String>,
+}
+
+#[async_trait]
+impl ToolHandler for DependencyAuditHandler {
+    fn kind(&self) -> ToolKind {
+        ToolKind::Function
+    }
+
+    async fn handle(&self, invocation: ToolInvocation) -> Result<ToolOutput, FunctionCallError> {
+        let ToolInvocation { payload, turn, .. } = invocation;
+
+        let arguments = match payload {
+            ToolPayload::Function { arguments } => arguments,
+            _ => {
+                return Err(FunctionCallError::RespondToModel(
+                    "dependency_audit handler received unsupported payload".to_string(),
+                ));
+            }
+        };
+
+        let args: DependencyAuditArgs = parse_arguments(&arguments)?;
+        let path = turn.resolve_path(args.path);
+        let lockfiles = find_lockfiles(&path).await?;
+
+        let mut reports = Vec::new();
+        let mut audited: Vec<(Ecosystem, Vec<Dependency>)> = Vec::new();
+        for lockfile in lockfiles {
+            let Some(ecosystem) = Ecosystem::for_lockfile(&lockfile) else {
+                continue;
+            };
+            let contents = tokio::fs::read_to_string(&lockfile).await.map_err(|err| {
+                FunctionCallError::RespondToModel(format!(
+                    "unable to read `{}`: {err}",
+                    lockfile.display()
+                ))
+            })?;
+            let dependencies = parse_lockfile(ecosystem, &contents).map_err(|err| {
+                FunctionCallError::RespondToModel(format!(
+                    "unable to parse `{}`: {err}",
+                    lockfile.display()
+                ))
+            })?;
+            reports.push(json!({
+                "path": lockfile.display().to_string(),
+                "ecosystem": ecosystem,
+                "total_packages": dependencies.len(),
+                "truncated": dependencies.len() > args.limit,
+                "packages": dependencies.iter().take(args.limit).collect::<Vec<_>>(),
+            }));
+            audited.push((ecosystem, dependencies));
+        }
+
+        let mut output = json!({ "lockfiles": reports });
+        if args.check_vulnerabilities {
+            match query_osv(&audited).await {
+                Ok(vulnerabilities) => {
+                    output["vulnerabilities"] = json!(vulnerabilities);
+                }
+                Err(err) => {
+                    output["vulnerability_check_error"] = json!(err);
+                }
+            }
+        }
+
+        let body = serde_json::to_string(&output).map_err(|err| {
+            FunctionCallError::Fatal(format!(
+                "failed to serialize dependency_audit output: {err}"
+            ))
+        })?;
+        Ok(ToolOutput::Function {
+            body: FunctionCallOutputBody::Text(body),
+            success: Some(true),
+        })
+    }
+}
+
+/// Accepts either a lockfile or a directory, in which case every supported
+/// lockfile directly inside it is audited.
+async fn find_lockfiles(path: &Path) -> Result<Vec<PathBuf>, FunctionCallError> {
+    let metadata = tokio::fs::metadata(path).await.map_err(|err| {
+        FunctionCallError::RespondToModel(format!("unable to access `{}`: {err}", path.display()))
+    })?;
+    if !metadata.is_dir() {
+        if Ecosystem::for_lockfile(path).is_none() {
+            return Err(FunctionCallError::RespondToModel(format!(
+                "`{}` is not a supported lockfile (expected one of {})",
+                path.display(),
+                LOCKFILE_NAMES.join(", ")
+            )));
+        }
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut found = Vec::new();
+    for name in LOCKFILE_NAMES {
+        let candidate = path.join(name);
+        if tokio::fs::try_exists(&candidate).await.unwrap_or(false) {
+            found.push(candidate);
+        }
+    }
+    if found.is_empty() {
+        return Err(FunctionCallError::RespondToModel(format!(
+            "no lockfile found in `{}` (looked for {})",
+            path.display(),
+            LOCKFILE_NAMES.join(", ")
+        )));
+    }
+    Ok(found)
+}
+
+fn parse_lockfile(ecosystem: Ecosystem, contents: &str) -> Result<Vec<Dependency>, String> {
+    let dependencies = match ecosystem {
+        Ecosystem::CratesIo => parse_cargo_lock(contents)?,
+        Ecosystem::Npm => parse_package_lock(contents)?,
+        Ecosystem::PyPi => parse_poetry_lock(contents)?,
+    };
+    Ok(dependencies.into_iter().collect())
+}
+
+#[derive(Deserialize)]
+struct CargoLock {
+    #[serde(default)]
+    package: Vec<CargoLockPackage>,
+}
+
+#[derive(Deserialize)]
+struct CargoLockPackage {
+    name: String,
+    version: String,
+    #[serde(default)]
+    source: Option<String>,
+}
+
+fn parse_cargo_lock(contents: &str) -> Result<BTreeSet<Dependency>, String> {
+    let lock: CargoLock = toml::from_str(contents).map_err(|err| err.to_string())?;
+    Ok(lock
+        .package
+        .into_iter()
+        .map(|package| {
+            let source = match package.source {
+                Some(source) if source.starts_with("registry+") => None,
+                Some(source) => Some(source),
+                None => Some("local".to_string()),
+            };
+            Dependency {
+                name: package.name,
+                version: package.version,
+                source,
+            }
+        })
+        .collect())
+}
+
+#[derive(Deserialize)]
+struct PackageLock {
+    #[serde(default)]
+    packages: BTreeMap<String, PackageLockEntry>,
+    #[serde(default)]
+    dependencies: BTreeMap<String, PackageLockEntry>,
+}
+
+#[derive(Deserialize)]
+struct PackageLockEntry {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    resolved: Option<String>,
+    #[serde(default)]
+    link: bool,
+    #[serde(default)]
+    dependencies: BTreeMap<String, JsonValue>,
+}
+
+fn parse_package_lock(contents: &str) -> Result<BTreeSet<Dependency>, String> {
+    let lock: PackageLock = serde_json::from_str(contents).map_err(|err| err.to_string())?;
+    let mut dependencies = BTreeSet::new();
+
+    // lockfileVersion 2 and 3 key every installed package by its
+    // `node_modules/...` path; the empty key is the project itself.
+    for (key, entry) in &lock.packages {
+        let Some(installed_name) = key.rsplit_once("node_modules/").map(|(_, name)| name) else {
+            continue;
+        };
+        if entry.link {
+            continue;
+        }
+        let Some(version) = &entry.version else {
+            continue;
+        };
+        dependencies.insert(Dependency {
+            name: entry
+                .name
+                .clone()
+                .unwrap_or_else(|| installed_name.to_string()),
+            version: version.clone(),
+            source: npm_source(entry.resolved.as_deref()),
+        });
+    }
+
+    // lockfileVersion 1 only has the nested `dependencies` tree.
+    if lock.packages.is_empty() {
+        collect_package_lock_v1(&lock.dependencies, &mut dependencies);
+    }
+    Ok(dependencies)
+}
+
+fn collect_package_lock_v1(
+    entries: &BTreeMap<String, PackageLockEntry>,
+    dependencies: &mut BTreeSet<Dependency>,
+) {
+    for (name, entry) in entries {
+        if let Some(version) = &entry.version {
+            dependencies.insert(Dependency {
+                name: name.clone(),
+                version: version.clone(),
+                source: npm_source(entry.resolved.as_deref()),
+            });
+        }
+        let nested: BTreeMap<String, PackageLockEntry> = entry
+            .dependencies
+            .iter()
+            .filter_map(|(name, value)| {
+                serde_json::from_value(value.clone())
+                    .ok()
+                    .map(|entry| (name.clone(), entry))
+            })
+            .collect();
+        collect_package_lock_v1(&nested, dependencies);
+    }
+}
+
+fn npm_source(resolved: Option<&str>) -> Option<String> {
+    match resolved {
+        Some(resolved) if !resolved.starts_with("https://registry.npmjs.org/") => {
+            Some(resolved.to_string())
+        }
+        _ => None,
+    }
+}
+
+#[derive(Deserialize)]
+struct PoetryLock {
+    #[serde(default)]
+    package: Vec<PoetryLockPackage>,
+}
+
+#[derive(Deserialize)]
+struct PoetryLockPackage {
+    name: String,
+    version: String,
+    #[serde(default)]
+    source: Option<PoetryLockSource>,
+}
+
+#[derive(Deserialize)]
+struct PoetryLockSource {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    url: Option<String>,
+}
+
+fn parse_poetry_lock(contents: &str) -> Result<BTreeSet<Dependency>, String> {
+    let lock: PoetryLock = toml::from_str(contents).map_err(|err| err.to_string())?;
+    Ok(lock
+        .package
+        .into_iter()
+        .map(|package| {
+            // Packages from an explicit `legacy` index are still PyPI
+            // distributions; git/directory/file/url sources are not.
+            let source = package
+                .source
+                .and_then(|source| match source.kind.as_str() {
+                    "legacy" => None,
+                    _ => Some(source.url.unwrap_or(source.kind)),
+                });
+            Dependency {
+                name: package.name,
+                version: package.version,
+                source,
+            }
+        })
+        .collect())
+}
+
+#[derive(Deserialize)]
+struct OsvBatchResponse {
+    #[serde(default)]
+    results: Vec<OsvBatchResult>,
+}
+
+#[derive(Deserialize)]
+struct OsvBatchResult {
+    #[serde(default)]
+    vulns: Vec<OsvVulnRef>,
+}
+
+#[derive(Deserialize)]
+struct OsvVulnRef {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct OsvVuln {
+    #[serde(default)]
+    summary: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    affected: Vec<JsonValue>,
+}
+
+/// Looks up every registry package with OSV's batch endpoint, then fetches
+/// the advisory bodies for the matches so the model gets summaries and fixed
+/// versions instead of bare ids.
+async fn query_osv(audited: &[(Ecosystem, Vec<Dependency>)]) -> Result<Vec<Vulnerability>, String> {
+    let queries: Vec<(Ecosystem, &Dependency)> = audited
+        .iter()
+        .flat_map(|(ecosystem, dependencies)| {
+            dependencies
+                .iter()
+                .filter(|dependency| dependency.is_registry_package())
+                .map(|dependency| (*ecosystem, dependency))
+        })
+        .collect();
+
+    let client = create_client();
+    let mut vulnerabilities = Vec::new();
+    for chunk in queries.chunks(OSV_BATCH_SIZE) {
+        let body = json!({
+            "queries": chunk
+                .iter()
+                .map(|(ecosystem, dependency)| json!({
+                    "package": { "name": dependency.name, "ecosystem": ecosystem.osv_name() },
+                    "version": dependency.version,
+                }))
+                .collect::<Vec<_>>(),
+        });
+        let response = client
+            .post(OSV_QUERY_BATCH_URL)
+            .timeout(OSV_TIMEOUT)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|err| format!("OSV request failed: {err}"))?;
+        if !response.status().is_success() {
+            return Err(format!("OSV returned {}", response.status()));
+        }
+        let batch: OsvBatchResponse = response
+            .json()
+            .await
+            .map_err(|err| format!("failed to decode OSV response: {err}"))?;
+        for ((ecosystem, dependency), result) in chunk.iter().zip(batch.results) {
+            for vuln in result.vulns {
+                vulnerabilities.push(Vulnerability {
+                    id: vuln.id,
+                    ecosystem: *ecosystem,
+                    package: dependency.name.clone(),
+                    version: dependency.version.clone(),
+                    summary: None,
+                    aliases: Vec::new(),
+                    fixed_in: Vec::new(),
+                });
+            }
+        }
+    }
+
+    let ids: BTreeSet<String> = vulnerabilities.iter().map(|vuln| vuln.id.clone()).collect();
+    let mut details = BTreeMap::new();
+    for id in ids.into_iter().take(MAX_ADVISORY_DETAILS) {
+        let response = client
+            .get(format!("{OSV_VULN_URL}/{id}"))
+            .timeout(OSV_TIMEOUT)
+            .send()
+            .await;
+        let Ok(response) = response else {
+            continue;
+        };
+        if let Ok(vuln) = response.json::<OsvVuln>().await {
+            details.insert(id, vuln);
+        }
+    }
+    for vulnerability in &mut vulnerabilities {
+        if let Some(detail) = details.get(&vulnerability.id) {
+            vulnerability.summary = detail.summary.clone();
+            vulnerability.aliases = detail.aliases.clone();
+            vulnerability.fixed_in = fixed_versions(detail, vulnerability);
+        }
+    }
+    Ok(vulnerabilities)
+}
+
+fn fixed_versions(detail: &OsvVuln, vulnerability: &Vulnerability) -> Vec<String> {
+    let mut fixed = BTreeSet::new();
+    for affected in &detail.affected {
+        let package = &affected["package"];
+        if package["name"].as_str() != Some(vulnerability.package.as_str())
+            || package["ecosystem"].as_str() != Some(vulnerability.ecosystem.osv_name())
+        {
+            continue;
+        }
+        let Some(ranges) = affected["ranges"].as_array() else {
+            continue;
+        };
+        for event in ranges
+            .iter()
+            .filter_map(|range| range["events"].as_array())
+            .flatten()
+        {
+            if let Some(version) = event["fixed"].as_str() {
+                fixed.insert(version.to_string());
+            }
+        }
+    }
+    fixed.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn dep(name: &str, version: &str, source: Option<&str>) -> Dependency {
+        Dependency {
+            name: name.to_string(),
+            version: version.to_string(),
+            source: source.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn parses_cargo_lock_and_marks_non_registry_sources() {
+        let contents = r#"
+version = 4
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["serde"]
+
+[[package]]
+name = "serde"
+version = "1.0.200"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "abc"
+
+[[package]]
+name = "forked"
+version = "0.2.0"
+source = "git+https://github.com/example/forked#deadbeef"
+"#;
+        assert_eq!(
+            parse_lockfile(Ecosystem::CratesIo, contents).expect("parse"),
+            vec![
+                dep("app", "0.1.0", Some("local")),
+                dep(
+                    "forked",
+                    "0.2.0",
+                    Some("git+https://github.com/example/forked#deadbeef")
+                ),
+                dep("serde", "1.0.200", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_package_lock_v3_and_v1() {
+        let v3 = r#"{
+  "name": "web",
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "web", "version": "1.0.0" },
+    "node_modules/lodash": {
+      "version": "4.17.20",
+      "resolved": "https://registry.npmjs.org/lodash/-/lodash-4.17.20.tgz"
+    },
+    "node_modules/a/node_modules/@scope/b": { "version": "2.0.0" },
+    "node_modules/local": { "resolved": "packages/local", "link": true },
+    "node_modules/alias": {
+      "name": "real-name",
+      "version": "1.2.3",
+      "resolved": "https://registry.npmjs.org/real-name/-/real-name-1.2.3.tgz"
+    }
+  }
+}"#;
+        assert_eq!(
+            parse_lockfile(Ecosystem::Npm, v3).expect("parse v3"),
+            vec![
+                dep("@scope/b", "2.0.0", None),
+                dep("lodash", "4.17.20", None),
+                dep("real-name", "1.2.3", None),
+            ]
+        );
+
+        let v1 = r#"{
+  "lockfileVersion": 1,
+  "dependencies": {
+    "express": {
+      "version": "4.17.1",
+      "dependencies": {
+        "debug": { "version": "2.6.9" }
+      }
+    },
+    "mine": { "version": "git+https://github.com/example/mine.git", "resolved": "git+https://github.com/example/mine.git" }
+  }
+}"#;
+        assert_eq!(
+            parse_lockfile(Ecosystem::Npm, v1).expect("parse v1"),
+            vec![
+                dep("debug", "2.6.9", None),
+                dep("express", "4.17.1", None),
+                dep(
+                    "mine",
+                    "git+https://github.com/example/mine.git",
+                    Some("git+https://github.com/example/mine.git")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_poetry_lock() {
+        let contents = r#"
+[[package]]
+name = "requests"
+version = "2.31.0"
+description = "HTTP for Humans."
+
+[[package]]
+name = "internal"
+version = "0.3.0"
+
+[package.source]
+type = "git"
+url = "https://github.com/example/internal.git"
+reference = "main"
+
+[[package]]
+name = "mirrored"
+version = "1.0.0"
+
+[package.source]
+type = "legacy"
+url = "https://pypi.example.com/simple"
+reference = "mirror"
+
+[metadata]
+lock-version = "2.0"
+"#;
+        assert_eq!(
+            parse_lockfile(Ecosystem::PyPi, contents).expect("parse"),
+            vec![
+                dep(
+                    "internal",
+                    "0.3.0",
+                    Some("https://github.com/example/internal.git")
+                ),
+                dep("mirrored", "1.0.0", None),
+                dep("requests", "2.31.0", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn fixed_versions_only_reads_matching_package() {
+        let detail: OsvVuln = serde_json::from_value(json!({
+            "summary": "Prototype pollution",
+            "affected": [
+                {
+                    "package": { "name": "lodash", "ecosystem": "npm" },
+                    "ranges": [{ "type": "SEMVER", "events": [{ "introduced": "0" }, { "fixed": "4.17.21" }] }]
+                },
+                {
+                    "package": { "name": "lodash-es", "ecosystem": "npm" },
+                    "ranges": [{ "type": "SEMVER", "events": [{ "introduced": "0" }, { "fixed": "4.17.22" }] }]
+                }
+            ]
+        }))
+        .expect("vuln");
+        let vulnerability = Vulnerability {
+            id: "GHSA-test".to_string(),
+            ecosystem: Ecosystem::Npm,
+            package: "lodash".to_string(),
+            version: "4.17.20".to_string(),
+            summary: None,
+            aliases: Vec::new(),
+            fixed_in: Vec::new(),
+        };
+        assert_eq!(
+            fixed_versions(&detail, &vulnerability),
+            vec!["4.17.21".to_string()]
+        );
+    }
+}
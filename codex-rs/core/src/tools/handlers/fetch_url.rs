@@ -0,0 +1,292 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use codex_protocol::items::TurnItem;
+use codex_protocol::items::WebCitation;
+use codex_protocol::items::WebSearchItem;
+use codex_protocol::models::FunctionCallOutputBody;
+use codex_protocol::models::WebSearchAction;
+use serde::Deserialize;
+use url::Url;
+
+use crate::codex::TurnContext;
+use crate::default_client::get_codex_user_agent;
+use crate::function_tool::FunctionCallError;
+use crate::html_text::extract_readable_text;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolOutput;
+use crate::tools::context::ToolPayload;
+use crate::tools::handlers::parse_arguments;
+use crate::tools::registry::ToolHandler;
+use crate::tools::registry::ToolKind;
+use crate::web_cache::FetchedPage;
+
+pub struct FetchUrlHandler;
+
+pub(crate) const DEFAULT_MAX_CHARS: usize = 20_000;
+const MAX_CHARS_LIMIT: usize = 100_000;
+const MAX_DOWNLOAD_BYTES: usize = 5 * 1024 * 1024;
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_REDIRECTS: usize = 5;
+const SNIPPET_CHARS: usize = 200;
+
+fn default_max_chars() -> usize {
+    DEFAULT_MAX_CHARS
+}
+
+#[derive(Deserialize)]
+struct FetchUrlArgs {
+    url: String,
+    #[serde(default = "default_max_chars")]
+    max_chars: usize,
+}
+
+#[async_trait]
+impl ToolHandler for FetchUrlHandler {
+    fn kind(&self) -> ToolKind {
+        ToolKind::Function
+    }
+
+    async fn handle(&self, invocation: ToolInvocation) -> Result<ToolOutput, FunctionCallError> {
+        let ToolInvocation {
+            session,
+            turn,
+            call_id,
+            payload,
+            ..
+        } = invocation;
+
+        let arguments = match payload {
+            ToolPayload::Function { arguments } => arguments,
+            _ => {
+                return Err(FunctionCallError::RespondToModel(
+                    "fetch_url handler received unsupported payload".to_string(),
+                ));
+            }
+        };
+        let args: FetchUrlArgs = parse_arguments(&arguments)?;
+        let url = Url::parse(args.url.trim()).map_err(|err| {
+            FunctionCallError::RespondToModel(format!("invalid url `{}`: {err}", args.url))
+        })?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(FunctionCallError::RespondToModel(format!(
+                "fetch_url only supports http and https URLs, got `{}`",
+                url.scheme()
+            )));
+        }
+        let client = fetch_client(&turn)?;
+
+        let mut item = WebSearchItem {
+            id: call_id,
+            query: url.to_string(),
+            action: WebSearchAction::OpenPage {
+                url: Some(url.to_string()),
+            },
+            citations: Vec::new(),
+        };
+        session
+            .emit_turn_item_started(&turn, &TurnItem::WebSearch(item.clone()))
+            .await;
+        let cached = session.services.web_cache.lock().await.page(&url);
+        let result = match cached {
+            Some(page) => Ok(page),
+            None => {
+                let result = fetch_page(&client, url.clone()).await;
+                if let Ok(page) = &result {
+                    session
+                        .services
+                        .web_cache
+                        .lock()
+                        .await
+                        .insert_page(&url, page.clone());
+                }
+                result
+            }
+        };
+        if let Ok(page) = &result {
+            item.citations = vec![WebCitation {
+                url: page.url.clone(),
+                title: page.title.clone(),
+                snippet: Some(page.text.chars().take(SNIPPET_CHARS).collect())
+                    .filter(|snippet: &String| !snippet.is_empty()),
+            }];
+            session.record_turn_sources(&item.citations).await;
+        }
+        session
+            .emit_turn_item_completed(&turn, TurnItem::WebSearch(item))
+            .await;
+
+        let page = result.map_err(FunctionCallError::RespondToModel)?;
+        let body = render_page(
+            &page,
+            args.max_chars.clamp(1, MAX_CHARS_LIMIT),
+            &Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        );
+        Ok(ToolOutput::Function {
+            body: FunctionCallOutputBody::Text(body),
+            success: Some(true),
+        })
+    }
+}
+
+/// HTTP client for page fetches. Requests go through the session's managed
+/// network proxy when there is one, so its allow/deny rules apply; otherwise
+/// the sandbox policy has to grant network access.
+fn fetch_client(turn: &TurnContext) -> Result<reqwest::Client, FunctionCallError> {
+    let mut builder = reqwest::Client::builder()
+        .user_agent(get_codex_user_agent())
+        .timeout(FETCH_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::limited(MAX_REDIRECTS));
+    if let Some(network) = &turn.network {
+        let proxy =
+            reqwest::Proxy::all(format!("http://{}", network.http_addr())).map_err(|err| {
+                FunctionCallError::RespondToModel(format!("network proxy unavailable: {err}"))
+            })?;
+        builder = builder.proxy(proxy);
+    } else if !turn.sandbox_policy.has_full_network_access() {
+        return Err(FunctionCallError::RespondToModel(
+            "fetch_url needs network access, which the current sandbox policy does not allow. \
+             Ask the user to enable network access for this session if the page is needed."
+                .to_string(),
+        ));
+    }
+    builder
+        .build()
+        .map_err(|err| FunctionCallError::Fatal(format!("failed to build HTTP client: {err}")))
+}
+
+async fn fetch_page(client: &reqwest::Client, url: Url) -> Result<FetchedPage, String> {
+    let mut response = client
+        .get(url.clone())
+        .send()
+        .await
+        .map_err(|err| format!("failed to fetch {url}: {err}"))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("{url} returned {status}"));
+    }
+    let final_url = response.url().to_string();
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("text/html")
+        .to_ascii_lowercase();
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|err| format!("failed to read {final_url}: {err}"))?
+    {
+        let remaining = MAX_DOWNLOAD_BYTES.saturating_sub(bytes.len());
+        bytes.extend_from_slice(&chunk[..chunk.len().min(remaining)]);
+        if bytes.len() >= MAX_DOWNLOAD_BYTES {
+            break;
+        }
+    }
+    page_from_body(final_url, &content_type, &bytes)
+}
+
+fn page_from_body(url: String, content_type: &str, bytes: &[u8]) -> Result<FetchedPage, String> {
+    let body = String::from_utf8_lossy(bytes);
+    if content_type.contains("html") {
+        let page = extract_readable_text(&body);
+        return Ok(FetchedPage {
+            url,
+            title: page.title,
+            text: page.text,
+        });
+    }
+    let is_text = content_type.starts_with("text/")
+        || content_type.contains("json")
+        || content_type.contains("xml")
+        || content_type.contains("javascript");
+    if !is_text {
+        return Err(format!(
+            "{url} is `{content_type}`, which fetch_url cannot turn into text"
+        ));
+    }
+    Ok(FetchedPage {
+        url,
+        title: None,
+        text: body.trim().to_string(),
+    })
+}
+
+/// Page text prefixed with the citation metadata the model should quote.
+fn render_page(page: &FetchedPage, max_chars: usize, retrieved_at: &str) -> String {
+    let mut out = String::new();
+    if let Some(title) = &page.title {
+        out.push_str(&format!("Title: {title}\n"));
+    }
+    out.push_str(&format!("URL: {}\nRetrieved: {retrieved_at}\n\n", page.url));
+    let total = page.text.chars().count();
+    out.extend(page.text.chars().take(max_chars));
+    if total > max_chars {
+        out.push_str(&format!(
+            "\n\n[truncated: showing {max_chars} of {total} characters; call fetch_url again with a larger max_chars to read more]"
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn html_bodies_are_extracted_and_binary_bodies_rejected() {
+        let page = page_from_body(
+            "https://example.com/post".to_string(),
+            "text/html; charset=utf-8",
+            b"<html><head><title>Post</title></head><body><p>Hello&nbsp;there</p></body></html>",
+        )
+        .expect("html page");
+        assert_eq!(
+            page,
+            FetchedPage {
+                url: "https://example.com/post".to_string(),
+                title: Some("Post".to_string()),
+                text: "Hello there".to_string(),
+            }
+        );
+
+        let json = page_from_body(
+            "https://example.com/data.json".to_string(),
+            "application/json",
+            b" {\"ok\": true}\n",
+        )
+        .expect("json body");
+        assert_eq!(json.text, "{\"ok\": true}");
+
+        assert_eq!(
+            page_from_body(
+                "https://example.com/logo.png".to_string(),
+                "image/png",
+                b"\x89PNG",
+            ),
+            Err("https://example.com/logo.png is `image/png`, which fetch_url cannot turn into text".to_string())
+        );
+    }
+
+    #[test]
+    fn render_page_includes_citation_metadata_and_truncation_note() {
+        let page = FetchedPage {
+            url: "https://example.com/post".to_string(),
+            title: Some("Post".to_string()),
+            text: "abcdefghij".to_string(),
+        };
+        assert_eq!(
+            render_page(&page, 4, "2026-01-02T03:04:05Z"),
+            "Title: Post\nURL: https://example.com/post\nRetrieved: 2026-01-02T03:04:05Z\n\nabcd\n\n[truncated: showing 4 of 10 characters; call fetch_url again with a larger max_chars to read more]"
+        );
+        assert_eq!(
+            render_page(&page, 100, "2026-01-02T03:04:05Z"),
+            "Title: Post\nURL: https://example.com/post\nRetrieved: 2026-01-02T03:04:05Z\n\nabcdefghij"
+        );
+    }
+}
@@ -7,7 +7,9 @@ mod claude_tool_adapter;
 mod claude_write;
 pub(crate) mod collab;
 mod collab_batch;
+mod dependency_audit;
 mod dynamic;
+mod fetch_url;
 mod grep_files;
 mod list_dir;
 mod mcp;
@@ -20,6 +22,7 @@ mod shell;
 mod test_sync;
 mod unified_exec;
 mod view_image;
+mod web_search;
 
 pub use plan::PLAN_TOOL;
 use serde::Deserialize;
@@ -34,7 +37,11 @@ pub use claude_tool_adapter::ClaudeToolAdapterHandler;
 pub use claude_write::ClaudeWriteHandler;
 pub use collab::CollabHandler;
 pub use collab_batch::CollabBatchHandler;
+pub(crate) use dependency_audit::DEFAULT_PACKAGE_LIMIT as DEPENDENCY_AUDIT_DEFAULT_LIMIT;
+pub use dependency_audit::DependencyAuditHandler;
 pub use dynamic::DynamicToolHandler;
+pub(crate) use fetch_url::DEFAULT_MAX_CHARS as FETCH_URL_DEFAULT_MAX_CHARS;
+pub use fetch_url::FetchUrlHandler;
 pub use grep_files::GrepFilesHandler;
 pub use list_dir::ListDirHandler;
 pub use mcp::McpHandler;
@@ -50,6 +57,7 @@ pub use shell::ShellHandler;
 pub use test_sync::TestSyncHandler;
 pub use unified_exec::UnifiedExecHandler;
 pub use view_image::ViewImageHandler;
+pub use web_search::WebSearchHandler;
 
 fn parse_arguments<T>(arguments: &str) -> Result<T, FunctionCallError>
 where
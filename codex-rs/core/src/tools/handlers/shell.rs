@@ -11,6 +11,8 @@ use crate::exec_env::create_env;
 use crate::exec_policy::ExecApprovalRequest;
 use crate::function_tool::FunctionCallError;
 use crate::is_safe_command::is_known_safe_command;
+use crate::protocol::ExecCommandOrigin;
+use crate::protocol::ExecCommandProvenance;
 use crate::protocol::ExecCommandSource;
 use crate::shell::Shell;
 use crate::tools::context::ToolInvocation;
@@ -51,7 +53,10 @@ impl ShellHandler {
         ExecParams {
             command: params.command.clone(),
             cwd: turn_context.resolve_path(params.workdir.clone()),
-            expiration: params.timeout_ms.into(),
+            expiration: params
+                .timeout_ms
+                .or(turn_context.config.exec_timeout_ms)
+                .into(),
             env: create_env(&turn_context.shell_environment_policy, Some(thread_id)),
             network: turn_context.network.clone(),
             sandbox_permissions: params.sandbox_permissions.unwrap_or_default(),
@@ -63,8 +68,13 @@ impl ShellHandler {
 }
 
 impl ShellCommandHandler {
-    fn base_command(shell: &Shell, command: &str, login: Option<bool>) -> Vec<String> {
-        let use_login_shell = login.unwrap_or(true);
+    fn base_command(
+        shell: &Shell,
+        command: &str,
+        login: Option<bool>,
+        default_login: bool,
+    ) -> Vec<String> {
+        let use_login_shell = login.unwrap_or(default_login);
         shell.derive_exec_args(command, use_login_shell)
     }
 
@@ -75,12 +85,20 @@ impl ShellCommandHandler {
         thread_id: ThreadId,
     ) -> ExecParams {
         let shell = session.user_shell();
-        let command = Self::base_command(shell.as_ref(), &params.command, params.login);
+        let command = Self::base_command(
+            shell.as_ref(),
+            &params.command,
+            params.login,
+            turn_context.config.shell.login,
+        );
 
         ExecParams {
             command,
             cwd: turn_context.resolve_path(params.workdir.clone()),
-            expiration: params.timeout_ms.into(),
+            expiration: params
+                .timeout_ms
+                .or(turn_context.config.exec_timeout_ms)
+                .into(),
             env: create_env(&turn_context.shell_environment_policy, Some(thread_id)),
             network: turn_context.network.clone(),
             sandbox_permissions: params.sandbox_permissions.unwrap_or_default(),
@@ -184,7 +202,12 @@ impl ToolHandler for ShellCommandHandler {
         serde_json::from_str::<ShellCommandToolCallParams>(arguments)
             .map(|params| {
                 let shell = invocation.session.user_shell();
-                let command = Self::base_command(shell.as_ref(), &params.command, params.login);
+                let command = Self::base_command(
+                    shell.as_ref(),
+                    &params.command,
+                    params.login,
+                    invocation.turn.config.shell.login,
+                );
                 !is_known_safe_command(&command)
             })
             .unwrap_or(true)
@@ -329,6 +352,14 @@ impl ShellHandler {
         let out = orchestrator
             .run(&mut runtime, &req, &tool_ctx, &turn, turn.approval_policy)
             .await;
+        let emitter = emitter.with_provenance(ExecCommandProvenance {
+            origin: ExecCommandOrigin::Model,
+            policy_rule: session
+                .services
+                .exec_policy
+                .matched_rule_for_command(&exec_params.command),
+            approval: orchestrator.approval().cloned(),
+        });
         let event_ctx = ToolEventCtx::new(session.as_ref(), turn.as_ref(), &call_id, None);
         let content = emitter.finish(event_ctx, out).await?;
         Ok(ToolOutput::Function {
@@ -451,6 +482,80 @@ mod tests {
         assert_eq!(exec_params.arg0, None);
     }
 
+    #[tokio::test]
+    async fn configured_exec_timeout_applies_when_call_sets_none() {
+        let (session, mut turn_context) = make_session_and_context().await;
+        let mut config = (*turn_context.config).clone();
+        config.exec_timeout_ms = Some(90_000);
+        turn_context.config = Arc::new(config);
+
+        let params = |timeout_ms| ShellCommandToolCallParams {
+            command: "sleep 100".to_string(),
+            workdir: None,
+            login: None,
+            timeout_ms,
+            sandbox_permissions: None,
+            prefix_rule: None,
+            justification: None,
+        };
+
+        let exec_params = ShellCommandHandler::to_exec_params(
+            &params(None),
+            &session,
+            &turn_context,
+            session.conversation_id,
+        );
+        assert_eq!(exec_params.expiration.timeout_ms(), Some(90_000));
+
+        let exec_params = ShellCommandHandler::to_exec_params(
+            &params(Some(1_000)),
+            &session,
+            &turn_context,
+            session.conversation_id,
+        );
+        assert_eq!(exec_params.expiration.timeout_ms(), Some(1_000));
+    }
+
+    #[tokio::test]
+    async fn configured_login_default_applies_when_call_sets_none() {
+        let (session, mut turn_context) = make_session_and_context().await;
+        let mut config = (*turn_context.config).clone();
+        config.shell.login = false;
+        turn_context.config = Arc::new(config);
+
+        let params = |login| ShellCommandToolCallParams {
+            command: "echo hello".to_string(),
+            workdir: None,
+            login,
+            timeout_ms: None,
+            sandbox_permissions: None,
+            prefix_rule: None,
+            justification: None,
+        };
+
+        let exec_params = ShellCommandHandler::to_exec_params(
+            &params(None),
+            &session,
+            &turn_context,
+            session.conversation_id,
+        );
+        assert_eq!(
+            exec_params.command,
+            session.user_shell().derive_exec_args("echo hello", false)
+        );
+
+        let exec_params = ShellCommandHandler::to_exec_params(
+            &params(Some(true)),
+            &session,
+            &turn_context,
+            session.conversation_id,
+        );
+        assert_eq!(
+            exec_params.command,
+            session.user_shell().derive_exec_args("echo hello", true)
+        );
+    }
+
     #[test]
     fn shell_command_handler_respects_explicit_login_flag() {
         let (_tx, shell_snapshot) = watch::channel(Some(Arc::new(ShellSnapshot {
@@ -464,14 +569,14 @@ mod tests {
         };
 
         let login_command =
-            ShellCommandHandler::base_command(&shell, "echo login shell", Some(true));
+            ShellCommandHandler::base_command(&shell, "echo login shell", Some(true), false);
         assert_eq!(
             login_command,
             shell.derive_exec_args("echo login shell", true)
         );
 
         let non_login_command =
-            ShellCommandHandler::base_command(&shell, "echo non login shell", Some(false));
+            ShellCommandHandler::base_command(&shell, "echo non login shell", Some(false), true);
         assert_eq!(
             non_login_command,
             shell.derive_exec_args("echo non login shell", false)
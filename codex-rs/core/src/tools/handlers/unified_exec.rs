@@ -32,8 +32,8 @@ struct ExecCommandArgs {
     workdir: Option<String>,
     #[serde(default)]
     shell: Option<String>,
-    #[serde(default = "default_login")]
-    login: bool,
+    #[serde(default)]
+    login: Option<bool>,
     #[serde(default = "default_tty")]
     tty: bool,
     #[serde(default = "default_exec_yield_time_ms")]
@@ -68,10 +68,6 @@ fn default_write_stdin_yield_time_ms() -> u64 {
     250
 }
 
-fn default_login() -> bool {
-    true
-}
-
 fn default_tty() -> bool {
     false
 }
@@ -98,7 +94,11 @@ impl ToolHandler for UnifiedExecHandler {
         let Ok(params) = serde_json::from_str::<ExecCommandArgs>(arguments) else {
             return true;
         };
-        let command = get_command(&params, invocation.session.user_shell());
+        let command = get_command(
+            &params,
+            invocation.session.user_shell(),
+            invocation.turn.config.shell.login,
+        );
         !is_known_safe_command(&command)
     }
 
@@ -129,7 +129,7 @@ impl ToolHandler for UnifiedExecHandler {
             "exec_command" => {
                 let args: ExecCommandArgs = parse_arguments(&arguments)?;
                 let process_id = manager.allocate_process_id().await;
-                let command = get_command(&args, session.user_shell());
+                let command = get_command(&args, session.user_shell(), turn.config.shell.login);
 
                 let ExecCommandArgs {
                     workdir,
@@ -246,7 +246,11 @@ impl ToolHandler for UnifiedExecHandler {
     }
 }
 
-fn get_command(args: &ExecCommandArgs, session_shell: Arc<Shell>) -> Vec<String> {
+fn get_command(
+    args: &ExecCommandArgs,
+    session_shell: Arc<Shell>,
+    default_login: bool,
+) -> Vec<String> {
     let model_shell = args.shell.as_ref().map(|shell_str| {
         let mut shell = get_shell_by_model_provided_path(&PathBuf::from(shell_str));
         shell.shell_snapshot = crate::shell::empty_shell_snapshot_receiver();
@@ -255,7 +259,7 @@ fn get_command(args: &ExecCommandArgs, session_shell: Arc<Shell>) -> Vec<String>
 
     let shell = model_shell.as_ref().unwrap_or(session_shell.as_ref());
 
-    shell.derive_exec_args(&args.cmd, args.login)
+    shell.derive_exec_args(&args.cmd, args.login.unwrap_or(default_login))
 }
 
 fn format_response(response: &UnifiedExecResponse) -> String {
@@ -301,7 +305,7 @@ mod tests {
 
         assert!(args.shell.is_none());
 
-        let command = get_command(&args, Arc::new(default_user_shell()));
+        let command = get_command(&args, Arc::new(default_user_shell()), true);
 
         assert_eq!(command.len(), 3);
         assert_eq!(command[2], "echo hello");
@@ -316,7 +320,7 @@ mod tests {
 
         assert_eq!(args.shell.as_deref(), Some("/bin/bash"));
 
-        let command = get_command(&args, Arc::new(default_user_shell()));
+        let command = get_command(&args, Arc::new(default_user_shell()), true);
 
         assert_eq!(command.last(), Some(&"echo hello".to_string()));
         if command
@@ -336,7 +340,7 @@ mod tests {
 
         assert_eq!(args.shell.as_deref(), Some("powershell"));
 
-        let command = get_command(&args, Arc::new(default_user_shell()));
+        let command = get_command(&args, Arc::new(default_user_shell()), true);
 
         assert_eq!(command[2], "echo hello");
         Ok(())
@@ -350,7 +354,7 @@ mod tests {
 
         assert_eq!(args.shell.as_deref(), Some("cmd"));
 
-        let command = get_command(&args, Arc::new(default_user_shell()));
+        let command = get_command(&args, Arc::new(default_user_shell()), true);
 
         assert_eq!(command[2], "echo hello");
         Ok(())
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use codex_protocol::items::TurnItem;
+use codex_protocol::items::WebSearchItem;
+use codex_protocol::models::FunctionCallOutputBody;
+use codex_protocol::models::WebSearchAction;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolOutput;
+use crate::tools::context::ToolPayload;
+use crate::tools::handlers::parse_arguments;
+use crate::tools::registry::ToolHandler;
+use crate::tools::registry::ToolKind;
+use crate::web_search_provider;
+
+/// `web_search` backed by the `[web_search_provider]` backend rather than the
+/// model provider's hosted search.
+pub struct WebSearchHandler;
+
+#[derive(Deserialize)]
+struct WebSearchArgs {
+    query: String,
+    #[serde(default)]
+    count: Option<usize>,
+}
+
+#[async_trait]
+impl ToolHandler for WebSearchHandler {
+    fn kind(&self) -> ToolKind {
+        ToolKind::Function
+    }
+
+    async fn handle(&self, invocation: ToolInvocation) -> Result<ToolOutput, FunctionCallError> {
+        let ToolInvocation {
+            session,
+            turn,
+            call_id,
+            payload,
+            ..
+        } = invocation;
+
+        let arguments = match payload {
+            ToolPayload::Function { arguments } => arguments,
+            _ => {
+                return Err(FunctionCallError::RespondToModel(
+                    "web_search handler received unsupported payload".to_string(),
+                ));
+            }
+        };
+        let args: WebSearchArgs = parse_arguments(&arguments)?;
+        let query = args.query.trim().to_string();
+        if query.is_empty() {
+            return Err(FunctionCallError::RespondToModel(
+                "query must not be empty".to_string(),
+            ));
+        }
+        let Some(provider) = turn.config.web_search_provider.as_ref() else {
+            return Err(FunctionCallError::RespondToModel(
+                "web_search is not configured for this session".to_string(),
+            ));
+        };
+
+        let mut item = WebSearchItem {
+            id: call_id,
+            query: query.clone(),
+            action: WebSearchAction::Search {
+                query: Some(query.clone()),
+                queries: None,
+            },
+            citations: Vec::new(),
+        };
+        session
+            .emit_turn_item_started(&turn, &TurnItem::WebSearch(item.clone()))
+            .await;
+        let count = args
+            .count
+            .unwrap_or(provider.max_results)
+            .clamp(1, web_search_provider::MAX_RESULTS);
+        let cached = session
+            .services
+            .web_cache
+            .lock()
+            .await
+            .search(provider.kind, &query, count);
+        let result = match cached {
+            Some(results) => Ok(results),
+            None => {
+                let result = web_search_provider::search(provider, &query, count).await;
+                if let Ok(results) = &result {
+                    session.services.web_cache.lock().await.insert_search(
+                        provider.kind,
+                        &query,
+                        count,
+                        results.clone(),
+                    );
+                }
+                result
+            }
+        };
+        if let Ok(results) = &result {
+            session.record_turn_sources(results).await;
+            item.citations = results.clone();
+        }
+        session
+            .emit_turn_item_completed(&turn, TurnItem::WebSearch(item))
+            .await;
+
+        let results = result.map_err(FunctionCallError::RespondToModel)?;
+        let output = json!({
+            "query": query,
+            "provider": provider.kind.label(),
+            "results": results,
+        });
+        Ok(ToolOutput::Function {
+            body: FunctionCallOutputBody::Text(output.to_string()),
+            success: Some(true),
+        })
+    }
+}
@@ -11,10 +11,12 @@ pub mod spec;
 
 use crate::exec::ExecToolCallOutput;
 use crate::truncate::TruncationPolicy;
+use crate::truncate::formatted_summarize_lines;
 use crate::truncate::formatted_truncate_text;
-use crate::truncate::truncate_text;
+use crate::truncate::summarize_lines;
 pub use router::ToolRouter;
 use serde::Serialize;
+use std::path::Path;
 
 // Telemetry preview limits: keep log events smaller than model budgets.
 pub(crate) const TELEMETRY_PREVIEW_MAX_BYTES: usize = 2 * 1024; // 2 KiB
@@ -23,14 +25,17 @@ pub(crate) const TELEMETRY_PREVIEW_TRUNCATION_NOTICE: &str =
     "[... telemetry preview truncated ...]";
 
 /// Format the combined exec output for sending back to the model.
-/// Includes exit code and duration metadata; truncates large bodies safely.
+/// Includes exit code and duration metadata; summarizes large bodies, naming
+/// `full_output` as the place the complete output was saved.
 pub fn format_exec_output_for_model_structured(
     exec_output: &ExecToolCallOutput,
     truncation_policy: TruncationPolicy,
+    full_output: Option<&Path>,
 ) -> String {
     let ExecToolCallOutput {
         exit_code,
         duration,
+        timed_out,
         ..
     } = exec_output;
 
@@ -38,6 +43,8 @@ pub fn format_exec_output_for_model_structured(
     struct ExecMetadata {
         exit_code: i32,
         duration_seconds: f32,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        timed_out: bool,
     }
 
     #[derive(Serialize)]
@@ -49,13 +56,15 @@ pub fn format_exec_output_for_model_structured(
     // round to 1 decimal place
     let duration_seconds = ((duration.as_secs_f32()) * 10.0).round() / 10.0;
 
-    let formatted_output = format_exec_output_str(exec_output, truncation_policy);
+    let content = build_content_with_timeout(exec_output);
+    let formatted_output = formatted_summarize_lines(&content, truncation_policy, full_output);
 
     let payload = ExecOutput {
         output: &formatted_output,
         metadata: ExecMetadata {
             exit_code: *exit_code,
             duration_seconds,
+            timed_out: *timed_out,
         },
     };
 
@@ -66,6 +75,7 @@ pub fn format_exec_output_for_model_structured(
 pub fn format_exec_output_for_model_freeform(
     exec_output: &ExecToolCallOutput,
     truncation_policy: TruncationPolicy,
+    full_output: Option<&Path>,
 ) -> String {
     // round to 1 decimal place
     let duration_seconds = ((exec_output.duration.as_secs_f32()) * 10.0).round() / 10.0;
@@ -74,7 +84,7 @@ pub fn format_exec_output_for_model_freeform(
 
     let total_lines = content.lines().count();
 
-    let formatted_output = truncate_text(&content, truncation_policy);
+    let formatted_output = summarize_lines(&content, truncation_policy, full_output);
 
     let mut sections = Vec::new();
 
@@ -100,6 +110,15 @@ pub fn format_exec_output_str(
     formatted_truncate_text(&content, truncation_policy)
 }
 
+/// Whether the output sent to the model will be shortened, in which case the
+/// full output is worth keeping for the user.
+pub(crate) fn exceeds_model_budget(
+    exec_output: &ExecToolCallOutput,
+    truncation_policy: TruncationPolicy,
+) -> bool {
+    exec_output.aggregated_output.text.len() > truncation_policy.byte_budget()
+}
+
 /// Extracts exec output content and prepends a timeout message if the command timed out.
 fn build_content_with_timeout(exec_output: &ExecToolCallOutput) -> String {
     if exec_output.timed_out {
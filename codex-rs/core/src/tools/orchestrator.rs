@@ -25,15 +25,23 @@ use codex_protocol::protocol::ReviewDecision;
 
 pub(crate) struct ToolOrchestrator {
     sandbox: SandboxManager,
+    approval: Option<ReviewDecision>,
 }
 
 impl ToolOrchestrator {
     pub fn new() -> Self {
         Self {
             sandbox: SandboxManager::new(),
+            approval: None,
         }
     }
 
+    /// The user's most recent answer to an approval prompt during `run`, or
+    /// `None` when the call never asked.
+    pub fn approval(&self) -> Option<&ReviewDecision> {
+        self.approval.as_ref()
+    }
+
     pub async fn run<Rq, Out, T>(
         &mut self,
         tool: &mut T,
@@ -74,6 +82,7 @@ impl ToolOrchestrator {
                 let decision = tool.start_approval_async(req, approval_ctx).await;
 
                 otel.tool_decision(otel_tn, otel_ci, &decision, otel_user.clone());
+                self.approval = Some(decision.clone());
 
                 match decision {
                     ReviewDecision::Denied | ReviewDecision::Abort => {
@@ -81,7 +90,8 @@ impl ToolOrchestrator {
                     }
                     ReviewDecision::Approved
                     | ReviewDecision::ApprovedExecpolicyAmendment { .. }
-                    | ReviewDecision::ApprovedForSession => {}
+                    | ReviewDecision::ApprovedForSession
+                    | ReviewDecision::ApprovedHunks { .. } => {}
                 }
                 already_approved = true;
             }
@@ -107,15 +117,22 @@ impl ToolOrchestrator {
         // Platform-specific flag gating is handled by SandboxManager::select_initial
         // via crate::safety::get_platform_sandbox(..).
         let use_linux_sandbox_bwrap = turn_ctx.features.enabled(Feature::UseLinuxSandboxBwrap);
+        let windows_conpty = turn_ctx.features.enabled(Feature::WindowsConptyExec);
+        if initial_sandbox == crate::exec::SandboxType::None {
+            guard_unsandboxed::<Rq, Out, T>(tool, req, tool_ctx, turn_ctx).await?;
+        }
+        let protected_paths = turn_ctx.config.protected_paths.sandbox_paths(&turn_ctx.cwd);
         let initial_attempt = SandboxAttempt {
             sandbox: initial_sandbox,
             policy: &turn_ctx.sandbox_policy,
             enforce_managed_network: has_managed_network_requirements,
             manager: &self.sandbox,
             sandbox_cwd: &turn_ctx.cwd,
+            protected_paths: &protected_paths,
             codex_linux_sandbox_exe: turn_ctx.codex_linux_sandbox_exe.as_ref(),
             use_linux_sandbox_bwrap,
             windows_sandbox_level: turn_ctx.windows_sandbox_level,
+            windows_conpty,
         };
 
         match tool.run(req, &initial_attempt, tool_ctx).await {
@@ -137,6 +154,8 @@ impl ToolOrchestrator {
                     })));
                 }
 
+                guard_unsandboxed::<Rq, Out, T>(tool, req, tool_ctx, turn_ctx).await?;
+
                 // Ask for approval before retrying with the escalated sandbox.
                 if !tool.should_bypass_approval(approval_policy, already_approved) {
                     let reason_msg = build_denial_reason_from_output(output.as_ref());
@@ -149,6 +168,7 @@ impl ToolOrchestrator {
 
                     let decision = tool.start_approval_async(req, approval_ctx).await;
                     otel.tool_decision(otel_tn, otel_ci, &decision, otel_user);
+                    self.approval = Some(decision.clone());
 
                     match decision {
                         ReviewDecision::Denied | ReviewDecision::Abort => {
@@ -156,7 +176,8 @@ impl ToolOrchestrator {
                         }
                         ReviewDecision::Approved
                         | ReviewDecision::ApprovedExecpolicyAmendment { .. }
-                        | ReviewDecision::ApprovedForSession => {}
+                        | ReviewDecision::ApprovedForSession
+                        | ReviewDecision::ApprovedHunks { .. } => {}
                     }
                 }
 
@@ -166,9 +187,11 @@ impl ToolOrchestrator {
                     enforce_managed_network: has_managed_network_requirements,
                     manager: &self.sandbox,
                     sandbox_cwd: &turn_ctx.cwd,
+                    protected_paths: &protected_paths,
                     codex_linux_sandbox_exe: None,
                     use_linux_sandbox_bwrap,
                     windows_sandbox_level: turn_ctx.windows_sandbox_level,
+                    windows_conpty,
                 };
 
                 // Second attempt.
@@ -179,6 +202,37 @@ impl ToolOrchestrator {
     }
 }
 
+/// Refuse to run a command outside the sandbox when it names a protected
+/// path, since nothing would keep it from writing there.
+async fn guard_unsandboxed<Rq, Out, T>(
+    tool: &T,
+    req: &Rq,
+    tool_ctx: &ToolCtx<'_>,
+    turn_ctx: &crate::codex::TurnContext,
+) -> Result<(), ToolError>
+where
+    T: ToolRuntime<Rq, Out>,
+{
+    let Some((command, cwd)) = tool.command(req) else {
+        return Ok(());
+    };
+    let matches = turn_ctx
+        .config
+        .protected_paths
+        .check_command(command, cwd, &turn_ctx.cwd);
+    if matches.is_empty() {
+        return Ok(());
+    }
+    let message = crate::protected_paths::block_command(
+        tool_ctx.session,
+        turn_ctx,
+        &tool_ctx.call_id,
+        matches,
+    )
+    .await;
+    Err(ToolError::Rejected(message))
+}
+
 fn build_denial_reason_from_output(_output: &ExecToolCallOutput) -> String {
     // Keep approval reason terse and stable for UX/tests, but accept the
     // output so we can evolve heuristics later without touching call sites.
@@ -3,9 +3,11 @@
 //! Assumes `apply_patch` verification/approval happened upstream. Reuses that
 //! decision to avoid re-prompting, builds the self-invocation command for
 //! `codex --codex-run-as-apply-patch`, and runs under the current
-//! `SandboxAttempt` with a minimal environment.
+//! `SandboxAttempt` with a minimal environment. When the user approves only
+//! some hunks, the patch is narrowed to them before it runs.
 use crate::CODEX_APPLY_PATCH_ARG1;
 use crate::exec::ExecToolCallOutput;
+use crate::patch_hunks;
 use crate::sandboxing::CommandSpec;
 use crate::sandboxing::SandboxPermissions;
 use crate::sandboxing::execute_env;
@@ -39,14 +41,18 @@ pub struct ApplyPatchRequest {
 }
 
 #[derive(Default)]
-pub struct ApplyPatchRuntime;
+pub struct ApplyPatchRuntime {
+    /// When the user approved only some hunks: the patch limited to them, or
+    /// why it could not be built.
+    partial_patch: Option<Result<String, String>>,
+}
 
 impl ApplyPatchRuntime {
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
 
-    fn build_command_spec(req: &ApplyPatchRequest) -> Result<CommandSpec, ToolError> {
+    fn build_command_spec(req: &ApplyPatchRequest, patch: &str) -> Result<CommandSpec, ToolError> {
         use std::env;
         let exe = if let Some(path) = &req.codex_exe {
             path.clone()
@@ -57,7 +63,7 @@ impl ApplyPatchRuntime {
         let program = exe.to_string_lossy().to_string();
         Ok(CommandSpec {
             program,
-            args: vec![CODEX_APPLY_PATCH_ARG1.to_string(), req.action.patch.clone()],
+            args: vec![CODEX_APPLY_PATCH_ARG1.to_string(), patch.to_string()],
             cwd: req.action.cwd.clone(),
             expiration: req.timeout_ms.into(),
             // Run apply_patch with a minimal environment for determinism and to avoid leaks.
@@ -104,25 +110,32 @@ impl Approvable<ApplyPatchRequest> for ApplyPatchRuntime {
         let approval_keys = self.approval_keys(req);
         let changes = req.changes.clone();
         Box::pin(async move {
-            if let Some(reason) = retry_reason {
-                let rx_approve = session
+            let decision = if let Some(reason) = retry_reason {
+                session
                     .request_patch_approval(turn, call_id, changes.clone(), Some(reason), None)
-                    .await;
-                return rx_approve.await.unwrap_or_default();
+                    .await
+            } else {
+                with_cached_approval(
+                    &session.services,
+                    "apply_patch",
+                    approval_keys,
+                    || async move {
+                        session
+                            .request_patch_approval(turn, call_id, changes, None, None)
+                            .await
+                    },
+                )
+                .await
+            };
+            self.partial_patch = None;
+            if let ReviewDecision::ApprovedHunks { hunk_ids } = &decision {
+                match patch_hunks::partial_patch(&req.action, &req.changes, hunk_ids) {
+                    Ok(None) => return ReviewDecision::Denied,
+                    Ok(Some(patch)) if patch == req.action.patch => {}
+                    result => self.partial_patch = result.transpose(),
+                }
             }
-
-            with_cached_approval(
-                &session.services,
-                "apply_patch",
-                approval_keys,
-                || async move {
-                    let rx_approve = session
-                        .request_patch_approval(turn, call_id, changes, None, None)
-                        .await;
-                    rx_approve.await.unwrap_or_default()
-                },
-            )
-            .await
+            decision
         })
     }
 
@@ -149,13 +162,27 @@ impl ToolRuntime<ApplyPatchRequest, ExecToolCallOutput> for ApplyPatchRuntime {
         attempt: &SandboxAttempt<'_>,
         ctx: &ToolCtx<'_>,
     ) -> Result<ExecToolCallOutput, ToolError> {
-        let spec = Self::build_command_spec(req)?;
+        let patch = match &self.partial_patch {
+            None => req.action.patch.as_str(),
+            Some(Ok(patch)) => patch.as_str(),
+            Some(Err(err)) => {
+                return Err(ToolError::Rejected(format!(
+                    "the user approved only some hunks of this patch, but {err}; nothing was applied"
+                )));
+            }
+        };
+        let spec = Self::build_command_spec(req, patch)?;
         let env = attempt
             .env_for(spec, None)
             .map_err(|err| ToolError::Codex(err.into()))?;
-        let out = execute_env(env, attempt.policy, Self::stdout_stream(ctx))
+        let mut out = execute_env(env, attempt.policy, Self::stdout_stream(ctx))
             .await
             .map_err(ToolError::Codex)?;
+        if self.partial_patch.is_some() && out.exit_code == 0 {
+            let note = "\nThe user approved only some hunks of this patch; the other hunks were not applied.\n";
+            out.stdout.text.push_str(note);
+            out.aggregated_output.text.push_str(note);
+        }
         Ok(out)
     }
 }
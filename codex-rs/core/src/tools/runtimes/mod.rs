@@ -6,12 +6,16 @@ small and focused and reuses the orchestrator for approvals + sandbox + retry.
 */
 use crate::exec::ExecExpiration;
 use crate::path_utils;
+use crate::powershell::extract_powershell_command;
 use crate::sandboxing::CommandSpec;
 use crate::sandboxing::SandboxPermissions;
 use crate::shell::Shell;
+use crate::shell::ShellType;
+use crate::shell_detect::detect_shell_type;
 use crate::tools::sandboxing::ToolError;
 use std::collections::HashMap;
 use std::path::Path;
+use std::path::PathBuf;
 
 pub mod apply_patch;
 pub mod shell;
@@ -102,6 +106,55 @@ pub(crate) fn maybe_wrap_shell_lc_with_snapshot(
     vec![shell_path.to_string(), "-c".to_string(), rewritten_script]
 }
 
+/// Prepends sourcing of the configured `shell.rc_files` to the script of a
+/// command produced by `Shell::derive_exec_args`, so tools set up in rc files
+/// (nvm, pyenv, ...) are on `PATH`. Missing files are skipped and output from
+/// the rc files is discarded. Anything other than a POSIX `-c`/`-lc` or a
+/// PowerShell `-Command` invocation is returned unchanged.
+///
+/// Runs before [`maybe_wrap_shell_lc_with_snapshot`] so the rc files are
+/// sourced by the shell that runs the script.
+pub(crate) fn source_rc_files(command: &[String], rc_files: &[PathBuf]) -> Vec<String> {
+    let Some(program) = command.first() else {
+        return command.to_vec();
+    };
+    if rc_files.is_empty() {
+        return command.to_vec();
+    }
+
+    match detect_shell_type(&PathBuf::from(program)) {
+        Some(ShellType::Zsh | ShellType::Bash | ShellType::Sh)
+            if command.len() >= 3 && matches!(command[1].as_str(), "-c" | "-lc") =>
+        {
+            let prelude: String = rc_files
+                .iter()
+                .map(|file| {
+                    let file = shell_single_quote(&file.to_string_lossy());
+                    format!("if [ -r '{file}' ]; then . '{file}' >/dev/null 2>&1; fi\n")
+                })
+                .collect();
+            let mut command = command.to_vec();
+            command[2] = format!("{prelude}{}", command[2]);
+            command
+        }
+        Some(ShellType::PowerShell) if extract_powershell_command(command).is_some() => {
+            let prelude: String = rc_files
+                .iter()
+                .map(|file| {
+                    let file = file.to_string_lossy().replace('\'', "''");
+                    format!("if (Test-Path -LiteralPath '{file}') {{ . '{file}' *> $null }}\n")
+                })
+                .collect();
+            let mut command = command.to_vec();
+            if let Some(script) = command.last_mut() {
+                *script = format!("{prelude}{script}");
+            }
+            command
+        }
+        _ => command.to_vec(),
+    }
+}
+
 fn shell_single_quote(input: &str) -> String {
     input.replace('\'', r#"'"'"'"#)
 }
@@ -109,10 +162,8 @@ fn shell_single_quote(input: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::shell::ShellType;
     use crate::shell_snapshot::ShellSnapshot;
     use pretty_assertions::assert_eq;
-    use std::path::PathBuf;
     use std::sync::Arc;
     use tempfile::tempdir;
     use tokio::sync::watch;
@@ -134,6 +185,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn source_rc_files_prepends_posix_sourcing() {
+        let command = vec![
+            "/bin/zsh".to_string(),
+            "-lc".to_string(),
+            "node --version".to_string(),
+        ];
+        let rc_files = vec![
+            PathBuf::from("/home/me/.zshrc"),
+            PathBuf::from("/tmp/it's.sh"),
+        ];
+
+        let rewritten = source_rc_files(&command, &rc_files);
+
+        assert_eq!(
+            rewritten,
+            vec![
+                "/bin/zsh".to_string(),
+                "-lc".to_string(),
+                "if [ -r '/home/me/.zshrc' ]; then . '/home/me/.zshrc' >/dev/null 2>&1; fi\n\
+                 if [ -r '/tmp/it'\"'\"'s.sh' ]; then . '/tmp/it'\"'\"'s.sh' >/dev/null 2>&1; fi\n\
+                 node --version"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn source_rc_files_prepends_powershell_sourcing() {
+        let command = vec![
+            "pwsh".to_string(),
+            "-NoProfile".to_string(),
+            "-Command".to_string(),
+            "node --version".to_string(),
+        ];
+        let rc_files = vec![PathBuf::from("C:/Users/me/profile.ps1")];
+
+        let rewritten = source_rc_files(&command, &rc_files);
+
+        assert_eq!(
+            rewritten[3],
+            "if (Test-Path -LiteralPath 'C:/Users/me/profile.ps1') { . 'C:/Users/me/profile.ps1' *> $null }\nnode --version"
+        );
+        assert_eq!(rewritten[..3], command[..3]);
+    }
+
+    #[test]
+    fn source_rc_files_leaves_other_commands_alone() {
+        let rc_files = vec![PathBuf::from("/home/me/.bashrc")];
+        let direct = vec!["ls".to_string(), "-la".to_string()];
+        let cmd = vec!["cmd".to_string(), "/c".to_string(), "dir".to_string()];
+
+        assert_eq!(source_rc_files(&direct, &rc_files), direct);
+        assert_eq!(source_rc_files(&cmd, &rc_files), cmd);
+        assert_eq!(
+            source_rc_files(
+                &["/bin/bash".to_string(), "-lc".to_string(), "ls".to_string()],
+                &[]
+            ),
+            vec!["/bin/bash".to_string(), "-lc".to_string(), "ls".to_string()]
+        );
+    }
+
     #[test]
     fn maybe_wrap_shell_lc_with_snapshot_bootstraps_in_user_shell() {
         let dir = tempdir().expect("create temp dir");
@@ -16,6 +16,7 @@ use codex_network_proxy::NetworkProxy;
 use codex_protocol::approvals::ExecPolicyAmendment;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::ReviewDecision;
+use codex_utils_absolute_path::AbsolutePathBuf;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -261,6 +262,13 @@ pub(crate) enum ToolError {
 }
 
 pub(crate) trait ToolRuntime<Req, Out>: Approvable<Req> + Sandboxable {
+    /// The command line and working directory of a request that runs a
+    /// command, checked against `protected_paths` before running it outside
+    /// the sandbox. Defaults to `None`.
+    fn command<'r>(&self, _req: &'r Req) -> Option<(&'r [String], &'r Path)> {
+        None
+    }
+
     async fn run(
         &mut self,
         req: &Req,
@@ -275,9 +283,11 @@ pub(crate) struct SandboxAttempt<'a> {
     pub enforce_managed_network: bool,
     pub(crate) manager: &'a SandboxManager,
     pub(crate) sandbox_cwd: &'a Path,
+    pub(crate) protected_paths: &'a [AbsolutePathBuf],
     pub codex_linux_sandbox_exe: Option<&'a std::path::PathBuf>,
     pub use_linux_sandbox_bwrap: bool,
     pub windows_sandbox_level: codex_protocol::config_types::WindowsSandboxLevel,
+    pub windows_conpty: bool,
 }
 
 impl<'a> SandboxAttempt<'a> {
@@ -294,9 +304,11 @@ impl<'a> SandboxAttempt<'a> {
                 enforce_managed_network: self.enforce_managed_network,
                 network,
                 sandbox_policy_cwd: self.sandbox_cwd,
+                protected_paths: self.protected_paths,
                 codex_linux_sandbox_exe: self.codex_linux_sandbox_exe,
                 use_linux_sandbox_bwrap: self.use_linux_sandbox_bwrap,
                 windows_sandbox_level: self.windows_sandbox_level,
+                windows_conpty: self.windows_conpty,
             })
     }
 }
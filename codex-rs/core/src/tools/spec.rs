@@ -1,8 +1,11 @@
 use crate::agent::AgentRole;
 use crate::client_common::tools::ResponsesApiTool;
 use crate::client_common::tools::ToolSpec;
+use crate::config::types::WebSearchProviderKind;
 use crate::features::Feature;
 use crate::features::Features;
+use crate::tools::handlers::DEPENDENCY_AUDIT_DEFAULT_LIMIT;
+use crate::tools::handlers::FETCH_URL_DEFAULT_MAX_CHARS;
 use crate::tools::handlers::PLAN_TOOL;
 use crate::tools::handlers::SEARCH_TOOL_BM25_DEFAULT_LIMIT;
 use crate::tools::handlers::apply_patch::create_apply_patch_freeform_tool;
@@ -29,7 +32,9 @@ pub(crate) struct ToolsConfig {
     pub shell_type: ConfigShellToolType,
     pub apply_patch_tool_type: Option<ApplyPatchToolType>,
     pub web_search_mode: Option<WebSearchMode>,
+    pub web_search_provider: Option<WebSearchProviderKind>,
     pub search_tool: bool,
+    pub dependency_audit_tool: bool,
     pub collab_tools: bool,
     pub collaboration_modes_tools: bool,
     pub request_rule_enabled: bool,
@@ -54,6 +59,7 @@ impl ToolsConfig {
         let include_collaboration_modes_tools = features.enabled(Feature::CollaborationModes);
         let request_rule_enabled = features.enabled(Feature::RequestRule);
         let include_search_tool = features.enabled(Feature::SearchTool);
+        let include_dependency_audit_tool = features.enabled(Feature::DependencyAudit);
 
         let shell_type = if !features.enabled(Feature::ShellTool) {
             ConfigShellToolType::Disabled
@@ -84,13 +90,22 @@ impl ToolsConfig {
             shell_type,
             apply_patch_tool_type,
             web_search_mode: *web_search_mode,
+            web_search_provider: None,
             search_tool: include_search_tool,
+            dependency_audit_tool: include_dependency_audit_tool,
             collab_tools: include_collab_tools,
             collaboration_modes_tools: include_collaboration_modes_tools,
             request_rule_enabled,
             experimental_supported_tools: model_info.experimental_supported_tools.clone(),
         }
     }
+
+    /// Swaps the hosted web search for the configured backend and adds
+    /// `fetch_url`; see `[web_search_provider]`.
+    pub fn with_web_search_provider(mut self, provider: Option<WebSearchProviderKind>) -> Self {
+        self.web_search_provider = provider;
+        self
+    }
 }
 
 /// Generic JSON‑Schema subset needed for our tool definitions
@@ -1861,6 +1876,116 @@ fn create_search_tool_bm25_tool() -> ToolSpec {
     })
 }
 
+fn create_dependency_audit_tool() -> ToolSpec {
+    let properties = BTreeMap::from([
+        (
+            "path".to_string(),
+            JsonSchema::String {
+                description: Some(
+                    "Lockfile (Cargo.lock, package-lock.json, poetry.lock) or directory containing \
+                     them; defaults to the working directory."
+                        .to_string(),
+                ),
+            },
+        ),
+        (
+            "check_vulnerabilities".to_string(),
+            JsonSchema::Boolean {
+                description: Some(
+                    "Look up known vulnerabilities for registry packages in OSV (defaults to true)."
+                        .to_string(),
+                ),
+            },
+        ),
+        (
+            "limit".to_string(),
+            JsonSchema::Number {
+                description: Some(format!(
+                    "Maximum number of packages listed per lockfile (defaults to {DEPENDENCY_AUDIT_DEFAULT_LIMIT}). Vulnerability lookup always covers every package."
+                )),
+            },
+        ),
+    ]);
+
+    ToolSpec::Function(ResponsesApiTool {
+        name: "dependency_audit".to_string(),
+        description: "Lists the dependencies pinned in project lockfiles and reports known \
+                      vulnerabilities from OSV, including fixed versions when available."
+            .to_string(),
+        strict: false,
+        parameters: JsonSchema::Object {
+            properties,
+            required: None,
+            additional_properties: Some(false.into()),
+        },
+    })
+}
+
+fn create_web_search_function_tool() -> ToolSpec {
+    let properties = BTreeMap::from([
+        (
+            "query".to_string(),
+            JsonSchema::String {
+                description: Some("Search query.".to_string()),
+            },
+        ),
+        (
+            "count".to_string(),
+            JsonSchema::Number {
+                description: Some(
+                    "Number of results to return (defaults to the configured max_results)."
+                        .to_string(),
+                ),
+            },
+        ),
+    ]);
+
+    ToolSpec::Function(ResponsesApiTool {
+        name: "web_search".to_string(),
+        description: "Searches the web and returns result titles, URLs, and snippets. Use \
+                      fetch_url to read a result."
+            .to_string(),
+        strict: false,
+        parameters: JsonSchema::Object {
+            properties,
+            required: Some(vec!["query".to_string()]),
+            additional_properties: Some(false.into()),
+        },
+    })
+}
+
+fn create_fetch_url_tool() -> ToolSpec {
+    let properties = BTreeMap::from([
+        (
+            "url".to_string(),
+            JsonSchema::String {
+                description: Some("http or https URL to fetch.".to_string()),
+            },
+        ),
+        (
+            "max_chars".to_string(),
+            JsonSchema::Number {
+                description: Some(format!(
+                    "Maximum characters of page text to return (defaults to {FETCH_URL_DEFAULT_MAX_CHARS})."
+                )),
+            },
+        ),
+    ]);
+
+    ToolSpec::Function(ResponsesApiTool {
+        name: "fetch_url".to_string(),
+        description: "Downloads a web page and returns its readable text with the title, final \
+                      URL, and retrieval time to cite. Subject to the session's network policy."
+            .to_string(),
+        strict: false,
+        parameters: JsonSchema::Object {
+            properties,
+            required: Some(vec!["url".to_string()]),
+            additional_properties: Some(false.into()),
+        },
+    })
+}
+
 fn create_read_file_tool() -> ToolSpec {
     let indentation_properties = BTreeMap::from([
         (
@@ -2476,7 +2601,9 @@ pub(crate) fn build_specs(
     use crate::tools::handlers::ClaudeWriteHandler;
     use crate::tools::handlers::CollabBatchHandler;
     use crate::tools::handlers::CollabHandler;
+    use crate::tools::handlers::DependencyAuditHandler;
     use crate::tools::handlers::DynamicToolHandler;
+    use crate::tools::handlers::FetchUrlHandler;
     use crate::tools::handlers::GrepFilesHandler;
     use crate::tools::handlers::ListDirHandler;
     use crate::tools::handlers::McpHandler;
@@ -2490,6 +2617,7 @@ pub(crate) fn build_specs(
     use crate::tools::handlers::TestSyncHandler;
     use crate::tools::handlers::UnifiedExecHandler;
     use crate::tools::handlers::ViewImageHandler;
+    use crate::tools::handlers::WebSearchHandler;
     use std::sync::Arc;
 
     let mut builder = ToolRegistryBuilder::new();
@@ -2588,6 +2716,12 @@ pub(crate) fn build_specs(
     builder.push_spec_with_parallel_support(create_batches_read_file_tool(), true);
     builder.register_handler("batches_read_file", batches_read_file_handler);
 
+    if config.dependency_audit_tool {
+        let dependency_audit_handler = Arc::new(DependencyAuditHandler);
+        builder.push_spec_with_parallel_support(create_dependency_audit_tool(), true);
+        builder.register_handler("dependency_audit", dependency_audit_handler);
+    }
+
     if config
         .experimental_supported_tools
         .contains(&"grep_files".to_string())
@@ -2625,7 +2759,24 @@ pub(crate) fn build_specs(
         builder.register_handler("test_sync_tool", test_sync_handler);
     }
 
+    let web_search_enabled = matches!(
+        config.web_search_mode,
+        Some(WebSearchMode::Cached | WebSearchMode::Live)
+    );
+    let local_web_search = web_search_enabled
+        && config
+            .web_search_provider
+            .is_some_and(|provider| provider != WebSearchProviderKind::Hosted);
+    if web_search_enabled && config.web_search_provider.is_some() {
+        builder.push_spec_with_parallel_support(create_fetch_url_tool(), true);
+        builder.register_handler("fetch_url", Arc::new(FetchUrlHandler));
+    }
+
     match config.web_search_mode {
+        Some(WebSearchMode::Cached | WebSearchMode::Live) if local_web_search => {
+            builder.push_spec_with_parallel_support(create_web_search_function_tool(), true);
+            builder.register_handler("web_search", Arc::new(WebSearchHandler));
+        }
         Some(WebSearchMode::Cached) => {
             builder.push_spec(ToolSpec::WebSearch {
                 external_web_access: Some(false),
@@ -3511,6 +3662,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_specs_dependency_audit_tool_gated_by_feature() {
+        let config = test_config();
+        let model_info = ModelsManager::construct_model_info_offline("gpt-5-codex", &config);
+        let mut features = Features::with_defaults();
+        let tools_config = ToolsConfig::new(&ToolsConfigParams {
+            model_info: &model_info,
+            features: &features,
+            web_search_mode: Some(WebSearchMode::Cached),
+        });
+        let (tools, _) = build_specs(&tools_config, None, &[]).build();
+        assert!(
+            !tools
+                .iter()
+                .any(|tool| tool_name(&tool.spec) == "dependency_audit")
+        );
+
+        features.enable(Feature::DependencyAudit);
+        let tools_config = ToolsConfig::new(&ToolsConfigParams {
+            model_info: &model_info,
+            features: &features,
+            web_search_mode: Some(WebSearchMode::Cached),
+        });
+        let (tools, _) = build_specs(&tools_config, None, &[]).build();
+        assert_contains_tool_names(&tools, &["dependency_audit"]);
+    }
+
     #[test]
     fn collab_waiting_tools_support_parallel_tool_calls() {
         let config = test_config();
@@ -3656,6 +3834,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn web_search_provider_replaces_hosted_search_and_adds_fetch_url() {
+        let config = test_config();
+        let model_info = ModelsManager::construct_model_info_offline("gpt-5-codex", &config);
+        let features = Features::with_defaults();
+        let params = ToolsConfigParams {
+            model_info: &model_info,
+            features: &features,
+            web_search_mode: Some(WebSearchMode::Cached),
+        };
+
+        let brave =
+            ToolsConfig::new(&params).with_web_search_provider(Some(WebSearchProviderKind::Brave));
+        let (tools, _) = build_specs(&brave, None, &[]).build();
+        assert_eq!(
+            find_tool(&tools, "web_search").spec,
+            create_web_search_function_tool()
+        );
+        assert_contains_tool_names(&tools, &["fetch_url"]);
+
+        let hosted =
+            ToolsConfig::new(&params).with_web_search_provider(Some(WebSearchProviderKind::Hosted));
+        let (tools, _) = build_specs(&hosted, None, &[]).build();
+        assert_eq!(
+            find_tool(&tools, "web_search").spec,
+            ToolSpec::WebSearch {
+                external_web_access: Some(false),
+            }
+        );
+        assert_contains_tool_names(&tools, &["fetch_url"]);
+
+        let disabled = ToolsConfig::new(&ToolsConfigParams {
+            web_search_mode: Some(WebSearchMode::Disabled),
+            ..params
+        })
+        .with_web_search_provider(Some(WebSearchProviderKind::Brave));
+        let (tools, _) = build_specs(&disabled, None, &[]).build();
+        assert!(
+            !tools
+                .iter()
+                .any(|tool| { matches!(tool_name(&tool.spec), "web_search" | "fetch_url") })
+        );
+    }
+
     #[test]
     fn test_build_specs_gpt5_codex_default() {
         let mut features = Features::with_defaults();
@@ -2,13 +2,30 @@
 //! and suffix on UTF-8 boundaries, and helpers for line/token‑based truncation
 //! used across the core crate.
 
+use std::path::Path;
+
 use codex_protocol::models::FunctionCallOutputContentItem;
 use codex_protocol::openai_models::TruncationMode;
 use codex_protocol::openai_models::TruncationPolicyConfig;
 use codex_protocol::protocol::TruncationPolicy as ProtocolTruncationPolicy;
+use codex_utils_string::take_bytes_at_char_boundary;
 
 const APPROX_BYTES_PER_TOKEN: usize = 4;
 
+/// Case-insensitive substrings that mark a line from the omitted middle of a
+/// long output as worth keeping in its summary.
+const SUMMARY_MARKER_PATTERNS: &[&str] = &[
+    "error",
+    "warning",
+    "fail",
+    "panic",
+    "exception",
+    "fatal",
+    "traceback",
+];
+const SUMMARY_MAX_MARKER_LINES: usize = 20;
+const SUMMARY_MAX_MARKER_LINE_BYTES: usize = 200;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TruncationPolicy {
     Bytes(usize),
@@ -94,6 +111,125 @@ pub(crate) fn truncate_text(content: &str, policy: TruncationPolicy) -> String {
         }
     }
 }
+/// Like [`formatted_truncate_text`], but summarizes multi-line output with
+/// [`summarize_lines`].
+pub(crate) fn formatted_summarize_lines(
+    content: &str,
+    policy: TruncationPolicy,
+    full_output: Option<&Path>,
+) -> String {
+    if content.len() <= policy.byte_budget() {
+        return content.to_string();
+    }
+    let total_lines = content.lines().count();
+    let result = summarize_lines(content, policy, full_output);
+    format!("Total output lines: {total_lines}\n\n{result}")
+}
+
+/// Summarizes output that exceeds the policy's budget on line boundaries:
+/// whole lines from the head and tail, a `[… N lines omitted …]` marker that
+/// names `full_output` when the complete text was saved, and the omitted lines
+/// that look like errors or warnings, each tagged `[line N]` so they can be
+/// found in the full output. Falls back to [`truncate_text`] when the lines
+/// are too long to keep any of them whole.
+pub(crate) fn summarize_lines(
+    content: &str,
+    policy: TruncationPolicy,
+    full_output: Option<&Path>,
+) -> String {
+    let budget = policy.byte_budget();
+    if content.len() <= budget {
+        return content.to_string();
+    }
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    if lines.len() < 3 {
+        return truncate_text(content, policy);
+    }
+
+    // Reserve room for the omitted marker, sized for the largest line numbers
+    // it could name.
+    let widest = lines.len().to_string();
+    let omitted_marker_budget = omitted_marker(&widest, &widest, &widest, full_output).len();
+    let markers = marker_lines(&lines, budget / 4);
+    let markers_len: usize = markers.iter().map(|(_, marker)| marker.len()).sum();
+    let body_budget = budget.saturating_sub(omitted_marker_budget + markers_len);
+    let (head_budget, tail_budget) = split_budget(body_budget);
+
+    let mut head_end = 0;
+    let mut used = 0;
+    while head_end < lines.len() && used + lines[head_end].len() <= head_budget {
+        used += lines[head_end].len();
+        head_end += 1;
+    }
+    let mut tail_start = lines.len();
+    used = 0;
+    while tail_start > head_end + 1 && used + lines[tail_start - 1].len() <= tail_budget {
+        used += lines[tail_start - 1].len();
+        tail_start -= 1;
+    }
+    if head_end == 0 && tail_start == lines.len() {
+        return truncate_text(content, policy);
+    }
+
+    let mut out = String::with_capacity(budget);
+    for line in &lines[..head_end] {
+        out.push_str(line);
+    }
+    out.push_str(&omitted_marker(
+        &(tail_start - head_end).to_string(),
+        &(head_end + 1).to_string(),
+        &tail_start.to_string(),
+        full_output,
+    ));
+    for (_, marker) in markers
+        .iter()
+        .filter(|(idx, _)| (head_end..tail_start).contains(idx))
+    {
+        out.push_str(marker);
+    }
+    for line in &lines[tail_start..] {
+        out.push_str(line);
+    }
+    out
+}
+
+fn omitted_marker(count: &str, first: &str, last: &str, full_output: Option<&Path>) -> String {
+    match full_output {
+        Some(path) => format!(
+            "[… {count} lines omitted (lines {first}-{last}); full output: {} …]\n",
+            path.display()
+        ),
+        None => format!("[… {count} lines omitted (lines {first}-{last}) …]\n"),
+    }
+}
+
+/// Lines matching [`SUMMARY_MARKER_PATTERNS`] with their indices, formatted
+/// as `[line N] text`, within `budget` bytes.
+fn marker_lines(lines: &[&str], budget: usize) -> Vec<(usize, String)> {
+    let mut markers = Vec::new();
+    let mut used = 0;
+    for (idx, line) in lines.iter().enumerate() {
+        if markers.len() == SUMMARY_MAX_MARKER_LINES {
+            break;
+        }
+        let lower = line.to_lowercase();
+        if !SUMMARY_MARKER_PATTERNS
+            .iter()
+            .any(|pattern| lower.contains(pattern))
+        {
+            continue;
+        }
+        let text = take_bytes_at_char_boundary(line.trim_end(), SUMMARY_MAX_MARKER_LINE_BYTES);
+        let marker = format!("[line {}] {text}\n", idx + 1);
+        if used + marker.len() > budget {
+            break;
+        }
+        used += marker.len();
+        markers.push((idx, marker));
+    }
+    markers
+}
+
 /// Globally truncate function output items to fit within the given
 /// truncation policy's budget, preserving as many text/image items as
 /// possible and appending a summary for any omitted text items.
@@ -289,7 +425,8 @@ fn assemble_truncated_output(prefix: &str, suffix: &str, marker: &str) -> String
     out
 }
 
-pub(crate) fn approx_token_count(text: &str) -> usize {
+/// Rough token count of `text`, at about four bytes per token.
+pub fn approx_token_count(text: &str) -> usize {
     let len = text.len();
     len.saturating_add(APPROX_BYTES_PER_TOKEN.saturating_sub(1)) / APPROX_BYTES_PER_TOKEN
 }
@@ -319,11 +456,13 @@ mod tests {
     use super::approx_token_count;
     use super::formatted_truncate_text;
     use super::split_string;
+    use super::summarize_lines;
     use super::truncate_function_output_items_with_policy;
     use super::truncate_text;
     use super::truncate_with_token_budget;
     use codex_protocol::models::FunctionCallOutputContentItem;
     use pretty_assertions::assert_eq;
+    use std::path::Path;
 
     #[test]
     fn split_string_works() {
@@ -537,4 +676,48 @@ mod tests {
         };
         assert!(summary_text.contains("omitted 2 text items"));
     }
+
+    #[test]
+    fn summarize_lines_keeps_whole_head_and_tail_lines() {
+        let content: String = (1..=100).map(|i| format!("{i}\n")).collect();
+
+        let summary = summarize_lines(&content, TruncationPolicy::Bytes(100), None);
+
+        assert_eq!(
+            summary,
+            "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n\
+             [… 79 lines omitted (lines 13-91) …]\n\
+             92\n93\n94\n95\n96\n97\n98\n99\n100\n"
+        );
+    }
+
+    #[test]
+    fn summarize_lines_surfaces_omitted_errors_and_full_output_path() {
+        let mut lines: Vec<String> = (1..=200).map(|i| format!("compiling crate {i}")).collect();
+        lines[99] = "error[E0308]: mismatched types".to_string();
+        let content = lines.join("\n");
+
+        let summary = summarize_lines(
+            &content,
+            TruncationPolicy::Bytes(600),
+            Some(Path::new("/tmp/out.log")),
+        );
+
+        assert!(summary.len() <= 600, "summary too long: {}", summary.len());
+        assert!(summary.starts_with("compiling crate 1\n"));
+        assert!(summary.ends_with("compiling crate 200"));
+        assert!(summary.contains("lines omitted"));
+        assert!(summary.contains("full output: /tmp/out.log …]\n"));
+        assert!(summary.contains("[line 100] error[E0308]: mismatched types\n"));
+    }
+
+    #[test]
+    fn summarize_lines_falls_back_for_single_long_line() {
+        let content = "x".repeat(500);
+
+        assert_eq!(
+            summarize_lines(&content, TruncationPolicy::Bytes(100), None),
+            truncate_text(&content, TruncationPolicy::Bytes(100))
+        );
+    }
 }
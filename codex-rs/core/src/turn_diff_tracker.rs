@@ -11,6 +11,7 @@ use sha1::digest::Output;
 use uuid::Uuid;
 
 use crate::protocol::FileChange;
+use crate::protocol::SessionFileChange;
 
 const ZERO_OID: &str = "0000000000000000000000000000000000000000";
 const DEV_NULL: &str = "/dev/null";
@@ -225,17 +226,7 @@ impl TurnDiffTracker {
     pub fn get_unified_diff(&mut self) -> Result<Option<String>> {
         let mut aggregated = String::new();
 
-        // Compute diffs per tracked internal file in a stable order by external path.
-        let mut baseline_file_names: Vec<String> =
-            self.baseline_file_info.keys().cloned().collect();
-        // Sort lexicographically by full repo-relative path to match git behavior.
-        baseline_file_names.sort_by_key(|internal| {
-            self.get_path_for_internal(internal)
-                .map(|p| self.relative_to_git_root_str(&p))
-                .unwrap_or_default()
-        });
-
-        for internal in baseline_file_names {
+        for internal in self.sorted_internal_names() {
             aggregated.push_str(self.get_file_diff(&internal).as_str());
             if !aggregated.ends_with('\n') {
                 aggregated.push('\n');
@@ -249,6 +240,77 @@ impl TurnDiffTracker {
         }
     }
 
+    /// Lists every tracked file whose contents differ from its baseline, with
+    /// the number of added and removed lines, in the same order as the
+    /// unified diff.
+    pub fn file_changes(&mut self) -> Vec<SessionFileChange> {
+        let mut changes = Vec::new();
+        for internal in self.sorted_internal_names() {
+            let diff = self.get_file_diff(&internal);
+            if diff.is_empty() {
+                continue;
+            }
+            let Some(path) = self.get_path_for_internal(&internal) else {
+                continue;
+            };
+            let mut added_lines = 0;
+            let mut removed_lines = 0;
+            for line in diff.lines() {
+                if line.starts_with('+') && !line.starts_with("+++") {
+                    added_lines += 1;
+                } else if line.starts_with('-') && !line.starts_with("---") {
+                    removed_lines += 1;
+                }
+            }
+            changes.push(SessionFileChange {
+                path,
+                added_lines,
+                removed_lines,
+            });
+        }
+        changes
+    }
+
+    /// Restores the baseline snapshot for the file currently at `path` and
+    /// stops tracking it. Files added since the baseline are removed, and a
+    /// moved file is put back at its original location.
+    pub fn revert_path(&mut self, path: &Path) -> Result<()> {
+        let internal = self
+            .external_to_temp_name
+            .get(path)
+            .cloned()
+            .ok_or_else(|| anyhow!("no tracked changes for {}", path.display()))?;
+        let baseline = self
+            .baseline_file_info
+            .get(&internal)
+            .ok_or_else(|| anyhow!("no baseline recorded for {}", path.display()))?;
+
+        if fs::symlink_metadata(path).is_ok() {
+            fs::remove_file(path)
+                .with_context(|| format!("failed to remove {}", path.display()))?;
+        }
+        if baseline.oid != ZERO_OID {
+            restore_baseline(baseline)?;
+        }
+
+        self.external_to_temp_name.remove(path);
+        self.temp_name_to_current_path.remove(&internal);
+        self.baseline_file_info.remove(&internal);
+        Ok(())
+    }
+
+    /// Internal filenames sorted lexicographically by repo-relative path to
+    /// match git behavior.
+    fn sorted_internal_names(&mut self) -> Vec<String> {
+        let mut names: Vec<String> = self.baseline_file_info.keys().cloned().collect();
+        names.sort_by_key(|internal| {
+            self.get_path_for_internal(internal)
+                .map(|p| self.relative_to_git_root_str(&p))
+                .unwrap_or_default()
+        });
+        names
+    }
+
     fn get_file_diff(&mut self, internal_file_name: &str) -> String {
         let mut aggregated = String::new();
 
@@ -368,6 +430,37 @@ impl TurnDiffTracker {
     }
 }
 
+fn restore_baseline(baseline: &BaselineFileInfo) -> Result<()> {
+    let path = &baseline.path;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    if fs::symlink_metadata(path).is_ok() {
+        fs::remove_file(path).with_context(|| format!("failed to remove {}", path.display()))?;
+    }
+
+    match baseline.mode {
+        #[cfg(unix)]
+        FileMode::Symlink => {
+            use std::os::unix::ffi::OsStrExt;
+            let target = std::ffi::OsStr::from_bytes(&baseline.content);
+            std::os::unix::fs::symlink(target, path)
+                .with_context(|| format!("failed to restore symlink {}", path.display()))?;
+        }
+        _ => {
+            fs::write(path, &baseline.content)
+                .with_context(|| format!("failed to restore {}", path.display()))?;
+            #[cfg(unix)]
+            if baseline.mode == FileMode::Executable {
+                use std::os::unix::fs::PermissionsExt;
+                fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Compute the Git SHA-1 blob object ID for the given content (bytes).
 fn git_blob_sha1_hex_bytes(data: &[u8]) -> Output<sha1::Sha1> {
     // Git blob hash is sha1 of: "blob <len>\0<data>"
@@ -893,4 +986,75 @@ index {ZERO_OID}..{right_oid}
         };
         assert_eq!(combined, expected_combined);
     }
+
+    #[test]
+    fn file_changes_count_lines_and_revert_restores_baseline() {
+        let mut acc = TurnDiffTracker::new();
+        let dir = tempdir().unwrap();
+        let edited = dir.path().join("edited.txt");
+        let added = dir.path().join("added.txt");
+        let moved_from = dir.path().join("old.txt");
+        let moved_to = dir.path().join("new.txt");
+        fs::write(&edited, "one\ntwo\n").unwrap();
+        fs::write(&moved_from, "keep\n").unwrap();
+
+        acc.on_patch_begin(&HashMap::from([
+            (
+                edited.clone(),
+                FileChange::Update {
+                    unified_diff: String::new(),
+                    move_path: None,
+                },
+            ),
+            (
+                added.clone(),
+                FileChange::Add {
+                    content: "new\n".to_string(),
+                },
+            ),
+            (
+                moved_from.clone(),
+                FileChange::Update {
+                    unified_diff: String::new(),
+                    move_path: Some(moved_to.clone()),
+                },
+            ),
+        ]));
+        fs::write(&edited, "one\n2\n3\n").unwrap();
+        fs::write(&added, "new\n").unwrap();
+        fs::rename(&moved_from, &moved_to).unwrap();
+        fs::write(&moved_to, "keep\nmore\n").unwrap();
+
+        assert_eq!(
+            acc.file_changes(),
+            vec![
+                SessionFileChange {
+                    path: added.clone(),
+                    added_lines: 1,
+                    removed_lines: 0,
+                },
+                SessionFileChange {
+                    path: edited.clone(),
+                    added_lines: 2,
+                    removed_lines: 1,
+                },
+                SessionFileChange {
+                    path: moved_to.clone(),
+                    added_lines: 1,
+                    removed_lines: 0,
+                },
+            ]
+        );
+
+        acc.revert_path(&edited).unwrap();
+        acc.revert_path(&added).unwrap();
+        acc.revert_path(&moved_to).unwrap();
+
+        assert_eq!(fs::read_to_string(&edited).unwrap(), "one\ntwo\n");
+        assert!(!added.exists());
+        assert!(!moved_to.exists());
+        assert_eq!(fs::read_to_string(&moved_from).unwrap(), "keep\n");
+        assert_eq!(acc.file_changes(), Vec::new());
+        assert!(acc.revert_path(&edited).is_err());
+    }
 }
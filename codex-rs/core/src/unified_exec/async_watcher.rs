@@ -16,6 +16,7 @@ use crate::exec::MAX_EXEC_OUTPUT_DELTAS_PER_CALL;
 use crate::exec::StreamOutput;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecCommandOutputDeltaEvent;
+use crate::protocol::ExecCommandProvenance;
 use crate::protocol::ExecCommandSource;
 use crate::protocol::ExecOutputStream;
 use crate::tools::events::ToolEmitter;
@@ -113,6 +114,7 @@ pub(crate) fn spawn_exit_watcher(
     process_id: String,
     transcript: Arc<Mutex<HeadTailBuffer>>,
     started_at: Instant,
+    provenance: ExecCommandProvenance,
 ) {
     let exit_token = process.cancellation_token();
     let output_drained = process.output_drained_notify();
@@ -134,6 +136,7 @@ pub(crate) fn spawn_exit_watcher(
             String::new(),
             exit_code,
             duration,
+            provenance,
         )
         .await;
     });
@@ -186,6 +189,7 @@ pub(crate) async fn emit_exec_end_for_unified_exec(
     fallback_output: String,
     exit_code: i32,
     duration: Duration,
+    provenance: ExecCommandProvenance,
 ) {
     let aggregated_output = resolve_aggregated_output(&transcript, fallback_output).await;
     let output = ExecToolCallOutput {
@@ -202,7 +206,8 @@ pub(crate) async fn emit_exec_end_for_unified_exec(
         cwd,
         ExecCommandSource::UnifiedExecStartup,
         process_id,
-    );
+    )
+    .with_provenance(provenance);
     emitter
         .emit(event_ctx, ToolEventStage::Success(output))
         .await;
@@ -14,6 +14,8 @@ use tokio_util::sync::CancellationToken;
 
 use crate::exec_env::create_env;
 use crate::exec_policy::ExecApprovalRequest;
+use crate::protocol::ExecCommandOrigin;
+use crate::protocol::ExecCommandProvenance;
 use crate::protocol::ExecCommandSource;
 use crate::sandboxing::ExecRequest;
 use crate::tools::events::ToolEmitter;
@@ -156,8 +158,8 @@ impl UnifiedExecProcessManager {
             .open_session_with_sandbox(&request, cwd.clone(), context)
             .await;
 
-        let process = match process {
-            Ok(process) => Arc::new(process),
+        let (process, provenance) = match process {
+            Ok((process, provenance)) => (Arc::new(process), provenance),
             Err(err) => {
                 self.release_process_id(&request.process_id).await;
                 return Err(err);
@@ -229,6 +231,7 @@ impl UnifiedExecProcessManager {
                 output.clone(),
                 exit,
                 wall_time,
+                provenance,
             )
             .await;
 
@@ -248,6 +251,7 @@ impl UnifiedExecProcessManager {
                 process_id,
                 request.tty,
                 Arc::clone(&transcript),
+                provenance,
             )
             .await;
         };
@@ -446,6 +450,7 @@ impl UnifiedExecProcessManager {
         process_id: String,
         tty: bool,
         transcript: Arc<tokio::sync::Mutex<HeadTailBuffer>>,
+        provenance: ExecCommandProvenance,
     ) {
         let entry = ProcessEntry {
             process: Arc::clone(&process),
@@ -482,6 +487,7 @@ impl UnifiedExecProcessManager {
             process_id,
             transcript,
             started_at,
+            provenance,
         );
     }
 
@@ -524,7 +530,7 @@ impl UnifiedExecProcessManager {
         request: &ExecCommandRequest,
         cwd: PathBuf,
         context: &UnifiedExecContext,
-    ) -> Result<UnifiedExecProcess, UnifiedExecError> {
+    ) -> Result<(UnifiedExecProcess, ExecCommandProvenance), UnifiedExecError> {
         let env = apply_unified_exec_env(create_env(
             &context.turn.shell_environment_policy,
             Some(context.session.conversation_id),
@@ -559,7 +565,7 @@ impl UnifiedExecProcessManager {
             call_id: context.call_id.clone(),
             tool_name: "exec_command".to_string(),
         };
-        orchestrator
+        let process = orchestrator
             .run(
                 &mut runtime,
                 &req,
@@ -568,7 +574,17 @@ impl UnifiedExecProcessManager {
                 context.turn.approval_policy,
             )
             .await
-            .map_err(|e| UnifiedExecError::create_process(format!("{e:?}")))
+            .map_err(|e| UnifiedExecError::create_process(format!("{e:?}")))?;
+        let provenance = ExecCommandProvenance {
+            origin: ExecCommandOrigin::Model,
+            policy_rule: context
+                .session
+                .services
+                .exec_policy
+                .matched_rule_for_command(&request.command),
+            approval: orchestrator.approval().cloned(),
+        };
+        Ok((process, provenance))
     }
 
     pub(super) async fn collect_output_until_deadline(
@@ -0,0 +1,138 @@
+//! On-disk copy of the account's last `/usage` snapshot: plan type, credits,
+//! and rate-limit windows.
+//!
+//! The TUI writes `$CODEX_HOME/usage_cache.json` after every successful fetch
+//! and reads it at startup, so the status line and `/status` have something to
+//! show before the network answers, or at all when it never does (`--offline`).
+//! An entry younger than [`USAGE_CACHE_TTL`] is used instead of fetching.
+
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use codex_protocol::protocol::RateLimitSnapshot;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::path_utils::write_atomically;
+
+pub const USAGE_CACHE_FILE: &str = "usage_cache.json";
+
+/// How long a cached snapshot stands in for a fresh fetch.
+pub const USAGE_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Snapshots older than this are not shown even when offline.
+const USAGE_CACHE_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedUsage {
+    pub fetched_at: DateTime<Utc>,
+    /// ChatGPT account the snapshot belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    pub snapshot: RateLimitSnapshot,
+}
+
+impl CachedUsage {
+    pub fn is_fresh(&self) -> bool {
+        age_within(self.fetched_at, USAGE_CACHE_TTL)
+    }
+}
+
+pub fn usage_cache_path(codex_home: &Path) -> PathBuf {
+    codex_home.join(USAGE_CACHE_FILE)
+}
+
+/// The cached snapshot for `account_id`, or `None` when there is none, it
+/// belongs to another account, or it is too old to be useful.
+pub fn load_cached_usage(codex_home: &Path, account_id: Option<&str>) -> Option<CachedUsage> {
+    let contents = std::fs::read_to_string(usage_cache_path(codex_home)).ok()?;
+    let cached: CachedUsage = match serde_json::from_str(&contents) {
+        Ok(cached) => cached,
+        Err(err) => {
+            tracing::warn!("ignoring unreadable usage cache: {err}");
+            return None;
+        }
+    };
+    if cached.account_id.as_deref() != account_id
+        || !age_within(cached.fetched_at, USAGE_CACHE_MAX_AGE)
+    {
+        return None;
+    }
+    Some(cached)
+}
+
+pub fn persist_usage(
+    codex_home: &Path,
+    account_id: Option<String>,
+    snapshot: &RateLimitSnapshot,
+) -> io::Result<()> {
+    let cached = CachedUsage {
+        fetched_at: Utc::now(),
+        account_id,
+        snapshot: snapshot.clone(),
+    };
+    let contents = serde_json::to_string_pretty(&cached).map_err(io::Error::other)?;
+    write_atomically(&usage_cache_path(codex_home), &contents)
+}
+
+fn age_within(fetched_at: DateTime<Utc>, max_age: Duration) -> bool {
+    let Ok(max_age) = chrono::Duration::from_std(max_age) else {
+        return false;
+    };
+    Utc::now().signed_duration_since(fetched_at) <= max_age
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::account::PlanType;
+    use codex_protocol::protocol::CreditsSnapshot;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    fn snapshot() -> RateLimitSnapshot {
+        RateLimitSnapshot {
+            primary: None,
+            secondary: None,
+            credits: Some(CreditsSnapshot {
+                has_credits: true,
+                unlimited: false,
+                balance: Some("12.50".to_string()),
+            }),
+            plan_type: Some(PlanType::Plus),
+        }
+    }
+
+    #[test]
+    fn round_trips_for_the_same_account_only() {
+        let codex_home = tempdir().expect("tempdir");
+        persist_usage(codex_home.path(), Some("acct-1".to_string()), &snapshot())
+            .expect("persist usage");
+
+        let cached = load_cached_usage(codex_home.path(), Some("acct-1")).expect("cached usage");
+        assert_eq!(cached.snapshot, snapshot());
+        assert!(cached.is_fresh());
+        assert_eq!(load_cached_usage(codex_home.path(), Some("acct-2")), None);
+    }
+
+    #[test]
+    fn expired_snapshots_are_dropped() {
+        let codex_home = tempdir().expect("tempdir");
+        let cached = CachedUsage {
+            fetched_at: Utc::now() - chrono::Duration::days(8),
+            account_id: None,
+            snapshot: snapshot(),
+        };
+        std::fs::write(
+            usage_cache_path(codex_home.path()),
+            serde_json::to_string(&cached).expect("serialize"),
+        )
+        .expect("write cache");
+
+        assert_eq!(load_cached_usage(codex_home.path(), None), None);
+    }
+}
@@ -0,0 +1,381 @@
+//! Anonymized usage records for organizations running Codex headless.
+//!
+//! Enabled by `[reporting] endpoint`. Every finished session becomes one
+//! [`UsageRecord`] that is appended to a spool file under `CODEX_HOME` before
+//! anything is sent, so records survive being offline or the process exiting.
+//! A background task uploads the spool in batches every `flush_interval`.
+//! Records carry counts and model names only: no prompts, paths, or account
+//! details. This is separate from `[analytics]`, which never sees them.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::DateTime;
+use chrono::SecondsFormat;
+use chrono::Timelike;
+use chrono::Utc;
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::TokenUsage;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use crate::codex_thread::ThreadConfigSnapshot;
+use crate::config::Config;
+use crate::config::types::ReportingConfig;
+use crate::default_client::create_client;
+
+/// Version of the [`UsageRecord`] layout; bumped on any field change.
+pub const USAGE_RECORD_SCHEMA_VERSION: u32 = 1;
+
+const REPORTING_SUBDIR: &str = "reporting";
+const SPOOL_FILENAME: &str = "spool.jsonl";
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+static SHARED_REPORTER: OnceLock<Arc<UsageReporter>> = OnceLock::new();
+
+/// One finished session. Unknown fields are rejected so the spool and the
+/// receiving endpoint agree on exactly this shape.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UsageRecord {
+    pub schema_version: u32,
+    /// SHA-256 of the thread id: groups records without revealing the id.
+    pub session: String,
+    /// Surface that ran the session, e.g. `exec` or `vscode`.
+    pub source: String,
+    pub deployment: Option<String>,
+    pub model: String,
+    pub model_provider: String,
+    pub cli_version: String,
+    /// Session start, truncated to the minute.
+    pub started_at: String,
+    pub duration_ms: u64,
+    pub turns: u64,
+    pub input_tokens: i64,
+    pub cached_input_tokens: i64,
+    pub output_tokens: i64,
+    pub reasoning_output_tokens: i64,
+}
+
+#[derive(Serialize)]
+struct UsageBatch<'a> {
+    schema_version: u32,
+    records: &'a [UsageRecord],
+}
+
+pub(crate) struct UsageReporter {
+    config: ReportingConfig,
+    spool_dir: PathBuf,
+    upload_lock: Mutex<()>,
+}
+
+/// Per-session handle that remembers when the session started.
+pub(crate) struct SessionUsageReporter {
+    reporter: Arc<UsageReporter>,
+    started_at: DateTime<Utc>,
+    started: Instant,
+}
+
+impl UsageReporter {
+    fn new(config: ReportingConfig, codex_home: &Path) -> Self {
+        Self {
+            config,
+            spool_dir: codex_home.join(REPORTING_SUBDIR),
+            upload_lock: Mutex::new(()),
+        }
+    }
+
+    /// Returns the process-wide reporter, starting its upload loop on first
+    /// use. The first session's `[reporting]` settings apply to the process.
+    pub(crate) fn for_session(config: &Config) -> Option<SessionUsageReporter> {
+        let reporting = config.reporting.clone()?;
+        let reporter = SHARED_REPORTER
+            .get_or_init(|| {
+                let reporter = Arc::new(Self::new(reporting, &config.codex_home));
+                reporter.spawn_flush_loop();
+                reporter
+            })
+            .clone();
+        Some(SessionUsageReporter {
+            reporter,
+            started_at: Utc::now(),
+            started: Instant::now(),
+        })
+    }
+
+    fn spawn_flush_loop(self: &Arc<Self>) {
+        let reporter = Arc::clone(self);
+        tokio::spawn(async move {
+            // The first tick fires immediately and picks up records spooled
+            // by earlier runs.
+            let mut interval = tokio::time::interval(reporter.config.flush_interval);
+            loop {
+                interval.tick().await;
+                if let Err(err) = reporter.flush().await {
+                    warn!("failed to upload usage records: {err}");
+                }
+            }
+        });
+    }
+
+    fn spool_path(&self) -> PathBuf {
+        self.spool_dir.join(SPOOL_FILENAME)
+    }
+
+    async fn spool(&self, records: &[UsageRecord]) -> std::io::Result<()> {
+        tokio::fs::create_dir_all(&self.spool_dir).await?;
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.spool_path())
+            .await?;
+        let mut lines = String::new();
+        for record in records {
+            lines.push_str(&serde_json::to_string(record).map_err(std::io::Error::other)?);
+            lines.push('\n');
+        }
+        file.write_all(lines.as_bytes()).await?;
+        file.flush().await
+    }
+
+    /// Uploads everything spooled so far. Batches that fail to upload go
+    /// back to the spool for the next attempt.
+    pub(crate) async fn flush(&self) -> std::io::Result<()> {
+        let _guard = self.upload_lock.lock().await;
+        // Claim the current spool so records appended meanwhile, or by
+        // another process sharing CODEX_HOME, are not sent twice.
+        let claimed = self
+            .spool_dir
+            .join(format!("sending-{}.jsonl", uuid::Uuid::new_v4()));
+        match tokio::fs::rename(self.spool_path(), &claimed).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        }
+
+        let contents = tokio::fs::read_to_string(&claimed).await?;
+        let records: Vec<UsageRecord> = contents
+            .lines()
+            .filter_map(|line| match serde_json::from_str(line) {
+                Ok(record) => Some(record),
+                Err(err) => {
+                    warn!("dropping malformed spooled usage record: {err}");
+                    None
+                }
+            })
+            .collect();
+
+        let mut sent = 0;
+        for batch in records.chunks(self.config.max_batch_size) {
+            if let Err(err) = self.upload(batch).await {
+                warn!("usage reporting endpoint unavailable, keeping records spooled: {err}");
+                break;
+            }
+            sent += batch.len();
+        }
+        if sent < records.len() {
+            self.spool(&records[sent..]).await?;
+        }
+        tokio::fs::remove_file(&claimed).await
+    }
+
+    async fn upload(&self, records: &[UsageRecord]) -> Result<(), String> {
+        let mut request = create_client()
+            .post(&self.config.endpoint)
+            .timeout(UPLOAD_TIMEOUT)
+            .header("Content-Type", "application/json")
+            .json(&UsageBatch {
+                schema_version: USAGE_RECORD_SCHEMA_VERSION,
+                records,
+            });
+        if let Some(token) = self
+            .config
+            .bearer_token_env_var
+            .as_deref()
+            .and_then(|name| std::env::var(name).ok())
+        {
+            request = request.bearer_auth(token);
+        }
+        match request.send().await {
+            Ok(response) if response.status().is_success() => Ok(()),
+            Ok(response) => Err(format!("endpoint returned {}", response.status())),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
+
+impl SessionUsageReporter {
+    /// Spools the record for a finished session and tries to upload it right
+    /// away, since short-lived `codex exec` runs may exit before the next tick.
+    pub(crate) async fn finish(
+        &self,
+        thread_id: ThreadId,
+        snapshot: &ThreadConfigSnapshot,
+        turns: usize,
+        usage: Option<&TokenUsage>,
+    ) {
+        let record = build_record(
+            &self.reporter.config,
+            thread_id,
+            snapshot,
+            self.started_at,
+            self.started.elapsed(),
+            turns,
+            usage,
+        );
+        if let Err(err) = self.reporter.spool(&[record]).await {
+            warn!("failed to spool usage record: {err}");
+            return;
+        }
+        if let Err(err) = self.reporter.flush().await {
+            warn!("failed to upload usage records: {err}");
+        }
+    }
+}
+
+fn build_record(
+    config: &ReportingConfig,
+    thread_id: ThreadId,
+    snapshot: &ThreadConfigSnapshot,
+    started_at: DateTime<Utc>,
+    elapsed: Duration,
+    turns: usize,
+    usage: Option<&TokenUsage>,
+) -> UsageRecord {
+    let usage = usage.cloned().unwrap_or_default();
+    let started_at = started_at
+        .with_second(0)
+        .and_then(|time| time.with_nanosecond(0))
+        .unwrap_or(started_at);
+    UsageRecord {
+        schema_version: USAGE_RECORD_SCHEMA_VERSION,
+        session: format!("{:x}", Sha256::digest(thread_id.to_string().as_bytes())),
+        source: snapshot.session_source.to_string(),
+        deployment: config.deployment.clone(),
+        model: snapshot.model.clone(),
+        model_provider: snapshot.model_provider_id.clone(),
+        cli_version: env!("CARGO_PKG_VERSION").to_string(),
+        started_at: started_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+        duration_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+        turns: u64::try_from(turns).unwrap_or(u64::MAX),
+        input_tokens: usage.input_tokens,
+        cached_input_tokens: usage.cached_input_tokens,
+        output_tokens: usage.output_tokens,
+        reasoning_output_tokens: usage.reasoning_output_tokens,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::AskForApproval;
+    use codex_protocol::protocol::SandboxPolicy;
+    use codex_protocol::protocol::SessionSource;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+    use wiremock::matchers::method;
+
+    fn reporting_config(endpoint: String) -> ReportingConfig {
+        ReportingConfig {
+            endpoint,
+            flush_interval: Duration::from_secs(300),
+            max_batch_size: 2,
+            bearer_token_env_var: None,
+            deployment: Some("ci".to_string()),
+        }
+    }
+
+    fn record(config: &ReportingConfig) -> UsageRecord {
+        let snapshot = ThreadConfigSnapshot {
+            model: "gpt-5".to_string(),
+            model_provider_id: "openai".to_string(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::ReadOnly,
+            cwd: PathBuf::from("/secret/project"),
+            reasoning_effort: None,
+            personality: None,
+            session_source: SessionSource::Exec,
+        };
+        let usage = TokenUsage {
+            input_tokens: 120,
+            cached_input_tokens: 20,
+            output_tokens: 30,
+            reasoning_output_tokens: 5,
+            total_tokens: 150,
+        };
+        build_record(
+            config,
+            ThreadId::new(),
+            &snapshot,
+            Utc::now(),
+            Duration::from_millis(1500),
+            3,
+            Some(&usage),
+        )
+    }
+
+    #[test]
+    fn record_is_anonymized() {
+        let config = reporting_config("http://localhost".to_string());
+        let record = record(&config);
+        let json = serde_json::to_string(&record).expect("serialize");
+
+        assert_eq!(record.session.len(), 64);
+        assert_eq!(record.source, "exec");
+        assert_eq!(record.turns, 3);
+        assert_eq!(record.duration_ms, 1500);
+        assert!(record.started_at.ends_with(":00Z"));
+        assert!(!json.contains("/secret/project"));
+    }
+
+    #[tokio::test]
+    async fn flush_uploads_in_batches_and_keeps_failures_spooled() {
+        let codex_home = TempDir::new().expect("tempdir");
+        let server = MockServer::start().await;
+        let reporter = UsageReporter::new(reporting_config(server.uri()), codex_home.path());
+        let records: Vec<UsageRecord> = (0..3).map(|_| record(&reporter.config)).collect();
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        reporter.spool(&records).await.expect("spool");
+        reporter.flush().await.expect("flush while offline");
+        let spooled = tokio::fs::read_to_string(reporter.spool_path())
+            .await
+            .expect("spool kept");
+        assert_eq!(spooled.lines().count(), 3);
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        reporter.flush().await.expect("flush");
+        assert!(!reporter.spool_path().exists());
+
+        let requests = server.received_requests().await.expect("requests");
+        let batch_sizes: Vec<usize> = requests
+            .iter()
+            .skip(1)
+            .map(|request| {
+                let body: serde_json::Value =
+                    serde_json::from_slice(&request.body).expect("json body");
+                body["records"].as_array().map_or(0, Vec::len)
+            })
+            .collect();
+        assert_eq!(batch_sizes, vec![2, 1]);
+    }
+}
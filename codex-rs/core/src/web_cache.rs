@@ -0,0 +1,213 @@
+//! Session-scoped cache for the local `web_search` and `fetch_url` tools.
+//!
+//! Models often repeat a query or re-read a page within a session; serving
+//! those from memory keeps the turn fast and avoids spending search API quota
+//! twice. Entries expire after [`CACHE_TTL`] so a long session still sees
+//! pages that changed.
+
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_protocol::items::WebCitation;
+use url::Url;
+
+use crate::config::types::WebSearchProviderKind;
+
+const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+const MAX_ENTRIES: usize = 64;
+
+/// Readable text of a page fetched by `fetch_url`. `url` is the final URL
+/// after redirects.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FetchedPage {
+    pub(crate) url: String,
+    pub(crate) title: Option<String>,
+    pub(crate) text: String,
+}
+
+#[derive(Debug, Clone)]
+struct SearchEntry {
+    /// Result count the backend was asked for; smaller requests can reuse it.
+    requested: usize,
+    results: Vec<WebCitation>,
+}
+
+#[derive(Default)]
+pub(crate) struct WebCache {
+    searches: TimedCache<SearchEntry>,
+    pages: TimedCache<FetchedPage>,
+}
+
+impl WebCache {
+    /// Cached results for `query`, if an earlier search asked for at least
+    /// `count` results.
+    pub(crate) fn search(
+        &mut self,
+        provider: WebSearchProviderKind,
+        query: &str,
+        count: usize,
+    ) -> Option<Vec<WebCitation>> {
+        let entry = self
+            .searches
+            .get(&search_key(provider, query), Instant::now())?;
+        (entry.requested >= count).then(|| entry.results.into_iter().take(count).collect())
+    }
+
+    pub(crate) fn insert_search(
+        &mut self,
+        provider: WebSearchProviderKind,
+        query: &str,
+        count: usize,
+        results: Vec<WebCitation>,
+    ) {
+        self.searches.insert(
+            search_key(provider, query),
+            SearchEntry {
+                requested: count,
+                results,
+            },
+            Instant::now(),
+        );
+    }
+
+    pub(crate) fn page(&mut self, url: &Url) -> Option<FetchedPage> {
+        self.pages.get(&page_key(url), Instant::now())
+    }
+
+    pub(crate) fn insert_page(&mut self, url: &Url, page: FetchedPage) {
+        self.pages.insert(page_key(url), page, Instant::now());
+    }
+}
+
+/// Queries that differ only in case or spacing share an entry.
+fn search_key(provider: WebSearchProviderKind, query: &str) -> String {
+    let query = query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    format!("{}\n{query}", provider.label())
+}
+
+/// The fragment never reaches the server, so `#section` links share the page.
+fn page_key(url: &Url) -> String {
+    let mut url = url.clone();
+    url.set_fragment(None);
+    url.to_string()
+}
+
+struct TimedCache<V> {
+    entries: HashMap<String, (Instant, V)>,
+}
+
+impl<V> Default for TimedCache<V> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<V: Clone> TimedCache<V> {
+    fn get(&mut self, key: &str, now: Instant) -> Option<V> {
+        let (stored_at, value) = self.entries.get(key)?;
+        if now.saturating_duration_since(*stored_at) < CACHE_TTL {
+            return Some(value.clone());
+        }
+        self.entries.remove(key);
+        None
+    }
+
+    fn insert(&mut self, key: String, value: V, now: Instant) {
+        if self.entries.len() >= MAX_ENTRIES
+            && !self.entries.contains_key(&key)
+            && let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (stored_at, _))| *stored_at)
+                .map(|(key, _)| key.clone())
+        {
+            self.entries.remove(&oldest);
+        }
+        self.entries.insert(key, (now, value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn citation(url: &str) -> WebCitation {
+        WebCitation {
+            url: url.to_string(),
+            title: None,
+            snippet: None,
+        }
+    }
+
+    #[test]
+    fn search_hits_reuse_larger_result_sets_only() {
+        let mut cache = WebCache::default();
+        cache.insert_search(
+            WebSearchProviderKind::Brave,
+            "Tokio  runtime",
+            2,
+            vec![citation("https://tokio.rs"), citation("https://docs.rs")],
+        );
+
+        assert_eq!(
+            cache.search(WebSearchProviderKind::Brave, "tokio runtime", 1),
+            Some(vec![citation("https://tokio.rs")])
+        );
+        assert_eq!(
+            cache.search(WebSearchProviderKind::Brave, "tokio runtime", 5),
+            None
+        );
+        assert_eq!(
+            cache.search(WebSearchProviderKind::Bing, "tokio runtime", 1),
+            None
+        );
+    }
+
+    #[test]
+    fn entries_expire_and_oldest_is_evicted_when_full() {
+        let start = Instant::now();
+        let mut cache: TimedCache<usize> = TimedCache::default();
+        for index in 0..MAX_ENTRIES {
+            cache.insert(
+                index.to_string(),
+                index,
+                start + Duration::from_secs(index as u64),
+            );
+        }
+        cache.insert("new".to_string(), 0, start + Duration::from_secs(100));
+
+        assert_eq!(cache.get("0", start + Duration::from_secs(100)), None);
+        assert_eq!(cache.get("1", start + Duration::from_secs(100)), Some(1));
+        assert_eq!(
+            cache.get("1", start + CACHE_TTL + Duration::from_secs(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn page_keys_ignore_fragments() {
+        let mut cache = WebCache::default();
+        let page = FetchedPage {
+            url: "https://docs.rs/tokio".to_string(),
+            title: None,
+            text: "tokio".to_string(),
+        };
+        cache.insert_page(
+            &Url::parse("https://docs.rs/tokio#runtime").expect("url"),
+            page.clone(),
+        );
+
+        assert_eq!(
+            cache.page(&Url::parse("https://docs.rs/tokio").expect("url")),
+            Some(page)
+        );
+    }
+}
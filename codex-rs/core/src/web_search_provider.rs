@@ -0,0 +1,249 @@
+//! Search backends behind the `web_search` function tool.
+//!
+//! When `[web_search_provider]` names Brave, Bing, or SearxNG, the model gets a
+//! local `web_search` tool instead of the model provider's hosted search. This
+//! module builds the request for each backend and normalizes the responses
+//! into [`WebCitation`]s.
+
+use std::time::Duration;
+
+use codex_protocol::items::WebCitation;
+use serde_json::Value as JsonValue;
+use url::Url;
+
+use crate::config::types::WebSearchProviderConfig;
+use crate::config::types::WebSearchProviderKind;
+use crate::default_client::create_client;
+
+const SEARCH_TIMEOUT: Duration = Duration::from_secs(20);
+const BRAVE_ENDPOINT: &str = "https://api.search.brave.com/res/v1/web/search";
+const BING_ENDPOINT: &str = "https://api.bing.microsoft.com/v7.0/search";
+/// Upper bound on `count`, whatever the model asks for.
+pub(crate) const MAX_RESULTS: usize = 20;
+
+/// Runs `query` against the configured backend and returns at most `count`
+/// results.
+pub(crate) async fn search(
+    provider: &WebSearchProviderConfig,
+    query: &str,
+    count: usize,
+) -> Result<Vec<WebCitation>, String> {
+    let count = count.clamp(1, MAX_RESULTS);
+    let api_key = match provider.api_key_env.as_deref() {
+        Some(name) => Some(
+            std::env::var(name)
+                .ok()
+                .filter(|key| !key.trim().is_empty())
+                .ok_or_else(|| {
+                    format!(
+                        "{} search needs an API key in the `{name}` environment variable",
+                        provider.kind.label()
+                    )
+                })?,
+        ),
+        None => None,
+    };
+    let url = request_url(provider, query, count)?;
+
+    let mut request = create_client().get(url.as_str()).timeout(SEARCH_TIMEOUT);
+    match (provider.kind, api_key) {
+        (WebSearchProviderKind::Brave, Some(key)) => {
+            request = request.header("X-Subscription-Token", key);
+        }
+        (WebSearchProviderKind::Bing, Some(key)) => {
+            request = request.header("Ocp-Apim-Subscription-Key", key);
+        }
+        (WebSearchProviderKind::SearxNg, Some(key)) => {
+            request = request.bearer_auth(key);
+        }
+        _ => {}
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|err| format!("{} search request failed: {err}", provider.kind.label()))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!(
+            "{} search returned {status}",
+            provider.kind.label()
+        ));
+    }
+    let body: JsonValue = response.json().await.map_err(|err| {
+        format!(
+            "failed to decode {} search response: {err}",
+            provider.kind.label()
+        )
+    })?;
+    let mut results = parse_results(provider.kind, &body);
+    results.truncate(count);
+    Ok(results)
+}
+
+fn request_url(
+    provider: &WebSearchProviderConfig,
+    query: &str,
+    count: usize,
+) -> Result<Url, String> {
+    let count = count.to_string();
+    let (endpoint, params): (String, Vec<(&str, &str)>) = match provider.kind {
+        WebSearchProviderKind::Brave => (
+            provider
+                .base_url
+                .clone()
+                .unwrap_or_else(|| BRAVE_ENDPOINT.to_string()),
+            vec![("q", query), ("count", &count)],
+        ),
+        WebSearchProviderKind::Bing => (
+            provider
+                .base_url
+                .clone()
+                .unwrap_or_else(|| BING_ENDPOINT.to_string()),
+            vec![("q", query), ("count", &count)],
+        ),
+        WebSearchProviderKind::SearxNg => {
+            let base_url = provider.base_url.as_deref().ok_or_else(|| {
+                "SearxNG search needs `web_search_provider.base_url` in config.toml".to_string()
+            })?;
+            (
+                format!("{base_url}/search"),
+                vec![("q", query), ("format", "json")],
+            )
+        }
+        WebSearchProviderKind::Hosted => {
+            return Err("the hosted web search has no local endpoint".to_string());
+        }
+    };
+    let mut url = Url::parse(&endpoint)
+        .map_err(|err| format!("invalid web search endpoint `{endpoint}`: {err}"))?;
+    url.query_pairs_mut().extend_pairs(params);
+    Ok(url)
+}
+
+/// Reads the result list out of each backend's JSON shape.
+fn parse_results(kind: WebSearchProviderKind, body: &JsonValue) -> Vec<WebCitation> {
+    let (results, title_key, snippet_key) = match kind {
+        WebSearchProviderKind::Brave => (&body["web"]["results"], "title", "description"),
+        WebSearchProviderKind::Bing => (&body["webPages"]["value"], "name", "snippet"),
+        WebSearchProviderKind::SearxNg => (&body["results"], "title", "content"),
+        WebSearchProviderKind::Hosted => return Vec::new(),
+    };
+    results
+        .as_array()
+        .map(|results| {
+            results
+                .iter()
+                .filter_map(|result| {
+                    let url = result["url"].as_str()?.to_string();
+                    Some(WebCitation {
+                        url,
+                        title: non_empty_text(&result[title_key]),
+                        snippet: non_empty_text(&result[snippet_key]),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Search APIs wrap matched terms in `<strong>`/`<b>`; drop the markup.
+fn non_empty_text(value: &JsonValue) -> Option<String> {
+    let text = value.as_str()?;
+    let text = crate::html_text::extract_readable_text(text).text;
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn provider(kind: WebSearchProviderKind, base_url: Option<&str>) -> WebSearchProviderConfig {
+        WebSearchProviderConfig {
+            kind,
+            api_key_env: None,
+            base_url: base_url.map(str::to_string),
+            max_results: 5,
+        }
+    }
+
+    #[test]
+    fn builds_request_urls_per_backend() {
+        let brave = request_url(
+            &provider(WebSearchProviderKind::Brave, None),
+            "rust & tokio",
+            3,
+        )
+        .expect("brave url");
+        assert_eq!(
+            brave.as_str(),
+            "https://api.search.brave.com/res/v1/web/search?q=rust+%26+tokio&count=3"
+        );
+
+        let searx = request_url(
+            &provider(
+                WebSearchProviderKind::SearxNg,
+                Some("https://searx.example.com"),
+            ),
+            "tokio",
+            3,
+        )
+        .expect("searx url");
+        assert_eq!(
+            searx.as_str(),
+            "https://searx.example.com/search?q=tokio&format=json"
+        );
+
+        assert_eq!(
+            request_url(&provider(WebSearchProviderKind::SearxNg, None), "tokio", 3),
+            Err("SearxNG search needs `web_search_provider.base_url` in config.toml".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_backend_responses() {
+        let brave = json!({
+            "web": { "results": [
+                { "title": "Tokio", "url": "https://tokio.rs", "description": "An <strong>async</strong> runtime" },
+                { "title": "No url" }
+            ] }
+        });
+        assert_eq!(
+            parse_results(WebSearchProviderKind::Brave, &brave),
+            vec![WebCitation {
+                url: "https://tokio.rs".to_string(),
+                title: Some("Tokio".to_string()),
+                snippet: Some("An async runtime".to_string()),
+            }]
+        );
+
+        let bing = json!({
+            "webPages": { "value": [
+                { "name": "docs.rs", "url": "https://docs.rs", "snippet": "" }
+            ] }
+        });
+        assert_eq!(
+            parse_results(WebSearchProviderKind::Bing, &bing),
+            vec![WebCitation {
+                url: "https://docs.rs".to_string(),
+                title: Some("docs.rs".to_string()),
+                snippet: None,
+            }]
+        );
+
+        let searx = json!({
+            "results": [
+                { "title": "crates.io", "url": "https://crates.io", "content": "Rust package registry" }
+            ]
+        });
+        assert_eq!(
+            parse_results(WebSearchProviderKind::SearxNg, &searx),
+            vec![WebCitation {
+                url: "https://crates.io".to_string(),
+                title: Some("crates.io".to_string()),
+                snippet: Some("Rust package registry".to_string()),
+            }]
+        );
+    }
+}
@@ -0,0 +1,447 @@
+//! Monorepo package discovery for `/scope`.
+//!
+//! The workspace root is the nearest directory, from the session's working
+//! directory up to the git root, that holds a workspace manifest: a Cargo
+//! `[workspace]`, `pnpm-workspace.yaml`, a `package.json` with `workspaces`
+//! (npm and yarn), or a Bazel `MODULE.bazel` / `WORKSPACE` file. Its member
+//! packages are what a session can be scoped to.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::git_info::get_git_repo_root;
+
+/// Bazel packages are found by walking the tree; deeper ones are skipped.
+const MAX_BAZEL_DEPTH: usize = 4;
+/// Directories never searched for Bazel `BUILD` files.
+const SKIPPED_DIRS: &[&str] = &["node_modules", "target", "dist", "build"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceKind {
+    Cargo,
+    Pnpm,
+    /// npm or yarn `workspaces` in `package.json`.
+    Npm,
+    Bazel,
+}
+
+impl WorkspaceKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            WorkspaceKind::Cargo => "Cargo",
+            WorkspaceKind::Pnpm => "pnpm",
+            WorkspaceKind::Npm => "npm/yarn",
+            WorkspaceKind::Bazel => "Bazel",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspacePackage {
+    /// Package name from its manifest, or `//path` for Bazel packages.
+    pub name: String,
+    /// Path relative to the workspace root, with `/` separators.
+    pub relative_path: String,
+    pub path: PathBuf,
+    pub kind: WorkspaceKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workspace {
+    pub root: PathBuf,
+    /// Packages sorted by relative path. A directory listed by several
+    /// manifests appears once, under the first kind that found it.
+    pub packages: Vec<WorkspacePackage>,
+}
+
+impl Workspace {
+    /// Finds the workspace that `cwd` belongs to, or `None` outside a
+    /// monorepo.
+    pub fn discover(cwd: &Path) -> Option<Self> {
+        // Outside a git repository only `cwd` itself is considered.
+        let git_root = get_git_repo_root(cwd).unwrap_or_else(|| cwd.to_path_buf());
+        for dir in cwd.ancestors() {
+            let packages = discover_packages(dir);
+            if !packages.is_empty() {
+                return Some(Self {
+                    root: dir.to_path_buf(),
+                    packages,
+                });
+            }
+            if dir == git_root {
+                break;
+            }
+        }
+        None
+    }
+
+    /// The package named `query`, or at the relative path `query`.
+    pub fn find(&self, query: &str) -> Option<&WorkspacePackage> {
+        let query = query.trim().trim_end_matches('/');
+        let path_query = query.strip_prefix("./").unwrap_or(query);
+        self.packages
+            .iter()
+            .find(|package| package.name == query)
+            .or_else(|| {
+                self.packages
+                    .iter()
+                    .find(|package| package.relative_path == path_query)
+            })
+    }
+
+    /// The package containing `path`, preferring the most deeply nested one.
+    pub fn package_containing(&self, path: &Path) -> Option<&WorkspacePackage> {
+        self.packages
+            .iter()
+            .filter(|package| path.starts_with(&package.path))
+            .max_by_key(|package| package.path.components().count())
+    }
+}
+
+/// Member packages declared by the manifests in `root`.
+fn discover_packages(root: &Path) -> Vec<WorkspacePackage> {
+    let mut by_path: BTreeMap<String, WorkspacePackage> = BTreeMap::new();
+    let found = [
+        (WorkspaceKind::Cargo, cargo_members(root)),
+        (WorkspaceKind::Pnpm, pnpm_members(root)),
+        (WorkspaceKind::Npm, npm_members(root)),
+        (WorkspaceKind::Bazel, bazel_packages(root)),
+    ];
+    for (kind, members) in found {
+        for (path, name) in members {
+            let Ok(relative) = path.strip_prefix(root) else {
+                continue;
+            };
+            let relative_path = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if relative_path.is_empty() {
+                continue;
+            }
+            by_path
+                .entry(relative_path.clone())
+                .or_insert(WorkspacePackage {
+                    name,
+                    relative_path,
+                    path,
+                    kind,
+                });
+        }
+    }
+    by_path.into_values().collect()
+}
+
+#[derive(Deserialize)]
+struct CargoManifest {
+    workspace: Option<CargoWorkspace>,
+    package: Option<NamedManifest>,
+}
+
+#[derive(Deserialize)]
+struct CargoWorkspace {
+    #[serde(default)]
+    members: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct NamedManifest {
+    name: Option<String>,
+}
+
+fn cargo_members(root: &Path) -> Vec<(PathBuf, String)> {
+    let Some(workspace) = read_toml::<CargoManifest>(&root.join("Cargo.toml"))
+        .and_then(|manifest| manifest.workspace)
+    else {
+        return Vec::new();
+    };
+    let excluded: Vec<PathBuf> = workspace
+        .exclude
+        .iter()
+        .flat_map(|pattern| expand(root, pattern))
+        .collect();
+    workspace
+        .members
+        .iter()
+        .flat_map(|pattern| expand(root, pattern))
+        .filter(|dir| !excluded.contains(dir))
+        .filter_map(|dir| {
+            let manifest = read_toml::<CargoManifest>(&dir.join("Cargo.toml"))?;
+            let name = manifest
+                .package
+                .and_then(|package| package.name)
+                .unwrap_or_else(|| dir_name(&dir));
+            Some((dir, name))
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct PnpmWorkspace {
+    #[serde(default)]
+    packages: Vec<String>,
+}
+
+fn pnpm_members(root: &Path) -> Vec<(PathBuf, String)> {
+    let Some(workspace) = std::fs::read_to_string(root.join("pnpm-workspace.yaml"))
+        .ok()
+        .and_then(|contents| serde_yaml::from_str::<PnpmWorkspace>(&contents).ok())
+    else {
+        return Vec::new();
+    };
+    js_members(root, &workspace.packages)
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NpmWorkspaces {
+    List(Vec<String>),
+    /// Yarn's `{ "packages": [...], "nohoist": [...] }` form.
+    Object {
+        #[serde(default)]
+        packages: Vec<String>,
+    },
+}
+
+#[derive(Deserialize)]
+struct PackageJson {
+    name: Option<String>,
+    workspaces: Option<NpmWorkspaces>,
+}
+
+fn npm_members(root: &Path) -> Vec<(PathBuf, String)> {
+    let patterns = match read_json::<PackageJson>(&root.join("package.json"))
+        .and_then(|package| package.workspaces)
+    {
+        Some(NpmWorkspaces::List(patterns))
+        | Some(NpmWorkspaces::Object { packages: patterns }) => patterns,
+        None => return Vec::new(),
+    };
+    js_members(root, &patterns)
+}
+
+/// Directories with a `package.json` matched by `patterns`; patterns
+/// starting with `!` exclude directories.
+fn js_members(root: &Path, patterns: &[String]) -> Vec<(PathBuf, String)> {
+    let excluded: Vec<PathBuf> = patterns
+        .iter()
+        .filter_map(|pattern| pattern.strip_prefix('!'))
+        .flat_map(|pattern| expand(root, pattern))
+        .collect();
+    patterns
+        .iter()
+        .filter(|pattern| !pattern.starts_with('!'))
+        .flat_map(|pattern| expand(root, pattern))
+        .filter(|dir| !excluded.contains(dir))
+        .filter_map(|dir| {
+            let package = read_json::<PackageJson>(&dir.join("package.json"))?;
+            let name = package.name.unwrap_or_else(|| dir_name(&dir));
+            Some((dir, name))
+        })
+        .collect()
+}
+
+fn bazel_packages(root: &Path) -> Vec<(PathBuf, String)> {
+    let is_bazel = ["MODULE.bazel", "WORKSPACE", "WORKSPACE.bazel"]
+        .iter()
+        .any(|marker| root.join(marker).is_file());
+    if !is_bazel {
+        return Vec::new();
+    }
+    let mut packages = Vec::new();
+    collect_bazel_packages(root, root, 0, &mut packages);
+    packages
+}
+
+fn collect_bazel_packages(
+    root: &Path,
+    dir: &Path,
+    depth: usize,
+    packages: &mut Vec<(PathBuf, String)>,
+) {
+    if depth > MAX_BAZEL_DEPTH {
+        return;
+    }
+    if depth > 0
+        && ["BUILD", "BUILD.bazel"]
+            .iter()
+            .any(|f| dir.join(f).is_file())
+    {
+        let label = dir
+            .strip_prefix(root)
+            .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+        packages.push((dir.to_path_buf(), format!("//{label}")));
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let skipped = name.starts_with('.')
+            || name.starts_with("bazel-")
+            || SKIPPED_DIRS.contains(&name.as_str());
+        if !skipped && entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+            collect_bazel_packages(root, &entry.path(), depth + 1, packages);
+        }
+    }
+}
+
+/// Directories under `root` matching the member glob `pattern`.
+fn expand(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let pattern = pattern.trim().trim_end_matches('/');
+    let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+    if pattern.is_empty() {
+        return Vec::new();
+    }
+    let full = root.join(pattern);
+    let Some(full) = full.to_str() else {
+        return Vec::new();
+    };
+    let Ok(paths) = glob::glob(full) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<PathBuf> = paths
+        .flatten()
+        .filter(|path| path.is_dir())
+        .filter(|path| {
+            !path
+                .components()
+                .any(|component| component.as_os_str() == "node_modules")
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn dir_name(dir: &Path) -> String {
+    dir.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn read_toml<T: for<'de> Deserialize<'de>>(path: &Path) -> Option<T> {
+    toml::from_str(&std::fs::read_to_string(path).ok()?).ok()
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Option<T> {
+    serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, path: &str, contents: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().expect("parent")).expect("create dirs");
+        std::fs::write(path, contents).expect("write file");
+    }
+
+    fn names(workspace: &Workspace) -> Vec<(&str, &str, WorkspaceKind)> {
+        workspace
+            .packages
+            .iter()
+            .map(|package| {
+                (
+                    package.name.as_str(),
+                    package.relative_path.as_str(),
+                    package.kind,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_cargo_and_pnpm_members() {
+        let tmp = TempDir::new().expect("tempdir");
+        let root = tmp.path();
+        std::fs::create_dir(root.join(".git")).expect("git dir");
+        write(
+            root,
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/old\"]\n",
+        );
+        write(
+            root,
+            "crates/core/Cargo.toml",
+            "[package]\nname = \"app-core\"\n",
+        );
+        write(root, "crates/old/Cargo.toml", "[package]\nname = \"old\"\n");
+        write(root, "crates/notes/README.md", "not a crate");
+        write(
+            root,
+            "pnpm-workspace.yaml",
+            "packages:\n  - 'web/*'\n  - '!web/legacy'\n",
+        );
+        write(root, "web/site/package.json", r#"{"name": "@app/site"}"#);
+        write(root, "web/legacy/package.json", r#"{"name": "legacy"}"#);
+
+        let workspace = Workspace::discover(&root.join("crates/core/src")).expect("workspace");
+        assert_eq!(workspace.root, root);
+        assert_eq!(
+            names(&workspace),
+            vec![
+                ("app-core", "crates/core", WorkspaceKind::Cargo),
+                ("@app/site", "web/site", WorkspaceKind::Pnpm),
+            ]
+        );
+        assert_eq!(
+            workspace
+                .find("@app/site")
+                .map(|p| p.relative_path.as_str()),
+            Some("web/site")
+        );
+        assert_eq!(
+            workspace.find("./crates/core/").map(|p| p.name.as_str()),
+            Some("app-core")
+        );
+        assert_eq!(workspace.find("old"), None);
+    }
+
+    #[test]
+    fn finds_yarn_object_workspaces_and_bazel_packages() {
+        let tmp = TempDir::new().expect("tempdir");
+        let root = tmp.path();
+        write(
+            root,
+            "package.json",
+            r#"{"workspaces": {"packages": ["apps/*"], "nohoist": []}}"#,
+        );
+        write(root, "apps/api/package.json", "{}");
+        write(root, "MODULE.bazel", "");
+        write(root, "services/auth/BUILD.bazel", "");
+        write(root, "node_modules/dep/BUILD", "");
+
+        let workspace = Workspace::discover(root).expect("workspace");
+        assert_eq!(
+            names(&workspace),
+            vec![
+                ("api", "apps/api", WorkspaceKind::Npm),
+                ("//services/auth", "services/auth", WorkspaceKind::Bazel),
+            ]
+        );
+        assert_eq!(
+            workspace
+                .package_containing(&root.join("apps/api/src/main.ts"))
+                .map(|p| p.name.as_str()),
+            Some("api")
+        );
+    }
+
+    #[test]
+    fn plain_repositories_have_no_workspace() {
+        let tmp = TempDir::new().expect("tempdir");
+        write(tmp.path(), "Cargo.toml", "[package]\nname = \"single\"\n");
+        std::fs::create_dir(tmp.path().join(".git")).expect("git dir");
+        assert_eq!(Workspace::discover(tmp.path()), None);
+    }
+}
@@ -9,6 +9,7 @@ use codex_core::auth::AuthCredentialsStoreMode;
 use codex_core::auth::AuthDotJson;
 use codex_core::auth::REFRESH_TOKEN_URL_OVERRIDE_ENV_VAR;
 use codex_core::auth::RefreshTokenError;
+use codex_core::auth::TokenRefreshHealth;
 use codex_core::auth::load_auth_dot_json;
 use codex_core::auth::save_auth;
 use codex_core::error::RefreshTokenFailedReason;
@@ -232,6 +233,14 @@ async fn refresh_token_returns_permanent_error_for_expired_refresh_token() -> Re
         .err()
         .context("refresh should fail")?;
     assert_eq!(err.failed_reason(), Some(RefreshTokenFailedReason::Expired));
+    let health = ctx
+        .auth_manager
+        .token_refresh_health()
+        .context("ChatGPT auth should report refresh health")?;
+    assert!(
+        matches!(health, TokenRefreshHealth::Failed(_)),
+        "unexpected health: {health:?}"
+    );
 
     let stored = ctx.load_auth()?;
     assert_eq!(stored, initial_auth);
@@ -723,6 +723,9 @@ async fn parallel_priority_spec_injected_when_enabled_and_removed_after_override
             spec_parallel_priority: Some(false),
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await
         .expect("override spec toggle");
@@ -812,6 +815,9 @@ async fn sdd_planning_spec_injected_when_enabled_and_removed_after_override() {
             personality: None,
             spec_parallel_priority: None,
             spec_sdd_planning: Some(false),
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await
         .expect("override spec toggle");
@@ -902,6 +908,9 @@ async fn sdd_planning_override_keeps_parallel_priority_injection() {
             personality: None,
             spec_parallel_priority: None,
             spec_sdd_planning: Some(false),
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await
         .expect("override spec toggle");
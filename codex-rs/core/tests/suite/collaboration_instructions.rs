@@ -124,6 +124,9 @@ async fn user_input_includes_collaboration_instructions_after_override() -> Resu
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -217,6 +220,9 @@ async fn override_then_next_turn_uses_updated_collaboration_instructions() -> Re
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -270,6 +276,9 @@ async fn user_turn_overrides_collaboration_instructions_after_override() -> Resu
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -336,6 +345,9 @@ async fn collaboration_mode_update_emits_new_instruction_message() -> Result<()>
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -364,6 +376,9 @@ async fn collaboration_mode_update_emits_new_instruction_message() -> Result<()>
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -421,6 +436,9 @@ async fn collaboration_mode_update_noop_does_not_append() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -449,6 +467,9 @@ async fn collaboration_mode_update_noop_does_not_append() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -508,6 +529,9 @@ async fn collaboration_mode_update_emits_new_instruction_message_when_mode_chang
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -539,6 +563,9 @@ async fn collaboration_mode_update_emits_new_instruction_message_when_mode_chang
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -599,6 +626,9 @@ async fn collaboration_mode_update_noop_does_not_append_when_mode_is_unchanged()
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -630,6 +660,9 @@ async fn collaboration_mode_update_noop_does_not_append_when_mode_is_unchanged()
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -693,6 +726,9 @@ async fn resume_replays_collaboration_instructions() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -764,6 +800,9 @@ async fn empty_collaboration_instructions_are_ignored() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
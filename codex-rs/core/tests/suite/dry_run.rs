@@ -0,0 +1,150 @@
+#![cfg(not(target_os = "windows"))]
+
+use codex_core::protocol::EventMsg;
+use codex_core::protocol::Op;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::ev_shell_command_call;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::TestCodex;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
+
+fn text_input(text: &str) -> Op {
+    Op::UserInput {
+        items: vec![UserInput::Text {
+            text: text.into(),
+            text_elements: Vec::new(),
+        }],
+        final_output_json_schema: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn dry_run_runs_only_approved_calls() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let TestCodex { codex, .. } = test_codex().build(&server).await?;
+
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_shell_command_call("approved-call", "echo approved"),
+            ev_shell_command_call("declined-call", "echo declined"),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let second_mock = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    codex.submit(Op::SetDryRun { enabled: true }).await?;
+    codex.submit(text_input("say hi twice")).await?;
+
+    let plan = wait_for_event(&codex, |event| matches!(event, EventMsg::DryRunPlan(_))).await;
+    let EventMsg::DryRunPlan(plan) = plan else {
+        unreachable!("wait_for_event returned a different event");
+    };
+    let summaries: Vec<&str> = plan
+        .calls
+        .iter()
+        .map(|call| call.summary.as_str())
+        .collect();
+    assert_eq!(summaries, vec!["echo approved", "echo declined"]);
+
+    codex
+        .submit(Op::DryRunDecision {
+            id: plan.turn_id,
+            approved_call_ids: vec!["approved-call".to_string()],
+        })
+        .await?;
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    let request = second_mock.single_request();
+    let approved = request
+        .function_call_output_text("approved-call")
+        .expect("approved call output");
+    assert!(
+        approved.contains("approved"),
+        "unexpected output: {approved}"
+    );
+    let declined = request
+        .function_call_output_text("declined-call")
+        .expect("declined call output");
+    assert!(
+        declined.contains("chose not to run it"),
+        "unexpected output: {declined}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn dry_run_only_applies_to_the_next_turn() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let TestCodex { codex, .. } = test_codex().build(&server).await?;
+
+    mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp-1"), ev_completed("resp-1")]),
+    )
+    .await;
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-2"),
+            ev_shell_command_call("call-1", "echo ran"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+    let last_mock = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-3"),
+        ]),
+    )
+    .await;
+
+    codex.submit(Op::SetDryRun { enabled: true }).await?;
+    codex.submit(text_input("first")).await?;
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    codex.submit(text_input("second")).await?;
+    let mut saw_plan = false;
+    wait_for_event(&codex, |event| match event {
+        EventMsg::DryRunPlan(_) => {
+            saw_plan = true;
+            false
+        }
+        EventMsg::TurnComplete(_) => true,
+        _ => false,
+    })
+    .await;
+
+    assert!(!saw_plan, "the second turn should run its calls directly");
+    let output = last_mock
+        .single_request()
+        .function_call_output_text("call-1")
+        .expect("call output");
+    assert!(output.contains("ran"), "unexpected output: {output}");
+
+    Ok(())
+}
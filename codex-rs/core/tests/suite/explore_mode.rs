@@ -0,0 +1,160 @@
+#![cfg(not(target_os = "windows"))]
+
+use codex_core::features::Feature;
+use codex_core::i18n::tr;
+use codex_core::protocol::EventMsg;
+use codex_core::protocol::Op;
+use codex_protocol::config_types::Language;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_apply_patch_function_call;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::TestCodex;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
+
+fn text_input(text: &str) -> Op {
+    Op::UserInput {
+        items: vec![UserInput::Text {
+            text: text.into(),
+            text_elements: Vec::new(),
+        }],
+        final_output_json_schema: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn explore_mode_instruction_follows_override() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let resp_mock = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![ev_response_created("resp-1"), ev_completed("resp-1")]),
+            sse(vec![ev_response_created("resp-2"), ev_completed("resp-2")]),
+        ],
+    )
+    .await;
+
+    let TestCodex { codex, .. } = test_codex().build(&server).await?;
+
+    codex.submit(text_input("hello")).await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    codex
+        .submit(Op::OverrideTurnContext {
+            cwd: None,
+            approval_policy: None,
+            sandbox_policy: None,
+            windows_sandbox_level: None,
+            model: None,
+            effort: None,
+            summary: None,
+            collaboration_mode: None,
+            personality: None,
+            spec_parallel_priority: None,
+            spec_sdd_planning: None,
+            explore_mode: Some(true),
+            persona: None,
+            scope: None,
+        })
+        .await?;
+
+    codex.submit(text_input("look around")).await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let requests = resp_mock.requests();
+    assert_eq!(requests.len(), 2);
+    let explore_text = tr(Language::En, "prompt.explore_mode");
+    let has_instruction = |index: usize| {
+        requests[index]
+            .message_input_texts("user")
+            .iter()
+            .any(|text| text.contains(explore_text))
+    };
+    assert!(
+        !has_instruction(0),
+        "default mode should not mention explore mode"
+    );
+    assert!(
+        has_instruction(1),
+        "explore mode instruction should be injected"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn apply_patch_becomes_proposal_in_explore_mode() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mut builder = test_codex().with_config(|config| {
+        config.features.enable(Feature::ApplyPatchFreeform);
+        config.explore_mode = true;
+    });
+    let TestCodex { codex, cwd, .. } = builder.build(&server).await?;
+
+    let call_id = "explore-patch";
+    let patch = "*** Begin Patch\n*** Add File: notes.txt\n+proposed only\n*** End Patch";
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_apply_patch_function_call(call_id, patch),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let second_mock = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "proposed"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    codex.submit(text_input("add a notes file")).await?;
+
+    let mut proposal = None;
+    let mut saw_apply = false;
+    wait_for_event(&codex, |event| match event {
+        EventMsg::PatchProposal(event) => {
+            proposal = Some(event.clone());
+            false
+        }
+        EventMsg::PatchApplyBegin(_) => {
+            saw_apply = true;
+            false
+        }
+        EventMsg::TurnComplete(_) => true,
+        _ => false,
+    })
+    .await;
+
+    let proposal = proposal.expect("expected a PatchProposal event");
+    assert_eq!(proposal.call_id, call_id);
+    assert_eq!(proposal.changes.len(), 1);
+    assert!(!saw_apply, "explore mode must not start applying the patch");
+    assert!(!cwd.path().join("notes.txt").exists());
+
+    let output = second_mock
+        .single_request()
+        .function_call_output_text(call_id)
+        .expect("apply_patch output");
+    assert!(
+        output.contains("not applied"),
+        "unexpected output: {output}"
+    );
+
+    Ok(())
+}
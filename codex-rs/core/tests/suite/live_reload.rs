@@ -26,6 +26,8 @@ use tokio::time::timeout;
 fn enable_trusted_project(config: &mut codex_core::config::Config) {
     config.active_project = ProjectConfig {
         trust_level: Some(TrustLevel::Trusted),
+        persona: None,
+        profile: None,
     };
 }
 
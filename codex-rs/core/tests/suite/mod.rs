@@ -70,8 +70,10 @@ mod compact;
 mod compact_remote;
 mod compact_resume_fork;
 mod deprecation_notice;
+mod dry_run;
 mod exec;
 mod exec_policy;
+mod explore_mode;
 mod fork_thread;
 mod grep_files;
 mod hierarchical_agents;
@@ -92,6 +94,7 @@ mod pending_input;
 mod permissions_messages;
 mod personality;
 mod personality_migration;
+mod personas;
 mod prompt_caching;
 mod quota_exceeded;
 mod read_file;
@@ -103,6 +106,7 @@ mod resume_warning;
 mod review;
 mod rmcp_client;
 mod rollout_list_find;
+mod scope;
 mod sdd_git;
 mod search_tool;
 mod seatbelt;
@@ -38,6 +38,9 @@ async fn override_turn_context_does_not_persist_when_config_exists() {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await
         .expect("submit override");
@@ -77,6 +80,9 @@ async fn override_turn_context_does_not_create_config_file() {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await
         .expect("submit override");
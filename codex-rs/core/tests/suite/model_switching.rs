@@ -77,6 +77,9 @@ async fn model_change_appends_model_instructions_developer_message() -> Result<(
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -168,6 +171,9 @@ async fn model_and_personality_change_only_appends_model_instructions() -> Resul
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -126,6 +126,9 @@ async fn override_turn_context_without_user_turn_does_not_record_permissions_upd
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -169,6 +172,9 @@ async fn override_turn_context_without_user_turn_does_not_record_environment_upd
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -209,6 +215,9 @@ async fn override_turn_context_without_user_turn_does_not_record_collaboration_u
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -125,6 +125,9 @@ async fn permissions_message_added_on_override_change() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -270,6 +273,9 @@ async fn resume_replays_permissions_messages() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -372,6 +378,9 @@ async fn resume_and_fork_append_permissions_messages() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
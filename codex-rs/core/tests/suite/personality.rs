@@ -328,6 +328,9 @@ async fn user_turn_personality_some_adds_update_message() -> anyhow::Result<()>
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -428,6 +431,9 @@ async fn user_turn_personality_same_value_does_not_add_update_message() -> anyho
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -537,6 +543,9 @@ async fn user_turn_personality_skips_if_feature_disabled() -> anyhow::Result<()>
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -918,6 +927,9 @@ async fn user_turn_personality_remote_model_template_includes_update_message() -
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -0,0 +1,110 @@
+#![cfg(not(target_os = "windows"))]
+
+use codex_core::config::types::PersonaToml;
+use codex_core::i18n::tr;
+use codex_core::protocol::EventMsg;
+use codex_core::protocol::Op;
+use codex_protocol::config_types::Language;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::TestCodex;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
+
+const ARCHIVIST_INSTRUCTIONS: &str = "Cite the commit that introduced each line you discuss.";
+
+fn text_input(text: &str) -> Op {
+    Op::UserInput {
+        items: vec![UserInput::Text {
+            text: text.into(),
+            text_elements: Vec::new(),
+        }],
+        final_output_json_schema: None,
+    }
+}
+
+fn persona_override(persona: Option<&str>) -> Op {
+    Op::OverrideTurnContext {
+        cwd: None,
+        approval_policy: None,
+        sandbox_policy: None,
+        windows_sandbox_level: None,
+        model: None,
+        effort: None,
+        summary: None,
+        collaboration_mode: None,
+        personality: None,
+        spec_parallel_priority: None,
+        spec_sdd_planning: None,
+        explore_mode: None,
+        persona: Some(persona.map(str::to_string)),
+        scope: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn persona_instructions_follow_the_active_persona() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let resp_mock = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![ev_response_created("resp-1"), ev_completed("resp-1")]),
+            sse(vec![ev_response_created("resp-2"), ev_completed("resp-2")]),
+            sse(vec![ev_response_created("resp-3"), ev_completed("resp-3")]),
+        ],
+    )
+    .await;
+
+    let mut builder = test_codex().with_config(|config| {
+        config.personas.insert(
+            "archivist".to_string(),
+            PersonaToml {
+                description: None,
+                instructions: ARCHIVIST_INSTRUCTIONS.to_string(),
+            },
+        );
+        config.persona = Some("archivist".to_string());
+    });
+    let TestCodex { codex, .. } = builder.build(&server).await?;
+
+    codex.submit(text_input("first")).await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    codex.submit(persona_override(Some("terse"))).await?;
+    codex.submit(text_input("second")).await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    codex.submit(persona_override(None)).await?;
+    codex.submit(text_input("third")).await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let requests = resp_mock.requests();
+    assert_eq!(requests.len(), 3);
+    let terse_text = tr(Language::En, "prompt.persona.terse");
+    let sends = |index: usize, needle: &str| {
+        requests[index]
+            .message_input_texts("user")
+            .iter()
+            .any(|text| text.contains(needle))
+    };
+    assert!(
+        sends(0, ARCHIVIST_INSTRUCTIONS),
+        "configured persona is sent"
+    );
+    assert!(!sends(0, terse_text));
+    assert!(sends(1, terse_text), "switching persona takes effect");
+    assert!(
+        !sends(2, terse_text) && !sends(2, ARCHIVIST_INSTRUCTIONS),
+        "clearing the persona drops its instructions"
+    );
+
+    Ok(())
+}
@@ -394,6 +394,9 @@ async fn overrides_turn_context_but_keeps_cached_prefix_and_key_constant() -> an
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -476,6 +479,9 @@ async fn override_before_first_turn_emits_environment_context() -> anyhow::Resul
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -213,6 +213,9 @@ async fn remote_models_remote_model_uses_unified_exec() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -448,6 +451,9 @@ async fn remote_models_apply_remote_base_instructions() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
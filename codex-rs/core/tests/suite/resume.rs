@@ -331,6 +331,9 @@ async fn resume_model_switch_is_not_duplicated_after_pre_turn_override() -> Resu
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
     resumed
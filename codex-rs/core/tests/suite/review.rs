@@ -106,6 +106,7 @@ async fn review_op_emits_lifecycle_and_review_output() {
                 absolute_file_path: PathBuf::from("/tmp/file.rs"),
                 line_range: ReviewLineRange { start: 10, end: 20 },
             },
+            category: None,
         }],
         overall_correctness: "good".to_string(),
         overall_explanation: "All good with some improvements suggested.".to_string(),
@@ -849,6 +850,9 @@ async fn review_uses_overridden_cwd_for_base_branch_merge_base() {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await
         .unwrap();
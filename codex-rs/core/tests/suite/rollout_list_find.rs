@@ -168,6 +168,7 @@ async fn find_locates_rollout_file_written_by_recorder() -> std::io::Result<()>
         RolloutRecorderParams::new(
             thread_id,
             None,
+            None,
             SessionSource::Exec,
             BaseInstructions::default(),
             Vec::new(),
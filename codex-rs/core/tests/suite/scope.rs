@@ -0,0 +1,100 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::path::PathBuf;
+
+use codex_core::protocol::EventMsg;
+use codex_core::protocol::Op;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::TestCodex;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
+
+const PACKAGE_DOC: &str = "Run `pnpm test --filter web` before finishing.";
+
+fn text_input(text: &str) -> Op {
+    Op::UserInput {
+        items: vec![UserInput::Text {
+            text: text.into(),
+            text_elements: Vec::new(),
+        }],
+        final_output_json_schema: None,
+    }
+}
+
+fn scope_override(scope: Option<PathBuf>) -> Op {
+    Op::OverrideTurnContext {
+        cwd: None,
+        approval_policy: None,
+        sandbox_policy: None,
+        windows_sandbox_level: None,
+        model: None,
+        effort: None,
+        summary: None,
+        collaboration_mode: None,
+        personality: None,
+        spec_parallel_priority: None,
+        spec_sdd_planning: None,
+        explore_mode: None,
+        persona: None,
+        scope: Some(scope),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn scope_moves_cwd_into_the_package_and_sends_its_docs() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let resp_mock = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![ev_response_created("resp-1"), ev_completed("resp-1")]),
+            sse(vec![ev_response_created("resp-2"), ev_completed("resp-2")]),
+        ],
+    )
+    .await;
+
+    let test = test_codex().build(&server).await?;
+    let package = test.workspace_path("packages/web");
+    std::fs::create_dir_all(&package)?;
+    std::fs::write(package.join("AGENTS.md"), PACKAGE_DOC)?;
+    let TestCodex { codex, .. } = &test;
+
+    codex.submit(scope_override(Some(package.clone()))).await?;
+    codex.submit(text_input("scoped")).await?;
+    wait_for_event(codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    codex.submit(scope_override(None)).await?;
+    codex.submit(text_input("widened")).await?;
+    wait_for_event(codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let requests = resp_mock.requests();
+    assert_eq!(requests.len(), 2);
+    let sends = |index: usize, needle: &str| {
+        requests[index]
+            .message_input_texts("user")
+            .iter()
+            .any(|text| text.contains(needle))
+    };
+    let package_cwd = format!("<cwd>{}</cwd>", package.display());
+    let root_cwd = format!("<cwd>{}</cwd>", test.cwd_path().display());
+    assert!(sends(0, PACKAGE_DOC), "the package's AGENTS.md is sent");
+    assert!(
+        sends(0, &package_cwd),
+        "the package is the working directory"
+    );
+    assert!(!sends(1, PACKAGE_DOC), "widening drops the package docs");
+    assert!(
+        sends(1, &root_cwd),
+        "widening restores the working directory"
+    );
+
+    Ok(())
+}
@@ -78,6 +78,9 @@ async fn sdd_git_action_create_branch_dispatches() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
@@ -130,6 +133,9 @@ async fn sdd_git_action_create_branch_uses_non_default_base() -> Result<()> {
             spec_parallel_priority: None,
 
             spec_sdd_planning: None,
+            explore_mode: None,
+            persona: None,
+            scope: None,
         })
         .await?;
 
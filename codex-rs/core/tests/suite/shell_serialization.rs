@@ -434,7 +434,9 @@ Output:
 4
 5
 6
-.*…46 tokens truncated….*
+.*
+\[… \d+ lines omitted \(lines \d+-\d+\); full output: [^\n]*\.log …\]
+.*
 396
 397
 398
@@ -394,6 +394,11 @@ async fn shell_timeout_includes_timeout_prefix_and_metadata() -> Result<()> {
             Some(124),
             "expected timeout exit code 124",
         );
+        assert_eq!(
+            output_json["metadata"]["timed_out"].as_bool(),
+            Some(true),
+            "expected timed_out metadata",
+        );
 
         let stdout = output_json["output"].as_str().unwrap_or_default();
         assert!(
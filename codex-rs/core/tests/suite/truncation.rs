@@ -166,7 +166,7 @@ async fn tool_call_output_exceeds_limit_truncated_chars_limit() -> Result<()> {
         "expected truncated shell output to be plain text"
     );
 
-    let truncated_pattern = r#"(?s)^Exit code: 0\nWall time: [0-9]+(?:\.[0-9]+)? seconds\nTotal output lines: 100000\nOutput:\n.*?…\d+ chars truncated….*$"#;
+    let truncated_pattern = r#"(?s)^Exit code: 0\nWall time: [0-9]+(?:\.[0-9]+)? seconds\nTotal output lines: 100000\nOutput:\n1\n2\n3\n.*\n\[… \d+ lines omitted \(lines \d+-\d+\); full output: [^\n]*\.log …\]\n.*\n99999\n100000\n$"#;
 
     assert_regex_match(truncated_pattern, &output);
 
@@ -248,7 +248,9 @@ Output:
 4
 5
 6
-.*…137224 tokens truncated.*
+.*
+\[… \d+ lines omitted \(lines \d+-\d+\); full output: [^\n]*\.log …\]
+.*
 99999
 100000
 $"#;
@@ -304,12 +306,16 @@ async fn tool_call_output_truncated_only_once() -> Result<()> {
         .function_call_output_text(call_id)
         .context("function_call_output present for shell call")?;
 
-    let truncation_markers = output.matches("tokens truncated").count();
+    let truncation_markers = output.matches("lines omitted").count();
 
     assert_eq!(
         truncation_markers, 1,
         "shell output should carry only one truncation marker: {output}"
     );
+    assert!(
+        !output.contains("tokens truncated"),
+        "summarized shell output should not be truncated again: {output}"
+    );
 
     Ok(())
 }
@@ -510,16 +516,16 @@ async fn mcp_image_output_preserves_image_and_no_text_summary() -> Result<()> {
     Ok(())
 }
 
-// Token-based policy should report token counts even when truncation is byte-estimated.
+// Multi-line output over a token budget is summarized on line boundaries.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn token_policy_marker_reports_tokens() -> Result<()> {
+async fn token_policy_summarizes_output_by_lines() -> Result<()> {
     skip_if_no_network!(Ok(()));
 
     let server = start_mock_server().await;
     let mut builder = test_codex()
         .with_model("gpt-5.1-codex")
         .with_config(|config| {
-            config.tool_output_token_limit = Some(50); // small budget to force truncation
+            config.tool_output_token_limit = Some(100); // small budget to force truncation
         });
     let fixture = builder.build(&server).await?;
 
@@ -556,21 +562,21 @@ async fn token_policy_marker_reports_tokens() -> Result<()> {
         .function_call_output_text(call_id)
         .context("shell output present")?;
 
-    let pattern = r"(?s)^Exit code: 0\nWall time: [0-9]+(?:\.[0-9]+)? seconds\nTotal output lines: 150\nOutput:\n1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15\n16\n17\n18\n19.*tokens truncated.*129\n130\n131\n132\n133\n134\n135\n136\n137\n138\n139\n140\n141\n142\n143\n144\n145\n146\n147\n148\n149\n150\n$";
+    let pattern = r"(?s)^Exit code: 0\nWall time: [0-9]+(?:\.[0-9]+)? seconds\nTotal output lines: 150\nOutput:\n1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15\n16\n17\n18\n19\n.*\n\[… \d+ lines omitted \(lines \d+-\d+\); full output: [^\n]*\.log …\]\n.*\n144\n145\n146\n147\n148\n149\n150\n$";
 
     assert_regex_match(pattern, &output);
 
     Ok(())
 }
 
-// Byte-based policy should report bytes removed.
+// Multi-line output over a byte budget is summarized on line boundaries.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn byte_policy_marker_reports_bytes() -> Result<()> {
+async fn byte_policy_summarizes_output_by_lines() -> Result<()> {
     skip_if_no_network!(Ok(()));
 
     let server = start_mock_server().await;
     let mut builder = test_codex().with_model("gpt-5.1").with_config(|config| {
-        config.tool_output_token_limit = Some(50); // ~200 byte cap
+        config.tool_output_token_limit = Some(100); // ~400 byte cap
     });
     let fixture = builder.build(&server).await?;
 
@@ -607,7 +613,7 @@ async fn byte_policy_marker_reports_bytes() -> Result<()> {
         .function_call_output_text(call_id)
         .context("shell output present")?;
 
-    let pattern = r"(?s)^Exit code: 0\nWall time: [0-9]+(?:\.[0-9]+)? seconds\nTotal output lines: 150\nOutput:\n1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15\n16\n17\n18\n19.*chars truncated.*129\n130\n131\n132\n133\n134\n135\n136\n137\n138\n139\n140\n141\n142\n143\n144\n145\n146\n147\n148\n149\n150\n$";
+    let pattern = r"(?s)^Exit code: 0\nWall time: [0-9]+(?:\.[0-9]+)? seconds\nTotal output lines: 150\nOutput:\n1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15\n16\n17\n18\n19\n.*\n\[… \d+ lines omitted \(lines \d+-\d+\); full output: [^\n]*\.log …\]\n.*\n144\n145\n146\n147\n148\n149\n150\n$";
 
     assert_regex_match(pattern, &output);
 
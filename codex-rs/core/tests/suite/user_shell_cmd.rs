@@ -3,9 +3,13 @@ use codex_core::features::Feature;
 use codex_core::protocol::AskForApproval;
 use codex_core::protocol::EventMsg;
 use codex_core::protocol::ExecCommandEndEvent;
+use codex_core::protocol::ExecCommandOrigin;
+use codex_core::protocol::ExecCommandProvenance;
 use codex_core::protocol::ExecCommandSource;
 use codex_core::protocol::ExecOutputStream;
 use codex_core::protocol::Op;
+use codex_core::protocol::RolloutItem;
+use codex_core::protocol::RolloutLine;
 use codex_core::protocol::SandboxPolicy;
 use codex_core::protocol::TurnAbortReason;
 use codex_protocol::config_types::ReasoningSummary;
@@ -95,6 +99,47 @@ async fn user_shell_cmd_ls_and_cat_in_temp_dir() {
     assert_eq!(stdout, contents);
 }
 
+#[tokio::test]
+async fn user_shell_cmd_records_provenance_in_rollout() -> anyhow::Result<()> {
+    let server = start_mock_server().await;
+    let fixture = test_codex().build(&server).await?;
+    let codex = &fixture.codex;
+    let rollout_path = fixture
+        .session_configured
+        .rollout_path
+        .clone()
+        .context("rollout path")?;
+
+    codex
+        .submit(Op::RunUserShellCommand {
+            command: "echo provenance".to_string(),
+        })
+        .await?;
+    let msg = wait_for_event(codex, |ev| matches!(ev, EventMsg::ExecCommandEnd(_))).await;
+    let EventMsg::ExecCommandEnd(end) = msg else {
+        unreachable!()
+    };
+    let expected = ExecCommandProvenance::new(ExecCommandOrigin::UserShell);
+    assert_eq!(end.provenance.as_ref(), Some(&expected));
+
+    codex.submit(Op::Shutdown).await?;
+    wait_for_event(codex, |ev| matches!(ev, EventMsg::ShutdownComplete)).await;
+
+    let records: Vec<_> = std::fs::read_to_string(&rollout_path)?
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RolloutLine>(line).ok())
+        .filter_map(|line| match line.item {
+            RolloutItem::ExecProvenance(item) => Some(item),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(records.len(), 1, "unexpected records: {records:?}");
+    assert_eq!(records[0].call_id.as_deref(), Some(end.call_id.as_str()));
+    assert_eq!(records[0].exit_code, Some(0));
+    assert_eq!(records[0].provenance, expected);
+    Ok(())
+}
+
 #[tokio::test]
 async fn user_shell_cmd_can_be_interrupted() {
     // Set up isolated config and conversation.
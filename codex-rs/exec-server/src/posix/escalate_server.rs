@@ -10,6 +10,7 @@ use path_absolutize::Absolutize as _;
 
 use codex_core::SandboxState;
 use codex_core::exec::process_exec_tool_call;
+use codex_core::protected_paths::ProtectedPaths;
 use codex_core::protocol_config_types::WindowsSandboxLevel;
 use codex_core::sandboxing::SandboxPermissions;
 use tokio::process::Command;
@@ -95,6 +96,7 @@ impl EscalateServer {
             },
             &sandbox_state.sandbox_policy,
             &sandbox_state.sandbox_cwd,
+            &ProtectedPaths::default(),
             &sandbox_state.codex_linux_sandbox_exe,
             sandbox_state.use_linux_sandbox_bwrap,
             None,
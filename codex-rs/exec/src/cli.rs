@@ -59,6 +59,11 @@ pub struct Cli {
     )]
     pub dangerously_bypass_approvals_and_sandbox: bool,
 
+    /// Run in read-only explore mode: the sandbox denies all writes and
+    /// apply_patch calls are reported as proposals instead of being applied.
+    #[arg(long = "read-only", default_value_t = false, global = true)]
+    pub read_only: bool,
+
     /// Tell the agent to use the specified directory as its working root.
     #[clap(long = "cd", short = 'C', value_name = "DIR")]
     pub cwd: Option<PathBuf>,
@@ -95,16 +100,106 @@ pub struct Cli {
     )]
     pub json: bool,
 
+    /// How piped stdin is used: as the whole prompt, or as a context
+    /// document attached ahead of the PROMPT argument.
+    #[arg(
+        long = "stdin-as",
+        value_enum,
+        value_name = "MODE",
+        default_value_t = StdinMode::Prompt,
+        global = true
+    )]
+    pub stdin_as: StdinMode,
+
+    /// Kind of document piped in with `--stdin-as context`. Detected from the
+    /// content when omitted.
+    #[arg(
+        long = "stdin-format",
+        value_enum,
+        value_name = "FORMAT",
+        global = true
+    )]
+    pub stdin_format: Option<StdinFormat>,
+
+    /// Shape of the `--json` event stream. Version 2 emits each item once, as
+    /// a typed record tagged with `schema_version`.
+    #[arg(
+        long = "output-schema-version",
+        value_enum,
+        value_name = "VERSION",
+        default_value_t = OutputSchemaVersion::V1,
+        requires = "json",
+        global = true
+    )]
+    pub output_schema_version: OutputSchemaVersion,
+
     /// Specifies file where the last message from the agent should be written.
     #[arg(long = "output-last-message", short = 'o', value_name = "FILE")]
     pub last_message_file: Option<PathBuf>,
 
+    /// What the exit status reflects: `agent` (the run completed), `tests`
+    /// (the `--test-command` result) or `review` (the most urgent review
+    /// finding). The mapping is documented in docs/exec.md.
+    #[arg(
+        long = "exit-code-from",
+        value_enum,
+        value_name = "SOURCE",
+        default_value_t = ExitCodeFrom::Agent,
+        global = true
+    )]
+    pub exit_code_from: ExitCodeFrom,
+
+    /// Shell command run in the working directory once the agent is done,
+    /// e.g. `cargo test`. Its output is written to stderr.
+    #[arg(long = "test-command", value_name = "CMD", global = true)]
+    pub test_command: Option<String>,
+
+    #[clap(flatten)]
+    pub watch: WatchArgs,
+
     /// Initial instructions for the agent. If not provided as an argument (or
     /// if `-` is used), instructions are read from stdin.
     #[arg(value_name = "PROMPT", value_hint = clap::ValueHint::Other)]
     pub prompt: Option<String>,
 }
 
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    /// After the run, keep watching the working directory and re-run the
+    /// prompt whenever files change. Stop with Ctrl-C.
+    #[arg(long = "watch", default_value_t = false)]
+    pub watch: bool,
+
+    /// Quiet period after the last change before a re-run starts.
+    #[arg(
+        long = "watch-debounce-ms",
+        value_name = "MS",
+        default_value_t = 500,
+        requires = "watch"
+    )]
+    pub debounce_ms: u64,
+
+    /// Glob, relative to the working directory, whose changes are ignored.
+    /// Repeat to add more patterns. `.git` is always ignored.
+    #[arg(
+        long = "watch-ignore",
+        value_name = "GLOB",
+        num_args = 1,
+        requires = "watch"
+    )]
+    pub ignore: Vec<String>,
+
+    /// Most runs in a row that changes made during the previous run (such as
+    /// the agent's own edits) may trigger.
+    #[arg(
+        long = "watch-max-runs",
+        value_name = "N",
+        default_value_t = 3,
+        requires = "watch"
+    )]
+    pub max_consecutive_runs: usize,
+}
+
 #[derive(Debug, clap::Subcommand)]
 pub enum Command {
     /// Resume a previous session by id or pick the most recent with --last.
@@ -141,6 +236,30 @@ struct ResumeArgsRaw {
     )]
     images: Vec<PathBuf>,
 
+    /// Keep sending follow-up turns while the model reports that the task
+    /// needs more of them.
+    #[arg(long = "continue-until-done", default_value_t = false)]
+    continue_until_done: bool,
+
+    /// Most turns, including the first, that `--continue-until-done` may run.
+    #[arg(
+        long = "max-turns",
+        value_name = "N",
+        default_value_t = 10,
+        requires = "continue_until_done"
+    )]
+    max_turns: usize,
+
+    /// Reuse the sandbox permissions the session was last running with
+    /// instead of the ones configured for this run.
+    #[arg(long = "inherit-approvals", default_value_t = false)]
+    inherit_approvals: bool,
+
+    /// Resume even if another process is recording the session; that
+    /// process stops writing to it.
+    #[arg(long = "force-takeover", default_value_t = false)]
+    force_takeover: bool,
+
     /// Prompt to send after resuming the session. If `-` is used, read from stdin.
     #[arg(value_name = "PROMPT", value_hint = clap::ValueHint::Other)]
     prompt: Option<String>,
@@ -161,6 +280,21 @@ pub struct ResumeArgs {
     /// Optional image(s) to attach to the prompt sent after resuming.
     pub images: Vec<PathBuf>,
 
+    /// Keep sending follow-up turns while the model reports that the task
+    /// needs more of them.
+    pub continue_until_done: bool,
+
+    /// Most turns, including the first, that `--continue-until-done` may run.
+    pub max_turns: usize,
+
+    /// Reuse the sandbox permissions the session was last running with
+    /// instead of the ones configured for this run.
+    pub inherit_approvals: bool,
+
+    /// Resume even if another process is recording the session; that
+    /// process stops writing to it.
+    pub force_takeover: bool,
+
     /// Prompt to send after resuming the session. If `-` is used, read from stdin.
     pub prompt: Option<String>,
 }
@@ -179,6 +313,10 @@ impl From<ResumeArgsRaw> for ResumeArgs {
             last: raw.last,
             all: raw.all,
             images: raw.images,
+            continue_until_done: raw.continue_until_done,
+            max_turns: raw.max_turns,
+            inherit_approvals: raw.inherit_approvals,
+            force_takeover: raw.force_takeover,
             prompt,
         }
     }
@@ -211,15 +349,23 @@ pub struct ReviewArgs {
     #[arg(
         long = "uncommitted",
         default_value_t = false,
-        conflicts_with_all = ["base", "commit", "prompt"]
+        conflicts_with_all = ["staged", "base", "commit", "files", "prompt"]
     )]
     pub uncommitted: bool,
 
-    /// Review changes against the given base branch.
+    /// Review only the changes staged in the index.
+    #[arg(
+        long = "staged",
+        default_value_t = false,
+        conflicts_with_all = ["uncommitted", "base", "commit", "files", "prompt"]
+    )]
+    pub staged: bool,
+
+    /// Review changes against the given base branch or ref.
     #[arg(
         long = "base",
-        value_name = "BRANCH",
-        conflicts_with_all = ["uncommitted", "commit", "prompt"]
+        value_name = "REF",
+        conflicts_with_all = ["uncommitted", "staged", "commit", "files", "prompt"]
     )]
     pub base: Option<String>,
 
@@ -227,10 +373,20 @@ pub struct ReviewArgs {
     #[arg(
         long = "commit",
         value_name = "SHA",
-        conflicts_with_all = ["uncommitted", "base", "prompt"]
+        conflicts_with_all = ["uncommitted", "staged", "base", "files", "prompt"]
     )]
     pub commit: Option<String>,
 
+    /// Review the current contents of files matching a glob, relative to the
+    /// working directory. Repeat to add more patterns.
+    #[arg(
+        long = "files",
+        value_name = "GLOB",
+        num_args = 1,
+        conflicts_with_all = ["uncommitted", "staged", "base", "commit", "prompt"]
+    )]
+    pub files: Vec<String>,
+
     /// Optional commit title to display in the review summary.
     #[arg(long = "title", value_name = "TITLE", requires = "commit")]
     pub commit_title: Option<String>,
@@ -238,6 +394,95 @@ pub struct ReviewArgs {
     /// Custom review instructions. If `-` is used, read from stdin.
     #[arg(value_name = "PROMPT", value_hint = clap::ValueHint::Other)]
     pub prompt: Option<String>,
+
+    /// Materialize findings as TODO/FIXME comments at the reported lines:
+    /// `comments` edits the files after confirmation, `patch` writes
+    /// `review.patch` in the working directory instead.
+    #[arg(long = "annotate", value_enum, value_name = "MODE")]
+    pub annotate: Option<ReviewAnnotateMode>,
+
+    /// Apply `--annotate comments` without asking for confirmation.
+    #[arg(
+        long = "yes",
+        short = 'y',
+        default_value_t = false,
+        requires = "annotate"
+    )]
+    pub yes: bool,
+
+    /// Exit with status 1 when a finding has this priority or a more urgent
+    /// one (`p0` is the most urgent), e.g. to block a push from a git hook.
+    #[arg(
+        long = "fail-on",
+        value_enum,
+        value_name = "PRIORITY",
+        ignore_case = true
+    )]
+    pub fail_on: Option<ReviewPriority>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum ReviewAnnotateMode {
+    Comments,
+    Patch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum ReviewPriority {
+    P0,
+    P1,
+    P2,
+    P3,
+}
+
+impl ReviewPriority {
+    /// The `priority` value review findings carry for this level.
+    pub fn level(self) -> i32 {
+        match self {
+            ReviewPriority::P0 => 0,
+            ReviewPriority::P1 => 1,
+            ReviewPriority::P2 => 2,
+            ReviewPriority::P3 => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum ExitCodeFrom {
+    #[default]
+    Agent,
+    Tests,
+    Review,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputSchemaVersion {
+    #[default]
+    #[value(name = "1")]
+    V1,
+    #[value(name = "2")]
+    V2,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum StdinMode {
+    #[default]
+    Prompt,
+    Context,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum StdinFormat {
+    Text,
+    Diff,
+    Log,
+    Json,
+    Markdown,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
@@ -283,4 +528,72 @@ mod tests {
         });
         assert_eq!(effective_prompt.as_deref(), Some(PROMPT));
     }
+
+    #[test]
+    fn resume_last_accepts_continuation_flags_before_prompt() {
+        let cli = Cli::parse_from([
+            "codex-exec",
+            "resume",
+            "--last",
+            "--continue-until-done",
+            "--max-turns",
+            "4",
+            "--inherit-approvals",
+            "--force-takeover",
+            "--json",
+            "finish the migration",
+        ]);
+
+        let Some(Command::Resume(args)) = cli.command else {
+            panic!("expected resume command");
+        };
+        assert!(args.continue_until_done);
+        assert!(args.inherit_approvals);
+        assert!(args.force_takeover);
+        assert_eq!(args.max_turns, 4);
+        assert_eq!(args.prompt.as_deref(), Some("finish the migration"));
+    }
+
+    #[test]
+    fn stdin_context_flags_parse_for_new_sessions() {
+        let cli = Cli::parse_from([
+            "codex-exec",
+            "--stdin-as",
+            "context",
+            "--stdin-format",
+            "diff",
+            "review this diff",
+        ]);
+
+        assert_eq!(cli.stdin_as, StdinMode::Context);
+        assert_eq!(cli.stdin_format, Some(StdinFormat::Diff));
+        assert_eq!(cli.prompt.as_deref(), Some("review this diff"));
+    }
+
+    #[test]
+    fn exit_code_flags_parse_after_review_subcommand() {
+        let cli = Cli::parse_from([
+            "codex-exec",
+            "review",
+            "--uncommitted",
+            "--exit-code-from",
+            "review",
+            "--test-command",
+            "cargo test",
+        ]);
+
+        assert_eq!(cli.exit_code_from, ExitCodeFrom::Review);
+        assert_eq!(cli.test_command.as_deref(), Some("cargo test"));
+        assert!(matches!(cli.command, Some(Command::Review(_))));
+    }
+
+    #[test]
+    fn output_schema_version_requires_json() {
+        let cli = Cli::parse_from(["codex-exec", "--json", "--output-schema-version", "2", "hi"]);
+        assert_eq!(cli.output_schema_version, OutputSchemaVersion::V2);
+
+        let err = Cli::try_parse_from(["codex-exec", "--output-schema-version", "2", "hi"])
+            .expect_err("--output-schema-version without --json should fail");
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+    }
 }
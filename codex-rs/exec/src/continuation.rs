@@ -0,0 +1,95 @@
+//! `codex exec resume --continue-until-done`: keeps sending follow-up turns
+//! for as long as the model says the task needs more of them.
+//!
+//! The resumed prompt is extended with an instruction to end the final
+//! message with [`CONTINUE_MARKER`] while work remains. Each completed turn
+//! whose last agent message ends with the marker is followed by a short
+//! "continue" turn, up to `--max-turns` turns in total.
+
+/// Line the model appends to its final message to ask for another turn.
+pub(crate) const CONTINUE_MARKER: &str = "[[CONTINUE]]";
+
+/// User message sent to start each follow-up turn.
+pub(crate) const CONTINUE_PROMPT: &str = "Continue with the task from where you left off.";
+
+pub(crate) struct Continuation {
+    max_turns: usize,
+    turns: usize,
+}
+
+impl Continuation {
+    pub(crate) fn new(max_turns: usize) -> Self {
+        Self {
+            max_turns: max_turns.max(1),
+            // The resumed prompt is the first turn.
+            turns: 1,
+        }
+    }
+
+    pub(crate) fn turns(&self) -> usize {
+        self.turns
+    }
+
+    /// Whether another turn should be sent after a turn that ended with
+    /// `last_agent_message`. Counts the turn when it returns `true`.
+    pub(crate) fn next_turn(&mut self, last_agent_message: Option<&str>) -> NextTurn {
+        if !wants_another_turn(last_agent_message) {
+            return NextTurn::Done;
+        }
+        if self.turns >= self.max_turns {
+            return NextTurn::LimitReached;
+        }
+        self.turns += 1;
+        NextTurn::Continue
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NextTurn {
+    Continue,
+    Done,
+    LimitReached,
+}
+
+/// Instruction appended to the resumed prompt so the model knows how to ask
+/// for another turn.
+pub(crate) fn continuation_instructions() -> String {
+    format!(
+        "This run is unattended. If the task needs more turns after this one, end your final \
+         message with `{CONTINUE_MARKER}` on its own line and you will be asked to continue. \
+         Omit it once the task is complete."
+    )
+}
+
+fn wants_another_turn(last_agent_message: Option<&str>) -> bool {
+    last_agent_message
+        .and_then(|message| message.trim_end().lines().last())
+        .is_some_and(|line| line.trim() == CONTINUE_MARKER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn marker_must_be_the_last_line() {
+        assert!(wants_another_turn(Some("Step 1 done.\n[[CONTINUE]]\n")));
+        assert!(!wants_another_turn(Some(
+            "I will print [[CONTINUE]] when needed.\nAll done."
+        )));
+        assert!(!wants_another_turn(Some("All done.")));
+        assert!(!wants_another_turn(None));
+    }
+
+    #[test]
+    fn stops_at_the_turn_limit() {
+        let mut continuation = Continuation::new(2);
+        let wants_more = Some("partial\n[[CONTINUE]]");
+
+        assert_eq!(continuation.next_turn(wants_more), NextTurn::Continue);
+        assert_eq!(continuation.turns(), 2);
+        assert_eq!(continuation.next_turn(wants_more), NextTurn::LimitReached);
+        assert_eq!(continuation.next_turn(Some("finished")), NextTurn::Done);
+    }
+}
@@ -4,6 +4,7 @@ use codex_core::config::Config;
 use codex_core::protocol::AgentMessageEvent;
 use codex_core::protocol::AgentReasoningRawContentEvent;
 use codex_core::protocol::AgentStatus;
+use codex_core::protocol::ApprovalTimedOutEvent;
 use codex_core::protocol::BackgroundEventEvent;
 use codex_core::protocol::CollabAgentInteractionBeginEvent;
 use codex_core::protocol::CollabAgentInteractionEndEvent;
@@ -20,12 +21,16 @@ use codex_core::protocol::EventMsg;
 use codex_core::protocol::ExecCommandBeginEvent;
 use codex_core::protocol::ExecCommandEndEvent;
 use codex_core::protocol::FileChange;
+use codex_core::protocol::GuardrailBlockedEvent;
+use codex_core::protocol::GuardrailReason;
 use codex_core::protocol::ItemCompletedEvent;
 use codex_core::protocol::McpInvocation;
 use codex_core::protocol::McpToolCallBeginEvent;
 use codex_core::protocol::McpToolCallEndEvent;
 use codex_core::protocol::PatchApplyBeginEvent;
 use codex_core::protocol::PatchApplyEndEvent;
+use codex_core::protocol::PatchProposalEvent;
+use codex_core::protocol::ReviewDecision;
 use codex_core::protocol::SessionConfiguredEvent;
 use codex_core::protocol::StreamErrorEvent;
 use codex_core::protocol::TurnAbortReason;
@@ -124,6 +129,60 @@ impl EventProcessorWithHumanOutput {
             }
         }
     }
+
+    /// Prints each file change with colored diff markers so it's easy to
+    /// scan in the terminal output.
+    fn print_file_changes(&self, changes: &HashMap<PathBuf, FileChange>) {
+        for (path, change) in changes.iter() {
+            match change {
+                FileChange::Add { content } => {
+                    let header =
+                        format!("{} {}", format_file_change(change), path.to_string_lossy());
+                    eprintln!("{}", header.style(self.magenta));
+                    for line in content.lines() {
+                        eprintln!("{}", line.style(self.green));
+                    }
+                }
+                FileChange::Delete { content } => {
+                    let header =
+                        format!("{} {}", format_file_change(change), path.to_string_lossy());
+                    eprintln!("{}", header.style(self.magenta));
+                    for line in content.lines() {
+                        eprintln!("{}", line.style(self.red));
+                    }
+                }
+                FileChange::Update {
+                    unified_diff,
+                    move_path,
+                } => {
+                    let header = if let Some(dest) = move_path {
+                        format!(
+                            "{} {} -> {}",
+                            format_file_change(change),
+                            path.to_string_lossy(),
+                            dest.to_string_lossy()
+                        )
+                    } else {
+                        format!("{} {}", format_file_change(change), path.to_string_lossy())
+                    };
+                    eprintln!("{}", header.style(self.magenta));
+
+                    // Colorize diff lines. We keep file header lines
+                    // (--- / +++) without extra coloring so they are
+                    // still readable.
+                    for diff_line in unified_diff.lines() {
+                        if diff_line.starts_with('+') && !diff_line.starts_with("+++") {
+                            eprintln!("{}", diff_line.style(self.green));
+                        } else if diff_line.starts_with('-') && !diff_line.starts_with("---") {
+                            eprintln!("{}", diff_line.style(self.red));
+                        } else {
+                            eprintln!("{diff_line}");
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 struct PatchApplyBegin {
@@ -322,8 +381,10 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                 aggregated_output,
                 duration,
                 exit_code,
+                timed_out,
                 ..
             }) => {
+                let timeout_secs = duration.as_secs_f64().round();
                 let duration = format!(" in {}", format_duration(duration));
 
                 let truncated_output = aggregated_output
@@ -332,6 +393,10 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                     .collect::<Vec<_>>()
                     .join("\n");
                 match exit_code {
+                    _ if timed_out => {
+                        let title = format!(" timed out after {timeout_secs}s:");
+                        ts_msg!(self, "{}", title.style(self.red));
+                    }
                     0 => {
                         let title = format!(" succeeded{duration}:");
                         ts_msg!(self, "{}", title.style(self.green));
@@ -392,6 +457,7 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                 call_id: _,
                 query,
                 action,
+                citations: _,
             }) => {
                 let detail = web_search_detail(Some(&action), &query);
                 if detail.is_empty() {
@@ -422,65 +488,58 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                     "file update".style(self.magenta).style(self.italic),
                 );
 
-                // Pretty-print the patch summary with colored diff markers so
-                // it's easy to scan in the terminal output.
-                for (path, change) in changes.iter() {
-                    match change {
-                        FileChange::Add { content } => {
-                            let header = format!(
-                                "{} {}",
-                                format_file_change(change),
-                                path.to_string_lossy()
-                            );
-                            eprintln!("{}", header.style(self.magenta));
-                            for line in content.lines() {
-                                eprintln!("{}", line.style(self.green));
-                            }
-                        }
-                        FileChange::Delete { content } => {
-                            let header = format!(
-                                "{} {}",
-                                format_file_change(change),
-                                path.to_string_lossy()
-                            );
-                            eprintln!("{}", header.style(self.magenta));
-                            for line in content.lines() {
-                                eprintln!("{}", line.style(self.red));
-                            }
-                        }
-                        FileChange::Update {
-                            unified_diff,
-                            move_path,
-                        } => {
-                            let header = if let Some(dest) = move_path {
-                                format!(
-                                    "{} {} -> {}",
-                                    format_file_change(change),
-                                    path.to_string_lossy(),
-                                    dest.to_string_lossy()
-                                )
-                            } else {
-                                format!("{} {}", format_file_change(change), path.to_string_lossy())
-                            };
-                            eprintln!("{}", header.style(self.magenta));
-
-                            // Colorize diff lines. We keep file header lines
-                            // (--- / +++) without extra coloring so they are
-                            // still readable.
-                            for diff_line in unified_diff.lines() {
-                                if diff_line.starts_with('+') && !diff_line.starts_with("+++") {
-                                    eprintln!("{}", diff_line.style(self.green));
-                                } else if diff_line.starts_with('-')
-                                    && !diff_line.starts_with("---")
-                                {
-                                    eprintln!("{}", diff_line.style(self.red));
-                                } else {
-                                    eprintln!("{diff_line}");
-                                }
-                            }
-                        }
+                self.print_file_changes(&changes);
+            }
+            EventMsg::PatchProposal(PatchProposalEvent { changes, .. }) => {
+                ts_msg!(
+                    self,
+                    "{}",
+                    "proposed change (not applied)"
+                        .style(self.magenta)
+                        .style(self.italic),
+                );
+                self.print_file_changes(&changes);
+            }
+            EventMsg::ApprovalTimedOut(ApprovalTimedOutEvent {
+                timeout_seconds,
+                action,
+                decision,
+                ..
+            }) => {
+                let outcome = if matches!(decision, ReviewDecision::Approved) {
+                    "approved"
+                } else {
+                    "denied"
+                };
+                ts_msg!(
+                    self,
+                    "{}",
+                    format!("approval timed out after {timeout_seconds}s ({action}): {outcome}")
+                        .style(self.yellow),
+                );
+            }
+            EventMsg::GuardrailBlocked(GuardrailBlockedEvent {
+                paths,
+                patterns,
+                reason,
+                ..
+            }) => {
+                let paths = paths
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let rule = match reason {
+                    GuardrailReason::ProtectedPaths => {
+                        format!("protected_paths: {}", patterns.join(", "))
                     }
-                }
+                    GuardrailReason::EditLimits => "edit_limits".to_string(),
+                };
+                ts_msg!(
+                    self,
+                    "{} {paths} ({rule})",
+                    "blocked by guardrail:".style(self.red).style(self.bold),
+                );
             }
             EventMsg::PatchApplyEnd(PatchApplyEndEvent {
                 call_id,
@@ -775,6 +834,12 @@ impl EventProcessor for EventProcessorWithHumanOutput {
             | EventMsg::GetHistoryEntryResponse(_)
             | EventMsg::McpListToolsResponse(_)
             | EventMsg::ListCustomPromptsResponse(_)
+            | EventMsg::SessionChanges(_)
+            | EventMsg::DryRunPlan(_)
+            | EventMsg::SessionNote(_)
+            | EventMsg::SessionSummary(_)
+            | EventMsg::TurnSources(_)
+            | EventMsg::ContextBreakdown(_)
             | EventMsg::ListSkillsResponse(_)
             | EventMsg::ListRemoteSkillsResponse(_)
             | EventMsg::RemoteSkillDownloaded(_)
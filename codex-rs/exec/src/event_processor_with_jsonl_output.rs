@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::AtomicU64;
 
+use crate::cli::OutputSchemaVersion;
 use crate::event_processor::CodexStatus;
 use crate::event_processor::EventProcessor;
 use crate::event_processor::handle_last_message;
@@ -26,6 +27,7 @@ use crate::exec_events::McpToolCallStatus;
 use crate::exec_events::PatchApplyStatus;
 use crate::exec_events::PatchChangeKind;
 use crate::exec_events::ReasoningItem;
+use crate::exec_events::ReviewFindingItem;
 use crate::exec_events::ThreadErrorEvent;
 use crate::exec_events::ThreadEvent;
 use crate::exec_events::ThreadItem;
@@ -37,6 +39,8 @@ use crate::exec_events::TurnCompletedEvent;
 use crate::exec_events::TurnFailedEvent;
 use crate::exec_events::TurnStartedEvent;
 use crate::exec_events::Usage;
+use crate::exec_events::UserMessageItem;
+use crate::exec_events::VersionedThreadEvent;
 use crate::exec_events::WebSearchItem;
 use codex_core::config::Config;
 use codex_core::protocol;
@@ -58,6 +62,7 @@ use tracing::warn;
 
 pub struct EventProcessorWithJsonOutput {
     last_message_path: Option<PathBuf>,
+    schema_version: OutputSchemaVersion,
     last_proposed_plan: Option<String>,
     next_event_id: AtomicU64,
     // Tracks running commands by call_id, including the associated item id.
@@ -66,6 +71,9 @@ pub struct EventProcessorWithJsonOutput {
     // Tracks the todo list for the current turn (at most one per turn).
     running_todo_list: Option<RunningTodoList>,
     last_total_token_usage: Option<codex_core::protocol::TokenUsage>,
+    // Session totals when the current turn started, so `turn.completed`
+    // reports only that turn's usage (resumed sessions start non-zero).
+    turn_start_token_usage: Option<codex_core::protocol::TokenUsage>,
     running_mcp_tool_calls: HashMap<String, RunningMcpToolCall>,
     running_collab_tool_calls: HashMap<String, RunningCollabToolCall>,
     running_web_search_calls: HashMap<String, String>,
@@ -103,12 +111,14 @@ impl EventProcessorWithJsonOutput {
     pub fn new(last_message_path: Option<PathBuf>) -> Self {
         Self {
             last_message_path,
+            schema_version: OutputSchemaVersion::V1,
             last_proposed_plan: None,
             next_event_id: AtomicU64::new(0),
             running_commands: HashMap::new(),
             running_patch_applies: HashMap::new(),
             running_todo_list: None,
             last_total_token_usage: None,
+            turn_start_token_usage: None,
             running_mcp_tool_calls: HashMap::new(),
             running_collab_tool_calls: HashMap::new(),
             running_web_search_calls: HashMap::new(),
@@ -116,10 +126,17 @@ impl EventProcessorWithJsonOutput {
         }
     }
 
+    pub fn with_schema_version(mut self, schema_version: OutputSchemaVersion) -> Self {
+        self.schema_version = schema_version;
+        self
+    }
+
     pub fn collect_thread_events(&mut self, event: &protocol::Event) -> Vec<ThreadEvent> {
-        match &event.msg {
+        let events = match &event.msg {
             protocol::EventMsg::SessionConfigured(ev) => self.handle_session_configured(ev),
             protocol::EventMsg::ThreadNameUpdated(_) => Vec::new(),
+            protocol::EventMsg::UserMessage(ev) => self.handle_user_message(ev),
+            protocol::EventMsg::ExitedReviewMode(ev) => self.handle_exited_review_mode(ev),
             protocol::EventMsg::AgentMessage(ev) => self.handle_agent_message(ev),
             protocol::EventMsg::ItemCompleted(protocol::ItemCompletedEvent {
                 item: codex_protocol::items::TurnItem::Plan(item),
@@ -188,6 +205,19 @@ impl EventProcessorWithJsonOutput {
             }
             protocol::EventMsg::PlanUpdate(ev) => self.handle_plan_update(ev),
             _ => Vec::new(),
+        };
+        match self.schema_version {
+            OutputSchemaVersion::V1 => events,
+            // Version 2 reports each item once, in its final state.
+            OutputSchemaVersion::V2 => events
+                .into_iter()
+                .filter(|event| {
+                    !matches!(
+                        event,
+                        ThreadEvent::ItemStarted(_) | ThreadEvent::ItemUpdated(_)
+                    )
+                })
+                .collect(),
         }
     }
 
@@ -200,9 +230,23 @@ impl EventProcessorWithJsonOutput {
     }
 
     fn handle_session_configured(
-        &self,
+        &mut self,
         payload: &protocol::SessionConfiguredEvent,
     ) -> Vec<ThreadEvent> {
+        // A resumed session replays its history; pick up the totals so far.
+        if let Some(info) =
+            payload
+                .initial_messages
+                .iter()
+                .flatten()
+                .rev()
+                .find_map(|msg| match msg {
+                    protocol::EventMsg::TokenCount(ev) => ev.info.as_ref(),
+                    _ => None,
+                })
+        {
+            self.last_total_token_usage = Some(info.total_token_usage.clone());
+        }
         vec![ThreadEvent::ThreadStarted(ThreadStartedEvent {
             thread_id: payload.session_id.to_string(),
         })]
@@ -269,6 +313,49 @@ impl EventProcessorWithJsonOutput {
         vec![ThreadEvent::ItemCompleted(ItemCompletedEvent { item })]
     }
 
+    fn handle_user_message(&self, ev: &protocol::UserMessageEvent) -> Vec<ThreadEvent> {
+        if self.schema_version == OutputSchemaVersion::V1 {
+            return Vec::new();
+        }
+        let item = ThreadItem {
+            id: self.get_next_item_id(),
+            details: ThreadItemDetails::UserMessage(UserMessageItem {
+                text: ev.message.clone(),
+            }),
+        };
+        vec![ThreadEvent::ItemCompleted(ItemCompletedEvent { item })]
+    }
+
+    fn handle_exited_review_mode(&self, ev: &protocol::ExitedReviewModeEvent) -> Vec<ThreadEvent> {
+        if self.schema_version == OutputSchemaVersion::V1 {
+            return Vec::new();
+        }
+        let Some(output) = &ev.review_output else {
+            return Vec::new();
+        };
+        output
+            .findings
+            .iter()
+            .map(|finding| {
+                let location = &finding.code_location;
+                let item = ThreadItem {
+                    id: self.get_next_item_id(),
+                    details: ThreadItemDetails::ReviewFinding(ReviewFindingItem {
+                        title: finding.title.clone(),
+                        body: finding.body.clone(),
+                        priority: finding.priority,
+                        confidence_score: finding.confidence_score,
+                        path: location.absolute_file_path.display().to_string(),
+                        start_line: location.line_range.start,
+                        end_line: location.line_range.end,
+                        category: finding.category.clone(),
+                    }),
+                };
+                ThreadEvent::ItemCompleted(ItemCompletedEvent { item })
+            })
+            .collect()
+    }
+
     fn handle_reasoning_event(&self, ev: &protocol::AgentReasoningEvent) -> Vec<ThreadEvent> {
         let item = ThreadItem {
             id: self.get_next_item_id(),
@@ -746,15 +833,17 @@ impl EventProcessorWithJsonOutput {
 
     fn handle_task_started(&mut self, _: &protocol::TurnStartedEvent) -> Vec<ThreadEvent> {
         self.last_critical_error = None;
+        self.turn_start_token_usage = self.last_total_token_usage.clone();
         vec![ThreadEvent::TurnStarted(TurnStartedEvent {})]
     }
 
     fn handle_task_complete(&mut self) -> Vec<ThreadEvent> {
         let usage = if let Some(u) = &self.last_total_token_usage {
+            let start = self.turn_start_token_usage.take().unwrap_or_default();
             Usage {
-                input_tokens: u.input_tokens,
-                cached_input_tokens: u.cached_input_tokens,
-                output_tokens: u.output_tokens,
+                input_tokens: u.input_tokens - start.input_tokens,
+                cached_input_tokens: u.cached_input_tokens - start.cached_input_tokens,
+                output_tokens: u.output_tokens - start.output_tokens,
             }
         } else {
             Usage::default()
@@ -847,7 +936,14 @@ impl EventProcessor for EventProcessorWithJsonOutput {
     fn process_event(&mut self, event: protocol::Event) -> CodexStatus {
         let aggregated = self.collect_thread_events(&event);
         for conv_event in aggregated {
-            match serde_json::to_string(&conv_event) {
+            let line = match self.schema_version {
+                OutputSchemaVersion::V1 => serde_json::to_string(&conv_event),
+                OutputSchemaVersion::V2 => serde_json::to_string(&VersionedThreadEvent {
+                    schema_version: 2,
+                    event: conv_event,
+                }),
+            };
+            match line {
                 Ok(line) => {
                     println!("{line}");
                 }
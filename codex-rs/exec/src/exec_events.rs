@@ -36,6 +36,15 @@ pub enum ThreadEvent {
     Error(ThreadErrorEvent),
 }
 
+/// A JSONL line written with `--output-schema-version 2`. Every record names
+/// its schema version so consumers can reject streams they don't understand.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct VersionedThreadEvent {
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub event: ThreadEvent,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
 pub struct ThreadStartedEvent {
     /// The identified of the new thread. Can be used to resume the thread later.
@@ -100,6 +109,9 @@ pub struct ThreadItem {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ThreadItemDetails {
+    /// Message sent to the agent on the user's behalf. Only emitted with
+    /// `--output-schema-version 2`.
+    UserMessage(UserMessageItem),
     /// Response from the agent.
     /// Either a natural-language response or a JSON string when structured output is requested.
     AgentMessage(AgentMessageItem),
@@ -123,10 +135,19 @@ pub enum ThreadItemDetails {
     /// Tracks the agent's running to-do list. It starts when the plan is first
     /// issued, updates as steps change state, and completes when the turn ends.
     TodoList(TodoListItem),
+    /// A finding reported by a review. Only emitted with
+    /// `--output-schema-version 2`, once per finding when the review ends.
+    ReviewFinding(ReviewFindingItem),
     /// Describes a non-fatal error surfaced as an item.
     Error(ErrorItem),
 }
 
+/// Message sent to the agent on the user's behalf.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct UserMessageItem {
+    pub text: String,
+}
+
 /// Response from the agent.
 /// Either a natural-language response or a JSON string when structured output is requested.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
@@ -292,6 +313,21 @@ pub struct WebSearchItem {
     pub action: WebSearchAction,
 }
 
+/// A finding reported by a review.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct ReviewFindingItem {
+    pub title: String,
+    pub body: String,
+    /// `0` is the most urgent.
+    pub priority: i32,
+    pub confidence_score: f32,
+    pub path: String,
+    pub start_line: u32,
+    pub end_line: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
 /// An error notification.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
 pub struct ErrorItem {
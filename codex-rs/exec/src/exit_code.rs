@@ -0,0 +1,219 @@
+//! `codex exec --exit-code-from`: chooses what the process exit status
+//! reports once the run is over, so scripts can branch on the part of the
+//! run they care about.
+//!
+//! The mapping is part of the CLI contract (documented in `docs/exec.md`)
+//! and must not change:
+//!
+//! - `agent`: `0` when the run completed, `1` when it failed.
+//! - `tests`: the status of `--test-command`; `128 + N` when it was killed
+//!   by signal `N`, `127` when it could not be started.
+//! - `review`: `0` without findings, `1` when the review failed, otherwise
+//!   `5 - P` for the most urgent finding priority `P` (P0 = `5` … P3 = `2`).
+
+use std::path::Path;
+use std::process::ExitStatus;
+use std::process::Stdio;
+
+use codex_core::protocol::ReviewFinding;
+
+use crate::cli::ExitCodeFrom;
+
+/// Status for a run that failed, whatever the source.
+pub(crate) const EXIT_RUN_FAILED: i32 = 1;
+
+/// Status when `--test-command` could not be started, matching the shell's
+/// "command not found".
+pub(crate) const EXIT_TEST_COMMAND_NOT_RUN: i32 = 127;
+
+/// Least urgent review priority that maps to its own status.
+const LOWEST_REVIEW_PRIORITY: i32 = 3;
+
+/// What a finished run produced, as far as exit statuses are concerned.
+pub(crate) struct RunOutcome<'a> {
+    pub(crate) agent_failed: bool,
+    pub(crate) review_findings: Option<&'a [ReviewFinding]>,
+    pub(crate) test_status: Option<i32>,
+}
+
+pub(crate) fn exit_code(source: ExitCodeFrom, outcome: &RunOutcome<'_>) -> i32 {
+    match source {
+        ExitCodeFrom::Agent if outcome.agent_failed => EXIT_RUN_FAILED,
+        ExitCodeFrom::Agent => 0,
+        ExitCodeFrom::Tests => outcome.test_status.unwrap_or(EXIT_TEST_COMMAND_NOT_RUN),
+        ExitCodeFrom::Review => match outcome.review_findings {
+            // A review that never reported back counts as failed even when
+            // no error event arrived.
+            None => EXIT_RUN_FAILED,
+            Some(_) if outcome.agent_failed => EXIT_RUN_FAILED,
+            Some(findings) => review_exit_code(findings),
+        },
+    }
+}
+
+fn review_exit_code(findings: &[ReviewFinding]) -> i32 {
+    findings
+        .iter()
+        .map(|finding| finding.priority.clamp(0, LOWEST_REVIEW_PRIORITY))
+        .min()
+        .map_or(0, |priority| LOWEST_REVIEW_PRIORITY + 2 - priority)
+}
+
+/// Runs `--test-command` through the platform shell in `cwd` and returns its
+/// status as mapped for `--exit-code-from tests`. Output goes to stderr so it
+/// never mixes with `--json` events on stdout.
+pub(crate) async fn run_test_command(command: &str, cwd: &Path) -> i32 {
+    eprintln!("[test-command] running: {command}");
+    let mut process = shell_command(command);
+    process
+        .current_dir(cwd)
+        .stdin(Stdio::null())
+        .stdout(std::io::stderr())
+        .stderr(std::io::stderr());
+    match process.status().await {
+        Ok(status) => {
+            let code = status_code(status);
+            eprintln!("[test-command] exited with status {code}");
+            code
+        }
+        Err(err) => {
+            eprintln!("[test-command] could not start `{command}`: {err}");
+            EXIT_TEST_COMMAND_NOT_RUN
+        }
+    }
+}
+
+#[cfg(unix)]
+fn shell_command(command: &str) -> tokio::process::Command {
+    let mut process = tokio::process::Command::new("sh");
+    process.arg("-c").arg(command);
+    process
+}
+
+#[cfg(windows)]
+fn shell_command(command: &str) -> tokio::process::Command {
+    let mut process = tokio::process::Command::new("cmd");
+    process.arg("/C").arg(command);
+    process
+}
+
+fn status_code(status: ExitStatus) -> i32 {
+    if let Some(code) = status.code() {
+        return code;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+    EXIT_RUN_FAILED
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::protocol::ReviewCodeLocation;
+    use codex_core::protocol::ReviewLineRange;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    fn finding(priority: i32) -> ReviewFinding {
+        ReviewFinding {
+            title: format!("P{priority} finding"),
+            body: String::new(),
+            confidence_score: 0.9,
+            priority,
+            code_location: ReviewCodeLocation {
+                absolute_file_path: PathBuf::from("/repo/src/lib.rs"),
+                line_range: ReviewLineRange { start: 1, end: 1 },
+            },
+            category: None,
+        }
+    }
+
+    fn outcome(
+        agent_failed: bool,
+        review_findings: Option<&[ReviewFinding]>,
+        test_status: Option<i32>,
+    ) -> RunOutcome<'_> {
+        RunOutcome {
+            agent_failed,
+            review_findings,
+            test_status,
+        }
+    }
+
+    #[test]
+    fn agent_source_reports_run_failure_only() {
+        let findings = [finding(0)];
+        assert_eq!(
+            exit_code(
+                ExitCodeFrom::Agent,
+                &outcome(false, Some(&findings), Some(3))
+            ),
+            0
+        );
+        assert_eq!(
+            exit_code(ExitCodeFrom::Agent, &outcome(true, None, Some(0))),
+            EXIT_RUN_FAILED
+        );
+    }
+
+    #[test]
+    fn tests_source_passes_through_test_status() {
+        assert_eq!(
+            exit_code(ExitCodeFrom::Tests, &outcome(true, None, Some(0))),
+            0
+        );
+        assert_eq!(
+            exit_code(ExitCodeFrom::Tests, &outcome(false, None, Some(101))),
+            101
+        );
+        assert_eq!(
+            exit_code(ExitCodeFrom::Tests, &outcome(false, None, None)),
+            EXIT_TEST_COMMAND_NOT_RUN
+        );
+    }
+
+    #[test]
+    fn review_source_maps_most_urgent_priority() {
+        let cases = [
+            (vec![], 0),
+            (vec![finding(3)], 2),
+            (vec![finding(3), finding(2)], 3),
+            (vec![finding(1), finding(3)], 4),
+            (vec![finding(2), finding(0)], 5),
+            (vec![finding(7)], 2),
+        ];
+        for (findings, expected) in cases {
+            assert_eq!(
+                exit_code(ExitCodeFrom::Review, &outcome(false, Some(&findings), None)),
+                expected,
+                "findings: {findings:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn review_source_fails_without_review_output() {
+        assert_eq!(
+            exit_code(ExitCodeFrom::Review, &outcome(false, None, None)),
+            EXIT_RUN_FAILED
+        );
+        assert_eq!(
+            exit_code(ExitCodeFrom::Review, &outcome(true, Some(&[]), None)),
+            EXIT_RUN_FAILED
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_command_status_is_returned() {
+        let cwd = std::env::temp_dir();
+        assert_eq!(run_test_command("exit 0", &cwd).await, 0);
+        assert_eq!(run_test_command("exit 3", &cwd).await, 3);
+        assert_eq!(run_test_command("kill -TERM $$", &cwd).await, 128 + 15);
+    }
+}
@@ -5,15 +5,25 @@
 #![deny(clippy::print_stdout)]
 
 mod cli;
+mod continuation;
 mod event_processor;
 mod event_processor_with_human_output;
 pub mod event_processor_with_jsonl_output;
 pub mod exec_events;
+mod exit_code;
+mod stdin_context;
+mod watch;
 
 pub use cli::Cli;
 pub use cli::Command;
+pub use cli::OutputSchemaVersion;
+pub use cli::ReviewAnnotateMode;
 pub use cli::ReviewArgs;
+pub use cli::ReviewPriority;
+pub use cli::StdinFormat;
+pub use cli::StdinMode;
 use codex_cloud_requirements::cloud_requirements_loader;
+use codex_common::elapsed::format_elapsed;
 use codex_common::oss::ensure_oss_provider_ready;
 use codex_common::oss::get_default_model_for_oss_provider;
 use codex_core::AuthManager;
@@ -36,9 +46,16 @@ use codex_core::protocol::AskForApproval;
 use codex_core::protocol::Event;
 use codex_core::protocol::EventMsg;
 use codex_core::protocol::Op;
+use codex_core::protocol::ReviewFinding;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
+use codex_core::protocol::RolloutItem;
+use codex_core::protocol::SandboxPolicy;
 use codex_core::protocol::SessionSource;
+use codex_core::review_annotations::REVIEW_PATCH_FILE;
+use codex_core::review_annotations::ReviewAnnotations;
+use codex_core::review_annotations::annotate_findings;
+use codex_core::terminal::no_color_requested;
 use codex_protocol::approvals::ElicitationAction;
 use codex_protocol::config_types::SandboxMode;
 use codex_protocol::user_input::UserInput;
@@ -49,6 +66,8 @@ use serde_json::Value;
 use std::collections::HashSet;
 use std::io::IsTerminal;
 use std::io::Read;
+use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use supports_color::Stream;
@@ -62,8 +81,23 @@ use tracing_subscriber::prelude::*;
 use uuid::Uuid;
 
 use crate::cli::Command as ExecCommand;
+use crate::cli::ExitCodeFrom;
+use crate::continuation::CONTINUE_PROMPT;
+use crate::continuation::Continuation;
+use crate::continuation::NextTurn;
+use crate::continuation::continuation_instructions;
 use crate::event_processor::CodexStatus;
 use crate::event_processor::EventProcessor;
+use crate::exit_code::EXIT_RUN_FAILED;
+use crate::exit_code::RunOutcome;
+use crate::exit_code::exit_code;
+use crate::exit_code::run_test_command;
+use crate::stdin_context::context_document;
+use crate::stdin_context::detect_format;
+use crate::watch::FileWatch;
+use crate::watch::WatchOptions;
+use crate::watch::changed_paths_note;
+use crate::watch::format_changed_paths;
 use codex_core::default_client::set_default_client_residency_requirement;
 use codex_core::default_client::set_default_originator;
 use codex_core::find_thread_path_by_id_str;
@@ -100,25 +134,62 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         config_profile,
         full_auto,
         dangerously_bypass_approvals_and_sandbox,
+        read_only,
         cwd,
         skip_git_repo_check,
         add_dir,
         ephemeral,
         color,
         last_message_file,
+        exit_code_from,
+        test_command,
+        watch: watch_args,
         json: json_mode,
+        output_schema_version,
+        stdin_as,
+        stdin_format,
         sandbox_mode: sandbox_mode_cli_arg,
         prompt,
         output_schema: output_schema_path,
         config_overrides,
     } = cli;
 
+    if watch_args.watch && matches!(command, Some(ExecCommand::Review(_))) {
+        anyhow::bail!("--watch cannot be used with `codex exec review`");
+    }
+    if stdin_as == StdinMode::Context && matches!(command, Some(ExecCommand::Review(_))) {
+        anyhow::bail!("--stdin-as context cannot be used with `codex exec review`");
+    }
+    if exit_code_from == ExitCodeFrom::Tests && test_command.is_none() {
+        anyhow::bail!("--exit-code-from tests requires --test-command");
+    }
+    match &command {
+        Some(ExecCommand::Review(review_cli)) => {
+            if review_cli.fail_on.is_some() && exit_code_from != ExitCodeFrom::Agent {
+                anyhow::bail!("--fail-on only applies with --exit-code-from agent");
+            }
+        }
+        _ if exit_code_from == ExitCodeFrom::Review => {
+            anyhow::bail!("--exit-code-from review requires `codex exec review`");
+        }
+        _ => {}
+    }
+    if stdin_format.is_some() && stdin_as != StdinMode::Context {
+        anyhow::bail!("--stdin-format requires --stdin-as context");
+    }
+    // A missing image would otherwise reach the model as a placeholder
+    // without failing the run.
+    check_image_paths(&images)?;
+    if let Some(ExecCommand::Resume(args)) = &command {
+        check_image_paths(&args.images)?;
+    }
+
     let (stdout_with_ansi, stderr_with_ansi) = match color {
         cli::Color::Always => (true, true),
         cli::Color::Never => (false, false),
         cli::Color::Auto => (
-            supports_color::on_cached(Stream::Stdout).is_some(),
-            supports_color::on_cached(Stream::Stderr).is_some(),
+            !no_color_requested() && supports_color::on_cached(Stream::Stdout).is_some(),
+            !no_color_requested() && supports_color::on_cached(Stream::Stderr).is_some(),
         ),
     };
 
@@ -142,6 +213,10 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
     } else {
         sandbox_mode_cli_arg.map(Into::<SandboxMode>::into)
     };
+    // `resume --inherit-approvals` only fills in permissions the command
+    // line left unspecified.
+    let sandbox_chosen_on_cli = sandbox_mode.is_some() || read_only;
+    let force_takeover = matches!(&command, Some(ExecCommand::Resume(args)) if args.force_takeover);
 
     // Parse `-c` overrides from the CLI.
     let cli_kv_overrides = match config_overrides.parse_overrides() {
@@ -286,6 +361,9 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         show_raw_agent_reasoning: oss.then_some(true),
         tools_web_search_request: None,
         ephemeral: ephemeral.then_some(true),
+        explore_mode: read_only.then_some(true),
+        offline: None,
+        force_takeover: force_takeover.then_some(true),
         additional_writable_roots: add_dir,
     };
 
@@ -331,8 +409,15 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         .with(otel_logger_layer)
         .try_init();
 
+    for issue in codex_core::i18n::install_locale_overrides(&config.codex_home) {
+        tracing::warn!("ignoring locale override: {issue}");
+    }
+
     let mut event_processor: Box<dyn EventProcessor> = match json_mode {
-        true => Box::new(EventProcessorWithJsonOutput::new(last_message_file.clone())),
+        true => Box::new(
+            EventProcessorWithJsonOutput::new(last_message_file.clone())
+                .with_schema_version(output_schema_version),
+        ),
         _ => Box::new(EventProcessorWithHumanOutput::create_with_ansi(
             stdout_with_ansi,
             &config,
@@ -366,7 +451,7 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
 
     let default_cwd = config.cwd.to_path_buf();
     let default_approval_policy = config.approval_policy.value();
-    let default_sandbox_policy = config.sandbox_policy.get();
+    let mut default_sandbox_policy = config.sandbox_policy.get().clone();
     let default_effort = config.model_reasoning_effort;
     let default_summary = config.model_reasoning_summary;
 
@@ -404,6 +489,15 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         let resume_path = resolve_resume_path(&config, args).await?;
 
         if let Some(path) = resume_path {
+            if args.inherit_approvals && !sandbox_chosen_on_cli {
+                match inherited_sandbox_policy(&path).await? {
+                    Some(policy) => {
+                        config.sandbox_policy.set(policy.clone())?;
+                        default_sandbox_policy = policy;
+                    }
+                    None => warn!("no turn context recorded in {}", path.display()),
+                }
+            }
             thread_manager
                 .resume_thread_from_rollout(config.clone(), path, auth_manager.clone())
                 .await?
@@ -413,8 +507,13 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
     } else {
         thread_manager.start_thread(config.clone()).await?
     };
+    let mut review_annotate = None;
+    let mut review_fail_on = None;
+    let mut continuation = None;
     let (initial_operation, prompt_summary) = match (command, prompt, images) {
         (Some(ExecCommand::Review(review_cli)), _, _) => {
+            review_annotate = review_cli.annotate.map(|mode| (mode, review_cli.yes));
+            review_fail_on = review_cli.fail_on;
             let review_request = build_review_request(review_cli)?;
             let summary = codex_core::review_prompts::user_facing_hint(
                 &review_request.target,
@@ -434,17 +533,26 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
                     }
                 })
                 .or(root_prompt);
+            let stdin_document = read_stdin_context(stdin_as, stdin_format, prompt_arg.as_deref())?;
             let prompt_text = resolve_prompt(prompt_arg);
             let mut items: Vec<UserInput> = imgs
                 .into_iter()
                 .chain(args.images.into_iter())
                 .map(|path| UserInput::LocalImage { path })
                 .collect();
+            items.extend(stdin_document);
             items.push(UserInput::Text {
                 text: prompt_text.clone(),
                 // CLI input doesn't track UI element ranges, so none are available here.
                 text_elements: Vec::new(),
             });
+            if args.continue_until_done {
+                items.push(UserInput::Text {
+                    text: continuation_instructions(),
+                    text_elements: Vec::new(),
+                });
+                continuation = Some(Continuation::new(args.max_turns));
+            }
             let output_schema = load_output_schema(output_schema_path.clone());
             (
                 InitialOperation::UserTurn {
@@ -455,11 +563,14 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
             )
         }
         (None, root_prompt, imgs) => {
+            let stdin_document =
+                read_stdin_context(stdin_as, stdin_format, root_prompt.as_deref())?;
             let prompt_text = resolve_prompt(root_prompt);
             let mut items: Vec<UserInput> = imgs
                 .into_iter()
                 .map(|path| UserInput::LocalImage { path })
                 .collect();
+            items.extend(stdin_document);
             items.push(UserInput::Text {
                 text: prompt_text.clone(),
                 // CLI input doesn't track UI element ranges, so none are available here.
@@ -482,6 +593,29 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
 
     info!("Codex initialized with event: {session_configured:?}");
 
+    let mut file_watch = if watch_args.watch {
+        let mut ignore = watch_args.ignore;
+        // Writing the last message must not trigger the next run.
+        if let Some(relative) = last_message_file
+            .as_deref()
+            .and_then(|path| path.strip_prefix(&config.cwd).ok())
+        {
+            ignore.push(relative.to_string_lossy().into_owned());
+        }
+        Some(FileWatch::start(
+            &config.cwd,
+            WatchOptions {
+                debounce: std::time::Duration::from_millis(watch_args.debounce_ms),
+                max_consecutive_runs: watch_args.max_consecutive_runs,
+                ignore,
+            },
+        )?)
+    } else {
+        None
+    };
+    let mut watch_turn: Option<(Vec<UserInput>, Option<Value>)> = None;
+    let mut continuation_schema: Option<Value> = None;
+
     let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<ThreadEventEnvelope>();
     let attached_threads = Arc::new(Mutex::new(HashSet::from([primary_thread_id])));
     spawn_thread_listener(primary_thread_id, thread.clone(), tx.clone());
@@ -533,12 +667,18 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
             items,
             output_schema,
         } => {
+            if file_watch.is_some() {
+                watch_turn = Some((items.clone(), output_schema.clone()));
+            }
+            if continuation.is_some() {
+                continuation_schema = output_schema.clone();
+            }
             let task_id = thread
                 .submit(Op::UserTurn {
                     items,
                     cwd: default_cwd,
                     approval_policy: default_approval_policy,
-                    sandbox_policy: default_sandbox_policy.clone(),
+                    sandbox_policy: default_sandbox_policy,
                     model: default_model,
                     effort: default_effort,
                     summary: default_summary,
@@ -562,6 +702,11 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
     // exit with a non-zero status for automation-friendly signaling.
     let mut error_seen = false;
     let mut shutdown_requested = false;
+    let mut review_findings: Option<Vec<ReviewFinding>> = None;
+    let mut run = 1;
+    let mut run_started = std::time::Instant::now();
+    let mut run_failed = false;
+    let mut final_output_printed = false;
     while let Some(envelope) = rx.recv().await {
         let ThreadEventEnvelope {
             thread_id,
@@ -570,6 +715,7 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         } = envelope;
         if matches!(event.msg, EventMsg::Error(_)) {
             error_seen = true;
+            run_failed = true;
         }
         if shutdown_requested
             && !matches!(&event.msg, EventMsg::ShutdownComplete | EventMsg::Error(_))
@@ -603,6 +749,19 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         if thread_id != primary_thread_id && matches!(&event.msg, EventMsg::TurnComplete(_)) {
             continue;
         }
+        if thread_id == primary_thread_id
+            && let EventMsg::ExitedReviewMode(exited) = &event.msg
+            && let Some(output) = &exited.review_output
+        {
+            review_findings = Some(output.findings.clone());
+        }
+        let turn_aborted = matches!(event.msg, EventMsg::TurnAborted(_));
+        let last_agent_message = match &event.msg {
+            EventMsg::TurnComplete(complete) if thread_id == primary_thread_id => {
+                complete.last_agent_message.clone()
+            }
+            _ => None,
+        };
         let shutdown = event_processor.process_event(event);
         if thread_id != primary_thread_id && matches!(shutdown, CodexStatus::InitiateShutdown) {
             continue;
@@ -610,6 +769,71 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         match shutdown {
             CodexStatus::Running => continue,
             CodexStatus::InitiateShutdown => {
+                if !shutdown_requested
+                    && !turn_aborted
+                    && !run_failed
+                    && let Some(continuation) = continuation.as_mut()
+                {
+                    match continuation.next_turn(last_agent_message.as_deref()) {
+                        NextTurn::Continue => {
+                            eprintln!(
+                                "[continue] turn {}: the model asked for another turn",
+                                continuation.turns()
+                            );
+                            thread
+                                .submit(Op::UserInput {
+                                    items: vec![UserInput::Text {
+                                        text: CONTINUE_PROMPT.to_string(),
+                                        text_elements: Vec::new(),
+                                    }],
+                                    final_output_json_schema: continuation_schema.clone(),
+                                })
+                                .await?;
+                            continue;
+                        }
+                        NextTurn::LimitReached => {
+                            eprintln!(
+                                "[continue] stopping after {} turns; the model still asked for more (raise --max-turns to allow more)",
+                                continuation.turns()
+                            );
+                        }
+                        NextTurn::Done => {}
+                    }
+                }
+                // An aborted turn (e.g. Ctrl-C) also stops watching.
+                if !shutdown_requested
+                    && !turn_aborted
+                    && let (Some(watch), Some((items, output_schema))) =
+                        (file_watch.as_mut(), watch_turn.as_ref())
+                {
+                    event_processor.print_final_output();
+                    eprintln!(
+                        "[watch] run {run} {} in {}; watching {} for changes (Ctrl-C to stop)",
+                        if run_failed { "failed" } else { "completed" },
+                        format_elapsed(run_started),
+                        watch.root().display()
+                    );
+                    if let Some(changed) = watch.next_trigger().await {
+                        run += 1;
+                        run_started = std::time::Instant::now();
+                        run_failed = false;
+                        eprintln!("[watch] run {run}: {}", format_changed_paths(&changed));
+                        let mut items = items.clone();
+                        items.push(UserInput::Text {
+                            text: changed_paths_note(&changed),
+                            text_elements: Vec::new(),
+                        });
+                        thread
+                            .submit(Op::UserInput {
+                                items,
+                                final_output_json_schema: output_schema.clone(),
+                            })
+                            .await?;
+                        continue;
+                    }
+                    file_watch = None;
+                    final_output_printed = true;
+                }
                 if !shutdown_requested {
                     thread.submit(Op::Shutdown).await?;
                     shutdown_requested = true;
@@ -619,9 +843,38 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
             CodexStatus::Shutdown => continue,
         }
     }
-    event_processor.print_final_output();
-    if error_seen {
-        std::process::exit(1);
+    if !final_output_printed {
+        event_processor.print_final_output();
+    }
+    if let (Some((mode, assume_yes)), Some(findings)) = (review_annotate, &review_findings) {
+        annotate_review(mode, assume_yes, findings, config.cwd.as_path())?;
+    }
+    // The test command runs even after a failed run: with `--exit-code-from
+    // tests` the tree's state is what the caller asked about.
+    let test_status = match &test_command {
+        Some(test_command) => Some(run_test_command(test_command, config.cwd.as_path()).await),
+        None => None,
+    };
+    if let (Some(threshold), Some(findings)) = (review_fail_on, &review_findings) {
+        let blocking = blocking_findings(findings, threshold);
+        if blocking > 0 {
+            eprintln!(
+                "{blocking} review finding(s) at P{} or more urgent.",
+                threshold.level()
+            );
+            std::process::exit(EXIT_RUN_FAILED);
+        }
+    }
+    let code = exit_code(
+        exit_code_from,
+        &RunOutcome {
+            agent_failed: error_seen,
+            review_findings: review_findings.as_deref(),
+            test_status,
+        },
+    );
+    if code != 0 {
+        std::process::exit(code);
     }
 
     Ok(())
@@ -663,6 +916,19 @@ fn spawn_thread_listener(
     });
 }
 
+/// Sandbox policy recorded by the most recent turn of a stored session.
+async fn inherited_sandbox_policy(path: &Path) -> anyhow::Result<Option<SandboxPolicy>> {
+    let history = codex_core::RolloutRecorder::get_rollout_history(path).await?;
+    Ok(history
+        .get_rollout_items()
+        .into_iter()
+        .rev()
+        .find_map(|item| match item {
+            RolloutItem::TurnContext(context) => Some(context.sandbox_policy),
+            _ => None,
+        }))
+}
+
 async fn resolve_resume_path(
     config: &Config,
     args: &crate::cli::ResumeArgs,
@@ -804,6 +1070,47 @@ fn decode_utf16(
     String::from_utf16(&units).map_err(|_| PromptDecodeError::InvalidUtf16 { encoding })
 }
 
+fn check_image_paths(images: &[PathBuf]) -> anyhow::Result<()> {
+    for path in images {
+        if !path.is_file() {
+            anyhow::bail!("image not found: {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Reads piped stdin as a context document for `--stdin-as context`. The
+/// prompt then has to come from the command line.
+fn read_stdin_context(
+    stdin_as: StdinMode,
+    format: Option<StdinFormat>,
+    prompt_arg: Option<&str>,
+) -> anyhow::Result<Option<UserInput>> {
+    if stdin_as != StdinMode::Context {
+        return Ok(None);
+    }
+    if prompt_arg.is_none_or(|prompt| prompt == "-") {
+        anyhow::bail!("--stdin-as context needs the prompt as an argument");
+    }
+    if std::io::stdin().is_terminal() {
+        anyhow::bail!("--stdin-as context expects a document piped into stdin");
+    }
+    let mut bytes = Vec::new();
+    std::io::stdin()
+        .read_to_end(&mut bytes)
+        .map_err(|e| anyhow::anyhow!("Failed to read context from stdin: {e}"))?;
+    let content = decode_prompt_bytes(&bytes)
+        .map_err(|e| anyhow::anyhow!("Failed to read context from stdin: {e}"))?;
+    if content.trim().is_empty() {
+        anyhow::bail!("No context provided via stdin.");
+    }
+    let format = format.unwrap_or_else(|| detect_format(&content));
+    Ok(Some(UserInput::Text {
+        text: context_document(&content, format),
+        text_elements: Vec::new(),
+    }))
+}
+
 fn resolve_prompt(prompt_arg: Option<String>) -> String {
     match prompt_arg {
         Some(p) if p != "-" => p,
@@ -844,9 +1151,85 @@ fn resolve_prompt(prompt_arg: Option<String>) -> String {
     }
 }
 
+/// Number of findings at `threshold` or a more urgent priority.
+fn blocking_findings(findings: &[ReviewFinding], threshold: ReviewPriority) -> usize {
+    findings
+        .iter()
+        .filter(|finding| (0..=threshold.level()).contains(&finding.priority))
+        .count()
+}
+
+/// Writes review findings into the working tree as requested by `--annotate`.
+fn annotate_review(
+    mode: ReviewAnnotateMode,
+    assume_yes: bool,
+    findings: &[ReviewFinding],
+    cwd: &Path,
+) -> anyhow::Result<()> {
+    let annotations = annotate_findings(findings);
+    if annotations.skipped > 0 {
+        eprintln!(
+            "Skipped {} finding(s) that could not be placed as comments.",
+            annotations.skipped
+        );
+    }
+    if annotations.is_empty() {
+        eprintln!("No review annotations to write.");
+        return Ok(());
+    }
+    match mode {
+        ReviewAnnotateMode::Patch => {
+            let path = cwd.join(REVIEW_PATCH_FILE);
+            std::fs::write(&path, annotations.to_patch(cwd))?;
+            eprintln!(
+                "Wrote {} review comment(s) to {}. Apply it with `git apply {REVIEW_PATCH_FILE}`.",
+                annotations.comments(),
+                path.display()
+            );
+        }
+        ReviewAnnotateMode::Comments => {
+            if !assume_yes && !confirm_annotations(&annotations)? {
+                eprintln!("Review comments were not applied.");
+                return Ok(());
+            }
+            annotations.apply()?;
+            eprintln!(
+                "Inserted {} review comment(s) into {} file(s).",
+                annotations.comments(),
+                annotations.files.len()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn confirm_annotations(annotations: &ReviewAnnotations) -> anyhow::Result<bool> {
+    if !std::io::stdin().is_terminal() {
+        eprintln!("stdin is not a terminal; pass --yes to edit files or use --annotate patch.");
+        return Ok(false);
+    }
+    for file in &annotations.files {
+        eprintln!("  {} (+{})", file.path.display(), file.comments);
+    }
+    eprint!(
+        "Insert {} review comment(s) into {} file(s)? [y/N] ",
+        annotations.comments(),
+        annotations.files.len()
+    );
+    std::io::stderr().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(matches!(
+        answer.trim().to_ascii_lowercase().as_str(),
+        "y" | "yes"
+    ))
+}
+
 fn build_review_request(args: ReviewArgs) -> anyhow::Result<ReviewRequest> {
     let target = if args.uncommitted {
         ReviewTarget::UncommittedChanges
+    } else if args.staged {
+        ReviewTarget::StagedChanges
     } else if let Some(branch) = args.base {
         ReviewTarget::BaseBranch { branch }
     } else if let Some(sha) = args.commit {
@@ -854,6 +1237,10 @@ fn build_review_request(args: ReviewArgs) -> anyhow::Result<ReviewRequest> {
             sha,
             title: args.commit_title,
         }
+    } else if !args.files.is_empty() {
+        ReviewTarget::Files {
+            patterns: args.files,
+        }
     } else if let Some(prompt_arg) = args.prompt {
         let prompt = resolve_prompt(Some(prompt_arg)).trim().to_string();
         if prompt.is_empty() {
@@ -864,7 +1251,7 @@ fn build_review_request(args: ReviewArgs) -> anyhow::Result<ReviewRequest> {
         }
     } else {
         anyhow::bail!(
-            "Specify --uncommitted, --base, --commit, or provide custom review instructions"
+            "Specify --uncommitted, --staged, --base, --commit, --files, or provide custom review instructions"
         );
     };
 
@@ -879,14 +1266,53 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn rejects_missing_image_paths() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let image = dir.path().join("mock.png");
+        std::fs::write(&image, b"png").expect("write image");
+
+        assert!(check_image_paths(std::slice::from_ref(&image)).is_ok());
+        let missing = dir.path().join("missing.png");
+        let err = check_image_paths(&[image, missing.clone()]).expect_err("missing image");
+        assert_eq!(
+            err.to_string(),
+            format!("image not found: {}", missing.display())
+        );
+    }
+
+    #[test]
+    fn fail_on_counts_findings_at_or_above_the_threshold() {
+        let finding = |priority| ReviewFinding {
+            title: format!("[P{priority}] issue"),
+            body: String::new(),
+            confidence_score: 0.8,
+            priority,
+            code_location: codex_core::protocol::ReviewCodeLocation {
+                absolute_file_path: PathBuf::from("/repo/src/lib.rs"),
+                line_range: codex_core::protocol::ReviewLineRange { start: 1, end: 1 },
+            },
+            category: None,
+        };
+        let findings = vec![finding(0), finding(2), finding(3)];
+        assert_eq!(blocking_findings(&findings, ReviewPriority::P0), 1);
+        assert_eq!(blocking_findings(&findings, ReviewPriority::P1), 1);
+        assert_eq!(blocking_findings(&findings, ReviewPriority::P2), 2);
+    }
+
     #[test]
     fn builds_uncommitted_review_request() {
         let request = build_review_request(ReviewArgs {
             uncommitted: true,
+            staged: false,
             base: None,
             commit: None,
+            files: Vec::new(),
             commit_title: None,
             prompt: None,
+            annotate: None,
+            yes: false,
+            fail_on: None,
         })
         .expect("builds uncommitted review request");
 
@@ -902,10 +1328,15 @@ mod tests {
     fn builds_commit_review_request_with_title() {
         let request = build_review_request(ReviewArgs {
             uncommitted: false,
+            staged: false,
             base: None,
             commit: Some("123456789".to_string()),
+            files: Vec::new(),
             commit_title: Some("Add review command".to_string()),
             prompt: None,
+            annotate: None,
+            yes: false,
+            fail_on: None,
         })
         .expect("builds commit review request");
 
@@ -920,14 +1351,45 @@ mod tests {
         assert_eq!(request, expected);
     }
 
+    #[test]
+    fn builds_files_review_request() {
+        let request = build_review_request(ReviewArgs {
+            uncommitted: false,
+            staged: false,
+            base: None,
+            commit: None,
+            files: vec!["src/**/*.rs".to_string(), "Cargo.toml".to_string()],
+            commit_title: None,
+            prompt: None,
+            annotate: None,
+            yes: false,
+            fail_on: None,
+        })
+        .expect("builds files review request");
+
+        let expected = ReviewRequest {
+            target: ReviewTarget::Files {
+                patterns: vec!["src/**/*.rs".to_string(), "Cargo.toml".to_string()],
+            },
+            user_facing_hint: None,
+        };
+
+        assert_eq!(request, expected);
+    }
+
     #[test]
     fn builds_custom_review_request_trims_prompt() {
         let request = build_review_request(ReviewArgs {
             uncommitted: false,
+            staged: false,
             base: None,
             commit: None,
+            files: Vec::new(),
             commit_title: None,
             prompt: Some("  custom review instructions  ".to_string()),
+            annotate: None,
+            yes: false,
+            fail_on: None,
         })
         .expect("builds custom review request");
 
@@ -0,0 +1,266 @@
+//! `codex exec --watch`: re-runs the prompt when files under the working
+//! directory change.
+//!
+//! Changes are debounced into one batch per run. Changes that land while a
+//! run is in progress (usually the agent's own edits) trigger the next run
+//! immediately, up to `--watch-max-runs` runs in a row; after that they are
+//! discarded and the watcher waits for a fresh change.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Context;
+use globset::GlobBuilder;
+use globset::GlobSet;
+use globset::GlobSetBuilder;
+use notify::Event;
+use notify::EventKind;
+use notify::RecommendedWatcher;
+use notify::RecursiveMode;
+use notify::Watcher;
+use notify::event::ModifyKind;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Patterns that are always ignored, in addition to `--watch-ignore`.
+const DEFAULT_IGNORES: [&str; 2] = [".git", ".git/**"];
+
+/// Changed paths listed in a status line before the rest are summarized.
+const MAX_LISTED_PATHS: usize = 3;
+
+pub(crate) struct WatchOptions {
+    pub(crate) debounce: Duration,
+    pub(crate) max_consecutive_runs: usize,
+    pub(crate) ignore: Vec<String>,
+}
+
+pub(crate) struct FileWatch {
+    // Dropping the watcher stops the notify callbacks.
+    _watcher: RecommendedWatcher,
+    rx: mpsc::UnboundedReceiver<notify::Result<Event>>,
+    root: PathBuf,
+    ignore: GlobSet,
+    debounce: Duration,
+    max_consecutive_runs: usize,
+    consecutive_runs: usize,
+}
+
+impl FileWatch {
+    pub(crate) fn start(root: &Path, options: WatchOptions) -> anyhow::Result<Self> {
+        let ignore = build_ignore_set(&options.ignore)?;
+        // Some backends report canonical paths; match them against a
+        // canonical root.
+        let root = std::fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |res| {
+            let _ = tx.send(res);
+        })
+        .context("failed to start file watcher")?;
+        watcher
+            .watch(&root, RecursiveMode::Recursive)
+            .with_context(|| format!("failed to watch {}", root.display()))?;
+        Ok(Self {
+            _watcher: watcher,
+            rx,
+            root,
+            ignore,
+            debounce: options.debounce,
+            max_consecutive_runs: options.max_consecutive_runs.max(1),
+            // The initial run starts the first chain.
+            consecutive_runs: 1,
+        })
+    }
+
+    pub(crate) fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Waits for the batch of changes that should start the next run, as
+    /// paths relative to the root. Returns `None` on Ctrl-C or when the
+    /// watcher stops.
+    pub(crate) async fn next_trigger(&mut self) -> Option<Vec<PathBuf>> {
+        let mut changed = BTreeSet::new();
+        while let Ok(res) = self.rx.try_recv() {
+            collect(res, &self.root, &self.ignore, &mut changed);
+        }
+
+        if !changed.is_empty() {
+            if self.consecutive_runs < self.max_consecutive_runs {
+                self.consecutive_runs += 1;
+                return self.settle(changed).await;
+            }
+            eprintln!(
+                "[watch] {} consecutive runs reached; ignoring changes made during the last run",
+                self.max_consecutive_runs
+            );
+            changed.clear();
+        }
+
+        self.consecutive_runs = 1;
+        loop {
+            tokio::select! {
+                res = self.rx.recv() => {
+                    collect(res?, &self.root, &self.ignore, &mut changed);
+                    if !changed.is_empty() {
+                        return self.settle(changed).await;
+                    }
+                }
+                _ = tokio::signal::ctrl_c() => return None,
+            }
+        }
+    }
+
+    /// Keeps collecting until no relevant change arrives for the debounce
+    /// interval.
+    async fn settle(&mut self, mut changed: BTreeSet<PathBuf>) -> Option<Vec<PathBuf>> {
+        loop {
+            tokio::select! {
+                res = self.rx.recv() => collect(res?, &self.root, &self.ignore, &mut changed),
+                _ = tokio::time::sleep(self.debounce) => break,
+                _ = tokio::signal::ctrl_c() => return None,
+            }
+        }
+        Some(changed.into_iter().collect())
+    }
+}
+
+fn collect(
+    res: notify::Result<Event>,
+    root: &Path,
+    ignore: &GlobSet,
+    changed: &mut BTreeSet<PathBuf>,
+) {
+    match res {
+        Ok(event) => changed.extend(relevant_paths(&event, root, ignore)),
+        Err(err) => warn!("file watcher error: {err}"),
+    }
+}
+
+fn build_ignore_set(patterns: &[String]) -> anyhow::Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in DEFAULT_IGNORES
+        .iter()
+        .copied()
+        .chain(patterns.iter().map(String::as_str))
+    {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .with_context(|| format!("invalid --watch-ignore pattern `{pattern}`"))?;
+        builder.add(glob);
+    }
+    Ok(builder.build()?)
+}
+
+fn relevant_paths(event: &Event, root: &Path, ignore: &GlobSet) -> Vec<PathBuf> {
+    let relevant = match event.kind {
+        EventKind::Create(_) | EventKind::Remove(_) => true,
+        EventKind::Modify(kind) => !matches!(kind, ModifyKind::Metadata(_)),
+        _ => false,
+    };
+    if !relevant {
+        return Vec::new();
+    }
+    event
+        .paths
+        .iter()
+        .filter_map(|path| path.strip_prefix(root).ok())
+        .filter(|relative| !relative.as_os_str().is_empty() && !ignore.is_match(relative))
+        .map(Path::to_path_buf)
+        .collect()
+}
+
+/// `2 files changed: src/lib.rs, docs/api.md`
+pub(crate) fn format_changed_paths(paths: &[PathBuf]) -> String {
+    let noun = if paths.len() == 1 { "file" } else { "files" };
+    let mut listed = paths
+        .iter()
+        .take(MAX_LISTED_PATHS)
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if paths.len() > MAX_LISTED_PATHS {
+        listed.push_str(&format!(" (+{} more)", paths.len() - MAX_LISTED_PATHS));
+    }
+    format!("{} {noun} changed: {listed}", paths.len())
+}
+
+/// Text appended to the prompt on re-runs so the agent knows what changed.
+pub(crate) fn changed_paths_note(paths: &[PathBuf]) -> String {
+    let list = paths
+        .iter()
+        .map(|path| format!("- {}", path.display()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("Files changed since the previous run:\n{list}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::CreateKind;
+    use notify::event::MetadataKind;
+    use pretty_assertions::assert_eq;
+
+    fn event(kind: EventKind, paths: &[&str]) -> Event {
+        paths.iter().fold(Event::new(kind), |event, path| {
+            event.add_path(PathBuf::from(path))
+        })
+    }
+
+    #[test]
+    fn ignores_git_metadata_and_configured_patterns() {
+        let ignore =
+            build_ignore_set(&["target/**".to_string(), "*.log".to_string()]).expect("ignore set");
+        let paths = relevant_paths(
+            &event(
+                EventKind::Create(CreateKind::File),
+                &[
+                    "/repo/.git/index",
+                    "/repo/target/debug/app",
+                    "/repo/run.log",
+                    "/repo/logs/run.log",
+                    "/repo/src/lib.rs",
+                ],
+            ),
+            Path::new("/repo"),
+            &ignore,
+        );
+
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("logs/run.log"), PathBuf::from("src/lib.rs")]
+        );
+    }
+
+    #[test]
+    fn metadata_only_changes_do_not_trigger() {
+        let ignore = build_ignore_set(&[]).expect("ignore set");
+        let paths = relevant_paths(
+            &event(
+                EventKind::Modify(ModifyKind::Metadata(MetadataKind::Permissions)),
+                &["/repo/src/lib.rs"],
+            ),
+            Path::new("/repo"),
+            &ignore,
+        );
+
+        assert_eq!(paths, Vec::<PathBuf>::new());
+    }
+
+    #[test]
+    fn status_line_lists_a_few_paths() {
+        let paths: Vec<PathBuf> = ["a.rs", "b.rs", "c.rs", "d.rs", "e.rs"]
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+
+        assert_eq!(
+            format_changed_paths(&paths),
+            "5 files changed: a.rs, b.rs, c.rs (+2 more)"
+        );
+        assert_eq!(format_changed_paths(&paths[..1]), "1 file changed: a.rs");
+    }
+}
@@ -11,17 +11,24 @@ use codex_core::protocol::EventMsg;
 use codex_core::protocol::ExecCommandBeginEvent;
 use codex_core::protocol::ExecCommandEndEvent;
 use codex_core::protocol::ExecCommandSource;
+use codex_core::protocol::ExitedReviewModeEvent;
 use codex_core::protocol::FileChange;
 use codex_core::protocol::McpInvocation;
 use codex_core::protocol::McpToolCallBeginEvent;
 use codex_core::protocol::McpToolCallEndEvent;
 use codex_core::protocol::PatchApplyBeginEvent;
 use codex_core::protocol::PatchApplyEndEvent;
+use codex_core::protocol::ReviewCodeLocation;
+use codex_core::protocol::ReviewFinding;
+use codex_core::protocol::ReviewLineRange;
+use codex_core::protocol::ReviewOutputEvent;
 use codex_core::protocol::SandboxPolicy;
 use codex_core::protocol::SessionConfiguredEvent;
+use codex_core::protocol::UserMessageEvent;
 use codex_core::protocol::WarningEvent;
 use codex_core::protocol::WebSearchBeginEvent;
 use codex_core::protocol::WebSearchEndEvent;
+use codex_exec::OutputSchemaVersion;
 use codex_exec::event_processor_with_jsonl_output::EventProcessorWithJsonOutput;
 use codex_exec::exec_events::AgentMessageItem;
 use codex_exec::exec_events::CollabAgentState;
@@ -42,6 +49,7 @@ use codex_exec::exec_events::McpToolCallStatus;
 use codex_exec::exec_events::PatchApplyStatus;
 use codex_exec::exec_events::PatchChangeKind;
 use codex_exec::exec_events::ReasoningItem;
+use codex_exec::exec_events::ReviewFindingItem;
 use codex_exec::exec_events::ThreadErrorEvent;
 use codex_exec::exec_events::ThreadEvent;
 use codex_exec::exec_events::ThreadItem;
@@ -53,6 +61,8 @@ use codex_exec::exec_events::TurnCompletedEvent;
 use codex_exec::exec_events::TurnFailedEvent;
 use codex_exec::exec_events::TurnStartedEvent;
 use codex_exec::exec_events::Usage;
+use codex_exec::exec_events::UserMessageItem;
+use codex_exec::exec_events::VersionedThreadEvent;
 use codex_exec::exec_events::WebSearchItem;
 use codex_protocol::ThreadId;
 use codex_protocol::config_types::ModeKind;
@@ -139,6 +149,7 @@ fn web_search_end_emits_item_completed() {
             call_id: "call-123".to_string(),
             query: query.clone(),
             action: action.clone(),
+            citations: Vec::new(),
         }),
     ));
 
@@ -204,6 +215,7 @@ fn web_search_begin_then_end_reuses_item_id() {
             call_id: "call-1".to_string(),
             query: "rust async await".to_string(),
             action: action.clone(),
+            citations: Vec::new(),
         }),
     ));
     let ThreadEvent::ItemCompleted(ItemCompletedEvent {
@@ -896,7 +908,9 @@ fn exec_command_end_success_produces_completed_command_item() {
             aggregated_output: "hi\n".to_string(),
             exit_code: 0,
             duration: Duration::from_millis(5),
+            timed_out: false,
             formatted_output: String::new(),
+            provenance: None,
         }),
     );
     let out_ok = ep.collect_thread_events(&end_ok);
@@ -983,7 +997,9 @@ fn command_execution_output_delta_updates_item_progress() {
             aggregated_output: String::new(),
             exit_code: 0,
             duration: Duration::from_millis(3),
+            timed_out: false,
             formatted_output: String::new(),
+            provenance: None,
         }),
     );
     let out_end = ep.collect_thread_events(&end);
@@ -1056,7 +1072,9 @@ fn exec_command_end_failure_produces_failed_command_item() {
             aggregated_output: String::new(),
             exit_code: 1,
             duration: Duration::from_millis(2),
+            timed_out: false,
             formatted_output: String::new(),
+            provenance: None,
         }),
     );
     let out_fail = ep.collect_thread_events(&end_fail);
@@ -1097,7 +1115,9 @@ fn exec_command_end_without_begin_is_ignored() {
             aggregated_output: String::new(),
             exit_code: 0,
             duration: Duration::from_millis(1),
+            timed_out: false,
             formatted_output: String::new(),
+            provenance: None,
         }),
     );
     let out = ep.collect_thread_events(&end_only);
@@ -1291,3 +1311,260 @@ fn task_complete_produces_turn_completed_with_usage() {
         })]
     );
 }
+
+#[test]
+fn resumed_turn_reports_only_its_own_usage() {
+    let mut ep = EventProcessorWithJsonOutput::new(None);
+    let token_count = |input_tokens, cached_input_tokens, output_tokens| {
+        let usage = codex_core::protocol::TokenUsage {
+            input_tokens,
+            cached_input_tokens,
+            output_tokens,
+            reasoning_output_tokens: 0,
+            total_tokens: 0,
+        };
+        EventMsg::TokenCount(codex_core::protocol::TokenCountEvent {
+            info: Some(codex_core::protocol::TokenUsageInfo {
+                total_token_usage: usage.clone(),
+                last_token_usage: usage,
+                model_context_window: None,
+            }),
+            rate_limits: None,
+        })
+    };
+
+    // The resumed session already used tokens before this run.
+    let session_id =
+        codex_protocol::ThreadId::from_string("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    ep.collect_thread_events(&event(
+        "e1",
+        EventMsg::SessionConfigured(SessionConfiguredEvent {
+            session_id,
+            forked_from_id: None,
+            thread_name: None,
+            model: "codex-mini-latest".to_string(),
+            model_provider_id: "test-provider".to_string(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::ReadOnly,
+            cwd: PathBuf::from("/home/user/project"),
+            reasoning_effort: None,
+            history_log_id: 0,
+            history_entry_count: 0,
+            initial_messages: Some(vec![token_count(1000, 100, 200)]),
+            network_proxy: None,
+            rollout_path: None,
+        }),
+    ));
+
+    for (id, total) in [("t1", (1500, 150, 260)), ("t2", (1800, 160, 300))] {
+        ep.collect_thread_events(&event(
+            id,
+            EventMsg::TurnStarted(codex_core::protocol::TurnStartedEvent {
+                model_context_window: None,
+                collaboration_mode_kind: ModeKind::Default,
+            }),
+        ));
+        ep.collect_thread_events(&event(id, token_count(total.0, total.1, total.2)));
+        let out = ep.collect_thread_events(&event(
+            id,
+            EventMsg::TurnComplete(codex_core::protocol::TurnCompleteEvent {
+                last_agent_message: None,
+            }),
+        ));
+        let expected = if id == "t1" {
+            Usage {
+                input_tokens: 500,
+                cached_input_tokens: 50,
+                output_tokens: 60,
+            }
+        } else {
+            Usage {
+                input_tokens: 300,
+                cached_input_tokens: 10,
+                output_tokens: 40,
+            }
+        };
+        assert_eq!(
+            out,
+            vec![ThreadEvent::TurnCompleted(TurnCompletedEvent {
+                usage: expected
+            })]
+        );
+    }
+}
+
+#[test]
+fn schema_v2_emits_user_messages_and_only_completed_items() {
+    let mut ep =
+        EventProcessorWithJsonOutput::new(None).with_schema_version(OutputSchemaVersion::V2);
+    let user = ep.collect_thread_events(&event(
+        "u1",
+        EventMsg::UserMessage(UserMessageEvent {
+            message: "fix the build".to_string(),
+            images: None,
+            local_images: Vec::new(),
+            text_elements: Vec::new(),
+        }),
+    ));
+    assert_eq!(
+        user,
+        vec![ThreadEvent::ItemCompleted(ItemCompletedEvent {
+            item: ThreadItem {
+                id: "item_0".to_string(),
+                details: ThreadItemDetails::UserMessage(UserMessageItem {
+                    text: "fix the build".to_string(),
+                }),
+            },
+        })]
+    );
+
+    let command = vec!["cargo".to_string(), "test".to_string()];
+    let cwd = std::env::current_dir().unwrap();
+    let begin = ep.collect_thread_events(&event(
+        "c1",
+        EventMsg::ExecCommandBegin(ExecCommandBeginEvent {
+            call_id: "1".to_string(),
+            process_id: None,
+            turn_id: "turn-1".to_string(),
+            command: command.clone(),
+            cwd: cwd.clone(),
+            parsed_cmd: Vec::new(),
+            source: ExecCommandSource::Agent,
+            interaction_input: None,
+        }),
+    ));
+    assert_eq!(begin, Vec::new());
+
+    let end = ep.collect_thread_events(&event(
+        "c2",
+        EventMsg::ExecCommandEnd(ExecCommandEndEvent {
+            call_id: "1".to_string(),
+            process_id: None,
+            turn_id: "turn-1".to_string(),
+            command,
+            cwd,
+            parsed_cmd: Vec::new(),
+            source: ExecCommandSource::Agent,
+            interaction_input: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            aggregated_output: "1 failed\n".to_string(),
+            exit_code: 101,
+            duration: Duration::from_millis(5),
+            timed_out: false,
+            formatted_output: String::new(),
+            provenance: None,
+        }),
+    ));
+    assert_eq!(
+        end,
+        vec![ThreadEvent::ItemCompleted(ItemCompletedEvent {
+            item: ThreadItem {
+                id: "item_1".to_string(),
+                details: ThreadItemDetails::CommandExecution(CommandExecutionItem {
+                    command: "cargo test".to_string(),
+                    aggregated_output: "1 failed\n".to_string(),
+                    exit_code: Some(101),
+                    status: CommandExecutionStatus::Failed,
+                }),
+            },
+        })]
+    );
+}
+
+#[test]
+fn schema_v1_ignores_user_messages_and_review_findings() {
+    let mut ep = EventProcessorWithJsonOutput::new(None);
+    let user = ep.collect_thread_events(&event(
+        "u1",
+        EventMsg::UserMessage(UserMessageEvent {
+            message: "hello".to_string(),
+            images: None,
+            local_images: Vec::new(),
+            text_elements: Vec::new(),
+        }),
+    ));
+    assert_eq!(user, Vec::new());
+
+    let review = ep.collect_thread_events(&event(
+        "r1",
+        EventMsg::ExitedReviewMode(ExitedReviewModeEvent {
+            review_output: Some(ReviewOutputEvent {
+                findings: vec![review_finding()],
+                ..Default::default()
+            }),
+        }),
+    ));
+    assert_eq!(review, Vec::new());
+}
+
+#[test]
+fn schema_v2_emits_one_item_per_review_finding() {
+    let mut ep =
+        EventProcessorWithJsonOutput::new(None).with_schema_version(OutputSchemaVersion::V2);
+    let out = ep.collect_thread_events(&event(
+        "r1",
+        EventMsg::ExitedReviewMode(ExitedReviewModeEvent {
+            review_output: Some(ReviewOutputEvent {
+                findings: vec![review_finding()],
+                ..Default::default()
+            }),
+        }),
+    ));
+
+    let expected = ThreadEvent::ItemCompleted(ItemCompletedEvent {
+        item: ThreadItem {
+            id: "item_0".to_string(),
+            details: ThreadItemDetails::ReviewFinding(ReviewFindingItem {
+                title: "Unchecked unwrap".to_string(),
+                body: "Panics on empty input.".to_string(),
+                priority: 1,
+                confidence_score: 0.75,
+                path: "/repo/src/lib.rs".to_string(),
+                start_line: 10,
+                end_line: 12,
+                category: Some("correctness".to_string()),
+            }),
+        },
+    });
+    assert_eq!(out, vec![expected.clone()]);
+
+    let line = serde_json::to_value(VersionedThreadEvent {
+        schema_version: 2,
+        event: expected,
+    })
+    .unwrap();
+    assert_eq!(
+        line,
+        json!({
+            "schema_version": 2,
+            "type": "item.completed",
+            "item": {
+                "id": "item_0",
+                "type": "review_finding",
+                "title": "Unchecked unwrap",
+                "body": "Panics on empty input.",
+                "priority": 1,
+                "confidence_score": 0.75,
+                "path": "/repo/src/lib.rs",
+                "start_line": 10,
+                "end_line": 12,
+                "category": "correctness",
+            },
+        })
+    );
+}
+
+fn review_finding() -> ReviewFinding {
+    ReviewFinding {
+        title: "Unchecked unwrap".to_string(),
+        body: "Panics on empty input.".to_string(),
+        confidence_score: 0.75,
+        priority: 1,
+        code_location: ReviewCodeLocation {
+            absolute_file_path: PathBuf::from("/repo/src/lib.rs"),
+            line_range: ReviewLineRange { start: 10, end: 12 },
+        },
+        category: Some("correctness".to_string()),
+    }
+}
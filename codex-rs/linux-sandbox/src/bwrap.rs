@@ -3,8 +3,8 @@
 //! This module mirrors the semantics used by the macOS Seatbelt sandbox:
 //! - the filesystem is read-only by default,
 //! - explicit writable roots are layered on top, and
-//! - sensitive subpaths such as `.git` and `.codex` remain read-only even when
-//!   their parent root is writable.
+//! - sensitive subpaths such as `.git` and `.codex`, plus any `protected_paths`
+//!   passed in, remain read-only even when their parent root is writable.
 //!
 //! The overall Linux sandbox is composed of:
 //! - seccomp + `PR_SET_NO_NEW_PRIVS` applied in-process, and
@@ -17,6 +17,7 @@ use codex_core::error::CodexErr;
 use codex_core::error::Result;
 use codex_core::protocol::SandboxPolicy;
 use codex_core::protocol::WritableRoot;
+use codex_utils_absolute_path::AbsolutePathBuf;
 
 /// Options that control how bubblewrap is invoked.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -71,6 +72,7 @@ pub(crate) fn create_bwrap_command_args(
     command: Vec<String>,
     sandbox_policy: &SandboxPolicy,
     cwd: &Path,
+    protected_paths: &[AbsolutePathBuf],
     options: BwrapOptions,
 ) -> Result<Vec<String>> {
     if sandbox_policy.has_full_disk_write_access() {
@@ -81,7 +83,7 @@ pub(crate) fn create_bwrap_command_args(
         };
     }
 
-    create_bwrap_flags(command, sandbox_policy, cwd, options)
+    create_bwrap_flags(command, sandbox_policy, cwd, protected_paths, options)
 }
 
 fn create_bwrap_flags_full_filesystem(command: Vec<String>, options: BwrapOptions) -> Vec<String> {
@@ -110,12 +112,17 @@ fn create_bwrap_flags(
     command: Vec<String>,
     sandbox_policy: &SandboxPolicy,
     cwd: &Path,
+    protected_paths: &[AbsolutePathBuf],
     options: BwrapOptions,
 ) -> Result<Vec<String>> {
     let mut args = Vec::new();
     args.push("--new-session".to_string());
     args.push("--die-with-parent".to_string());
-    args.extend(create_filesystem_args(sandbox_policy, cwd)?);
+    args.extend(create_filesystem_args(
+        sandbox_policy,
+        cwd,
+        protected_paths,
+    )?);
     // Isolate the PID namespace.
     args.push("--unshare-pid".to_string());
     if options.network_mode.should_unshare_network() {
@@ -140,8 +147,13 @@ fn create_bwrap_flags(
 ///    those writable roots so protected subpaths win.
 /// 4. `--dev-bind /dev/null /dev/null` preserves the common sink even under a
 ///    read-only root.
-fn create_filesystem_args(sandbox_policy: &SandboxPolicy, cwd: &Path) -> Result<Vec<String>> {
-    let writable_roots = sandbox_policy.get_writable_roots_with_cwd(cwd);
+fn create_filesystem_args(
+    sandbox_policy: &SandboxPolicy,
+    cwd: &Path,
+    protected_paths: &[AbsolutePathBuf],
+) -> Result<Vec<String>> {
+    let writable_roots =
+        sandbox_policy.get_writable_roots_with_protected_paths(cwd, protected_paths);
     ensure_mount_targets_exist(&writable_roots)?;
 
     let mut args = Vec::new();
@@ -320,6 +332,7 @@ mod tests {
             command.clone(),
             &SandboxPolicy::DangerFullAccess,
             Path::new("/"),
+            &[],
             BwrapOptions {
                 mount_proc: true,
                 network_mode: BwrapNetworkMode::FullAccess,
@@ -337,6 +350,7 @@ mod tests {
             command,
             &SandboxPolicy::DangerFullAccess,
             Path::new("/"),
+            &[],
             BwrapOptions {
                 mount_proc: true,
                 network_mode: BwrapNetworkMode::ProxyOnly,
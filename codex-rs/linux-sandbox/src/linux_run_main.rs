@@ -1,4 +1,5 @@
 use clap::Parser;
+use codex_utils_absolute_path::AbsolutePathBuf;
 use std::ffi::CString;
 use std::fs::File;
 use std::io::Read;
@@ -55,6 +56,11 @@ pub struct LandlockCommand {
     #[arg(long = "no-proc", default_value_t = false)]
     pub no_proc: bool,
 
+    /// Internal: keep this path read-only even inside a writable root. Used
+    /// for existing paths listed in `protected_paths`. May be repeated.
+    #[arg(long = "protected-path", hide = true)]
+    pub protected_paths: Vec<PathBuf>,
+
     /// Full command args to run under the Linux sandbox helper.
     #[arg(trailing_var_arg = true)]
     pub command: Vec<String>,
@@ -75,8 +81,13 @@ pub fn run_main() -> ! {
         apply_seccomp_then_exec,
         allow_network_for_proxy,
         no_proc,
+        protected_paths,
         command,
     } = LandlockCommand::parse();
+    let protected_paths: Vec<AbsolutePathBuf> = protected_paths
+        .iter()
+        .filter_map(|path| AbsolutePathBuf::from_absolute_path(path).ok())
+        .collect();
 
     if command.is_empty() {
         panic!("No command specified to execute.");
@@ -122,6 +133,7 @@ pub fn run_main() -> ! {
         run_bwrap_with_proc_fallback(
             &sandbox_policy_cwd,
             &sandbox_policy,
+            &protected_paths,
             inner,
             !no_proc,
             allow_network_for_proxy,
@@ -143,13 +155,16 @@ pub fn run_main() -> ! {
 fn run_bwrap_with_proc_fallback(
     sandbox_policy_cwd: &Path,
     sandbox_policy: &codex_core::protocol::SandboxPolicy,
+    protected_paths: &[AbsolutePathBuf],
     inner: Vec<String>,
     mount_proc: bool,
     allow_network_for_proxy: bool,
 ) -> ! {
     let mut mount_proc = mount_proc;
 
-    if mount_proc && !preflight_proc_mount_support(sandbox_policy_cwd, sandbox_policy) {
+    if mount_proc
+        && !preflight_proc_mount_support(sandbox_policy_cwd, sandbox_policy, protected_paths)
+    {
         eprintln!("codex-linux-sandbox: bwrap could not mount /proc; retrying with --no-proc");
         mount_proc = false;
     }
@@ -159,7 +174,13 @@ fn run_bwrap_with_proc_fallback(
         mount_proc,
         network_mode,
     };
-    let argv = build_bwrap_argv(inner, sandbox_policy, sandbox_policy_cwd, options);
+    let argv = build_bwrap_argv(
+        inner,
+        sandbox_policy,
+        sandbox_policy_cwd,
+        protected_paths,
+        options,
+    );
     exec_vendored_bwrap(argv);
 }
 
@@ -180,10 +201,17 @@ fn build_bwrap_argv(
     inner: Vec<String>,
     sandbox_policy: &codex_core::protocol::SandboxPolicy,
     sandbox_policy_cwd: &Path,
+    protected_paths: &[AbsolutePathBuf],
     options: BwrapOptions,
 ) -> Vec<String> {
-    let mut args = create_bwrap_command_args(inner, sandbox_policy, sandbox_policy_cwd, options)
-        .unwrap_or_else(|err| panic!("error building bubblewrap command: {err:?}"));
+    let mut args = create_bwrap_command_args(
+        inner,
+        sandbox_policy,
+        sandbox_policy_cwd,
+        protected_paths,
+        options,
+    )
+    .unwrap_or_else(|err| panic!("error building bubblewrap command: {err:?}"));
 
     let command_separator_index = args
         .iter()
@@ -202,12 +230,14 @@ fn build_bwrap_argv(
 fn preflight_proc_mount_support(
     sandbox_policy_cwd: &Path,
     sandbox_policy: &codex_core::protocol::SandboxPolicy,
+    protected_paths: &[AbsolutePathBuf],
 ) -> bool {
     let preflight_command = vec![resolve_true_command()];
     let preflight_argv = build_bwrap_argv(
         preflight_command,
         sandbox_policy,
         sandbox_policy_cwd,
+        protected_paths,
         BwrapOptions {
             mount_proc: true,
             network_mode: BwrapNetworkMode::FullAccess,
@@ -393,6 +423,7 @@ mod tests {
             vec!["/bin/true".to_string()],
             &SandboxPolicy::ReadOnly,
             Path::new("/"),
+            &[],
             BwrapOptions {
                 mount_proc: true,
                 network_mode: BwrapNetworkMode::FullAccess,
@@ -427,6 +458,7 @@ mod tests {
             vec!["/bin/true".to_string()],
             &SandboxPolicy::ReadOnly,
             Path::new("/"),
+            &[],
             BwrapOptions {
                 mount_proc: true,
                 network_mode: BwrapNetworkMode::Isolated,
@@ -441,6 +473,7 @@ mod tests {
             vec!["/bin/true".to_string()],
             &SandboxPolicy::ReadOnly,
             Path::new("/"),
+            &[],
             BwrapOptions {
                 mount_proc: true,
                 network_mode: BwrapNetworkMode::ProxyOnly,
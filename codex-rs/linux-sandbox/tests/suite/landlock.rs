@@ -7,6 +7,7 @@ use codex_core::error::SandboxErr;
 use codex_core::exec::ExecParams;
 use codex_core::exec::process_exec_tool_call;
 use codex_core::exec_env::create_env;
+use codex_core::protected_paths::ProtectedPaths;
 use codex_core::protocol::SandboxPolicy;
 use codex_core::protocol_config_types::WindowsSandboxLevel;
 use codex_core::sandboxing::SandboxPermissions;
@@ -101,6 +102,7 @@ async fn run_cmd_result_with_writable_roots(
         params,
         &sandbox_policy,
         sandbox_cwd.as_path(),
+        &ProtectedPaths::default(),
         &codex_linux_sandbox_exe,
         use_bwrap_sandbox,
         None,
@@ -248,6 +250,7 @@ async fn assert_network_blocked(cmd: &[&str]) {
         params,
         &sandbox_policy,
         sandbox_cwd.as_path(),
+        &ProtectedPaths::default(),
         &codex_linux_sandbox_exe,
         false,
         None,
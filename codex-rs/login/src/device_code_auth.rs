@@ -3,16 +3,25 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde::de::Deserializer;
 use serde::de::{self};
+use std::io::IsTerminal;
+use std::io::Write;
 use std::time::Duration;
 use std::time::Instant;
 
 use crate::pkce::PkceCodes;
+use crate::qr::render_qr_code;
 use crate::server::ServerOptions;
 use std::io;
 
 const ANSI_BLUE: &str = "\x1b[94m";
 const ANSI_GRAY: &str = "\x1b[90m";
 const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_CLEAR_LINE: &str = "\x1b[2K";
+
+/// How long the server accepts a one-time code.
+const DEVICE_CODE_LIFETIME: Duration = Duration::from_secs(15 * 60);
+/// How often the countdown is redrawn while waiting between polls.
+const COUNTDOWN_TICK: Duration = Duration::from_secs(1);
 
 #[derive(Debug, Clone)]
 pub struct DeviceCode {
@@ -20,6 +29,14 @@ pub struct DeviceCode {
     pub user_code: String,
     device_auth_id: String,
     interval: u64,
+    requested_at: Instant,
+}
+
+impl DeviceCode {
+    /// Time left before the one-time code expires.
+    pub fn expires_in(&self) -> Duration {
+        DEVICE_CODE_LIFETIME.saturating_sub(self.requested_at.elapsed())
+    }
 }
 
 #[derive(Deserialize)]
@@ -96,22 +113,22 @@ async fn request_user_code(
     serde_json::from_str(&body).map_err(std::io::Error::other)
 }
 
-/// Poll token endpoint until a code is issued or timeout occurs.
+/// Poll token endpoint until a code is issued or timeout occurs. `on_wait`
+/// is called about once a second between polls with the time left.
 async fn poll_for_token(
     client: &reqwest::Client,
     auth_base_url: &str,
-    device_auth_id: &str,
-    user_code: &str,
-    interval: u64,
+    device_code: &DeviceCode,
+    on_wait: Option<&(dyn Fn(Duration) + Sync)>,
 ) -> std::io::Result<CodeSuccessResp> {
     let url = format!("{auth_base_url}/deviceauth/token");
-    let max_wait = Duration::from_secs(15 * 60);
-    let start = Instant::now();
+    let max_wait = DEVICE_CODE_LIFETIME;
+    let start = device_code.requested_at;
 
     loop {
         let body = serde_json::to_string(&TokenPollReq {
-            device_auth_id: device_auth_id.to_string(),
-            user_code: user_code.to_string(),
+            device_auth_id: device_code.device_auth_id.clone(),
+            user_code: device_code.user_code.clone(),
         })
         .map_err(std::io::Error::other)?;
         let resp = client
@@ -134,8 +151,16 @@ async fn poll_for_token(
                     "device auth timed out after 15 minutes",
                 ));
             }
-            let sleep_for = Duration::from_secs(interval).min(max_wait - start.elapsed());
-            tokio::time::sleep(sleep_for).await;
+            let mut sleep_for = Duration::from_secs(device_code.interval)
+                .min(max_wait.saturating_sub(start.elapsed()));
+            while !sleep_for.is_zero() {
+                if let Some(on_wait) = on_wait {
+                    on_wait(device_code.expires_in());
+                }
+                let tick = sleep_for.min(COUNTDOWN_TICK);
+                tokio::time::sleep(tick).await;
+                sleep_for -= tick;
+            }
             continue;
         }
 
@@ -148,15 +173,43 @@ async fn poll_for_token(
 
 fn print_device_code_prompt(verification_url: &str, code: &str) {
     let version = env!("CARGO_PKG_VERSION");
+    // A QR code is only useful when someone is looking at the terminal.
+    let qr_code = if std::io::stdout().is_terminal() {
+        render_qr_code(verification_url)
+    } else {
+        None
+    };
+    let qr_code = qr_code
+        .map(|lines| {
+            let lines: Vec<String> = lines.iter().map(|line| format!("   {line}")).collect();
+            format!(
+                "\n   {ANSI_GRAY}Or scan this QR code with your phone:{ANSI_RESET}\n\n{}\n",
+                lines.join("\n")
+            )
+        })
+        .unwrap_or_default();
     println!(
         "\nWelcome to Codex [v{ANSI_GRAY}{version}{ANSI_RESET}]\n{ANSI_GRAY}OpenAI's command-line coding agent{ANSI_RESET}\n\
 \nFollow these steps to sign in with ChatGPT using device code authorization:\n\
-\n1. Open this link in your browser and sign in to your account\n   {ANSI_BLUE}{verification_url}{ANSI_RESET}\n\
+\n1. Open this link in your browser and sign in to your account\n   {ANSI_BLUE}{verification_url}{ANSI_RESET}\n{qr_code}\
 \n2. Enter this one-time code {ANSI_GRAY}(expires in 15 minutes){ANSI_RESET}\n   {ANSI_BLUE}{code}{ANSI_RESET}\n\
 \n{ANSI_GRAY}Device codes are a common phishing target. Never share this code.{ANSI_RESET}\n",
     );
 }
 
+/// Redraw the "waiting" line with the time left on the code.
+fn print_countdown(remaining: Duration) {
+    let seconds = remaining.as_secs();
+    let mut stderr = std::io::stderr();
+    let _ = write!(
+        stderr,
+        "\r{ANSI_CLEAR_LINE}{ANSI_GRAY}Waiting for you to sign in... code expires in {}:{:02}{ANSI_RESET}",
+        seconds / 60,
+        seconds % 60
+    );
+    let _ = stderr.flush();
+}
+
 pub async fn request_device_code(opts: &ServerOptions) -> std::io::Result<DeviceCode> {
     let client = reqwest::Client::new();
     let base_url = opts.issuer.trim_end_matches('/');
@@ -168,25 +221,27 @@ pub async fn request_device_code(opts: &ServerOptions) -> std::io::Result<Device
         user_code: uc.user_code,
         device_auth_id: uc.device_auth_id,
         interval: uc.interval,
+        requested_at: Instant::now(),
     })
 }
 
 pub async fn complete_device_code_login(
     opts: ServerOptions,
     device_code: DeviceCode,
+) -> std::io::Result<()> {
+    complete_device_code_login_inner(opts, device_code, None).await
+}
+
+async fn complete_device_code_login_inner(
+    opts: ServerOptions,
+    device_code: DeviceCode,
+    on_wait: Option<&(dyn Fn(Duration) + Sync)>,
 ) -> std::io::Result<()> {
     let client = reqwest::Client::new();
     let base_url = opts.issuer.trim_end_matches('/');
     let api_base_url = format!("{base_url}/api/accounts");
 
-    let code_resp = poll_for_token(
-        &client,
-        &api_base_url,
-        &device_code.device_auth_id,
-        &device_code.user_code,
-        device_code.interval,
-    )
-    .await?;
+    let code_resp = poll_for_token(&client, &api_base_url, &device_code, on_wait).await?;
 
     let pkce = PkceCodes {
         code_verifier: code_resp.code_verifier,
@@ -226,5 +281,13 @@ pub async fn complete_device_code_login(
 pub async fn run_device_code_login(opts: ServerOptions) -> std::io::Result<()> {
     let device_code = request_device_code(&opts).await?;
     print_device_code_prompt(&device_code.verification_url, &device_code.user_code);
-    complete_device_code_login(opts, device_code).await
+    let show_countdown = std::io::stderr().is_terminal();
+    let countdown: &(dyn Fn(Duration) + Sync) = &print_countdown;
+    let result =
+        complete_device_code_login_inner(opts, device_code, show_countdown.then_some(countdown))
+            .await;
+    if show_countdown {
+        eprint!("\r{ANSI_CLEAR_LINE}");
+    }
+    result
 }
@@ -1,11 +1,13 @@
 mod device_code_auth;
 mod pkce;
+mod qr;
 mod server;
 
 pub use device_code_auth::DeviceCode;
 pub use device_code_auth::complete_device_code_login;
 pub use device_code_auth::request_device_code;
 pub use device_code_auth::run_device_code_login;
+pub use qr::render_qr_code;
 pub use server::LoginServer;
 pub use server::ServerOptions;
 pub use server::ShutdownHandle;
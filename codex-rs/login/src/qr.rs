@@ -0,0 +1,65 @@
+//! Shows the device-code verification URL as a QR code in the terminal.
+
+use qrcode::Color;
+use qrcode::EcLevel;
+use qrcode::QrCode;
+
+/// Modules of light border around the symbol.
+const QUIET_ZONE: usize = 2;
+
+/// Render `text` as a QR code for the terminal, or `None` when it does not fit.
+///
+/// Uses half-block characters, two module rows per line. Light modules are
+/// drawn as blocks so the code reads correctly on the usual dark terminal
+/// background.
+pub fn render_qr_code(text: &str) -> Option<Vec<String>> {
+    let qr = QrCode::with_error_correction_level(text, EcLevel::M).ok()?;
+    let size = qr.width();
+    let total = size + QUIET_ZONE * 2;
+    let light = |x: usize, y: usize| {
+        let inside = |v: usize| (QUIET_ZONE..QUIET_ZONE + size).contains(&v);
+        !(inside(x) && inside(y)) || qr[(x - QUIET_ZONE, y - QUIET_ZONE)] == Color::Light
+    };
+    let lines = (0..total)
+        .step_by(2)
+        .map(|y| {
+            (0..total)
+                .map(|x| match (light(x, y), light(x, y + 1)) {
+                    (true, true) => '█',
+                    (true, false) => '▀',
+                    (false, true) => '▄',
+                    (false, false) => ' ',
+                })
+                .collect()
+        })
+        .collect();
+    Some(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn renders_quiet_zone_and_finder_patterns() {
+        let lines = render_qr_code("https://auth.openai.com/codex/device").expect("fits");
+        let width = lines[0].chars().count();
+        let size = width - QUIET_ZONE * 2;
+        assert_eq!(lines.len(), width.div_ceil(2));
+        assert!(lines.iter().all(|line| line.chars().count() == width));
+
+        // The first line is all quiet zone; the second starts each top finder
+        // with its dark edge above a light ring.
+        assert_eq!(lines[0], "█".repeat(width));
+        let second: Vec<char> = lines[1].chars().collect();
+        assert_eq!(second[..3], ['█', '█', ' ']);
+        assert_eq!(second[3..8], ['▄'; 5]);
+        assert_eq!(second[QUIET_ZONE + size - 1], ' ');
+    }
+
+    #[test]
+    fn overlong_text_does_not_fit() {
+        assert_eq!(render_qr_code(&"a".repeat(3000)), None);
+    }
+}
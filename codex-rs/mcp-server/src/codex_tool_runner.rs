@@ -332,7 +332,16 @@ async fn run_codex_tool_session_inner(
                     | EventMsg::StreamError(_)
                     | EventMsg::PatchApplyBegin(_)
                     | EventMsg::PatchApplyEnd(_)
+                    | EventMsg::PatchProposal(_)
+                    | EventMsg::DryRunPlan(_)
+                    | EventMsg::ApprovalTimedOut(_)
+                    | EventMsg::GuardrailBlocked(_)
                     | EventMsg::TurnDiff(_)
+                    | EventMsg::SessionChanges(_)
+                    | EventMsg::SessionNote(_)
+                    | EventMsg::SessionSummary(_)
+                    | EventMsg::TurnSources(_)
+                    | EventMsg::ContextBreakdown(_)
                     | EventMsg::WebSearchBegin(_)
                     | EventMsg::WebSearchEnd(_)
                     | EventMsg::GetHistoryEntryResponse(_)
@@ -49,13 +49,31 @@ pub enum Verbosity {
     High,
 }
 
-#[derive(Debug, Serialize, Default, Clone, Copy, PartialEq, Eq, Display, JsonSchema, TS)]
+#[derive(Debug, Serialize, Default, Clone, Copy, PartialEq, Eq, Hash, Display, JsonSchema, TS)]
 #[serde(rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub enum Language {
     #[default]
     En,
     ZhCn,
+    Ja,
+    Ko,
+    De,
+    Fr,
+    Es,
+}
+
+impl Language {
+    /// Every supported UI language, in the order shown by `/lang`.
+    pub const ALL: [Language; 7] = [
+        Language::En,
+        Language::ZhCn,
+        Language::Ja,
+        Language::Ko,
+        Language::De,
+        Language::Fr,
+        Language::Es,
+    ];
 }
 
 impl<'de> Deserialize<'de> for Language {
@@ -68,11 +86,35 @@ impl<'de> Deserialize<'de> for Language {
         Ok(match normalized.as_str() {
             "en" | "en-us" | "en_us" => Self::En,
             "zh-cn" | "zh_cn" | "zh-hans" | "zh" => Self::ZhCn,
+            "ja" | "ja-jp" | "ja_jp" => Self::Ja,
+            "ko" | "ko-kr" | "ko_kr" => Self::Ko,
+            "de" | "de-de" | "de_de" => Self::De,
+            "fr" | "fr-fr" | "fr_fr" => Self::Fr,
+            "es" | "es-es" | "es_es" => Self::Es,
             _ => Self::En,
         })
     }
 }
 
+/// What happens to an approval request nobody answers within
+/// `approval_timeout_seconds`.
+#[derive(
+    Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Display, JsonSchema, TS,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum ApprovalTimeoutAction {
+    /// Deny the request; the agent continues and may try something else.
+    #[default]
+    Deny,
+    /// Approve when the request would be allowed without asking under the
+    /// current sandbox policy (known-safe commands, patches inside writable
+    /// roots); deny otherwise.
+    AllowIfPolicySafe,
+    /// Deny the request and interrupt the running turn.
+    AbortTurn,
+}
+
 #[derive(
     Deserialize, Debug, Clone, Copy, PartialEq, Default, Serialize, Display, JsonSchema, TS,
 )]
@@ -146,12 +188,20 @@ pub enum ForcedLoginMethod {
 }
 
 /// Represents the trust level for a project directory.
-/// This determines the approval policy and sandbox mode applied.
+/// This determines the approval policy and sandbox mode applied, and which
+/// repo-provided content (project config, `AGENTS.md`, skills, rules) loads.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Display, JsonSchema, TS)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
 pub enum TrustLevel {
+    /// Everything the repo provides is loaded.
     Trusted,
+    /// Project docs and skills are loaded; project config and rules are not,
+    /// and commands default to the read-only sandbox.
+    #[serde(rename = "read-only")]
+    #[strum(serialize = "read-only")]
+    ReadOnly,
+    /// Nothing the repo provides is loaded.
     Untrusted,
 }
 
@@ -77,6 +77,22 @@ pub struct WebSearchItem {
     pub id: String,
     pub query: String,
     pub action: WebSearchAction,
+    /// Pages returned by a local search backend or read by `fetch_url`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub citations: Vec<WebCitation>,
+}
+
+/// A source the model was given, shown under the web search history entry.
+#[derive(Debug, Clone, Deserialize, Serialize, TS, JsonSchema, PartialEq)]
+pub struct WebCitation {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub title: Option<String>,
+    /// Search snippet or the start of the fetched page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub snippet: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, TS, JsonSchema)]
@@ -232,6 +248,7 @@ impl WebSearchItem {
             call_id: self.id.clone(),
             query: self.query.clone(),
             action: self.action.clone(),
+            citations: self.citations.clone(),
         })
     }
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::OnceLock;
 
 use icu_decimal::DecimalFormatter;
@@ -5,6 +6,8 @@ use icu_decimal::input::Decimal;
 use icu_decimal::options::DecimalFormatterOptions;
 use icu_locale_core::Locale;
 
+use crate::config_types::Language;
+
 fn make_local_formatter() -> Option<DecimalFormatter> {
     let loc: Locale = sys_locale::get_locale()?.parse().ok()?;
     DecimalFormatter::try_new(loc.into(), DecimalFormatterOptions::default()).ok()
@@ -28,6 +31,59 @@ pub fn format_with_separators(n: i64) -> String {
     formatter().format(&Decimal::from(n)).to_string()
 }
 
+/// The locale used to format numbers for a UI language.
+///
+/// When the system locale shares the language (e.g. `de-CH` for German) it
+/// wins so regional separators are kept; otherwise the language's default
+/// region is used.
+fn language_locale(language: Language) -> Option<Locale> {
+    let default_tag = match language {
+        Language::En => "en-US",
+        Language::ZhCn => "zh-CN",
+        Language::Ja => "ja-JP",
+        Language::Ko => "ko-KR",
+        Language::De => "de-DE",
+        Language::Fr => "fr-FR",
+        Language::Es => "es-ES",
+    };
+    let default: Locale = default_tag.parse().ok()?;
+    let system = sys_locale::get_locale().and_then(|tag| tag.parse::<Locale>().ok());
+    match system {
+        Some(system) if system.id.language == default.id.language => Some(system),
+        _ => Some(default),
+    }
+}
+
+fn language_formatter(language: Language) -> &'static DecimalFormatter {
+    static FORMATTERS: OnceLock<HashMap<Language, DecimalFormatter>> = OnceLock::new();
+    let formatters = FORMATTERS.get_or_init(|| {
+        Language::ALL
+            .into_iter()
+            .filter_map(|language| {
+                let locale = language_locale(language)?;
+                DecimalFormatter::try_new(locale.into(), DecimalFormatterOptions::default())
+                    .ok()
+                    .map(|formatter| (language, formatter))
+            })
+            .collect()
+    });
+    formatters.get(&language).unwrap_or_else(|| formatter())
+}
+
+/// Like [`format_with_separators`], but follows the UI language instead of
+/// the system locale (e.g. "12.345" for German).
+pub fn format_with_separators_for(n: i64, language: Language) -> String {
+    format_with_separators_with_formatter(n, language_formatter(language))
+}
+
+/// Formats `scaled / 10^frac_digits` using the UI language's decimal
+/// separator (e.g. `(125, 2)` -> "1.25" in English, "1,25" in French).
+pub fn format_decimal_for(scaled: i64, frac_digits: u32, language: Language) -> String {
+    let mut dec = Decimal::from(scaled);
+    dec.multiply_pow10(-(frac_digits as i16));
+    language_formatter(language).format(&dec).to_string()
+}
+
 fn format_with_separators_with_formatter(n: i64, formatter: &DecimalFormatter) -> String {
     formatter.format(&Decimal::from(n)).to_string()
 }
@@ -76,6 +132,11 @@ pub fn format_si_suffix(n: i64) -> String {
     format_si_suffix_with_formatter(n, formatter())
 }
 
+/// Like [`format_si_suffix`], but follows the UI language.
+pub fn format_si_suffix_for(n: i64, language: Language) -> String {
+    format_si_suffix_with_formatter(n, language_formatter(language))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +161,26 @@ mod tests {
         // Above 1000G we keep whole‑G precision (no higher unit supported here).
         assert_eq!(fmt(1_234_000_000_000), "1,234G");
     }
+
+    #[test]
+    fn language_locales_keep_language() {
+        for language in Language::ALL {
+            let locale = language_locale(language).expect("locale");
+            let expected = language.to_string();
+            let expected = expected.split('-').next().unwrap_or_default();
+            assert_eq!(locale.id.language.as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn german_separators() {
+        let loc: Locale = "de-DE".parse().expect("valid locale");
+        let formatter = DecimalFormatter::try_new(loc.into(), DecimalFormatterOptions::default())
+            .expect("formatter");
+        assert_eq!(
+            format_with_separators_with_formatter(1_234_567, &formatter),
+            "1.234.567"
+        );
+        assert_eq!(format_si_suffix_with_formatter(1_200, &formatter), "1,20K");
+    }
 }
@@ -268,6 +268,27 @@ impl ModelInfo {
             .is_some_and(ModelMessages::supports_personality)
     }
 
+    /// Usable context window after reserving the model's headroom.
+    pub fn effective_context_window(&self) -> Option<i64> {
+        self.context_window.map(|context_window| {
+            context_window.saturating_mul(self.effective_context_window_percent) / 100
+        })
+    }
+
+    /// Summarize what this model supports for capability checks in clients.
+    pub fn capabilities(&self) -> ModelCapabilities {
+        ModelCapabilities {
+            vision: self.input_modalities.contains(&InputModality::Image),
+            parallel_tool_calls: self.supports_parallel_tool_calls,
+            reasoning_efforts: self
+                .supported_reasoning_levels
+                .iter()
+                .map(|preset| preset.effort)
+                .collect(),
+            context_window: self.effective_context_window(),
+        }
+    }
+
     pub fn get_model_instructions(&self, personality: Option<Personality>) -> String {
         if let Some(model_messages) = &self.model_messages
             && let Some(template) = &model_messages.instructions_template
@@ -290,6 +311,27 @@ impl ModelInfo {
     }
 }
 
+/// Capabilities of a single model, derived from its [`ModelInfo`].
+///
+/// Clients use this to hide affordances the active model cannot use.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, TS, JsonSchema)]
+pub struct ModelCapabilities {
+    /// Accepts image inputs.
+    pub vision: bool,
+    /// Can issue several tool calls in one response.
+    pub parallel_tool_calls: bool,
+    /// Reasoning efforts the model accepts; empty when reasoning is not configurable.
+    pub reasoning_efforts: Vec<ReasoningEffort>,
+    /// Usable context window in tokens, when known.
+    pub context_window: Option<i64>,
+}
+
+impl ModelCapabilities {
+    pub fn supports_reasoning_effort(&self, effort: ReasoningEffort) -> bool {
+        self.reasoning_efforts.contains(&effort)
+    }
+}
+
 /// A strongly-typed template for assembling model instructions and developer messages. If
 /// instructions_* is populated and valid, it will override base_instructions.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, TS, JsonSchema)]
@@ -521,6 +563,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn capabilities_reflect_model_info() {
+        let mut model = test_model(None);
+        model.supported_reasoning_levels = vec![
+            ReasoningEffortPreset {
+                effort: ReasoningEffort::Low,
+                description: "low".to_string(),
+            },
+            ReasoningEffortPreset {
+                effort: ReasoningEffort::High,
+                description: "high".to_string(),
+            },
+        ];
+        model.supports_parallel_tool_calls = true;
+        model.context_window = Some(200_000);
+        model.input_modalities = vec![InputModality::Text];
+
+        let capabilities = model.capabilities();
+
+        assert_eq!(
+            capabilities,
+            ModelCapabilities {
+                vision: false,
+                parallel_tool_calls: true,
+                reasoning_efforts: vec![ReasoningEffort::Low, ReasoningEffort::High],
+                context_window: Some(190_000),
+            }
+        );
+        assert!(!capabilities.supports_reasoning_effort(ReasoningEffort::XHigh));
+    }
+
     #[test]
     fn get_model_instructions_uses_template_when_placeholder_present() {
         let model = test_model(Some(ModelMessages {
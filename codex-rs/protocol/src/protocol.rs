@@ -13,6 +13,7 @@ use std::time::Duration;
 
 use crate::ThreadId;
 use crate::approvals::ElicitationRequestEvent;
+use crate::config_types::ApprovalTimeoutAction;
 use crate::config_types::CollaborationMode;
 use crate::config_types::ModeKind;
 use crate::config_types::Personality;
@@ -23,6 +24,7 @@ use crate::dynamic_tools::DynamicToolCallRequest;
 use crate::dynamic_tools::DynamicToolResponse;
 use crate::dynamic_tools::DynamicToolSpec;
 use crate::items::TurnItem;
+use crate::items::WebCitation;
 use crate::mcp::CallToolResult;
 use crate::mcp::RequestId;
 use crate::mcp::Resource as McpResource;
@@ -200,6 +202,28 @@ pub enum Op {
         /// Updated built-in spec toggle for "SDD Planning" request guidance.
         #[serde(skip_serializing_if = "Option::is_none")]
         spec_sdd_planning: Option<bool>,
+
+        /// Enter (`true`) or leave (`false`) read-only explore mode.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        explore_mode: Option<bool>,
+
+        /// Updated persona (a built-in preset or one defined under
+        /// `[personas]` in config).
+        ///
+        /// Use `Some(Some(_))` to switch persona, `Some(None)` to drop the
+        /// active persona, or `None` to leave it unchanged.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        persona: Option<Option<String>>,
+
+        /// Monorepo package directory to narrow the session to: it becomes
+        /// the working directory, so tool calls run in it and only it is
+        /// writable, and its project docs are sent with each request.
+        ///
+        /// Use `Some(Some(_))` to scope, `Some(None)` to return to the
+        /// working directory the session had before, or `None` to leave the
+        /// scope unchanged.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        scope: Option<Option<PathBuf>>,
     },
 
     /// Approve a command execution
@@ -305,6 +329,49 @@ pub enum Op {
     /// Request Codex to undo a turn (turn are stacked so it is the same effect as CMD + Z).
     Undo,
 
+    /// Request the files `apply_patch` changed since the session started.
+    /// Replies with [`EventMsg::SessionChanges`].
+    ListSessionChanges,
+
+    /// Restore one file listed by [`Op::ListSessionChanges`] to the contents
+    /// it had before this session first patched it, then reply with the
+    /// updated [`EventMsg::SessionChanges`].
+    DiscardSessionChange { path: PathBuf },
+
+    /// Save a note from the user with the session. The note is recorded in
+    /// the rollout and, when `notes_in_context` is enabled, added to the
+    /// conversation for the model. Replies with [`EventMsg::SessionNote`].
+    AddNote { text: String },
+
+    /// Summarize what the session accomplished, record the summary in the
+    /// rollout and reply with [`EventMsg::SessionSummary`]. Clients send this
+    /// before [`Op::Shutdown`] when the user quits.
+    SummarizeSession,
+
+    /// Request a per-component estimate of what the next model request will
+    /// carry. Replies with [`EventMsg::ContextBreakdown`].
+    InspectContext,
+
+    /// Leave the listed components out of the next turn's model request, then
+    /// reply with the updated [`EventMsg::ContextBreakdown`]. An empty list
+    /// clears earlier exclusions.
+    SetContextExclusions { excluded: Vec<ContextComponentKind> },
+
+    /// Hold back the tool calls of the next regular turn. The calls are
+    /// reported with [`EventMsg::DryRunPlan`] and nothing runs until the
+    /// client answers with [`Op::DryRunDecision`]. `false` disarms a dry run
+    /// that has not started yet.
+    SetDryRun { enabled: bool },
+
+    /// Resolve an [`EventMsg::DryRunPlan`]: run the approved calls and tell
+    /// the model the rest were declined. An empty list declines every call.
+    DryRunDecision {
+        /// Turn id of the plan.
+        id: String,
+        /// Call ids from the plan that may run.
+        approved_call_ids: Vec<String>,
+    },
+
     /// Request Codex to drop the last N user turns from in-memory context.
     ///
     /// This does not attempt to revert local filesystem changes. Clients are
@@ -543,6 +610,27 @@ impl SandboxPolicy {
         }
     }
 
+    /// Like [`Self::get_writable_roots_with_cwd`], with each path in
+    /// `protected_paths` added to the read-only subpaths of every writable
+    /// root that contains it.
+    pub fn get_writable_roots_with_protected_paths(
+        &self,
+        cwd: &Path,
+        protected_paths: &[AbsolutePathBuf],
+    ) -> Vec<WritableRoot> {
+        let mut roots = self.get_writable_roots_with_cwd(cwd);
+        for root in &mut roots {
+            for path in protected_paths {
+                if path.as_path().starts_with(root.root.as_path())
+                    && !root.read_only_subpaths.contains(path)
+                {
+                    root.read_only_subpaths.push(path.clone());
+                }
+            }
+        }
+        roots
+    }
+
     /// Returns the list of writable roots (tailored to the current working
     /// directory) together with subpaths that should remain read‑only under
     /// each writable root.
@@ -855,8 +943,40 @@ pub enum EventMsg {
     /// Notification that a patch application has finished.
     PatchApplyEnd(PatchApplyEndEvent),
 
+    /// A patch the agent proposed while in explore mode. Nothing was written.
+    PatchProposal(PatchProposalEvent),
+
+    /// Tool calls a dry-run turn is holding until the client sends
+    /// `Op::DryRunDecision`.
+    DryRunPlan(DryRunPlanEvent),
+
+    /// An approval request went unanswered and the configured timeout action
+    /// decided it instead.
+    ApprovalTimedOut(ApprovalTimedOutEvent),
+
+    /// A tool call tried to modify a path matching `protected_paths`, or to
+    /// rewrite a file `edit_limits` guards, and was refused before anything
+    /// was written.
+    GuardrailBlocked(GuardrailBlockedEvent),
+
     TurnDiff(TurnDiffEvent),
 
+    /// Every page the turn's local web searches and `fetch_url` calls cited.
+    /// Sent just before `TurnComplete`, and only when there was at least one.
+    TurnSources(TurnSourcesEvent),
+
+    /// Files changed by `apply_patch` since the session started.
+    SessionChanges(SessionChangesEvent),
+
+    /// A note the user saved with the session.
+    SessionNote(SessionNoteEvent),
+
+    /// Bullet summary of the session, written when the user quits.
+    SessionSummary(SessionSummaryEvent),
+
+    /// Estimated token cost of each part of the next model request.
+    ContextBreakdown(ContextBreakdownEvent),
+
     /// Response to GetHistoryEntryRequest.
     GetHistoryEntryResponse(GetHistoryEntryResponseEvent),
 
@@ -1523,6 +1643,8 @@ pub struct WebSearchEndEvent {
     pub call_id: String,
     pub query: String,
     pub action: WebSearchAction,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub citations: Vec<WebCitation>,
 }
 
 // Conversation kept for backward compatibility.
@@ -1713,6 +1835,9 @@ pub struct SessionMeta {
     pub id: ThreadId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forked_from_id: Option<ThreadId>,
+    /// Number of parent user turns this thread inherited when it was forked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fork_turn: Option<u32>,
     pub timestamp: String,
     pub cwd: PathBuf,
     pub originator: String,
@@ -1733,6 +1858,7 @@ impl Default for SessionMeta {
         SessionMeta {
             id: ThreadId::default(),
             forked_from_id: None,
+            fork_turn: None,
             timestamp: String::new(),
             cwd: PathBuf::new(),
             originator: String::new(),
@@ -1761,6 +1887,24 @@ pub enum RolloutItem {
     Compacted(CompactedItem),
     TurnContext(TurnContextItem),
     EventMsg(EventMsg),
+    ExecProvenance(ExecProvenanceItem),
+}
+
+/// Audit record of a command that ran during the session: what it was, why it
+/// ran, and what cleared it to run.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, TS)]
+pub struct ExecProvenanceItem {
+    /// Tool call that ran the command; `None` for hooks, which have no call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_id: Option<String>,
+    pub turn_id: String,
+    pub command: Vec<String>,
+    pub cwd: PathBuf,
+    /// `None` when the exit status is not observed (hooks run detached).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    #[serde(flatten)]
+    pub provenance: ExecCommandProvenance,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, TS)]
@@ -1848,6 +1992,9 @@ pub enum ReviewTarget {
     /// Review the working tree: staged, unstaged, and untracked files.
     UncommittedChanges,
 
+    /// Review only the changes staged in the index.
+    StagedChanges,
+
     /// Review changes between the current branch and the given base branch.
     #[serde(rename_all = "camelCase")]
     #[ts(rename_all = "camelCase")]
@@ -1862,6 +2009,12 @@ pub enum ReviewTarget {
         title: Option<String>,
     },
 
+    /// Review the current contents of the files matching the given globs,
+    /// relative to the working directory.
+    #[serde(rename_all = "camelCase")]
+    #[ts(rename_all = "camelCase")]
+    Files { patterns: Vec<String> },
+
     /// Arbitrary instructions provided by the user.
     #[serde(rename_all = "camelCase")]
     #[ts(rename_all = "camelCase")]
@@ -1905,6 +2058,11 @@ pub struct ReviewFinding {
     pub confidence_score: f32,
     pub priority: i32,
     pub code_location: ReviewCodeLocation,
+    /// Focus area from the repository's review rubric, when the finding
+    /// belongs to one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub category: Option<String>,
 }
 
 /// Location of the code related to a review finding.
@@ -1933,6 +2091,48 @@ pub enum ExecCommandSource {
     UnifiedExecInteraction,
 }
 
+/// Why a command ran, independent of how it was executed.
+#[derive(Debug, Clone, Copy, Display, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExecCommandOrigin {
+    /// The model called a shell tool.
+    Model,
+    /// The user ran `!cmd`.
+    UserShell,
+    /// An SDD branch action ran git on the user's behalf.
+    SddGit,
+    /// A configured hook such as `notify`.
+    Hook,
+}
+
+/// Why a command ran and what cleared it to run, for audits of what the
+/// agent actually did.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct ExecCommandProvenance {
+    pub origin: ExecCommandOrigin,
+    /// Most specific execpolicy rule that matched the command, e.g.
+    /// `prefix_rule(pattern=["git", "push"], decision="prompt")`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub policy_rule: Option<String>,
+    /// The user's answer when the command needed approval; `None` when it
+    /// ran without asking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub approval: Option<ReviewDecision>,
+}
+
+impl ExecCommandProvenance {
+    pub fn new(origin: ExecCommandOrigin) -> Self {
+        Self {
+            origin,
+            policy_rule: None,
+            approval: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct ExecCommandBeginEvent {
     /// Identifier so this can be paired with the ExecCommandEnd event.
@@ -1992,8 +2192,15 @@ pub struct ExecCommandEndEvent {
     /// The duration of the command execution.
     #[ts(type = "string")]
     pub duration: Duration,
+    /// Whether the command was killed for exceeding its timeout.
+    #[serde(default)]
+    pub timed_out: bool,
     /// Formatted output from the command, as seen by the model.
     pub formatted_output: String,
+    /// Why the command ran and what cleared it to run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub provenance: Option<ExecCommandProvenance>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
@@ -2119,11 +2326,182 @@ pub struct PatchApplyEndEvent {
     pub changes: HashMap<PathBuf, FileChange>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct PatchProposalEvent {
+    /// Identifier of the `apply_patch` call that was turned into a proposal.
+    pub call_id: String,
+    /// Turn ID that this proposal belongs to.
+    #[serde(default)]
+    pub turn_id: String,
+    /// The changes the patch would have made.
+    pub changes: HashMap<PathBuf, FileChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct DryRunPlanEvent {
+    /// Turn ID that this plan belongs to.
+    pub turn_id: String,
+    /// Calls in the order the model made them.
+    pub calls: Vec<PlannedToolCall>,
+}
+
+/// One tool call held back by a dry run.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct PlannedToolCall {
+    pub call_id: String,
+    pub tool_name: String,
+    /// What the call would do: the command line for shell tools, the raw
+    /// arguments for anything else.
+    pub summary: String,
+    /// Files an `apply_patch` call would add, update, or delete.
+    pub files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ApprovalTimedOutEvent {
+    /// Identifier of the exec or patch call whose approval timed out.
+    pub call_id: String,
+    /// Turn ID that the approval request belongs to.
+    #[serde(default)]
+    pub turn_id: String,
+    /// How long the request waited before the timeout fired.
+    pub timeout_seconds: u64,
+    /// The configured action that produced `decision`.
+    pub action: ApprovalTimeoutAction,
+    /// Decision recorded on the user's behalf.
+    pub decision: ReviewDecision,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct GuardrailBlockedEvent {
+    /// Identifier of the tool call that was refused.
+    pub call_id: String,
+    /// Turn ID that the call belongs to.
+    #[serde(default)]
+    pub turn_id: String,
+    /// Paths the call would have modified that are protected.
+    pub paths: Vec<PathBuf>,
+    /// The `protected_paths` patterns those paths matched. Empty when the
+    /// call tripped `edit_limits` instead.
+    pub patterns: Vec<String>,
+    /// Which guardrail refused the call.
+    #[serde(default)]
+    pub reason: GuardrailReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardrailReason {
+    /// The paths match `protected_paths`.
+    #[default]
+    ProtectedPaths,
+    /// The call would rewrite a file over the `edit_limits` size limit or a
+    /// binary file.
+    EditLimits,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct TurnDiffEvent {
     pub unified_diff: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, PartialEq)]
+pub struct TurnSourcesEvent {
+    /// Cited pages in the order they were first seen, one entry per URL.
+    pub citations: Vec<WebCitation>,
+}
+
+/// Response payload for `Op::ListSessionChanges` and `Op::DiscardSessionChange`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SessionChangesEvent {
+    /// Files whose contents differ from what they were before this session
+    /// first patched them, sorted by path.
+    pub files: Vec<SessionFileChange>,
+    /// Cumulative unified diff covering every entry in `files`.
+    pub unified_diff: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SessionFileChange {
+    /// Current location of the file; differs from the original after a move.
+    pub path: PathBuf,
+    pub added_lines: u32,
+    pub removed_lines: u32,
+}
+
+/// A note saved with `Op::AddNote`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SessionNoteEvent {
+    pub text: String,
+    /// Whether the note was also added to the conversation for the model.
+    #[serde(default)]
+    pub in_context: bool,
+}
+
+/// Response payload for `Op::SummarizeSession`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SessionSummaryEvent {
+    /// What the session accomplished, one item per bullet. Empty when no
+    /// summary could be generated.
+    pub bullets: Vec<String>,
+}
+
+/// Response payload for `Op::InspectContext` and `Op::SetContextExclusions`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ContextBreakdownEvent {
+    /// One entry per component, in the order they appear in the request.
+    pub components: Vec<ContextComponent>,
+    /// Estimated tokens for the whole request, excluded components left out.
+    pub total_tokens: i64,
+    pub model_context_window: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ContextComponent {
+    pub kind: ContextComponentKind,
+    /// Estimated tokens, using the same bytes-per-token heuristic as compaction.
+    pub tokens: i64,
+    /// Whether `Op::SetContextExclusions` accepts this component.
+    pub droppable: bool,
+    /// Whether the next turn will leave this component out.
+    pub excluded: bool,
+}
+
+/// Parts of a model request that `/context` reports on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(rename_all = "snake_case")]
+pub enum ContextComponentKind {
+    /// Model base instructions.
+    SystemPrompt,
+    /// Policy, collaboration mode, personality, and other developer messages.
+    DeveloperInstructions,
+    /// AGENTS.md and other user instructions.
+    ProjectDocs,
+    /// Skill bodies injected for `$skill` mentions.
+    Skills,
+    /// The `<environment_context>` block (cwd, shell, sandbox).
+    EnvironmentContext,
+    /// Earlier turns of the conversation. The turn being sent is always kept.
+    History,
+    /// Function and MCP tool definitions.
+    ToolSchemas,
+}
+
+impl ContextComponentKind {
+    /// Components that can be left out of a request without breaking it.
+    pub fn is_droppable(self) -> bool {
+        matches!(
+            self,
+            Self::ProjectDocs
+                | Self::Skills
+                | Self::EnvironmentContext
+                | Self::History
+                | Self::ToolSchemas
+        )
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct GetHistoryEntryResponseEvent {
     pub offset: usize,
@@ -2398,6 +2776,11 @@ pub enum ReviewDecision {
     /// remainder of the session.
     ApprovedForSession,
 
+    /// User has approved only some hunks of a proposed patch, identified by
+    /// the ids `codex_core::patch_hunks` assigns. The rest of the patch is not
+    /// applied.
+    ApprovedHunks { hunk_ids: Vec<String> },
+
     /// User has denied this command and the agent should not execute it, but
     /// it should continue the session and try something else.
     #[default]
@@ -2416,6 +2799,7 @@ impl ReviewDecision {
             ReviewDecision::Approved => "approved",
             ReviewDecision::ApprovedExecpolicyAmendment { .. } => "approved_with_amendment",
             ReviewDecision::ApprovedForSession => "approved_for_session",
+            ReviewDecision::ApprovedHunks { .. } => "approved_hunks",
             ReviewDecision::Denied => "denied",
             ReviewDecision::Abort => "abort",
         }
@@ -2449,6 +2833,11 @@ pub struct Chunk {
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct TurnAbortedEvent {
     pub reason: TurnAbortReason,
+    /// Approximate output tokens the model had streamed for the request the
+    /// abort cut off. `None` when no model request was in flight.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub cut_off_output_tokens: Option<i64>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema, TS)]
@@ -2616,6 +3005,7 @@ mod tests {
                     query: Some("find docs".into()),
                     queries: None,
                 },
+                citations: Vec::new(),
             }),
         };
 
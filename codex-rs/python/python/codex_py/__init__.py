@@ -0,0 +1,25 @@
+"""Drive the Codex agent loop from Python.
+
+    import asyncio
+    import codex_py
+
+    async def main() -> None:
+        agent = await codex_py.Agent.load(cwd=".")
+        thread = await agent.start_thread()
+        await thread.send_message("Summarize README.md")
+        async for event in thread.events():
+            msg = event["msg"]
+            if msg["type"] == "exec_approval_request":
+                await thread.approve(msg["call_id"], "denied")
+            elif msg["type"] == "task_complete":
+                print(msg["last_agent_message"])
+                break
+
+    asyncio.run(main())
+"""
+
+from ._native import Agent, EventStream, Thread
+
+DECISIONS = ("approved", "approved_for_session", "denied", "abort")
+
+__all__ = ["Agent", "DECISIONS", "EventStream", "Thread"]
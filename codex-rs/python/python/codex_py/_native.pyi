@@ -0,0 +1,33 @@
+from os import PathLike
+from pathlib import Path
+from typing import Any, Optional, Union
+
+_Path = Union[str, PathLike[str]]
+
+class Agent:
+    @staticmethod
+    async def load(
+        cwd: Optional[_Path] = None,
+        model: Optional[str] = None,
+        sandbox: Optional[str] = None,
+        approval_policy: Optional[str] = None,
+    ) -> Agent: ...
+    async def start_thread(self) -> Thread: ...
+    async def resume_thread(self, rollout_path: _Path) -> Thread: ...
+
+class Thread:
+    @property
+    def id(self) -> str: ...
+    @property
+    def model(self) -> str: ...
+    @property
+    def rollout_path(self) -> Optional[Path]: ...
+    async def send_message(self, text: str) -> str: ...
+    def events(self) -> EventStream: ...
+    async def approve(self, call_id: str, decision: str) -> None: ...
+    async def interrupt(self) -> None: ...
+    async def shutdown(self) -> None: ...
+
+class EventStream:
+    def __aiter__(self) -> EventStream: ...
+    async def __anext__(self) -> dict[str, Any]: ...
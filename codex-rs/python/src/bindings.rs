@@ -0,0 +1,298 @@
+//! The `codex_py._native` extension module.
+//!
+//! Every blocking call returns an awaitable driven by a shared Tokio runtime,
+//! so the classes can be used from asyncio code and notebooks alike. Events
+//! are handed to Python as dicts with the same shape as the protocol JSON:
+//! `{"id": ..., "msg": {"type": ..., ...}}`.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use codex_core::Agent;
+use codex_core::AgentOptions;
+use codex_core::AgentThread;
+use codex_core::ApprovalRequest;
+use codex_protocol::config_types::SandboxMode;
+use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::EventMsg;
+use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::types::PyList;
+use pyo3_async_runtimes::tokio::future_into_py;
+use serde_json::Value;
+
+use crate::parse_decision;
+
+fn runtime_error(err: impl std::fmt::Display) -> PyErr {
+    PyRuntimeError::new_err(err.to_string())
+}
+
+fn parse_json_enum<T: serde::de::DeserializeOwned>(name: &str, value: &str) -> PyResult<T> {
+    serde_json::from_value(Value::String(value.to_string()))
+        .map_err(|_| PyValueError::new_err(format!("invalid {name} `{value}`")))
+}
+
+/// Entry point: loads configuration and starts or resumes threads.
+#[pyclass(name = "Agent", module = "codex_py")]
+struct PyAgent {
+    agent: Arc<Agent>,
+}
+
+#[pymethods]
+impl PyAgent {
+    /// `await Agent.load(cwd=None, model=None, sandbox=None, approval_policy=None)`
+    ///
+    /// Reads `config.toml` from `CODEX_HOME` like the CLI. `sandbox` takes the
+    /// `--sandbox` values and `approval_policy` the `--ask-for-approval` ones.
+    #[staticmethod]
+    #[pyo3(signature = (cwd=None, model=None, sandbox=None, approval_policy=None))]
+    fn load(
+        py: Python<'_>,
+        cwd: Option<PathBuf>,
+        model: Option<String>,
+        sandbox: Option<String>,
+        approval_policy: Option<String>,
+    ) -> PyResult<Bound<'_, PyAny>> {
+        let sandbox_mode = sandbox
+            .as_deref()
+            .map(|value| parse_json_enum::<SandboxMode>("sandbox", value))
+            .transpose()?;
+        let approval_policy = approval_policy
+            .as_deref()
+            .map(|value| parse_json_enum::<AskForApproval>("approval_policy", value))
+            .transpose()?;
+        let mut options = AgentOptions::new();
+        if let Some(cwd) = cwd {
+            options = options.cwd(cwd);
+        }
+        if let Some(model) = model {
+            options = options.model(model);
+        }
+        if let Some(sandbox_mode) = sandbox_mode {
+            options = options.sandbox_mode(sandbox_mode);
+        }
+        if let Some(approval_policy) = approval_policy {
+            options = options.approval_policy(approval_policy);
+        }
+        future_into_py(py, async move {
+            let agent = Agent::load(options).await.map_err(runtime_error)?;
+            Ok(PyAgent {
+                agent: Arc::new(agent),
+            })
+        })
+    }
+
+    /// `await agent.start_thread()`
+    fn start_thread<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let agent = self.agent.clone();
+        future_into_py(py, async move {
+            let thread = agent.start_thread().await.map_err(runtime_error)?;
+            Ok(PyThread::new(thread))
+        })
+    }
+
+    /// `await agent.resume_thread(rollout_path)`
+    fn resume_thread<'py>(
+        &self,
+        py: Python<'py>,
+        rollout_path: PathBuf,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let agent = self.agent.clone();
+        future_into_py(py, async move {
+            let thread = agent
+                .resume_thread(rollout_path)
+                .await
+                .map_err(runtime_error)?;
+            Ok(PyThread::new(thread))
+        })
+    }
+}
+
+/// Approval requests seen on the event stream, by call id, until answered.
+type PendingApprovals = Arc<Mutex<HashMap<String, ApprovalRequest>>>;
+
+/// One conversation.
+#[pyclass(name = "Thread", module = "codex_py")]
+struct PyThread {
+    thread: Arc<AgentThread>,
+    pending: PendingApprovals,
+    closed: Arc<AtomicBool>,
+}
+
+impl PyThread {
+    fn new(thread: AgentThread) -> Self {
+        Self {
+            thread: Arc::new(thread),
+            pending: Arc::default(),
+            closed: Arc::default(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyThread {
+    #[getter]
+    fn id(&self) -> String {
+        self.thread.id().to_string()
+    }
+
+    #[getter]
+    fn model(&self) -> String {
+        self.thread.session().model.clone()
+    }
+
+    #[getter]
+    fn rollout_path(&self) -> Option<PathBuf> {
+        self.thread.rollout_path()
+    }
+
+    /// `await thread.send_message(text)`; returns the submission id.
+    fn send_message<'py>(&self, py: Python<'py>, text: String) -> PyResult<Bound<'py, PyAny>> {
+        let thread = self.thread.clone();
+        future_into_py(py, async move {
+            thread.send_message(text).await.map_err(runtime_error)
+        })
+    }
+
+    /// `async for event in thread.events(): ...`
+    ///
+    /// Every event goes to exactly one iterator; iteration ends after the
+    /// thread shuts down.
+    fn events(&self) -> PyEventStream {
+        PyEventStream {
+            thread: self.thread.clone(),
+            pending: self.pending.clone(),
+            closed: self.closed.clone(),
+        }
+    }
+
+    /// `await thread.approve(call_id, decision)`
+    ///
+    /// Answers an `exec_approval_request` or `apply_patch_approval_request`
+    /// event already yielded by `events()`. `decision` is one of `approved`,
+    /// `approved_for_session`, `denied`, or `abort`.
+    fn approve<'py>(
+        &self,
+        py: Python<'py>,
+        call_id: String,
+        decision: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let decision = parse_decision(&decision).map_err(PyValueError::new_err)?;
+        let request = self
+            .pending
+            .lock()
+            .map_err(runtime_error)?
+            .remove(&call_id)
+            .ok_or_else(|| PyValueError::new_err(format!("no pending approval for `{call_id}`")))?;
+        let thread = self.thread.clone();
+        future_into_py(py, async move {
+            thread
+                .approve(&request, decision)
+                .await
+                .map_err(runtime_error)
+        })
+    }
+
+    /// `await thread.interrupt()`
+    fn interrupt<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let thread = self.thread.clone();
+        future_into_py(py, async move {
+            thread.interrupt().await.map_err(runtime_error)
+        })
+    }
+
+    /// `await thread.shutdown()`
+    fn shutdown<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let thread = self.thread.clone();
+        future_into_py(
+            py,
+            async move { thread.shutdown().await.map_err(runtime_error) },
+        )
+    }
+}
+
+/// Async iterator over a thread's events.
+#[pyclass(name = "EventStream", module = "codex_py")]
+struct PyEventStream {
+    thread: Arc<AgentThread>,
+    pending: PendingApprovals,
+    closed: Arc<AtomicBool>,
+}
+
+#[pymethods]
+impl PyEventStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let thread = self.thread.clone();
+        let pending = self.pending.clone();
+        let closed = self.closed.clone();
+        future_into_py(py, async move {
+            if closed.load(Ordering::Acquire) {
+                return Err(PyStopAsyncIteration::new_err(()));
+            }
+            let event = thread.next_event().await.map_err(runtime_error)?;
+            if let Some(request) = ApprovalRequest::from_event(&event.msg) {
+                let call_id = match &request {
+                    ApprovalRequest::Exec(event) => event.call_id.clone(),
+                    ApprovalRequest::Patch(event) => event.call_id.clone(),
+                    _ => String::new(),
+                };
+                pending
+                    .lock()
+                    .map_err(runtime_error)?
+                    .insert(call_id, request);
+            }
+            if matches!(event.msg, EventMsg::ShutdownComplete) {
+                closed.store(true, Ordering::Release);
+            }
+            let value = serde_json::to_value(&event).map_err(runtime_error)?;
+            Python::with_gil(|py| json_to_py(py, &value))
+        })
+    }
+}
+
+fn json_to_py(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    match value {
+        Value::Null => Ok(py.None()),
+        Value::Bool(value) => value.into_py_any(py),
+        Value::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(value), _) => value.into_py_any(py),
+            (None, Some(value)) => value.into_py_any(py),
+            (None, None) => number.as_f64().unwrap_or_default().into_py_any(py),
+        },
+        Value::String(value) => value.into_py_any(py),
+        Value::Array(values) => {
+            let list = PyList::empty(py);
+            for value in values {
+                list.append(json_to_py(py, value)?)?;
+            }
+            list.into_py_any(py)
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, value) in map {
+                dict.set_item(key, json_to_py(py, value)?)?;
+            }
+            dict.into_py_any(py)
+        }
+    }
+}
+
+#[pymodule]
+fn _native(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyAgent>()?;
+    module.add_class::<PyThread>()?;
+    module.add_class::<PyEventStream>()?;
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! Python bindings for the [`codex_core::Agent`] embedding API, shipped as the
+//! `codex-py` wheel.
+//!
+//! The extension module is only compiled with the `python` feature, which
+//! maturin enables; without it the crate holds just the interpreter-free
+//! helpers below, so workspace builds do not need Python.
+
+#[cfg(feature = "python")]
+mod bindings;
+
+use codex_protocol::protocol::ReviewDecision;
+
+/// Decisions accepted by `Thread.approve`.
+pub const DECISIONS: [&str; 4] = ["approved", "approved_for_session", "denied", "abort"];
+
+/// Parses the decision string passed from Python.
+pub fn parse_decision(decision: &str) -> Result<ReviewDecision, String> {
+    match decision {
+        "approved" => Ok(ReviewDecision::Approved),
+        "approved_for_session" => Ok(ReviewDecision::ApprovedForSession),
+        "denied" => Ok(ReviewDecision::Denied),
+        "abort" => Ok(ReviewDecision::Abort),
+        other => Err(format!(
+            "unknown decision `{other}`; expected one of: {}",
+            DECISIONS.join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn decisions_round_trip() {
+        for decision in DECISIONS {
+            let parsed = parse_decision(decision).expect("known decision");
+            assert_eq!(
+                serde_json::to_value(&parsed).expect("serialize"),
+                serde_json::Value::String(decision.to_string())
+            );
+        }
+        assert!(parse_decision("yes").is_err());
+    }
+}
@@ -0,0 +1,529 @@
+//! Policy for sharing one upstream credential between several clients, loaded
+//! from the JSON file passed with `--gateway-config`.
+//!
+//! ```json
+//! {
+//!   "clients": [
+//!     { "name": "alice", "key": "alice-secret", "token_budget": 2000000, "max_concurrent_requests": 2 }
+//!   ],
+//!   "max_concurrent_requests": 8,
+//!   "budget_window_seconds": 86400,
+//!   "routes": [
+//!     { "name": "azure", "header": "x-codex-upstream", "value": "azure", "upstream_url": "https://example.openai.azure.com/openai/v1/responses" }
+//!   ],
+//!   "shaping": { "allowed_models": ["gpt-5.1-codex"], "max_output_tokens": 32000 }
+//! }
+//! ```
+//!
+//! Clients authenticate with `Authorization: Bearer <key>`; the proxy replaces
+//! that header with the real credential before forwarding. When `clients` is
+//! empty, every request is accepted and accounted to [`DEFAULT_CLIENT`].
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::anyhow;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::ForwardConfig;
+
+/// Client name used when no clients are configured.
+pub(crate) const DEFAULT_CLIENT: &str = "default";
+/// Upstream name used for `--upstream-url`.
+pub(crate) const DEFAULT_UPSTREAM: &str = "default";
+
+const DEFAULT_BUDGET_WINDOW_SECONDS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GatewayConfig {
+    /// Clients allowed to use the proxy.
+    #[serde(default)]
+    pub clients: Vec<ClientConfig>,
+    /// Limit on requests in flight across all clients.
+    pub max_concurrent_requests: Option<usize>,
+    /// Length of the window token budgets apply to. Defaults to a day.
+    pub budget_window_seconds: Option<u64>,
+    /// Upstreams picked by a request header, first match wins. Requests that
+    /// match no route go to `--upstream-url`.
+    #[serde(default)]
+    pub routes: Vec<RouteConfig>,
+    #[serde(default)]
+    pub shaping: ShapingConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    pub name: String,
+    /// Bearer token the client sends in place of the real credential.
+    pub key: String,
+    /// Tokens the client may use per budget window.
+    pub token_budget: Option<u64>,
+    pub max_concurrent_requests: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RouteConfig {
+    pub name: String,
+    pub header: String,
+    pub value: String,
+    pub upstream_url: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShapingConfig {
+    /// Models requests may ask for. Any model is allowed when unset.
+    pub allowed_models: Option<Vec<String>>,
+    /// Upper bound written into each request's `max_output_tokens`.
+    pub max_output_tokens: Option<u64>,
+}
+
+impl GatewayConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("reading gateway config {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("parsing gateway config {}", path.display()))
+    }
+}
+
+/// Why a request was refused before reaching the upstream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Rejection {
+    pub status: u16,
+    /// Short label used in metrics.
+    pub reason: &'static str,
+    pub message: String,
+    /// Unix time at which the client's budget resets, for budget refusals.
+    pub resets_at: Option<u64>,
+}
+
+impl Rejection {
+    fn new(status: u16, reason: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            reason,
+            message: message.into(),
+            resets_at: None,
+        }
+    }
+
+    /// An error body in the shape of the Responses API, so Codex surfaces the
+    /// message. Exhausted budgets use `usage_limit_reached` so Codex stops
+    /// retrying and shows when the budget resets.
+    pub(crate) fn body(&self) -> String {
+        let error_type = match self.reason {
+            "token_budget" => "usage_limit_reached",
+            _ => "proxy_error",
+        };
+        let mut error = serde_json::json!({
+            "type": error_type,
+            "code": self.reason,
+            "message": self.message,
+        });
+        if let Some(resets_at) = self.resets_at {
+            error["resets_at"] = resets_at.into();
+        }
+        serde_json::json!({ "error": error }).to_string()
+    }
+}
+
+struct Route {
+    name: String,
+    header: String,
+    value: String,
+    forward: Arc<ForwardConfig>,
+}
+
+#[derive(Default)]
+struct State {
+    in_flight: usize,
+    clients: HashMap<String, ClientState>,
+}
+
+struct ClientState {
+    in_flight: usize,
+    tokens_used: u64,
+    window_start: Instant,
+}
+
+impl ClientState {
+    fn new() -> Self {
+        Self {
+            in_flight: 0,
+            tokens_used: 0,
+            window_start: Instant::now(),
+        }
+    }
+}
+
+/// Per-client usage, for the metrics endpoint.
+pub(crate) struct ClientUsage {
+    pub name: String,
+    pub in_flight: usize,
+    pub tokens_used: u64,
+    pub token_budget: Option<u64>,
+}
+
+pub(crate) struct Gateway {
+    clients: Vec<ClientConfig>,
+    max_concurrent_requests: Option<usize>,
+    budget_window: Duration,
+    routes: Vec<Route>,
+    default_upstream: Arc<ForwardConfig>,
+    shaping: ShapingConfig,
+    state: Mutex<State>,
+}
+
+impl Gateway {
+    pub(crate) fn new(config: GatewayConfig, default_upstream: ForwardConfig) -> Result<Self> {
+        let mut names = HashSet::new();
+        let mut keys = HashSet::new();
+        for client in &config.clients {
+            if client.key.is_empty() {
+                return Err(anyhow!("client `{}` has an empty key", client.name));
+            }
+            if !names.insert(client.name.as_str()) {
+                return Err(anyhow!("client `{}` is configured twice", client.name));
+            }
+            if !keys.insert(client.key.as_str()) {
+                return Err(anyhow!(
+                    "client `{}` reuses another client's key",
+                    client.name
+                ));
+            }
+        }
+        let routes = config
+            .routes
+            .into_iter()
+            .map(|route| {
+                let forward = ForwardConfig::from_url(&route.upstream_url)
+                    .with_context(|| format!("route `{}`", route.name))?;
+                Ok(Route {
+                    name: route.name,
+                    header: route.header.to_ascii_lowercase(),
+                    value: route.value,
+                    forward: Arc::new(forward),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let window_seconds = config
+            .budget_window_seconds
+            .unwrap_or(DEFAULT_BUDGET_WINDOW_SECONDS);
+        if window_seconds == 0 {
+            return Err(anyhow!("budget_window_seconds must be positive"));
+        }
+
+        Ok(Self {
+            clients: config.clients,
+            max_concurrent_requests: config.max_concurrent_requests,
+            budget_window: Duration::from_secs(window_seconds),
+            routes,
+            default_upstream: Arc::new(default_upstream),
+            shaping: config.shaping,
+            state: Mutex::new(State::default()),
+        })
+    }
+
+    /// The client a request belongs to, from its `Authorization` header.
+    pub(crate) fn authenticate(&self, authorization: Option<&str>) -> Result<String, Rejection> {
+        if self.clients.is_empty() {
+            return Ok(DEFAULT_CLIENT.to_string());
+        }
+        let key = authorization
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .unwrap_or_default();
+        self.clients
+            .iter()
+            .find(|client| constant_time_eq(client.key.as_bytes(), key.as_bytes()))
+            .map(|client| client.name.clone())
+            .ok_or_else(|| Rejection::new(401, "unauthorized", "unknown or missing client key"))
+    }
+
+    /// Reserve a slot for a request from `client`, checking its budget and the
+    /// concurrency limits. The slot is released when the permit is dropped.
+    pub(crate) fn admit(self: &Arc<Self>, client: &str) -> Result<Permit, Rejection> {
+        let config = self.clients.iter().find(|config| config.name == client);
+        let mut state = self.lock_state();
+        if let Some(limit) = self.max_concurrent_requests
+            && state.in_flight >= limit
+        {
+            return Err(Rejection::new(
+                429,
+                "concurrency",
+                format!("the proxy is already serving {limit} requests; retry shortly"),
+            ));
+        }
+
+        let budget_window = self.budget_window;
+        let client_state = state
+            .clients
+            .entry(client.to_string())
+            .or_insert_with(ClientState::new);
+        let elapsed = client_state.window_start.elapsed();
+        if elapsed >= budget_window {
+            let windows = (elapsed.as_secs() / budget_window.as_secs()) as u32;
+            client_state.window_start += budget_window * windows;
+            client_state.tokens_used = 0;
+        }
+        if let Some(budget) = config.and_then(|config| config.token_budget)
+            && client_state.tokens_used >= budget
+        {
+            let remaining = budget_window.saturating_sub(client_state.window_start.elapsed());
+            let resets_at = (SystemTime::now() + remaining)
+                .duration_since(UNIX_EPOCH)
+                .map(|since| since.as_secs())
+                .ok();
+            return Err(Rejection {
+                resets_at,
+                ..Rejection::new(
+                    429,
+                    "token_budget",
+                    format!("client `{client}` has used its budget of {budget} tokens"),
+                )
+            });
+        }
+        if let Some(limit) = config.and_then(|config| config.max_concurrent_requests)
+            && client_state.in_flight >= limit
+        {
+            return Err(Rejection::new(
+                429,
+                "client_concurrency",
+                format!("client `{client}` already has {limit} requests in flight; retry shortly"),
+            ));
+        }
+
+        client_state.in_flight += 1;
+        state.in_flight += 1;
+        Ok(Permit {
+            gateway: Arc::clone(self),
+            client: client.to_string(),
+        })
+    }
+
+    pub(crate) fn record_tokens(&self, client: &str, tokens: u64) {
+        let mut state = self.lock_state();
+        let client_state = state
+            .clients
+            .entry(client.to_string())
+            .or_insert_with(ClientState::new);
+        client_state.tokens_used = client_state.tokens_used.saturating_add(tokens);
+    }
+
+    /// The upstream for a request with `headers`, as `(name, value)` pairs.
+    pub(crate) fn route<'a>(
+        &self,
+        headers: impl IntoIterator<Item = (&'a str, &'a str)> + Clone,
+    ) -> (&str, Arc<ForwardConfig>) {
+        self.routes
+            .iter()
+            .find(|route| {
+                headers.clone().into_iter().any(|(name, value)| {
+                    name.eq_ignore_ascii_case(&route.header) && value == route.value
+                })
+            })
+            .map(|route| (route.name.as_str(), Arc::clone(&route.forward)))
+            .unwrap_or((DEFAULT_UPSTREAM, Arc::clone(&self.default_upstream)))
+    }
+
+    /// Apply `shaping` to a request body. Bodies are left untouched when no
+    /// shaping is configured.
+    pub(crate) fn shape(&self, body: Vec<u8>) -> Result<Vec<u8>, Rejection> {
+        let ShapingConfig {
+            allowed_models,
+            max_output_tokens,
+        } = &self.shaping;
+        if allowed_models.is_none() && max_output_tokens.is_none() {
+            return Ok(body);
+        }
+        let Ok(Value::Object(mut request)) = serde_json::from_slice::<Value>(&body) else {
+            return Err(Rejection::new(
+                400,
+                "invalid_body",
+                "request body must be a JSON object",
+            ));
+        };
+
+        if let Some(allowed) = allowed_models {
+            let model = request.get("model").and_then(Value::as_str).unwrap_or("");
+            if !allowed.iter().any(|candidate| candidate == model) {
+                return Err(Rejection::new(
+                    403,
+                    "model_not_allowed",
+                    format!(
+                        "model `{model}` is not allowed by this proxy; use one of: {}",
+                        allowed.join(", ")
+                    ),
+                ));
+            }
+        }
+        if let Some(cap) = max_output_tokens {
+            let requested = request.get("max_output_tokens").and_then(Value::as_u64);
+            let capped = requested.map_or(*cap, |requested| requested.min(*cap));
+            request.insert("max_output_tokens".to_string(), capped.into());
+        }
+
+        serde_json::to_vec(&request)
+            .map_err(|err| Rejection::new(400, "invalid_body", err.to_string()))
+    }
+
+    pub(crate) fn usage(&self) -> Vec<ClientUsage> {
+        let state = self.lock_state();
+        let mut usage: Vec<ClientUsage> = state
+            .clients
+            .iter()
+            .map(|(name, client_state)| ClientUsage {
+                name: name.clone(),
+                in_flight: client_state.in_flight,
+                tokens_used: client_state.tokens_used,
+                token_budget: self
+                    .clients
+                    .iter()
+                    .find(|config| &config.name == name)
+                    .and_then(|config| config.token_budget),
+            })
+            .collect();
+        usage.sort_by(|a, b| a.name.cmp(&b.name));
+        usage
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// A request slot held for the lifetime of a forwarded request.
+pub(crate) struct Permit {
+    gateway: Arc<Gateway>,
+    client: String,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let mut state = self.gateway.lock_state();
+        state.in_flight = state.in_flight.saturating_sub(1);
+        if let Some(client_state) = state.clients.get_mut(&self.client) {
+            client_state.in_flight = client_state.in_flight.saturating_sub(1);
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gateway(config: serde_json::Value) -> Arc<Gateway> {
+        let config: GatewayConfig = serde_json::from_value(config).expect("valid config");
+        let upstream =
+            ForwardConfig::from_url("https://api.openai.com/v1/responses").expect("valid url");
+        Arc::new(Gateway::new(config, upstream).expect("valid gateway"))
+    }
+
+    #[test]
+    fn clients_authenticate_with_their_own_key() {
+        let gateway = gateway(serde_json::json!({
+            "clients": [{ "name": "alice", "key": "a-key" }, { "name": "bob", "key": "b-key" }]
+        }));
+
+        assert_eq!(
+            gateway.authenticate(Some("Bearer b-key")),
+            Ok("bob".to_string())
+        );
+        assert_eq!(
+            gateway
+                .authenticate(Some("Bearer nope"))
+                .map_err(|err| err.status),
+            Err(401)
+        );
+        assert_eq!(
+            gateway.authenticate(None).map_err(|err| err.status),
+            Err(401)
+        );
+
+        let open = self::gateway(serde_json::json!({}));
+        assert_eq!(open.authenticate(None), Ok(DEFAULT_CLIENT.to_string()));
+    }
+
+    #[test]
+    fn budgets_and_concurrency_limit_admission() {
+        let gateway = gateway(serde_json::json!({
+            "clients": [{ "name": "alice", "key": "a-key", "token_budget": 100, "max_concurrent_requests": 1 }],
+            "max_concurrent_requests": 2
+        }));
+
+        let permit = gateway.admit("alice").expect("first request");
+        assert_eq!(
+            gateway.admit("alice").map(drop).map_err(|err| err.reason),
+            Err("client_concurrency")
+        );
+        drop(permit);
+
+        gateway.record_tokens("alice", 100);
+        let rejection = gateway.admit("alice").map(drop).expect_err("over budget");
+        assert_eq!(rejection.reason, "token_budget");
+        assert!(rejection.resets_at.is_some());
+        assert!(rejection.body().contains("usage_limit_reached"));
+    }
+
+    #[test]
+    fn routes_match_on_header() {
+        let gateway = gateway(serde_json::json!({
+            "routes": [{
+                "name": "azure",
+                "header": "X-Codex-Upstream",
+                "value": "azure",
+                "upstream_url": "https://example.openai.azure.com/openai/v1/responses"
+            }]
+        }));
+
+        let (name, forward) = gateway.route([("x-codex-upstream", "azure")]);
+        assert_eq!(name, "azure");
+        assert_eq!(
+            forward.upstream_url.host_str(),
+            Some("example.openai.azure.com")
+        );
+        let (name, _) = gateway.route([("x-codex-upstream", "other")]);
+        assert_eq!(name, DEFAULT_UPSTREAM);
+    }
+
+    #[test]
+    fn shaping_caps_output_and_filters_models() {
+        let gateway = gateway(serde_json::json!({
+            "shaping": { "allowed_models": ["gpt-5.1-codex"], "max_output_tokens": 1000 }
+        }));
+
+        let shaped = gateway
+            .shape(br#"{"model":"gpt-5.1-codex","max_output_tokens":5000}"#.to_vec())
+            .expect("allowed model");
+        let shaped: Value = serde_json::from_slice(&shaped).expect("json");
+        assert_eq!(shaped["max_output_tokens"], 1000);
+
+        let rejection = gateway
+            .shape(br#"{"model":"gpt-4o"}"#.to_vec())
+            .expect_err("model not allowed");
+        assert_eq!(rejection.status, 403);
+    }
+}
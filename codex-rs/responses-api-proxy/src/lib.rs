@@ -6,7 +6,10 @@ use std::net::TcpListener;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
+use std::time::Instant;
 
 use anyhow::Context;
 use anyhow::Result;
@@ -27,8 +30,17 @@ use tiny_http::Response;
 use tiny_http::Server;
 use tiny_http::StatusCode;
 
+mod gateway;
+mod metrics;
 mod read_api_key;
+mod usage;
+use gateway::DEFAULT_CLIENT;
+use gateway::Gateway;
+use gateway::GatewayConfig;
+use gateway::Rejection;
+use metrics::Metrics;
 use read_api_key::read_auth_header_from_stdin;
+use usage::UsageTap;
 
 /// CLI arguments for the proxy.
 #[derive(Debug, Clone, Parser)]
@@ -49,6 +61,15 @@ pub struct Args {
     /// Absolute URL the proxy should forward requests to (defaults to OpenAI).
     #[arg(long, default_value = "https://api.openai.com/v1/responses")]
     pub upstream_url: String,
+
+    /// JSON file with client keys, token budgets, concurrency limits, routes,
+    /// and request shaping for sharing the credential between clients.
+    #[arg(long, value_name = "FILE")]
+    pub gateway_config: Option<PathBuf>,
+
+    /// Enable Prometheus metrics at GET /metrics
+    #[arg(long)]
+    pub http_metrics: bool,
 }
 
 #[derive(Serialize)]
@@ -62,23 +83,35 @@ struct ForwardConfig {
     host_header: HeaderValue,
 }
 
+impl ForwardConfig {
+    fn from_url(url: &str) -> Result<Self> {
+        let upstream_url =
+            Url::parse(url).with_context(|| format!("parsing upstream URL {url}"))?;
+        let host = match (upstream_url.host_str(), upstream_url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            _ => return Err(anyhow!("upstream URL must include a host")),
+        };
+        let host_header =
+            HeaderValue::from_str(&host).context("constructing Host header from upstream URL")?;
+        Ok(Self {
+            upstream_url,
+            host_header,
+        })
+    }
+}
+
 /// Entry point for the library main, for parity with other crates.
 pub fn run_main(args: Args) -> Result<()> {
     let auth_header = read_auth_header_from_stdin()?;
 
-    let upstream_url = Url::parse(&args.upstream_url).context("parsing --upstream-url")?;
-    let host = match (upstream_url.host_str(), upstream_url.port()) {
-        (Some(host), Some(port)) => format!("{host}:{port}"),
-        (Some(host), None) => host.to_string(),
-        _ => return Err(anyhow!("upstream URL must include a host")),
+    let forward_config = ForwardConfig::from_url(&args.upstream_url)?;
+    let gateway_config = match args.gateway_config.as_deref() {
+        Some(path) => GatewayConfig::load(path)?,
+        None => GatewayConfig::default(),
     };
-    let host_header =
-        HeaderValue::from_str(&host).context("constructing Host header from upstream URL")?;
-
-    let forward_config = Arc::new(ForwardConfig {
-        upstream_url,
-        host_header,
-    });
+    let gateway = Arc::new(Gateway::new(gateway_config, forward_config)?);
+    let metrics = Arc::new(Metrics::default());
 
     let (listener, bound_addr) = bind_listener(args.port)?;
     if let Some(path) = args.server_info.as_ref() {
@@ -97,16 +130,25 @@ pub fn run_main(args: Args) -> Result<()> {
     eprintln!("responses-api-proxy listening on {bound_addr}");
 
     let http_shutdown = args.http_shutdown;
+    let http_metrics = args.http_metrics;
     for request in server.incoming_requests() {
         let client = client.clone();
-        let forward_config = forward_config.clone();
+        let gateway = gateway.clone();
+        let metrics = metrics.clone();
         std::thread::spawn(move || {
             if http_shutdown && request.method() == &Method::Get && request.url() == "/shutdown" {
                 let _ = request.respond(Response::new_empty(StatusCode(200)));
                 std::process::exit(0);
             }
 
-            if let Err(e) = forward_request(&client, auth_header, &forward_config, request) {
+            if http_metrics && request.method() == &Method::Get && request.url() == "/metrics" {
+                let body = metrics.render(&gateway.usage());
+                let response = Response::from_string(body);
+                let _ = request.respond(with_content_type(response, "text/plain; version=0.0.4"));
+                return;
+            }
+
+            if let Err(e) = forward_request(&client, auth_header, &gateway, &metrics, request) {
                 eprintln!("forwarding error: {e}");
             }
         });
@@ -140,12 +182,29 @@ fn write_server_info(path: &Path, port: u16) -> Result<()> {
     Ok(())
 }
 
+fn with_content_type<R: std::io::Read>(response: Response<R>, value: &str) -> Response<R> {
+    match Header::from_bytes(&b"Content-Type"[..], value.as_bytes()) {
+        Ok(header) => response.with_header(header),
+        Err(()) => response,
+    }
+}
+
+fn reject(req: Request, metrics: &Metrics, client: &str, rejection: Rejection) {
+    metrics.record_rejection(client, rejection.reason);
+    let response =
+        Response::from_string(rejection.body()).with_status_code(StatusCode(rejection.status));
+    let response = with_content_type(response, "application/json");
+    let _ = req.respond(response);
+}
+
 fn forward_request(
     client: &Client,
     auth_header: &'static str,
-    config: &ForwardConfig,
+    gateway: &Arc<Gateway>,
+    metrics: &Metrics,
     mut req: Request,
 ) -> Result<()> {
+    let started = Instant::now();
     // Only allow POST /v1/responses exactly, no query string.
     let method = req.method().clone();
     let url_path = req.url().to_string();
@@ -157,10 +216,43 @@ fn forward_request(
         return Ok(());
     }
 
+    let authorization = req
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv("authorization"))
+        .map(|header| header.value.as_str().to_string());
+    let client_name = match gateway.authenticate(authorization.as_deref()) {
+        Ok(name) => name,
+        Err(rejection) => {
+            reject(req, metrics, DEFAULT_CLIENT, rejection);
+            return Ok(());
+        }
+    };
+    let _permit = match gateway.admit(&client_name) {
+        Ok(permit) => permit,
+        Err(rejection) => {
+            reject(req, metrics, &client_name, rejection);
+            return Ok(());
+        }
+    };
+
     // Read request body
     let mut body = Vec::new();
     let mut reader = req.as_reader();
     std::io::Read::read_to_end(&mut reader, &mut body)?;
+    let body = match gateway.shape(body) {
+        Ok(body) => body,
+        Err(rejection) => {
+            reject(req, metrics, &client_name, rejection);
+            return Ok(());
+        }
+    };
+    let (upstream_name, config) = gateway.route(
+        req.headers()
+            .iter()
+            .map(|header| (header.field.as_str().as_str(), header.value.as_str())),
+    );
+    let upstream_name = upstream_name.to_string();
 
     // Build headers for upstream, forwarding everything from the incoming
     // request except Authorization (we replace it below).
@@ -189,12 +281,18 @@ fn forward_request(
 
     headers.insert(HOST, config.host_header.clone());
 
-    let upstream_resp = client
+    let upstream_resp = match client
         .post(config.upstream_url.clone())
         .headers(headers)
         .body(body)
         .send()
-        .context("forwarding request to upstream")?;
+    {
+        Ok(resp) => resp,
+        Err(err) => {
+            metrics.record_request(&client_name, &upstream_name, 502, started.elapsed(), 0);
+            return Err(err).context("forwarding request to upstream");
+        }
+    };
 
     // We have to create an adapter between a `reqwest::blocking::Response`
     // and a `tiny_http::Response`. Fortunately, `reqwest::blocking::Response`
@@ -224,14 +322,25 @@ fn forward_request(
         }
     });
 
+    let total_tokens = Arc::new(AtomicU64::new(0));
     let response = Response::new(
         StatusCode(status.as_u16()),
         response_headers,
-        upstream_resp,
+        UsageTap::new(upstream_resp, total_tokens.clone()),
         content_length,
         None,
     );
 
+    // `respond` returns once the whole body has been streamed to the client.
     let _ = req.respond(response);
+    let tokens = total_tokens.load(Ordering::Relaxed);
+    gateway.record_tokens(&client_name, tokens);
+    metrics.record_request(
+        &client_name,
+        &upstream_name,
+        status.as_u16(),
+        started.elapsed(),
+        tokens,
+    );
     Ok(())
 }
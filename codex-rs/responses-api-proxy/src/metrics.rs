@@ -0,0 +1,198 @@
+//! Request counters served in the Prometheus text format at `GET /metrics`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::gateway::ClientUsage;
+
+#[derive(Default)]
+pub(crate) struct Metrics {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// Keyed by `(client, upstream, status)`.
+    requests: BTreeMap<(String, String, u16), u64>,
+    /// Keyed by `(client, upstream)`: total seconds and count.
+    durations: BTreeMap<(String, String), (f64, u64)>,
+    /// Keyed by `(client, reason)`.
+    rejections: BTreeMap<(String, String), u64>,
+    tokens: BTreeMap<String, u64>,
+}
+
+impl Metrics {
+    /// Record a request that reached the upstream.
+    pub(crate) fn record_request(
+        &self,
+        client: &str,
+        upstream: &str,
+        status: u16,
+        duration: Duration,
+        tokens: u64,
+    ) {
+        let mut state = self.lock_state();
+        *state
+            .requests
+            .entry((client.to_string(), upstream.to_string(), status))
+            .or_default() += 1;
+        let (seconds, count) = state
+            .durations
+            .entry((client.to_string(), upstream.to_string()))
+            .or_default();
+        *seconds += duration.as_secs_f64();
+        *count += 1;
+        *state.tokens.entry(client.to_string()).or_default() += tokens;
+    }
+
+    /// Record a request the proxy refused itself.
+    pub(crate) fn record_rejection(&self, client: &str, reason: &str) {
+        let mut state = self.lock_state();
+        *state
+            .rejections
+            .entry((client.to_string(), reason.to_string()))
+            .or_default() += 1;
+    }
+
+    pub(crate) fn render(&self, usage: &[ClientUsage]) -> String {
+        let state = self.lock_state();
+        let mut out = String::new();
+
+        out.push_str("# HELP codex_proxy_requests_total Requests forwarded upstream.\n");
+        out.push_str("# TYPE codex_proxy_requests_total counter\n");
+        for ((client, upstream, status), count) in &state.requests {
+            let _ = writeln!(
+                out,
+                "codex_proxy_requests_total{{client=\"{}\",upstream=\"{}\",status=\"{status}\"}} {count}",
+                escape(client),
+                escape(upstream)
+            );
+        }
+
+        out.push_str(
+            "# HELP codex_proxy_request_duration_seconds Time from receiving a request to the end of the upstream response.\n",
+        );
+        out.push_str("# TYPE codex_proxy_request_duration_seconds summary\n");
+        for ((client, upstream), (seconds, count)) in &state.durations {
+            let labels = format!(
+                "client=\"{}\",upstream=\"{}\"",
+                escape(client),
+                escape(upstream)
+            );
+            let _ = writeln!(
+                out,
+                "codex_proxy_request_duration_seconds_sum{{{labels}}} {seconds}"
+            );
+            let _ = writeln!(
+                out,
+                "codex_proxy_request_duration_seconds_count{{{labels}}} {count}"
+            );
+        }
+
+        out.push_str("# HELP codex_proxy_rejections_total Requests refused by the proxy.\n");
+        out.push_str("# TYPE codex_proxy_rejections_total counter\n");
+        for ((client, reason), count) in &state.rejections {
+            let _ = writeln!(
+                out,
+                "codex_proxy_rejections_total{{client=\"{}\",reason=\"{}\"}} {count}",
+                escape(client),
+                escape(reason)
+            );
+        }
+
+        out.push_str("# HELP codex_proxy_tokens_total Tokens reported by upstream responses.\n");
+        out.push_str("# TYPE codex_proxy_tokens_total counter\n");
+        for (client, tokens) in &state.tokens {
+            let _ = writeln!(
+                out,
+                "codex_proxy_tokens_total{{client=\"{}\"}} {tokens}",
+                escape(client)
+            );
+        }
+
+        out.push_str("# HELP codex_proxy_in_flight Requests currently being forwarded.\n");
+        out.push_str("# TYPE codex_proxy_in_flight gauge\n");
+        for client in usage {
+            let _ = writeln!(
+                out,
+                "codex_proxy_in_flight{{client=\"{}\"}} {}",
+                escape(&client.name),
+                client.in_flight
+            );
+        }
+
+        out.push_str(
+            "# HELP codex_proxy_budget_tokens_used Tokens used in the current budget window.\n",
+        );
+        out.push_str("# TYPE codex_proxy_budget_tokens_used gauge\n");
+        for client in usage {
+            let _ = writeln!(
+                out,
+                "codex_proxy_budget_tokens_used{{client=\"{}\"}} {}",
+                escape(&client.name),
+                client.tokens_used
+            );
+        }
+        out.push_str("# HELP codex_proxy_budget_tokens Token budget per window.\n");
+        out.push_str("# TYPE codex_proxy_budget_tokens gauge\n");
+        for client in usage {
+            if let Some(budget) = client.token_budget {
+                let _ = writeln!(
+                    out,
+                    "codex_proxy_budget_tokens{{client=\"{}\"}} {budget}",
+                    escape(&client.name)
+                );
+            }
+        }
+
+        out
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_counters_per_client() {
+        let metrics = Metrics::default();
+        metrics.record_request("alice", "default", 200, Duration::from_millis(1500), 42);
+        metrics.record_rejection("bob", "token_budget");
+
+        let rendered = metrics.render(&[ClientUsage {
+            name: "alice".to_string(),
+            in_flight: 1,
+            tokens_used: 42,
+            token_budget: Some(100),
+        }]);
+
+        for line in [
+            "codex_proxy_requests_total{client=\"alice\",upstream=\"default\",status=\"200\"} 1",
+            "codex_proxy_request_duration_seconds_sum{client=\"alice\",upstream=\"default\"} 1.5",
+            "codex_proxy_rejections_total{client=\"bob\",reason=\"token_budget\"} 1",
+            "codex_proxy_tokens_total{client=\"alice\"} 42",
+            "codex_proxy_in_flight{client=\"alice\"} 1",
+            "codex_proxy_budget_tokens{client=\"alice\"} 100",
+        ] {
+            assert!(
+                rendered.lines().any(|l| l == line),
+                "missing {line}:\n{rendered}"
+            );
+        }
+    }
+}
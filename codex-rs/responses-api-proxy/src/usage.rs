@@ -0,0 +1,94 @@
+//! Reads token usage out of a streamed Responses API body as it is forwarded.
+
+use std::io::Read;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use serde_json::Value;
+
+const COMPLETED_EVENT: &str = "\"response.completed\"";
+
+/// Wraps the upstream body and records `usage.total_tokens` from the
+/// `response.completed` server-sent event into `total_tokens`. Bytes are passed
+/// through unchanged.
+pub(crate) struct UsageTap<R> {
+    inner: R,
+    line: Vec<u8>,
+    total_tokens: Arc<AtomicU64>,
+}
+
+impl<R> UsageTap<R> {
+    pub(crate) fn new(inner: R, total_tokens: Arc<AtomicU64>) -> Self {
+        Self {
+            inner,
+            line: Vec::new(),
+            total_tokens,
+        }
+    }
+
+    fn scan(&mut self, bytes: &[u8]) {
+        for chunk in bytes.split_inclusive(|byte| *byte == b'\n') {
+            self.line.extend_from_slice(chunk);
+            if chunk.ends_with(b"\n") {
+                let line = std::mem::take(&mut self.line);
+                if let Some(tokens) = completed_total_tokens(&line) {
+                    self.total_tokens.store(tokens, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+}
+
+impl<R: Read> Read for UsageTap<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.scan(&buf[..read]);
+        Ok(read)
+    }
+}
+
+fn completed_total_tokens(line: &[u8]) -> Option<u64> {
+    let line = std::str::from_utf8(line).ok()?;
+    let data = line.trim_end().strip_prefix("data:")?.trim_start();
+    if !data.contains(COMPLETED_EVENT) {
+        return None;
+    }
+    let event: Value = serde_json::from_str(data).ok()?;
+    if event.get("type").and_then(Value::as_str) != Some("response.completed") {
+        return None;
+    }
+    event
+        .pointer("/response/usage/total_tokens")
+        .and_then(Value::as_u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_total_tokens_from_completed_event() {
+        let body = concat!(
+            "event: response.created\n",
+            "data: {\"type\":\"response.created\",\"response\":{}}\n\n",
+            "event: response.completed\n",
+            "data: {\"type\":\"response.completed\",\"response\":{\"usage\":{\"input_tokens\":10,\"output_tokens\":5,\"total_tokens\":15}}}\n\n",
+        );
+        let total_tokens = Arc::new(AtomicU64::new(0));
+        // Tiny reads split lines across calls.
+        let mut tap = UsageTap::new(body.as_bytes(), total_tokens.clone());
+        let mut forwarded = Vec::new();
+        let mut buf = [0u8; 7];
+        loop {
+            let read = tap.read(&mut buf).expect("read");
+            if read == 0 {
+                break;
+            }
+            forwarded.extend_from_slice(&buf[..read]);
+        }
+
+        assert_eq!(forwarded, body.as_bytes());
+        assert_eq!(total_tokens.load(Ordering::Relaxed), 15);
+    }
+}
@@ -0,0 +1,212 @@
+//! `codex serve`: a local HTTP API for scripts and internal tools that want to
+//! drive Codex without speaking the app-server stdio protocol.
+//!
+//! Every request must carry `Authorization: Bearer <token>`. Routes:
+//!
+//! - `POST /v1/runs` starts a `codex exec` run (optionally resuming a session)
+//! - `GET /v1/runs`, `GET /v1/runs/{id}` report run status and the final message
+//! - `GET /v1/runs/{id}/events` streams the run's `codex exec --json` events as SSE
+//! - `POST /v1/runs/{id}/cancel` stops a run
+//! - `GET /v1/sessions` lists recorded sessions, newest first
+//! - `GET /v1/sessions/{id}` returns a session's transcript
+//!
+//! Runs are tracked in memory, with the oldest finished runs dropped once too
+//! many pile up; what they do is recorded as ordinary sessions.
+
+mod runs;
+mod sessions;
+
+use std::net::IpAddr;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context;
+use axum::Json;
+use axum::Router;
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::http::Request;
+use axum::http::StatusCode;
+use axum::http::header::AUTHORIZATION;
+use axum::middleware;
+use axum::middleware::Next;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::routing::get;
+use axum::routing::post;
+use codex_common::CliConfigOverrides;
+use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
+use serde_json::json;
+
+use crate::runs::RunRegistry;
+
+/// Environment variable holding the bearer token clients must send. When it
+/// is unset, a random token is generated and printed at startup.
+pub const SERVE_TOKEN_ENV_VAR: &str = "CODEX_SERVE_TOKEN";
+
+#[derive(Debug, clap::Parser)]
+pub struct ServeCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    /// Address to listen on. Only loopback is safe unless the port is otherwise protected.
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: IpAddr,
+
+    /// Port to listen on; 0 picks a free port.
+    #[arg(long, default_value_t = 8765)]
+    pub port: u16,
+}
+
+pub(crate) struct AppState {
+    pub(crate) config: Config,
+    /// The `codex` binary runs are started with.
+    pub(crate) codex_bin: PathBuf,
+    /// `-c key=value` overrides passed to every run.
+    pub(crate) config_overrides: Vec<String>,
+    token: String,
+    pub(crate) runs: RunRegistry,
+}
+
+/// Serves the API until Ctrl-C. `codex_bin` is the `codex` executable used to
+/// start `codex exec` runs.
+pub async fn run_main(cli: ServeCli, codex_bin: PathBuf) -> anyhow::Result<()> {
+    let overrides = cli
+        .config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = Config::load_with_cli_overrides_and_harness_overrides(
+        overrides,
+        ConfigOverrides::default(),
+    )
+    .await
+    .context("failed to load configuration")?;
+
+    let token = match std::env::var(SERVE_TOKEN_ENV_VAR) {
+        Ok(token) if !token.trim().is_empty() => token.trim().to_string(),
+        _ => {
+            let token = uuid::Uuid::new_v4().simple().to_string();
+            eprintln!("{SERVE_TOKEN_ENV_VAR} is not set; using generated token {token}");
+            token
+        }
+    };
+
+    let state = Arc::new(AppState {
+        config,
+        codex_bin,
+        config_overrides: cli.config_overrides.raw_overrides,
+        token,
+        runs: RunRegistry::default(),
+    });
+
+    let listener = tokio::net::TcpListener::bind(SocketAddr::new(cli.host, cli.port))
+        .await
+        .with_context(|| format!("failed to listen on {}:{}", cli.host, cli.port))?;
+    eprintln!("codex serve listening on http://{}", listener.local_addr()?);
+
+    axum::serve(listener, router(state))
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
+
+fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/v1/runs", post(runs::create_run).get(runs::list_runs))
+        .route("/v1/runs/{id}", get(runs::get_run))
+        .route("/v1/runs/{id}/events", get(runs::run_events))
+        .route("/v1/runs/{id}/cancel", post(runs::cancel_run))
+        .route("/v1/sessions", get(sessions::list_sessions))
+        .route("/v1/sessions/{id}", get(sessions::get_session))
+        .layer(middleware::from_fn_with_state(state.clone(), require_token))
+        .with_state(state)
+}
+
+async fn require_token(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    if is_authorized(request.headers(), &state.token) {
+        next.run(request).await
+    } else {
+        ApiError::new(StatusCode::UNAUTHORIZED, "missing or invalid bearer token").into_response()
+    }
+}
+
+fn is_authorized(headers: &HeaderMap, token: &str) -> bool {
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|provided| constant_time_eq(provided.trim().as_bytes(), token.as_bytes()))
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Error body: `{"error": "<message>"}`.
+#[derive(Debug)]
+pub(crate) struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    pub(crate) fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, message)
+    }
+
+    pub(crate) fn not_found(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_FOUND, message)
+    }
+
+    pub(crate) fn internal(err: impl std::fmt::Display) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(json!({ "error": self.message }))).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(authorization: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(authorization).expect("header value"),
+        );
+        headers
+    }
+
+    #[test]
+    fn bearer_token_must_match() {
+        assert!(is_authorized(&headers("Bearer secret"), "secret"));
+        assert!(!is_authorized(&headers("Bearer other"), "secret"));
+        assert!(!is_authorized(&headers("secret"), "secret"));
+        assert!(!is_authorized(&HeaderMap::new(), "secret"));
+    }
+}
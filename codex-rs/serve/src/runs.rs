@@ -0,0 +1,567 @@
+//! `codex exec` runs started through the API.
+//!
+//! Each run is a `codex exec --json` child process. Its most recent JSONL
+//! events are kept so `/events` can replay them to late subscribers before
+//! following the live stream. Only the newest finished runs are kept.
+
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use axum::Json;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::Sse;
+use axum::response::sse::Event;
+use axum::response::sse::KeepAlive;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use codex_protocol::config_types::SandboxMode;
+use futures::Stream;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncReadExt;
+use tokio::io::BufReader;
+use tokio::sync::Notify;
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use crate::ApiError;
+use crate::AppState;
+
+/// Type of the event the server appends once the child process exits.
+const RUN_FINISHED_EVENT: &str = "run.finished";
+
+/// Type of the event replayed first when older events were dropped.
+const RUN_EVENTS_DROPPED_EVENT: &str = "run.events_dropped";
+
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Events kept per run for replay; older ones are dropped.
+const MAX_RUN_EVENTS: usize = 10_000;
+
+/// Finished runs kept for `/v1/runs`; older ones are forgotten.
+const MAX_FINISHED_RUNS: usize = 100;
+
+/// Trailing stderr kept as the error of a failed run.
+const MAX_ERROR_CHARS: usize = 4000;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CreateRunRequest {
+    prompt: String,
+    /// Working directory; defaults to the server's.
+    #[serde(default)]
+    cwd: Option<PathBuf>,
+    /// Session id or thread name to continue instead of starting a new one.
+    #[serde(default)]
+    session_id: Option<String>,
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    sandbox: Option<SandboxMode>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum RunStatus {
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct RunSummary {
+    id: String,
+    status: RunStatus,
+    prompt: String,
+    cwd: PathBuf,
+    created_at: String,
+    /// Session the run is recorded as, once `codex exec` reports it.
+    thread_id: Option<String>,
+    last_message: Option<String>,
+    exit_code: Option<i32>,
+    error: Option<String>,
+}
+
+#[derive(Default)]
+struct RunLog {
+    events: VecDeque<Arc<str>>,
+    /// Events dropped from the front to stay within [`MAX_RUN_EVENTS`].
+    dropped: usize,
+    finished: bool,
+}
+
+impl RunLog {
+    fn push(&mut self, line: Arc<str>) {
+        if self.events.len() == MAX_RUN_EVENTS {
+            self.events.pop_front();
+            self.dropped += 1;
+        }
+        self.events.push_back(line);
+    }
+
+    fn replay(&self) -> Vec<Arc<str>> {
+        let mut replay = Vec::with_capacity(self.events.len() + 1);
+        if self.dropped > 0 {
+            let line = json!({
+                "type": RUN_EVENTS_DROPPED_EVENT,
+                "count": self.dropped,
+            })
+            .to_string();
+            replay.push(line.into());
+        }
+        replay.extend(self.events.iter().cloned());
+        replay
+    }
+}
+
+pub(crate) struct Run {
+    id: String,
+    summary: Mutex<RunSummary>,
+    log: Mutex<RunLog>,
+    events_tx: broadcast::Sender<Arc<str>>,
+    cancel: Notify,
+}
+
+impl Run {
+    fn summary(&self) -> RunSummary {
+        lock(&self.summary).clone()
+    }
+
+    fn publish(&self, line: String) {
+        if let Some(thread_id) = thread_started_id(&line) {
+            lock(&self.summary).thread_id = Some(thread_id);
+        }
+        let line: Arc<str> = line.into();
+        let mut log = lock(&self.log);
+        log.push(line.clone());
+        let _ = self.events_tx.send(line);
+    }
+
+    fn finish(&self, update: impl FnOnce(&mut RunSummary)) {
+        let summary = {
+            let mut summary = lock(&self.summary);
+            update(&mut summary);
+            summary.clone()
+        };
+        let line: Arc<str> = json!({
+            "type": RUN_FINISHED_EVENT,
+            "status": summary.status,
+            "thread_id": summary.thread_id,
+            "exit_code": summary.exit_code,
+        })
+        .to_string()
+        .into();
+        let mut log = lock(&self.log);
+        log.push(line.clone());
+        log.finished = true;
+        let _ = self.events_tx.send(line);
+    }
+
+    /// Events recorded so far plus a receiver for the rest, taken under the
+    /// same lock so nothing is missed or repeated.
+    fn subscribe(&self) -> (Vec<Arc<str>>, Option<broadcast::Receiver<Arc<str>>>) {
+        let log = lock(&self.log);
+        let live = (!log.finished).then(|| self.events_tx.subscribe());
+        (log.replay(), live)
+    }
+
+    fn is_finished(&self) -> bool {
+        lock(&self.log).finished
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Runs started by this server, oldest first.
+#[derive(Default)]
+pub(crate) struct RunRegistry {
+    runs: Mutex<Vec<Arc<Run>>>,
+}
+
+impl RunRegistry {
+    /// Adds `run` and forgets the oldest finished runs beyond
+    /// [`MAX_FINISHED_RUNS`].
+    fn insert(&self, run: Arc<Run>) {
+        let mut runs = lock(&self.runs);
+        runs.push(run);
+        let finished = runs.iter().filter(|run| run.is_finished()).count();
+        let mut excess = finished.saturating_sub(MAX_FINISHED_RUNS);
+        runs.retain(|run| {
+            if excess > 0 && run.is_finished() {
+                excess -= 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    fn get(&self, id: &str) -> Result<Arc<Run>, ApiError> {
+        lock(&self.runs)
+            .iter()
+            .find(|run| run.id == id)
+            .cloned()
+            .ok_or_else(|| ApiError::not_found(format!("no run with id {id}")))
+    }
+
+    fn summaries(&self) -> Vec<RunSummary> {
+        lock(&self.runs).iter().map(|run| run.summary()).collect()
+    }
+}
+
+pub(crate) async fn create_run(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CreateRunRequest>,
+) -> Result<(StatusCode, Json<RunSummary>), ApiError> {
+    if request.prompt.trim().is_empty() {
+        return Err(ApiError::bad_request("prompt must not be empty"));
+    }
+    let cwd = request
+        .cwd
+        .clone()
+        .unwrap_or_else(|| state.config.cwd.clone());
+    if !cwd.is_dir() {
+        return Err(ApiError::bad_request(format!(
+            "cwd {} is not a directory",
+            cwd.display()
+        )));
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let (events_tx, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+    let run = Arc::new(Run {
+        id: id.clone(),
+        summary: Mutex::new(RunSummary {
+            id,
+            status: RunStatus::Running,
+            prompt: request.prompt.clone(),
+            cwd: cwd.clone(),
+            created_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            thread_id: None,
+            last_message: None,
+            exit_code: None,
+            error: None,
+        }),
+        log: Mutex::new(RunLog::default()),
+        events_tx,
+        cancel: Notify::new(),
+    });
+    state.runs.insert(run.clone());
+
+    let summary = run.summary();
+    tokio::spawn(execute(state, run, request, cwd));
+    Ok((StatusCode::CREATED, Json(summary)))
+}
+
+pub(crate) async fn list_runs(State(state): State<Arc<AppState>>) -> Json<Value> {
+    Json(json!({ "runs": state.runs.summaries() }))
+}
+
+pub(crate) async fn get_run(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<RunSummary>, ApiError> {
+    Ok(Json(state.runs.get(&id)?.summary()))
+}
+
+pub(crate) async fn cancel_run(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<RunSummary>, ApiError> {
+    let run = state.runs.get(&id)?;
+    if run.summary().status != RunStatus::Running {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            format!("run {id} has already finished"),
+        ));
+    }
+    run.cancel.notify_one();
+    Ok(Json(run.summary()))
+}
+
+/// Replays the run's events, then follows new ones until the run finishes.
+/// Each SSE event is named after the JSON event's `type`.
+pub(crate) async fn run_events(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let run = state.runs.get(&id)?;
+    let (recorded, mut live) = run.subscribe();
+    let stream = async_stream::stream! {
+        for line in recorded {
+            yield Ok(sse_event(&line));
+        }
+        if let Some(live) = live.as_mut() {
+            loop {
+                match live.recv().await {
+                    Ok(line) => {
+                        let finished = event_type(&line).as_deref() == Some(RUN_FINISHED_EVENT);
+                        yield Ok(sse_event(&line));
+                        if finished {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("run {id} event stream lagged; skipped {skipped} events");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    };
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+fn sse_event(line: &str) -> Event {
+    let event = Event::default().data(line);
+    match event_type(line) {
+        Some(kind) => event.event(kind),
+        None => event,
+    }
+}
+
+fn event_type(line: &str) -> Option<String> {
+    let event: Value = serde_json::from_str(line).ok()?;
+    Some(event.get("type")?.as_str()?.to_string())
+}
+
+/// Extracts the session id from exec's `thread.started` event.
+fn thread_started_id(line: &str) -> Option<String> {
+    let event: Value = serde_json::from_str(line).ok()?;
+    if event.get("type")?.as_str()? != "thread.started" {
+        return None;
+    }
+    Some(event.get("thread_id")?.as_str()?.to_string())
+}
+
+async fn execute(state: Arc<AppState>, run: Arc<Run>, request: CreateRunRequest, cwd: PathBuf) {
+    let last_message_file = match tempfile::NamedTempFile::new() {
+        Ok(file) => file,
+        Err(err) => {
+            run.finish(|summary| {
+                summary.status = RunStatus::Failed;
+                summary.error = Some(format!("failed to create output file: {err}"));
+            });
+            return;
+        }
+    };
+
+    let mut command = tokio::process::Command::new(&state.codex_bin);
+    command.arg("exec");
+    for raw in &state.config_overrides {
+        command.arg("-c").arg(raw);
+    }
+    if let Some(model) = &request.model {
+        command.arg("--model").arg(model);
+    }
+    if let Some(sandbox) = request.sandbox {
+        command.arg("--sandbox").arg(sandbox.to_string());
+    }
+    command
+        .arg("--json")
+        .arg("--skip-git-repo-check")
+        .arg("--cd")
+        .arg(&cwd)
+        .arg("--output-last-message")
+        .arg(last_message_file.path());
+    if let Some(session_id) = &request.session_id {
+        command.arg("resume").arg(session_id);
+    }
+    command
+        .arg("--")
+        .arg(&request.prompt)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(err) => {
+            run.finish(|summary| {
+                summary.status = RunStatus::Failed;
+                summary.error = Some(format!("failed to start codex exec: {err}"));
+            });
+            return;
+        }
+    };
+
+    let stderr = child.stderr.take().map(|mut stderr| {
+        tokio::spawn(async move {
+            let mut output = String::new();
+            let _ = stderr.read_to_string(&mut output).await;
+            output
+        })
+    });
+
+    let mut cancelled = false;
+    if let Some(stdout) = child.stdout.take() {
+        let mut lines = BufReader::new(stdout).lines();
+        loop {
+            tokio::select! {
+                line = lines.next_line() => match line {
+                    Ok(Some(line)) if !line.trim().is_empty() => run.publish(line),
+                    Ok(Some(_)) => {}
+                    Ok(None) | Err(_) => break,
+                },
+                _ = run.cancel.notified() => {
+                    let _ = child.start_kill();
+                    cancelled = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    let status = child.wait().await;
+    let stderr = match stderr {
+        Some(task) => task.await.unwrap_or_default(),
+        None => String::new(),
+    };
+    let last_message = std::fs::read_to_string(last_message_file.path())
+        .ok()
+        .map(|message| message.trim().to_string())
+        .filter(|message| !message.is_empty());
+
+    run.finish(|summary| {
+        summary.last_message = last_message;
+        match status {
+            Ok(status) => {
+                summary.exit_code = status.code();
+                summary.status = if cancelled {
+                    RunStatus::Cancelled
+                } else if status.success() {
+                    RunStatus::Completed
+                } else {
+                    RunStatus::Failed
+                };
+            }
+            Err(err) => {
+                summary.status = RunStatus::Failed;
+                summary.error = Some(err.to_string());
+            }
+        }
+        if summary.status == RunStatus::Failed && summary.error.is_none() {
+            summary.error = Some(tail(stderr.trim(), MAX_ERROR_CHARS).to_string());
+        }
+    });
+}
+
+fn tail(text: &str, max_chars: usize) -> &str {
+    let skip = text.chars().count().saturating_sub(max_chars);
+    match text.char_indices().nth(skip) {
+        Some((index, _)) => &text[index..],
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn run(id: &str) -> Run {
+        let (events_tx, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Run {
+            id: id.to_string(),
+            summary: Mutex::new(RunSummary {
+                id: id.to_string(),
+                status: RunStatus::Running,
+                prompt: "hi".to_string(),
+                cwd: PathBuf::from("/repo"),
+                created_at: String::new(),
+                thread_id: None,
+                last_message: None,
+                exit_code: None,
+                error: None,
+            }),
+            log: Mutex::new(RunLog::default()),
+            events_tx,
+            cancel: Notify::new(),
+        }
+    }
+
+    #[test]
+    fn published_events_are_replayed_and_record_the_session() {
+        let run = run("run");
+        run.publish(r#"{"type":"thread.started","thread_id":"abc"}"#.to_string());
+
+        let (recorded, live) = run.subscribe();
+        assert_eq!(recorded.len(), 1);
+        assert!(live.is_some());
+        assert_eq!(run.summary().thread_id, Some("abc".to_string()));
+
+        run.finish(|summary| summary.status = RunStatus::Completed);
+        let (recorded, live) = run.subscribe();
+        assert!(live.is_none());
+        assert_eq!(
+            recorded
+                .iter()
+                .map(|line| event_type(line))
+                .collect::<Vec<_>>(),
+            vec![
+                Some("thread.started".to_string()),
+                Some(RUN_FINISHED_EVENT.to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn replay_keeps_the_newest_events_and_reports_dropped_ones() {
+        let run = run("run");
+        for n in 0..MAX_RUN_EVENTS + 5 {
+            run.publish(format!(r#"{{"type":"item.completed","n":{n}}}"#));
+        }
+
+        let (recorded, _) = run.subscribe();
+        assert_eq!(recorded.len(), MAX_RUN_EVENTS + 1);
+        let dropped: Value = serde_json::from_str(&recorded[0]).expect("json");
+        assert_eq!(
+            dropped,
+            json!({ "type": RUN_EVENTS_DROPPED_EVENT, "count": 5 })
+        );
+        assert_eq!(&*recorded[1], r#"{"type":"item.completed","n":5}"#);
+    }
+
+    #[test]
+    fn registry_forgets_the_oldest_finished_runs() {
+        let registry = RunRegistry::default();
+        let running = Arc::new(run("running"));
+        registry.insert(running);
+        for n in 0..=MAX_FINISHED_RUNS {
+            let finished = Arc::new(run(&format!("finished-{n}")));
+            finished.finish(|summary| summary.status = RunStatus::Completed);
+            registry.insert(finished);
+        }
+
+        let summaries = registry.summaries();
+        assert_eq!(summaries.len(), MAX_FINISHED_RUNS + 1);
+        assert!(registry.get("running").is_ok());
+        assert!(registry.get("finished-0").is_err());
+        assert!(
+            registry
+                .get(&format!("finished-{MAX_FINISHED_RUNS}"))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn tail_keeps_the_end_of_the_text() {
+        assert_eq!(tail("abcdef", 3), "def");
+        assert_eq!(tail("abc", 10), "abc");
+    }
+}
@@ -0,0 +1,183 @@
+//! Read-only access to recorded sessions.
+
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::State;
+use codex_core::RolloutRecorder;
+use codex_core::ThreadItem;
+use codex_core::ThreadSortKey;
+use codex_core::find_thread_path_by_id_str;
+use codex_core::find_thread_path_by_name_str;
+use codex_core::parse_cursor;
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::ApiError;
+use crate::AppState;
+
+const DEFAULT_PAGE_SIZE: usize = 20;
+const MAX_PAGE_SIZE: usize = 100;
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ListSessionsQuery {
+    #[serde(default)]
+    limit: Option<usize>,
+    /// `next_cursor` from the previous page.
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct SessionSummary {
+    id: Option<String>,
+    /// First user message.
+    preview: Option<String>,
+    cwd: Option<String>,
+    git_branch: Option<String>,
+    created_at: Option<String>,
+    updated_at: Option<String>,
+}
+
+impl From<ThreadItem> for SessionSummary {
+    fn from(item: ThreadItem) -> Self {
+        Self {
+            id: item.thread_id.map(|id| id.to_string()),
+            preview: item.first_user_message,
+            cwd: item.cwd.map(|cwd| cwd.display().to_string()),
+            git_branch: item.git_branch,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct TranscriptMessage {
+    role: &'static str,
+    text: String,
+}
+
+pub(crate) async fn list_sessions(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ListSessionsQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let cursor = match query.cursor.as_deref() {
+        Some(token) => {
+            Some(parse_cursor(token).ok_or_else(|| ApiError::bad_request("invalid cursor"))?)
+        }
+        None => None,
+    };
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let page = RolloutRecorder::list_threads(
+        &state.config,
+        limit,
+        cursor.as_ref(),
+        ThreadSortKey::UpdatedAt,
+        &[],
+        None,
+        &state.config.model_provider_id,
+    )
+    .await
+    .map_err(ApiError::internal)?;
+
+    let sessions: Vec<SessionSummary> = page.items.into_iter().map(Into::into).collect();
+    Ok(Json(json!({
+        "sessions": sessions,
+        "next_cursor": page.next_cursor,
+    })))
+}
+
+/// Returns the session's user and assistant messages, plus every recorded
+/// rollout item for callers that need tool calls and metadata.
+pub(crate) async fn get_session(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, ApiError> {
+    let codex_home = &state.config.codex_home;
+    let path = if ThreadId::from_string(&id).is_ok() {
+        find_thread_path_by_id_str(codex_home, &id).await
+    } else {
+        find_thread_path_by_name_str(codex_home, &id).await
+    }
+    .map_err(ApiError::internal)?
+    .ok_or_else(|| ApiError::not_found(format!("no recorded session matches `{id}`")))?;
+
+    let history = RolloutRecorder::get_rollout_history(&path)
+        .await
+        .map_err(ApiError::internal)?;
+    let items = history.get_rollout_items();
+    let session_id = items.iter().find_map(|item| match item {
+        RolloutItem::SessionMeta(meta) => Some(meta.meta.id.to_string()),
+        _ => None,
+    });
+    Ok(Json(json!({
+        "id": session_id,
+        "messages": transcript_messages(&items),
+        "items": items,
+    })))
+}
+
+fn transcript_messages(items: &[RolloutItem]) -> Vec<TranscriptMessage> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            RolloutItem::EventMsg(EventMsg::UserMessage(event)) => Some(TranscriptMessage {
+                role: "user",
+                text: event.message.clone(),
+            }),
+            RolloutItem::EventMsg(EventMsg::AgentMessage(event)) => Some(TranscriptMessage {
+                role: "assistant",
+                text: event.message.clone(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::AgentMessageEvent;
+    use codex_protocol::protocol::UserMessageEvent;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transcript_keeps_user_and_assistant_messages_in_order() {
+        let items = vec![
+            RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                message: "fix the build".to_string(),
+                images: None,
+                local_images: Vec::new(),
+                text_elements: Vec::new(),
+            })),
+            RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+                message: "Fixed.".to_string(),
+            })),
+        ];
+
+        assert_eq!(
+            transcript_messages(&items),
+            vec![
+                TranscriptMessage {
+                    role: "user",
+                    text: "fix the build".to_string(),
+                },
+                TranscriptMessage {
+                    role: "assistant",
+                    text: "Fixed.".to_string(),
+                },
+            ]
+        );
+    }
+}
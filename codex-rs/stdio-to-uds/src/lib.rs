@@ -1,52 +1,262 @@
 #![deny(clippy::print_stdout)]
 
 use std::io;
+use std::io::Read;
 use std::io::Write;
-use std::net::Shutdown;
-use std::path::Path;
+use std::sync::mpsc;
 use std::thread;
+use std::time::Duration;
+use std::time::Instant;
 
 use anyhow::Context;
 use anyhow::anyhow;
 
-#[cfg(unix)]
-use std::os::unix::net::UnixStream;
+mod target;
 
-#[cfg(windows)]
-use uds_windows::UnixStream;
+use target::Connection;
+pub use target::Target;
 
-/// Connects to the Unix Domain Socket at `socket_path` and relays data between
-/// standard input/output and the socket.
-pub fn run(socket_path: &Path) -> anyhow::Result<()> {
-    let mut stream = UnixStream::connect(socket_path)
-        .with_context(|| format!("failed to connect to socket at {}", socket_path.display()))?;
+/// How long to keep reconnecting when `reconnect` is set without a `timeout`.
+const DEFAULT_RECONNECT_WINDOW: Duration = Duration::from_secs(30);
+const RECONNECT_BACKOFF_INITIAL: Duration = Duration::from_millis(50);
+const RECONNECT_BACKOFF_MAX: Duration = Duration::from_secs(2);
+/// Upper bound on stdin data held while the peer is away.
+const MAX_BUFFERED_BYTES: usize = 16 * 1024 * 1024;
+const STDIN_CHUNK_BYTES: usize = 64 * 1024;
 
-    let mut reader = stream
-        .try_clone()
-        .context("failed to clone socket for reading")?;
+#[derive(Debug, Clone, Default)]
+pub struct RelayOptions {
+    /// Reconnect when the peer goes away before stdin is closed, resending
+    /// stdin data that could not be delivered.
+    pub reconnect: bool,
+    /// Give up when the peer cannot be reached for this long, on the first
+    /// connection and on each reconnect.
+    pub timeout: Option<Duration>,
+}
 
-    let stdout_thread = thread::spawn(move || -> io::Result<()> {
-        let stdout = io::stdout();
-        let mut handle = stdout.lock();
-        io::copy(&mut reader, &mut handle)?;
-        handle.flush()?;
-        Ok(())
-    });
+/// Connects to `target` and relays data between standard input/output and the
+/// connection until stdin is closed and the peer has finished responding.
+pub fn run(target: &Target, options: &RelayOptions) -> anyhow::Result<()> {
+    let mut connection = connect(target, options.timeout)?;
+    let (events_tx, events_rx) = mpsc::channel();
+    spawn_stdin_reader(events_tx.clone());
+    let mut generation = 0;
+    spawn_output_thread(connection.as_ref(), generation, events_tx.clone())?;
 
-    let stdin = io::stdin();
-    {
-        let mut handle = stdin.lock();
-        io::copy(&mut handle, &mut stream).context("failed to copy data from stdin to socket")?;
+    // Stdin data not yet written to the peer.
+    let mut pending: Vec<u8> = Vec::new();
+    let mut stdin_closed = false;
+    let mut peer_closed = false;
+    let mut output_result: Option<io::Result<()>> = None;
+
+    loop {
+        let event = events_rx
+            .recv()
+            .map_err(|_| anyhow!("relay threads exited unexpectedly"))?;
+        let disconnected = match event {
+            Event::Stdin(data) => {
+                pending.extend_from_slice(&data);
+                if pending.len() > MAX_BUFFERED_BYTES {
+                    return Err(anyhow!(
+                        "more than {MAX_BUFFERED_BYTES} bytes of stdin are waiting for {target}"
+                    ));
+                }
+                deliver(connection.as_mut(), &mut pending, options)?
+            }
+            Event::StdinClosed(result) => {
+                result.context("failed to read data from stdin")?;
+                stdin_closed = true;
+                let disconnected = deliver(connection.as_mut(), &mut pending, options)?;
+                if !disconnected {
+                    connection
+                        .shutdown_write()
+                        .context("failed to shutdown socket writer")?;
+                }
+                disconnected
+            }
+            Event::OutputClosed {
+                generation: closed,
+                result,
+            } => {
+                if closed != generation {
+                    continue;
+                }
+                if result.as_ref().is_err_and(is_output_error) {
+                    return result.context("failed to copy data from socket to stdout");
+                }
+                peer_closed = true;
+                output_result = Some(result);
+                options.reconnect && !stdin_closed
+            }
+        };
+
+        if disconnected {
+            let window = options.timeout.unwrap_or(DEFAULT_RECONNECT_WINDOW);
+            eprintln!("connection to {target} lost; reconnecting");
+            connection = connect(target, Some(window))?;
+            generation += 1;
+            peer_closed = false;
+            output_result = None;
+            spawn_output_thread(connection.as_ref(), generation, events_tx.clone())?;
+            write_pending(connection.as_mut(), &mut pending)
+                .context("failed to copy data from stdin to socket")?;
+            if stdin_closed {
+                connection
+                    .shutdown_write()
+                    .context("failed to shutdown socket writer")?;
+            }
+            continue;
+        }
+
+        if stdin_closed && peer_closed {
+            return output_result
+                .unwrap_or(Ok(()))
+                .context("failed to copy data from socket to stdout");
+        }
+    }
+}
+
+enum Event {
+    Stdin(Vec<u8>),
+    StdinClosed(io::Result<()>),
+    OutputClosed {
+        generation: u64,
+        result: io::Result<()>,
+    },
+}
+
+/// Connect, retrying with backoff for up to `window` when one is given.
+fn connect(target: &Target, window: Option<Duration>) -> anyhow::Result<Box<dyn Connection>> {
+    let Some(window) = window else {
+        return target
+            .connect(None)
+            .with_context(|| format!("failed to connect to {target}"));
+    };
+    let deadline = Instant::now() + window;
+    let mut backoff = RECONNECT_BACKOFF_INITIAL;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match target.connect(Some(remaining.max(RECONNECT_BACKOFF_INITIAL))) {
+            Ok(connection) => return Ok(connection),
+            Err(_) if Instant::now() + backoff < deadline => {
+                thread::sleep(backoff);
+                backoff = (backoff * 2).min(RECONNECT_BACKOFF_MAX);
+            }
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!(
+                        "failed to connect to {target} within {}s",
+                        window.as_secs_f32()
+                    )
+                });
+            }
+        }
     }
+}
 
-    stream
-        .shutdown(Shutdown::Write)
-        .context("failed to shutdown socket writer")?;
+/// Write `pending` to the peer. Returns whether the connection was lost and
+/// should be re-established; without `reconnect` that is an error.
+fn deliver(
+    connection: &mut dyn Connection,
+    pending: &mut Vec<u8>,
+    options: &RelayOptions,
+) -> anyhow::Result<bool> {
+    match write_pending(connection, pending) {
+        Ok(()) => Ok(false),
+        Err(_) if options.reconnect => Ok(true),
+        Err(err) => Err(err).context("failed to copy data from stdin to socket"),
+    }
+}
+
+/// Write as much of `pending` as the peer accepts, dropping what was written.
+fn write_pending(connection: &mut dyn Connection, pending: &mut Vec<u8>) -> io::Result<()> {
+    while !pending.is_empty() {
+        match connection.write(pending) {
+            Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+            Ok(written) => {
+                pending.drain(..written);
+            }
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    connection.flush()
+}
 
-    let stdout_result = stdout_thread
-        .join()
-        .map_err(|_| anyhow!("thread panicked while copying socket data to stdout"))?;
-    stdout_result.context("failed to copy data from socket to stdout")?;
+fn spawn_stdin_reader(events: mpsc::Sender<Event>) {
+    thread::spawn(move || {
+        let stdin = io::stdin();
+        let mut handle = stdin.lock();
+        let mut buf = vec![0u8; STDIN_CHUNK_BYTES];
+        let result = loop {
+            match handle.read(&mut buf) {
+                Ok(0) => break Ok(()),
+                Ok(read) => {
+                    if events.send(Event::Stdin(buf[..read].to_vec())).is_err() {
+                        return;
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => break Err(err),
+            }
+        };
+        let _ = events.send(Event::StdinClosed(result));
+    });
+}
 
+fn spawn_output_thread(
+    connection: &dyn Connection,
+    generation: u64,
+    events: mpsc::Sender<Event>,
+) -> anyhow::Result<()> {
+    let mut reader = connection
+        .try_clone_reader()
+        .context("failed to clone socket for reading")?;
+    thread::spawn(move || {
+        let result = copy_to_stdout(&mut reader);
+        let _ = events.send(Event::OutputClosed { generation, result });
+    });
     Ok(())
 }
+
+fn copy_to_stdout(reader: &mut dyn Read) -> io::Result<()> {
+    let stdout = io::stdout();
+    let mut handle = stdout.lock();
+    let mut buf = vec![0u8; STDIN_CHUNK_BYTES];
+    loop {
+        let read = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        handle
+            .write_all(&buf[..read])
+            .and_then(|()| handle.flush())
+            .map_err(OutputError::wrap)?;
+    }
+    handle.flush().map_err(OutputError::wrap)
+}
+
+/// Marks failures writing to our own stdout, which no reconnect can fix.
+#[derive(Debug)]
+struct OutputError(io::Error);
+
+impl OutputError {
+    fn wrap(err: io::Error) -> io::Error {
+        io::Error::new(err.kind(), OutputError(err))
+    }
+}
+
+impl std::fmt::Display for OutputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to write to stdout: {}", self.0)
+    }
+}
+
+impl std::error::Error for OutputError {}
+
+fn is_output_error(err: &io::Error) -> bool {
+    err.get_ref()
+        .is_some_and(|inner| inner.downcast_ref::<OutputError>().is_some())
+}
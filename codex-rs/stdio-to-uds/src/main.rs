@@ -1,19 +1,38 @@
 use std::env;
-use std::path::PathBuf;
 use std::process;
+use std::time::Duration;
 
-fn main() -> anyhow::Result<()> {
-    let mut args = env::args_os().skip(1);
-    let Some(socket_path) = args.next() else {
-        eprintln!("Usage: codex-stdio-to-uds <socket-path>");
-        process::exit(1);
-    };
+use codex_stdio_to_uds::RelayOptions;
+use codex_stdio_to_uds::Target;
 
-    if args.next().is_some() {
-        eprintln!("Expected exactly one argument: <socket-path>");
-        process::exit(1);
+const USAGE: &str = "Usage: codex-stdio-to-uds [--reconnect] [--timeout <SECONDS>] <target>";
+
+fn main() -> anyhow::Result<()> {
+    let mut options = RelayOptions::default();
+    let mut target = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--reconnect" => options.reconnect = true,
+            "--timeout" => {
+                let Some(seconds) = args.next().and_then(|value| value.parse::<u64>().ok()) else {
+                    eprintln!("--timeout expects a number of seconds");
+                    process::exit(1);
+                };
+                options.timeout = Some(Duration::from_secs(seconds));
+            }
+            _ if target.is_none() => target = Some(arg),
+            _ => {
+                eprintln!("Expected exactly one target\n{USAGE}");
+                process::exit(1);
+            }
+        }
     }
 
-    let socket_path = PathBuf::from(socket_path);
-    codex_stdio_to_uds::run(&socket_path)
+    let Some(target) = target else {
+        eprintln!("{USAGE}");
+        process::exit(1);
+    };
+    let target: Target = target.parse()?;
+    codex_stdio_to_uds::run(&target, &options)
 }
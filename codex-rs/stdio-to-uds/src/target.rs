@@ -0,0 +1,292 @@
+use std::fmt;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::net::Shutdown;
+use std::net::TcpStream;
+use std::net::ToSocketAddrs;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+
+#[cfg(windows)]
+use uds_windows::UnixStream;
+
+/// What the bridge connects to.
+///
+/// - `tcp://HOST:PORT` for a TCP socket.
+/// - `\\.\pipe\NAME` or `npipe://NAME` for a Windows named pipe.
+/// - `unix://PATH` or a plain path for a Unix domain socket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Unix(PathBuf),
+    Tcp(String),
+    NamedPipe(String),
+}
+
+const NAMED_PIPE_PREFIX: &str = r"\\.\pipe\";
+
+impl FromStr for Target {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        if let Some(addr) = value.strip_prefix("tcp://") {
+            if addr.is_empty() {
+                anyhow::bail!("TCP target must be tcp://HOST:PORT");
+            }
+            return Ok(Self::Tcp(addr.to_string()));
+        }
+        if let Some(name) = value
+            .strip_prefix("npipe://")
+            .or_else(|| value.strip_prefix(NAMED_PIPE_PREFIX))
+        {
+            if name.is_empty() {
+                anyhow::bail!("named pipe target must name a pipe");
+            }
+            return Ok(Self::NamedPipe(name.to_string()));
+        }
+        let path = value.strip_prefix("unix://").unwrap_or(value);
+        if path.is_empty() {
+            anyhow::bail!("socket path must not be empty");
+        }
+        Ok(Self::Unix(PathBuf::from(path)))
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unix(path) => write!(f, "socket at {}", path.display()),
+            Self::Tcp(addr) => write!(f, "tcp://{addr}"),
+            Self::NamedPipe(name) => write!(f, "{NAMED_PIPE_PREFIX}{name}"),
+        }
+    }
+}
+
+/// A connected, bidirectional byte stream.
+pub(crate) trait Connection: Read + Write + Send {
+    /// A handle reading from the same connection, for the output thread.
+    fn try_clone_reader(&self) -> io::Result<Box<dyn Read + Send>>;
+
+    /// Tell the peer no more data is coming.
+    fn shutdown_write(&mut self) -> io::Result<()>;
+}
+
+impl Connection for UnixStream {
+    fn try_clone_reader(&self) -> io::Result<Box<dyn Read + Send>> {
+        Ok(Box::new(self.try_clone()?))
+    }
+
+    fn shutdown_write(&mut self) -> io::Result<()> {
+        self.shutdown(Shutdown::Write)
+    }
+}
+
+impl Connection for TcpStream {
+    fn try_clone_reader(&self) -> io::Result<Box<dyn Read + Send>> {
+        Ok(Box::new(self.try_clone()?))
+    }
+
+    fn shutdown_write(&mut self) -> io::Result<()> {
+        self.shutdown(Shutdown::Write)
+    }
+}
+
+impl Target {
+    /// Connect once. `timeout` bounds the TCP handshake; Unix sockets and
+    /// named pipes fail immediately when the peer is not there.
+    pub(crate) fn connect(&self, timeout: Option<Duration>) -> io::Result<Box<dyn Connection>> {
+        match self {
+            Self::Unix(path) => Ok(Box::new(UnixStream::connect(path)?)),
+            Self::Tcp(addr) => {
+                let stream = match timeout {
+                    Some(timeout) => connect_tcp_with_timeout(addr, timeout)?,
+                    None => TcpStream::connect(addr)?,
+                };
+                stream.set_nodelay(true)?;
+                Ok(Box::new(stream))
+            }
+            Self::NamedPipe(name) => named_pipe::connect(name),
+        }
+    }
+}
+
+fn connect_tcp_with_timeout(addr: &str, timeout: Duration) -> io::Result<TcpStream> {
+    let mut last_err = None;
+    for addr in addr.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(err) => last_err = Some(err),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "address resolved to nothing")
+    }))
+}
+
+#[cfg(windows)]
+mod named_pipe {
+    use std::ffi::c_void;
+    use std::fs::File;
+    use std::fs::OpenOptions;
+    use std::io;
+    use std::io::Read;
+    use std::io::Write;
+    use std::os::windows::io::AsRawHandle;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    use super::Connection;
+    use super::NAMED_PIPE_PREFIX;
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn PeekNamedPipe(
+            handle: *mut c_void,
+            buffer: *mut c_void,
+            buffer_size: u32,
+            bytes_read: *mut u32,
+            total_bytes_avail: *mut u32,
+            bytes_left_this_message: *mut u32,
+        ) -> i32;
+    }
+
+    /// The client end of a named pipe. Synchronous pipe handles serialize
+    /// reads and writes, so the reader only calls `read` once data is waiting
+    /// and a blocked read never holds up a write.
+    struct NamedPipe {
+        file: Arc<File>,
+    }
+
+    struct NamedPipeReader {
+        file: Arc<File>,
+    }
+
+    pub(super) fn connect(name: &str) -> io::Result<Box<dyn Connection>> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(format!("{NAMED_PIPE_PREFIX}{name}"))?;
+        Ok(Box::new(NamedPipe {
+            file: Arc::new(file),
+        }))
+    }
+
+    impl Read for NamedPipe {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            (&*self.file).read(buf)
+        }
+    }
+
+    impl Write for NamedPipe {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            (&*self.file).write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            (&*self.file).flush()
+        }
+    }
+
+    impl Connection for NamedPipe {
+        fn try_clone_reader(&self) -> io::Result<Box<dyn Read + Send>> {
+            Ok(Box::new(NamedPipeReader {
+                file: Arc::clone(&self.file),
+            }))
+        }
+
+        fn shutdown_write(&mut self) -> io::Result<()> {
+            // Pipes have no half-close; the peer sees EOF when we exit.
+            Ok(())
+        }
+    }
+
+    impl Read for NamedPipeReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if buf.is_empty() {
+                return Ok(0);
+            }
+            loop {
+                let mut available = 0u32;
+                // SAFETY: the handle is open for the lifetime of `self.file`
+                // and only `available` is written.
+                let ok = unsafe {
+                    PeekNamedPipe(
+                        self.file.as_raw_handle(),
+                        std::ptr::null_mut(),
+                        0,
+                        std::ptr::null_mut(),
+                        &mut available,
+                        std::ptr::null_mut(),
+                    )
+                };
+                if ok == 0 {
+                    let err = io::Error::last_os_error();
+                    // ERROR_BROKEN_PIPE: the server closed its end.
+                    return match err.raw_os_error() {
+                        Some(109) => Ok(0),
+                        _ => Err(err),
+                    };
+                }
+                if available > 0 {
+                    let len = buf.len().min(available as usize);
+                    return (&*self.file).read(&mut buf[..len]);
+                }
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+#[cfg(not(windows))]
+mod named_pipe {
+    use std::io;
+
+    use super::Connection;
+
+    pub(super) fn connect(_name: &str) -> io::Result<Box<dyn Connection>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "named pipes are only supported on Windows",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_each_target_kind() {
+        let parse = |value: &str| value.parse::<Target>().ok();
+
+        assert_eq!(
+            parse("tcp://127.0.0.1:8080"),
+            Some(Target::Tcp("127.0.0.1:8080".to_string()))
+        );
+        assert_eq!(
+            parse(r"\\.\pipe\codex-mcp"),
+            Some(Target::NamedPipe("codex-mcp".to_string()))
+        );
+        assert_eq!(
+            parse("npipe://codex-mcp"),
+            Some(Target::NamedPipe("codex-mcp".to_string()))
+        );
+        assert_eq!(
+            parse("unix:///tmp/mcp.sock"),
+            Some(Target::Unix(PathBuf::from("/tmp/mcp.sock")))
+        );
+        assert_eq!(
+            parse("/tmp/mcp.sock"),
+            Some(Target::Unix(PathBuf::from("/tmp/mcp.sock")))
+        );
+        assert_eq!(parse("tcp://"), None);
+    }
+}
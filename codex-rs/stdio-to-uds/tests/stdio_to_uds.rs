@@ -1,6 +1,7 @@
 use std::io::ErrorKind;
 use std::io::Read;
 use std::io::Write;
+use std::net::TcpListener;
 use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
@@ -66,3 +67,64 @@ fn pipes_stdin_and_stdout_through_socket() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn pipes_stdin_and_stdout_through_tcp() -> anyhow::Result<()> {
+    let listener = match TcpListener::bind("127.0.0.1:0") {
+        Ok(listener) => listener,
+        Err(err) if err.kind() == ErrorKind::PermissionDenied => {
+            eprintln!("skipping test: failed to bind tcp socket: {err}");
+            return Ok(());
+        }
+        Err(err) => {
+            return Err(err).context("failed to bind test tcp socket");
+        }
+    };
+    let addr = listener.local_addr()?;
+
+    let server_thread = thread::spawn(move || -> anyhow::Result<Vec<u8>> {
+        let (mut connection, _) = listener
+            .accept()
+            .context("failed to accept test connection")?;
+        let mut received = Vec::new();
+        connection
+            .read_to_end(&mut received)
+            .context("failed to read data from client")?;
+        connection
+            .write_all(b"response")
+            .context("failed to write response to client")?;
+        Ok(received)
+    });
+
+    Command::new(codex_utils_cargo_bin::cargo_bin("codex-stdio-to-uds")?)
+        .arg(format!("tcp://{addr}"))
+        .write_stdin("request")
+        .assert()
+        .success()
+        .stdout("response");
+
+    let received = server_thread
+        .join()
+        .map_err(|_| anyhow::anyhow!("server thread panicked"))?
+        .context("server failed")?;
+    assert_eq!(received, b"request");
+
+    Ok(())
+}
+
+#[test]
+fn gives_up_after_timeout_when_peer_is_missing() -> anyhow::Result<()> {
+    let dir = tempfile::TempDir::new().context("failed to create temp dir")?;
+    let socket_path = dir.path().join("missing.sock");
+
+    Command::new(codex_utils_cargo_bin::cargo_bin("codex-stdio-to-uds")?)
+        .arg("--timeout")
+        .arg("1")
+        .arg(&socket_path)
+        .write_stdin("request")
+        .timeout(Duration::from_secs(10))
+        .assert()
+        .failure();
+
+    Ok(())
+}
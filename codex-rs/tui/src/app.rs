@@ -11,9 +11,22 @@ use crate::bottom_pane::FeedbackAudience;
 use crate::bottom_pane::SelectionItem;
 use crate::bottom_pane::SelectionViewParams;
 use crate::bottom_pane::popup_consts::standard_popup_hint_line;
+use crate::branches::branch_description;
+use crate::branches::branch_diff_lines;
+use crate::branches::branch_label;
 use crate::chatwidget::ChatWidget;
 use crate::chatwidget::ExternalEditorState;
+use crate::clipboard_paste;
+use crate::compare::CompareBranch;
+use crate::compare::CompareOverlay;
+use crate::compare::CompareSide;
+use crate::compare::branch_op;
+use crate::compare::prompt_text;
+use crate::compare::spawn_branch;
+use crate::config_reload::ConfigReload;
 use crate::cwd_prompt::CwdPromptAction;
+use crate::diagram;
+use crate::diagram::RenderedDiagram;
 use crate::diff_render::DiffSummary;
 use crate::exec_command::strip_bash_lc_and_escape;
 use crate::external_editor;
@@ -22,14 +35,25 @@ use crate::history_cell;
 use crate::history_cell::HistoryCell;
 #[cfg(not(debug_assertions))]
 use crate::history_cell::UpdateAvailableHistoryCell;
+use crate::i18n::tr;
 use crate::i18n::tr_args;
+use crate::markdown::append_markdown;
 use crate::model_migration::ModelMigrationOutcome;
 use crate::model_migration::migration_copy_for_models;
 use crate::model_migration::run_model_migration_prompt;
+use crate::multiplexer_panes;
+use crate::multiplexer_panes::PaneFollows;
+use crate::pager_overlay::ExecOutputOverlay;
 use crate::pager_overlay::Overlay;
 use crate::render::highlight::highlight_bash_to_lines;
 use crate::render::renderable::Renderable;
+use crate::render_stats::RenderStats;
 use crate::resume_picker::SessionSelection;
+use crate::session_status::SessionStatusPublisher;
+use crate::session_status::StatusSnapshot;
+use crate::terminal_graphics::InlineImage;
+use crate::transcript_links;
+use crate::transcript_links::LinkTarget;
 use crate::tui;
 use crate::tui::TuiEvent;
 use crate::update_action::UpdateAction;
@@ -37,14 +61,20 @@ use codex_ansi_escape::ansi_escape_line;
 use codex_app_server_protocol::ConfigLayerSource;
 use codex_core::AuthManager;
 use codex_core::CodexAuth;
+use codex_core::FileWatcherEvent;
 use codex_core::ThreadManager;
 use codex_core::config::Config;
 use codex_core::config::ConfigBuilder;
 use codex_core::config::ConfigOverrides;
 use codex_core::config::edit::ConfigEdit;
 use codex_core::config::edit::ConfigEditsBuilder;
+use codex_core::config::types::FollowInPane;
 use codex_core::config_loader::ConfigLayerStackOrdering;
+use codex_core::features::FEATURES;
 use codex_core::features::Feature;
+use codex_core::find_branch_tree;
+use codex_core::git_info::resolve_root_git_project_for_trust;
+use codex_core::load_branch_turns;
 use codex_core::models_manager::manager::RefreshStrategy;
 use codex_core::models_manager::model_presets::HIDE_GPT_5_1_CODEX_MAX_MIGRATION_PROMPT_CONFIG;
 use codex_core::models_manager::model_presets::HIDE_GPT5_1_MIGRATION_PROMPT_CONFIG;
@@ -53,11 +83,15 @@ use codex_core::protocol::Event;
 use codex_core::protocol::EventMsg;
 use codex_core::protocol::FinalOutput;
 use codex_core::protocol::ListSkillsResponseEvent;
+use codex_core::protocol::McpServerRefreshConfig;
 use codex_core::protocol::Op;
 use codex_core::protocol::SandboxPolicy;
 use codex_core::protocol::SessionSource;
 use codex_core::protocol::SkillErrorInfo;
 use codex_core::protocol::TokenUsage;
+use codex_core::session_status::SessionActivity;
+use codex_core::shared_turn_count;
+use codex_core::terminal::terminal_info;
 #[cfg(target_os = "windows")]
 use codex_core::windows_sandbox::WindowsSandboxLevelExt;
 use codex_otel::OtelManager;
@@ -116,6 +150,8 @@ pub struct AppExitInfo {
     pub thread_id: Option<ThreadId>,
     pub thread_name: Option<String>,
     pub update_action: Option<UpdateAction>,
+    /// Bullets of the summary written on quit (`exit_summary`), if any.
+    pub exit_summary: Vec<String>,
     pub exit_reason: ExitReason,
 }
 
@@ -126,6 +162,7 @@ impl AppExitInfo {
             thread_id: None,
             thread_name: None,
             update_action: None,
+            exit_summary: Vec::new(),
             exit_reason: ExitReason::Fatal(message.into()),
         }
     }
@@ -141,6 +178,8 @@ pub(crate) enum AppRunControl {
 pub enum ExitReason {
     UserRequested,
     Fatal(String),
+    /// `/ui switch`: resume `thread_id` in the other frontend.
+    SwitchFrontend,
 }
 
 fn session_summary(
@@ -502,6 +541,7 @@ async fn handle_model_migration_prompt_if_needed(
                     thread_id: None,
                     thread_name: None,
                     update_action: None,
+                    exit_summary: Vec::new(),
                     exit_reason: ExitReason::UserRequested,
                 });
             }
@@ -531,6 +571,15 @@ pub(crate) struct App {
 
     // Pager overlay state (Transcript or Static like Diff)
     pub(crate) overlay: Option<Overlay>,
+    /// Forked threads of an in-flight `/compare`; empty otherwise.
+    compare_branches: Vec<CompareBranch>,
+    /// Commands followed in tmux or Zellij panes (`/follow`, `tui.follow_in_pane`).
+    pane_follows: PaneFollows,
+    /// Frame and event counters for the `render_stats_hud` feature.
+    render_stats: Option<RenderStats>,
+    /// Config as last read from disk. `config.toml` reloads diff against it to
+    /// tell edits to the file from changes made inside the session.
+    disk_config: Config,
     pub(crate) deferred_history_lines: Vec<Line<'static>>,
     has_emitted_history_lines: bool,
 
@@ -557,6 +606,13 @@ pub(crate) struct App {
     /// stopping a thread (e.g., before starting a new one).
     suppress_shutdown_complete: bool,
 
+    /// Set by `/ui switch`; the exit after the next shutdown reports
+    /// [`ExitReason::SwitchFrontend`].
+    switch_frontend_on_exit: bool,
+
+    /// Writes `$CODEX_HOME/status/<pid>.json` when `tui.status_file` is on.
+    session_status: Option<SessionStatusPublisher>,
+
     windows_sandbox: WindowsSandboxState,
 
     thread_event_channels: HashMap<ThreadId, ThreadEventChannel>,
@@ -628,6 +684,175 @@ impl App {
             .wrap_err_with(|| format!("Failed to rebuild config for cwd {cwd_display}"))
     }
 
+    /// Apply a `config.toml` edit to the running session; see `config_reload`.
+    async fn reload_config_from_disk(&mut self, tui: &mut tui::Tui, paths: Vec<PathBuf>) {
+        let reloaded = match self.rebuild_config_for_cwd(self.config.cwd.clone()).await {
+            Ok(config) => config,
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to reload config");
+                self.chat_widget
+                    .add_error_message(crate::config_reload::reload_failed_message(
+                        self.config.language,
+                        &format!("{err:#}"),
+                    ));
+                return;
+            }
+        };
+        let reload = ConfigReload::diff(&self.disk_config, &self.config, &reloaded, &paths);
+        self.disk_config = reloaded;
+        if reload.is_empty() {
+            return;
+        }
+
+        crate::config_reload::apply_live_settings(&mut self.config, &self.disk_config);
+        self.chat_widget.apply_reloaded_config(&self.disk_config);
+        tui.set_notification_method(self.config.tui_notification_method);
+        if reload.mcp_servers_changed() {
+            self.refresh_mcp_servers().await;
+        }
+        self.chat_widget
+            .add_to_history(reload.history_cell(self.config.language));
+    }
+
+    /// Switch the session to `profile` where that can happen mid-session and
+    /// remember it for the current project.
+    ///
+    /// The model defaults, feature flags, and MCP servers follow the profile
+    /// right away; any other setting the profile changes is listed as needing
+    /// a new session.
+    async fn switch_profile(&mut self, profile: Option<String>) {
+        let language = self.config.language;
+        if let Some(name) = profile.as_deref() {
+            let profiles = self.config.profile_names();
+            if !profiles.iter().any(|known| known == name) {
+                self.chat_widget.add_error_message(tr_args(
+                    language,
+                    "app.profile.unknown",
+                    &[("profile", name), ("profiles", &profiles.join(", "))],
+                ));
+                return;
+            }
+        }
+
+        let project = resolve_root_git_project_for_trust(&self.config.cwd)
+            .unwrap_or_else(|| self.config.cwd.clone());
+        if let Err(err) = ConfigEditsBuilder::new(&self.config.codex_home)
+            .set_project_profile(project, profile.as_deref())
+            .apply()
+            .await
+        {
+            tracing::error!(error = %err, "failed to persist profile selection");
+            self.chat_widget.add_error_message(tr_args(
+                language,
+                "app.profile.save_failed",
+                &[("error", &err.to_string())],
+            ));
+            return;
+        }
+
+        let previous_profile = self.harness_overrides.config_profile.take();
+        self.harness_overrides.config_profile = profile.clone();
+        let next = match self.rebuild_config_for_cwd(self.config.cwd.clone()).await {
+            Ok(config) => config,
+            Err(err) => {
+                self.harness_overrides.config_profile = previous_profile;
+                self.chat_widget.add_error_message(tr_args(
+                    language,
+                    "app.profile.switch_failed",
+                    &[("error", &format!("{err:#}"))],
+                ));
+                return;
+            }
+        };
+
+        let pending_restart: Vec<&str> = crate::config_reload::restart_changes(&self.config, &next)
+            .into_iter()
+            .filter(|key| !matches!(*key, "model" | "model_reasoning_effort" | "features"))
+            .collect();
+
+        let model = next
+            .model
+            .clone()
+            .unwrap_or_else(|| self.chat_widget.current_model().to_string());
+        let effort = next.model_reasoning_effort;
+        if model != self.chat_widget.current_model() || effort != self.config.model_reasoning_effort
+        {
+            self.chat_widget.submit_op(Op::OverrideTurnContext {
+                cwd: None,
+                approval_policy: None,
+                sandbox_policy: None,
+                windows_sandbox_level: None,
+                model: Some(model.clone()),
+                effort: Some(effort),
+                summary: None,
+                collaboration_mode: None,
+                personality: None,
+                spec_parallel_priority: None,
+                spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
+            });
+            self.chat_widget.set_model(&model);
+            self.config.model = Some(model);
+            self.on_update_reasoning_effort(effort);
+        }
+
+        for spec in FEATURES {
+            let enabled = next.features.enabled(spec.id);
+            if enabled != self.config.features.enabled(spec.id) {
+                self.chat_widget.set_feature_enabled(spec.id, enabled);
+            }
+        }
+        self.config.features = next.features.clone();
+
+        let mcp_servers_changed = self.config.mcp_servers.get() != next.mcp_servers.get();
+        self.config.mcp_servers = next.mcp_servers.clone();
+        if mcp_servers_changed {
+            self.refresh_mcp_servers().await;
+        }
+
+        self.config.active_profile = profile.clone();
+        self.active_profile = profile.clone();
+        self.chat_widget.set_active_profile(profile.clone());
+        // The edit above rewrote config.toml; keep the reload that follows
+        // from reporting the profile's settings a second time.
+        self.disk_config = next;
+        self.refresh_status_line();
+
+        let message = match profile.as_deref() {
+            Some(profile) => tr_args(language, "app.profile.switched", &[("profile", profile)]),
+            None => tr(language, "app.profile.switched_default").to_string(),
+        };
+        self.chat_widget.add_info_message(message, None);
+        if !pending_restart.is_empty() {
+            self.chat_widget
+                .add_to_history(history_cell::new_warning_event(tr_args(
+                    language,
+                    "app.profile.pending_restart",
+                    &[("keys", &pending_restart.join(", "))],
+                )));
+        }
+    }
+
+    async fn refresh_mcp_servers(&mut self) {
+        let mcp_servers = serde_json::to_value(self.config.mcp_servers.get());
+        let store_mode = serde_json::to_value(self.config.mcp_oauth_credentials_store_mode);
+        match (mcp_servers, store_mode) {
+            (Ok(mcp_servers), Ok(mcp_oauth_credentials_store_mode)) => {
+                self.server
+                    .refresh_mcp_servers(McpServerRefreshConfig {
+                        mcp_servers,
+                        mcp_oauth_credentials_store_mode,
+                    })
+                    .await;
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                tracing::warn!(error = %err, "failed to serialize MCP servers for refresh");
+            }
+        }
+    }
+
     fn apply_runtime_policy_overrides(&mut self, config: &mut Config) {
         if let Some(policy) = self.runtime_approval_policy_override.as_ref()
             && let Err(err) = config.approval_policy.set(*policy)
@@ -658,6 +883,172 @@ impl App {
             .add_info_message(format!("Opened {url} in your browser."), None);
     }
 
+    /// Open a tmux or Zellij pane that follows `call_id`'s output.
+    fn follow_in_pane(
+        &mut self,
+        placement: FollowInPane,
+        call_id: &str,
+        command: &str,
+        output: &str,
+    ) {
+        let language = self.config.language;
+        let Some(multiplexer) = terminal_info().multiplexer else {
+            self.chat_widget
+                .add_error_message(multiplexer_panes::no_multiplexer_message(language));
+            return;
+        };
+        match self
+            .pane_follows
+            .start(&multiplexer, placement, call_id, command, output)
+        {
+            Ok(()) => self.chat_widget.add_info_message(
+                multiplexer_panes::opened_message(language, &multiplexer, placement, command),
+                None,
+            ),
+            Err(err) => self.chat_widget.add_error_message(tr_args(
+                language,
+                "follow_pane.open_failed",
+                &[
+                    (
+                        "multiplexer",
+                        multiplexer_panes::multiplexer_name(&multiplexer),
+                    ),
+                    ("error", &err.to_string()),
+                ],
+            )),
+        }
+    }
+
+    /// Open a link selected in the transcript overlay: URLs go to the browser,
+    /// file references to `tui.open_file_command` or `$VISUAL`/`$EDITOR`.
+    async fn open_transcript_link(&mut self, tui: &mut tui::Tui, target: LinkTarget) {
+        let language = self.config.language;
+        let (path, line) = match target {
+            LinkTarget::Url(url) => {
+                self.open_url_in_browser(url);
+                return;
+            }
+            LinkTarget::File { path, line } => (self.config.cwd.join(path), line),
+        };
+        let display_path = path.display().to_string();
+        if !path.exists() {
+            self.chat_widget.add_error_message(tr_args(
+                language,
+                "transcript_links.file_missing",
+                &[("path", &display_path)],
+            ));
+            return;
+        }
+        let command = match transcript_links::file_open_command(
+            self.config.tui_open_file_command.as_deref(),
+            &path,
+            line,
+        ) {
+            Ok(command) => command,
+            Err(external_editor::EditorError::MissingEditor) => {
+                self.chat_widget
+                    .add_error_message(tr(language, "transcript_links.no_editor"));
+                return;
+            }
+            Err(err) => {
+                self.chat_widget.add_error_message(tr_args(
+                    language,
+                    "transcript_links.open_failed",
+                    &[("path", &display_path), ("error", &err.to_string())],
+                ));
+                return;
+            }
+        };
+        let result = tui
+            .with_restored(tui::RestoreMode::KeepRaw, || async {
+                external_editor::run_command(&command).await
+            })
+            .await;
+        if let Err(err) = result {
+            self.chat_widget.add_error_message(tr_args(
+                language,
+                "transcript_links.open_failed",
+                &[("path", &display_path), ("error", &err.to_string())],
+            ));
+        }
+        tui.frame_requester().schedule_frame();
+    }
+
+    /// Replace the current thread with the session recorded at `path`,
+    /// prompting for the working directory when it differs.
+    async fn resume_session_at(&mut self, tui: &mut tui::Tui, path: PathBuf) -> Result<()> {
+        let current_cwd = self.config.cwd.clone();
+        let resume_cwd = match crate::resolve_cwd_for_resume_or_fork(
+            tui,
+            &current_cwd,
+            &path,
+            CwdPromptAction::Resume,
+            true,
+        )
+        .await?
+        {
+            Some(cwd) => cwd,
+            None => current_cwd.clone(),
+        };
+        let mut resume_config = if crate::cwds_differ(&current_cwd, &resume_cwd) {
+            match self.rebuild_config_for_cwd(resume_cwd).await {
+                Ok(cfg) => cfg,
+                Err(err) => {
+                    self.chat_widget.add_error_message(format!(
+                        "Failed to rebuild configuration for resume: {err}"
+                    ));
+                    return Ok(());
+                }
+            }
+        } else {
+            // No rebuild needed: current_cwd comes from self.config.cwd.
+            self.config.clone()
+        };
+        self.apply_runtime_policy_overrides(&mut resume_config);
+        let summary = session_summary(
+            self.chat_widget.token_usage(),
+            self.chat_widget.thread_id(),
+            self.chat_widget.thread_name(),
+        );
+        match self
+            .server
+            .resume_thread_from_rollout(
+                resume_config.clone(),
+                path.clone(),
+                self.auth_manager.clone(),
+            )
+            .await
+        {
+            Ok(resumed) => {
+                self.shutdown_current_thread().await;
+                self.disk_config = resume_config.clone();
+                self.config = resume_config;
+                tui.set_notification_method(self.config.tui_notification_method);
+                self.file_search.update_search_dir(self.config.cwd.clone());
+                let init =
+                    self.chatwidget_init_for_forked_or_resumed_thread(tui, self.config.clone());
+                self.chat_widget =
+                    ChatWidget::new_from_existing(init, resumed.thread, resumed.session_configured);
+                self.reset_thread_event_state();
+                if let Some(summary) = summary {
+                    let mut lines: Vec<Line<'static>> = vec![summary.usage_line.clone().into()];
+                    if let Some(command) = summary.resume_command {
+                        let spans = vec!["To continue this session, run ".into(), command.cyan()];
+                        lines.push(spans.into());
+                    }
+                    self.chat_widget.add_plain_history_lines(lines);
+                }
+            }
+            Err(err) => {
+                let path_display = path.display();
+                self.chat_widget.add_error_message(format!(
+                    "Failed to resume session from {path_display}: {err}"
+                ));
+            }
+        }
+        Ok(())
+    }
+
     async fn shutdown_current_thread(&mut self) {
         if let Some(thread_id) = self.chat_widget.thread_id() {
             // Clear any in-flight rollback guard when switching threads.
@@ -668,6 +1059,131 @@ impl App {
         }
     }
 
+    /// Fork the current thread once per model and run `op` on both forks,
+    /// showing the answers side by side (`/compare`).
+    async fn start_compare(&mut self, tui: &mut tui::Tui, op: Op, model_b: String) {
+        let language = self.config.language;
+        let Op::UserTurn { model: model_a, .. } = &op else {
+            return;
+        };
+        let Some(path) = self.chat_widget.rollout_path().filter(|path| path.exists()) else {
+            self.chat_widget
+                .add_error_message(tr(language, "compare.not_ready").to_string());
+            return;
+        };
+        self.otel_manager.counter("codex.thread.compare", 1, &[]);
+
+        let models = [model_a.clone(), model_b];
+        for (side, model) in CompareSide::ALL.into_iter().zip(models.iter()) {
+            let mut config = self.config.clone();
+            config.model = Some(model.clone());
+            match self
+                .server
+                .fork_thread(usize::MAX, config, path.clone())
+                .await
+            {
+                Ok(forked) => self.compare_branches.push(CompareBranch {
+                    side,
+                    model: model.clone(),
+                    thread_id: forked.thread_id,
+                    thread: forked.thread,
+                    session_configured: forked.session_configured,
+                    outcome: None,
+                }),
+                Err(err) => {
+                    self.shutdown_compare_branches(None).await;
+                    self.chat_widget.add_error_message(tr_args(
+                        language,
+                        "compare.start_failed",
+                        &[("error", &err.to_string())],
+                    ));
+                    return;
+                }
+            }
+        }
+
+        for branch in &self.compare_branches {
+            spawn_branch(
+                Arc::clone(&branch.thread),
+                branch.thread_id,
+                branch_op(&op, &branch.model),
+                self.app_event_tx.clone(),
+            );
+        }
+        let _ = tui.enter_alt_screen();
+        self.overlay = Some(Overlay::Compare(CompareOverlay::new(
+            prompt_text(&op),
+            models,
+            self.app_event_tx.clone(),
+            tui.frame_requester(),
+            language,
+        )));
+        tui.frame_requester().schedule_frame();
+    }
+
+    /// Shut down every `/compare` fork except `keep`, which is returned.
+    async fn shutdown_compare_branches(
+        &mut self,
+        keep: Option<CompareSide>,
+    ) -> Option<CompareBranch> {
+        let mut kept = None;
+        for branch in std::mem::take(&mut self.compare_branches) {
+            if Some(branch.side) == keep {
+                kept = Some(branch);
+                continue;
+            }
+            if let Err(err) = branch.thread.submit(Op::Shutdown).await {
+                tracing::warn!(
+                    "failed to shut down compare branch {}: {err}",
+                    branch.thread_id
+                );
+            }
+            self.server.remove_thread(&branch.thread_id).await;
+        }
+        kept
+    }
+
+    /// Continue the conversation on the kept `/compare` fork, or stay on the
+    /// current thread when the comparison was discarded.
+    async fn finish_compare(&mut self, tui: &mut tui::Tui, choice: Option<CompareSide>) {
+        let language = self.config.language;
+        let Some(branch) = self.shutdown_compare_branches(choice).await else {
+            let model = self.chat_widget.current_model().to_string();
+            self.chat_widget.add_info_message(
+                tr_args(language, "compare.discarded", &[("model", &model)]),
+                None,
+            );
+            return;
+        };
+
+        self.shutdown_current_thread().await;
+        let mut init = self.chatwidget_init_for_forked_or_resumed_thread(tui, self.config.clone());
+        init.model = Some(branch.model.clone());
+        let CompareBranch {
+            model,
+            thread,
+            mut session_configured,
+            outcome,
+            ..
+        } = branch;
+        // The transcript already shows the history the fork started from.
+        session_configured.initial_messages = None;
+        self.chat_widget = ChatWidget::new_from_existing(init, thread, session_configured);
+        self.file_search.update_search_dir(self.config.cwd.clone());
+        self.reset_thread_event_state();
+
+        if let Some(message) = outcome.and_then(|outcome| outcome.message) {
+            let mut lines = Vec::new();
+            append_markdown(&message, None, &mut lines);
+            self.chat_widget
+                .add_to_history(history_cell::AgentMessageCell::new(lines, true));
+        }
+        self.chat_widget.add_info_message(
+            tr_args(language, "compare.kept", &[("model", &model)]),
+            None,
+        );
+    }
+
     fn ensure_thread_channel(&mut self, thread_id: ThreadId) -> &mut ThreadEventChannel {
         self.thread_event_channels
             .entry(thread_id)
@@ -784,6 +1300,148 @@ impl App {
         Ok(())
     }
 
+    /// List the fork tree of the current thread (`/branches`).
+    async fn open_branches(&mut self) {
+        let language = self.config.language;
+        let Some(thread_id) = self.chat_widget.thread_id() else {
+            self.chat_widget
+                .add_info_message(tr(language, "branches.none").to_string(), None);
+            return;
+        };
+        let tree = match find_branch_tree(&self.config.codex_home, thread_id).await {
+            Ok(Some(tree)) => tree,
+            Ok(None) => {
+                self.chat_widget
+                    .add_info_message(tr(language, "branches.none").to_string(), None);
+                return;
+            }
+            Err(err) => {
+                self.chat_widget.add_error_message(tr_args(
+                    language,
+                    "branches.load_failed",
+                    &[("error", &err.to_string())],
+                ));
+                return;
+            }
+        };
+
+        let mut initial_selected_idx = None;
+        let items: Vec<SelectionItem> = tree
+            .tree_rows()
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (prefix, node))| {
+                let is_current = node.thread_id == thread_id;
+                if is_current {
+                    initial_selected_idx = Some(idx);
+                }
+                let label = branch_label(node);
+                let path = node.path.clone();
+                let action_label = label.clone();
+                SelectionItem {
+                    name: format!("{prefix}{label}"),
+                    description: Some(branch_description(node, language)),
+                    is_current,
+                    actions: if is_current {
+                        Vec::new()
+                    } else {
+                        vec![Box::new(move |tx| {
+                            tx.send(AppEvent::OpenBranchActions {
+                                label: action_label.clone(),
+                                path: path.clone(),
+                            });
+                        })]
+                    },
+                    dismiss_on_select: true,
+                    search_value: Some(format!("{label} {}", node.thread_id)),
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        self.chat_widget.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "branches.title").to_string()),
+            subtitle: Some(tr(language, "branches.subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            initial_selected_idx,
+            ..Default::default()
+        });
+    }
+
+    fn open_branch_actions(&mut self, label: String, path: PathBuf) {
+        let language = self.config.language;
+        let resume_path = path.clone();
+        let diff_label = label.clone();
+        let items = vec![
+            SelectionItem {
+                name: tr(language, "branches.resume").to_string(),
+                description: Some(tr(language, "branches.resume_description").to_string()),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::ResumeBranch(resume_path.clone()));
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: tr(language, "branches.diff").to_string(),
+                description: Some(tr(language, "branches.diff_description").to_string()),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::DiffBranch {
+                        label: diff_label.clone(),
+                        path: path.clone(),
+                    });
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+
+        self.chat_widget.show_selection_view(SelectionViewParams {
+            title: Some(label),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+    }
+
+    /// Show the turns after the point where the current thread and the
+    /// branch at `path` diverge.
+    async fn diff_branch(&mut self, tui: &mut tui::Tui, label: String, path: PathBuf) {
+        let language = self.config.language;
+        let Some(current_path) = self.chat_widget.rollout_path().filter(|path| path.exists())
+        else {
+            self.chat_widget
+                .add_info_message(tr(language, "branches.none").to_string(), None);
+            return;
+        };
+        let turns = match load_branch_turns(&current_path).await {
+            Ok(ours) => load_branch_turns(&path).await.map(|theirs| (ours, theirs)),
+            Err(err) => Err(err),
+        };
+        let (ours, theirs) = match turns {
+            Ok(turns) => turns,
+            Err(err) => {
+                self.chat_widget.add_error_message(tr_args(
+                    language,
+                    "branches.load_failed",
+                    &[("error", &err.to_string())],
+                ));
+                return;
+            }
+        };
+
+        let shared = shared_turn_count(&ours, &theirs);
+        let lines = branch_diff_lines(&label, &ours, &theirs, shared, language);
+        let _ = tui.enter_alt_screen();
+        self.overlay = Some(Overlay::new_static_with_lines(
+            lines,
+            tr(language, "branches.diff_title").to_string(),
+            language,
+        ));
+        tui.frame_requester().schedule_frame();
+    }
+
     async fn open_agent_picker(&mut self) {
         let thread_ids: Vec<ThreadId> = self.thread_event_channels.keys().cloned().collect();
         for thread_id in thread_ids {
@@ -1112,6 +1770,14 @@ impl App {
         chat_widget.maybe_prompt_windows_sandbox_enable();
 
         let file_search = FileSearchManager::new(config.cwd.clone(), app_event_tx.clone());
+        let session_status = config
+            .tui_status_file
+            .then(|| SessionStatusPublisher::start(config.codex_home.clone()));
+        let render_stats = config
+            .features
+            .enabled(Feature::RenderStatsHud)
+            .then(RenderStats::default);
+        let disk_config = config.clone();
         #[cfg(not(debug_assertions))]
         let upgrade_version = crate::updates::get_upgrade_version(&config);
 
@@ -1131,6 +1797,10 @@ impl App {
             enhanced_keys_supported,
             transcript_cells: Vec::new(),
             overlay: None,
+            compare_branches: Vec::new(),
+            pane_follows: PaneFollows::default(),
+            render_stats,
+            disk_config,
             deferred_history_lines: Vec::new(),
             has_emitted_history_lines: false,
             commit_anim_running: Arc::new(AtomicBool::new(false)),
@@ -1141,6 +1811,8 @@ impl App {
             feedback_audience,
             pending_update_action: None,
             suppress_shutdown_complete: false,
+            switch_frontend_on_exit: false,
+            session_status,
             windows_sandbox: WindowsSandboxState::default(),
             thread_event_channels: HashMap::new(),
             active_thread_id: None,
@@ -1192,6 +1864,7 @@ impl App {
                     thread_id: app.chat_widget.thread_id(),
                     thread_name: app.chat_widget.thread_name(),
                     update_action: app.pending_update_action,
+                    exit_summary: app.chat_widget.exit_summary_bullets(),
                     exit_reason,
                 });
             }
@@ -1204,6 +1877,8 @@ impl App {
 
         let mut thread_created_rx = thread_manager.subscribe_thread_created();
         let mut listen_for_threads = true;
+        let mut file_watcher_rx = thread_manager.subscribe_file_watcher();
+        let mut listen_for_file_changes = true;
 
         let exit_reason = loop {
             let control = select! {
@@ -1242,6 +1917,19 @@ impl App {
                     }
                     AppRunControl::Continue
                 }
+                changed = file_watcher_rx.recv(), if listen_for_file_changes => {
+                    match changed {
+                        Ok(FileWatcherEvent::ConfigChanged { paths }) => {
+                            app.reload_config_from_disk(tui, paths).await;
+                        }
+                        Ok(FileWatcherEvent::SkillsChanged { .. })
+                        | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(broadcast::error::RecvError::Closed) => {
+                            listen_for_file_changes = false;
+                        }
+                    }
+                    AppRunControl::Continue
+                }
             };
             match control {
                 AppRunControl::Continue => {}
@@ -1254,6 +1942,7 @@ impl App {
             thread_id: app.chat_widget.thread_id(),
             thread_name: app.chat_widget.thread_name(),
             update_action: app.pending_update_action,
+            exit_summary: app.chat_widget.exit_summary_bullets(),
             exit_reason,
         })
     }
@@ -1264,6 +1953,7 @@ impl App {
         event: TuiEvent,
     ) -> Result<AppRunControl> {
         if matches!(event, TuiEvent::Draw) {
+            self.chat_widget.flush_pending_exec_output();
             let size = tui.terminal.size()?;
             if size != tui.terminal.last_known_screen_size {
                 self.refresh_status_line();
@@ -1297,15 +1987,23 @@ impl App {
                     {
                         return Ok(AppRunControl::Continue);
                     }
+                    let render_stats = self.render_stats.as_ref();
+                    let draw_started = Instant::now();
                     tui.draw(
                         self.chat_widget.desired_height(tui.terminal.size()?.width),
                         |frame| {
                             self.chat_widget.render(frame.area(), frame.buffer);
+                            if let Some(stats) = render_stats {
+                                stats.render(frame.area(), frame.buffer);
+                            }
                             if let Some((x, y)) = self.chat_widget.cursor_pos(frame.area()) {
                                 frame.set_cursor_position((x, y));
                             }
                         },
                     )?;
+                    if let Some(stats) = self.render_stats.as_mut() {
+                        stats.record_frame(draw_started, draw_started.elapsed());
+                    }
                     if self.chat_widget.external_editor_state() == ExternalEditorState::Requested {
                         self.chat_widget
                             .set_external_editor_state(ExternalEditorState::Active);
@@ -1347,6 +2045,7 @@ impl App {
                     otel_manager: self.otel_manager.clone(),
                 };
                 self.chat_widget = ChatWidget::new(init, self.server.clone());
+                self.file_search.update_search_dir(self.config.cwd.clone());
                 self.reset_thread_event_state();
                 if let Some(summary) = summary {
                     let mut lines: Vec<Line<'static>> = vec![summary.usage_line.clone().into()];
@@ -1359,86 +2058,11 @@ impl App {
                 tui.frame_requester().schedule_frame();
             }
             AppEvent::OpenResumePicker => {
-                match crate::resume_picker::run_resume_picker(tui, &self.config, false).await? {
-                    SessionSelection::Resume(path) => {
-                        let current_cwd = self.config.cwd.clone();
-                        let resume_cwd = match crate::resolve_cwd_for_resume_or_fork(
-                            tui,
-                            &current_cwd,
-                            &path,
-                            CwdPromptAction::Resume,
-                            true,
-                        )
-                        .await?
-                        {
-                            Some(cwd) => cwd,
-                            None => current_cwd.clone(),
-                        };
-                        let mut resume_config = if crate::cwds_differ(&current_cwd, &resume_cwd) {
-                            match self.rebuild_config_for_cwd(resume_cwd).await {
-                                Ok(cfg) => cfg,
-                                Err(err) => {
-                                    self.chat_widget.add_error_message(format!(
-                                        "Failed to rebuild configuration for resume: {err}"
-                                    ));
-                                    return Ok(AppRunControl::Continue);
-                                }
-                            }
-                        } else {
-                            // No rebuild needed: current_cwd comes from self.config.cwd.
-                            self.config.clone()
-                        };
-                        self.apply_runtime_policy_overrides(&mut resume_config);
-                        let summary = session_summary(
-                            self.chat_widget.token_usage(),
-                            self.chat_widget.thread_id(),
-                            self.chat_widget.thread_name(),
-                        );
-                        match self
-                            .server
-                            .resume_thread_from_rollout(
-                                resume_config.clone(),
-                                path.clone(),
-                                self.auth_manager.clone(),
-                            )
-                            .await
-                        {
-                            Ok(resumed) => {
-                                self.shutdown_current_thread().await;
-                                self.config = resume_config;
-                                tui.set_notification_method(self.config.tui_notification_method);
-                                self.file_search.update_search_dir(self.config.cwd.clone());
-                                let init = self.chatwidget_init_for_forked_or_resumed_thread(
-                                    tui,
-                                    self.config.clone(),
-                                );
-                                self.chat_widget = ChatWidget::new_from_existing(
-                                    init,
-                                    resumed.thread,
-                                    resumed.session_configured,
-                                );
-                                self.reset_thread_event_state();
-                                if let Some(summary) = summary {
-                                    let mut lines: Vec<Line<'static>> =
-                                        vec![summary.usage_line.clone().into()];
-                                    if let Some(command) = summary.resume_command {
-                                        let spans = vec![
-                                            "To continue this session, run ".into(),
-                                            command.cyan(),
-                                        ];
-                                        lines.push(spans.into());
-                                    }
-                                    self.chat_widget.add_plain_history_lines(lines);
-                                }
-                            }
-                            Err(err) => {
-                                let path_display = path.display();
-                                self.chat_widget.add_error_message(format!(
-                                    "Failed to resume session from {path_display}: {err}"
-                                ));
-                            }
-                        }
-                    }
+                let active_path = self.chat_widget.rollout_path();
+                match crate::resume_picker::run_resume_picker(tui, &self.config, false, active_path)
+                    .await?
+                {
+                    SessionSelection::Resume(path) => self.resume_session_at(tui, path).await?,
                     SessionSelection::Exit
                     | SessionSelection::StartFresh
                     | SessionSelection::Fork(_) => {}
@@ -1477,6 +2101,7 @@ impl App {
                                     forked.thread,
                                     forked.session_configured,
                                 );
+                                self.file_search.update_search_dir(self.config.cwd.clone());
                                 self.reset_thread_event_state();
                                 if let Some(summary) = summary {
                                     let mut lines: Vec<Line<'static>> =
@@ -1513,29 +2138,63 @@ impl App {
 
                 tui.frame_requester().schedule_frame();
             }
-            AppEvent::InsertHistoryCell(cell) => {
-                let cell: Arc<dyn HistoryCell> = cell.into();
-                if let Some(Overlay::Transcript(t)) = &mut self.overlay {
-                    t.insert_cell(cell.clone());
-                    tui.frame_requester().schedule_frame();
-                }
-                self.transcript_cells.push(cell.clone());
-                let mut display = cell.display_lines(tui.terminal.last_known_screen_size.width);
-                if !display.is_empty() {
-                    // Only insert a separating blank line for new cells that are not
-                    // part of an ongoing stream. Streaming continuations should not
-                    // accrue extra blank lines between chunks.
-                    if !cell.is_stream_continuation() {
-                        if self.has_emitted_history_lines {
-                            display.insert(0, Line::from(""));
-                        } else {
-                            self.has_emitted_history_lines = true;
-                        }
+            AppEvent::OpenBranches => {
+                self.open_branches().await;
+            }
+            AppEvent::OpenBranchActions { label, path } => {
+                self.open_branch_actions(label, path);
+            }
+            AppEvent::ResumeBranch(path) => {
+                self.resume_session_at(tui, path).await?;
+                tui.frame_requester().schedule_frame();
+            }
+            AppEvent::DiffBranch { label, path } => {
+                self.diff_branch(tui, label, path).await;
+            }
+            AppEvent::StartCompare { op, model_b } => {
+                self.start_compare(tui, op, model_b).await;
+            }
+            AppEvent::CompareBranchFinished { thread_id, outcome } => {
+                if let Some(branch) = self
+                    .compare_branches
+                    .iter_mut()
+                    .find(|branch| branch.thread_id == thread_id)
+                {
+                    if let Some(Overlay::Compare(overlay)) = &mut self.overlay {
+                        overlay.set_outcome(branch.side, outcome.clone());
                     }
-                    if self.overlay.is_some() {
-                        self.deferred_history_lines.extend(display);
-                    } else {
-                        tui.insert_history_lines(display);
+                    branch.outcome = Some(outcome);
+                }
+            }
+            AppEvent::FinishCompare(choice) => {
+                self.finish_compare(tui, choice).await;
+                tui.frame_requester().schedule_frame();
+            }
+            AppEvent::InsertHistoryCell(cell) => {
+                self.insert_history_cell(tui, cell);
+            }
+            AppEvent::ChangedSymbolsFound(symbols) => {
+                self.chat_widget.show_changed_symbols(symbols);
+            }
+            AppEvent::DiagramsRendered(results) => {
+                for result in results {
+                    let cell = diagram::result_cell(&result, self.config.language);
+                    self.insert_history_cell(tui, Box::new(cell));
+                    // Images go straight to the terminal, so skip them while an
+                    // overlay holds back history.
+                    if let Ok(RenderedDiagram::Image {
+                        png, width, height, ..
+                    }) = result
+                        && self.overlay.is_none()
+                        && !tui.is_alt_screen_active()
+                        && let Some(image) = InlineImage::for_current_terminal(
+                            png,
+                            width,
+                            height,
+                            tui.terminal.last_known_screen_size,
+                        )
+                    {
+                        tui.insert_history_image(image);
                     }
                 }
             }
@@ -1570,14 +2229,25 @@ impl App {
                 self.enqueue_primary_event(event).await?;
             }
             AppEvent::Exit(mode) => match mode {
-                ExitMode::ShutdownFirst => self.chat_widget.submit_op(Op::Shutdown),
+                ExitMode::ShutdownFirst => self.chat_widget.begin_shutdown(),
                 ExitMode::Immediate => {
-                    return Ok(AppRunControl::Exit(ExitReason::UserRequested));
+                    let reason = if self.switch_frontend_on_exit {
+                        ExitReason::SwitchFrontend
+                    } else {
+                        ExitReason::UserRequested
+                    };
+                    return Ok(AppRunControl::Exit(reason));
                 }
             },
             AppEvent::FatalExitRequest(message) => {
                 return Ok(AppRunControl::Exit(ExitReason::Fatal(message)));
             }
+            AppEvent::SwitchFrontend => {
+                // Skip the exit summary: the session continues in the other
+                // frontend.
+                self.switch_frontend_on_exit = true;
+                self.chat_widget.submit_op(Op::Shutdown);
+            }
             AppEvent::CodexOp(op) => {
                 self.chat_widget.submit_op(op);
             }
@@ -1616,6 +2286,9 @@ impl App {
             AppEvent::OpenUrlInBrowser { url } => {
                 self.open_url_in_browser(url);
             }
+            AppEvent::OpenTranscriptLink(target) => {
+                self.open_transcript_link(tui, target).await;
+            }
             AppEvent::RefreshConnectors { force_refetch } => {
                 self.chat_widget.refresh_connectors(force_refetch);
             }
@@ -1635,6 +2308,13 @@ impl App {
             AppEvent::RateLimitSnapshotFetched(snapshot) => {
                 self.chat_widget.on_rate_limit_snapshot(Some(snapshot));
             }
+            AppEvent::CachedRateLimitSnapshotLoaded {
+                snapshot,
+                fetched_at,
+            } => {
+                self.chat_widget
+                    .on_cached_rate_limit_snapshot(snapshot, fetched_at);
+            }
             AppEvent::ConnectorsLoaded { result, is_final } => {
                 self.chat_widget.on_connectors_loaded(result, is_final);
             }
@@ -1644,6 +2324,7 @@ impl App {
             }
             AppEvent::UpdateModel(model) => {
                 self.chat_widget.set_model(&model);
+                self.config.model = Some(model);
                 self.refresh_status_line();
             }
             AppEvent::UpdateLanguage(language) => {
@@ -1662,12 +2343,50 @@ impl App {
             AppEvent::UpdatePersonality(personality) => {
                 self.on_update_personality(personality);
             }
+            AppEvent::UpdatePersona(persona) => {
+                self.config.persona = persona.clone();
+                self.chat_widget.set_persona(persona);
+            }
+            AppEvent::UpdateScope(package) => {
+                // `@` file search follows the scope; new threads start unscoped,
+                // so the app's own config keeps the workspace cwd.
+                let search_dir = package
+                    .as_ref()
+                    .map_or_else(|| self.config.cwd.clone(), |(_, path)| path.clone());
+                self.file_search.update_search_dir(search_dir);
+                self.chat_widget.set_scope(package);
+            }
+            AppEvent::UpdateTranscriptDensity(density) => {
+                self.config.tui_density = density;
+                self.chat_widget.set_transcript_density(density);
+            }
             AppEvent::OpenReasoningPopup { model } => {
                 self.chat_widget.open_reasoning_popup(model);
             }
             AppEvent::OpenAllModelsPopup { models } => {
                 self.chat_widget.open_all_models_popup(models);
             }
+            AppEvent::OpenDiscardSessionChangeConfirmation { path } => {
+                self.chat_widget
+                    .open_discard_session_change_confirmation(path);
+            }
+            AppEvent::OpenSessionNotePrompt => {
+                self.chat_widget.show_session_note_prompt();
+            }
+            AppEvent::FollowInPane {
+                call_id,
+                command,
+                output,
+            } => {
+                let placement = self
+                    .config
+                    .tui_follow_in_pane
+                    .unwrap_or(FollowInPane::Split);
+                self.follow_in_pane(placement, &call_id, &command, &output);
+            }
+            AppEvent::ExportSessionDiff { unified_diff } => {
+                self.chat_widget.export_session_diff(unified_diff);
+            }
             AppEvent::OpenFullAccessConfirmation {
                 preset,
                 return_to_permissions,
@@ -1702,6 +2421,13 @@ impl App {
                     self.launch_external_editor(tui).await;
                 }
             }
+            AppEvent::PasteImageViaOsc52(error) => {
+                // The reply arrives on stdin; keep crossterm from reading it as keys.
+                tui.pause_events();
+                let result = clipboard_paste::paste_image_via_osc52(error);
+                tui.resume_events();
+                self.chat_widget.on_image_paste_result(result);
+            }
             AppEvent::OpenWindowsSandboxEnablePrompt { preset } => {
                 self.chat_widget.open_windows_sandbox_enable_prompt(preset);
             }
@@ -1865,6 +2591,9 @@ impl App {
                                         spec_parallel_priority: None,
 
                                         spec_sdd_planning: None,
+                                        explore_mode: None,
+                                        persona: None,
+                                        scope: None,
                                     },
                                 ));
                                 self.app_event_tx.send(
@@ -1890,6 +2619,9 @@ impl App {
                                         spec_parallel_priority: None,
 
                                         spec_sdd_planning: None,
+                                        explore_mode: None,
+                                        persona: None,
+                                        scope: None,
                                     },
                                 ));
                                 self.app_event_tx
@@ -1962,6 +2694,15 @@ impl App {
                     }
                 }
             }
+            AppEvent::PersistTranscriptDensity { density } => {
+                if let Err(err) = ConfigEditsBuilder::new(&self.config.codex_home)
+                    .set_tui_density(density)
+                    .apply()
+                    .await
+                {
+                    tracing::error!(error = %err, "failed to persist transcript density");
+                }
+            }
             AppEvent::PersistLanguageSelection { language } => {
                 if let Err(err) = ConfigEditsBuilder::new(&self.config.codex_home)
                     .set_language(language)
@@ -2033,6 +2774,38 @@ impl App {
                     }
                 }
             }
+            AppEvent::SwitchProfile { profile } => {
+                self.switch_profile(profile).await;
+            }
+            AppEvent::PersistPersonaSelection { persona } => {
+                let project = resolve_root_git_project_for_trust(&self.config.cwd)
+                    .unwrap_or_else(|| self.config.cwd.clone());
+                match ConfigEditsBuilder::new(&self.config.codex_home)
+                    .set_project_persona(project, persona.as_deref())
+                    .apply()
+                    .await
+                {
+                    Ok(()) => {
+                        let message = match persona.as_deref() {
+                            Some(persona) => tr_args(
+                                self.config.language,
+                                "app.persona.set",
+                                &[("persona", persona)],
+                            ),
+                            None => tr(self.config.language, "app.persona.cleared").to_string(),
+                        };
+                        self.chat_widget.add_info_message(message, None);
+                    }
+                    Err(err) => {
+                        tracing::error!(error = %err, "failed to persist persona selection");
+                        self.chat_widget.add_error_message(tr_args(
+                            self.config.language,
+                            "app.persona.save_failed",
+                            &[("error", &err.to_string())],
+                        ));
+                    }
+                }
+            }
             AppEvent::UpdateAskForApprovalPolicy(policy) => {
                 self.runtime_approval_policy_override = Some(policy);
                 if let Err(err) = self.config.approval_policy.set(policy) {
@@ -2156,6 +2929,9 @@ impl App {
                                 spec_parallel_priority: None,
 
                                 spec_sdd_planning: None,
+                                explore_mode: None,
+                                persona: None,
+                                scope: None,
                             }));
                     }
                 }
@@ -2287,6 +3063,9 @@ impl App {
             AppEvent::OpenReviewCommitPicker(cwd) => {
                 self.chat_widget.show_review_commit_picker(&cwd).await;
             }
+            AppEvent::OpenReviewFilesPrompt => {
+                self.chat_widget.show_review_files_prompt();
+            }
             AppEvent::OpenReviewCustomPrompt => {
                 self.chat_widget.show_review_custom_prompt();
             }
@@ -2300,6 +3079,35 @@ impl App {
             AppEvent::ManageSkillsClosed => {
                 self.chat_widget.handle_manage_skills_closed();
             }
+            AppEvent::OpenSkillsTrustPrompt => {
+                self.chat_widget.open_skills_trust_prompt();
+            }
+            AppEvent::TrustSkills { paths } => {
+                let edits = paths
+                    .iter()
+                    .map(|path| ConfigEdit::SetSkillTrusted {
+                        path: path.clone(),
+                        trusted: true,
+                    })
+                    .collect::<Vec<_>>();
+                match ConfigEditsBuilder::new(&self.config.codex_home)
+                    .with_edits(edits)
+                    .apply()
+                    .await
+                {
+                    Ok(()) => {
+                        self.chat_widget.mark_skills_trusted(&paths);
+                    }
+                    Err(err) => {
+                        let error = err.to_string();
+                        self.chat_widget.add_error_message(tr_args(
+                            self.config.language,
+                            "app.skills.trust_failed",
+                            &[("error", &error)],
+                        ));
+                    }
+                }
+            }
             AppEvent::FullScreenApprovalRequest(request) => match request {
                 ApprovalRequest::ApplyPatch { cwd, changes, .. } => {
                     let _ = tui.enter_alt_screen();
@@ -2378,6 +3186,9 @@ impl App {
     }
 
     fn handle_codex_event_now(&mut self, event: Event) {
+        if let Some(stats) = self.render_stats.as_mut() {
+            stats.record_event(Instant::now());
+        }
         let needs_refresh = matches!(
             event.msg,
             EventMsg::SessionConfigured(_) | EventMsg::TokenCount(_)
@@ -2392,11 +3203,61 @@ impl App {
             emit_skill_load_warnings(&self.app_event_tx, &errors);
         }
         self.handle_backtrack_event(&event.msg);
+        let awaiting_user = matches!(
+            event.msg,
+            EventMsg::ExecApprovalRequest(_)
+                | EventMsg::ApplyPatchApprovalRequest(_)
+                | EventMsg::ElicitationRequest(_)
+                | EventMsg::RequestUserInput(_)
+        );
+        match &event.msg {
+            EventMsg::ExecCommandOutputDelta(delta) => {
+                self.pane_follows.push_output(&delta.call_id, &delta.chunk);
+            }
+            EventMsg::ExecCommandEnd(end) => {
+                self.pane_follows
+                    .finish(&end.call_id, end.exit_code, self.config.language);
+            }
+            _ => {}
+        }
+        if let Some(Overlay::ExecOutput(overlay)) = &mut self.overlay {
+            match &event.msg {
+                EventMsg::ExecCommandOutputDelta(delta) if delta.call_id == overlay.call_id() => {
+                    overlay.push_output(&String::from_utf8_lossy(&delta.chunk));
+                }
+                EventMsg::ExecCommandEnd(end) if end.call_id == overlay.call_id() => {
+                    overlay.finish(end.exit_code);
+                }
+                _ => {}
+            }
+        }
         self.chat_widget.handle_codex_event(event);
 
         if needs_refresh {
             self.refresh_status_line();
         }
+        self.publish_session_status(awaiting_user);
+    }
+
+    /// Any later event means the pending approval or question was answered.
+    fn publish_session_status(&self, awaiting_user: bool) {
+        let Some(publisher) = self.session_status.as_ref() else {
+            return;
+        };
+        let activity = if awaiting_user {
+            SessionActivity::WaitingForApproval
+        } else if self.chat_widget.is_task_running() {
+            SessionActivity::Running
+        } else {
+            SessionActivity::Idle
+        };
+        publisher.update(StatusSnapshot {
+            thread_id: self.chat_widget.thread_id(),
+            cwd: self.chat_widget.config_ref().cwd.to_path_buf(),
+            model: self.chat_widget.current_model().to_string(),
+            activity,
+            tokens_used: self.chat_widget.token_usage().total_tokens,
+        });
     }
 
     fn handle_codex_event_replay(&mut self, event: Event) {
@@ -2512,6 +3373,37 @@ impl App {
         }
     }
 
+    fn insert_history_cell(&mut self, tui: &mut tui::Tui, cell: Box<dyn HistoryCell>) {
+        let cell: Arc<dyn HistoryCell> = cell.into();
+        if let Some(Overlay::Transcript(t)) = &mut self.overlay {
+            t.insert_cell(cell.clone());
+            tui.frame_requester().schedule_frame();
+        }
+        self.transcript_cells.push(cell.clone());
+        let mut display: Vec<Line<'static>> = cell
+            .display_lines(tui.terminal.last_known_screen_size.width)
+            .into_iter()
+            .map(transcript_links::underline_links)
+            .collect();
+        if !display.is_empty() {
+            // Only insert a separating blank line for new cells that are not
+            // part of an ongoing stream. Streaming continuations should not
+            // accrue extra blank lines between chunks.
+            if !cell.is_stream_continuation() {
+                if self.has_emitted_history_lines {
+                    display.insert(0, Line::from(""));
+                } else {
+                    self.has_emitted_history_lines = true;
+                }
+            }
+            if self.overlay.is_some() {
+                self.deferred_history_lines.extend(display);
+            } else {
+                tui.insert_history_lines(display);
+            }
+        }
+    }
+
     async fn launch_external_editor(&mut self, tui: &mut tui::Tui) {
         let editor_cmd = match external_editor::resolve_editor_command() {
             Ok(cmd) => cmd,
@@ -2591,6 +3483,49 @@ impl App {
                 ));
                 tui.frame_requester().schedule_frame();
             }
+            KeyEvent {
+                code: KeyCode::Char('o'),
+                modifiers: crossterm::event::KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press,
+                ..
+            } => {
+                // Follow the running command's full output, in a multiplexer pane when
+                // `tui.follow_in_pane` is set; a no-op when nothing is running.
+                if let Some((call_id, command, output)) = self.chat_widget.running_exec_output() {
+                    if let Some(placement) = self.config.tui_follow_in_pane
+                        && terminal_info().multiplexer.is_some()
+                    {
+                        self.follow_in_pane(placement, &call_id, &command, &output);
+                    } else {
+                        let _ = tui.enter_alt_screen();
+                        self.overlay = Some(Overlay::ExecOutput(ExecOutputOverlay::new(
+                            call_id,
+                            command,
+                            &output,
+                            tui.frame_requester(),
+                            self.config.language,
+                        )));
+                        tui.frame_requester().schedule_frame();
+                    }
+                }
+            }
+            KeyEvent {
+                code: KeyCode::Char('r'),
+                modifiers: crossterm::event::KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press,
+                ..
+            } if self.overlay.is_none() => {
+                self.chat_widget.cycle_reasoning_effort();
+            }
+            KeyEvent {
+                code: KeyCode::Char('s'),
+                modifiers: crossterm::event::KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press,
+                ..
+            } if self.overlay.is_none() => {
+                // Jump from the permissions chip in the footer to the picker.
+                self.chat_widget.open_permissions_popup();
+            }
             KeyEvent {
                 code: KeyCode::Char('g'),
                 modifiers: crossterm::event::KeyModifiers::CONTROL,
@@ -2809,6 +3744,7 @@ mod tests {
         let file_search = FileSearchManager::new(config.cwd.clone(), app_event_tx.clone());
         let model = ModelsManager::get_model_offline(config.model.as_deref());
         let otel_manager = test_otel_manager(&config, model.as_str());
+        let disk_config = config.clone();
 
         App {
             server,
@@ -2825,6 +3761,10 @@ mod tests {
             file_search,
             transcript_cells: Vec::new(),
             overlay: None,
+            compare_branches: Vec::new(),
+            pane_follows: PaneFollows::default(),
+            render_stats: None,
+            disk_config,
             deferred_history_lines: Vec::new(),
             has_emitted_history_lines: false,
             enhanced_keys_supported: false,
@@ -2836,6 +3776,8 @@ mod tests {
             feedback_audience: FeedbackAudience::External,
             pending_update_action: None,
             suppress_shutdown_complete: false,
+            switch_frontend_on_exit: false,
+            session_status: None,
             windows_sandbox: WindowsSandboxState::default(),
             thread_event_channels: HashMap::new(),
             active_thread_id: None,
@@ -2862,6 +3804,7 @@ mod tests {
         let file_search = FileSearchManager::new(config.cwd.clone(), app_event_tx.clone());
         let model = ModelsManager::get_model_offline(config.model.as_deref());
         let otel_manager = test_otel_manager(&config, model.as_str());
+        let disk_config = config.clone();
 
         (
             App {
@@ -2879,6 +3822,10 @@ mod tests {
                 file_search,
                 transcript_cells: Vec::new(),
                 overlay: None,
+                compare_branches: Vec::new(),
+                pane_follows: PaneFollows::default(),
+                render_stats: None,
+                disk_config,
                 deferred_history_lines: Vec::new(),
                 has_emitted_history_lines: false,
                 enhanced_keys_supported: false,
@@ -2890,6 +3837,8 @@ mod tests {
                 feedback_audience: FeedbackAudience::External,
                 pending_update_action: None,
                 suppress_shutdown_complete: false,
+                switch_frontend_on_exit: false,
+                session_status: None,
                 windows_sandbox: WindowsSandboxState::default(),
                 thread_event_channels: HashMap::new(),
                 active_thread_id: None,
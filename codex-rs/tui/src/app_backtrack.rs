@@ -153,6 +153,10 @@ impl App {
             kind: KeyEventKind::Press | KeyEventKind::Repeat,
             ..
         }) = event
+            && !matches!(
+                self.overlay,
+                Some(Overlay::ExecOutput(_) | Overlay::Compare(_))
+            )
         {
             // First Esc in transcript overlay: begin backtrack preview at latest user message.
             self.begin_overlay_backtrack_preview(tui);
@@ -386,6 +390,11 @@ impl App {
 
         if let Some(overlay) = &mut self.overlay {
             overlay.handle_event(tui, event)?;
+            if let Overlay::Transcript(t) = overlay
+                && let Some(target) = t.take_link_to_open()
+            {
+                self.app_event_tx.send(AppEvent::OpenTranscriptLink(target));
+            }
             if overlay.is_done() {
                 self.close_transcript_overlay(tui);
                 tui.frame_requester().schedule_frame();
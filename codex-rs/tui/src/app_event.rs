@@ -10,8 +10,11 @@
 
 use std::path::PathBuf;
 
+use chrono::DateTime;
+use chrono::Utc;
 use codex_chatgpt::connectors::AppInfo;
 use codex_common::approval_presets::ApprovalPreset;
+use codex_core::config::types::TranscriptDensity;
 use codex_core::protocol::Event;
 use codex_core::protocol::RateLimitSnapshot;
 use codex_file_search::FileMatch;
@@ -22,7 +25,14 @@ use codex_protocol::openai_models::ModelPreset;
 
 use crate::bottom_pane::ApprovalRequest;
 use crate::bottom_pane::StatusLineItem;
+use crate::changed_symbols::ChangedSymbol;
+use crate::clipboard_paste::PasteImageError;
+use crate::compare::BranchOutcome;
+use crate::compare::CompareSide;
+use crate::diagram::DiagramError;
+use crate::diagram::RenderedDiagram;
 use crate::history_cell::HistoryCell;
+use crate::transcript_links::LinkTarget;
 
 use codex_core::features::Feature;
 use codex_core::protocol::AskForApproval;
@@ -67,6 +77,40 @@ pub(crate) enum AppEvent {
     /// Fork the current session into a new thread.
     ForkCurrentSession,
 
+    /// Show the fork tree of the current thread (`/branches`).
+    OpenBranches,
+
+    /// Offer to resume or diff the selected branch.
+    OpenBranchActions {
+        label: String,
+        path: PathBuf,
+    },
+
+    /// Replace the current thread with the branch recorded at this path.
+    ResumeBranch(PathBuf),
+
+    /// Show how the branch recorded at `path` diverges from the current thread.
+    DiffBranch {
+        label: String,
+        path: PathBuf,
+    },
+
+    /// Run the user turn `op` on two forks of the current thread, one on the
+    /// turn's own model and one on `model_b` (`/compare`).
+    StartCompare {
+        op: codex_core::protocol::Op,
+        model_b: String,
+    },
+
+    /// A `/compare` branch finished its turn.
+    CompareBranchFinished {
+        thread_id: ThreadId,
+        outcome: BranchOutcome,
+    },
+
+    /// The user kept one `/compare` branch, or discarded both with `None`.
+    FinishCompare(Option<CompareSide>),
+
     /// Request to exit the application.
     ///
     /// Use `ShutdownFirst` for user-initiated quits so core cleanup runs and the
@@ -78,6 +122,10 @@ pub(crate) enum AppEvent {
     /// Request to exit the application due to a fatal error.
     FatalExitRequest(String),
 
+    /// `/ui switch`: shut the thread down and exit so the CLI can resume it in
+    /// the other frontend.
+    SwitchFrontend,
+
     /// Forward an `Op` to the Agent. Using an `AppEvent` for this avoids
     /// bubbling channels through layers of widgets.
     CodexOp(codex_core::protocol::Op),
@@ -113,6 +161,13 @@ pub(crate) enum AppEvent {
     /// Result of refreshing rate limits
     RateLimitSnapshotFetched(RateLimitSnapshot),
 
+    /// Rate limits saved by an earlier fetch, shown until a live snapshot
+    /// arrives.
+    CachedRateLimitSnapshotLoaded {
+        snapshot: RateLimitSnapshot,
+        fetched_at: DateTime<Utc>,
+    },
+
     /// Result of prefetching connectors.
     ConnectorsLoaded {
         result: Result<ConnectorsSnapshot, String>,
@@ -136,6 +191,10 @@ pub(crate) enum AppEvent {
         url: String,
     },
 
+    /// Open a URL, or a file at a line, picked from the transcript overlay or
+    /// `/symbols`.
+    OpenTranscriptLink(LinkTarget),
+
     /// Refresh app connector state and mention bindings.
     RefreshConnectors {
         force_refetch: bool,
@@ -174,12 +233,27 @@ pub(crate) enum AppEvent {
     /// Update the current personality in the running app and widget.
     UpdatePersonality(Personality),
 
+    /// Update the active persona in the running app and widget.
+    UpdatePersona(Option<String>),
+
+    /// The session was scoped to a monorepo package (name and directory), or
+    /// widened back to the whole workspace.
+    UpdateScope(Option<(String, PathBuf)>),
+
+    /// Update how much detail new transcript cells show.
+    UpdateTranscriptDensity(TranscriptDensity),
+
     /// Persist the selected model and reasoning effort to the appropriate config.
     PersistModelSelection {
         model: String,
         effort: Option<ReasoningEffort>,
     },
 
+    /// Persist the transcript density to the user config.
+    PersistTranscriptDensity {
+        density: TranscriptDensity,
+    },
+
     /// Persist the selected UI language to the appropriate config.
     PersistLanguageSelection {
         language: Language,
@@ -201,6 +275,17 @@ pub(crate) enum AppEvent {
         personality: Personality,
     },
 
+    /// Persist the selected persona for the current project.
+    PersistPersonaSelection {
+        persona: Option<String>,
+    },
+
+    /// Switch to a config profile (or back to the top-level settings) and
+    /// remember the choice for the current project.
+    SwitchProfile {
+        profile: Option<String>,
+    },
+
     /// Open the reasoning selection popup after picking a model.
     OpenReasoningPopup {
         model: ModelPreset,
@@ -211,6 +296,27 @@ pub(crate) enum AppEvent {
         models: Vec<ModelPreset>,
     },
 
+    /// Ask before discarding this session's changes to one file.
+    OpenDiscardSessionChangeConfirmation {
+        path: PathBuf,
+    },
+
+    /// Prompt for a new session note.
+    OpenSessionNotePrompt,
+
+    /// Follow a command's output in a tmux or Zellij pane (`/follow`).
+    FollowInPane {
+        call_id: String,
+        command: String,
+        /// Output produced before the pane opens.
+        output: String,
+    },
+
+    /// Write the session's cumulative diff to a patch file in the cwd.
+    ExportSessionDiff {
+        unified_diff: String,
+    },
+
     /// Open the confirmation prompt before enabling full access mode.
     OpenFullAccessConfirmation {
         preset: ApprovalPreset,
@@ -320,6 +426,14 @@ pub(crate) enum AppEvent {
     /// Notify that the manage skills popup was closed.
     ManageSkillsClosed,
 
+    /// Open the trust prompt for repository skills that are not trusted yet.
+    OpenSkillsTrustPrompt,
+
+    /// Persist trust for the given repository skill paths.
+    TrustSkills {
+        paths: Vec<PathBuf>,
+    },
+
     /// Re-open the permissions presets popup.
     OpenPermissionsPopup,
 
@@ -329,6 +443,9 @@ pub(crate) enum AppEvent {
     /// Open the commit picker option from the review popup.
     OpenReviewCommitPicker(PathBuf),
 
+    /// Open the file glob prompt from the review popup.
+    OpenReviewFilesPrompt,
+
     /// Open the custom prompt option from the review popup.
     OpenReviewCustomPrompt,
 
@@ -355,6 +472,16 @@ pub(crate) enum AppEvent {
     /// Launch the external editor after a normal draw has completed.
     LaunchExternalEditor,
 
+    /// Retry a failed clipboard image paste by asking the terminal for its
+    /// clipboard over OSC 52; terminal input must be paused while it answers.
+    PasteImageViaOsc52(PasteImageError),
+
+    /// Results of `/diagram`, one per rendered block.
+    DiagramsRendered(Vec<Result<RenderedDiagram, DiagramError>>),
+
+    /// Result of the `/symbols` search over the last patch.
+    ChangedSymbolsFound(Vec<ChangedSymbol>),
+
     /// Async update of the current git branch for status line rendering.
     StatusLineBranchUpdated {
         cwd: PathBuf,
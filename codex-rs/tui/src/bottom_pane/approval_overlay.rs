@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
 
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
@@ -16,12 +18,14 @@ use crate::key_hint::KeyBinding;
 use crate::render::highlight::highlight_bash_to_lines;
 use crate::render::renderable::ColumnRenderable;
 use crate::render::renderable::Renderable;
+use crate::tui::FrameRequester;
 use codex_core::features::Features;
 use codex_core::protocol::ElicitationAction;
 use codex_core::protocol::ExecPolicyAmendment;
 use codex_core::protocol::FileChange;
 use codex_core::protocol::Op;
 use codex_core::protocol::ReviewDecision;
+use codex_protocol::config_types::ApprovalTimeoutAction;
 use codex_protocol::mcp::RequestId;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
@@ -57,6 +61,71 @@ pub(crate) enum ApprovalRequest {
     },
 }
 
+/// Configured auto-decision for approvals nobody answers, shown in the
+/// overlay as a countdown.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ApprovalTimeout {
+    pub(crate) timeout: Duration,
+    pub(crate) action: ApprovalTimeoutAction,
+}
+
+impl ApprovalRequest {
+    /// Id the agent uses for the request; elicitations have none and never
+    /// time out.
+    fn approval_id(&self) -> Option<&str> {
+        match self {
+            ApprovalRequest::Exec { id, .. } | ApprovalRequest::ApplyPatch { id, .. } => Some(id),
+            ApprovalRequest::McpElicitation { .. } => None,
+        }
+    }
+
+    /// Plain-text description of the request for accessible mode, where the
+    /// rendered modal header is not read aloud.
+    fn accessible_summary(&self) -> Vec<String> {
+        match self {
+            ApprovalRequest::Exec {
+                command, reason, ..
+            } => {
+                let mut lines = vec![format!(
+                    "Approval requested to run: {}",
+                    strip_bash_lc_and_escape(command)
+                )];
+                lines.extend(reason.as_ref().map(|reason| format!("Reason: {reason}")));
+                lines
+            }
+            ApprovalRequest::ApplyPatch {
+                reason,
+                cwd,
+                changes,
+                ..
+            } => {
+                let mut files: Vec<String> = changes
+                    .keys()
+                    .map(|path| path.strip_prefix(cwd).unwrap_or(path).display().to_string())
+                    .collect();
+                files.sort();
+                let mut lines = vec![format!(
+                    "Approval requested to edit {} file(s): {}",
+                    files.len(),
+                    files.join(", ")
+                )];
+                lines.extend(
+                    reason
+                        .as_ref()
+                        .filter(|reason| !reason.is_empty())
+                        .map(|reason| format!("Reason: {reason}")),
+                );
+                lines
+            }
+            ApprovalRequest::McpElicitation {
+                server_name,
+                message,
+                ..
+            } => vec![format!("Approval requested by {server_name}: {message}")],
+        }
+    }
+}
+
 /// Modal overlay asking the user to approve or deny one or more requests.
 pub(crate) struct ApprovalOverlay {
     current_request: Option<ApprovalRequest>,
@@ -68,6 +137,10 @@ pub(crate) struct ApprovalOverlay {
     current_complete: bool,
     done: bool,
     features: Features,
+    accessible_announcements: bool,
+    /// When each pending request arrived, keyed by approval id.
+    requested_at: HashMap<String, Instant>,
+    timeout: Option<(ApprovalTimeout, FrameRequester)>,
 }
 
 impl ApprovalOverlay {
@@ -82,12 +155,96 @@ impl ApprovalOverlay {
             current_complete: false,
             done: false,
             features,
+            accessible_announcements: false,
+            requested_at: HashMap::new(),
+            timeout: None,
         };
+        view.record_arrival(&request);
         view.set_current(request);
         view
     }
 
+    /// Show a countdown to the configured timeout action. `frame_requester`
+    /// keeps the countdown ticking while the overlay is idle.
+    pub(crate) fn set_timeout(
+        &mut self,
+        timeout: ApprovalTimeout,
+        frame_requester: FrameRequester,
+    ) {
+        self.timeout = Some((timeout, frame_requester));
+    }
+
+    /// The agent decided `id` on the user's behalf; drop it without sending a
+    /// decision.
+    pub(crate) fn dismiss_request(&mut self, id: &str) {
+        self.requested_at.remove(id);
+        self.queue
+            .retain(|request| request.approval_id() != Some(id));
+        let is_current = self
+            .current_request
+            .as_ref()
+            .is_some_and(|request| request.approval_id() == Some(id));
+        if is_current && !self.current_complete {
+            self.current_complete = true;
+            self.advance_queue();
+        }
+    }
+
+    fn record_arrival(&mut self, request: &ApprovalRequest) {
+        if let Some(id) = request.approval_id() {
+            self.requested_at.insert(id.to_string(), Instant::now());
+        }
+    }
+
+    /// Countdown shown under the options while the current request waits.
+    fn countdown_line(&self) -> Option<Line<'static>> {
+        let (timeout, _) = self.timeout.as_ref()?;
+        if self.current_complete {
+            return None;
+        }
+        let id = self.current_request.as_ref()?.approval_id()?;
+        let requested_at = self.requested_at.get(id)?;
+        let remaining = timeout
+            .timeout
+            .saturating_sub(requested_at.elapsed())
+            .as_secs();
+        let action = match timeout.action {
+            ApprovalTimeoutAction::Deny => "Auto-deny",
+            ApprovalTimeoutAction::AllowIfPolicySafe => "Auto-decide by sandbox policy",
+            ApprovalTimeoutAction::AbortTurn => "Auto-abort turn",
+        };
+        Some(Line::from(format!("  {action} in {remaining}s")).dim())
+    }
+
+    /// Announce this and every later request as plain history lines.
+    pub(crate) fn enable_accessible_announcements(&mut self) {
+        self.accessible_announcements = true;
+        self.announce_current();
+    }
+
+    fn announce_current(&self) {
+        let Some(request) = self.current_request.as_ref() else {
+            return;
+        };
+        let mut lines = request.accessible_summary();
+        lines.extend(self.options.iter().enumerate().map(|(idx, option)| {
+            match option.shortcuts().next() {
+                Some(shortcut) => format!(
+                    "{}. {} ({})",
+                    idx + 1,
+                    option.label,
+                    Span::from(shortcut).content
+                ),
+                None => format!("{}. {}", idx + 1, option.label),
+            }
+        }));
+        self.app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+            history_cell::new_accessible_announcement(lines),
+        )));
+    }
+
     pub fn enqueue_request(&mut self, req: ApprovalRequest) {
+        self.record_arrival(&req);
         self.queue.push(req);
     }
 
@@ -99,6 +256,9 @@ impl ApprovalOverlay {
         let (options, params) = Self::build_options(variant, header, &self.features);
         self.options = options;
         self.list = ListSelectionView::new(params, self.app_event_tx.clone());
+        if self.accessible_announcements {
+            self.announce_current();
+        }
     }
 
     fn build_options(
@@ -314,15 +474,39 @@ impl BottomPaneView for ApprovalOverlay {
         self.enqueue_request(request);
         None
     }
+
+    fn dismiss_approval_request(&mut self, id: &str) {
+        self.dismiss_request(id);
+    }
 }
 
 impl Renderable for ApprovalOverlay {
     fn desired_height(&self, width: u16) -> u16 {
-        self.list.desired_height(width)
+        let countdown = u16::from(self.countdown_line().is_some());
+        self.list.desired_height(width).saturating_add(countdown)
     }
 
     fn render(&self, area: Rect, buf: &mut Buffer) {
-        self.list.render(area, buf);
+        let Some(countdown) = self.countdown_line() else {
+            self.list.render(area, buf);
+            return;
+        };
+        let list_area = Rect {
+            height: area.height.saturating_sub(1),
+            ..area
+        };
+        self.list.render(list_area, buf);
+        if area.height > 0 {
+            let countdown_area = Rect {
+                y: area.bottom() - 1,
+                height: 1,
+                ..area
+            };
+            countdown.render(countdown_area, buf);
+        }
+        if let Some((_, frame_requester)) = self.timeout.as_ref() {
+            frame_requester.schedule_frame_in(Duration::from_secs(1));
+        }
     }
 
     fn cursor_pos(&self, area: Rect) -> Option<(u16, u16)> {
@@ -554,6 +738,41 @@ mod tests {
         assert!(view.is_complete());
     }
 
+    #[test]
+    fn timed_out_request_is_dismissed_without_a_decision() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view = ApprovalOverlay::new(make_exec_request(), tx, Features::with_defaults());
+        view.set_timeout(
+            ApprovalTimeout {
+                timeout: Duration::from_secs(30),
+                action: ApprovalTimeoutAction::Deny,
+            },
+            FrameRequester::test_dummy(),
+        );
+        view.enqueue_request(ApprovalRequest::Exec {
+            id: "next".to_string(),
+            command: vec!["ls".to_string()],
+            reason: None,
+            proposed_execpolicy_amendment: None,
+        });
+        let countdown = view
+            .countdown_line()
+            .expect("countdown for pending request");
+        assert!(countdown.to_string().contains("Auto-deny in"));
+
+        view.dismiss_request("test");
+
+        assert!(!view.is_complete());
+        assert_eq!(
+            view.current_request
+                .as_ref()
+                .and_then(ApprovalRequest::approval_id),
+            Some("next")
+        );
+        assert!(rx.try_recv().is_err(), "dismissal must not send a decision");
+    }
+
     #[test]
     fn shortcut_triggers_selection() {
         let (tx, mut rx) = unbounded_channel::<AppEvent>();
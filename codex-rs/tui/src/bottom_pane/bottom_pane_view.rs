@@ -63,6 +63,9 @@ pub(crate) trait BottomPaneView: Renderable {
         Some(request)
     }
 
+    /// Drop a pending approval the agent already decided (e.g. on timeout).
+    fn dismiss_approval_request(&mut self, _id: &str) {}
+
     /// Try to handle request_user_input; return the original value if not
     /// consumed.
     fn try_consume_user_input_request(
@@ -121,6 +121,7 @@ use super::footer::FooterMode;
 use super::footer::FooterProps;
 use super::footer::SummaryLeft;
 use super::footer::can_show_left_with_context;
+use super::footer::context_forecast_line;
 use super::footer::context_window_line;
 use super::footer::esc_hint_mode;
 use super::footer::footer_height;
@@ -157,6 +158,7 @@ use codex_protocol::config_types::Language;
 use codex_protocol::custom_prompts::CustomPrompt;
 use codex_protocol::custom_prompts::PROMPTS_CMD_PREFIX;
 use codex_protocol::models::local_image_label_text;
+use codex_protocol::protocol::TokenUsage;
 use codex_protocol::user_input::ByteRange;
 use codex_protocol::user_input::TextElement;
 
@@ -173,6 +175,7 @@ use crate::history_cell;
 use crate::ui_consts::LIVE_PREFIX_COLS;
 use codex_chatgpt::connectors;
 use codex_chatgpt::connectors::AppInfo;
+use codex_core::approx_token_count;
 use codex_core::skills::model::SkillMetadata;
 use codex_file_search::FileMatch;
 use std::cell::RefCell;
@@ -188,6 +191,10 @@ use std::time::Instant;
 /// placeholder in the UI.
 const LARGE_PASTE_CHAR_THRESHOLD: usize = 1000;
 
+/// Share of the context window (after the fixed baseline) at which the footer
+/// warns that sending the draft may overflow the context.
+const CONTEXT_FORECAST_WARN_PERCENT: i64 = 90;
+
 /// Result returned when the user interacts with the text area.
 #[derive(Debug, PartialEq)]
 pub enum InputResult {
@@ -201,6 +208,11 @@ pub enum InputResult {
     },
     Command(SlashCommand),
     CommandWithArgs(SlashCommand, String, Vec<TextElement>),
+    /// Compact the conversation, then send the draft once compaction finishes.
+    CompactThenQueued {
+        text: String,
+        text_elements: Vec<TextElement>,
+    },
     None,
 }
 
@@ -295,6 +307,8 @@ pub(crate) struct ChatComposer {
     footer_flash: Option<FooterFlash>,
     context_window_percent: Option<i64>,
     context_window_used_tokens: Option<i64>,
+    context_budget: Option<ContextBudget>,
+    permissions_chip: Option<Line<'static>>,
     skills: Option<Vec<SkillMetadata>>,
     connectors_snapshot: Option<ConnectorsSnapshot>,
     dismissed_mention_popup_token: Option<String>,
@@ -314,6 +328,14 @@ pub(crate) struct ChatComposer {
 }
 
 #[derive(Clone, Debug)]
+/// Tokens already in the context window and the window's size, used to
+/// forecast whether the draft still fits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ContextBudget {
+    used_tokens: i64,
+    context_window: i64,
+}
+
 struct FooterFlash {
     line: Line<'static>,
     expires_at: Instant,
@@ -395,6 +417,8 @@ impl ChatComposer {
             footer_flash: None,
             context_window_percent: None,
             context_window_used_tokens: None,
+            context_budget: None,
+            permissions_chip: None,
             skills: None,
             connectors_snapshot: None,
             dismissed_mention_popup_token: None,
@@ -759,7 +783,6 @@ impl ChatComposer {
         self.footer_hint_override = items;
     }
 
-    #[cfg(test)]
     pub(crate) fn show_footer_flash(&mut self, line: Line<'static>, duration: Duration) {
         let expires_at = Instant::now()
             .checked_add(duration)
@@ -2429,6 +2452,27 @@ impl ChatComposer {
             } if self.is_task_running && !self.is_bang_shell_command() => {
                 self.handle_submission(true)
             }
+            KeyEvent {
+                code: KeyCode::Char('l'),
+                modifiers: KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press,
+                ..
+            } if self.context_forecast_percent().is_some() => match self.handle_submission(true) {
+                (
+                    InputResult::Queued {
+                        text,
+                        text_elements,
+                    },
+                    redraw,
+                ) => (
+                    InputResult::CompactThenQueued {
+                        text,
+                        text_elements,
+                    },
+                    redraw,
+                ),
+                other => other,
+            },
             KeyEvent {
                 code: KeyCode::Enter,
                 modifiers: KeyModifiers::NONE,
@@ -2702,6 +2746,7 @@ impl ChatComposer {
             steer_enabled: self.steer_enabled,
             collaboration_modes_enabled: self.collaboration_modes_enabled,
             is_wsl,
+            image_paste_enabled: self.image_paste_enabled(),
             context_window_percent: self.context_window_percent,
             context_window_used_tokens: self.context_window_used_tokens,
             status_line_value: self.status_line_value.clone(),
@@ -3187,6 +3232,75 @@ impl ChatComposer {
         self.context_window_used_tokens = used_tokens;
     }
 
+    /// Show `chip` (the active sandbox and approval mode) before the context
+    /// indicator in the footer.
+    pub(crate) fn set_permissions_chip(&mut self, chip: Option<Line<'static>>) {
+        self.permissions_chip = chip;
+    }
+
+    /// Prefix `line` with the permissions chip, if one is set.
+    fn with_permissions_chip(&self, line: Option<Line<'static>>) -> Option<Line<'static>> {
+        let Some(chip) = self.permissions_chip.clone() else {
+            return line;
+        };
+        Some(match line {
+            Some(line) => {
+                let mut spans = chip.spans;
+                spans.push("  ".into());
+                spans.extend(line.spans);
+                Line::from(spans)
+            }
+            None => chip,
+        })
+    }
+
+    /// Record how many tokens the context already holds and the window size,
+    /// enabling the overflow forecast for drafts.
+    pub(crate) fn set_context_budget(
+        &mut self,
+        used_tokens: Option<i64>,
+        context_window: Option<i64>,
+    ) {
+        self.context_budget = match (used_tokens, context_window) {
+            (Some(used_tokens), Some(context_window)) => Some(ContextBudget {
+                used_tokens,
+                context_window,
+            }),
+            _ => None,
+        };
+    }
+
+    /// Forecast share of the context window used once the draft is sent, when
+    /// it reaches [`CONTEXT_FORECAST_WARN_PERCENT`]. Commands and drafts sent
+    /// while a task runs are not forecast.
+    fn context_forecast_percent(&self) -> Option<i64> {
+        let budget = self.context_budget?;
+        let text = self.textarea.text();
+        if self.is_task_running
+            || !self.input_enabled
+            || text.trim().is_empty()
+            || text.trim_start().starts_with('/')
+            || self.is_bang_shell_command()
+        {
+            return None;
+        }
+        // Large pastes are shown as placeholders; count what will be sent.
+        let draft_tokens = approx_token_count(text)
+            + self
+                .pending_pastes
+                .iter()
+                .map(|(_, pasted)| approx_token_count(pasted))
+                .sum::<usize>();
+        let usage = TokenUsage {
+            total_tokens: budget
+                .used_tokens
+                .saturating_add(i64::try_from(draft_tokens).unwrap_or(i64::MAX)),
+            ..TokenUsage::default()
+        };
+        let percent = usage.percent_of_context_window_used(budget.context_window);
+        (percent >= CONTEXT_FORECAST_WARN_PERCENT).then_some(percent)
+    }
+
     pub(crate) fn set_esc_backtrack_hint(&mut self, show: bool) {
         self.esc_backtrack_hint = show;
         if show {
@@ -3378,6 +3492,7 @@ impl ChatComposer {
                 } else {
                     self.collaboration_mode_indicator
                 };
+                let forecast_line = self.context_forecast_percent().map(context_forecast_line);
                 let mut left_width = if self.footer_flash_visible() {
                     self.footer_flash
                         .as_ref()
@@ -3385,6 +3500,8 @@ impl ChatComposer {
                         .unwrap_or(0)
                 } else if let Some(items) = self.footer_hint_override.as_ref() {
                     footer_hint_items_width(items)
+                } else if let Some(line) = forecast_line.as_ref() {
+                    line.width() as u16
                 } else if status_line_active {
                     truncated_status_line
                         .as_ref()
@@ -3415,6 +3532,7 @@ impl ChatComposer {
                         footer_props.context_window_used_tokens,
                     ))
                 };
+                let right_line = self.with_permissions_chip(right_line);
                 let right_width = right_line.as_ref().map(|l| l.width() as u16).unwrap_or(0);
                 if status_line_active
                     && let Some(max_left) = max_left_width_for_right(hint_rect, right_width)
@@ -3428,8 +3546,9 @@ impl ChatComposer {
                 }
                 let can_show_left_and_context =
                     can_show_left_with_context(hint_rect, left_width, right_width);
-                let has_override =
-                    self.footer_flash_visible() || self.footer_hint_override.is_some();
+                let has_override = self.footer_flash_visible()
+                    || self.footer_hint_override.is_some()
+                    || forecast_line.is_some();
                 let single_line_layout = if has_override {
                     None
                 } else {
@@ -3507,6 +3626,8 @@ impl ChatComposer {
                     }
                 } else if let Some(items) = self.footer_hint_override.as_ref() {
                     render_footer_hint_items(hint_rect, buf, items);
+                } else if let Some(line) = forecast_line {
+                    render_footer_line(hint_rect, buf, line);
                 } else if status_line_active {
                     if let Some(line) = truncated_status_line {
                         render_footer_line(hint_rect, buf, line);
@@ -5255,7 +5376,7 @@ mod tests {
             InputResult::Submitted { text, .. } => {
                 panic!("expected command dispatch, but composer submitted literal text: {text}")
             }
-            InputResult::Queued { .. } => {
+            InputResult::Queued { .. } | InputResult::CompactThenQueued { .. } => {
                 panic!("expected command dispatch, but composer queued literal text")
             }
             InputResult::None => panic!("expected Command result for '/init'"),
@@ -5399,7 +5520,7 @@ mod tests {
             InputResult::Submitted { text, .. } => {
                 panic!("expected command dispatch after Tab completion, got literal submit: {text}")
             }
-            InputResult::Queued { .. } => {
+            InputResult::Queued { .. } | InputResult::CompactThenQueued { .. } => {
                 panic!("expected command dispatch after Tab completion, got literal queue")
             }
             InputResult::None => panic!("expected Command result for '/diff'"),
@@ -5568,7 +5689,7 @@ mod tests {
             InputResult::Submitted { text, .. } => {
                 panic!("expected command dispatch, but composer submitted literal text: {text}")
             }
-            InputResult::Queued { .. } => {
+            InputResult::Queued { .. } | InputResult::CompactThenQueued { .. } => {
                 panic!("expected command dispatch, but composer queued literal text")
             }
             InputResult::None => panic!("expected Command result for '/mention'"),
@@ -7346,6 +7467,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ctrl_l_compacts_before_sending_a_draft_forecast_to_overflow() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        // 88% of the effective 100k window is already used.
+        composer.set_context_budget(Some(100_000), Some(112_000));
+
+        composer.textarea.set_text_clearing_elements("hi");
+        assert_eq!(composer.context_forecast_percent(), None);
+
+        let draft = "a".repeat(8_000);
+        composer.textarea.set_text_clearing_elements(&draft);
+        composer.textarea.set_cursor(draft.len());
+        assert_eq!(composer.context_forecast_percent(), Some(90));
+        composer.set_task_running(true);
+        assert_eq!(composer.context_forecast_percent(), None);
+        composer.set_task_running(false);
+
+        let (result, _needs_redraw) =
+            composer.handle_key_event(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::CONTROL));
+        assert!(matches!(
+            result,
+            InputResult::CompactThenQueued { text, .. } if text == draft
+        ));
+        assert!(composer.textarea.is_empty());
+    }
+
     #[test]
     fn selecting_custom_prompt_with_positional_args_submits_numeric_expansion() {
         let prompt_text = "Header: $1\nArgs: $ARGUMENTS\n";
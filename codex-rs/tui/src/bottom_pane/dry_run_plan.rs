@@ -0,0 +1,93 @@
+//! Picker for the tool calls a `/dry-run` turn is holding back.
+//!
+//! Every call starts checked. Enter runs the checked calls and Esc runs none;
+//! either way the turn continues with the model told which calls were
+//! declined.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::app_event::AppEvent;
+use crate::app_event_sender::AppEventSender;
+use crate::bottom_pane::CancellationEvent;
+use crate::bottom_pane::bottom_pane_view::BottomPaneView;
+use crate::bottom_pane::multi_select_picker::MultiSelectItem;
+use crate::bottom_pane::multi_select_picker::MultiSelectPicker;
+use crate::i18n::tr;
+use crate::render::renderable::Renderable;
+use codex_protocol::config_types::Language;
+use codex_protocol::protocol::DryRunPlanEvent;
+use codex_protocol::protocol::Op;
+
+pub(crate) struct DryRunPlanView {
+    picker: MultiSelectPicker,
+}
+
+impl DryRunPlanView {
+    pub(crate) fn new(
+        event: DryRunPlanEvent,
+        app_event_tx: AppEventSender,
+        language: Language,
+    ) -> Self {
+        let items = event
+            .calls
+            .into_iter()
+            .map(|call| MultiSelectItem {
+                id: call.call_id,
+                name: call.tool_name,
+                description: Some(call.summary),
+                enabled: true,
+            })
+            .collect();
+        let confirm_turn_id = event.turn_id.clone();
+        let cancel_turn_id = event.turn_id;
+
+        Self {
+            picker: MultiSelectPicker::builder(
+                tr(language, "dry_run.picker.title").to_string(),
+                Some(tr(language, "dry_run.picker.subtitle").to_string()),
+                app_event_tx,
+            )
+            .instructions(vec![tr(language, "dry_run.picker.instructions").into()])
+            .items(items)
+            .on_confirm(move |ids, app_event| {
+                app_event.send(AppEvent::CodexOp(Op::DryRunDecision {
+                    id: confirm_turn_id.clone(),
+                    approved_call_ids: ids.to_vec(),
+                }));
+            })
+            .on_cancel(move |app_event| {
+                app_event.send(AppEvent::CodexOp(Op::DryRunDecision {
+                    id: cancel_turn_id.clone(),
+                    approved_call_ids: Vec::new(),
+                }));
+            })
+            .build(),
+        }
+    }
+}
+
+impl BottomPaneView for DryRunPlanView {
+    fn handle_key_event(&mut self, key_event: crossterm::event::KeyEvent) {
+        self.picker.handle_key_event(key_event);
+    }
+
+    fn is_complete(&self) -> bool {
+        self.picker.complete
+    }
+
+    fn on_ctrl_c(&mut self) -> CancellationEvent {
+        self.picker.close();
+        CancellationEvent::Handled
+    }
+}
+
+impl Renderable for DryRunPlanView {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        self.picker.render(area, buf)
+    }
+
+    fn desired_height(&self, width: u16) -> u16 {
+        self.picker.desired_height(width)
+    }
+}
@@ -37,6 +37,8 @@ use crate::key_hint::KeyBinding;
 use crate::render::line_utils::prefix_lines;
 use crate::status::format_tokens_compact;
 use crate::ui_consts::FOOTER_INDENT_COLS;
+use codex_core::protocol::AskForApproval;
+use codex_core::protocol::SandboxPolicy;
 use crossterm::event::KeyCode;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
@@ -62,6 +64,8 @@ pub(crate) struct FooterProps {
     pub(crate) steer_enabled: bool,
     pub(crate) collaboration_modes_enabled: bool,
     pub(crate) is_wsl: bool,
+    /// Whether the active model accepts images; hides the paste-image hint when false.
+    pub(crate) image_paste_enabled: bool,
     /// Which key the user must press again to quit.
     ///
     /// This is rendered when `mode` is `FooterMode::QuitShortcutReminder`.
@@ -278,6 +282,43 @@ fn left_side_line(
     line
 }
 
+/// Warning shown while sending the draft is forecast to fill `percent` of the
+/// context window, with the key that compacts before sending. It turns red
+/// once the draft would not fit at all.
+pub(crate) fn context_forecast_line(percent: i64) -> Line<'static> {
+    let forecast = format!("~{percent}% of context after sending · ");
+    Line::from(vec![
+        if percent >= 100 {
+            forecast.red()
+        } else {
+            forecast.cyan()
+        },
+        key_hint::ctrl(KeyCode::Char('l')).into(),
+        " to compact first".dim(),
+    ])
+}
+
+/// Chip naming the active sandbox mode and approval policy, shown before the
+/// context indicator. Running with neither sandbox nor approvals (for example
+/// `--dangerously-bypass-approvals-and-sandbox`) is shown in red.
+pub(crate) fn permissions_chip_line(
+    approval: AskForApproval,
+    sandbox: &SandboxPolicy,
+) -> Line<'static> {
+    let sandbox_label = match sandbox {
+        SandboxPolicy::DangerFullAccess => "full-access",
+        SandboxPolicy::ReadOnly => "read-only",
+        SandboxPolicy::ExternalSandbox { .. } => "external-sandbox",
+        SandboxPolicy::WorkspaceWrite { .. } => "workspace-write",
+    };
+    let chip = format!("{sandbox_label} · {approval}");
+    if matches!(sandbox, SandboxPolicy::DangerFullAccess) && approval == AskForApproval::Never {
+        Line::from(chip.red().bold())
+    } else {
+        Line::from(chip.dim())
+    }
+}
+
 pub(crate) enum SummaryLeft {
     Default,
     Custom(Line<'static>),
@@ -594,6 +635,7 @@ fn footer_from_props_lines(
                 esc_backtrack_hint: props.esc_backtrack_hint,
                 is_wsl: props.is_wsl,
                 collaboration_modes_enabled: props.collaboration_modes_enabled,
+                image_paste_enabled: props.image_paste_enabled,
             };
             shortcut_overlay_lines(state)
         }
@@ -657,6 +699,7 @@ struct ShortcutsState {
     esc_backtrack_hint: bool,
     is_wsl: bool,
     collaboration_modes_enabled: bool,
+    image_paste_enabled: bool,
 }
 
 fn quit_shortcut_reminder_line(key: KeyBinding) -> Line<'static> {
@@ -699,7 +742,8 @@ fn shortcut_overlay_lines(state: ShortcutsState) -> Vec<Line<'static>> {
                 ShortcutId::InsertNewline => newline = text,
                 ShortcutId::QueueMessageTab => queue_message_tab = text,
                 ShortcutId::FilePaths => file_paths = text,
-                ShortcutId::PasteImage => paste_image = text,
+                ShortcutId::PasteImage if state.image_paste_enabled => paste_image = text,
+                ShortcutId::PasteImage => {}
                 ShortcutId::ExternalEditor => external_editor = text,
                 ShortcutId::EditPrevious => edit_previous = text,
                 ShortcutId::Quit => quit = text,
@@ -715,11 +759,11 @@ fn shortcut_overlay_lines(state: ShortcutsState) -> Vec<Line<'static>> {
         newline,
         queue_message_tab,
         file_paths,
-        paste_image,
-        external_editor,
-        edit_previous,
-        quit,
     ];
+    if paste_image.width() > 0 {
+        ordered.push(paste_image);
+    }
+    ordered.extend([external_editor, edit_previous, quit]);
     if change_mode.width() > 0 {
         ordered.push(change_mode);
     }
@@ -1192,6 +1236,7 @@ mod tests {
                 steer_enabled: false,
                 collaboration_modes_enabled: false,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: None,
                 context_window_used_tokens: None,
@@ -1210,6 +1255,7 @@ mod tests {
                 steer_enabled: false,
                 collaboration_modes_enabled: false,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: None,
                 context_window_used_tokens: None,
@@ -1228,6 +1274,7 @@ mod tests {
                 steer_enabled: false,
                 collaboration_modes_enabled: true,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: None,
                 context_window_used_tokens: None,
@@ -1246,6 +1293,7 @@ mod tests {
                 steer_enabled: false,
                 collaboration_modes_enabled: false,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: None,
                 context_window_used_tokens: None,
@@ -1264,6 +1312,7 @@ mod tests {
                 steer_enabled: false,
                 collaboration_modes_enabled: false,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: None,
                 context_window_used_tokens: None,
@@ -1282,6 +1331,7 @@ mod tests {
                 steer_enabled: false,
                 collaboration_modes_enabled: false,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: None,
                 context_window_used_tokens: None,
@@ -1300,6 +1350,7 @@ mod tests {
                 steer_enabled: false,
                 collaboration_modes_enabled: false,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: None,
                 context_window_used_tokens: None,
@@ -1318,6 +1369,7 @@ mod tests {
                 steer_enabled: false,
                 collaboration_modes_enabled: false,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: Some(72),
                 context_window_used_tokens: None,
@@ -1336,6 +1388,7 @@ mod tests {
                 steer_enabled: false,
                 collaboration_modes_enabled: false,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: None,
                 context_window_used_tokens: Some(123_456),
@@ -1354,6 +1407,7 @@ mod tests {
                 steer_enabled: false,
                 collaboration_modes_enabled: false,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: None,
                 context_window_used_tokens: None,
@@ -1372,6 +1426,7 @@ mod tests {
                 steer_enabled: true,
                 collaboration_modes_enabled: false,
                 is_wsl: false,
+                image_paste_enabled: true,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
                 context_window_percent: None,
                 context_window_used_tokens: None,
@@ -1388,6 +1443,7 @@ mod tests {
             steer_enabled: false,
             collaboration_modes_enabled: true,
             is_wsl: false,
+            image_paste_enabled: true,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
             context_window_percent: None,
             context_window_used_tokens: None,
@@ -1417,6 +1473,7 @@ mod tests {
             steer_enabled: false,
             collaboration_modes_enabled: true,
             is_wsl: false,
+            image_paste_enabled: true,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
             context_window_percent: None,
             context_window_used_tokens: None,
@@ -1439,6 +1496,7 @@ mod tests {
             steer_enabled: false,
             collaboration_modes_enabled: false,
             is_wsl: false,
+            image_paste_enabled: true,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
             context_window_percent: None,
             context_window_used_tokens: None,
@@ -1456,6 +1514,7 @@ mod tests {
             steer_enabled: false,
             collaboration_modes_enabled: true,
             is_wsl: false,
+            image_paste_enabled: true,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
             context_window_percent: Some(50),
             context_window_used_tokens: None,
@@ -1478,6 +1537,7 @@ mod tests {
             steer_enabled: false,
             collaboration_modes_enabled: true,
             is_wsl: false,
+            image_paste_enabled: true,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
             context_window_percent: Some(50),
             context_window_used_tokens: None,
@@ -1500,6 +1560,7 @@ mod tests {
             steer_enabled: false,
             collaboration_modes_enabled: false,
             is_wsl: false,
+            image_paste_enabled: true,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
             context_window_percent: Some(50),
             context_window_used_tokens: None,
@@ -1523,6 +1584,7 @@ mod tests {
             steer_enabled: false,
             collaboration_modes_enabled: true,
             is_wsl: false,
+            image_paste_enabled: true,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
             context_window_percent: Some(50),
             context_window_used_tokens: None,
@@ -1550,6 +1612,7 @@ mod tests {
             steer_enabled: false,
             collaboration_modes_enabled: true,
             is_wsl: false,
+            image_paste_enabled: true,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
             context_window_percent: Some(50),
             context_window_used_tokens: None,
@@ -1577,6 +1640,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shortcut_overlay_hides_paste_image_for_text_only_models() {
+        let state = ShortcutsState {
+            use_shift_enter_hint: false,
+            esc_backtrack_hint: false,
+            is_wsl: false,
+            collaboration_modes_enabled: false,
+            image_paste_enabled: true,
+        };
+        let render = |state: ShortcutsState| {
+            shortcut_overlay_lines(state)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        assert!(render(state).contains("to paste images"));
+        let text_only = render(ShortcutsState {
+            image_paste_enabled: false,
+            ..state
+        });
+        assert!(!text_only.contains("to paste images"));
+        assert!(text_only.contains("to edit in external editor"));
+    }
+
     #[test]
     fn paste_image_shortcut_prefers_ctrl_alt_v_under_wsl() {
         let descriptor = SHORTCUTS
@@ -1607,10 +1696,35 @@ mod tests {
                 esc_backtrack_hint: false,
                 is_wsl,
                 collaboration_modes_enabled: false,
+                image_paste_enabled: true,
             })
             .expect("shortcut binding")
             .key;
 
         assert_eq!(actual_key, expected_key);
     }
+
+    #[test]
+    fn permissions_chip_is_red_only_without_sandbox_and_approvals() {
+        let chip = permissions_chip_line(
+            AskForApproval::OnRequest,
+            &SandboxPolicy::new_workspace_write_policy(),
+        );
+        assert_eq!(chip.spans[0].content, "workspace-write · on-request");
+        assert_eq!(chip.spans[0].style.fg, None);
+
+        let chip = permissions_chip_line(AskForApproval::Never, &SandboxPolicy::DangerFullAccess);
+        assert_eq!(chip.spans[0].content, "full-access · never");
+        assert_eq!(chip.spans[0].style.fg, Some(ratatui::style::Color::Red));
+    }
+
+    #[test]
+    fn context_forecast_is_red_only_when_it_overflows() {
+        let line = context_forecast_line(80);
+        assert_eq!(line.spans[0].style.fg, Some(ratatui::style::Color::Cyan));
+
+        let line = context_forecast_line(120);
+        assert_eq!(line.spans[0].content, "~120% of context after sending · ");
+        assert_eq!(line.spans[0].style.fg, Some(ratatui::style::Color::Red));
+    }
 }
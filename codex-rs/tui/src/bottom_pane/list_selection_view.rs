@@ -15,6 +15,7 @@ use ratatui::widgets::Widget;
 use super::selection_popup_common::render_menu_surface;
 use super::selection_popup_common::wrap_styled_line;
 use crate::app_event_sender::AppEventSender;
+use crate::i18n::tr;
 use crate::key_hint::KeyBinding;
 use crate::render::renderable::ColumnRenderable;
 use crate::render::renderable::Renderable;
@@ -31,6 +32,7 @@ use super::selection_popup_common::measure_rows_height_with_col_width_mode;
 use super::selection_popup_common::render_rows;
 use super::selection_popup_common::render_rows_stable_col_widths;
 use super::selection_popup_common::render_rows_with_col_width_mode;
+use codex_protocol::config_types::Language;
 use unicode_width::UnicodeWidthStr;
 
 /// One selectable item in the generic selection list.
@@ -99,6 +101,46 @@ impl Default for SelectionViewParams {
     }
 }
 
+/// Plain-text form of a menu for accessible mode: the title, then one numbered
+/// line per item using the same numbers the popup accepts as shortcuts.
+pub(crate) fn accessible_menu_lines(
+    params: &SelectionViewParams,
+    language: Language,
+) -> Vec<String> {
+    let mut lines = vec![
+        params
+            .title
+            .clone()
+            .unwrap_or_else(|| tr(language, "accessibility.menu.title").to_string()),
+    ];
+    lines.extend(params.subtitle.clone());
+    for (idx, item) in params.items.iter().enumerate() {
+        let mut line = format!("{}. {}", idx + 1, item.name);
+        if item.is_current {
+            line.push_str(tr(language, "accessibility.menu.current"));
+        }
+        if let Some(description) = &item.description {
+            line.push_str(" - ");
+            line.push_str(description);
+        }
+        if item.is_disabled || item.disabled_reason.is_some() {
+            let reason = item
+                .disabled_reason
+                .as_deref()
+                .unwrap_or_else(|| tr(language, "accessibility.menu.unavailable"));
+            line.push_str(&format!(" ({reason})"));
+        }
+        lines.push(line);
+    }
+    let hint_key = if params.is_searchable {
+        "accessibility.menu.hint_search"
+    } else {
+        "accessibility.menu.hint_numbers"
+    };
+    lines.push(tr(language, hint_key).to_string());
+    lines
+}
+
 /// Runtime state for rendering and interacting with a list-based selection popup.
 ///
 /// This type is the single authority for filtered index mapping between
@@ -687,6 +729,38 @@ mod tests {
     use ratatui::layout::Rect;
     use tokio::sync::mpsc::unbounded_channel;
 
+    #[test]
+    fn accessible_menu_lines_number_items() {
+        let params = SelectionViewParams {
+            title: Some("Select Approval Mode".to_string()),
+            items: vec![
+                SelectionItem {
+                    name: "Read Only".to_string(),
+                    description: Some("Codex can read files".to_string()),
+                    is_current: true,
+                    ..Default::default()
+                },
+                SelectionItem {
+                    name: "Full Access".to_string(),
+                    disabled_reason: Some("blocked by admin".to_string()),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            accessible_menu_lines(&params, Language::En),
+            vec![
+                "Select Approval Mode".to_string(),
+                "1. Read Only (current) - Codex can read files".to_string(),
+                "2. Full Access (blocked by admin)".to_string(),
+                "Press a number, or use Up/Down and Enter, to choose. Esc closes the menu."
+                    .to_string(),
+            ]
+        );
+    }
+
     fn make_selection_view(subtitle: Option<&str>) -> ListSelectionView {
         let (tx_raw, _rx) = unbounded_channel::<AppEvent>();
         let tx = AppEventSender::new(tx_raw);
@@ -15,10 +15,12 @@
 //! hint. The pane schedules redraws so those hints can expire even when the UI is otherwise idle.
 use std::path::PathBuf;
 
+use crate::app_event::AppEvent;
 use crate::app_event::ConnectorsSnapshot;
 use crate::app_event_sender::AppEventSender;
 use crate::bottom_pane::queued_user_messages::QueuedUserMessages;
 use crate::bottom_pane::unified_exec_footer::UnifiedExecFooter;
+use crate::history_cell;
 use crate::i18n::tr;
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
@@ -48,6 +50,7 @@ mod status_line_setup;
 pub(crate) use app_link_view::AppLinkView;
 pub(crate) use approval_overlay::ApprovalOverlay;
 pub(crate) use approval_overlay::ApprovalRequest;
+pub(crate) use approval_overlay::ApprovalTimeout;
 pub(crate) use request_user_input::RequestUserInputOverlay;
 mod bottom_pane_view;
 
@@ -68,6 +71,7 @@ mod chat_composer;
 mod chat_composer_history;
 mod command_popup;
 pub mod custom_prompt_view;
+mod dry_run_plan;
 mod experimental_features_view;
 mod file_search_popup;
 mod footer;
@@ -76,7 +80,9 @@ mod prompt_args;
 mod skill_popup;
 mod skills_toggle_view;
 mod slash_commands;
+pub(crate) use dry_run_plan::DryRunPlanView;
 pub(crate) use footer::CollaborationModeIndicator;
+pub(crate) use footer::permissions_chip_line;
 pub(crate) use list_selection_view::ColumnWidthMode;
 pub(crate) use list_selection_view::SelectionViewParams;
 pub(crate) use prompt_args::parse_slash_name;
@@ -159,6 +165,9 @@ pub(crate) struct BottomPane {
     is_task_running: bool,
     esc_backtrack_hint: bool,
     animations_enabled: bool,
+    /// Accessible mode: no in-place status row, and menus/approvals are also
+    /// announced as plain history lines.
+    accessible: bool,
     language: Language,
 
     /// Inline status indicator shown above the composer while a task is running.
@@ -173,6 +182,11 @@ pub(crate) struct BottomPane {
     context_window_percent: Option<i64>,
     context_window_used_tokens: Option<i64>,
     token_usage: Option<TokenUsageSplit>,
+    /// Auto-decision for unanswered approvals, shown as a countdown.
+    approval_timeout: Option<ApprovalTimeout>,
+    /// Warning pinned above everything else while approvals and sandboxing
+    /// are both disabled.
+    danger_banner: Option<Line<'static>>,
 }
 
 pub(crate) struct BottomPaneParams {
@@ -183,6 +197,7 @@ pub(crate) struct BottomPaneParams {
     pub(crate) placeholder_text: String,
     pub(crate) disable_paste_burst: bool,
     pub(crate) animations_enabled: bool,
+    pub(crate) accessible: bool,
     pub(crate) skills: Option<Vec<SkillMetadata>>,
     pub(crate) language: Language,
 }
@@ -197,6 +212,7 @@ impl BottomPane {
             placeholder_text,
             disable_paste_burst,
             animations_enabled,
+            accessible,
             skills,
             language,
         } = params;
@@ -224,10 +240,13 @@ impl BottomPane {
             queued_user_messages: QueuedUserMessages::new(),
             esc_backtrack_hint: false,
             animations_enabled,
+            accessible,
             language,
             context_window_percent: None,
             context_window_used_tokens: None,
             token_usage: None,
+            approval_timeout: None,
+            danger_banner: None,
         }
     }
 
@@ -273,6 +292,10 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    pub(crate) fn set_approval_timeout(&mut self, timeout: Option<ApprovalTimeout>) {
+        self.approval_timeout = timeout;
+    }
+
     pub fn set_connectors_enabled(&mut self, enabled: bool) {
         self.composer.set_connectors_enabled(enabled);
     }
@@ -318,6 +341,16 @@ impl BottomPane {
         self.view_stack.last().map(std::convert::AsRef::as_ref)
     }
 
+    /// Append plain lines to the transcript (accessible mode only).
+    pub(crate) fn announce(&self, lines: Vec<String>) {
+        if !self.accessible || lines.is_empty() {
+            return;
+        }
+        self.app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+            history_cell::new_accessible_announcement(lines),
+        )));
+    }
+
     fn push_view(&mut self, view: Box<dyn BottomPaneView>) {
         self.view_stack.push(view);
         self.request_redraw();
@@ -530,6 +563,14 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    /// Show `line` in place of the footer hints for `duration`.
+    pub(crate) fn show_footer_flash(&mut self, line: Line<'static>, duration: Duration) {
+        self.composer.show_footer_flash(line, duration);
+        self.request_redraw();
+        // Redraw once more after expiry so the footer falls back to its hints.
+        self.request_redraw_in(duration);
+    }
+
     pub(crate) fn set_language(&mut self, language: Language) {
         if self.language == language {
             return;
@@ -622,7 +663,7 @@ impl BottomPane {
 
         if running {
             if !was_running {
-                if self.status.is_none() {
+                if self.status.is_none() && !self.accessible {
                     self.status = Some(StatusIndicatorWidget::new(
                         self.app_event_tx.clone(),
                         self.frame_requester.clone(),
@@ -650,7 +691,7 @@ impl BottomPane {
     }
 
     pub(crate) fn ensure_status_indicator(&mut self) {
-        if self.status.is_none() {
+        if self.status.is_none() && !self.accessible {
             self.status = Some(StatusIndicatorWidget::new(
                 self.app_event_tx.clone(),
                 self.frame_requester.clone(),
@@ -682,6 +723,30 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    pub(crate) fn set_permissions_chip(&mut self, chip: Option<Line<'static>>) {
+        self.composer.set_permissions_chip(chip);
+        self.request_redraw();
+    }
+
+    pub(crate) fn set_danger_banner(&mut self, banner: Option<Line<'static>>) {
+        if self.danger_banner != banner {
+            self.danger_banner = banner;
+            self.request_redraw();
+        }
+    }
+
+    /// Forward the context already used and the window size so the composer
+    /// can warn before a draft overflows the context.
+    pub(crate) fn set_context_budget(
+        &mut self,
+        used_tokens: Option<i64>,
+        context_window: Option<i64>,
+    ) {
+        self.composer
+            .set_context_budget(used_tokens, context_window);
+        self.request_redraw();
+    }
+
     pub(crate) fn set_token_usage(&mut self, usage: Option<TokenUsageSplit>) {
         if self.token_usage == usage {
             return;
@@ -693,6 +758,12 @@ impl BottomPane {
 
     /// Show a generic list selection view with the provided items.
     pub(crate) fn show_selection_view(&mut self, params: list_selection_view::SelectionViewParams) {
+        if self.accessible {
+            self.announce(list_selection_view::accessible_menu_lines(
+                &params,
+                self.language,
+            ));
+        }
         let view = list_selection_view::ListSelectionView::new(params, self.app_event_tx.clone());
         self.push_view(Box::new(view));
     }
@@ -797,11 +868,30 @@ impl BottomPane {
         };
 
         // Otherwise create a new approval modal overlay.
-        let modal = ApprovalOverlay::new(request, self.app_event_tx.clone(), features.clone());
+        let mut modal = ApprovalOverlay::new(request, self.app_event_tx.clone(), features.clone());
+        if let Some(timeout) = self.approval_timeout {
+            modal.set_timeout(timeout, self.frame_requester.clone());
+        }
+        if self.accessible {
+            modal.enable_accessible_announcements();
+        }
         self.pause_status_timer_for_modal();
         self.push_view(Box::new(modal));
     }
 
+    /// Called when the agent decided a pending approval on the user's behalf.
+    pub(crate) fn dismiss_approval_request(&mut self, id: &str) {
+        for view in &mut self.view_stack {
+            view.dismiss_approval_request(id);
+        }
+        let had_views = !self.view_stack.is_empty();
+        self.view_stack.retain(|view| !view.is_complete());
+        if had_views && self.view_stack.is_empty() {
+            self.on_active_view_complete();
+        }
+        self.request_redraw();
+    }
+
     /// Called when the agent requests user input.
     pub fn push_user_input_request(&mut self, request: RequestUserInputEvent) {
         let request = if let Some(view) = self.view_stack.last_mut() {
@@ -931,6 +1021,17 @@ impl BottomPane {
     }
 
     fn as_renderable(&'_ self) -> RenderableItem<'_> {
+        let pane = self.pane_renderable();
+        let Some(banner) = &self.danger_banner else {
+            return pane;
+        };
+        let mut flex = FlexRenderable::new();
+        flex.push(0, RenderableItem::Owned(Box::new(banner.clone())));
+        flex.push(1, pane);
+        RenderableItem::Owned(Box::new(flex))
+    }
+
+    fn pane_renderable(&'_ self) -> RenderableItem<'_> {
         if let Some(view) = self.active_view() {
             RenderableItem::Borrowed(view)
         } else {
@@ -1040,6 +1141,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1049,6 +1151,55 @@ mod tests {
         assert_eq!(CancellationEvent::NotHandled, pane.on_ctrl_c());
     }
 
+    #[test]
+    fn accessible_mode_announces_approvals_without_status_row() {
+        use crate::history_cell::HistoryCell as _;
+
+        let (tx_raw, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx_raw);
+        let features = Features::with_defaults();
+        let mut pane = BottomPane::new(BottomPaneParams {
+            app_event_tx: tx,
+            frame_requester: FrameRequester::test_dummy(),
+            has_input_focus: true,
+            enhanced_keys_supported: false,
+            placeholder_text: "Ask Codex to do anything".to_string(),
+            disable_paste_burst: false,
+            animations_enabled: false,
+            accessible: true,
+            skills: Some(Vec::new()),
+            language: Language::En,
+        });
+
+        pane.set_task_running(true);
+        assert!(pane.status_widget().is_none());
+
+        pane.push_approval_request(exec_request(), &features);
+        let lines = std::iter::from_fn(|| rx.try_recv().ok())
+            .find_map(|event| match event {
+                AppEvent::InsertHistoryCell(cell) => Some(cell.display_lines(80)),
+                _ => None,
+            })
+            .expect("approval announcement");
+        let lines: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Approval requested to run: echo ok".to_string(),
+                "1. Yes, proceed (y)".to_string(),
+                "2. No, and tell Codex what to do differently (esc)".to_string(),
+            ]
+        );
+    }
+
     // live ring removed; related tests deleted.
 
     #[test]
@@ -1064,6 +1215,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1100,6 +1252,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1169,6 +1322,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1201,6 +1355,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1233,6 +1388,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1257,6 +1413,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1287,6 +1444,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1319,6 +1477,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1348,6 +1507,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1376,6 +1536,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(vec![SkillMetadata {
                 name: "test-skill".to_string(),
                 description: "test skill".to_string(),
@@ -1424,6 +1585,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1460,6 +1622,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
@@ -1517,6 +1680,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            accessible: false,
             skills: Some(Vec::new()),
             language: Language::En,
         });
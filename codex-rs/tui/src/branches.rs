@@ -0,0 +1,156 @@
+//! Fork tree view for the current thread (`/branches`).
+//!
+//! `App` loads the tree with [`codex_core::find_branch_tree`] and lists it in a
+//! selection popup; picking a branch offers to resume it or to diff it against
+//! the current thread in a static pager overlay.
+
+use codex_core::BranchNode;
+use codex_core::BranchTurn;
+use codex_protocol::config_types::Language;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+
+use crate::i18n::tr;
+use crate::i18n::tr_args;
+use crate::text_formatting::truncate_text;
+
+const DIFF_PREVIEW_GRAPHEMES: usize = 160;
+
+/// Name shown for a branch: its thread name, or the thread id when unnamed.
+pub(crate) fn branch_label(node: &BranchNode) -> String {
+    node.thread_name
+        .clone()
+        .unwrap_or_else(|| node.thread_id.to_string())
+}
+
+pub(crate) fn branch_description(node: &BranchNode, language: Language) -> String {
+    match node.fork_turn {
+        Some(turn) => tr_args(
+            language,
+            "branches.forked_after",
+            &[("turn", &turn.to_string()), ("time", &node.timestamp)],
+        ),
+        None => tr_args(language, "branches.started", &[("time", &node.timestamp)]),
+    }
+}
+
+/// Pager lines comparing the turns of the current thread (`ours`) with those
+/// of another branch (`theirs`) after the point where they diverge.
+pub(crate) fn branch_diff_lines(
+    other_label: &str,
+    ours: &[BranchTurn],
+    theirs: &[BranchTurn],
+    shared: usize,
+    language: Language,
+) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = vec![
+        tr_args(
+            language,
+            "branches.shared_turns",
+            &[("count", &shared.to_string())],
+        )
+        .dim()
+        .into(),
+    ];
+    push_branch_tail(
+        &mut lines,
+        tr(language, "branches.current_branch").to_string(),
+        &ours[shared.min(ours.len())..],
+        language,
+    );
+    push_branch_tail(
+        &mut lines,
+        other_label.to_string(),
+        &theirs[shared.min(theirs.len())..],
+        language,
+    );
+    lines
+}
+
+fn push_branch_tail(
+    lines: &mut Vec<Line<'static>>,
+    label: String,
+    turns: &[BranchTurn],
+    language: Language,
+) {
+    lines.push(Line::default());
+    lines.push(label.bold().into());
+    if turns.is_empty() {
+        lines.push(
+            format!("  {}", tr(language, "branches.no_further_turns"))
+                .dim()
+                .into(),
+        );
+        return;
+    }
+    for turn in turns {
+        lines.push(
+            vec![
+                "  › ".cyan(),
+                truncate_text(first_line(&turn.prompt), DIFF_PREVIEW_GRAPHEMES).into(),
+            ]
+            .into(),
+        );
+        if let Some(reply) = &turn.reply {
+            lines.push(
+                format!(
+                    "    {}",
+                    truncate_text(first_line(reply), DIFF_PREVIEW_GRAPHEMES)
+                )
+                .dim()
+                .into(),
+            );
+        }
+    }
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn turn(prompt: &str, reply: &str) -> BranchTurn {
+        BranchTurn {
+            prompt: prompt.to_string(),
+            reply: Some(reply.to_string()),
+        }
+    }
+
+    fn plain(lines: &[Line<'static>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn diff_lists_turns_after_the_fork_point() {
+        let ours = vec![turn("plan", "ok"), turn("use sqlite\nplease", "done")];
+        let theirs = vec![turn("plan", "ok")];
+
+        let lines = branch_diff_lines("experiment", &ours, &theirs, 1, Language::En);
+
+        assert_eq!(
+            plain(&lines),
+            vec![
+                "Both branches share 1 turn(s).".to_string(),
+                String::new(),
+                "Current branch".to_string(),
+                "  › use sqlite".to_string(),
+                "    done".to_string(),
+                String::new(),
+                "experiment".to_string(),
+                "  (no further turns)".to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,377 @@
+//! Functions and types touched by a patch, for `/symbols`.
+//!
+//! The files of the last applied patch are parsed with tree-sitter and every
+//! definition whose lines overlap the patch is listed. A definition is only
+//! listed when the change is not fully explained by a definition nested in
+//! it, so editing one method lists the method rather than its whole class.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_core::protocol::FileChange;
+use tree_sitter::Language;
+use tree_sitter::Node;
+use tree_sitter::Parser;
+
+/// Files larger than this are skipped rather than parsed.
+const MAX_SOURCE_BYTES: usize = 2 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ChangedSymbol {
+    /// Name qualified by its enclosing type, e.g. `App::render`.
+    pub(crate) name: String,
+    /// Definition keyword in the file's language, e.g. `fn` or `class`.
+    pub(crate) kind: &'static str,
+    pub(crate) path: PathBuf,
+    /// 1-based line of the symbol's name.
+    pub(crate) line: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SourceLanguage {
+    Rust,
+    Python,
+    Go,
+    JavaScript,
+    TypeScript,
+    Tsx,
+}
+
+impl SourceLanguage {
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "rs" => Some(Self::Rust),
+            "py" | "pyi" => Some(Self::Python),
+            "go" => Some(Self::Go),
+            "js" | "mjs" | "cjs" | "jsx" => Some(Self::JavaScript),
+            "ts" | "mts" | "cts" => Some(Self::TypeScript),
+            "tsx" => Some(Self::Tsx),
+            _ => None,
+        }
+    }
+
+    fn grammar(self) -> Language {
+        match self {
+            Self::Rust => tree_sitter_rust::LANGUAGE.into(),
+            Self::Python => tree_sitter_python::LANGUAGE.into(),
+            Self::Go => tree_sitter_go::LANGUAGE.into(),
+            Self::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
+            Self::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            Self::Tsx => tree_sitter_typescript::LANGUAGE_TSX.into(),
+        }
+    }
+
+    /// Keyword shown for a definition node, or `None` when `node` is not a
+    /// definition in this language.
+    fn definition_keyword(self, node: Node<'_>) -> Option<&'static str> {
+        match self {
+            Self::Rust => match node.kind() {
+                "function_item" | "function_signature_item" => Some("fn"),
+                "struct_item" => Some("struct"),
+                "enum_item" => Some("enum"),
+                "union_item" => Some("union"),
+                "trait_item" => Some("trait"),
+                "type_item" => Some("type"),
+                "const_item" => Some("const"),
+                "static_item" => Some("static"),
+                "macro_definition" => Some("macro_rules!"),
+                _ => None,
+            },
+            Self::Python => match node.kind() {
+                "function_definition" => Some("def"),
+                "class_definition" => Some("class"),
+                _ => None,
+            },
+            Self::Go => match node.kind() {
+                "function_declaration" | "method_declaration" => Some("func"),
+                "type_spec" => Some("type"),
+                _ => None,
+            },
+            Self::JavaScript | Self::TypeScript | Self::Tsx => match node.kind() {
+                "function_declaration" | "generator_function_declaration" => Some("function"),
+                "class_declaration" | "abstract_class_declaration" => Some("class"),
+                "method_definition" => Some("method"),
+                "interface_declaration" => Some("interface"),
+                "type_alias_declaration" => Some("type"),
+                "enum_declaration" => Some("enum"),
+                // `const handler = () => {}` and friends.
+                "variable_declarator"
+                    if node.child_by_field_name("value").is_some_and(|value| {
+                        matches!(value.kind(), "arrow_function" | "function_expression")
+                    }) =>
+                {
+                    Some("function")
+                }
+                _ => None,
+            },
+        }
+    }
+
+    fn scope_separator(self) -> &'static str {
+        match self {
+            Self::Rust => "::",
+            _ => ".",
+        }
+    }
+}
+
+/// Symbols touched by `changes`, ordered by path and line. Relative paths are
+/// resolved against `cwd`; files in unsupported languages are skipped.
+pub(crate) fn changed_symbols(
+    changes: &HashMap<PathBuf, FileChange>,
+    cwd: &Path,
+) -> Vec<ChangedSymbol> {
+    let mut symbols = Vec::new();
+    for (path, change) in changes {
+        let (path, source, rows) = match change {
+            FileChange::Add { content } => {
+                let rows = (0..content.lines().count()).collect();
+                (cwd.join(path), content.clone(), rows)
+            }
+            FileChange::Update {
+                unified_diff,
+                move_path,
+            } => {
+                let path = cwd.join(move_path.as_ref().unwrap_or(path));
+                let Ok(source) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                (path, source, changed_rows(unified_diff))
+            }
+            FileChange::Delete { .. } => continue,
+        };
+        let Some(language) = SourceLanguage::from_path(&path) else {
+            continue;
+        };
+        if source.len() > MAX_SOURCE_BYTES || rows.is_empty() {
+            continue;
+        }
+        symbols.extend(symbols_in_rows(&source, language, &rows).into_iter().map(
+            |(kind, name, row)| ChangedSymbol {
+                name,
+                kind,
+                path: path.clone(),
+                line: u32::try_from(row + 1).unwrap_or(u32::MAX),
+            },
+        ));
+    }
+    symbols.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+    symbols
+}
+
+/// 0-based rows of the new file that a unified diff inserted, or next to
+/// which it deleted lines.
+fn changed_rows(unified_diff: &str) -> BTreeSet<usize> {
+    let mut rows = BTreeSet::new();
+    let Ok(patch) = diffy::Patch::from_str(unified_diff) else {
+        return rows;
+    };
+    for hunk in patch.hunks() {
+        let mut row = hunk.new_range().start().saturating_sub(1);
+        for line in hunk.lines() {
+            match line {
+                diffy::Line::Insert(_) => {
+                    rows.insert(row);
+                    row += 1;
+                }
+                diffy::Line::Delete(_) => {
+                    rows.insert(row);
+                }
+                diffy::Line::Context(_) => row += 1,
+            }
+        }
+    }
+    rows
+}
+
+/// `(keyword, qualified name, name row)` of each definition in `source`
+/// touched by `rows`.
+fn symbols_in_rows(
+    source: &str,
+    language: SourceLanguage,
+    rows: &BTreeSet<usize>,
+) -> Vec<(&'static str, String, usize)> {
+    let mut parser = Parser::new();
+    if parser.set_language(&language.grammar()).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(source, None) else {
+        return Vec::new();
+    };
+    let mut walker = Walker {
+        source,
+        language,
+        rows,
+        out: Vec::new(),
+    };
+    walker.visit(tree.root_node(), None);
+    let mut symbols = walker.out;
+    symbols.sort_by_key(|(_, _, row)| *row);
+    symbols
+}
+
+struct Walker<'a> {
+    source: &'a str,
+    language: SourceLanguage,
+    rows: &'a BTreeSet<usize>,
+    out: Vec<(&'static str, String, usize)>,
+}
+
+impl Walker<'_> {
+    /// Visit `node` and return the touched rows it accounts for through the
+    /// definitions listed within it.
+    fn visit(&mut self, node: Node<'_>, scope: Option<&str>) -> BTreeSet<usize> {
+        let start = node.start_position().row;
+        let end = node.end_position().row;
+        if self.rows.range(start..=end).next().is_none() {
+            return BTreeSet::new();
+        }
+
+        let definition = self.language.definition_keyword(node).and_then(|keyword| {
+            let name_node = node.child_by_field_name("name")?;
+            let name = self.text(name_node)?;
+            let qualified = match self.receiver_type(node).as_deref().or(scope) {
+                Some(scope) => format!("{scope}{}{name}", self.language.scope_separator()),
+                None => name.to_string(),
+            };
+            Some((keyword, qualified, name_node.start_position().row))
+        });
+        let child_scope = match &definition {
+            Some((_, qualified, _)) => Some(qualified.clone()),
+            None => self.impl_type(node),
+        };
+        let child_scope = child_scope.as_deref().or(scope);
+
+        let mut explained = BTreeSet::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            explained.extend(self.visit(child, child_scope));
+        }
+
+        if let Some(definition) = definition {
+            let own: BTreeSet<usize> = self.rows.range(start..=end).copied().collect();
+            if !own.is_subset(&explained) {
+                self.out.push(definition);
+                return own;
+            }
+        }
+        explained
+    }
+
+    fn text(&self, node: Node<'_>) -> Option<&str> {
+        node.utf8_text(self.source.as_bytes()).ok()
+    }
+
+    /// Type named by a Rust `impl` block, which scopes the methods in it.
+    fn impl_type(&self, node: Node<'_>) -> Option<String> {
+        if self.language != SourceLanguage::Rust || node.kind() != "impl_item" {
+            return None;
+        }
+        let ty = self.text(node.child_by_field_name("type")?)?;
+        Some(ty.split('<').next().unwrap_or(ty).to_string())
+    }
+
+    /// Receiver type of a Go method, e.g. `Server` for `func (s *Server) Run()`.
+    fn receiver_type(&self, node: Node<'_>) -> Option<String> {
+        if self.language != SourceLanguage::Go || node.kind() != "method_declaration" {
+            return None;
+        }
+        let receiver = self.text(node.child_by_field_name("receiver")?)?;
+        let ty = receiver
+            .trim_matches(|c| c == '(' || c == ')')
+            .split_whitespace()
+            .last()?
+            .trim_start_matches('*');
+        Some(ty.split('[').next().unwrap_or(ty).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn names(
+        source: &str,
+        language: SourceLanguage,
+        rows: &[usize],
+    ) -> Vec<(&'static str, String)> {
+        let rows = rows.iter().copied().collect();
+        symbols_in_rows(source, language, &rows)
+            .into_iter()
+            .map(|(kind, name, _)| (kind, name))
+            .collect()
+    }
+
+    #[test]
+    fn changed_rows_follow_the_new_file() {
+        let diff = "@@ -2,3 +2,4 @@\n a\n-b\n+c\n+d\n e\n@@ -10,2 +11,1 @@\n x\n-y\n";
+        assert_eq!(
+            changed_rows(diff).into_iter().collect::<Vec<_>>(),
+            vec![2, 3, 11]
+        );
+    }
+
+    #[test]
+    fn lists_the_innermost_rust_definition() {
+        let source = "\
+struct Point {
+    x: i32,
+}
+
+impl Point {
+    fn norm(&self) -> i32 {
+        self.x.abs()
+    }
+}
+
+fn untouched() {}
+";
+        assert_eq!(
+            names(source, SourceLanguage::Rust, &[6]),
+            vec![("fn", "Point::norm".to_string())]
+        );
+        assert_eq!(
+            names(source, SourceLanguage::Rust, &[1, 6]),
+            vec![
+                ("struct", "Point".to_string()),
+                ("fn", "Point::norm".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn lists_the_class_when_the_change_is_outside_its_methods() {
+        let source = "\
+class Greeter:
+    greeting = 'hi'
+
+    def greet(self, name):
+        return f'{self.greeting} {name}'
+";
+        assert_eq!(
+            names(source, SourceLanguage::Python, &[4]),
+            vec![("def", "Greeter.greet".to_string())]
+        );
+        assert_eq!(
+            names(source, SourceLanguage::Python, &[1]),
+            vec![("class", "Greeter".to_string())]
+        );
+    }
+
+    #[test]
+    fn qualifies_go_methods_and_typescript_arrow_functions() {
+        let go = "package main\n\ntype Server struct{}\n\nfunc (s *Server) Run() error {\n\treturn nil\n}\n";
+        assert_eq!(
+            names(go, SourceLanguage::Go, &[5]),
+            vec![("func", "Server.Run".to_string())]
+        );
+        let ts = "export const handler = (event: Event): void => {\n  console.log(event);\n};\n";
+        assert_eq!(
+            names(ts, SourceLanguage::TypeScript, &[1]),
+            vec![("function", "handler".to_string())]
+        );
+    }
+}
@@ -40,6 +40,7 @@ use crate::bottom_pane::StatusLineItem;
 use crate::bottom_pane::StatusLineSetupView;
 use crate::i18n::tr;
 use crate::i18n::tr_args;
+use crate::status::InterruptedResponses;
 use crate::status::RateLimitWindowDisplay;
 use crate::status::format_directory_display;
 use crate::status::format_tokens_compact;
@@ -51,14 +52,18 @@ use codex_chatgpt::connectors;
 use codex_core::config::Config;
 use codex_core::config::ConstraintResult;
 use codex_core::config::types::Notifications;
+use codex_core::config::types::TranscriptDensity;
 use codex_core::config_loader::ConfigLayerStackOrdering;
+use codex_core::danger_audit;
 use codex_core::features::FEATURES;
 use codex_core::features::Feature;
 use codex_core::find_thread_name_by_id;
 use codex_core::git_info::current_branch_name;
 use codex_core::git_info::get_git_repo_root;
 use codex_core::git_info::local_git_branches;
+use codex_core::locale_format::format_limit_window;
 use codex_core::models_manager::manager::ModelsManager;
+use codex_core::personas::available_personas;
 use codex_core::project_doc::DEFAULT_PROJECT_DOC_FILENAME;
 use codex_core::protocol::AgentMessageDeltaEvent;
 use codex_core::protocol::AgentMessageEvent;
@@ -67,10 +72,14 @@ use codex_core::protocol::AgentReasoningEvent;
 use codex_core::protocol::AgentReasoningRawContentDeltaEvent;
 use codex_core::protocol::AgentReasoningRawContentEvent;
 use codex_core::protocol::ApplyPatchApprovalRequestEvent;
+use codex_core::protocol::ApprovalTimedOutEvent;
 use codex_core::protocol::BackgroundEventEvent;
 use codex_core::protocol::CodexErrorInfo;
+use codex_core::protocol::ContextBreakdownEvent;
+use codex_core::protocol::ContextComponentKind;
 use codex_core::protocol::CreditsSnapshot;
 use codex_core::protocol::DeprecationNoticeEvent;
+use codex_core::protocol::DryRunPlanEvent;
 use codex_core::protocol::ErrorEvent;
 use codex_core::protocol::Event;
 use codex_core::protocol::EventMsg;
@@ -80,6 +89,9 @@ use codex_core::protocol::ExecCommandEndEvent;
 use codex_core::protocol::ExecCommandOutputDeltaEvent;
 use codex_core::protocol::ExecCommandSource;
 use codex_core::protocol::ExitedReviewModeEvent;
+use codex_core::protocol::FileChange;
+use codex_core::protocol::GuardrailBlockedEvent;
+use codex_core::protocol::GuardrailReason;
 use codex_core::protocol::ListCustomPromptsResponseEvent;
 use codex_core::protocol::ListSkillsResponseEvent;
 use codex_core::protocol::McpListToolsResponseEvent;
@@ -90,9 +102,14 @@ use codex_core::protocol::McpToolCallBeginEvent;
 use codex_core::protocol::McpToolCallEndEvent;
 use codex_core::protocol::Op;
 use codex_core::protocol::PatchApplyBeginEvent;
+use codex_core::protocol::PatchProposalEvent;
 use codex_core::protocol::RateLimitSnapshot;
+use codex_core::protocol::ReviewDecision;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
+use codex_core::protocol::SessionChangesEvent;
+use codex_core::protocol::SessionNoteEvent;
+use codex_core::protocol::SessionSummaryEvent;
 use codex_core::protocol::SkillMetadata as ProtocolSkillMetadata;
 use codex_core::protocol::StreamErrorEvent;
 use codex_core::protocol::TerminalInteractionEvent;
@@ -101,6 +118,7 @@ use codex_core::protocol::TokenUsageInfo;
 use codex_core::protocol::TurnAbortReason;
 use codex_core::protocol::TurnCompleteEvent;
 use codex_core::protocol::TurnDiffEvent;
+use codex_core::protocol::TurnSourcesEvent;
 use codex_core::protocol::UndoCompletedEvent;
 use codex_core::protocol::UndoStartedEvent;
 use codex_core::protocol::UserMessageEvent;
@@ -109,8 +127,14 @@ use codex_core::protocol::WarningEvent;
 use codex_core::protocol::WebSearchBeginEvent;
 use codex_core::protocol::WebSearchEndEvent;
 use codex_core::skills::model::SkillMetadata;
+use codex_core::usage_cache::load_cached_usage;
+use codex_core::usage_cache::persist_usage;
 #[cfg(target_os = "windows")]
 use codex_core::windows_sandbox::WindowsSandboxLevelExt;
+use codex_login::ServerOptions;
+use codex_login::complete_device_code_login;
+use codex_login::request_device_code;
+use codex_login::run_login_server;
 use codex_otel::OtelManager;
 use codex_otel::RuntimeMetricsSummary;
 use codex_protocol::ThreadId;
@@ -165,12 +189,14 @@ use crate::app_event::WindowsSandboxEnableMode;
 use crate::app_event::WindowsSandboxFallbackReason;
 use crate::app_event_sender::AppEventSender;
 use crate::bottom_pane::ApprovalRequest;
+use crate::bottom_pane::ApprovalTimeout;
 use crate::bottom_pane::BottomPane;
 use crate::bottom_pane::BottomPaneParams;
 use crate::bottom_pane::CancellationEvent;
 use crate::bottom_pane::CollaborationModeIndicator;
 use crate::bottom_pane::ColumnWidthMode;
 use crate::bottom_pane::DOUBLE_PRESS_QUIT_SHORTCUT_ENABLED;
+use crate::bottom_pane::DryRunPlanView;
 use crate::bottom_pane::ExperimentalFeatureItem;
 use crate::bottom_pane::ExperimentalFeaturesView;
 use crate::bottom_pane::FeedbackAudience;
@@ -182,10 +208,17 @@ use crate::bottom_pane::SelectionAction;
 use crate::bottom_pane::SelectionItem;
 use crate::bottom_pane::SelectionViewParams;
 use crate::bottom_pane::custom_prompt_view::CustomPromptView;
+use crate::bottom_pane::permissions_chip_line;
 use crate::bottom_pane::popup_consts::standard_popup_hint_line;
+use crate::changed_symbols;
+use crate::changed_symbols::ChangedSymbol;
+use crate::clipboard_paste;
+use crate::clipboard_paste::PasteImageError;
+use crate::clipboard_paste::PastedImageInfo;
 use crate::clipboard_paste::paste_image_to_temp_png;
 use crate::collab;
 use crate::collaboration_modes;
+use crate::diagram;
 use crate::diff_render::display_path_for;
 use crate::exec_cell::CommandOutput;
 use crate::exec_cell::ExecCell;
@@ -210,7 +243,9 @@ use crate::render::renderable::RenderableItem;
 use crate::slash_command::SlashCommand;
 use crate::status::RateLimitSnapshotDisplay;
 use crate::text_formatting::truncate_text;
+use crate::transcript_links::LinkTarget;
 use crate::tui::FrameRequester;
+use crate::tui::OUTPUT_FRAME_INTERVAL;
 mod interrupts;
 use self::interrupts::InterruptManager;
 mod agent;
@@ -231,16 +266,23 @@ use crate::streaming::commit_tick::run_commit_tick;
 use crate::streaming::controller::PlanStreamController;
 use crate::streaming::controller::StreamController;
 
+use chrono::DateTime;
 use chrono::Local;
+use chrono::Utc;
 use codex_common::approval_presets::ApprovalPreset;
 use codex_common::approval_presets::builtin_approval_presets;
 use codex_core::AuthManager;
 use codex_core::CodexAuth;
 use codex_core::ThreadManager;
+use codex_core::auth::CLIENT_ID;
+use codex_core::auth::TokenRefreshHealth;
+use codex_core::env::is_headless_environment;
 use codex_core::protocol::AskForApproval;
 use codex_core::protocol::SandboxPolicy;
+use codex_core::workspace_packages::Workspace;
 use codex_file_search::FileMatch;
 use codex_protocol::openai_models::InputModality;
+use codex_protocol::openai_models::ModelCapabilities;
 use codex_protocol::openai_models::ModelPreset;
 use codex_protocol::openai_models::ReasoningEffort as ReasoningEffortConfig;
 use codex_protocol::plan_tool::UpdatePlanArgs;
@@ -249,6 +291,8 @@ use strum::IntoEnumIterator;
 const USER_SHELL_COMMAND_HELP_TITLE: &str = "Prefix a command with ! to run it locally";
 const USER_SHELL_COMMAND_HELP_HINT: &str = "Example: !ls";
 const DEFAULT_OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+/// Written to the session cwd by the "Export patch" entry of `/changes`.
+const SESSION_PATCH_FILENAME: &str = "codex-session.patch";
 // Track information about an in-flight exec command.
 struct RunningCommand {
     command: Vec<String>,
@@ -315,6 +359,8 @@ fn is_standard_tool_call(parsed_cmd: &[ParsedCommand]) -> bool {
 
 const RATE_LIMIT_WARNING_THRESHOLDS: [f64; 3] = [75.0, 90.0, 95.0];
 const NUDGE_MODEL_SLUG: &str = "gpt-5.1-codex-mini";
+/// How long the model/effort chip stays in the footer after `ctrl+r`.
+const REASONING_CHIP_DURATION: Duration = Duration::from_secs(3);
 const RATE_LIMIT_SWITCH_PROMPT_THRESHOLD: f64 = 90.0;
 
 #[derive(Default)]
@@ -383,25 +429,7 @@ impl RateLimitWarningState {
 }
 
 pub(crate) fn get_limits_duration(windows_minutes: i64) -> String {
-    const MINUTES_PER_HOUR: i64 = 60;
-    const MINUTES_PER_DAY: i64 = 24 * MINUTES_PER_HOUR;
-    const MINUTES_PER_WEEK: i64 = 7 * MINUTES_PER_DAY;
-    const MINUTES_PER_MONTH: i64 = 30 * MINUTES_PER_DAY;
-    const ROUNDING_BIAS_MINUTES: i64 = 3;
-
-    let windows_minutes = windows_minutes.max(0);
-
-    if windows_minutes <= MINUTES_PER_DAY.saturating_add(ROUNDING_BIAS_MINUTES) {
-        let adjusted = windows_minutes.saturating_add(ROUNDING_BIAS_MINUTES);
-        let hours = std::cmp::max(1, adjusted / MINUTES_PER_HOUR);
-        format!("{hours}h")
-    } else if windows_minutes <= MINUTES_PER_WEEK.saturating_add(ROUNDING_BIAS_MINUTES) {
-        "weekly".to_string()
-    } else if windows_minutes <= MINUTES_PER_MONTH.saturating_add(ROUNDING_BIAS_MINUTES) {
-        "monthly".to_string()
-    } else {
-        "annual".to_string()
-    }
+    format_limit_window(Language::En, windows_minutes)
 }
 
 /// Common initialization parameters shared by all `ChatWidget` constructors.
@@ -466,6 +494,36 @@ fn rate_limit_error_kind(info: &CodexErrorInfo) -> Option<RateLimitErrorKind> {
     }
 }
 
+/// Reloads the auth manager after a successful `/login` and builds the cell
+/// reporting the outcome.
+fn relogin_result_cell(
+    auth_manager: &AuthManager,
+    language: Language,
+    result: std::io::Result<()>,
+) -> Box<dyn HistoryCell> {
+    match result {
+        Ok(()) => {
+            auth_manager.reload();
+            Box::new(history_cell::new_info_event(
+                tr(language, "chatwidget.login.succeeded").to_string(),
+                None,
+            ))
+        }
+        Err(err) => Box::new(history_cell::new_error_event(tr_args(
+            language,
+            "chatwidget.login.failed",
+            &[("error", &err.to_string())],
+        ))),
+    }
+}
+
+/// Sign-in problem the last `/login` warning was shown for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ReloginWarning {
+    RefreshDue,
+    Expired,
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub(crate) enum ExternalEditorState {
     #[default]
@@ -528,6 +586,9 @@ pub(crate) struct ChatWidget {
     suppressed_exec_calls: HashSet<String>,
     skills_all: Vec<ProtocolSkillMetadata>,
     skills_initial_state: Option<HashMap<PathBuf, bool>>,
+    // Repository skills trusted during this session, on top of `[skills].trusted`.
+    skills_trusted: HashSet<PathBuf>,
+    skills_trust_prompted: bool,
     last_unified_wait: Option<UnifiedExecWaitState>,
     unified_exec_wait_streak: Option<UnifiedExecWaitStreak>,
     task_complete_pending: bool,
@@ -560,6 +621,12 @@ pub(crate) struct ChatWidget {
     thread_id: Option<ThreadId>,
     thread_name: Option<String>,
     forked_from: Option<ThreadId>,
+    // Exec output deltas (call id, text) not yet applied to the active cell.
+    pending_exec_output: Vec<(String, String)>,
+    // Responses cut off by interrupts, for `/status`.
+    interrupted_responses: InterruptedResponses,
+    // Last sign-in warning shown; cleared when tokens recover.
+    relogin_warning: Option<ReloginWarning>,
     frame_requester: FrameRequester,
     // Whether to include the initial welcome banner on session configured
     show_welcome_banner: bool,
@@ -588,6 +655,8 @@ pub(crate) struct ChatWidget {
     // The separator itself is only rendered if the turn recorded "work" activity (see
     // `had_work_activity`).
     needs_final_message_separator: bool,
+    // Model to compare the next prompt against (`/compare <model>`).
+    pending_compare_model: Option<String>,
     // Whether the current turn performed "work" (exec commands, MCP tool calls, patch applications).
     //
     // This gates rendering of the "Worked for …" separator so purely conversational turns don't
@@ -597,6 +666,18 @@ pub(crate) struct ChatWidget {
     saw_plan_update_this_turn: bool,
     // Whether the current turn emitted a proposed plan item.
     saw_plan_item_this_turn: bool,
+    // Final message of the last completed turn; `/diagram` renders from it.
+    last_agent_message: Option<String>,
+    // Changes of the last patch applied; `/symbols` lists what it touched.
+    last_patch: Option<HashMap<PathBuf, FileChange>>,
+    // Notes saved with `/note`, including ones replayed from the rollout.
+    session_notes: Vec<String>,
+    // Whether a live turn completed; quitting only summarizes sessions that did work.
+    completed_live_turn: bool,
+    // Set once quitting has asked core for the exit summary.
+    exit_summary_requested: bool,
+    // Bullets of the exit summary, printed after the UI closes.
+    exit_summary_bullets: Vec<String>,
     // Incremental buffer for streamed plan content.
     plan_delta_buffer: String,
     // True while a plan item is streaming.
@@ -817,10 +898,10 @@ impl ChatWidget {
     }
 
     fn flush_answer_stream_with_separator(&mut self) {
-        if let Some(mut controller) = self.stream_controller.take()
-            && let Some(cell) = controller.finalize()
-        {
-            self.add_boxed_history(cell);
+        if let Some(mut controller) = self.stream_controller.take() {
+            for cell in controller.finalize() {
+                self.add_boxed_history(cell);
+            }
         }
         self.adaptive_chunking.reset();
     }
@@ -859,7 +940,17 @@ impl ChatWidget {
     /// Update the status indicator header and details.
     ///
     /// Passing `None` clears any existing details.
+    ///
+    /// In accessible mode a changed header is also appended to the transcript,
+    /// since the in-place status row is not rendered there.
     fn set_status(&mut self, header: String, details: Option<String>) {
+        if header != self.current_status_header {
+            self.bottom_pane.announce(vec![tr_args(
+                self.config.language,
+                "accessibility.status",
+                &[("status", header.as_str())],
+            )]);
+        }
         self.current_status_header = header.clone();
         self.bottom_pane.update_status(header, details);
     }
@@ -1042,6 +1133,7 @@ impl ChatWidget {
         );
         self.refresh_model_display();
         self.sync_personality_command_enabled();
+        self.refresh_permissions_chip();
         let session_info_cell = history_cell::new_session_info(
             &self.config,
             &model_for_header,
@@ -1055,14 +1147,16 @@ impl ChatWidget {
 
         if let Some(messages) = initial_messages {
             self.replay_initial_messages(messages);
+            self.warn_if_resumed_context_window_shrank();
         }
+        self.maybe_warn_relogin();
         // Ask codex-core to enumerate custom prompts for this session.
         self.submit_op(Op::ListCustomPrompts);
         self.submit_op(Op::ListSkills {
             cwds: Vec::new(),
             force_reload: true,
         });
-        if self.connectors_enabled() {
+        if self.connectors_enabled() && !self.config.offline {
             self.prefetch_connectors();
         }
         if let Some(user_message) = self.initial_user_message.take() {
@@ -1286,8 +1380,10 @@ impl ChatWidget {
         // At the end of a reasoning block, record transcript-only content.
         self.full_reasoning_buffer.push_str(&self.reasoning_buffer);
         if !self.full_reasoning_buffer.is_empty() {
-            let cell =
-                history_cell::new_reasoning_summary_block(self.full_reasoning_buffer.clone());
+            let cell = history_cell::new_reasoning_block(
+                self.full_reasoning_buffer.clone(),
+                self.config.tui_density,
+            );
             self.add_boxed_history(cell);
         }
         self.reasoning_buffer.clear();
@@ -1321,6 +1417,8 @@ impl ChatWidget {
         self.retry_status_header = None;
         self.pending_status_indicator_restore = false;
         self.bottom_pane.set_interrupt_hint_visible(true);
+        // Every turn starts from a fresh header so accessible mode announces it.
+        self.current_status_header.clear();
         self.set_status_header(String::from("Working"));
         self.full_reasoning_buffer.clear();
         self.reasoning_buffer.clear();
@@ -1328,6 +1426,9 @@ impl ChatWidget {
     }
 
     fn on_task_complete(&mut self, last_agent_message: Option<String>, from_replay: bool) {
+        if !from_replay {
+            self.maybe_warn_relogin();
+        }
         // If a stream is currently active, finalize it.
         self.flush_answer_stream_with_separator();
         if let Some(mut controller) = self.plan_stream_controller.take()
@@ -1377,9 +1478,13 @@ impl ChatWidget {
         // still show the prompt once after thread switch replay.
         if !from_replay {
             self.saw_plan_item_this_turn = false;
+            self.completed_live_turn = true;
         }
         // If there is a queued user message, send exactly one now to begin the next turn.
         self.maybe_send_next_queued_input();
+        if last_agent_message.is_some() {
+            self.last_agent_message.clone_from(&last_agent_message);
+        }
         // Emit a notification when the turn completes (suppressed if focused).
         self.notify(Notification::AgentTurnComplete {
             response: last_agent_message.unwrap_or_default(),
@@ -1467,6 +1572,7 @@ impl ChatWidget {
             Some(info) => self.apply_token_info(info),
             None => {
                 self.bottom_pane.set_context_window(None, None);
+                self.bottom_pane.set_context_budget(None, None);
                 self.token_info = None;
             }
         }
@@ -1476,6 +1582,11 @@ impl ChatWidget {
         let percent = self.context_remaining_percent(&info);
         let used_tokens = self.context_used_tokens(&info, percent.is_some());
         self.bottom_pane.set_context_window(percent, used_tokens);
+        self.bottom_pane.set_context_budget(
+            Some(info.last_token_usage.tokens_in_context_window()),
+            info.model_context_window
+                .or(self.config.model_context_window),
+        );
         self.token_info = Some(info);
     }
 
@@ -1500,12 +1611,33 @@ impl ChatWidget {
                 Some(info) => self.apply_token_info(info),
                 None => {
                     self.bottom_pane.set_context_window(None, None);
+                    self.bottom_pane.set_context_budget(None, None);
                     self.token_info = None;
                 }
             }
         }
     }
 
+    /// Show rate limits saved by an earlier fetch. They are labelled with
+    /// their fetch time, so `/status` marks them stale once they age, and they
+    /// never replace a live snapshot or raise usage warnings.
+    pub(crate) fn on_cached_rate_limit_snapshot(
+        &mut self,
+        snapshot: RateLimitSnapshot,
+        fetched_at: DateTime<Utc>,
+    ) {
+        if self.rate_limit_snapshot.is_some() {
+            return;
+        }
+        self.plan_type = snapshot.plan_type.or(self.plan_type);
+        self.rate_limit_snapshot = Some(crate::status::rate_limit_snapshot_display(
+            &snapshot,
+            fetched_at.with_timezone(&Local),
+            self.config.language,
+        ));
+        self.refresh_status_line();
+    }
+
     pub(crate) fn on_rate_limit_snapshot(&mut self, snapshot: Option<RateLimitSnapshot>) {
         if let Some(mut snapshot) = snapshot {
             if snapshot.credits.is_none() {
@@ -1621,6 +1753,7 @@ impl ChatWidget {
     fn on_error(&mut self, message: String) {
         self.finalize_turn();
         self.add_to_history(history_cell::new_error_event(message));
+        self.maybe_warn_relogin();
         self.request_redraw();
 
         // After an error ends the turn, try sending the next queued input.
@@ -1855,7 +1988,34 @@ impl ChatWidget {
         if !self.bottom_pane.is_task_running() {
             return;
         }
+        let chunk = std::str::from_utf8(&ev.chunk).unwrap_or("");
+        if chunk.is_empty() {
+            return;
+        }
+
+        // Chatty commands send thousands of deltas per turn; buffer them and
+        // apply the batch once per frame.
+        if self.pending_exec_output.is_empty() {
+            self.frame_requester
+                .schedule_frame_in(OUTPUT_FRAME_INTERVAL);
+        }
+        match self.pending_exec_output.last_mut() {
+            Some((call_id, pending)) if *call_id == ev.call_id => pending.push_str(chunk),
+            _ => self
+                .pending_exec_output
+                .push((ev.call_id, chunk.to_string())),
+        }
+    }
 
+    /// Apply exec output buffered since the last frame to the active cell.
+    ///
+    /// Runs before each draw, and before any other event or key is handled so
+    /// that they observe the output in the order it arrived.
+    pub(crate) fn flush_pending_exec_output(&mut self) {
+        if self.pending_exec_output.is_empty() {
+            return;
+        }
+        let pending = std::mem::take(&mut self.pending_exec_output);
         let Some(cell) = self
             .active_cell
             .as_mut()
@@ -1864,9 +2024,12 @@ impl ChatWidget {
             return;
         };
 
-        if cell.append_output(&ev.call_id, std::str::from_utf8(&ev.chunk).unwrap_or("")) {
+        let mut appended = false;
+        for (call_id, chunk) in pending {
+            appended |= cell.append_output(&call_id, &chunk);
+        }
+        if appended {
             self.bump_active_cell_revision();
-            self.request_redraw();
         }
     }
 
@@ -1922,12 +2085,119 @@ impl ChatWidget {
     }
 
     fn on_patch_apply_begin(&mut self, event: PatchApplyBeginEvent) {
+        self.last_patch = Some(event.changes.clone());
+        self.add_to_history(history_cell::new_patch_event(
+            event.changes,
+            &self.config.cwd,
+        ));
+    }
+
+    fn on_patch_proposal(&mut self, event: PatchProposalEvent) {
+        let language = self.config.language;
+        self.add_info_message(
+            tr(language, "explore_mode.proposal").to_string(),
+            Some(tr(language, "explore_mode.proposal_hint").to_string()),
+        );
         self.add_to_history(history_cell::new_patch_event(
             event.changes,
             &self.config.cwd,
         ));
     }
 
+    fn on_dry_run_plan(&mut self, event: DryRunPlanEvent) {
+        self.flush_answer_stream_with_separator();
+        let count = event.calls.len().to_string();
+        let mut lines: Vec<Line<'static>> = vec![
+            vec![
+                "• ".dim(),
+                tr_args(
+                    self.config.language,
+                    "dry_run.plan.title",
+                    &[("count", count.as_str())],
+                )
+                .bold(),
+            ]
+            .into(),
+        ];
+        for call in &event.calls {
+            let summary = call.summary.lines().next().unwrap_or_default().to_string();
+            lines.push(Line::from(vec![
+                "  - ".dim(),
+                call.tool_name.clone().cyan(),
+                " ".into(),
+                summary.into(),
+            ]));
+        }
+        self.add_plain_history_lines(lines);
+        let view = DryRunPlanView::new(event, self.app_event_tx.clone(), self.config.language);
+        self.bottom_pane.show_view(Box::new(view));
+        self.request_redraw();
+    }
+
+    fn set_dry_run(&mut self, enabled: bool) {
+        self.submit_op(Op::SetDryRun { enabled });
+        let key = if enabled {
+            "dry_run.armed"
+        } else {
+            "dry_run.disarmed"
+        };
+        self.add_info_message(tr(self.config.language, key).to_string(), None);
+    }
+
+    fn on_approval_timed_out(&mut self, event: ApprovalTimedOutEvent) {
+        self.bottom_pane.dismiss_approval_request(&event.call_id);
+        let outcome = match event.decision {
+            ReviewDecision::Approved => "approved as safe under the sandbox policy",
+            ReviewDecision::Abort => "denied and the turn was interrupted",
+            _ => "denied",
+        };
+        self.add_info_message(
+            format!(
+                "Approval timed out after {}s: {outcome}",
+                event.timeout_seconds
+            ),
+            Some(format!("approval_timeout_action = \"{}\"", event.action)),
+        );
+    }
+
+    fn on_guardrail_blocked(&mut self, event: GuardrailBlockedEvent) {
+        self.flush_answer_stream_with_separator();
+        let language = self.config.language;
+        let mut lines: Vec<Line<'static>> = vec![
+            vec![
+                "■ ".red(),
+                tr(language, "guardrail.blocked.title").red().bold(),
+            ]
+            .into(),
+        ];
+        for path in &event.paths {
+            lines.push(Line::from(vec![
+                "  - ".dim(),
+                display_path_for(path, &self.config.cwd).into(),
+            ]));
+        }
+        let (rule, note) = match event.reason {
+            GuardrailReason::ProtectedPaths => {
+                let patterns = event.patterns.join(", ");
+                (
+                    tr_args(
+                        language,
+                        "guardrail.blocked.patterns",
+                        &[("patterns", patterns.as_str())],
+                    ),
+                    "guardrail.blocked.note",
+                )
+            }
+            GuardrailReason::EditLimits => (
+                tr(language, "guardrail.blocked.edit_limits").to_string(),
+                "guardrail.blocked.edit_limits_note",
+            ),
+        };
+        lines.push(Line::from(vec!["  ".into(), rule.dim()]));
+        lines.push(Line::from(vec!["  ".into(), tr(language, note).dim()]));
+        self.add_plain_history_lines(lines);
+    }
+
     fn on_view_image_tool_call(&mut self, event: ViewImageToolCallEvent) {
         self.flush_answer_stream_with_separator();
         self.add_to_history(history_cell::new_view_image_tool_call(
@@ -2070,6 +2340,7 @@ impl ChatWidget {
             call_id,
             query,
             action,
+            citations,
         } = ev;
         let mut handled = false;
         if let Some(cell) = self
@@ -2078,7 +2349,7 @@ impl ChatWidget {
             .and_then(|cell| cell.as_any_mut().downcast_mut::<WebSearchCell>())
             && cell.call_id() == call_id
         {
-            cell.update(action.clone(), query.clone());
+            cell.update(action.clone(), query.clone(), citations.clone());
             cell.complete();
             self.bump_active_cell_revision();
             self.flush_active_cell();
@@ -2086,7 +2357,9 @@ impl ChatWidget {
         }
 
         if !handled {
-            self.add_to_history(history_cell::new_web_search_call(call_id, query, action));
+            self.add_to_history(history_cell::new_web_search_call(
+                call_id, query, action, citations,
+            ));
         }
         self.had_work_activity = true;
     }
@@ -2114,11 +2387,67 @@ impl ChatWidget {
         self.request_immediate_exit();
     }
 
+    /// Start a shutdown-first quit. With `exit_summary` on and a turn completed
+    /// in this run, core is asked for the session summary first and shutdown
+    /// follows once it arrives; quitting again while it is pending skips it.
+    pub(crate) fn begin_shutdown(&mut self) {
+        if self.config.exit_summary && self.completed_live_turn && !self.exit_summary_requested {
+            self.exit_summary_requested = true;
+            let language = self.config.language;
+            self.add_info_message(
+                tr(language, "session_summary.pending").to_string(),
+                Some(tr(language, "session_summary.skip_hint").to_string()),
+            );
+            self.submit_op(Op::SummarizeSession);
+            return;
+        }
+        self.submit_op(Op::Shutdown);
+    }
+
+    fn on_session_summary(&mut self, event: SessionSummaryEvent, from_replay: bool) {
+        if from_replay {
+            // Show the summary written when the session was last quit.
+            if !event.bullets.is_empty() {
+                let mut lines: Vec<Line<'static>> = vec![
+                    vec![
+                        "• ".dim(),
+                        tr(self.config.language, "session_summary.previous").bold(),
+                    ]
+                    .into(),
+                ];
+                lines.extend(
+                    event
+                        .bullets
+                        .into_iter()
+                        .map(|bullet| Line::from(vec!["  - ".dim(), bullet.into()])),
+                );
+                self.add_plain_history_lines(lines);
+            }
+            return;
+        }
+        self.exit_summary_bullets = event.bullets;
+        if self.exit_summary_requested {
+            self.submit_op(Op::Shutdown);
+        }
+    }
+
+    pub(crate) fn exit_summary_bullets(&self) -> Vec<String> {
+        self.exit_summary_bullets.clone()
+    }
+
     fn on_turn_diff(&mut self, unified_diff: String) {
         debug!("TurnDiffEvent: {unified_diff}");
         self.refresh_status_line();
     }
 
+    fn on_turn_sources(&mut self, event: TurnSourcesEvent) {
+        if event.citations.is_empty() {
+            return;
+        }
+        self.flush_answer_stream_with_separator();
+        self.add_to_history(history_cell::new_sources_cell(event.citations));
+    }
+
     fn on_deprecation_notice(&mut self, event: DeprecationNoticeEvent) {
         let DeprecationNoticeEvent { summary, details } = event;
         self.add_to_history(history_cell::new_deprecation_notice(summary, details));
@@ -2325,15 +2654,19 @@ impl ChatWidget {
             .unwrap_or(true);
         if needs_new {
             self.flush_active_cell();
-            self.active_cell = Some(Box::new(new_active_exec_command(
-                ev.call_id.clone(),
-                command,
-                parsed,
-                source,
-                ev.interaction_input.clone(),
-                self.config.animations,
-                self.config.language,
-            )));
+            self.active_cell = Some(Box::new(
+                new_active_exec_command(
+                    ev.call_id.clone(),
+                    command,
+                    parsed,
+                    source,
+                    ev.interaction_input.clone(),
+                    self.config.animations,
+                    self.config.language,
+                )
+                .with_output_max_lines(self.config.tui_exec_output_lines)
+                .with_density(self.config.tui_density),
+            ));
         }
 
         if let Some(cell) = self
@@ -2344,14 +2677,18 @@ impl ChatWidget {
             let output = if is_unified_exec_interaction {
                 CommandOutput {
                     exit_code: ev.exit_code,
+                    timed_out: ev.timed_out,
                     formatted_output: String::new(),
                     aggregated_output: String::new(),
+                    provenance: ev.provenance.clone(),
                 }
             } else {
                 CommandOutput {
                     exit_code: ev.exit_code,
+                    timed_out: ev.timed_out,
                     formatted_output: ev.formatted_output.clone(),
                     aggregated_output: ev.aggregated_output.clone(),
+                    provenance: ev.provenance.clone(),
                 }
             };
             cell.complete_call(&ev.call_id, output, ev.duration);
@@ -2373,6 +2710,7 @@ impl ChatWidget {
         // If the patch was successful, just let the "Edited" block stand.
         // Otherwise, add a failure block.
         if !event.success {
+            self.last_patch = None;
             self.add_to_history(history_cell::new_patch_apply_failure(event.stderr));
         }
         // Mark that actual work was done (patch applied)
@@ -2487,15 +2825,19 @@ impl ChatWidget {
         } else {
             self.flush_active_cell();
 
-            self.active_cell = Some(Box::new(new_active_exec_command(
-                ev.call_id.clone(),
-                ev.command.clone(),
-                ev.parsed_cmd,
-                ev.source,
-                interaction_input,
-                self.config.animations,
-                self.config.language,
-            )));
+            self.active_cell = Some(Box::new(
+                new_active_exec_command(
+                    ev.call_id.clone(),
+                    ev.command.clone(),
+                    ev.parsed_cmd,
+                    ev.source,
+                    interaction_input,
+                    self.config.animations,
+                    self.config.language,
+                )
+                .with_output_max_lines(self.config.tui_exec_output_lines)
+                .with_density(self.config.tui_density),
+            ));
             self.bump_active_cell_revision();
         }
 
@@ -2609,6 +2951,7 @@ impl ChatWidget {
                 placeholder_text: placeholder,
                 disable_paste_burst: config.disable_paste_burst,
                 animations_enabled: config.animations,
+                accessible: config.tui_accessible,
                 skills: None,
                 language: config.language,
             }),
@@ -2617,6 +2960,8 @@ impl ChatWidget {
             config,
             skills_all: Vec::new(),
             skills_initial_state: None,
+            skills_trusted: HashSet::new(),
+            skills_trust_prompted: false,
             current_collaboration_mode,
             active_collaboration_mask,
             auth_manager,
@@ -2652,6 +2997,9 @@ impl ChatWidget {
             thread_id: None,
             thread_name: None,
             forked_from: None,
+            pending_exec_output: Vec::new(),
+            interrupted_responses: InterruptedResponses::default(),
+            relogin_warning: None,
             queued_user_messages: VecDeque::new(),
             show_welcome_banner: is_first_run,
             suppress_session_configured_redraw: false,
@@ -2661,9 +3009,16 @@ impl ChatWidget {
             is_review_mode: false,
             pre_review_token_info: None,
             needs_final_message_separator: false,
+            pending_compare_model: None,
             had_work_activity: false,
             saw_plan_update_this_turn: false,
             saw_plan_item_this_turn: false,
+            last_agent_message: None,
+            last_patch: None,
+            session_notes: Vec::new(),
+            completed_live_turn: false,
+            exit_summary_requested: false,
+            exit_summary_bullets: Vec::new(),
             plan_delta_buffer: String::new(),
             plan_item_active: false,
             last_separator_elapsed_secs: None,
@@ -2697,6 +3052,7 @@ impl ChatWidget {
             widget.config.features.enabled(Feature::CollaborationModes),
         );
         widget.sync_personality_command_enabled();
+        widget.sync_approval_timeout();
         #[cfg(target_os = "windows")]
         widget.bottom_pane.set_windows_degraded_sandbox_active(
             codex_core::windows_sandbox::ELEVATED_SANDBOX_NUX_ENABLED
@@ -2775,6 +3131,7 @@ impl ChatWidget {
                 placeholder_text: placeholder,
                 disable_paste_burst: config.disable_paste_burst,
                 animations_enabled: config.animations,
+                accessible: config.tui_accessible,
                 skills: None,
                 language: config.language,
             }),
@@ -2783,6 +3140,8 @@ impl ChatWidget {
             config,
             skills_all: Vec::new(),
             skills_initial_state: None,
+            skills_trusted: HashSet::new(),
+            skills_trust_prompted: false,
             current_collaboration_mode,
             active_collaboration_mask,
             auth_manager,
@@ -2818,8 +3177,17 @@ impl ChatWidget {
             thread_id: None,
             thread_name: None,
             forked_from: None,
+            pending_exec_output: Vec::new(),
+            interrupted_responses: InterruptedResponses::default(),
+            relogin_warning: None,
             saw_plan_update_this_turn: false,
             saw_plan_item_this_turn: false,
+            last_agent_message: None,
+            last_patch: None,
+            session_notes: Vec::new(),
+            completed_live_turn: false,
+            exit_summary_requested: false,
+            exit_summary_bullets: Vec::new(),
             plan_delta_buffer: String::new(),
             plan_item_active: false,
             queued_user_messages: VecDeque::new(),
@@ -2831,6 +3199,7 @@ impl ChatWidget {
             is_review_mode: false,
             pre_review_token_info: None,
             needs_final_message_separator: false,
+            pending_compare_model: None,
             had_work_activity: false,
             last_separator_elapsed_secs: None,
             turn_runtime_metrics: RuntimeMetricsSummary::default(),
@@ -2863,6 +3232,7 @@ impl ChatWidget {
             widget.config.features.enabled(Feature::CollaborationModes),
         );
         widget.sync_personality_command_enabled();
+        widget.sync_approval_timeout();
 
         widget
     }
@@ -2930,6 +3300,7 @@ impl ChatWidget {
                 placeholder_text: placeholder,
                 disable_paste_burst: config.disable_paste_burst,
                 animations_enabled: config.animations,
+                accessible: config.tui_accessible,
                 skills: None,
                 language: config.language,
             }),
@@ -2938,6 +3309,8 @@ impl ChatWidget {
             config,
             skills_all: Vec::new(),
             skills_initial_state: None,
+            skills_trusted: HashSet::new(),
+            skills_trust_prompted: false,
             current_collaboration_mode,
             active_collaboration_mask,
             auth_manager,
@@ -2973,6 +3346,9 @@ impl ChatWidget {
             thread_id: None,
             thread_name: None,
             forked_from: None,
+            pending_exec_output: Vec::new(),
+            interrupted_responses: InterruptedResponses::default(),
+            relogin_warning: None,
             queued_user_messages: VecDeque::new(),
             show_welcome_banner: false,
             suppress_session_configured_redraw: true,
@@ -2982,9 +3358,16 @@ impl ChatWidget {
             is_review_mode: false,
             pre_review_token_info: None,
             needs_final_message_separator: false,
+            pending_compare_model: None,
             had_work_activity: false,
             saw_plan_update_this_turn: false,
             saw_plan_item_this_turn: false,
+            last_agent_message: None,
+            last_patch: None,
+            session_notes: Vec::new(),
+            completed_live_turn: false,
+            exit_summary_requested: false,
+            exit_summary_bullets: Vec::new(),
             plan_delta_buffer: String::new(),
             plan_item_active: false,
             last_separator_elapsed_secs: None,
@@ -3018,6 +3401,7 @@ impl ChatWidget {
             widget.config.features.enabled(Feature::CollaborationModes),
         );
         widget.sync_personality_command_enabled();
+        widget.sync_approval_timeout();
         #[cfg(target_os = "windows")]
         widget.bottom_pane.set_windows_degraded_sandbox_active(
             codex_core::windows_sandbox::ELEVATED_SANDBOX_NUX_ENABLED
@@ -3032,6 +3416,7 @@ impl ChatWidget {
     }
 
     pub(crate) fn handle_key_event(&mut self, key_event: KeyEvent) {
+        self.flush_pending_exec_output();
         match key_event {
             KeyEvent {
                 code: KeyCode::Char(c),
@@ -3064,21 +3449,10 @@ impl ChatWidget {
                 && c.eq_ignore_ascii_case(&'v') =>
             {
                 match paste_image_to_temp_png() {
-                    Ok((path, info)) => {
-                        tracing::debug!(
-                            "pasted image size={}x{} format={}",
-                            info.width,
-                            info.height,
-                            info.encoded_format.label()
-                        );
-                        self.attach_image(path);
-                    }
-                    Err(err) => {
-                        tracing::warn!("failed to paste image: {err}");
-                        self.add_to_history(history_cell::new_error_event(format!(
-                            "Failed to paste image: {err}",
-                        )));
+                    Err(err) if clipboard_paste::osc52_fallback_applies(&err) => {
+                        self.app_event_tx.send(AppEvent::PasteImageViaOsc52(err));
                     }
+                    result => self.on_image_paste_result(result),
                 }
                 return;
             }
@@ -3156,6 +3530,26 @@ impl ChatWidget {
                     };
                     self.queue_user_message(user_message);
                 }
+                InputResult::CompactThenQueued {
+                    text,
+                    text_elements,
+                } => {
+                    let user_message = UserMessage {
+                        text,
+                        local_images: self
+                            .bottom_pane
+                            .take_recent_submission_images_with_placeholders(),
+                        text_elements,
+                        mention_bindings: self
+                            .bottom_pane
+                            .take_recent_submission_mention_bindings(),
+                    };
+                    // The draft waits in the queue and is sent when the
+                    // compaction task completes.
+                    self.queued_user_messages.push_back(user_message);
+                    self.refresh_queued_user_messages();
+                    self.dispatch_command(SlashCommand::Compact);
+                }
                 InputResult::Command(cmd) => {
                     self.dispatch_command(cmd);
                 }
@@ -3184,6 +3578,134 @@ impl ChatWidget {
         self.request_redraw();
     }
 
+    /// Render the mermaid / Graphviz blocks of the last agent message in the
+    /// background; results come back as `AppEvent::DiagramsRendered`.
+    fn render_last_diagrams(&mut self) {
+        let blocks = self
+            .last_agent_message
+            .as_deref()
+            .map(diagram::extract_diagram_blocks)
+            .unwrap_or_default();
+        if blocks.is_empty() {
+            self.add_info_message(tr(self.config.language, "diagram.none").to_string(), None);
+            return;
+        }
+        self.add_info_message(
+            tr_args(
+                self.config.language,
+                "diagram.rendering",
+                &[("count", &blocks.len().to_string())],
+            ),
+            None,
+        );
+        let tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let rendered = tokio::task::spawn_blocking(move || {
+                blocks
+                    .iter()
+                    .map(diagram::render_diagram)
+                    .collect::<Vec<_>>()
+            })
+            .await;
+            match rendered {
+                Ok(results) => tx.send(AppEvent::DiagramsRendered(results)),
+                Err(err) => tracing::warn!("diagram rendering task failed: {err}"),
+            }
+        });
+    }
+
+    /// Find the symbols touched by the last patch in the background; results
+    /// come back as `AppEvent::ChangedSymbolsFound`.
+    fn find_changed_symbols(&mut self) {
+        let Some(changes) = self.last_patch.clone() else {
+            self.add_info_message(
+                tr(self.config.language, "changed_symbols.no_patch").to_string(),
+                None,
+            );
+            return;
+        };
+        let cwd = self.config.cwd.clone();
+        let tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let found = tokio::task::spawn_blocking(move || {
+                changed_symbols::changed_symbols(&changes, &cwd)
+            })
+            .await;
+            match found {
+                Ok(symbols) => tx.send(AppEvent::ChangedSymbolsFound(symbols)),
+                Err(err) => tracing::warn!("changed symbol search failed: {err}"),
+            }
+        });
+    }
+
+    /// Let the user pick one of `symbols` to open in their editor.
+    pub(crate) fn show_changed_symbols(&mut self, symbols: Vec<ChangedSymbol>) {
+        let language = self.config.language;
+        if symbols.is_empty() {
+            self.add_info_message(tr(language, "changed_symbols.none").to_string(), None);
+            return;
+        }
+        let items = symbols
+            .into_iter()
+            .map(|symbol| {
+                let location = format!(
+                    "{}:{}",
+                    display_path_for(&symbol.path, &self.config.cwd),
+                    symbol.line
+                );
+                let target = LinkTarget::File {
+                    path: symbol.path.to_string_lossy().into_owned(),
+                    line: Some(symbol.line),
+                };
+                let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+                    tx.send(AppEvent::OpenTranscriptLink(target.clone()));
+                })];
+                SelectionItem {
+                    name: symbol.name,
+                    description: Some(format!("{} · {location}", symbol.kind)),
+                    actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "changed_symbols.title").to_string()),
+            subtitle: Some(tr(language, "changed_symbols.subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    pub(crate) fn on_image_paste_result(
+        &mut self,
+        result: Result<(PathBuf, PastedImageInfo), PasteImageError>,
+    ) {
+        match result {
+            Ok((path, info)) => {
+                tracing::debug!(
+                    "pasted image size={}x{} format={}",
+                    info.width,
+                    info.height,
+                    info.encoded_format.label()
+                );
+                self.attach_image(path);
+            }
+            Err(err) => {
+                tracing::warn!("failed to paste image: {err}");
+                let detail = err.to_message(self.config.language);
+                self.add_to_history(history_cell::new_error_event(tr_args(
+                    self.config.language,
+                    "chatwidget.clipboard.image_paste_failed",
+                    &[("detail", &detail)],
+                )));
+                self.request_redraw();
+            }
+        }
+    }
+
     pub(crate) fn composer_text_with_pending(&self) -> String {
         self.bottom_pane.composer_text_with_pending()
     }
@@ -3250,6 +3772,12 @@ impl ChatWidget {
             SlashCommand::Fork => {
                 self.app_event_tx.send(AppEvent::ForkCurrentSession);
             }
+            SlashCommand::Branches => {
+                self.app_event_tx.send(AppEvent::OpenBranches);
+            }
+            SlashCommand::Compare => {
+                self.add_error_message(tr(self.config.language, "compare.usage").to_string());
+            }
             SlashCommand::Init => {
                 let init_target = self.config.cwd.join(DEFAULT_PROJECT_DOC_FILENAME);
                 if init_target.exists() {
@@ -3279,6 +3807,9 @@ impl ChatWidget {
                 self.otel_manager.counter("codex.thread.rename", 1, &[]);
                 self.show_rename_prompt();
             }
+            SlashCommand::Note => {
+                self.open_session_notes();
+            }
             SlashCommand::Model => {
                 self.open_model_popup();
             }
@@ -3297,6 +3828,12 @@ impl ChatWidget {
             SlashCommand::Personality => {
                 self.open_personality_popup();
             }
+            SlashCommand::Persona => {
+                self.open_persona_popup();
+            }
+            SlashCommand::Profile => {
+                self.open_profile_popup();
+            }
             SlashCommand::Plan => {
                 if !self.collaboration_modes_enabled() {
                     self.add_info_message(
@@ -3311,6 +3848,12 @@ impl ChatWidget {
                     self.add_info_message("Plan mode unavailable right now.".to_string(), None);
                 }
             }
+            SlashCommand::Mode => {
+                self.show_explore_mode_status();
+            }
+            SlashCommand::DryRun => {
+                self.set_dry_run(true);
+            }
             SlashCommand::Collab => {
                 if !self.collaboration_modes_enabled() {
                     self.add_info_message(
@@ -3381,6 +3924,9 @@ impl ChatWidget {
             SlashCommand::Quit | SlashCommand::Exit => {
                 self.request_quit_without_confirmation();
             }
+            SlashCommand::Login => {
+                self.start_relogin();
+            }
             SlashCommand::Logout => {
                 if let Err(e) = codex_core::auth::logout(
                     &self.config.codex_home,
@@ -3411,9 +3957,24 @@ impl ChatWidget {
                     tx.send(AppEvent::DiffResult(text));
                 });
             }
+            SlashCommand::Diagram => {
+                self.render_last_diagrams();
+            }
+            SlashCommand::Changes => {
+                self.submit_op(Op::ListSessionChanges);
+            }
+            SlashCommand::Symbols => {
+                self.find_changed_symbols();
+            }
+            SlashCommand::Context => {
+                self.submit_op(Op::InspectContext);
+            }
             SlashCommand::Mention => {
                 self.insert_str("@");
             }
+            SlashCommand::Scope => {
+                self.open_scope_popup();
+            }
             SlashCommand::Skills => {
                 self.open_skills_menu();
             }
@@ -3432,12 +3993,35 @@ impl ChatWidget {
             SlashCommand::Statusline => {
                 self.open_status_line_setup();
             }
+            SlashCommand::Density => {
+                self.add_info_message(
+                    tr_args(
+                        self.config.language,
+                        "density.current",
+                        &[("density", self.config.tui_density.as_str())],
+                    ),
+                    None,
+                );
+            }
+            SlashCommand::Ui => {
+                self.add_info_message(
+                    tr_args(
+                        self.config.language,
+                        "ui.current",
+                        &[("current", "TUI1"), ("other", "TUI2")],
+                    ),
+                    None,
+                );
+            }
             SlashCommand::Ps => {
                 self.add_ps_output();
             }
             SlashCommand::Clean => {
                 self.clean_background_terminals();
             }
+            SlashCommand::Follow => {
+                self.open_follow_in_pane_picker();
+            }
             SlashCommand::Mcp => {
                 self.add_mcp_output();
             }
@@ -3459,7 +4043,6 @@ impl ChatWidget {
                 use std::collections::HashMap;
 
                 use codex_core::protocol::ApplyPatchApprovalRequestEvent;
-                use codex_core::protocol::FileChange;
 
                 self.app_event_tx.send(AppEvent::CodexEvent(Event {
                     id: "1".to_string(),
@@ -3495,6 +4078,40 @@ impl ChatWidget {
         }
     }
 
+    fn set_explore_mode(&mut self, enabled: bool) {
+        self.config.explore_mode = enabled;
+        self.submit_op(Op::OverrideTurnContext {
+            cwd: None,
+            approval_policy: None,
+            sandbox_policy: None,
+            windows_sandbox_level: None,
+            model: None,
+            effort: None,
+            summary: None,
+            collaboration_mode: None,
+            personality: None,
+            spec_parallel_priority: None,
+            spec_sdd_planning: None,
+            explore_mode: Some(enabled),
+            persona: None,
+            scope: None,
+        });
+        self.show_explore_mode_status();
+    }
+
+    fn show_explore_mode_status(&mut self) {
+        let language = self.config.language;
+        let (message, hint) = if self.config.explore_mode {
+            ("explore_mode.on", "explore_mode.on_hint")
+        } else {
+            ("explore_mode.off", "explore_mode.off_hint")
+        };
+        self.add_info_message(
+            tr(language, message).to_string(),
+            Some(tr(language, hint).to_string()),
+        );
+    }
+
     fn dispatch_command_with_args(
         &mut self,
         cmd: SlashCommand,
@@ -3535,6 +4152,41 @@ impl ChatWidget {
                     .send(AppEvent::CodexOp(Op::SetThreadName { name }));
                 self.bottom_pane.drain_pending_submission_state();
             }
+            SlashCommand::Note if !trimmed.is_empty() => {
+                let Some((prepared_args, _prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(false)
+                else {
+                    return;
+                };
+                self.submit_op(Op::AddNote {
+                    text: prepared_args.trim().to_string(),
+                });
+                self.bottom_pane.drain_pending_submission_state();
+            }
+            SlashCommand::Compare if !trimmed.is_empty() => {
+                let Some((prepared_args, _prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(false)
+                else {
+                    return;
+                };
+                self.bottom_pane.drain_pending_submission_state();
+                if !self.rollout_path().is_some_and(|path| path.exists()) {
+                    self.add_error_message(
+                        tr(self.config.language, "compare.not_ready").to_string(),
+                    );
+                    return;
+                }
+                let model_b = prepared_args.trim().to_string();
+                self.add_info_message(
+                    tr_args(
+                        self.config.language,
+                        "compare.pending",
+                        &[("model_a", self.current_model()), ("model_b", &model_b)],
+                    ),
+                    None,
+                );
+                self.pending_compare_model = Some(model_b);
+            }
             SlashCommand::Plan if !trimmed.is_empty() => {
                 self.dispatch_command(cmd);
                 if self.active_mode_kind() != ModeKind::Plan {
@@ -3562,17 +4214,133 @@ impl ChatWidget {
                     self.queue_user_message(user_message);
                 }
             }
-            SlashCommand::Review if !trimmed.is_empty() => {
+            SlashCommand::Mode if !trimmed.is_empty() => {
                 let Some((prepared_args, _prepared_elements)) =
                     self.bottom_pane.prepare_inline_args_submission(false)
                 else {
                     return;
                 };
-                self.submit_op(Op::Review {
-                    review_request: ReviewRequest {
-                        target: ReviewTarget::Custom {
-                            instructions: prepared_args,
-                        },
+                match prepared_args.trim() {
+                    "explore" => self.set_explore_mode(true),
+                    "default" => self.set_explore_mode(false),
+                    other => self.add_error_message(tr_args(
+                        self.config.language,
+                        "explore_mode.unknown",
+                        &[("mode", other)],
+                    )),
+                }
+                self.bottom_pane.drain_pending_submission_state();
+            }
+            SlashCommand::DryRun if trimmed == "off" => {
+                if self
+                    .bottom_pane
+                    .prepare_inline_args_submission(false)
+                    .is_none()
+                {
+                    return;
+                }
+                self.bottom_pane.drain_pending_submission_state();
+                self.set_dry_run(false);
+            }
+            SlashCommand::DryRun if !trimmed.is_empty() => {
+                let Some((prepared_args, prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(true)
+                else {
+                    return;
+                };
+                // Arm first: ops reach the session in order, so the dry run
+                // applies to exactly this prompt's turn.
+                self.submit_op(Op::SetDryRun { enabled: true });
+                let user_message = UserMessage {
+                    text: prepared_args,
+                    local_images: self
+                        .bottom_pane
+                        .take_recent_submission_images_with_placeholders(),
+                    text_elements: prepared_elements,
+                    mention_bindings: self.bottom_pane.take_recent_submission_mention_bindings(),
+                };
+                if self.is_session_configured() {
+                    self.reasoning_buffer.clear();
+                    self.full_reasoning_buffer.clear();
+                    self.set_status_header(String::from("Working"));
+                    self.submit_user_message(user_message);
+                } else {
+                    self.queue_user_message(user_message);
+                }
+            }
+            SlashCommand::Density if !trimmed.is_empty() => {
+                let Some((prepared_args, _prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(false)
+                else {
+                    return;
+                };
+                self.bottom_pane.drain_pending_submission_state();
+                match prepared_args.parse::<TranscriptDensity>() {
+                    Ok(density) => {
+                        self.app_event_tx
+                            .send(AppEvent::UpdateTranscriptDensity(density));
+                        self.app_event_tx
+                            .send(AppEvent::PersistTranscriptDensity { density });
+                        self.add_info_message(
+                            tr_args(
+                                self.config.language,
+                                "density.set",
+                                &[("density", density.as_str())],
+                            ),
+                            None,
+                        );
+                    }
+                    Err(_) => {
+                        self.add_error_message(
+                            tr(self.config.language, "density.usage").to_string(),
+                        );
+                    }
+                }
+            }
+            SlashCommand::Ui if !trimmed.is_empty() => {
+                let Some((prepared_args, _prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(false)
+                else {
+                    return;
+                };
+                self.bottom_pane.drain_pending_submission_state();
+                if prepared_args.trim() == "switch" {
+                    self.switch_frontend();
+                } else {
+                    self.add_error_message(tr(self.config.language, "ui.usage").to_string());
+                }
+            }
+            SlashCommand::Profile if !trimmed.is_empty() => {
+                let Some((prepared_args, _prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(false)
+                else {
+                    return;
+                };
+                self.bottom_pane.drain_pending_submission_state();
+                self.app_event_tx.send(AppEvent::SwitchProfile {
+                    profile: Some(prepared_args.trim().to_string()),
+                });
+            }
+            SlashCommand::Scope if !trimmed.is_empty() => {
+                let Some((prepared_args, _prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(false)
+                else {
+                    return;
+                };
+                self.bottom_pane.drain_pending_submission_state();
+                self.scope_to(prepared_args.trim());
+            }
+            SlashCommand::Review if !trimmed.is_empty() => {
+                let Some((prepared_args, _prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(false)
+                else {
+                    return;
+                };
+                self.submit_op(Op::Review {
+                    review_request: ReviewRequest {
+                        target: ReviewTarget::Custom {
+                            instructions: prepared_args,
+                        },
                         user_facing_hint: None,
                     },
                 });
@@ -3582,6 +4350,25 @@ impl ChatWidget {
         }
     }
 
+    /// Hands the thread to TUI2. Resuming needs a rollout on disk, which only
+    /// exists once the first turn has been recorded.
+    fn switch_frontend(&mut self) {
+        let language = self.config.language;
+        if self.thread_id.is_none() || !self.rollout_path().is_some_and(|path| path.exists()) {
+            self.add_error_message(tr_args(
+                language,
+                "ui.not_ready",
+                &[("flag", "--tui2"), ("other", "TUI2")],
+            ));
+            return;
+        }
+        self.add_info_message(
+            tr_args(language, "ui.switching", &[("other", "TUI2")]),
+            None,
+        );
+        self.app_event_tx.send(AppEvent::SwitchFrontend);
+    }
+
     fn show_rename_prompt(&mut self) {
         let tx = self.app_event_tx.clone();
         let has_name = self
@@ -3842,9 +4629,14 @@ impl ChatWidget {
             personality,
         };
 
-        self.codex_op_tx.send(op).unwrap_or_else(|e| {
-            tracing::error!("failed to send message: {e}");
-        });
+        if let Some(model_b) = self.pending_compare_model.take() {
+            self.app_event_tx
+                .send(AppEvent::StartCompare { op, model_b });
+        } else {
+            self.codex_op_tx.send(op).unwrap_or_else(|e| {
+                tracing::error!("failed to send message: {e}");
+            });
+        }
 
         // Persist the text to cross-session message history.
         if !text.is_empty() {
@@ -3941,6 +4733,9 @@ impl ChatWidget {
     /// `replay_initial_messages()`. Callers should treat `None` as a "fake" id
     /// that must not be used to correlate follow-up actions.
     fn dispatch_event_msg(&mut self, id: Option<String>, msg: EventMsg, from_replay: bool) {
+        if !matches!(msg, EventMsg::ExecCommandOutputDelta(_)) {
+            self.flush_pending_exec_output();
+        }
         let is_stream_error = matches!(&msg, EventMsg::StreamError(_));
         if !is_stream_error {
             self.restore_retry_status_header_if_present();
@@ -4006,17 +4801,23 @@ impl ChatWidget {
             }
             EventMsg::McpStartupUpdate(ev) => self.on_mcp_startup_update(ev),
             EventMsg::McpStartupComplete(ev) => self.on_mcp_startup_complete(ev),
-            EventMsg::TurnAborted(ev) => match ev.reason {
-                TurnAbortReason::Interrupted => {
-                    self.on_interrupted_turn(ev.reason);
+            EventMsg::TurnAborted(ev) => {
+                if let Some(tokens) = ev.cut_off_output_tokens {
+                    self.interrupted_responses.count += 1;
+                    self.interrupted_responses.streamed_tokens += tokens;
                 }
-                TurnAbortReason::Replaced => {
-                    self.on_error("Turn aborted: replaced by a new task".to_owned())
-                }
-                TurnAbortReason::ReviewEnded => {
-                    self.on_interrupted_turn(ev.reason);
+                match ev.reason {
+                    TurnAbortReason::Interrupted => {
+                        self.on_interrupted_turn(ev.reason);
+                    }
+                    TurnAbortReason::Replaced => {
+                        self.on_error("Turn aborted: replaced by a new task".to_owned())
+                    }
+                    TurnAbortReason::ReviewEnded => {
+                        self.on_interrupted_turn(ev.reason);
+                    }
                 }
-            },
+            }
             EventMsg::PlanUpdate(update) => self.on_plan_update(update),
             EventMsg::ExecApprovalRequest(ev) => {
                 // For replayed events, synthesize an empty id (these should not occur).
@@ -4035,6 +4836,10 @@ impl ChatWidget {
             EventMsg::TerminalInteraction(delta) => self.on_terminal_interaction(delta),
             EventMsg::ExecCommandOutputDelta(delta) => self.on_exec_command_output_delta(delta),
             EventMsg::PatchApplyBegin(ev) => self.on_patch_apply_begin(ev),
+            EventMsg::PatchProposal(ev) => self.on_patch_proposal(ev),
+            EventMsg::DryRunPlan(ev) => self.on_dry_run_plan(ev),
+            EventMsg::ApprovalTimedOut(ev) => self.on_approval_timed_out(ev),
+            EventMsg::GuardrailBlocked(ev) => self.on_guardrail_blocked(ev),
             EventMsg::PatchApplyEnd(ev) => self.on_patch_apply_end(ev),
             EventMsg::ExecCommandEnd(ev) => self.on_exec_command_end(ev),
             EventMsg::ViewImageToolCall(ev) => self.on_view_image_tool_call(ev),
@@ -4055,6 +4860,11 @@ impl ChatWidget {
             }
             EventMsg::ShutdownComplete => self.on_shutdown_complete(),
             EventMsg::TurnDiff(TurnDiffEvent { unified_diff }) => self.on_turn_diff(unified_diff),
+            EventMsg::TurnSources(ev) => self.on_turn_sources(ev),
+            EventMsg::SessionChanges(ev) => self.on_session_changes(ev),
+            EventMsg::SessionNote(ev) => self.on_session_note(ev),
+            EventMsg::SessionSummary(ev) => self.on_session_summary(ev, from_replay),
+            EventMsg::ContextBreakdown(ev) => self.on_context_breakdown(ev),
             EventMsg::DeprecationNotice(ev) => self.on_deprecation_notice(ev),
             EventMsg::BackgroundEvent(BackgroundEventEvent { message }) => {
                 self.on_background_event(message)
@@ -4289,6 +5099,7 @@ impl ChatWidget {
             self.model_display_name(),
             collaboration_mode,
             reasoning_effort_override,
+            self.interrupted_responses,
         ));
     }
 
@@ -4404,8 +5215,11 @@ impl ChatWidget {
         match item {
             StatusLineItem::ModelName => Some(self.model_display_name().to_string()),
             StatusLineItem::ModelWithReasoning => {
-                let label =
-                    Self::status_line_reasoning_effort_label(self.effective_reasoning_effort());
+                let capabilities = self.current_model_capabilities();
+                let effort = self.effective_reasoning_effort().filter(|effort| {
+                    Self::capabilities_allow_effort(capabilities.as_ref(), *effort)
+                });
+                let label = Self::status_line_reasoning_effort_label(effort);
                 Some(format!("{} {label}", self.model_display_name()))
             }
             StatusLineItem::CurrentDir => {
@@ -4535,6 +5349,71 @@ impl ChatWidget {
         self.add_to_history(history_cell::new_unified_exec_processes_output(processes));
     }
 
+    /// `/follow`: pick the running command or a background terminal and
+    /// follow its output in a tmux or Zellij pane.
+    fn open_follow_in_pane_picker(&mut self) {
+        let language = self.config.language;
+        if codex_core::terminal::terminal_info().multiplexer.is_none() {
+            self.add_error_message(crate::multiplexer_panes::no_multiplexer_message(language));
+            return;
+        }
+        let mut targets: Vec<(String, String, String, &str)> = Vec::new();
+        if let Some((call_id, command, output)) = self.running_exec_output() {
+            targets.push((call_id, command, output, "follow_pane.running_command"));
+        }
+        targets.extend(self.unified_exec_processes.iter().map(|process| {
+            let mut output = process.recent_chunks.join("\n");
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            (
+                process.call_id.clone(),
+                process.command_display.clone(),
+                output,
+                "follow_pane.background_terminal",
+            )
+        }));
+        if let [(call_id, command, output, _)] = targets.as_slice() {
+            self.app_event_tx.send(AppEvent::FollowInPane {
+                call_id: call_id.clone(),
+                command: command.clone(),
+                output: output.clone(),
+            });
+            return;
+        }
+        if targets.is_empty() {
+            self.add_info_message(
+                tr(language, "follow_pane.nothing_running").to_string(),
+                None,
+            );
+            return;
+        }
+        let items = targets
+            .into_iter()
+            .map(|(call_id, command, output, kind)| SelectionItem {
+                name: command.clone(),
+                description: Some(tr(language, kind).to_string()),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::FollowInPane {
+                        call_id: call_id.clone(),
+                        command: command.clone(),
+                        output: output.clone(),
+                    });
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            })
+            .collect();
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "follow_pane.title").to_string()),
+            subtitle: Some(tr(language, "follow_pane.subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
     fn clean_background_terminals(&mut self) {
         self.submit_op(Op::CleanBackgroundTerminals);
         self.add_info_message("Stopping all background terminals.".to_string(), None);
@@ -4617,17 +5496,45 @@ impl ChatWidget {
         }
 
         let base_url = self.config.chatgpt_base_url.clone();
+        let codex_home = self.config.codex_home.clone();
+        let offline = self.config.offline;
         let app_event_tx = self.app_event_tx.clone();
         let auth_manager = Arc::clone(&self.auth_manager);
 
         let handle = tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(60));
 
+            // Show the last saved snapshot right away; when it is recent
+            // enough, it also stands in for the first fetch.
+            let cached = match auth_manager.auth().await {
+                Some(auth) if auth.is_chatgpt_auth() => {
+                    load_cached_usage(&codex_home, auth.get_account_id().as_deref())
+                }
+                _ => None,
+            };
+            if let Some(cached) = cached {
+                let fresh = cached.is_fresh();
+                app_event_tx.send(AppEvent::CachedRateLimitSnapshotLoaded {
+                    snapshot: cached.snapshot,
+                    fetched_at: cached.fetched_at,
+                });
+                if fresh && !offline {
+                    interval.reset();
+                    interval.tick().await;
+                }
+            }
+            if offline {
+                return;
+            }
+
             loop {
                 if let Some(auth) = auth_manager.auth().await
                     && auth.is_chatgpt_auth()
-                    && let Some(snapshot) = fetch_rate_limits(base_url.clone(), auth).await
+                    && let Some(snapshot) = fetch_rate_limits(base_url.clone(), auth.clone()).await
                 {
+                    if let Err(err) = persist_usage(&codex_home, auth.get_account_id(), &snapshot) {
+                        debug!(error = ?err, "failed to save rate limits to the usage cache");
+                    }
                     app_event_tx.send(AppEvent::RateLimitSnapshotFetched(snapshot));
                 }
                 interval.tick().await;
@@ -4701,6 +5608,9 @@ impl ChatWidget {
                 spec_parallel_priority: None,
 
                 spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
             }));
             tx.send(AppEvent::UpdateModel(switch_model.clone()));
             tx.send(AppEvent::UpdateReasoningEffort(Some(default_effort)));
@@ -4824,6 +5734,9 @@ impl ChatWidget {
                         spec_parallel_priority: None,
 
                         spec_sdd_planning: None,
+                        explore_mode: None,
+                        persona: None,
+                        scope: None,
                     }));
                     tx.send(AppEvent::UpdatePersonality(personality));
                     tx.send(AppEvent::PersistPersonalitySelection { personality });
@@ -4852,6 +5765,211 @@ impl ChatWidget {
         });
     }
 
+    pub(crate) fn open_persona_popup(&mut self) {
+        let language = self.config.language;
+        let current = self.config.persona.clone();
+        let mut items: Vec<SelectionItem> = available_personas(&self.config)
+            .into_iter()
+            .map(|persona| {
+                let is_current = current.as_deref() == Some(persona.name.as_str());
+                SelectionItem {
+                    actions: Self::persona_selection_actions(Some(persona.name.clone())),
+                    name: persona.name,
+                    description: persona.description,
+                    is_current,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        items.push(SelectionItem {
+            name: tr(language, "chatwidget.persona_popup.none_name").to_string(),
+            description: Some(
+                tr(language, "chatwidget.persona_popup.none_description").to_string(),
+            ),
+            is_current: current.is_none(),
+            actions: Self::persona_selection_actions(None),
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "chatwidget.persona_popup.title").to_string()),
+            subtitle: Some(tr(language, "chatwidget.persona_popup.subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+    }
+
+    pub(crate) fn open_profile_popup(&mut self) {
+        let language = self.config.language;
+        let profiles = self.config.profile_names();
+        if profiles.is_empty() {
+            self.add_info_message(
+                tr(language, "chatwidget.profile_popup.empty").to_string(),
+                None,
+            );
+            return;
+        }
+        let current = self.config.active_profile.clone();
+        let mut items: Vec<SelectionItem> = profiles
+            .into_iter()
+            .map(|profile| SelectionItem {
+                is_current: current.as_deref() == Some(profile.as_str()),
+                actions: Self::profile_selection_actions(Some(profile.clone())),
+                name: profile,
+                dismiss_on_select: true,
+                ..Default::default()
+            })
+            .collect();
+        items.push(SelectionItem {
+            name: tr(language, "chatwidget.profile_popup.none_name").to_string(),
+            description: Some(
+                tr(language, "chatwidget.profile_popup.none_description").to_string(),
+            ),
+            is_current: current.is_none(),
+            actions: Self::profile_selection_actions(None),
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "chatwidget.profile_popup.title").to_string()),
+            subtitle: Some(tr(language, "chatwidget.profile_popup.subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+    }
+
+    fn profile_selection_actions(profile: Option<String>) -> Vec<SelectionAction> {
+        vec![Box::new(move |tx| {
+            tx.send(AppEvent::SwitchProfile {
+                profile: profile.clone(),
+            });
+        })]
+    }
+
+    fn persona_selection_actions(persona: Option<String>) -> Vec<SelectionAction> {
+        vec![Box::new(move |tx| {
+            tx.send(AppEvent::CodexOp(Op::OverrideTurnContext {
+                cwd: None,
+                approval_policy: None,
+                sandbox_policy: None,
+                windows_sandbox_level: None,
+                model: None,
+                effort: None,
+                summary: None,
+                collaboration_mode: None,
+                personality: None,
+                spec_parallel_priority: None,
+                spec_sdd_planning: None,
+                explore_mode: None,
+                persona: Some(persona.clone()),
+                scope: None,
+            }));
+            tx.send(AppEvent::UpdatePersona(persona.clone()));
+            tx.send(AppEvent::PersistPersonaSelection {
+                persona: persona.clone(),
+            });
+        })]
+    }
+
+    pub(crate) fn open_scope_popup(&mut self) {
+        let language = self.config.language;
+        let Some(workspace) = Workspace::discover(&self.config.cwd) else {
+            self.add_info_message(tr(language, "scope.no_workspace").to_string(), None);
+            return;
+        };
+        let current = self.config.scope.clone();
+        let mut items: Vec<SelectionItem> = vec![SelectionItem {
+            name: tr(language, "scope.whole_name").to_string(),
+            description: Some(tr(language, "scope.whole_description").to_string()),
+            is_current: current.is_none(),
+            actions: Self::scope_selection_actions(None),
+            dismiss_on_select: true,
+            ..Default::default()
+        }];
+        items.extend(workspace.packages.into_iter().map(|package| {
+            let is_current = current.as_deref() == Some(package.path.as_path());
+            SelectionItem {
+                description: Some(format!(
+                    "{} · {}",
+                    package.relative_path,
+                    package.kind.label()
+                )),
+                name: package.name.clone(),
+                search_value: Some(format!("{} {}", package.name, package.relative_path)),
+                is_current,
+                actions: Self::scope_selection_actions(Some((package.name, package.path))),
+                dismiss_on_select: true,
+                ..Default::default()
+            }
+        }));
+
+        let root = workspace.root.display().to_string();
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "scope.popup_title").to_string()),
+            subtitle: Some(tr_args(
+                language,
+                "scope.popup_subtitle",
+                &[("root", &root)],
+            )),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            is_searchable: true,
+            search_placeholder: Some(tr(language, "scope.search_placeholder").to_string()),
+            ..Default::default()
+        });
+    }
+
+    /// `/scope <package>` and `/scope off`.
+    fn scope_to(&mut self, query: &str) {
+        let language = self.config.language;
+        let Some(workspace) = Workspace::discover(&self.config.cwd) else {
+            self.add_info_message(tr(language, "scope.no_workspace").to_string(), None);
+            return;
+        };
+        let package = workspace
+            .find(query)
+            .map(|package| (package.name.clone(), package.path.clone()));
+        if package.is_none() && query != "off" {
+            self.add_error_message(tr_args(
+                language,
+                "scope.unknown_package",
+                &[("query", query)],
+            ));
+            return;
+        }
+        for action in Self::scope_selection_actions(package) {
+            action(&self.app_event_tx);
+        }
+    }
+
+    fn scope_selection_actions(package: Option<(String, PathBuf)>) -> Vec<SelectionAction> {
+        vec![Box::new(move |tx| {
+            let scope = package.as_ref().map(|(_, path)| path.clone());
+            tx.send(AppEvent::CodexOp(Op::OverrideTurnContext {
+                cwd: None,
+                approval_policy: None,
+                sandbox_policy: None,
+                windows_sandbox_level: None,
+                model: None,
+                effort: None,
+                summary: None,
+                collaboration_mode: None,
+                personality: None,
+                spec_parallel_priority: None,
+                spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: Some(scope),
+            }));
+            tx.send(AppEvent::UpdateScope(package.clone()));
+        })]
+    }
+
     fn model_menu_header(&self, title: &str, subtitle: &str) -> Box<dyn Renderable> {
         let title = title.to_string();
         let subtitle = subtitle.to_string();
@@ -5096,6 +6214,9 @@ impl ChatWidget {
                 spec_parallel_priority: None,
 
                 spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
             }));
             tx.send(AppEvent::UpdateModel(model_for_action.clone()));
             tx.send(AppEvent::UpdateReasoningEffort(effort_for_action));
@@ -5141,9 +6262,15 @@ impl ChatWidget {
             stored: Option<ReasoningEffortConfig>,
             display: ReasoningEffortConfig,
         }
+        let capabilities = self
+            .models_manager
+            .try_get_model_capabilities(&preset.model, &self.config)
+            .ok();
         let mut choices: Vec<EffortChoice> = Vec::new();
         for effort in ReasoningEffortConfig::iter() {
-            if supported.iter().any(|option| option.effort == effort) {
+            if supported.iter().any(|option| option.effort == effort)
+                && Self::capabilities_allow_effort(capabilities.as_ref(), effort)
+            {
                 choices.push(EffortChoice {
                     stored: Some(effort),
                     display: effort,
@@ -5273,6 +6400,9 @@ impl ChatWidget {
                 spec_parallel_priority: None,
 
                 spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
             }));
         self.app_event_tx.send(AppEvent::UpdateModel(model.clone()));
         self.app_event_tx
@@ -5290,15 +6420,88 @@ impl ChatWidget {
         );
     }
 
-    /// Open the permissions popup (alias for /permissions).
-    pub(crate) fn open_approvals_popup(&mut self) {
-        self.open_permissions_popup();
-    }
-
-    /// Open a popup to choose the permissions mode (approval policy + sandbox policy).
-    pub(crate) fn open_permissions_popup(&mut self) {
-        let include_read_only = cfg!(target_os = "windows");
-        let current_approval = self.config.approval_policy.value();
+    /// Switch the next turn to the following reasoning effort the current
+    /// model supports (wrapping around) and flash a model/effort chip in the
+    /// footer. Bound to `ctrl+r`; persisted only with `tui.persist_reasoning_toggle`.
+    pub(crate) fn cycle_reasoning_effort(&mut self) {
+        let model = self.current_model().to_string();
+        let Some(preset) = self
+            .models_manager
+            .try_list_models(&self.config)
+            .ok()
+            .and_then(|models| models.into_iter().find(|preset| preset.model == model))
+        else {
+            return;
+        };
+        let capabilities = self.current_model_capabilities();
+        let efforts: Vec<ReasoningEffortConfig> = preset
+            .supported_reasoning_efforts
+            .iter()
+            .map(|option| option.effort)
+            .filter(|effort| Self::capabilities_allow_effort(capabilities.as_ref(), *effort))
+            .collect();
+        if efforts.len() < 2 {
+            return;
+        }
+
+        let current = self
+            .effective_reasoning_effort()
+            .unwrap_or(preset.default_reasoning_effort);
+        let next = efforts
+            .iter()
+            .position(|effort| *effort == current)
+            .map_or(efforts[0], |index| efforts[(index + 1) % efforts.len()]);
+
+        self.app_event_tx
+            .send(AppEvent::CodexOp(Op::OverrideTurnContext {
+                cwd: None,
+                approval_policy: None,
+                sandbox_policy: None,
+                windows_sandbox_level: None,
+                model: None,
+                effort: Some(Some(next)),
+                summary: None,
+                collaboration_mode: None,
+                personality: None,
+                spec_parallel_priority: None,
+                spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
+            }));
+        self.app_event_tx
+            .send(AppEvent::UpdateReasoningEffort(Some(next)));
+        if self.config.tui_persist_reasoning_toggle {
+            self.app_event_tx.send(AppEvent::PersistModelSelection {
+                model,
+                effort: Some(next),
+            });
+        }
+
+        let chip = tr_args(
+            self.config.language,
+            "chatwidget.reasoning_cycled",
+            &[
+                ("model", self.model_display_name()),
+                (
+                    "effort",
+                    Self::status_line_reasoning_effort_label(Some(next)),
+                ),
+            ],
+        );
+        self.bottom_pane
+            .show_footer_flash(Line::from(chip).dim(), REASONING_CHIP_DURATION);
+    }
+
+    /// Open the permissions popup (alias for /permissions).
+    pub(crate) fn open_approvals_popup(&mut self) {
+        self.open_permissions_popup();
+    }
+
+    /// Open a popup to choose the permissions mode (approval policy + sandbox policy).
+    pub(crate) fn open_permissions_popup(&mut self) {
+        let include_read_only = cfg!(target_os = "windows");
+        let current_approval = self.config.approval_policy.value();
         let current_sandbox = self.config.sandbox_policy.get();
         let mut items: Vec<SelectionItem> = Vec::new();
         let presets: Vec<ApprovalPreset> = builtin_approval_presets();
@@ -5426,9 +6629,11 @@ impl ChatWidget {
     pub(crate) fn open_experimental_popup(&mut self) {
         let features: Vec<ExperimentalFeatureItem> = FEATURES
             .iter()
+            .filter(|spec| !self.config.features.is_locked(spec.id))
             .filter_map(|spec| {
-                let name = spec.stage.experimental_menu_name()?;
-                let description = spec.stage.experimental_menu_description()?;
+                let stage = self.config.features.stage(spec.id);
+                let name = stage.experimental_menu_name()?;
+                let description = stage.experimental_menu_description()?;
                 Some(ExperimentalFeatureItem {
                     feature: spec.id,
                     name: name.to_string(),
@@ -5461,6 +6666,9 @@ impl ChatWidget {
                 spec_parallel_priority: None,
 
                 spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
             }));
             tx.send(AppEvent::UpdateAskForApprovalPolicy(approval));
             tx.send(AppEvent::UpdateSandboxPolicy(sandbox_clone));
@@ -6013,6 +7221,37 @@ impl ChatWidget {
         if let Err(err) = self.config.approval_policy.set(policy) {
             tracing::warn!(%err, "failed to set approval_policy on chat config");
         }
+        self.refresh_permissions_chip();
+    }
+
+    /// Update the footer chip showing the active sandbox and approval mode.
+    fn refresh_permissions_chip(&mut self) {
+        let chip = permissions_chip_line(
+            self.config.approval_policy.value(),
+            self.config.sandbox_policy.get(),
+        );
+        self.bottom_pane.set_permissions_chip(Some(chip));
+        self.refresh_danger_banner();
+    }
+
+    /// Pin a red warning above the composer for as long as the session runs
+    /// without approvals or sandboxing, pointing at the audit log.
+    fn refresh_danger_banner(&mut self) {
+        let banner = danger_audit::is_danger_mode(
+            self.config.approval_policy.value(),
+            self.config.sandbox_policy.get(),
+        )
+        .then(|| {
+            let path = danger_audit::audit_log_path(&self.config);
+            Line::from(tr_args(
+                self.config.language,
+                "danger_banner.text",
+                &[("path", &path.display().to_string())],
+            ))
+            .red()
+            .bold()
+        });
+        self.bottom_pane.set_danger_banner(banner);
     }
 
     pub(crate) fn set_language(&mut self, language: Language) {
@@ -6024,6 +7263,46 @@ impl ChatWidget {
         self.config.spec.parallel_priority = enabled;
     }
 
+    /// Set the active persona in the widget's config copy.
+    pub(crate) fn set_persona(&mut self, persona: Option<String>) {
+        self.config.persona = persona;
+    }
+
+    /// Set the active profile in the widget's config copy.
+    pub(crate) fn set_active_profile(&mut self, profile: Option<String>) {
+        self.config.active_profile = profile;
+    }
+
+    pub(crate) fn set_transcript_density(&mut self, density: TranscriptDensity) {
+        self.config.tui_density = density;
+    }
+
+    /// Take the live settings from a `config.toml` edited mid-session.
+    pub(crate) fn apply_reloaded_config(&mut self, reloaded: &Config) {
+        crate::config_reload::apply_live_settings(&mut self.config, reloaded);
+        self.bottom_pane.set_language(self.config.language);
+        self.refresh_status_line();
+    }
+
+    /// Record the package the session is scoped to, or `None` after widening.
+    pub(crate) fn set_scope(&mut self, package: Option<(String, PathBuf)>) {
+        let language = self.config.language;
+        let message = match &package {
+            Some((name, path)) => {
+                let path = path.display().to_string();
+                tr_args(
+                    language,
+                    "scope.scoped",
+                    &[("package", name.as_str()), ("path", &path)],
+                )
+            }
+            None if self.config.scope.is_some() => tr(language, "scope.widened").to_string(),
+            None => tr(language, "scope.not_scoped").to_string(),
+        };
+        self.config.scope = package.map(|(_, path)| path);
+        self.add_info_message(message, None);
+    }
+
     /// Set the sandbox policy in the widget's config copy.
     pub(crate) fn set_sandbox_policy(&mut self, policy: SandboxPolicy) -> ConstraintResult<()> {
         #[cfg(target_os = "windows")]
@@ -6031,6 +7310,7 @@ impl ChatWidget {
             || WindowsSandboxLevel::from_config(&self.config) != WindowsSandboxLevel::Disabled;
 
         self.config.sandbox_policy.set(policy)?;
+        self.refresh_permissions_chip();
 
         #[cfg(target_os = "windows")]
         if should_clear_downgrade {
@@ -6152,6 +7432,15 @@ impl ChatWidget {
             .set_personality_command_enabled(self.config.features.enabled(Feature::Personality));
     }
 
+    fn sync_approval_timeout(&mut self) {
+        let action = self.config.approval_timeout_action;
+        self.bottom_pane.set_approval_timeout(
+            self.config
+                .approval_timeout
+                .map(|timeout| ApprovalTimeout { timeout, action }),
+        );
+    }
+
     fn current_model_supports_personality(&self) -> bool {
         let model = self.current_model();
         self.models_manager
@@ -6184,6 +7473,54 @@ impl ChatWidget {
             .unwrap_or(true)
     }
 
+    /// Capability table entry for the effective model, if the catalog is readable right now.
+    fn current_model_capabilities(&self) -> Option<ModelCapabilities> {
+        self.models_manager
+            .try_get_model_capabilities(self.current_model(), &self.config)
+            .ok()
+    }
+
+    /// Efforts are only hidden when the catalog lists the model's efforts and this one is absent.
+    fn capabilities_allow_effort(
+        capabilities: Option<&ModelCapabilities>,
+        effort: ReasoningEffortConfig,
+    ) -> bool {
+        capabilities.is_none_or(|capabilities| {
+            capabilities.reasoning_efforts.is_empty()
+                || capabilities.supports_reasoning_effort(effort)
+        })
+    }
+
+    /// Warn when a resumed session recorded a larger context window than the current model offers.
+    fn warn_if_resumed_context_window_shrank(&mut self) {
+        let Some(recorded_window) = self
+            .token_info
+            .as_ref()
+            .and_then(|info| info.model_context_window)
+        else {
+            return;
+        };
+        let Some(current_window) = self
+            .current_model_capabilities()
+            .and_then(|capabilities| capabilities.context_window)
+        else {
+            return;
+        };
+        if current_window >= recorded_window {
+            return;
+        }
+        let message = tr_args(
+            self.config.language,
+            "chatwidget.context_window_shrank",
+            &[
+                ("recorded", &format_tokens_compact(recorded_window)),
+                ("model", self.current_model()),
+                ("current", &format_tokens_compact(current_window)),
+            ],
+        );
+        self.on_warning(message);
+    }
+
     fn sync_image_paste_enabled(&mut self) {
         let enabled = self.current_model_supports_images();
         self.bottom_pane.set_image_paste_enabled(enabled);
@@ -6406,6 +7743,131 @@ impl ChatWidget {
         }
     }
 
+    /// Warns once per problem when the stored ChatGPT login is due for a
+    /// refresh or can no longer be refreshed, so the user can run `/login`
+    /// before a request fails.
+    fn maybe_warn_relogin(&mut self) {
+        let warning = match self.auth_manager.token_refresh_health() {
+            Some(TokenRefreshHealth::Failed(_)) => ReloginWarning::Expired,
+            Some(TokenRefreshHealth::RefreshDue { .. }) => ReloginWarning::RefreshDue,
+            Some(TokenRefreshHealth::Healthy { .. }) | None => {
+                self.relogin_warning = None;
+                return;
+            }
+        };
+        if self.relogin_warning == Some(warning) {
+            return;
+        }
+        self.relogin_warning = Some(warning);
+        let language = self.config.language;
+        let key = match warning {
+            ReloginWarning::RefreshDue => "chatwidget.login.refresh_due_warning",
+            ReloginWarning::Expired => "chatwidget.login.expired_warning",
+        };
+        self.add_to_history(history_cell::new_warning_event(
+            tr(language, key).to_string(),
+        ));
+        // Prefill `/login` so re-auth is a single Enter, unless the user has a
+        // draft. A due refresh usually succeeds on its own, so only suggest it.
+        if warning == ReloginWarning::Expired && self.bottom_pane.composer_is_empty() {
+            self.bottom_pane.set_composer_text(
+                format!("/{}", SlashCommand::Login.command()),
+                Vec::new(),
+                Vec::new(),
+            );
+        }
+        self.request_redraw();
+    }
+
+    /// Signs in to ChatGPT again without leaving the session. The auth manager
+    /// is reloaded in place, so the next request picks up the new tokens.
+    /// Hosts without a browser sign in with a device code instead.
+    fn start_relogin(&mut self) {
+        let opts = ServerOptions::new(
+            self.config.codex_home.clone(),
+            CLIENT_ID.to_string(),
+            self.config.forced_chatgpt_workspace_id.clone(),
+            self.config.cli_auth_credentials_store_mode,
+        );
+        if is_headless_environment() {
+            self.start_device_code_relogin(opts);
+        } else {
+            self.start_browser_relogin(opts);
+        }
+    }
+
+    fn start_browser_relogin(&mut self, opts: ServerOptions) {
+        let language = self.config.language;
+        let server = match run_login_server(opts) {
+            Ok(server) => server,
+            Err(err) => {
+                self.add_error_message(tr_args(
+                    language,
+                    "chatwidget.login.start_failed",
+                    &[("error", &err.to_string())],
+                ));
+                return;
+            }
+        };
+        self.add_info_message(
+            tr(language, "chatwidget.login.continue_in_browser").to_string(),
+            Some(server.auth_url.clone()),
+        );
+
+        let auth_manager = Arc::clone(&self.auth_manager);
+        let tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = match server.block_until_done().await {
+                // A newer `/login` replaced this callback server.
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => return,
+                result => result,
+            };
+            tx.send(AppEvent::InsertHistoryCell(relogin_result_cell(
+                &auth_manager,
+                language,
+                result,
+            )));
+        });
+    }
+
+    fn start_device_code_relogin(&mut self, mut opts: ServerOptions) {
+        opts.open_browser = false;
+        let language = self.config.language;
+        let auth_manager = Arc::clone(&self.auth_manager);
+        let tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let device_code = match request_device_code(&opts).await {
+                Ok(device_code) => device_code,
+                Err(err) => {
+                    tx.send(AppEvent::InsertHistoryCell(Box::new(
+                        history_cell::new_error_event(tr_args(
+                            language,
+                            "chatwidget.login.start_failed",
+                            &[("error", &err.to_string())],
+                        )),
+                    )));
+                    return;
+                }
+            };
+            tx.send(AppEvent::InsertHistoryCell(Box::new(
+                history_cell::new_info_event(
+                    tr_args(
+                        language,
+                        "chatwidget.login.device_code",
+                        &[("code", device_code.user_code.as_str())],
+                    ),
+                    Some(device_code.verification_url.clone()),
+                ),
+            )));
+            let result = complete_device_code_login(opts, device_code).await;
+            tx.send(AppEvent::InsertHistoryCell(relogin_result_cell(
+                &auth_manager,
+                language,
+                result,
+            )));
+        });
+    }
+
     pub(crate) fn add_info_message(&mut self, message: String, hint: Option<String>) {
         self.add_to_history(history_cell::new_info_event(message, hint));
         self.request_redraw();
@@ -6806,6 +8268,296 @@ impl ChatWidget {
         self.bottom_pane.set_custom_prompts(ev.custom_prompts);
     }
 
+    fn on_context_breakdown(&mut self, event: ContextBreakdownEvent) {
+        let language = self.config.language;
+        let excluded: Vec<ContextComponentKind> = event
+            .components
+            .iter()
+            .filter(|component| component.excluded)
+            .map(|component| component.kind)
+            .collect();
+        let items: Vec<SelectionItem> = event
+            .components
+            .iter()
+            .map(|component| {
+                let count = format_tokens_compact(component.tokens);
+                let tokens = tr_args(
+                    language,
+                    "context_breakdown.tokens",
+                    &[("count", count.as_str())],
+                );
+                if !component.droppable {
+                    return SelectionItem {
+                        name: context_component_label(language, component.kind).to_string(),
+                        description: Some(tokens),
+                        is_disabled: true,
+                        disabled_reason: Some(
+                            tr(language, "context_breakdown.always_sent").to_string(),
+                        ),
+                        ..Default::default()
+                    };
+                }
+
+                let kind = component.kind;
+                let mut next_excluded = excluded.clone();
+                let (description, selected_description) = if component.excluded {
+                    next_excluded.retain(|candidate| *candidate != kind);
+                    (
+                        format!("{tokens}  {}", tr(language, "context_breakdown.dropped")),
+                        format!(
+                            "{tokens}  {}",
+                            tr(language, "context_breakdown.send_again_hint")
+                        ),
+                    )
+                } else {
+                    next_excluded.push(kind);
+                    (
+                        tokens.clone(),
+                        format!("{tokens}  {}", tr(language, "context_breakdown.drop_hint")),
+                    )
+                };
+                let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+                    tx.send(AppEvent::CodexOp(Op::SetContextExclusions {
+                        excluded: next_excluded.clone(),
+                    }));
+                })];
+                SelectionItem {
+                    name: context_component_label(language, kind).to_string(),
+                    description: Some(description),
+                    selected_description: Some(selected_description),
+                    actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        let total = format_tokens_compact(event.total_tokens);
+        let subtitle = match event.model_context_window {
+            Some(window) => {
+                let window = format_tokens_compact(window);
+                tr_args(
+                    language,
+                    "context_breakdown.subtitle_window",
+                    &[("total", total.as_str()), ("window", window.as_str())],
+                )
+            }
+            None => tr_args(
+                language,
+                "context_breakdown.subtitle",
+                &[("total", total.as_str())],
+            ),
+        };
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "context_breakdown.title").to_string()),
+            subtitle: Some(subtitle),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    fn on_session_changes(&mut self, event: SessionChangesEvent) {
+        let language = self.config.language;
+        if event.files.is_empty() {
+            self.add_info_message(tr(language, "session_changes.empty").to_string(), None);
+            return;
+        }
+
+        let added: u32 = event.files.iter().map(|file| file.added_lines).sum();
+        let removed: u32 = event.files.iter().map(|file| file.removed_lines).sum();
+        let mut items: Vec<SelectionItem> = event
+            .files
+            .iter()
+            .map(|file| {
+                let path = file.path.clone();
+                let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+                    tx.send(AppEvent::OpenDiscardSessionChangeConfirmation { path: path.clone() });
+                })];
+                let stats = format!("+{} -{}", file.added_lines, file.removed_lines);
+                SelectionItem {
+                    name: display_path_for(&file.path, &self.config.cwd),
+                    selected_description: Some(format!(
+                        "{stats}  {}",
+                        tr(language, "session_changes.discard_hint")
+                    )),
+                    description: Some(stats),
+                    actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        let unified_diff = event.unified_diff;
+        items.push(SelectionItem {
+            name: tr(language, "session_changes.export").to_string(),
+            description: Some(tr_args(
+                language,
+                "session_changes.export_description",
+                &[("file", SESSION_PATCH_FILENAME)],
+            )),
+            actions: vec![Box::new(move |tx| {
+                tx.send(AppEvent::ExportSessionDiff {
+                    unified_diff: unified_diff.clone(),
+                });
+            })],
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+
+        let files = event.files.len().to_string();
+        let (added, removed) = (added.to_string(), removed.to_string());
+        let summary_key = if event.files.len() == 1 {
+            "session_changes.summary_one"
+        } else {
+            "session_changes.summary"
+        };
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "session_changes.title").to_string()),
+            subtitle: Some(tr_args(
+                language,
+                summary_key,
+                &[
+                    ("count", files.as_str()),
+                    ("added", added.as_str()),
+                    ("removed", removed.as_str()),
+                ],
+            )),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    fn on_session_note(&mut self, event: SessionNoteEvent) {
+        let language = self.config.language;
+        let hint = event
+            .in_context
+            .then(|| tr(language, "session_note.in_context").to_string());
+        self.add_info_message(
+            tr_args(language, "session_note.saved", &[("text", &event.text)]),
+            hint,
+        );
+        self.session_notes.push(event.text);
+    }
+
+    /// List the notes saved with this session, with an entry to add one.
+    fn open_session_notes(&mut self) {
+        if self.session_notes.is_empty() {
+            self.show_session_note_prompt();
+            return;
+        }
+        let language = self.config.language;
+        let mut items: Vec<SelectionItem> = vec![SelectionItem {
+            name: tr(language, "session_note.add").to_string(),
+            description: Some(tr(language, "session_note.add_description").to_string()),
+            actions: vec![Box::new(|tx| tx.send(AppEvent::OpenSessionNotePrompt))],
+            dismiss_on_select: true,
+            ..Default::default()
+        }];
+        items.extend(self.session_notes.iter().rev().map(|note| SelectionItem {
+            name: note.clone(),
+            is_disabled: true,
+            ..Default::default()
+        }));
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "session_note.title").to_string()),
+            subtitle: Some(tr(language, "session_note.subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    pub(crate) fn show_session_note_prompt(&mut self) {
+        let tx = self.app_event_tx.clone();
+        let language = self.config.language;
+        let view = CustomPromptView::new(
+            tr(language, "session_note.prompt_title").to_string(),
+            tr(language, "session_note.prompt_hint").to_string(),
+            None,
+            language,
+            Box::new(move |text: String| {
+                let text = text.trim().to_string();
+                if text.is_empty() {
+                    return;
+                }
+                tx.send(AppEvent::CodexOp(Op::AddNote { text }));
+            }),
+        );
+        self.bottom_pane.show_view(Box::new(view));
+    }
+
+    pub(crate) fn open_discard_session_change_confirmation(&mut self, path: PathBuf) {
+        let display = display_path_for(&path, &self.config.cwd);
+        let discard_actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+            tx.send(AppEvent::CodexOp(Op::DiscardSessionChange {
+                path: path.clone(),
+            }));
+        })];
+        let keep_actions: Vec<SelectionAction> = vec![Box::new(|tx| {
+            tx.send(AppEvent::CodexOp(Op::ListSessionChanges));
+        })];
+
+        let language = self.config.language;
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr_args(
+                language,
+                "session_changes.discard_title",
+                &[("path", display.as_str())],
+            )),
+            subtitle: Some(tr(language, "session_changes.discard_subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items: vec![
+                SelectionItem {
+                    name: tr(language, "session_changes.discard").to_string(),
+                    actions: discard_actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                },
+                SelectionItem {
+                    name: tr(language, "session_changes.keep").to_string(),
+                    actions: keep_actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    pub(crate) fn export_session_diff(&mut self, unified_diff: String) {
+        let language = self.config.language;
+        let target = self.config.cwd.join(SESSION_PATCH_FILENAME);
+        let display = target.display().to_string();
+        match std::fs::write(&target, unified_diff) {
+            Ok(()) => self.add_info_message(
+                tr_args(
+                    language,
+                    "session_changes.exported",
+                    &[("path", display.as_str())],
+                ),
+                Some(tr_args(
+                    language,
+                    "session_changes.apply_hint",
+                    &[("file", SESSION_PATCH_FILENAME)],
+                )),
+            ),
+            Err(err) => {
+                let err = err.to_string();
+                self.add_error_message(tr_args(
+                    language,
+                    "session_changes.export_failed",
+                    &[("path", display.as_str()), ("error", err.as_str())],
+                ))
+            }
+        }
+    }
+
     fn on_list_skills(&mut self, ev: ListSkillsResponseEvent) {
         self.set_skills_from_response(&ev);
     }
@@ -6840,11 +8592,12 @@ impl ChatWidget {
     }
 
     pub(crate) fn open_review_popup(&mut self) {
+        let language = self.config.language;
         let mut items: Vec<SelectionItem> = Vec::new();
 
         items.push(SelectionItem {
-            name: "Review against a base branch".to_string(),
-            description: Some("(PR Style)".into()),
+            name: tr(language, "chatwidget.review.base_branch").to_string(),
+            description: Some(tr(language, "chatwidget.review.pr_style_desc").to_string()),
             actions: vec![Box::new({
                 let cwd = self.config.cwd.clone();
                 move |tx| {
@@ -6856,7 +8609,7 @@ impl ChatWidget {
         });
 
         items.push(SelectionItem {
-            name: "Review uncommitted changes".to_string(),
+            name: tr(language, "chatwidget.review.uncommitted").to_string(),
             actions: vec![Box::new(move |tx: &AppEventSender| {
                 tx.send(AppEvent::CodexOp(Op::Review {
                     review_request: ReviewRequest {
@@ -6869,9 +8622,23 @@ impl ChatWidget {
             ..Default::default()
         });
 
+        items.push(SelectionItem {
+            name: tr(language, "chatwidget.review.staged").to_string(),
+            actions: vec![Box::new(move |tx: &AppEventSender| {
+                tx.send(AppEvent::CodexOp(Op::Review {
+                    review_request: ReviewRequest {
+                        target: ReviewTarget::StagedChanges,
+                        user_facing_hint: None,
+                    },
+                }));
+            })],
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+
         // New: Review a specific commit (opens commit picker)
         items.push(SelectionItem {
-            name: "Review a commit".to_string(),
+            name: tr(language, "chatwidget.review.commit").to_string(),
             actions: vec![Box::new({
                 let cwd = self.config.cwd.clone();
                 move |tx| {
@@ -6883,7 +8650,16 @@ impl ChatWidget {
         });
 
         items.push(SelectionItem {
-            name: "Custom review instructions".to_string(),
+            name: tr(language, "chatwidget.review.files").to_string(),
+            actions: vec![Box::new(move |tx| {
+                tx.send(AppEvent::OpenReviewFilesPrompt);
+            })],
+            dismiss_on_select: false,
+            ..Default::default()
+        });
+
+        items.push(SelectionItem {
+            name: tr(language, "chatwidget.review.custom").to_string(),
             actions: vec![Box::new(move |tx| {
                 tx.send(AppEvent::OpenReviewCustomPrompt);
             })],
@@ -6892,8 +8668,8 @@ impl ChatWidget {
         });
 
         self.bottom_pane.show_selection_view(SelectionViewParams {
-            title: Some("Select a review preset".into()),
-            footer_hint: Some(standard_popup_hint_line(self.config.language)),
+            title: Some(tr(language, "chatwidget.review.title").into()),
+            footer_hint: Some(standard_popup_hint_line(language)),
             items,
             ..Default::default()
         });
@@ -6901,9 +8677,9 @@ impl ChatWidget {
 
     pub(crate) async fn show_review_branch_picker(&mut self, cwd: &Path) {
         let branches = local_git_branches(cwd).await;
-        let current_branch = current_branch_name(cwd)
-            .await
-            .unwrap_or_else(|| "(detached HEAD)".to_string());
+        let current_branch = current_branch_name(cwd).await.unwrap_or_else(|| {
+            tr(self.config.language, "chatwidget.review.detached_head").to_string()
+        });
         let mut items: Vec<SelectionItem> = Vec::with_capacity(branches.len());
 
         for option in branches {
@@ -6927,11 +8703,15 @@ impl ChatWidget {
         }
 
         self.bottom_pane.show_selection_view(SelectionViewParams {
-            title: Some("Select a base branch".to_string()),
+            title: Some(
+                tr(self.config.language, "chatwidget.review.base_branch_title").to_string(),
+            ),
             footer_hint: Some(standard_popup_hint_line(self.config.language)),
             items,
             is_searchable: true,
-            search_placeholder: Some("Type to search branches".to_string()),
+            search_placeholder: Some(
+                tr(self.config.language, "chatwidget.review.search_branches").to_string(),
+            ),
             ..Default::default()
         });
     }
@@ -6965,20 +8745,45 @@ impl ChatWidget {
         }
 
         self.bottom_pane.show_selection_view(SelectionViewParams {
-            title: Some("Select a commit to review".to_string()),
+            title: Some(tr(self.config.language, "chatwidget.review.commit_title").to_string()),
             footer_hint: Some(standard_popup_hint_line(self.config.language)),
             items,
             is_searchable: true,
-            search_placeholder: Some("Type to search commits".to_string()),
+            search_placeholder: Some(
+                tr(self.config.language, "chatwidget.review.search_commits").to_string(),
+            ),
             ..Default::default()
         });
     }
 
+    pub(crate) fn show_review_files_prompt(&mut self) {
+        let tx = self.app_event_tx.clone();
+        let view = CustomPromptView::new(
+            tr(self.config.language, "chatwidget.review.files").to_string(),
+            tr(self.config.language, "chatwidget.review.files_hint").to_string(),
+            None,
+            self.config.language,
+            Box::new(move |input: String| {
+                let patterns: Vec<String> = input.split_whitespace().map(str::to_string).collect();
+                if patterns.is_empty() {
+                    return;
+                }
+                tx.send(AppEvent::CodexOp(Op::Review {
+                    review_request: ReviewRequest {
+                        target: ReviewTarget::Files { patterns },
+                        user_facing_hint: None,
+                    },
+                }));
+            }),
+        );
+        self.bottom_pane.show_view(Box::new(view));
+    }
+
     pub(crate) fn show_review_custom_prompt(&mut self) {
         let tx = self.app_event_tx.clone();
         let view = CustomPromptView::new(
-            "Custom review instructions".to_string(),
-            "Type instructions and press Enter".to_string(),
+            tr(self.config.language, "chatwidget.review.custom").to_string(),
+            tr(self.config.language, "chatwidget.review.custom_hint").to_string(),
             None,
             self.config.language,
             Box::new(move |prompt: String| {
@@ -7006,6 +8811,10 @@ impl ChatWidget {
             .unwrap_or_default()
     }
 
+    pub(crate) fn is_task_running(&self) -> bool {
+        self.bottom_pane.is_task_running()
+    }
+
     pub(crate) fn thread_id(&self) -> Option<ThreadId> {
         self.thread_id
     }
@@ -7054,6 +8863,31 @@ impl ChatWidget {
         (!lines.is_empty()).then_some(lines)
     }
 
+    /// Returns `(call_id, command, output so far)` for the command the active
+    /// exec cell is still running, used to open the follow-output overlay.
+    pub(crate) fn running_exec_output(&self) -> Option<(String, String, String)> {
+        let cell = self
+            .active_cell
+            .as_ref()?
+            .as_any()
+            .downcast_ref::<ExecCell>()?;
+        let call = cell.running_call()?;
+        if call.is_unified_exec_interaction() {
+            return None;
+        }
+        let command = strip_bash_lc_and_escape(&call.command)
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let output = call
+            .output
+            .as_ref()
+            .map(|output| output.aggregated_output.clone())
+            .unwrap_or_default();
+        Some((call.call_id.clone(), command, output))
+    }
+
     /// Return a reference to the widget's current config (includes any
     /// runtime overrides applied via TUI, e.g., model or approval policy).
     pub(crate) fn config_ref(&self) -> &Config {
@@ -7275,6 +9109,23 @@ pub(crate) fn show_review_commit_picker_with_entries(
     });
 }
 
+fn context_component_label(language: Language, kind: ContextComponentKind) -> &'static str {
+    let key = match kind {
+        ContextComponentKind::SystemPrompt => "context_breakdown.component.system_prompt",
+        ContextComponentKind::DeveloperInstructions => {
+            "context_breakdown.component.developer_instructions"
+        }
+        ContextComponentKind::ProjectDocs => "context_breakdown.component.project_docs",
+        ContextComponentKind::Skills => "context_breakdown.component.skills",
+        ContextComponentKind::EnvironmentContext => {
+            "context_breakdown.component.environment_context"
+        }
+        ContextComponentKind::History => "context_breakdown.component.history",
+        ContextComponentKind::ToolSchemas => "context_breakdown.component.tool_schemas",
+    };
+    tr(language, key)
+}
+
 fn format_duration_short(seconds: u64) -> String {
     if seconds < 60 {
         "less than a minute".to_string()
@@ -10,17 +10,23 @@ use crate::bottom_pane::SelectionViewParams;
 use crate::bottom_pane::SkillsToggleItem;
 use crate::bottom_pane::SkillsToggleView;
 use crate::bottom_pane::popup_consts::standard_popup_hint_line;
+use crate::i18n::tr;
+use crate::i18n::tr_args;
 use crate::skills_helpers::skill_description;
 use crate::skills_helpers::skill_display_name;
 use codex_chatgpt::connectors::AppInfo;
 use codex_core::connectors::connector_mention_slug;
 use codex_core::protocol::ListSkillsResponseEvent;
+use codex_core::protocol::Op;
 use codex_core::protocol::SkillMetadata as ProtocolSkillMetadata;
+use codex_core::protocol::SkillScope;
 use codex_core::protocol::SkillsListEntry;
 use codex_core::skills::model::SkillDependencies;
 use codex_core::skills::model::SkillInterface;
 use codex_core::skills::model::SkillMetadata;
 use codex_core::skills::model::SkillToolDependency;
+use codex_core::skills::trusted_skill_paths;
+use codex_protocol::config_types::Language;
 
 impl ChatWidget {
     pub(crate) fn open_skills_list(&mut self) {
@@ -47,6 +53,17 @@ impl ChatWidget {
                 dismiss_on_select: true,
                 ..Default::default()
             },
+            SelectionItem {
+                name: tr(self.config.language, "chatwidget.skills_trust.review_name").to_string(),
+                description: Some(
+                    tr(self.config.language, "chatwidget.skills_trust.review_desc").to_string(),
+                ),
+                actions: vec![Box::new(|tx| {
+                    tx.send(AppEvent::OpenSkillsTrustPrompt);
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
         ];
 
         self.bottom_pane.show_selection_view(SelectionViewParams {
@@ -140,7 +157,137 @@ impl ChatWidget {
         let skills = skills_for_cwd(&self.config.cwd, &response.skills);
         self.skills_all = skills;
         self.set_skills(Some(enabled_skills_for_mentions(&self.skills_all)));
+
+        if !self.skills_trust_prompted && !self.untrusted_repo_skills().is_empty() {
+            self.skills_trust_prompted = true;
+            self.open_skills_trust_prompt();
+        }
     }
+
+    /// Repository skills that have not been trusted in config or this session.
+    fn untrusted_repo_skills(&self) -> Vec<&ProtocolSkillMetadata> {
+        let trusted = trusted_skill_paths(&self.config.config_layer_stack);
+        self.skills_all
+            .iter()
+            .filter(|skill| skill.scope == SkillScope::Repo)
+            .filter(|skill| {
+                let path = normalize_skill_config_path(&skill.path);
+                !trusted.contains(&path) && !self.skills_trusted.contains(&path)
+            })
+            .collect()
+    }
+
+    pub(crate) fn open_skills_trust_prompt(&mut self) {
+        let language = self.config.language;
+        let untrusted = self.untrusted_repo_skills();
+        if untrusted.is_empty() {
+            self.add_info_message(
+                tr(language, "chatwidget.skills_trust.none_pending").to_string(),
+                None,
+            );
+            return;
+        }
+
+        let all_paths: Vec<PathBuf> = untrusted.iter().map(|skill| skill.path.clone()).collect();
+        let count = all_paths.len().to_string();
+        let names = untrusted
+            .iter()
+            .map(|skill| skill.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut items = vec![SelectionItem {
+            name: tr_args(
+                language,
+                "chatwidget.skills_trust.trust_all",
+                &[("count", &count)],
+            ),
+            description: Some(names),
+            actions: vec![Box::new(move |tx| {
+                tx.send(AppEvent::TrustSkills {
+                    paths: all_paths.clone(),
+                });
+            })],
+            dismiss_on_select: true,
+            ..Default::default()
+        }];
+        for skill in &untrusted {
+            let path = skill.path.clone();
+            items.push(SelectionItem {
+                name: tr_args(
+                    language,
+                    "chatwidget.skills_trust.trust_one",
+                    &[("name", &skill.name)],
+                ),
+                description: Some(describe_tool_dependencies(language, skill)),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::TrustSkills {
+                        paths: vec![path.clone()],
+                    });
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            });
+        }
+        items.push(SelectionItem {
+            name: tr(language, "chatwidget.skills_trust.not_now").to_string(),
+            description: Some(tr(language, "chatwidget.skills_trust.not_now_desc").to_string()),
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "chatwidget.skills_trust.title").to_string()),
+            subtitle: Some(tr(language, "chatwidget.skills_trust.subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+    }
+
+    /// Records a successful trust edit and reloads skills so the newly trusted
+    /// entries become mentionable.
+    pub(crate) fn mark_skills_trusted(&mut self, paths: &[PathBuf]) {
+        for path in paths {
+            self.skills_trusted
+                .insert(normalize_skill_config_path(path));
+        }
+        let count = paths.len().to_string();
+        self.add_info_message(
+            tr_args(
+                self.config.language,
+                "chatwidget.skills_trust.trusted",
+                &[("count", &count)],
+            ),
+            None,
+        );
+        self.submit_op(Op::ListSkills {
+            cwds: Vec::new(),
+            force_reload: true,
+        });
+    }
+}
+
+fn describe_tool_dependencies(language: Language, skill: &ProtocolSkillMetadata) -> String {
+    let tools = skill
+        .dependencies
+        .as_ref()
+        .map(|dependencies| {
+            dependencies
+                .tools
+                .iter()
+                .map(|tool| format!("{}:{}", tool.r#type, tool.value))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if tools.is_empty() {
+        return tr(language, "chatwidget.skills_trust.no_dependencies").to_string();
+    }
+    tr_args(
+        language,
+        "chatwidget.skills_trust.dependencies",
+        &[("tools", &tools.join(", "))],
+    )
 }
 
 fn skills_for_cwd(cwd: &Path, skills_entries: &[SkillsListEntry]) -> Vec<ProtocolSkillMetadata> {
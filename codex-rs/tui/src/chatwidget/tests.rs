@@ -23,6 +23,8 @@ use codex_core::config::ConfigBuilder;
 use codex_core::config::Constrained;
 use codex_core::config::ConstraintError;
 use codex_core::config_loader::RequirementSource;
+use codex_core::error::RefreshTokenFailedError;
+use codex_core::error::RefreshTokenFailedReason;
 use codex_core::features::Feature;
 use codex_core::models_manager::manager::ModelsManager;
 use codex_core::protocol::AgentMessageDeltaEvent;
@@ -38,9 +40,11 @@ use codex_core::protocol::ExecApprovalRequestEvent;
 use codex_core::protocol::ExecCommandBeginEvent;
 use codex_core::protocol::ExecCommandEndEvent;
 use codex_core::protocol::ExecCommandSource;
+use codex_core::protocol::ExecOutputStream;
 use codex_core::protocol::ExecPolicyAmendment;
 use codex_core::protocol::ExitedReviewModeEvent;
 use codex_core::protocol::FileChange;
+use codex_core::protocol::GuardrailBlockedEvent;
 use codex_core::protocol::ItemCompletedEvent;
 use codex_core::protocol::McpStartupCompleteEvent;
 use codex_core::protocol::McpStartupStatus;
@@ -48,10 +52,12 @@ use codex_core::protocol::McpStartupUpdateEvent;
 use codex_core::protocol::Op;
 use codex_core::protocol::PatchApplyBeginEvent;
 use codex_core::protocol::PatchApplyEndEvent;
+use codex_core::protocol::PlannedToolCall;
 use codex_core::protocol::RateLimitWindow;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
 use codex_core::protocol::SessionSource;
+use codex_core::protocol::SkillsListEntry;
 use codex_core::protocol::StreamErrorEvent;
 use codex_core::protocol::TerminalInteractionEvent;
 use codex_core::protocol::ThreadRolledBackEvent;
@@ -59,6 +65,7 @@ use codex_core::protocol::TokenCountEvent;
 use codex_core::protocol::TokenUsage;
 use codex_core::protocol::TokenUsageInfo;
 use codex_core::protocol::TurnCompleteEvent;
+use codex_core::protocol::TurnSourcesEvent;
 use codex_core::protocol::TurnStartedEvent;
 use codex_core::protocol::UndoCompletedEvent;
 use codex_core::protocol::UndoStartedEvent;
@@ -76,6 +83,7 @@ use codex_protocol::config_types::Settings;
 use codex_protocol::items::AgentMessageContent;
 use codex_protocol::items::AgentMessageItem;
 use codex_protocol::items::TurnItem;
+use codex_protocol::items::WebCitation;
 use codex_protocol::models::MessagePhase;
 use codex_protocol::openai_models::ModelPreset;
 use codex_protocol::openai_models::ReasoningEffortPreset;
@@ -196,6 +204,47 @@ async fn resumed_initial_messages_render_history() {
     );
 }
 
+#[tokio::test]
+async fn resume_warns_when_current_model_has_smaller_context_window() {
+    let (mut chat, mut rx, _ops) = make_chatwidget_manual(None).await;
+
+    let rollout_file = NamedTempFile::new().unwrap();
+    let configured = codex_core::protocol::SessionConfiguredEvent {
+        session_id: ThreadId::new(),
+        forked_from_id: None,
+        thread_name: None,
+        model: "test-model".to_string(),
+        model_provider_id: "test-provider".to_string(),
+        approval_policy: AskForApproval::Never,
+        sandbox_policy: SandboxPolicy::ReadOnly,
+        cwd: PathBuf::from("/home/user/project"),
+        reasoning_effort: Some(ReasoningEffortConfig::default()),
+        history_log_id: 0,
+        history_entry_count: 0,
+        initial_messages: Some(vec![EventMsg::TokenCount(TokenCountEvent {
+            info: Some(make_token_info(120_000, 1_000_000)),
+            rate_limits: None,
+        })]),
+        network_proxy: None,
+        rollout_path: Some(rollout_file.path().to_path_buf()),
+    };
+
+    chat.handle_codex_event(Event {
+        id: "initial".into(),
+        msg: EventMsg::SessionConfigured(configured),
+    });
+
+    let text_blob = drain_insert_history(&mut rx)
+        .iter()
+        .map(|lines| lines_to_single_string(lines))
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(
+        text_blob.contains("This session was recorded with a 1M context window"),
+        "expected context window warning, got: {text_blob}"
+    );
+}
+
 #[tokio::test]
 async fn replayed_user_message_preserves_text_elements_and_local_images() {
     let (mut chat, mut rx, _ops) = make_chatwidget_manual(None).await;
@@ -590,6 +639,7 @@ async fn interrupted_turn_restores_queued_messages_with_images_and_elements() {
         id: "interrupt".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -653,6 +703,7 @@ async fn interrupted_turn_restore_keeps_active_mode_for_resubmission() {
         id: "interrupt".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -1030,6 +1081,7 @@ async fn make_chatwidget_manual(
         placeholder_text: "Ask Codex to do anything".to_string(),
         disable_paste_burst: false,
         animations_enabled: cfg.animations,
+        accessible: false,
         skills: None,
         language: cfg.language,
     });
@@ -1075,6 +1127,8 @@ async fn make_chatwidget_manual(
         suppressed_exec_calls: HashSet::new(),
         skills_all: Vec::new(),
         skills_initial_state: None,
+        skills_trusted: HashSet::new(),
+        skills_trust_prompted: false,
         last_unified_wait: None,
         unified_exec_wait_streak: None,
         task_complete_pending: false,
@@ -1092,6 +1146,9 @@ async fn make_chatwidget_manual(
         thread_id: None,
         thread_name: None,
         forked_from: None,
+        pending_exec_output: Vec::new(),
+        interrupted_responses: InterruptedResponses::default(),
+        relogin_warning: None,
         frame_requester: FrameRequester::test_dummy(),
         show_welcome_banner: true,
         queued_user_messages: VecDeque::new(),
@@ -1102,9 +1159,16 @@ async fn make_chatwidget_manual(
         is_review_mode: false,
         pre_review_token_info: None,
         needs_final_message_separator: false,
+        pending_compare_model: None,
         had_work_activity: false,
         saw_plan_update_this_turn: false,
         saw_plan_item_this_turn: false,
+        last_agent_message: None,
+        last_patch: None,
+        session_notes: Vec::new(),
+        completed_live_turn: false,
+        exit_summary_requested: false,
+        exit_summary_bullets: Vec::new(),
         plan_delta_buffer: String::new(),
         plan_item_active: false,
         last_separator_elapsed_secs: None,
@@ -1212,6 +1276,12 @@ fn lines_to_single_string(lines: &[ratatui::text::Line<'static>]) -> String {
     s
 }
 
+/// Whether a history cell shows `text`, ignoring where it was wrapped.
+fn cell_shows_text(lines: &[ratatui::text::Line<'static>], text: &str) -> bool {
+    let squash = |text: &str| text.split_whitespace().collect::<String>();
+    squash(&lines_to_single_string(lines)).contains(&squash(text))
+}
+
 fn make_token_info(total_tokens: i64, context_window: i64) -> TokenUsageInfo {
     fn usage(total_tokens: i64) -> TokenUsage {
         TokenUsage {
@@ -1323,6 +1393,59 @@ async fn rate_limit_snapshot_keeps_prior_credits_when_missing_from_headers() {
     );
 }
 
+#[tokio::test]
+async fn cached_rate_limits_show_until_a_live_snapshot_arrives() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    let fetched_at = chrono::Utc::now() - chrono::Duration::hours(2);
+
+    chat.on_cached_rate_limit_snapshot(
+        RateLimitSnapshot {
+            primary: Some(RateLimitWindow {
+                used_percent: 40.0,
+                window_minutes: Some(300),
+                resets_at: None,
+            }),
+            secondary: None,
+            credits: None,
+            plan_type: Some(PlanType::Plus),
+        },
+        fetched_at,
+    );
+    assert_eq!(chat.plan_type, Some(PlanType::Plus));
+    let display = chat.rate_limit_snapshot.as_ref().expect("cached limits");
+    assert_eq!(
+        display.captured_at,
+        fetched_at.with_timezone(&chrono::Local)
+    );
+
+    chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
+        primary: Some(RateLimitWindow {
+            used_percent: 55.0,
+            window_minutes: Some(300),
+            resets_at: None,
+        }),
+        secondary: None,
+        credits: None,
+        plan_type: None,
+    }));
+    chat.on_cached_rate_limit_snapshot(
+        RateLimitSnapshot {
+            primary: None,
+            secondary: None,
+            credits: None,
+            plan_type: None,
+        },
+        fetched_at,
+    );
+    assert_eq!(
+        chat.rate_limit_snapshot
+            .as_ref()
+            .and_then(|display| display.primary.as_ref())
+            .map(|window| window.used_percent),
+        Some(55.0)
+    );
+}
+
 #[tokio::test]
 async fn rate_limit_snapshot_updates_and_retains_plan_type() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
@@ -1376,6 +1499,77 @@ async fn rate_limit_snapshot_updates_and_retains_plan_type() {
     assert_eq!(chat.plan_type, Some(PlanType::Pro));
 }
 
+#[tokio::test]
+async fn relogin_warning_prefills_login_once_after_refresh_failure() {
+    let (mut chat, mut rx, _) = make_chatwidget_manual(None).await;
+    chat.auth_manager =
+        AuthManager::from_auth_for_testing(CodexAuth::create_dummy_chatgpt_auth_for_testing());
+
+    chat.maybe_warn_relogin();
+    assert!(drain_insert_history(&mut rx).is_empty());
+
+    chat.auth_manager
+        .record_refresh_failure_for_testing(RefreshTokenFailedError::new(
+            RefreshTokenFailedReason::Revoked,
+            "refresh token revoked",
+        ));
+    let warning = tr(chat.config.language, "chatwidget.login.expired_warning");
+    let warnings_shown = |rx: &mut tokio::sync::mpsc::UnboundedReceiver<AppEvent>| {
+        drain_insert_history(rx)
+            .iter()
+            .filter(|cell| cell_shows_text(cell, warning))
+            .count()
+    };
+    chat.on_task_complete(None, false);
+    assert_eq!(warnings_shown(&mut rx), 1);
+    assert_eq!(chat.bottom_pane.composer_text(), "/login");
+
+    // The same problem is not reported again on the next turn.
+    chat.bottom_pane
+        .set_composer_text(String::new(), Vec::new(), Vec::new());
+    chat.on_task_complete(None, false);
+    assert_eq!(warnings_shown(&mut rx), 0);
+    assert_eq!(chat.bottom_pane.composer_text(), "");
+}
+
+#[tokio::test]
+async fn relogin_warning_mentions_due_refresh_without_prefilling() {
+    let (mut chat, mut rx, _) = make_chatwidget_manual(None).await;
+    chat.auth_manager = AuthManager::from_auth_for_testing(
+        CodexAuth::create_dummy_chatgpt_auth_refreshed_at_for_testing(
+            chrono::Utc::now() - chrono::Duration::days(30),
+        ),
+    );
+
+    chat.maybe_warn_relogin();
+    let cells = drain_insert_history(&mut rx);
+    let language = chat.config.language;
+    assert_eq!(cells.len(), 1);
+    assert!(
+        cell_shows_text(
+            &cells[0],
+            tr(language, "chatwidget.login.refresh_due_warning")
+        ),
+        "unexpected warning: {cells:?}"
+    );
+    assert_eq!(chat.bottom_pane.composer_text(), "");
+
+    // A failed refresh escalates to the expired warning.
+    chat.auth_manager
+        .record_refresh_failure_for_testing(RefreshTokenFailedError::new(
+            RefreshTokenFailedReason::Expired,
+            "refresh token expired",
+        ));
+    chat.maybe_warn_relogin();
+    let cells = drain_insert_history(&mut rx);
+    assert_eq!(cells.len(), 1);
+    assert!(
+        cell_shows_text(&cells[0], tr(language, "chatwidget.login.expired_warning")),
+        "unexpected warning: {cells:?}"
+    );
+    assert_eq!(chat.bottom_pane.composer_text(), "/login");
+}
+
 #[tokio::test]
 async fn rate_limit_switch_prompt_skips_when_on_lower_cost_model() {
     let (mut chat, _, _) = make_chatwidget_manual(Some(NUDGE_MODEL_SLUG)).await;
@@ -1463,6 +1657,55 @@ async fn rate_limit_switch_prompt_popup_snapshot() {
     assert_snapshot!("rate_limit_switch_prompt_popup", popup);
 }
 
+#[tokio::test]
+async fn untrusted_repo_skills_prompt_once_and_emit_trust_event() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(Some("gpt-5")).await;
+    let skill_path = chat.config.cwd.join(".codex/skills/deploy/SKILL.md");
+    let response = ListSkillsResponseEvent {
+        skills: vec![SkillsListEntry {
+            cwd: chat.config.cwd.clone(),
+            skills: vec![codex_core::protocol::SkillMetadata {
+                name: "deploy".to_string(),
+                description: "Deploy the app".to_string(),
+                short_description: None,
+                interface: None,
+                dependencies: Some(codex_core::protocol::SkillDependencies {
+                    tools: vec![codex_core::protocol::SkillToolDependency {
+                        r#type: "mcp".to_string(),
+                        value: "github".to_string(),
+                        description: None,
+                        transport: None,
+                        command: None,
+                        url: None,
+                    }],
+                }),
+                path: skill_path.clone(),
+                scope: SkillScope::Repo,
+                enabled: false,
+            }],
+            errors: Vec::new(),
+        }],
+    };
+
+    chat.set_skills_from_response(&response);
+    let popup = render_bottom_popup(&chat, 80);
+    assert!(popup.contains("Trust repository skills?"), "{popup}");
+    assert!(popup.contains("Requires: mcp:github"), "{popup}");
+
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    let mut trusted_paths = None;
+    while let Ok(event) = rx.try_recv() {
+        if let AppEvent::TrustSkills { paths } = event {
+            trusted_paths = Some(paths);
+        }
+    }
+    assert_eq!(trusted_paths, Some(vec![skill_path]));
+
+    chat.set_skills_from_response(&response);
+    let popup = render_bottom_popup(&chat, 80);
+    assert!(!popup.contains("Trust repository skills?"), "{popup}");
+}
+
 #[tokio::test]
 async fn plan_implementation_popup_snapshot() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(Some("gpt-5")).await;
@@ -2074,11 +2317,44 @@ fn end_exec(
             aggregated_output: aggregated.clone(),
             exit_code,
             duration: std::time::Duration::from_millis(5),
+            timed_out: false,
             formatted_output: aggregated,
+            provenance: None,
         }),
     });
 }
 
+#[tokio::test]
+async fn exec_output_deltas_are_applied_once_per_frame() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.bottom_pane.set_task_running(true);
+    begin_exec(&mut chat, "call-1", "cargo build");
+    let revision = chat.active_cell_revision;
+
+    for chunk in ["Compiling a\n", "Compiling b\n", "Compiling c\n"] {
+        chat.handle_codex_event(Event {
+            id: "turn-1".into(),
+            msg: EventMsg::ExecCommandOutputDelta(ExecCommandOutputDeltaEvent {
+                call_id: "call-1".to_string(),
+                stream: ExecOutputStream::Stdout,
+                chunk: chunk.as_bytes().to_vec(),
+            }),
+        });
+    }
+    assert_eq!(
+        chat.pending_exec_output,
+        vec![(
+            "call-1".to_string(),
+            "Compiling a\nCompiling b\nCompiling c\n".to_string()
+        )]
+    );
+    assert_eq!(chat.active_cell_revision, revision);
+
+    chat.flush_pending_exec_output();
+    assert!(chat.pending_exec_output.is_empty());
+    assert_eq!(chat.active_cell_revision, revision.wrapping_add(1));
+}
+
 fn active_blob(chat: &ChatWidget) -> String {
     let lines = chat
         .active_cell
@@ -2535,7 +2811,9 @@ async fn exec_end_without_begin_uses_event_command() {
             aggregated_output: "done".to_string(),
             exit_code: 0,
             duration: std::time::Duration::from_millis(5),
+            timed_out: false,
             formatted_output: "done".to_string(),
+            provenance: None,
         }),
     });
 
@@ -2827,6 +3105,30 @@ async fn unified_exec_non_empty_then_empty_snapshots() {
     assert_snapshot!("unified_exec_non_empty_then_empty_after", combined);
 }
 
+/// Selecting the staged option from the review popup submits a review of the
+/// staged changes.
+#[tokio::test]
+async fn review_popup_staged_action_submits_staged_review() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.open_review_popup();
+
+    // Move selection down to the third item: "Review staged changes"
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    let mut found = false;
+    while let Ok(ev) = rx.try_recv() {
+        if let AppEvent::CodexOp(Op::Review { review_request }) = ev {
+            assert_eq!(review_request.target, ReviewTarget::StagedChanges);
+            found = true;
+            break;
+        }
+    }
+    assert!(found, "expected a staged review to be submitted");
+}
+
 /// Selecting the custom prompt option from the review popup sends
 /// OpenReviewCustomPrompt to the app event channel.
 #[tokio::test]
@@ -2836,7 +3138,9 @@ async fn review_popup_custom_prompt_action_sends_event() {
     // Open the preset selection popup
     chat.open_review_popup();
 
-    // Move selection down to the fourth item: "Custom review instructions"
+    // Move selection down to the sixth item: "Custom review instructions"
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
     chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
     chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
     chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
@@ -3027,6 +3331,213 @@ async fn plan_slash_command_with_args_submits_prompt_in_plan_mode() {
     assert_eq!(chat.active_collaboration_mode_kind(), ModeKind::Plan);
 }
 
+#[tokio::test]
+async fn density_slash_command_updates_and_persists() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.bottom_pane
+        .set_composer_text("/density loud".to_string(), Vec::new(), Vec::new());
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    assert!(
+        !std::iter::from_fn(|| rx.try_recv().ok())
+            .any(|event| matches!(event, AppEvent::PersistTranscriptDensity { .. }))
+    );
+
+    chat.bottom_pane
+        .set_composer_text("/density compact".to_string(), Vec::new(), Vec::new());
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    let events: Vec<AppEvent> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+    assert!(events.iter().any(|event| matches!(
+        event,
+        AppEvent::UpdateTranscriptDensity(TranscriptDensity::Compact)
+    )));
+    assert!(events.iter().any(|event| matches!(
+        event,
+        AppEvent::PersistTranscriptDensity {
+            density: TranscriptDensity::Compact
+        }
+    )));
+}
+
+#[tokio::test]
+async fn dry_run_slash_command_arms_and_disarms() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(None).await;
+
+    chat.bottom_pane
+        .set_composer_text("/dry-run".to_string(), Vec::new(), Vec::new());
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    assert_matches!(op_rx.try_recv(), Ok(Op::SetDryRun { enabled: true }));
+
+    chat.bottom_pane
+        .set_composer_text("/dry-run off".to_string(), Vec::new(), Vec::new());
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    assert_matches!(op_rx.try_recv(), Ok(Op::SetDryRun { enabled: false }));
+}
+
+#[tokio::test]
+async fn dry_run_plan_sends_the_checked_calls() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    let planned = |call_id: &str, summary: &str| PlannedToolCall {
+        call_id: call_id.to_string(),
+        tool_name: "shell_command".to_string(),
+        summary: summary.to_string(),
+        files: Vec::new(),
+    };
+
+    chat.handle_codex_event(Event {
+        id: "turn-1".to_string(),
+        msg: EventMsg::DryRunPlan(DryRunPlanEvent {
+            turn_id: "turn-1".to_string(),
+            calls: vec![
+                planned("call-1", "rm -rf build"),
+                planned("call-2", "cargo test"),
+            ],
+        }),
+    });
+    let history = drain_insert_history(&mut rx)
+        .iter()
+        .map(|lines| lines_to_single_string(lines))
+        .collect::<String>();
+    assert!(history.contains("2 proposed tool call"), "{history}");
+    assert!(history.contains("rm -rf build"), "{history}");
+
+    // Uncheck the first call, then confirm.
+    chat.handle_key_event(KeyEvent::from(KeyCode::Char(' ')));
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    let decision = std::iter::from_fn(|| rx.try_recv().ok()).find_map(|event| match event {
+        AppEvent::CodexOp(op @ Op::DryRunDecision { .. }) => Some(op),
+        _ => None,
+    });
+    assert_eq!(
+        decision,
+        Some(Op::DryRunDecision {
+            id: "turn-1".to_string(),
+            approved_call_ids: vec!["call-2".to_string()],
+        })
+    );
+}
+
+#[tokio::test]
+async fn guardrail_blocked_lists_the_protected_paths() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    let cwd = chat.config.cwd.clone();
+
+    chat.handle_codex_event(Event {
+        id: "turn-1".to_string(),
+        msg: EventMsg::GuardrailBlocked(GuardrailBlockedEvent {
+            call_id: "call-1".to_string(),
+            turn_id: "turn-1".to_string(),
+            paths: vec![cwd.join(".env")],
+            patterns: vec![".env".to_string()],
+            reason: GuardrailReason::ProtectedPaths,
+        }),
+    });
+    let history = drain_insert_history(&mut rx)
+        .iter()
+        .map(|lines| lines_to_single_string(lines))
+        .collect::<String>();
+    assert!(history.contains("protected_paths"), "{history}");
+    assert!(history.contains("- .env"), "{history}");
+}
+
+#[tokio::test]
+async fn ui_switch_waits_for_a_recorded_thread() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.bottom_pane
+        .set_composer_text("/ui switch".to_string(), Vec::new(), Vec::new());
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    assert!(
+        !std::iter::from_fn(|| rx.try_recv().ok())
+            .any(|event| matches!(event, AppEvent::SwitchFrontend)),
+        "switching must wait until the thread has a rollout to resume"
+    );
+
+    let rollout_file = NamedTempFile::new().unwrap();
+    let configured = codex_core::protocol::SessionConfiguredEvent {
+        session_id: ThreadId::new(),
+        forked_from_id: None,
+        thread_name: None,
+        model: "test-model".to_string(),
+        model_provider_id: "test-provider".to_string(),
+        approval_policy: AskForApproval::Never,
+        sandbox_policy: SandboxPolicy::ReadOnly,
+        cwd: PathBuf::from("/home/user/project"),
+        reasoning_effort: Some(ReasoningEffortConfig::default()),
+        history_log_id: 0,
+        history_entry_count: 0,
+        initial_messages: None,
+        network_proxy: None,
+        rollout_path: Some(rollout_file.path().to_path_buf()),
+    };
+    chat.handle_codex_event(Event {
+        id: "configured".into(),
+        msg: EventMsg::SessionConfigured(configured),
+    });
+
+    chat.bottom_pane
+        .set_composer_text("/ui switch".to_string(), Vec::new(), Vec::new());
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    assert!(
+        std::iter::from_fn(|| rx.try_recv().ok())
+            .any(|event| matches!(event, AppEvent::SwitchFrontend))
+    );
+}
+
+#[tokio::test]
+async fn compare_slash_command_routes_next_prompt_to_compare() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
+
+    let rollout_file = NamedTempFile::new().unwrap();
+    let configured = codex_core::protocol::SessionConfiguredEvent {
+        session_id: ThreadId::new(),
+        forked_from_id: None,
+        thread_name: None,
+        model: "test-model".to_string(),
+        model_provider_id: "test-provider".to_string(),
+        approval_policy: AskForApproval::Never,
+        sandbox_policy: SandboxPolicy::ReadOnly,
+        cwd: PathBuf::from("/home/user/project"),
+        reasoning_effort: Some(ReasoningEffortConfig::default()),
+        history_log_id: 0,
+        history_entry_count: 0,
+        initial_messages: None,
+        network_proxy: None,
+        rollout_path: Some(rollout_file.path().to_path_buf()),
+    };
+    chat.handle_codex_event(Event {
+        id: "configured".into(),
+        msg: EventMsg::SessionConfigured(configured),
+    });
+
+    chat.bottom_pane
+        .set_composer_text("/compare other-model".to_string(), Vec::new(), Vec::new());
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    chat.bottom_pane
+        .set_composer_text("which is faster?".to_string(), Vec::new(), Vec::new());
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+
+    let compare = std::iter::from_fn(|| rx.try_recv().ok()).find_map(|event| match event {
+        AppEvent::StartCompare { op, model_b } => Some((op, model_b)),
+        _ => None,
+    });
+    let Some((Op::UserTurn { items, .. }, model_b)) = compare else {
+        panic!("expected StartCompare with a user turn");
+    };
+    assert_eq!(model_b, "other-model");
+    assert_eq!(
+        items,
+        vec![UserInput::Text {
+            text: "which is faster?".to_string(),
+            text_elements: Vec::new(),
+        }]
+    );
+    assert!(
+        !std::iter::from_fn(|| op_rx.try_recv().ok()).any(|op| matches!(op, Op::UserTurn { .. })),
+        "the compared prompt must not run on the current thread"
+    );
+}
+
 #[tokio::test]
 async fn collaboration_modes_defaults_to_code_on_startup() {
     let codex_home = tempdir().expect("tempdir");
@@ -3281,6 +3792,15 @@ async fn slash_fork_requests_current_fork() {
     assert_matches!(rx.try_recv(), Ok(AppEvent::ForkCurrentSession));
 }
 
+#[tokio::test]
+async fn slash_branches_requests_branch_tree() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.dispatch_command(SlashCommand::Branches);
+
+    assert_matches!(rx.try_recv(), Ok(AppEvent::OpenBranches));
+}
+
 #[tokio::test]
 async fn slash_rollout_displays_current_path() {
     let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
@@ -3545,6 +4065,7 @@ async fn interrupt_exec_marks_failed_snapshot() {
         id: "call-int".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -3579,6 +4100,7 @@ async fn interrupted_turn_error_message_snapshot() {
         id: "task-1".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -4141,6 +4663,49 @@ async fn model_reasoning_selection_popup_snapshot() {
     assert_snapshot!("model_reasoning_selection_popup", popup);
 }
 
+#[tokio::test]
+async fn ctrl_r_cycles_reasoning_effort_for_next_turn() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(Some("gpt-5.1-codex-max")).await;
+    chat.set_reasoning_effort(Some(ReasoningEffortConfig::High));
+
+    chat.cycle_reasoning_effort();
+
+    let events: Vec<AppEvent> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+    assert!(
+        events.iter().any(|event| matches!(
+            event,
+            AppEvent::CodexOp(Op::OverrideTurnContext {
+                model: None,
+                effort: Some(Some(ReasoningEffortConfig::XHigh)),
+                ..
+            })
+        )),
+        "expected an effort override for the next level, got: {events:?}"
+    );
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, AppEvent::PersistModelSelection { .. })),
+        "cycling should not persist by default"
+    );
+
+    chat.config.tui_persist_reasoning_toggle = true;
+    chat.set_reasoning_effort(Some(ReasoningEffortConfig::XHigh));
+    chat.cycle_reasoning_effort();
+
+    let events: Vec<AppEvent> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+    assert!(
+        events.iter().any(|event| matches!(
+            event,
+            AppEvent::PersistModelSelection {
+                effort: Some(ReasoningEffortConfig::Low),
+                ..
+            }
+        )),
+        "expected the wrapped-around effort to be persisted, got: {events:?}"
+    );
+}
+
 #[tokio::test]
 async fn model_reasoning_selection_popup_extra_high_warning_snapshot() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(Some("gpt-5.1-codex-max")).await;
@@ -4623,6 +5188,39 @@ async fn approval_modal_patch_snapshot() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn status_counts_responses_cut_off_by_interrupts() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    for cut_off_output_tokens in [Some(120), None, Some(30)] {
+        chat.handle_codex_event(Event {
+            id: "turn-1".into(),
+            msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
+                reason: TurnAbortReason::Interrupted,
+                cut_off_output_tokens,
+            }),
+        });
+    }
+    assert_eq!(
+        chat.interrupted_responses,
+        InterruptedResponses {
+            count: 2,
+            streamed_tokens: 150,
+        }
+    );
+
+    drain_insert_history(&mut rx);
+    chat.add_status_output();
+    let status = drain_insert_history(&mut rx)
+        .iter()
+        .map(|lines| lines_to_single_string(lines))
+        .collect::<String>();
+    assert!(
+        status.contains("2 responses cut off (~150 output tokens streamed)"),
+        "unexpected /status output: {status}"
+    );
+}
+
 #[tokio::test]
 async fn interrupt_restores_queued_messages_into_composer() {
     let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
@@ -4642,6 +5240,7 @@ async fn interrupt_restores_queued_messages_into_composer() {
         id: "turn-1".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -4680,6 +5279,7 @@ async fn interrupt_prepends_queued_messages_before_existing_composer_text() {
         id: "turn-1".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -4708,6 +5308,7 @@ async fn interrupt_clears_unified_exec_processes() {
         id: "turn-1".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -4728,6 +5329,7 @@ async fn review_ended_keeps_unified_exec_processes() {
         id: "turn-1".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::ReviewEnded,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -4771,6 +5373,7 @@ async fn interrupt_clears_unified_exec_wait_streak_snapshot() {
         id: "turn-1".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -5634,6 +6237,7 @@ async fn status_line_branch_refreshes_after_interrupt() {
         id: "turn-1".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -5917,7 +6521,9 @@ async fn chatwidget_exec_and_status_layout_vt100_snapshot() {
             aggregated_output: String::new(),
             exit_code: 0,
             duration: std::time::Duration::from_millis(16000),
+            timed_out: false,
             formatted_output: String::new(),
+            provenance: None,
         }),
     });
     chat.handle_codex_event(Event {
@@ -6111,3 +6717,55 @@ async fn review_queues_user_messages_snapshot() {
     .unwrap();
     assert_snapshot!(term.backend().vt100().screen().contents());
 }
+
+#[tokio::test]
+async fn quitting_after_a_turn_waits_for_the_exit_summary() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(None).await;
+    chat.begin_shutdown();
+    assert_matches!(op_rx.try_recv(), Ok(Op::Shutdown));
+
+    chat.handle_codex_event(Event {
+        id: "turn".to_string(),
+        msg: EventMsg::TurnComplete(TurnCompleteEvent {
+            last_agent_message: Some("Fixed it.".to_string()),
+        }),
+    });
+    while op_rx.try_recv().is_ok() {}
+    chat.begin_shutdown();
+    assert_matches!(op_rx.try_recv(), Ok(Op::SummarizeSession));
+
+    chat.handle_codex_event(Event {
+        id: "summary".to_string(),
+        msg: EventMsg::SessionSummary(SessionSummaryEvent {
+            bullets: vec!["Fixed the login redirect".to_string()],
+        }),
+    });
+    assert_matches!(op_rx.try_recv(), Ok(Op::Shutdown));
+    assert_eq!(
+        chat.exit_summary_bullets(),
+        vec!["Fixed the login redirect".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn turn_sources_add_a_sources_footer() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.handle_codex_event(Event {
+        id: "turn".to_string(),
+        msg: EventMsg::TurnSources(TurnSourcesEvent {
+            citations: vec![WebCitation {
+                url: "https://tokio.rs".to_string(),
+                title: Some("Tokio".to_string()),
+                snippet: None,
+            }],
+        }),
+    });
+
+    let cells = drain_insert_history(&mut rx);
+    assert_eq!(cells.len(), 1);
+    assert_eq!(
+        lines_to_single_string(&cells[0]),
+        "• Sources (1)\n  └ 1. Tokio https://tokio.rs\n"
+    );
+}
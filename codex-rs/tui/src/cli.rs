@@ -4,7 +4,7 @@ use codex_common::ApprovalModeCliArg;
 use codex_common::CliConfigOverrides;
 use std::path::PathBuf;
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Clone)]
 #[command(version)]
 pub struct Cli {
     /// 可选，启动会话时的初始提示词。
@@ -33,6 +33,10 @@ pub struct Cli {
     #[clap(skip)]
     pub resume_show_all: bool,
 
+    /// 内部用途：由 `codex resume --force-takeover` 设置，接管正被其他进程使用的会话。
+    #[clap(skip)]
+    pub resume_force_takeover: bool,
+
     // Internal controls set by the top-level `codex fork` subcommand.
     // These are not exposed as user flags on the base `codex` command.
     #[clap(skip)]
@@ -93,6 +97,18 @@ pub struct Cli {
     )]
     pub dangerously_bypass_approvals_and_sandbox: bool,
 
+    /// 只读探索模式：沙箱拒绝一切写入，apply_patch 仅展示为提案而不落盘。
+    #[arg(long = "read-only", default_value_t = false)]
+    pub read_only: bool,
+
+    /// 离线启动：跳过启动时非必需的网络请求（模型列表、额度与套餐、更新检查、应用连接器），改用本地缓存。
+    #[arg(long = "offline", default_value_t = false)]
+    pub offline: bool,
+
+    /// 使用 ChatGPT 登录时不自动打开浏览器，只显示登录链接。
+    #[arg(long = "no-browser", default_value_t = false)]
+    pub no_browser: bool,
+
     /// 将代理的工作根目录切换到指定路径。
     #[clap(long = "cd", short = 'C', value_name = "DIR")]
     pub cwd: Option<PathBuf>,
@@ -114,6 +130,18 @@ pub struct Cli {
     #[arg(long = "no-alt-screen", default_value_t = false)]
     pub no_alt_screen: bool,
 
+    /// 屏幕阅读器友好模式：关闭动画，状态、审批与菜单以追加行的形式输出。
+    /// 等效于 -c tui.accessible=true。
+    /// Screen-reader friendly mode; equivalent to `-c tui.accessible=true`.
+    #[arg(long = "a11y", default_value_t = false)]
+    pub accessible: bool,
+
+    /// 纯文本逐行界面：不使用全屏 TUI，逐行打印事件并通过标准输入应答审批。
+    /// `TERM=dumb` 时自动启用。
+    /// Line-oriented output without the full-screen TUI; implied by `TERM=dumb`.
+    #[arg(long = "plain", default_value_t = false)]
+    pub plain: bool,
+
     #[clap(skip)]
     pub config_overrides: CliConfigOverrides,
 }
@@ -15,6 +15,57 @@ pub enum PasteImageError {
     NoImage(String),
     EncodeFailed(String),
     IoError(String),
+    /// Every clipboard source was tried; each entry says why it gave nothing.
+    NoSource(Vec<ClipboardProbe>),
+}
+
+/// Outcome of one clipboard source tried while looking for an image, so the
+/// error can say what is missing instead of failing generically.
+#[derive(Debug, Clone)]
+pub enum ClipboardProbe {
+    /// The native clipboard (arboard) failed.
+    Native(PasteImageError),
+    /// Neither `DISPLAY` nor `WAYLAND_DISPLAY` is set.
+    NoDisplay,
+    /// `WAYLAND_DISPLAY` is set but `wl-paste` is not installed.
+    WlPasteMissing,
+    /// `wl-paste` ran but the Wayland clipboard offers no image.
+    WlPasteNoImage,
+    WlPasteFailed(String),
+    /// The terminal did not answer the OSC 52 clipboard query.
+    Osc52NoReply,
+    /// The terminal answered OSC 52 with something that is not an image.
+    Osc52NotImage,
+}
+
+impl ClipboardProbe {
+    fn to_message(&self, language: Language) -> String {
+        match self {
+            ClipboardProbe::Native(err) => tr_args(
+                language,
+                "clipboard.probe.native",
+                &[("detail", &err.to_message(language))],
+            ),
+            ClipboardProbe::NoDisplay => tr(language, "clipboard.probe.no_display").to_string(),
+            ClipboardProbe::WlPasteMissing => {
+                tr(language, "clipboard.probe.wl_paste_missing").to_string()
+            }
+            ClipboardProbe::WlPasteNoImage => {
+                tr(language, "clipboard.probe.wl_paste_no_image").to_string()
+            }
+            ClipboardProbe::WlPasteFailed(msg) => tr_args(
+                language,
+                "clipboard.probe.wl_paste_failed",
+                &[("detail", msg)],
+            ),
+            ClipboardProbe::Osc52NoReply => {
+                tr(language, "clipboard.probe.osc52_no_reply").to_string()
+            }
+            ClipboardProbe::Osc52NotImage => {
+                tr(language, "clipboard.probe.osc52_not_image").to_string()
+            }
+        }
+    }
 }
 
 impl std::fmt::Display for PasteImageError {
@@ -57,6 +108,18 @@ impl PasteImageError {
                 "clipboard.error.io_error",
                 &[("detail", self.localized_detail(language, msg).as_ref())],
             ),
+            PasteImageError::NoSource(probes) => {
+                let detail = probes
+                    .iter()
+                    .map(|probe| probe.to_message(language))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                tr_args(
+                    language,
+                    "clipboard.error.no_source",
+                    &[("detail", &detail)],
+                )
+            }
         }
     }
 }
@@ -86,9 +149,25 @@ pub struct PastedImageInfo {
 }
 
 /// Capture image from system clipboard, encode to PNG, and return bytes + info.
+///
+/// On Linux, when the native clipboard yields nothing under Wayland, the image
+/// is read through `wl-paste` (wl-clipboard) instead, which also works on
+/// compositors without the data-control protocol arboard relies on.
 #[cfg(not(target_os = "android"))]
 pub fn paste_image_as_png() -> Result<(Vec<u8>, PastedImageInfo), PasteImageError> {
     let _span = tracing::debug_span!("paste_image_as_png").entered();
+    let dyn_img = match read_native_clipboard_image() {
+        Ok(img) => img,
+        #[cfg(target_os = "linux")]
+        Err(err) => read_wayland_clipboard_image(err)?,
+        #[cfg(not(target_os = "linux"))]
+        Err(err) => return Err(err),
+    };
+    encode_png(&dyn_img)
+}
+
+#[cfg(not(target_os = "android"))]
+fn read_native_clipboard_image() -> Result<image::DynamicImage, PasteImageError> {
     tracing::debug!("attempting clipboard image read");
     let mut cb = arboard::Clipboard::new()
         .map_err(|e| PasteImageError::ClipboardUnavailable(e.to_string()))?;
@@ -125,7 +204,13 @@ pub fn paste_image_as_png() -> Result<(Vec<u8>, PastedImageInfo), PasteImageErro
 
         image::DynamicImage::ImageRgba8(rgba_img)
     };
+    Ok(dyn_img)
+}
 
+#[cfg(not(target_os = "android"))]
+fn encode_png(
+    dyn_img: &image::DynamicImage,
+) -> Result<(Vec<u8>, PastedImageInfo), PasteImageError> {
     let mut png: Vec<u8> = Vec::new();
     {
         let span =
@@ -147,6 +232,81 @@ pub fn paste_image_as_png() -> Result<(Vec<u8>, PastedImageInfo), PasteImageErro
     ))
 }
 
+/// Fallback for Linux after the native clipboard failed: read the Wayland
+/// clipboard with `wl-paste`, or explain that there is no graphical session
+/// to read from at all.
+#[cfg(target_os = "linux")]
+fn read_wayland_clipboard_image(
+    error: PasteImageError,
+) -> Result<image::DynamicImage, PasteImageError> {
+    use PasteImageError::ClipboardUnavailable;
+    use PasteImageError::NoImage;
+
+    if !matches!(error, ClipboardUnavailable(_) | NoImage(_)) || is_probably_wsl() {
+        return Err(error);
+    }
+    if std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        if std::env::var_os("DISPLAY").is_none() {
+            return Err(PasteImageError::NoSource(vec![
+                ClipboardProbe::Native(error),
+                ClipboardProbe::NoDisplay,
+            ]));
+        }
+        return Err(error);
+    }
+
+    tracing::debug!("attempting wl-paste clipboard fallback");
+    wl_paste_image()
+        .map_err(|probe| PasteImageError::NoSource(vec![ClipboardProbe::Native(error), probe]))
+}
+
+#[cfg(target_os = "linux")]
+fn wl_paste_image() -> Result<image::DynamicImage, ClipboardProbe> {
+    let listed = run_wl_paste(&["--list-types"])?;
+    let Some(mime) = pick_image_mime_type(&String::from_utf8_lossy(&listed)) else {
+        return Err(ClipboardProbe::WlPasteNoImage);
+    };
+    tracing::debug!("wl-paste offers {mime}");
+    let bytes = run_wl_paste(&["--no-newline", "--type", &mime])?;
+    image::load_from_memory(&bytes).map_err(|e| ClipboardProbe::WlPasteFailed(e.to_string()))
+}
+
+#[cfg(target_os = "linux")]
+fn run_wl_paste(args: &[&str]) -> Result<Vec<u8>, ClipboardProbe> {
+    let output = std::process::Command::new("wl-paste")
+        .args(args)
+        .output()
+        .map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => ClipboardProbe::WlPasteMissing,
+            _ => ClipboardProbe::WlPasteFailed(err.to_string()),
+        })?;
+    // wl-paste exits non-zero when the clipboard is empty.
+    if !output.status.success() {
+        return Err(ClipboardProbe::WlPasteNoImage);
+    }
+    Ok(output.stdout)
+}
+
+/// Choose the type to request from `wl-paste --list-types` output: PNG when
+/// offered, otherwise the first image type we can decode.
+#[cfg(target_os = "linux")]
+fn pick_image_mime_type(listed: &str) -> Option<String> {
+    let image_types: Vec<&str> = listed
+        .lines()
+        .map(str::trim)
+        .filter(|mime| mime.starts_with("image/"))
+        .collect();
+    image_types
+        .iter()
+        .find(|mime| **mime == "image/png")
+        .or_else(|| {
+            image_types
+                .iter()
+                .find(|mime| image::ImageFormat::from_mime_type(mime).is_some())
+        })
+        .map(|mime| (*mime).to_string())
+}
+
 /// Android/Termux does not support arboard; return a clear error.
 #[cfg(target_os = "android")]
 pub fn paste_image_as_png() -> Result<(Vec<u8>, PastedImageInfo), PasteImageError> {
@@ -160,21 +320,7 @@ pub fn paste_image_as_png() -> Result<(Vec<u8>, PastedImageInfo), PasteImageErro
 pub fn paste_image_to_temp_png() -> Result<(PathBuf, PastedImageInfo), PasteImageError> {
     // First attempt: read image from system clipboard via arboard (native paths or image data).
     match paste_image_as_png() {
-        Ok((png, info)) => {
-            // Create a unique temporary file with a .png suffix to avoid collisions.
-            let tmp = Builder::new()
-                .prefix("codex-clipboard-")
-                .suffix(".png")
-                .tempfile()
-                .map_err(|e| PasteImageError::IoError(e.to_string()))?;
-            std::fs::write(tmp.path(), &png)
-                .map_err(|e| PasteImageError::IoError(e.to_string()))?;
-            // Persist the file (so it remains after the handle is dropped) and return its PathBuf.
-            let (_file, path) = tmp
-                .keep()
-                .map_err(|e| PasteImageError::IoError(e.error.to_string()))?;
-            Ok((path, info))
-        }
+        Ok((png, info)) => Ok((write_temp_png(&png)?, info)),
         Err(e) => {
             #[cfg(target_os = "linux")]
             {
@@ -188,6 +334,163 @@ pub fn paste_image_to_temp_png() -> Result<(PathBuf, PastedImageInfo), PasteImag
     }
 }
 
+#[cfg(not(target_os = "android"))]
+fn write_temp_png(png: &[u8]) -> Result<PathBuf, PasteImageError> {
+    // Create a unique temporary file with a .png suffix to avoid collisions.
+    let tmp = Builder::new()
+        .prefix("codex-clipboard-")
+        .suffix(".png")
+        .tempfile()
+        .map_err(|e| PasteImageError::IoError(e.to_string()))?;
+    std::fs::write(tmp.path(), png).map_err(|e| PasteImageError::IoError(e.to_string()))?;
+    // Persist the file (so it remains after the handle is dropped) and return its PathBuf.
+    let (_file, path) = tmp
+        .keep()
+        .map_err(|e| PasteImageError::IoError(e.error.to_string()))?;
+    Ok(path)
+}
+
+/// Whether reading the terminal's clipboard over OSC 52 is worth a try after
+/// `error`: over SSH, or without any graphical session, the terminal may be
+/// the only clipboard within reach.
+pub(crate) fn osc52_fallback_applies(error: &PasteImageError) -> bool {
+    if !cfg!(all(unix, not(target_os = "android"))) {
+        return false;
+    }
+    match error {
+        PasteImageError::ClipboardUnavailable(_) | PasteImageError::NoImage(_) => is_ssh_session(),
+        PasteImageError::NoSource(probes) => {
+            is_ssh_session()
+                || probes
+                    .iter()
+                    .any(|probe| matches!(probe, ClipboardProbe::NoDisplay))
+        }
+        PasteImageError::EncodeFailed(_) | PasteImageError::IoError(_) => false,
+    }
+}
+
+fn is_ssh_session() -> bool {
+    ["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some())
+}
+
+/// Ask the terminal for its clipboard with an OSC 52 query and save the image
+/// it holds, if any. Most terminals only answer when clipboard reads are
+/// enabled in their settings.
+///
+/// The caller must stop reading terminal input first (`Tui::pause_events`),
+/// otherwise the reply is consumed as key presses.
+#[cfg(all(unix, not(target_os = "android")))]
+pub(crate) fn paste_image_via_osc52(
+    error: PasteImageError,
+) -> Result<(PathBuf, PastedImageInfo), PasteImageError> {
+    let mut probes = match error {
+        PasteImageError::NoSource(probes) => probes,
+        other => vec![ClipboardProbe::Native(other)],
+    };
+    tracing::debug!("attempting OSC 52 clipboard read");
+    let probe = match osc52::query_clipboard() {
+        None => ClipboardProbe::Osc52NoReply,
+        Some(payload) => match osc52::payload_image(&payload) {
+            Some(img) => {
+                let (png, info) = encode_png(&img)?;
+                return Ok((write_temp_png(&png)?, info));
+            }
+            None => ClipboardProbe::Osc52NotImage,
+        },
+    };
+    probes.push(probe);
+    Err(PasteImageError::NoSource(probes))
+}
+
+#[cfg(not(all(unix, not(target_os = "android"))))]
+pub(crate) fn paste_image_via_osc52(
+    error: PasteImageError,
+) -> Result<(PathBuf, PastedImageInfo), PasteImageError> {
+    Err(error)
+}
+
+#[cfg(all(unix, not(target_os = "android")))]
+mod osc52 {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+    use std::io::Read;
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+    use std::time::Duration;
+    use std::time::Instant;
+
+    /// Query the `c` (clipboard) selection. Terminals that allow reads answer
+    /// with `ESC ] 52 ; c ; <base64> BEL` (or `ST` instead of `BEL`).
+    const QUERY: &[u8] = b"\x1b]52;c;?\x07";
+    const REPLY_PREFIX: &[u8] = b"\x1b]52;";
+    /// How long to wait for the reply to start, and between chunks of it.
+    const REPLY_TIMEOUT: Duration = Duration::from_millis(500);
+
+    /// Send the query to the controlling terminal and return the decoded
+    /// clipboard contents, or `None` when the terminal does not answer.
+    pub(super) fn query_clipboard() -> Option<Vec<u8>> {
+        let mut tty = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open("/dev/tty")
+            .ok()?;
+        tty.write_all(QUERY).ok()?;
+        tty.flush().ok()?;
+
+        let mut reply = Vec::new();
+        let mut buf = [0u8; 8192];
+        let mut deadline = Instant::now() + REPLY_TIMEOUT;
+        while Instant::now() < deadline {
+            match tty.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => {
+                    reply.extend_from_slice(&buf[..n]);
+                    if let Some(payload) = parse_reply(&reply) {
+                        return Some(payload);
+                    }
+                    deadline = Instant::now() + REPLY_TIMEOUT;
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(_) => break,
+            }
+        }
+        None
+    }
+
+    /// Decoded payload of a complete OSC 52 reply, or `None` while the reply
+    /// has not fully arrived. A payload that is not valid base64 decodes to
+    /// nothing.
+    pub(super) fn parse_reply(reply: &[u8]) -> Option<Vec<u8>> {
+        let start = reply
+            .windows(REPLY_PREFIX.len())
+            .position(|window| window == REPLY_PREFIX)?
+            + REPLY_PREFIX.len();
+        let rest = &reply[start..];
+        let selection_end = rest.iter().position(|&b| b == b';')?;
+        let body = &rest[selection_end + 1..];
+        let end = body.iter().position(|&b| b == 0x07 || b == 0x1b)?;
+        Some(BASE64_STANDARD.decode(&body[..end]).unwrap_or_default())
+    }
+
+    /// The clipboard holds text over OSC 52, so an image arrives either as
+    /// raw image bytes or as a `data:image/...;base64,` URL.
+    pub(super) fn payload_image(payload: &[u8]) -> Option<image::DynamicImage> {
+        if let Ok(img) = image::load_from_memory(payload) {
+            return Some(img);
+        }
+        let text = std::str::from_utf8(payload).ok()?.trim();
+        let (_, data) = text.strip_prefix("data:image/")?.split_once(";base64,")?;
+        let bytes = BASE64_STANDARD.decode(data).ok()?;
+        image::load_from_memory(&bytes).ok()
+    }
+}
+
 /// Attempt WSL fallback for clipboard image paste.
 ///
 /// If clipboard is unavailable (common under WSL because arboard cannot access
@@ -200,8 +503,9 @@ fn try_wsl_clipboard_fallback(
 ) -> Result<(PathBuf, PastedImageInfo), PasteImageError> {
     use PasteImageError::ClipboardUnavailable;
     use PasteImageError::NoImage;
+    use PasteImageError::NoSource;
 
-    if !is_probably_wsl() || !matches!(error, ClipboardUnavailable(_) | NoImage(_)) {
+    if !is_probably_wsl() || !matches!(error, ClipboardUnavailable(_) | NoImage(_) | NoSource(_)) {
         return Err(error.clone());
     }
 
@@ -586,3 +890,74 @@ mod pasted_paths_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod clipboard_fallback_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn prefers_png_from_wl_paste_types() {
+        assert_eq!(
+            pick_image_mime_type("text/plain\nimage/jpeg\nimage/png\n"),
+            Some("image/png".to_string())
+        );
+        assert_eq!(
+            pick_image_mime_type("image/x-unknown\nimage/jpeg\n"),
+            Some("image/jpeg".to_string())
+        );
+        assert_eq!(pick_image_mime_type("text/plain\nUTF8_STRING\n"), None);
+    }
+
+    #[test]
+    fn no_source_error_lists_every_probe() {
+        let err = PasteImageError::NoSource(vec![
+            ClipboardProbe::Native(PasteImageError::ClipboardUnavailable("timeout".into())),
+            ClipboardProbe::WlPasteMissing,
+            ClipboardProbe::Osc52NoReply,
+        ]);
+        let message = err.to_message(Language::En);
+        assert!(message.contains("timeout"), "{message}");
+        assert!(message.contains("wl-clipboard"), "{message}");
+        assert!(message.contains("OSC 52"), "{message}");
+    }
+
+    #[cfg(all(unix, not(target_os = "android")))]
+    mod osc52_tests {
+        use super::super::osc52::*;
+        use base64::Engine;
+        use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+        use pretty_assertions::assert_eq;
+
+        fn tiny_png() -> Vec<u8> {
+            let img = image::DynamicImage::ImageRgba8(image::RgbaImage::new(2, 1));
+            let mut png = Vec::new();
+            img.write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+                .expect("encode png");
+            png
+        }
+
+        #[test]
+        fn parses_replies_terminated_by_bel_or_st() {
+            let encoded = BASE64_STANDARD.encode("hello");
+            let bel = format!("\x1b]52;c;{encoded}\x07");
+            let st = format!("\x1b]52;c;{encoded}\x1b\\");
+            assert_eq!(parse_reply(bel.as_bytes()), Some(b"hello".to_vec()));
+            assert_eq!(parse_reply(st.as_bytes()), Some(b"hello".to_vec()));
+            assert_eq!(parse_reply(format!("\x1b]52;c;{encoded}").as_bytes()), None);
+        }
+
+        #[test]
+        fn accepts_raw_images_and_data_urls_only() {
+            let png = tiny_png();
+            let data_url = format!("data:image/png;base64,{}", BASE64_STANDARD.encode(&png));
+
+            let img = payload_image(&png).expect("raw png");
+            assert_eq!((img.width(), img.height()), (2, 1));
+            assert!(payload_image(data_url.as_bytes()).is_some());
+            assert!(payload_image(b"just some copied text").is_none());
+            assert!(payload_image(b"").is_none());
+        }
+    }
+}
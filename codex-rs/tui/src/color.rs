@@ -11,6 +11,17 @@ pub(crate) fn blend(fg: (u8, u8, u8), bg: (u8, u8, u8), alpha: f32) -> (u8, u8,
     (r, g, b)
 }
 
+/// Color actually sent to the terminal for a cell or span. With `NO_COLOR` set
+/// every color collapses to `Reset`, so only modifiers (bold, dim, italic, ...)
+/// reach the screen.
+pub(crate) fn terminal_color(color: ratatui::style::Color) -> ratatui::style::Color {
+    if codex_core::terminal::no_color_requested() {
+        ratatui::style::Color::Reset
+    } else {
+        color
+    }
+}
+
 /// Returns the perceptual color distance between two RGB colors.
 /// Uses the CIE76 formula (Euclidean distance in Lab space approximation).
 pub(crate) fn perceptual_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> f32 {
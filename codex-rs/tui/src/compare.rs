@@ -0,0 +1,557 @@
+//! A/B comparison of one prompt across two models (`/compare <model>`).
+//!
+//! `App` forks the current thread twice, runs the next prompt on each fork in
+//! parallel, and shows both answers side by side in [`CompareOverlay`]. The
+//! forks run read-only without approvals so they cannot race each other on
+//! the workspace. Picking a side hands that fork to the chat widget; the other
+//! fork (or both, when discarded) is shut down.
+
+use std::io::Result;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_core::CodexThread;
+use codex_core::protocol::AskForApproval;
+use codex_core::protocol::EventMsg;
+use codex_core::protocol::Op;
+use codex_core::protocol::SandboxPolicy;
+use codex_core::protocol::SessionConfiguredEvent;
+use codex_core::protocol::TokenUsage;
+use codex_protocol::ThreadId;
+use codex_protocol::config_types::Language;
+use codex_protocol::user_input::UserInput;
+use crossterm::event::KeyCode;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::text::Span;
+use ratatui::text::Text;
+use ratatui::widgets::Clear;
+use ratatui::widgets::Paragraph;
+use ratatui::widgets::Widget;
+use ratatui::widgets::WidgetRef;
+use ratatui::widgets::Wrap;
+
+use crate::app_event::AppEvent;
+use crate::app_event_sender::AppEventSender;
+use crate::i18n::tr;
+use crate::i18n::tr_args;
+use crate::key_hint;
+use crate::key_hint::KeyBinding;
+use crate::markdown::append_markdown;
+use crate::status::format_tokens_compact;
+use crate::status_indicator_widget::fmt_elapsed_compact;
+use crate::tui;
+use crate::tui::FrameRequester;
+use crate::tui::TuiEvent;
+
+const KEY_1: KeyBinding = key_hint::plain(KeyCode::Char('1'));
+const KEY_2: KeyBinding = key_hint::plain(KeyCode::Char('2'));
+const KEY_UP: KeyBinding = key_hint::plain(KeyCode::Up);
+const KEY_DOWN: KeyBinding = key_hint::plain(KeyCode::Down);
+const KEY_PAGE_UP: KeyBinding = key_hint::plain(KeyCode::PageUp);
+const KEY_PAGE_DOWN: KeyBinding = key_hint::plain(KeyCode::PageDown);
+const KEY_ESC: KeyBinding = key_hint::plain(KeyCode::Esc);
+const KEY_Q: KeyBinding = key_hint::plain(KeyCode::Char('q'));
+const KEY_CTRL_C: KeyBinding = key_hint::ctrl(KeyCode::Char('c'));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompareSide {
+    A,
+    B,
+}
+
+impl CompareSide {
+    pub(crate) const ALL: [CompareSide; 2] = [CompareSide::A, CompareSide::B];
+
+    fn index(self) -> usize {
+        match self {
+            CompareSide::A => 0,
+            CompareSide::B => 1,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            CompareSide::A => "A",
+            CompareSide::B => "B",
+        }
+    }
+}
+
+/// What one branch produced for the compared prompt.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BranchOutcome {
+    pub(crate) message: Option<String>,
+    pub(crate) usage: TokenUsage,
+    pub(crate) elapsed: Duration,
+    pub(crate) error: Option<String>,
+}
+
+/// A forked thread running one side of the comparison.
+pub(crate) struct CompareBranch {
+    pub(crate) side: CompareSide,
+    pub(crate) model: String,
+    pub(crate) thread_id: ThreadId,
+    pub(crate) thread: Arc<CodexThread>,
+    pub(crate) session_configured: SessionConfiguredEvent,
+    pub(crate) outcome: Option<BranchOutcome>,
+}
+
+/// Rewrite the user's turn for one branch: swap in the branch model and run
+/// read-only without approval prompts, since nobody is there to answer them.
+pub(crate) fn branch_op(op: &Op, model: &str) -> Op {
+    let mut op = op.clone();
+    if let Op::UserTurn {
+        model: turn_model,
+        effort,
+        approval_policy,
+        sandbox_policy,
+        collaboration_mode,
+        ..
+    } = &mut op
+    {
+        if turn_model != model {
+            // The other model picks its own default effort.
+            *effort = None;
+            *turn_model = model.to_string();
+        }
+        *approval_policy = AskForApproval::Never;
+        *sandbox_policy = SandboxPolicy::ReadOnly;
+        *collaboration_mode = collaboration_mode
+            .take()
+            .map(|mode| mode.with_updates(Some(model.to_string()), Some(*effort), None));
+    }
+    op
+}
+
+/// First text item of the turn, used as the overlay subtitle.
+pub(crate) fn prompt_text(op: &Op) -> String {
+    match op {
+        Op::UserTurn { items, .. } => items
+            .iter()
+            .find_map(|item| match item {
+                UserInput::Text { text, .. } => Some(text.clone()),
+                _ => None,
+            })
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+/// Submit `op` on `thread` and report the result as
+/// [`AppEvent::CompareBranchFinished`] once the turn ends.
+pub(crate) fn spawn_branch(
+    thread: Arc<CodexThread>,
+    thread_id: ThreadId,
+    op: Op,
+    tx: AppEventSender,
+) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        let mut outcome = BranchOutcome::default();
+        if let Err(err) = thread.submit(op).await {
+            outcome.error = Some(err.to_string());
+            outcome.elapsed = started.elapsed();
+            tx.send(AppEvent::CompareBranchFinished { thread_id, outcome });
+            return;
+        }
+        loop {
+            let event = match thread.next_event().await {
+                Ok(event) => event,
+                Err(err) => {
+                    if outcome.error.is_none() {
+                        outcome.error = Some(err.to_string());
+                    }
+                    break;
+                }
+            };
+            match event.msg {
+                EventMsg::AgentMessage(message) => outcome.message = Some(message.message),
+                EventMsg::TokenCount(count) => {
+                    if let Some(info) = count.info {
+                        outcome.usage.add_assign(&info.last_token_usage);
+                    }
+                }
+                EventMsg::Error(error) => outcome.error = Some(error.message),
+                EventMsg::TurnComplete(complete) => {
+                    if let Some(message) = complete.last_agent_message {
+                        outcome.message = Some(message);
+                    }
+                    break;
+                }
+                EventMsg::TurnAborted(_) => {
+                    if outcome.error.is_none() {
+                        outcome.error = Some("interrupted".to_string());
+                    }
+                    break;
+                }
+                EventMsg::ShutdownComplete => break,
+                _ => {}
+            }
+        }
+        outcome.elapsed = started.elapsed();
+        tx.send(AppEvent::CompareBranchFinished { thread_id, outcome });
+    });
+}
+
+/// Side-by-side view of both answers. `1`/`2` keep a branch, `Esc` discards
+/// the comparison. The choice is reported as [`AppEvent::FinishCompare`].
+pub(crate) struct CompareOverlay {
+    prompt: String,
+    models: [String; 2],
+    outcomes: [Option<BranchOutcome>; 2],
+    started: Instant,
+    scroll_offset: u16,
+    last_body_height: u16,
+    app_event_tx: AppEventSender,
+    frame_requester: FrameRequester,
+    is_done: bool,
+    language: Language,
+}
+
+impl CompareOverlay {
+    pub(crate) fn new(
+        prompt: String,
+        models: [String; 2],
+        app_event_tx: AppEventSender,
+        frame_requester: FrameRequester,
+        language: Language,
+    ) -> Self {
+        Self {
+            prompt,
+            models,
+            outcomes: [None, None],
+            started: Instant::now(),
+            scroll_offset: 0,
+            last_body_height: 0,
+            app_event_tx,
+            frame_requester,
+            is_done: false,
+            language,
+        }
+    }
+
+    pub(crate) fn set_outcome(&mut self, side: CompareSide, outcome: BranchOutcome) {
+        self.outcomes[side.index()] = Some(outcome);
+        self.frame_requester.schedule_frame();
+    }
+
+    pub(crate) fn is_done(&self) -> bool {
+        self.is_done
+    }
+
+    fn can_keep(&self, side: CompareSide) -> bool {
+        self.outcomes[side.index()]
+            .as_ref()
+            .is_some_and(|outcome| outcome.message.is_some())
+    }
+
+    fn finish(&mut self, choice: Option<CompareSide>) {
+        self.app_event_tx.send(AppEvent::FinishCompare(choice));
+        self.is_done = true;
+    }
+
+    fn scroll_by(&mut self, delta: i32) {
+        let offset = i32::from(self.scroll_offset).saturating_add(delta).max(0);
+        self.scroll_offset = u16::try_from(offset).unwrap_or(u16::MAX);
+        self.frame_requester.schedule_frame();
+    }
+
+    pub(crate) fn handle_event(&mut self, tui: &mut tui::Tui, event: TuiEvent) -> Result<()> {
+        match event {
+            TuiEvent::Key(key_event) => {
+                let page = i32::from(self.last_body_height.max(1));
+                match key_event {
+                    e if KEY_1.is_press(e) && self.can_keep(CompareSide::A) => {
+                        self.finish(Some(CompareSide::A));
+                    }
+                    e if KEY_2.is_press(e) && self.can_keep(CompareSide::B) => {
+                        self.finish(Some(CompareSide::B));
+                    }
+                    e if KEY_ESC.is_press(e) || KEY_Q.is_press(e) || KEY_CTRL_C.is_press(e) => {
+                        self.finish(None);
+                    }
+                    e if KEY_UP.is_press(e) => self.scroll_by(-1),
+                    e if KEY_DOWN.is_press(e) => self.scroll_by(1),
+                    e if KEY_PAGE_UP.is_press(e) => self.scroll_by(-page),
+                    e if KEY_PAGE_DOWN.is_press(e) => self.scroll_by(page),
+                    _ => {}
+                }
+                Ok(())
+            }
+            TuiEvent::Draw => {
+                tui.draw(u16::MAX, |frame| {
+                    self.render(frame.area(), frame.buffer);
+                })?;
+                if self.outcomes.iter().any(Option::is_none) {
+                    // Keep the running timers ticking.
+                    self.frame_requester
+                        .schedule_frame_in(Duration::from_secs(1));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn status_line(&self, side: CompareSide) -> Line<'static> {
+        let Some(outcome) = &self.outcomes[side.index()] else {
+            let elapsed = fmt_elapsed_compact(self.language, self.started.elapsed().as_secs());
+            return Line::from(
+                tr_args(self.language, "compare.running", &[("elapsed", &elapsed)]).dim(),
+            );
+        };
+        let elapsed = fmt_elapsed_compact(self.language, outcome.elapsed.as_secs());
+        let stats = tr_args(
+            self.language,
+            "compare.stats",
+            &[
+                ("input", &format_tokens_compact(outcome.usage.input_tokens)),
+                (
+                    "output",
+                    &format_tokens_compact(outcome.usage.output_tokens),
+                ),
+                ("elapsed", &elapsed),
+            ],
+        );
+        let mut spans: Vec<Span<'static>> = vec![stats.dim()];
+        if let Some(error) = &outcome.error {
+            spans.push("  ".into());
+            spans.push(tr_args(self.language, "compare.branch_failed", &[("error", error)]).red());
+        }
+        Line::from(spans)
+    }
+
+    fn column_lines(&self, side: CompareSide, width: u16) -> Vec<Line<'static>> {
+        let mut lines = vec![
+            Line::from(vec![
+                side.label().cyan().bold(),
+                "  ".into(),
+                self.models[side.index()].clone().bold(),
+            ]),
+            self.status_line(side),
+            Line::default(),
+        ];
+        match self.outcomes[side.index()]
+            .as_ref()
+            .and_then(|outcome| outcome.message.as_deref())
+        {
+            Some(message) => append_markdown(message, Some(usize::from(width)), &mut lines),
+            None if self.outcomes[side.index()].is_some() => {
+                lines.push(tr(self.language, "compare.no_answer").dim().into());
+            }
+            None => {}
+        }
+        lines
+    }
+
+    fn render_hints(&self, area: Rect, buf: &mut Buffer) {
+        let mut spans: Vec<Span<'static>> = vec![" ".into()];
+        for (side, key) in [(CompareSide::A, KEY_1), (CompareSide::B, KEY_2)] {
+            if self.can_keep(side) {
+                spans.push(Span::from(key));
+                spans.push(
+                    format!(
+                        " {} {}   ",
+                        tr(self.language, "compare.hint_keep"),
+                        side.label()
+                    )
+                    .into(),
+                );
+            }
+        }
+        spans.push(Span::from(KEY_UP));
+        spans.push("/".into());
+        spans.push(Span::from(KEY_DOWN));
+        spans.push(format!(" {}   ", tr(self.language, "pager_overlay.hint.scroll")).into());
+        spans.push(Span::from(KEY_ESC));
+        spans.push(format!(" {}", tr(self.language, "compare.hint_discard")).into());
+        Paragraph::new(vec![Line::from(spans).dim()]).render_ref(area, buf);
+    }
+
+    pub(crate) fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        if area.height < 4 || area.width < 8 {
+            return;
+        }
+        let title = Rect::new(area.x, area.y, area.width, 1);
+        let body = Rect::new(area.x, area.y + 1, area.width, area.height - 3);
+        let hints = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+
+        let prompt = self.prompt.lines().next().unwrap_or_default().to_string();
+        Line::from(vec![
+            "/ ".dim(),
+            tr(self.language, "compare.title").bold(),
+            " · ".dim(),
+            prompt.into(),
+        ])
+        .render_ref(title, buf);
+
+        // Two columns separated by a one-cell gutter.
+        let left_width = (body.width - 1) / 2;
+        let right_width = body.width - 1 - left_width;
+        let left = Rect::new(body.x, body.y, left_width, body.height);
+        let gutter = Rect::new(body.x + left_width, body.y, 1, body.height);
+        let right = Rect::new(gutter.right(), body.y, right_width, body.height);
+        self.last_body_height = body.height;
+
+        for (side, column) in CompareSide::ALL.into_iter().zip([left, right]) {
+            let lines = self.column_lines(side, column.width);
+            Paragraph::new(Text::from(lines))
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll_offset, 0))
+                .render(column, buf);
+        }
+        for y in gutter.top()..gutter.bottom() {
+            buf[(gutter.x, y)]
+                .set_symbol("│")
+                .set_style(Style::default().dim());
+        }
+
+        self.render_hints(hints, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::config_types::ReasoningSummary;
+    use codex_protocol::openai_models::ReasoningEffort;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    fn user_turn(model: &str) -> Op {
+        Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "explain the build".to_string(),
+                text_elements: Vec::new(),
+            }],
+            cwd: PathBuf::from("/tmp"),
+            approval_policy: AskForApproval::OnRequest,
+            sandbox_policy: SandboxPolicy::new_workspace_write_policy(),
+            model: model.to_string(),
+            effort: Some(ReasoningEffort::High),
+            summary: ReasoningSummary::Auto,
+            final_output_json_schema: None,
+            collaboration_mode: None,
+            personality: None,
+        }
+    }
+
+    #[test]
+    fn branch_op_swaps_model_and_runs_read_only() {
+        let op = user_turn("model-a");
+
+        let Op::UserTurn {
+            model,
+            effort,
+            approval_policy,
+            sandbox_policy,
+            ..
+        } = branch_op(&op, "model-b")
+        else {
+            panic!("expected a user turn");
+        };
+        assert_eq!(
+            (model.as_str(), effort, approval_policy, sandbox_policy),
+            (
+                "model-b",
+                None,
+                AskForApproval::Never,
+                SandboxPolicy::ReadOnly
+            )
+        );
+
+        let Op::UserTurn { model, effort, .. } = branch_op(&op, "model-a") else {
+            panic!("expected a user turn");
+        };
+        assert_eq!(
+            (model.as_str(), effort),
+            ("model-a", Some(ReasoningEffort::High))
+        );
+        assert_eq!(prompt_text(&op), "explain the build");
+    }
+
+    #[test]
+    fn overlay_only_keeps_branches_with_an_answer() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let mut overlay = CompareOverlay::new(
+            "explain the build".to_string(),
+            ["model-a".to_string(), "model-b".to_string()],
+            AppEventSender::new(tx),
+            FrameRequester::test_dummy(),
+            Language::En,
+        );
+        overlay.set_outcome(
+            CompareSide::A,
+            BranchOutcome {
+                error: Some("boom".to_string()),
+                ..BranchOutcome::default()
+            },
+        );
+        overlay.set_outcome(
+            CompareSide::B,
+            BranchOutcome {
+                message: Some("It uses cargo.".to_string()),
+                ..BranchOutcome::default()
+            },
+        );
+
+        assert!(!overlay.can_keep(CompareSide::A));
+        assert!(overlay.can_keep(CompareSide::B));
+        overlay.finish(Some(CompareSide::B));
+        assert!(overlay.is_done());
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEvent::FinishCompare(Some(CompareSide::B)))
+        ));
+    }
+
+    #[test]
+    fn overlay_renders_both_answers_side_by_side() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let mut overlay = CompareOverlay::new(
+            "explain the build".to_string(),
+            ["model-a".to_string(), "model-b".to_string()],
+            AppEventSender::new(tx),
+            FrameRequester::test_dummy(),
+            Language::En,
+        );
+        for (side, message) in [
+            (CompareSide::A, "left answer"),
+            (CompareSide::B, "right answer"),
+        ] {
+            overlay.set_outcome(
+                side,
+                BranchOutcome {
+                    message: Some(message.to_string()),
+                    ..BranchOutcome::default()
+                },
+            );
+        }
+
+        let area = Rect::new(0, 0, 60, 10);
+        let mut buf = Buffer::empty(area);
+        overlay.render(area, &mut buf);
+        let rows: Vec<String> = (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect();
+        let answer_row = rows
+            .iter()
+            .find(|row| row.contains("left answer"))
+            .expect("left answer rendered");
+        assert!(
+            answer_row.contains("right answer"),
+            "answers should share a row: {rows:?}"
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Applies `config.toml` edits made while the TUI is running.
+//!
+//! The core file watcher reports changes to any config layer's `config.toml`
+//! or `rules/*.rules`. `App` rebuilds its config, copies the settings listed in
+//! [`LIVE_SETTINGS`] into the running session, asks the session to reconnect
+//! MCP servers when they changed, and lists the keys in [`RESTART_SETTINGS`]
+//! that changed on disk as pending a restart. The session itself re-reads the
+//! rules files.
+
+use std::path::PathBuf;
+
+use codex_core::config::Config;
+use codex_protocol::config_types::Language;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+
+use crate::history_cell::PlainHistoryCell;
+use crate::i18n::tr;
+use crate::i18n::tr_args;
+
+struct LiveSetting {
+    key: &'static str,
+    differs: fn(&Config, &Config) -> bool,
+    apply: fn(&mut Config, &Config),
+}
+
+/// Settings the TUI reads on every use, so copying them into its config is
+/// enough for the change to take effect.
+const LIVE_SETTINGS: &[LiveSetting] = &[
+    LiveSetting {
+        key: "tui.notifications",
+        differs: |a, b| a.tui_notifications != b.tui_notifications,
+        apply: |to, from| to.tui_notifications = from.tui_notifications.clone(),
+    },
+    LiveSetting {
+        key: "tui.notification_method",
+        differs: |a, b| a.tui_notification_method != b.tui_notification_method,
+        apply: |to, from| to.tui_notification_method = from.tui_notification_method,
+    },
+    LiveSetting {
+        key: "tui.status_line",
+        differs: |a, b| a.tui_status_line != b.tui_status_line,
+        apply: |to, from| to.tui_status_line = from.tui_status_line.clone(),
+    },
+    LiveSetting {
+        key: "tui.exec_output_lines",
+        differs: |a, b| a.tui_exec_output_lines != b.tui_exec_output_lines,
+        apply: |to, from| to.tui_exec_output_lines = from.tui_exec_output_lines,
+    },
+    LiveSetting {
+        key: "tui.open_file_command",
+        differs: |a, b| a.tui_open_file_command != b.tui_open_file_command,
+        apply: |to, from| to.tui_open_file_command = from.tui_open_file_command.clone(),
+    },
+    LiveSetting {
+        key: "tui.density",
+        differs: |a, b| a.tui_density != b.tui_density,
+        apply: |to, from| to.tui_density = from.tui_density,
+    },
+    LiveSetting {
+        key: "language",
+        differs: |a, b| a.language != b.language,
+        apply: |to, from| to.language = from.language,
+    },
+    LiveSetting {
+        key: "mcp_servers",
+        differs: |a, b| a.mcp_servers.get() != b.mcp_servers.get(),
+        apply: |to, from| to.mcp_servers = from.mcp_servers.clone(),
+    },
+];
+
+struct RestartSetting {
+    key: &'static str,
+    differs: fn(&Config, &Config) -> bool,
+}
+
+/// Settings baked into the session or the terminal setup at startup.
+const RESTART_SETTINGS: &[RestartSetting] = &[
+    RestartSetting {
+        key: "model",
+        differs: |a, b| a.model != b.model,
+    },
+    RestartSetting {
+        key: "model_provider",
+        differs: |a, b| a.model_provider_id != b.model_provider_id,
+    },
+    RestartSetting {
+        key: "model_reasoning_effort",
+        differs: |a, b| a.model_reasoning_effort != b.model_reasoning_effort,
+    },
+    RestartSetting {
+        key: "personality",
+        differs: |a, b| a.personality != b.personality,
+    },
+    RestartSetting {
+        key: "approval_policy",
+        differs: |a, b| a.approval_policy.get() != b.approval_policy.get(),
+    },
+    RestartSetting {
+        key: "sandbox_mode",
+        differs: |a, b| a.sandbox_policy.get() != b.sandbox_policy.get(),
+    },
+    RestartSetting {
+        key: "web_search",
+        differs: |a, b| a.web_search_mode.get() != b.web_search_mode.get(),
+    },
+    RestartSetting {
+        key: "developer_instructions",
+        differs: |a, b| a.developer_instructions != b.developer_instructions,
+    },
+    RestartSetting {
+        key: "notify",
+        differs: |a, b| a.notify != b.notify,
+    },
+    RestartSetting {
+        key: "features",
+        differs: |a, b| a.features != b.features,
+    },
+    RestartSetting {
+        key: "tui.alternate_screen",
+        differs: |a, b| a.tui_alternate_screen != b.tui_alternate_screen,
+    },
+];
+
+/// What a reload changed, by `config.toml` key.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct ConfigReload {
+    pub(crate) applied: Vec<&'static str>,
+    pub(crate) pending_restart: Vec<&'static str>,
+    pub(crate) rules_changed: bool,
+}
+
+impl ConfigReload {
+    /// Compare `reloaded` against the running `current` config and the
+    /// `baseline` read from disk at startup or on the previous reload.
+    ///
+    /// A restart-only key is listed only when the file changed it and the
+    /// session is not already using the new value, so persisting a `/model`
+    /// pick does not show up as pending.
+    pub(crate) fn diff(
+        baseline: &Config,
+        current: &Config,
+        reloaded: &Config,
+        changed_paths: &[PathBuf],
+    ) -> Self {
+        Self {
+            applied: LIVE_SETTINGS
+                .iter()
+                .filter(|setting| (setting.differs)(current, reloaded))
+                .map(|setting| setting.key)
+                .collect(),
+            pending_restart: RESTART_SETTINGS
+                .iter()
+                .filter(|setting| {
+                    (setting.differs)(baseline, reloaded) && (setting.differs)(current, reloaded)
+                })
+                .map(|setting| setting.key)
+                .collect(),
+            rules_changed: changed_paths
+                .iter()
+                .any(|path| path.extension().is_some_and(|ext| ext == "rules")),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.applied.is_empty() && self.pending_restart.is_empty() && !self.rules_changed
+    }
+
+    pub(crate) fn mcp_servers_changed(&self) -> bool {
+        self.applied.contains(&"mcp_servers")
+    }
+
+    pub(crate) fn history_cell(&self, language: Language) -> PlainHistoryCell {
+        let mut lines: Vec<Line<'static>> =
+            vec![vec!["• ".dim(), tr(language, "app.config_reload.title").bold()].into()];
+        let mut detail = |label: &'static str, value: Option<String>| {
+            let mut spans = vec!["  ".into(), tr(language, label).dim()];
+            spans.extend(value.map(Into::into));
+            lines.push(spans.into());
+        };
+        if !self.applied.is_empty() {
+            detail("app.config_reload.applied", Some(self.applied.join(", ")));
+        }
+        if self.mcp_servers_changed() {
+            detail("app.config_reload.mcp_servers", None);
+        }
+        if self.rules_changed {
+            detail("app.config_reload.rules", None);
+        }
+        if !self.pending_restart.is_empty() {
+            detail(
+                "app.config_reload.pending_restart",
+                Some(self.pending_restart.join(", ")),
+            );
+        }
+        PlainHistoryCell::new(lines)
+    }
+}
+
+/// Restart-only keys whose values differ between `a` and `b`.
+pub(crate) fn restart_changes(a: &Config, b: &Config) -> Vec<&'static str> {
+    RESTART_SETTINGS
+        .iter()
+        .filter(|setting| (setting.differs)(a, b))
+        .map(|setting| setting.key)
+        .collect()
+}
+
+/// Copy every live setting from `reloaded` into `target`.
+pub(crate) fn apply_live_settings(target: &mut Config, reloaded: &Config) {
+    for setting in LIVE_SETTINGS {
+        (setting.apply)(target, reloaded);
+    }
+}
+
+pub(crate) fn reload_failed_message(language: Language, error: &str) -> String {
+    tr_args(language, "app.config_reload.failed", &[("error", error)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::config::ConfigBuilder;
+    use codex_core::protocol::AskForApproval;
+    use pretty_assertions::assert_eq;
+
+    async fn test_config() -> Config {
+        ConfigBuilder::default()
+            .codex_home(std::env::temp_dir())
+            .build()
+            .await
+            .expect("config")
+    }
+
+    #[tokio::test]
+    async fn splits_live_and_restart_changes() {
+        let baseline = test_config().await;
+        let mut current = baseline.clone();
+        // Chosen with /model and persisted, so the reload carries it too.
+        current.model = Some("picked-in-session".to_string());
+
+        let mut reloaded = baseline.clone();
+        reloaded.model = Some("picked-in-session".to_string());
+        reloaded.tui_exec_output_lines = baseline.tui_exec_output_lines + 10;
+        reloaded
+            .approval_policy
+            .set(AskForApproval::Never)
+            .expect("approval policy");
+
+        let reload = ConfigReload::diff(
+            &baseline,
+            &current,
+            &reloaded,
+            &[PathBuf::from("/tmp/home/rules/default.rules")],
+        );
+        assert_eq!(
+            reload,
+            ConfigReload {
+                applied: vec!["tui.exec_output_lines"],
+                pending_restart: vec!["approval_policy"],
+                rules_changed: true,
+            }
+        );
+
+        apply_live_settings(&mut current, &reloaded);
+        assert_eq!(
+            current.tui_exec_output_lines,
+            reloaded.tui_exec_output_lines
+        );
+        assert_eq!(current.model.as_deref(), Some("picked-in-session"));
+    }
+}
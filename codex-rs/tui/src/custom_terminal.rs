@@ -45,6 +45,8 @@ use ratatui::style::Color;
 use ratatui::style::Modifier;
 use ratatui::widgets::WidgetRef;
 
+use crate::color::terminal_color;
+use crate::glyphs::terminal_text;
 use crate::i18n::tr_args;
 
 #[derive(Debug, Hash)]
@@ -515,18 +517,21 @@ where
                     diff.queue(writer)?;
                     modifier = cell.modifier;
                 }
-                if cell.fg != fg || cell.bg != bg {
+                let cell_fg = terminal_color(cell.fg);
+                let cell_bg = terminal_color(cell.bg);
+                if cell_fg != fg || cell_bg != bg {
                     queue!(
                         writer,
-                        SetColors(Colors::new(cell.fg.into(), cell.bg.into()))
+                        SetColors(Colors::new(cell_fg.into(), cell_bg.into()))
                     )?;
-                    fg = cell.fg;
-                    bg = cell.bg;
+                    fg = cell_fg;
+                    bg = cell_bg;
                 }
 
-                queue!(writer, Print(cell.symbol()))?;
+                queue!(writer, Print(terminal_text(cell.symbol())))?;
             }
             DrawCommand::ClearToEnd { bg: clear_bg, .. } => {
+                let clear_bg = terminal_color(clear_bg);
                 queue!(writer, SetAttribute(crossterm::style::Attribute::Reset))?;
                 modifier = Modifier::empty();
                 queue!(writer, SetBackgroundColor(clear_bg.into()))?;
@@ -0,0 +1,236 @@
+use crate::i18n::tr;
+use crate::key_hint;
+use crate::render::Insets;
+use crate::render::renderable::ColumnRenderable;
+use crate::render::renderable::Renderable;
+use crate::render::renderable::RenderableExt as _;
+use crate::selection_list::selection_option_row;
+use crate::tui::FrameRequester;
+use crate::tui::Tui;
+use crate::tui::TuiEvent;
+use codex_core::config::Config;
+use codex_core::danger_audit;
+use codex_core::git_info::get_git_repo_root;
+use codex_protocol::config_types::Language;
+use color_eyre::Result;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use crossterm::event::KeyEventKind;
+use crossterm::event::KeyModifiers;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::prelude::Widget;
+use ratatui::style::Stylize as _;
+use ratatui::text::Line;
+use ratatui::widgets::Clear;
+use ratatui::widgets::Paragraph;
+use ratatui::widgets::WidgetRef;
+use ratatui::widgets::Wrap;
+use std::path::PathBuf;
+use tokio_stream::StreamExt;
+
+pub(crate) enum DangerPromptOutcome {
+    Continue,
+    Quit,
+}
+
+/// Ask for an explicit confirmation before running without approvals or
+/// sandboxing in a directory git cannot roll back.
+pub(crate) async fn run_danger_prompt_if_needed(
+    tui: &mut Tui,
+    config: &Config,
+) -> Result<DangerPromptOutcome> {
+    if !danger_audit::is_danger_mode(config.approval_policy.value(), config.sandbox_policy.get())
+        || get_git_repo_root(&config.cwd).is_some()
+    {
+        return Ok(DangerPromptOutcome::Continue);
+    }
+
+    let mut screen =
+        DangerPromptScreen::new(tui.frame_requester(), config.cwd.clone(), config.language);
+    tui.draw(u16::MAX, |frame| {
+        frame.render_widget_ref(&screen, frame.area());
+    })?;
+
+    let events = tui.event_stream();
+    tokio::pin!(events);
+
+    while !screen.is_done() {
+        if let Some(event) = events.next().await {
+            match event {
+                TuiEvent::Key(key_event) => screen.handle_key(key_event),
+                TuiEvent::Paste(_) => {}
+                TuiEvent::Draw => {
+                    tui.draw(u16::MAX, |frame| {
+                        frame.render_widget_ref(&screen, frame.area());
+                    })?;
+                }
+            }
+        } else {
+            break;
+        }
+    }
+
+    match screen.selection() {
+        Some(DangerSelection::Continue) => {
+            tui.terminal.clear()?;
+            Ok(DangerPromptOutcome::Continue)
+        }
+        Some(DangerSelection::Quit) | None => Ok(DangerPromptOutcome::Quit),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DangerSelection {
+    Quit,
+    Continue,
+}
+
+impl DangerSelection {
+    fn toggle(self) -> Self {
+        match self {
+            DangerSelection::Quit => DangerSelection::Continue,
+            DangerSelection::Continue => DangerSelection::Quit,
+        }
+    }
+}
+
+struct DangerPromptScreen {
+    request_frame: FrameRequester,
+    cwd: PathBuf,
+    highlighted: DangerSelection,
+    selection: Option<DangerSelection>,
+    language: Language,
+}
+
+impl DangerPromptScreen {
+    fn new(request_frame: FrameRequester, cwd: PathBuf, language: Language) -> Self {
+        Self {
+            request_frame,
+            cwd,
+            highlighted: DangerSelection::Quit,
+            selection: None,
+            language,
+        }
+    }
+
+    fn handle_key(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        if key_event.modifiers.contains(KeyModifiers::CONTROL)
+            && matches!(key_event.code, KeyCode::Char('c') | KeyCode::Char('d'))
+        {
+            self.select(DangerSelection::Quit);
+            return;
+        }
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') | KeyCode::Down | KeyCode::Char('j') => {
+                self.highlighted = self.highlighted.toggle();
+                self.request_frame.schedule_frame();
+            }
+            KeyCode::Char('1') => self.select(DangerSelection::Quit),
+            KeyCode::Char('2') => self.select(DangerSelection::Continue),
+            KeyCode::Enter => self.select(self.highlighted),
+            KeyCode::Esc => self.select(DangerSelection::Quit),
+            _ => {}
+        }
+    }
+
+    fn select(&mut self, selection: DangerSelection) {
+        self.highlighted = selection;
+        self.selection = Some(selection);
+        self.request_frame.schedule_frame();
+    }
+
+    fn is_done(&self) -> bool {
+        self.selection.is_some()
+    }
+
+    fn selection(&self) -> Option<DangerSelection> {
+        self.selection
+    }
+}
+
+impl WidgetRef for &DangerPromptScreen {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let mut column = ColumnRenderable::new();
+
+        column.push("");
+        column.push(Line::from(vec![
+            "  ⚠ ".red().bold(),
+            tr(self.language, "danger_prompt.title").red().bold(),
+        ]));
+        column.push("");
+        column.push(
+            Paragraph::new(tr(self.language, "danger_prompt.body"))
+                .wrap(Wrap { trim: false })
+                .inset(Insets::tlbr(0, 2, 0, 0)),
+        );
+        column.push(
+            Line::from(vec![
+                tr(self.language, "danger_prompt.cwd_prefix").dim(),
+                self.cwd.display().to_string().dim(),
+            ])
+            .inset(Insets::tlbr(0, 2, 0, 0)),
+        );
+        column.push("");
+        column.push(selection_option_row(
+            0,
+            tr(self.language, "danger_prompt.option.quit").to_string(),
+            self.highlighted == DangerSelection::Quit,
+        ));
+        column.push(selection_option_row(
+            1,
+            tr(self.language, "danger_prompt.option.continue").to_string(),
+            self.highlighted == DangerSelection::Continue,
+        ));
+        column.push("");
+        column.push(
+            Line::from(vec![
+                tr(self.language, "update_prompt.hint.press").dim(),
+                key_hint::plain(KeyCode::Enter).into(),
+                tr(self.language, "update_prompt.hint.continue").dim(),
+            ])
+            .inset(Insets::tlbr(0, 2, 0, 0)),
+        );
+        column.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn new_prompt() -> DangerPromptScreen {
+        DangerPromptScreen::new(
+            FrameRequester::test_dummy(),
+            PathBuf::from("/tmp/scratch"),
+            Language::En,
+        )
+    }
+
+    #[test]
+    fn enter_defaults_to_quit() {
+        let mut screen = new_prompt();
+        screen.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(screen.selection(), Some(DangerSelection::Quit));
+    }
+
+    #[test]
+    fn continue_requires_moving_off_the_default() {
+        let mut screen = new_prompt();
+        screen.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        screen.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(screen.selection(), Some(DangerSelection::Continue));
+    }
+
+    #[test]
+    fn ctrl_c_quits() {
+        let mut screen = new_prompt();
+        screen.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert_eq!(screen.selection(), Some(DangerSelection::Quit));
+    }
+}
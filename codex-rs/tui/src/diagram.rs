@@ -0,0 +1,871 @@
+//! `/diagram`: renders the mermaid and Graphviz blocks of the last agent
+//! response.
+//!
+//! A local `mmdc` (mermaid-cli) or `dot` (Graphviz) turns each block into a
+//! PNG, which is saved to a temp file and, on terminals with an image
+//! protocol, also drawn inline. When the renderer is not installed, simple
+//! flowcharts are laid out in-process and drawn with box characters instead.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+use std::process::Stdio;
+
+use codex_protocol::config_types::Language;
+use ratatui::style::Stylize as _;
+use ratatui::text::Line;
+use tempfile::Builder;
+use unicode_width::UnicodeWidthChar;
+use unicode_width::UnicodeWidthStr;
+
+use crate::history_cell::PlainHistoryCell;
+use crate::i18n::tr_args;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DiagramKind {
+    Mermaid,
+    Graphviz,
+}
+
+impl DiagramKind {
+    fn from_fence_info(info: &str) -> Option<Self> {
+        match info
+            .split_whitespace()
+            .next()?
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "mermaid" => Some(Self::Mermaid),
+            "dot" | "graphviz" | "gv" => Some(Self::Graphviz),
+            _ => None,
+        }
+    }
+
+    /// Executable that renders this kind of diagram.
+    fn renderer(self) -> &'static str {
+        match self {
+            Self::Mermaid => "mmdc",
+            Self::Graphviz => "dot",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DiagramBlock {
+    pub(crate) kind: DiagramKind,
+    pub(crate) source: String,
+}
+
+/// Fenced mermaid / Graphviz blocks in `markdown`, in order.
+pub(crate) fn extract_diagram_blocks(markdown: &str) -> Vec<DiagramBlock> {
+    let mut blocks = Vec::new();
+    let mut lines = markdown.lines();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        let Some(fence_char) = trimmed.chars().next().filter(|c| *c == '`' || *c == '~') else {
+            continue;
+        };
+        let fence_len = trimmed.chars().take_while(|c| *c == fence_char).count();
+        if fence_len < 3 {
+            continue;
+        }
+        let kind = DiagramKind::from_fence_info(&trimmed[fence_len..]);
+        let mut body = Vec::new();
+        for inner in lines.by_ref() {
+            let inner = inner.trim();
+            if inner.len() >= fence_len && inner.chars().all(|c| c == fence_char) {
+                break;
+            }
+            body.push(inner);
+        }
+        if let Some(kind) = kind {
+            blocks.push(DiagramBlock {
+                kind,
+                source: body.join("\n"),
+            });
+        }
+    }
+    blocks
+}
+
+#[derive(Debug)]
+pub(crate) enum RenderedDiagram {
+    /// PNG produced by the renderer and saved to `path`.
+    Image {
+        renderer: &'static str,
+        path: PathBuf,
+        png: Vec<u8>,
+        width: u32,
+        height: u32,
+    },
+    /// Box drawing made because the renderer is not installed.
+    Text {
+        renderer: &'static str,
+        layout: TextLayout,
+    },
+}
+
+#[derive(Debug)]
+pub(crate) enum DiagramError {
+    /// The renderer is not installed and the diagram is not a plain flowchart.
+    RendererMissing(&'static str),
+    RendererFailed {
+        renderer: &'static str,
+        detail: String,
+    },
+    Io(String),
+}
+
+/// Render one block. Runs the external renderer synchronously, so call it
+/// from a blocking task.
+pub(crate) fn render_diagram(block: &DiagramBlock) -> Result<RenderedDiagram, DiagramError> {
+    let renderer = block.kind.renderer();
+    let result = match block.kind {
+        DiagramKind::Graphviz => run_dot(&block.source),
+        DiagramKind::Mermaid => run_mmdc(&block.source),
+    };
+    let png = match result {
+        Ok(png) => png,
+        Err(RunError::NotInstalled) => {
+            return parse_simple_graph(block)
+                .map(|graph| RenderedDiagram::Text {
+                    renderer,
+                    layout: layout_graph(&graph),
+                })
+                .ok_or(DiagramError::RendererMissing(renderer));
+        }
+        Err(RunError::Failed(detail)) => {
+            return Err(DiagramError::RendererFailed { renderer, detail });
+        }
+    };
+    let (width, height) = image::load_from_memory(&png)
+        .map(|img| (img.width(), img.height()))
+        .map_err(|err| DiagramError::RendererFailed {
+            renderer,
+            detail: err.to_string(),
+        })?;
+    let path = save_png(&png)?;
+    Ok(RenderedDiagram::Image {
+        renderer,
+        path,
+        png,
+        width,
+        height,
+    })
+}
+
+/// History cell reporting one rendered (or failed) diagram.
+pub(crate) fn result_cell(
+    result: &Result<RenderedDiagram, DiagramError>,
+    language: Language,
+) -> PlainHistoryCell {
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    match result {
+        Ok(RenderedDiagram::Image { renderer, path, .. }) => {
+            let message = tr_args(
+                language,
+                "diagram.rendered",
+                &[
+                    ("renderer", renderer),
+                    ("path", &path.display().to_string()),
+                ],
+            );
+            lines.push(vec!["• ".dim(), message.into()].into());
+        }
+        Ok(RenderedDiagram::Text { renderer, layout }) => {
+            let message = tr_args(language, "diagram.text_fallback", &[("renderer", renderer)]);
+            lines.push(vec!["• ".dim(), message.into()].into());
+            lines.extend(
+                layout
+                    .lines
+                    .iter()
+                    .map(|line| Line::from(format!("  {line}"))),
+            );
+            if !layout.unrouted.is_empty() {
+                let edges = layout
+                    .unrouted
+                    .iter()
+                    .map(|(from, to)| format!("{from} → {to}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let message = tr_args(language, "diagram.unrouted", &[("edges", &edges)]);
+                lines.push(Line::from(format!("  {message}")).dim());
+            }
+        }
+        Err(err) => {
+            let message = match err {
+                DiagramError::RendererMissing(renderer) => tr_args(
+                    language,
+                    "diagram.renderer_missing",
+                    &[("renderer", renderer)],
+                ),
+                DiagramError::RendererFailed { renderer, detail } => tr_args(
+                    language,
+                    "diagram.renderer_failed",
+                    &[("renderer", renderer), ("detail", detail)],
+                ),
+                DiagramError::Io(detail) => {
+                    tr_args(language, "diagram.io_error", &[("detail", detail)])
+                }
+            };
+            lines.push(vec!["■ ".red(), message.red()].into());
+        }
+    }
+    PlainHistoryCell::new(lines)
+}
+
+enum RunError {
+    NotInstalled,
+    Failed(String),
+}
+
+fn run_dot(source: &str) -> Result<Vec<u8>, RunError> {
+    let mut child = Command::new("dot")
+        .arg("-Tpng")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(spawn_error)?;
+    // dot reads all of its input before writing, so this cannot deadlock.
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(source.as_bytes())
+            .map_err(|err| RunError::Failed(err.to_string()))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|err| RunError::Failed(err.to_string()))?;
+    if !output.status.success() {
+        return Err(RunError::Failed(failure_detail(&output)));
+    }
+    Ok(output.stdout)
+}
+
+fn run_mmdc(source: &str) -> Result<Vec<u8>, RunError> {
+    let dir = tempfile::tempdir().map_err(|err| RunError::Failed(err.to_string()))?;
+    let input = dir.path().join("diagram.mmd");
+    let output_path = dir.path().join("diagram.png");
+    std::fs::write(&input, source).map_err(|err| RunError::Failed(err.to_string()))?;
+    let output = Command::new("mmdc")
+        .arg("--quiet")
+        .arg("--input")
+        .arg(&input)
+        .arg("--output")
+        .arg(&output_path)
+        .args(["--backgroundColor", "white"])
+        .stdin(Stdio::null())
+        .output()
+        .map_err(spawn_error)?;
+    if !output.status.success() {
+        return Err(RunError::Failed(failure_detail(&output)));
+    }
+    std::fs::read(&output_path).map_err(|err| RunError::Failed(err.to_string()))
+}
+
+fn spawn_error(err: std::io::Error) -> RunError {
+    match err.kind() {
+        std::io::ErrorKind::NotFound => RunError::NotInstalled,
+        _ => RunError::Failed(err.to_string()),
+    }
+}
+
+/// First non-empty stderr line, or the exit status.
+fn failure_detail(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| output.status.to_string())
+}
+
+fn save_png(png: &[u8]) -> Result<PathBuf, DiagramError> {
+    let tmp = Builder::new()
+        .prefix("codex-diagram-")
+        .suffix(".png")
+        .tempfile()
+        .map_err(|err| DiagramError::Io(err.to_string()))?;
+    std::fs::write(tmp.path(), png).map_err(|err| DiagramError::Io(err.to_string()))?;
+    let (_file, path) = tmp
+        .keep()
+        .map_err(|err| DiagramError::Io(err.error.to_string()))?;
+    Ok(path)
+}
+
+/// Largest graph the built-in layout draws; bigger ones need the renderer.
+const MAX_LAYOUT_NODES: usize = 40;
+const BOX_HEIGHT: usize = 3;
+/// Rows between two layers: a stub under the parent, a routing row and the
+/// arrow above the child.
+const LAYER_GAP: usize = 3;
+const NODE_GAP: usize = 2;
+
+const MERMAID_ARROWS: &[&str] = &[
+    "<-->", "-.->", "-->", "---", "==>", "===", "-.-", "--o", "--x",
+];
+const MERMAID_IGNORED: &[&str] = &[
+    "classDef ",
+    "class ",
+    "style ",
+    "linkStyle ",
+    "click ",
+    "subgraph ",
+    "direction ",
+];
+
+/// Nodes and edges of a flowchart simple enough for [`layout_graph`].
+#[derive(Debug, Default)]
+pub(crate) struct SimpleGraph {
+    labels: Vec<String>,
+    ids: HashMap<String, usize>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl SimpleGraph {
+    fn node(&mut self, id: &str, label: Option<&str>) -> usize {
+        let next = self.labels.len();
+        let index = *self.ids.entry(id.to_string()).or_insert(next);
+        if index == next {
+            self.labels.push(id.to_string());
+        }
+        if let Some(label) = label.filter(|label| !label.is_empty()) {
+            self.labels[index] = label.to_string();
+        }
+        index
+    }
+
+    fn edge(&mut self, from: usize, to: usize) {
+        if !self.edges.contains(&(from, to)) {
+            self.edges.push((from, to));
+        }
+    }
+}
+
+/// Parse `block` as a plain flowchart, or `None` when it uses anything the
+/// built-in layout does not understand.
+pub(crate) fn parse_simple_graph(block: &DiagramBlock) -> Option<SimpleGraph> {
+    let graph = match block.kind {
+        DiagramKind::Mermaid => parse_mermaid(&block.source)?,
+        DiagramKind::Graphviz => parse_dot(&block.source)?,
+    };
+    (!graph.labels.is_empty() && graph.labels.len() <= MAX_LAYOUT_NODES).then_some(graph)
+}
+
+fn parse_mermaid(source: &str) -> Option<SimpleGraph> {
+    let mut statements = source
+        .lines()
+        .flat_map(|line| line.split(';'))
+        .map(str::trim)
+        .filter(|statement| !statement.is_empty() && !statement.starts_with("%%"));
+    let keyword = statements.next()?.split_whitespace().next()?;
+    if keyword != "graph" && keyword != "flowchart" {
+        return None;
+    }
+
+    let mut graph = SimpleGraph::default();
+    for statement in statements {
+        if statement == "end"
+            || MERMAID_IGNORED
+                .iter()
+                .any(|prefix| statement.starts_with(prefix))
+        {
+            continue;
+        }
+        let mut groups = Vec::new();
+        for part in split_mermaid_chain(statement) {
+            let nodes = part
+                .split(" & ")
+                .map(|node| mermaid_node(&mut graph, node))
+                .collect::<Option<Vec<_>>>()?;
+            groups.push(nodes);
+        }
+        for pair in groups.windows(2) {
+            for &from in &pair[0] {
+                for &to in &pair[1] {
+                    graph.edge(from, to);
+                }
+            }
+        }
+    }
+    Some(graph)
+}
+
+/// Split `A --> B -->|label| C` into its node parts.
+fn split_mermaid_chain(statement: &str) -> Vec<&str> {
+    let bytes = statement.as_bytes();
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut in_quotes = false;
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => in_quotes = !in_quotes,
+            b'[' | b'(' | b'{' if !in_quotes => depth += 1,
+            b']' | b')' | b'}' if !in_quotes => depth = depth.saturating_sub(1),
+            _ if depth == 0 && !in_quotes => {
+                if let Some(arrow) = MERMAID_ARROWS
+                    .iter()
+                    .find(|arrow| bytes[i..].starts_with(arrow.as_bytes()))
+                {
+                    parts.push(statement[start..i].trim());
+                    i += arrow.len();
+                    // Longer links such as `--->` or `====>`.
+                    while i < bytes.len() && matches!(bytes[i], b'-' | b'=' | b'.' | b'>') {
+                        i += 1;
+                    }
+                    // Edge text: `-->|label|`.
+                    let mut j = i;
+                    while j < bytes.len() && bytes[j] == b' ' {
+                        j += 1;
+                    }
+                    if j < bytes.len()
+                        && bytes[j] == b'|'
+                        && let Some(end) = bytes[j + 1..].iter().position(|&b| b == b'|')
+                    {
+                        i = j + 1 + end + 1;
+                    }
+                    start = i;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    parts.push(statement[start..].trim());
+    parts
+}
+
+/// `A`, `A[Label]`, `A(Label)`, `A{Label}`, `A((Label))`, ...
+fn mermaid_node(graph: &mut SimpleGraph, text: &str) -> Option<usize> {
+    let text = text.trim();
+    let id_end = text.find(['[', '(', '{', '>']).unwrap_or(text.len());
+    let id = text[..id_end].split(":::").next()?.trim();
+    if id.is_empty() || id.contains(char::is_whitespace) {
+        return None;
+    }
+    let label = text[id_end..]
+        .trim_matches(|c: char| "[](){}>/\\".contains(c))
+        .trim()
+        .trim_matches('"');
+    Some(graph.node(id, Some(label)))
+}
+
+fn parse_dot(source: &str) -> Option<SimpleGraph> {
+    let open = source.find('{')?;
+    let close = source.rfind('}')?;
+    if !source[..open].to_ascii_lowercase().contains("graph") || close < open {
+        return None;
+    }
+
+    let mut graph = SimpleGraph::default();
+    let statements = source[open + 1..close]
+        .split(['\n', ';'])
+        .map(str::trim)
+        .filter(|statement| !statement.is_empty() && !statement.starts_with("//"));
+    for statement in statements {
+        let (target, attrs) = match statement.find('[') {
+            Some(index) => (statement[..index].trim(), Some(&statement[index + 1..])),
+            None => (statement, None),
+        };
+        let keyword = target.split_whitespace().next().unwrap_or_default();
+        let operator = if target.contains("->") { "->" } else { "--" };
+        if matches!(keyword, "graph" | "node" | "edge" | "subgraph" | "{" | "}")
+            || target.is_empty()
+            || (target.contains('=') && !target.contains(operator))
+        {
+            continue;
+        }
+
+        let ids = target
+            .split(operator)
+            .map(|id| {
+                let id = id.trim().trim_matches('"');
+                (!id.is_empty() && (!id.contains(char::is_whitespace) || target.contains('"')))
+                    .then_some(id)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        if let [id] = ids.as_slice() {
+            let label = attrs.and_then(dot_label);
+            graph.node(id, label.as_deref());
+            continue;
+        }
+        let nodes: Vec<usize> = ids.iter().map(|id| graph.node(id, None)).collect();
+        for pair in nodes.windows(2) {
+            graph.edge(pair[0], pair[1]);
+        }
+    }
+    Some(graph)
+}
+
+fn dot_label(attrs: &str) -> Option<String> {
+    let (_, rest) = attrs.split_once("label")?;
+    let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+    let label = match rest.strip_prefix('"') {
+        Some(quoted) => quoted.split('"').next()?,
+        None => rest.split([',', ' ', ']']).next()?,
+    };
+    Some(label.replace("\\n", " "))
+}
+
+/// A flowchart drawn with box characters, top to bottom.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct TextLayout {
+    pub(crate) lines: Vec<String>,
+    /// Edges the drawing cannot route (cycles and edges that skip a layer),
+    /// as `(from, to)` labels.
+    pub(crate) unrouted: Vec<(String, String)>,
+}
+
+/// Lay `graph` out in layers (longest path from the roots, cycles broken by
+/// a depth-first search) and draw edges between adjacent layers.
+pub(crate) fn layout_graph(graph: &SimpleGraph) -> TextLayout {
+    let node_count = graph.labels.len();
+    let back_edges = back_edges(graph);
+    let forward: Vec<(usize, usize)> = graph
+        .edges
+        .iter()
+        .zip(&back_edges)
+        .filter(|(_, back)| !**back)
+        .map(|(edge, _)| *edge)
+        .collect();
+
+    let mut layer = vec![0usize; node_count];
+    for _ in 0..node_count {
+        let mut changed = false;
+        for &(from, to) in &forward {
+            if layer[to] < layer[from] + 1 {
+                layer[to] = layer[from] + 1;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let layer_count = layer.iter().max().map_or(0, |max| max + 1);
+    let mut layers: Vec<Vec<usize>> = vec![Vec::new(); layer_count];
+    for (node, &node_layer) in layer.iter().enumerate() {
+        layers[node_layer].push(node);
+    }
+
+    // Order each layer by the mean position of its parents to limit crossings.
+    let mut position = vec![0f64; node_count];
+    for nodes in &mut layers {
+        let keys: Vec<f64> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, &node)| {
+                let parents: Vec<f64> = forward
+                    .iter()
+                    .filter(|(_, to)| *to == node)
+                    .map(|(from, _)| position[*from])
+                    .collect();
+                if parents.is_empty() {
+                    index as f64
+                } else {
+                    parents.iter().sum::<f64>() / parents.len() as f64
+                }
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..nodes.len()).collect();
+        order.sort_by(|a, b| keys[*a].total_cmp(&keys[*b]));
+        *nodes = order.into_iter().map(|index| nodes[index]).collect();
+        for (index, &node) in nodes.iter().enumerate() {
+            position[node] = index as f64;
+        }
+    }
+
+    let box_width: Vec<usize> = graph
+        .labels
+        .iter()
+        .map(|label| UnicodeWidthStr::width(label.as_str()) + 4)
+        .collect();
+    let layer_width = |nodes: &[usize]| {
+        nodes.iter().map(|node| box_width[*node]).sum::<usize>()
+            + NODE_GAP * nodes.len().saturating_sub(1)
+    };
+    let width = layers
+        .iter()
+        .map(|nodes| layer_width(nodes))
+        .max()
+        .unwrap_or(0);
+    let height = layer_count * (BOX_HEIGHT + LAYER_GAP) - LAYER_GAP;
+    let layer_top = |layer: usize| layer * (BOX_HEIGHT + LAYER_GAP);
+
+    let mut canvas = Canvas::new(width, height);
+    let mut center = vec![0usize; node_count];
+    for (index, nodes) in layers.iter().enumerate() {
+        let mut x = (width - layer_width(nodes)) / 2;
+        for &node in nodes {
+            canvas.draw_box(x, layer_top(index), box_width[node], &graph.labels[node]);
+            center[node] = x + box_width[node] / 2;
+            x += box_width[node] + NODE_GAP;
+        }
+    }
+
+    let mut unrouted = Vec::new();
+    for (&(from, to), back) in graph.edges.iter().zip(&back_edges) {
+        if *back || layer[to] != layer[from] + 1 {
+            unrouted.push((graph.labels[from].clone(), graph.labels[to].clone()));
+            continue;
+        }
+        let bottom = layer_top(layer[from]) + BOX_HEIGHT - 1;
+        let (x_from, x_to) = (center[from], center[to]);
+        canvas.put(x_from, bottom, '┬');
+        canvas.connect(x_from, bottom + 1, UP | DOWN);
+        if x_from == x_to {
+            canvas.connect(x_from, bottom + 2, UP | DOWN);
+        } else {
+            let (toward, back_toward) = if x_to > x_from {
+                (RIGHT, LEFT)
+            } else {
+                (LEFT, RIGHT)
+            };
+            canvas.connect(x_from, bottom + 2, UP | toward);
+            for x in x_from.min(x_to) + 1..x_from.max(x_to) {
+                canvas.connect(x, bottom + 2, LEFT | RIGHT);
+            }
+            canvas.connect(x_to, bottom + 2, DOWN | back_toward);
+        }
+        canvas.put(x_to, bottom + 3, '▼');
+    }
+
+    TextLayout {
+        lines: canvas.into_lines(),
+        unrouted,
+    }
+}
+
+/// Marks the edges that close a cycle, found by a depth-first search.
+fn back_edges(graph: &SimpleGraph) -> Vec<bool> {
+    fn visit(node: usize, graph: &SimpleGraph, state: &mut [u8], back: &mut [bool]) {
+        state[node] = 1;
+        for (index, &(from, to)) in graph.edges.iter().enumerate() {
+            if from != node {
+                continue;
+            }
+            match state[to] {
+                0 => visit(to, graph, state, back),
+                1 => back[index] = true,
+                _ => {}
+            }
+        }
+        state[node] = 2;
+    }
+
+    let mut state = vec![0u8; graph.labels.len()];
+    let mut back = vec![false; graph.edges.len()];
+    for node in 0..graph.labels.len() {
+        if state[node] == 0 {
+            visit(node, graph, &mut state, &mut back);
+        }
+    }
+    back
+}
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+#[derive(Clone, Copy)]
+enum Cell {
+    Empty,
+    Char(char),
+    /// Second column of a double-width character.
+    WideTail,
+    /// Line segments leaving the cell, as `UP | DOWN | LEFT | RIGHT` bits.
+    Lines(u8),
+}
+
+struct Canvas {
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            rows: vec![vec![Cell::Empty; width]; height],
+        }
+    }
+
+    fn put(&mut self, x: usize, y: usize, ch: char) {
+        if let Some(cell) = self.rows.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *cell = Cell::Char(ch);
+        }
+    }
+
+    fn connect(&mut self, x: usize, y: usize, bits: u8) {
+        if let Some(cell) = self.rows.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *cell = match *cell {
+                Cell::Empty => Cell::Lines(bits),
+                Cell::Lines(existing) => Cell::Lines(existing | bits),
+                other => other,
+            };
+        }
+    }
+
+    fn text(&mut self, mut x: usize, y: usize, text: &str) {
+        for ch in text.chars() {
+            let width = UnicodeWidthChar::width(ch).unwrap_or(0);
+            if width == 0 {
+                continue;
+            }
+            self.put(x, y, ch);
+            if width == 2
+                && let Some(cell) = self.rows.get_mut(y).and_then(|row| row.get_mut(x + 1))
+            {
+                *cell = Cell::WideTail;
+            }
+            x += width;
+        }
+    }
+
+    fn draw_box(&mut self, x: usize, y: usize, width: usize, label: &str) {
+        let rule = "─".repeat(width - 2);
+        self.text(x, y, &format!("┌{rule}┐"));
+        self.text(x, y + 1, &format!("│ {label} │"));
+        self.text(x, y + 2, &format!("└{rule}┘"));
+    }
+
+    fn into_lines(self) -> Vec<String> {
+        self.rows
+            .into_iter()
+            .map(|row| {
+                let line: String = row
+                    .into_iter()
+                    .filter_map(|cell| match cell {
+                        Cell::Empty => Some(' '),
+                        Cell::Char(ch) => Some(ch),
+                        Cell::WideTail => None,
+                        Cell::Lines(bits) => Some(line_char(bits)),
+                    })
+                    .collect();
+                line.trim_end().to_string()
+            })
+            .collect()
+    }
+}
+
+fn line_char(bits: u8) -> char {
+    let up = bits & UP != 0;
+    let down = bits & DOWN != 0;
+    let left = bits & LEFT != 0;
+    let right = bits & RIGHT != 0;
+    match (up, down, left, right) {
+        (_, _, false, false) => '│',
+        (false, false, _, _) => '─',
+        (false, true, false, true) => '┌',
+        (false, true, true, false) => '┐',
+        (true, false, false, true) => '└',
+        (true, false, true, false) => '┘',
+        (true, true, false, true) => '├',
+        (true, true, true, false) => '┤',
+        (false, true, true, true) => '┬',
+        (true, false, true, true) => '┴',
+        (true, true, true, true) => '┼',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn mermaid(source: &str) -> DiagramBlock {
+        DiagramBlock {
+            kind: DiagramKind::Mermaid,
+            source: source.to_string(),
+        }
+    }
+
+    #[test]
+    fn extracts_only_diagram_fences() {
+        let markdown = "Here:\n\n```mermaid\ngraph TD\n  A --> B\n```\n\n```rust\nfn main() {}\n```\n\n~~~dot\ndigraph { a -> b }\n~~~\n";
+        assert_eq!(
+            extract_diagram_blocks(markdown),
+            vec![
+                mermaid("graph TD\nA --> B"),
+                DiagramBlock {
+                    kind: DiagramKind::Graphviz,
+                    source: "digraph { a -> b }".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn lays_out_a_branching_flowchart() {
+        let graph = parse_simple_graph(&mermaid(
+            "graph TD\n  A[Start] --> B{Ok?}\n  B -->|yes| C[Done]\n  B -->|no| D[Retry]\n  D --> A",
+        ))
+        .expect("simple flowchart");
+        let layout = layout_graph(&graph);
+        assert_eq!(
+            layout.lines,
+            vec![
+                "     ┌───────┐",
+                "     │ Start │",
+                "     └───┬───┘",
+                "         │",
+                "         │",
+                "         ▼",
+                "      ┌─────┐",
+                "      │ Ok? │",
+                "      └──┬──┘",
+                "         │",
+                "    ┌────┴────┐",
+                "    ▼         ▼",
+                "┌──────┐  ┌───────┐",
+                "│ Done │  │ Retry │",
+                "└──────┘  └───────┘",
+            ]
+        );
+        assert_eq!(
+            layout.unrouted,
+            vec![("Retry".to_string(), "Start".to_string())]
+        );
+    }
+
+    #[test]
+    fn joins_fan_in_edges_and_reads_dot_labels() {
+        let graph = parse_simple_graph(&mermaid("flowchart LR\n  A & B --> C[Merge]"))
+            .expect("simple flowchart");
+        assert_eq!(
+            layout_graph(&graph).lines,
+            vec![
+                "┌───┐  ┌───┐",
+                "│ A │  │ B │",
+                "└─┬─┘  └─┬─┘",
+                "  │      │",
+                "  └──┬───┘",
+                "     ▼",
+                " ┌───────┐",
+                " │ Merge │",
+                " └───────┘",
+            ]
+        );
+
+        let dot = DiagramBlock {
+            kind: DiagramKind::Graphviz,
+            source: "digraph G {\n  a [label=\"Parse\"];\n  a -> b -> c;\n  a -> c;\n}".to_string(),
+        };
+        let layout = layout_graph(&parse_simple_graph(&dot).expect("simple digraph"));
+        assert_eq!(layout.lines[1], "│ Parse │");
+        assert_eq!(
+            layout.unrouted,
+            vec![("Parse".to_string(), "c".to_string())]
+        );
+    }
+
+    #[test]
+    fn rejects_diagrams_that_are_not_flowcharts() {
+        assert!(parse_simple_graph(&mermaid("sequenceDiagram\n  A->>B: hi")).is_none());
+        assert!(parse_simple_graph(&mermaid("graph TD\n  A -- text --> B")).is_none());
+    }
+}
@@ -15,6 +15,7 @@ use std::path::PathBuf;
 use codex_protocol::config_types::Language;
 
 use crate::exec_command::relativize_to_home;
+use crate::exec_command::strip_verbatim_path_prefix;
 use crate::i18n::tr;
 use crate::render::Insets;
 use crate::render::line_utils::prefix_lines;
@@ -312,6 +313,9 @@ fn render_change(change: &FileChange, out: &mut Vec<RtLine<'static>>, width: usi
 /// possible, keeping output stable in jj/no-`.git` workspaces (e.g. image
 /// tool calls should show `example.png` instead of an absolute path).
 pub(crate) fn display_path_for(path: &Path, cwd: &Path) -> String {
+    let path = strip_verbatim_path_prefix(path);
+    let cwd = strip_verbatim_path_prefix(cwd);
+    let (path, cwd) = (path.as_ref(), cwd.as_ref());
     if path.is_relative() {
         return path.display().to_string();
     }
@@ -500,6 +504,15 @@ mod tests {
         );
     }
 
+    #[cfg(windows)]
+    #[test]
+    fn display_path_strips_verbatim_prefix() {
+        let cwd = PathBuf::from(r"C:\workspace\codex");
+        let path = PathBuf::from(r"\\?\C:\workspace\codex\文档\说明.md");
+
+        assert_eq!(display_path_for(&path, &cwd), r"文档\说明.md");
+    }
+
     #[test]
     fn ui_snapshot_wrap_behavior_insert() {
         // Narrow width to force wrapping within our diff line rendering
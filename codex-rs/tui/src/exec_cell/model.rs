@@ -1,17 +1,25 @@
 use std::time::Duration;
 use std::time::Instant;
 
+use codex_core::config::types::TranscriptDensity;
+use codex_core::protocol::ExecCommandProvenance;
 use codex_core::protocol::ExecCommandSource;
 use codex_protocol::config_types::Language;
 use codex_protocol::parse_command::ParsedCommand;
 
+use super::render::TOOL_CALL_MAX_LINES;
+
 #[derive(Clone, Debug, Default)]
 pub(crate) struct CommandOutput {
     pub(crate) exit_code: i32,
+    /// Whether the command was killed for exceeding its timeout.
+    pub(crate) timed_out: bool,
     /// The aggregated stderr + stdout interleaved.
     pub(crate) aggregated_output: String,
     /// The formatted output of the command, as seen by the model.
     pub(crate) formatted_output: String,
+    /// Why the command ran and what cleared it, shown in the transcript.
+    pub(crate) provenance: Option<ExecCommandProvenance>,
 }
 
 #[derive(Debug, Clone)]
@@ -31,6 +39,8 @@ pub(crate) struct ExecCell {
     pub(crate) calls: Vec<ExecCall>,
     animations_enabled: bool,
     language: Language,
+    output_max_lines: usize,
+    density: TranscriptDensity,
 }
 
 impl ExecCell {
@@ -39,6 +49,8 @@ impl ExecCell {
             calls: vec![call],
             animations_enabled,
             language: Language::En,
+            output_max_lines: TOOL_CALL_MAX_LINES,
+            density: TranscriptDensity::Normal,
         }
     }
 
@@ -51,9 +63,30 @@ impl ExecCell {
             calls: vec![call],
             animations_enabled,
             language,
+            output_max_lines: TOOL_CALL_MAX_LINES,
+            density: TranscriptDensity::Normal,
         }
     }
 
+    /// Sets how many output lines an agent command keeps inline.
+    pub(crate) fn with_output_max_lines(mut self, output_max_lines: usize) -> Self {
+        self.output_max_lines = output_max_lines;
+        self
+    }
+
+    pub(crate) fn output_max_lines(&self) -> usize {
+        self.output_max_lines
+    }
+
+    pub(crate) fn with_density(mut self, density: TranscriptDensity) -> Self {
+        self.density = density;
+        self
+    }
+
+    pub(crate) fn density(&self) -> TranscriptDensity {
+        self.density
+    }
+
     pub(crate) fn set_language(&mut self, language: Language) {
         self.language = language;
     }
@@ -85,6 +118,8 @@ impl ExecCell {
                 calls: [self.calls.clone(), vec![call]].concat(),
                 animations_enabled: self.animations_enabled,
                 language: self.language,
+                output_max_lines: self.output_max_lines,
+                density: self.density,
             })
         } else {
             None
@@ -119,8 +154,10 @@ impl ExecCell {
                 call.duration = Some(elapsed);
                 call.output = Some(CommandOutput {
                     exit_code: 1,
+                    timed_out: false,
                     formatted_output: String::new(),
                     aggregated_output: String::new(),
+                    provenance: None,
                 });
             }
         }
@@ -149,6 +186,16 @@ impl ExecCell {
         self.calls.iter()
     }
 
+    /// The call that has started but not yet finished, if any. Unlike
+    /// [`Self::is_active`], this still reports a call after its first output
+    /// delta has arrived.
+    pub(crate) fn running_call(&self) -> Option<&ExecCall> {
+        self.calls
+            .iter()
+            .rev()
+            .find(|c| c.duration.is_none() && c.start_time.is_some())
+    }
+
     pub(crate) fn append_output(&mut self, call_id: &str, chunk: &str) -> bool {
         if chunk.is_empty() {
             return false;
@@ -4,6 +4,7 @@ use super::model::CommandOutput;
 use super::model::ExecCall;
 use super::model::ExecCell;
 use crate::exec_command::strip_bash_lc_and_escape;
+use crate::exec_command::strip_verbatim_prefix;
 use crate::history_cell::HistoryCell;
 use crate::i18n::tr;
 use crate::i18n::tr_args;
@@ -11,13 +12,18 @@ use crate::render::highlight::highlight_bash_to_lines;
 use crate::render::line_utils::prefix_lines;
 use crate::render::line_utils::push_owned_lines;
 use crate::shimmer::shimmer_spans;
+use crate::shimmer::true_color_enabled;
 use crate::wrapping::RtOptions;
 use crate::wrapping::word_wrap_line;
 use crate::wrapping::word_wrap_lines;
 use codex_ansi_escape::ansi_escape_line;
 use codex_common::elapsed::format_duration;
 use codex_core::bash::extract_bash_command;
+use codex_core::config::types::TranscriptDensity;
+use codex_core::protocol::ExecCommandOrigin;
+use codex_core::protocol::ExecCommandProvenance;
 use codex_core::protocol::ExecCommandSource;
+use codex_core::protocol::ReviewDecision;
 use codex_protocol::config_types::Language;
 use codex_protocol::parse_command::ParsedCommand;
 use itertools::Itertools;
@@ -93,6 +99,56 @@ fn format_unified_exec_interaction(
     }
 }
 
+fn timed_out_message(call: &ExecCall, language: Language) -> String {
+    let seconds = call
+        .duration
+        .unwrap_or_default()
+        .as_secs_f64()
+        .round()
+        .to_string();
+    tr_args(
+        language,
+        "exec_cell.output.timed_out",
+        &[("seconds", seconds.as_str())],
+    )
+}
+
+/// One line saying why a command ran, how it was cleared, and which rule
+/// matched, e.g. "run by the model · approved for this session · rule …".
+fn provenance_summary(provenance: &ExecCommandProvenance, language: Language) -> String {
+    let origin = match provenance.origin {
+        ExecCommandOrigin::Model => "exec_cell.provenance.origin_model",
+        ExecCommandOrigin::UserShell => "exec_cell.provenance.origin_user_shell",
+        ExecCommandOrigin::SddGit => "exec_cell.provenance.origin_sdd_git",
+        ExecCommandOrigin::Hook => "exec_cell.provenance.origin_hook",
+    };
+    let approval = match provenance.approval {
+        None => "exec_cell.provenance.no_approval",
+        Some(ReviewDecision::Approved | ReviewDecision::ApprovedHunks { .. }) => {
+            "exec_cell.provenance.approved"
+        }
+        Some(ReviewDecision::ApprovedExecpolicyAmendment { .. }) => {
+            "exec_cell.provenance.approved_with_amendment"
+        }
+        Some(ReviewDecision::ApprovedForSession) => "exec_cell.provenance.approved_for_session",
+        Some(ReviewDecision::Denied) => "exec_cell.provenance.denied",
+        Some(ReviewDecision::Abort) => "exec_cell.provenance.abort",
+    };
+    let mut parts = vec![tr(language, origin).to_string()];
+    // Commands the user or an SDD action ran never go through approval.
+    if provenance.origin == ExecCommandOrigin::Model {
+        parts.push(tr(language, approval).to_string());
+    }
+    if let Some(rule) = provenance.policy_rule.as_deref() {
+        parts.push(tr_args(
+            language,
+            "exec_cell.provenance.rule",
+            &[("rule", rule)],
+        ));
+    }
+    parts.join(" · ")
+}
+
 fn summarize_interaction_input(input: &str) -> String {
     let single_line = input.replace('\n', "\\n");
     let sanitized = single_line.replace('`', "\\`");
@@ -208,10 +264,7 @@ pub(crate) fn spinner(start_time: Option<Instant>, animations_enabled: bool) ->
         return "•".dim();
     }
     let elapsed = start_time.map(|st| st.elapsed()).unwrap_or_default();
-    if supports_color::on_cached(supports_color::Stream::Stdout)
-        .map(|level| level.has_16m)
-        .unwrap_or(false)
-    {
+    if true_color_enabled() {
         shimmer_spans("•")[0].clone()
     } else {
         let blink_on = (elapsed.as_millis() / 600).is_multiple_of(2);
@@ -269,7 +322,19 @@ impl HistoryCell for ExecCell {
                     ])
                 };
                 result.push_span(format!(" • {duration}").dim());
+                if output.timed_out {
+                    result.push_span(" • ".dim());
+                    result.push_span(timed_out_message(call, self.language()).red());
+                }
                 lines.push(result);
+                if let Some(provenance) = output.provenance.as_ref() {
+                    let line = Line::from(provenance_summary(provenance, self.language())).dim();
+                    let wrapped = word_wrap_line(
+                        &line,
+                        RtOptions::new(width.max(1) as usize).subsequent_indent("  ".into()),
+                    );
+                    push_owned_lines(&wrapped, &mut lines);
+                }
             }
         }
         lines
@@ -348,7 +413,14 @@ impl ExecCell {
                         ParsedCommand::ListFiles { cmd, path } => {
                             lines.push((
                                 tr(language, "exec_cell.command.list"),
-                                vec![path.clone().unwrap_or(cmd.clone()).into()],
+                                vec![
+                                    path.as_deref()
+                                        .map_or_else(
+                                            || cmd.clone(),
+                                            |path| strip_verbatim_prefix(path).into_owned(),
+                                        )
+                                        .into(),
+                                ],
                             ));
                         }
                         ParsedCommand::Search { cmd, query, path } => {
@@ -357,7 +429,7 @@ impl ExecCell {
                                     vec![
                                         q.clone().into(),
                                         tr(language, "exec_cell.command.search_in").dim(),
-                                        p.clone().into(),
+                                        strip_verbatim_prefix(p).into_owned().into(),
                                     ]
                                 }
                                 (Some(q), None) => vec![q.clone().into()],
@@ -473,11 +545,43 @@ impl ExecCell {
             ));
         }
 
-        if let Some(output) = call.output.as_ref() {
+        let density = if call.is_user_shell_command() {
+            TranscriptDensity::Normal
+        } else {
+            self.density()
+        };
+        if let Some(output) = call.output.as_ref()
+            && density == TranscriptDensity::Compact
+        {
+            if !is_interaction {
+                let count = output.aggregated_output.lines().count();
+                let summary = if count == 0 {
+                    tr(language, "exec_cell.output.no_output").to_string()
+                } else {
+                    tr_args(
+                        language,
+                        "exec_cell.output.line_count",
+                        &[("count", &count.to_string())],
+                    )
+                };
+                lines.push(Line::from(vec![
+                    Span::from(layout.output_block.initial_prefix).dim(),
+                    summary.dim(),
+                ]));
+            }
+            if output.timed_out {
+                lines.push(Line::from(vec![
+                    Span::from(layout.output_block.subsequent_prefix),
+                    timed_out_message(call, language).red(),
+                ]));
+            }
+        } else if let Some(output) = call.output.as_ref() {
             let line_limit = if call.is_user_shell_command() {
                 USER_SHELL_TOOL_CALL_MAX_LINES
+            } else if density == TranscriptDensity::Verbose {
+                output.aggregated_output.lines().count().max(1)
             } else {
-                TOOL_CALL_MAX_LINES
+                self.output_max_lines()
             };
             let raw_output = output_lines(
                 Some(output),
@@ -489,10 +593,10 @@ impl ExecCell {
                 },
                 language,
             );
-            let display_limit = if call.is_user_shell_command() {
-                USER_SHELL_TOOL_CALL_MAX_LINES
+            let display_limit = if density == TranscriptDensity::Verbose {
+                usize::MAX
             } else {
-                layout.output_max_lines
+                line_limit
             };
 
             if raw_output.lines.is_empty() {
@@ -518,6 +622,8 @@ impl ExecCell {
                     );
                 }
 
+                let truncated =
+                    raw_output.omitted.is_some() || wrapped_output.len() > display_limit;
                 let trimmed_output =
                     self.truncate_lines_middle(&wrapped_output, display_limit, raw_output.omitted);
 
@@ -528,6 +634,22 @@ impl ExecCell {
                         Span::from(layout.output_block.subsequent_prefix),
                     ));
                 }
+
+                // Only a running command can be followed; once it finishes the
+                // full output lives in the transcript overlay instead.
+                if truncated && self.running_call().is_some() && !is_interaction {
+                    lines.push(Line::from(vec![
+                        Span::from(layout.output_block.subsequent_prefix),
+                        tr(language, "exec_cell.output.follow_hint").dim(),
+                    ]));
+                }
+            }
+
+            if output.timed_out {
+                lines.push(Line::from(vec![
+                    Span::from(layout.output_block.subsequent_prefix),
+                    timed_out_message(call, language).red(),
+                ]));
             }
         }
 
@@ -631,7 +753,6 @@ struct ExecDisplayLayout {
     command_continuation: PrefixedBlock,
     command_continuation_max_lines: usize,
     output_block: PrefixedBlock,
-    output_max_lines: usize,
 }
 
 impl ExecDisplayLayout {
@@ -639,13 +760,11 @@ impl ExecDisplayLayout {
         command_continuation: PrefixedBlock,
         command_continuation_max_lines: usize,
         output_block: PrefixedBlock,
-        output_max_lines: usize,
     ) -> Self {
         Self {
             command_continuation,
             command_continuation_max_lines,
             output_block,
-            output_max_lines,
         }
     }
 }
@@ -654,13 +773,13 @@ const EXEC_DISPLAY_LAYOUT: ExecDisplayLayout = ExecDisplayLayout::new(
     PrefixedBlock::new("  │ ", "  │ "),
     2,
     PrefixedBlock::new("  └ ", "    "),
-    5,
 );
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use codex_core::protocol::ExecCommandSource;
+    use pretty_assertions::assert_eq;
 
     #[test]
     fn user_shell_output_is_limited_by_screen_lines() {
@@ -680,8 +799,10 @@ mod tests {
         // all logical lines without any truncation?
         let output = CommandOutput {
             exit_code: 0,
+            timed_out: false,
             aggregated_output,
             formatted_output: String::new(),
+            provenance: None,
         };
         let width = 20;
         let layout = EXEC_DISPLAY_LAYOUT;
@@ -752,4 +873,147 @@ mod tests {
             "expected at most {USER_SHELL_TOOL_CALL_MAX_LINES} screen lines of user shell output, got {output_screen_lines}",
         );
     }
+
+    #[test]
+    fn timed_out_command_shows_timeout_line() {
+        let call = ExecCall {
+            call_id: "call-id".to_string(),
+            command: vec!["bash".into(), "-lc".into(), "sleep 100".into()],
+            parsed: Vec::new(),
+            output: Some(CommandOutput {
+                exit_code: 124,
+                timed_out: true,
+                aggregated_output: "waiting\n".to_string(),
+                formatted_output: String::new(),
+                provenance: None,
+            }),
+            source: ExecCommandSource::Agent,
+            start_time: None,
+            duration: Some(std::time::Duration::from_millis(10_020)),
+            interaction_input: None,
+        };
+        let cell = ExecCell::new(call, false);
+
+        let text: Vec<String> = cell
+            .command_display_lines(80)
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(
+            text.last().map(String::as_str),
+            Some("    timed out after 10s")
+        );
+    }
+
+    #[test]
+    fn transcript_shows_command_provenance() {
+        let call = ExecCall {
+            call_id: "call-id".to_string(),
+            command: vec!["git".into(), "push".into()],
+            parsed: Vec::new(),
+            output: Some(CommandOutput {
+                exit_code: 0,
+                timed_out: false,
+                aggregated_output: String::new(),
+                formatted_output: String::new(),
+                provenance: Some(ExecCommandProvenance {
+                    origin: ExecCommandOrigin::Model,
+                    policy_rule: Some(
+                        r#"prefix_rule(pattern=["git", "push"], decision="prompt")"#.to_string(),
+                    ),
+                    approval: Some(ReviewDecision::ApprovedForSession),
+                }),
+            }),
+            source: ExecCommandSource::Agent,
+            start_time: None,
+            duration: Some(std::time::Duration::from_millis(10)),
+            interaction_input: None,
+        };
+        let cell = ExecCell::new(call, false);
+
+        let text: Vec<String> = cell
+            .transcript_lines(200)
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(
+            text.last().map(String::as_str),
+            Some(
+                r#"run by the model · approved for this session · rule prefix_rule(pattern=["git", "push"], decision="prompt")"#
+            )
+        );
+    }
+
+    #[test]
+    fn density_controls_agent_output() {
+        let aggregated_output: String = (1..=12).map(|n| format!("line {n}\n")).collect();
+        let call = ExecCall {
+            call_id: "call-id".to_string(),
+            command: vec!["bash".into(), "-lc".into(), "cargo test".into()],
+            parsed: Vec::new(),
+            output: Some(CommandOutput {
+                exit_code: 0,
+                timed_out: false,
+                aggregated_output,
+                formatted_output: String::new(),
+                provenance: None,
+            }),
+            source: ExecCommandSource::Agent,
+            start_time: None,
+            duration: Some(std::time::Duration::from_millis(10)),
+            interaction_input: None,
+        };
+        let render = |density| -> Vec<String> {
+            ExecCell::new(call.clone(), false)
+                .with_density(density)
+                .command_display_lines(80)
+                .iter()
+                .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+                .collect()
+        };
+
+        assert_eq!(
+            render(TranscriptDensity::Compact),
+            vec!["• Ran cargo test", "  └ 12 lines of output"]
+        );
+        let verbose = render(TranscriptDensity::Verbose);
+        assert_eq!(verbose.len(), 13);
+        assert_eq!(verbose.last().map(String::as_str), Some("    line 12"));
+        assert!(render(TranscriptDensity::Normal).len() < 13);
+    }
+
+    #[test]
+    fn progress_rewrites_and_colors_render_as_final_state() {
+        let output = CommandOutput {
+            exit_code: 0,
+            timed_out: false,
+            aggregated_output: "\u{1b}[1;32mfetch\u{1b}[0m\u{1b}[K\n 10%\r 55%\r100%\ndone\r\n"
+                .to_string(),
+            formatted_output: String::new(),
+            provenance: None,
+        };
+        let rendered = output_lines(
+            Some(&output),
+            OutputLinesParams {
+                line_limit: TOOL_CALL_MAX_LINES,
+                only_err: false,
+                include_angle_pipe: false,
+                include_prefix: false,
+            },
+            Language::En,
+        );
+
+        let text: Vec<String> = rendered
+            .lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(text, vec!["fetch", "100%", "done"]);
+        let fetch = rendered.lines[0]
+            .spans
+            .iter()
+            .find(|span| span.content == "fetch")
+            .expect("fetch span");
+        assert_eq!(fetch.style.fg, Some(Color::Green));
+    }
 }
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::path::Path;
 use std::path::PathBuf;
 
@@ -34,6 +35,35 @@ where
     Some(rel.to_path_buf())
 }
 
+/// Drop the Windows extended-length prefix that canonicalized paths carry
+/// (`\\?\C:\repo` becomes `C:\repo`, `\\?\UNC\server\share` becomes
+/// `\\server\share`) so paths display the way users type them.
+pub(crate) fn strip_verbatim_prefix(path: &str) -> Cow<'_, str> {
+    if let Some(rest) = path.strip_prefix(r"\\?\UNC\") {
+        return Cow::Owned(format!(r"\\{rest}"));
+    }
+    match path.strip_prefix(r"\\?\") {
+        Some(rest) if has_drive_prefix(rest) => Cow::Borrowed(rest),
+        _ => Cow::Borrowed(path),
+    }
+}
+
+/// [`strip_verbatim_prefix`] for paths; non-UTF-8 paths are returned as-is.
+pub(crate) fn strip_verbatim_path_prefix(path: &Path) -> Cow<'_, Path> {
+    let Some(text) = path.to_str() else {
+        return Cow::Borrowed(path);
+    };
+    match strip_verbatim_prefix(text) {
+        Cow::Borrowed(stripped) => Cow::Borrowed(Path::new(stripped)),
+        Cow::Owned(stripped) => Cow::Owned(PathBuf::from(stripped)),
+    }
+}
+
+fn has_drive_prefix(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,4 +97,22 @@ mod tests {
         let cmdline = strip_bash_lc_and_escape(&args);
         assert_eq!(cmdline, "echo hello");
     }
+
+    #[test]
+    fn strip_verbatim_prefix_handles_drive_and_unc_paths() {
+        assert_eq!(
+            strip_verbatim_prefix(r"\\?\C:\repo\文档\说明.md"),
+            r"C:\repo\文档\说明.md"
+        );
+        assert_eq!(
+            strip_verbatim_prefix(r"\\?\UNC\server\share\src\lib.rs"),
+            r"\\server\share\src\lib.rs"
+        );
+        // Device paths without a drive letter keep their prefix.
+        assert_eq!(
+            strip_verbatim_prefix(r"\\?\Volume{1234}\file"),
+            r"\\?\Volume{1234}\file"
+        );
+        assert_eq!(strip_verbatim_prefix("/home/user/repo"), "/home/user/repo");
+    }
 }
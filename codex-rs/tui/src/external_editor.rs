@@ -34,14 +34,20 @@ pub(crate) fn resolve_editor_command() -> std::result::Result<Vec<String>, Edito
     let raw = env::var("VISUAL")
         .or_else(|_| env::var("EDITOR"))
         .map_err(|_| EditorError::MissingEditor)?;
+    split_command(&raw)
+}
+
+/// Split a command line into program and arguments the way the platform
+/// shell would.
+pub(crate) fn split_command(raw: &str) -> std::result::Result<Vec<String>, EditorError> {
     let parts = {
         #[cfg(windows)]
         {
-            winsplit::split(&raw)
+            winsplit::split(raw)
         }
         #[cfg(not(windows))]
         {
-            shlex::split(&raw).ok_or(EditorError::ParseFailed)?
+            shlex::split(raw).ok_or(EditorError::ParseFailed)?
         }
     };
     if parts.is_empty() {
@@ -90,6 +96,36 @@ pub(crate) async fn run_editor(seed: &str, editor_cmd: &[String]) -> Result<Stri
     Ok(contents)
 }
 
+/// Run `command` with the terminal attached and wait for it to exit.
+pub(crate) async fn run_command(command: &[String]) -> Result<()> {
+    let Some((program, args)) = command.split_first() else {
+        return Err(Report::msg("editor command is empty"));
+    };
+    let mut cmd = {
+        #[cfg(windows)]
+        {
+            Command::new(resolve_windows_program(program))
+        }
+        #[cfg(not(windows))]
+        {
+            Command::new(program)
+        }
+    };
+    let status = cmd
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .await?;
+    if !status.success() {
+        return Err(Report::msg(format!(
+            "{program} exited with status {status}"
+        )));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
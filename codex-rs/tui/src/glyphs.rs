@@ -0,0 +1,125 @@
+//! ASCII stand-ins for the symbols the TUI draws.
+//!
+//! History cells, spinners, and borders are written with box-drawing
+//! characters, bullets, and the odd emoji. Terminals without reliable Unicode
+//! (the Linux console, non-UTF-8 locales, CJK setups that draw ambiguous-width
+//! characters double-wide) misalign them, so the renderer swaps each one for
+//! an ASCII character of the same width right before it reaches the terminal.
+
+use std::borrow::Cow;
+
+use codex_core::terminal::TerminalCapabilities;
+use codex_core::terminal::terminal_capabilities;
+
+/// Text as it should be written to the current terminal.
+pub(crate) fn terminal_text(text: &str) -> Cow<'_, str> {
+    // Snapshot and vt100 tests expect the Unicode glyphs whatever locale the
+    // machine running them has.
+    if cfg!(test) {
+        return Cow::Borrowed(text);
+    }
+    substitute(text, terminal_capabilities())
+}
+
+fn substitute(text: &str, capabilities: TerminalCapabilities) -> Cow<'_, str> {
+    let ascii = capabilities.ascii_glyphs();
+    let replace = |ch: char| {
+        if ascii {
+            ascii_fallback(ch).or_else(|| emoji_fallback(ch))
+        } else if !capabilities.emoji {
+            emoji_fallback(ch)
+        } else {
+            None
+        }
+    };
+    if !text.chars().any(|ch| replace(ch).is_some()) {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match replace(ch) {
+            Some(fallback) => out.push_str(fallback),
+            None => out.push(ch),
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Single-column replacements for single-column symbols.
+fn ascii_fallback(ch: char) -> Option<&'static str> {
+    let fallback = match ch {
+        '─' | '╌' | '━' | '–' | '—' | '‑' => "-",
+        '│' | '┆' | '┃' | '▌' => "|",
+        '┌' | '┐' | '└' | '┘' | '╭' | '╮' | '╰' | '╯' | '├' | '┤' | '┬' | '┴' | '┼' => {
+            "+"
+        }
+        '•' | '●' | '■' | '█' => "*",
+        '◦' | '○' | '□' => "o",
+        '░' | '⋮' => ":",
+        '…' => ".",
+        '›' | '→' | '↳' => ">",
+        '←' => "<",
+        '↑' => "^",
+        '↓' | '▼' => "v",
+        '✔' | '✓' => "v",
+        '✗' | '✘' => "x",
+        '⚠' => "!",
+        '≈' => "~",
+        '‘' | '’' => "'",
+        '“' | '”' => "\"",
+        _ => return None,
+    };
+    Some(fallback)
+}
+
+/// Double-column replacements for emoji, so the rest of the row stays put.
+fn emoji_fallback(ch: char) -> Option<&'static str> {
+    match ch {
+        '✨' => Some("**"),
+        '\u{1F300}'..='\u{1FAFF}' => Some("[]"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use unicode_width::UnicodeWidthStr;
+
+    fn capabilities(unicode: bool, ambiguous_wide: bool, emoji: bool) -> TerminalCapabilities {
+        TerminalCapabilities {
+            truecolor: false,
+            unicode,
+            ambiguous_wide,
+            emoji,
+        }
+    }
+
+    #[test]
+    fn unicode_terminals_keep_text_unchanged() {
+        let text = "╭─ • Ran cargo test ✨";
+        assert!(matches!(
+            substitute(text, capabilities(true, false, true)),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn ascii_terminals_get_same_width_replacements() {
+        let text = "╭──╮ • done ✔ … └ 🔌 ✨";
+        let replaced = substitute(text, capabilities(false, false, false));
+
+        assert_eq!(replaced, "+--+ * done v . + [] **");
+        assert_eq!(replaced.width(), text.width());
+    }
+
+    #[test]
+    fn missing_emoji_only_replaces_emoji() {
+        assert_eq!(
+            substitute("• plugged 🔌", capabilities(true, false, false)),
+            "• plugged []"
+        );
+        assert_eq!(substitute("─ • ─", capabilities(true, true, true)), "- * -");
+    }
+}
@@ -21,6 +21,7 @@ use crate::exec_command::relativize_to_home;
 use crate::exec_command::strip_bash_lc_and_escape;
 use crate::live_wrap::take_prefix_by_width;
 use crate::markdown::append_markdown;
+use crate::markdown_table;
 use crate::render::line_utils::line_to_static;
 use crate::render::line_utils::prefix_lines;
 use crate::render::line_utils::push_owned_lines;
@@ -40,6 +41,7 @@ use base64::Engine;
 use codex_common::format_env_display::format_env_display;
 use codex_core::config::Config;
 use codex_core::config::types::McpServerTransportConfig;
+use codex_core::config::types::TranscriptDensity;
 use codex_core::protocol::FileChange;
 use codex_core::protocol::McpAuthStatus;
 use codex_core::protocol::McpInvocation;
@@ -48,6 +50,7 @@ use codex_core::web_search::web_search_detail;
 use codex_otel::RuntimeMetricsSummary;
 use codex_protocol::account::PlanType;
 use codex_protocol::config_types::Language;
+use codex_protocol::items::WebCitation;
 use codex_protocol::mcp::Resource;
 use codex_protocol::mcp::ResourceTemplate;
 use codex_protocol::models::WebSearchAction;
@@ -364,18 +367,51 @@ impl AgentMessageCell {
     }
 }
 
+impl AgentMessageCell {
+    /// Word-wrap prose to `width`. Table rows are never wrapped; they are
+    /// clipped when `clip_tables` is set and otherwise kept whole so the
+    /// transcript overlay can pan across them.
+    fn render_lines(&self, width: u16, clip_tables: bool) -> Vec<Line<'static>> {
+        let mut out = Vec::new();
+        for (idx, line) in self.lines.iter().enumerate() {
+            let indent: Span<'static> = if idx == 0 && self.is_first_line {
+                "• ".dim()
+            } else {
+                "  ".into()
+            };
+            if markdown_table::is_table_line(line) {
+                let mut spans = vec![indent];
+                spans.extend(line.spans.iter().cloned());
+                let mut row = Line::from(spans).style(line.style);
+                row.alignment = line.alignment;
+                if clip_tables {
+                    row = markdown_table::clip_table_line(row, width as usize);
+                }
+                out.push(row);
+            } else {
+                out.extend(word_wrap_lines(
+                    [line],
+                    RtOptions::new(width as usize)
+                        .initial_indent(indent.into())
+                        .subsequent_indent("  ".into()),
+                ));
+            }
+        }
+        out
+    }
+}
+
 impl HistoryCell for AgentMessageCell {
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
-        word_wrap_lines(
-            &self.lines,
-            RtOptions::new(width as usize)
-                .initial_indent(if self.is_first_line {
-                    "• ".dim().into()
-                } else {
-                    "  ".into()
-                })
-                .subsequent_indent("  ".into()),
-        )
+        self.render_lines(width, true)
+    }
+
+    fn transcript_lines(&self, width: u16) -> Vec<Line<'static>> {
+        self.render_lines(width, false)
+    }
+
+    fn desired_transcript_height(&self, width: u16) -> u16 {
+        self.transcript_lines(width).len() as u16
     }
 
     fn is_stream_continuation(&self) -> bool {
@@ -730,7 +766,7 @@ pub fn new_approval_decision_cell(
     use codex_core::protocol::ReviewDecision::*;
 
     let (symbol, summary): (Span<'static>, Vec<Span<'static>>) = match decision {
-        Approved => {
+        Approved | ApprovedHunks { .. } => {
             let snippet = Span::from(exec_snippet(&command)).dim();
             (
                 "✔ ".green(),
@@ -1416,6 +1452,7 @@ pub(crate) struct WebSearchCell {
     call_id: String,
     query: String,
     action: Option<WebSearchAction>,
+    citations: Vec<WebCitation>,
     start_time: Instant,
     completed: bool,
     animations_enabled: bool,
@@ -1432,6 +1469,7 @@ impl WebSearchCell {
             call_id,
             query,
             action,
+            citations: Vec::new(),
             start_time: Instant::now(),
             completed: false,
             animations_enabled,
@@ -1442,9 +1480,15 @@ impl WebSearchCell {
         &self.call_id
     }
 
-    pub(crate) fn update(&mut self, action: WebSearchAction, query: String) {
+    pub(crate) fn update(
+        &mut self,
+        action: WebSearchAction,
+        query: String,
+        citations: Vec<WebCitation>,
+    ) {
         self.action = Some(action);
         self.query = query;
+        self.citations = citations;
     }
 
     pub(crate) fn complete(&mut self) {
@@ -1466,7 +1510,31 @@ impl HistoryCell for WebSearchCell {
         } else {
             Line::from(vec![header.bold(), " ".into(), detail.into()]).into()
         };
-        PrefixedWrappedHistoryCell::new(text, vec![bullet, " ".into()], "  ").display_lines(width)
+        let mut lines = PrefixedWrappedHistoryCell::new(text, vec![bullet, " ".into()], "  ")
+            .display_lines(width);
+        if self.citations.is_empty() {
+            return lines;
+        }
+        let citation_lines: Vec<Line<'static>> = self
+            .citations
+            .iter()
+            .map(|citation| match &citation.title {
+                Some(title) => Line::from(vec![
+                    title.clone().into(),
+                    " ".into(),
+                    citation.url.clone().cyan().underlined(),
+                ]),
+                None => Line::from(citation.url.clone().cyan().underlined()),
+            })
+            .collect();
+        let wrapped = word_wrap_lines(
+            citation_lines,
+            RtOptions::new(width.max(1) as usize)
+                .initial_indent(Line::from("  └ ".dim()))
+                .subsequent_indent(Line::from("    ")),
+        );
+        push_owned_lines(&wrapped, &mut lines);
+        lines
     }
 }
 
@@ -1482,12 +1550,76 @@ pub(crate) fn new_web_search_call(
     call_id: String,
     query: String,
     action: WebSearchAction,
+    citations: Vec<WebCitation>,
 ) -> WebSearchCell {
     let mut cell = WebSearchCell::new(call_id, query, Some(action), false);
+    cell.citations = citations;
     cell.complete();
     cell
 }
 
+/// Sources listed inline under a turn; the transcript overlay shows them all.
+const SOURCES_INLINE_LIMIT: usize = 3;
+
+/// Footer after a turn that used web search, listing every page it cited.
+#[derive(Debug)]
+pub(crate) struct SourcesCell {
+    citations: Vec<WebCitation>,
+}
+
+impl SourcesCell {
+    fn render(&self, width: u16, limit: usize) -> Vec<Line<'static>> {
+        let mut lines: Vec<Line<'static>> = vec![Line::from(vec![
+            "• ".dim(),
+            "Sources".bold(),
+            format!(" ({})", self.citations.len()).dim(),
+        ])];
+        let mut entries: Vec<Line<'static>> = self
+            .citations
+            .iter()
+            .take(limit)
+            .enumerate()
+            .map(|(index, citation)| {
+                let mut spans = vec![format!("{}. ", index + 1).dim()];
+                if let Some(title) = &citation.title {
+                    spans.push(title.clone().into());
+                    spans.push(" ".into());
+                }
+                spans.push(citation.url.clone().cyan().underlined());
+                Line::from(spans)
+            })
+            .collect();
+        let hidden = self.citations.len().saturating_sub(limit);
+        if hidden > 0 {
+            entries.push(Line::from(
+                format!("… +{hidden} more (ctrl + t to view all)").dim(),
+            ));
+        }
+        let wrapped = word_wrap_lines(
+            entries,
+            RtOptions::new(width.max(1) as usize)
+                .initial_indent(Line::from("  └ ".dim()))
+                .subsequent_indent(Line::from("    ")),
+        );
+        push_owned_lines(&wrapped, &mut lines);
+        lines
+    }
+}
+
+impl HistoryCell for SourcesCell {
+    fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
+        self.render(width, SOURCES_INLINE_LIMIT)
+    }
+
+    fn transcript_lines(&self, width: u16) -> Vec<Line<'static>> {
+        self.render(width, usize::MAX)
+    }
+}
+
+pub(crate) fn new_sources_cell(citations: Vec<WebCitation>) -> SourcesCell {
+    SourcesCell { citations }
+}
+
 /// Returns an additional history cell if an MCP tool result includes a decodable image.
 ///
 /// This intentionally returns at most one cell: the first image in `CallToolResult.content` that
@@ -1783,6 +1915,14 @@ pub(crate) fn new_info_event(message: String, hint: Option<String>) -> PlainHist
     PlainHistoryCell { lines }
 }
 
+/// Unstyled lines appended by accessible mode so screen readers announce them
+/// once, instead of following in-place redraws of the bottom pane.
+pub(crate) fn new_accessible_announcement(lines: Vec<String>) -> PlainHistoryCell {
+    PlainHistoryCell {
+        lines: lines.into_iter().map(Line::from).collect(),
+    }
+}
+
 pub(crate) fn new_error_event(message: String) -> PlainHistoryCell {
     // Use a hair space (U+200A) to create a subtle, near-invisible separation
     // before the text. VS16 is intentionally omitted to keep spacing tighter
@@ -2082,8 +2222,10 @@ pub(crate) fn new_patch_apply_failure(stderr: String) -> PlainHistoryCell {
         let output = output_lines(
             Some(&CommandOutput {
                 exit_code: 1,
+                timed_out: false,
                 formatted_output: String::new(),
                 aggregated_output: stderr,
+                provenance: None,
             }),
             OutputLinesParams {
                 line_limit: TOOL_CALL_MAX_LINES,
@@ -2110,6 +2252,25 @@ pub(crate) fn new_view_image_tool_call(path: PathBuf, cwd: &Path) -> PlainHistor
     PlainHistoryCell { lines }
 }
 
+/// Reasoning cell for the chosen transcript density: compact keeps reasoning
+/// in the transcript overlay only, verbose shows all of it including the
+/// summary headers.
+pub(crate) fn new_reasoning_block(
+    full_reasoning_buffer: String,
+    density: TranscriptDensity,
+) -> Box<dyn HistoryCell> {
+    let transcript_only = match density {
+        TranscriptDensity::Normal => return new_reasoning_summary_block(full_reasoning_buffer),
+        TranscriptDensity::Compact => true,
+        TranscriptDensity::Verbose => false,
+    };
+    Box::new(ReasoningSummaryCell::new(
+        String::new(),
+        full_reasoning_buffer.trim().to_string(),
+        transcript_only,
+    ))
+}
+
 pub(crate) fn new_reasoning_summary_block(full_reasoning_buffer: String) -> Box<dyn HistoryCell> {
     let full_reasoning_buffer = full_reasoning_buffer.trim();
     if let Some(open) = full_reasoning_buffer.find("**") {
@@ -2619,6 +2780,25 @@ mod tests {
         insta::assert_snapshot!(rendered);
     }
 
+    #[test]
+    fn agent_message_clips_wide_table_rows_but_transcript_keeps_them() {
+        let mut lines = Vec::new();
+        append_markdown(
+            "| name | description |\n|---|---|\n| a | a long description here |\n",
+            None,
+            &mut lines,
+        );
+        let cell = AgentMessageCell::new(lines, true);
+
+        let display = render_lines(&cell.display_lines(20));
+        assert_eq!(display[0], "• name │ description");
+        assert_eq!(display[2], "  a    │ a long des…");
+        assert_eq!(display.len(), 3);
+
+        let transcript = render_lines(&cell.transcript_lines(20));
+        assert_eq!(transcript[2], "  a    │ a long description here");
+    }
+
     #[test]
     fn empty_agent_message_cell_transcript() {
         let cell = AgentMessageCell::new(vec![Line::default()], false);
@@ -2660,6 +2840,7 @@ mod tests {
                 query: Some(query),
                 queries: None,
             },
+            Vec::new(),
         );
         let rendered = render_lines(&cell.display_lines(64)).join("\n");
 
@@ -2677,6 +2858,7 @@ mod tests {
                 query: Some(query),
                 queries: None,
             },
+            Vec::new(),
         );
         let rendered = render_lines(&cell.display_lines(64));
 
@@ -2699,12 +2881,83 @@ mod tests {
                 query: Some(query),
                 queries: None,
             },
+            Vec::new(),
         );
         let rendered = render_lines(&cell.display_lines(64));
 
         assert_eq!(rendered, vec!["• Searched short query".to_string()]);
     }
 
+    #[test]
+    fn web_search_history_cell_lists_citations() {
+        let cell = new_web_search_call(
+            "call-1".to_string(),
+            "https://docs.rs/tokio".to_string(),
+            WebSearchAction::OpenPage {
+                url: Some("https://docs.rs/tokio".to_string()),
+            },
+            vec![
+                WebCitation {
+                    url: "https://docs.rs/tokio".to_string(),
+                    title: Some("tokio - Rust".to_string()),
+                    snippet: Some(
+                        "A runtime for writing reliable network applications".to_string(),
+                    ),
+                },
+                WebCitation {
+                    url: "https://tokio.rs".to_string(),
+                    title: None,
+                    snippet: None,
+                },
+            ],
+        );
+        let rendered = render_lines(&cell.display_lines(64));
+
+        assert_eq!(
+            rendered,
+            vec![
+                "• Searched https://docs.rs/tokio".to_string(),
+                "  └ tokio - Rust https://docs.rs/tokio".to_string(),
+                "    https://tokio.rs".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn sources_cell_collapses_inline_and_lists_all_in_transcript() {
+        let cell = new_sources_cell(
+            (1..=5)
+                .map(|index| WebCitation {
+                    url: format!("https://example.com/{index}"),
+                    title: (index == 1).then(|| "Example".to_string()),
+                    snippet: None,
+                })
+                .collect(),
+        );
+
+        assert_eq!(
+            render_lines(&cell.display_lines(64)),
+            vec![
+                "• Sources (5)".to_string(),
+                "  └ 1. Example https://example.com/1".to_string(),
+                "    2. https://example.com/2".to_string(),
+                "    3. https://example.com/3".to_string(),
+                "    … +2 more (ctrl + t to view all)".to_string(),
+            ]
+        );
+        assert_eq!(
+            render_lines(&cell.transcript_lines(64)),
+            vec![
+                "• Sources (5)".to_string(),
+                "  └ 1. Example https://example.com/1".to_string(),
+                "    2. https://example.com/2".to_string(),
+                "    3. https://example.com/3".to_string(),
+                "    4. https://example.com/4".to_string(),
+                "    5. https://example.com/5".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn web_search_history_cell_transcript_snapshot() {
         let query =
@@ -2716,6 +2969,7 @@ mod tests {
                 query: Some(query),
                 queries: None,
             },
+            Vec::new(),
         );
         let rendered = render_lines(&cell.transcript_lines(64)).join("\n");
 
@@ -3299,8 +3553,10 @@ mod tests {
             &call_id,
             CommandOutput {
                 exit_code: 1,
+                timed_out: false,
                 formatted_output: String::new(),
                 aggregated_output: stderr,
+                provenance: None,
             },
             Duration::from_millis(1),
         );
@@ -3347,8 +3603,10 @@ mod tests {
             &call_id,
             CommandOutput {
                 exit_code: 1,
+                timed_out: false,
                 formatted_output: String::new(),
                 aggregated_output: stderr,
+                provenance: None,
             },
             Duration::from_millis(5),
         );
@@ -3509,6 +3767,26 @@ mod tests {
         assert_eq!(rendered_transcript, vec!["• We should fix the bug next."]);
     }
 
+    #[test]
+    fn reasoning_block_follows_density() {
+        let reasoning = "**High level plan**\n\nWe should fix the bug next.".to_string();
+
+        let compact = new_reasoning_block(reasoning.clone(), TranscriptDensity::Compact);
+        assert!(compact.display_lines(80).is_empty());
+        assert!(!render_transcript(compact.as_ref()).is_empty());
+
+        let verbose = new_reasoning_block(reasoning, TranscriptDensity::Verbose);
+        let rendered = render_lines(&verbose.display_lines(80));
+        assert_eq!(
+            rendered.first().map(String::as_str),
+            Some("• High level plan")
+        );
+        assert_eq!(
+            rendered.last().map(String::as_str),
+            Some("  We should fix the bug next.")
+        );
+    }
+
     #[test]
     fn deprecation_notice_renders_summary_with_details() {
         let cell = new_deprecation_notice(
@@ -2,6 +2,9 @@ use std::fmt;
 use std::io;
 use std::io::Write;
 
+use crate::color::terminal_color;
+use crate::glyphs::terminal_text;
+use crate::terminal_graphics::InlineImage;
 use crate::wrapping::word_wrap_lines_borrowed;
 use crossterm::Command;
 use crossterm::cursor::MoveTo;
@@ -99,10 +102,12 @@ where
             SetColors(Colors::new(
                 line.style
                     .fg
+                    .map(terminal_color)
                     .map(std::convert::Into::into)
                     .unwrap_or(CColor::Reset),
                 line.style
                     .bg
+                    .map(terminal_color)
                     .map(std::convert::Into::into)
                     .unwrap_or(CColor::Reset)
             ))
@@ -134,6 +139,33 @@ where
     Ok(())
 }
 
+/// Draw `image` over the blank rows reserved for it by the preceding
+/// [`insert_history_lines`] call. `lines_from_image` are the inserted lines
+/// starting at the first reserved row, which locates the image relative to
+/// the top of the viewport.
+pub(crate) fn draw_history_image<B>(
+    terminal: &mut crate::custom_terminal::Terminal<B>,
+    lines_from_image: &[Line],
+    image: &InlineImage,
+) -> io::Result<()>
+where
+    B: Backend + Write,
+{
+    let area = terminal.viewport_area;
+    let rows_below_top =
+        word_wrap_lines_borrowed(lines_from_image, area.width.max(1) as usize).len() as u16;
+    // Already scrolled off the screen; leave the blank rows.
+    if rows_below_top > area.top() {
+        return Ok(());
+    }
+    let last_cursor_pos = terminal.last_known_cursor_pos;
+    let writer = terminal.backend_mut();
+    queue!(writer, MoveTo(0, area.top() - rows_below_top))?;
+    queue!(writer, Print(image.escape_sequence()))?;
+    queue!(writer, MoveTo(last_cursor_pos.x, last_cursor_pos.y))?;
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetScrollRegion(pub std::ops::Range<u16>);
 
@@ -260,8 +292,8 @@ where
             diff.queue(&mut writer)?;
             last_modifier = modifier;
         }
-        let next_fg = span.style.fg.unwrap_or(Color::Reset);
-        let next_bg = span.style.bg.unwrap_or(Color::Reset);
+        let next_fg = terminal_color(span.style.fg.unwrap_or(Color::Reset));
+        let next_bg = terminal_color(span.style.bg.unwrap_or(Color::Reset));
         if next_fg != fg || next_bg != bg {
             queue!(
                 writer,
@@ -271,7 +303,7 @@ where
             bg = next_bg;
         }
 
-        queue!(writer, Print(span.content.clone()))?;
+        queue!(writer, Print(terminal_text(&span.content)))?;
     }
 
     queue!(
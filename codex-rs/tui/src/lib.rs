@@ -59,15 +59,21 @@ mod app_event;
 mod app_event_sender;
 mod ascii_animation;
 mod bottom_pane;
+mod branches;
+mod changed_symbols;
 mod chatwidget;
 mod cli;
 mod clipboard_paste;
 mod collab;
 mod collaboration_modes;
 mod color;
+mod compare;
+mod config_reload;
 pub mod custom_terminal;
 mod cwd_prompt;
+mod danger_prompt;
 mod debug_config;
+mod diagram;
 mod diff_render;
 mod exec_cell;
 mod exec_command;
@@ -75,6 +81,7 @@ mod external_editor;
 mod file_search;
 mod frames;
 mod get_git_diff;
+mod glyphs;
 mod history_cell;
 mod i18n;
 pub mod insert_history;
@@ -83,17 +90,22 @@ pub mod live_wrap;
 mod markdown;
 mod markdown_render;
 mod markdown_stream;
+mod markdown_table;
 mod mention_codec;
 mod model_migration;
+mod multiplexer_panes;
 mod notifications;
 pub mod onboarding;
 mod oss_selection;
 mod pager_overlay;
+mod plain;
 pub mod public_widgets;
 mod render;
+mod render_stats;
 mod resume_picker;
 mod selection_list;
 mod session_log;
+mod session_status;
 mod shimmer;
 mod skills_helpers;
 mod slash_command;
@@ -101,9 +113,11 @@ mod status;
 mod status_indicator_widget;
 mod streaming;
 mod style;
+mod terminal_graphics;
 mod terminal_palette;
 mod text_formatting;
 mod tooltips;
+mod transcript_links;
 mod tui;
 mod ui_consts;
 pub mod update_action;
@@ -153,6 +167,12 @@ pub async fn run_main(
             .push("web_search=\"live\"".to_string());
     }
 
+    if cli.accessible {
+        cli.config_overrides
+            .raw_overrides
+            .push("tui.accessible=true".to_string());
+    }
+
     // When using `--oss`, let the bootstrapper pick the model (defaulting to
     // gpt-oss:20b) and ensure it is present locally. Also, force the built‑in
     let raw_overrides = cli.config_overrides.raw_overrides.clone();
@@ -276,6 +296,9 @@ pub async fn run_main(
         config_profile: cli.config_profile.clone(),
         codex_linux_sandbox_exe,
         show_raw_agent_reasoning: cli.oss.then_some(true),
+        explore_mode: cli.read_only.then_some(true),
+        offline: cli.offline.then_some(true),
+        force_takeover: cli.resume_force_takeover.then_some(true),
         additional_writable_roots: additional_dirs,
         ..Default::default()
     };
@@ -401,6 +424,14 @@ pub async fn run_main(
         .with(otel_tracing_layer)
         .try_init();
 
+    for issue in codex_core::i18n::install_locale_overrides(&config.codex_home) {
+        tracing::warn!("ignoring locale override: {issue}");
+    }
+
+    if cli.plain {
+        return plain::run_plain_app(cli, config).await;
+    }
+
     run_ratatui_app(
         cli,
         config,
@@ -454,6 +485,7 @@ async fn run_ratatui_app(
                         thread_id: None,
                         thread_name: None,
                         update_action: Some(action),
+                        exit_summary: Vec::new(),
                         exit_reason: ExitReason::UserRequested,
                     });
                 }
@@ -461,6 +493,20 @@ async fn run_ratatui_app(
         }
     }
 
+    if let danger_prompt::DangerPromptOutcome::Quit =
+        danger_prompt::run_danger_prompt_if_needed(&mut tui, &initial_config).await?
+    {
+        crate::tui::restore()?;
+        return Ok(AppExitInfo {
+            token_usage: codex_core::protocol::TokenUsage::default(),
+            thread_id: None,
+            thread_name: None,
+            update_action: None,
+            exit_summary: Vec::new(),
+            exit_reason: ExitReason::UserRequested,
+        });
+    }
+
     // Initialize high-fidelity session event logging if enabled.
     session_log::maybe_init(&initial_config);
 
@@ -482,6 +528,7 @@ async fn run_ratatui_app(
                 show_trust_screen: should_show_trust_screen_flag,
                 login_status,
                 auth_manager: auth_manager.clone(),
+                open_browser: !cli.no_browser,
                 config: initial_config.clone(),
             },
             &mut tui,
@@ -496,6 +543,7 @@ async fn run_ratatui_app(
                 thread_id: None,
                 thread_name: None,
                 update_action: None,
+                exit_summary: Vec::new(),
                 exit_reason: ExitReason::UserRequested,
             });
         }
@@ -534,6 +582,7 @@ async fn run_ratatui_app(
             thread_id: None,
             thread_name: None,
             update_action: None,
+            exit_summary: Vec::new(),
             exit_reason: ExitReason::Fatal(format!(
                 "No saved session found with ID {id_str}. Run `codex {action}` without an ID to choose from existing sessions."
             )),
@@ -583,6 +632,7 @@ async fn run_ratatui_app(
                         thread_id: None,
                         thread_name: None,
                         update_action: None,
+                        exit_summary: Vec::new(),
                         exit_reason: ExitReason::UserRequested,
                     });
                 }
@@ -625,7 +675,8 @@ async fn run_ratatui_app(
             _ => resume_picker::SessionSelection::StartFresh,
         }
     } else if cli.resume_picker {
-        match resume_picker::run_resume_picker(&mut tui, &config, cli.resume_show_all).await? {
+        match resume_picker::run_resume_picker(&mut tui, &config, cli.resume_show_all, None).await?
+        {
             resume_picker::SessionSelection::Exit => {
                 restore();
                 session_log::log_session_end();
@@ -634,6 +685,7 @@ async fn run_ratatui_app(
                     thread_id: None,
                     thread_name: None,
                     update_action: None,
+                    exit_summary: Vec::new(),
                     exit_reason: ExitReason::UserRequested,
                 });
             }
@@ -681,7 +733,11 @@ async fn run_ratatui_app(
         ..
     } = cli;
 
-    let use_alt_screen = determine_alt_screen_mode(no_alt_screen, config.tui_alternate_screen);
+    // Screen readers follow the normal scrollback, so accessible mode stays inline.
+    let use_alt_screen = determine_alt_screen_mode(
+        no_alt_screen || config.tui_accessible,
+        config.tui_alternate_screen,
+    );
     tui.set_alt_screen_enabled(use_alt_screen);
 
     let app_result = App::run(
@@ -944,7 +1000,11 @@ mod tests {
         let temp_dir = TempDir::new()?;
         let mut config = build_config(&temp_dir).await?;
         config.did_user_set_custom_approval_policy_or_sandbox_mode = false;
-        config.active_project = ProjectConfig { trust_level: None };
+        config.active_project = ProjectConfig {
+            trust_level: None,
+            persona: None,
+            profile: None,
+        };
         config.set_windows_sandbox_enabled(false);
 
         let should_show = should_show_trust_screen(&config);
@@ -967,7 +1027,11 @@ mod tests {
         let temp_dir = TempDir::new()?;
         let mut config = build_config(&temp_dir).await?;
         config.did_user_set_custom_approval_policy_or_sandbox_mode = false;
-        config.active_project = ProjectConfig { trust_level: None };
+        config.active_project = ProjectConfig {
+            trust_level: None,
+            persona: None,
+            profile: None,
+        };
         config.set_windows_sandbox_enabled(true);
 
         let should_show = should_show_trust_screen(&config);
@@ -992,6 +1056,8 @@ mod tests {
         config.did_user_set_custom_approval_policy_or_sandbox_mode = false;
         config.active_project = ProjectConfig {
             trust_level: Some(TrustLevel::Untrusted),
+            persona: None,
+            profile: None,
         };
 
         let should_show = should_show_trust_screen(&config);
@@ -1,3 +1,4 @@
+use crate::markdown_table::TableBuilder;
 use crate::render::line_utils::line_to_static;
 use crate::wrapping::RtOptions;
 use crate::wrapping::word_wrap_line;
@@ -78,6 +79,7 @@ pub fn render_markdown_text(input: &str) -> Text<'static> {
 pub(crate) fn render_markdown_text_with_width(input: &str, width: Option<usize>) -> Text<'static> {
     let mut options = Options::empty();
     options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TABLES);
     let parser = Parser::new_ext(input, options);
     let mut w = Writer::new(parser, width);
     w.run();
@@ -99,12 +101,14 @@ where
     pending_marker_line: bool,
     in_paragraph: bool,
     in_code_block: bool,
+    table: Option<TableBuilder>,
     wrap_width: Option<usize>,
     current_line_content: Option<Line<'static>>,
     current_initial_indent: Vec<Span<'static>>,
     current_subsequent_indent: Vec<Span<'static>>,
     current_line_style: Style,
     current_line_in_code_block: bool,
+    current_line_in_table: bool,
 }
 
 impl<'a, I> Writer<'a, I>
@@ -124,12 +128,14 @@ where
             pending_marker_line: false,
             in_paragraph: false,
             in_code_block: false,
+            table: None,
             wrap_width,
             current_line_content: None,
             current_initial_indent: Vec::new(),
             current_subsequent_indent: Vec::new(),
             current_line_style: Style::default(),
             current_line_in_code_block: false,
+            current_line_in_table: false,
         }
     }
 
@@ -185,9 +191,9 @@ where
             Tag::Strong => self.push_inline_style(self.styles.strong),
             Tag::Strikethrough => self.push_inline_style(self.styles.strikethrough),
             Tag::Link { dest_url, .. } => self.push_link(dest_url.to_string()),
+            Tag::Table(alignments) => self.start_table(alignments),
             Tag::HtmlBlock
             | Tag::FootnoteDefinition(_)
-            | Tag::Table(_)
             | Tag::TableHead
             | Tag::TableRow
             | Tag::TableCell
@@ -209,12 +215,24 @@ where
             }
             TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => self.pop_inline_style(),
             TagEnd::Link => self.pop_link(),
+            TagEnd::Table => self.end_table(),
+            TagEnd::TableHead => {
+                if let Some(table) = self.table.as_mut() {
+                    table.end_head();
+                }
+            }
+            TagEnd::TableRow => {
+                if let Some(table) = self.table.as_mut() {
+                    table.end_row();
+                }
+            }
+            TagEnd::TableCell => {
+                if let Some(table) = self.table.as_mut() {
+                    table.end_cell();
+                }
+            }
             TagEnd::HtmlBlock
             | TagEnd::FootnoteDefinition
-            | TagEnd::Table
-            | TagEnd::TableHead
-            | TagEnd::TableRow
-            | TagEnd::TableCell
             | TagEnd::Image
             | TagEnd::MetadataBlock(_) => {}
         }
@@ -274,6 +292,11 @@ where
     }
 
     fn text(&mut self, text: CowStr<'a>) {
+        if self.table.is_some() {
+            let style = self.inline_styles.last().copied().unwrap_or_default();
+            self.push_span(Span::styled(text.into_string(), style));
+            return;
+        }
         if self.pending_marker_line {
             self.push_line(Line::default());
         }
@@ -313,7 +336,7 @@ where
     }
 
     fn code(&mut self, code: CowStr<'a>) {
-        if self.pending_marker_line {
+        if self.pending_marker_line && self.table.is_none() {
             self.push_line(Line::default());
             self.pending_marker_line = false;
         }
@@ -322,6 +345,11 @@ where
     }
 
     fn html(&mut self, html: CowStr<'a>, inline: bool) {
+        if self.table.is_some() {
+            let style = self.inline_styles.last().copied().unwrap_or_default();
+            self.push_span(Span::styled(html.into_string(), style));
+            return;
+        }
         self.pending_marker_line = false;
         for (i, line) in html.lines().enumerate() {
             if self.needs_newline {
@@ -338,6 +366,10 @@ where
     }
 
     fn hard_break(&mut self) {
+        if self.table.is_some() {
+            self.push_span(" ".into());
+            return;
+        }
         self.push_line(Line::default());
     }
 
@@ -414,6 +446,34 @@ where
         self.indent_stack.pop();
     }
 
+    fn start_table(&mut self, alignments: Vec<pulldown_cmark::Alignment>) {
+        self.flush_current_line();
+        if !self.text.lines.is_empty() {
+            self.push_blank_line();
+        }
+        self.pending_marker_line = false;
+        self.table = Some(TableBuilder::new(alignments));
+    }
+
+    fn end_table(&mut self) {
+        let Some(table) = self.table.take() else {
+            return;
+        };
+        let indent_width = self
+            .prefix_spans(false)
+            .iter()
+            .map(Span::width)
+            .sum::<usize>();
+        let max_width = self
+            .wrap_width
+            .map(|width| width.saturating_sub(indent_width));
+        for line in table.into_lines(max_width, self.styles.strong) {
+            self.push_line(line);
+            self.current_line_in_table = true;
+        }
+        self.needs_newline = true;
+    }
+
     fn push_inline_style(&mut self, style: Style) {
         let current = self.inline_styles.last().copied().unwrap_or_default();
         let merged = current.patch(style);
@@ -440,7 +500,9 @@ where
         if let Some(line) = self.current_line_content.take() {
             let style = self.current_line_style;
             // NB we don't wrap code in code blocks, in order to preserve whitespace for copy/paste.
+            // Table rows are already laid out to the width.
             if !self.current_line_in_code_block
+                && !self.current_line_in_table
                 && let Some(width) = self.wrap_width
             {
                 let opts = RtOptions::new(width)
@@ -454,11 +516,14 @@ where
                 let mut spans = self.current_initial_indent.clone();
                 let mut line = line;
                 spans.append(&mut line.spans);
-                self.text.lines.push(Line::from_iter(spans).style(style));
+                let mut owned = Line::from_iter(spans).style(style);
+                owned.alignment = line.alignment;
+                self.text.lines.push(owned);
             }
             self.current_initial_indent.clear();
             self.current_subsequent_indent.clear();
             self.current_line_in_code_block = false;
+            self.current_line_in_table = false;
         }
     }
 
@@ -485,6 +550,10 @@ where
     }
 
     fn push_span(&mut self, span: Span<'static>) {
+        if let Some(table) = self.table.as_mut() {
+            table.push_span(span);
+            return;
+        }
         if let Some(line) = self.current_line_content.as_mut() {
             line.push_span(span);
         } else {
@@ -665,6 +734,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn renders_tables_as_aligned_columns() {
+        let markdown = "| a | b |\n|---|---|\n| one | two |\n\nafter";
+        let rendered = render_markdown_text_with_width(markdown, Some(40));
+        let lines = lines_to_strings(&rendered);
+        assert_eq!(
+            lines,
+            vec![
+                "a   │ b".to_string(),
+                "────┼────".to_string(),
+                "one │ two".to_string(),
+                String::new(),
+                "after".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn does_not_wrap_code_blocks() {
         let markdown = "````\nfn main() { println!(\"hi from a long line\"); }\n````";
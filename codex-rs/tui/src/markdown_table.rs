@@ -0,0 +1,324 @@
+//! Layout for GFM tables in rendered markdown.
+//!
+//! Tables are drawn as aligned columns separated by `│`, with a rule under
+//! the header row. When the rows are wider than the wrap width, the widest
+//! columns give up space first and clipped cells end in `…`. A table that
+//! still does not fit keeps its width: history cells clip it to the screen,
+//! and the transcript overlay lets the user pan across it.
+
+use pulldown_cmark::Alignment;
+use ratatui::layout::Alignment as LineAlignment;
+use ratatui::style::Style;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::text::Span;
+use unicode_width::UnicodeWidthChar;
+use unicode_width::UnicodeWidthStr;
+
+const COLUMN_SEPARATOR: &str = " │ ";
+const RULE_SEPARATOR: &str = "─┼─";
+const ELLIPSIS: &str = "…";
+/// Columns are not narrowed below this many cells (or their content width, if
+/// smaller) when fitting a table into the wrap width.
+const MIN_COLUMN_WIDTH: usize = 6;
+
+type Cell = Vec<Span<'static>>;
+
+/// Collects the cells of one table while the markdown writer walks it.
+#[derive(Debug, Default)]
+pub(crate) struct TableBuilder {
+    alignments: Vec<Alignment>,
+    header: Vec<Cell>,
+    rows: Vec<Vec<Cell>>,
+    row: Vec<Cell>,
+    cell: Cell,
+}
+
+impl TableBuilder {
+    pub(crate) fn new(alignments: Vec<Alignment>) -> Self {
+        Self {
+            alignments,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn push_span(&mut self, span: Span<'static>) {
+        self.cell.push(span);
+    }
+
+    pub(crate) fn end_cell(&mut self) {
+        self.row.push(std::mem::take(&mut self.cell));
+    }
+
+    pub(crate) fn end_head(&mut self) {
+        self.header = std::mem::take(&mut self.row);
+    }
+
+    pub(crate) fn end_row(&mut self) {
+        self.rows.push(std::mem::take(&mut self.row));
+    }
+
+    /// Lay the table out within `max_width` columns (unbounded when `None`).
+    /// The returned lines are tagged with [`is_table_line`].
+    pub(crate) fn into_lines(
+        self,
+        max_width: Option<usize>,
+        header_style: Style,
+    ) -> Vec<Line<'static>> {
+        let columns = std::iter::once(&self.header)
+            .chain(&self.rows)
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+            .max(self.alignments.len());
+        if columns == 0 {
+            return Vec::new();
+        }
+
+        let mut natural = vec![1; columns];
+        for row in std::iter::once(&self.header).chain(&self.rows) {
+            for (column, cell) in row.iter().enumerate() {
+                natural[column] = natural[column].max(spans_width(cell));
+            }
+        }
+        let widths = match max_width {
+            Some(max_width) => fit_column_widths(
+                &natural,
+                max_width.saturating_sub(COLUMN_SEPARATOR.width() * (columns - 1)),
+            ),
+            None => natural,
+        };
+
+        let mut lines = Vec::with_capacity(self.rows.len() + 2);
+        let header: Vec<Cell> = self
+            .header
+            .into_iter()
+            .map(|cell| {
+                cell.into_iter()
+                    .map(|span| {
+                        let style = header_style.patch(span.style);
+                        span.style(style)
+                    })
+                    .collect()
+            })
+            .collect();
+        lines.push(row_line(header, &widths, &self.alignments));
+        let rule = widths
+            .iter()
+            .map(|width| "─".repeat(*width))
+            .collect::<Vec<_>>()
+            .join(RULE_SEPARATOR);
+        lines.push(Line::from(rule.dim()).alignment(LineAlignment::Left));
+        for row in self.rows {
+            lines.push(row_line(row, &widths, &self.alignments));
+        }
+        lines
+    }
+}
+
+/// Whether `line` is a table row produced by [`TableBuilder::into_lines`].
+///
+/// Table rows carry an explicit left alignment, which renders like the
+/// default but lets history cells clip them instead of word-wrapping.
+pub(crate) fn is_table_line(line: &Line<'_>) -> bool {
+    line.alignment == Some(LineAlignment::Left)
+}
+
+/// Clip a table row to `width` columns, ending it in `…` when anything was
+/// cut off.
+pub(crate) fn clip_table_line(line: Line<'static>, width: usize) -> Line<'static> {
+    if line.width() <= width {
+        return line;
+    }
+    let Line {
+        style,
+        alignment,
+        spans,
+    } = line;
+    let mut spans = truncate_spans(spans, width.saturating_sub(1));
+    if width > 0 {
+        spans.push(Span::from(ELLIPSIS).dim());
+    }
+    Line {
+        style,
+        alignment,
+        spans,
+    }
+}
+
+/// Narrow the widest columns one cell at a time until the table fits in
+/// `budget`, never going below [`MIN_COLUMN_WIDTH`].
+fn fit_column_widths(natural: &[usize], budget: usize) -> Vec<usize> {
+    let mut widths = natural.to_vec();
+    let mut total: usize = widths.iter().sum();
+    while total > budget {
+        let Some((column, _)) = widths
+            .iter()
+            .enumerate()
+            .filter(|(column, width)| **width > MIN_COLUMN_WIDTH.min(natural[*column]))
+            .max_by_key(|(column, width)| (**width, std::cmp::Reverse(*column)))
+        else {
+            break;
+        };
+        widths[column] -= 1;
+        total -= 1;
+    }
+    widths
+}
+
+fn row_line(row: Vec<Cell>, widths: &[usize], alignments: &[Alignment]) -> Line<'static> {
+    let mut row = row.into_iter();
+    let mut spans = Vec::new();
+    for (column, width) in widths.iter().enumerate() {
+        if column > 0 {
+            spans.push(Span::from(COLUMN_SEPARATOR).dim());
+        }
+        let cell = row.next().unwrap_or_default();
+        let cell = if spans_width(&cell) > *width {
+            let mut cell = truncate_spans(cell, width.saturating_sub(1));
+            cell.push(Span::from(ELLIPSIS));
+            cell
+        } else {
+            cell
+        };
+        let padding = width.saturating_sub(spans_width(&cell));
+        let (left, right) = match alignments.get(column) {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Left) | Some(Alignment::None) | None => (0, padding),
+        };
+        if left > 0 {
+            spans.push(Span::from(" ".repeat(left)));
+        }
+        spans.extend(cell);
+        // Trailing padding on the last column would only add invisible width.
+        if right > 0 && column + 1 < widths.len() {
+            spans.push(Span::from(" ".repeat(right)));
+        }
+    }
+    Line::from(spans).alignment(LineAlignment::Left)
+}
+
+fn spans_width(spans: &[Span<'_>]) -> usize {
+    spans.iter().map(|span| span.content.width()).sum()
+}
+
+fn truncate_spans(spans: Vec<Span<'static>>, max_width: usize) -> Vec<Span<'static>> {
+    let mut used = 0;
+    let mut out = Vec::new();
+    for span in spans {
+        let span_width = span.content.width();
+        if used + span_width <= max_width {
+            used += span_width;
+            out.push(span);
+            continue;
+        }
+        let mut truncated = String::new();
+        for ch in span.content.chars() {
+            let ch_width = ch.width().unwrap_or(0);
+            if used + ch_width > max_width {
+                break;
+            }
+            truncated.push(ch);
+            used += ch_width;
+        }
+        if !truncated.is_empty() {
+            out.push(Span::styled(truncated, span.style));
+        }
+        break;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn plain(lines: &[Line<'_>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    fn table(alignments: Vec<Alignment>, header: &[&str], rows: &[&[&str]]) -> TableBuilder {
+        let mut builder = TableBuilder::new(alignments);
+        for cell in header {
+            builder.push_span(Span::from(cell.to_string()));
+            builder.end_cell();
+        }
+        builder.end_head();
+        for row in rows {
+            for cell in *row {
+                builder.push_span(Span::from(cell.to_string()));
+                builder.end_cell();
+            }
+            builder.end_row();
+        }
+        builder
+    }
+
+    #[test]
+    fn aligns_columns_to_their_widest_cell() {
+        let builder = table(
+            vec![Alignment::None, Alignment::Right, Alignment::Center],
+            &["Name", "Size", "Kind"],
+            &[&["main.rs", "12", "file"], &["src", "4096", "dir"]],
+        );
+        let lines = builder.into_lines(None, Style::default());
+        assert_eq!(
+            plain(&lines),
+            vec![
+                "Name    │ Size │ Kind",
+                "────────┼──────┼─────",
+                "main.rs │   12 │ file",
+                "src     │ 4096 │ dir",
+            ]
+        );
+        assert!(lines.iter().all(is_table_line));
+    }
+
+    #[test]
+    fn narrows_the_widest_column_and_marks_clipped_cells() {
+        let builder = table(
+            vec![],
+            &["Key", "Description"],
+            &[&["a", "a rather long explanation of the key"]],
+        );
+        let lines = builder.into_lines(Some(24), Style::default());
+        assert_eq!(
+            plain(&lines),
+            vec![
+                "Key │ Description",
+                "────┼───────────────────",
+                "a   │ a rather long exp…",
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_minimum_widths_and_clips_the_line_instead() {
+        let builder = table(
+            vec![],
+            &["first column", "second column"],
+            &[&["one", "two"]],
+        );
+        let lines = builder.into_lines(Some(10), Style::default());
+        assert_eq!(
+            plain(&lines),
+            vec!["first… │ secon…", "───────┼───────", "one    │ two"]
+        );
+        assert_eq!(
+            plain(&[clip_table_line(lines[0].clone(), 10)]),
+            vec!["first… │ …"]
+        );
+    }
+
+    #[test]
+    fn missing_cells_are_padded() {
+        let builder = table(vec![], &["a", "b"], &[&["only"]]);
+        let lines = builder.into_lines(None, Style::default());
+        assert_eq!(plain(&lines), vec!["a    │ b", "─────┼──", "only │ "]);
+    }
+}
@@ -0,0 +1,274 @@
+//! Live command output in tmux or Zellij panes.
+//!
+//! When Codex runs inside a terminal multiplexer, a running command or a
+//! background terminal can be followed in a pane next to the chat instead of
+//! the full-screen overlay. The output is written to a log file in a private
+//! temp directory, and the new pane runs `tail -F` on it; deltas and the exit
+//! code are appended as they arrive. tmux panes and windows open in the
+//! background (`-d`) so the chat keeps focus. Zellij has no command-running
+//! tabs, so `window` opens a floating pane there instead.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+
+use codex_core::config::types::FollowInPane;
+use codex_core::terminal::Multiplexer;
+use codex_protocol::config_types::Language;
+use tempfile::TempDir;
+
+use crate::i18n::tr;
+use crate::i18n::tr_args;
+
+/// Longest pane or window title, in characters.
+const MAX_TITLE_CHARS: usize = 30;
+
+struct FollowLog {
+    path: PathBuf,
+    file: File,
+}
+
+/// Log files behind the panes opened so far, keyed by call id. The files are
+/// removed when Codex exits.
+#[derive(Default)]
+pub(crate) struct PaneFollows {
+    dir: Option<TempDir>,
+    logs: HashMap<String, FollowLog>,
+    next_log: usize,
+}
+
+impl PaneFollows {
+    /// Open a pane following `call_id`, seeded with `output` so far. Following
+    /// the same call again opens another pane on the same log.
+    pub(crate) fn start(
+        &mut self,
+        multiplexer: &Multiplexer,
+        placement: FollowInPane,
+        call_id: &str,
+        command: &str,
+        output: &str,
+    ) -> io::Result<()> {
+        if !self.logs.contains_key(call_id) {
+            let dir = match self.dir.take() {
+                Some(dir) => dir,
+                None => tempfile::Builder::new().prefix("codex-follow-").tempdir()?,
+            };
+            let dir = self.dir.insert(dir);
+            let path = dir.path().join(format!("{}.log", self.next_log));
+            self.next_log += 1;
+            let mut file = File::create(&path)?;
+            writeln!(file, "$ {command}")?;
+            file.write_all(output.as_bytes())?;
+            self.logs
+                .insert(call_id.to_string(), FollowLog { path, file });
+        }
+        let Some(log) = self.logs.get(call_id) else {
+            return Ok(());
+        };
+        let argv = pane_command(multiplexer, placement, &pane_title(command), &log.path);
+        let Some((program, args)) = argv.split_first() else {
+            return Ok(());
+        };
+        let result = Command::new(program).args(args).output()?;
+        if result.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&result.stderr).trim().to_string();
+            Err(io::Error::other(if stderr.is_empty() {
+                result.status.to_string()
+            } else {
+                stderr
+            }))
+        }
+    }
+
+    pub(crate) fn push_output(&mut self, call_id: &str, chunk: &[u8]) {
+        if let Some(log) = self.logs.get_mut(call_id) {
+            let _ = log.file.write_all(chunk);
+        }
+    }
+
+    /// Append the exit code and stop tracking `call_id`; the pane stays open
+    /// until the user closes it.
+    pub(crate) fn finish(&mut self, call_id: &str, exit_code: i32, language: Language) {
+        if let Some(mut log) = self.logs.remove(call_id) {
+            let code = exit_code.to_string();
+            let _ = writeln!(
+                log.file,
+                "\n[{}]",
+                tr_args(language, "follow_pane.exited", &[("code", &code)])
+            );
+        }
+    }
+}
+
+pub(crate) fn multiplexer_name(multiplexer: &Multiplexer) -> &'static str {
+    match multiplexer {
+        Multiplexer::Tmux { .. } => "tmux",
+        Multiplexer::Zellij {} => "Zellij",
+    }
+}
+
+/// Message shown after a pane was opened.
+pub(crate) fn opened_message(
+    language: Language,
+    multiplexer: &Multiplexer,
+    placement: FollowInPane,
+    command: &str,
+) -> String {
+    let key = match (multiplexer, placement) {
+        (Multiplexer::Tmux { .. }, FollowInPane::Window) => "follow_pane.opened_window",
+        _ => "follow_pane.opened_pane",
+    };
+    tr_args(
+        language,
+        key,
+        &[
+            ("command", command),
+            ("multiplexer", multiplexer_name(multiplexer)),
+        ],
+    )
+}
+
+/// Message shown when `/follow` is used outside tmux and Zellij.
+pub(crate) fn no_multiplexer_message(language: Language) -> String {
+    tr(language, "follow_pane.no_multiplexer").to_string()
+}
+
+fn pane_title(command: &str) -> String {
+    let command = command.trim();
+    if command.chars().count() <= MAX_TITLE_CHARS {
+        return command.to_string();
+    }
+    let mut title: String = command.chars().take(MAX_TITLE_CHARS).collect();
+    title.push('…');
+    title
+}
+
+/// Argument vector that opens a pane running `tail -F` on `log_path`.
+fn pane_command(
+    multiplexer: &Multiplexer,
+    placement: FollowInPane,
+    title: &str,
+    log_path: &Path,
+) -> Vec<String> {
+    let log_path = log_path.display().to_string();
+    let tail = ["tail", "-n", "+1", "-F", log_path.as_str()];
+    let title = title.to_string();
+    match multiplexer {
+        Multiplexer::Tmux { .. } => {
+            let mut argv: Vec<String> = match placement {
+                FollowInPane::Split => {
+                    vec![
+                        "tmux".into(),
+                        "split-window".into(),
+                        "-d".into(),
+                        "-h".into(),
+                    ]
+                }
+                FollowInPane::Window => {
+                    vec![
+                        "tmux".into(),
+                        "new-window".into(),
+                        "-d".into(),
+                        "-n".into(),
+                        title,
+                    ]
+                }
+            };
+            // tmux hands its last argument to the shell.
+            argv.push(shlex::try_join(tail).unwrap_or_else(|_| tail.join(" ")));
+            argv
+        }
+        Multiplexer::Zellij {} => {
+            let mut argv: Vec<String> = vec!["zellij".into(), "run".into()];
+            match placement {
+                FollowInPane::Split => {
+                    argv.extend(["--name".into(), title, "--direction".into(), "right".into()])
+                }
+                FollowInPane::Window => {
+                    argv.extend(["--floating".into(), "--name".into(), title]);
+                }
+            }
+            argv.push("--".into());
+            argv.extend(tail.iter().map(ToString::to_string));
+            argv
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn tmux_opens_background_split_or_window() {
+        let tmux = Multiplexer::Tmux { version: None };
+        let log = Path::new("/tmp/codex follow/0.log");
+        assert_eq!(
+            pane_command(&tmux, FollowInPane::Split, "cargo test", log),
+            strings(&[
+                "tmux",
+                "split-window",
+                "-d",
+                "-h",
+                "tail -n +1 -F '/tmp/codex follow/0.log'",
+            ])
+        );
+        assert_eq!(
+            pane_command(&tmux, FollowInPane::Window, "cargo test", log),
+            strings(&[
+                "tmux",
+                "new-window",
+                "-d",
+                "-n",
+                "cargo test",
+                "tail -n +1 -F '/tmp/codex follow/0.log'",
+            ])
+        );
+    }
+
+    #[test]
+    fn zellij_runs_tail_in_split_or_floating_pane() {
+        let zellij = Multiplexer::Zellij {};
+        let log = Path::new("/tmp/follow/0.log");
+        assert_eq!(
+            pane_command(&zellij, FollowInPane::Split, "npm run dev", log),
+            strings(&[
+                "zellij",
+                "run",
+                "--name",
+                "npm run dev",
+                "--direction",
+                "right",
+                "--",
+                "tail",
+                "-n",
+                "+1",
+                "-F",
+                "/tmp/follow/0.log",
+            ])
+        );
+        assert_eq!(
+            pane_command(&zellij, FollowInPane::Window, "npm run dev", log)[..5],
+            strings(&["zellij", "run", "--floating", "--name", "npm run dev"])[..]
+        );
+    }
+
+    #[test]
+    fn long_titles_are_cut() {
+        assert_eq!(
+            pane_title("  cargo test --workspace --all-features -- --nocapture "),
+            "cargo test --workspace --all-f…"
+        );
+    }
+}
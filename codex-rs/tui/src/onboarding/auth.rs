@@ -171,6 +171,7 @@ pub(crate) struct AuthModeWidget {
     pub forced_chatgpt_workspace_id: Option<String>,
     pub forced_login_method: Option<ForcedLoginMethod>,
     pub animations_enabled: bool,
+    pub open_browser: bool,
     pub language: Language,
 }
 
@@ -385,7 +386,12 @@ impl AuthModeWidget {
         if let SignInState::ChatGptContinueInBrowser(state) = &*sign_in_state
             && !state.auth_url.is_empty()
         {
-            lines.push(tr(language, "onboarding.auth.continue_in_browser.manual_link").into());
+            let link_hint = if self.open_browser {
+                "onboarding.auth.continue_in_browser.manual_link"
+            } else {
+                "onboarding.auth.continue_in_browser.open_link"
+            };
+            lines.push(tr(language, link_hint).into());
             lines.push("".into());
             lines.push(Line::from(vec![
                 "  ".into(),
@@ -737,12 +743,13 @@ impl AuthModeWidget {
         }
 
         self.error = None;
-        let opts = ServerOptions::new(
+        let mut opts = ServerOptions::new(
             self.codex_home.clone(),
             CLIENT_ID.to_string(),
             self.forced_chatgpt_workspace_id.clone(),
             self.cli_auth_credentials_store_mode,
         );
+        opts.open_browser = self.open_browser;
 
         match run_login_server(opts) {
             Ok(child) => {
@@ -870,6 +877,7 @@ mod tests {
             forced_chatgpt_workspace_id: None,
             forced_login_method: Some(ForcedLoginMethod::Chatgpt),
             animations_enabled: true,
+            open_browser: true,
             language: Language::En,
         };
         (widget, codex_home)
@@ -1,6 +1,7 @@
 use codex_core::AuthManager;
 use codex_login::ServerOptions;
 use codex_login::complete_device_code_login;
+use codex_login::render_qr_code;
 use codex_login::request_device_code;
 use codex_login::run_login_server;
 use ratatui::buffer::Buffer;
@@ -15,6 +16,7 @@ use std::sync::RwLock;
 use tokio::sync::Notify;
 
 use crate::i18n::tr;
+use crate::i18n::tr_args;
 use crate::shimmer::shimmer_spans;
 use crate::tui::FrameRequester;
 
@@ -163,6 +165,7 @@ pub(super) fn render_device_code_login(
             device_code.verification_url.as_str().cyan().underlined(),
         ]));
         lines.push("".into());
+        let qr_insert_at = lines.len();
         lines.push(tr(language, "onboarding.auth.device_code.step2").into());
         lines.push("".into());
         lines.push(Line::from(vec![
@@ -170,12 +173,43 @@ pub(super) fn render_device_code_login(
             device_code.user_code.as_str().cyan().bold(),
         ]));
         lines.push("".into());
+        let remaining = device_code.expires_in().as_secs();
+        let remaining = format!("{}:{:02}", remaining / 60, remaining % 60);
+        lines.push(
+            tr_args(
+                language,
+                "onboarding.auth.device_code.expires_in",
+                &[("remaining", remaining.as_str())],
+            )
+            .dim()
+            .into(),
+        );
+        lines.push("".into());
         lines.push(
             tr(language, "onboarding.auth.device_code.phishing_warning")
                 .dim()
                 .into(),
         );
         lines.push("".into());
+        // Keep the countdown ticking.
+        widget
+            .request_frame
+            .schedule_frame_in(std::time::Duration::from_secs(1));
+
+        // The QR code is a convenience; show it only when everything still
+        // fits (the Esc hint takes one more line).
+        if let Some(qr) = render_qr_code(&device_code.verification_url) {
+            let needed = lines.len() + qr.len() + 3;
+            if needed <= usize::from(area.height) {
+                let mut qr_lines: Vec<Line> = vec![
+                    tr(language, "onboarding.auth.device_code.scan_qr").into(),
+                    "".into(),
+                ];
+                qr_lines.extend(qr.into_iter().map(|row| Line::from(format!("  {row}"))));
+                qr_lines.push("".into());
+                lines.splice(qr_insert_at..qr_insert_at, qr_lines);
+            }
+        }
     } else {
         lines.push(
             tr(language, "onboarding.auth.device_code.requesting")
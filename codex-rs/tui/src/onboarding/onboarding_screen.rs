@@ -12,6 +12,7 @@ use ratatui::widgets::Clear;
 use ratatui::widgets::WidgetRef;
 
 use codex_protocol::config_types::ForcedLoginMethod;
+use codex_utils_absolute_path::AbsolutePathBuf;
 
 use crate::LoginStatus;
 use crate::onboarding::auth::AuthModeWidget;
@@ -62,6 +63,8 @@ pub(crate) struct OnboardingScreenArgs {
     pub show_login_screen: bool,
     pub login_status: LoginStatus,
     pub auth_manager: Arc<AuthManager>,
+    /// False when started with `--no-browser`.
+    pub open_browser: bool,
     pub config: Config,
 }
 
@@ -77,6 +80,7 @@ impl OnboardingScreen {
             show_login_screen,
             login_status,
             auth_manager,
+            open_browser,
             config,
         } = args;
         let cwd = config.cwd.clone();
@@ -109,6 +113,7 @@ impl OnboardingScreen {
                 forced_chatgpt_workspace_id,
                 forced_login_method,
                 animations_enabled: config.animations,
+                open_browser,
                 language,
             }))
         }
@@ -120,10 +125,17 @@ impl OnboardingScreen {
             TrustDirectorySelection::DontTrust
         };
         if show_trust_screen {
+            let project_config_files = config
+                .config_layer_stack
+                .untrusted_project_config_files()
+                .into_iter()
+                .map(AbsolutePathBuf::into_path_buf)
+                .collect();
             steps.push(Step::TrustDirectory(TrustDirectoryWidget {
                 cwd,
                 codex_home,
                 is_git_repo,
+                project_config_files,
                 selection: None,
                 highlighted,
                 error: None,
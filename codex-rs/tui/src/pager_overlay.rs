@@ -1,7 +1,8 @@
 //! Overlay UIs rendered in an alternate screen.
 //!
 //! This module implements the pager-style overlays used by the TUI, including the transcript
-//! overlay (`Ctrl+T`) that renders a full history view separate from the main viewport.
+//! overlay (`Ctrl+T`) that renders a full history view separate from the main viewport, and the
+//! exec output overlay (`Ctrl+O`) that follows a running command's full output.
 //!
 //! The transcript overlay renders committed transcript cells plus an optional render-only live tail
 //! derived from the current in-flight active cell. Because rebuilding wrapped `Line`s on every draw
@@ -15,24 +16,36 @@
 //! recomputed. `ChatWidget` is responsible for producing a key that changes when the active cell
 //! mutates in place or when its transcript output is time-dependent.
 
+use std::cell::Cell as StdCell;
+use std::cell::RefCell;
 use std::io::Result;
+use std::ops::Range;
+use std::rc::Rc;
 use std::sync::Arc;
 
 use crate::chatwidget::ActiveCellTranscriptKey;
+use crate::compare::CompareOverlay;
 use crate::history_cell::HistoryCell;
 use crate::history_cell::UserHistoryCell;
 use crate::i18n::tr;
+use crate::i18n::tr_args;
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
 use crate::render::Insets;
 use crate::render::renderable::InsetRenderable;
 use crate::render::renderable::Renderable;
 use crate::style::user_message_style;
+use crate::transcript_links;
+use crate::transcript_links::LinkTarget;
 use crate::tui;
+use crate::tui::FrameRequester;
 use crate::tui::TuiEvent;
+use codex_ansi_escape::ansi_escape_line;
 use codex_protocol::config_types::Language;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
+use crossterm::event::KeyEventKind;
+use crossterm::event::KeyModifiers;
 use ratatui::buffer::Buffer;
 use ratatui::buffer::Cell;
 use ratatui::layout::Rect;
@@ -50,6 +63,8 @@ use ratatui::widgets::Wrap;
 pub(crate) enum Overlay {
     Transcript(TranscriptOverlay),
     Static(StaticOverlay),
+    ExecOutput(ExecOutputOverlay),
+    Compare(CompareOverlay),
 }
 
 impl Overlay {
@@ -81,6 +96,8 @@ impl Overlay {
         match self {
             Overlay::Transcript(o) => o.handle_event(tui, event),
             Overlay::Static(o) => o.handle_event(tui, event),
+            Overlay::ExecOutput(o) => o.handle_event(tui, event),
+            Overlay::Compare(o) => o.handle_event(tui, event),
         }
     }
 
@@ -88,10 +105,15 @@ impl Overlay {
         match self {
             Overlay::Transcript(o) => o.is_done(),
             Overlay::Static(o) => o.is_done(),
+            Overlay::ExecOutput(o) => o.is_done(),
+            Overlay::Compare(o) => o.is_done(),
         }
     }
 }
 
+/// Columns moved per Left/Right press in the transcript overlay.
+const PAN_STEP: u16 = 8;
+
 const KEY_UP: KeyBinding = key_hint::plain(KeyCode::Up);
 const KEY_DOWN: KeyBinding = key_hint::plain(KeyCode::Down);
 const KEY_K: KeyBinding = key_hint::plain(KeyCode::Char('k'));
@@ -104,6 +126,10 @@ const KEY_HOME: KeyBinding = key_hint::plain(KeyCode::Home);
 const KEY_END: KeyBinding = key_hint::plain(KeyCode::End);
 const KEY_LEFT: KeyBinding = key_hint::plain(KeyCode::Left);
 const KEY_RIGHT: KeyBinding = key_hint::plain(KeyCode::Right);
+const KEY_H: KeyBinding = key_hint::plain(KeyCode::Char('h'));
+const KEY_L: KeyBinding = key_hint::plain(KeyCode::Char('l'));
+const KEY_O: KeyBinding = key_hint::plain(KeyCode::Char('o'));
+const KEY_TAB: KeyBinding = key_hint::plain(KeyCode::Tab);
 const KEY_CTRL_F: KeyBinding = key_hint::ctrl(KeyCode::Char('f'));
 const KEY_CTRL_D: KeyBinding = key_hint::ctrl(KeyCode::Char('d'));
 const KEY_CTRL_B: KeyBinding = key_hint::ctrl(KeyCode::Char('b'));
@@ -113,6 +139,10 @@ const KEY_ESC: KeyBinding = key_hint::plain(KeyCode::Esc);
 const KEY_ENTER: KeyBinding = key_hint::plain(KeyCode::Enter);
 const KEY_CTRL_T: KeyBinding = key_hint::ctrl(KeyCode::Char('t'));
 const KEY_CTRL_C: KeyBinding = key_hint::ctrl(KeyCode::Char('c'));
+const KEY_CTRL_O: KeyBinding = key_hint::ctrl(KeyCode::Char('o'));
+const KEY_SLASH: KeyBinding = key_hint::plain(KeyCode::Char('/'));
+const KEY_N: KeyBinding = key_hint::plain(KeyCode::Char('n'));
+const KEY_SHIFT_N: KeyBinding = key_hint::shift(KeyCode::Char('N'));
 
 // Common pager navigation hints rendered on the first line
 fn pager_key_hints(language: Language) -> [(&'static [KeyBinding], &'static str); 3] {
@@ -162,6 +192,9 @@ struct PagerView {
     last_rendered_height: Option<usize>,
     /// If set, on next render ensure this chunk is visible.
     pending_scroll_chunk: Option<usize>,
+    /// If set, on next render ensure this line is visible, given as a chunk
+    /// index and the line's distance from the bottom of that chunk.
+    pending_scroll_line: Option<(usize, usize)>,
 }
 
 impl PagerView {
@@ -173,6 +206,7 @@ impl PagerView {
             last_content_height: None,
             last_rendered_height: None,
             pending_scroll_chunk: None,
+            pending_scroll_line: None,
         }
     }
 
@@ -195,6 +229,9 @@ impl PagerView {
         if let Some(idx) = self.pending_scroll_chunk.take() {
             self.ensure_chunk_visible(idx, content_area);
         }
+        if let Some((idx, lines_from_bottom)) = self.pending_scroll_line.take() {
+            self.ensure_line_visible(idx, lines_from_bottom, content_area);
+        }
         self.scroll_offset = self
             .scroll_offset
             .min(content_height.saturating_sub(content_area.height as usize));
@@ -372,6 +409,32 @@ impl PagerView {
         self.pending_scroll_chunk = Some(chunk_index);
     }
 
+    /// Request that a line of the given chunk be scrolled into view on next
+    /// render. Lines are counted from the bottom of the chunk so that any top
+    /// inset around the chunk does not matter.
+    fn scroll_line_into_view(&mut self, chunk_index: usize, lines_from_bottom: usize) {
+        self.pending_scroll_line = Some((chunk_index, lines_from_bottom));
+    }
+
+    fn ensure_line_visible(&mut self, idx: usize, lines_from_bottom: usize, area: Rect) {
+        if area.height == 0 || idx >= self.renderables.len() {
+            return;
+        }
+        let chunk_bottom: usize = self
+            .renderables
+            .iter()
+            .take(idx + 1)
+            .map(|r| r.desired_height(area.width) as usize)
+            .sum();
+        let row = chunk_bottom.saturating_sub(lines_from_bottom);
+        let height = area.height as usize;
+        if row < self.scroll_offset {
+            self.scroll_offset = row;
+        } else if row >= self.scroll_offset.saturating_add(height) {
+            self.scroll_offset = row + 1 - height;
+        }
+    }
+
     fn ensure_chunk_visible(&mut self, idx: usize, area: Rect) {
         if area.height == 0 || idx >= self.renderables.len() {
             return;
@@ -424,15 +487,52 @@ impl Renderable for CachedRenderable {
     }
 }
 
+/// View state shared between the transcript overlay and its cell renderables.
+#[derive(Default)]
+struct TranscriptViewState {
+    /// Columns scrolled off the left edge, for lines wider than the screen
+    /// such as wide markdown tables.
+    horizontal_scroll: StdCell<u16>,
+    /// Link picked with Tab / Shift+Tab, drawn reversed.
+    selected_link: RefCell<Option<LinkSpot>>,
+}
+
+/// A link found in a committed transcript cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LinkSpot {
+    cell: usize,
+    line: usize,
+    /// Byte range of the link within the line's text.
+    range: Range<usize>,
+    target: LinkTarget,
+}
+
 struct CellRenderable {
+    index: usize,
     cell: Arc<dyn HistoryCell>,
     style: Style,
+    view_state: Rc<TranscriptViewState>,
 }
 
 impl Renderable for CellRenderable {
     fn render(&self, area: Rect, buf: &mut Buffer) {
-        let p =
-            Paragraph::new(Text::from(self.cell.transcript_lines(area.width))).style(self.style);
+        let selected = self.view_state.selected_link.borrow();
+        let selected = selected.as_ref().filter(|spot| spot.cell == self.index);
+        let lines: Vec<Line<'static>> = self
+            .cell
+            .transcript_lines(area.width)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, line)| {
+                let range = selected
+                    .filter(|spot| spot.line == idx)
+                    .map(|spot| &spot.range);
+                transcript_links::decorate_line(line, range)
+            })
+            .collect();
+        let p = Paragraph::new(Text::from(lines))
+            .style(self.style)
+            .scroll((0, self.view_state.horizontal_scroll.get()));
         p.render(area, buf);
     }
 
@@ -450,6 +550,10 @@ pub(crate) struct TranscriptOverlay {
     /// Committed transcript cells (does not include the live tail).
     cells: Vec<Arc<dyn HistoryCell>>,
     highlight_cell: Option<usize>,
+    /// Horizontal pan and link selection, shared with the cell renderables.
+    view_state: Rc<TranscriptViewState>,
+    /// Link the user asked to open; taken by `App` after each event.
+    link_to_open: Option<LinkTarget>,
     /// Cache key for the render-only live tail appended after committed cells.
     live_tail_key: Option<LiveTailKey>,
     is_done: bool,
@@ -477,14 +581,17 @@ impl TranscriptOverlay {
     /// This overlay does not own the "active cell"; callers may optionally append a live tail via
     /// `sync_live_tail` during draws to reflect in-flight activity.
     pub(crate) fn new(transcript_cells: Vec<Arc<dyn HistoryCell>>, language: Language) -> Self {
+        let view_state = Rc::new(TranscriptViewState::default());
         Self {
             view: PagerView::new(
-                Self::render_cells(&transcript_cells, None),
+                Self::render_cells(&transcript_cells, None, &view_state),
                 tr(language, "pager_overlay.title.transcript").to_string(),
                 usize::MAX,
             ),
             cells: transcript_cells,
             highlight_cell: None,
+            view_state,
+            link_to_open: None,
             live_tail_key: None,
             is_done: false,
             language,
@@ -494,6 +601,7 @@ impl TranscriptOverlay {
     fn render_cells(
         cells: &[Arc<dyn HistoryCell>],
         highlight_cell: Option<usize>,
+        view_state: &Rc<TranscriptViewState>,
     ) -> Vec<Box<dyn Renderable>> {
         cells
             .iter()
@@ -502,17 +610,21 @@ impl TranscriptOverlay {
                 let mut v: Vec<Box<dyn Renderable>> = Vec::new();
                 let mut cell_renderable = if c.as_any().is::<UserHistoryCell>() {
                     Box::new(CachedRenderable::new(CellRenderable {
+                        index: i,
                         cell: c.clone(),
                         style: if highlight_cell == Some(i) {
                             user_message_style().reversed()
                         } else {
                             user_message_style()
                         },
+                        view_state: view_state.clone(),
                     })) as Box<dyn Renderable>
                 } else {
                     Box::new(CachedRenderable::new(CellRenderable {
+                        index: i,
                         cell: c.clone(),
                         style: Style::default(),
+                        view_state: view_state.clone(),
                     })) as Box<dyn Renderable>
                 };
                 if !c.is_stream_continuation() && i > 0 {
@@ -542,7 +654,8 @@ impl TranscriptOverlay {
         let had_prior_cells = !self.cells.is_empty();
         let tail_renderable = self.take_live_tail_renderable();
         self.cells.push(cell);
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        self.view.renderables =
+            Self::render_cells(&self.cells, self.highlight_cell, &self.view_state);
         if let Some(tail) = tail_renderable {
             let tail = if !had_prior_cells
                 && self
@@ -571,6 +684,7 @@ impl TranscriptOverlay {
     pub(crate) fn replace_cells(&mut self, cells: Vec<Arc<dyn HistoryCell>>) {
         let follow_bottom = self.view.is_scrolled_to_bottom();
         self.cells = cells;
+        self.view_state.selected_link.replace(None);
         if self
             .highlight_cell
             .is_some_and(|idx| idx >= self.cells.len())
@@ -649,7 +763,8 @@ impl TranscriptOverlay {
 
     fn rebuild_renderables(&mut self) {
         let tail_renderable = self.take_live_tail_renderable();
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        self.view.renderables =
+            Self::render_cells(&self.cells, self.highlight_cell, &self.view_state);
         if let Some(tail) = tail_renderable {
             self.view.renderables.push(tail);
         }
@@ -724,6 +839,35 @@ impl TranscriptOverlay {
                     self.is_done = true;
                     Ok(())
                 }
+                e if KEY_LEFT.is_press(e) || KEY_H.is_press(e) => {
+                    self.pan(tui, false);
+                    Ok(())
+                }
+                e if KEY_RIGHT.is_press(e) || KEY_L.is_press(e) => {
+                    self.pan(tui, true);
+                    Ok(())
+                }
+                e if KEY_TAB.is_press(e) => {
+                    self.step_link(tui, true);
+                    Ok(())
+                }
+                e if e.code == KeyCode::BackTab
+                    && matches!(e.kind, KeyEventKind::Press | KeyEventKind::Repeat) =>
+                {
+                    self.step_link(tui, false);
+                    Ok(())
+                }
+                e if (KEY_ENTER.is_press(e) || KEY_O.is_press(e))
+                    && self.view_state.selected_link.borrow().is_some() =>
+                {
+                    self.link_to_open = self
+                        .view_state
+                        .selected_link
+                        .borrow()
+                        .as_ref()
+                        .map(|spot| spot.target.clone());
+                    Ok(())
+                }
                 other => self.view.handle_key_event(tui, other),
             },
             TuiEvent::Draw => {
@@ -735,6 +879,81 @@ impl TranscriptOverlay {
             _ => Ok(()),
         }
     }
+
+    /// Pan sideways by [`PAN_STEP`] columns, stopping once the widest
+    /// transcript line is fully in view.
+    fn pan(&mut self, tui: &mut tui::Tui, right: bool) {
+        let width = tui.terminal.viewport_area.width;
+        let current = self.view_state.horizontal_scroll.get();
+        let next = if right {
+            let widest = self
+                .cells
+                .iter()
+                .flat_map(|cell| cell.transcript_lines(width))
+                .map(|line| line.width())
+                .max()
+                .unwrap_or(0);
+            let max_scroll =
+                u16::try_from(widest.saturating_sub(usize::from(width))).unwrap_or(u16::MAX);
+            current.saturating_add(PAN_STEP).min(max_scroll)
+        } else {
+            current.saturating_sub(PAN_STEP)
+        };
+        if next != current {
+            self.view_state.horizontal_scroll.set(next);
+            tui.frame_requester()
+                .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+        }
+    }
+
+    /// Select the next (or previous) link in the committed transcript,
+    /// wrapping around, and scroll it into view.
+    fn step_link(&mut self, tui: &mut tui::Tui, forward: bool) {
+        let width = tui.terminal.viewport_area.width;
+        let mut links: Vec<(LinkSpot, usize)> = Vec::new();
+        for (cell_idx, cell) in self.cells.iter().enumerate() {
+            let lines = cell.transcript_lines(width);
+            for (line_idx, line) in lines.iter().enumerate() {
+                let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+                for link in transcript_links::find_links(&text) {
+                    let spot = LinkSpot {
+                        cell: cell_idx,
+                        line: line_idx,
+                        range: link.range,
+                        target: link.target,
+                    };
+                    links.push((spot, lines.len() - line_idx));
+                }
+            }
+        }
+        if links.is_empty() {
+            return;
+        }
+        let current = self
+            .view_state
+            .selected_link
+            .borrow()
+            .as_ref()
+            .and_then(|selected| links.iter().position(|(spot, _)| spot == selected));
+        let next = match (current, forward) {
+            (Some(idx), true) => (idx + 1) % links.len(),
+            (Some(idx), false) => (idx + links.len() - 1) % links.len(),
+            (None, true) => 0,
+            (None, false) => links.len() - 1,
+        };
+        let (spot, lines_from_bottom) = links.swap_remove(next);
+        self.view
+            .scroll_line_into_view(spot.cell, lines_from_bottom);
+        self.view_state.selected_link.replace(Some(spot));
+        tui.frame_requester()
+            .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+    }
+
+    /// Link the user asked to open since the last call, if any.
+    pub(crate) fn take_link_to_open(&mut self) -> Option<LinkTarget> {
+        self.link_to_open.take()
+    }
+
     pub(crate) fn is_done(&self) -> bool {
         self.is_done
     }
@@ -816,6 +1035,363 @@ impl StaticOverlay {
     }
 }
 
+/// Full-screen view of a single command's output that keeps streaming while
+/// the command runs (`Ctrl+O` on a running exec cell).
+///
+/// Output arrives as raw chunks, so the trailing partial line is held in
+/// `pending` until its newline shows up. Completed lines are appended to the
+/// pager incrementally; only a search change rebuilds every renderable.
+pub(crate) struct ExecOutputOverlay {
+    view: PagerView,
+    call_id: String,
+    command: String,
+    lines: Vec<String>,
+    pending: String,
+    exit_code: Option<i32>,
+    search: OutputSearch,
+    frame_requester: FrameRequester,
+    is_done: bool,
+    language: Language,
+}
+
+#[derive(Default)]
+struct OutputSearch {
+    /// Query being typed after `/`; `None` while the prompt is closed.
+    input: Option<String>,
+    query: String,
+    /// Indices into the displayed lines (including the pending partial line).
+    matches: Vec<usize>,
+    current: usize,
+}
+
+impl OutputSearch {
+    fn current_line(&self) -> Option<usize> {
+        self.matches.get(self.current).copied()
+    }
+}
+
+impl ExecOutputOverlay {
+    pub(crate) fn new(
+        call_id: String,
+        command: String,
+        output: &str,
+        frame_requester: FrameRequester,
+        language: Language,
+    ) -> Self {
+        let mut overlay = Self {
+            view: PagerView::new(Vec::new(), String::new(), usize::MAX),
+            call_id,
+            command,
+            lines: Vec::new(),
+            pending: String::new(),
+            exit_code: None,
+            search: OutputSearch::default(),
+            frame_requester,
+            is_done: false,
+            language,
+        };
+        overlay.split_into_lines(output);
+        overlay.rebuild_renderables();
+        overlay.update_title();
+        overlay
+    }
+
+    pub(crate) fn call_id(&self) -> &str {
+        &self.call_id
+    }
+
+    /// Appends a raw output chunk, keeping the view pinned to the bottom if
+    /// it was already there.
+    pub(crate) fn push_output(&mut self, chunk: &str) {
+        if chunk.is_empty() {
+            return;
+        }
+        let follow_bottom = self.view.is_scrolled_to_bottom();
+        let had_pending = !self.pending.is_empty();
+        let first_new = self.lines.len();
+        self.split_into_lines(chunk);
+
+        if had_pending {
+            self.view.renderables.pop();
+        }
+        for idx in first_new..self.lines.len() {
+            self.refresh_match(idx);
+            let renderable = self.line_renderable(idx);
+            self.view.renderables.push(renderable);
+        }
+        if !self.pending.is_empty() {
+            let idx = self.lines.len();
+            self.refresh_match(idx);
+            let renderable = self.line_renderable(idx);
+            self.view.renderables.push(renderable);
+        }
+
+        if follow_bottom {
+            self.view.scroll_offset = usize::MAX;
+        }
+        self.frame_requester.schedule_frame();
+    }
+
+    pub(crate) fn finish(&mut self, exit_code: i32) {
+        self.exit_code = Some(exit_code);
+        self.update_title();
+        self.frame_requester.schedule_frame();
+    }
+
+    fn split_into_lines(&mut self, chunk: &str) {
+        self.pending.push_str(chunk);
+        while let Some(newline) = self.pending.find('\n') {
+            let mut line: String = self.pending.drain(..=newline).collect();
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+            self.lines.push(line);
+        }
+    }
+
+    fn line_count(&self) -> usize {
+        self.lines.len() + usize::from(!self.pending.is_empty())
+    }
+
+    fn line_text(&self, idx: usize) -> &str {
+        self.lines
+            .get(idx)
+            .map(String::as_str)
+            .unwrap_or(self.pending.as_str())
+    }
+
+    fn line_renderable(&self, idx: usize) -> Box<dyn Renderable> {
+        let mut line = ansi_escape_line(self.line_text(idx));
+        if self.search.current_line() == Some(idx) {
+            line = line.reversed();
+        } else if self.search.matches.binary_search(&idx).is_ok() {
+            line = line.underlined();
+        }
+        let paragraph = Paragraph::new(Text::from(line)).wrap(Wrap { trim: false });
+        Box::new(CachedRenderable::new(paragraph))
+    }
+
+    fn rebuild_renderables(&mut self) {
+        self.view.renderables = (0..self.line_count())
+            .map(|idx| self.line_renderable(idx))
+            .collect();
+    }
+
+    fn update_title(&mut self) {
+        let status = match self.exit_code {
+            None => tr(self.language, "pager_overlay.title.exec_output_running").to_string(),
+            Some(code) => tr_args(
+                self.language,
+                "pager_overlay.title.exec_output_exit",
+                &[("code", &code.to_string())],
+            ),
+        };
+        self.view.title = format!(
+            "{} · {} · {status}",
+            tr(self.language, "pager_overlay.title.exec_output"),
+            self.command
+        );
+    }
+
+    fn line_matches(&self, idx: usize) -> bool {
+        if self.search.query.is_empty() {
+            return false;
+        }
+        let plain: String = ansi_escape_line(self.line_text(idx))
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        plain
+            .to_lowercase()
+            .contains(&self.search.query.to_lowercase())
+    }
+
+    /// Keeps the match list current for a line that was just added or grew.
+    fn refresh_match(&mut self, idx: usize) {
+        let matched = self.line_matches(idx);
+        match self.search.matches.binary_search(&idx) {
+            Ok(pos) if !matched => {
+                self.search.matches.remove(pos);
+                self.search.current = self
+                    .search
+                    .current
+                    .min(self.search.matches.len().saturating_sub(1));
+            }
+            Err(pos) if matched => self.search.matches.insert(pos, idx),
+            _ => {}
+        }
+    }
+
+    fn set_query(&mut self, query: String) {
+        self.search.query = query;
+        self.search.matches = (0..self.line_count())
+            .filter(|idx| self.line_matches(*idx))
+            .collect();
+        // Start from the most recent match: in build logs that is usually the
+        // one worth looking at.
+        self.search.current = self.search.matches.len().saturating_sub(1);
+        self.rebuild_renderables();
+        if let Some(idx) = self.search.current_line() {
+            self.view.scroll_chunk_into_view(idx);
+        }
+    }
+
+    fn step_match(&mut self, forward: bool) {
+        let total = self.search.matches.len();
+        if total == 0 {
+            return;
+        }
+        self.search.current = if forward {
+            (self.search.current + 1) % total
+        } else {
+            (self.search.current + total - 1) % total
+        };
+        self.rebuild_renderables();
+        if let Some(idx) = self.search.current_line() {
+            self.view.scroll_chunk_into_view(idx);
+        }
+    }
+
+    fn render_hints(&self, area: Rect, buf: &mut Buffer) {
+        let line1 = Rect::new(area.x, area.y, area.width, 1);
+        let line2 = Rect::new(area.x, area.y.saturating_add(1), area.width, 1);
+        let pager_hints = pager_key_hints(self.language);
+        render_key_hints(line1, buf, &pager_hints);
+
+        if let Some(input) = &self.search.input {
+            Line::from(vec![" /".into(), input.clone().into(), "▏".dim()]).render_ref(line2, buf);
+            return;
+        }
+
+        let mut pairs: Vec<(&[KeyBinding], &str)> = vec![
+            (&[KEY_Q], tr(self.language, "pager_overlay.hint.quit")),
+            (&[KEY_SLASH], tr(self.language, "pager_overlay.hint.search")),
+        ];
+        if !self.search.query.is_empty() {
+            pairs.push((
+                &[KEY_N, KEY_SHIFT_N],
+                tr(self.language, "pager_overlay.hint.search_next"),
+            ));
+        }
+        if self.exit_code.is_none() {
+            pairs.push((&[KEY_END], tr(self.language, "pager_overlay.hint.follow")));
+        }
+        render_key_hints(line2, buf, &pairs);
+
+        if !self.search.query.is_empty() {
+            let status = if self.search.matches.is_empty() {
+                tr(self.language, "pager_overlay.title.search_no_match").to_string()
+            } else {
+                tr_args(
+                    self.language,
+                    "pager_overlay.title.search_matches",
+                    &[
+                        ("current", &(self.search.current + 1).to_string()),
+                        ("total", &self.search.matches.len().to_string()),
+                    ],
+                )
+            };
+            let status = format!(" {status} ");
+            let width = (status.chars().count() as u16).min(line2.width);
+            Span::from(status)
+                .dim()
+                .render_ref(Rect::new(line2.right() - width, line2.y, width, 1), buf);
+        }
+    }
+
+    pub(crate) fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let top_h = area.height.saturating_sub(3);
+        let top = Rect::new(area.x, area.y, area.width, top_h);
+        let bottom = Rect::new(area.x, area.y + top_h, area.width, 3);
+        self.view.render(top, buf);
+        self.render_hints(bottom, buf);
+    }
+
+    /// Handles a key while the `/` prompt is open. Returns `false` for keys
+    /// the prompt does not consume.
+    fn handle_search_input(&mut self, key_event: KeyEvent) -> bool {
+        let Some(input) = self.search.input.as_mut() else {
+            return false;
+        };
+        if !matches!(key_event.kind, KeyEventKind::Press | KeyEventKind::Repeat) {
+            return true;
+        }
+        match key_event.code {
+            KeyCode::Enter => {
+                let query = std::mem::take(input);
+                self.search.input = None;
+                self.set_query(query);
+            }
+            KeyCode::Esc => self.search.input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c)
+                if !key_event
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                input.push(c);
+            }
+            _ => return false,
+        }
+        self.frame_requester.schedule_frame();
+        true
+    }
+}
+
+impl ExecOutputOverlay {
+    pub(crate) fn handle_event(&mut self, tui: &mut tui::Tui, event: TuiEvent) -> Result<()> {
+        match event {
+            TuiEvent::Key(key_event) => {
+                if self.handle_search_input(key_event) {
+                    return Ok(());
+                }
+                match key_event {
+                    e if KEY_Q.is_press(e) || KEY_CTRL_C.is_press(e) || KEY_CTRL_O.is_press(e) => {
+                        self.is_done = true;
+                    }
+                    e if KEY_ESC.is_press(e) => {
+                        if self.search.query.is_empty() {
+                            self.is_done = true;
+                        } else {
+                            self.set_query(String::new());
+                            self.frame_requester.schedule_frame();
+                        }
+                    }
+                    e if KEY_SLASH.is_press(e) => {
+                        self.search.input = Some(String::new());
+                        self.frame_requester.schedule_frame();
+                    }
+                    e if KEY_N.is_press(e) => {
+                        self.step_match(true);
+                        self.frame_requester.schedule_frame();
+                    }
+                    e if KEY_SHIFT_N.is_press(e) || e.code == KeyCode::Char('N') => {
+                        self.step_match(false);
+                        self.frame_requester.schedule_frame();
+                    }
+                    other => self.view.handle_key_event(tui, other)?,
+                }
+                Ok(())
+            }
+            TuiEvent::Draw => {
+                tui.draw(u16::MAX, |frame| {
+                    self.render(frame.area(), frame.buffer);
+                })?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn is_done(&self) -> bool {
+        self.is_done
+    }
+}
+
 fn render_offset_content(
     area: Rect,
     buf: &mut Buffer,
@@ -1074,8 +1650,10 @@ mod tests {
             "exec-1",
             CommandOutput {
                 exit_code: 0,
+                timed_out: false,
                 aggregated_output: "src\nREADME.md\n".into(),
                 formatted_output: "src\nREADME.md\n".into(),
+                provenance: None,
             },
             Duration::from_millis(420),
         );
@@ -1355,4 +1933,44 @@ mod tests {
             "expected view to report at bottom after scrolling to end"
         );
     }
+
+    #[test]
+    fn exec_output_overlay_streams_partial_lines_and_searches() {
+        let mut overlay = ExecOutputOverlay::new(
+            "call-1".to_string(),
+            "cargo build".to_string(),
+            "Compiling a\n\u{1b}[31merror\u{1b}[0m: first\nCompil",
+            FrameRequester::test_dummy(),
+            Language::En,
+        );
+        overlay.push_output("ing b\r\nerror: second\n");
+        assert_eq!(
+            overlay.lines,
+            vec![
+                "Compiling a",
+                "\u{1b}[31merror\u{1b}[0m: first",
+                "Compiling b",
+                "error: second"
+            ]
+        );
+        assert_eq!(overlay.view.renderables.len(), 4);
+
+        overlay.set_query("ERROR".to_string());
+        assert_eq!(overlay.search.matches, vec![1, 3]);
+        assert_eq!(overlay.search.current_line(), Some(3));
+        overlay.step_match(true);
+        assert_eq!(overlay.search.current_line(), Some(1));
+
+        overlay.push_output("error: third");
+        assert_eq!(overlay.search.matches, vec![1, 3, 4]);
+        overlay.finish(101);
+
+        let area = Rect::new(0, 0, 60, 10);
+        let mut buf = Buffer::empty(area);
+        overlay.render(area, &mut buf);
+        let text = buffer_to_text(&buf, area);
+        assert!(text.contains("cargo build · exit 101"), "{text}");
+        assert!(text.contains("error: third"), "{text}");
+        assert!(text.contains("1/3"), "{text}");
+    }
 }
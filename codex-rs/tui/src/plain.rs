@@ -0,0 +1,920 @@
+//! Line-oriented frontend for terminals that cannot host the full-screen TUI.
+//!
+//! `codex --plain` (implied by `TERM=dumb`) skips ratatui entirely: events are
+//! printed as plain lines, approvals and `request_user_input` questions are
+//! answered on stdin, and the slash commands that make sense without a screen
+//! keep working. This keeps Codex usable in Emacs shells, basic consoles, and
+//! CI debug sessions where stdin may be a pipe.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::io::BufRead;
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use codex_common::create_config_summary_entries;
+use codex_core::AuthManager;
+use codex_core::CodexThread;
+use codex_core::INTERACTIVE_SESSION_SOURCES;
+use codex_core::NewThread;
+use codex_core::RolloutRecorder;
+use codex_core::ThreadManager;
+use codex_core::ThreadSortKey;
+use codex_core::config::Config;
+use codex_core::find_thread_path_by_id_str;
+use codex_core::find_thread_path_by_name_str;
+use codex_core::project_doc::DEFAULT_PROJECT_DOC_FILENAME;
+use codex_core::protocol::Event;
+use codex_core::protocol::EventMsg;
+use codex_core::protocol::FileChange;
+use codex_core::protocol::Op;
+use codex_core::protocol::ReviewDecision;
+use codex_core::protocol::ReviewRequest;
+use codex_core::protocol::ReviewTarget;
+use codex_core::protocol::SessionSource;
+use codex_core::protocol::TokenUsage;
+use codex_protocol::ThreadId;
+use codex_protocol::approvals::ElicitationAction;
+use codex_protocol::mcp::RequestId;
+use codex_protocol::plan_tool::StepStatus;
+use codex_protocol::request_user_input::RequestUserInputAnswer;
+use codex_protocol::request_user_input::RequestUserInputQuestion;
+use codex_protocol::request_user_input::RequestUserInputResponse;
+use codex_protocol::user_input::UserInput;
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::mpsc::unbounded_channel;
+
+use crate::AppExitInfo;
+use crate::Cli;
+use crate::ExitReason;
+use crate::exec_command::strip_bash_lc_and_escape;
+use crate::get_git_diff::get_git_diff;
+use crate::get_login_status;
+use crate::should_show_login_screen;
+use crate::slash_command::SlashCommand;
+
+/// Command output is trimmed to its last lines; the full output stays in the
+/// rollout.
+const MAX_OUTPUT_LINES: usize = 20;
+const INIT_PROMPT: &str = include_str!("../prompt_for_init_command.md");
+const APPROVAL_CHOICES: &str = "[y]es, [a]lways this session, [N]o, [q]uit turn";
+
+enum PlainEvent {
+    Codex(ThreadId, Event),
+    /// A line read from stdin; `None` once stdin is closed.
+    Input(Option<String>),
+    CtrlC,
+}
+
+/// A line typed by the user, before it is routed anywhere.
+#[derive(Debug, PartialEq, Eq)]
+enum PlainInput {
+    Empty,
+    Message(String),
+    Command(SlashCommand, String),
+}
+
+fn parse_input(line: &str) -> PlainInput {
+    let line = line.trim();
+    if line.is_empty() {
+        return PlainInput::Empty;
+    }
+    if let Some(rest) = line.strip_prefix('/') {
+        let (name, args) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        // Anything that is not a known command (e.g. an absolute path) is
+        // sent to the model as typed.
+        if let Ok(command) = SlashCommand::from_str(name) {
+            return PlainInput::Command(command, args.trim().to_string());
+        }
+    }
+    PlainInput::Message(line.to_string())
+}
+
+/// Reads an approval answer; the default (empty line) denies.
+fn parse_approval(answer: &str) -> Option<ReviewDecision> {
+    match answer.trim().to_ascii_lowercase().as_str() {
+        "y" | "yes" => Some(ReviewDecision::Approved),
+        "a" | "always" => Some(ReviewDecision::ApprovedForSession),
+        "" | "n" | "no" => Some(ReviewDecision::Denied),
+        "q" | "quit" | "abort" => Some(ReviewDecision::Abort),
+        _ => None,
+    }
+}
+
+/// A numbered answer picks that option's label; anything else is taken as
+/// free text.
+fn parse_question_answer(question: &RequestUserInputQuestion, answer: &str) -> Vec<String> {
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return Vec::new();
+    }
+    let picked = answer
+        .parse::<usize>()
+        .ok()
+        .and_then(|index| index.checked_sub(1))
+        .and_then(|index| question.options.as_ref()?.get(index));
+    match picked {
+        Some(option) => vec![option.label.clone()],
+        None => vec![answer.to_string()],
+    }
+}
+
+fn output_tail(output: &str) -> Vec<&str> {
+    let lines: Vec<&str> = output.trim_end().lines().collect();
+    let skipped = lines.len().saturating_sub(MAX_OUTPUT_LINES);
+    lines[skipped..].to_vec()
+}
+
+fn change_marker(change: &FileChange) -> &'static str {
+    match change {
+        FileChange::Add { .. } => "A",
+        FileChange::Delete { .. } => "D",
+        FileChange::Update { .. } => "M",
+    }
+}
+
+fn sorted_changes(changes: &HashMap<PathBuf, FileChange>) -> Vec<String> {
+    let mut lines: Vec<String> = changes
+        .iter()
+        .map(|(path, change)| format!("  {} {}", change_marker(change), path.display()))
+        .collect();
+    lines.sort();
+    lines
+}
+
+/// What the next stdin line answers, if anything.
+enum Pending {
+    Exec {
+        id: String,
+    },
+    Patch {
+        id: String,
+    },
+    Elicitation {
+        server_name: String,
+        request_id: RequestId,
+    },
+    UserInput {
+        turn_id: String,
+        questions: VecDeque<RequestUserInputQuestion>,
+        answers: HashMap<String, RequestUserInputAnswer>,
+    },
+}
+
+struct PlainSession {
+    config: Config,
+    thread_manager: Arc<ThreadManager>,
+    thread: Arc<CodexThread>,
+    thread_id: ThreadId,
+    thread_name: Option<String>,
+    model: String,
+    tx: UnboundedSender<PlainEvent>,
+    turn_running: bool,
+    pending: Option<Pending>,
+    /// Lines typed while a turn runs; sent one per turn afterwards.
+    queued: VecDeque<String>,
+    stdin_closed: bool,
+    shutting_down: bool,
+    token_usage: TokenUsage,
+}
+
+pub(crate) async fn run_plain_app(cli: Cli, config: Config) -> std::io::Result<AppExitInfo> {
+    if should_show_login_screen(get_login_status(&config), &config) {
+        return Ok(AppExitInfo::fatal(
+            "Not logged in. Run `codex login` first; the plain frontend cannot show the login screen.",
+        ));
+    }
+    if cli.resume_picker || cli.fork_picker || cli.fork_last || cli.fork_session_id.is_some() {
+        return Ok(AppExitInfo::fatal(
+            "Session pickers and forks need the full-screen TUI. Run `codex resume <SESSION_ID>` or `codex resume --last` instead.",
+        ));
+    }
+
+    let auth_manager = AuthManager::shared(
+        config.codex_home.clone(),
+        false,
+        config.cli_auth_credentials_store_mode,
+    );
+    let thread_manager = Arc::new(ThreadManager::new(
+        config.codex_home.clone(),
+        auth_manager.clone(),
+        SessionSource::Cli,
+    ));
+    let resume_path = if let Some(id_str) = cli.resume_session_id.as_deref() {
+        let path = if uuid::Uuid::parse_str(id_str).is_ok() {
+            find_thread_path_by_id_str(&config.codex_home, id_str).await?
+        } else {
+            find_thread_path_by_name_str(&config.codex_home, id_str).await?
+        };
+        let Some(path) = path else {
+            return Ok(AppExitInfo::fatal(format!(
+                "No saved session found with ID {id_str}. Run `codex resume --last` to continue the latest session."
+            )));
+        };
+        Some(path)
+    } else if cli.resume_last {
+        let provider_filter = vec![config.model_provider_id.clone()];
+        let filter_cwd = (!cli.resume_show_all).then_some(config.cwd.as_path());
+        RolloutRecorder::find_latest_thread_path(
+            &config,
+            1,
+            None,
+            ThreadSortKey::UpdatedAt,
+            INTERACTIVE_SESSION_SOURCES,
+            Some(provider_filter.as_slice()),
+            &config.model_provider_id,
+            filter_cwd,
+        )
+        .await
+        .ok()
+        .flatten()
+    } else {
+        None
+    };
+    let new_thread = match resume_path {
+        Some(path) => {
+            thread_manager
+                .resume_thread_from_rollout(config.clone(), path, auth_manager)
+                .await
+        }
+        None => thread_manager.start_thread(config.clone()).await,
+    }
+    .map_err(std::io::Error::other)?;
+
+    let (tx, rx) = unbounded_channel();
+    spawn_stdin_reader(tx.clone());
+    spawn_ctrl_c_listener(tx.clone());
+
+    let mut session = PlainSession {
+        model: new_thread.session_configured.model.clone(),
+        thread_name: new_thread.session_configured.thread_name.clone(),
+        thread_id: new_thread.thread_id,
+        thread: new_thread.thread.clone(),
+        config,
+        thread_manager,
+        tx,
+        turn_running: false,
+        pending: None,
+        queued: VecDeque::new(),
+        stdin_closed: false,
+        shutting_down: false,
+        token_usage: TokenUsage::default(),
+    };
+    session.attach(new_thread)?;
+
+    let mut initial_items: Vec<UserInput> = cli
+        .images
+        .into_iter()
+        .map(|path| UserInput::LocalImage { path })
+        .collect();
+    if let Some(prompt) = cli.prompt.filter(|prompt| !prompt.trim().is_empty()) {
+        initial_items.push(UserInput::Text {
+            text: prompt,
+            text_elements: Vec::new(),
+        });
+    }
+    if initial_items.is_empty() {
+        session.print_prompt()?;
+    } else {
+        session.submit_items(initial_items).await?;
+    }
+
+    session.run(rx).await
+}
+
+fn spawn_stdin_reader(tx: UnboundedSender<PlainEvent>) {
+    std::thread::spawn(move || {
+        let stdin = std::io::stdin();
+        for line in stdin.lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if tx.send(PlainEvent::Input(Some(line))).is_err() {
+                return;
+            }
+        }
+        let _ = tx.send(PlainEvent::Input(None));
+    });
+}
+
+fn spawn_ctrl_c_listener(tx: UnboundedSender<PlainEvent>) {
+    tokio::spawn(async move {
+        while tokio::signal::ctrl_c().await.is_ok() {
+            if tx.send(PlainEvent::CtrlC).is_err() {
+                return;
+            }
+        }
+    });
+}
+
+fn spawn_thread_listener(
+    thread_id: ThreadId,
+    thread: Arc<CodexThread>,
+    tx: UnboundedSender<PlainEvent>,
+) {
+    tokio::spawn(async move {
+        while let Ok(event) = thread.next_event().await {
+            let shutdown = matches!(event.msg, EventMsg::ShutdownComplete);
+            if tx.send(PlainEvent::Codex(thread_id, event)).is_err() || shutdown {
+                return;
+            }
+        }
+    });
+}
+
+impl PlainSession {
+    async fn run(mut self, mut rx: UnboundedReceiver<PlainEvent>) -> std::io::Result<AppExitInfo> {
+        while let Some(event) = rx.recv().await {
+            let done = match event {
+                PlainEvent::Codex(thread_id, event) if thread_id == self.thread_id => {
+                    self.handle_event(event).await?
+                }
+                // Events from a thread replaced by `/new`.
+                PlainEvent::Codex(..) => false,
+                PlainEvent::Input(Some(line)) => {
+                    self.handle_line(line).await?;
+                    false
+                }
+                PlainEvent::Input(None) => {
+                    self.stdin_closed = true;
+                    self.handle_stdin_closed().await?;
+                    false
+                }
+                PlainEvent::CtrlC => {
+                    if self.turn_running {
+                        self.println("^C interrupting the current turn")?;
+                        self.submit(Op::Interrupt).await?;
+                    } else {
+                        self.shutdown().await?;
+                    }
+                    false
+                }
+            };
+            if done {
+                break;
+            }
+        }
+
+        Ok(AppExitInfo {
+            token_usage: self.token_usage,
+            thread_id: Some(self.thread_id),
+            thread_name: self.thread_name,
+            update_action: None,
+            exit_summary: Vec::new(),
+            exit_reason: ExitReason::UserRequested,
+        })
+    }
+
+    fn attach(&mut self, new_thread: NewThread) -> std::io::Result<()> {
+        let NewThread {
+            thread_id,
+            thread,
+            session_configured,
+        } = new_thread;
+        spawn_thread_listener(thread_id, thread.clone(), self.tx.clone());
+        self.thread_id = thread_id;
+        self.thread = thread;
+        self.thread_name = session_configured.thread_name.clone();
+        self.model = session_configured.model.clone();
+        self.token_usage = TokenUsage::default();
+
+        self.println(format!(
+            "codex session {thread_id} (plain output; /quit to exit)"
+        ))?;
+        self.print_config_summary()
+    }
+
+    async fn handle_line(&mut self, line: String) -> std::io::Result<()> {
+        if let Some(pending) = self.pending.take() {
+            return self.answer_pending(pending, &line).await;
+        }
+        if self.shutting_down {
+            return Ok(());
+        }
+        match parse_input(&line) {
+            PlainInput::Empty => {
+                if !self.turn_running {
+                    self.print_prompt()?;
+                }
+            }
+            PlainInput::Message(text) if self.turn_running => {
+                self.println("(queued until the current turn finishes)")?;
+                self.queued.push_back(text);
+            }
+            PlainInput::Message(text) => self.submit_text(text).await?,
+            PlainInput::Command(command, args) => self.handle_command(command, args).await?,
+        }
+        Ok(())
+    }
+
+    async fn handle_command(&mut self, command: SlashCommand, args: String) -> std::io::Result<()> {
+        let name = command.command();
+        if self.turn_running && !command.available_during_task() {
+            return self.println(format!(
+                "`/{name}` is disabled while a task is in progress."
+            ));
+        }
+        match command {
+            SlashCommand::Quit | SlashCommand::Exit => self.shutdown().await?,
+            SlashCommand::New => {
+                self.submit(Op::Shutdown).await?;
+                let new_thread = self
+                    .thread_manager
+                    .start_thread(self.config.clone())
+                    .await
+                    .map_err(std::io::Error::other)?;
+                self.attach(new_thread)?;
+                self.print_prompt()?;
+            }
+            SlashCommand::Compact => {
+                self.turn_running = true;
+                self.submit(Op::Compact).await?;
+            }
+            SlashCommand::Init => {
+                let target = self.config.cwd.join(DEFAULT_PROJECT_DOC_FILENAME);
+                if target.exists() {
+                    self.println(format!(
+                        "{} already exists here. Skipping /init to avoid overwriting it.",
+                        DEFAULT_PROJECT_DOC_FILENAME
+                    ))?;
+                    self.print_prompt()?;
+                } else {
+                    self.submit_text(INIT_PROMPT.to_string()).await?;
+                }
+            }
+            SlashCommand::Review => {
+                let target = if args.is_empty() {
+                    ReviewTarget::UncommittedChanges
+                } else {
+                    ReviewTarget::Custom { instructions: args }
+                };
+                self.turn_running = true;
+                self.submit(Op::Review {
+                    review_request: ReviewRequest {
+                        target,
+                        user_facing_hint: None,
+                    },
+                })
+                .await?;
+            }
+            SlashCommand::Rename if !args.is_empty() => {
+                self.thread_name = Some(args.clone());
+                self.submit(Op::SetThreadName { name: args }).await?;
+                self.print_prompt_if_idle()?;
+            }
+            SlashCommand::Model if !args.is_empty() => {
+                self.submit(Op::OverrideTurnContext {
+                    cwd: None,
+                    approval_policy: None,
+                    sandbox_policy: None,
+                    windows_sandbox_level: None,
+                    model: Some(args.clone()),
+                    effort: None,
+                    summary: None,
+                    collaboration_mode: None,
+                    personality: None,
+                })
+                .await?;
+                self.println(format!("model set to {args} for the next turns"))?;
+                self.model = args;
+                self.print_prompt_if_idle()?;
+            }
+            SlashCommand::Rename | SlashCommand::Model => {
+                self.println(format!("Usage: /{name} <value>"))?;
+                self.print_prompt_if_idle()?;
+            }
+            SlashCommand::Status => {
+                self.print_status()?;
+                self.print_prompt_if_idle()?;
+            }
+            SlashCommand::Diff => {
+                match get_git_diff(self.config.language).await {
+                    Ok((true, diff)) if !diff.trim().is_empty() => {
+                        for line in diff.lines() {
+                            self.println(line)?;
+                        }
+                    }
+                    Ok((true, _)) => self.println("No changes.")?,
+                    Ok((false, _)) => self.println("Not inside a git repository.")?,
+                    Err(err) => self.println(format!("Failed to compute diff: {err}"))?,
+                }
+                self.print_prompt_if_idle()?;
+            }
+            _ => {
+                self.println(format!(
+                    "`/{name}` needs the full-screen TUI and is not available in plain mode."
+                ))?;
+                self.print_prompt_if_idle()?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn answer_pending(&mut self, pending: Pending, line: &str) -> std::io::Result<()> {
+        match pending {
+            Pending::Exec { id } => match parse_approval(line) {
+                Some(decision) => {
+                    self.submit(Op::ExecApproval {
+                        id,
+                        turn_id: None,
+                        decision,
+                    })
+                    .await?;
+                }
+                None => {
+                    self.println(format!("Please answer {APPROVAL_CHOICES}:"))?;
+                    self.pending = Some(Pending::Exec { id });
+                }
+            },
+            Pending::Patch { id } => match parse_approval(line) {
+                Some(decision) => self.submit(Op::PatchApproval { id, decision }).await?,
+                None => {
+                    self.println(format!("Please answer {APPROVAL_CHOICES}:"))?;
+                    self.pending = Some(Pending::Patch { id });
+                }
+            },
+            Pending::Elicitation {
+                server_name,
+                request_id,
+            } => {
+                let decision = match parse_approval(line) {
+                    Some(ReviewDecision::Approved | ReviewDecision::ApprovedForSession) => {
+                        ElicitationAction::Accept
+                    }
+                    Some(ReviewDecision::Abort) => ElicitationAction::Cancel,
+                    _ => ElicitationAction::Decline,
+                };
+                self.submit(Op::ResolveElicitation {
+                    server_name,
+                    request_id,
+                    decision,
+                })
+                .await?;
+            }
+            Pending::UserInput {
+                turn_id,
+                mut questions,
+                mut answers,
+            } => {
+                if let Some(question) = questions.pop_front() {
+                    answers.insert(
+                        question.id.clone(),
+                        RequestUserInputAnswer {
+                            answers: parse_question_answer(&question, line),
+                        },
+                    );
+                }
+                self.ask_next_question(turn_id, questions, answers).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn ask_next_question(
+        &mut self,
+        turn_id: String,
+        mut questions: VecDeque<RequestUserInputQuestion>,
+        answers: HashMap<String, RequestUserInputAnswer>,
+    ) -> std::io::Result<()> {
+        if self.stdin_closed {
+            for question in questions.drain(..) {
+                self.println(format!("{} (skipped: stdin is closed)", question.question))?;
+            }
+        }
+        let Some(question) = questions.front() else {
+            return self
+                .submit(Op::UserInputAnswer {
+                    id: turn_id,
+                    response: RequestUserInputResponse { answers },
+                })
+                .await;
+        };
+        self.println(format!("{}: {}", question.header, question.question))?;
+        for (index, option) in question.options.iter().flatten().enumerate() {
+            self.println(format!(
+                "  {}. {} - {}",
+                index + 1,
+                option.label,
+                option.description
+            ))?;
+        }
+        self.print_inline("answer> ")?;
+        self.pending = Some(Pending::UserInput {
+            turn_id,
+            questions,
+            answers,
+        });
+        Ok(())
+    }
+
+    async fn handle_stdin_closed(&mut self) -> std::io::Result<()> {
+        // Nobody is left to answer, so anything waiting on stdin is refused.
+        if let Some(pending) = self.pending.take() {
+            self.println("stdin closed; declining the pending request")?;
+            self.answer_pending(pending, "n").await?;
+        }
+        if !self.turn_running && self.queued.is_empty() {
+            self.shutdown().await?;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` once the session has shut down.
+    async fn handle_event(&mut self, event: Event) -> std::io::Result<bool> {
+        let Event { id, msg } = event;
+        match msg {
+            EventMsg::TurnStarted(_) => self.turn_running = true,
+            EventMsg::AgentMessage(ev) => {
+                self.println("")?;
+                self.println("codex")?;
+                self.println(ev.message)?;
+            }
+            EventMsg::AgentReasoning(ev) if !self.config.hide_agent_reasoning => {
+                self.println(format!("thinking: {}", ev.text))?;
+            }
+            EventMsg::ExecCommandBegin(ev) => {
+                self.println(format!(
+                    "exec: {} (in {})",
+                    strip_bash_lc_and_escape(&ev.command),
+                    ev.cwd.display()
+                ))?;
+            }
+            EventMsg::ExecCommandEnd(ev) => {
+                for line in output_tail(&ev.aggregated_output) {
+                    self.println(format!("  {line}"))?;
+                }
+                self.println(format!(
+                    "exit {} in {:.1}s: {}",
+                    ev.exit_code,
+                    ev.duration.as_secs_f32(),
+                    strip_bash_lc_and_escape(&ev.command)
+                ))?;
+            }
+            EventMsg::PatchApplyBegin(ev) => {
+                self.println("apply_patch:")?;
+                for line in sorted_changes(&ev.changes) {
+                    self.println(line)?;
+                }
+            }
+            EventMsg::PatchApplyEnd(ev) if !ev.success => {
+                self.println(format!("apply_patch failed: {}", ev.stderr.trim()))?;
+            }
+            EventMsg::McpToolCallBegin(ev) => {
+                self.println(format!(
+                    "tool: {}.{}",
+                    ev.invocation.server, ev.invocation.tool
+                ))?;
+            }
+            EventMsg::WebSearchEnd(ev) => {
+                self.println(format!("web search: {}", ev.query))?;
+            }
+            EventMsg::PlanUpdate(update) => {
+                self.println("plan:")?;
+                for item in update.plan {
+                    let mark = match item.status {
+                        StepStatus::Completed => "[x]",
+                        StepStatus::InProgress => "[>]",
+                        StepStatus::Pending => "[ ]",
+                    };
+                    self.println(format!("  {mark} {}", item.step))?;
+                }
+            }
+            EventMsg::Warning(ev) => self.println(format!("warning: {}", ev.message))?,
+            EventMsg::StreamError(ev) => self.println(format!("warning: {}", ev.message))?,
+            EventMsg::Error(ev) => self.println(format!("ERROR: {}", ev.message))?,
+            EventMsg::TokenCount(ev) => {
+                if let Some(info) = ev.info {
+                    self.token_usage = info.total_token_usage;
+                }
+            }
+            EventMsg::ExecApprovalRequest(ev) => {
+                self.println("")?;
+                self.println(format!(
+                    "Approval requested to run: {}",
+                    strip_bash_lc_and_escape(&ev.command)
+                ))?;
+                if let Some(reason) = ev.reason {
+                    self.println(format!("reason: {reason}"))?;
+                }
+                self.request_approval(Pending::Exec { id: ev.call_id })
+                    .await?;
+            }
+            EventMsg::ApplyPatchApprovalRequest(ev) => {
+                self.println("")?;
+                self.println("Approval requested to apply these changes:")?;
+                for line in sorted_changes(&ev.changes) {
+                    self.println(line)?;
+                }
+                if let Some(reason) = ev.reason {
+                    self.println(format!("reason: {reason}"))?;
+                }
+                self.request_approval(Pending::Patch { id: ev.call_id })
+                    .await?;
+            }
+            EventMsg::ElicitationRequest(ev) => {
+                self.println("")?;
+                self.println(format!("{} asks: {}", ev.server_name, ev.message))?;
+                self.request_approval(Pending::Elicitation {
+                    server_name: ev.server_name,
+                    request_id: ev.id,
+                })
+                .await?;
+            }
+            EventMsg::RequestUserInput(ev) => {
+                self.println("")?;
+                self.ask_next_question(ev.turn_id, ev.questions.into(), HashMap::new())
+                    .await?;
+            }
+            EventMsg::TurnAborted(_) => {
+                self.println("turn interrupted")?;
+                self.finish_turn().await?;
+            }
+            EventMsg::TurnComplete(_) => self.finish_turn().await?,
+            EventMsg::ShutdownComplete => return Ok(true),
+            _ => {
+                tracing::trace!(event_id = %id, "plain frontend ignores event");
+            }
+        }
+        Ok(false)
+    }
+
+    async fn request_approval(&mut self, pending: Pending) -> std::io::Result<()> {
+        if self.stdin_closed {
+            self.println("stdin closed; declining")?;
+            return self.answer_pending(pending, "n").await;
+        }
+        self.print_inline(&format!("Allow? {APPROVAL_CHOICES}: "))?;
+        self.pending = Some(pending);
+        Ok(())
+    }
+
+    async fn finish_turn(&mut self) -> std::io::Result<()> {
+        self.turn_running = false;
+        self.pending = None;
+        if let Some(text) = self.queued.pop_front() {
+            return self.submit_text(text).await;
+        }
+        if self.stdin_closed {
+            return self.shutdown().await;
+        }
+        self.print_prompt()
+    }
+
+    async fn submit_text(&mut self, text: String) -> std::io::Result<()> {
+        self.submit_items(vec![UserInput::Text {
+            text,
+            text_elements: Vec::new(),
+        }])
+        .await
+    }
+
+    async fn submit_items(&mut self, items: Vec<UserInput>) -> std::io::Result<()> {
+        self.turn_running = true;
+        self.submit(Op::UserInput {
+            items,
+            final_output_json_schema: None,
+        })
+        .await
+    }
+
+    async fn submit(&self, op: Op) -> std::io::Result<()> {
+        self.thread
+            .submit(op)
+            .await
+            .map(|_| ())
+            .map_err(std::io::Error::other)
+    }
+
+    async fn shutdown(&mut self) -> std::io::Result<()> {
+        if self.shutting_down {
+            return Ok(());
+        }
+        self.shutting_down = true;
+        self.submit(Op::Shutdown).await
+    }
+
+    fn print_status(&mut self) -> std::io::Result<()> {
+        self.println(format!("session: {}", self.thread_id))?;
+        if let Some(name) = self.thread_name.clone() {
+            self.println(format!("name: {name}"))?;
+        }
+        self.print_config_summary()?;
+        self.println(format!(
+            "tokens: {} total ({} input, {} output)",
+            self.token_usage.total_tokens,
+            self.token_usage.input_tokens,
+            self.token_usage.output_tokens
+        ))
+    }
+
+    fn print_config_summary(&mut self) -> std::io::Result<()> {
+        for (key, value) in create_config_summary_entries(&self.config, &self.model) {
+            self.println(format!("{key}: {value}"))?;
+        }
+        Ok(())
+    }
+
+    fn print_prompt_if_idle(&mut self) -> std::io::Result<()> {
+        if self.turn_running {
+            return Ok(());
+        }
+        self.print_prompt()
+    }
+
+    fn print_prompt(&mut self) -> std::io::Result<()> {
+        if self.stdin_closed || self.shutting_down {
+            return Ok(());
+        }
+        self.print_inline("> ")
+    }
+
+    fn println(&mut self, text: impl AsRef<str>) -> std::io::Result<()> {
+        let mut out = std::io::stdout().lock();
+        writeln!(out, "{}", text.as_ref())?;
+        out.flush()
+    }
+
+    fn print_inline(&mut self, text: &str) -> std::io::Result<()> {
+        let mut out = std::io::stdout().lock();
+        write!(out, "{text}")?;
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::request_user_input::RequestUserInputQuestionOption;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_messages_and_known_commands() {
+        assert_eq!(parse_input("  "), PlainInput::Empty);
+        assert_eq!(
+            parse_input("/model gpt-5.1-codex "),
+            PlainInput::Command(SlashCommand::Model, "gpt-5.1-codex".to_string())
+        );
+        assert_eq!(
+            parse_input("/quit"),
+            PlainInput::Command(SlashCommand::Quit, String::new())
+        );
+        assert_eq!(
+            parse_input("/usr/bin/env is missing"),
+            PlainInput::Message("/usr/bin/env is missing".to_string())
+        );
+    }
+
+    #[test]
+    fn approval_answers_default_to_deny() {
+        assert_eq!(parse_approval("Y"), Some(ReviewDecision::Approved));
+        assert_eq!(
+            parse_approval("always"),
+            Some(ReviewDecision::ApprovedForSession)
+        );
+        assert_eq!(parse_approval(""), Some(ReviewDecision::Denied));
+        assert_eq!(parse_approval("q"), Some(ReviewDecision::Abort));
+        assert_eq!(parse_approval("maybe"), None);
+    }
+
+    #[test]
+    fn numbered_answers_pick_options() {
+        let question = RequestUserInputQuestion {
+            id: "scope".to_string(),
+            header: "Scope".to_string(),
+            question: "Which crate?".to_string(),
+            is_other: true,
+            is_secret: false,
+            options: Some(vec![
+                RequestUserInputQuestionOption {
+                    label: "core".to_string(),
+                    description: "Core logic".to_string(),
+                },
+                RequestUserInputQuestionOption {
+                    label: "tui".to_string(),
+                    description: "Terminal UI".to_string(),
+                },
+            ]),
+        };
+
+        assert_eq!(parse_question_answer(&question, "2"), vec!["tui"]);
+        assert_eq!(parse_question_answer(&question, "7"), vec!["7"]);
+        assert_eq!(parse_question_answer(&question, "exec"), vec!["exec"]);
+        assert_eq!(parse_question_answer(&question, " "), Vec::<String>::new());
+    }
+
+    #[test]
+    fn long_output_keeps_the_last_lines() {
+        let output = (1..=30)
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let tail = output_tail(&output);
+
+        assert_eq!(tail.len(), MAX_OUTPUT_LINES);
+        assert_eq!(tail.first(), Some(&"11"));
+        assert_eq!(tail.last(), Some(&"30"));
+    }
+}
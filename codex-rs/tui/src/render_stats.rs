@@ -0,0 +1,138 @@
+//! Frame-time and event-rate counters behind the `render_stats_hud` feature.
+//!
+//! `App` records every protocol event it handles and how long each draw took.
+//! The HUD shows the last second of both in the top-right corner of the
+//! viewport, which is enough to see whether a chatty command is driving more
+//! redraws than the frame scheduler should allow.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+use std::time::Instant;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Widget;
+use unicode_width::UnicodeWidthStr;
+
+const WINDOW: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Default)]
+pub(crate) struct RenderStats {
+    /// Start time and duration of recent draws.
+    frames: VecDeque<(Instant, Duration)>,
+    events: VecDeque<Instant>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RenderStatsSummary {
+    pub(crate) frames_per_sec: usize,
+    pub(crate) avg_frame_ms: f64,
+    pub(crate) max_frame_ms: f64,
+    pub(crate) events_per_sec: usize,
+}
+
+impl RenderStats {
+    pub(crate) fn record_event(&mut self, at: Instant) {
+        self.events.push_back(at);
+        prune(&mut self.events, at, |at| *at);
+    }
+
+    pub(crate) fn record_frame(&mut self, started_at: Instant, elapsed: Duration) {
+        self.frames.push_back((started_at, elapsed));
+        prune(&mut self.frames, started_at, |(at, _)| *at);
+    }
+
+    pub(crate) fn summary(&self, now: Instant) -> RenderStatsSummary {
+        let in_window = |at: &Instant| now.saturating_duration_since(*at) <= WINDOW;
+        let frames: Vec<Duration> = self
+            .frames
+            .iter()
+            .filter(|(at, _)| in_window(at))
+            .map(|(_, elapsed)| *elapsed)
+            .collect();
+        let total: Duration = frames.iter().sum();
+        let avg_frame_ms = if frames.is_empty() {
+            0.0
+        } else {
+            total.as_secs_f64() * 1000.0 / frames.len() as f64
+        };
+        let max_frame_ms = frames
+            .iter()
+            .max()
+            .map_or(0.0, |elapsed| elapsed.as_secs_f64() * 1000.0);
+        RenderStatsSummary {
+            frames_per_sec: frames.len(),
+            avg_frame_ms,
+            max_frame_ms,
+            events_per_sec: self.events.iter().filter(|at| in_window(at)).count(),
+        }
+    }
+
+    /// Draw the summary on the first row of `area`, right-aligned.
+    pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
+        let summary = self.summary(Instant::now());
+        let text = format!(
+            " {} fps · frame {:.1}/{:.1} ms · {} ev/s ",
+            summary.frames_per_sec,
+            summary.avg_frame_ms,
+            summary.max_frame_ms,
+            summary.events_per_sec
+        );
+        let width = u16::try_from(text.width())
+            .unwrap_or(u16::MAX)
+            .min(area.width);
+        if width == 0 || area.height == 0 {
+            return;
+        }
+        let hud_area = Rect::new(area.right() - width, area.y, width, 1);
+        Line::from(text).reversed().dim().render(hud_area, buf);
+    }
+}
+
+fn prune<T>(entries: &mut VecDeque<T>, now: Instant, at: impl Fn(&T) -> Instant) {
+    while let Some(front) = entries.front() {
+        if now.saturating_duration_since(at(front)) <= WINDOW {
+            break;
+        }
+        entries.pop_front();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn summarizes_only_the_last_second() {
+        let start = Instant::now();
+        let mut stats = RenderStats::default();
+        stats.record_event(start);
+        stats.record_frame(start, Duration::from_millis(9));
+        for offset in [1100, 1200, 1300] {
+            let at = start + Duration::from_millis(offset);
+            stats.record_event(at);
+            stats.record_event(at);
+        }
+        stats.record_frame(
+            start + Duration::from_millis(1200),
+            Duration::from_millis(2),
+        );
+        stats.record_frame(
+            start + Duration::from_millis(1300),
+            Duration::from_millis(4),
+        );
+
+        assert_eq!(
+            stats.summary(start + Duration::from_millis(1500)),
+            RenderStatsSummary {
+                frames_per_sec: 2,
+                avg_frame_ms: 3.0,
+                max_frame_ms: 4.0,
+                events_per_sec: 6,
+            }
+        );
+    }
+}
@@ -11,16 +11,23 @@ use crate::tui::FrameRequester;
 use crate::tui::Tui;
 use crate::tui::TuiEvent;
 use chrono::DateTime;
+use chrono::Local;
+use chrono::NaiveDate;
 use chrono::Utc;
 use codex_core::Cursor;
 use codex_core::INTERACTIVE_SESSION_SOURCES;
 use codex_core::RolloutRecorder;
+use codex_core::SessionPreview;
 use codex_core::ThreadItem;
 use codex_core::ThreadSortKey;
 use codex_core::ThreadsPage;
+use codex_core::archive_session;
 use codex_core::config::Config;
+use codex_core::delete_session;
 use codex_core::find_thread_names_by_ids;
+use codex_core::load_session_preview;
 use codex_core::path_utils;
+use codex_core::rollout_lost_events;
 use codex_protocol::ThreadId;
 use color_eyre::eyre::Result;
 use crossterm::event::KeyCode;
@@ -28,10 +35,13 @@ use crossterm::event::KeyEvent;
 use crossterm::event::KeyEventKind;
 use ratatui::layout::Constraint;
 use ratatui::layout::Layout;
+use ratatui::layout::Margin;
 use ratatui::layout::Rect;
 use ratatui::style::Stylize as _;
 use ratatui::text::Line;
 use ratatui::text::Span;
+use ratatui::widgets::Paragraph;
+use ratatui::widgets::Wrap;
 use tokio::sync::mpsc;
 use tokio_stream::StreamExt;
 use tokio_stream::wrappers::UnboundedReceiverStream;
@@ -39,6 +49,9 @@ use unicode_width::UnicodeWidthStr;
 
 const PAGE_SIZE: usize = 25;
 const LOAD_NEAR_THRESHOLD: usize = 5;
+/// Narrower terminals hide the preview pane to keep room for the table.
+const PREVIEW_MIN_WIDTH: u16 = 100;
+const PREVIEW_GRAPHEMES: usize = 600;
 #[derive(Debug, Clone)]
 pub enum SessionSelection {
     StartFresh,
@@ -87,20 +100,65 @@ struct PageLoadRequest {
 
 type PageLoader = Arc<dyn Fn(PageLoadRequest) + Send + Sync>;
 
+/// Work on individual sessions that runs in the background and reports back
+/// through [`BackgroundEvent`].
+enum SessionTask {
+    Preview(PathBuf),
+    Remove {
+        action: RemoveAction,
+        paths: Vec<PathBuf>,
+    },
+}
+
+type SessionTaskRunner = Arc<dyn Fn(SessionTask) + Send + Sync>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RemoveAction {
+    Archive,
+    Delete,
+}
+
+impl RemoveAction {
+    fn verb(self) -> &'static str {
+        match self {
+            RemoveAction::Archive => "Archive",
+            RemoveAction::Delete => "Delete",
+        }
+    }
+
+    fn past_tense(self) -> &'static str {
+        match self {
+            RemoveAction::Archive => "Archived",
+            RemoveAction::Delete => "Deleted",
+        }
+    }
+}
+
 enum BackgroundEvent {
     PageLoaded {
         request_token: usize,
         search_token: Option<usize>,
         page: std::io::Result<ThreadsPage>,
     },
+    PreviewLoaded {
+        path: PathBuf,
+        preview: std::io::Result<SessionPreview>,
+    },
+    SessionsRemoved {
+        action: RemoveAction,
+        removed: Vec<PathBuf>,
+        errors: Vec<String>,
+    },
 }
 
 /// Interactive session picker that lists recorded rollout files with simple
 /// search and pagination.
 ///
 /// The picker displays sessions in a table with timestamp columns (created/updated),
-/// git branch, working directory, and conversation preview. Users can toggle
-/// between sorting by creation time and last-updated time using the Tab key.
+/// git branch, working directory, and conversation preview. Users can cycle
+/// between sorting by creation time, last-updated time and name using the Tab
+/// key. On wide terminals a preview pane shows the selected session's first
+/// prompt and latest agent reply.
 ///
 /// Sessions are loaded on-demand via cursor-based pagination. The backend
 /// `RolloutRecorder::list_threads` returns pages ordered by the selected sort key,
@@ -110,13 +168,27 @@ enum BackgroundEvent {
 /// Filtering happens in two layers:
 /// 1. Provider and source filtering at the backend (only interactive CLI sessions
 ///    for the current model provider).
-/// 2. Working-directory filtering at the picker (unless `--all` is passed).
+/// 2. Working-directory filtering at the picker (unless `--all` is passed),
+///    plus the `key:value` filters typed into the search box (see
+///    `SearchFilters`).
+///
+/// Rows can be marked and archived or deleted from the picker. `active_path`
+/// is the rollout of the session the picker was opened from, which is never
+/// removed.
 pub async fn run_resume_picker(
     tui: &mut Tui,
     config: &Config,
     show_all: bool,
+    active_path: Option<PathBuf>,
 ) -> Result<SessionSelection> {
-    run_session_picker(tui, config, show_all, SessionPickerAction::Resume).await
+    run_session_picker(
+        tui,
+        config,
+        show_all,
+        SessionPickerAction::Resume,
+        active_path,
+    )
+    .await
 }
 
 pub async fn run_fork_picker(
@@ -124,7 +196,7 @@ pub async fn run_fork_picker(
     config: &Config,
     show_all: bool,
 ) -> Result<SessionSelection> {
-    run_session_picker(tui, config, show_all, SessionPickerAction::Fork).await
+    run_session_picker(tui, config, show_all, SessionPickerAction::Fork, None).await
 }
 
 async fn run_session_picker(
@@ -132,6 +204,7 @@ async fn run_session_picker(
     config: &Config,
     show_all: bool,
     action: SessionPickerAction,
+    active_path: Option<PathBuf>,
 ) -> Result<SessionSelection> {
     let alt = AltScreenGuard::enter(tui);
     let (bg_tx, bg_rx) = mpsc::unbounded_channel();
@@ -145,6 +218,7 @@ async fn run_session_picker(
     };
 
     let config = config.clone();
+    let task_config = config.clone();
     let loader_tx = bg_tx.clone();
     let page_loader: PageLoader = Arc::new(move |request: PageLoadRequest| {
         let tx = loader_tx.clone();
@@ -169,15 +243,53 @@ async fn run_session_picker(
         });
     });
 
+    let task_tx = bg_tx.clone();
+    let session_tasks: SessionTaskRunner = Arc::new(move |task: SessionTask| {
+        let tx = task_tx.clone();
+        let config = task_config.clone();
+        tokio::spawn(async move {
+            let event = match task {
+                SessionTask::Preview(path) => {
+                    let preview = load_session_preview(&path).await;
+                    BackgroundEvent::PreviewLoaded { path, preview }
+                }
+                SessionTask::Remove { action, paths } => {
+                    let mut removed = Vec::new();
+                    let mut errors = Vec::new();
+                    for path in paths {
+                        let result = match action {
+                            RemoveAction::Archive => {
+                                archive_session(&config, &path).await.map(|_| ())
+                            }
+                            RemoveAction::Delete => delete_session(&config, &path).await,
+                        };
+                        match result {
+                            Ok(()) => removed.push(path),
+                            Err(err) => errors.push(err.to_string()),
+                        }
+                    }
+                    BackgroundEvent::SessionsRemoved {
+                        action,
+                        removed,
+                        errors,
+                    }
+                }
+            };
+            let _ = tx.send(event);
+        });
+    });
+
     let mut state = PickerState::new(
         codex_home.to_path_buf(),
         alt.tui.frame_requester(),
         page_loader,
+        session_tasks,
         default_provider.clone(),
         show_all,
         filter_cwd,
         action,
     );
+    state.active_path = active_path;
     state.start_initial_load();
     state.request_frame();
 
@@ -198,7 +310,7 @@ async fn run_session_picker(
                     }
                     TuiEvent::Draw => {
                         if let Ok(size) = alt.tui.terminal.size() {
-                            let list_height = size.height.saturating_sub(4) as usize;
+                            let list_height = size.height.saturating_sub(5) as usize;
                             state.update_view_rows(list_height);
                             state.ensure_minimum_rows_for_view(list_height);
                         }
@@ -258,13 +370,171 @@ struct PickerState {
     next_request_token: usize,
     next_search_token: usize,
     page_loader: PageLoader,
+    session_tasks: SessionTaskRunner,
     view_rows: Option<usize>,
     default_provider: String,
     show_all: bool,
     filter_cwd: Option<PathBuf>,
     action: SessionPickerAction,
     sort_key: ThreadSortKey,
+    /// Sort the loaded rows by name instead of the backend's time order.
+    sort_by_name: bool,
     thread_name_cache: HashMap<ThreadId, Option<String>>,
+    filters: SearchFilters,
+    show_preview: bool,
+    previews: HashMap<PathBuf, PreviewState>,
+    marked: HashSet<PathBuf>,
+    pending_removal: Option<PendingRemoval>,
+    status: Option<String>,
+    active_path: Option<PathBuf>,
+}
+
+enum PreviewState {
+    Loading,
+    Loaded(SessionPreview),
+    Failed(String),
+}
+
+/// Archive or delete waiting for the user to confirm with `y`.
+struct PendingRemoval {
+    action: RemoveAction,
+    paths: Vec<PathBuf>,
+}
+
+/// Search box contents split into free text and `key:value` filters:
+///
+/// - `model:<name>` – the model of the session's latest turn contains `<name>`.
+/// - `branch:<name>` – the git branch contains `<name>`.
+/// - `since:<when>` / `until:<when>` – last updated on/after or before
+///   `<when>`, either a local date (`2025-01-31`) or an age such as `12h`,
+///   `3d` or `2w`.
+/// - `has:diff` – the session changed files with `apply_patch`.
+/// - `note:<text>` – a note saved with `/note` contains `<text>`.
+///
+/// Tokens that are not a recognized filter are searched for as text.
+#[derive(Debug, Default, PartialEq)]
+struct SearchFilters {
+    text: String,
+    model: Option<String>,
+    branch: Option<String>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    has_diff: bool,
+    note: Option<String>,
+}
+
+impl SearchFilters {
+    fn parse(query: &str, now: DateTime<Utc>) -> Self {
+        let mut filters = Self::default();
+        let mut text = Vec::new();
+        for token in query.split_whitespace() {
+            let parsed = match token.split_once(':') {
+                Some(("model", value)) if !value.is_empty() => {
+                    filters.model = Some(value.to_lowercase());
+                    true
+                }
+                Some(("branch", value)) if !value.is_empty() => {
+                    filters.branch = Some(value.to_lowercase());
+                    true
+                }
+                Some(("since", value)) => parse_date_filter(value, now, false)
+                    .map(|since| filters.since = Some(since))
+                    .is_some(),
+                Some(("until", value)) => parse_date_filter(value, now, true)
+                    .map(|until| filters.until = Some(until))
+                    .is_some(),
+                Some(("has", "diff")) => {
+                    filters.has_diff = true;
+                    true
+                }
+                Some(("note", value)) if !value.is_empty() => {
+                    filters.note = Some(value.to_lowercase());
+                    true
+                }
+                _ => false,
+            };
+            if !parsed {
+                text.push(token);
+            }
+        }
+        filters.text = text.join(" ").to_lowercase();
+        filters
+    }
+
+    /// Whether matching needs the session details read from the rollout.
+    fn needs_preview(&self) -> bool {
+        self.model.is_some() || self.has_diff || self.note.is_some()
+    }
+
+    fn matches(&self, row: &Row, preview: Option<&SessionPreview>) -> bool {
+        if !self.text.is_empty() && !row.matches_query(&self.text) {
+            return false;
+        }
+        if let Some(branch) = self.branch.as_deref()
+            && !row
+                .git_branch
+                .as_deref()
+                .is_some_and(|row_branch| row_branch.to_lowercase().contains(branch))
+        {
+            return false;
+        }
+        let updated_at = row.updated_at.or(row.created_at);
+        if let Some(since) = self.since
+            && !updated_at.is_some_and(|ts| ts >= since)
+        {
+            return false;
+        }
+        if let Some(until) = self.until
+            && !updated_at.is_some_and(|ts| ts < until)
+        {
+            return false;
+        }
+        if !self.needs_preview() {
+            return true;
+        }
+        let Some(preview) = preview else {
+            return false;
+        };
+        if let Some(model) = self.model.as_deref()
+            && !preview
+                .model
+                .as_deref()
+                .is_some_and(|row_model| row_model.to_lowercase().contains(model))
+        {
+            return false;
+        }
+        if let Some(note) = self.note.as_deref()
+            && !preview
+                .notes
+                .iter()
+                .any(|row_note| row_note.to_lowercase().contains(note))
+        {
+            return false;
+        }
+        !self.has_diff || preview.has_diff
+    }
+}
+
+/// A local date (start of day, or start of the next day for `until`) or an
+/// age like `3d` counted back from `now`.
+fn parse_date_filter(value: &str, now: DateTime<Utc>, end_of_day: bool) -> Option<DateTime<Utc>> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let date = if end_of_day { date.succ_opt()? } else { date };
+        return date
+            .and_hms_opt(0, 0, 0)?
+            .and_local_timezone(Local)
+            .earliest()
+            .map(|ts| ts.with_timezone(&Utc));
+    }
+    let unit = value.chars().last()?;
+    let amount: i64 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    let age = match unit {
+        'h' => chrono::Duration::try_hours(amount)?,
+        'd' => chrono::Duration::try_days(amount)?,
+        'w' => chrono::Duration::try_weeks(amount)?,
+        _ => return None,
+    };
+    now.checked_sub_signed(age)
 }
 
 struct PaginationState {
@@ -326,6 +596,8 @@ struct Row {
     updated_at: Option<DateTime<Utc>>,
     cwd: Option<PathBuf>,
     git_branch: Option<String>,
+    /// Events dropped when the rollout was salvaged after a crash.
+    recovered_lost_events: Option<usize>,
 }
 
 impl Row {
@@ -347,10 +619,12 @@ impl Row {
 }
 
 impl PickerState {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         codex_home: PathBuf,
         requester: FrameRequester,
         page_loader: PageLoader,
+        session_tasks: SessionTaskRunner,
         default_provider: String,
         show_all: bool,
         filter_cwd: Option<PathBuf>,
@@ -375,13 +649,22 @@ impl PickerState {
             next_request_token: 0,
             next_search_token: 0,
             page_loader,
+            session_tasks,
             view_rows: None,
             default_provider,
             show_all,
             filter_cwd,
             action,
             sort_key: ThreadSortKey::CreatedAt,
+            sort_by_name: false,
             thread_name_cache: HashMap::new(),
+            filters: SearchFilters::default(),
+            show_preview: true,
+            previews: HashMap::new(),
+            marked: HashSet::new(),
+            pending_removal: None,
+            status: None,
+            active_path: None,
         }
     }
 
@@ -390,6 +673,14 @@ impl PickerState {
     }
 
     async fn handle_key(&mut self, key: KeyEvent) -> Result<Option<SessionSelection>> {
+        self.status = None;
+        if let Some(pending) = self.pending_removal.take() {
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.start_removal(pending);
+            }
+            self.request_frame();
+            return Ok(None);
+        }
         match key.code {
             KeyCode::Esc => return Ok(Some(SessionSelection::StartFresh)),
             KeyCode::Char('c')
@@ -441,6 +732,35 @@ impl PickerState {
                 self.toggle_sort_key();
                 self.request_frame();
             }
+            KeyCode::Char('t')
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                self.toggle_mark();
+            }
+            KeyCode::Char('a')
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                self.request_removal(RemoveAction::Archive);
+            }
+            KeyCode::Char('d')
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                self.request_removal(RemoveAction::Delete);
+            }
+            KeyCode::Char('p')
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                self.show_preview = !self.show_preview;
+                self.request_frame();
+            }
             KeyCode::Backspace => {
                 let mut new_query = self.query.clone();
                 new_query.pop();
@@ -460,9 +780,131 @@ impl PickerState {
             }
             _ => {}
         }
+        self.load_previews_if_needed();
         Ok(None)
     }
 
+    /// Mark or unmark the selected row for archiving/deleting and move on to
+    /// the next one.
+    fn toggle_mark(&mut self) {
+        let Some(row) = self.filtered_rows.get(self.selected) else {
+            return;
+        };
+        if !self.marked.remove(&row.path) {
+            self.marked.insert(row.path.clone());
+        }
+        if self.selected + 1 < self.filtered_rows.len() {
+            self.selected += 1;
+            self.ensure_selected_visible();
+        }
+        self.request_frame();
+    }
+
+    /// Ask to archive or delete the marked rows, or the selected row when
+    /// nothing is marked. The session the picker was opened from is skipped.
+    fn request_removal(&mut self, action: RemoveAction) {
+        let mut paths: Vec<PathBuf> = if self.marked.is_empty() {
+            self.filtered_rows
+                .get(self.selected)
+                .map(|row| row.path.clone())
+                .into_iter()
+                .collect()
+        } else {
+            self.all_rows
+                .iter()
+                .filter(|row| self.marked.contains(&row.path))
+                .map(|row| row.path.clone())
+                .collect()
+        };
+        let before = paths.len();
+        if let Some(active) = self.active_path.as_deref() {
+            paths.retain(|path| !paths_match(path, active));
+        }
+        if paths.is_empty() {
+            if before > 0 {
+                self.status = Some(String::from("The current session cannot be removed"));
+            }
+            self.request_frame();
+            return;
+        }
+        self.pending_removal = Some(PendingRemoval { action, paths });
+        self.request_frame();
+    }
+
+    fn start_removal(&mut self, pending: PendingRemoval) {
+        let PendingRemoval { action, paths } = pending;
+        self.status = Some(format!(
+            "{}…",
+            match action {
+                RemoveAction::Archive => "Archiving",
+                RemoveAction::Delete => "Deleting",
+            }
+        ));
+        (self.session_tasks)(SessionTask::Remove { action, paths });
+    }
+
+    fn handle_sessions_removed(
+        &mut self,
+        action: RemoveAction,
+        removed: Vec<PathBuf>,
+        errors: Vec<String>,
+    ) {
+        let removed_paths: HashSet<PathBuf> = removed.into_iter().collect();
+        self.all_rows
+            .retain(|row| !removed_paths.contains(&row.path));
+        for path in &removed_paths {
+            self.marked.remove(path);
+            self.previews.remove(path);
+        }
+        let mut status = format!("{} {} session(s)", action.past_tense(), removed_paths.len());
+        if let Some(error) = errors.first() {
+            status.push_str(&format!("; {} failed: {error}", errors.len()));
+        }
+        self.status = Some(status);
+        self.apply_filter();
+    }
+
+    /// Request session details for the selected row while the preview pane is
+    /// shown, and for every loaded row while a filter depends on them.
+    fn load_previews_if_needed(&mut self) {
+        let mut paths = Vec::new();
+        if self.filters.needs_preview() {
+            paths.extend(self.all_rows.iter().map(|row| row.path.clone()));
+        } else if self.show_preview
+            && let Some(row) = self.filtered_rows.get(self.selected)
+        {
+            paths.push(row.path.clone());
+        }
+        for path in paths {
+            if self.previews.contains_key(&path) {
+                continue;
+            }
+            self.previews.insert(path.clone(), PreviewState::Loading);
+            (self.session_tasks)(SessionTask::Preview(path));
+        }
+    }
+
+    fn loaded_preview(&self, path: &Path) -> Option<&SessionPreview> {
+        match self.previews.get(path) {
+            Some(PreviewState::Loaded(preview)) => Some(preview),
+            _ => None,
+        }
+    }
+
+    fn previews_pending(&self) -> bool {
+        self.previews
+            .values()
+            .any(|preview| matches!(preview, PreviewState::Loading))
+    }
+
+    fn sort_label(&self) -> &'static str {
+        if self.sort_by_name {
+            "Name"
+        } else {
+            sort_key_label(self.sort_key)
+        }
+    }
+
     fn start_initial_load(&mut self) {
         self.reset_pagination();
         self.all_rows.clear();
@@ -516,6 +958,23 @@ impl PickerState {
                 let completed_token = pending.search_token.or(search_token);
                 self.continue_search_if_token_matches(completed_token);
             }
+            BackgroundEvent::PreviewLoaded { path, preview } => {
+                let preview = match preview {
+                    Ok(preview) => PreviewState::Loaded(preview),
+                    Err(err) => PreviewState::Failed(err.to_string()),
+                };
+                self.previews.insert(path, preview);
+                if self.filters.needs_preview() {
+                    self.apply_filter();
+                } else {
+                    self.request_frame();
+                }
+            }
+            BackgroundEvent::SessionsRemoved {
+                action,
+                removed,
+                errors,
+            } => self.handle_sessions_removed(action, removed, errors),
         }
         Ok(())
     }
@@ -594,15 +1053,17 @@ impl PickerState {
     }
 
     fn apply_filter(&mut self) {
-        let base_iter = self
+        self.filters = SearchFilters::parse(&self.query, Utc::now());
+        self.filtered_rows = self
             .all_rows
             .iter()
-            .filter(|row| self.row_matches_filter(row));
-        if self.query.is_empty() {
-            self.filtered_rows = base_iter.cloned().collect();
-        } else {
-            let q = self.query.to_lowercase();
-            self.filtered_rows = base_iter.filter(|r| r.matches_query(&q)).cloned().collect();
+            .filter(|row| self.row_matches_filter(row))
+            .filter(|row| self.filters.matches(row, self.loaded_preview(&row.path)))
+            .cloned()
+            .collect();
+        if self.sort_by_name {
+            self.filtered_rows
+                .sort_by_cached_key(|row| row.display_preview().to_lowercase());
         }
         if self.selected >= self.filtered_rows.len() {
             self.selected = self.filtered_rows.len().saturating_sub(1);
@@ -611,6 +1072,7 @@ impl PickerState {
             self.scroll_top = 0;
         }
         self.ensure_selected_visible();
+        self.load_previews_if_needed();
         self.request_frame();
     }
 
@@ -777,17 +1239,30 @@ impl PickerState {
         token
     }
 
-    /// Cycles the sort order between creation time and last-updated time.
+    /// Cycles the sort order between creation time, last-updated time and
+    /// name.
     ///
-    /// Triggers a full reload because the backend must re-sort all sessions.
-    /// The existing `all_rows` are cleared and pagination restarts from the
-    /// beginning with the new sort key.
+    /// Switching the time key triggers a full reload because the backend must
+    /// re-sort all sessions. The existing `all_rows` are cleared and
+    /// pagination restarts from the beginning with the new sort key. Sorting
+    /// by name reorders the rows loaded so far (fetched by last-updated time).
     fn toggle_sort_key(&mut self) {
-        self.sort_key = match self.sort_key {
-            ThreadSortKey::CreatedAt => ThreadSortKey::UpdatedAt,
-            ThreadSortKey::UpdatedAt => ThreadSortKey::CreatedAt,
-        };
-        self.start_initial_load();
+        match (self.sort_key, self.sort_by_name) {
+            (ThreadSortKey::CreatedAt, _) => {
+                self.sort_key = ThreadSortKey::UpdatedAt;
+                self.start_initial_load();
+            }
+            (ThreadSortKey::UpdatedAt, false) => {
+                self.sort_by_name = true;
+                self.selected = 0;
+                self.apply_filter();
+            }
+            (ThreadSortKey::UpdatedAt, true) => {
+                self.sort_by_name = false;
+                self.sort_key = ThreadSortKey::CreatedAt;
+                self.start_initial_load();
+            }
+        }
     }
 }
 
@@ -820,6 +1295,7 @@ fn head_to_row(item: &ThreadItem) -> Row {
         updated_at,
         cwd: item.cwd.clone(),
         git_branch: item.git_branch.clone(),
+        recovered_lost_events: rollout_lost_events(&item.path).map(|lost| lost.lost_events),
     }
 }
 
@@ -844,12 +1320,12 @@ fn draw_picker(tui: &mut Tui, state: &PickerState) -> std::io::Result<()> {
     let height = tui.terminal.size()?.height;
     tui.draw(height, |frame| {
         let area = frame.area();
-        let [header, search, columns, list, hint] = Layout::vertical([
-            Constraint::Length(1),
+        let [header, search, body, hint, actions] = Layout::vertical([
             Constraint::Length(1),
             Constraint::Length(1),
             Constraint::Min(area.height.saturating_sub(4)),
             Constraint::Length(1),
+            Constraint::Length(1),
         ])
         .areas(area);
 
@@ -859,24 +1335,43 @@ fn draw_picker(tui: &mut Tui, state: &PickerState) -> std::io::Result<()> {
             "  ".into(),
             "Sort:".dim(),
             " ".into(),
-            sort_key_label(state.sort_key).magenta(),
+            state.sort_label().magenta(),
         ]
         .into();
         frame.render_widget_ref(header_line, header);
 
         // Search line
         let q = if state.query.is_empty() {
-            "Type to search".dim().to_string()
+            "Type to search (filters: model: branch: since: until: has:diff note:)"
+                .dim()
+                .to_string()
         } else {
             format!("Search: {}", state.query)
         };
         frame.render_widget_ref(Line::from(q), search);
 
+        let (table, preview) = if state.show_preview && body.width >= PREVIEW_MIN_WIDTH {
+            let [table, preview] =
+                Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+                    .areas(body);
+            (table, Some(preview))
+        } else {
+            (body, None)
+        };
+        let [columns, list] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(table);
+
         let metrics = calculate_column_metrics(&state.filtered_rows, state.show_all);
 
         // Column headers and list
         render_column_headers(frame, columns, &metrics, state.sort_key);
         render_list(frame, list, state, &metrics);
+        if let Some(preview) = preview {
+            frame.render_widget_ref(
+                Paragraph::new(preview_lines(state)).wrap(Wrap { trim: false }),
+                preview.inner(Margin::new(1, 0)),
+            );
+        }
 
         // Hint line
         let action_label = state.action.action_label();
@@ -900,9 +1395,123 @@ fn draw_picker(tui: &mut Tui, state: &PickerState) -> std::io::Result<()> {
         ]
         .into();
         frame.render_widget_ref(hint_line, hint);
+        frame.render_widget_ref(actions_line(state), actions);
     })
 }
 
+/// Second hint row: removal confirmation, the last status message, or the
+/// mark/archive/delete/preview keys.
+fn actions_line(state: &PickerState) -> Line<'static> {
+    if let Some(pending) = state.pending_removal.as_ref() {
+        let question = format!(
+            "{} {} session(s)? ",
+            pending.action.verb(),
+            pending.paths.len()
+        );
+        let question = match pending.action {
+            RemoveAction::Archive => question.bold(),
+            RemoveAction::Delete => question.bold().red(),
+        };
+        return vec![
+            question,
+            "Press ".dim(),
+            "y".bold(),
+            " to confirm, any other key to cancel".dim(),
+        ]
+        .into();
+    }
+    if let Some(status) = state.status.as_ref() {
+        return vec![Span::from(status.clone()).italic()].into();
+    }
+    let mut spans: Vec<Span<'static>> = vec![
+        key_hint::ctrl(KeyCode::Char('t')).into(),
+        " to mark ".dim(),
+        "    ".dim(),
+        key_hint::ctrl(KeyCode::Char('a')).into(),
+        " to archive ".dim(),
+        "    ".dim(),
+        key_hint::ctrl(KeyCode::Char('d')).into(),
+        " to delete ".dim(),
+        "    ".dim(),
+        key_hint::ctrl(KeyCode::Char('p')).into(),
+        " to toggle preview".dim(),
+    ];
+    if !state.marked.is_empty() {
+        spans.push("    ".dim());
+        spans.push(format!("{} marked", state.marked.len()).magenta());
+    }
+    spans.into()
+}
+
+/// Preview pane contents for the selected row.
+fn preview_lines(state: &PickerState) -> Vec<Line<'static>> {
+    let Some(row) = state.filtered_rows.get(state.selected) else {
+        return Vec::new();
+    };
+    let title = row
+        .thread_name
+        .clone()
+        .unwrap_or_else(|| String::from("Preview"));
+    let mut lines: Vec<Line<'static>> = vec![title.bold().into()];
+    match state.previews.get(&row.path) {
+        None | Some(PreviewState::Loading) => {
+            lines.push(Line::default());
+            lines.push("Loading…".italic().dim().into());
+        }
+        Some(PreviewState::Failed(err)) => {
+            lines.push(Line::default());
+            lines.push(format!("Could not read session: {err}").red().into());
+        }
+        Some(PreviewState::Loaded(preview)) => {
+            push_preview_bullets(&mut lines, "Summary", &preview.summary);
+            push_preview_section(&mut lines, "First prompt", preview.first_prompt.as_deref());
+            push_preview_section(
+                &mut lines,
+                "Latest reply",
+                preview.last_agent_message.as_deref(),
+            );
+            push_preview_bullets(&mut lines, "Notes", &preview.notes);
+            lines.push(Line::default());
+            let model = preview.model.as_deref().unwrap_or("-");
+            let changed = if preview.has_diff { "yes" } else { "no" };
+            lines.push(
+                format!("Model: {model}  ·  Changed files: {changed}")
+                    .dim()
+                    .into(),
+            );
+        }
+    }
+    lines
+}
+
+fn push_preview_section(lines: &mut Vec<Line<'static>>, label: &'static str, text: Option<&str>) {
+    lines.push(Line::default());
+    lines.push(label.dim().into());
+    match text.map(str::trim).filter(|text| !text.is_empty()) {
+        Some(text) => lines.extend(
+            truncate_text(text, PREVIEW_GRAPHEMES)
+                .lines()
+                .map(|line| Line::from(line.to_string())),
+        ),
+        None => lines.push("-".dim().into()),
+    }
+}
+
+/// A labelled bulleted section; nothing when `items` is empty.
+fn push_preview_bullets(lines: &mut Vec<Line<'static>>, label: &'static str, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    lines.push(Line::default());
+    lines.push(label.dim().into());
+    lines.extend(items.iter().map(|item| {
+        Line::from(format!(
+            "• {}",
+            truncate_text(item.trim(), PREVIEW_GRAPHEMES)
+        ))
+    }));
+}
+
 fn render_list(
     frame: &mut crate::custom_terminal::Frame,
     area: Rect,
@@ -938,7 +1547,12 @@ fn render_list(
         .enumerate()
     {
         let is_sel = start + idx == state.selected;
-        let marker = if is_sel { "> ".bold() } else { "  ".into() };
+        let marker = match (is_sel, state.marked.contains(&row.path)) {
+            (true, true) => ">*".bold(),
+            (true, false) => "> ".bold(),
+            (false, true) => " *".magenta(),
+            (false, false) => "  ".into(),
+        };
         let marker_width = 2usize;
         let created_span = if visibility.show_created {
             Some(Span::from(format!("{created_label:<max_created_width$}")).dim())
@@ -1000,6 +1614,11 @@ fn render_list(
         if add_leading_gap {
             preview_width = preview_width.saturating_sub(2);
         }
+        let recovered_span = row.recovered_lost_events.map(|lost| {
+            let label = recovered_label(lost);
+            preview_width = preview_width.saturating_sub(label.chars().count() + 1);
+            Span::from(label).red()
+        });
         let preview = truncate_text(row.display_preview(), preview_width);
         let mut spans: Vec<Span> = vec![marker];
         if let Some(created) = created_span {
@@ -1021,6 +1640,10 @@ fn render_list(
         if add_leading_gap {
             spans.push("  ".into());
         }
+        if let Some(recovered) = recovered_span {
+            spans.push(recovered);
+            spans.push(" ".into());
+        }
         spans.push(preview.into());
 
         let line: Line = spans.into();
@@ -1036,7 +1659,15 @@ fn render_list(
     }
 }
 
+fn recovered_label(lost_events: usize) -> String {
+    let noun = if lost_events == 1 { "event" } else { "events" };
+    format!("recovered session (last {lost_events} {noun} lost)")
+}
+
 fn render_empty_state_line(state: &PickerState) -> Line<'static> {
+    if state.filters.needs_preview() && state.previews_pending() {
+        return vec!["Loading session details…".italic().dim()].into();
+    }
     if !state.query.is_empty() {
         if state.search_state.is_active()
             || (state.pagination.loading.is_pending() && state.pagination.next_cursor.is_some())
@@ -1478,6 +2109,37 @@ mod tests {
         assert_eq!(row.preview, "real question");
     }
 
+    #[test]
+    fn head_to_row_reports_recovered_and_torn_rollouts() {
+        let tempdir = tempfile::tempdir().expect("tempdir");
+        let path = tempdir.path().join("rollout.jsonl");
+        std::fs::write(&path, "{\"type\":\"session_meta\"}\n{\"type\":\"resp")
+            .expect("write torn rollout");
+        std::fs::write(
+            tempdir.path().join("rollout.jsonl.recovery"),
+            r#"{"lost_events":3}"#,
+        )
+        .expect("write recovery note");
+        let item = ThreadItem {
+            path,
+            thread_id: None,
+            first_user_message: Some("real question".to_string()),
+            cwd: None,
+            git_branch: None,
+            git_sha: None,
+            git_origin_url: None,
+            source: None,
+            model_provider: None,
+            cli_version: None,
+            created_at: Some("2025-01-01T00:00:00Z".into()),
+            updated_at: Some("2025-01-01T00:00:00Z".into()),
+        };
+        let row = head_to_row(&item);
+        assert_eq!(row.recovered_lost_events, Some(4));
+        assert_eq!(recovered_label(3), "recovered session (last 3 events lost)");
+        assert_eq!(recovered_label(1), "recovered session (last 1 event lost)");
+    }
+
     #[test]
     fn rows_from_items_preserves_backend_order() {
         // Construct two items with different timestamps and real user text.
@@ -1556,6 +2218,7 @@ mod tests {
             updated_at: None,
             cwd: None,
             git_branch: None,
+            recovered_lost_events: None,
         };
 
         assert_eq!(row.display_preview(), "My session");
@@ -1573,6 +2236,7 @@ mod tests {
             PathBuf::from("/tmp"),
             FrameRequester::test_dummy(),
             loader,
+            Arc::new(|_| {}),
             String::from("openai"),
             true,
             None,
@@ -1590,6 +2254,7 @@ mod tests {
                 updated_at: Some(now - Duration::seconds(42)),
                 cwd: None,
                 git_branch: None,
+                recovered_lost_events: None,
             },
             Row {
                 path: PathBuf::from("/tmp/b.jsonl"),
@@ -1600,6 +2265,7 @@ mod tests {
                 updated_at: Some(now - Duration::minutes(35)),
                 cwd: None,
                 git_branch: None,
+                recovered_lost_events: None,
             },
             Row {
                 path: PathBuf::from("/tmp/c.jsonl"),
@@ -1610,6 +2276,7 @@ mod tests {
                 updated_at: Some(now - Duration::hours(2)),
                 cwd: None,
                 git_branch: None,
+                recovered_lost_events: None,
             },
         ];
         state.all_rows = rows.clone();
@@ -1729,6 +2396,7 @@ mod tests {
     //         PathBuf::from("/tmp"),
     //         FrameRequester::test_dummy(),
     //         loader,
+    //         Arc::new(|_| {}),
     //         String::from("openai"),
     //         true,
     //         None,
@@ -1851,6 +2519,7 @@ mod tests {
             tempdir.path().to_path_buf(),
             FrameRequester::test_dummy(),
             loader,
+            Arc::new(|_| {}),
             String::from("openai"),
             true,
             None,
@@ -1868,6 +2537,7 @@ mod tests {
                 updated_at: Some(now - Duration::days(2)),
                 cwd: None,
                 git_branch: None,
+                recovered_lost_events: None,
             },
             Row {
                 path: PathBuf::from("/tmp/b.jsonl"),
@@ -1878,6 +2548,7 @@ mod tests {
                 updated_at: Some(now - Duration::days(3)),
                 cwd: None,
                 git_branch: None,
+                recovered_lost_events: None,
             },
         ];
         state.all_rows = rows.clone();
@@ -1918,6 +2589,7 @@ mod tests {
             PathBuf::from("/tmp"),
             FrameRequester::test_dummy(),
             loader,
+            Arc::new(|_| {}),
             String::from("openai"),
             true,
             None,
@@ -1987,6 +2659,7 @@ mod tests {
             PathBuf::from("/tmp"),
             FrameRequester::test_dummy(),
             loader,
+            Arc::new(|_| {}),
             String::from("openai"),
             true,
             None,
@@ -2068,6 +2741,7 @@ mod tests {
             PathBuf::from("/tmp"),
             FrameRequester::test_dummy(),
             loader,
+            Arc::new(|_| {}),
             String::from("openai"),
             true,
             None,
@@ -2098,6 +2772,7 @@ mod tests {
             PathBuf::from("/tmp"),
             FrameRequester::test_dummy(),
             loader,
+            Arc::new(|_| {}),
             String::from("openai"),
             true,
             None,
@@ -2143,6 +2818,7 @@ mod tests {
             PathBuf::from("/tmp"),
             FrameRequester::test_dummy(),
             loader,
+            Arc::new(|_| {}),
             String::from("openai"),
             true,
             None,
@@ -2188,6 +2864,7 @@ mod tests {
             PathBuf::from("/tmp"),
             FrameRequester::test_dummy(),
             loader,
+            Arc::new(|_| {}),
             String::from("openai"),
             true,
             None,
@@ -2293,4 +2970,220 @@ mod tests {
         assert!(!state.search_state.is_active());
         assert!(state.pagination.reached_scan_cap);
     }
+
+    fn branch_row(path: &str, preview: &str, branch: &str, updated_at: DateTime<Utc>) -> Row {
+        Row {
+            path: PathBuf::from(path),
+            preview: preview.to_string(),
+            thread_id: None,
+            thread_name: None,
+            created_at: Some(updated_at),
+            updated_at: Some(updated_at),
+            cwd: None,
+            git_branch: Some(branch.to_string()),
+            recovered_lost_events: None,
+        }
+    }
+
+    #[test]
+    fn search_filters_split_text_from_filters() {
+        let now = Utc::now();
+        let filters =
+            SearchFilters::parse("Fix model:GPT-5 login branch:main since:3d has:diff", now);
+        let noted = SearchFilters::parse("note:Staging", now);
+        assert_eq!(noted.note, Some("staging".to_string()));
+        assert_eq!(
+            filters,
+            SearchFilters {
+                text: "fix login".to_string(),
+                model: Some("gpt-5".to_string()),
+                branch: Some("main".to_string()),
+                since: Some(now - Duration::days(3)),
+                until: None,
+                has_diff: true,
+                note: None,
+            }
+        );
+        assert_eq!(
+            SearchFilters::parse("since:soon", now).text,
+            "since:soon".to_string()
+        );
+
+        let row = branch_row(
+            "/tmp/a.jsonl",
+            "Fix login redirect",
+            "main",
+            now - Duration::days(1),
+        );
+        let preview = SessionPreview {
+            model: Some("gpt-5-codex".to_string()),
+            has_diff: true,
+            ..SessionPreview::default()
+        };
+        assert!(filters.matches(&row, Some(&preview)));
+        assert!(!filters.matches(&row, None));
+        assert!(!filters.matches(
+            &row,
+            Some(&SessionPreview {
+                has_diff: false,
+                ..preview.clone()
+            })
+        ));
+        let note_preview = SessionPreview {
+            notes: vec!["Deploy to STAGING first".to_string()],
+            ..SessionPreview::default()
+        };
+        assert!(noted.matches(&row, Some(&note_preview)));
+        assert!(!noted.matches(&row, Some(&preview)));
+        let old_row = branch_row(
+            "/tmp/b.jsonl",
+            "Fix login redirect",
+            "main",
+            now - Duration::days(5),
+        );
+        assert!(!filters.matches(&old_row, Some(&preview)));
+    }
+
+    #[tokio::test]
+    async fn marked_rows_are_removed_after_confirmation() {
+        let recorded_tasks: Arc<Mutex<Vec<(RemoveAction, Vec<PathBuf>)>>> =
+            Arc::new(Mutex::new(Vec::new()));
+        let task_sink = recorded_tasks.clone();
+        let session_tasks: SessionTaskRunner = Arc::new(move |task: SessionTask| {
+            if let SessionTask::Remove { action, paths } = task {
+                task_sink.lock().unwrap().push((action, paths));
+            }
+        });
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state = PickerState::new(
+            PathBuf::from("/tmp"),
+            FrameRequester::test_dummy(),
+            loader,
+            session_tasks,
+            String::from("openai"),
+            true,
+            None,
+            SessionPickerAction::Resume,
+        );
+        let now = Utc::now();
+        state.all_rows = vec![
+            branch_row("/tmp/a.jsonl", "first", "main", now),
+            branch_row("/tmp/b.jsonl", "second", "main", now),
+            branch_row("/tmp/c.jsonl", "current", "main", now),
+        ];
+        state.active_path = Some(PathBuf::from("/tmp/c.jsonl"));
+        state.apply_filter();
+
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        state.handle_key(ctrl('t')).await.unwrap();
+        state.handle_key(ctrl('t')).await.unwrap();
+        state.handle_key(ctrl('t')).await.unwrap();
+        state.handle_key(ctrl('d')).await.unwrap();
+        assert_eq!(
+            state
+                .pending_removal
+                .as_ref()
+                .map(|pending| pending.paths.clone()),
+            Some(vec![
+                PathBuf::from("/tmp/a.jsonl"),
+                PathBuf::from("/tmp/b.jsonl")
+            ])
+        );
+
+        state
+            .handle_key(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE))
+            .await
+            .unwrap();
+        assert_eq!(
+            recorded_tasks.lock().unwrap().clone(),
+            vec![(
+                RemoveAction::Delete,
+                vec![PathBuf::from("/tmp/a.jsonl"), PathBuf::from("/tmp/b.jsonl")]
+            )]
+        );
+        assert_eq!(state.query, String::new());
+
+        state
+            .handle_background_event(BackgroundEvent::SessionsRemoved {
+                action: RemoveAction::Delete,
+                removed: vec![PathBuf::from("/tmp/a.jsonl"), PathBuf::from("/tmp/b.jsonl")],
+                errors: Vec::new(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            state
+                .filtered_rows
+                .iter()
+                .map(|row| row.path.clone())
+                .collect::<Vec<_>>(),
+            vec![PathBuf::from("/tmp/c.jsonl")]
+        );
+        assert!(state.marked.contains(&PathBuf::from("/tmp/c.jsonl")));
+        assert_eq!(state.status.as_deref(), Some("Deleted 2 session(s)"));
+    }
+
+    #[test]
+    fn preview_pane_shows_first_prompt_and_latest_reply() {
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state = PickerState::new(
+            PathBuf::from("/tmp"),
+            FrameRequester::test_dummy(),
+            loader,
+            Arc::new(|_| {}),
+            String::from("openai"),
+            true,
+            None,
+            SessionPickerAction::Resume,
+        );
+        let row = branch_row("/tmp/a.jsonl", "Add tests", "main", Utc::now());
+        state.previews.insert(
+            row.path.clone(),
+            PreviewState::Loaded(SessionPreview {
+                first_prompt: Some("Add tests\nfor the parser".to_string()),
+                last_agent_message: Some("Added 3 tests.".to_string()),
+                model: Some("gpt-5".to_string()),
+                has_diff: true,
+                notes: vec!["Parser tests cover the happy path only".to_string()],
+                summary: vec![
+                    "Added parser tests".to_string(),
+                    "Error recovery is still untested".to_string(),
+                ],
+            }),
+        );
+        state.all_rows = vec![row];
+        state.apply_filter();
+
+        let text: Vec<String> = preview_lines(&state)
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect();
+        assert_eq!(
+            text,
+            vec![
+                "Preview".to_string(),
+                String::new(),
+                "Summary".to_string(),
+                "• Added parser tests".to_string(),
+                "• Error recovery is still untested".to_string(),
+                String::new(),
+                "First prompt".to_string(),
+                "Add tests".to_string(),
+                "for the parser".to_string(),
+                String::new(),
+                "Latest reply".to_string(),
+                "Added 3 tests.".to_string(),
+                String::new(),
+                "Notes".to_string(),
+                "• Parser tests cover the happy path only".to_string(),
+                String::new(),
+                "Model: gpt-5  ·  Changed files: yes".to_string(),
+            ]
+        );
+    }
 }
@@ -0,0 +1,98 @@
+//! Publishes this session's state for `codex status --porcelain`.
+//!
+//! The chat widget reports a [`StatusSnapshot`] after every event; only real
+//! changes reach the background task, which looks up the git branch and
+//! rewrites `$CODEX_HOME/status/<pid>.json`. The file is removed when the
+//! publisher is dropped.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use codex_core::git_info::current_branch_name;
+use codex_core::session_status::SessionActivity;
+use codex_core::session_status::SessionStatus;
+use codex_core::session_status::remove_status;
+use codex_core::session_status::write_status;
+use codex_protocol::ThreadId;
+use tokio::sync::watch;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StatusSnapshot {
+    pub(crate) thread_id: Option<ThreadId>,
+    pub(crate) cwd: PathBuf,
+    pub(crate) model: String,
+    pub(crate) activity: SessionActivity,
+    pub(crate) tokens_used: i64,
+}
+
+pub(crate) struct SessionStatusPublisher {
+    codex_home: PathBuf,
+    tx: watch::Sender<Option<StatusSnapshot>>,
+    closed: Arc<AtomicBool>,
+}
+
+impl SessionStatusPublisher {
+    pub(crate) fn start(codex_home: PathBuf) -> Self {
+        let (tx, mut rx) = watch::channel(None::<StatusSnapshot>);
+        let closed = Arc::new(AtomicBool::new(false));
+        let task_home = codex_home.clone();
+        let task_closed = closed.clone();
+        tokio::spawn(async move {
+            let mut branch: Option<(PathBuf, Option<String>)> = None;
+            while rx.changed().await.is_ok() {
+                let Some(snapshot) = rx.borrow_and_update().clone() else {
+                    continue;
+                };
+                // Turns can switch branches, so look again whenever one ends.
+                let stale = branch.as_ref().is_none_or(|(cwd, _)| *cwd != snapshot.cwd)
+                    || snapshot.activity == SessionActivity::Idle;
+                if stale {
+                    let name = current_branch_name(&snapshot.cwd).await;
+                    branch = Some((snapshot.cwd.clone(), name));
+                }
+                if task_closed.load(Ordering::Acquire) {
+                    return;
+                }
+                let status = SessionStatus {
+                    pid: std::process::id(),
+                    thread_id: snapshot.thread_id.map(|id| id.to_string()),
+                    cwd: snapshot.cwd,
+                    model: snapshot.model,
+                    activity: snapshot.activity,
+                    tokens_used: snapshot.tokens_used,
+                    branch: branch.as_ref().and_then(|(_, name)| name.clone()),
+                    updated_at: chrono::Utc::now().timestamp(),
+                };
+                if let Err(err) = write_status(&task_home, &status) {
+                    tracing::warn!("failed to write session status: {err}");
+                }
+            }
+        });
+        Self {
+            codex_home,
+            tx,
+            closed,
+        }
+    }
+
+    pub(crate) fn update(&self, snapshot: StatusSnapshot) {
+        self.tx.send_if_modified(|current| {
+            if current.as_ref() == Some(&snapshot) {
+                return false;
+            }
+            *current = Some(snapshot);
+            true
+        });
+    }
+}
+
+impl Drop for SessionStatusPublisher {
+    fn drop(&mut self) {
+        self.closed.store(true, Ordering::Release);
+        if let Err(err) = remove_status(&self.codex_home, std::process::id()) {
+            tracing::warn!("failed to remove session status: {err}");
+        }
+    }
+}
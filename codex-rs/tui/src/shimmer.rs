@@ -18,6 +18,15 @@ fn elapsed_since_start() -> Duration {
     start.elapsed()
 }
 
+/// Whether animated effects may use RGB colors. `NO_COLOR` forces the
+/// modifier-only fallback even on true-color terminals.
+pub(crate) fn true_color_enabled() -> bool {
+    !codex_core::terminal::no_color_requested()
+        && supports_color::on_cached(supports_color::Stream::Stdout)
+            .map(|level| level.has_16m || codex_core::terminal::terminal_capabilities().truecolor)
+            .unwrap_or(false)
+}
+
 pub(crate) fn shimmer_spans(text: &str) -> Vec<Span<'static>> {
     let chars: Vec<char> = text.chars().collect();
     if chars.is_empty() {
@@ -30,9 +39,7 @@ pub(crate) fn shimmer_spans(text: &str) -> Vec<Span<'static>> {
     let pos_f =
         (elapsed_since_start().as_secs_f32() % sweep_seconds) / sweep_seconds * (period as f32);
     let pos = pos_f as usize;
-    let has_true_color = supports_color::on_cached(supports_color::Stream::Stdout)
-        .map(|level| level.has_16m)
-        .unwrap_or(false);
+    let has_true_color = true_color_enabled();
     let band_half_width = 5.0;
 
     let mut spans: Vec<Span<'static>> = Vec::with_capacity(chars.len());
@@ -25,33 +25,49 @@ pub enum SlashCommand {
     Skills,
     Review,
     Rename,
+    Note,
     New,
     Resume,
     Fork,
+    Branches,
     Init,
     Checkpoint,
     Compact,
+    Compare,
     Plan,
+    Mode,
+    DryRun,
     Collab,
     Agent,
     // Undo,
     Diff,
+    Diagram,
+    Changes,
+    Symbols,
+    Context,
     Mention,
+    Scope,
     Status,
     SddDevelop,
     DebugConfig,
     SddDevelopParallels,
     Statusline,
+    Density,
+    Ui,
     Ps,
     Clean,
+    Follow,
     Mcp,
     Apps,
+    Login,
     Logout,
     Quit,
     Exit,
     Feedback,
     Rollout,
     Personality,
+    Persona,
+    Profile,
     TestApproval,
 }
 
@@ -65,17 +81,26 @@ impl SlashCommand {
             SlashCommand::Compact => tr(language, "slash_command.description.compact"),
             SlashCommand::Review => tr(language, "slash_command.description.review"),
             SlashCommand::Rename => tr(language, "slash_command.description.rename"),
+            SlashCommand::Note => tr(language, "slash_command.description.note"),
             SlashCommand::Resume => tr(language, "slash_command.description.resume"),
             SlashCommand::Fork => tr(language, "slash_command.description.fork"),
+            SlashCommand::Branches => tr(language, "slash_command.description.branches"),
+            SlashCommand::Compare => tr(language, "slash_command.description.compare"),
             SlashCommand::Quit | SlashCommand::Exit => {
                 tr(language, "slash_command.description.exit")
             }
             SlashCommand::Diff => tr(language, "slash_command.description.diff"),
+            SlashCommand::Diagram => tr(language, "slash_command.description.diagram"),
+            SlashCommand::Changes => tr(language, "slash_command.description.changes"),
+            SlashCommand::Symbols => tr(language, "slash_command.description.symbols"),
+            SlashCommand::Context => tr(language, "slash_command.description.context"),
             SlashCommand::Mention => tr(language, "slash_command.description.mention"),
+            SlashCommand::Scope => tr(language, "slash_command.description.scope"),
             SlashCommand::Skills => tr(language, "slash_command.description.skills"),
             SlashCommand::Status => tr(language, "slash_command.description.status"),
             SlashCommand::Ps => tr(language, "slash_command.description.ps"),
             SlashCommand::Clean => tr(language, "slash_command.description.clean"),
+            SlashCommand::Follow => tr(language, "slash_command.description.follow"),
             SlashCommand::Model => tr(language, "slash_command.description.model"),
             SlashCommand::Lang => tr(language, "slash_command.description.lang"),
             SlashCommand::Spec => tr(language, "slash_command.description.spec"),
@@ -87,6 +112,7 @@ impl SlashCommand {
             SlashCommand::Experimental => tr(language, "slash_command.description.experimental"),
             SlashCommand::Mcp => tr(language, "slash_command.description.mcp"),
             SlashCommand::Apps => tr(language, "slash_command.description.apps"),
+            SlashCommand::Login => tr(language, "slash_command.description.login"),
             SlashCommand::Logout => tr(language, "slash_command.description.logout"),
             SlashCommand::Rollout => tr(language, "slash_command.description.rollout"),
             SlashCommand::TestApproval => tr(language, "slash_command.description.test_approval"),
@@ -97,8 +123,14 @@ impl SlashCommand {
                 tr(language, "slash_command.description.sdd_develop_parallels")
             }
             SlashCommand::Statusline => tr(language, "slash_command.description.statusline"),
+            SlashCommand::Density => tr(language, "slash_command.description.density"),
+            SlashCommand::Ui => tr(language, "slash_command.description.ui"),
             SlashCommand::Personality => tr(language, "slash_command.description.personality"),
+            SlashCommand::Persona => tr(language, "slash_command.description.persona"),
+            SlashCommand::Profile => tr(language, "slash_command.description.profile"),
             SlashCommand::Plan => tr(language, "slash_command.description.plan"),
+            SlashCommand::Mode => tr(language, "slash_command.description.mode"),
+            SlashCommand::DryRun => tr(language, "slash_command.description.dry_run"),
             SlashCommand::Collab => tr(language, "slash_command.description.collab"),
             SlashCommand::Agent => tr(language, "slash_command.description.agent"),
         }
@@ -116,7 +148,15 @@ impl SlashCommand {
             self,
             SlashCommand::Review
                 | SlashCommand::Rename
+                | SlashCommand::Note
+                | SlashCommand::Compare
                 | SlashCommand::Plan
+                | SlashCommand::Mode
+                | SlashCommand::DryRun
+                | SlashCommand::Scope
+                | SlashCommand::Density
+                | SlashCommand::Ui
+                | SlashCommand::Profile
                 | SlashCommand::SddDevelop
                 | SlashCommand::SddDevelopParallels
         )
@@ -128,33 +168,49 @@ impl SlashCommand {
             SlashCommand::New
             | SlashCommand::Resume
             | SlashCommand::Fork
+            | SlashCommand::Branches
             | SlashCommand::Init
             | SlashCommand::Checkpoint
             | SlashCommand::Compact
+            | SlashCommand::Compare
             | SlashCommand::SddDevelop
             | SlashCommand::SddDevelopParallels
             | SlashCommand::Model
             | SlashCommand::Lang
             | SlashCommand::Spec
             | SlashCommand::Personality
+            | SlashCommand::Persona
+            | SlashCommand::Profile
             | SlashCommand::Approvals
             | SlashCommand::Permissions
             | SlashCommand::ElevateSandbox
             | SlashCommand::Experimental
             | SlashCommand::Review
+            | SlashCommand::Changes
             | SlashCommand::Plan
+            | SlashCommand::Mode
+            | SlashCommand::DryRun
+            | SlashCommand::Scope
             | SlashCommand::Logout
-            | SlashCommand::Statusline => false,
+            | SlashCommand::Statusline
+            | SlashCommand::Ui => false,
             SlashCommand::Diff
+            | SlashCommand::Diagram
+            | SlashCommand::Symbols
+            | SlashCommand::Context
             | SlashCommand::Rename
+            | SlashCommand::Note
             | SlashCommand::Mention
             | SlashCommand::Skills
             | SlashCommand::Status
+            | SlashCommand::Density
             | SlashCommand::DebugConfig
             | SlashCommand::Ps
             | SlashCommand::Clean
+            | SlashCommand::Follow
             | SlashCommand::Mcp
             | SlashCommand::Apps
+            | SlashCommand::Login
             | SlashCommand::Feedback
             | SlashCommand::Quit
             | SlashCommand::Exit => true,
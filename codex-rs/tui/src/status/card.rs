@@ -9,6 +9,7 @@ use chrono::DateTime;
 use chrono::Local;
 use codex_common::summarize_sandbox_policy;
 use codex_core::WireApi;
+use codex_core::auth::TokenRefreshHealth;
 use codex_core::config::Config;
 use codex_core::protocol::NetworkAccess;
 use codex_core::protocol::SandboxPolicy;
@@ -33,6 +34,7 @@ use super::helpers::compose_account_display;
 use super::helpers::compose_agents_summary;
 use super::helpers::compose_model_display;
 use super::helpers::format_directory_display;
+use super::helpers::format_reset_timestamp;
 use super::helpers::format_tokens_compact;
 use super::rate_limits::RateLimitSnapshotDisplay;
 use super::rate_limits::StatusRateLimitData;
@@ -60,6 +62,14 @@ pub(crate) struct StatusTokenUsageData {
     context_window: Option<StatusContextWindowData>,
 }
 
+/// Model responses the user interrupted mid-stream during this session.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct InterruptedResponses {
+    pub(crate) count: usize,
+    /// Approximate output tokens that had streamed before the aborts.
+    pub(crate) streamed_tokens: i64,
+}
+
 #[derive(Debug)]
 struct StatusHistoryCell {
     model_name: String,
@@ -71,10 +81,13 @@ struct StatusHistoryCell {
     collaboration_mode: Option<String>,
     model_provider: Option<String>,
     account: Option<StatusAccountDisplay>,
+    sign_in: Option<TokenRefreshHealth>,
+    captured_at: DateTime<Local>,
     thread_name: Option<String>,
     session_id: Option<String>,
     forked_from: Option<String>,
     token_usage: StatusTokenUsageData,
+    interrupted: InterruptedResponses,
     rate_limits: StatusRateLimitData,
     language: Language,
 }
@@ -94,6 +107,7 @@ pub(crate) fn new_status_output(
     model_name: &str,
     collaboration_mode: Option<&str>,
     reasoning_effort_override: Option<Option<ReasoningEffort>>,
+    interrupted: InterruptedResponses,
 ) -> CompositeHistoryCell {
     let command = PlainHistoryCell::new(vec!["/status".magenta().into()]);
     let card = StatusHistoryCell::new(
@@ -110,6 +124,7 @@ pub(crate) fn new_status_output(
         model_name,
         collaboration_mode,
         reasoning_effort_override,
+        interrupted,
     );
 
     CompositeHistoryCell::new(vec![Box::new(command), Box::new(card)])
@@ -131,6 +146,7 @@ impl StatusHistoryCell {
         model_name: &str,
         collaboration_mode: Option<&str>,
         reasoning_effort_override: Option<Option<ReasoningEffort>>,
+        interrupted: InterruptedResponses,
     ) -> Self {
         let mut config_entries = vec![
             ("workdir", config.cwd.display().to_string()),
@@ -175,6 +191,7 @@ impl StatusHistoryCell {
         let agents_summary = compose_agents_summary(config, config.language);
         let model_provider = format_model_provider(config);
         let account = compose_account_display(auth_manager, plan_type, config.language);
+        let sign_in = auth_manager.token_refresh_health();
         let session_id = session_id.as_ref().map(std::string::ToString::to_string);
         let forked_from = forked_from.map(|id| id.to_string());
         let default_usage = TokenUsage::default();
@@ -206,10 +223,13 @@ impl StatusHistoryCell {
             collaboration_mode: collaboration_mode.map(ToString::to_string),
             model_provider,
             account,
+            sign_in,
+            captured_at: now,
             thread_name,
             session_id,
             forked_from,
             token_usage,
+            interrupted,
             rate_limits,
             language: config.language,
         }
@@ -254,6 +274,64 @@ impl StatusHistoryCell {
         ])
     }
 
+    fn interrupted_spans(&self) -> Option<Vec<Span<'static>>> {
+        if self.interrupted.count == 0 {
+            return None;
+        }
+        let count = self.interrupted.count.to_string();
+        let tokens = format_tokens_compact(self.interrupted.streamed_tokens);
+        Some(vec![
+            Span::from(tr_args(
+                self.language,
+                "status.interrupted.summary",
+                &[("count", count.as_str())],
+            )),
+            Span::from(tr_args(
+                self.language,
+                "status.interrupted.streamed",
+                &[("tokens", tokens.as_str())],
+            ))
+            .dim(),
+        ])
+    }
+
+    fn sign_in_spans(&self) -> Option<Vec<Span<'static>>> {
+        let health = self.sign_in.as_ref()?;
+        let refreshed_at = |last_refresh: &DateTime<chrono::Utc>| {
+            format_reset_timestamp(
+                last_refresh.with_timezone(&Local),
+                self.captured_at,
+                self.language,
+            )
+        };
+        let spans = match health {
+            TokenRefreshHealth::Healthy { last_refresh } => {
+                let time = refreshed_at(last_refresh);
+                vec![Span::from(tr_args(
+                    self.language,
+                    "status.sign_in.healthy",
+                    &[("time", time.as_str())],
+                ))]
+            }
+            TokenRefreshHealth::RefreshDue { last_refresh } => {
+                let time = refreshed_at(last_refresh);
+                vec![
+                    Span::from(tr_args(
+                        self.language,
+                        "status.sign_in.refresh_due",
+                        &[("time", time.as_str())],
+                    ))
+                    .cyan(),
+                ]
+            }
+            TokenRefreshHealth::Failed(_) => vec![
+                Span::from(tr(self.language, "status.sign_in.needs_login")).red(),
+                Span::from(tr(self.language, "status.sign_in.login_hint")).dim(),
+            ],
+        };
+        Some(spans)
+    }
+
     fn rate_limit_lines(
         &self,
         available_inner_width: usize,
@@ -410,12 +488,14 @@ impl HistoryCell for StatusHistoryCell {
         let label_sandbox = tr(language, "status.fields.sandbox");
         let label_model_provider = tr(language, "status.fields.model_provider");
         let label_account = tr(language, "status.fields.account");
+        let label_sign_in = tr(language, "status.fields.sign_in");
         let label_thread_name = tr(language, "status.fields.thread_name");
         let label_forked_from = tr(language, "status.fields.forked_from");
         let label_collaboration_mode = tr(language, "status.fields.collaboration_mode");
         let label_session = tr(language, "status.fields.session");
         let label_token_usage = tr(language, "status.fields.token_usage");
         let label_context_window = tr(language, "status.fields.context_window");
+        let label_interrupted = tr(language, "status.fields.interrupted");
 
         let mut labels: Vec<String> = vec![
             label_model,
@@ -436,6 +516,10 @@ impl HistoryCell for StatusHistoryCell {
         if account_value.is_some() {
             push_label(&mut labels, &mut seen, label_account);
         }
+        let sign_in_spans = self.sign_in_spans();
+        if sign_in_spans.is_some() {
+            push_label(&mut labels, &mut seen, label_sign_in);
+        }
         if thread_name.is_some() {
             push_label(&mut labels, &mut seen, label_thread_name);
         }
@@ -452,6 +536,9 @@ impl HistoryCell for StatusHistoryCell {
         if self.token_usage.context_window.is_some() {
             push_label(&mut labels, &mut seen, label_context_window);
         }
+        if self.interrupted.count > 0 {
+            push_label(&mut labels, &mut seen, label_interrupted);
+        }
 
         self.collect_rate_limit_labels(&mut seen, &mut labels);
 
@@ -499,6 +586,9 @@ impl HistoryCell for StatusHistoryCell {
         if let Some(account_value) = account_value {
             lines.push(formatter.line(label_account, vec![Span::from(account_value)]));
         }
+        if let Some(spans) = sign_in_spans {
+            lines.push(formatter.line(label_sign_in, spans));
+        }
 
         if let Some(thread_name) = thread_name {
             lines
@@ -529,6 +619,10 @@ impl HistoryCell for StatusHistoryCell {
             lines.push(formatter.line(label_context_window, spans));
         }
 
+        if let Some(spans) = self.interrupted_spans() {
+            lines.push(formatter.line(label_interrupted, spans));
+        }
+
         lines.extend(self.rate_limit_lines(available_inner_width, &formatter));
 
         let content_width = lines.iter().map(line_display_width).max().unwrap_or(0);
@@ -7,6 +7,8 @@ use chrono::Local;
 use codex_core::AuthManager;
 use codex_core::auth::AuthMode as CoreAuthMode;
 use codex_core::config::Config;
+use codex_core::locale_format::format_month_day;
+use codex_core::locale_format::format_time_on_date;
 use codex_core::project_doc::discover_project_doc_paths;
 use codex_protocol::account::PlanType;
 use codex_protocol::config_types::Language;
@@ -190,10 +192,8 @@ pub(crate) fn format_reset_timestamp(
     if dt.date_naive() == captured_at.date_naive() {
         time
     } else {
-        match language {
-            Language::ZhCn => format!("{time} · {}", dt.format("%-d %b")),
-            Language::En => format!("{time} on {}", dt.format("%-d %b")),
-        }
+        let date = format_month_day(language, dt.date_naive());
+        format_time_on_date(language, &time, &date)
     }
 }
 
@@ -12,6 +12,7 @@ mod format;
 mod helpers;
 mod rate_limits;
 
+pub(crate) use card::InterruptedResponses;
 pub(crate) use card::new_status_output;
 pub(crate) use helpers::format_directory_display;
 pub(crate) use helpers::format_tokens_compact;
@@ -5,7 +5,6 @@
 //!
 //! The key contract is that time-sensitive values are interpreted relative to a caller-provided
 //! capture timestamp so stale detection and reset labels remain coherent for a given draw cycle.
-use crate::chatwidget::get_limits_duration;
 use crate::i18n::tr;
 use crate::i18n::tr_args;
 use crate::text_formatting::capitalize_first;
@@ -15,6 +14,7 @@ use chrono::DateTime;
 use chrono::Duration as ChronoDuration;
 use chrono::Local;
 use chrono::Utc;
+use codex_core::locale_format::format_limit_window;
 use codex_core::protocol::CreditsSnapshot as CoreCreditsSnapshot;
 use codex_core::protocol::RateLimitSnapshot;
 use codex_core::protocol::RateLimitWindow;
@@ -163,7 +163,7 @@ pub(crate) fn compose_rate_limit_data(
             if let Some(primary) = snapshot.primary.as_ref() {
                 let label: String = primary
                     .window_minutes
-                    .map(get_limits_duration)
+                    .map(|minutes| format_limit_window(language, minutes))
                     .unwrap_or_else(|| {
                         tr(language, "status.rate_limits.default_primary_window").to_string()
                     });
@@ -184,7 +184,7 @@ pub(crate) fn compose_rate_limit_data(
             if let Some(secondary) = snapshot.secondary.as_ref() {
                 let label: String = secondary
                     .window_minutes
-                    .map(get_limits_duration)
+                    .map(|minutes| format_limit_window(language, minutes))
                     .unwrap_or_else(|| {
                         tr(language, "status.rate_limits.default_secondary_window").to_string()
                     });
@@ -1,3 +1,4 @@
+use super::InterruptedResponses;
 use super::new_status_output;
 use super::rate_limit_snapshot_display;
 use crate::history_cell::HistoryCell;
@@ -5,8 +6,11 @@ use chrono::Duration as ChronoDuration;
 use chrono::TimeZone;
 use chrono::Utc;
 use codex_core::AuthManager;
+use codex_core::CodexAuth;
 use codex_core::config::Config;
 use codex_core::config::ConfigBuilder;
+use codex_core::error::RefreshTokenFailedError;
+use codex_core::error::RefreshTokenFailedReason;
 use codex_core::models_manager::manager::ModelsManager;
 use codex_core::protocol::CreditsSnapshot;
 use codex_core::protocol::RateLimitSnapshot;
@@ -158,6 +162,7 @@ async fn status_snapshot_includes_reasoning_details() {
         &model_slug,
         None,
         reasoning_effort_override,
+        InterruptedResponses::default(),
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
     if cfg!(windows) {
@@ -212,6 +217,7 @@ async fn status_snapshot_includes_forked_from() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
     if cfg!(windows) {
@@ -223,6 +229,63 @@ async fn status_snapshot_includes_forked_from() {
     assert_snapshot!(sanitized);
 }
 
+#[tokio::test]
+async fn status_snapshot_shows_sign_in_health() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home).await;
+    config.model = Some("gpt-5.1-codex-max".to_string());
+    config.model_provider_id = "openai".to_string();
+    config.cwd = PathBuf::from("/workspace/tests");
+
+    let usage = TokenUsage::default();
+    let captured_at = chrono::Local
+        .with_ymd_and_hms(2024, 1, 2, 10, 4, 5)
+        .single()
+        .expect("timestamp");
+    let last_refresh = (captured_at - ChronoDuration::hours(2)).with_timezone(&Utc);
+    let model_slug = ModelsManager::get_model_offline(config.model.as_deref());
+
+    let refresh_due = AuthManager::from_auth_for_testing(
+        CodexAuth::create_dummy_chatgpt_auth_refreshed_at_for_testing(last_refresh),
+    );
+    let expired = AuthManager::from_auth_for_testing(
+        CodexAuth::create_dummy_chatgpt_auth_refreshed_at_for_testing(last_refresh),
+    );
+    expired.record_refresh_failure_for_testing(RefreshTokenFailedError::new(
+        RefreshTokenFailedReason::Revoked,
+        "refresh token revoked",
+    ));
+
+    let mut sign_in_rows = Vec::new();
+    for auth_manager in [&refresh_due, &expired] {
+        let composite = new_status_output(
+            &config,
+            auth_manager,
+            None,
+            &usage,
+            &None,
+            None,
+            None,
+            None,
+            None,
+            captured_at,
+            &model_slug,
+            None,
+            None,
+            InterruptedResponses::default(),
+        );
+        // Keep only the sign-in row, without the card border and padding.
+        let row = render_lines(&composite.display_lines(80))
+            .into_iter()
+            .find(|line| line.contains("登录状态:"))
+            .expect("sign-in row");
+        let row = row.trim_matches(|c: char| c == '│' || c.is_whitespace());
+        let (label, value) = row.split_once(':').expect("label");
+        sign_in_rows.push(format!("{}: {}", label.trim(), value.trim()));
+    }
+    assert_snapshot!(sign_in_rows.join("\n"));
+}
+
 #[tokio::test]
 async fn status_snapshot_includes_monthly_limit() {
     let temp_home = TempDir::new().expect("temp home");
@@ -272,6 +335,7 @@ async fn status_snapshot_includes_monthly_limit() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
     if cfg!(windows) {
@@ -320,6 +384,7 @@ async fn status_snapshot_shows_unlimited_credits() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let rendered = render_lines(&composite.display_lines(120));
     assert!(
@@ -367,6 +432,7 @@ async fn status_snapshot_shows_positive_credits() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let rendered = render_lines(&composite.display_lines(120));
     assert!(
@@ -414,6 +480,7 @@ async fn status_snapshot_hides_zero_credits() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let rendered = render_lines(&composite.display_lines(120));
     assert!(
@@ -459,6 +526,7 @@ async fn status_snapshot_hides_when_has_no_credits_flag() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let rendered = render_lines(&composite.display_lines(120));
     assert!(
@@ -504,6 +572,7 @@ async fn status_card_token_usage_excludes_cached_tokens() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let rendered = render_lines(&composite.display_lines(120));
 
@@ -564,6 +633,7 @@ async fn status_snapshot_truncates_in_narrow_terminal() {
         &model_slug,
         None,
         reasoning_effort_override,
+        InterruptedResponses::default(),
     );
     let mut rendered_lines = render_lines(&composite.display_lines(70));
     if cfg!(windows) {
@@ -613,6 +683,7 @@ async fn status_snapshot_shows_missing_limits_message() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
     if cfg!(windows) {
@@ -680,6 +751,7 @@ async fn status_snapshot_includes_credits_and_limits() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
     if cfg!(windows) {
@@ -735,6 +807,7 @@ async fn status_snapshot_shows_empty_limits_message() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
     if cfg!(windows) {
@@ -799,6 +872,7 @@ async fn status_snapshot_shows_stale_limits_message() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
     if cfg!(windows) {
@@ -867,6 +941,7 @@ async fn status_snapshot_cached_limits_hide_credits_without_flag() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
     if cfg!(windows) {
@@ -925,6 +1000,7 @@ async fn status_context_window_uses_last_usage() {
         &model_slug,
         None,
         None,
+        InterruptedResponses::default(),
     );
     let rendered_lines = render_lines(&composite.display_lines(80));
     let context_line = rendered_lines
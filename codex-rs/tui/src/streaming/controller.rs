@@ -9,6 +9,11 @@ use std::time::Instant;
 
 use super::StreamState;
 
+/// Most lines one finalized history cell holds. A long message that is still
+/// queued when the stream ends is emitted as several continuation cells, so
+/// no single cell stores or re-wraps the whole message.
+const MAX_FINALIZED_CELL_LINES: usize = 256;
+
 /// Controller that manages newline-gated streaming, header emission, and
 /// commit animation across streams.
 pub(crate) struct StreamController {
@@ -43,28 +48,24 @@ impl StreamController {
         false
     }
 
-    /// Finalize the active stream. Drain and emit now.
-    pub(crate) fn finalize(&mut self) -> Option<Box<dyn HistoryCell>> {
+    /// Finalize the active stream. Drain and emit now, in cells of at most
+    /// `MAX_FINALIZED_CELL_LINES` lines.
+    pub(crate) fn finalize(&mut self) -> Vec<Box<dyn HistoryCell>> {
         // Finalize collector first.
-        let remaining = {
-            let state = &mut self.state;
-            state.collector.finalize_and_drain()
-        };
-        // Collect all output first to avoid emitting headers when there is no content.
-        let mut out_lines = Vec::new();
-        {
-            let state = &mut self.state;
-            if !remaining.is_empty() {
-                state.enqueue(remaining);
-            }
-            let step = state.drain_all();
-            out_lines.extend(step);
+        let remaining = self.state.collector.finalize_and_drain();
+        if !remaining.is_empty() {
+            self.state.enqueue(remaining);
+        }
+        let mut cells = Vec::new();
+        while !self.state.is_idle() {
+            let step = self.state.drain_n(MAX_FINALIZED_CELL_LINES);
+            cells.extend(self.emit(step));
         }
 
         // Cleanup
         self.state.clear();
         self.finishing_after_drain = false;
-        self.emit(out_lines)
+        cells
     }
 
     /// Step animation: commit at most one queued line and handle end-of-drain cleanup.
@@ -333,7 +334,7 @@ mod tests {
             }
         }
         // Finalize and flush remaining lines now.
-        if let Some(cell) = ctrl.finalize() {
+        for cell in ctrl.finalize() {
             lines.extend(cell.transcript_lines(u16::MAX));
         }
 
@@ -369,4 +370,36 @@ mod tests {
             "expected exact rendered lines for loose/tight section"
         );
     }
+
+    #[tokio::test]
+    async fn finalizing_a_long_backlog_emits_bounded_cells() {
+        let mut source = String::new();
+        for i in 0..400 {
+            source.push_str(&format!("## Part {i}\n\nBody of part {i}.\n\n"));
+        }
+        let mut ctrl = StreamController::new(None);
+        for delta in source.split_inclusive('\n') {
+            ctrl.push(delta);
+        }
+        // Nothing was drained by commit ticks, so the whole message is queued.
+        let cells = ctrl.finalize();
+        assert!(cells.len() > 1, "expected the backlog to be split");
+        assert!(!cells[0].is_stream_continuation());
+        assert!(cells[1..].iter().all(|cell| cell.is_stream_continuation()));
+        assert!(
+            cells
+                .iter()
+                .all(|cell| cell.transcript_lines(u16::MAX).len() <= MAX_FINALIZED_CELL_LINES)
+        );
+
+        let streamed: Vec<String> = cells
+            .iter()
+            .flat_map(|cell| lines_to_plain_strings(&cell.transcript_lines(u16::MAX)))
+            .map(|s| s.chars().skip(2).collect())
+            .collect();
+        let mut rendered: Vec<ratatui::text::Line<'static>> = Vec::new();
+        crate::markdown::append_markdown(&source, None, &mut rendered);
+        assert_eq!(streamed, lines_to_plain_strings(&rendered));
+        assert_eq!(ctrl.queued_lines(), 0);
+    }
 }
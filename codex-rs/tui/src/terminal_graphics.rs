@@ -0,0 +1,192 @@
+//! Inline images for terminals that speak the kitty graphics protocol or
+//! iTerm2's inline image escape.
+//!
+//! Images are placed over blank rows reserved in the scrollback (see
+//! [`crate::tui::Tui::insert_history_image`]), so they scroll away with the
+//! rest of the history.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use codex_core::terminal::TerminalInfo;
+use codex_core::terminal::TerminalName;
+use ratatui::layout::Size;
+
+/// Cell size assumed when choosing how many rows an image spans; terminals
+/// scale the image into the requested cells, so this only affects proportions.
+const CELL_WIDTH_PX: u64 = 8;
+const CELL_HEIGHT_PX: u64 = 16;
+/// Tallest image placed inline, in rows.
+const MAX_IMAGE_ROWS: u16 = 30;
+/// kitty limits each escape to 4096 bytes of base64 payload.
+const KITTY_CHUNK: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GraphicsProtocol {
+    Kitty,
+    Iterm2,
+}
+
+/// Image protocol understood by the terminal described by `info`, if any.
+/// Multiplexers are excluded: they need passthrough wrapping and usually drop
+/// the image anyway.
+pub(crate) fn detect_protocol(info: &TerminalInfo) -> Option<GraphicsProtocol> {
+    if info.multiplexer.is_some() {
+        return None;
+    }
+    match info.name {
+        TerminalName::Kitty | TerminalName::Ghostty => Some(GraphicsProtocol::Kitty),
+        TerminalName::Iterm2 | TerminalName::WezTerm => Some(GraphicsProtocol::Iterm2),
+        _ => None,
+    }
+}
+
+/// A PNG ready to be drawn inline, sized in terminal cells.
+#[derive(Debug, Clone)]
+pub(crate) struct InlineImage {
+    protocol: GraphicsProtocol,
+    png: Vec<u8>,
+    cols: u16,
+    rows: u16,
+}
+
+impl InlineImage {
+    /// Prepare `png` for the current terminal, or `None` when the terminal
+    /// cannot show images.
+    pub(crate) fn for_current_terminal(
+        png: Vec<u8>,
+        width_px: u32,
+        height_px: u32,
+        screen: Size,
+    ) -> Option<Self> {
+        let protocol = detect_protocol(&codex_core::terminal::terminal_info())?;
+        let max_rows = MAX_IMAGE_ROWS.min(screen.height / 2);
+        let (cols, rows) = fit_cells(width_px, height_px, screen.width, max_rows)?;
+        Some(Self {
+            protocol,
+            png,
+            cols,
+            rows,
+        })
+    }
+
+    /// Number of rows the image covers.
+    pub(crate) fn rows(&self) -> u16 {
+        self.rows
+    }
+
+    /// Escape sequence that draws the image with its top-left corner at the
+    /// cursor.
+    pub(crate) fn escape_sequence(&self) -> String {
+        let encoded = BASE64_STANDARD.encode(&self.png);
+        match self.protocol {
+            GraphicsProtocol::Kitty => kitty_sequence(&encoded, self.cols, self.rows),
+            GraphicsProtocol::Iterm2 => format!(
+                "\x1b]1337;File=inline=1;size={size};width={cols};height={rows};preserveAspectRatio=1:{encoded}\x07",
+                size = self.png.len(),
+                cols = self.cols,
+                rows = self.rows,
+            ),
+        }
+    }
+}
+
+/// `q=2` keeps the terminal from answering on stdin; `C=1` leaves the cursor
+/// where it was.
+fn kitty_sequence(encoded: &str, cols: u16, rows: u16) -> String {
+    let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(KITTY_CHUNK).collect();
+    let mut out = String::new();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(index + 1 < chunks.len());
+        // Base64 is ASCII, so every chunk boundary is a char boundary.
+        let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+        if index == 0 {
+            out.push_str(&format!(
+                "\x1b_Ga=T,f=100,q=2,C=1,c={cols},r={rows},m={more};{chunk}\x1b\\"
+            ));
+        } else {
+            out.push_str(&format!("\x1b_Gm={more};{chunk}\x1b\\"));
+        }
+    }
+    out
+}
+
+/// Cells covered by a `width_px` x `height_px` image: one cell per
+/// [`CELL_WIDTH_PX`] pixels, shrunk to fit `max_cols` x `max_rows` while
+/// keeping the aspect ratio.
+fn fit_cells(width_px: u32, height_px: u32, max_cols: u16, max_rows: u16) -> Option<(u16, u16)> {
+    if width_px == 0 || height_px == 0 || max_cols == 0 || max_rows == 0 {
+        return None;
+    }
+    let (width_px, height_px) = (u64::from(width_px), u64::from(height_px));
+    let (max_cols, max_rows) = (u64::from(max_cols), u64::from(max_rows));
+
+    let mut cols = width_px.div_ceil(CELL_WIDTH_PX).clamp(1, max_cols);
+    let mut rows = (cols * height_px * CELL_WIDTH_PX)
+        .div_ceil(width_px * CELL_HEIGHT_PX)
+        .max(1);
+    if rows > max_rows {
+        rows = max_rows;
+        cols = (rows * CELL_HEIGHT_PX * width_px)
+            .div_ceil(height_px * CELL_WIDTH_PX)
+            .clamp(1, max_cols);
+    }
+    Some((u16::try_from(cols).ok()?, u16::try_from(rows).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::terminal::Multiplexer;
+    use pretty_assertions::assert_eq;
+
+    fn info(name: TerminalName, multiplexer: Option<Multiplexer>) -> TerminalInfo {
+        TerminalInfo {
+            name,
+            term_program: None,
+            version: None,
+            term: None,
+            multiplexer,
+        }
+    }
+
+    #[test]
+    fn detects_protocol_from_terminal_name() {
+        assert_eq!(
+            detect_protocol(&info(TerminalName::Kitty, None)),
+            Some(GraphicsProtocol::Kitty)
+        );
+        assert_eq!(
+            detect_protocol(&info(TerminalName::Iterm2, None)),
+            Some(GraphicsProtocol::Iterm2)
+        );
+        assert_eq!(
+            detect_protocol(&info(TerminalName::AppleTerminal, None)),
+            None
+        );
+        assert_eq!(
+            detect_protocol(&info(
+                TerminalName::Kitty,
+                Some(Multiplexer::Tmux { version: None })
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn fits_images_into_the_screen() {
+        assert_eq!(fit_cells(800, 400, 120, 30), Some((100, 25)));
+        assert_eq!(fit_cells(800, 400, 50, 30), Some((50, 13)));
+        assert_eq!(fit_cells(100, 1000, 80, 20), Some((4, 20)));
+        assert_eq!(fit_cells(0, 10, 80, 20), None);
+    }
+
+    #[test]
+    fn kitty_payload_is_chunked() {
+        let encoded = "A".repeat(KITTY_CHUNK + 10);
+        let sequence = kitty_sequence(&encoded, 10, 5);
+        let escapes: Vec<&str> = sequence.split("\x1b\\").filter(|s| !s.is_empty()).collect();
+        assert_eq!(escapes.len(), 2);
+        assert!(escapes[0].starts_with("\x1b_Ga=T,f=100,q=2,C=1,c=10,r=5,m=1;"));
+        assert!(escapes[1].starts_with("\x1b_Gm=0;"));
+    }
+}
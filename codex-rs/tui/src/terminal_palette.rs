@@ -14,7 +14,9 @@ pub fn best_color(target: (u8, u8, u8)) -> Color {
     let Some(color_level) = supports_color::on_cached(supports_color::Stream::Stdout) else {
         return Color::default();
     };
-    if color_level.has_16m {
+    // Terminals such as Windows Terminal render RGB without advertising it
+    // through `COLORTERM`.
+    if color_level.has_16m || codex_core::terminal::terminal_capabilities().truecolor {
         let (r, g, b) = target;
         #[allow(clippy::disallowed_methods)]
         Color::Rgb(r, g, b)
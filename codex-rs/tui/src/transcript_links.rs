@@ -0,0 +1,315 @@
+//! URLs and `path:line` references in transcript text.
+//!
+//! History lines are scanned for `http(s)://` URLs and file references such
+//! as `src/main.rs:42` (optionally with a trailing `:column`). Matches are
+//! underlined, and the transcript overlay lets the user step through them
+//! and open one: URLs in the browser, files in the editor configured by
+//! `tui.open_file_command` or `$VISUAL`/`$EDITOR`.
+
+use std::ops::Range;
+use std::path::Path;
+
+use ratatui::style::Modifier;
+use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::text::Span;
+
+use crate::external_editor;
+use crate::external_editor::EditorError;
+
+/// Characters stripped from the start of a token before matching.
+const LEADING_PUNCTUATION: &[char] = &['(', '[', '{', '<', '"', '\'', '`'];
+/// Characters stripped from the end of a token before matching.
+const TRAILING_PUNCTUATION: &[char] = &[
+    ')', ']', '}', '>', '"', '\'', '`', ',', '.', ';', ':', '!', '?',
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LinkTarget {
+    Url(String),
+    File { path: String, line: Option<u32> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LinkMatch {
+    /// Byte range of the link in the scanned text.
+    pub(crate) range: Range<usize>,
+    pub(crate) target: LinkTarget,
+}
+
+/// Find the links in `text`, in order of appearance.
+pub(crate) fn find_links(text: &str) -> Vec<LinkMatch> {
+    let mut links = Vec::new();
+    let mut offset = 0;
+    for token in text.split_inclusive(char::is_whitespace) {
+        let start = offset;
+        offset += token.len();
+        let trimmed_start = token.trim_start_matches(LEADING_PUNCTUATION);
+        let start = start + (token.len() - trimmed_start.len());
+        let candidate = trimmed_start.trim_end();
+        if let Some((len, target)) = match_url(candidate).or_else(|| match_file(candidate)) {
+            links.push(LinkMatch {
+                range: start..start + len,
+                target,
+            });
+        }
+    }
+    links
+}
+
+fn match_url(candidate: &str) -> Option<(usize, LinkTarget)> {
+    if !(candidate.starts_with("https://") || candidate.starts_with("http://")) {
+        return None;
+    }
+    let mut url = candidate;
+    loop {
+        let trimmed = url.trim_end_matches(TRAILING_PUNCTUATION);
+        // Keep a closing paren that belongs to the URL, as in wiki links.
+        let trimmed = if url[trimmed.len()..].starts_with(')')
+            && trimmed.matches('(').count() > trimmed.matches(')').count()
+        {
+            &url[..trimmed.len() + 1]
+        } else {
+            trimmed
+        };
+        if trimmed.len() == url.len() {
+            break;
+        }
+        url = trimmed;
+    }
+    let scheme_len = url.find("://").map_or(0, |idx| idx + 3);
+    (url.len() > scheme_len).then(|| (url.len(), LinkTarget::Url(url.to_string())))
+}
+
+fn match_file(candidate: &str) -> Option<(usize, LinkTarget)> {
+    let candidate = candidate.trim_end_matches(TRAILING_PUNCTUATION);
+    let mut parts = candidate.splitn(3, ':');
+    let path = parts.next()?;
+    let line: u32 = parts.next()?.parse().ok()?;
+    if let Some(column) = parts.next()
+        && column.parse::<u32>().is_err()
+    {
+        return None;
+    }
+    if path.is_empty() || path.contains("://") || !looks_like_path(path) {
+        return None;
+    }
+    Some((
+        candidate.len(),
+        LinkTarget::File {
+            path: path.to_string(),
+            line: Some(line),
+        },
+    ))
+}
+
+/// A path needs a directory separator or a file extension, which keeps times
+/// such as `12:30` from matching.
+fn looks_like_path(path: &str) -> bool {
+    if path.contains('/') || path.contains('\\') {
+        return true;
+    }
+    path.rsplit_once('.').is_some_and(|(stem, extension)| {
+        !stem.is_empty()
+            && !extension.is_empty()
+            && extension.chars().all(|c| c.is_ascii_alphanumeric())
+            && extension.chars().any(|c| c.is_ascii_alphabetic())
+    })
+}
+
+/// Underline every link in `line`, and reverse the link at `selected` (a
+/// byte range into the line's text), if any.
+pub(crate) fn decorate_line(line: Line<'static>, selected: Option<&Range<usize>>) -> Line<'static> {
+    let text: String = line
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect();
+    let links = find_links(&text);
+    if links.is_empty() {
+        return line;
+    }
+    let ranges: Vec<(Range<usize>, Style)> = links
+        .into_iter()
+        .map(|link| {
+            let mut style = Style::new().add_modifier(Modifier::UNDERLINED);
+            if selected == Some(&link.range) {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            (link.range, style)
+        })
+        .collect();
+    style_ranges(line, &ranges)
+}
+
+/// Underline the links in `line`.
+pub(crate) fn underline_links(line: Line<'static>) -> Line<'static> {
+    decorate_line(line, None)
+}
+
+/// Patch `style` onto the byte ranges of `line`, splitting spans at the range
+/// boundaries. `ranges` must be sorted and non-overlapping.
+fn style_ranges(line: Line<'static>, ranges: &[(Range<usize>, Style)]) -> Line<'static> {
+    let Line {
+        style,
+        alignment,
+        spans,
+    } = line;
+    let mut out = Vec::with_capacity(spans.len() + ranges.len() * 2);
+    let mut offset = 0;
+    for span in spans {
+        let content = span.content.as_ref();
+        let span_range = offset..offset + content.len();
+        offset = span_range.end;
+        let mut cursor = span_range.start;
+        for (range, patch) in ranges {
+            let start = range.start.max(cursor);
+            let end = range.end.min(span_range.end);
+            if start >= end {
+                continue;
+            }
+            if start > cursor {
+                out.push(Span::styled(
+                    content[cursor - span_range.start..start - span_range.start].to_string(),
+                    span.style,
+                ));
+            }
+            out.push(Span::styled(
+                content[start - span_range.start..end - span_range.start].to_string(),
+                span.style.patch(*patch),
+            ));
+            cursor = end;
+        }
+        if cursor == span_range.start {
+            out.push(span);
+        } else if cursor < span_range.end {
+            out.push(Span::styled(
+                content[cursor - span_range.start..].to_string(),
+                span.style,
+            ));
+        }
+    }
+    Line {
+        style,
+        alignment,
+        spans: out,
+    }
+}
+
+/// Command that opens `path` at `line`.
+///
+/// `template` is `tui.open_file_command`, split like a shell command line
+/// with `{file}` and `{line}` substituted in each argument. Without a
+/// template the `$VISUAL`/`$EDITOR` command is used with `+<line>` before the
+/// path, which vi, emacs, nano and most terminal editors understand.
+pub(crate) fn file_open_command(
+    template: Option<&str>,
+    path: &Path,
+    line: Option<u32>,
+) -> Result<Vec<String>, EditorError> {
+    let file = path.to_string_lossy();
+    let line = line.unwrap_or(1).to_string();
+    match template {
+        Some(template) => {
+            let parts = external_editor::split_command(template)?;
+            Ok(parts
+                .into_iter()
+                .map(|part| part.replace("{file}", &file).replace("{line}", &line))
+                .collect())
+        }
+        None => {
+            let mut command = external_editor::resolve_editor_command()?;
+            command.push(format!("+{line}"));
+            command.push(file.into_owned());
+            Ok(command)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    fn targets(text: &str) -> Vec<(&str, LinkTarget)> {
+        find_links(text)
+            .into_iter()
+            .map(|link| (&text[link.range], link.target))
+            .collect()
+    }
+
+    #[test]
+    fn finds_urls_and_file_references() {
+        assert_eq!(
+            targets("See (https://example.com/docs), then edit `src/main.rs:42:7`."),
+            vec![
+                (
+                    "https://example.com/docs",
+                    LinkTarget::Url("https://example.com/docs".to_string())
+                ),
+                (
+                    "src/main.rs:42:7",
+                    LinkTarget::File {
+                        path: "src/main.rs".to_string(),
+                        line: Some(42),
+                    }
+                ),
+            ]
+        );
+        assert_eq!(
+            targets("https://en.wikipedia.org/wiki/Rust_(programming_language)."),
+            vec![(
+                "https://en.wikipedia.org/wiki/Rust_(programming_language)",
+                LinkTarget::Url(
+                    "https://en.wikipedia.org/wiki/Rust_(programming_language)".to_string()
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn ignores_times_and_bare_words() {
+        assert_eq!(targets("at 12:30 we ran build:1 and https://"), vec![]);
+        assert_eq!(
+            targets("Cargo.toml:3"),
+            vec![(
+                "Cargo.toml:3",
+                LinkTarget::File {
+                    path: "Cargo.toml".to_string(),
+                    line: Some(3),
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn underlines_links_across_spans() {
+        let line = Line::from(vec![Span::from("open src/"), Span::from("lib.rs:9 now")]);
+        let decorated = underline_links(line);
+        let underlined: Vec<&str> = decorated
+            .spans
+            .iter()
+            .filter(|span| span.style.add_modifier.contains(Modifier::UNDERLINED))
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(underlined, vec!["src/", "lib.rs:9"]);
+        let text: String = decorated
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(text, "open src/lib.rs:9 now");
+    }
+
+    #[test]
+    fn substitutes_the_open_file_template() {
+        let command = file_open_command(
+            Some("code -g {file}:{line}"),
+            &PathBuf::from("/work/my file.rs"),
+            Some(12),
+        )
+        .expect("template should parse");
+        assert_eq!(command, vec!["code", "-g", "/work/my file.rs:12"]);
+    }
+}
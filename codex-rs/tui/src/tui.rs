@@ -38,11 +38,13 @@ use tokio::sync::broadcast;
 use tokio_stream::Stream;
 
 pub use self::frame_requester::FrameRequester;
+pub(crate) use self::frame_requester::OUTPUT_FRAME_INTERVAL;
 use crate::custom_terminal;
 use crate::custom_terminal::Terminal as CustomTerminal;
 use crate::i18n::tr;
 use crate::notifications::DesktopNotificationBackend;
 use crate::notifications::detect_backend;
+use crate::terminal_graphics::InlineImage;
 use crate::tui::event_stream::EventBroker;
 use crate::tui::event_stream::TuiEventStream;
 #[cfg(unix)]
@@ -254,6 +256,9 @@ pub struct Tui {
     event_broker: Arc<EventBroker>,
     pub(crate) terminal: Terminal,
     pending_history_lines: Vec<Line<'static>>,
+    /// Inline images waiting for the next draw, each with the index of the
+    /// first blank row reserved for it in `pending_history_lines`.
+    pending_history_images: Vec<(usize, InlineImage)>,
     alt_saved_viewport: Option<ratatui::layout::Rect>,
     #[cfg(unix)]
     suspend_context: SuspendContext,
@@ -285,6 +290,7 @@ impl Tui {
             event_broker: Arc::new(EventBroker::new()),
             terminal,
             pending_history_lines: vec![],
+            pending_history_images: vec![],
             alt_saved_viewport: None,
             #[cfg(unix)]
             suspend_context: SuspendContext::new(),
@@ -455,6 +461,16 @@ impl Tui {
         self.frame_requester().schedule_frame();
     }
 
+    /// Reserve blank history rows for `image` and draw it over them once they
+    /// have been inserted.
+    pub(crate) fn insert_history_image(&mut self, image: InlineImage) {
+        let start = self.pending_history_lines.len();
+        self.pending_history_lines
+            .extend((0..image.rows()).map(|_| Line::from("")));
+        self.pending_history_images.push((start, image));
+        self.frame_requester().schedule_frame();
+    }
+
     pub fn draw(
         &mut self,
         height: u16,
@@ -506,6 +522,13 @@ impl Tui {
                     terminal,
                     self.pending_history_lines.clone(),
                 )?;
+                for (start, image) in self.pending_history_images.drain(..) {
+                    crate::insert_history::draw_history_image(
+                        terminal,
+                        &self.pending_history_lines[start..],
+                        &image,
+                    )?;
+                }
                 self.pending_history_lines.clear();
             }
 
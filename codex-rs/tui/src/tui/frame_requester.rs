@@ -20,6 +20,13 @@ use tokio::sync::mpsc;
 
 use super::frame_rate_limiter::FrameRateLimiter;
 
+/// How long streamed command output may wait before it is drawn.
+///
+/// Output-driven redraws are scheduled this far out instead of immediately, so
+/// a burst of output deltas lands in a single frame (about 30 FPS) while input
+/// and other immediate requests still draw at the full frame rate.
+pub(crate) const OUTPUT_FRAME_INTERVAL: Duration = Duration::from_millis(33);
+
 /// A requester for scheduling future frame draws on the TUI event loop.
 ///
 /// This is the handler side of an actor/handler pair with `FrameScheduler`, which coalesces
@@ -22,13 +22,16 @@ pub fn get_upgrade_version(config: &Config) -> Option<String> {
     let version_file = version_filepath(config);
     let info = read_version_info(&version_file).ok();
 
-    if match &info {
-        None => true,
-        Some(info) => info.last_checked_at < Utc::now() - Duration::hours(20),
-    } {
+    if !config.offline
+        && match &info {
+            None => true,
+            Some(info) => info.last_checked_at < Utc::now() - Duration::hours(20),
+        }
+    {
         // Refresh the cached latest version in the background so TUI startup
         // isn’t blocked by a network call. The UI reads the previously cached
         // value (if any) for this run; the next run shows the banner if needed.
+        // `--offline` keeps whatever was cached last time.
         tokio::spawn(async move {
             check_for_update(&version_file)
                 .await
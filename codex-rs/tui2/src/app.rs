@@ -20,6 +20,7 @@ use crate::i18n::tr_args;
 use crate::model_migration::ModelMigrationOutcome;
 use crate::model_migration::migration_copy_for_models;
 use crate::model_migration::run_model_migration_prompt;
+use crate::pager_overlay::ExecOutputOverlay;
 use crate::pager_overlay::Overlay;
 use crate::render::highlight::highlight_bash_to_lines;
 use crate::render::renderable::Renderable;
@@ -53,6 +54,7 @@ use codex_core::config::Config;
 use codex_core::config::edit::ConfigEditsBuilder;
 use codex_core::config::types::SubagentPreset;
 use codex_core::features::Feature;
+use codex_core::git_info::resolve_root_git_project_for_trust;
 use codex_core::models_manager::manager::RefreshStrategy;
 use codex_core::models_manager::model_presets::HIDE_GPT_5_1_CODEX_MAX_MIGRATION_PROMPT_CONFIG;
 use codex_core::models_manager::model_presets::HIDE_GPT5_1_MIGRATION_PROMPT_CONFIG;
@@ -126,6 +128,8 @@ pub(crate) enum AppRunControl {
 pub enum ExitReason {
     UserRequested,
     Fatal(String),
+    /// `/ui switch`: resume the conversation in the other frontend.
+    SwitchFrontend,
 }
 
 impl From<AppExitInfo> for codex_tui::AppExitInfo {
@@ -133,12 +137,14 @@ impl From<AppExitInfo> for codex_tui::AppExitInfo {
         let exit_reason = match info.exit_reason {
             ExitReason::UserRequested => codex_tui::ExitReason::UserRequested,
             ExitReason::Fatal(message) => codex_tui::ExitReason::Fatal(message),
+            ExitReason::SwitchFrontend => codex_tui::ExitReason::SwitchFrontend,
         };
         codex_tui::AppExitInfo {
             token_usage: info.token_usage,
             thread_id: info.conversation_id,
             thread_name: None,
             update_action: info.update_action.map(Into::into),
+            exit_summary: Vec::new(),
             exit_reason,
         }
     }
@@ -435,6 +441,10 @@ pub(crate) struct App {
     /// stopping a conversation (e.g., before starting a new one).
     suppress_shutdown_complete: bool,
 
+    /// Set by `/ui switch`; the exit after the next shutdown reports
+    /// [`ExitReason::SwitchFrontend`].
+    switch_frontend_on_exit: bool,
+
     // One-shot suppression of the next world-writable scan after user confirmation.
     skip_world_writable_scan_once: bool,
 }
@@ -611,6 +621,7 @@ impl App {
             feedback: feedback.clone(),
             pending_update_action: None,
             suppress_shutdown_complete: false,
+            switch_frontend_on_exit: false,
             skip_world_writable_scan_once: false,
         };
 
@@ -1685,17 +1696,39 @@ impl App {
                     let errors = errors_for_cwd(&cwd, response);
                     emit_skill_load_warnings(&self.app_event_tx, &errors, self.config.language);
                 }
+                if let Some(Overlay::ExecOutput(overlay)) = &mut self.overlay {
+                    match &event.msg {
+                        EventMsg::ExecCommandOutputDelta(delta)
+                            if delta.call_id == overlay.call_id() =>
+                        {
+                            overlay.push_output(&String::from_utf8_lossy(&delta.chunk));
+                        }
+                        EventMsg::ExecCommandEnd(end) if end.call_id == overlay.call_id() => {
+                            overlay.finish(end.exit_code);
+                        }
+                        _ => {}
+                    }
+                }
                 self.chat_widget.handle_codex_event(event);
             }
             AppEvent::Exit(mode) => match mode {
                 ExitMode::ShutdownFirst => self.chat_widget.submit_op(Op::Shutdown),
                 ExitMode::Immediate => {
-                    return Ok(AppRunControl::Exit(ExitReason::UserRequested));
+                    let reason = if self.switch_frontend_on_exit {
+                        ExitReason::SwitchFrontend
+                    } else {
+                        ExitReason::UserRequested
+                    };
+                    return Ok(AppRunControl::Exit(reason));
                 }
             },
             AppEvent::FatalExitRequest(message) => {
                 return Ok(AppRunControl::Exit(ExitReason::Fatal(message)));
             }
+            AppEvent::SwitchFrontend => {
+                self.switch_frontend_on_exit = true;
+                self.chat_widget.submit_op(Op::Shutdown);
+            }
             AppEvent::CodexOp(op) => self.chat_widget.submit_op(op),
             AppEvent::DiffResult(text) => {
                 // Clear the in-progress state in the bottom pane
@@ -1775,6 +1808,10 @@ impl App {
                 self.chat_widget
                     .set_feature_enabled(Feature::Collab, enabled);
             }
+            AppEvent::UpdatePersona(persona) => {
+                self.config.persona = persona.clone();
+                self.chat_widget.set_persona(persona);
+            }
             AppEvent::UpdateSubagentPresetModel { preset, model } => {
                 self.chat_widget
                     .set_subagent_preset_model(preset, model.clone());
@@ -1833,6 +1870,13 @@ impl App {
             AppEvent::OpenAllModelsPopup { models } => {
                 self.chat_widget.open_all_models_popup(models);
             }
+            AppEvent::OpenDiscardSessionChangeConfirmation { path } => {
+                self.chat_widget
+                    .open_discard_session_change_confirmation(path);
+            }
+            AppEvent::ExportSessionDiff { unified_diff } => {
+                self.chat_widget.export_session_diff(unified_diff);
+            }
             AppEvent::OpenFullAccessConfirmation { preset } => {
                 self.chat_widget.open_full_access_confirmation(preset);
             }
@@ -1972,6 +2016,9 @@ impl App {
                                         personality: None,
                                         spec_parallel_priority: None,
                                         spec_sdd_planning: None,
+                                        explore_mode: None,
+                                        persona: None,
+                                        scope: None,
                                     },
                                 ));
                                 self.app_event_tx
@@ -2181,6 +2228,35 @@ impl App {
                     }
                 }
             }
+            AppEvent::PersistPersonaSelection { persona } => {
+                let project = resolve_root_git_project_for_trust(&self.config.cwd)
+                    .unwrap_or_else(|| self.config.cwd.clone());
+                match ConfigEditsBuilder::new(&self.config.codex_home)
+                    .set_project_persona(project, persona.as_deref())
+                    .apply()
+                    .await
+                {
+                    Ok(()) => {
+                        let message = match persona.as_deref() {
+                            Some(persona) => tr_args(
+                                self.config.language,
+                                "app.persona.set",
+                                &[("persona", persona)],
+                            ),
+                            None => tr(self.config.language, "app.persona.cleared").to_string(),
+                        };
+                        self.chat_widget.add_info_message(message, None);
+                    }
+                    Err(err) => {
+                        tracing::error!(error = %err, "failed to persist persona selection");
+                        self.chat_widget.add_error_message(tr_args(
+                            self.config.language,
+                            "app.persona.save_failed",
+                            &[("error", &err.to_string())],
+                        ));
+                    }
+                }
+            }
             AppEvent::PersistSubagentPresetModel { preset, model } => {
                 let preset_label = Self::subagent_preset_label(preset, self.config.language);
                 match ConfigEditsBuilder::new(&self.config.codex_home)
@@ -2451,6 +2527,9 @@ impl App {
             AppEvent::OpenReviewCommitPicker(cwd) => {
                 self.chat_widget.show_review_commit_picker(&cwd).await;
             }
+            AppEvent::OpenReviewFilesPrompt => {
+                self.chat_widget.show_review_files_prompt();
+            }
             AppEvent::OpenReviewCustomPrompt => {
                 self.chat_widget.show_review_custom_prompt();
             }
@@ -2557,6 +2636,33 @@ impl App {
                 ));
                 tui.frame_requester().schedule_frame();
             }
+            KeyEvent {
+                code: KeyCode::Char('o'),
+                modifiers: crossterm::event::KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press,
+                ..
+            } => {
+                // Follow the running command's full output; a no-op when nothing is running.
+                if let Some((call_id, command, output)) = self.chat_widget.running_exec_output() {
+                    let _ = tui.enter_alt_screen();
+                    self.overlay = Some(Overlay::ExecOutput(ExecOutputOverlay::new(
+                        call_id,
+                        command,
+                        &output,
+                        tui.frame_requester(),
+                        self.config.language,
+                    )));
+                    tui.frame_requester().schedule_frame();
+                }
+            }
+            KeyEvent {
+                code: KeyCode::Char('r'),
+                modifiers: crossterm::event::KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press,
+                ..
+            } if self.overlay.is_none() => {
+                self.chat_widget.cycle_reasoning_effort();
+            }
             // Esc primes/advances backtracking only in normal (not working) mode
             // with the composer focused and empty. In any other state, forward
             // Esc so the active UI (e.g. status indicator, modals, popups)
@@ -2805,6 +2911,7 @@ mod tests {
             feedback: codex_feedback::CodexFeedback::new(),
             pending_update_action: None,
             suppress_shutdown_complete: false,
+            switch_frontend_on_exit: false,
             skip_world_writable_scan_once: false,
         }
     }
@@ -2860,6 +2967,7 @@ mod tests {
                 feedback: codex_feedback::CodexFeedback::new(),
                 pending_update_action: None,
                 suppress_shutdown_complete: false,
+                switch_frontend_on_exit: false,
                 skip_world_writable_scan_once: false,
             },
             rx,
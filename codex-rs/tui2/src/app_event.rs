@@ -64,6 +64,10 @@ pub(crate) enum AppEvent {
     /// Request to exit the application due to a fatal error.
     FatalExitRequest(String),
 
+    /// `/ui switch`: shut the conversation down and exit so the CLI can resume
+    /// it in the other frontend.
+    SwitchFrontend,
+
     /// Forward an `Op` to the Agent. Using an `AppEvent` for this avoids
     /// bubbling channels through layers of widgets.
     CodexOp(codex_core::protocol::Op),
@@ -123,6 +127,9 @@ pub(crate) enum AppEvent {
     /// Update whether the collab feature is enabled in the running app and widget.
     UpdateCollabFeature(bool),
 
+    /// Update the active persona in the running app and widget.
+    UpdatePersona(Option<String>),
+
     /// Update one sub-agent preset model override in the running app and widget.
     UpdateSubagentPresetModel {
         preset: SubagentPreset,
@@ -156,6 +163,11 @@ pub(crate) enum AppEvent {
         enabled: bool,
     },
 
+    /// Persist the selected persona for the current project.
+    PersistPersonaSelection {
+        persona: Option<String>,
+    },
+
     /// Persist one sub-agent preset model override.
     PersistSubagentPresetModel {
         preset: SubagentPreset,
@@ -199,6 +211,16 @@ pub(crate) enum AppEvent {
         models: Vec<ModelPreset>,
     },
 
+    /// Ask before discarding this session's changes to one file.
+    OpenDiscardSessionChangeConfirmation {
+        path: PathBuf,
+    },
+
+    /// Write the session's cumulative diff to a patch file in the cwd.
+    ExportSessionDiff {
+        unified_diff: String,
+    },
+
     /// Open the confirmation prompt before enabling full access mode.
     OpenFullAccessConfirmation {
         preset: ApprovalPreset,
@@ -290,6 +312,9 @@ pub(crate) enum AppEvent {
     /// Open the commit picker option from the review popup.
     OpenReviewCommitPicker(PathBuf),
 
+    /// Open the file glob prompt from the review popup.
+    OpenReviewFilesPrompt,
+
     /// Open the custom prompt option from the review popup.
     OpenReviewCustomPrompt,
 
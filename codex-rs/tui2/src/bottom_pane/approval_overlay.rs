@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
 
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
@@ -16,12 +18,14 @@ use crate::key_hint::KeyBinding;
 use crate::render::highlight::highlight_bash_to_lines;
 use crate::render::renderable::ColumnRenderable;
 use crate::render::renderable::Renderable;
+use crate::tui::FrameRequester;
 use codex_core::features::Features;
 use codex_core::protocol::ElicitationAction;
 use codex_core::protocol::ExecPolicyAmendment;
 use codex_core::protocol::FileChange;
 use codex_core::protocol::Op;
 use codex_core::protocol::ReviewDecision;
+use codex_protocol::config_types::ApprovalTimeoutAction;
 use codex_protocol::config_types::Language;
 use codex_protocol::mcp::RequestId;
 use crossterm::event::KeyCode;
@@ -58,6 +62,25 @@ pub(crate) enum ApprovalRequest {
     },
 }
 
+/// Configured auto-decision for approvals nobody answers, shown in the
+/// overlay as a countdown.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ApprovalTimeout {
+    pub(crate) timeout: Duration,
+    pub(crate) action: ApprovalTimeoutAction,
+}
+
+impl ApprovalRequest {
+    /// Id the agent uses for the request; elicitations have none and never
+    /// time out.
+    fn approval_id(&self) -> Option<&str> {
+        match self {
+            ApprovalRequest::Exec { id, .. } | ApprovalRequest::ApplyPatch { id, .. } => Some(id),
+            ApprovalRequest::McpElicitation { .. } => None,
+        }
+    }
+}
+
 /// Modal overlay asking the user to approve or deny one or more requests.
 pub(crate) struct ApprovalOverlay {
     current_request: Option<ApprovalRequest>,
@@ -70,6 +93,9 @@ pub(crate) struct ApprovalOverlay {
     done: bool,
     features: Features,
     language: Language,
+    /// When each pending request arrived, keyed by approval id.
+    requested_at: HashMap<String, Instant>,
+    timeout: Option<(ApprovalTimeout, FrameRequester)>,
 }
 
 impl ApprovalOverlay {
@@ -90,12 +116,68 @@ impl ApprovalOverlay {
             done: false,
             features,
             language,
+            requested_at: HashMap::new(),
+            timeout: None,
         };
+        view.record_arrival(&request);
         view.set_current(request);
         view
     }
 
+    /// Show a countdown to the configured timeout action. `frame_requester`
+    /// keeps the countdown ticking while the overlay is idle.
+    pub(crate) fn set_timeout(
+        &mut self,
+        timeout: ApprovalTimeout,
+        frame_requester: FrameRequester,
+    ) {
+        self.timeout = Some((timeout, frame_requester));
+    }
+
+    /// The agent decided `id` on the user's behalf; drop it without sending a
+    /// decision.
+    pub(crate) fn dismiss_request(&mut self, id: &str) {
+        self.requested_at.remove(id);
+        self.queue
+            .retain(|request| request.approval_id() != Some(id));
+        let is_current = self
+            .current_request
+            .as_ref()
+            .is_some_and(|request| request.approval_id() == Some(id));
+        if is_current && !self.current_complete {
+            self.current_complete = true;
+            self.advance_queue();
+        }
+    }
+
+    fn record_arrival(&mut self, request: &ApprovalRequest) {
+        if let Some(id) = request.approval_id() {
+            self.requested_at.insert(id.to_string(), Instant::now());
+        }
+    }
+
+    /// Countdown shown under the options while the current request waits.
+    fn countdown_line(&self) -> Option<Line<'static>> {
+        let (timeout, _) = self.timeout.as_ref()?;
+        if self.current_complete {
+            return None;
+        }
+        let id = self.current_request.as_ref()?.approval_id()?;
+        let requested_at = self.requested_at.get(id)?;
+        let remaining = timeout
+            .timeout
+            .saturating_sub(requested_at.elapsed())
+            .as_secs();
+        let action = match timeout.action {
+            ApprovalTimeoutAction::Deny => "Auto-deny",
+            ApprovalTimeoutAction::AllowIfPolicySafe => "Auto-decide by sandbox policy",
+            ApprovalTimeoutAction::AbortTurn => "Auto-abort turn",
+        };
+        Some(Line::from(format!("  {action} in {remaining}s")).dim())
+    }
+
     pub fn enqueue_request(&mut self, req: ApprovalRequest) {
+        self.record_arrival(&req);
         self.queue.push(req);
     }
 
@@ -326,15 +408,39 @@ impl BottomPaneView for ApprovalOverlay {
         self.enqueue_request(request);
         None
     }
+
+    fn dismiss_approval_request(&mut self, id: &str) {
+        self.dismiss_request(id);
+    }
 }
 
 impl Renderable for ApprovalOverlay {
     fn desired_height(&self, width: u16) -> u16 {
-        self.list.desired_height(width)
+        let countdown = u16::from(self.countdown_line().is_some());
+        self.list.desired_height(width).saturating_add(countdown)
     }
 
     fn render(&self, area: Rect, buf: &mut Buffer) {
-        self.list.render(area, buf);
+        let Some(countdown) = self.countdown_line() else {
+            self.list.render(area, buf);
+            return;
+        };
+        let list_area = Rect {
+            height: area.height.saturating_sub(1),
+            ..area
+        };
+        self.list.render(list_area, buf);
+        if area.height > 0 {
+            let countdown_area = Rect {
+                y: area.bottom() - 1,
+                height: 1,
+                ..area
+            };
+            countdown.render(countdown_area, buf);
+        }
+        if let Some((_, frame_requester)) = self.timeout.as_ref() {
+            frame_requester.schedule_frame_in(Duration::from_secs(1));
+        }
     }
 
     fn cursor_pos(&self, area: Rect) -> Option<(u16, u16)> {
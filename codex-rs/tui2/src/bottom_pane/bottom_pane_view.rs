@@ -34,4 +34,7 @@ pub(crate) trait BottomPaneView: Renderable {
     ) -> Option<ApprovalRequest> {
         Some(request)
     }
+
+    /// Drop a pending approval the agent already decided (e.g. on timeout).
+    fn dismiss_approval_request(&mut self, _id: &str) {}
 }
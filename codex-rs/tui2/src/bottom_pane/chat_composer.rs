@@ -1471,7 +1471,10 @@ impl ChatComposer {
                 && let Some((_n, cmd)) = built_in_slash_commands()
                     .into_iter()
                     .find(|(command_name, _)| *command_name == name)
-                && cmd == SlashCommand::Review
+                && matches!(
+                    cmd,
+                    SlashCommand::Review | SlashCommand::Mode | SlashCommand::Ui
+                )
             {
                 self.textarea.set_text("");
                 return Some(InputResult::CommandWithArgs(cmd, rest.to_string()));
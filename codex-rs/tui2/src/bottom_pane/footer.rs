@@ -364,7 +364,7 @@ fn context_window_line(
     }
 
     if let Some(tokens) = used_tokens {
-        let used_fmt = format_tokens_compact(tokens);
+        let used_fmt = format_tokens_compact(tokens, language);
         let mut line = Line::from(vec![
             Span::from(format!(
                 "{used_fmt} {}",
@@ -37,6 +37,7 @@ use std::time::Duration;
 mod approval_overlay;
 pub(crate) use approval_overlay::ApprovalOverlay;
 pub(crate) use approval_overlay::ApprovalRequest;
+pub(crate) use approval_overlay::ApprovalTimeout;
 mod bottom_pane_view;
 mod chat_composer;
 mod chat_composer_history;
@@ -133,6 +134,8 @@ pub(crate) struct BottomPane {
     context_window_percent: Option<i64>,
     context_window_used_tokens: Option<i64>,
     token_usage: Option<TokenUsageSplit>,
+    /// Auto-decision for unanswered approvals, shown as a countdown.
+    approval_timeout: Option<ApprovalTimeout>,
 }
 
 pub(crate) struct BottomPaneParams {
@@ -185,6 +188,7 @@ impl BottomPane {
             context_window_percent: None,
             context_window_used_tokens: None,
             token_usage: None,
+            approval_timeout: None,
         }
     }
 
@@ -205,6 +209,10 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    pub(crate) fn set_approval_timeout(&mut self, timeout: Option<ApprovalTimeout>) {
+        self.approval_timeout = timeout;
+    }
+
     pub fn set_steer_enabled(&mut self, enabled: bool) {
         self.composer.set_steer_enabled(enabled);
     }
@@ -589,16 +597,32 @@ impl BottomPane {
         };
 
         // Otherwise create a new approval modal overlay.
-        let modal = ApprovalOverlay::new(
+        let mut modal = ApprovalOverlay::new(
             request,
             self.app_event_tx.clone(),
             features.clone(),
             self.language,
         );
+        if let Some(timeout) = self.approval_timeout {
+            modal.set_timeout(timeout, self.frame_requester.clone());
+        }
         self.pause_status_timer_for_modal();
         self.push_view(Box::new(modal));
     }
 
+    /// Called when the agent decided a pending approval on the user's behalf.
+    pub(crate) fn dismiss_approval_request(&mut self, id: &str) {
+        for view in &mut self.view_stack {
+            view.dismiss_approval_request(id);
+        }
+        let had_views = !self.view_stack.is_empty();
+        self.view_stack.retain(|view| !view.is_complete());
+        if had_views && self.view_stack.is_empty() {
+            self.on_active_view_complete();
+        }
+        self.request_redraw();
+    }
+
     fn on_active_view_complete(&mut self) {
         self.resume_status_timer_after_modal();
     }
@@ -36,7 +36,9 @@ use codex_core::config::types::SubagentPreset;
 use codex_core::features::Feature;
 use codex_core::git_info::current_branch_name;
 use codex_core::git_info::local_git_branches;
+use codex_core::locale_format::format_limit_window;
 use codex_core::models_manager::manager::ModelsManager;
+use codex_core::personas::available_personas;
 use codex_core::project_doc::DEFAULT_PROJECT_DOC_FILENAME;
 use codex_core::protocol::AgentMessageDeltaEvent;
 use codex_core::protocol::AgentMessageEvent;
@@ -46,6 +48,7 @@ use codex_core::protocol::AgentReasoningRawContentDeltaEvent;
 use codex_core::protocol::AgentReasoningRawContentEvent;
 use codex_core::protocol::AgentStatus;
 use codex_core::protocol::ApplyPatchApprovalRequestEvent;
+use codex_core::protocol::ApprovalTimedOutEvent;
 use codex_core::protocol::BackgroundEventEvent;
 use codex_core::protocol::CollabAgentInteractionBeginEvent;
 use codex_core::protocol::CollabAgentInteractionEndEvent;
@@ -55,6 +58,8 @@ use codex_core::protocol::CollabCloseBeginEvent;
 use codex_core::protocol::CollabCloseEndEvent;
 use codex_core::protocol::CollabWaitingBeginEvent;
 use codex_core::protocol::CollabWaitingEndEvent;
+use codex_core::protocol::ContextBreakdownEvent;
+use codex_core::protocol::ContextComponentKind;
 use codex_core::protocol::CreditsSnapshot;
 use codex_core::protocol::DeprecationNoticeEvent;
 use codex_core::protocol::ErrorEvent;
@@ -76,11 +81,16 @@ use codex_core::protocol::McpToolCallBeginEvent;
 use codex_core::protocol::McpToolCallEndEvent;
 use codex_core::protocol::Op;
 use codex_core::protocol::PatchApplyBeginEvent;
+use codex_core::protocol::PatchProposalEvent;
 use codex_core::protocol::RateLimitSnapshot;
 use codex_core::protocol::RequestUserInputEvent;
+use codex_core::protocol::ReviewDecision;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
 use codex_core::protocol::SddGitAction;
+use codex_core::protocol::SessionChangesEvent;
+use codex_core::protocol::SessionNoteEvent;
+use codex_core::protocol::SessionSummaryEvent;
 use codex_core::protocol::SkillsListEntry;
 use codex_core::protocol::StreamErrorEvent;
 use codex_core::protocol::TerminalInteractionEvent;
@@ -138,6 +148,7 @@ use crate::app_event::WindowsSandboxEnableMode;
 use crate::app_event::WindowsSandboxFallbackReason;
 use crate::app_event_sender::AppEventSender;
 use crate::bottom_pane::ApprovalRequest;
+use crate::bottom_pane::ApprovalTimeout;
 use crate::bottom_pane::BottomPane;
 use crate::bottom_pane::BottomPaneParams;
 use crate::bottom_pane::CancellationEvent;
@@ -158,6 +169,7 @@ use crate::diff_render::display_path_for;
 use crate::exec_cell::CommandOutput;
 use crate::exec_cell::ExecCell;
 use crate::exec_cell::new_active_exec_command;
+use crate::exec_command::strip_bash_lc_and_escape;
 use crate::get_git_diff::get_git_diff;
 use crate::history_cell;
 use crate::history_cell::AgentMessageCell;
@@ -179,6 +191,7 @@ use crate::render::renderable::RenderableExt;
 use crate::render::renderable::RenderableItem;
 use crate::slash_command::SlashCommand;
 use crate::status::RateLimitSnapshotDisplay;
+use crate::status::format_tokens_compact;
 use crate::text_formatting::truncate_text;
 use crate::tui::FrameRequester;
 mod interrupts;
@@ -199,11 +212,19 @@ use codex_common::token_usage::split_total_and_last;
 use codex_core::AuthManager;
 use codex_core::CodexAuth;
 use codex_core::ThreadManager;
+use codex_core::auth::CLIENT_ID;
+use codex_core::auth::TokenRefreshHealth;
+use codex_core::env::is_headless_environment;
 use codex_core::git_info::get_git_repo_root;
 use codex_core::protocol::AskForApproval;
 use codex_core::protocol::SandboxPolicy;
 use codex_file_search::FileMatch;
+use codex_login::ServerOptions;
+use codex_login::complete_device_code_login;
+use codex_login::request_device_code;
+use codex_login::run_login_server;
 use codex_protocol::config_types::Language;
+use codex_protocol::openai_models::ModelCapabilities;
 use codex_protocol::openai_models::ModelPreset;
 use codex_protocol::openai_models::ReasoningEffort as ReasoningEffortConfig;
 use codex_protocol::plan_tool::UpdatePlanArgs;
@@ -217,6 +238,8 @@ fn user_shell_command_help_hint(language: Language) -> &'static str {
     tr(language, "chatwidget.shell_help.example")
 }
 const SDD_BRANCH_PREFIX: &str = "sdd/";
+/// Written to the session cwd by the "Export patch" entry of `/changes`.
+const SESSION_PATCH_FILENAME: &str = "codex-session.patch";
 
 fn init_prompt(language: Language) -> &'static str {
     tr(language, "prompt.init")
@@ -305,9 +328,8 @@ impl RateLimitWarningState {
             }
             if let Some(threshold) = highest_secondary {
                 let limit_label = secondary_window_minutes
-                    .map(get_limits_duration)
-                    .unwrap_or_else(|| "weekly".to_string());
-                let limit_label = localize_limit_label(limit_label, language);
+                    .map(|minutes| format_limit_window(language, minutes))
+                    .unwrap_or_else(|| tr(language, "format.limit_window.weekly").to_string());
                 let remaining_percent = 100.0 - threshold;
                 let remaining_percent = format!("{remaining_percent:.0}");
                 let message = tr_args(
@@ -332,9 +354,8 @@ impl RateLimitWarningState {
             }
             if let Some(threshold) = highest_primary {
                 let limit_label = primary_window_minutes
-                    .map(get_limits_duration)
-                    .unwrap_or_else(|| "5h".to_string());
-                let limit_label = localize_limit_label(limit_label, language);
+                    .map(|minutes| format_limit_window(language, minutes))
+                    .unwrap_or_else(|| format_limit_window(language, 5 * 60));
                 let remaining_percent = 100.0 - threshold;
                 let remaining_percent = format!("{remaining_percent:.0}");
                 let message = tr_args(
@@ -353,49 +374,6 @@ impl RateLimitWarningState {
     }
 }
 
-fn localize_limit_label(label: String, language: Language) -> String {
-    match label.as_str() {
-        "weekly" => tr(language, "chatwidget.rate_limit.label.weekly").to_string(),
-        "monthly" => tr(language, "chatwidget.rate_limit.label.monthly").to_string(),
-        "annual" => tr(language, "chatwidget.rate_limit.label.annual").to_string(),
-        _ => {
-            if let Some(number) = label.strip_suffix('h')
-                && number.parse::<i64>().is_ok()
-            {
-                tr_args(
-                    language,
-                    "chatwidget.rate_limit.label.hours",
-                    &[("count", number)],
-                )
-            } else {
-                label
-            }
-        }
-    }
-}
-
-pub(crate) fn get_limits_duration(windows_minutes: i64) -> String {
-    const MINUTES_PER_HOUR: i64 = 60;
-    const MINUTES_PER_DAY: i64 = 24 * MINUTES_PER_HOUR;
-    const MINUTES_PER_WEEK: i64 = 7 * MINUTES_PER_DAY;
-    const MINUTES_PER_MONTH: i64 = 30 * MINUTES_PER_DAY;
-    const ROUNDING_BIAS_MINUTES: i64 = 3;
-
-    let windows_minutes = windows_minutes.max(0);
-
-    if windows_minutes <= MINUTES_PER_DAY.saturating_add(ROUNDING_BIAS_MINUTES) {
-        let adjusted = windows_minutes.saturating_add(ROUNDING_BIAS_MINUTES);
-        let hours = std::cmp::max(1, adjusted / MINUTES_PER_HOUR);
-        format!("{hours}h")
-    } else if windows_minutes <= MINUTES_PER_WEEK.saturating_add(ROUNDING_BIAS_MINUTES) {
-        "weekly".to_string()
-    } else if windows_minutes <= MINUTES_PER_MONTH.saturating_add(ROUNDING_BIAS_MINUTES) {
-        "monthly".to_string()
-    } else {
-        "annual".to_string()
-    }
-}
-
 /// Common initialization parameters shared by all `ChatWidget` constructors.
 pub(crate) struct ChatWidgetInit {
     pub(crate) config: Config,
@@ -440,6 +418,36 @@ struct SddDevelopState {
     stage: SddDevelopStage,
 }
 
+/// Reloads the auth manager after a successful `/login` and builds the cell
+/// reporting the outcome.
+fn relogin_result_cell(
+    auth_manager: &AuthManager,
+    language: Language,
+    result: std::io::Result<()>,
+) -> Box<dyn HistoryCell> {
+    match result {
+        Ok(()) => {
+            auth_manager.reload();
+            Box::new(history_cell::new_info_event(
+                tr(language, "chatwidget.login.succeeded").to_string(),
+                None,
+            ))
+        }
+        Err(err) => Box::new(history_cell::new_error_event(tr_args(
+            language,
+            "chatwidget.login.failed",
+            &[("error", &err.to_string())],
+        ))),
+    }
+}
+
+/// Sign-in problem the last `/login` warning was shown for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ReloginWarning {
+    RefreshDue,
+    Expired,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum SddGitPendingAction {
     CreateBranchForPlan { description: String },
@@ -514,6 +522,8 @@ pub(crate) struct ChatWidget {
     // Previous status header to restore after a transient stream retry.
     retry_status_header: Option<String>,
     conversation_id: Option<ThreadId>,
+    // Last sign-in warning shown; cleared when tokens recover.
+    relogin_warning: Option<ReloginWarning>,
     frame_requester: FrameRequester,
     // Whether to include the initial welcome banner on session configured
     show_welcome_banner: bool,
@@ -634,10 +644,10 @@ impl ChatWidget {
             .set_task_running(self.agent_turn_running || self.mcp_startup_status.is_some());
     }
     fn flush_answer_stream_with_separator(&mut self) {
-        if let Some(mut controller) = self.stream_controller.take()
-            && let Some(cell) = controller.finalize()
-        {
-            self.add_boxed_history(cell);
+        if let Some(mut controller) = self.stream_controller.take() {
+            for cell in controller.finalize() {
+                self.add_boxed_history(cell);
+            }
         }
     }
 
@@ -682,7 +692,9 @@ impl ChatWidget {
 
         if let Some(messages) = initial_messages {
             self.replay_initial_messages(messages);
+            self.warn_if_resumed_context_window_shrank();
         }
+        self.maybe_warn_relogin();
         // Ask codex-core to enumerate custom prompts for this session.
         self.submit_op(Op::ListCustomPrompts);
         self.submit_op(Op::ListSkills {
@@ -809,6 +821,7 @@ impl ChatWidget {
     }
 
     fn on_task_complete(&mut self, last_agent_message: Option<String>) {
+        self.maybe_warn_relogin();
         // If a stream is currently active, finalize it.
         self.flush_answer_stream_with_separator();
         // Mark task stopped and request redraw now that all content is in history.
@@ -1069,6 +1082,7 @@ impl ChatWidget {
         self.pending_request_user_input.clear();
         self.interrupts.take_request_user_input();
         self.add_to_history(history_cell::new_error_event(message));
+        self.maybe_warn_relogin();
         self.request_redraw();
 
         // After an error ends the turn, try sending the next queued input.
@@ -1299,6 +1313,34 @@ impl ChatWidget {
         ));
     }
 
+    fn on_patch_proposal(&mut self, event: PatchProposalEvent) {
+        let language = self.config.language;
+        self.add_info_message(
+            tr(language, "explore_mode.proposal").to_string(),
+            Some(tr(language, "explore_mode.proposal_hint").to_string()),
+        );
+        self.add_to_history(history_cell::new_patch_event(
+            event.changes,
+            &self.config.cwd,
+        ));
+    }
+
+    fn on_approval_timed_out(&mut self, event: ApprovalTimedOutEvent) {
+        self.bottom_pane.dismiss_approval_request(&event.call_id);
+        let outcome = match event.decision {
+            ReviewDecision::Approved => "approved as safe under the sandbox policy",
+            ReviewDecision::Abort => "denied and the turn was interrupted",
+            _ => "denied",
+        };
+        self.add_info_message(
+            format!(
+                "Approval timed out after {}s: {outcome}",
+                event.timeout_seconds
+            ),
+            Some(format!("approval_timeout_action = \"{}\"", event.action)),
+        );
+    }
+
     fn on_view_image_tool_call(&mut self, event: ViewImageToolCallEvent) {
         self.flush_answer_stream_with_separator();
         self.add_to_history(history_cell::new_view_image_tool_call(
@@ -1672,15 +1714,18 @@ impl ChatWidget {
             .unwrap_or(true);
         if needs_new {
             self.flush_active_cell();
-            self.active_cell = Some(Box::new(new_active_exec_command(
-                ev.call_id.clone(),
-                command,
-                parsed,
-                source,
-                ev.interaction_input.clone(),
-                self.config.animations,
-                self.config.language,
-            )));
+            self.active_cell = Some(Box::new(
+                new_active_exec_command(
+                    ev.call_id.clone(),
+                    command,
+                    parsed,
+                    source,
+                    ev.interaction_input.clone(),
+                    self.config.animations,
+                    self.config.language,
+                )
+                .with_output_max_lines(self.config.tui_exec_output_lines),
+            ));
         }
 
         if let Some(cell) = self
@@ -1691,12 +1736,14 @@ impl ChatWidget {
             let output = if is_unified_exec_interaction {
                 CommandOutput {
                     exit_code: ev.exit_code,
+                    timed_out: ev.timed_out,
                     formatted_output: String::new(),
                     aggregated_output: String::new(),
                 }
             } else {
                 CommandOutput {
                     exit_code: ev.exit_code,
+                    timed_out: ev.timed_out,
                     formatted_output: ev.formatted_output.clone(),
                     aggregated_output: ev.aggregated_output.clone(),
                 }
@@ -1927,15 +1974,18 @@ impl ChatWidget {
         } else {
             self.flush_active_cell();
 
-            self.active_cell = Some(Box::new(new_active_exec_command(
-                ev.call_id.clone(),
-                ev.command.clone(),
-                ev.parsed_cmd,
-                ev.source,
-                interaction_input,
-                self.config.animations,
-                self.config.language,
-            )));
+            self.active_cell = Some(Box::new(
+                new_active_exec_command(
+                    ev.call_id.clone(),
+                    ev.command.clone(),
+                    ev.parsed_cmd,
+                    ev.source,
+                    interaction_input,
+                    self.config.animations,
+                    self.config.language,
+                )
+                .with_output_max_lines(self.config.tui_exec_output_lines),
+            ));
             self.bump_active_cell_revision();
         }
 
@@ -2080,6 +2130,7 @@ impl ChatWidget {
             current_status_header: tr(language, "chatwidget.status.working").to_string(),
             retry_status_header: None,
             conversation_id: None,
+            relogin_warning: None,
             queued_user_messages: VecDeque::new(),
             pending_request_user_input: VecDeque::new(),
             show_welcome_banner: is_first_run,
@@ -2107,6 +2158,7 @@ impl ChatWidget {
         widget
             .bottom_pane
             .set_steer_enabled(widget.config.features.enabled(Feature::Steer));
+        widget.sync_approval_timeout();
 
         widget
     }
@@ -2191,6 +2243,7 @@ impl ChatWidget {
             current_status_header: status_header,
             retry_status_header: None,
             conversation_id: None,
+            relogin_warning: None,
             queued_user_messages: VecDeque::new(),
             pending_request_user_input: VecDeque::new(),
             show_welcome_banner: false,
@@ -2218,6 +2271,7 @@ impl ChatWidget {
         widget
             .bottom_pane
             .set_steer_enabled(widget.config.features.enabled(Feature::Steer));
+        widget.sync_approval_timeout();
 
         widget
     }
@@ -2437,9 +2491,25 @@ impl ChatWidget {
             SlashCommand::Preset => {
                 self.open_preset_popup();
             }
+            SlashCommand::Mode => {
+                self.show_explore_mode_status();
+            }
+            SlashCommand::Ui => {
+                self.add_info_message(
+                    tr_args(
+                        self.config.language,
+                        "ui.current",
+                        &[("current", "TUI2"), ("other", "TUI1")],
+                    ),
+                    None,
+                );
+            }
             SlashCommand::Collab => {
                 self.open_collab_popup();
             }
+            SlashCommand::Persona => {
+                self.open_persona_popup();
+            }
             SlashCommand::Approvals => {
                 self.open_approvals_popup();
             }
@@ -2485,6 +2555,9 @@ impl ChatWidget {
             SlashCommand::Quit | SlashCommand::Exit => {
                 self.request_quit_without_confirmation();
             }
+            SlashCommand::Login => {
+                self.start_relogin();
+            }
             SlashCommand::Logout => {
                 if let Err(e) = codex_core::auth::logout(
                     &self.config.codex_home,
@@ -2555,6 +2628,12 @@ impl ChatWidget {
                     }
                 }
             }
+            SlashCommand::Changes => {
+                self.submit_op(Op::ListSessionChanges);
+            }
+            SlashCommand::Context => {
+                self.submit_op(Op::InspectContext);
+            }
             SlashCommand::Mention => {
                 self.insert_str("@");
             }
@@ -3216,6 +3295,9 @@ impl ChatWidget {
             personality: None,
             spec_parallel_priority: None,
             spec_sdd_planning: Some(true),
+            explore_mode: None,
+            persona: None,
+            scope: None,
         });
     }
 
@@ -3239,6 +3321,9 @@ impl ChatWidget {
             personality: None,
             spec_parallel_priority: None,
             spec_sdd_planning: Some(previous),
+            explore_mode: None,
+            persona: None,
+            scope: None,
         });
     }
 
@@ -3252,6 +3337,40 @@ impl ChatWidget {
         }
         format!("{planning_prompt}\n\n{message}")
     }
+    fn set_explore_mode(&mut self, enabled: bool) {
+        self.config.explore_mode = enabled;
+        self.submit_op(Op::OverrideTurnContext {
+            cwd: None,
+            approval_policy: None,
+            sandbox_policy: None,
+            windows_sandbox_level: None,
+            model: None,
+            effort: None,
+            summary: None,
+            collaboration_mode: None,
+            personality: None,
+            spec_parallel_priority: None,
+            spec_sdd_planning: None,
+            explore_mode: Some(enabled),
+            persona: None,
+            scope: None,
+        });
+        self.show_explore_mode_status();
+    }
+
+    fn show_explore_mode_status(&mut self) {
+        let language = self.config.language;
+        let (message, hint) = if self.config.explore_mode {
+            ("explore_mode.on", "explore_mode.on_hint")
+        } else {
+            ("explore_mode.off", "explore_mode.off_hint")
+        };
+        self.add_info_message(
+            tr(language, message).to_string(),
+            Some(tr(language, hint).to_string()),
+        );
+    }
+
     fn dispatch_command_with_args(&mut self, cmd: SlashCommand, args: String) {
         if !cmd.available_during_task() && self.bottom_pane.is_task_running() {
             let message = format!(
@@ -3265,6 +3384,22 @@ impl ChatWidget {
 
         let trimmed = args.trim();
         match cmd {
+            SlashCommand::Ui if !trimmed.is_empty() => {
+                if trimmed == "switch" {
+                    self.switch_frontend();
+                } else {
+                    self.add_error_message(tr(self.config.language, "ui.usage").to_string());
+                }
+            }
+            SlashCommand::Mode if !trimmed.is_empty() => match trimmed {
+                "explore" => self.set_explore_mode(true),
+                "default" => self.set_explore_mode(false),
+                other => self.add_error_message(tr_args(
+                    self.config.language,
+                    "explore_mode.unknown",
+                    &[("mode", other)],
+                )),
+            },
             SlashCommand::Review if !trimmed.is_empty() => {
                 self.submit_op(Op::Review {
                     review_request: ReviewRequest {
@@ -3295,6 +3430,26 @@ impl ChatWidget {
         }
     }
 
+    /// Hands the conversation to TUI1. Resuming needs a rollout on
+    /// disk, which only exists once the first turn has been recorded.
+    fn switch_frontend(&mut self) {
+        let language = self.config.language;
+        if self.conversation_id.is_none() || !self.rollout_path().is_some_and(|path| path.exists())
+        {
+            self.add_error_message(tr_args(
+                language,
+                "ui.not_ready",
+                &[("flag", "--tui1"), ("other", "TUI1")],
+            ));
+            return;
+        }
+        self.add_info_message(
+            tr_args(language, "ui.switching", &[("other", "TUI1")]),
+            None,
+        );
+        self.app_event_tx.send(AppEvent::SwitchFrontend);
+    }
+
     pub(crate) fn handle_paste(&mut self, text: String) {
         self.bottom_pane.handle_paste(text);
     }
@@ -3590,6 +3745,8 @@ impl ChatWidget {
             EventMsg::TerminalInteraction(delta) => self.on_terminal_interaction(delta),
             EventMsg::ExecCommandOutputDelta(delta) => self.on_exec_command_output_delta(delta),
             EventMsg::PatchApplyBegin(ev) => self.on_patch_apply_begin(ev),
+            EventMsg::PatchProposal(ev) => self.on_patch_proposal(ev),
+            EventMsg::ApprovalTimedOut(ev) => self.on_approval_timed_out(ev),
             EventMsg::PatchApplyEnd(ev) => self.on_patch_apply_end(ev),
             EventMsg::ExecCommandEnd(ev) => self.on_exec_command_end(ev),
             EventMsg::ViewImageToolCall(ev) => self.on_view_image_tool_call(ev),
@@ -3610,6 +3767,16 @@ impl ChatWidget {
             }
             EventMsg::ShutdownComplete => self.on_shutdown_complete(),
             EventMsg::TurnDiff(TurnDiffEvent { unified_diff }) => self.on_turn_diff(unified_diff),
+            EventMsg::TurnSources(_) => {}
+            EventMsg::DryRunPlan(_) | EventMsg::GuardrailBlocked(_) => {}
+            EventMsg::SessionChanges(ev) => self.on_session_changes(ev),
+            EventMsg::SessionNote(ev) => self.on_session_note(ev),
+            EventMsg::SessionSummary(ev) => {
+                if from_replay {
+                    self.on_previous_session_summary(ev);
+                }
+            }
+            EventMsg::ContextBreakdown(ev) => self.on_context_breakdown(ev),
             EventMsg::DeprecationNotice(ev) => self.on_deprecation_notice(ev),
             EventMsg::BackgroundEvent(BackgroundEventEvent { message }) => {
                 self.on_background_event(message)
@@ -3909,6 +4076,9 @@ impl ChatWidget {
                 personality: None,
                 spec_parallel_priority: None,
                 spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
             }));
             tx.send(AppEvent::UpdateModel(switch_model.clone()));
             tx.send(AppEvent::UpdateReasoningEffort(Some(default_effort)));
@@ -3996,28 +4166,19 @@ impl ChatWidget {
     /// opens the full picker with every available preset.
     pub(crate) fn open_language_popup(&mut self) {
         let ui_language = self.config.language;
-        let items = vec![
-            SelectionItem {
-                name: language_name(ui_language, Language::En).to_string(),
-                description: Some(
-                    tr(ui_language, "chatwidget.language_popup.english_desc").to_string(),
-                ),
-                is_current: ui_language == Language::En,
-                actions: Self::language_selection_actions(Language::En),
-                dismiss_on_select: true,
-                ..Default::default()
-            },
-            SelectionItem {
-                name: language_name(ui_language, Language::ZhCn).to_string(),
+        let items = Language::ALL
+            .into_iter()
+            .map(|language| SelectionItem {
+                name: language_name(ui_language, language).to_string(),
                 description: Some(
-                    tr(ui_language, "chatwidget.language_popup.chinese_desc").to_string(),
+                    tr(ui_language, language_popup_description_key(language)).to_string(),
                 ),
-                is_current: ui_language == Language::ZhCn,
-                actions: Self::language_selection_actions(Language::ZhCn),
+                is_current: ui_language == language,
+                actions: Self::language_selection_actions(language),
                 dismiss_on_select: true,
                 ..Default::default()
-            },
-        ];
+            })
+            .collect();
 
         self.bottom_pane.show_selection_view(SelectionViewParams {
             title: Some(tr(ui_language, "chatwidget.language_popup.title").to_string()),
@@ -4118,6 +4279,68 @@ impl ChatWidget {
         });
     }
 
+    pub(crate) fn open_persona_popup(&mut self) {
+        let language = self.config.language;
+        let current = self.config.persona.clone();
+        let mut items: Vec<SelectionItem> = available_personas(&self.config)
+            .into_iter()
+            .map(|persona| {
+                let is_current = current.as_deref() == Some(persona.name.as_str());
+                SelectionItem {
+                    actions: Self::persona_selection_actions(Some(persona.name.clone())),
+                    name: persona.name,
+                    description: persona.description,
+                    is_current,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        items.push(SelectionItem {
+            name: tr(language, "chatwidget.persona_popup.none_name").to_string(),
+            description: Some(
+                tr(language, "chatwidget.persona_popup.none_description").to_string(),
+            ),
+            is_current: current.is_none(),
+            actions: Self::persona_selection_actions(None),
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "chatwidget.persona_popup.title").to_string()),
+            subtitle: Some(tr(language, "chatwidget.persona_popup.subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+    }
+
+    fn persona_selection_actions(persona: Option<String>) -> Vec<SelectionAction> {
+        vec![Box::new(move |tx| {
+            tx.send(AppEvent::CodexOp(Op::OverrideTurnContext {
+                cwd: None,
+                approval_policy: None,
+                sandbox_policy: None,
+                windows_sandbox_level: None,
+                model: None,
+                effort: None,
+                summary: None,
+                collaboration_mode: None,
+                personality: None,
+                spec_parallel_priority: None,
+                spec_sdd_planning: None,
+                explore_mode: None,
+                persona: Some(persona.clone()),
+                scope: None,
+            }));
+            tx.send(AppEvent::UpdatePersona(persona.clone()));
+            tx.send(AppEvent::PersistPersonaSelection {
+                persona: persona.clone(),
+            });
+        })]
+    }
+
     pub(crate) fn open_preset_popup(&mut self) {
         let language = self.config.language;
         let items = [
@@ -4536,6 +4759,9 @@ impl ChatWidget {
                 personality: None,
                 spec_parallel_priority: None,
                 spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
             }));
             tx.send(AppEvent::UpdateModel(model_for_action.clone()));
             tx.send(AppEvent::UpdateReasoningEffort(effort_for_action));
@@ -4572,6 +4798,9 @@ impl ChatWidget {
                 personality: None,
                 spec_parallel_priority: Some(enabled),
                 spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
             }));
             tx.send(AppEvent::UpdateSpecParallelPriority(enabled));
             tx.send(AppEvent::PersistSpecParallelPriority { enabled });
@@ -4605,6 +4834,9 @@ impl ChatWidget {
                     personality: None,
                     spec_parallel_priority: None,
                     spec_sdd_planning: None,
+                    explore_mode: None,
+                    persona: None,
+                    scope: None,
                 }));
             }
         })]
@@ -4666,6 +4898,9 @@ impl ChatWidget {
                 personality: None,
                 spec_parallel_priority: None,
                 spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
             }));
     }
 
@@ -4704,9 +4939,15 @@ impl ChatWidget {
             stored: Option<ReasoningEffortConfig>,
             display: ReasoningEffortConfig,
         }
+        let capabilities = self
+            .models_manager
+            .try_get_model_capabilities(&preset.model, &self.config)
+            .ok();
         let mut choices: Vec<EffortChoice> = Vec::new();
         for effort in ReasoningEffortConfig::iter() {
-            if supported.iter().any(|option| option.effort == effort) {
+            if supported.iter().any(|option| option.effort == effort)
+                && Self::capabilities_allow_effort(capabilities.as_ref(), effort)
+            {
                 choices.push(EffortChoice {
                     stored: Some(effort),
                     display: effort,
@@ -4845,6 +5086,9 @@ impl ChatWidget {
                 personality: None,
                 spec_parallel_priority: None,
                 spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
             }));
         self.app_event_tx.send(AppEvent::UpdateModel(model.clone()));
         self.app_event_tx
@@ -4862,6 +5106,82 @@ impl ChatWidget {
         );
     }
 
+    /// Switch the next turn to the following reasoning effort the current
+    /// model supports (wrapping around). Bound to `ctrl+r`; persisted only
+    /// with `tui.persist_reasoning_toggle`.
+    pub(crate) fn cycle_reasoning_effort(&mut self) {
+        let Some(model) = self.current_model().map(str::to_string) else {
+            return;
+        };
+        let Some(preset) = self
+            .models_manager
+            .try_list_models(&self.config)
+            .ok()
+            .and_then(|models| models.into_iter().find(|preset| preset.model == model))
+        else {
+            return;
+        };
+        let capabilities = self
+            .models_manager
+            .try_get_model_capabilities(&model, &self.config)
+            .ok();
+        let efforts: Vec<ReasoningEffortConfig> = preset
+            .supported_reasoning_efforts
+            .iter()
+            .map(|option| option.effort)
+            .filter(|effort| Self::capabilities_allow_effort(capabilities.as_ref(), *effort))
+            .collect();
+        if efforts.len() < 2 {
+            return;
+        }
+
+        let current = self
+            .config
+            .model_reasoning_effort
+            .unwrap_or(preset.default_reasoning_effort);
+        let next = efforts
+            .iter()
+            .position(|effort| *effort == current)
+            .map_or(efforts[0], |index| efforts[(index + 1) % efforts.len()]);
+
+        self.app_event_tx
+            .send(AppEvent::CodexOp(Op::OverrideTurnContext {
+                cwd: None,
+                approval_policy: None,
+                sandbox_policy: None,
+                windows_sandbox_level: None,
+                model: None,
+                effort: Some(Some(next)),
+                summary: None,
+                collaboration_mode: None,
+                personality: None,
+                spec_parallel_priority: None,
+                spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
+            }));
+        self.app_event_tx
+            .send(AppEvent::UpdateReasoningEffort(Some(next)));
+        if self.config.tui_persist_reasoning_toggle {
+            self.app_event_tx.send(AppEvent::PersistModelSelection {
+                model: model.clone(),
+                effort: Some(next),
+            });
+        }
+
+        let language = self.config.language;
+        let message = tr_args(
+            language,
+            "chatwidget.reasoning_cycled",
+            &[
+                ("model", &model),
+                ("effort", Self::reasoning_effort_label(language, next)),
+            ],
+        );
+        self.add_info_message(message, None);
+    }
+
     /// Open a popup to choose the approvals mode (ask for approval policy + sandbox policy).
     pub(crate) fn open_approvals_popup(&mut self) {
         let current_approval = self.config.approval_policy.value();
@@ -5026,6 +5346,9 @@ impl ChatWidget {
                 personality: None,
                 spec_parallel_priority: None,
                 spec_sdd_planning: None,
+                explore_mode: None,
+                persona: None,
+                scope: None,
             }));
             tx.send(AppEvent::UpdateAskForApprovalPolicy(approval));
             tx.send(AppEvent::UpdateSandboxPolicy(sandbox_clone));
@@ -5614,6 +5937,15 @@ impl ChatWidget {
         }
     }
 
+    fn sync_approval_timeout(&mut self) {
+        let action = self.config.approval_timeout_action;
+        self.bottom_pane.set_approval_timeout(
+            self.config
+                .approval_timeout
+                .map(|timeout| ApprovalTimeout { timeout, action }),
+        );
+    }
+
     pub(crate) fn set_full_access_warning_acknowledged(&mut self, acknowledged: bool) {
         self.config.notices.hide_full_access_warning = Some(acknowledged);
     }
@@ -5653,6 +5985,11 @@ impl ChatWidget {
         self.config.spec.parallel_priority = enabled;
     }
 
+    /// Set the active persona in the widget's config copy.
+    pub(crate) fn set_persona(&mut self, persona: Option<String>) {
+        self.config.persona = persona;
+    }
+
     /// Set one sub-agent preset model override in the widget's config copy.
     pub(crate) fn set_subagent_preset_model(
         &mut self,
@@ -5689,6 +6026,56 @@ impl ChatWidget {
         self.model.as_deref()
     }
 
+    /// Efforts are only hidden when the catalog lists the model's efforts and this one is absent.
+    fn capabilities_allow_effort(
+        capabilities: Option<&ModelCapabilities>,
+        effort: ReasoningEffortConfig,
+    ) -> bool {
+        capabilities.is_none_or(|capabilities| {
+            capabilities.reasoning_efforts.is_empty()
+                || capabilities.supports_reasoning_effort(effort)
+        })
+    }
+
+    /// Warn when a resumed session recorded a larger context window than the current model offers.
+    fn warn_if_resumed_context_window_shrank(&mut self) {
+        let Some(recorded_window) = self
+            .token_info
+            .as_ref()
+            .and_then(|info| info.model_context_window)
+        else {
+            return;
+        };
+        let Some(model) = self.current_model() else {
+            return;
+        };
+        let Some(current_window) = self
+            .models_manager
+            .try_get_model_capabilities(model, &self.config)
+            .ok()
+            .and_then(|capabilities| capabilities.context_window)
+        else {
+            return;
+        };
+        if current_window >= recorded_window {
+            return;
+        }
+        let language = self.config.language;
+        let message = tr_args(
+            language,
+            "chatwidget.context_window_shrank",
+            &[
+                (
+                    "recorded",
+                    &format_tokens_compact(recorded_window, language),
+                ),
+                ("model", model),
+                ("current", &format_tokens_compact(current_window, language)),
+            ],
+        );
+        self.on_warning(message);
+    }
+
     fn model_display_name(&self) -> &str {
         self.model.as_deref().unwrap_or(DEFAULT_MODEL_DISPLAY_NAME)
     }
@@ -5733,6 +6120,128 @@ impl ChatWidget {
         }
     }
 
+    /// Warns once per problem when the stored ChatGPT login is due for a
+    /// refresh or can no longer be refreshed, so the user can run `/login`
+    /// before a request fails.
+    fn maybe_warn_relogin(&mut self) {
+        let warning = match self.auth_manager.token_refresh_health() {
+            Some(TokenRefreshHealth::Failed(_)) => ReloginWarning::Expired,
+            Some(TokenRefreshHealth::RefreshDue { .. }) => ReloginWarning::RefreshDue,
+            Some(TokenRefreshHealth::Healthy { .. }) | None => {
+                self.relogin_warning = None;
+                return;
+            }
+        };
+        if self.relogin_warning == Some(warning) {
+            return;
+        }
+        self.relogin_warning = Some(warning);
+        let language = self.config.language;
+        let key = match warning {
+            ReloginWarning::RefreshDue => "chatwidget.login.refresh_due_warning",
+            ReloginWarning::Expired => "chatwidget.login.expired_warning",
+        };
+        self.add_to_history(history_cell::new_warning_event(
+            tr(language, key).to_string(),
+        ));
+        // Prefill `/login` so re-auth is a single Enter, unless the user has a
+        // draft. A due refresh usually succeeds on its own, so only suggest it.
+        if warning == ReloginWarning::Expired && self.bottom_pane.composer_is_empty() {
+            self.bottom_pane
+                .set_composer_text(format!("/{}", SlashCommand::Login.command()));
+        }
+        self.request_redraw();
+    }
+
+    /// Signs in to ChatGPT again without leaving the session. The auth manager
+    /// is reloaded in place, so the next request picks up the new tokens.
+    /// Hosts without a browser sign in with a device code instead.
+    fn start_relogin(&mut self) {
+        let opts = ServerOptions::new(
+            self.config.codex_home.clone(),
+            CLIENT_ID.to_string(),
+            self.config.forced_chatgpt_workspace_id.clone(),
+            self.config.cli_auth_credentials_store_mode,
+        );
+        if is_headless_environment() {
+            self.start_device_code_relogin(opts);
+        } else {
+            self.start_browser_relogin(opts);
+        }
+    }
+
+    fn start_browser_relogin(&mut self, opts: ServerOptions) {
+        let language = self.config.language;
+        let server = match run_login_server(opts) {
+            Ok(server) => server,
+            Err(err) => {
+                self.add_error_message(tr_args(
+                    language,
+                    "chatwidget.login.start_failed",
+                    &[("error", &err.to_string())],
+                ));
+                return;
+            }
+        };
+        self.add_info_message(
+            tr(language, "chatwidget.login.continue_in_browser").to_string(),
+            Some(server.auth_url.clone()),
+        );
+
+        let auth_manager = Arc::clone(&self.auth_manager);
+        let tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = match server.block_until_done().await {
+                // A newer `/login` replaced this callback server.
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => return,
+                result => result,
+            };
+            tx.send(AppEvent::InsertHistoryCell(relogin_result_cell(
+                &auth_manager,
+                language,
+                result,
+            )));
+        });
+    }
+
+    fn start_device_code_relogin(&mut self, mut opts: ServerOptions) {
+        opts.open_browser = false;
+        let language = self.config.language;
+        let auth_manager = Arc::clone(&self.auth_manager);
+        let tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let device_code = match request_device_code(&opts).await {
+                Ok(device_code) => device_code,
+                Err(err) => {
+                    tx.send(AppEvent::InsertHistoryCell(Box::new(
+                        history_cell::new_error_event(tr_args(
+                            language,
+                            "chatwidget.login.start_failed",
+                            &[("error", &err.to_string())],
+                        )),
+                    )));
+                    return;
+                }
+            };
+            tx.send(AppEvent::InsertHistoryCell(Box::new(
+                history_cell::new_info_event(
+                    tr_args(
+                        language,
+                        "chatwidget.login.device_code",
+                        &[("code", device_code.user_code.as_str())],
+                    ),
+                    Some(device_code.verification_url.clone()),
+                ),
+            )));
+            let result = complete_device_code_login(opts, device_code).await;
+            tx.send(AppEvent::InsertHistoryCell(relogin_result_cell(
+                &auth_manager,
+                language,
+                result,
+            )));
+        });
+    }
+
     pub(crate) fn add_info_message(&mut self, message: String, hint: Option<String>) {
         self.add_to_history(history_cell::new_info_event(message, hint));
         self.request_redraw();
@@ -5968,6 +6477,268 @@ impl ChatWidget {
         self.bottom_pane.set_custom_prompts(ev.custom_prompts);
     }
 
+    fn on_context_breakdown(&mut self, event: ContextBreakdownEvent) {
+        let language = self.config.language;
+        let excluded: Vec<ContextComponentKind> = event
+            .components
+            .iter()
+            .filter(|component| component.excluded)
+            .map(|component| component.kind)
+            .collect();
+        let items: Vec<SelectionItem> = event
+            .components
+            .iter()
+            .map(|component| {
+                let count = format_tokens_compact(component.tokens, language);
+                let tokens = tr_args(
+                    language,
+                    "context_breakdown.tokens",
+                    &[("count", count.as_str())],
+                );
+                if !component.droppable {
+                    return SelectionItem {
+                        name: context_component_label(language, component.kind).to_string(),
+                        description: Some(tokens),
+                        is_disabled: true,
+                        disabled_reason: Some(
+                            tr(language, "context_breakdown.always_sent").to_string(),
+                        ),
+                        ..Default::default()
+                    };
+                }
+
+                let kind = component.kind;
+                let mut next_excluded = excluded.clone();
+                let (description, selected_description) = if component.excluded {
+                    next_excluded.retain(|candidate| *candidate != kind);
+                    (
+                        format!("{tokens}  {}", tr(language, "context_breakdown.dropped")),
+                        format!(
+                            "{tokens}  {}",
+                            tr(language, "context_breakdown.send_again_hint")
+                        ),
+                    )
+                } else {
+                    next_excluded.push(kind);
+                    (
+                        tokens.clone(),
+                        format!("{tokens}  {}", tr(language, "context_breakdown.drop_hint")),
+                    )
+                };
+                let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+                    tx.send(AppEvent::CodexOp(Op::SetContextExclusions {
+                        excluded: next_excluded.clone(),
+                    }));
+                })];
+                SelectionItem {
+                    name: context_component_label(language, kind).to_string(),
+                    description: Some(description),
+                    selected_description: Some(selected_description),
+                    actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        let total = format_tokens_compact(event.total_tokens, language);
+        let subtitle = match event.model_context_window {
+            Some(window) => {
+                let window = format_tokens_compact(window, language);
+                tr_args(
+                    language,
+                    "context_breakdown.subtitle_window",
+                    &[("total", total.as_str()), ("window", window.as_str())],
+                )
+            }
+            None => tr_args(
+                language,
+                "context_breakdown.subtitle",
+                &[("total", total.as_str())],
+            ),
+        };
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "context_breakdown.title").to_string()),
+            subtitle: Some(subtitle),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    fn on_session_note(&mut self, event: SessionNoteEvent) {
+        let language = self.config.language;
+        let hint = event
+            .in_context
+            .then(|| tr(language, "session_note.in_context").to_string());
+        self.add_info_message(
+            tr_args(language, "session_note.saved", &[("text", &event.text)]),
+            hint,
+        );
+    }
+
+    /// Show the summary written when a resumed session was last quit.
+    fn on_previous_session_summary(&mut self, event: SessionSummaryEvent) {
+        if event.bullets.is_empty() {
+            return;
+        }
+        let mut lines: Vec<Line<'static>> = vec![
+            vec![
+                "• ".dim(),
+                tr(self.config.language, "session_summary.previous").bold(),
+            ]
+            .into(),
+        ];
+        lines.extend(
+            event
+                .bullets
+                .into_iter()
+                .map(|bullet| Line::from(vec!["  - ".dim(), bullet.into()])),
+        );
+        self.add_plain_history_lines(lines);
+    }
+
+    fn on_session_changes(&mut self, event: SessionChangesEvent) {
+        let language = self.config.language;
+        if event.files.is_empty() {
+            self.add_info_message(tr(language, "session_changes.empty").to_string(), None);
+            return;
+        }
+
+        let added: u32 = event.files.iter().map(|file| file.added_lines).sum();
+        let removed: u32 = event.files.iter().map(|file| file.removed_lines).sum();
+        let mut items: Vec<SelectionItem> = event
+            .files
+            .iter()
+            .map(|file| {
+                let path = file.path.clone();
+                let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+                    tx.send(AppEvent::OpenDiscardSessionChangeConfirmation { path: path.clone() });
+                })];
+                let stats = format!("+{} -{}", file.added_lines, file.removed_lines);
+                SelectionItem {
+                    name: display_path_for(&file.path, &self.config.cwd),
+                    selected_description: Some(format!(
+                        "{stats}  {}",
+                        tr(language, "session_changes.discard_hint")
+                    )),
+                    description: Some(stats),
+                    actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        let unified_diff = event.unified_diff;
+        items.push(SelectionItem {
+            name: tr(language, "session_changes.export").to_string(),
+            description: Some(tr_args(
+                language,
+                "session_changes.export_description",
+                &[("file", SESSION_PATCH_FILENAME)],
+            )),
+            actions: vec![Box::new(move |tx| {
+                tx.send(AppEvent::ExportSessionDiff {
+                    unified_diff: unified_diff.clone(),
+                });
+            })],
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+
+        let files = event.files.len().to_string();
+        let (added, removed) = (added.to_string(), removed.to_string());
+        let summary_key = if event.files.len() == 1 {
+            "session_changes.summary_one"
+        } else {
+            "session_changes.summary"
+        };
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "session_changes.title").to_string()),
+            subtitle: Some(tr_args(
+                language,
+                summary_key,
+                &[
+                    ("count", files.as_str()),
+                    ("added", added.as_str()),
+                    ("removed", removed.as_str()),
+                ],
+            )),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    pub(crate) fn open_discard_session_change_confirmation(&mut self, path: PathBuf) {
+        let display = display_path_for(&path, &self.config.cwd);
+        let discard_actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+            tx.send(AppEvent::CodexOp(Op::DiscardSessionChange {
+                path: path.clone(),
+            }));
+        })];
+        let keep_actions: Vec<SelectionAction> = vec![Box::new(|tx| {
+            tx.send(AppEvent::CodexOp(Op::ListSessionChanges));
+        })];
+
+        let language = self.config.language;
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr_args(
+                language,
+                "session_changes.discard_title",
+                &[("path", display.as_str())],
+            )),
+            subtitle: Some(tr(language, "session_changes.discard_subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items: vec![
+                SelectionItem {
+                    name: tr(language, "session_changes.discard").to_string(),
+                    actions: discard_actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                },
+                SelectionItem {
+                    name: tr(language, "session_changes.keep").to_string(),
+                    actions: keep_actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    pub(crate) fn export_session_diff(&mut self, unified_diff: String) {
+        let language = self.config.language;
+        let target = self.config.cwd.join(SESSION_PATCH_FILENAME);
+        let display = target.display().to_string();
+        match std::fs::write(&target, unified_diff) {
+            Ok(()) => self.add_info_message(
+                tr_args(
+                    language,
+                    "session_changes.exported",
+                    &[("path", display.as_str())],
+                ),
+                Some(tr_args(
+                    language,
+                    "session_changes.apply_hint",
+                    &[("file", SESSION_PATCH_FILENAME)],
+                )),
+            ),
+            Err(err) => {
+                let err = err.to_string();
+                self.add_error_message(tr_args(
+                    language,
+                    "session_changes.export_failed",
+                    &[("path", display.as_str()), ("error", err.as_str())],
+                ))
+            }
+        }
+    }
+
     fn on_list_skills(&mut self, ev: ListSkillsResponseEvent) {
         self.set_skills_from_response(&ev);
     }
@@ -6003,6 +6774,20 @@ impl ChatWidget {
             ..Default::default()
         });
 
+        items.push(SelectionItem {
+            name: tr(language, "chatwidget.review.staged").to_string(),
+            actions: vec![Box::new(move |tx: &AppEventSender| {
+                tx.send(AppEvent::CodexOp(Op::Review {
+                    review_request: ReviewRequest {
+                        target: ReviewTarget::StagedChanges,
+                        user_facing_hint: None,
+                    },
+                }));
+            })],
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+
         // New: Review a specific commit (opens commit picker)
         items.push(SelectionItem {
             name: tr(language, "chatwidget.review.commit").to_string(),
@@ -6016,6 +6801,15 @@ impl ChatWidget {
             ..Default::default()
         });
 
+        items.push(SelectionItem {
+            name: tr(language, "chatwidget.review.files").to_string(),
+            actions: vec![Box::new(move |tx| {
+                tx.send(AppEvent::OpenReviewFilesPrompt);
+            })],
+            dismiss_on_select: false,
+            ..Default::default()
+        });
+
         items.push(SelectionItem {
             name: tr(language, "chatwidget.review.custom").to_string(),
             actions: vec![Box::new(move |tx| {
@@ -6114,6 +6908,29 @@ impl ChatWidget {
         });
     }
 
+    pub(crate) fn show_review_files_prompt(&mut self) {
+        let tx = self.app_event_tx.clone();
+        let view = CustomPromptView::new(
+            tr(self.config.language, "chatwidget.review.files").to_string(),
+            tr(self.config.language, "chatwidget.review.files_hint").to_string(),
+            None,
+            self.config.language,
+            Box::new(move |input: String| {
+                let patterns: Vec<String> = input.split_whitespace().map(str::to_string).collect();
+                if patterns.is_empty() {
+                    return;
+                }
+                tx.send(AppEvent::CodexOp(Op::Review {
+                    review_request: ReviewRequest {
+                        target: ReviewTarget::Files { patterns },
+                        user_facing_hint: None,
+                    },
+                }));
+            }),
+        );
+        self.bottom_pane.show_view(Box::new(view));
+    }
+
     pub(crate) fn show_review_custom_prompt(&mut self) {
         let tx = self.app_event_tx.clone();
         let view = CustomPromptView::new(
@@ -6189,6 +7006,31 @@ impl ChatWidget {
         (!lines.is_empty()).then_some(lines)
     }
 
+    /// Returns `(call_id, command, output so far)` for the command the active
+    /// exec cell is still running, used to open the follow-output overlay.
+    pub(crate) fn running_exec_output(&self) -> Option<(String, String, String)> {
+        let cell = self
+            .active_cell
+            .as_ref()?
+            .as_any()
+            .downcast_ref::<ExecCell>()?;
+        let call = cell.running_call()?;
+        if call.is_unified_exec_interaction() {
+            return None;
+        }
+        let command = strip_bash_lc_and_escape(&call.command)
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let output = call
+            .output
+            .as_ref()
+            .map(|output| output.aggregated_output.clone())
+            .unwrap_or_default();
+        Some((call.call_id.clone(), command, output))
+    }
+
     /// Return a reference to the widget's current config (includes any
     /// runtime overrides applied via TUI, e.g., model or approval policy).
     pub(crate) fn config_ref(&self) -> &Config {
@@ -6210,6 +7052,18 @@ impl ChatWidget {
     }
 }
 
+fn language_popup_description_key(language: Language) -> &'static str {
+    match language {
+        Language::En => "chatwidget.language_popup.english_desc",
+        Language::ZhCn => "chatwidget.language_popup.chinese_desc",
+        Language::Ja => "chatwidget.language_popup.japanese_desc",
+        Language::Ko => "chatwidget.language_popup.korean_desc",
+        Language::De => "chatwidget.language_popup.german_desc",
+        Language::Fr => "chatwidget.language_popup.french_desc",
+        Language::Es => "chatwidget.language_popup.spanish_desc",
+    }
+}
+
 impl Drop for ChatWidget {
     fn drop(&mut self) {
         self.stop_rate_limit_poller();
@@ -6326,6 +7180,23 @@ fn example_prompt_placeholder(language: Language) -> String {
 
 // Extract the first bold (Markdown) element in the form **...** from `s`.
 // Returns the inner text if found; otherwise `None`.
+fn context_component_label(language: Language, kind: ContextComponentKind) -> &'static str {
+    let key = match kind {
+        ContextComponentKind::SystemPrompt => "context_breakdown.component.system_prompt",
+        ContextComponentKind::DeveloperInstructions => {
+            "context_breakdown.component.developer_instructions"
+        }
+        ContextComponentKind::ProjectDocs => "context_breakdown.component.project_docs",
+        ContextComponentKind::Skills => "context_breakdown.component.skills",
+        ContextComponentKind::EnvironmentContext => {
+            "context_breakdown.component.environment_context"
+        }
+        ContextComponentKind::History => "context_breakdown.component.history",
+        ContextComponentKind::ToolSchemas => "context_breakdown.component.tool_schemas",
+    };
+    tr(language, key)
+}
+
 fn extract_first_bold(s: &str) -> Option<String> {
     let bytes = s.as_bytes();
     let mut i = 0usize;
@@ -20,6 +20,8 @@ use codex_core::ThreadManager;
 use codex_core::config::Config;
 use codex_core::config::ConfigBuilder;
 use codex_core::config::Constrained;
+use codex_core::error::RefreshTokenFailedError;
+use codex_core::error::RefreshTokenFailedReason;
 use codex_core::features::Feature;
 use codex_core::models_manager::manager::ModelsManager;
 use codex_core::protocol::AgentMessageDeltaEvent;
@@ -480,6 +482,7 @@ async fn make_chatwidget_manual(
         current_status_header: String::from("Working"),
         retry_status_header: None,
         conversation_id: None,
+        relogin_warning: None,
         frame_requester: FrameRequester::test_dummy(),
         show_welcome_banner: true,
         queued_user_messages: VecDeque::new(),
@@ -572,6 +575,12 @@ fn find_text_input(op: &Op) -> Option<&str> {
     }
 }
 
+/// Whether a history cell shows `text`, ignoring where it was wrapped.
+fn cell_shows_text(lines: &[ratatui::text::Line<'static>], text: &str) -> bool {
+    let squash = |text: &str| text.split_whitespace().collect::<String>();
+    squash(&lines_to_single_string(lines)).contains(&squash(text))
+}
+
 fn make_token_info(total_tokens: i64, context_window: i64) -> TokenUsageInfo {
     fn usage(total_tokens: i64) -> TokenUsage {
         TokenUsage {
@@ -772,6 +781,76 @@ async fn rate_limit_snapshot_updates_and_retains_plan_type() {
     assert_eq!(chat.plan_type, Some(PlanType::Pro));
 }
 
+#[tokio::test]
+async fn relogin_warning_prefills_login_once_after_refresh_failure() {
+    let (mut chat, mut rx, _) = make_chatwidget_manual(None).await;
+    chat.auth_manager =
+        AuthManager::from_auth_for_testing(CodexAuth::create_dummy_chatgpt_auth_for_testing());
+
+    chat.maybe_warn_relogin();
+    assert!(drain_insert_history(&mut rx).is_empty());
+
+    chat.auth_manager
+        .record_refresh_failure_for_testing(RefreshTokenFailedError::new(
+            RefreshTokenFailedReason::Revoked,
+            "refresh token revoked",
+        ));
+    let warning = tr(chat.config.language, "chatwidget.login.expired_warning");
+    let warnings_shown = |rx: &mut tokio::sync::mpsc::UnboundedReceiver<AppEvent>| {
+        drain_insert_history(rx)
+            .iter()
+            .filter(|cell| cell_shows_text(cell, warning))
+            .count()
+    };
+    chat.on_task_complete(None);
+    assert_eq!(warnings_shown(&mut rx), 1);
+    assert_eq!(chat.bottom_pane.composer_text(), "/login");
+
+    // The same problem is not reported again on the next turn.
+    chat.bottom_pane.set_composer_text(String::new());
+    chat.on_task_complete(None);
+    assert_eq!(warnings_shown(&mut rx), 0);
+    assert_eq!(chat.bottom_pane.composer_text(), "");
+}
+
+#[tokio::test]
+async fn relogin_warning_mentions_due_refresh_without_prefilling() {
+    let (mut chat, mut rx, _) = make_chatwidget_manual(None).await;
+    chat.auth_manager = AuthManager::from_auth_for_testing(
+        CodexAuth::create_dummy_chatgpt_auth_refreshed_at_for_testing(
+            chrono::Utc::now() - chrono::Duration::days(30),
+        ),
+    );
+
+    chat.maybe_warn_relogin();
+    let cells = drain_insert_history(&mut rx);
+    let language = chat.config.language;
+    assert_eq!(cells.len(), 1);
+    assert!(
+        cell_shows_text(
+            &cells[0],
+            tr(language, "chatwidget.login.refresh_due_warning")
+        ),
+        "unexpected warning: {cells:?}"
+    );
+    assert_eq!(chat.bottom_pane.composer_text(), "");
+
+    // A failed refresh escalates to the expired warning.
+    chat.auth_manager
+        .record_refresh_failure_for_testing(RefreshTokenFailedError::new(
+            RefreshTokenFailedReason::Expired,
+            "refresh token expired",
+        ));
+    chat.maybe_warn_relogin();
+    let cells = drain_insert_history(&mut rx);
+    assert_eq!(cells.len(), 1);
+    assert!(
+        cell_shows_text(&cells[0], tr(language, "chatwidget.login.expired_warning")),
+        "unexpected warning: {cells:?}"
+    );
+    assert_eq!(chat.bottom_pane.composer_text(), "/login");
+}
+
 #[tokio::test]
 async fn rate_limit_switch_prompt_skips_when_on_lower_cost_model() {
     let (mut chat, _, _) = make_chatwidget_manual(Some(NUDGE_MODEL_SLUG)).await;
@@ -1096,7 +1175,9 @@ fn end_exec(
             aggregated_output: aggregated.clone(),
             exit_code,
             duration: std::time::Duration::from_millis(5),
+            timed_out: false,
             formatted_output: aggregated,
+            provenance: None,
         }),
     });
 }
@@ -1371,7 +1452,9 @@ async fn exec_end_without_begin_uses_event_command() {
             aggregated_output: "done".to_string(),
             exit_code: 0,
             duration: std::time::Duration::from_millis(5),
+            timed_out: false,
             formatted_output: "done".to_string(),
+            provenance: None,
         }),
     });
 
@@ -1414,6 +1497,30 @@ async fn exec_history_shows_unified_exec_startup_commands() {
     );
 }
 
+/// Selecting the staged option from the review popup submits a review of the
+/// staged changes.
+#[tokio::test]
+async fn review_popup_staged_action_submits_staged_review() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.open_review_popup();
+
+    // Move selection down to the third item: "Review staged changes"
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    let mut found = false;
+    while let Ok(ev) = rx.try_recv() {
+        if let AppEvent::CodexOp(Op::Review { review_request }) = ev {
+            assert_eq!(review_request.target, ReviewTarget::StagedChanges);
+            found = true;
+            break;
+        }
+    }
+    assert!(found, "expected a staged review to be submitted");
+}
+
 /// Selecting the custom prompt option from the review popup sends
 /// OpenReviewCustomPrompt to the app event channel.
 #[tokio::test]
@@ -1423,7 +1530,9 @@ async fn review_popup_custom_prompt_action_sends_event() {
     // Open the preset selection popup
     chat.open_review_popup();
 
-    // Move selection down to the fourth item: "Custom review instructions"
+    // Move selection down to the sixth item: "Custom review instructions"
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
     chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
     chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
     chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
@@ -2139,6 +2248,7 @@ async fn interrupt_exec_marks_failed_snapshot() {
         id: "call-int".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -2173,6 +2283,7 @@ async fn interrupted_turn_error_message_snapshot() {
         id: "task-1".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -2269,6 +2380,7 @@ async fn interrupted_turn_renders_queued_request_user_input_status() {
         id: "turn-2".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -3038,6 +3150,7 @@ async fn interrupt_restores_queued_messages_into_composer() {
         id: "turn-1".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -3076,6 +3189,7 @@ async fn interrupt_prepends_queued_messages_before_existing_composer_text() {
         id: "turn-1".into(),
         msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
             reason: TurnAbortReason::Interrupted,
+            cut_off_output_tokens: None,
         }),
     });
 
@@ -4037,7 +4151,9 @@ async fn chatwidget_exec_and_status_layout_vt100_snapshot() {
             aggregated_output: String::new(),
             exit_code: 0,
             duration: std::time::Duration::from_millis(16000),
+            timed_out: false,
             formatted_output: String::new(),
+            provenance: None,
         }),
     });
     chat.handle_codex_event(Event {
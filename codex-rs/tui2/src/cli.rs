@@ -32,6 +32,10 @@ pub struct Cli {
     #[clap(skip)]
     pub resume_show_all: bool,
 
+    /// Internal: take over a session another process is recording.
+    #[clap(skip)]
+    pub resume_force_takeover: bool,
+
     // Internal controls set by the top-level `codex fork` subcommand.
     // These are not exposed as user flags on the base `codex` command.
     #[clap(skip)]
@@ -90,6 +94,11 @@ pub struct Cli {
     )]
     pub dangerously_bypass_approvals_and_sandbox: bool,
 
+    /// Start in read-only explore mode: the sandbox denies all writes and
+    /// apply_patch calls are shown as proposals instead of being applied.
+    #[arg(long = "read-only", default_value_t = false)]
+    pub read_only: bool,
+
     /// Tell the agent to use the specified directory as its working root.
     #[clap(long = "cd", short = 'C', value_name = "DIR")]
     pub cwd: Option<PathBuf>,
@@ -107,6 +116,10 @@ pub struct Cli {
     #[arg(long = "no-alt-screen", default_value_t = false)]
     pub no_alt_screen: bool,
 
+    /// Screen-reader friendly mode; equivalent to `-c tui.accessible=true`.
+    #[arg(long = "a11y", default_value_t = false)]
+    pub accessible: bool,
+
     #[clap(skip)]
     pub config_overrides: CliConfigOverrides,
 }
@@ -120,6 +133,7 @@ impl From<codex_tui::Cli> for Cli {
             resume_last: cli.resume_last,
             resume_session_id: cli.resume_session_id,
             resume_show_all: cli.resume_show_all,
+            resume_force_takeover: cli.resume_force_takeover,
             fork_picker: cli.fork_picker,
             fork_last: cli.fork_last,
             fork_session_id: cli.fork_session_id,
@@ -132,10 +146,12 @@ impl From<codex_tui::Cli> for Cli {
             approval_policy: cli.approval_policy,
             full_auto: cli.full_auto,
             dangerously_bypass_approvals_and_sandbox: cli.dangerously_bypass_approvals_and_sandbox,
+            read_only: cli.read_only,
             cwd: cli.cwd,
             web_search: cli.web_search,
             add_dir: cli.add_dir,
             no_alt_screen: cli.no_alt_screen,
+            accessible: cli.accessible,
             config_overrides: cli.config_overrides,
         }
     }
@@ -18,6 +18,57 @@ pub enum PasteImageError {
     NoImage(String),
     EncodeFailed(String),
     IoError(String),
+    /// Every clipboard source was tried; each entry says why it gave nothing.
+    NoSource(Vec<ClipboardProbe>),
+}
+
+/// Outcome of one clipboard source tried while looking for an image, so the
+/// error can say what is missing instead of failing generically.
+#[derive(Debug, Clone)]
+pub enum ClipboardProbe {
+    /// The native clipboard (arboard) failed.
+    Native(PasteImageError),
+    /// Neither `DISPLAY` nor `WAYLAND_DISPLAY` is set.
+    NoDisplay,
+    /// `WAYLAND_DISPLAY` is set but `wl-paste` is not installed.
+    WlPasteMissing,
+    /// `wl-paste` ran but the Wayland clipboard offers no image.
+    WlPasteNoImage,
+    WlPasteFailed(String),
+    /// The terminal did not answer the OSC 52 clipboard query.
+    Osc52NoReply,
+    /// The terminal answered OSC 52 with something that is not an image.
+    Osc52NotImage,
+}
+
+impl ClipboardProbe {
+    fn to_message(&self, language: Language) -> String {
+        match self {
+            ClipboardProbe::Native(err) => tr_args(
+                language,
+                "clipboard.probe.native",
+                &[("detail", &err.to_message(language))],
+            ),
+            ClipboardProbe::NoDisplay => tr(language, "clipboard.probe.no_display").to_string(),
+            ClipboardProbe::WlPasteMissing => {
+                tr(language, "clipboard.probe.wl_paste_missing").to_string()
+            }
+            ClipboardProbe::WlPasteNoImage => {
+                tr(language, "clipboard.probe.wl_paste_no_image").to_string()
+            }
+            ClipboardProbe::WlPasteFailed(msg) => tr_args(
+                language,
+                "clipboard.probe.wl_paste_failed",
+                &[("detail", msg)],
+            ),
+            ClipboardProbe::Osc52NoReply => {
+                tr(language, "clipboard.probe.osc52_no_reply").to_string()
+            }
+            ClipboardProbe::Osc52NotImage => {
+                tr(language, "clipboard.probe.osc52_not_image").to_string()
+            }
+        }
+    }
 }
 
 impl std::fmt::Display for PasteImageError {
@@ -77,6 +128,18 @@ impl PasteImageError {
                 "clipboard.error.io_error",
                 &[("detail", self.localized_detail(language, msg).as_ref())],
             ),
+            Self::NoSource(probes) => {
+                let detail = probes
+                    .iter()
+                    .map(|probe| probe.to_message(language))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                tr_args(
+                    language,
+                    "clipboard.error.no_source",
+                    &[("detail", &detail)],
+                )
+            }
         }
     }
 }
@@ -178,9 +241,25 @@ pub fn paste_image_as_data_url() -> Result<ClipboardImage, PasteImageError> {
 }
 
 /// Capture image from system clipboard, encode to PNG, and return bytes + info.
+///
+/// On Linux, when the native clipboard yields nothing under Wayland, the image
+/// is read through `wl-paste` (wl-clipboard) instead, which also works on
+/// compositors without the data-control protocol arboard relies on.
 #[cfg(not(target_os = "android"))]
 pub fn paste_image_as_png() -> Result<(Vec<u8>, PastedImageInfo), PasteImageError> {
     let _span = tracing::debug_span!("paste_image_as_png").entered();
+    let dyn_img = match read_native_clipboard_image() {
+        Ok(img) => img,
+        #[cfg(target_os = "linux")]
+        Err(err) => read_wayland_clipboard_image(err)?,
+        #[cfg(not(target_os = "linux"))]
+        Err(err) => return Err(err),
+    };
+    encode_png(&dyn_img)
+}
+
+#[cfg(not(target_os = "android"))]
+fn read_native_clipboard_image() -> Result<image::DynamicImage, PasteImageError> {
     tracing::debug!("attempting clipboard image read");
     let mut cb = arboard::Clipboard::new()
         .map_err(|e| PasteImageError::ClipboardUnavailable(e.to_string()))?;
@@ -217,7 +296,13 @@ pub fn paste_image_as_png() -> Result<(Vec<u8>, PastedImageInfo), PasteImageErro
 
         image::DynamicImage::ImageRgba8(rgba_img)
     };
+    Ok(dyn_img)
+}
 
+#[cfg(not(target_os = "android"))]
+fn encode_png(
+    dyn_img: &image::DynamicImage,
+) -> Result<(Vec<u8>, PastedImageInfo), PasteImageError> {
     let mut png: Vec<u8> = Vec::new();
     {
         let span =
@@ -239,6 +324,81 @@ pub fn paste_image_as_png() -> Result<(Vec<u8>, PastedImageInfo), PasteImageErro
     ))
 }
 
+/// Fallback for Linux after the native clipboard failed: read the Wayland
+/// clipboard with `wl-paste`, or explain that there is no graphical session
+/// to read from at all.
+#[cfg(target_os = "linux")]
+fn read_wayland_clipboard_image(
+    error: PasteImageError,
+) -> Result<image::DynamicImage, PasteImageError> {
+    use PasteImageError::ClipboardUnavailable;
+    use PasteImageError::NoImage;
+
+    if !matches!(error, ClipboardUnavailable(_) | NoImage(_)) || is_probably_wsl() {
+        return Err(error);
+    }
+    if std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        if std::env::var_os("DISPLAY").is_none() {
+            return Err(PasteImageError::NoSource(vec![
+                ClipboardProbe::Native(error),
+                ClipboardProbe::NoDisplay,
+            ]));
+        }
+        return Err(error);
+    }
+
+    tracing::debug!("attempting wl-paste clipboard fallback");
+    wl_paste_image()
+        .map_err(|probe| PasteImageError::NoSource(vec![ClipboardProbe::Native(error), probe]))
+}
+
+#[cfg(target_os = "linux")]
+fn wl_paste_image() -> Result<image::DynamicImage, ClipboardProbe> {
+    let listed = run_wl_paste(&["--list-types"])?;
+    let Some(mime) = pick_image_mime_type(&String::from_utf8_lossy(&listed)) else {
+        return Err(ClipboardProbe::WlPasteNoImage);
+    };
+    tracing::debug!("wl-paste offers {mime}");
+    let bytes = run_wl_paste(&["--no-newline", "--type", &mime])?;
+    image::load_from_memory(&bytes).map_err(|e| ClipboardProbe::WlPasteFailed(e.to_string()))
+}
+
+#[cfg(target_os = "linux")]
+fn run_wl_paste(args: &[&str]) -> Result<Vec<u8>, ClipboardProbe> {
+    let output = std::process::Command::new("wl-paste")
+        .args(args)
+        .output()
+        .map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => ClipboardProbe::WlPasteMissing,
+            _ => ClipboardProbe::WlPasteFailed(err.to_string()),
+        })?;
+    // wl-paste exits non-zero when the clipboard is empty.
+    if !output.status.success() {
+        return Err(ClipboardProbe::WlPasteNoImage);
+    }
+    Ok(output.stdout)
+}
+
+/// Choose the type to request from `wl-paste --list-types` output: PNG when
+/// offered, otherwise the first image type we can decode.
+#[cfg(target_os = "linux")]
+fn pick_image_mime_type(listed: &str) -> Option<String> {
+    let image_types: Vec<&str> = listed
+        .lines()
+        .map(str::trim)
+        .filter(|mime| mime.starts_with("image/"))
+        .collect();
+    image_types
+        .iter()
+        .find(|mime| **mime == "image/png")
+        .or_else(|| {
+            image_types
+                .iter()
+                .find(|mime| image::ImageFormat::from_mime_type(mime).is_some())
+        })
+        .map(|mime| (*mime).to_string())
+}
+
 /// Android/Termux does not support arboard; return a clear error.
 #[cfg(target_os = "android")]
 pub fn paste_image_as_png() -> Result<(Vec<u8>, PastedImageInfo), PasteImageError> {
@@ -292,8 +452,9 @@ fn try_wsl_clipboard_fallback(
 ) -> Result<(PathBuf, PastedImageInfo), PasteImageError> {
     use PasteImageError::ClipboardUnavailable;
     use PasteImageError::NoImage;
+    use PasteImageError::NoSource;
 
-    if !is_probably_wsl() || !matches!(error, ClipboardUnavailable(_) | NoImage(_)) {
+    if !is_probably_wsl() || !matches!(error, ClipboardUnavailable(_) | NoImage(_) | NoSource(_)) {
         return Err(error.clone());
     }
 
@@ -674,3 +835,34 @@ mod clipboard_cache_tests {
         assert_eq!(result.found_cache_dir, false);
     }
 }
+
+#[cfg(test)]
+mod clipboard_fallback_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn prefers_png_from_wl_paste_types() {
+        assert_eq!(
+            pick_image_mime_type("text/plain\nimage/jpeg\nimage/png\n"),
+            Some("image/png".to_string())
+        );
+        assert_eq!(
+            pick_image_mime_type("image/x-unknown\nimage/jpeg\n"),
+            Some("image/jpeg".to_string())
+        );
+        assert_eq!(pick_image_mime_type("text/plain\nUTF8_STRING\n"), None);
+    }
+
+    #[test]
+    fn no_source_error_lists_every_probe() {
+        let err = PasteImageError::NoSource(vec![
+            ClipboardProbe::Native(PasteImageError::ClipboardUnavailable("timeout".into())),
+            ClipboardProbe::WlPasteMissing,
+        ]);
+        let message = err.to_message(Language::En);
+        assert!(message.contains("timeout"), "{message}");
+        assert!(message.contains("wl-clipboard"), "{message}");
+    }
+}
@@ -5,9 +5,13 @@ use codex_core::protocol::ExecCommandSource;
 use codex_protocol::config_types::Language;
 use codex_protocol::parse_command::ParsedCommand;
 
+use super::render::TOOL_CALL_MAX_LINES;
+
 #[derive(Clone, Debug, Default)]
 pub(crate) struct CommandOutput {
     pub(crate) exit_code: i32,
+    /// Whether the command was killed for exceeding its timeout.
+    pub(crate) timed_out: bool,
     /// The aggregated stderr + stdout interleaved.
     pub(crate) aggregated_output: String,
     /// The formatted output of the command, as seen by the model.
@@ -31,6 +35,7 @@ pub(crate) struct ExecCell {
     pub(crate) calls: Vec<ExecCall>,
     animations_enabled: bool,
     language: Language,
+    output_max_lines: usize,
 }
 
 impl ExecCell {
@@ -39,6 +44,7 @@ impl ExecCell {
             calls: vec![call],
             animations_enabled,
             language: Language::En,
+            output_max_lines: TOOL_CALL_MAX_LINES,
         }
     }
 
@@ -51,9 +57,20 @@ impl ExecCell {
             calls: vec![call],
             animations_enabled,
             language,
+            output_max_lines: TOOL_CALL_MAX_LINES,
         }
     }
 
+    /// Sets how many output lines an agent command keeps inline.
+    pub(crate) fn with_output_max_lines(mut self, output_max_lines: usize) -> Self {
+        self.output_max_lines = output_max_lines;
+        self
+    }
+
+    pub(crate) fn output_max_lines(&self) -> usize {
+        self.output_max_lines
+    }
+
     pub(crate) fn set_language(&mut self, language: Language) {
         self.language = language;
     }
@@ -85,6 +102,7 @@ impl ExecCell {
                 calls: [self.calls.clone(), vec![call]].concat(),
                 animations_enabled: self.animations_enabled,
                 language: self.language,
+                output_max_lines: self.output_max_lines,
             })
         } else {
             None
@@ -119,6 +137,7 @@ impl ExecCell {
                 call.duration = Some(elapsed);
                 call.output = Some(CommandOutput {
                     exit_code: 1,
+                    timed_out: false,
                     formatted_output: String::new(),
                     aggregated_output: String::new(),
                 });
@@ -149,6 +168,16 @@ impl ExecCell {
         self.calls.iter()
     }
 
+    /// The call that has started but not yet finished, if any. Unlike
+    /// [`Self::is_active`], this still reports a call after its first output
+    /// delta has arrived.
+    pub(crate) fn running_call(&self) -> Option<&ExecCall> {
+        self.calls
+            .iter()
+            .rev()
+            .find(|c| c.duration.is_none() && c.start_time.is_some())
+    }
+
     pub(crate) fn append_output(&mut self, call_id: &str, chunk: &str) -> bool {
         if chunk.is_empty() {
             return false;
@@ -11,6 +11,7 @@ use crate::render::highlight::highlight_bash_to_lines;
 use crate::render::line_utils::prefix_lines;
 use crate::render::line_utils::push_owned_lines;
 use crate::shimmer::shimmer_spans;
+use crate::shimmer::true_color_enabled;
 use crate::wrapping::RtOptions;
 use crate::wrapping::word_wrap_line;
 use crate::wrapping::word_wrap_lines;
@@ -93,6 +94,20 @@ fn format_unified_exec_interaction(
     }
 }
 
+fn timed_out_message(call: &ExecCall, language: Language) -> String {
+    let seconds = call
+        .duration
+        .unwrap_or_default()
+        .as_secs_f64()
+        .round()
+        .to_string();
+    tr_args(
+        language,
+        "exec_cell.output.timed_out",
+        &[("seconds", seconds.as_str())],
+    )
+}
+
 fn summarize_interaction_input(input: &str) -> String {
     let single_line = input.replace('\n', "\\n");
     let sanitized = single_line.replace('`', "\\`");
@@ -208,10 +223,7 @@ pub(crate) fn spinner(start_time: Option<Instant>, animations_enabled: bool) ->
         return "•".dim();
     }
     let elapsed = start_time.map(|st| st.elapsed()).unwrap_or_default();
-    if supports_color::on_cached(supports_color::Stream::Stdout)
-        .map(|level| level.has_16m)
-        .unwrap_or(false)
-    {
+    if true_color_enabled() {
         shimmer_spans("•")[0].clone()
     } else {
         let blink_on = (elapsed.as_millis() / 600).is_multiple_of(2);
@@ -269,6 +281,10 @@ impl HistoryCell for ExecCell {
                     ])
                 };
                 result.push_span(format!(" • {duration}").dim());
+                if output.timed_out {
+                    result.push_span(" • ".dim());
+                    result.push_span(timed_out_message(call, self.language()).red());
+                }
                 lines.push(result);
             }
         }
@@ -477,7 +493,7 @@ impl ExecCell {
             let line_limit = if call.is_user_shell_command() {
                 USER_SHELL_TOOL_CALL_MAX_LINES
             } else {
-                TOOL_CALL_MAX_LINES
+                self.output_max_lines()
             };
             let raw_output = output_lines(
                 Some(output),
@@ -489,11 +505,7 @@ impl ExecCell {
                 },
                 language,
             );
-            let display_limit = if call.is_user_shell_command() {
-                USER_SHELL_TOOL_CALL_MAX_LINES
-            } else {
-                layout.output_max_lines
-            };
+            let display_limit = line_limit;
 
             if raw_output.lines.is_empty() {
                 if !call.is_unified_exec_interaction() {
@@ -518,6 +530,8 @@ impl ExecCell {
                     );
                 }
 
+                let truncated =
+                    raw_output.omitted.is_some() || wrapped_output.len() > display_limit;
                 let trimmed_output =
                     self.truncate_lines_middle(&wrapped_output, display_limit, raw_output.omitted);
 
@@ -528,6 +542,22 @@ impl ExecCell {
                         Span::from(layout.output_block.subsequent_prefix),
                     ));
                 }
+
+                // Only a running command can be followed; once it finishes the
+                // full output lives in the transcript overlay instead.
+                if truncated && self.running_call().is_some() && !is_interaction {
+                    lines.push(Line::from(vec![
+                        Span::from(layout.output_block.subsequent_prefix),
+                        tr(language, "exec_cell.output.follow_hint").dim(),
+                    ]));
+                }
+            }
+
+            if output.timed_out {
+                lines.push(Line::from(vec![
+                    Span::from(layout.output_block.subsequent_prefix),
+                    timed_out_message(call, language).red(),
+                ]));
             }
         }
 
@@ -631,7 +661,6 @@ struct ExecDisplayLayout {
     command_continuation: PrefixedBlock,
     command_continuation_max_lines: usize,
     output_block: PrefixedBlock,
-    output_max_lines: usize,
 }
 
 impl ExecDisplayLayout {
@@ -639,13 +668,11 @@ impl ExecDisplayLayout {
         command_continuation: PrefixedBlock,
         command_continuation_max_lines: usize,
         output_block: PrefixedBlock,
-        output_max_lines: usize,
     ) -> Self {
         Self {
             command_continuation,
             command_continuation_max_lines,
             output_block,
-            output_max_lines,
         }
     }
 }
@@ -654,13 +681,13 @@ const EXEC_DISPLAY_LAYOUT: ExecDisplayLayout = ExecDisplayLayout::new(
     PrefixedBlock::new("  │ ", "  │ "),
     2,
     PrefixedBlock::new("  └ ", "    "),
-    5,
 );
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use codex_core::protocol::ExecCommandSource;
+    use pretty_assertions::assert_eq;
 
     #[test]
     fn user_shell_output_is_limited_by_screen_lines() {
@@ -680,6 +707,7 @@ mod tests {
         // all logical lines without any truncation?
         let output = CommandOutput {
             exit_code: 0,
+            timed_out: false,
             aggregated_output,
             formatted_output: String::new(),
         };
@@ -752,4 +780,68 @@ mod tests {
             "expected at most {USER_SHELL_TOOL_CALL_MAX_LINES} screen lines of user shell output, got {output_screen_lines}",
         );
     }
+
+    #[test]
+    fn timed_out_command_shows_timeout_line() {
+        let call = ExecCall {
+            call_id: "call-id".to_string(),
+            command: vec!["bash".into(), "-lc".into(), "sleep 100".into()],
+            parsed: Vec::new(),
+            output: Some(CommandOutput {
+                exit_code: 124,
+                timed_out: true,
+                aggregated_output: "waiting\n".to_string(),
+                formatted_output: String::new(),
+            }),
+            source: ExecCommandSource::Agent,
+            start_time: None,
+            duration: Some(std::time::Duration::from_millis(10_020)),
+            interaction_input: None,
+        };
+        let cell = ExecCell::new(call, false);
+
+        let text: Vec<String> = cell
+            .command_display_lines(80)
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(
+            text.last().map(String::as_str),
+            Some("    timed out after 10s")
+        );
+    }
+
+    #[test]
+    fn progress_rewrites_and_colors_render_as_final_state() {
+        let output = CommandOutput {
+            exit_code: 0,
+            timed_out: false,
+            aggregated_output: "\u{1b}[1;32mfetch\u{1b}[0m\u{1b}[K\n 10%\r 55%\r100%\ndone\r\n"
+                .to_string(),
+            formatted_output: String::new(),
+        };
+        let rendered = output_lines(
+            Some(&output),
+            OutputLinesParams {
+                line_limit: TOOL_CALL_MAX_LINES,
+                only_err: false,
+                include_angle_pipe: false,
+                include_prefix: false,
+            },
+            Language::En,
+        );
+
+        let text: Vec<String> = rendered
+            .lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(text, vec!["fetch", "100%", "done"]);
+        let fetch = rendered.lines[0]
+            .spans
+            .iter()
+            .find(|span| span.content == "fetch")
+            .expect("fetch span");
+        assert_eq!(fetch.style.fg, Some(Color::Green));
+    }
 }
@@ -674,7 +674,7 @@ pub fn new_approval_decision_cell(
 
     let snippet = Span::from(exec_snippet(&command)).dim();
     let (symbol, summary): (Span<'static>, Vec<Span<'static>>) = match decision {
-        Approved => (
+        Approved | ApprovedHunks { .. } => (
             "✔ ".green(),
             vec![
                 tr(language, "history.approval.approved.prefix").into(),
@@ -1085,7 +1085,7 @@ impl HistoryCell for SessionHeaderHistoryCell {
         let model_label = tr(self.language, "history.session_header.model_label");
         let directory_label = tr(self.language, "history.session_header.directory_label");
         let (model_spans, dir_spans) = match self.language {
-            Language::ZhCn => {
+            Language::ZhCn | Language::Ja | Language::Ko => {
                 let mut model_spans: Vec<Span<'static>> = vec![
                     Span::from(model_label).dim(),
                     Span::styled(self.model.clone(), self.model_style),
@@ -1106,7 +1106,7 @@ impl HistoryCell for SessionHeaderHistoryCell {
                 let dir_spans = vec![Span::from(dir_prefix).dim(), " ".into(), Span::from(dir)];
                 (model_spans, dir_spans)
             }
-            Language::En => {
+            Language::En | Language::De | Language::Fr | Language::Es => {
                 let label_width = UnicodeWidthStr::width(directory_label);
                 let model_label = format!("{model_label:<label_width$}");
                 let mut model_spans: Vec<Span<'static>> = vec![
@@ -1829,6 +1829,7 @@ pub(crate) fn new_patch_apply_failure(stderr: String, language: Language) -> Pla
         let output = output_lines(
             Some(&CommandOutput {
                 exit_code: 1,
+                timed_out: false,
                 formatted_output: String::new(),
                 aggregated_output: stderr,
             }),
@@ -2811,6 +2812,7 @@ mod tests {
             &call_id,
             CommandOutput {
                 exit_code: 1,
+                timed_out: false,
                 formatted_output: String::new(),
                 aggregated_output: stderr,
             },
@@ -2859,6 +2861,7 @@ mod tests {
             &call_id,
             CommandOutput {
                 exit_code: 1,
+                timed_out: false,
                 formatted_output: String::new(),
                 aggregated_output: stderr,
             },
@@ -25,6 +25,7 @@ use std::fmt;
 use std::io;
 use std::io::Write;
 
+use crate::color::terminal_color;
 use crate::wrapping::word_wrap_lines_borrowed;
 use crossterm::Command;
 use crossterm::cursor::MoveTo;
@@ -117,8 +118,16 @@ where
         queue!(
             writer,
             SetColors(Colors::new(
-                line.style.fg.map(Into::into).unwrap_or(CColor::Reset),
-                line.style.bg.map(Into::into).unwrap_or(CColor::Reset),
+                line.style
+                    .fg
+                    .map(terminal_color)
+                    .map(Into::into)
+                    .unwrap_or(CColor::Reset),
+                line.style
+                    .bg
+                    .map(terminal_color)
+                    .map(Into::into)
+                    .unwrap_or(CColor::Reset),
             ))
         )?;
         queue!(writer, Clear(ClearType::UntilNewLine))?;
@@ -274,8 +283,18 @@ where
             diff.queue(&mut writer)?;
             last_modifier = modifier;
         }
-        let next_fg = span.style.fg.map(Into::into).unwrap_or(CColor::Reset);
-        let next_bg = span.style.bg.map(Into::into).unwrap_or(CColor::Reset);
+        let next_fg = span
+            .style
+            .fg
+            .map(terminal_color)
+            .map(Into::into)
+            .unwrap_or(CColor::Reset);
+        let next_bg = span
+            .style
+            .bg
+            .map(terminal_color)
+            .map(Into::into)
+            .unwrap_or(CColor::Reset);
         if next_fg != fg || next_bg != bg {
             queue!(writer, SetColors(Colors::new(next_fg, next_bg)))?;
             fg = next_fg;
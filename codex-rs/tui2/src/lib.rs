@@ -102,7 +102,6 @@ mod wrapping;
 #[cfg(test)]
 pub mod test_backend;
 
-use crate::onboarding::TrustDirectorySelection;
 use crate::onboarding::onboarding_screen::OnboardingScreenArgs;
 use crate::onboarding::onboarding_screen::run_onboarding_app;
 use crate::tui::Tui;
@@ -142,6 +141,12 @@ pub async fn run_main(
             .push("web_search=\"live\"".to_string());
     }
 
+    if cli.accessible {
+        cli.config_overrides
+            .raw_overrides
+            .push("tui.accessible=true".to_string());
+    }
+
     // When using `--oss`, let the bootstrapper pick the model (defaulting to
     // gpt-oss:20b) and ensure it is present locally. Also, force the built‑in
     let raw_overrides = cli.config_overrides.raw_overrides.clone();
@@ -244,6 +249,9 @@ pub async fn run_main(
         show_raw_agent_reasoning: cli.oss.then_some(true),
         tools_web_search_request: None,
         ephemeral: None,
+        explore_mode: cli.read_only.then_some(true),
+        offline: None,
+        force_takeover: cli.resume_force_takeover.then_some(true),
         additional_writable_roots: additional_dirs,
     };
 
@@ -353,6 +361,10 @@ pub async fn run_main(
         .with(otel_logger_layer)
         .try_init();
 
+    for issue in codex_core::i18n::install_locale_overrides(&config.codex_home) {
+        tracing::warn!("ignoring locale override: {issue}");
+    }
+
     let terminal_info = codex_core::terminal::terminal_info();
     tracing::info!(terminal = ?terminal_info, "Detected terminal info");
 
@@ -453,12 +465,8 @@ async fn run_ratatui_app(
                 session_lines: Vec::new(),
             });
         }
-        // if the user acknowledged windows or made an explicit decision ato trust the directory, reload the config accordingly
-        if onboarding_result
-            .directory_trust_decision
-            .map(|d| d == TrustDirectorySelection::Trust)
-            .unwrap_or(false)
-        {
+        // Any trust decision changes what the project contributes, so reload the config.
+        if onboarding_result.directory_trust_decision.is_some() {
             load_config_or_exit(cli_kv_overrides, overrides).await
         } else {
             initial_config
@@ -605,8 +613,8 @@ async fn run_ratatui_app(
     // Zellij that strictly follow the xterm spec (which disallows scrollback in
     // alternate screen buffers). This auto-detects the terminal and disables
     // alternate screen in Zellij while keeping it enabled elsewhere.
-    let use_alt_screen = if no_alt_screen {
-        // CLI flag explicitly disables alternate screen
+    let use_alt_screen = if no_alt_screen || config.tui_accessible {
+        // CLI flag or accessible mode explicitly disables alternate screen
         false
     } else {
         match config.tui_alternate_screen {
@@ -773,7 +781,11 @@ mod tests {
         let temp_dir = TempDir::new()?;
         let mut config = build_config(&temp_dir).await?;
         config.did_user_set_custom_approval_policy_or_sandbox_mode = false;
-        config.active_project = ProjectConfig { trust_level: None };
+        config.active_project = ProjectConfig {
+            trust_level: None,
+            persona: None,
+            profile: None,
+        };
         config.set_windows_sandbox_enabled(false);
 
         let should_show = should_show_trust_screen(&config);
@@ -796,7 +808,11 @@ mod tests {
         let temp_dir = TempDir::new()?;
         let mut config = build_config(&temp_dir).await?;
         config.did_user_set_custom_approval_policy_or_sandbox_mode = false;
-        config.active_project = ProjectConfig { trust_level: None };
+        config.active_project = ProjectConfig {
+            trust_level: None,
+            persona: None,
+            profile: None,
+        };
         config.set_windows_sandbox_enabled(true);
 
         let should_show = should_show_trust_screen(&config);
@@ -821,6 +837,8 @@ mod tests {
         config.did_user_set_custom_approval_policy_or_sandbox_mode = false;
         config.active_project = ProjectConfig {
             trust_level: Some(TrustLevel::Untrusted),
+            persona: None,
+            profile: None,
         };
 
         let should_show = should_show_trust_screen(&config);
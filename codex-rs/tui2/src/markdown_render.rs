@@ -59,7 +59,7 @@ use ratatui::text::Text;
 /// - prepend a transcript gutter prefix (`• ` / `  `),
 /// - prepend markdown-specific indents (`initial_indent` / `subsequent_indent`), and
 /// - wrap `content` to the current width while producing joiners for copy/paste.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) struct MarkdownLogicalLine {
     /// The raw content for this logical line (does not include markdown prefix/indent spans).
     pub(crate) content: Line<'static>,
@@ -14,6 +14,14 @@
 //! - When committing, it re-renders the markdown for the *completed* prefix of the buffer and
 //!   returns only the newly completed logical lines since the last commit.
 //!
+//! ## Long messages
+//!
+//! Re-rendering the whole buffer on every commit makes long messages quadratic. Once enough source
+//! has accumulated, the collector freezes the committed blocks before the last safe block
+//! boundary: their source is dropped and later renders start at the boundary. Blocks that may use
+//! a link reference are never frozen, since a definition later in the message changes how they
+//! render.
+//!
 //! ## Width-agnostic output
 //!
 //! The committed output is `Vec<MarkdownLogicalLine>`, produced by
@@ -23,29 +31,40 @@
 
 use crate::markdown_render::MarkdownLogicalLine;
 
+/// Unfrozen source beyond this many bytes is worth trying to freeze.
+const FREEZE_THRESHOLD_BYTES: usize = 8 * 1024;
+
 /// Newline-gated accumulator that renders markdown and commits only fully
 /// completed logical lines.
 pub(crate) struct MarkdownStreamCollector {
-    /// Accumulated raw markdown source (concatenated streaming deltas).
+    /// Raw markdown source after the frozen blocks (concatenated streaming deltas).
     buffer: String,
-    /// Number of logical lines already emitted from the latest rendered prefix.
+    /// Logical line count of the dropped source. `buffer` renders to the lines that follow them.
+    frozen_line_count: usize,
+    /// Number of logical lines already emitted, counting frozen ones.
     ///
-    /// This is an index into the vector returned by `render_markdown_logical_lines` when applied
-    /// to the committed prefix of `buffer`.
+    /// Less `frozen_line_count`, this is an index into the vector returned by
+    /// `render_markdown_logical_lines` when applied to the committed prefix of `buffer`.
     committed_line_count: usize,
+    /// `buffer` length at which to try freezing again.
+    next_freeze_len: usize,
 }
 
 impl MarkdownStreamCollector {
     pub fn new() -> Self {
         Self {
             buffer: String::new(),
+            frozen_line_count: 0,
             committed_line_count: 0,
+            next_freeze_len: FREEZE_THRESHOLD_BYTES,
         }
     }
 
     pub fn clear(&mut self) {
         self.buffer.clear();
+        self.frozen_line_count = 0;
         self.committed_line_count = 0;
+        self.next_freeze_len = FREEZE_THRESHOLD_BYTES;
     }
 
     /// Append a streaming delta to the internal buffer.
@@ -54,31 +73,30 @@ impl MarkdownStreamCollector {
         self.buffer.push_str(delta);
     }
 
-    /// Render the full buffer and return only the newly completed logical lines
-    /// since the last commit. When the buffer does not end with a newline, the
-    /// final rendered line is considered incomplete and is not emitted.
+    /// Render the unfrozen buffer and return only the newly completed logical
+    /// lines since the last commit. When the buffer does not end with a
+    /// newline, the final rendered line is considered incomplete and is not
+    /// emitted.
     pub fn commit_complete_lines(&mut self) -> Vec<MarkdownLogicalLine> {
-        let source = self.buffer.clone();
-        let last_newline_idx = source.rfind('\n');
-        let source = if let Some(last_newline_idx) = last_newline_idx {
-            source[..=last_newline_idx].to_string()
-        } else {
+        let Some(last_newline_idx) = self.buffer.rfind('\n') else {
             return Vec::new();
         };
-        let rendered = crate::markdown_render::render_markdown_logical_lines(&source);
+        let complete_len = last_newline_idx + 1;
+        let rendered =
+            crate::markdown_render::render_markdown_logical_lines(&self.buffer[..complete_len]);
         let mut complete_line_count = rendered.len();
         if complete_line_count > 0 && is_blank_logical_line(&rendered[complete_line_count - 1]) {
             complete_line_count -= 1;
         }
 
-        if self.committed_line_count >= complete_line_count {
+        let already_committed = self.committed_line_count - self.frozen_line_count;
+        if already_committed >= complete_line_count {
             return Vec::new();
         }
 
-        let out_slice = &rendered[self.committed_line_count..complete_line_count];
-
-        let out = out_slice.to_vec();
-        self.committed_line_count = complete_line_count;
+        let out = rendered[already_committed..complete_line_count].to_vec();
+        self.committed_line_count = self.frozen_line_count + complete_line_count;
+        self.maybe_freeze(&rendered, complete_len);
         out
     }
 
@@ -87,32 +105,152 @@ impl MarkdownStreamCollector {
     /// for rendering. Optionally unwraps ```markdown language fences in
     /// non-test builds.
     pub fn finalize_and_drain(&mut self) -> Vec<MarkdownLogicalLine> {
-        let raw_buffer = self.buffer.clone();
-        let mut source: String = raw_buffer.clone();
+        let raw_len = self.buffer.len();
+        let mut source = std::mem::take(&mut self.buffer);
         if !source.ends_with('\n') {
             source.push('\n');
         }
         tracing::debug!(
-            raw_len = raw_buffer.len(),
+            raw_len,
             source_len = source.len(),
+            frozen_lines = self.frozen_line_count,
             "markdown finalize (raw length: {}, rendered length: {})",
-            raw_buffer.len(),
+            raw_len,
             source.len()
         );
         tracing::trace!("markdown finalize (raw source):\n---\n{source}\n---");
 
-        let rendered = crate::markdown_render::render_markdown_logical_lines(&source);
+        let mut rendered = crate::markdown_render::render_markdown_logical_lines(&source);
 
-        let out = if self.committed_line_count >= rendered.len() {
+        let already_committed = self.committed_line_count - self.frozen_line_count;
+        let out = if already_committed >= rendered.len() {
             Vec::new()
         } else {
-            rendered[self.committed_line_count..].to_vec()
+            rendered.split_off(already_committed)
         };
 
         // Reset collector state for next stream.
         self.clear();
         out
     }
+
+    /// Drop the committed blocks before the last block boundary in the first
+    /// `complete_len` bytes of the buffer, which render to `rendered`.
+    ///
+    /// The cut is kept only if rendering the rest on its own reproduces the
+    /// tail of `rendered` exactly and every line before it has been committed.
+    fn maybe_freeze(&mut self, rendered: &[MarkdownLogicalLine], complete_len: usize) {
+        if self.buffer.len() < self.next_freeze_len {
+            return;
+        }
+        self.next_freeze_len = self.buffer.len() + FREEZE_THRESHOLD_BYTES;
+        let Some(boundary) = last_block_boundary(&self.buffer[..complete_len]) else {
+            return;
+        };
+        let tail = crate::markdown_render::render_markdown_logical_lines(
+            &self.buffer[boundary..complete_len],
+        );
+        let Some(frozen) = rendered.len().checked_sub(tail.len()) else {
+            return;
+        };
+        if rendered[frozen..] != tail[..]
+            || self.frozen_line_count + frozen > self.committed_line_count
+        {
+            return;
+        }
+        self.buffer.drain(..boundary);
+        self.frozen_line_count += frozen;
+        self.next_freeze_len = self.buffer.len() + FREEZE_THRESHOLD_BYTES;
+    }
+}
+
+/// Byte offset of the last line in `source` that starts a top-level block no
+/// earlier content can extend: it follows a blank line outside any code fence
+/// and does not open a list item, block quote, table row, or indented block.
+///
+/// The search stops at the first line that may use a link reference, so its
+/// block is never frozen: the definition can arrive later in the message and
+/// change how the block renders, and a definition itself must stay in the
+/// source for the blocks that use it.
+fn last_block_boundary(source: &str) -> Option<usize> {
+    let mut boundary = None;
+    let mut fence: Option<(char, usize)> = None;
+    let mut previous_blank = false;
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let content = line.trim_end_matches(['\n', '\r']);
+        let trimmed = content.trim_start_matches(' ');
+        let indent = content.len() - trimmed.len();
+
+        let fence_marker = (indent < 4)
+            .then(|| {
+                let marker = trimmed.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+                let len = trimmed.chars().take_while(|c| *c == marker).count();
+                (len >= 3).then_some((marker, len))
+            })
+            .flatten();
+        if let Some((open_marker, open_len)) = fence {
+            if let Some((marker, len)) = fence_marker
+                && marker == open_marker
+                && len >= open_len
+                && trimmed[len..].trim().is_empty()
+            {
+                fence = None;
+            }
+            previous_blank = false;
+            continue;
+        }
+
+        let opens_block = match content.chars().next() {
+            None => false,
+            Some(first) => {
+                !(first.is_whitespace()
+                    || first.is_ascii_digit()
+                    || matches!(first, '-' | '*' | '+' | '>' | '|' | '<'))
+            }
+        };
+        if previous_blank && opens_block && line_start > 0 {
+            boundary = Some(line_start);
+        }
+        if may_reference_link(content) {
+            break;
+        }
+        if fence_marker.is_some() {
+            fence = fence_marker;
+        }
+        previous_blank = content.trim().is_empty();
+    }
+    boundary
+}
+
+/// Whether `line` has a closing bracket outside code spans that is not the end
+/// of an inline link, so it may be a reference link or a definition.
+fn may_reference_link(line: &str) -> bool {
+    let mut code_span_ticks = None;
+    let mut seen_open = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '`' => {
+                let mut len = 1;
+                while chars.next_if_eq(&'`').is_some() {
+                    len += 1;
+                }
+                code_span_ticks = match code_span_ticks {
+                    None => Some(len),
+                    Some(open) if open == len => None,
+                    open => open,
+                };
+            }
+            _ if code_span_ticks.is_some() => {}
+            '[' => seen_open = true,
+            ']' if seen_open && chars.peek() != Some(&'(') => return true,
+            _ => {}
+        }
+    }
+    false
 }
 
 fn is_blank_logical_line(line: &MarkdownLogicalLine) -> bool {
@@ -564,6 +702,101 @@ mod tests {
     }
 
     // Targeted tests derived from fuzz findings. Each asserts streamed == full render.
+    #[test]
+    fn block_boundaries_skip_fences_lists_and_quotes() {
+        let source = "Intro\n\n```\ncode\n\nstill code\n```\n\n- a\n\n> quote\n\n    indented\n";
+        assert_eq!(super::last_block_boundary(source), source.find("```"));
+        assert_eq!(super::last_block_boundary("Intro\n\nNext\n"), Some(7));
+        assert_eq!(super::last_block_boundary("Intro\nNext\n"), None);
+    }
+
+    #[test]
+    fn block_boundaries_stop_at_possible_reference_links() {
+        let source = "Intro\n\nA [link](https://example.com) and `a[i]`.\n\nNext\n";
+        assert_eq!(super::last_block_boundary(source), source.find("Next"));
+        assert_eq!(
+            super::last_block_boundary("Intro\n\nSee [docs][d].\n\nMore\n"),
+            Some(7)
+        );
+        assert_eq!(
+            super::last_block_boundary("Intro\n\n[d]: https://example.com\n\nMore\n"),
+            Some(7)
+        );
+    }
+
+    /// Feed `source` one line per delta, committing after each.
+    fn stream_by_line(
+        c: &mut super::MarkdownStreamCollector,
+        source: &str,
+    ) -> Vec<MarkdownLogicalLine> {
+        let mut streamed = Vec::new();
+        for delta in source.split_inclusive('\n') {
+            c.push_delta(delta);
+            streamed.extend(c.commit_complete_lines());
+        }
+        streamed
+    }
+
+    #[tokio::test]
+    async fn long_messages_freeze_committed_blocks_and_match_full_render() {
+        let mut full = String::new();
+        for i in 0..200 {
+            full.push_str(&format!(
+                "## Section {i}\n\nParagraph {i} with **bold** text.\n\n"
+            ));
+            full.push_str("- item one\n- item two\n\n");
+            full.push_str("```rust\nfn main() {}\n\nfn other() {}\n```\n\n");
+        }
+        let mut c = super::MarkdownStreamCollector::new();
+        let mut streamed = stream_by_line(&mut c, &full);
+        assert!(
+            c.frozen_line_count > 0,
+            "expected committed blocks to freeze"
+        );
+        assert!(
+            c.buffer.len() < 2 * FREEZE_THRESHOLD_BYTES,
+            "unfrozen source kept growing: {} bytes",
+            c.buffer.len()
+        );
+        streamed.extend(c.finalize_and_drain());
+
+        let rendered = crate::markdown_render::render_markdown_logical_lines(&full);
+        assert_eq!(
+            lines_to_plain_strings(&streamed),
+            lines_to_plain_strings(&rendered)
+        );
+    }
+
+    #[tokio::test]
+    async fn long_messages_keep_reference_links_until_defined() {
+        let mut full = String::new();
+        for i in 0..100 {
+            full.push_str(&format!("Paragraph {i} before the reference.\n\n"));
+        }
+        full.push_str("See [the docs][docs] for details.\n\n");
+        for i in 0..300 {
+            full.push_str(&format!("Paragraph {i} after the reference.\n\n"));
+        }
+        full.push_str("[docs]: https://example.com/docs\n");
+        let mut c = super::MarkdownStreamCollector::new();
+        let mut streamed = stream_by_line(&mut c, &full);
+        assert!(
+            c.frozen_line_count > 0,
+            "expected blocks before the reference to freeze"
+        );
+        assert!(
+            c.buffer.starts_with("See [the docs][docs]"),
+            "the block using the reference must not freeze"
+        );
+        streamed.extend(c.finalize_and_drain());
+
+        let rendered = crate::markdown_render::render_markdown_logical_lines(&full);
+        assert_eq!(
+            lines_to_plain_strings(&streamed),
+            lines_to_plain_strings(&rendered)
+        );
+    }
+
     async fn assert_streamed_equals_full(deltas: &[&str]) {
         let streamed = simulate_stream_markdown_for_tests(deltas, true);
         let streamed_strs = lines_to_plain_strings(&streamed);
@@ -13,6 +13,7 @@ use ratatui::widgets::WidgetRef;
 
 use codex_app_server_protocol::AuthMode;
 use codex_protocol::config_types::ForcedLoginMethod;
+use codex_utils_absolute_path::AbsolutePathBuf;
 
 use crate::LoginStatus;
 use crate::onboarding::auth::AuthModeWidget;
@@ -119,10 +120,17 @@ impl OnboardingScreen {
             TrustDirectorySelection::DontTrust
         };
         if show_trust_screen {
+            let project_config_files = config
+                .config_layer_stack
+                .untrusted_project_config_files()
+                .into_iter()
+                .map(AbsolutePathBuf::into_path_buf)
+                .collect();
             steps.push(Step::TrustDirectory(TrustDirectoryWidget {
                 cwd,
                 codex_home,
                 is_git_repo,
+                project_config_files,
                 selection: None,
                 highlighted,
                 error: None,
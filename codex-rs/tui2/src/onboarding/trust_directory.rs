@@ -31,6 +31,8 @@ pub(crate) struct TrustDirectoryWidget {
     pub codex_home: PathBuf,
     pub cwd: PathBuf,
     pub is_git_repo: bool,
+    /// Project `.codex/config.toml` files that only apply once trusted.
+    pub project_config_files: Vec<PathBuf>,
     pub selection: Option<TrustDirectorySelection>,
     pub highlighted: TrustDirectorySelection,
     pub error: Option<String>,
@@ -40,9 +42,26 @@ pub(crate) struct TrustDirectoryWidget {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TrustDirectorySelection {
     Trust,
+    ReadOnly,
     DontTrust,
 }
 
+impl TrustDirectorySelection {
+    fn previous(self) -> Self {
+        match self {
+            Self::Trust | Self::ReadOnly => Self::Trust,
+            Self::DontTrust => Self::ReadOnly,
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::Trust => Self::ReadOnly,
+            Self::ReadOnly | Self::DontTrust => Self::DontTrust,
+        }
+    }
+}
+
 impl WidgetRef for &TrustDirectoryWidget {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
         let language = self.language;
@@ -68,12 +87,30 @@ impl WidgetRef for &TrustDirectoryWidget {
         );
         column.push("");
 
+        if !self.project_config_files.is_empty() {
+            column.push(
+                Paragraph::new(
+                    tr(language, "onboarding.trust_directory.project_config").to_string(),
+                )
+                .wrap(Wrap { trim: true })
+                .inset(Insets::tlbr(0, 2, 0, 0)),
+            );
+            for file in &self.project_config_files {
+                column.push(Line::from(format!("    {}", file.display())).dim());
+            }
+            column.push("");
+        }
+
         let mut options: Vec<(&str, TrustDirectorySelection)> = Vec::new();
         if self.is_git_repo {
             options.push((
                 tr(language, "onboarding.trust_directory.option.trust_git"),
                 TrustDirectorySelection::Trust,
             ));
+            options.push((
+                tr(language, "onboarding.trust_directory.option.read_only"),
+                TrustDirectorySelection::ReadOnly,
+            ));
             options.push((
                 tr(language, "onboarding.trust_directory.option.dont_trust_git"),
                 TrustDirectorySelection::DontTrust,
@@ -83,6 +120,10 @@ impl WidgetRef for &TrustDirectoryWidget {
                 tr(language, "onboarding.trust_directory.option.trust_no_git"),
                 TrustDirectorySelection::Trust,
             ));
+            options.push((
+                tr(language, "onboarding.trust_directory.option.read_only"),
+                TrustDirectorySelection::ReadOnly,
+            ));
             options.push((
                 tr(
                     language,
@@ -133,15 +174,17 @@ impl KeyboardHandler for TrustDirectoryWidget {
 
         match key_event.code {
             KeyCode::Up | KeyCode::Char('k') => {
-                self.highlighted = TrustDirectorySelection::Trust;
+                self.highlighted = self.highlighted.previous();
             }
             KeyCode::Down | KeyCode::Char('j') => {
-                self.highlighted = TrustDirectorySelection::DontTrust;
+                self.highlighted = self.highlighted.next();
             }
             KeyCode::Char('1') | KeyCode::Char('y') => self.handle_trust(),
-            KeyCode::Char('2') | KeyCode::Char('n') => self.handle_dont_trust(),
+            KeyCode::Char('2') => self.handle_read_only(),
+            KeyCode::Char('3') | KeyCode::Char('n') => self.handle_dont_trust(),
             KeyCode::Enter => match self.highlighted {
                 TrustDirectorySelection::Trust => self.handle_trust(),
+                TrustDirectorySelection::ReadOnly => self.handle_read_only(),
                 TrustDirectorySelection::DontTrust => self.handle_dont_trust(),
             },
             _ => {}
@@ -177,6 +220,25 @@ impl TrustDirectoryWidget {
         self.selection = Some(TrustDirectorySelection::Trust);
     }
 
+    fn handle_read_only(&mut self) {
+        self.highlighted = TrustDirectorySelection::ReadOnly;
+        let target =
+            resolve_root_git_project_for_trust(&self.cwd).unwrap_or_else(|| self.cwd.clone());
+        if let Err(e) = set_project_trust_level(&self.codex_home, &target, TrustLevel::ReadOnly) {
+            tracing::error!("Failed to set project read-only: {e:?}");
+            self.error = Some(tr_args(
+                self.language,
+                "onboarding.trust_directory.error.set_trust_failed",
+                &[
+                    ("path", &target.display().to_string()),
+                    ("err", &e.to_string()),
+                ],
+            ));
+        }
+
+        self.selection = Some(TrustDirectorySelection::ReadOnly);
+    }
+
     fn handle_dont_trust(&mut self) {
         self.highlighted = TrustDirectorySelection::DontTrust;
         let target =
@@ -218,6 +280,7 @@ mod tests {
             codex_home: codex_home.path().to_path_buf(),
             cwd: PathBuf::from("."),
             is_git_repo: false,
+            project_config_files: Vec::new(),
             selection: None,
             highlighted: TrustDirectorySelection::DontTrust,
             error: None,
@@ -236,6 +299,32 @@ mod tests {
         assert_eq!(widget.selection, Some(TrustDirectorySelection::DontTrust));
     }
 
+    #[test]
+    fn read_only_choice_records_read_only_trust() {
+        let codex_home = TempDir::new().expect("temp home");
+        let project = TempDir::new().expect("temp project");
+        let mut widget = TrustDirectoryWidget {
+            codex_home: codex_home.path().to_path_buf(),
+            cwd: project.path().to_path_buf(),
+            is_git_repo: false,
+            project_config_files: Vec::new(),
+            selection: None,
+            highlighted: TrustDirectorySelection::Trust,
+            error: None,
+            language: Language::En,
+        };
+
+        widget.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        assert_eq!(widget.highlighted, TrustDirectorySelection::ReadOnly);
+        widget.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert_eq!(widget.selection, Some(TrustDirectorySelection::ReadOnly));
+        assert_eq!(widget.error, None);
+        let config =
+            std::fs::read_to_string(codex_home.path().join("config.toml")).expect("config");
+        assert!(config.contains(r#"trust_level = "read-only""#));
+    }
+
     #[test]
     fn renders_snapshot_for_git_repo() {
         let codex_home = TempDir::new().expect("temp home");
@@ -243,6 +332,7 @@ mod tests {
             codex_home: codex_home.path().to_path_buf(),
             cwd: PathBuf::from("/workspace/project"),
             is_git_repo: true,
+            project_config_files: Vec::new(),
             selection: None,
             highlighted: TrustDirectorySelection::Trust,
             error: None,
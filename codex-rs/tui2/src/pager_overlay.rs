@@ -1,7 +1,8 @@
 //! Overlay UIs rendered in an alternate screen.
 //!
 //! This module implements the pager-style overlays used by the TUI, including the transcript
-//! overlay (`Ctrl+T`) that renders a full history view separate from the main viewport.
+//! overlay (`Ctrl+T`) that renders a full history view separate from the main viewport, and the
+//! exec output overlay (`Ctrl+O`) that follows a running command's full output.
 //!
 //! The transcript overlay renders committed transcript cells plus an optional render-only live tail
 //! derived from the current in-flight active cell. Because rebuilding wrapped `Line`s on every draw
@@ -22,6 +23,7 @@ use crate::chatwidget::ActiveCellTranscriptKey;
 use crate::history_cell::HistoryCell;
 use crate::history_cell::UserHistoryCell;
 use crate::i18n::tr;
+use crate::i18n::tr_args;
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
 use crate::render::Insets;
@@ -29,10 +31,14 @@ use crate::render::renderable::InsetRenderable;
 use crate::render::renderable::Renderable;
 use crate::style::user_message_style;
 use crate::tui;
+use crate::tui::FrameRequester;
 use crate::tui::TuiEvent;
+use codex_ansi_escape::ansi_escape_line;
 use codex_protocol::config_types::Language;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
+use crossterm::event::KeyEventKind;
+use crossterm::event::KeyModifiers;
 use crossterm::event::MouseEvent;
 use crossterm::event::MouseEventKind;
 use ratatui::buffer::Buffer;
@@ -52,6 +58,7 @@ use ratatui::widgets::Wrap;
 pub(crate) enum Overlay {
     Transcript(TranscriptOverlay),
     Static(StaticOverlay),
+    ExecOutput(ExecOutputOverlay),
 }
 
 impl Overlay {
@@ -83,6 +90,7 @@ impl Overlay {
         match self {
             Overlay::Transcript(o) => o.handle_event(tui, event),
             Overlay::Static(o) => o.handle_event(tui, event),
+            Overlay::ExecOutput(o) => o.handle_event(tui, event),
         }
     }
 
@@ -90,6 +98,7 @@ impl Overlay {
         match self {
             Overlay::Transcript(o) => o.is_done(),
             Overlay::Static(o) => o.is_done(),
+            Overlay::ExecOutput(o) => o.is_done(),
         }
     }
 }
@@ -113,6 +122,10 @@ const KEY_ESC: KeyBinding = key_hint::plain(KeyCode::Esc);
 const KEY_ENTER: KeyBinding = key_hint::plain(KeyCode::Enter);
 const KEY_CTRL_T: KeyBinding = key_hint::ctrl(KeyCode::Char('t'));
 const KEY_CTRL_C: KeyBinding = key_hint::ctrl(KeyCode::Char('c'));
+const KEY_CTRL_O: KeyBinding = key_hint::ctrl(KeyCode::Char('o'));
+const KEY_SLASH: KeyBinding = key_hint::plain(KeyCode::Char('/'));
+const KEY_N: KeyBinding = key_hint::plain(KeyCode::Char('n'));
+const KEY_SHIFT_N: KeyBinding = key_hint::shift(KeyCode::Char('N'));
 
 // Common pager navigation hints rendered on the first line
 fn pager_key_hints(language: Language) -> [(&'static [KeyBinding], &'static str); 3] {
@@ -803,6 +816,364 @@ impl StaticOverlay {
     }
 }
 
+/// Full-screen view of a single command's output that keeps streaming while
+/// the command runs (`Ctrl+O` on a running exec cell).
+///
+/// Output arrives as raw chunks, so the trailing partial line is held in
+/// `pending` until its newline shows up. Completed lines are appended to the
+/// pager incrementally; only a search change rebuilds every renderable.
+pub(crate) struct ExecOutputOverlay {
+    view: PagerView,
+    call_id: String,
+    command: String,
+    lines: Vec<String>,
+    pending: String,
+    exit_code: Option<i32>,
+    search: OutputSearch,
+    frame_requester: FrameRequester,
+    is_done: bool,
+    language: Language,
+}
+
+#[derive(Default)]
+struct OutputSearch {
+    /// Query being typed after `/`; `None` while the prompt is closed.
+    input: Option<String>,
+    query: String,
+    /// Indices into the displayed lines (including the pending partial line).
+    matches: Vec<usize>,
+    current: usize,
+}
+
+impl OutputSearch {
+    fn current_line(&self) -> Option<usize> {
+        self.matches.get(self.current).copied()
+    }
+}
+
+impl ExecOutputOverlay {
+    pub(crate) fn new(
+        call_id: String,
+        command: String,
+        output: &str,
+        frame_requester: FrameRequester,
+        language: Language,
+    ) -> Self {
+        let mut overlay = Self {
+            view: PagerView::new(Vec::new(), String::new(), usize::MAX),
+            call_id,
+            command,
+            lines: Vec::new(),
+            pending: String::new(),
+            exit_code: None,
+            search: OutputSearch::default(),
+            frame_requester,
+            is_done: false,
+            language,
+        };
+        overlay.split_into_lines(output);
+        overlay.rebuild_renderables();
+        overlay.update_title();
+        overlay
+    }
+
+    pub(crate) fn call_id(&self) -> &str {
+        &self.call_id
+    }
+
+    /// Appends a raw output chunk, keeping the view pinned to the bottom if
+    /// it was already there.
+    pub(crate) fn push_output(&mut self, chunk: &str) {
+        if chunk.is_empty() {
+            return;
+        }
+        let follow_bottom = self.view.is_scrolled_to_bottom();
+        let had_pending = !self.pending.is_empty();
+        let first_new = self.lines.len();
+        self.split_into_lines(chunk);
+
+        if had_pending {
+            self.view.renderables.pop();
+        }
+        for idx in first_new..self.lines.len() {
+            self.refresh_match(idx);
+            let renderable = self.line_renderable(idx);
+            self.view.renderables.push(renderable);
+        }
+        if !self.pending.is_empty() {
+            let idx = self.lines.len();
+            self.refresh_match(idx);
+            let renderable = self.line_renderable(idx);
+            self.view.renderables.push(renderable);
+        }
+
+        if follow_bottom {
+            self.view.scroll_offset = usize::MAX;
+        }
+        self.frame_requester.schedule_frame();
+    }
+
+    pub(crate) fn finish(&mut self, exit_code: i32) {
+        self.exit_code = Some(exit_code);
+        self.update_title();
+        self.frame_requester.schedule_frame();
+    }
+
+    fn split_into_lines(&mut self, chunk: &str) {
+        self.pending.push_str(chunk);
+        while let Some(newline) = self.pending.find('\n') {
+            let mut line: String = self.pending.drain(..=newline).collect();
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+            self.lines.push(line);
+        }
+    }
+
+    fn line_count(&self) -> usize {
+        self.lines.len() + usize::from(!self.pending.is_empty())
+    }
+
+    fn line_text(&self, idx: usize) -> &str {
+        self.lines
+            .get(idx)
+            .map(String::as_str)
+            .unwrap_or(self.pending.as_str())
+    }
+
+    fn line_renderable(&self, idx: usize) -> Box<dyn Renderable> {
+        let mut line = ansi_escape_line(self.line_text(idx));
+        if self.search.current_line() == Some(idx) {
+            line = line.reversed();
+        } else if self.search.matches.binary_search(&idx).is_ok() {
+            line = line.underlined();
+        }
+        let paragraph = Paragraph::new(Text::from(line)).wrap(Wrap { trim: false });
+        Box::new(CachedRenderable::new(paragraph))
+    }
+
+    fn rebuild_renderables(&mut self) {
+        self.view.renderables = (0..self.line_count())
+            .map(|idx| self.line_renderable(idx))
+            .collect();
+    }
+
+    fn update_title(&mut self) {
+        let status = match self.exit_code {
+            None => tr(self.language, "pager_overlay.title.exec_output_running").to_string(),
+            Some(code) => tr_args(
+                self.language,
+                "pager_overlay.title.exec_output_exit",
+                &[("code", &code.to_string())],
+            ),
+        };
+        self.view.title = format!(
+            "{} · {} · {status}",
+            tr(self.language, "pager_overlay.title.exec_output"),
+            self.command
+        );
+    }
+
+    fn line_matches(&self, idx: usize) -> bool {
+        if self.search.query.is_empty() {
+            return false;
+        }
+        let plain: String = ansi_escape_line(self.line_text(idx))
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        plain
+            .to_lowercase()
+            .contains(&self.search.query.to_lowercase())
+    }
+
+    /// Keeps the match list current for a line that was just added or grew.
+    fn refresh_match(&mut self, idx: usize) {
+        let matched = self.line_matches(idx);
+        match self.search.matches.binary_search(&idx) {
+            Ok(pos) if !matched => {
+                self.search.matches.remove(pos);
+                self.search.current = self
+                    .search
+                    .current
+                    .min(self.search.matches.len().saturating_sub(1));
+            }
+            Err(pos) if matched => self.search.matches.insert(pos, idx),
+            _ => {}
+        }
+    }
+
+    fn set_query(&mut self, query: String) {
+        self.search.query = query;
+        self.search.matches = (0..self.line_count())
+            .filter(|idx| self.line_matches(*idx))
+            .collect();
+        // Start from the most recent match: in build logs that is usually the
+        // one worth looking at.
+        self.search.current = self.search.matches.len().saturating_sub(1);
+        self.rebuild_renderables();
+        if let Some(idx) = self.search.current_line() {
+            self.view.scroll_chunk_into_view(idx);
+        }
+    }
+
+    fn step_match(&mut self, forward: bool) {
+        let total = self.search.matches.len();
+        if total == 0 {
+            return;
+        }
+        self.search.current = if forward {
+            (self.search.current + 1) % total
+        } else {
+            (self.search.current + total - 1) % total
+        };
+        self.rebuild_renderables();
+        if let Some(idx) = self.search.current_line() {
+            self.view.scroll_chunk_into_view(idx);
+        }
+    }
+
+    fn render_hints(&self, area: Rect, buf: &mut Buffer) {
+        let line1 = Rect::new(area.x, area.y, area.width, 1);
+        let line2 = Rect::new(area.x, area.y.saturating_add(1), area.width, 1);
+        let pager_hints = pager_key_hints(self.language);
+        render_key_hints(line1, buf, &pager_hints);
+
+        if let Some(input) = &self.search.input {
+            Line::from(vec![" /".into(), input.clone().into(), "▏".dim()]).render_ref(line2, buf);
+            return;
+        }
+
+        let mut pairs: Vec<(&[KeyBinding], &str)> = vec![
+            (&[KEY_Q], tr(self.language, "pager_overlay.hint.quit")),
+            (&[KEY_SLASH], tr(self.language, "pager_overlay.hint.search")),
+        ];
+        if !self.search.query.is_empty() {
+            pairs.push((
+                &[KEY_N, KEY_SHIFT_N],
+                tr(self.language, "pager_overlay.hint.search_next"),
+            ));
+        }
+        if self.exit_code.is_none() {
+            pairs.push((&[KEY_END], tr(self.language, "pager_overlay.hint.follow")));
+        }
+        render_key_hints(line2, buf, &pairs);
+
+        if !self.search.query.is_empty() {
+            let status = if self.search.matches.is_empty() {
+                tr(self.language, "pager_overlay.title.search_no_match").to_string()
+            } else {
+                tr_args(
+                    self.language,
+                    "pager_overlay.title.search_matches",
+                    &[
+                        ("current", &(self.search.current + 1).to_string()),
+                        ("total", &self.search.matches.len().to_string()),
+                    ],
+                )
+            };
+            let status = format!(" {status} ");
+            let width = (status.chars().count() as u16).min(line2.width);
+            Span::from(status)
+                .dim()
+                .render_ref(Rect::new(line2.right() - width, line2.y, width, 1), buf);
+        }
+    }
+
+    pub(crate) fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let top_h = area.height.saturating_sub(3);
+        let top = Rect::new(area.x, area.y, area.width, top_h);
+        let bottom = Rect::new(area.x, area.y + top_h, area.width, 3);
+        self.view.render(top, buf);
+        self.render_hints(bottom, buf);
+    }
+
+    /// Handles a key while the `/` prompt is open. Returns `false` for keys
+    /// the prompt does not consume.
+    fn handle_search_input(&mut self, key_event: KeyEvent) -> bool {
+        let Some(input) = self.search.input.as_mut() else {
+            return false;
+        };
+        if !matches!(key_event.kind, KeyEventKind::Press | KeyEventKind::Repeat) {
+            return true;
+        }
+        match key_event.code {
+            KeyCode::Enter => {
+                let query = std::mem::take(input);
+                self.search.input = None;
+                self.set_query(query);
+            }
+            KeyCode::Esc => self.search.input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c)
+                if !key_event
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                input.push(c);
+            }
+            _ => return false,
+        }
+        self.frame_requester.schedule_frame();
+        true
+    }
+}
+
+impl ExecOutputOverlay {
+    pub(crate) fn handle_event(&mut self, tui: &mut tui::Tui, event: TuiEvent) -> Result<()> {
+        match event {
+            TuiEvent::Key(key_event) => {
+                if self.handle_search_input(key_event) {
+                    return Ok(());
+                }
+                match key_event {
+                    e if KEY_Q.is_press(e) || KEY_CTRL_C.is_press(e) || KEY_CTRL_O.is_press(e) => {
+                        self.is_done = true;
+                    }
+                    e if KEY_ESC.is_press(e) => {
+                        if self.search.query.is_empty() {
+                            self.is_done = true;
+                        } else {
+                            self.set_query(String::new());
+                            self.frame_requester.schedule_frame();
+                        }
+                    }
+                    e if KEY_SLASH.is_press(e) => {
+                        self.search.input = Some(String::new());
+                        self.frame_requester.schedule_frame();
+                    }
+                    e if KEY_N.is_press(e) => {
+                        self.step_match(true);
+                        self.frame_requester.schedule_frame();
+                    }
+                    e if KEY_SHIFT_N.is_press(e) || e.code == KeyCode::Char('N') => {
+                        self.step_match(false);
+                        self.frame_requester.schedule_frame();
+                    }
+                    other => self.view.handle_key_event(tui, other)?,
+                }
+                Ok(())
+            }
+            TuiEvent::Mouse(mouse_event) => self.view.handle_mouse_scroll(tui, mouse_event),
+            TuiEvent::Draw => {
+                tui.draw(u16::MAX, |frame| {
+                    self.render(frame.area(), frame.buffer);
+                })?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn is_done(&self) -> bool {
+        self.is_done
+    }
+}
+
 fn render_offset_content(
     area: Rect,
     buf: &mut Buffer,
@@ -1049,6 +1420,7 @@ mod tests {
             "exec-1",
             CommandOutput {
                 exit_code: 0,
+                timed_out: false,
                 aggregated_output: "src\nREADME.md\n".into(),
                 formatted_output: "src\nREADME.md\n".into(),
             },
@@ -1330,4 +1702,44 @@ mod tests {
             "expected view to report at bottom after scrolling to end"
         );
     }
+
+    #[test]
+    fn exec_output_overlay_streams_partial_lines_and_searches() {
+        let mut overlay = ExecOutputOverlay::new(
+            "call-1".to_string(),
+            "cargo build".to_string(),
+            "Compiling a\n\u{1b}[31merror\u{1b}[0m: first\nCompil",
+            FrameRequester::test_dummy(),
+            Language::En,
+        );
+        overlay.push_output("ing b\r\nerror: second\n");
+        assert_eq!(
+            overlay.lines,
+            vec![
+                "Compiling a",
+                "\u{1b}[31merror\u{1b}[0m: first",
+                "Compiling b",
+                "error: second"
+            ]
+        );
+        assert_eq!(overlay.view.renderables.len(), 4);
+
+        overlay.set_query("ERROR".to_string());
+        assert_eq!(overlay.search.matches, vec![1, 3]);
+        assert_eq!(overlay.search.current_line(), Some(3));
+        overlay.step_match(true);
+        assert_eq!(overlay.search.current_line(), Some(1));
+
+        overlay.push_output("error: third");
+        assert_eq!(overlay.search.matches, vec![1, 3, 4]);
+        overlay.finish(101);
+
+        let area = Rect::new(0, 0, 60, 10);
+        let mut buf = Buffer::empty(area);
+        overlay.render(area, &mut buf);
+        let text = buffer_to_text(&buf, area);
+        assert!(text.contains("cargo build · exit 101"), "{text}");
+        assert!(text.contains("error: third"), "{text}");
+        assert!(text.contains("1/3"), "{text}");
+    }
 }
@@ -4,6 +4,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use chrono::DateTime;
+use chrono::Local;
 use chrono::Utc;
 use codex_core::Cursor;
 use codex_core::INTERACTIVE_SESSION_SOURCES;
@@ -12,6 +13,8 @@ use codex_core::ThreadItem;
 use codex_core::ThreadSortKey;
 use codex_core::ThreadsPage;
 use codex_core::config::ConfigBuilder;
+use codex_core::locale_format::format_count;
+use codex_core::locale_format::format_date;
 use codex_core::path_utils;
 use color_eyre::eyre::Result;
 use crossterm::event::KeyCode;
@@ -989,39 +992,25 @@ fn render_empty_state_line(state: &PickerState) -> Line<'static> {
     .into()
 }
 
+/// Sessions older than this show a calendar date instead of "N days ago".
+const RELATIVE_TIME_MAX_DAYS: i64 = 7;
+
 fn human_time_ago(ts: DateTime<Utc>, language: Language) -> String {
     let now = Utc::now();
     let delta = now - ts;
     let secs = delta.num_seconds();
-    if secs < 60 {
-        let n = secs.max(0);
-        tr_args(
-            language,
-            "resume_picker.time.seconds_ago",
-            &[("count", &n.to_string())],
-        )
+    let (key, count) = if secs < 60 {
+        ("resume_picker.time.seconds_ago", secs.max(0))
     } else if secs < 60 * 60 {
-        let m = secs / 60;
-        tr_args(
-            language,
-            "resume_picker.time.minutes_ago",
-            &[("count", &m.to_string())],
-        )
+        ("resume_picker.time.minutes_ago", secs / 60)
     } else if secs < 60 * 60 * 24 {
-        let h = secs / 3600;
-        tr_args(
-            language,
-            "resume_picker.time.hours_ago",
-            &[("count", &h.to_string())],
-        )
+        ("resume_picker.time.hours_ago", secs / 3600)
+    } else if secs < 60 * 60 * 24 * RELATIVE_TIME_MAX_DAYS {
+        ("resume_picker.time.days_ago", secs / (60 * 60 * 24))
     } else {
-        let d = secs / (60 * 60 * 24);
-        tr_args(
-            language,
-            "resume_picker.time.days_ago",
-            &[("count", &d.to_string())],
-        )
-    }
+        return format_date(language, ts.with_timezone(&Local).date_naive());
+    };
+    tr_args(language, key, &[("count", &format_count(language, count))])
 }
 
 fn format_updated_label(row: &Row, language: Language) -> String {
@@ -1187,6 +1176,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn old_sessions_show_localized_date() {
+        let ts = Utc::now() - Duration::days(40);
+        let expected = format_date(Language::ZhCn, ts.with_timezone(&Local).date_naive());
+
+        assert_eq!(human_time_ago(ts, Language::ZhCn), expected);
+        assert_eq!(
+            human_time_ago(Utc::now() - Duration::days(3), Language::En),
+            "3d ago"
+        );
+    }
+
     #[test]
     fn row_preview_uses_first_user_message() {
         let item = make_item("/tmp/a.jsonl", "2025-01-01T00:00:00Z", "real question");
@@ -28,19 +28,25 @@ pub enum SlashCommand {
     Fork,
     Init,
     Compact,
+    Mode,
     Collab,
+    Persona,
     // Undo,
     Diff,
+    Changes,
+    Context,
     Clean,
     Mention,
     Status,
     SddDevelop,
     SddDevelopParallels,
     Mcp,
+    Login,
     Logout,
     Quit,
     Exit,
     Feedback,
+    Ui,
     Rollout,
     TestApproval,
 }
@@ -60,6 +66,8 @@ impl SlashCommand {
                 tr(language, "slash_command.description.exit")
             }
             SlashCommand::Diff => tr(language, "slash_command.description.diff"),
+            SlashCommand::Changes => tr(language, "slash_command.description.changes"),
+            SlashCommand::Context => tr(language, "slash_command.description.context"),
             SlashCommand::Clean => tr(language, "slash_command.description.clean"),
             SlashCommand::Mention => tr(language, "slash_command.description.mention"),
             SlashCommand::Skills => tr(language, "slash_command.description.skills"),
@@ -72,13 +80,17 @@ impl SlashCommand {
             SlashCommand::Lang => tr(language, "slash_command.description.lang"),
             SlashCommand::Spec => tr(language, "slash_command.description.spec"),
             SlashCommand::Preset => tr(language, "slash_command.description.preset"),
+            SlashCommand::Mode => tr(language, "slash_command.description.mode"),
             SlashCommand::Collab => tr(language, "slash_command.description.collab"),
+            SlashCommand::Persona => tr(language, "slash_command.description.persona"),
             SlashCommand::Approvals => tr(language, "slash_command.description.approvals"),
             SlashCommand::ElevateSandbox => {
                 tr(language, "slash_command.description.elevate_sandbox")
             }
             SlashCommand::Mcp => tr(language, "slash_command.description.mcp"),
+            SlashCommand::Login => tr(language, "slash_command.description.login"),
             SlashCommand::Logout => tr(language, "slash_command.description.logout"),
+            SlashCommand::Ui => tr(language, "slash_command.description.ui"),
             SlashCommand::Rollout => tr(language, "slash_command.description.rollout"),
             SlashCommand::TestApproval => tr(language, "slash_command.description.test_approval"),
         }
@@ -107,13 +119,19 @@ impl SlashCommand {
             | SlashCommand::Approvals
             | SlashCommand::ElevateSandbox
             | SlashCommand::Review
+            | SlashCommand::Changes
+            | SlashCommand::Mode
+            | SlashCommand::Persona
+            | SlashCommand::Ui
             | SlashCommand::Logout => false,
             SlashCommand::Diff
+            | SlashCommand::Context
             | SlashCommand::Clean
             | SlashCommand::Mention
             | SlashCommand::Skills
             | SlashCommand::Status
             | SlashCommand::Mcp
+            | SlashCommand::Login
             | SlashCommand::Feedback
             | SlashCommand::Collab
             | SlashCommand::Quit
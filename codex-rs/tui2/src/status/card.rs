@@ -8,6 +8,7 @@ use crate::version::CODEX_CLI_VERSION;
 use chrono::DateTime;
 use chrono::Local;
 use codex_common::create_config_summary_entries;
+use codex_core::auth::TokenRefreshHealth;
 use codex_core::config::Config;
 use codex_core::protocol::NetworkAccess;
 use codex_core::protocol::SandboxPolicy;
@@ -31,6 +32,7 @@ use super::helpers::compose_account_display;
 use super::helpers::compose_agents_summary;
 use super::helpers::compose_model_display;
 use super::helpers::format_directory_display;
+use super::helpers::format_reset_timestamp;
 use super::helpers::format_tokens_compact;
 use super::rate_limits::RateLimitSnapshotDisplay;
 use super::rate_limits::StatusRateLimitData;
@@ -68,6 +70,8 @@ struct StatusHistoryCell {
     agents_summary: String,
     model_provider: Option<String>,
     account: Option<StatusAccountDisplay>,
+    sign_in: Option<TokenRefreshHealth>,
+    captured_at: DateTime<Local>,
     session_id: Option<String>,
     token_usage: StatusTokenUsageData,
     rate_limits: StatusRateLimitData,
@@ -138,6 +142,7 @@ impl StatusHistoryCell {
         let agents_summary = compose_agents_summary(config, config.language);
         let model_provider = format_model_provider(config);
         let account = compose_account_display(auth_manager, plan_type, config.language);
+        let sign_in = auth_manager.token_refresh_health();
         let session_id = session_id.as_ref().map(std::string::ToString::to_string);
         let default_usage = TokenUsage::default();
         let (context_usage, context_window) = match token_info {
@@ -167,6 +172,8 @@ impl StatusHistoryCell {
             agents_summary,
             model_provider,
             account,
+            sign_in,
+            captured_at: now,
             session_id,
             token_usage,
             rate_limits,
@@ -175,9 +182,9 @@ impl StatusHistoryCell {
     }
 
     fn token_usage_spans(&self) -> Vec<Span<'static>> {
-        let total_fmt = format_tokens_compact(self.token_usage.total);
-        let input_fmt = format_tokens_compact(self.token_usage.input);
-        let output_fmt = format_tokens_compact(self.token_usage.output);
+        let total_fmt = format_tokens_compact(self.token_usage.total, self.language);
+        let input_fmt = format_tokens_compact(self.token_usage.input, self.language);
+        let output_fmt = format_tokens_compact(self.token_usage.output, self.language);
 
         vec![
             Span::from(total_fmt),
@@ -195,8 +202,8 @@ impl StatusHistoryCell {
     fn context_window_spans(&self) -> Option<Vec<Span<'static>>> {
         let context = self.token_usage.context_window.as_ref()?;
         let percent = context.percent_remaining;
-        let used_fmt = format_tokens_compact(context.tokens_in_context);
-        let window_fmt = format_tokens_compact(context.window);
+        let used_fmt = format_tokens_compact(context.tokens_in_context, self.language);
+        let window_fmt = format_tokens_compact(context.window, self.language);
 
         let percent_value = percent.to_string();
         Some(vec![
@@ -213,6 +220,43 @@ impl StatusHistoryCell {
         ])
     }
 
+    fn sign_in_spans(&self) -> Option<Vec<Span<'static>>> {
+        let health = self.sign_in.as_ref()?;
+        let refreshed_at = |last_refresh: &DateTime<chrono::Utc>| {
+            format_reset_timestamp(
+                last_refresh.with_timezone(&Local),
+                self.captured_at,
+                self.language,
+            )
+        };
+        let spans = match health {
+            TokenRefreshHealth::Healthy { last_refresh } => {
+                let time = refreshed_at(last_refresh);
+                vec![Span::from(tr_args(
+                    self.language,
+                    "status.sign_in.healthy",
+                    &[("time", time.as_str())],
+                ))]
+            }
+            TokenRefreshHealth::RefreshDue { last_refresh } => {
+                let time = refreshed_at(last_refresh);
+                vec![
+                    Span::from(tr_args(
+                        self.language,
+                        "status.sign_in.refresh_due",
+                        &[("time", time.as_str())],
+                    ))
+                    .cyan(),
+                ]
+            }
+            TokenRefreshHealth::Failed(_) => vec![
+                Span::from(tr(self.language, "status.sign_in.needs_login")).red(),
+                Span::from(tr(self.language, "status.sign_in.login_hint")).dim(),
+            ],
+        };
+        Some(spans)
+    }
+
     fn rate_limit_lines(
         &self,
         available_inner_width: usize,
@@ -369,6 +413,7 @@ impl HistoryCell for StatusHistoryCell {
         let label_approval = tr(language, "status.fields.approval");
         let label_sandbox = tr(language, "status.fields.sandbox");
         let label_account = tr(language, "status.fields.account");
+        let label_sign_in = tr(language, "status.fields.sign_in");
         let label_session = tr(language, "status.fields.session");
         let label_token_usage = tr(language, "status.fields.token_usage");
         let label_context_window = tr(language, "status.fields.context_window");
@@ -391,6 +436,10 @@ impl HistoryCell for StatusHistoryCell {
         if account_value.is_some() {
             push_label(&mut labels, &mut seen, label_account);
         }
+        let sign_in_spans = self.sign_in_spans();
+        if sign_in_spans.is_some() {
+            push_label(&mut labels, &mut seen, label_sign_in);
+        }
         if self.session_id.is_some() {
             push_label(&mut labels, &mut seen, label_session);
         }
@@ -444,6 +493,9 @@ impl HistoryCell for StatusHistoryCell {
         if let Some(account_value) = account_value {
             lines.push(formatter.line(label_account, vec![Span::from(account_value)]));
         }
+        if let Some(spans) = sign_in_spans {
+            lines.push(formatter.line(label_sign_in, spans));
+        }
 
         if let Some(session) = self.session_id.as_ref() {
             lines.push(formatter.line(label_session, vec![Span::from(session.clone())]));
@@ -7,9 +7,12 @@ use chrono::Local;
 use codex_core::AuthManager;
 use codex_core::auth::AuthMode as CoreAuthMode;
 use codex_core::config::Config;
+use codex_core::locale_format::format_month_day;
+use codex_core::locale_format::format_time_on_date;
 use codex_core::project_doc::discover_project_doc_paths;
 use codex_protocol::account::PlanType;
 use codex_protocol::config_types::Language;
+use codex_protocol::num_format::format_decimal_for;
 use std::path::Path;
 use unicode_width::UnicodeWidthStr;
 
@@ -117,45 +120,37 @@ pub(crate) fn compose_account_display(
     }
 }
 
-pub(crate) fn format_tokens_compact(value: i64) -> String {
+pub(crate) fn format_tokens_compact(value: i64, language: Language) -> String {
     let value = value.max(0);
-    if value == 0 {
-        return "0".to_string();
-    }
     if value < 1_000 {
         return value.to_string();
     }
 
-    let value_f64 = value as f64;
-    let (scaled, suffix) = if value >= 1_000_000_000_000 {
-        (value_f64 / 1_000_000_000_000.0, "T")
+    let (scale, suffix) = if value >= 1_000_000_000_000 {
+        (1_000_000_000_000_i64, "T")
     } else if value >= 1_000_000_000 {
-        (value_f64 / 1_000_000_000.0, "B")
+        (1_000_000_000, "B")
     } else if value >= 1_000_000 {
-        (value_f64 / 1_000_000.0, "M")
+        (1_000_000, "M")
     } else {
-        (value_f64 / 1_000.0, "K")
+        (1_000, "K")
     };
 
-    let decimals = if scaled < 10.0 {
+    let scaled = value as f64 / scale as f64;
+    let mut decimals: u32 = if scaled < 10.0 {
         2
     } else if scaled < 100.0 {
         1
     } else {
         0
     };
-
-    let mut formatted = format!("{scaled:.decimals$}");
-    if formatted.contains('.') {
-        while formatted.ends_with('0') {
-            formatted.pop();
-        }
-        if formatted.ends_with('.') {
-            formatted.pop();
-        }
+    let mut digits = (scaled * 10f64.powi(decimals as i32)).round() as i64;
+    while decimals > 0 && digits % 10 == 0 {
+        digits /= 10;
+        decimals -= 1;
     }
 
-    format!("{formatted}{suffix}")
+    format!("{}{suffix}", format_decimal_for(digits, decimals, language))
 }
 
 pub(crate) fn format_directory_display(directory: &Path, max_width: Option<usize>) -> String {
@@ -190,10 +185,8 @@ pub(crate) fn format_reset_timestamp(
     if dt.date_naive() == captured_at.date_naive() {
         time
     } else {
-        match language {
-            Language::ZhCn => format!("{time} · {}", dt.format("%-d %b")),
-            Language::En => format!("{time} on {}", dt.format("%-d %b")),
-        }
+        let date = format_month_day(language, dt.date_naive());
+        format_time_on_date(language, &time, &date)
     }
 }
 
@@ -5,8 +5,11 @@ use chrono::Duration as ChronoDuration;
 use chrono::TimeZone;
 use chrono::Utc;
 use codex_core::AuthManager;
+use codex_core::CodexAuth;
 use codex_core::config::Config;
 use codex_core::config::ConfigBuilder;
+use codex_core::error::RefreshTokenFailedError;
+use codex_core::error::RefreshTokenFailedReason;
 use codex_core::models_manager::manager::ModelsManager;
 use codex_core::protocol::CreditsSnapshot;
 use codex_core::protocol::RateLimitSnapshot;
@@ -216,6 +219,58 @@ async fn status_snapshot_includes_monthly_limit() {
     let sanitized = sanitize_directory(rendered_lines).join("\n");
     assert_snapshot!(sanitized);
 }
+#[tokio::test]
+async fn status_snapshot_shows_sign_in_health() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home).await;
+    config.model = Some("gpt-5.1-codex-max".to_string());
+    config.model_provider_id = "openai".to_string();
+    config.cwd = PathBuf::from("/workspace/tests");
+
+    let usage = TokenUsage::default();
+    let captured_at = chrono::Local
+        .with_ymd_and_hms(2024, 1, 2, 10, 4, 5)
+        .single()
+        .expect("timestamp");
+    let last_refresh = (captured_at - ChronoDuration::hours(2)).with_timezone(&Utc);
+    let model_slug = ModelsManager::get_model_offline(config.model.as_deref());
+
+    let refresh_due = AuthManager::from_auth_for_testing(
+        CodexAuth::create_dummy_chatgpt_auth_refreshed_at_for_testing(last_refresh),
+    );
+    let expired = AuthManager::from_auth_for_testing(
+        CodexAuth::create_dummy_chatgpt_auth_refreshed_at_for_testing(last_refresh),
+    );
+    expired.record_refresh_failure_for_testing(RefreshTokenFailedError::new(
+        RefreshTokenFailedReason::Revoked,
+        "refresh token revoked",
+    ));
+
+    let mut sign_in_rows = Vec::new();
+    for auth_manager in [&refresh_due, &expired] {
+        let composite = new_status_output(
+            &config,
+            auth_manager,
+            None,
+            &usage,
+            &None,
+            None,
+            None,
+            captured_at,
+            &model_slug,
+        );
+        // Keep only the sign-in row, without the card border and padding.
+        let row = render_lines(&composite.display_lines(80))
+            .into_iter()
+            .find(|line| line.contains("Sign-in:"))
+            .expect("sign-in row");
+        let row = row.trim_matches(|c: char| c == '│' || c.is_whitespace());
+        let (label, value) = row.split_once(':').expect("label");
+        sign_in_rows.push(format!("{}: {}", label.trim(), value.trim()));
+    }
+    assert_snapshot!(sign_in_rows.join("\n"));
+}
+
 #[tokio::test]
 async fn status_snapshot_shows_unlimited_credits() {
     let temp_home = TempDir::new().expect("temp home");
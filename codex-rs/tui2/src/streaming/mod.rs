@@ -44,9 +44,10 @@ impl StreamState {
     pub(crate) fn step(&mut self) -> Vec<MarkdownLogicalLine> {
         self.queued_lines.pop_front().into_iter().collect()
     }
-    /// Drain all queued logical lines (used on finalize).
-    pub(crate) fn drain_all(&mut self) -> Vec<MarkdownLogicalLine> {
-        self.queued_lines.drain(..).collect()
+    /// Drain up to `max_lines` queued logical lines (used on finalize).
+    pub(crate) fn drain_n(&mut self, max_lines: usize) -> Vec<MarkdownLogicalLine> {
+        let end = max_lines.min(self.queued_lines.len());
+        self.queued_lines.drain(..end).collect()
     }
     /// True when there is no queued output waiting to be emitted by commit ticks.
     pub(crate) fn is_idle(&self) -> bool {
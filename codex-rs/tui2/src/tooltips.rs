@@ -43,7 +43,7 @@ pub(crate) fn random_tooltip(language: Language) -> Option<String> {
 fn pick_tooltip<R: Rng + ?Sized>(rng: &mut R, language: Language) -> Option<&'static str> {
     let tooltips = match language {
         Language::ZhCn => &*ALL_TOOLTIPS_ZH,
-        Language::En => &*ALL_TOOLTIPS_EN,
+        _ => &*ALL_TOOLTIPS_EN,
     };
     if tooltips.is_empty() {
         None